@@ -0,0 +1,230 @@
+//! Hot Path Benchmarks
+//!
+//! Measures each stage a market data message passes through between Alpaca
+//! and a gRPC client:
+//!
+//! - `decode`: WebSocket frame to domain messages (SIP JSON, OPRA `MessagePack`)
+//! - `fan_out`: broadcast of one message to many stream receivers
+//! - `to_proto`: domain message to proto
+//! - `encode`: proto to a length-prefixed gRPC frame
+//! - `pipeline`: all of the above for a full SIP or OPRA frame
+//!
+//! Throughput is reported in messages per second. Criterion runs on a
+//! single thread, so the figure is per core: divide the peak feed rate by it
+//! to size instances.
+//!
+//! Run: `cargo bench -p alpaca-stream-proxy --bench hot_path`
+
+#![allow(missing_docs, clippy::unwrap_used, clippy::expect_used)]
+
+use std::hint::black_box;
+
+use alpaca_stream_proxy::BroadcastHub;
+use alpaca_stream_proxy::infrastructure::alpaca::{
+    AlpacaMessage, JsonCodec, MsgPackCodec, OptionQuoteMessage, StockQuoteMessage,
+};
+use alpaca_stream_proxy::infrastructure::grpc::server::{
+    option_quote_to_proto, stock_quote_to_proto,
+};
+use alpaca_stream_proxy::proto::{StreamOptionQuotesResponse, StreamQuotesResponse};
+use criterion::{BenchmarkId, Criterion, Throughput, criterion_group, criterion_main};
+use prost::Message;
+use rust_decimal::Decimal;
+
+/// Messages per WebSocket frame (Alpaca batches up to this many under load).
+const FRAME_SIZE: usize = 100;
+
+/// gRPC length-prefixed message header: compression flag + u32 length.
+const GRPC_HEADER_LEN: usize = 5;
+
+// =============================================================================
+// Fixtures
+// =============================================================================
+
+fn stock_quote(i: usize) -> StockQuoteMessage {
+    StockQuoteMessage {
+        msg_type: "q".to_string(),
+        symbol: format!("SYM{}", i % 500),
+        bid_exchange: "V".to_string(),
+        bid_price: Decimal::new(15_000 + i64::try_from(i).unwrap(), 2),
+        bid_size: 100,
+        ask_exchange: "V".to_string(),
+        ask_price: Decimal::new(15_005 + i64::try_from(i).unwrap(), 2),
+        ask_size: 200,
+        timestamp: chrono::Utc::now(),
+        conditions: vec!["R".to_string()],
+        tape: "C".to_string(),
+    }
+}
+
+fn option_quote(i: usize) -> OptionQuoteMessage {
+    OptionQuoteMessage {
+        msg_type: "q".to_string(),
+        symbol: format!("SPY250117C{:08}", 400_000 + i % 500 * 1000),
+        timestamp: chrono::Utc::now(),
+        bid_exchange: "C".to_string(),
+        bid_price: Decimal::new(550, 2),
+        bid_size: 10,
+        ask_exchange: "C".to_string(),
+        ask_price: Decimal::new(560, 2),
+        ask_size: 15,
+        condition: Some("A".to_string()),
+    }
+}
+
+/// A SIP text frame carrying `n` quotes.
+fn sip_quote_frame(n: usize) -> String {
+    let quotes: Vec<_> = (0..n).map(stock_quote).collect();
+    serde_json::to_string(&quotes).unwrap()
+}
+
+/// An OPRA binary frame carrying `n` quotes.
+fn opra_quote_frame(n: usize) -> Vec<u8> {
+    let quotes: Vec<_> = (0..n).map(option_quote).collect();
+    MsgPackCodec::new().encode_named(&quotes).unwrap()
+}
+
+/// Write `message` as a gRPC frame into `buf`, reusing its allocation.
+fn encode_frame<M: Message>(message: &M, buf: &mut Vec<u8>) {
+    let len = message.encoded_len();
+    buf.clear();
+    buf.reserve(GRPC_HEADER_LEN + len);
+    buf.push(0);
+    buf.extend_from_slice(&u32::try_from(len).unwrap().to_be_bytes());
+    message.encode(buf).unwrap();
+}
+
+fn stock_quote_response(quote: &StockQuoteMessage) -> StreamQuotesResponse {
+    StreamQuotesResponse {
+        quote: Some(stock_quote_to_proto(quote)),
+        lag_report: None,
+    }
+}
+
+fn option_quote_response(quote: &OptionQuoteMessage) -> StreamOptionQuotesResponse {
+    StreamOptionQuotesResponse {
+        quote: Some(option_quote_to_proto(quote)),
+        lag_report: None,
+    }
+}
+
+// =============================================================================
+// Benchmarks
+// =============================================================================
+
+fn decode(c: &mut Criterion) {
+    let mut group = c.benchmark_group("decode");
+    group.throughput(Throughput::Elements(FRAME_SIZE as u64));
+
+    let json = JsonCodec::new();
+    let sip_frame = sip_quote_frame(FRAME_SIZE);
+    group.bench_function("sip_json_quotes", |b| {
+        b.iter(|| json.decode(black_box(&sip_frame)).unwrap());
+    });
+
+    let msgpack = MsgPackCodec::new();
+    let opra_frame = opra_quote_frame(FRAME_SIZE);
+    group.bench_function("opra_msgpack_quotes", |b| {
+        b.iter(|| msgpack.decode(black_box(&opra_frame)).unwrap());
+    });
+
+    group.finish();
+}
+
+fn fan_out(c: &mut Criterion) {
+    let mut group = c.benchmark_group("fan_out");
+    group.throughput(Throughput::Elements(1));
+
+    for receivers in [1, 8, 64] {
+        let hub = BroadcastHub::with_defaults();
+        let mut rxs: Vec<_> = (0..receivers).map(|_| hub.stock_quotes_rx()).collect();
+        let quote = stock_quote(0);
+
+        group.bench_with_input(
+            BenchmarkId::new("stock_quote", receivers),
+            &receivers,
+            |b, _| {
+                b.iter(|| {
+                    let _ = hub.send_stock_quote(quote.clone());
+                    for rx in &mut rxs {
+                        black_box(rx.try_recv().unwrap());
+                    }
+                });
+            },
+        );
+    }
+
+    group.finish();
+}
+
+fn to_proto(c: &mut Criterion) {
+    let mut group = c.benchmark_group("to_proto");
+    group.throughput(Throughput::Elements(1));
+
+    let stock = stock_quote(0);
+    group.bench_function("stock_quote", |b| {
+        b.iter(|| stock_quote_to_proto(black_box(&stock)));
+    });
+
+    let option = option_quote(0);
+    group.bench_function("option_quote", |b| {
+        b.iter(|| option_quote_to_proto(black_box(&option)));
+    });
+
+    group.finish();
+}
+
+fn encode(c: &mut Criterion) {
+    let mut group = c.benchmark_group("encode");
+    group.throughput(Throughput::Elements(1));
+
+    let mut buf = Vec::new();
+
+    let stock = stock_quote_response(&stock_quote(0));
+    group.bench_function("stock_quote", |b| {
+        b.iter(|| encode_frame(black_box(&stock), &mut buf));
+    });
+
+    let option = option_quote_response(&option_quote(0));
+    group.bench_function("option_quote", |b| {
+        b.iter(|| encode_frame(black_box(&option), &mut buf));
+    });
+
+    group.finish();
+}
+
+fn pipeline(c: &mut Criterion) {
+    let mut group = c.benchmark_group("pipeline");
+    group.throughput(Throughput::Elements(FRAME_SIZE as u64));
+
+    let mut buf = Vec::new();
+
+    let json = JsonCodec::new();
+    let sip_frame = sip_quote_frame(FRAME_SIZE);
+    group.bench_function("sip_quotes", |b| {
+        b.iter(|| {
+            for message in json.decode(black_box(&sip_frame)).unwrap() {
+                if let AlpacaMessage::StockQuote(quote) = message {
+                    encode_frame(&stock_quote_response(&quote), &mut buf);
+                }
+            }
+        });
+    });
+
+    let msgpack = MsgPackCodec::new();
+    let opra_frame = opra_quote_frame(FRAME_SIZE);
+    group.bench_function("opra_quotes", |b| {
+        b.iter(|| {
+            for message in msgpack.decode(black_box(&opra_frame)).unwrap() {
+                if let AlpacaMessage::OptionQuote(quote) = message {
+                    encode_frame(&option_quote_response(&quote), &mut buf);
+                }
+            }
+        });
+    });
+
+    group.finish();
+}
+
+criterion_group!(benches, decode, fan_out, to_proto, encode, pipeline);
+criterion_main!(benches);
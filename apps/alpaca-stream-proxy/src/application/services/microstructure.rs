@@ -0,0 +1,136 @@
+//! Microstructure Application Service
+//!
+//! Maintains per-symbol microstructure state from the stock trade and quote
+//! streams and publishes throttled signals on the signals channel.
+
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use chrono::{DateTime, Utc};
+use parking_lot::Mutex;
+use rust_decimal::prelude::ToPrimitive;
+use tokio::sync::broadcast;
+use tokio_util::sync::CancellationToken;
+
+use crate::domain::microstructure::{
+    MicrostructureParams, MicrostructureQuote, MicrostructureTrade, SymbolMicrostructure,
+};
+use crate::infrastructure::alpaca::messages::{StockQuoteMessage, StockTradeMessage};
+use crate::infrastructure::broadcast::SharedBroadcastHub;
+
+/// Microstructure service turning trades and quotes into signals.
+pub struct MicrostructureService {
+    broadcast_hub: SharedBroadcastHub,
+    params: MicrostructureParams,
+    symbols: Mutex<HashMap<String, SymbolMicrostructure>>,
+}
+
+impl MicrostructureService {
+    /// Create microstructure service.
+    #[must_use]
+    pub fn new(broadcast_hub: SharedBroadcastHub, params: MicrostructureParams) -> Self {
+        Self {
+            broadcast_hub,
+            params,
+            symbols: Mutex::new(HashMap::new()),
+        }
+    }
+
+    /// Number of symbols currently tracked in memory.
+    #[must_use]
+    pub fn symbols_tracked(&self) -> usize {
+        self.symbols.lock().len()
+    }
+
+    /// Start processing stock trades and quotes from the broadcast hub.
+    pub async fn run(self: Arc<Self>, cancel: CancellationToken) {
+        let mut trade_rx = self.broadcast_hub.stock_trades_rx();
+        let mut quote_rx = self.broadcast_hub.stock_quotes_rx();
+
+        loop {
+            tokio::select! {
+                () = cancel.cancelled() => {
+                    break;
+                }
+                recv_result = trade_rx.recv() => {
+                    match recv_result {
+                        Ok(broadcast) => self.handle_trade(&broadcast.trade),
+                        Err(broadcast::error::RecvError::Lagged(count)) => {
+                            tracing::warn!(lagged = count, "Microstructure trade receiver lagged");
+                        }
+                        Err(broadcast::error::RecvError::Closed) => {
+                            tracing::warn!("Microstructure trade receiver closed");
+                            break;
+                        }
+                    }
+                }
+                recv_result = quote_rx.recv() => {
+                    match recv_result {
+                        Ok(broadcast) => self.handle_quote(&broadcast.quote),
+                        Err(broadcast::error::RecvError::Lagged(count)) => {
+                            tracing::warn!(lagged = count, "Microstructure quote receiver lagged");
+                        }
+                        Err(broadcast::error::RecvError::Closed) => {
+                            tracing::warn!("Microstructure quote receiver closed");
+                            break;
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    /// Update state from a stock trade, publishing a signal if one is due.
+    pub fn handle_trade(&self, message: &StockTradeMessage) {
+        let Some(trade) = stock_trade_to_microstructure(message) else {
+            return;
+        };
+
+        let mut symbols = self.symbols.lock();
+        let state = symbols.entry(message.symbol.clone()).or_default();
+        state.update_from_trade(&trade, &self.params);
+        self.publish_if_due(&message.symbol, state, trade.timestamp);
+        drop(symbols);
+    }
+
+    /// Update state from a stock quote, publishing a signal if one is due.
+    pub fn handle_quote(&self, message: &StockQuoteMessage) {
+        let Some(quote) = stock_quote_to_microstructure(message) else {
+            return;
+        };
+
+        let timestamp = quote.timestamp;
+        let mut symbols = self.symbols.lock();
+        let state = symbols.entry(message.symbol.clone()).or_default();
+        state.update_from_quote(quote, &self.params);
+        self.publish_if_due(&message.symbol, state, timestamp);
+        drop(symbols);
+    }
+
+    fn publish_if_due(&self, symbol: &str, state: &mut SymbolMicrostructure, now: DateTime<Utc>) {
+        if !state.is_due(now, &self.params) {
+            return;
+        }
+        if let Some(signal) = state.signal(symbol, now, &self.params) {
+            state.mark_signal(now);
+            let _ = self.broadcast_hub.send_signal(signal);
+        }
+    }
+}
+
+fn stock_trade_to_microstructure(trade: &StockTradeMessage) -> Option<MicrostructureTrade> {
+    let price = trade.price.to_f64().filter(|price| *price > 0.0)?;
+    (trade.size > 0).then(|| MicrostructureTrade {
+        timestamp: trade.timestamp,
+        price,
+        size: i64::from(trade.size),
+    })
+}
+
+fn stock_quote_to_microstructure(quote: &StockQuoteMessage) -> Option<MicrostructureQuote> {
+    Some(MicrostructureQuote {
+        timestamp: quote.timestamp,
+        bid_price: quote.bid_price.to_f64()?,
+        ask_price: quote.ask_price.to_f64()?,
+    })
+}
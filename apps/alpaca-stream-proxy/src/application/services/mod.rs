@@ -5,5 +5,8 @@
 //! - `SubscriptionService`: Manages client subscriptions and routing
 //! - `HealthService`: Monitors connection health and status
 
+/// Microstructure signal service.
+pub mod microstructure;
+
 /// Scanner orchestration service.
 pub mod scanner;
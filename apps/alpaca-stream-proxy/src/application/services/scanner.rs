@@ -3,11 +3,11 @@
 //! Coordinates scanner domain state updates from live bar streams and emits alerts.
 
 use std::collections::{HashMap, VecDeque};
-use std::str::FromStr;
 use std::sync::Arc;
 
 use chrono::{DateTime, Duration, Utc};
 use rust_decimal::Decimal;
+use rust_decimal::prelude::ToPrimitive;
 use tokio::sync::RwLock;
 use tokio::sync::broadcast;
 use tokio_util::sync::CancellationToken;
@@ -169,7 +169,7 @@ impl ScannerService {
                 recv_result = bar_rx.recv() => {
                     match recv_result {
                         Ok(broadcast) => {
-                            self.process_bar_message(broadcast.bar).await;
+                            self.process_bar_message(Arc::unwrap_or_clone(broadcast.bar)).await;
                         }
                         Err(broadcast::error::RecvError::Lagged(count)) => {
                             tracing::warn!(lagged = count, "Scanner bar receiver lagged");
@@ -268,5 +268,5 @@ fn stock_bar_to_scanner_bar(bar: StockBarMessage) -> Option<ScannerBar> {
 }
 
 fn decimal_to_f64(value: Decimal) -> Option<f64> {
-    f64::from_str(&value.to_string()).ok()
+    value.to_f64()
 }
@@ -0,0 +1,301 @@
+//! Microstructure Domain Types
+//!
+//! Pure domain logic for per-symbol order flow signals: rolling trade
+//! imbalance, tick direction, and quote stability.
+//!
+//! Trades are signed with the Lee-Ready rule: above the prevailing mid is a
+//! buy, below is a sell, and a trade at the mid (or before any quote) takes
+//! the sign of its tick.
+
+use std::collections::VecDeque;
+use std::time::Duration;
+
+use chrono::{DateTime, Utc};
+
+/// Microstructure signal parameters.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct MicrostructureParams {
+    /// Rolling window the imbalance and quote stability cover.
+    pub window: Duration,
+    /// Minimum time between two signals for the same symbol.
+    pub publish_interval: Duration,
+}
+
+impl Default for MicrostructureParams {
+    fn default() -> Self {
+        Self {
+            window: Duration::from_secs(30),
+            publish_interval: Duration::from_secs(1),
+        }
+    }
+}
+
+/// Direction of a trade against the previous trade price (tick test).
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum TickDirection {
+    /// No earlier trade to compare with.
+    #[default]
+    Unknown,
+    /// Traded above the previous price.
+    Up,
+    /// Traded below the previous price.
+    Down,
+    /// Traded at the previous price, last move was up.
+    ZeroUp,
+    /// Traded at the previous price, last move was down.
+    ZeroDown,
+}
+
+impl TickDirection {
+    /// Direction of a trade at `price` following one at `previous` with
+    /// direction `self`.
+    #[must_use]
+    pub fn next(self, previous: f64, price: f64) -> Self {
+        if price > previous {
+            Self::Up
+        } else if price < previous {
+            Self::Down
+        } else {
+            match self {
+                Self::Up | Self::ZeroUp => Self::ZeroUp,
+                Self::Down | Self::ZeroDown => Self::ZeroDown,
+                Self::Unknown => Self::Unknown,
+            }
+        }
+    }
+
+    /// 1 for buyer-initiated ticks, -1 for seller-initiated, 0 if unknown.
+    #[must_use]
+    pub const fn sign(self) -> i64 {
+        match self {
+            Self::Up | Self::ZeroUp => 1,
+            Self::Down | Self::ZeroDown => -1,
+            Self::Unknown => 0,
+        }
+    }
+}
+
+/// Single trade input used by microstructure state updates.
+#[derive(Debug, Clone)]
+pub struct MicrostructureTrade {
+    /// Trade timestamp.
+    pub timestamp: DateTime<Utc>,
+    /// Trade price.
+    pub price: f64,
+    /// Trade size (shares).
+    pub size: i64,
+}
+
+/// Single quote input used by microstructure state updates.
+#[derive(Debug, Clone)]
+pub struct MicrostructureQuote {
+    /// Quote timestamp.
+    pub timestamp: DateTime<Utc>,
+    /// Bid price.
+    pub bid_price: f64,
+    /// Ask price.
+    pub ask_price: f64,
+}
+
+impl MicrostructureQuote {
+    fn mid(&self) -> Option<f64> {
+        (self.bid_price > 0.0 && self.ask_price >= self.bid_price)
+            .then(|| f64::midpoint(self.bid_price, self.ask_price))
+    }
+}
+
+/// Signals published for a symbol.
+#[derive(Debug, Clone, PartialEq)]
+pub struct MicrostructureSignal {
+    /// Symbol.
+    pub symbol: String,
+    /// Timestamp of the event that produced the signal.
+    pub timestamp: DateTime<Utc>,
+    /// Last trade price.
+    pub last_price: f64,
+    /// Tick direction of the last trade.
+    pub tick_direction: TickDirection,
+    /// `(buy - sell) / (buy + sell)` volume over the window, in `[-1, 1]`.
+    pub trade_imbalance: f64,
+    /// Buyer-initiated volume in the window.
+    pub buy_volume: i64,
+    /// Seller-initiated volume in the window.
+    pub sell_volume: i64,
+    /// Trades in the window.
+    pub trade_count: usize,
+    /// Share of quote updates in the window that left the bid and ask
+    /// unchanged, in `[0, 1]`.
+    pub quote_stability: f64,
+    /// Quote updates in the window.
+    pub quote_updates: usize,
+    /// Current spread in basis points of the mid, if quoted.
+    pub spread_bps: Option<f64>,
+    /// Rolling window length.
+    pub window: Duration,
+}
+
+#[derive(Debug, Clone, Copy)]
+struct SignedTrade {
+    timestamp: DateTime<Utc>,
+    /// Size signed by aggressor side (0 if unclassified).
+    signed_size: i64,
+}
+
+#[derive(Debug, Clone, Copy)]
+struct QuoteUpdate {
+    timestamp: DateTime<Utc>,
+    moved: bool,
+}
+
+/// Per-symbol rolling microstructure state.
+#[derive(Debug, Clone, Default)]
+pub struct SymbolMicrostructure {
+    trades: VecDeque<SignedTrade>,
+    quote_updates: VecDeque<QuoteUpdate>,
+    last_price: Option<f64>,
+    tick_direction: TickDirection,
+    quote: Option<MicrostructureQuote>,
+    last_signal_at: Option<DateTime<Utc>>,
+}
+
+impl SymbolMicrostructure {
+    /// Record a trade and return its tick direction.
+    pub fn update_from_trade(
+        &mut self,
+        trade: &MicrostructureTrade,
+        params: &MicrostructureParams,
+    ) -> TickDirection {
+        if let Some(previous) = self.last_price {
+            self.tick_direction = self.tick_direction.next(previous, trade.price);
+        }
+        self.last_price = Some(trade.price);
+
+        let side = match self.quote.as_ref().and_then(MicrostructureQuote::mid) {
+            Some(mid) if trade.price > mid => 1,
+            Some(mid) if trade.price < mid => -1,
+            _ => self.tick_direction.sign(),
+        };
+        self.trades.push_back(SignedTrade {
+            timestamp: trade.timestamp,
+            signed_size: side * trade.size,
+        });
+
+        self.prune(trade.timestamp, params.window);
+        self.tick_direction
+    }
+
+    /// Record a quote update.
+    pub fn update_from_quote(&mut self, quote: MicrostructureQuote, params: &MicrostructureParams) {
+        if let Some(previous) = &self.quote {
+            #[allow(clippy::float_cmp)]
+            let moved =
+                previous.bid_price != quote.bid_price || previous.ask_price != quote.ask_price;
+            self.quote_updates.push_back(QuoteUpdate {
+                timestamp: quote.timestamp,
+                moved,
+            });
+        }
+
+        let timestamp = quote.timestamp;
+        self.quote = Some(quote);
+        self.prune(timestamp, params.window);
+    }
+
+    /// Check whether a signal is due at `now`.
+    ///
+    /// Nothing is due before the first trade.
+    #[must_use]
+    pub fn is_due(&self, now: DateTime<Utc>, params: &MicrostructureParams) -> bool {
+        self.last_price.is_some()
+            && self.last_signal_at.is_none_or(|last| {
+                (now - last)
+                    .to_std()
+                    .is_ok_and(|elapsed| elapsed >= params.publish_interval)
+            })
+    }
+
+    /// Mark signal timestamp for publish throttling.
+    pub const fn mark_signal(&mut self, now: DateTime<Utc>) {
+        self.last_signal_at = Some(now);
+    }
+
+    /// Current signals, or `None` before the first trade.
+    #[must_use]
+    #[allow(clippy::cast_precision_loss)]
+    pub fn signal(
+        &self,
+        symbol: &str,
+        now: DateTime<Utc>,
+        params: &MicrostructureParams,
+    ) -> Option<MicrostructureSignal> {
+        let last_price = self.last_price?;
+
+        let (buy_volume, sell_volume) =
+            self.trades
+                .iter()
+                .fold((0, 0), |(buy, sell), trade| match trade.signed_size {
+                    size if size > 0 => (buy + size, sell),
+                    size => (buy, sell - size),
+                });
+        let classified = buy_volume + sell_volume;
+        let trade_imbalance = if classified > 0 {
+            (buy_volume - sell_volume) as f64 / classified as f64
+        } else {
+            0.0
+        };
+
+        let quote_updates = self.quote_updates.len();
+        let quote_stability = if quote_updates > 0 {
+            let unchanged = self.quote_updates.iter().filter(|q| !q.moved).count();
+            unchanged as f64 / quote_updates as f64
+        } else {
+            1.0
+        };
+
+        let spread_bps = self.quote.as_ref().and_then(|quote| {
+            let mid = quote.mid()?;
+            Some((quote.ask_price - quote.bid_price) / mid * 10_000.0)
+        });
+
+        Some(MicrostructureSignal {
+            symbol: symbol.to_string(),
+            timestamp: now,
+            last_price,
+            tick_direction: self.tick_direction,
+            trade_imbalance,
+            buy_volume,
+            sell_volume,
+            trade_count: self.trades.len(),
+            quote_stability,
+            quote_updates,
+            spread_bps,
+            window: params.window,
+        })
+    }
+
+    /// Drop trades and quote updates older than `window` before `now`.
+    fn prune(&mut self, now: DateTime<Utc>, window: Duration) {
+        let Ok(window) = chrono::Duration::from_std(window) else {
+            return;
+        };
+        let cutoff = now - window;
+
+        while self
+            .trades
+            .front()
+            .is_some_and(|trade| trade.timestamp < cutoff)
+        {
+            let _ = self.trades.pop_front();
+        }
+        while self
+            .quote_updates
+            .front()
+            .is_some_and(|quote| quote.timestamp < cutoff)
+        {
+            let _ = self.quote_updates.pop_front();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,120 @@
+use chrono::{DateTime, Duration, TimeZone, Utc};
+
+use super::{
+    MicrostructureParams, MicrostructureQuote, MicrostructureTrade, SymbolMicrostructure,
+    TickDirection,
+};
+
+fn at(second_offset: i64) -> DateTime<Utc> {
+    Utc.with_ymd_and_hms(2026, 1, 5, 14, 30, 0).unwrap() + Duration::seconds(second_offset)
+}
+
+fn trade(second_offset: i64, price: f64, size: i64) -> MicrostructureTrade {
+    MicrostructureTrade {
+        timestamp: at(second_offset),
+        price,
+        size,
+    }
+}
+
+fn quote(second_offset: i64, bid_price: f64, ask_price: f64) -> MicrostructureQuote {
+    MicrostructureQuote {
+        timestamp: at(second_offset),
+        bid_price,
+        ask_price,
+    }
+}
+
+#[test]
+fn tick_test_carries_last_move_through_zero_ticks() {
+    let mut state = SymbolMicrostructure::default();
+    let params = MicrostructureParams::default();
+
+    let directions: Vec<_> = (0..)
+        .zip([100.0, 100.5, 100.5, 100.2, 100.2, 100.2])
+        .map(|(offset, price)| state.update_from_trade(&trade(offset, price, 100), &params))
+        .collect();
+
+    assert_eq!(
+        directions,
+        [
+            TickDirection::Unknown,
+            TickDirection::Up,
+            TickDirection::ZeroUp,
+            TickDirection::Down,
+            TickDirection::ZeroDown,
+            TickDirection::ZeroDown,
+        ]
+    );
+}
+
+#[test]
+fn imbalance_uses_quote_mid_before_tick() {
+    let mut state = SymbolMicrostructure::default();
+    let params = MicrostructureParams::default();
+
+    state.update_from_quote(quote(0, 99.9, 100.1), &params);
+    state.update_from_trade(&trade(1, 100.1, 300), &params);
+    // Downtick above the mid is still a buy
+    state.update_from_trade(&trade(2, 100.05, 100), &params);
+    // At the mid: falls back to the tick (down)
+    state.update_from_trade(&trade(3, 100.0, 200), &params);
+
+    let signal = state.signal("AAPL", at(3), &params).unwrap();
+    assert_eq!(signal.buy_volume, 400);
+    assert_eq!(signal.sell_volume, 200);
+    assert_eq!(signal.trade_count, 3);
+    assert!((signal.trade_imbalance - 1.0 / 3.0).abs() < 1e-9);
+    assert!((signal.spread_bps.unwrap() - 20.0).abs() < 1e-9);
+}
+
+#[test]
+fn window_drops_old_trades_and_quotes() {
+    let mut state = SymbolMicrostructure::default();
+    let params = MicrostructureParams::default();
+
+    state.update_from_quote(quote(0, 99.9, 100.1), &params);
+    state.update_from_quote(quote(1, 99.8, 100.1), &params);
+    state.update_from_trade(&trade(1, 100.0, 500), &params);
+    state.update_from_trade(&trade(2, 101.0, 500), &params);
+    state.update_from_quote(quote(40, 99.8, 100.1), &params);
+    state.update_from_trade(&trade(40, 99.9, 100), &params);
+
+    let signal = state.signal("AAPL", at(40), &params).unwrap();
+    assert_eq!(signal.trade_count, 1);
+    assert_eq!(signal.sell_volume, 100);
+    assert!((signal.trade_imbalance + 1.0).abs() < f64::EPSILON);
+    assert_eq!(signal.quote_updates, 1);
+    assert!((signal.quote_stability - 1.0).abs() < f64::EPSILON);
+}
+
+#[test]
+fn quote_stability_counts_unchanged_updates() {
+    let mut state = SymbolMicrostructure::default();
+    let params = MicrostructureParams::default();
+
+    for (offset, bid) in [(0, 99.9), (1, 99.9), (2, 99.8), (3, 99.8), (4, 99.8)] {
+        state.update_from_quote(quote(offset, bid, 100.1), &params);
+    }
+    state.update_from_trade(&trade(5, 100.0, 100), &params);
+
+    let signal = state.signal("AAPL", at(5), &params).unwrap();
+    assert_eq!(signal.quote_updates, 4);
+    assert!((signal.quote_stability - 0.75).abs() < f64::EPSILON);
+}
+
+#[test]
+fn signals_are_throttled_per_publish_interval() {
+    let mut state = SymbolMicrostructure::default();
+    let params = MicrostructureParams::default();
+
+    assert!(!state.is_due(at(0), &params));
+    assert!(state.signal("AAPL", at(0), &params).is_none());
+
+    state.update_from_trade(&trade(0, 100.0, 100), &params);
+    assert!(state.is_due(at(0), &params));
+
+    state.mark_signal(at(0));
+    assert!(!state.is_due(at(0), &params));
+    assert!(state.is_due(at(1), &params));
+}
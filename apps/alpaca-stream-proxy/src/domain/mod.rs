@@ -7,6 +7,9 @@
 /// Market data streaming types (quotes, trades, bars).
 pub mod streaming;
 
+/// Per-symbol order flow signals from trades and quotes.
+pub mod microstructure;
+
 /// Scanner domain types and signal detection logic.
 pub mod scanner;
 
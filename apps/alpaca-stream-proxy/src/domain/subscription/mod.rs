@@ -12,6 +12,14 @@
 //!
 //! This allows multiple consumers to subscribe to the same symbol
 //! while maintaining only one upstream subscription.
+//!
+//! # Limits
+//!
+//! Alpaca caps the number of symbols a single connection may subscribe to
+//! (per channel, depending on the market data plan). A manager built with
+//! [`SubscriptionManager::with_limit`] enforces such a cap per subscription
+//! type, either rejecting requests that would exceed it or evicting the
+//! least recently requested symbols to make room.
 
 use std::collections::{HashMap, HashSet};
 
@@ -43,6 +51,18 @@ pub enum SubscriptionType {
 }
 
 impl SubscriptionType {
+    /// Get the type as a string.
+    #[must_use]
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::Quotes => "quotes",
+            Self::Trades => "trades",
+            Self::Bars => "bars",
+            Self::DailyBars => "daily_bars",
+            Self::UpdatedBars => "updated_bars",
+        }
+    }
+
     /// Get all subscription types.
     #[must_use]
     pub const fn all() -> &'static [Self] {
@@ -56,6 +76,86 @@ impl SubscriptionType {
     }
 }
 
+// =============================================================================
+// Subscription Limits
+// =============================================================================
+
+/// What to do when a subscription request would exceed the symbol cap.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum OverflowPolicy {
+    /// Reject the request, leaving existing subscriptions untouched.
+    #[default]
+    Reject,
+    /// Evict the least recently requested symbols to make room.
+    EvictLru,
+}
+
+impl OverflowPolicy {
+    /// Parse a policy name (`reject` or `evict_lru`).
+    ///
+    /// Unknown names fall back to `reject`.
+    #[must_use]
+    pub fn from_str_case_insensitive(s: &str) -> Self {
+        match s.to_lowercase().as_str() {
+            "evict_lru" | "evict" | "lru" => Self::EvictLru,
+            _ => Self::Reject,
+        }
+    }
+
+    /// Get the policy as a string.
+    #[must_use]
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::Reject => "reject",
+            Self::EvictLru => "evict_lru",
+        }
+    }
+}
+
+/// Cap on the number of unique symbols per subscription type.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SubscriptionLimit {
+    /// Maximum unique symbols per subscription type.
+    pub max_symbols: usize,
+    /// Behavior when a request would exceed `max_symbols`.
+    pub overflow: OverflowPolicy,
+}
+
+impl SubscriptionLimit {
+    /// Create a limit that rejects requests over `max_symbols`.
+    #[must_use]
+    pub const fn new(max_symbols: usize) -> Self {
+        Self {
+            max_symbols,
+            overflow: OverflowPolicy::Reject,
+        }
+    }
+
+    /// Set the overflow policy.
+    #[must_use]
+    pub const fn with_overflow(mut self, overflow: OverflowPolicy) -> Self {
+        self.overflow = overflow;
+        self
+    }
+}
+
+/// A subscription request that would exceed the symbol cap.
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+#[error(
+    "{} subscription limit of {limit} symbols exceeded: {active} active, {requested} new requested",
+    sub_type.as_str()
+)]
+pub struct SubscriptionLimitExceeded {
+    /// Subscription type the request was for.
+    pub sub_type: SubscriptionType,
+    /// Configured symbol cap.
+    pub limit: usize,
+    /// Symbols active when the request was made.
+    pub active: usize,
+    /// Symbols in the request that were not already active.
+    pub requested: usize,
+}
+
 // =============================================================================
 // Subscription Changes
 // =============================================================================
@@ -106,6 +206,10 @@ struct TypeSubscriptionState {
     consumer_symbols: HashMap<ConsumerId, HashSet<Symbol>>,
     /// Map from symbol to reference count.
     symbol_refcount: HashMap<Symbol, usize>,
+    /// Map from symbol to the tick it was last requested at.
+    last_requested: HashMap<Symbol, u64>,
+    /// Monotonic tick, advanced on every request.
+    tick: u64,
 }
 
 impl TypeSubscriptionState {
@@ -113,10 +217,13 @@ impl TypeSubscriptionState {
     ///
     /// Returns symbols that need upstream subscription (refcount went 0→1).
     fn add(&mut self, consumer: ConsumerId, symbols: &[Symbol]) -> Vec<Symbol> {
+        self.tick += 1;
         let consumer_set = self.consumer_symbols.entry(consumer).or_default();
         let mut new_upstream = Vec::new();
 
         for symbol in symbols {
+            self.last_requested.insert(symbol.clone(), self.tick);
+
             // Skip if consumer already subscribed
             if consumer_set.contains(symbol) {
                 continue;
@@ -158,6 +265,7 @@ impl TypeSubscriptionState {
                 // Last subscription removed - needs upstream unsubscribe
                 if *refcount == 0 {
                     self.symbol_refcount.remove(symbol);
+                    self.last_requested.remove(symbol);
                     remove_upstream.push(symbol.clone());
                 }
             }
@@ -188,6 +296,7 @@ impl TypeSubscriptionState {
 
                 if *refcount == 0 {
                     self.symbol_refcount.remove(symbol);
+                    self.last_requested.remove(symbol);
                     remove_upstream.push(symbol.clone());
                 }
             }
@@ -196,6 +305,85 @@ impl TypeSubscriptionState {
         remove_upstream
     }
 
+    /// Pick the symbols to evict so that `symbols` fit under `limit`.
+    ///
+    /// Returns an empty list when they already fit, and an error when they
+    /// don't and the policy is to reject, or when evicting every symbol not
+    /// in the request still wouldn't make enough room.
+    fn overflow(
+        &self,
+        sub_type: SubscriptionType,
+        symbols: &[Symbol],
+        limit: SubscriptionLimit,
+    ) -> Result<Vec<Symbol>, SubscriptionLimitExceeded> {
+        let requested: HashSet<&Symbol> = symbols.iter().collect();
+        let new = requested
+            .iter()
+            .filter(|symbol| !self.symbol_refcount.contains_key(**symbol))
+            .count();
+        let excess = (self.symbol_count() + new).saturating_sub(limit.max_symbols);
+        if excess == 0 {
+            return Ok(vec![]);
+        }
+
+        let exceeded = SubscriptionLimitExceeded {
+            sub_type,
+            limit: limit.max_symbols,
+            active: self.symbol_count(),
+            requested: new,
+        };
+        if limit.overflow == OverflowPolicy::Reject {
+            return Err(exceeded);
+        }
+
+        let mut candidates: Vec<(&Symbol, u64)> = self
+            .symbol_refcount
+            .keys()
+            .filter(|symbol| !requested.contains(symbol))
+            .map(|symbol| {
+                (
+                    symbol,
+                    self.last_requested.get(symbol).copied().unwrap_or(0),
+                )
+            })
+            .collect();
+        if candidates.len() < excess {
+            return Err(exceeded);
+        }
+
+        candidates.sort_unstable_by_key(|(_, tick)| *tick);
+        Ok(candidates
+            .into_iter()
+            .take(excess)
+            .map(|(symbol, _)| symbol.clone())
+            .collect())
+    }
+
+    /// Drop symbols for every consumer holding them.
+    ///
+    /// Returns the symbols that were active.
+    fn evict(&mut self, symbols: &[Symbol]) -> Vec<Symbol> {
+        let mut evicted = Vec::new();
+
+        for symbol in symbols {
+            if self.symbol_refcount.remove(symbol).is_none() {
+                continue;
+            }
+            self.last_requested.remove(symbol);
+            evicted.push(symbol.clone());
+        }
+
+        for consumer_set in self.consumer_symbols.values_mut() {
+            for symbol in &evicted {
+                consumer_set.remove(symbol);
+            }
+        }
+        self.consumer_symbols
+            .retain(|_, consumer_set| !consumer_set.is_empty());
+
+        evicted
+    }
+
     /// Get all symbols with active subscriptions.
     fn active_symbols(&self) -> Vec<Symbol> {
         self.symbol_refcount.keys().cloned().collect()
@@ -230,6 +418,7 @@ impl TypeSubscriptionState {
 /// - Per-consumer subscriptions
 /// - Reference counting for shared symbols
 /// - Separate state for quotes, trades, and bars
+/// - An optional cap on unique symbols per subscription type
 ///
 /// # Example
 ///
@@ -239,12 +428,12 @@ impl TypeSubscriptionState {
 /// let manager = SubscriptionManager::new();
 ///
 /// // Consumer 1 subscribes to AAPL quotes
-/// let changes = manager.add_subscriptions(1, SubscriptionType::Quotes, &["AAPL".to_string()]);
+/// let changes = manager.add_subscriptions(1, SubscriptionType::Quotes, &["AAPL".to_string()])?;
 /// assert!(changes.subscribe.contains("AAPL"));
 /// assert!(changes.unsubscribe.is_empty());
 ///
 /// // Consumer 2 also subscribes - no upstream change needed
-/// let changes = manager.add_subscriptions(2, SubscriptionType::Quotes, &["AAPL".to_string()]);
+/// let changes = manager.add_subscriptions(2, SubscriptionType::Quotes, &["AAPL".to_string()])?;
 /// assert!(changes.subscribe.is_empty());
 ///
 /// // Consumer 1 unsubscribes - still subscribed via Consumer 2
@@ -254,8 +443,10 @@ impl TypeSubscriptionState {
 /// // Consumer 2 unsubscribes - now unsubscribe upstream
 /// let changes = manager.remove_subscriptions(2, SubscriptionType::Quotes, &["AAPL".to_string()]);
 /// assert!(changes.unsubscribe.contains("AAPL"));
+/// # Ok::<(), alpaca_stream_proxy::domain::subscription::SubscriptionLimitExceeded>(())
 /// ```
 pub struct SubscriptionManager {
+    limit: Option<SubscriptionLimit>,
     quotes: RwLock<TypeSubscriptionState>,
     trades: RwLock<TypeSubscriptionState>,
     bars: RwLock<TypeSubscriptionState>,
@@ -270,10 +461,11 @@ impl Default for SubscriptionManager {
 }
 
 impl SubscriptionManager {
-    /// Create a new subscription manager.
+    /// Create a new subscription manager with no symbol cap.
     #[must_use]
     pub fn new() -> Self {
         Self {
+            limit: None,
             quotes: RwLock::new(TypeSubscriptionState::default()),
             trades: RwLock::new(TypeSubscriptionState::default()),
             bars: RwLock::new(TypeSubscriptionState::default()),
@@ -282,19 +474,56 @@ impl SubscriptionManager {
         }
     }
 
+    /// Create a subscription manager that caps unique symbols per
+    /// subscription type.
+    #[must_use]
+    pub fn with_limit(limit: SubscriptionLimit) -> Self {
+        Self {
+            limit: Some(limit),
+            ..Self::new()
+        }
+    }
+
+    /// Get the symbol cap, if any.
+    #[must_use]
+    pub const fn limit(&self) -> Option<SubscriptionLimit> {
+        self.limit
+    }
+
     /// Add subscriptions for a consumer.
     ///
-    /// Returns changes that need to be applied upstream.
+    /// Returns changes that need to be applied upstream. Under the
+    /// `EvictLru` policy these may include unsubscribes for symbols evicted
+    /// to stay within the cap; evicted symbols are dropped for every
+    /// consumer holding them.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`SubscriptionLimitExceeded`] if the request would exceed the
+    /// symbol cap and room can't be made for it. No subscriptions are
+    /// changed in that case.
     pub fn add_subscriptions(
         &self,
         consumer: ConsumerId,
         sub_type: SubscriptionType,
         symbols: &[Symbol],
-    ) -> SubscriptionChanges {
-        let state = self.get_state(sub_type);
-        let new_symbols = state.write().add(consumer, symbols);
+    ) -> Result<SubscriptionChanges, SubscriptionLimitExceeded> {
+        let mut state = self.get_state(sub_type).write();
+
+        let evicted = match self.limit {
+            Some(limit) => {
+                let overflow = state.overflow(sub_type, symbols, limit)?;
+                state.evict(&overflow)
+            }
+            None => vec![],
+        };
+        let new_symbols = state.add(consumer, symbols);
+        drop(state);
 
-        SubscriptionChanges::subscribe_only(new_symbols)
+        Ok(SubscriptionChanges {
+            subscribe: new_symbols.into_iter().collect(),
+            unsubscribe: evicted.into_iter().collect(),
+        })
     }
 
     /// Remove subscriptions for a consumer.
@@ -357,6 +586,7 @@ impl SubscriptionManager {
         SubscriptionStats {
             symbol_count: state.symbol_count(),
             consumer_count: state.consumer_count(),
+            max_symbols: self.limit.map(|limit| limit.max_symbols),
         }
     }
 
@@ -395,6 +625,19 @@ pub struct SubscriptionStats {
     pub symbol_count: usize,
     /// Number of consumers.
     pub consumer_count: usize,
+    /// Symbol cap (`None` = unlimited).
+    pub max_symbols: Option<usize>,
+}
+
+impl SubscriptionStats {
+    /// Fraction of the symbol cap in use (`None` = unlimited).
+    #[must_use]
+    #[allow(clippy::cast_precision_loss)]
+    pub fn utilization(&self) -> Option<f64> {
+        self.max_symbols
+            .filter(|max| *max > 0)
+            .map(|max| self.symbol_count as f64 / max as f64)
+    }
 }
 
 /// Overall subscription statistics.
@@ -424,7 +667,9 @@ mod tests {
     fn add_subscription_new_symbol() {
         let manager = SubscriptionManager::new();
 
-        let changes = manager.add_subscriptions(1, SubscriptionType::Quotes, &["AAPL".to_string()]);
+        let changes = manager
+            .add_subscriptions(1, SubscriptionType::Quotes, &["AAPL".to_string()])
+            .unwrap();
 
         assert!(changes.subscribe.contains("AAPL"));
         assert!(changes.unsubscribe.is_empty());
@@ -435,10 +680,14 @@ mod tests {
         let manager = SubscriptionManager::new();
 
         // First consumer
-        manager.add_subscriptions(1, SubscriptionType::Quotes, &["AAPL".to_string()]);
+        manager
+            .add_subscriptions(1, SubscriptionType::Quotes, &["AAPL".to_string()])
+            .unwrap();
 
         // Second consumer - no upstream change needed
-        let changes = manager.add_subscriptions(2, SubscriptionType::Quotes, &["AAPL".to_string()]);
+        let changes = manager
+            .add_subscriptions(2, SubscriptionType::Quotes, &["AAPL".to_string()])
+            .unwrap();
 
         assert!(changes.subscribe.is_empty());
         assert!(changes.unsubscribe.is_empty());
@@ -448,10 +697,14 @@ mod tests {
     fn add_subscription_duplicate_consumer() {
         let manager = SubscriptionManager::new();
 
-        manager.add_subscriptions(1, SubscriptionType::Quotes, &["AAPL".to_string()]);
+        manager
+            .add_subscriptions(1, SubscriptionType::Quotes, &["AAPL".to_string()])
+            .unwrap();
 
         // Same consumer adds same symbol again
-        let changes = manager.add_subscriptions(1, SubscriptionType::Quotes, &["AAPL".to_string()]);
+        let changes = manager
+            .add_subscriptions(1, SubscriptionType::Quotes, &["AAPL".to_string()])
+            .unwrap();
 
         assert!(changes.subscribe.is_empty());
     }
@@ -460,8 +713,12 @@ mod tests {
     fn remove_subscription_with_remaining_consumers() {
         let manager = SubscriptionManager::new();
 
-        manager.add_subscriptions(1, SubscriptionType::Quotes, &["AAPL".to_string()]);
-        manager.add_subscriptions(2, SubscriptionType::Quotes, &["AAPL".to_string()]);
+        manager
+            .add_subscriptions(1, SubscriptionType::Quotes, &["AAPL".to_string()])
+            .unwrap();
+        manager
+            .add_subscriptions(2, SubscriptionType::Quotes, &["AAPL".to_string()])
+            .unwrap();
 
         // Consumer 1 unsubscribes - still have Consumer 2
         let changes =
@@ -474,7 +731,9 @@ mod tests {
     fn remove_subscription_last_consumer() {
         let manager = SubscriptionManager::new();
 
-        manager.add_subscriptions(1, SubscriptionType::Quotes, &["AAPL".to_string()]);
+        manager
+            .add_subscriptions(1, SubscriptionType::Quotes, &["AAPL".to_string()])
+            .unwrap();
 
         let changes =
             manager.remove_subscriptions(1, SubscriptionType::Quotes, &["AAPL".to_string()]);
@@ -486,12 +745,16 @@ mod tests {
     fn consumer_disconnected_cleans_up() {
         let manager = SubscriptionManager::new();
 
-        manager.add_subscriptions(1, SubscriptionType::Quotes, &["AAPL".to_string()]);
-        manager.add_subscriptions(
-            1,
-            SubscriptionType::Trades,
-            &["MSFT".to_string(), "GOOG".to_string()],
-        );
+        manager
+            .add_subscriptions(1, SubscriptionType::Quotes, &["AAPL".to_string()])
+            .unwrap();
+        manager
+            .add_subscriptions(
+                1,
+                SubscriptionType::Trades,
+                &["MSFT".to_string(), "GOOG".to_string()],
+            )
+            .unwrap();
 
         let all_changes = manager.consumer_disconnected(1);
 
@@ -522,8 +785,12 @@ mod tests {
     fn consumer_disconnected_preserves_other_consumers() {
         let manager = SubscriptionManager::new();
 
-        manager.add_subscriptions(1, SubscriptionType::Quotes, &["AAPL".to_string()]);
-        manager.add_subscriptions(2, SubscriptionType::Quotes, &["AAPL".to_string()]);
+        manager
+            .add_subscriptions(1, SubscriptionType::Quotes, &["AAPL".to_string()])
+            .unwrap();
+        manager
+            .add_subscriptions(2, SubscriptionType::Quotes, &["AAPL".to_string()])
+            .unwrap();
 
         let all_changes = manager.consumer_disconnected(1);
 
@@ -539,11 +806,13 @@ mod tests {
     fn active_symbols_returns_subscribed() {
         let manager = SubscriptionManager::new();
 
-        manager.add_subscriptions(
-            1,
-            SubscriptionType::Quotes,
-            &["AAPL".to_string(), "MSFT".to_string()],
-        );
+        manager
+            .add_subscriptions(
+                1,
+                SubscriptionType::Quotes,
+                &["AAPL".to_string(), "MSFT".to_string()],
+            )
+            .unwrap();
 
         let active = manager.active_symbols(SubscriptionType::Quotes);
 
@@ -556,12 +825,16 @@ mod tests {
     fn stats_are_accurate() {
         let manager = SubscriptionManager::new();
 
-        manager.add_subscriptions(
-            1,
-            SubscriptionType::Quotes,
-            &["AAPL".to_string(), "MSFT".to_string()],
-        );
-        manager.add_subscriptions(2, SubscriptionType::Quotes, &["AAPL".to_string()]);
+        manager
+            .add_subscriptions(
+                1,
+                SubscriptionType::Quotes,
+                &["AAPL".to_string(), "MSFT".to_string()],
+            )
+            .unwrap();
+        manager
+            .add_subscriptions(2, SubscriptionType::Quotes, &["AAPL".to_string()])
+            .unwrap();
 
         let stats = manager.stats(SubscriptionType::Quotes);
 
@@ -573,8 +846,12 @@ mod tests {
     fn different_types_are_independent() {
         let manager = SubscriptionManager::new();
 
-        manager.add_subscriptions(1, SubscriptionType::Quotes, &["AAPL".to_string()]);
-        manager.add_subscriptions(1, SubscriptionType::Trades, &["MSFT".to_string()]);
+        manager
+            .add_subscriptions(1, SubscriptionType::Quotes, &["AAPL".to_string()])
+            .unwrap();
+        manager
+            .add_subscriptions(1, SubscriptionType::Trades, &["MSFT".to_string()])
+            .unwrap();
 
         let quote_active = manager.active_symbols(SubscriptionType::Quotes);
         let trade_active = manager.active_symbols(SubscriptionType::Trades);
@@ -599,7 +876,9 @@ mod tests {
         let manager = SubscriptionManager::new();
 
         // Add subscription for consumer 1
-        manager.add_subscriptions(1, SubscriptionType::Quotes, &["AAPL".to_string()]);
+        manager
+            .add_subscriptions(1, SubscriptionType::Quotes, &["AAPL".to_string()])
+            .unwrap();
 
         // Try to remove for consumer 2 (who never subscribed)
         let changes =
@@ -615,7 +894,9 @@ mod tests {
         let manager = SubscriptionManager::new();
 
         // Add subscription for consumer 1
-        manager.add_subscriptions(1, SubscriptionType::Quotes, &["AAPL".to_string()]);
+        manager
+            .add_subscriptions(1, SubscriptionType::Quotes, &["AAPL".to_string()])
+            .unwrap();
 
         // Disconnect consumer 2 (who never subscribed)
         let changes = manager.consumer_disconnected(2);
@@ -641,12 +922,16 @@ mod tests {
     fn consumer_symbols_returns_correct_symbols() {
         let manager = SubscriptionManager::new();
 
-        manager.add_subscriptions(
-            1,
-            SubscriptionType::Quotes,
-            &["AAPL".to_string(), "MSFT".to_string()],
-        );
-        manager.add_subscriptions(2, SubscriptionType::Quotes, &["GOOG".to_string()]);
+        manager
+            .add_subscriptions(
+                1,
+                SubscriptionType::Quotes,
+                &["AAPL".to_string(), "MSFT".to_string()],
+            )
+            .unwrap();
+        manager
+            .add_subscriptions(2, SubscriptionType::Quotes, &["GOOG".to_string()])
+            .unwrap();
 
         let consumer1_symbols = manager.consumer_symbols(1, SubscriptionType::Quotes);
         let consumer2_symbols = manager.consumer_symbols(2, SubscriptionType::Quotes);
@@ -671,11 +956,21 @@ mod tests {
     fn total_stats_covers_all_types() {
         let manager = SubscriptionManager::new();
 
-        manager.add_subscriptions(1, SubscriptionType::Quotes, &["AAPL".to_string()]);
-        manager.add_subscriptions(1, SubscriptionType::Trades, &["MSFT".to_string()]);
-        manager.add_subscriptions(1, SubscriptionType::Bars, &["GOOG".to_string()]);
-        manager.add_subscriptions(2, SubscriptionType::DailyBars, &["TSLA".to_string()]);
-        manager.add_subscriptions(2, SubscriptionType::UpdatedBars, &["NVDA".to_string()]);
+        manager
+            .add_subscriptions(1, SubscriptionType::Quotes, &["AAPL".to_string()])
+            .unwrap();
+        manager
+            .add_subscriptions(1, SubscriptionType::Trades, &["MSFT".to_string()])
+            .unwrap();
+        manager
+            .add_subscriptions(1, SubscriptionType::Bars, &["GOOG".to_string()])
+            .unwrap();
+        manager
+            .add_subscriptions(2, SubscriptionType::DailyBars, &["TSLA".to_string()])
+            .unwrap();
+        manager
+            .add_subscriptions(2, SubscriptionType::UpdatedBars, &["NVDA".to_string()])
+            .unwrap();
 
         let total = manager.total_stats();
 
@@ -701,11 +996,13 @@ mod tests {
     fn add_multiple_symbols_at_once() {
         let manager = SubscriptionManager::new();
 
-        let changes = manager.add_subscriptions(
-            1,
-            SubscriptionType::Quotes,
-            &["AAPL".to_string(), "MSFT".to_string(), "GOOG".to_string()],
-        );
+        let changes = manager
+            .add_subscriptions(
+                1,
+                SubscriptionType::Quotes,
+                &["AAPL".to_string(), "MSFT".to_string(), "GOOG".to_string()],
+            )
+            .unwrap();
 
         assert_eq!(changes.subscribe.len(), 3);
         assert!(changes.subscribe.contains("AAPL"));
@@ -718,14 +1015,18 @@ mod tests {
         let manager = SubscriptionManager::new();
 
         // First consumer subscribes to AAPL
-        manager.add_subscriptions(1, SubscriptionType::Quotes, &["AAPL".to_string()]);
+        manager
+            .add_subscriptions(1, SubscriptionType::Quotes, &["AAPL".to_string()])
+            .unwrap();
 
         // Second consumer subscribes to AAPL and MSFT
-        let changes = manager.add_subscriptions(
-            2,
-            SubscriptionType::Quotes,
-            &["AAPL".to_string(), "MSFT".to_string()],
-        );
+        let changes = manager
+            .add_subscriptions(
+                2,
+                SubscriptionType::Quotes,
+                &["AAPL".to_string(), "MSFT".to_string()],
+            )
+            .unwrap();
 
         // Only MSFT should need upstream subscribe (AAPL already subscribed)
         assert_eq!(changes.subscribe.len(), 1);
@@ -748,7 +1049,8 @@ mod tests {
                     i,
                     SubscriptionType::Quotes,
                     &[format!("SYM{i}"), "SHARED".to_string()],
-                );
+                )
+                .unwrap();
             }));
         }
 
@@ -772,7 +1074,9 @@ mod tests {
 
         // Set up subscriptions first
         for i in 0..10u64 {
-            manager.add_subscriptions(i, SubscriptionType::Quotes, &["SHARED".to_string()]);
+            manager
+                .add_subscriptions(i, SubscriptionType::Quotes, &["SHARED".to_string()])
+                .unwrap();
         }
 
         let mut handles = vec![];
@@ -794,4 +1098,147 @@ mod tests {
         assert_eq!(stats.consumer_count, 0);
         assert_eq!(stats.symbol_count, 0);
     }
+
+    fn symbols(names: &[&str]) -> Vec<Symbol> {
+        names.iter().map(ToString::to_string).collect()
+    }
+
+    #[test]
+    fn limit_reject_leaves_subscriptions_untouched() {
+        let manager = SubscriptionManager::with_limit(SubscriptionLimit::new(2));
+
+        manager
+            .add_subscriptions(1, SubscriptionType::Quotes, &symbols(&["AAPL", "MSFT"]))
+            .unwrap();
+
+        let err = manager
+            .add_subscriptions(2, SubscriptionType::Quotes, &symbols(&["MSFT", "GOOG"]))
+            .unwrap_err();
+
+        assert_eq!(err.sub_type, SubscriptionType::Quotes);
+        assert_eq!(err.limit, 2);
+        assert_eq!(err.active, 2);
+        assert_eq!(err.requested, 1);
+        assert!(
+            manager
+                .consumer_symbols(2, SubscriptionType::Quotes)
+                .is_empty()
+        );
+        assert_eq!(manager.stats(SubscriptionType::Quotes).symbol_count, 2);
+    }
+
+    #[test]
+    fn limit_counts_only_new_symbols() {
+        let manager = SubscriptionManager::with_limit(SubscriptionLimit::new(2));
+
+        manager
+            .add_subscriptions(1, SubscriptionType::Quotes, &symbols(&["AAPL", "MSFT"]))
+            .unwrap();
+
+        // Sharing already-active symbols doesn't count against the cap
+        let changes = manager
+            .add_subscriptions(2, SubscriptionType::Quotes, &symbols(&["AAPL", "MSFT"]))
+            .unwrap();
+
+        assert!(changes.is_empty());
+        // Other subscription types have their own cap
+        manager
+            .add_subscriptions(1, SubscriptionType::Trades, &symbols(&["GOOG", "TSLA"]))
+            .unwrap();
+    }
+
+    #[test]
+    fn limit_evict_lru_drops_least_recently_requested() {
+        let limit = SubscriptionLimit::new(2).with_overflow(OverflowPolicy::EvictLru);
+        let manager = SubscriptionManager::with_limit(limit);
+
+        manager
+            .add_subscriptions(1, SubscriptionType::Quotes, &symbols(&["AAPL"]))
+            .unwrap();
+        manager
+            .add_subscriptions(2, SubscriptionType::Quotes, &symbols(&["MSFT"]))
+            .unwrap();
+        // Re-requesting AAPL makes MSFT the least recently requested
+        manager
+            .add_subscriptions(3, SubscriptionType::Quotes, &symbols(&["AAPL"]))
+            .unwrap();
+
+        let changes = manager
+            .add_subscriptions(3, SubscriptionType::Quotes, &symbols(&["GOOG"]))
+            .unwrap();
+
+        assert!(changes.subscribe.contains("GOOG"));
+        assert!(changes.unsubscribe.contains("MSFT"));
+        assert_eq!(changes.unsubscribe.len(), 1);
+        assert!(
+            manager
+                .consumer_symbols(2, SubscriptionType::Quotes)
+                .is_empty()
+        );
+        assert_eq!(manager.stats(SubscriptionType::Quotes).consumer_count, 2);
+    }
+
+    #[test]
+    fn limit_evict_lru_rejects_request_larger_than_cap() {
+        let limit = SubscriptionLimit::new(2).with_overflow(OverflowPolicy::EvictLru);
+        let manager = SubscriptionManager::with_limit(limit);
+
+        manager
+            .add_subscriptions(1, SubscriptionType::Quotes, &symbols(&["AAPL"]))
+            .unwrap();
+
+        let result = manager.add_subscriptions(
+            2,
+            SubscriptionType::Quotes,
+            &symbols(&["MSFT", "GOOG", "TSLA"]),
+        );
+
+        assert!(result.is_err());
+        assert_eq!(
+            manager.active_symbols(SubscriptionType::Quotes),
+            vec!["AAPL".to_string()]
+        );
+    }
+
+    #[test]
+    fn stats_report_utilization() {
+        let manager = SubscriptionManager::with_limit(SubscriptionLimit::new(4));
+
+        manager
+            .add_subscriptions(1, SubscriptionType::Quotes, &symbols(&["AAPL"]))
+            .unwrap();
+
+        let stats = manager.stats(SubscriptionType::Quotes);
+        assert_eq!(stats.max_symbols, Some(4));
+        assert!(
+            stats
+                .utilization()
+                .is_some_and(|u| (u - 0.25).abs() < f64::EPSILON)
+        );
+
+        let unlimited = SubscriptionManager::new();
+        assert!(
+            unlimited
+                .stats(SubscriptionType::Quotes)
+                .utilization()
+                .is_none()
+        );
+    }
+
+    #[test]
+    fn overflow_policy_parsing() {
+        assert_eq!(
+            OverflowPolicy::from_str_case_insensitive("EVICT_LRU"),
+            OverflowPolicy::EvictLru
+        );
+        assert_eq!(
+            OverflowPolicy::from_str_case_insensitive("reject"),
+            OverflowPolicy::Reject
+        );
+        assert_eq!(
+            OverflowPolicy::from_str_case_insensitive("unknown"),
+            OverflowPolicy::Reject
+        );
+        assert_eq!(OverflowPolicy::EvictLru.as_str(), "evict_lru");
+    }
 }
@@ -2,7 +2,8 @@
 //!
 //! Provides encoding and decoding for Alpaca WebSocket streams.
 //!
-//! - **SIP/IEX Streams**: JSON codec for stock data
+//! - **SIP/IEX Streams**: JSON codec for stock data, or `MessagePack` when
+//!   negotiated (see [`WireFormat`])
 //! - **OPRA Stream**: `MessagePack` codec for options data
 //! - **Trade Updates**: JSON codec for order events
 //!
@@ -16,11 +17,21 @@
 //! [{"T":"q","S":"AAPL240315C00172500","bp":5.50,...}]
 //! ```
 
+use std::time::Instant;
+
+use rmpv::ValueRef;
+use serde_json::value::RawValue;
+use tokio_tungstenite::tungstenite::Message;
+use tokio_tungstenite::tungstenite::client::IntoClientRequest;
+use tokio_tungstenite::tungstenite::handshake::client::Request;
+use tokio_tungstenite::tungstenite::http::HeaderValue;
+use tokio_tungstenite::tungstenite::http::header::CONTENT_TYPE;
+
 use crate::infrastructure::alpaca::messages::{
     AlpacaMessage, AuthorizationMessage, ErrorMessage, ListeningMessage, OptionQuoteMessage,
-    OptionTradeMessage, StockBarMessage, StockQuoteMessage, StockStatusMessage, StockTradeMessage,
-    SubscriptionMessage, SuccessMessage, TradeUpdateMessage,
+    OptionTradeMessage, SubscriptionMessage, SuccessMessage, TradeUpdateMessage,
 };
+use crate::infrastructure::metrics::{self, FeedType};
 
 /// Codec errors.
 #[derive(Debug, thiserror::Error)]
@@ -50,6 +61,72 @@ pub enum CodecError {
     InvalidFormat(String),
 }
 
+/// Encoding of market data WebSocket frames.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum WireFormat {
+    /// JSON text frames.
+    #[default]
+    Json,
+    /// `MessagePack` binary frames.
+    MsgPack,
+}
+
+impl WireFormat {
+    /// Parse a wire format name (`json` or `msgpack`).
+    ///
+    /// Unknown names fall back to `json`.
+    #[must_use]
+    pub fn from_str_case_insensitive(s: &str) -> Self {
+        match s.to_lowercase().as_str() {
+            "msgpack" | "messagepack" => Self::MsgPack,
+            _ => Self::Json,
+        }
+    }
+
+    /// Get the wire format name.
+    #[must_use]
+    pub const fn as_str(&self) -> &'static str {
+        match self {
+            Self::Json => "json",
+            Self::MsgPack => "msgpack",
+        }
+    }
+
+    /// Build the WebSocket handshake request for `url`.
+    ///
+    /// `MessagePack` is negotiated with a `Content-Type: application/msgpack`
+    /// header. A server that ignores it keeps sending JSON text frames.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `url` is not a valid WebSocket URL.
+    pub fn handshake_request(
+        self,
+        url: &str,
+    ) -> Result<Request, tokio_tungstenite::tungstenite::Error> {
+        let mut request = url.into_client_request()?;
+        if self == Self::MsgPack {
+            request.headers_mut().insert(
+                CONTENT_TYPE,
+                HeaderValue::from_static("application/msgpack"),
+            );
+        }
+        Ok(request)
+    }
+}
+
+/// Message type tag of a JSON message, borrowed from the raw text.
+///
+/// Market data messages carry their type in `T`; trading stream messages
+/// carry it in `stream`. All other fields are skipped.
+#[derive(Debug, serde::Deserialize)]
+struct MessageTag<'a> {
+    #[serde(rename = "T", default)]
+    msg_type: Option<&'a str>,
+    #[serde(default)]
+    stream: Option<&'a str>,
+}
+
 /// JSON codec for SIP/IEX stock streams and trade updates.
 #[derive(Debug, Default, Clone)]
 pub struct JsonCodec;
@@ -88,64 +165,36 @@ impl JsonCodec {
     }
 
     /// Decode a JSON array of messages.
+    ///
+    /// Each element is kept as a borrowed slice of `text` and parsed straight
+    /// into its message type, so no intermediate `serde_json::Value` tree is
+    /// built on the hot path.
     fn decode_array(text: &str) -> Result<Vec<AlpacaMessage>, CodecError> {
-        // First try to parse as an array of raw values to inspect types
-        let raw_array: Vec<serde_json::Value> = serde_json::from_str(text)?;
-
-        if raw_array.is_empty() {
-            return Ok(vec![]);
-        }
+        let raw_array: Vec<&RawValue> = serde_json::from_str(text)?;
 
         let mut messages = Vec::with_capacity(raw_array.len());
 
-        for value in raw_array {
+        for raw in raw_array {
+            let json = raw.get();
+
             // Determine message type from "T" field or "stream" field
-            let msg_type = value
-                .get("T")
-                .and_then(|v| v.as_str())
-                .or_else(|| value.get("stream").and_then(|v| v.as_str()));
-
-            let message = match msg_type {
-                Some("success") => {
-                    let m: SuccessMessage = serde_json::from_value(value)?;
-                    AlpacaMessage::Success(m)
-                }
-                Some("error") => {
-                    let m: ErrorMessage = serde_json::from_value(value)?;
-                    AlpacaMessage::Error(m)
-                }
-                Some("subscription") => {
-                    let m: SubscriptionMessage = serde_json::from_value(value)?;
-                    AlpacaMessage::Subscription(m)
-                }
-                Some("q") => {
-                    let m: StockQuoteMessage = serde_json::from_value(value)?;
-                    AlpacaMessage::StockQuote(m)
-                }
-                Some("t") => {
-                    let m: StockTradeMessage = serde_json::from_value(value)?;
-                    AlpacaMessage::StockTrade(m)
-                }
-                Some("b" | "d" | "u") => {
-                    let m: StockBarMessage = serde_json::from_value(value)?;
-                    AlpacaMessage::StockBar(m)
-                }
-                Some("s") => {
-                    let m: StockStatusMessage = serde_json::from_value(value)?;
-                    AlpacaMessage::StockStatus(m)
-                }
-                Some("trade_updates" | "authorization" | "listening") => {
-                    let m: AlpacaMessage = serde_json::from_value(value)?;
-                    m
+            let tag: MessageTag<'_> = serde_json::from_str(json)?;
+
+            let message = match tag.msg_type.or(tag.stream) {
+                Some("success") => AlpacaMessage::Success(serde_json::from_str(json)?),
+                Some("error") => AlpacaMessage::Error(serde_json::from_str(json)?),
+                Some("subscription") => AlpacaMessage::Subscription(serde_json::from_str(json)?),
+                Some("q") => AlpacaMessage::StockQuote(serde_json::from_str(json)?),
+                Some("t") => AlpacaMessage::StockTrade(serde_json::from_str(json)?),
+                Some("b" | "d" | "u") => AlpacaMessage::StockBar(serde_json::from_str(json)?),
+                Some("s") => AlpacaMessage::StockStatus(serde_json::from_str(json)?),
+                Some("trade_updates" | "authorization" | "listening") | None => {
+                    // Try to parse as a generic AlpacaMessage
+                    serde_json::from_str(json)?
                 }
                 Some(other) => {
                     return Err(CodecError::UnknownMessageType(other.to_string()));
                 }
-                None => {
-                    // Try to parse as a generic AlpacaMessage
-                    let m: AlpacaMessage = serde_json::from_value(value)?;
-                    m
-                }
             };
 
             messages.push(message);
@@ -205,10 +254,22 @@ impl JsonCodec {
     }
 }
 
-/// `MessagePack` codec for OPRA options stream.
+/// `T` tag of a `MessagePack` message map.
+fn message_tag<'v>(value: &'v ValueRef<'_>) -> Option<&'v str> {
+    let ValueRef::Map(entries) = value else {
+        return None;
+    };
+    entries.iter().find_map(|(key, value)| match (key, value) {
+        (ValueRef::String(key), ValueRef::String(tag)) if key.as_str() == Some("T") => tag.as_str(),
+        _ => None,
+    })
+}
+
+/// `MessagePack` codec for the OPRA options stream, and for stock streams
+/// when they negotiate `MessagePack`.
 ///
-/// The OPRA stream sends binary `MessagePack`-encoded messages for efficiency.
-/// Messages are typically arrays of quote/trade objects.
+/// Binary `MessagePack` frames are cheaper to parse than JSON at high
+/// message rates. Messages are typically arrays of quote/trade objects.
 #[derive(Debug, Default, Clone)]
 pub struct MsgPackCodec;
 
@@ -219,57 +280,77 @@ impl MsgPackCodec {
         Self
     }
 
-    /// Decode binary `MessagePack` data into Alpaca messages.
+    /// Decode binary `MessagePack` data from the OPRA stream into Alpaca messages.
     ///
-    /// OPRA messages are sent as arrays where each element is a message object.
-    /// We try to decode as option quotes first (most common), then trades.
+    /// OPRA messages are sent as arrays where each element is a message
+    /// object, decoded by its `T` tag.
     ///
     /// # Errors
     ///
     /// Returns an error if `MessagePack` parsing fails or the message format is invalid.
     pub fn decode(&self, data: &[u8]) -> Result<Vec<AlpacaMessage>, CodecError> {
-        // Try to decode as array of option quotes first (most common case)
-        if let Ok(quotes) = rmp_serde::from_slice::<Vec<OptionQuoteMessage>>(data)
-            && !quotes.is_empty()
-            && quotes.iter().all(|q| q.msg_type == "q")
-        {
-            return Ok(quotes.into_iter().map(AlpacaMessage::OptionQuote).collect());
-        }
-
-        // Try as array of option trades
-        if let Ok(trades) = rmp_serde::from_slice::<Vec<OptionTradeMessage>>(data)
-            && !trades.is_empty()
-            && trades.iter().all(|t| t.msg_type == "t")
-        {
-            return Ok(trades.into_iter().map(AlpacaMessage::OptionTrade).collect());
-        }
+        Self::decode_array(data, |tag, raw| match tag {
+            "q" => Ok(AlpacaMessage::OptionQuote(rmp_serde::from_slice(raw)?)),
+            "t" => Ok(AlpacaMessage::OptionTrade(rmp_serde::from_slice(raw)?)),
+            _ => Self::decode_control(tag, raw),
+        })
+    }
 
-        // Try as array of success messages (connection/auth)
-        if let Ok(msgs) = rmp_serde::from_slice::<Vec<SuccessMessage>>(data)
-            && !msgs.is_empty()
-        {
-            return Ok(msgs.into_iter().map(AlpacaMessage::Success).collect());
-        }
+    /// Decode binary `MessagePack` data from the SIP/IEX stream into Alpaca messages.
+    ///
+    /// Stock streams send `MessagePack` when the connection asks for it with a
+    /// `Content-Type: application/msgpack` handshake header. Messages have the
+    /// same shape as their JSON counterparts.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `MessagePack` parsing fails or the message format is invalid.
+    pub fn decode_stock(&self, data: &[u8]) -> Result<Vec<AlpacaMessage>, CodecError> {
+        Self::decode_array(data, |tag, raw| match tag {
+            "q" => Ok(AlpacaMessage::StockQuote(rmp_serde::from_slice(raw)?)),
+            "t" => Ok(AlpacaMessage::StockTrade(rmp_serde::from_slice(raw)?)),
+            "b" | "d" | "u" => Ok(AlpacaMessage::StockBar(rmp_serde::from_slice(raw)?)),
+            "s" => Ok(AlpacaMessage::StockStatus(rmp_serde::from_slice(raw)?)),
+            _ => Self::decode_control(tag, raw),
+        })
+    }
 
-        // Try as array of error messages
-        if let Ok(msgs) = rmp_serde::from_slice::<Vec<ErrorMessage>>(data)
-            && !msgs.is_empty()
-        {
-            return Ok(msgs.into_iter().map(AlpacaMessage::Error).collect());
+    /// Decode a control message common to all market data streams.
+    fn decode_control(tag: &str, raw: &[u8]) -> Result<AlpacaMessage, CodecError> {
+        match tag {
+            "success" => Ok(AlpacaMessage::Success(rmp_serde::from_slice(raw)?)),
+            "error" => Ok(AlpacaMessage::Error(rmp_serde::from_slice(raw)?)),
+            "subscription" => Ok(AlpacaMessage::Subscription(rmp_serde::from_slice(raw)?)),
+            other => Err(CodecError::UnknownMessageType(other.to_string())),
         }
+    }
 
-        // Try to read as raw Value to check for empty array
-        let mut cursor = std::io::Cursor::new(data);
-        if let Ok(raw_value) = rmpv::decode::read_value(&mut cursor)
-            && let Some(arr) = raw_value.as_array()
-            && arr.is_empty()
-        {
-            return Ok(vec![]);
+    /// Decode a `MessagePack` array of messages.
+    ///
+    /// Each element is read as a borrowed value to find its `T` tag, then
+    /// decoded straight from its byte range by `decode_element`.
+    fn decode_array(
+        data: &[u8],
+        decode_element: impl Fn(&str, &[u8]) -> Result<AlpacaMessage, CodecError>,
+    ) -> Result<Vec<AlpacaMessage>, CodecError> {
+        let mut rest = data;
+        let len = rmp::decode::read_array_len(&mut rest)
+            .map_err(|e| CodecError::InvalidFormat(format!("expected message array: {e}")))?;
+
+        let mut messages = Vec::with_capacity(usize::try_from(len).unwrap_or_default());
+
+        for _ in 0..len {
+            let element = rest;
+            let value = rmpv::decode::read_value_ref(&mut rest)
+                .map_err(|e| CodecError::InvalidFormat(e.to_string()))?;
+            let raw = &element[..element.len() - rest.len()];
+
+            let tag = message_tag(&value)
+                .ok_or_else(|| CodecError::InvalidFormat("message without a T tag".to_string()))?;
+            messages.push(decode_element(tag, raw)?);
         }
 
-        Err(CodecError::InvalidFormat(
-            "could not determine message type".to_string(),
-        ))
+        Ok(messages)
     }
 
     /// Decode a single option quote from `MessagePack` bytes.
@@ -312,6 +393,57 @@ impl MsgPackCodec {
     }
 }
 
+/// Decoder for the data frames of a market data feed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FrameCodec {
+    /// SIP/IEX stock messages.
+    Stock,
+    /// OPRA option messages.
+    Option,
+}
+
+impl FrameCodec {
+    const fn feed(self) -> FeedType {
+        match self {
+            Self::Stock => FeedType::Sip,
+            Self::Option => FeedType::Opra,
+        }
+    }
+}
+
+/// Decode a data frame and record its decode time.
+///
+/// Text frames are JSON and binary frames `MessagePack`, whichever format
+/// was negotiated, so a server that falls back to JSON is still read.
+/// Returns the messages with the frame's wire format.
+///
+/// # Errors
+///
+/// Returns an error if the frame fails to decode or is not a text or binary
+/// frame.
+pub fn decode_frame(
+    codec: FrameCodec,
+    msg: &Message,
+) -> Result<(Vec<AlpacaMessage>, WireFormat), CodecError> {
+    let started = Instant::now();
+    let (messages, format) = match (codec, msg) {
+        (_, Message::Text(text)) => (JsonCodec.decode(text)?, WireFormat::Json),
+        (FrameCodec::Stock, Message::Binary(data)) => {
+            (MsgPackCodec.decode_stock(data)?, WireFormat::MsgPack)
+        }
+        (FrameCodec::Option, Message::Binary(data)) => {
+            (MsgPackCodec.decode(data)?, WireFormat::MsgPack)
+        }
+        _ => {
+            return Err(CodecError::InvalidFormat(
+                "expected a text or binary frame".to_string(),
+            ));
+        }
+    };
+    metrics::record_processing_duration(codec.feed(), format, started.elapsed());
+    Ok((messages, format))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -520,6 +652,98 @@ mod tests {
         assert!(matches!(&messages[1], AlpacaMessage::OptionQuote(_)));
     }
 
+    #[test]
+    fn msgpack_codec_decode_mixed_option_batch() {
+        let codec = MsgPackCodec::new();
+        let bytes = rmp_serde::to_vec_named(&serde_json::json!([
+            {"T":"q","S":"SPY240315P00450000","t":"2024-03-12T11:59:38Z","bx":"C","bp":2.5,"bs":20,"ax":"C","ap":2.6,"as":25},
+            {"T":"t","S":"SPY240315P00450000","t":"2024-03-12T11:59:39Z","p":2.55,"s":5,"x":"C"},
+            {"T":"subscription","quotes":["SPY240315P00450000"],"trades":[]}
+        ]))
+        .unwrap();
+
+        let messages = codec.decode(&bytes).unwrap();
+        assert_eq!(messages.len(), 3);
+        assert!(matches!(&messages[0], AlpacaMessage::OptionQuote(_)));
+        assert!(matches!(&messages[1], AlpacaMessage::OptionTrade(_)));
+        assert!(matches!(&messages[2], AlpacaMessage::Subscription(_)));
+    }
+
+    #[test]
+    fn msgpack_codec_decode_stock_messages() {
+        let codec = MsgPackCodec::new();
+        let bytes = rmp_serde::to_vec_named(&serde_json::json!([
+            {"T":"q","S":"AAPL","bx":"Q","bp":150.0,"bs":1,"ax":"P","ap":150.01,"as":2,"t":"2024-01-15T10:00:00Z","z":"C"},
+            {"T":"t","i":123,"S":"AAPL","x":"Q","p":150.005,"s":100,"t":"2024-01-15T10:00:01Z","z":"C"},
+            {"T":"success","msg":"authenticated"}
+        ]))
+        .unwrap();
+
+        let messages = codec.decode_stock(&bytes).unwrap();
+        assert_eq!(messages.len(), 3);
+        match &messages[0] {
+            AlpacaMessage::StockQuote(quote) => {
+                assert_eq!(quote.symbol, "AAPL");
+                assert_eq!(quote.ask_price, Decimal::new(15001, 2));
+            }
+            _ => panic!("expected StockQuote message"),
+        }
+        assert!(matches!(&messages[1], AlpacaMessage::StockTrade(_)));
+        assert!(matches!(&messages[2], AlpacaMessage::Success(_)));
+    }
+
+    #[test]
+    fn decode_frame_follows_the_frame_type() {
+        let quote = serde_json::json!([
+            {"T":"q","S":"AAPL","bx":"Q","bp":150.0,"bs":1,"ax":"P","ap":150.01,"as":2,"t":"2024-01-15T10:00:00Z","z":"C"}
+        ]);
+        let binary = Message::Binary(rmp_serde::to_vec_named(&quote).unwrap().into());
+        let text = Message::Text(quote.to_string().into());
+
+        let (messages, format) = decode_frame(FrameCodec::Stock, &binary).unwrap();
+        assert_eq!(format, WireFormat::MsgPack);
+        assert!(matches!(&messages[0], AlpacaMessage::StockQuote(_)));
+
+        let (messages, format) = decode_frame(FrameCodec::Stock, &text).unwrap();
+        assert_eq!(format, WireFormat::Json);
+        assert!(matches!(&messages[0], AlpacaMessage::StockQuote(_)));
+
+        assert!(decode_frame(FrameCodec::Option, &Message::Ping(Vec::new().into())).is_err());
+    }
+
+    #[test]
+    fn msgpack_codec_rejects_unknown_type() {
+        let codec = MsgPackCodec::new();
+        let bytes = rmp_serde::to_vec_named(&serde_json::json!([{"T":"x"}])).unwrap();
+
+        assert!(matches!(
+            codec.decode_stock(&bytes),
+            Err(CodecError::UnknownMessageType(t)) if t == "x"
+        ));
+    }
+
+    #[test]
+    fn wire_format_handshake_header() {
+        assert_eq!(
+            WireFormat::from_str_case_insensitive("MsgPack"),
+            WireFormat::MsgPack
+        );
+        assert_eq!(
+            WireFormat::from_str_case_insensitive("other"),
+            WireFormat::Json
+        );
+
+        let url = "wss://stream.data.alpaca.markets/v2/sip";
+        let json = WireFormat::Json.handshake_request(url).unwrap();
+        assert!(json.headers().get(CONTENT_TYPE).is_none());
+
+        let msgpack = WireFormat::MsgPack.handshake_request(url).unwrap();
+        assert_eq!(
+            msgpack.headers().get(CONTENT_TYPE).unwrap(),
+            "application/msgpack"
+        );
+    }
+
     #[test]
     fn msgpack_codec_decode_empty() {
         let codec = MsgPackCodec::new();
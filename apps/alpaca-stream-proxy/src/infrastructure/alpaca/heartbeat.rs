@@ -2,6 +2,15 @@
 //!
 //! Manages WebSocket connection health through periodic ping/pong messages.
 //! Triggers reconnection when heartbeat timeouts occur.
+//!
+//! Two kinds of silence end a connection:
+//!
+//! - **No response**: nothing at all (pong or data) arrived within
+//!   `pong_timeout` of a ping. This catches half-open TCP connections, where
+//!   writes still succeed but the peer is gone.
+//! - **No data**: the server answers pings but has sent no data frame for
+//!   `data_timeout`. Disabled by default, since feeds go quiet outside market
+//!   hours or without subscriptions.
 
 use std::sync::Arc;
 use std::sync::atomic::{AtomicBool, Ordering};
@@ -18,6 +27,9 @@ pub struct HeartbeatConfig {
     pub ping_interval: Duration,
     /// Timeout for pong response before connection is considered dead.
     pub pong_timeout: Duration,
+    /// Longest gap between data frames before the connection is considered
+    /// stale (`None` = never).
+    pub data_timeout: Option<Duration>,
 }
 
 impl Default for HeartbeatConfig {
@@ -25,6 +37,7 @@ impl Default for HeartbeatConfig {
         Self {
             ping_interval: Duration::from_secs(20),
             pong_timeout: Duration::from_secs(20),
+            data_timeout: None,
         }
     }
 }
@@ -36,6 +49,7 @@ impl HeartbeatConfig {
         Self {
             ping_interval,
             pong_timeout,
+            data_timeout: None,
         }
     }
 
@@ -45,8 +59,27 @@ impl HeartbeatConfig {
         Self {
             ping_interval: settings.heartbeat_interval,
             pong_timeout: settings.heartbeat_timeout,
+            data_timeout: settings.data_timeout,
         }
     }
+
+    /// Tear the connection down after `timeout` without a data frame.
+    #[must_use]
+    pub const fn with_data_timeout(mut self, timeout: Option<Duration>) -> Self {
+        self.data_timeout = timeout;
+        self
+    }
+
+    /// Interval between timeout checks.
+    ///
+    /// A quarter of the shortest timeout, so silence is detected within
+    /// 25% of its window rather than at the next ping.
+    fn check_interval(&self) -> Duration {
+        let shortest = self
+            .data_timeout
+            .map_or(self.pong_timeout, |data| data.min(self.pong_timeout));
+        (shortest / 4).max(Duration::from_millis(10))
+    }
 }
 
 /// Events emitted by the heartbeat manager.
@@ -62,6 +95,7 @@ pub enum HeartbeatEvent {
 #[derive(Debug)]
 pub struct HeartbeatState {
     last_pong: RwLock<Instant>,
+    last_message: RwLock<Instant>,
     waiting_for_pong: AtomicBool,
 }
 
@@ -77,6 +111,7 @@ impl HeartbeatState {
     pub fn new() -> Self {
         Self {
             last_pong: RwLock::new(Instant::now()),
+            last_message: RwLock::new(Instant::now()),
             waiting_for_pong: AtomicBool::new(false),
         }
     }
@@ -87,6 +122,14 @@ impl HeartbeatState {
         self.waiting_for_pong.store(false, Ordering::SeqCst);
     }
 
+    /// Record that a data frame was received.
+    ///
+    /// Data also proves the connection is alive, so it counts as a pong.
+    pub fn record_message(&self) {
+        *self.last_message.write() = Instant::now();
+        self.record_pong();
+    }
+
     /// Mark that we're waiting for a pong.
     pub fn mark_ping_sent(&self) {
         self.waiting_for_pong.store(true, Ordering::SeqCst);
@@ -104,9 +147,16 @@ impl HeartbeatState {
         self.last_pong.read().elapsed()
     }
 
+    /// Get the time since the last data frame.
+    #[must_use]
+    pub fn time_since_message(&self) -> Duration {
+        self.last_message.read().elapsed()
+    }
+
     /// Reset state for new connection.
     pub fn reset(&self) {
         *self.last_pong.write() = Instant::now();
+        *self.last_message.write() = Instant::now();
         self.waiting_for_pong.store(false, Ordering::SeqCst);
     }
 }
@@ -177,8 +227,10 @@ impl HeartbeatManager {
     ///
     /// This method runs until cancelled or a timeout is detected.
     pub async fn run(self) {
-        let mut interval = tokio::time::interval(self.config.ping_interval);
-        interval.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Skip);
+        let mut ping = tokio::time::interval(self.config.ping_interval);
+        ping.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Skip);
+        let mut check = tokio::time::interval(self.config.check_interval());
+        check.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Skip);
 
         loop {
             tokio::select! {
@@ -186,8 +238,15 @@ impl HeartbeatManager {
                     tracing::debug!("Heartbeat manager cancelled");
                     break;
                 }
-                _ = interval.tick() => {
-                    if self.check_and_ping().await.is_err() {
+                _ = ping.tick() => {
+                    if self.event_tx.send(HeartbeatEvent::SendPing).await.is_err() {
+                        tracing::debug!("Event channel closed, stopping heartbeat");
+                        break;
+                    }
+                }
+                _ = check.tick() => {
+                    if self.timed_out() {
+                        let _ = self.event_tx.send(HeartbeatEvent::Timeout).await;
                         break;
                     }
                 }
@@ -195,11 +254,8 @@ impl HeartbeatManager {
         }
     }
 
-    /// Check heartbeat state and send ping if needed.
-    ///
-    /// Returns `Err(())` if a timeout occurred and the loop should exit.
-    async fn check_and_ping(&self) -> Result<(), ()> {
-        // Check for timeout
+    /// Check whether the connection has been silent for too long.
+    fn timed_out(&self) -> bool {
         if self.state.is_waiting_for_pong() {
             let elapsed = self.state.time_since_pong();
             if elapsed > self.config.pong_timeout {
@@ -208,18 +264,23 @@ impl HeartbeatManager {
                     timeout_secs = self.config.pong_timeout.as_secs(),
                     "Heartbeat timeout detected"
                 );
-                let _ = self.event_tx.send(HeartbeatEvent::Timeout).await;
-                return Err(());
+                return true;
             }
         }
 
-        // Send ping request
-        if self.event_tx.send(HeartbeatEvent::SendPing).await.is_err() {
-            tracing::debug!("Event channel closed, stopping heartbeat");
-            return Err(());
+        if let Some(timeout) = self.config.data_timeout {
+            let elapsed = self.state.time_since_message();
+            if elapsed > timeout {
+                tracing::warn!(
+                    elapsed_secs = elapsed.as_secs(),
+                    timeout_secs = timeout.as_secs(),
+                    "No data received within timeout, connection is stale"
+                );
+                return true;
+            }
         }
 
-        Ok(())
+        false
     }
 }
 
@@ -259,6 +320,26 @@ mod tests {
         assert!(!state.is_waiting_for_pong());
     }
 
+    #[test]
+    fn state_record_message_counts_as_pong() {
+        let state = HeartbeatState::new();
+        *state.last_message.write() = Instant::now().checked_sub(Duration::from_secs(5)).unwrap();
+        state.mark_ping_sent();
+
+        state.record_message();
+        assert!(!state.is_waiting_for_pong());
+        assert!(state.time_since_message() < Duration::from_millis(100));
+    }
+
+    #[test]
+    fn check_interval_tracks_shortest_timeout() {
+        let config = HeartbeatConfig::new(Duration::from_secs(20), Duration::from_secs(20));
+        assert_eq!(config.check_interval(), Duration::from_secs(5));
+
+        let config = config.with_data_timeout(Some(Duration::from_secs(8)));
+        assert_eq!(config.check_interval(), Duration::from_secs(2));
+    }
+
     #[test]
     fn state_reset() {
         let state = HeartbeatState::new();
@@ -334,6 +415,33 @@ mod tests {
         let _ = tokio::time::timeout(Duration::from_millis(100), handle).await;
     }
 
+    #[tokio::test]
+    async fn manager_detects_stale_data() {
+        // Pongs keep arriving, but no data does
+        let config = HeartbeatConfig::new(Duration::from_secs(10), Duration::from_secs(10))
+            .with_data_timeout(Some(Duration::from_millis(100)));
+        let state = Arc::new(HeartbeatState::new());
+        let (event_tx, mut event_rx) = mpsc::channel(10);
+        let cancel = CancellationToken::new();
+
+        let manager = HeartbeatManager::new(config, state.clone(), event_tx, cancel.clone());
+        let handle = tokio::spawn(manager.run());
+
+        let mut received_timeout = false;
+        while let Ok(Some(event)) =
+            tokio::time::timeout(Duration::from_millis(500), event_rx.recv()).await
+        {
+            state.record_pong();
+            if matches!(event, HeartbeatEvent::Timeout) {
+                received_timeout = true;
+                break;
+            }
+        }
+
+        assert!(received_timeout, "should time out without data");
+        let _ = tokio::time::timeout(Duration::from_millis(100), handle).await;
+    }
+
     #[tokio::test]
     async fn manager_cancellation() {
         let config = HeartbeatConfig::new(Duration::from_secs(10), Duration::from_secs(10));
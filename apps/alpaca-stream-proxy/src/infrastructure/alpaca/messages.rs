@@ -935,7 +935,7 @@ pub struct ListeningData {
 // =============================================================================
 
 /// Authentication request for market data streams.
-#[derive(Debug, Clone, Serialize)]
+#[derive(Clone, Serialize)]
 pub struct AuthRequest {
     /// Action: "auth"
     pub action: &'static str,
@@ -947,6 +947,16 @@ pub struct AuthRequest {
     pub secret: String,
 }
 
+impl std::fmt::Debug for AuthRequest {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("AuthRequest")
+            .field("action", &self.action)
+            .field("key", &self.key)
+            .field("secret", &"[REDACTED]")
+            .finish()
+    }
+}
+
 impl AuthRequest {
     /// Create a new authentication request.
     #[must_use]
@@ -970,7 +980,7 @@ pub struct TradeAuthRequest {
 }
 
 /// Authentication data for trade updates stream.
-#[derive(Debug, Clone, Serialize)]
+#[derive(Clone, Serialize)]
 pub struct TradeAuthData {
     /// API key
     pub key_id: String,
@@ -979,6 +989,15 @@ pub struct TradeAuthData {
     pub secret_key: String,
 }
 
+impl std::fmt::Debug for TradeAuthData {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("TradeAuthData")
+            .field("key_id", &self.key_id)
+            .field("secret_key", &"[REDACTED]")
+            .finish()
+    }
+}
+
 impl TradeAuthRequest {
     /// Create a new trade authentication request.
     #[must_use]
@@ -1078,6 +1097,13 @@ impl SubscriptionRequest {
         self.daily_bars = symbols;
         self
     }
+
+    /// Add trading status symbols.
+    #[must_use]
+    pub fn with_statuses(mut self, symbols: Vec<String>) -> Self {
+        self.statuses = symbols;
+        self
+    }
 }
 
 /// Listen request for trade updates stream.
@@ -1320,4 +1346,15 @@ mod tests {
         assert!(rate_error.is_rate_limit_error());
         assert!(!rate_error.is_auth_error());
     }
+
+    #[test]
+    fn test_auth_debug_redacts_secret() {
+        let auth = AuthRequest::new("PKTEST".to_string(), "hunter2".to_string());
+        let debug = format!("{auth:?}");
+        assert!(debug.contains("PKTEST"));
+        assert!(!debug.contains("hunter2"));
+
+        let trade_auth = TradeAuthRequest::new("PKTEST".to_string(), "hunter2".to_string());
+        assert!(!format!("{trade_auth:?}").contains("hunter2"));
+    }
 }
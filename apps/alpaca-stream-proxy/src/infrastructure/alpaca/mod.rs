@@ -3,7 +3,8 @@
 //! Implements WebSocket clients for Alpaca's market data streams:
 //!
 //! - **SIP**: Stock quotes, trades, bars (JSON codec)
-//! - **OPRA**: Options quotes, trades (MessagePack codec)
+//! - **OPRA**: Options quotes, trades (MessagePack codec), optionally sharded
+//!   across several connections
 //! - **Trade Updates**: Order fills and updates (JSON codec)
 
 pub mod auth;
@@ -11,17 +12,19 @@ pub mod codec;
 pub mod heartbeat;
 pub mod messages;
 pub mod opra;
+pub mod opra_shards;
 pub mod reconnect;
 pub mod sip;
 pub mod trading;
 
 pub use auth::{AuthError, AuthHandler, AuthMessage, AuthState, Credentials, StreamType};
-pub use codec::{CodecError, JsonCodec, MsgPackCodec};
+pub use codec::{CodecError, JsonCodec, MsgPackCodec, WireFormat};
 pub use heartbeat::{
     HeartbeatConfig, HeartbeatError, HeartbeatEvent, HeartbeatManager, HeartbeatState,
 };
 pub use messages::*;
 pub use opra::{OpraClient, OpraClientConfig, OpraClientError, OpraEvent, OptionSubscriptionState};
+pub use opra_shards::{OpraShardPool, ShardStrategy, option_underlying};
 pub use reconnect::{ReconnectConfig, ReconnectError, ReconnectPolicy};
 pub use sip::{SipClient, SipClientConfig, SipClientError, SipEvent, SubscriptionState};
 pub use trading::{TradingClient, TradingClientConfig, TradingClientError, TradingEvent};
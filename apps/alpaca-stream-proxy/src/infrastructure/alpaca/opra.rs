@@ -12,7 +12,9 @@
 //!
 //! All messages (including auth and subscriptions) use `MessagePack` binary encoding.
 //! This is different from SIP which uses JSON - OPRA requires msgpack format
-//! (error 412 if not used).
+//! (error 412 if not used). With [`WireFormat::MsgPack`] the client also asks
+//! for `MessagePack` in the handshake. JSON text frames, such as a 412 error,
+//! are still decoded so their errors surface.
 
 use std::sync::Arc;
 
@@ -22,10 +24,11 @@ use tokio_tungstenite::tungstenite::Message;
 use tokio_util::sync::CancellationToken;
 
 use super::auth::{AuthHandler, AuthState, Credentials};
-use super::codec::{CodecError, MsgPackCodec};
+use super::codec::{CodecError, FrameCodec, MsgPackCodec, WireFormat, decode_frame};
 use super::heartbeat::{HeartbeatConfig, HeartbeatEvent, HeartbeatManager, HeartbeatState};
 use super::messages::{AlpacaMessage, SubscriptionRequest};
 use super::reconnect::{ReconnectConfig, ReconnectPolicy};
+use crate::infrastructure::metrics::{self, FeedType, MessageType};
 
 // =============================================================================
 // Error Type
@@ -109,6 +112,8 @@ pub struct OpraClientConfig {
     pub reconnect: ReconnectConfig,
     /// Heartbeat configuration.
     pub heartbeat: HeartbeatConfig,
+    /// Frame encoding to request in the handshake.
+    pub wire_format: WireFormat,
 }
 
 impl OpraClientConfig {
@@ -120,9 +125,24 @@ impl OpraClientConfig {
             credentials,
             reconnect: ReconnectConfig::default(),
             heartbeat: HeartbeatConfig::default(),
+            wire_format: WireFormat::default(),
         }
     }
 
+    /// Use `heartbeat` for connection health checks.
+    #[must_use]
+    pub const fn with_heartbeat(mut self, heartbeat: HeartbeatConfig) -> Self {
+        self.heartbeat = heartbeat;
+        self
+    }
+
+    /// Request `wire_format` frames in the handshake.
+    #[must_use]
+    pub const fn with_wire_format(mut self, wire_format: WireFormat) -> Self {
+        self.wire_format = wire_format;
+        self
+    }
+
     /// Create configuration for paper trading environment.
     ///
     /// Uses the `indicative` feed which provides indicative options data
@@ -282,10 +302,18 @@ impl OpraClient {
 
     /// Connect to WebSocket and run until error or cancellation.
     async fn connect_and_run(&self) -> Result<(), OpraClientError> {
-        tracing::info!(url = %self.config.url, "Connecting to OPRA stream");
+        tracing::info!(
+            url = %self.config.url,
+            wire_format = self.config.wire_format.as_str(),
+            "Connecting to OPRA stream"
+        );
 
         // Connect to WebSocket
-        let (ws_stream, _response) = tokio_tungstenite::connect_async(&self.config.url).await?;
+        let request = self
+            .config
+            .wire_format
+            .handshake_request(&self.config.url)?;
+        let (ws_stream, _response) = tokio_tungstenite::connect_async(request).await?;
 
         let (mut write, mut read) = ws_stream.split();
 
@@ -331,11 +359,12 @@ impl OpraClient {
                 }
                 msg = read.next() => {
                     match msg {
-                        Some(Ok(Message::Binary(data))) => {
-                            heartbeat_state.record_pong();
+                        Some(Ok(frame @ (Message::Binary(_) | Message::Text(_)))) => {
+                            heartbeat_state.record_message();
 
-                            self.handle_binary_message(
-                                &data,
+                            let (messages, _) = decode_frame(FrameCodec::Option, &frame)?;
+                            self.handle_messages(
+                                messages,
                                 &mut auth_handler,
                                 &mut write,
                             ).await?;
@@ -344,6 +373,7 @@ impl OpraClient {
                             heartbeat_state.record_pong();
                         }
                         Some(Ok(Message::Ping(data))) => {
+                            heartbeat_state.record_pong();
                             write.send(Message::Pong(data)).await?;
                         }
                         Some(Ok(Message::Close(_))) => {
@@ -352,7 +382,7 @@ impl OpraClient {
                             return Err(OpraClientError::ConnectionClosed);
                         }
                         Some(Ok(_)) => {
-                            // Ignore other message types (e.g., Text for control messages)
+                            // Ignore other message types
                         }
                         Some(Err(e)) => {
                             heartbeat_cancel.cancel();
@@ -369,10 +399,10 @@ impl OpraClient {
         }
     }
 
-    /// Handle a binary `MessagePack` message from the WebSocket.
-    async fn handle_binary_message<W>(
+    /// Handle messages decoded from one WebSocket frame.
+    async fn handle_messages<W>(
         &self,
-        data: &[u8],
+        messages: Vec<AlpacaMessage>,
         auth_handler: &mut AuthHandler,
         write: &mut W,
     ) -> Result<(), OpraClientError>
@@ -380,8 +410,6 @@ impl OpraClient {
         W: SinkExt<Message> + Unpin,
         W::Error: std::fmt::Display,
     {
-        let messages = self.msgpack_codec.decode(data)?;
-
         for msg in messages {
             match msg {
                 AlpacaMessage::Success(success) => {
@@ -440,9 +468,11 @@ impl OpraClient {
                         .await;
                 }
                 AlpacaMessage::OptionQuote(quote) => {
+                    metrics::record_message_received(FeedType::Opra, MessageType::OptionQuote);
                     let _ = self.event_tx.send(OpraEvent::Quote(quote)).await;
                 }
                 AlpacaMessage::OptionTrade(trade) => {
+                    metrics::record_message_received(FeedType::Opra, MessageType::OptionTrade);
                     let _ = self.event_tx.send(OpraEvent::Trade(trade)).await;
                 }
                 _ => {
@@ -584,4 +614,14 @@ mod tests {
         assert!(config.url.contains("stream.data.alpaca.markets"));
         assert!(config.url.contains("/v1beta1/opra"));
     }
+
+    #[test]
+    fn opra_config_wire_format() {
+        let creds = Credentials::new("key", "secret").unwrap();
+        let config = OpraClientConfig::live(creds);
+        assert_eq!(config.wire_format, WireFormat::Json);
+
+        let config = config.with_wire_format(WireFormat::MsgPack);
+        assert_eq!(config.wire_format, WireFormat::MsgPack);
+    }
 }
@@ -0,0 +1,314 @@
+//! Sharded OPRA Connections
+//!
+//! A single OPRA WebSocket can't carry subscriptions to the full chains of
+//! many active underlyings. [`OpraShardPool`] spreads option symbols across
+//! several parallel [`OpraClient`] connections instead.
+//!
+//! # Sharding
+//!
+//! - **Underlying** (default): every contract of an underlying goes to the
+//!   same shard, so a chain is never split across connections.
+//! - **Hash**: each contract is placed independently, which balances better
+//!   when a few underlyings dominate.
+//!
+//! Shard placement uses a fixed FNV-1a hash, so a symbol lands on the same
+//! shard across restarts.
+//!
+//! Each shard emits its own [`OpraEvent`] stream; consumers typically run one
+//! handler per shard, all feeding the same broadcast hub.
+
+use std::sync::Arc;
+
+use tokio::sync::mpsc;
+use tokio_util::sync::CancellationToken;
+
+use super::opra::{OpraClient, OpraClientConfig, OpraEvent};
+
+// =============================================================================
+// Shard Strategy
+// =============================================================================
+
+/// How option symbols are assigned to shards.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ShardStrategy {
+    /// Keep each underlying's chain on one shard.
+    #[default]
+    Underlying,
+    /// Place each contract by its own hash.
+    Hash,
+}
+
+impl ShardStrategy {
+    /// Parse a strategy name (`underlying` or `hash`).
+    ///
+    /// Unknown names fall back to `underlying`.
+    #[must_use]
+    pub fn from_str_case_insensitive(s: &str) -> Self {
+        match s.to_lowercase().as_str() {
+            "hash" => Self::Hash,
+            _ => Self::Underlying,
+        }
+    }
+
+    /// Get the strategy as a string.
+    #[must_use]
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::Underlying => "underlying",
+            Self::Hash => "hash",
+        }
+    }
+
+    /// The part of `symbol` that decides its shard.
+    fn shard_key(self, symbol: &str) -> &str {
+        match self {
+            Self::Underlying => option_underlying(symbol),
+            Self::Hash => symbol,
+        }
+    }
+}
+
+/// Length of the OCC suffix: expiration (6), type (1), strike (8).
+const OCC_SUFFIX_LEN: usize = 15;
+
+/// Underlying root of an OCC option symbol (`AAPL240315C00172500` → `AAPL`).
+///
+/// Symbols too short to carry an OCC suffix are returned unchanged.
+#[must_use]
+pub fn option_underlying(symbol: &str) -> &str {
+    symbol
+        .len()
+        .checked_sub(OCC_SUFFIX_LEN)
+        .filter(|root_len| *root_len > 0)
+        .and_then(|root_len| symbol.get(..root_len))
+        .map_or(symbol, str::trim_end)
+}
+
+/// 64-bit FNV-1a, stable across builds and restarts.
+fn fnv1a(key: &str) -> u64 {
+    const OFFSET_BASIS: u64 = 0xcbf2_9ce4_8422_2325;
+    const PRIME: u64 = 0x0000_0100_0000_01b3;
+
+    key.bytes().fold(OFFSET_BASIS, |hash, byte| {
+        (hash ^ u64::from(byte)).wrapping_mul(PRIME)
+    })
+}
+
+// =============================================================================
+// Shard Pool
+// =============================================================================
+
+/// Parallel OPRA connections with option symbols sharded across them.
+pub struct OpraShardPool {
+    clients: Vec<Arc<OpraClient>>,
+    strategy: ShardStrategy,
+}
+
+impl OpraShardPool {
+    /// Create `shards` OPRA clients sharing `config`.
+    ///
+    /// Returns the pool and one event receiver per shard, in shard order.
+    /// At least one shard is always created.
+    #[must_use]
+    pub fn new(
+        config: &OpraClientConfig,
+        shards: usize,
+        strategy: ShardStrategy,
+        event_capacity: usize,
+        cancel: &CancellationToken,
+    ) -> (Self, Vec<mpsc::Receiver<OpraEvent>>) {
+        let (clients, receivers) = (0..shards.max(1))
+            .map(|_| {
+                let (tx, rx) = mpsc::channel(event_capacity);
+                let client = OpraClient::new(config.clone(), tx, cancel.clone());
+                (Arc::new(client), rx)
+            })
+            .unzip();
+
+        (Self { clients, strategy }, receivers)
+    }
+
+    /// Number of shards.
+    #[must_use]
+    pub const fn len(&self) -> usize {
+        self.clients.len()
+    }
+
+    /// Whether the pool has no shards (never true for a pool from `new`).
+    #[must_use]
+    pub const fn is_empty(&self) -> bool {
+        self.clients.is_empty()
+    }
+
+    /// Sharding strategy.
+    #[must_use]
+    pub const fn strategy(&self) -> ShardStrategy {
+        self.strategy
+    }
+
+    /// Shard index that `symbol` is assigned to.
+    #[must_use]
+    pub fn shard_for(&self, symbol: &str) -> usize {
+        let hash = fnv1a(self.strategy.shard_key(symbol));
+        // The remainder is below the shard count, so it always fits
+        usize::try_from(hash % self.clients.len() as u64).unwrap_or_default()
+    }
+
+    /// Client for one shard.
+    #[must_use]
+    pub fn client(&self, shard: usize) -> Option<&Arc<OpraClient>> {
+        self.clients.get(shard)
+    }
+
+    /// Subscribe to option symbols, each on its shard.
+    ///
+    /// # Arguments
+    ///
+    /// * `quotes` - Symbols to subscribe for quotes
+    /// * `trades` - Symbols to subscribe for trades
+    pub fn subscribe(&self, quotes: Vec<String>, trades: Vec<String>) {
+        let quotes = self.partition(quotes);
+        let trades = self.partition(trades);
+
+        for ((client, quotes), trades) in self.clients.iter().zip(quotes).zip(trades) {
+            if !quotes.is_empty() || !trades.is_empty() {
+                client.subscribe(quotes, trades);
+            }
+        }
+    }
+
+    /// Unsubscribe from option symbols on their shards.
+    ///
+    /// # Arguments
+    ///
+    /// * `quotes` - Symbols to unsubscribe from quotes
+    /// * `trades` - Symbols to unsubscribe from trades
+    pub fn unsubscribe(&self, quotes: &[String], trades: &[String]) {
+        let quotes = self.partition(quotes.to_vec());
+        let trades = self.partition(trades.to_vec());
+
+        for ((client, quotes), trades) in self.clients.iter().zip(quotes).zip(trades) {
+            if !quotes.is_empty() || !trades.is_empty() {
+                client.unsubscribe(&quotes, &trades);
+            }
+        }
+    }
+
+    /// Spawn every shard's connection loop.
+    pub fn spawn(&self) {
+        for (shard, client) in self.clients.iter().enumerate() {
+            let client = Arc::clone(client);
+            tokio::spawn(async move {
+                if let Err(e) = client.run().await {
+                    tracing::error!(shard, error = %e, "OPRA client error");
+                }
+            });
+        }
+    }
+
+    /// Split `symbols` into one list per shard.
+    fn partition(&self, symbols: Vec<String>) -> Vec<Vec<String>> {
+        let mut shards = vec![Vec::new(); self.clients.len()];
+        for symbol in symbols {
+            shards[self.shard_for(&symbol)].push(symbol);
+        }
+        shards
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::infrastructure::alpaca::Credentials;
+
+    fn config() -> OpraClientConfig {
+        OpraClientConfig::paper(Credentials::new("key", "secret").unwrap())
+    }
+
+    fn pool(shards: usize, strategy: ShardStrategy) -> OpraShardPool {
+        OpraShardPool::new(&config(), shards, strategy, 16, &CancellationToken::new()).0
+    }
+
+    #[test]
+    fn option_underlying_strips_occ_suffix() {
+        assert_eq!(option_underlying("AAPL240315C00172500"), "AAPL");
+        assert_eq!(option_underlying("SPY250117P00400000"), "SPY");
+        assert_eq!(option_underlying("AAPL"), "AAPL");
+    }
+
+    #[test]
+    fn shard_strategy_parsing() {
+        assert_eq!(
+            ShardStrategy::from_str_case_insensitive("HASH"),
+            ShardStrategy::Hash
+        );
+        assert_eq!(
+            ShardStrategy::from_str_case_insensitive("underlying"),
+            ShardStrategy::Underlying
+        );
+        assert_eq!(
+            ShardStrategy::from_str_case_insensitive("unknown"),
+            ShardStrategy::Underlying
+        );
+        assert_eq!(ShardStrategy::Hash.as_str(), "hash");
+    }
+
+    #[test]
+    fn pool_always_has_a_shard() {
+        let (pool, receivers) = OpraShardPool::new(
+            &config(),
+            0,
+            ShardStrategy::default(),
+            16,
+            &CancellationToken::new(),
+        );
+
+        assert_eq!(pool.len(), 1);
+        assert_eq!(receivers.len(), 1);
+        assert_eq!(pool.shard_for("AAPL240315C00172500"), 0);
+    }
+
+    #[test]
+    fn underlying_strategy_keeps_chains_together() {
+        let pool = pool(8, ShardStrategy::Underlying);
+
+        let shard = pool.shard_for("AAPL240315C00172500");
+        assert_eq!(pool.shard_for("AAPL240315P00172500"), shard);
+        assert_eq!(pool.shard_for("AAPL250117C00200000"), shard);
+    }
+
+    #[test]
+    fn hash_strategy_spreads_a_chain() {
+        let pool = pool(8, ShardStrategy::Hash);
+
+        let shards: std::collections::HashSet<_> = (0..64)
+            .map(|strike| pool.shard_for(&format!("SPY250117C{:08}", strike * 1000)))
+            .collect();
+
+        assert!(shards.len() > 1);
+        assert!(shards.iter().all(|shard| *shard < 8));
+    }
+
+    #[test]
+    fn subscribe_routes_symbols_to_their_shard() {
+        let pool = pool(4, ShardStrategy::Hash);
+        let symbols: Vec<String> = (0..32)
+            .map(|strike| format!("SPY250117C{:08}", strike * 1000))
+            .collect();
+
+        pool.subscribe(symbols.clone(), vec![]);
+
+        let mut routed = 0;
+        for shard in 0..pool.len() {
+            let subs = pool.client(shard).unwrap().subscriptions();
+            assert!(subs.quotes.iter().all(|s| pool.shard_for(s) == shard));
+            routed += subs.quotes.len();
+        }
+        assert_eq!(routed, symbols.len());
+
+        pool.unsubscribe(&symbols, &[]);
+        assert!(
+            (0..pool.len()).all(|shard| pool.client(shard).unwrap().subscriptions().is_empty())
+        );
+    }
+}
@@ -10,7 +10,9 @@
 //!
 //! # Protocol
 //!
-//! Messages are JSON-encoded arrays of market data objects.
+//! Messages are arrays of market data objects, JSON-encoded by default. With
+//! [`WireFormat::MsgPack`] the client asks for `MessagePack` in the handshake;
+//! if the server answers with JSON instead, the client carries on in JSON.
 
 use std::sync::Arc;
 
@@ -20,10 +22,11 @@ use tokio_tungstenite::tungstenite::Message;
 use tokio_util::sync::CancellationToken;
 
 use super::auth::{AuthHandler, AuthState, Credentials};
-use super::codec::{CodecError, JsonCodec};
+use super::codec::{CodecError, FrameCodec, JsonCodec, MsgPackCodec, WireFormat, decode_frame};
 use super::heartbeat::{HeartbeatConfig, HeartbeatEvent, HeartbeatManager, HeartbeatState};
 use super::messages::{AlpacaMessage, SubscriptionRequest};
 use super::reconnect::{ReconnectConfig, ReconnectPolicy};
+use crate::infrastructure::metrics::{self, FeedType, MessageType};
 
 // =============================================================================
 // Error Type
@@ -85,6 +88,8 @@ pub enum SipEvent {
     Bar(super::messages::StockBarMessage),
     /// Received a stock daily bar.
     DailyBar(super::messages::StockBarMessage),
+    /// Received a trading status change (halt or resumption).
+    Status(super::messages::StockStatusMessage),
     /// Subscription confirmation.
     Subscribed {
         /// Subscribed quote symbols.
@@ -115,6 +120,8 @@ pub struct SipClientConfig {
     pub reconnect: ReconnectConfig,
     /// Heartbeat configuration.
     pub heartbeat: HeartbeatConfig,
+    /// Frame encoding to request from the server.
+    pub wire_format: WireFormat,
 }
 
 impl SipClientConfig {
@@ -126,9 +133,24 @@ impl SipClientConfig {
             credentials,
             reconnect: ReconnectConfig::default(),
             heartbeat: HeartbeatConfig::default(),
+            wire_format: WireFormat::default(),
         }
     }
 
+    /// Use `heartbeat` for connection health checks.
+    #[must_use]
+    pub const fn with_heartbeat(mut self, heartbeat: HeartbeatConfig) -> Self {
+        self.heartbeat = heartbeat;
+        self
+    }
+
+    /// Request `wire_format` frames from the server.
+    #[must_use]
+    pub const fn with_wire_format(mut self, wire_format: WireFormat) -> Self {
+        self.wire_format = wire_format;
+        self
+    }
+
     /// Create configuration for paper trading environment.
     ///
     /// Note: Market data streams always use production URLs. Paper vs live only
@@ -204,6 +226,7 @@ impl SubscriptionState {
 pub struct SipClient {
     config: SipClientConfig,
     codec: JsonCodec,
+    msgpack_codec: MsgPackCodec,
     event_tx: mpsc::Sender<SipEvent>,
     cancel: CancellationToken,
     subscriptions: parking_lot::RwLock<SubscriptionState>,
@@ -220,6 +243,7 @@ impl SipClient {
         Self {
             config,
             codec: JsonCodec::new(),
+            msgpack_codec: MsgPackCodec::new(),
             event_tx,
             cancel,
             subscriptions: parking_lot::RwLock::new(SubscriptionState::default()),
@@ -283,10 +307,18 @@ impl SipClient {
 
     /// Connect to WebSocket and run until error or cancellation.
     async fn connect_and_run(&self) -> Result<(), SipClientError> {
-        tracing::info!(url = %self.config.url, "Connecting to SIP stream");
+        tracing::info!(
+            url = %self.config.url,
+            wire_format = self.config.wire_format.as_str(),
+            "Connecting to SIP stream"
+        );
 
         // Connect to WebSocket
-        let (ws_stream, _response) = tokio_tungstenite::connect_async(&self.config.url).await?;
+        let request = self
+            .config
+            .wire_format
+            .handshake_request(&self.config.url)?;
+        let (ws_stream, _response) = tokio_tungstenite::connect_async(request).await?;
 
         let (mut write, mut read) = ws_stream.split();
 
@@ -332,11 +364,13 @@ impl SipClient {
                 }
                 msg = read.next() => {
                     match msg {
-                        Some(Ok(Message::Text(text))) => {
-                            heartbeat_state.record_pong();
+                        Some(Ok(frame @ (Message::Text(_) | Message::Binary(_)))) => {
+                            heartbeat_state.record_message();
 
-                            self.handle_text_message(
-                                &text,
+                            let (messages, format) = decode_frame(FrameCodec::Stock, &frame)?;
+                            self.handle_messages(
+                                messages,
+                                format,
                                 &mut auth_handler,
                                 &mut write,
                             ).await?;
@@ -345,6 +379,7 @@ impl SipClient {
                             heartbeat_state.record_pong();
                         }
                         Some(Ok(Message::Ping(data))) => {
+                            heartbeat_state.record_pong();
                             write.send(Message::Pong(data)).await?;
                         }
                         Some(Ok(Message::Close(_))) => {
@@ -370,10 +405,13 @@ impl SipClient {
         }
     }
 
-    /// Handle a text message from the WebSocket.
-    async fn handle_text_message<W>(
+    /// Handle messages decoded from one WebSocket frame.
+    ///
+    /// Replies are sent in `format`, the encoding the server used for the frame.
+    async fn handle_messages<W>(
         &self,
-        text: &str,
+        messages: Vec<AlpacaMessage>,
+        format: WireFormat,
         auth_handler: &mut AuthHandler,
         write: &mut W,
     ) -> Result<(), SipClientError>
@@ -381,15 +419,20 @@ impl SipClient {
         W: SinkExt<Message> + Unpin,
         W::Error: std::fmt::Display,
     {
-        let messages = self.codec.decode(text)?;
-
         for msg in messages {
             match msg {
                 AlpacaMessage::Success(success) => {
                     let authenticated = auth_handler.on_success(&success)?;
 
                     if authenticated {
-                        tracing::info!("SIP stream authenticated");
+                        tracing::info!(wire_format = format.as_str(), "SIP stream authenticated");
+                        if format != self.config.wire_format {
+                            tracing::warn!(
+                                requested = self.config.wire_format.as_str(),
+                                negotiated = format.as_str(),
+                                "SIP stream did not accept the requested wire format"
+                            );
+                        }
                         let _ = self.event_tx.send(SipEvent::Connected).await;
 
                         let subs = self.subscriptions.read().clone();
@@ -397,18 +440,30 @@ impl SipClient {
                             .with_quotes(subs.quotes)
                             .with_trades(subs.trades)
                             .with_bars(vec!["*".to_string()])
-                            .with_daily_bars(vec!["*".to_string()]);
-                        self.send_subscribe(write, &wildcard_request).await?;
+                            .with_daily_bars(vec!["*".to_string()])
+                            .with_statuses(vec!["*".to_string()]);
+                        self.send_subscribe(write, &wildcard_request, format)
+                            .await?;
                     } else if auth_handler.state() == AuthState::Connected {
                         // Send authentication
                         let auth_msg = auth_handler.create_auth_request();
-                        let json = auth_msg.to_json().map_err(|e| {
+                        let frame = match format {
+                            WireFormat::Json => auth_msg
+                                .to_json()
+                                .map(|json| Message::Text(json.into()))
+                                .map_err(|e| e.to_string()),
+                            WireFormat::MsgPack => auth_msg
+                                .to_msgpack()
+                                .map(|msgpack| Message::Binary(msgpack.into()))
+                                .map_err(|e| e.to_string()),
+                        }
+                        .map_err(|e| {
                             SipClientError::ConnectionFailed(format!(
                                 "failed to serialize auth: {e}"
                             ))
                         })?;
 
-                        write.send(Message::Text(json.into())).await.map_err(|e| {
+                        write.send(frame).await.map_err(|e| {
                             SipClientError::ConnectionFailed(format!("failed to send auth: {e}"))
                         })?;
                     }
@@ -441,18 +496,25 @@ impl SipClient {
                         .await;
                 }
                 AlpacaMessage::StockQuote(quote) => {
+                    metrics::record_message_received(FeedType::Sip, MessageType::StockQuote);
                     let _ = self.event_tx.send(SipEvent::Quote(quote)).await;
                 }
                 AlpacaMessage::StockTrade(trade) => {
+                    metrics::record_message_received(FeedType::Sip, MessageType::StockTrade);
                     let _ = self.event_tx.send(SipEvent::Trade(trade)).await;
                 }
                 AlpacaMessage::StockBar(bar) => {
+                    metrics::record_message_received(FeedType::Sip, MessageType::StockBar);
                     if bar.msg_type == "d" {
                         let _ = self.event_tx.send(SipEvent::DailyBar(bar)).await;
                     } else {
                         let _ = self.event_tx.send(SipEvent::Bar(bar)).await;
                     }
                 }
+                AlpacaMessage::StockStatus(status) => {
+                    metrics::record_message_received(FeedType::Sip, MessageType::TradingStatus);
+                    let _ = self.event_tx.send(SipEvent::Status(status)).await;
+                }
                 _ => {
                     tracing::trace!("Ignoring unhandled message type");
                 }
@@ -462,17 +524,28 @@ impl SipClient {
         Ok(())
     }
 
-    /// Send a subscribe request.
+    /// Send a subscribe request encoded as `format`.
     async fn send_subscribe<W>(
         &self,
         write: &mut W,
         request: &SubscriptionRequest,
+        format: WireFormat,
     ) -> Result<(), SipClientError>
     where
         W: SinkExt<Message> + Unpin,
         W::Error: std::fmt::Display,
     {
-        let json = serde_json::to_string(request).map_err(|e| {
+        let frame = match format {
+            WireFormat::Json => self
+                .codec
+                .encode(request)
+                .map(|json| Message::Text(json.into())),
+            WireFormat::MsgPack => self
+                .msgpack_codec
+                .encode_named(request)
+                .map(|msgpack| Message::Binary(msgpack.into())),
+        }
+        .map_err(|e| {
             SipClientError::ConnectionFailed(format!("failed to serialize subscribe: {e}"))
         })?;
 
@@ -484,7 +557,7 @@ impl SipClient {
             "Sending subscribe request"
         );
 
-        write.send(Message::Text(json.into())).await.map_err(|e| {
+        write.send(frame).await.map_err(|e| {
             SipClientError::ConnectionFailed(format!("failed to send subscribe: {e}"))
         })?;
 
@@ -598,4 +671,14 @@ mod tests {
         assert!(config.url.contains("stream.data.alpaca.markets"));
         assert!(config.url.contains("/v2/sip"));
     }
+
+    #[test]
+    fn sip_config_wire_format() {
+        let creds = Credentials::new("key", "secret").unwrap();
+        let config = SipClientConfig::live(creds, "sip");
+        assert_eq!(config.wire_format, WireFormat::Json);
+
+        let config = config.with_wire_format(WireFormat::MsgPack);
+        assert_eq!(config.wire_format, WireFormat::MsgPack);
+    }
 }
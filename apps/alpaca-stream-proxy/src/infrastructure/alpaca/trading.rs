@@ -115,6 +115,13 @@ impl TradingClientConfig {
         }
     }
 
+    /// Use `heartbeat` for connection health checks.
+    #[must_use]
+    pub const fn with_heartbeat(mut self, heartbeat: HeartbeatConfig) -> Self {
+        self.heartbeat = heartbeat;
+        self
+    }
+
     /// Create configuration for paper trading environment.
     #[must_use]
     pub fn paper(credentials: Credentials) -> Self {
@@ -282,7 +289,7 @@ impl TradingClient {
                 msg = read.next() => {
                     match msg {
                         Some(Ok(Message::Text(text))) => {
-                            heartbeat_state.record_pong();
+                            heartbeat_state.record_message();
                             self.handle_text_message(
                                 &text,
                                 &mut auth_handler,
@@ -290,7 +297,7 @@ impl TradingClient {
                             ).await?;
                         }
                         Some(Ok(Message::Binary(data))) => {
-                            heartbeat_state.record_pong();
+                            heartbeat_state.record_message();
                             // Alpaca sends Binary messages for trade updates
                             if let Ok(text) = String::from_utf8(data.to_vec()) {
                                 self.handle_text_message(
@@ -306,6 +313,7 @@ impl TradingClient {
                             heartbeat_state.record_pong();
                         }
                         Some(Ok(Message::Ping(data))) => {
+                            heartbeat_state.record_pong();
                             write.send(Message::Pong(data)).await?;
                         }
                         Some(Ok(Message::Close(_))) => {
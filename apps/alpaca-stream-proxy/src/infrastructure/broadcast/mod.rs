@@ -6,21 +6,25 @@
 //! # Architecture
 //!
 //! The `BroadcastHub` provides separate channels for each market data type:
-//! - Stock quotes, trades, and bars from SIP stream
+//! - Stock quotes, trades, bars, and trading status from SIP stream
 //! - Options quotes and trades from OPRA stream
 //! - Order updates from Trade Updates stream
+//! - Scanner alerts and microstructure signals derived from the streams
 //!
 //! Each channel supports multiple receivers with configurable capacity.
+//! Market data payloads are wrapped in `Arc`, so fanning a message out to
+//! many receivers clones a pointer rather than the message.
 
 use std::sync::Arc;
 
 use tokio::sync::broadcast;
 
 use super::alpaca::messages::{
-    OptionQuoteMessage, OptionTradeMessage, StockBarMessage, StockQuoteMessage, StockTradeMessage,
-    TradeUpdateMessage,
+    OptionQuoteMessage, OptionTradeMessage, StockBarMessage, StockQuoteMessage, StockStatusMessage,
+    StockTradeMessage, TradeUpdateMessage,
 };
 use crate::BroadcastSettings;
+use crate::domain::microstructure::MicrostructureSignal;
 use crate::domain::scanner::ScannerAlertDomain;
 
 // =============================================================================
@@ -31,42 +35,49 @@ use crate::domain::scanner::ScannerAlertDomain;
 #[derive(Debug, Clone)]
 pub struct StockQuoteBroadcast {
     /// The quote data.
-    pub quote: StockQuoteMessage,
+    pub quote: Arc<StockQuoteMessage>,
 }
 
 /// Stock trade broadcast message.
 #[derive(Debug, Clone)]
 pub struct StockTradeBroadcast {
     /// The trade data.
-    pub trade: StockTradeMessage,
+    pub trade: Arc<StockTradeMessage>,
 }
 
 /// Stock bar broadcast message.
 #[derive(Debug, Clone)]
 pub struct StockBarBroadcast {
     /// The bar data.
-    pub bar: StockBarMessage,
+    pub bar: Arc<StockBarMessage>,
+}
+
+/// Trading status broadcast message.
+#[derive(Debug, Clone)]
+pub struct TradingStatusBroadcast {
+    /// The status data.
+    pub status: Arc<StockStatusMessage>,
 }
 
 /// Option quote broadcast message.
 #[derive(Debug, Clone)]
 pub struct OptionQuoteBroadcast {
     /// The quote data.
-    pub quote: OptionQuoteMessage,
+    pub quote: Arc<OptionQuoteMessage>,
 }
 
 /// Option trade broadcast message.
 #[derive(Debug, Clone)]
 pub struct OptionTradeBroadcast {
     /// The trade data.
-    pub trade: OptionTradeMessage,
+    pub trade: Arc<OptionTradeMessage>,
 }
 
 /// Order update broadcast message.
 #[derive(Debug, Clone)]
 pub struct OrderUpdateBroadcast {
     /// The order update data.
-    pub update: TradeUpdateMessage,
+    pub update: Arc<TradeUpdateMessage>,
 }
 
 /// Scanner alert broadcast message.
@@ -76,6 +87,13 @@ pub struct ScannerAlertBroadcast {
     pub alert: ScannerAlertDomain,
 }
 
+/// Microstructure signal broadcast message.
+#[derive(Debug, Clone)]
+pub struct SignalBroadcast {
+    /// The signal payload.
+    pub signal: Arc<MicrostructureSignal>,
+}
+
 // =============================================================================
 // Broadcast Hub
 // =============================================================================
@@ -89,6 +107,8 @@ pub struct BroadcastConfig {
     pub stock_trades_capacity: usize,
     /// Capacity for stock bar channel.
     pub stock_bars_capacity: usize,
+    /// Capacity for trading status channel.
+    pub trading_statuses_capacity: usize,
     /// Capacity for option quote channel.
     pub options_quotes_capacity: usize,
     /// Capacity for option trade channel.
@@ -97,6 +117,8 @@ pub struct BroadcastConfig {
     pub order_updates_capacity: usize,
     /// Capacity for scanner alert channel.
     pub scanner_alerts_capacity: usize,
+    /// Capacity for microstructure signal channel.
+    pub signals_capacity: usize,
 }
 
 impl Default for BroadcastConfig {
@@ -105,10 +127,12 @@ impl Default for BroadcastConfig {
             stock_quotes_capacity: 10_000,
             stock_trades_capacity: 10_000,
             stock_bars_capacity: 1_000,
+            trading_statuses_capacity: 1_000,
             options_quotes_capacity: 50_000,
             options_trades_capacity: 10_000,
             order_updates_capacity: 1_000,
             scanner_alerts_capacity: 1_000,
+            signals_capacity: 10_000,
         }
     }
 }
@@ -119,10 +143,12 @@ impl From<BroadcastSettings> for BroadcastConfig {
             stock_quotes_capacity: settings.stock_quotes_capacity,
             stock_trades_capacity: settings.stock_trades_capacity,
             stock_bars_capacity: settings.stock_bars_capacity,
+            trading_statuses_capacity: 1_000,
             options_quotes_capacity: settings.options_quotes_capacity,
             options_trades_capacity: settings.options_trades_capacity,
             order_updates_capacity: settings.order_updates_capacity,
             scanner_alerts_capacity: 1_000,
+            signals_capacity: 10_000,
         }
     }
 }
@@ -151,10 +177,12 @@ pub struct BroadcastHub {
     stock_quotes_tx: broadcast::Sender<StockQuoteBroadcast>,
     stock_trades_tx: broadcast::Sender<StockTradeBroadcast>,
     stock_bars_tx: broadcast::Sender<StockBarBroadcast>,
+    trading_statuses_tx: broadcast::Sender<TradingStatusBroadcast>,
     options_quotes_tx: broadcast::Sender<OptionQuoteBroadcast>,
     options_trades_tx: broadcast::Sender<OptionTradeBroadcast>,
     order_updates_tx: broadcast::Sender<OrderUpdateBroadcast>,
     scanner_alerts_tx: broadcast::Sender<ScannerAlertBroadcast>,
+    signals_tx: broadcast::Sender<SignalBroadcast>,
 }
 
 impl BroadcastHub {
@@ -165,10 +193,12 @@ impl BroadcastHub {
             stock_quotes_tx: broadcast::channel(config.stock_quotes_capacity).0,
             stock_trades_tx: broadcast::channel(config.stock_trades_capacity).0,
             stock_bars_tx: broadcast::channel(config.stock_bars_capacity).0,
+            trading_statuses_tx: broadcast::channel(config.trading_statuses_capacity).0,
             options_quotes_tx: broadcast::channel(config.options_quotes_capacity).0,
             options_trades_tx: broadcast::channel(config.options_trades_capacity).0,
             order_updates_tx: broadcast::channel(config.order_updates_capacity).0,
             scanner_alerts_tx: broadcast::channel(config.scanner_alerts_capacity).0,
+            signals_tx: broadcast::channel(config.signals_capacity).0,
         }
     }
 
@@ -189,7 +219,9 @@ impl BroadcastHub {
     #[must_use]
     pub fn send_stock_quote(&self, quote: StockQuoteMessage) -> Option<usize> {
         self.stock_quotes_tx
-            .send(StockQuoteBroadcast { quote })
+            .send(StockQuoteBroadcast {
+                quote: Arc::new(quote),
+            })
             .ok()
     }
 
@@ -213,7 +245,9 @@ impl BroadcastHub {
     #[must_use]
     pub fn send_stock_trade(&self, trade: StockTradeMessage) -> Option<usize> {
         self.stock_trades_tx
-            .send(StockTradeBroadcast { trade })
+            .send(StockTradeBroadcast {
+                trade: Arc::new(trade),
+            })
             .ok()
     }
 
@@ -236,7 +270,9 @@ impl BroadcastHub {
     /// Send a stock bar to all subscribers.
     #[must_use]
     pub fn send_stock_bar(&self, bar: StockBarMessage) -> Option<usize> {
-        self.stock_bars_tx.send(StockBarBroadcast { bar }).ok()
+        self.stock_bars_tx
+            .send(StockBarBroadcast { bar: Arc::new(bar) })
+            .ok()
     }
 
     /// Get a new receiver for stock bars.
@@ -251,6 +287,32 @@ impl BroadcastHub {
         self.stock_bars_tx.receiver_count()
     }
 
+    // =========================================================================
+    // Trading Status Channel
+    // =========================================================================
+
+    /// Send a trading status change to all subscribers.
+    #[must_use]
+    pub fn send_trading_status(&self, status: StockStatusMessage) -> Option<usize> {
+        self.trading_statuses_tx
+            .send(TradingStatusBroadcast {
+                status: Arc::new(status),
+            })
+            .ok()
+    }
+
+    /// Get a new receiver for trading status changes.
+    #[must_use]
+    pub fn trading_statuses_rx(&self) -> broadcast::Receiver<TradingStatusBroadcast> {
+        self.trading_statuses_tx.subscribe()
+    }
+
+    /// Get the number of active trading status receivers.
+    #[must_use]
+    pub fn trading_statuses_receiver_count(&self) -> usize {
+        self.trading_statuses_tx.receiver_count()
+    }
+
     // =========================================================================
     // Options Quote Channel
     // =========================================================================
@@ -259,7 +321,9 @@ impl BroadcastHub {
     #[must_use]
     pub fn send_options_quote(&self, quote: OptionQuoteMessage) -> Option<usize> {
         self.options_quotes_tx
-            .send(OptionQuoteBroadcast { quote })
+            .send(OptionQuoteBroadcast {
+                quote: Arc::new(quote),
+            })
             .ok()
     }
 
@@ -283,7 +347,9 @@ impl BroadcastHub {
     #[must_use]
     pub fn send_options_trade(&self, trade: OptionTradeMessage) -> Option<usize> {
         self.options_trades_tx
-            .send(OptionTradeBroadcast { trade })
+            .send(OptionTradeBroadcast {
+                trade: Arc::new(trade),
+            })
             .ok()
     }
 
@@ -307,7 +373,9 @@ impl BroadcastHub {
     #[must_use]
     pub fn send_order_update(&self, update: TradeUpdateMessage) -> Option<usize> {
         self.order_updates_tx
-            .send(OrderUpdateBroadcast { update })
+            .send(OrderUpdateBroadcast {
+                update: Arc::new(update),
+            })
             .ok()
     }
 
@@ -347,6 +415,32 @@ impl BroadcastHub {
         self.scanner_alerts_tx.receiver_count()
     }
 
+    // =========================================================================
+    // Microstructure Signals Channel
+    // =========================================================================
+
+    /// Send a microstructure signal to all subscribers.
+    #[must_use]
+    pub fn send_signal(&self, signal: MicrostructureSignal) -> Option<usize> {
+        self.signals_tx
+            .send(SignalBroadcast {
+                signal: Arc::new(signal),
+            })
+            .ok()
+    }
+
+    /// Get a new receiver for microstructure signals.
+    #[must_use]
+    pub fn signals_rx(&self) -> broadcast::Receiver<SignalBroadcast> {
+        self.signals_tx.subscribe()
+    }
+
+    /// Get the number of active microstructure signal receivers.
+    #[must_use]
+    pub fn signals_receiver_count(&self) -> usize {
+        self.signals_tx.receiver_count()
+    }
+
     // =========================================================================
     // Statistics
     // =========================================================================
@@ -358,10 +452,12 @@ impl BroadcastHub {
             stock_quotes_receivers: self.stock_quotes_receiver_count(),
             stock_trades_receivers: self.stock_trades_receiver_count(),
             stock_bars_receivers: self.stock_bars_receiver_count(),
+            trading_statuses_receivers: self.trading_statuses_receiver_count(),
             options_quotes_receivers: self.options_quotes_receiver_count(),
             options_trades_receivers: self.options_trades_receiver_count(),
             order_updates_receivers: self.order_updates_receiver_count(),
             scanner_alerts_receivers: self.scanner_alerts_receiver_count(),
+            signals_receivers: self.signals_receiver_count(),
         }
     }
 }
@@ -378,6 +474,8 @@ pub struct BroadcastStats {
     pub stock_trades_receivers: usize,
     /// Number of stock bar receivers.
     pub stock_bars_receivers: usize,
+    /// Number of trading status receivers.
+    pub trading_statuses_receivers: usize,
     /// Number of options quote receivers.
     pub options_quotes_receivers: usize,
     /// Number of options trade receivers.
@@ -386,6 +484,8 @@ pub struct BroadcastStats {
     pub order_updates_receivers: usize,
     /// Number of scanner alert receivers.
     pub scanner_alerts_receivers: usize,
+    /// Number of microstructure signal receivers.
+    pub signals_receivers: usize,
 }
 
 impl BroadcastStats {
@@ -395,10 +495,12 @@ impl BroadcastStats {
         self.stock_quotes_receivers
             + self.stock_trades_receivers
             + self.stock_bars_receivers
+            + self.trading_statuses_receivers
             + self.options_quotes_receivers
             + self.options_trades_receivers
             + self.order_updates_receivers
             + self.scanner_alerts_receivers
+            + self.signals_receivers
     }
 }
 
@@ -437,10 +539,12 @@ mod tests {
         assert_eq!(hub.stock_quotes_receiver_count(), 0);
         assert_eq!(hub.stock_trades_receiver_count(), 0);
         assert_eq!(hub.stock_bars_receiver_count(), 0);
+        assert_eq!(hub.trading_statuses_receiver_count(), 0);
         assert_eq!(hub.options_quotes_receiver_count(), 0);
         assert_eq!(hub.options_trades_receiver_count(), 0);
         assert_eq!(hub.order_updates_receiver_count(), 0);
         assert_eq!(hub.scanner_alerts_receiver_count(), 0);
+        assert_eq!(hub.signals_receiver_count(), 0);
     }
 
     #[test]
@@ -494,6 +598,8 @@ mod tests {
         let r2 = rx2.recv().await.unwrap();
 
         assert_eq!(r1.quote.symbol, r2.quote.symbol);
+        // Receivers share one copy of the payload
+        assert!(Arc::ptr_eq(&r1.quote, &r2.quote));
     }
 
     #[test]
@@ -526,10 +632,12 @@ mod tests {
             stock_quotes_capacity: 100,
             stock_trades_capacity: 100,
             stock_bars_capacity: 50,
+            trading_statuses_capacity: 50,
             options_quotes_capacity: 200,
             options_trades_capacity: 100,
             order_updates_capacity: 50,
             scanner_alerts_capacity: 10,
+            signals_capacity: 100,
         };
         let _hub = BroadcastHub::new(config);
         // Just verify it creates successfully with custom config
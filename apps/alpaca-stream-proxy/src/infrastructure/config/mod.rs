@@ -5,6 +5,7 @@
 mod settings;
 
 pub use settings::{
-    BroadcastSettings, ConfigError, Credentials, DataFeed, Environment, ProxyConfig,
-    ServerSettings, WebSocketSettings,
+    BroadcastSettings, ConfigError, Credentials, DataFeed, DropPolicy, Environment,
+    OpraShardSettings, ProxyConfig, ServerSettings, StreamBufferSettings,
+    SubscriptionLimitSettings, TlsSettings, WebSocketSettings,
 };
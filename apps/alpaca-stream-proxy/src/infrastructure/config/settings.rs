@@ -2,8 +2,13 @@
 //!
 //! Configuration types for the stream proxy, loaded from environment variables.
 
+use std::path::PathBuf;
 use std::time::Duration;
 
+use crate::domain::microstructure::MicrostructureParams;
+use crate::domain::subscription::{OverflowPolicy, SubscriptionLimit};
+use crate::infrastructure::alpaca::{ShardStrategy, WireFormat};
+
 /// Market data feed type for Alpaca streams.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
 pub enum DataFeed {
@@ -124,6 +129,46 @@ pub struct WebSocketSettings {
     pub reconnect_delay_multiplier: f64,
     /// Maximum reconnection attempts before giving up (0 = unlimited).
     pub max_reconnect_attempts: u32,
+    /// Longest gap between market data frames before the SIP and OPRA
+    /// connections are torn down as stale (`None` = never).
+    pub data_timeout: Option<Duration>,
+}
+
+impl WebSocketSettings {
+    fn from_env() -> Self {
+        let defaults = Self::default();
+        Self {
+            heartbeat_interval: parse_env_duration_secs(
+                "STREAM_PROXY_HEARTBEAT_INTERVAL_SECS",
+                defaults.heartbeat_interval,
+            ),
+            heartbeat_timeout: parse_env_duration_secs(
+                "STREAM_PROXY_HEARTBEAT_TIMEOUT_SECS",
+                defaults.heartbeat_timeout,
+            ),
+            reconnect_delay_initial: parse_env_duration_millis(
+                "STREAM_PROXY_RECONNECT_DELAY_INITIAL_MS",
+                defaults.reconnect_delay_initial,
+            ),
+            reconnect_delay_max: parse_env_duration_secs(
+                "STREAM_PROXY_RECONNECT_DELAY_MAX_SECS",
+                defaults.reconnect_delay_max,
+            ),
+            reconnect_delay_multiplier: parse_env_f64(
+                "STREAM_PROXY_RECONNECT_DELAY_MULTIPLIER",
+                defaults.reconnect_delay_multiplier,
+            ),
+            max_reconnect_attempts: parse_env_u32(
+                "STREAM_PROXY_MAX_RECONNECT_ATTEMPTS",
+                defaults.max_reconnect_attempts,
+            ),
+            data_timeout: Some(parse_env_duration_secs(
+                "STREAM_PROXY_DATA_TIMEOUT_SECS",
+                Duration::ZERO,
+            ))
+            .filter(|timeout| !timeout.is_zero()),
+        }
+    }
 }
 
 impl Default for WebSocketSettings {
@@ -135,6 +180,7 @@ impl Default for WebSocketSettings {
             reconnect_delay_max: Duration::from_secs(30),
             reconnect_delay_multiplier: 2.0,
             max_reconnect_attempts: 0, // Unlimited
+            data_timeout: None,
         }
     }
 }
@@ -169,6 +215,164 @@ impl Default for BroadcastSettings {
     }
 }
 
+/// What to do when a client's stream buffer is full.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum DropPolicy {
+    /// Drop the oldest buffered message to make room.
+    #[default]
+    DropOldest,
+    /// Drop the oldest buffered message, and end the stream with
+    /// `RESOURCE_EXHAUSTED` once the client has dropped more than
+    /// `max_dropped` messages.
+    Disconnect {
+        /// Dropped messages tolerated before disconnecting.
+        max_dropped: u64,
+    },
+}
+
+impl DropPolicy {
+    /// Parse a policy name (`drop_oldest` or `disconnect`).
+    ///
+    /// Unknown names fall back to `drop_oldest`.
+    #[must_use]
+    pub fn from_str_case_insensitive(s: &str, max_dropped: u64) -> Self {
+        match s.to_lowercase().as_str() {
+            "disconnect" => Self::Disconnect { max_dropped },
+            _ => Self::DropOldest,
+        }
+    }
+}
+
+/// Buffering between the broadcast hub and each client's gRPC stream.
+#[derive(Debug, Clone)]
+pub struct StreamBufferSettings {
+    /// Policy when a client's buffer is full.
+    pub drop_policy: DropPolicy,
+    /// How often each stream gets a `LagReport` (`None` = never).
+    pub lag_report_interval: Option<Duration>,
+}
+
+impl StreamBufferSettings {
+    /// Default dropped messages tolerated under the disconnect policy.
+    pub const DEFAULT_MAX_DROPPED: u64 = 10_000;
+
+    fn from_env() -> Self {
+        let max_dropped = parse_env_u64(
+            "STREAM_PROXY_DISCONNECT_AFTER_DROPPED",
+            Self::DEFAULT_MAX_DROPPED,
+        );
+        Self {
+            drop_policy: std::env::var("STREAM_PROXY_DROP_POLICY").map_or_else(
+                |_| Self::default().drop_policy,
+                |s| DropPolicy::from_str_case_insensitive(&s, max_dropped),
+            ),
+            lag_report_interval: Some(parse_env_duration_secs(
+                "STREAM_PROXY_LAG_REPORT_INTERVAL_SECS",
+                Duration::from_secs(10),
+            ))
+            .filter(|interval| !interval.is_zero()),
+        }
+    }
+}
+
+impl Default for StreamBufferSettings {
+    fn default() -> Self {
+        Self {
+            drop_policy: DropPolicy::default(),
+            lag_report_interval: Some(Duration::from_secs(10)),
+        }
+    }
+}
+
+/// Caps on upstream symbol subscriptions per feed connection.
+///
+/// Alpaca limits how many symbols one connection may subscribe to per
+/// channel, depending on the market data plan.
+#[derive(Debug, Clone)]
+pub struct SubscriptionLimitSettings {
+    /// Max symbols per channel on the SIP stream (`None` = unlimited).
+    pub sip_max_symbols: Option<usize>,
+    /// Max symbols per channel on the OPRA stream (`None` = unlimited).
+    pub opra_max_symbols: Option<usize>,
+    /// Behavior when a client request would exceed a cap.
+    pub overflow: OverflowPolicy,
+}
+
+impl SubscriptionLimitSettings {
+    /// SIP subscription limit, if capped.
+    #[must_use]
+    pub fn sip(&self) -> Option<SubscriptionLimit> {
+        self.sip_max_symbols
+            .map(|max| SubscriptionLimit::new(max).with_overflow(self.overflow))
+    }
+
+    /// OPRA subscription limit, if capped.
+    #[must_use]
+    pub fn opra(&self) -> Option<SubscriptionLimit> {
+        self.opra_max_symbols
+            .map(|max| SubscriptionLimit::new(max).with_overflow(self.overflow))
+    }
+
+    fn from_env() -> Self {
+        let defaults = Self::default();
+        Self {
+            sip_max_symbols: Some(parse_env_usize(
+                "STREAM_PROXY_SIP_MAX_SYMBOLS",
+                defaults.sip_max_symbols.unwrap_or(0),
+            ))
+            .filter(|max| *max > 0),
+            opra_max_symbols: Some(parse_env_usize(
+                "STREAM_PROXY_OPRA_MAX_SYMBOLS",
+                defaults.opra_max_symbols.unwrap_or(0),
+            ))
+            .filter(|max| *max > 0),
+            overflow: std::env::var("STREAM_PROXY_SUBSCRIPTION_OVERFLOW")
+                .map_or(defaults.overflow, |s| {
+                    OverflowPolicy::from_str_case_insensitive(&s)
+                }),
+        }
+    }
+}
+
+impl Default for SubscriptionLimitSettings {
+    fn default() -> Self {
+        Self {
+            sip_max_symbols: None,
+            opra_max_symbols: Some(1000),
+            overflow: OverflowPolicy::default(),
+        }
+    }
+}
+
+/// Parallel OPRA connections.
+#[derive(Debug, Clone)]
+pub struct OpraShardSettings {
+    /// Number of OPRA connections.
+    pub shards: usize,
+    /// How option symbols are assigned to connections.
+    pub strategy: ShardStrategy,
+}
+
+impl OpraShardSettings {
+    fn from_env() -> Self {
+        Self {
+            shards: parse_env_usize("STREAM_PROXY_OPRA_SHARDS", 1).max(1),
+            strategy: std::env::var("STREAM_PROXY_OPRA_SHARD_STRATEGY")
+                .map(|s| ShardStrategy::from_str_case_insensitive(&s))
+                .unwrap_or_default(),
+        }
+    }
+}
+
+impl Default for OpraShardSettings {
+    fn default() -> Self {
+        Self {
+            shards: 1,
+            strategy: ShardStrategy::default(),
+        }
+    }
+}
+
 /// Server port settings.
 #[derive(Debug, Clone)]
 pub struct ServerSettings {
@@ -178,6 +382,8 @@ pub struct ServerSettings {
     pub health_port: u16,
     /// Prometheus metrics port (0 = disabled).
     pub metrics_port: u16,
+    /// gRPC TLS settings (`None` = plaintext).
+    pub tls: Option<TlsSettings>,
 }
 
 impl Default for ServerSettings {
@@ -186,10 +392,54 @@ impl Default for ServerSettings {
             grpc_port: 50052,
             health_port: 8082,
             metrics_port: 9090,
+            tls: None,
         }
     }
 }
 
+/// gRPC server TLS settings.
+#[derive(Debug, Clone)]
+pub struct TlsSettings {
+    /// PEM certificate chain path.
+    pub cert_path: PathBuf,
+    /// PEM private key path.
+    pub key_path: PathBuf,
+    /// PEM client CA bundle path; enables mutual TLS when set.
+    pub client_ca_path: Option<PathBuf>,
+    /// How often certificate files are checked for changes.
+    pub reload_interval: Duration,
+}
+
+impl TlsSettings {
+    /// Default certificate change poll interval.
+    pub const DEFAULT_RELOAD_INTERVAL: Duration = Duration::from_secs(60);
+
+    /// Whether client certificates are required.
+    #[must_use]
+    pub const fn is_mutual(&self) -> bool {
+        self.client_ca_path.is_some()
+    }
+
+    fn from_env() -> Result<Option<Self>, ConfigError> {
+        let Some(cert_path) = non_empty_env("STREAM_PROXY_TLS_CERT_PATH") else {
+            return Ok(None);
+        };
+        let key_path = non_empty_env("STREAM_PROXY_TLS_KEY_PATH")
+            .ok_or_else(|| ConfigError::MissingEnvVar("STREAM_PROXY_TLS_KEY_PATH".to_string()))?;
+
+        Ok(Some(Self {
+            cert_path: cert_path.into(),
+            key_path: key_path.into(),
+            client_ca_path: non_empty_env("STREAM_PROXY_TLS_CLIENT_CA_PATH").map(PathBuf::from),
+            reload_interval: parse_env_duration_secs(
+                "STREAM_PROXY_TLS_RELOAD_SECS",
+                Self::DEFAULT_RELOAD_INTERVAL,
+            )
+            .max(Duration::from_secs(1)),
+        }))
+    }
+}
+
 /// Complete proxy configuration.
 #[derive(Debug, Clone)]
 pub struct ProxyConfig {
@@ -197,6 +447,8 @@ pub struct ProxyConfig {
     pub environment: Environment,
     /// Market data feed type.
     pub feed: DataFeed,
+    /// Frame encoding requested from the market data streams.
+    pub wire_format: WireFormat,
     /// API credentials.
     pub credentials: Credentials,
     /// Server port settings.
@@ -205,6 +457,14 @@ pub struct ProxyConfig {
     pub websocket: WebSocketSettings,
     /// Broadcast channel settings.
     pub broadcast: BroadcastSettings,
+    /// Per-client stream buffer settings.
+    pub stream_buffer: StreamBufferSettings,
+    /// Upstream subscription caps.
+    pub subscription_limits: SubscriptionLimitSettings,
+    /// OPRA connection sharding.
+    pub opra_shards: OpraShardSettings,
+    /// Microstructure signal window and publish rate.
+    pub signals: MicrostructureParams,
 }
 
 impl ProxyConfig {
@@ -236,6 +496,10 @@ impl ProxyConfig {
             .map(|s| DataFeed::from_str_case_insensitive(&s))
             .unwrap_or_default();
 
+        let wire_format = std::env::var("ALPACA_WIRE_FORMAT")
+            .map(|s| WireFormat::from_str_case_insensitive(&s))
+            .unwrap_or_default();
+
         let server = ServerSettings {
             grpc_port: parse_env_u16(
                 "STREAM_PROXY_GRPC_PORT",
@@ -249,33 +513,7 @@ impl ProxyConfig {
                 "STREAM_PROXY_METRICS_PORT",
                 ServerSettings::default().metrics_port,
             ),
-        };
-
-        let websocket = WebSocketSettings {
-            heartbeat_interval: parse_env_duration_secs(
-                "STREAM_PROXY_HEARTBEAT_INTERVAL_SECS",
-                WebSocketSettings::default().heartbeat_interval,
-            ),
-            heartbeat_timeout: parse_env_duration_secs(
-                "STREAM_PROXY_HEARTBEAT_TIMEOUT_SECS",
-                WebSocketSettings::default().heartbeat_timeout,
-            ),
-            reconnect_delay_initial: parse_env_duration_millis(
-                "STREAM_PROXY_RECONNECT_DELAY_INITIAL_MS",
-                WebSocketSettings::default().reconnect_delay_initial,
-            ),
-            reconnect_delay_max: parse_env_duration_secs(
-                "STREAM_PROXY_RECONNECT_DELAY_MAX_SECS",
-                WebSocketSettings::default().reconnect_delay_max,
-            ),
-            reconnect_delay_multiplier: parse_env_f64(
-                "STREAM_PROXY_RECONNECT_DELAY_MULTIPLIER",
-                WebSocketSettings::default().reconnect_delay_multiplier,
-            ),
-            max_reconnect_attempts: parse_env_u32(
-                "STREAM_PROXY_MAX_RECONNECT_ATTEMPTS",
-                WebSocketSettings::default().max_reconnect_attempts,
-            ),
+            tls: TlsSettings::from_env()?,
         };
 
         let broadcast = BroadcastSettings {
@@ -308,10 +546,15 @@ impl ProxyConfig {
         Ok(Self {
             environment,
             feed,
+            wire_format,
             credentials: Credentials::new(api_key, api_secret),
             server,
-            websocket,
+            websocket: WebSocketSettings::from_env(),
             broadcast,
+            stream_buffer: StreamBufferSettings::from_env(),
+            subscription_limits: SubscriptionLimitSettings::from_env(),
+            opra_shards: OpraShardSettings::from_env(),
+            signals: signal_params_from_env(),
         })
     }
 
@@ -359,6 +602,22 @@ pub enum ConfigError {
     EmptyValue(String),
 }
 
+/// Microstructure signal window and publish rate from the environment.
+fn signal_params_from_env() -> MicrostructureParams {
+    let defaults = MicrostructureParams::default();
+    MicrostructureParams {
+        window: parse_env_duration_secs("STREAM_PROXY_SIGNAL_WINDOW_SECS", defaults.window),
+        publish_interval: parse_env_duration_millis(
+            "STREAM_PROXY_SIGNAL_INTERVAL_MS",
+            defaults.publish_interval,
+        ),
+    }
+}
+
+fn non_empty_env(key: &str) -> Option<String> {
+    std::env::var(key).ok().filter(|v| !v.trim().is_empty())
+}
+
 fn parse_env_u16(key: &str, default: u16) -> u16 {
     std::env::var(key)
         .ok()
@@ -373,6 +632,13 @@ fn parse_env_u32(key: &str, default: u32) -> u32 {
         .unwrap_or(default)
 }
 
+fn parse_env_u64(key: &str, default: u64) -> u64 {
+    std::env::var(key)
+        .ok()
+        .and_then(|v| v.parse().ok())
+        .unwrap_or(default)
+}
+
 fn parse_env_usize(key: &str, default: usize) -> usize {
     std::env::var(key)
         .ok()
@@ -473,6 +739,22 @@ mod tests {
         assert_eq!(settings.options_quotes_capacity, 50_000);
     }
 
+    #[test]
+    fn drop_policy_parsing() {
+        assert_eq!(
+            DropPolicy::from_str_case_insensitive("DISCONNECT", 50),
+            DropPolicy::Disconnect { max_dropped: 50 }
+        );
+        assert_eq!(
+            DropPolicy::from_str_case_insensitive("drop_oldest", 50),
+            DropPolicy::DropOldest
+        );
+        assert_eq!(
+            DropPolicy::from_str_case_insensitive("unknown", 50),
+            DropPolicy::DropOldest
+        );
+    }
+
     #[test]
     fn server_settings_defaults() {
         let settings = ServerSettings::default();
@@ -480,4 +762,27 @@ mod tests {
         assert_eq!(settings.health_port, 8082);
         assert_eq!(settings.metrics_port, 9090);
     }
+
+    #[test]
+    fn opra_shard_settings_defaults() {
+        let settings = OpraShardSettings::default();
+        assert_eq!(settings.shards, 1);
+        assert_eq!(settings.strategy, ShardStrategy::Underlying);
+    }
+
+    #[test]
+    fn subscription_limit_settings_defaults() {
+        let settings = SubscriptionLimitSettings::default();
+        assert!(settings.sip().is_none());
+        assert_eq!(settings.opra(), Some(SubscriptionLimit::new(1000)));
+
+        let evicting = SubscriptionLimitSettings {
+            overflow: OverflowPolicy::EvictLru,
+            ..settings
+        };
+        assert_eq!(
+            evicting.opra().map(|limit| limit.overflow),
+            Some(OverflowPolicy::EvictLru)
+        );
+    }
 }
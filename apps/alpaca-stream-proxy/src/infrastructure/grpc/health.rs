@@ -0,0 +1,142 @@
+//! gRPC Health Service
+//!
+//! Serves the standard `grpc.health.v1.Health` protocol so Kubernetes gRPC
+//! probes and health clients can check the proxy without the HTTP endpoint.
+//!
+//! Per-service status follows feed connectivity:
+//!
+//! - `cream.v1.StreamProxyService` is serving while any feed is connected
+//! - `cream.v1.ScannerService` is serving while the SIP feed is connected
+//! - the overall (`""`) status mirrors `StreamProxyService`
+
+use std::sync::Arc;
+use std::time::Duration;
+
+use tokio_util::sync::CancellationToken;
+use tonic_health::ServingStatus;
+use tonic_health::server::HealthReporter;
+
+use super::StreamProxyServer;
+use super::proto::cream::v1::{
+    ConnectionState, scanner_service_server, stream_proxy_service_server,
+};
+
+/// How often serving status is re-evaluated.
+const UPDATE_INTERVAL: Duration = Duration::from_secs(5);
+
+/// Keeps `grpc.health.v1` serving status in sync with feed connectivity.
+pub struct GrpcHealthUpdater {
+    reporter: HealthReporter,
+    grpc_server: Arc<StreamProxyServer>,
+}
+
+impl GrpcHealthUpdater {
+    /// Create a new updater.
+    #[must_use]
+    pub const fn new(reporter: HealthReporter, grpc_server: Arc<StreamProxyServer>) -> Self {
+        Self {
+            reporter,
+            grpc_server,
+        }
+    }
+
+    /// Update serving status until cancelled, then mark all services as
+    /// not serving so clients drain before the server stops.
+    pub async fn run(self, shutdown: CancellationToken) {
+        let mut interval = tokio::time::interval(UPDATE_INTERVAL);
+        interval.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Skip);
+
+        loop {
+            tokio::select! {
+                _ = interval.tick() => self.update().await,
+                () = shutdown.cancelled() => break,
+            }
+        }
+
+        self.set_all(ServingStatus::NotServing).await;
+    }
+
+    async fn update(&self) {
+        let sip = self.grpc_server.sip_state().get_state();
+        let opra = self.grpc_server.opra_state().get_state();
+        let trading = self.grpc_server.trading_state().get_state();
+
+        let stream_status = stream_proxy_status(sip, opra, trading);
+        self.reporter
+            .set_service_status(stream_proxy_service_server::SERVICE_NAME, stream_status)
+            .await;
+        self.reporter
+            .set_service_status(scanner_service_server::SERVICE_NAME, scanner_status(sip))
+            .await;
+        self.reporter.set_service_status("", stream_status).await;
+    }
+
+    async fn set_all(&self, status: ServingStatus) {
+        self.reporter
+            .set_service_status(stream_proxy_service_server::SERVICE_NAME, status)
+            .await;
+        self.reporter
+            .set_service_status(scanner_service_server::SERVICE_NAME, status)
+            .await;
+        self.reporter.set_service_status("", status).await;
+    }
+}
+
+/// Serving status for the stream proxy service: any connected feed suffices.
+fn stream_proxy_status(
+    sip: ConnectionState,
+    opra: ConnectionState,
+    trading: ConnectionState,
+) -> ServingStatus {
+    if [sip, opra, trading].contains(&ConnectionState::Connected) {
+        ServingStatus::Serving
+    } else {
+        ServingStatus::NotServing
+    }
+}
+
+/// Serving status for the scanner service, which is fed by SIP only.
+fn scanner_status(sip: ConnectionState) -> ServingStatus {
+    if sip == ConnectionState::Connected {
+        ServingStatus::Serving
+    } else {
+        ServingStatus::NotServing
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn stream_proxy_serving_with_any_feed() {
+        assert_eq!(
+            stream_proxy_status(
+                ConnectionState::Disconnected,
+                ConnectionState::Connected,
+                ConnectionState::Reconnecting,
+            ),
+            ServingStatus::Serving
+        );
+        assert_eq!(
+            stream_proxy_status(
+                ConnectionState::Disconnected,
+                ConnectionState::Error,
+                ConnectionState::Reconnecting,
+            ),
+            ServingStatus::NotServing
+        );
+    }
+
+    #[test]
+    fn scanner_requires_sip() {
+        assert_eq!(
+            scanner_status(ConnectionState::Connected),
+            ServingStatus::Serving
+        );
+        assert_eq!(
+            scanner_status(ConnectionState::Authenticating),
+            ServingStatus::NotServing
+        );
+    }
+}
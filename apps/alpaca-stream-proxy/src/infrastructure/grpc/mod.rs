@@ -8,14 +8,20 @@
 //! The gRPC server bridges the broadcast channels (fed by WebSocket clients)
 //! to downstream gRPC clients. Each streaming RPC:
 //!
-//! 1. Registers the client's subscriptions with the SubscriptionManager
+//! 1. Registers the client's symbols with the feed's SubscriptionManager,
+//!    failing with `RESOURCE_EXHAUSTED` if they exceed the symbol cap
 //! 2. Subscribes to the appropriate broadcast channel
 //! 3. Filters messages by the client's requested symbols
-//! 4. Streams matching messages to the client
-//! 5. Cleans up subscriptions on disconnect
+//! 4. Buffers matching messages per client, dropping the oldest when the
+//!    client falls behind (see `stream_buffer`)
+//! 5. Streams buffered messages and periodic lag reports to the client
+//! 6. Cleans up subscriptions on disconnect
 
+pub mod health;
 pub mod scanner_server;
 pub mod server;
+pub mod stream_buffer;
+pub mod tls;
 
 // Allow clippy warnings and missing docs in generated code
 #[allow(
@@ -34,5 +40,8 @@ pub mod proto {
     }
 }
 
+pub use health::GrpcHealthUpdater;
 pub use scanner_server::ScannerGrpcServer;
 pub use server::{StreamProxyServer, StreamProxyServerConfig};
+pub use stream_buffer::ConsumerLag;
+pub use tls::{ReloadingTlsAcceptor, TlsError};
@@ -12,26 +12,38 @@ use std::time::Instant;
 use chrono::{DateTime, Utc};
 use prost_types::Timestamp;
 use rust_decimal::Decimal;
+use rust_decimal::prelude::ToPrimitive;
 use tokio::sync::broadcast;
 use tokio_stream::Stream;
-use tokio_stream::wrappers::ReceiverStream;
 use tonic::{Request, Response, Status};
 
 use super::proto::cream::v1::{
     self as proto, ConnectionState, ConnectionStatus, Environment, FeedStatus, FeedType,
-    GetConnectionStatusRequest, GetConnectionStatusResponse, OptionQuoteUpdate, OptionTrade,
-    OrderDetails, OrderEvent, OrderUpdate, StockBar, StockQuote, StockTrade, StreamBarsRequest,
-    StreamBarsResponse, StreamOptionQuotesRequest, StreamOptionQuotesResponse,
-    StreamOptionTradesRequest, StreamOptionTradesResponse, StreamOrderUpdatesRequest,
-    StreamOrderUpdatesResponse, StreamQuotesRequest, StreamQuotesResponse, StreamTradesRequest,
-    StreamTradesResponse, stream_proxy_service_server::StreamProxyService,
+    GetConnectionStatusRequest, GetConnectionStatusResponse, LagReport, OptionQuoteUpdate,
+    OptionTrade, OrderDetails, OrderEvent, OrderUpdate, ShardStatus, StockBar, StockQuote,
+    StockTrade, StreamBarsRequest, StreamBarsResponse, StreamOptionQuotesRequest,
+    StreamOptionQuotesResponse, StreamOptionTradesRequest, StreamOptionTradesResponse,
+    StreamOrderUpdatesRequest, StreamOrderUpdatesResponse, StreamQuotesRequest,
+    StreamQuotesResponse, StreamSignalsRequest, StreamSignalsResponse, StreamTradesRequest,
+    StreamTradesResponse, StreamTradingStatusRequest, StreamTradingStatusResponse, TradingStatus,
+    stream_proxy_service_server::StreamProxyService,
+};
+use super::stream_buffer::{
+    ConsumerLag, ConsumerRegistry, LagReported, lag_report_interval, next_tick, stream_buffer,
+};
+use crate::domain::microstructure::{MicrostructureSignal, TickDirection};
+use crate::domain::subscription::{
+    ConsumerId, SubscriptionLimitExceeded, SubscriptionManager, SubscriptionType, Symbol,
 };
-use crate::SubscriptionManager;
 use crate::infrastructure::alpaca::messages::{
     OptionQuoteMessage, OptionTradeMessage, OrderEventType, StockBarMessage, StockQuoteMessage,
-    StockTradeMessage, TradeUpdateMessage,
+    StockStatusMessage, StockTradeMessage, TradeUpdateMessage,
 };
 use crate::infrastructure::broadcast::SharedBroadcastHub;
+use crate::infrastructure::config::StreamBufferSettings;
+use crate::infrastructure::metrics::{
+    FeedType as MetricsFeed, MessageType, set_subscription_utilization, set_subscriptions,
+};
 
 // =============================================================================
 // Type Aliases
@@ -51,6 +63,8 @@ pub struct StreamProxyServerConfig {
     pub version: String,
     /// Environment (PAPER or LIVE).
     pub environment: Environment,
+    /// Per-client stream buffering.
+    pub stream_buffer: StreamBufferSettings,
 }
 
 impl Default for StreamProxyServerConfig {
@@ -58,6 +72,7 @@ impl Default for StreamProxyServerConfig {
         Self {
             version: env!("CARGO_PKG_VERSION").to_string(),
             environment: Environment::Paper,
+            stream_buffer: StreamBufferSettings::default(),
         }
     }
 }
@@ -67,6 +82,9 @@ impl Default for StreamProxyServerConfig {
 // =============================================================================
 
 /// Tracks the state of an upstream feed connection.
+///
+/// A feed sharded across several connections also tracks each shard; its
+/// overall state is then that of its least healthy shard.
 #[derive(Debug)]
 pub struct FeedState {
     feed_type: FeedType,
@@ -76,6 +94,61 @@ pub struct FeedState {
     subscription_count: AtomicI32,
     reconnect_attempts: AtomicI32,
     messages_received: AtomicU64,
+    shards: parking_lot::RwLock<Vec<ShardState>>,
+}
+
+/// State of one connection of a sharded feed.
+#[derive(Debug)]
+struct ShardState {
+    state: parking_lot::RwLock<ConnectionState>,
+    subscription_count: AtomicI32,
+    reconnect_attempts: AtomicI32,
+    messages_received: AtomicU64,
+}
+
+impl ShardState {
+    const fn new() -> Self {
+        Self {
+            state: parking_lot::RwLock::new(ConnectionState::Disconnected),
+            subscription_count: AtomicI32::new(0),
+            reconnect_attempts: AtomicI32::new(0),
+            messages_received: AtomicU64::new(0),
+        }
+    }
+
+    fn health(&self) -> ShardHealth {
+        ShardHealth {
+            state: *self.state.read(),
+            subscription_count: self.subscription_count.load(Ordering::Relaxed),
+            reconnect_attempts: self.reconnect_attempts.load(Ordering::Relaxed),
+            messages_received: self.messages_received.load(Ordering::Relaxed),
+        }
+    }
+}
+
+/// Snapshot of one connection of a sharded feed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ShardHealth {
+    /// Connection state.
+    pub state: ConnectionState,
+    /// Active subscriptions on this connection.
+    pub subscription_count: i32,
+    /// Reconnection attempts since last success.
+    pub reconnect_attempts: i32,
+    /// Messages received on this connection.
+    pub messages_received: u64,
+}
+
+/// Rank connection states from healthiest (0) to least healthy.
+const fn state_severity(state: ConnectionState) -> u8 {
+    match state {
+        ConnectionState::Connected => 0,
+        ConnectionState::Authenticating => 1,
+        ConnectionState::Connecting => 2,
+        ConnectionState::Reconnecting => 3,
+        ConnectionState::Disconnected | ConnectionState::Unspecified => 4,
+        ConnectionState::Error => 5,
+    }
 }
 
 impl FeedState {
@@ -88,9 +161,79 @@ impl FeedState {
             subscription_count: AtomicI32::new(0),
             reconnect_attempts: AtomicI32::new(0),
             messages_received: AtomicU64::new(0),
+            shards: parking_lot::RwLock::new(Vec::new()),
         }
     }
 
+    /// Track `count` shard connections, all initially disconnected.
+    pub fn set_shard_count(&self, count: usize) {
+        *self.shards.write() = (0..count).map(|_| ShardState::new()).collect();
+    }
+
+    /// Set one shard's connection state and recompute the feed state.
+    pub fn set_shard_state(&self, shard: usize, state: ConnectionState) {
+        let shards = self.shards.read();
+        let Some(shard_state) = shards.get(shard) else {
+            return;
+        };
+        *shard_state.state.write() = state;
+        if state == ConnectionState::Connected {
+            shard_state.reconnect_attempts.store(0, Ordering::Relaxed);
+        }
+        drop(shards);
+        self.set_state(self.least_healthy_shard());
+    }
+
+    /// Set one shard's error state with message.
+    pub fn set_shard_error(&self, shard: usize, message: &str) {
+        self.set_shard_state(shard, ConnectionState::Error);
+        *self.error_message.write() = Some(format!("shard {shard}: {message}"));
+    }
+
+    /// Increment one shard's reconnect attempts (and the feed's).
+    pub fn increment_shard_reconnect_attempts(&self, shard: usize) {
+        if let Some(shard_state) = self.shards.read().get(shard) {
+            shard_state
+                .reconnect_attempts
+                .fetch_add(1, Ordering::Relaxed);
+        }
+        self.increment_reconnect_attempts();
+    }
+
+    /// Increment one shard's messages received counter (and the feed's).
+    pub fn increment_shard_messages(&self, shard: usize) {
+        if let Some(shard_state) = self.shards.read().get(shard) {
+            shard_state
+                .messages_received
+                .fetch_add(1, Ordering::Relaxed);
+        }
+        self.increment_messages();
+    }
+
+    /// Update one shard's subscription count.
+    pub fn set_shard_subscription_count(&self, shard: usize, count: i32) {
+        if let Some(shard_state) = self.shards.read().get(shard) {
+            shard_state
+                .subscription_count
+                .store(count, Ordering::Relaxed);
+        }
+    }
+
+    /// Snapshot of each shard (empty if the feed isn't sharded).
+    #[must_use]
+    pub fn shards(&self) -> Vec<ShardHealth> {
+        self.shards.read().iter().map(ShardState::health).collect()
+    }
+
+    fn least_healthy_shard(&self) -> ConnectionState {
+        self.shards
+            .read()
+            .iter()
+            .map(|shard| *shard.state.read())
+            .max_by_key(|state| state_severity(*state))
+            .unwrap_or(ConnectionState::Disconnected)
+    }
+
     /// Set the connection state.
     pub fn set_state(&self, state: ConnectionState) {
         *self.state.write() = state;
@@ -150,10 +293,124 @@ impl FeedState {
             reconnect_attempts: self.reconnect_attempts.load(Ordering::Relaxed),
             messages_received: i64::try_from(self.messages_received.load(Ordering::Relaxed))
                 .unwrap_or(i64::MAX),
+            shards: self
+                .shards()
+                .into_iter()
+                .enumerate()
+                .map(|(shard, health)| ShardStatus {
+                    shard: i32::try_from(shard).unwrap_or(i32::MAX),
+                    state: health.state.into(),
+                    subscription_count: health.subscription_count,
+                    reconnect_attempts: health.reconnect_attempts,
+                    messages_received: i64::try_from(health.messages_received).unwrap_or(i64::MAX),
+                })
+                .collect(),
         }
     }
 }
 
+// =============================================================================
+// Subscription Leases
+// =============================================================================
+
+impl From<SubscriptionLimitExceeded> for Status {
+    fn from(err: SubscriptionLimitExceeded) -> Self {
+        Self::resource_exhausted(err.to_string())
+    }
+}
+
+fn next_consumer_id() -> ConsumerId {
+    uuid::Uuid::new_v4().as_u64_pair().0
+}
+
+/// Symbols a client stream holds in a feed's subscription manager.
+///
+/// Released when the stream's forwarding task ends.
+struct SubscriptionLease {
+    manager: Arc<SubscriptionManager>,
+    feed: MetricsFeed,
+    feed_state: Arc<FeedState>,
+    consumer_id: ConsumerId,
+}
+
+impl SubscriptionLease {
+    /// Register `symbols` for a new consumer on each of `sub_types`.
+    ///
+    /// Streams that don't name symbols take no lease: they only filter what
+    /// the feed already delivers.
+    fn acquire(
+        manager: &Arc<SubscriptionManager>,
+        feed: MetricsFeed,
+        feed_state: &Arc<FeedState>,
+        sub_types: &[SubscriptionType],
+        symbols: &HashSet<String>,
+    ) -> Result<Option<Self>, Status> {
+        if symbols.is_empty() {
+            return Ok(None);
+        }
+
+        let consumer_id = next_consumer_id();
+        let symbols: Vec<Symbol> = symbols.iter().cloned().collect();
+        for sub_type in sub_types {
+            let changes = match manager.add_subscriptions(consumer_id, *sub_type, &symbols) {
+                Ok(changes) => changes,
+                Err(err) => {
+                    tracing::warn!(
+                        consumer_id = %consumer_id,
+                        error = %err,
+                        "Rejected subscription over the symbol cap"
+                    );
+                    // Release whatever the earlier types registered
+                    manager.consumer_disconnected(consumer_id);
+                    return Err(err.into());
+                }
+            };
+
+            if !changes.unsubscribe.is_empty() {
+                tracing::warn!(
+                    consumer_id = %consumer_id,
+                    sub_type = sub_type.as_str(),
+                    evicted = changes.unsubscribe.len(),
+                    "Evicted least recently requested symbols to stay under the symbol cap"
+                );
+            }
+        }
+
+        let lease = Self {
+            manager: Arc::clone(manager),
+            feed,
+            feed_state: Arc::clone(feed_state),
+            consumer_id,
+        };
+        lease.publish();
+        Ok(Some(lease))
+    }
+
+    /// Push the feed's subscription counts to its state and metrics.
+    #[allow(clippy::cast_precision_loss)]
+    fn publish(&self) {
+        let mut symbols = 0;
+        for sub_type in SubscriptionType::all() {
+            let stats = self.manager.stats(*sub_type);
+            if let Some(utilization) = stats.utilization() {
+                set_subscription_utilization(self.feed, *sub_type, utilization);
+            }
+            symbols += stats.symbol_count;
+        }
+
+        set_subscriptions(self.feed, symbols as f64);
+        self.feed_state
+            .set_subscription_count(i32::try_from(symbols).unwrap_or(i32::MAX));
+    }
+}
+
+impl Drop for SubscriptionLease {
+    fn drop(&mut self) {
+        self.manager.consumer_disconnected(self.consumer_id);
+        self.publish();
+    }
+}
+
 // =============================================================================
 // Server Implementation
 // =============================================================================
@@ -162,10 +419,11 @@ impl FeedState {
 pub struct StreamProxyServer {
     config: StreamProxyServerConfig,
     broadcast_hub: SharedBroadcastHub,
-    #[allow(dead_code)]
-    subscription_manager: Arc<SubscriptionManager>,
+    sip_subscriptions: Arc<SubscriptionManager>,
+    opra_subscriptions: Arc<SubscriptionManager>,
     started_at: Instant,
     client_count: Arc<AtomicI32>,
+    consumers: Arc<ConsumerRegistry>,
     sip_state: Arc<FeedState>,
     opra_state: Arc<FeedState>,
     trading_state: Arc<FeedState>,
@@ -173,6 +431,10 @@ pub struct StreamProxyServer {
 
 impl StreamProxyServer {
     /// Create a new gRPC streaming server.
+    ///
+    /// `subscription_manager` tracks stock symbols requested on the SIP
+    /// streams. Option symbols are tracked by an unlimited manager unless one
+    /// is set with [`Self::with_opra_subscriptions`].
     #[must_use]
     pub fn new(
         config: StreamProxyServerConfig,
@@ -182,15 +444,24 @@ impl StreamProxyServer {
         Self {
             config,
             broadcast_hub,
-            subscription_manager,
+            sip_subscriptions: subscription_manager,
+            opra_subscriptions: Arc::new(SubscriptionManager::new()),
             started_at: Instant::now(),
             client_count: Arc::new(AtomicI32::new(0)),
+            consumers: Arc::new(ConsumerRegistry::new()),
             sip_state: Arc::new(FeedState::new(FeedType::Sip)),
             opra_state: Arc::new(FeedState::new(FeedType::Opra)),
             trading_state: Arc::new(FeedState::new(FeedType::TradeUpdates)),
         }
     }
 
+    /// Track option symbols requested on the OPRA streams with `manager`.
+    #[must_use]
+    pub fn with_opra_subscriptions(mut self, manager: Arc<SubscriptionManager>) -> Self {
+        self.opra_subscriptions = manager;
+        self
+    }
+
     /// Get the SIP feed state for external updates.
     #[must_use]
     pub fn sip_state(&self) -> Arc<FeedState> {
@@ -209,19 +480,134 @@ impl StreamProxyServer {
         Arc::clone(&self.trading_state)
     }
 
+    /// Lag snapshots of every open client stream.
+    #[must_use]
+    pub fn consumer_lag(&self) -> Vec<ConsumerLag> {
+        self.consumers.snapshot()
+    }
+
     fn increment_client_count(&self) {
         self.client_count.fetch_add(1, Ordering::Relaxed);
     }
+
+    /// Forward broadcast messages that `to_response` keeps into a buffered
+    /// client stream.
+    ///
+    /// The task drains the broadcast channel into the stream's own bounded
+    /// buffer, applies the drop policy when the client falls behind, and
+    /// queues a lag report on every report interval. The subscription lease,
+    /// if any, is released when the stream ends.
+    fn forward<M, R>(
+        &self,
+        lease: Option<SubscriptionLease>,
+        stream: MessageType,
+        mut rx: broadcast::Receiver<M>,
+        capacity: usize,
+        feed_state: Arc<FeedState>,
+        to_response: impl Fn(&M) -> Option<R> + Send + 'static,
+    ) -> BoxedStream<R>
+    where
+        M: Clone + Send + 'static,
+        R: LagReported + Send + 'static,
+    {
+        let consumer_id = lease
+            .as_ref()
+            .map_or_else(next_consumer_id, |lease| lease.consumer_id);
+        self.increment_client_count();
+
+        let (mut tx, grpc_rx) = stream_buffer(
+            &self.consumers,
+            consumer_id,
+            stream,
+            capacity,
+            self.config.stream_buffer.drop_policy,
+        );
+        let mut lag_reports = self
+            .config
+            .stream_buffer
+            .lag_report_interval
+            .map(lag_report_interval);
+        let client_count = self.client_count.clone();
+
+        tokio::spawn(async move {
+            let failure = loop {
+                tokio::select! {
+                    message = rx.recv() => match message {
+                        Ok(message) => {
+                            let Some(response) = to_response(&message) else {
+                                continue;
+                            };
+                            feed_state.increment_messages();
+                            if let Err(status) = tx.push(response) {
+                                break Some(status);
+                            }
+                        }
+                        Err(broadcast::error::RecvError::Lagged(n)) => {
+                            tracing::warn!(
+                                consumer_id = %consumer_id,
+                                stream = stream.as_str(),
+                                lagged = n,
+                                "Broadcast receiver lagged"
+                            );
+                            if let Err(status) = tx.record_dropped(n) {
+                                break Some(status);
+                            }
+                        }
+                        Err(broadcast::error::RecvError::Closed) => break None,
+                    },
+                    () = tx.closed() => break None,
+                    () = next_tick(&mut lag_reports) => tx.queue_report(),
+                }
+            };
+
+            if let Some(status) = failure {
+                tx.fail(status);
+            }
+            client_count.fetch_sub(1, Ordering::Relaxed);
+            drop(lease);
+        });
+
+        grpc_rx.into_stream()
+    }
+}
+
+/// Implement `LagReported` for stream responses whose payload is `$field`.
+macro_rules! impl_lag_reported {
+    ($($response:ident { $field:ident }),* $(,)?) => {
+        $(
+            impl LagReported for $response {
+                fn from_lag_report(report: LagReport) -> Self {
+                    Self {
+                        $field: None,
+                        lag_report: Some(report),
+                    }
+                }
+            }
+        )*
+    };
 }
 
+impl_lag_reported!(
+    StreamQuotesResponse { quote },
+    StreamTradesResponse { trade },
+    StreamBarsResponse { bar },
+    StreamTradingStatusResponse { status },
+    StreamOptionQuotesResponse { quote },
+    StreamOptionTradesResponse { trade },
+    StreamOrderUpdatesResponse { update },
+    StreamSignalsResponse { signal },
+);
+
 #[tonic::async_trait]
 impl StreamProxyService for StreamProxyServer {
     type StreamQuotesStream = BoxedStream<StreamQuotesResponse>;
     type StreamTradesStream = BoxedStream<StreamTradesResponse>;
     type StreamBarsStream = BoxedStream<StreamBarsResponse>;
+    type StreamTradingStatusStream = BoxedStream<StreamTradingStatusResponse>;
     type StreamOptionQuotesStream = BoxedStream<StreamOptionQuotesResponse>;
     type StreamOptionTradesStream = BoxedStream<StreamOptionTradesResponse>;
     type StreamOrderUpdatesStream = BoxedStream<StreamOrderUpdatesResponse>;
+    type StreamSignalsStream = BoxedStream<StreamSignalsResponse>;
 
     async fn stream_quotes(
         &self,
@@ -231,41 +617,30 @@ impl StreamProxyService for StreamProxyServer {
         let symbols: HashSet<String> = req.symbols.into_iter().collect();
         let filter_all = symbols.is_empty();
 
-        let consumer_id = uuid::Uuid::new_v4().as_u64_pair().0;
-        self.increment_client_count();
-
-        let mut rx = self.broadcast_hub.stock_quotes_rx();
-        let (tx, grpc_rx) = tokio::sync::mpsc::channel(1024);
-        let client_count = self.client_count.clone();
-        let sip_state = Arc::clone(&self.sip_state);
-
-        tokio::spawn(async move {
-            loop {
-                match rx.recv().await {
-                    Ok(broadcast) => {
-                        if filter_all || symbols.contains(&broadcast.quote.symbol) {
-                            sip_state.increment_messages();
-                            let response = StreamQuotesResponse {
-                                quote: Some(stock_quote_to_proto(&broadcast.quote)),
-                            };
-                            if tx.send(Ok(response)).await.is_err() {
-                                break;
-                            }
-                        }
-                    }
-                    Err(broadcast::error::RecvError::Lagged(n)) => {
-                        tracing::warn!(consumer_id = %consumer_id, lagged = n, "Quote receiver lagged");
-                    }
-                    Err(broadcast::error::RecvError::Closed) => {
-                        break;
+        let lease = SubscriptionLease::acquire(
+            &self.sip_subscriptions,
+            MetricsFeed::Sip,
+            &self.sip_state,
+            &[SubscriptionType::Quotes],
+            &symbols,
+        )?;
+
+        let stream = self.forward(
+            lease,
+            MessageType::StockQuote,
+            self.broadcast_hub.stock_quotes_rx(),
+            1024,
+            Arc::clone(&self.sip_state),
+            move |broadcast| {
+                (filter_all || symbols.contains(&broadcast.quote.symbol)).then(|| {
+                    StreamQuotesResponse {
+                        quote: Some(stock_quote_to_proto(&broadcast.quote)),
+                        lag_report: None,
                     }
-                }
-            }
-            client_count.fetch_sub(1, Ordering::Relaxed);
-        });
-
-        let stream = ReceiverStream::new(grpc_rx);
-        Ok(Response::new(Box::pin(stream) as Self::StreamQuotesStream))
+                })
+            },
+        );
+        Ok(Response::new(stream))
     }
 
     async fn stream_trades(
@@ -276,41 +651,30 @@ impl StreamProxyService for StreamProxyServer {
         let symbols: HashSet<String> = req.symbols.into_iter().collect();
         let filter_all = symbols.is_empty();
 
-        let consumer_id = uuid::Uuid::new_v4().as_u64_pair().0;
-        self.increment_client_count();
-
-        let mut rx = self.broadcast_hub.stock_trades_rx();
-        let (tx, grpc_rx) = tokio::sync::mpsc::channel(1024);
-        let client_count = self.client_count.clone();
-        let sip_state = Arc::clone(&self.sip_state);
-
-        tokio::spawn(async move {
-            loop {
-                match rx.recv().await {
-                    Ok(broadcast) => {
-                        if filter_all || symbols.contains(&broadcast.trade.symbol) {
-                            sip_state.increment_messages();
-                            let response = StreamTradesResponse {
-                                trade: Some(stock_trade_to_proto(&broadcast.trade)),
-                            };
-                            if tx.send(Ok(response)).await.is_err() {
-                                break;
-                            }
-                        }
-                    }
-                    Err(broadcast::error::RecvError::Lagged(n)) => {
-                        tracing::warn!(consumer_id = %consumer_id, lagged = n, "Trade receiver lagged");
-                    }
-                    Err(broadcast::error::RecvError::Closed) => {
-                        break;
+        let lease = SubscriptionLease::acquire(
+            &self.sip_subscriptions,
+            MetricsFeed::Sip,
+            &self.sip_state,
+            &[SubscriptionType::Trades],
+            &symbols,
+        )?;
+
+        let stream = self.forward(
+            lease,
+            MessageType::StockTrade,
+            self.broadcast_hub.stock_trades_rx(),
+            1024,
+            Arc::clone(&self.sip_state),
+            move |broadcast| {
+                (filter_all || symbols.contains(&broadcast.trade.symbol)).then(|| {
+                    StreamTradesResponse {
+                        trade: Some(stock_trade_to_proto(&broadcast.trade)),
+                        lag_report: None,
                     }
-                }
-            }
-            client_count.fetch_sub(1, Ordering::Relaxed);
-        });
-
-        let stream = ReceiverStream::new(grpc_rx);
-        Ok(Response::new(Box::pin(stream) as Self::StreamTradesStream))
+                })
+            },
+        );
+        Ok(Response::new(stream))
     }
 
     async fn stream_bars(
@@ -321,41 +685,48 @@ impl StreamProxyService for StreamProxyServer {
         let symbols: HashSet<String> = req.symbols.into_iter().collect();
         let filter_all = symbols.is_empty();
 
-        let consumer_id = uuid::Uuid::new_v4().as_u64_pair().0;
-        self.increment_client_count();
+        let stream = self.forward(
+            None,
+            MessageType::StockBar,
+            self.broadcast_hub.stock_bars_rx(),
+            256,
+            Arc::clone(&self.sip_state),
+            move |broadcast| {
+                (filter_all || symbols.contains(&broadcast.bar.symbol)).then(|| {
+                    StreamBarsResponse {
+                        bar: Some(stock_bar_to_proto(&broadcast.bar)),
+                        lag_report: None,
+                    }
+                })
+            },
+        );
+        Ok(Response::new(stream))
+    }
 
-        let mut rx = self.broadcast_hub.stock_bars_rx();
-        let (tx, grpc_rx) = tokio::sync::mpsc::channel(256);
-        let client_count = self.client_count.clone();
-        let sip_state = Arc::clone(&self.sip_state);
+    async fn stream_trading_status(
+        &self,
+        request: Request<StreamTradingStatusRequest>,
+    ) -> StreamResult<Self::StreamTradingStatusStream> {
+        let req = request.into_inner();
+        let symbols: HashSet<String> = req.symbols.into_iter().collect();
+        let filter_all = symbols.is_empty();
 
-        tokio::spawn(async move {
-            loop {
-                match rx.recv().await {
-                    Ok(broadcast) => {
-                        if filter_all || symbols.contains(&broadcast.bar.symbol) {
-                            sip_state.increment_messages();
-                            let response = StreamBarsResponse {
-                                bar: Some(stock_bar_to_proto(&broadcast.bar)),
-                            };
-                            if tx.send(Ok(response)).await.is_err() {
-                                break;
-                            }
-                        }
-                    }
-                    Err(broadcast::error::RecvError::Lagged(n)) => {
-                        tracing::warn!(consumer_id = %consumer_id, lagged = n, "Bar receiver lagged");
+        let stream = self.forward(
+            None,
+            MessageType::TradingStatus,
+            self.broadcast_hub.trading_statuses_rx(),
+            256,
+            Arc::clone(&self.sip_state),
+            move |broadcast| {
+                (filter_all || symbols.contains(&broadcast.status.symbol)).then(|| {
+                    StreamTradingStatusResponse {
+                        status: Some(trading_status_to_proto(&broadcast.status)),
+                        lag_report: None,
                     }
-                    Err(broadcast::error::RecvError::Closed) => {
-                        break;
-                    }
-                }
-            }
-            client_count.fetch_sub(1, Ordering::Relaxed);
-        });
-
-        let stream = ReceiverStream::new(grpc_rx);
-        Ok(Response::new(Box::pin(stream) as Self::StreamBarsStream))
+                })
+            },
+        );
+        Ok(Response::new(stream))
     }
 
     async fn stream_option_quotes(
@@ -367,49 +738,34 @@ impl StreamProxyService for StreamProxyServer {
         let underlyings: HashSet<String> = req.underlyings.into_iter().collect();
         let filter_all = symbols.is_empty() && underlyings.is_empty();
 
-        let consumer_id = uuid::Uuid::new_v4().as_u64_pair().0;
-        self.increment_client_count();
-
-        let mut rx = self.broadcast_hub.options_quotes_rx();
-        let (tx, grpc_rx) = tokio::sync::mpsc::channel(4096);
-        let client_count = self.client_count.clone();
-        let opra_state = Arc::clone(&self.opra_state);
-
-        tokio::spawn(async move {
-            loop {
-                match rx.recv().await {
-                    Ok(broadcast) => {
-                        let matches = filter_all
-                            || symbols.contains(&broadcast.quote.symbol)
-                            || underlyings
-                                .iter()
-                                .any(|u| broadcast.quote.symbol.starts_with(u));
-
-                        if matches {
-                            opra_state.increment_messages();
-                            let response = StreamOptionQuotesResponse {
-                                quote: Some(option_quote_to_proto(&broadcast.quote)),
-                            };
-                            if tx.send(Ok(response)).await.is_err() {
-                                break;
-                            }
-                        }
-                    }
-                    Err(broadcast::error::RecvError::Lagged(n)) => {
-                        tracing::warn!(consumer_id = %consumer_id, lagged = n, "Option quote receiver lagged");
-                    }
-                    Err(broadcast::error::RecvError::Closed) => {
-                        break;
-                    }
-                }
-            }
-            client_count.fetch_sub(1, Ordering::Relaxed);
-        });
-
-        let stream = ReceiverStream::new(grpc_rx);
-        Ok(Response::new(
-            Box::pin(stream) as Self::StreamOptionQuotesStream
-        ))
+        let lease = SubscriptionLease::acquire(
+            &self.opra_subscriptions,
+            MetricsFeed::Opra,
+            &self.opra_state,
+            &[SubscriptionType::Quotes],
+            &symbols,
+        )?;
+
+        let stream = self.forward(
+            lease,
+            MessageType::OptionQuote,
+            self.broadcast_hub.options_quotes_rx(),
+            4096,
+            Arc::clone(&self.opra_state),
+            move |broadcast| {
+                let matches = filter_all
+                    || symbols.contains(&broadcast.quote.symbol)
+                    || underlyings
+                        .iter()
+                        .any(|u| broadcast.quote.symbol.starts_with(u));
+
+                matches.then(|| StreamOptionQuotesResponse {
+                    quote: Some(option_quote_to_proto(&broadcast.quote)),
+                    lag_report: None,
+                })
+            },
+        );
+        Ok(Response::new(stream))
     }
 
     async fn stream_option_trades(
@@ -421,49 +777,34 @@ impl StreamProxyService for StreamProxyServer {
         let underlyings: HashSet<String> = req.underlyings.into_iter().collect();
         let filter_all = symbols.is_empty() && underlyings.is_empty();
 
-        let consumer_id = uuid::Uuid::new_v4().as_u64_pair().0;
-        self.increment_client_count();
-
-        let mut rx = self.broadcast_hub.options_trades_rx();
-        let (tx, grpc_rx) = tokio::sync::mpsc::channel(1024);
-        let client_count = self.client_count.clone();
-        let opra_state = Arc::clone(&self.opra_state);
-
-        tokio::spawn(async move {
-            loop {
-                match rx.recv().await {
-                    Ok(broadcast) => {
-                        let matches = filter_all
-                            || symbols.contains(&broadcast.trade.symbol)
-                            || underlyings
-                                .iter()
-                                .any(|u| broadcast.trade.symbol.starts_with(u));
-
-                        if matches {
-                            opra_state.increment_messages();
-                            let response = StreamOptionTradesResponse {
-                                trade: Some(option_trade_to_proto(&broadcast.trade)),
-                            };
-                            if tx.send(Ok(response)).await.is_err() {
-                                break;
-                            }
-                        }
-                    }
-                    Err(broadcast::error::RecvError::Lagged(n)) => {
-                        tracing::warn!(consumer_id = %consumer_id, lagged = n, "Option trade receiver lagged");
-                    }
-                    Err(broadcast::error::RecvError::Closed) => {
-                        break;
-                    }
-                }
-            }
-            client_count.fetch_sub(1, Ordering::Relaxed);
-        });
-
-        let stream = ReceiverStream::new(grpc_rx);
-        Ok(Response::new(
-            Box::pin(stream) as Self::StreamOptionTradesStream
-        ))
+        let lease = SubscriptionLease::acquire(
+            &self.opra_subscriptions,
+            MetricsFeed::Opra,
+            &self.opra_state,
+            &[SubscriptionType::Trades],
+            &symbols,
+        )?;
+
+        let stream = self.forward(
+            lease,
+            MessageType::OptionTrade,
+            self.broadcast_hub.options_trades_rx(),
+            1024,
+            Arc::clone(&self.opra_state),
+            move |broadcast| {
+                let matches = filter_all
+                    || symbols.contains(&broadcast.trade.symbol)
+                    || underlyings
+                        .iter()
+                        .any(|u| broadcast.trade.symbol.starts_with(u));
+
+                matches.then(|| StreamOptionTradesResponse {
+                    trade: Some(option_trade_to_proto(&broadcast.trade)),
+                    lag_report: None,
+                })
+            },
+        );
+        Ok(Response::new(stream))
     }
 
     async fn stream_order_updates(
@@ -475,47 +816,59 @@ impl StreamProxyService for StreamProxyServer {
         let symbols: HashSet<String> = req.symbols.into_iter().collect();
         let filter_all = order_ids.is_empty() && symbols.is_empty();
 
-        let consumer_id = uuid::Uuid::new_v4().as_u64_pair().0;
-        self.increment_client_count();
+        let stream = self.forward(
+            None,
+            MessageType::OrderUpdate,
+            self.broadcast_hub.order_updates_rx(),
+            256,
+            Arc::clone(&self.trading_state),
+            move |broadcast| {
+                let matches = filter_all
+                    || order_ids.contains(&broadcast.update.data.order.id)
+                    || symbols.contains(&broadcast.update.data.order.symbol);
+
+                matches.then(|| StreamOrderUpdatesResponse {
+                    update: Some(order_update_to_proto(&broadcast.update)),
+                    lag_report: None,
+                })
+            },
+        );
+        Ok(Response::new(stream))
+    }
 
-        let mut rx = self.broadcast_hub.order_updates_rx();
-        let (tx, grpc_rx) = tokio::sync::mpsc::channel(256);
-        let client_count = self.client_count.clone();
-        let trading_state = Arc::clone(&self.trading_state);
+    async fn stream_signals(
+        &self,
+        request: Request<StreamSignalsRequest>,
+    ) -> StreamResult<Self::StreamSignalsStream> {
+        let req = request.into_inner();
+        let symbols: HashSet<String> = req.symbols.into_iter().collect();
+        let filter_all = symbols.is_empty();
 
-        tokio::spawn(async move {
-            loop {
-                match rx.recv().await {
-                    Ok(broadcast) => {
-                        let matches = filter_all
-                            || order_ids.contains(&broadcast.update.data.order.id)
-                            || symbols.contains(&broadcast.update.data.order.symbol);
-
-                        if matches {
-                            trading_state.increment_messages();
-                            let response = StreamOrderUpdatesResponse {
-                                update: Some(order_update_to_proto(&broadcast.update)),
-                            };
-                            if tx.send(Ok(response)).await.is_err() {
-                                break;
-                            }
-                        }
-                    }
-                    Err(broadcast::error::RecvError::Lagged(n)) => {
-                        tracing::warn!(consumer_id = %consumer_id, lagged = n, "Order update receiver lagged");
+        // Signals are computed from both trades and quotes
+        let lease = SubscriptionLease::acquire(
+            &self.sip_subscriptions,
+            MetricsFeed::Sip,
+            &self.sip_state,
+            &[SubscriptionType::Trades, SubscriptionType::Quotes],
+            &symbols,
+        )?;
+
+        let stream = self.forward(
+            lease,
+            MessageType::Signal,
+            self.broadcast_hub.signals_rx(),
+            1024,
+            Arc::clone(&self.sip_state),
+            move |broadcast| {
+                (filter_all || symbols.contains(&broadcast.signal.symbol)).then(|| {
+                    StreamSignalsResponse {
+                        signal: Some(microstructure_signal_to_proto(&broadcast.signal)),
+                        lag_report: None,
                     }
-                    Err(broadcast::error::RecvError::Closed) => {
-                        break;
-                    }
-                }
-            }
-            client_count.fetch_sub(1, Ordering::Relaxed);
-        });
-
-        let stream = ReceiverStream::new(grpc_rx);
-        Ok(Response::new(
-            Box::pin(stream) as Self::StreamOrderUpdatesStream
-        ))
+                })
+            },
+        );
+        Ok(Response::new(stream))
     }
 
     async fn get_connection_status(
@@ -549,7 +902,7 @@ impl StreamProxyService for StreamProxyServer {
 // Conversion Functions
 // =============================================================================
 
-fn datetime_to_timestamp(dt: DateTime<Utc>) -> Timestamp {
+pub(super) fn datetime_to_timestamp(dt: DateTime<Utc>) -> Timestamp {
     Timestamp {
         seconds: dt.timestamp(),
         nanos: i32::try_from(dt.timestamp_subsec_nanos()).unwrap_or(i32::MAX),
@@ -557,11 +910,12 @@ fn datetime_to_timestamp(dt: DateTime<Utc>) -> Timestamp {
 }
 
 fn decimal_to_f64(d: Decimal) -> f64 {
-    use std::str::FromStr;
-    f64::from_str(&d.to_string()).unwrap_or(0.0)
+    d.to_f64().unwrap_or(0.0)
 }
 
-fn stock_quote_to_proto(msg: &StockQuoteMessage) -> StockQuote {
+/// Convert a SIP quote to its proto form.
+#[must_use]
+pub fn stock_quote_to_proto(msg: &StockQuoteMessage) -> StockQuote {
     StockQuote {
         symbol: msg.symbol.clone(),
         timestamp: Some(datetime_to_timestamp(msg.timestamp)),
@@ -576,7 +930,9 @@ fn stock_quote_to_proto(msg: &StockQuoteMessage) -> StockQuote {
     }
 }
 
-fn stock_trade_to_proto(msg: &StockTradeMessage) -> StockTrade {
+/// Convert a SIP trade to its proto form.
+#[must_use]
+pub fn stock_trade_to_proto(msg: &StockTradeMessage) -> StockTrade {
     StockTrade {
         symbol: msg.symbol.clone(),
         timestamp: Some(datetime_to_timestamp(msg.timestamp)),
@@ -603,7 +959,21 @@ fn stock_bar_to_proto(msg: &StockBarMessage) -> StockBar {
     }
 }
 
-fn option_quote_to_proto(msg: &OptionQuoteMessage) -> OptionQuoteUpdate {
+fn trading_status_to_proto(msg: &StockStatusMessage) -> TradingStatus {
+    TradingStatus {
+        symbol: msg.symbol.clone(),
+        timestamp: msg.timestamp.map(datetime_to_timestamp),
+        status_code: msg.status_code.clone().unwrap_or_default(),
+        status_message: msg.status_message.clone().unwrap_or_default(),
+        reason_code: msg.reason_code.clone().unwrap_or_default(),
+        reason_message: msg.reason_message.clone().unwrap_or_default(),
+        tape: msg.tape.clone().unwrap_or_default(),
+    }
+}
+
+/// Convert an OPRA quote to its proto form.
+#[must_use]
+pub fn option_quote_to_proto(msg: &OptionQuoteMessage) -> OptionQuoteUpdate {
     OptionQuoteUpdate {
         symbol: msg.symbol.clone(),
         timestamp: Some(datetime_to_timestamp(msg.timestamp)),
@@ -617,7 +987,9 @@ fn option_quote_to_proto(msg: &OptionQuoteMessage) -> OptionQuoteUpdate {
     }
 }
 
-fn option_trade_to_proto(msg: &OptionTradeMessage) -> OptionTrade {
+/// Convert an OPRA trade to its proto form.
+#[must_use]
+pub fn option_trade_to_proto(msg: &OptionTradeMessage) -> OptionTrade {
     OptionTrade {
         symbol: msg.symbol.clone(),
         timestamp: Some(datetime_to_timestamp(msg.timestamp)),
@@ -628,6 +1000,37 @@ fn option_trade_to_proto(msg: &OptionTradeMessage) -> OptionTrade {
     }
 }
 
+fn tick_direction_to_proto(direction: TickDirection) -> i32 {
+    match direction {
+        TickDirection::Unknown => proto::TickDirection::Unspecified.into(),
+        TickDirection::Up => proto::TickDirection::Up.into(),
+        TickDirection::Down => proto::TickDirection::Down.into(),
+        TickDirection::ZeroUp => proto::TickDirection::ZeroUp.into(),
+        TickDirection::ZeroDown => proto::TickDirection::ZeroDown.into(),
+    }
+}
+
+/// Convert a microstructure signal to its proto form.
+#[must_use]
+pub fn microstructure_signal_to_proto(
+    signal: &MicrostructureSignal,
+) -> proto::MicrostructureSignal {
+    proto::MicrostructureSignal {
+        symbol: signal.symbol.clone(),
+        timestamp: Some(datetime_to_timestamp(signal.timestamp)),
+        last_price: signal.last_price,
+        tick_direction: tick_direction_to_proto(signal.tick_direction),
+        trade_imbalance: signal.trade_imbalance,
+        buy_volume: signal.buy_volume,
+        sell_volume: signal.sell_volume,
+        trade_count: i32::try_from(signal.trade_count).unwrap_or(i32::MAX),
+        quote_stability: signal.quote_stability,
+        quote_updates: i32::try_from(signal.quote_updates).unwrap_or(i32::MAX),
+        spread_bps: signal.spread_bps,
+        window_seconds: i32::try_from(signal.window.as_secs()).unwrap_or(i32::MAX),
+    }
+}
+
 fn order_event_to_proto(event: OrderEventType) -> i32 {
     match event {
         OrderEventType::New => OrderEvent::New.into(),
@@ -806,6 +1209,53 @@ mod tests {
         assert_eq!(state.subscription_count.load(Ordering::Relaxed), 5);
     }
 
+    #[test]
+    fn feed_state_follows_least_healthy_shard() {
+        let state = FeedState::new(FeedType::Opra);
+        state.set_shard_count(3);
+
+        state.set_shard_state(0, ConnectionState::Connected);
+        state.set_shard_state(1, ConnectionState::Connected);
+        assert_eq!(state.get_state(), ConnectionState::Disconnected);
+
+        state.set_shard_state(2, ConnectionState::Connected);
+        assert_eq!(state.get_state(), ConnectionState::Connected);
+
+        state.set_shard_state(1, ConnectionState::Reconnecting);
+        state.increment_shard_reconnect_attempts(1);
+        assert_eq!(state.get_state(), ConnectionState::Reconnecting);
+        assert_eq!(state.shards()[1].reconnect_attempts, 1);
+
+        state.set_shard_error(2, "auth failed");
+        assert_eq!(state.get_state(), ConnectionState::Error);
+        assert_eq!(
+            state.error_message.read().as_deref(),
+            Some("shard 2: auth failed")
+        );
+    }
+
+    #[test]
+    fn feed_state_shard_counters_roll_up() {
+        let state = FeedState::new(FeedType::Opra);
+        state.set_shard_count(2);
+
+        state.increment_shard_messages(0);
+        state.increment_shard_messages(1);
+        state.increment_shard_messages(1);
+        state.set_shard_subscription_count(1, 7);
+
+        let shards = state.shards();
+        assert_eq!(shards[0].messages_received, 1);
+        assert_eq!(shards[1].messages_received, 2);
+        assert_eq!(shards[1].subscription_count, 7);
+        assert_eq!(state.get_messages_received(), 3);
+
+        let status = state.to_proto();
+        assert_eq!(status.shards.len(), 2);
+        assert_eq!(status.shards[1].shard, 1);
+        assert!(FeedState::new(FeedType::Sip).to_proto().shards.is_empty());
+    }
+
     #[test]
     fn datetime_conversion() {
         let dt = Utc::now();
@@ -822,4 +1272,25 @@ mod tests {
         let f = decimal_to_f64(d);
         assert!((f - 123.45).abs() < 0.001);
     }
+
+    #[test]
+    fn trading_status_conversion() {
+        let msg = StockStatusMessage {
+            msg_type: "s".to_string(),
+            symbol: "AAPL".to_string(),
+            status_code: Some("H".to_string()),
+            status_message: Some("Trading Halt".to_string()),
+            reason_code: Some("LUDP".to_string()),
+            reason_message: None,
+            timestamp: Some(Utc::now()),
+            tape: Some("C".to_string()),
+        };
+
+        let status = trading_status_to_proto(&msg);
+        assert_eq!(status.symbol, "AAPL");
+        assert_eq!(status.status_code, "H");
+        assert_eq!(status.reason_code, "LUDP");
+        assert!(status.reason_message.is_empty());
+        assert!(status.timestamp.is_some());
+    }
 }
@@ -0,0 +1,493 @@
+//! Per-Client Stream Buffering
+//!
+//! Bounded buffer between a broadcast receiver and one client's gRPC
+//! response stream. The forwarding task drains the broadcast channel as fast
+//! as messages arrive and parks them here, so a stalled client fills its own
+//! buffer instead of lagging silently on the hub.
+//!
+//! # Drop Policy
+//!
+//! When a buffer is full the oldest message is dropped and counted. Under
+//! [`DropPolicy::Disconnect`] the stream is ended with `RESOURCE_EXHAUSTED`
+//! once the client has dropped more than the configured number of messages.
+//!
+//! # Lag Reports
+//!
+//! A [`LagReport`] can be queued at any time; it is delivered ahead of
+//! buffered messages and replaces an earlier report the client has not read.
+
+use std::collections::{HashMap, VecDeque};
+use std::pin::Pin;
+use std::sync::Arc;
+use std::sync::atomic::{AtomicBool, AtomicU64, AtomicUsize, Ordering};
+
+use chrono::{DateTime, Utc};
+use tokio::sync::Notify;
+use tokio_stream::Stream;
+use tonic::Status;
+
+use super::proto::cream::v1::LagReport;
+use super::server::datetime_to_timestamp;
+use crate::infrastructure::config::DropPolicy;
+use crate::infrastructure::metrics::{self, MessageType};
+
+// =============================================================================
+// Consumer Statistics
+// =============================================================================
+
+/// Delivery counters for one client stream.
+#[derive(Debug)]
+pub struct ConsumerStats {
+    consumer_id: u64,
+    stream: MessageType,
+    opened_at: DateTime<Utc>,
+    capacity: usize,
+    delivered: AtomicU64,
+    dropped: AtomicU64,
+    buffered: AtomicUsize,
+}
+
+impl ConsumerStats {
+    fn new(consumer_id: u64, stream: MessageType, capacity: usize) -> Self {
+        Self {
+            consumer_id,
+            stream,
+            opened_at: Utc::now(),
+            capacity,
+            delivered: AtomicU64::new(0),
+            dropped: AtomicU64::new(0),
+            buffered: AtomicUsize::new(0),
+        }
+    }
+
+    /// Point-in-time copy of the counters.
+    #[must_use]
+    pub fn snapshot(&self) -> ConsumerLag {
+        ConsumerLag {
+            consumer_id: self.consumer_id,
+            stream: self.stream,
+            opened_at: self.opened_at,
+            capacity: self.capacity,
+            delivered: self.delivered.load(Ordering::Relaxed),
+            dropped: self.dropped.load(Ordering::Relaxed),
+            buffered: self.buffered.load(Ordering::Relaxed),
+        }
+    }
+}
+
+/// Snapshot of one client stream's delivery counters.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ConsumerLag {
+    /// Stream identifier.
+    pub consumer_id: u64,
+    /// Message type carried by the stream.
+    pub stream: MessageType,
+    /// When the stream was opened.
+    pub opened_at: DateTime<Utc>,
+    /// Buffer capacity.
+    pub capacity: usize,
+    /// Messages handed to the gRPC transport.
+    pub delivered: u64,
+    /// Messages dropped because the client fell behind.
+    pub dropped: u64,
+    /// Messages currently buffered.
+    pub buffered: usize,
+}
+
+/// Open client streams, for per-consumer lag reporting.
+#[derive(Debug, Default)]
+pub struct ConsumerRegistry {
+    consumers: parking_lot::Mutex<HashMap<u64, Arc<ConsumerStats>>>,
+}
+
+impl ConsumerRegistry {
+    /// Create an empty registry.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Lag snapshots of every open stream, oldest first.
+    #[must_use]
+    pub fn snapshot(&self) -> Vec<ConsumerLag> {
+        let mut consumers: Vec<ConsumerLag> = self
+            .consumers
+            .lock()
+            .values()
+            .map(|stats| stats.snapshot())
+            .collect();
+        consumers.sort_by_key(|c| (c.opened_at, c.consumer_id));
+        consumers
+    }
+
+    fn register(self: &Arc<Self>, stats: Arc<ConsumerStats>) -> Registration {
+        let consumer_id = stats.consumer_id;
+        self.consumers.lock().insert(consumer_id, stats);
+        Registration {
+            registry: Arc::clone(self),
+            consumer_id,
+        }
+    }
+}
+
+/// Removes a stream from the registry when dropped.
+#[derive(Debug)]
+struct Registration {
+    registry: Arc<ConsumerRegistry>,
+    consumer_id: u64,
+}
+
+impl Drop for Registration {
+    fn drop(&mut self) {
+        self.registry.consumers.lock().remove(&self.consumer_id);
+    }
+}
+
+// =============================================================================
+// Buffer
+// =============================================================================
+
+/// Response types that can carry a lag report.
+pub trait LagReported: Sized {
+    /// Response carrying only `report`.
+    fn from_lag_report(report: LagReport) -> Self;
+}
+
+#[derive(Debug)]
+struct Queue<T> {
+    messages: VecDeque<T>,
+    report: Option<LagReport>,
+    error: Option<Status>,
+    finished: bool,
+}
+
+#[derive(Debug)]
+struct Shared<T> {
+    queue: parking_lot::Mutex<Queue<T>>,
+    stats: Arc<ConsumerStats>,
+    /// Woken when the receiver has something to read.
+    ready: Notify,
+    /// Set once the receiver is dropped.
+    closed: AtomicBool,
+    /// Woken when the receiver is dropped.
+    gone: Notify,
+}
+
+/// Create a buffer for one client stream and register it in `registry`.
+#[must_use]
+pub fn stream_buffer<T: LagReported>(
+    registry: &Arc<ConsumerRegistry>,
+    consumer_id: u64,
+    stream: MessageType,
+    capacity: usize,
+    policy: DropPolicy,
+) -> (BufferSender<T>, BufferReceiver<T>) {
+    let capacity = capacity.max(1);
+    let stats = Arc::new(ConsumerStats::new(consumer_id, stream, capacity));
+    let shared = Arc::new(Shared {
+        queue: parking_lot::Mutex::new(Queue {
+            messages: VecDeque::with_capacity(capacity),
+            report: None,
+            error: None,
+            finished: false,
+        }),
+        stats: Arc::clone(&stats),
+        ready: Notify::new(),
+        closed: AtomicBool::new(false),
+        gone: Notify::new(),
+    });
+
+    let sender = BufferSender {
+        shared: Arc::clone(&shared),
+        policy,
+        reported_dropped: 0,
+        _registration: registry.register(stats),
+    };
+    (sender, BufferReceiver { shared })
+}
+
+/// Producer half, owned by the forwarding task.
+#[derive(Debug)]
+pub struct BufferSender<T> {
+    shared: Arc<Shared<T>>,
+    policy: DropPolicy,
+    reported_dropped: u64,
+    _registration: Registration,
+}
+
+impl<T> BufferSender<T> {
+    /// Buffer `message`, dropping the oldest one if the buffer is full.
+    ///
+    /// # Errors
+    ///
+    /// Returns `RESOURCE_EXHAUSTED` when the drop policy says the client
+    /// should be disconnected.
+    pub fn push(&self, message: T) -> Result<(), Status> {
+        let overflowed = {
+            let mut queue = self.shared.queue.lock();
+            let overflowed = queue.messages.len() >= self.shared.stats.capacity;
+            if overflowed {
+                queue.messages.pop_front();
+            }
+            queue.messages.push_back(message);
+            self.shared
+                .stats
+                .buffered
+                .store(queue.messages.len(), Ordering::Relaxed);
+            overflowed
+        };
+        self.shared.ready.notify_one();
+
+        if overflowed {
+            self.record_dropped(1)
+        } else {
+            Ok(())
+        }
+    }
+
+    /// Count messages lost before they reached the buffer (broadcast lag).
+    ///
+    /// # Errors
+    ///
+    /// Returns `RESOURCE_EXHAUSTED` when the drop policy says the client
+    /// should be disconnected.
+    pub fn record_dropped(&self, count: u64) -> Result<(), Status> {
+        let stats = &self.shared.stats;
+        let dropped = stats.dropped.fetch_add(count, Ordering::Relaxed) + count;
+        metrics::record_messages_dropped(stats.stream, count);
+
+        match self.policy {
+            DropPolicy::Disconnect { max_dropped } if dropped > max_dropped => {
+                metrics::record_slow_consumer_disconnect(stats.stream);
+                tracing::warn!(
+                    consumer_id = %stats.consumer_id,
+                    stream = stats.stream.as_str(),
+                    dropped,
+                    "Disconnecting slow consumer"
+                );
+                Err(Status::resource_exhausted(format!(
+                    "client fell behind: {dropped} messages dropped"
+                )))
+            }
+            _ => Ok(()),
+        }
+    }
+
+    /// Queue a lag report, replacing one the client has not read yet.
+    pub fn queue_report(&mut self) {
+        let lag = self.shared.stats.snapshot();
+        let dropped_since_last_report = lag.dropped - self.reported_dropped;
+        self.reported_dropped = lag.dropped;
+
+        metrics::record_stream_buffer_depth(lag.stream, lag.buffered);
+        if dropped_since_last_report > 0 {
+            tracing::warn!(
+                consumer_id = %lag.consumer_id,
+                stream = lag.stream.as_str(),
+                dropped = dropped_since_last_report,
+                buffered = lag.buffered,
+                "Consumer lagging"
+            );
+        }
+
+        let report = LagReport {
+            timestamp: Some(datetime_to_timestamp(Utc::now())),
+            delivered: i64::try_from(lag.delivered).unwrap_or(i64::MAX),
+            dropped: i64::try_from(lag.dropped).unwrap_or(i64::MAX),
+            dropped_since_last_report: i64::try_from(dropped_since_last_report).unwrap_or(i64::MAX),
+            buffered: i32::try_from(lag.buffered).unwrap_or(i32::MAX),
+            capacity: i32::try_from(lag.capacity).unwrap_or(i32::MAX),
+        };
+        self.shared.queue.lock().report = Some(report);
+        self.shared.ready.notify_one();
+    }
+
+    /// End the stream with `status`, discarding buffered messages.
+    pub fn fail(self, status: Status) {
+        {
+            let mut queue = self.shared.queue.lock();
+            queue.messages.clear();
+            queue.report = None;
+            queue.error = Some(status);
+        }
+        self.shared.stats.buffered.store(0, Ordering::Relaxed);
+    }
+
+    /// Wait until the client has gone away.
+    pub async fn closed(&self) {
+        loop {
+            if self.shared.closed.load(Ordering::Acquire) {
+                return;
+            }
+            self.shared.gone.notified().await;
+        }
+    }
+}
+
+impl<T> Drop for BufferSender<T> {
+    fn drop(&mut self) {
+        self.shared.queue.lock().finished = true;
+        self.shared.ready.notify_one();
+    }
+}
+
+/// Consumer half, turned into the gRPC response stream.
+#[derive(Debug)]
+pub struct BufferReceiver<T> {
+    shared: Arc<Shared<T>>,
+}
+
+impl<T: LagReported> BufferReceiver<T> {
+    /// Next item for the client: a pending lag report, then buffered
+    /// messages, then a terminal error. `None` once the sender is gone and
+    /// everything has been read.
+    pub async fn recv(&mut self) -> Option<Result<T, Status>> {
+        loop {
+            {
+                let mut queue = self.shared.queue.lock();
+                if let Some(status) = queue.error.take() {
+                    queue.finished = true;
+                    return Some(Err(status));
+                }
+                if let Some(report) = queue.report.take() {
+                    return Some(Ok(T::from_lag_report(report)));
+                }
+                if let Some(message) = queue.messages.pop_front() {
+                    let stats = &self.shared.stats;
+                    stats
+                        .buffered
+                        .store(queue.messages.len(), Ordering::Relaxed);
+                    stats.delivered.fetch_add(1, Ordering::Relaxed);
+                    return Some(Ok(message));
+                }
+                if queue.finished {
+                    return None;
+                }
+            }
+            self.shared.ready.notified().await;
+        }
+    }
+}
+
+impl<T: LagReported + Send + 'static> BufferReceiver<T> {
+    /// Response stream for tonic.
+    #[must_use]
+    pub fn into_stream(self) -> Pin<Box<dyn Stream<Item = Result<T, Status>> + Send>> {
+        Box::pin(futures::stream::unfold(self, |mut receiver| async move {
+            receiver.recv().await.map(|item| (item, receiver))
+        }))
+    }
+}
+
+impl<T> Drop for BufferReceiver<T> {
+    fn drop(&mut self) {
+        self.shared.closed.store(true, Ordering::Release);
+        self.shared.gone.notify_one();
+    }
+}
+
+/// Resolves on each tick of `interval`, or never when it is `None`.
+pub async fn next_tick(interval: &mut Option<tokio::time::Interval>) {
+    match interval {
+        Some(interval) => {
+            interval.tick().await;
+        }
+        None => std::future::pending().await,
+    }
+}
+
+/// Interval for lag reports whose first tick is one `period` from now.
+#[must_use]
+pub fn lag_report_interval(period: std::time::Duration) -> tokio::time::Interval {
+    let mut interval = tokio::time::interval_at(tokio::time::Instant::now() + period, period);
+    interval.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Delay);
+    interval
+}
+
+// =============================================================================
+// Tests
+// =============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Debug, PartialEq)]
+    enum Item {
+        Message(u32),
+        Report(i64),
+    }
+
+    impl LagReported for Item {
+        fn from_lag_report(report: LagReport) -> Self {
+            Self::Report(report.dropped)
+        }
+    }
+
+    fn buffer(capacity: usize, policy: DropPolicy) -> (BufferSender<Item>, BufferReceiver<Item>) {
+        stream_buffer(
+            &Arc::new(ConsumerRegistry::new()),
+            1,
+            MessageType::StockQuote,
+            capacity,
+            policy,
+        )
+    }
+
+    #[tokio::test]
+    async fn full_buffer_drops_oldest() {
+        let (mut tx, mut rx) = buffer(2, DropPolicy::DropOldest);
+
+        for i in 1..=4 {
+            tx.push(Item::Message(i)).unwrap();
+        }
+        tx.queue_report();
+
+        // Report first, then the two newest messages
+        assert_eq!(rx.recv().await.unwrap().unwrap(), Item::Report(2));
+        assert_eq!(rx.recv().await.unwrap().unwrap(), Item::Message(3));
+        assert_eq!(rx.recv().await.unwrap().unwrap(), Item::Message(4));
+
+        let lag = tx.shared.stats.snapshot();
+        assert_eq!((lag.delivered, lag.dropped, lag.buffered), (2, 2, 0));
+
+        drop(tx);
+        assert!(rx.recv().await.is_none());
+    }
+
+    #[tokio::test]
+    async fn disconnect_policy_ends_stream_past_threshold() {
+        let (tx, mut rx) = buffer(1, DropPolicy::Disconnect { max_dropped: 1 });
+
+        tx.push(Item::Message(1)).unwrap();
+        tx.push(Item::Message(2)).unwrap();
+        let status = tx.push(Item::Message(3)).unwrap_err();
+        assert_eq!(status.code(), tonic::Code::ResourceExhausted);
+
+        tx.fail(status);
+        let error = rx.recv().await.unwrap().unwrap_err();
+        assert_eq!(error.code(), tonic::Code::ResourceExhausted);
+        assert!(rx.recv().await.is_none());
+    }
+
+    #[tokio::test]
+    async fn dropping_receiver_closes_and_unregisters() {
+        let registry = Arc::new(ConsumerRegistry::new());
+        let (tx, rx) = stream_buffer::<Item>(
+            &registry,
+            7,
+            MessageType::StockTrade,
+            8,
+            DropPolicy::DropOldest,
+        );
+        assert_eq!(registry.snapshot().len(), 1);
+
+        drop(rx);
+        tokio::time::timeout(std::time::Duration::from_secs(1), tx.closed())
+            .await
+            .unwrap();
+
+        drop(tx);
+        assert!(registry.snapshot().is_empty());
+    }
+}
@@ -46,6 +46,8 @@ pub struct HealthResponse {
     pub clients: ClientStatus,
     /// Subscription statistics.
     pub subscriptions: SubscriptionStatus,
+    /// Delivery lag of each open client stream.
+    pub consumers: Vec<ConsumerInfo>,
 }
 
 /// Overall health status.
@@ -82,6 +84,24 @@ pub struct FeedInfo {
     pub messages_received: u64,
     /// Current reconnect attempts (0 if connected).
     pub reconnect_attempts: i32,
+    /// Per-connection status when the feed is sharded.
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub shards: Vec<ShardInfo>,
+}
+
+/// Status of one connection of a sharded feed.
+#[derive(Debug, Clone, Serialize)]
+pub struct ShardInfo {
+    /// Connection state.
+    pub state: String,
+    /// Whether this connection is up.
+    pub connected: bool,
+    /// Active subscriptions on this connection.
+    pub subscriptions: i32,
+    /// Messages received on this connection.
+    pub messages_received: u64,
+    /// Current reconnect attempts (0 if connected).
+    pub reconnect_attempts: i32,
 }
 
 /// Active client information.
@@ -98,6 +118,25 @@ pub struct SubscriptionStatus {
     pub broadcast_receivers: usize,
 }
 
+/// Delivery lag of one client stream.
+#[derive(Debug, Clone, Serialize)]
+pub struct ConsumerInfo {
+    /// Stream identifier.
+    pub consumer_id: u64,
+    /// Message type carried by the stream.
+    pub stream: &'static str,
+    /// When the stream was opened.
+    pub opened_at: DateTime<Utc>,
+    /// Messages handed to the gRPC transport.
+    pub delivered: u64,
+    /// Messages dropped because the client fell behind.
+    pub dropped: u64,
+    /// Messages currently buffered.
+    pub buffered: usize,
+    /// Buffer capacity.
+    pub capacity: usize,
+}
+
 // =============================================================================
 // Health Server State
 // =============================================================================
@@ -262,6 +301,20 @@ fn build_health_response(state: &HealthServerState) -> HealthResponse {
         subscriptions: SubscriptionStatus {
             broadcast_receivers: broadcast_stats.total_receivers(),
         },
+        consumers: state
+            .grpc_server
+            .consumer_lag()
+            .into_iter()
+            .map(|lag| ConsumerInfo {
+                consumer_id: lag.consumer_id,
+                stream: lag.stream.as_str(),
+                opened_at: lag.opened_at,
+                delivered: lag.delivered,
+                dropped: lag.dropped,
+                buffered: lag.buffered,
+                capacity: lag.capacity,
+            })
+            .collect(),
     }
 }
 
@@ -274,6 +327,17 @@ fn feed_state_to_info(state: &FeedState) -> FeedInfo {
         connected,
         messages_received: state.get_messages_received(),
         reconnect_attempts: state.get_reconnect_attempts(),
+        shards: state
+            .shards()
+            .into_iter()
+            .map(|shard| ShardInfo {
+                state: connection_state_to_string(shard.state),
+                connected: shard.state == ConnectionState::Connected,
+                subscriptions: shard.subscription_count,
+                messages_received: shard.messages_received,
+                reconnect_attempts: shard.reconnect_attempts,
+            })
+            .collect(),
     }
 }
 
@@ -349,6 +413,7 @@ mod tests {
             connected: true,
             messages_received: 100,
             reconnect_attempts: 0,
+            shards: vec![],
         };
 
         let status = determine_health_status(&connected, &connected, &connected);
@@ -362,12 +427,14 @@ mod tests {
             connected: true,
             messages_received: 100,
             reconnect_attempts: 0,
+            shards: vec![],
         };
         let disconnected = FeedInfo {
             state: "disconnected".to_string(),
             connected: false,
             messages_received: 0,
             reconnect_attempts: 5,
+            shards: vec![],
         };
 
         let status = determine_health_status(&connected, &disconnected, &disconnected);
@@ -381,6 +448,7 @@ mod tests {
             connected: false,
             messages_received: 0,
             reconnect_attempts: 5,
+            shards: vec![],
         };
 
         let status = determine_health_status(&disconnected, &disconnected, &disconnected);
@@ -6,7 +6,7 @@
 //!
 //! - **Messages**: Counts of messages received and sent by type
 //! - **Connections**: WebSocket and gRPC connection states
-//! - **Subscriptions**: Active subscription counts
+//! - **Subscriptions**: Active subscription counts and cap utilization
 //! - **Latency**: Message processing and delivery latencies
 //!
 //! # Integration
@@ -19,6 +19,9 @@ use std::time::Duration;
 use metrics::{counter, describe_counter, describe_gauge, describe_histogram, gauge, histogram};
 use metrics_exporter_prometheus::{PrometheusBuilder, PrometheusHandle};
 
+use crate::domain::subscription::SubscriptionType;
+use crate::infrastructure::alpaca::WireFormat;
+
 // =============================================================================
 // Global Metrics Handle
 // =============================================================================
@@ -71,6 +74,14 @@ fn register_metrics() {
         "alpaca_proxy_messages_dropped_total",
         "Total messages dropped due to slow consumers"
     );
+    describe_counter!(
+        "alpaca_proxy_slow_consumer_disconnects_total",
+        "Total gRPC streams ended for dropping too many messages"
+    );
+    describe_histogram!(
+        "alpaca_proxy_stream_buffer_depth",
+        "Messages buffered per gRPC stream, sampled at each lag report"
+    );
 
     // Connection gauges
     describe_gauge!(
@@ -87,6 +98,10 @@ fn register_metrics() {
         "alpaca_proxy_subscriptions_total",
         "Total number of active subscriptions"
     );
+    describe_gauge!(
+        "alpaca_proxy_subscription_utilization",
+        "Fraction of the per-channel symbol subscription cap in use"
+    );
 
     // Error counters
     describe_counter!(
@@ -101,7 +116,7 @@ fn register_metrics() {
     // Latency histograms
     describe_histogram!(
         "alpaca_proxy_message_processing_seconds",
-        "Time to process messages from WebSocket to broadcast"
+        "Time to decode a WebSocket frame, by feed and wire format"
     );
 }
 
@@ -131,7 +146,7 @@ impl FeedType {
 }
 
 /// Metric labels for message types.
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum MessageType {
     /// Stock quote.
     StockQuote,
@@ -139,23 +154,31 @@ pub enum MessageType {
     StockTrade,
     /// Stock bar.
     StockBar,
+    /// Trading status.
+    TradingStatus,
     /// Option quote.
     OptionQuote,
     /// Option trade.
     OptionTrade,
     /// Order update.
     OrderUpdate,
+    /// Microstructure signal.
+    Signal,
 }
 
 impl MessageType {
-    const fn as_str(self) -> &'static str {
+    /// Metric label value.
+    #[must_use]
+    pub const fn as_str(self) -> &'static str {
         match self {
             Self::StockQuote => "stock_quote",
             Self::StockTrade => "stock_trade",
             Self::StockBar => "stock_bar",
+            Self::TradingStatus => "trading_status",
             Self::OptionQuote => "option_quote",
             Self::OptionTrade => "option_trade",
             Self::OrderUpdate => "order_update",
+            Self::Signal => "signal",
         }
     }
 }
@@ -188,6 +211,25 @@ pub fn record_messages_dropped(msg_type: MessageType, count: u64) {
     .increment(count);
 }
 
+/// Record a gRPC stream ended for dropping too many messages.
+pub fn record_slow_consumer_disconnect(msg_type: MessageType) {
+    counter!(
+        "alpaca_proxy_slow_consumer_disconnects_total",
+        "message_type" => msg_type.as_str()
+    )
+    .increment(1);
+}
+
+/// Record the number of messages buffered for a gRPC stream.
+#[allow(clippy::cast_precision_loss)]
+pub fn record_stream_buffer_depth(msg_type: MessageType, depth: usize) {
+    histogram!(
+        "alpaca_proxy_stream_buffer_depth",
+        "message_type" => msg_type.as_str()
+    )
+    .record(depth as f64);
+}
+
 /// Update the WebSocket connection count for a feed.
 pub fn set_websocket_connections(feed: FeedType, count: f64) {
     gauge!(
@@ -211,6 +253,16 @@ pub fn set_subscriptions(feed: FeedType, count: f64) {
     .set(count);
 }
 
+/// Update the fraction of a feed channel's symbol cap in use.
+pub fn set_subscription_utilization(feed: FeedType, sub_type: SubscriptionType, ratio: f64) {
+    gauge!(
+        "alpaca_proxy_subscription_utilization",
+        "feed" => feed.as_str(),
+        "type" => sub_type.as_str()
+    )
+    .set(ratio);
+}
+
 /// Record a WebSocket error.
 pub fn record_websocket_error(feed: FeedType, error_type: &str) {
     counter!(
@@ -230,11 +282,12 @@ pub fn record_reconnect(feed: FeedType) {
     .increment(1);
 }
 
-/// Record message processing duration.
-pub fn record_processing_duration(feed: FeedType, duration: Duration) {
+/// Record the time to decode one WebSocket frame.
+pub fn record_processing_duration(feed: FeedType, format: WireFormat, duration: Duration) {
     histogram!(
         "alpaca_proxy_message_processing_seconds",
-        "feed" => feed.as_str()
+        "feed" => feed.as_str(),
+        "format" => format.as_str()
     )
     .record(duration.as_secs_f64());
 }
@@ -259,6 +312,7 @@ mod tests {
         assert_eq!(MessageType::StockQuote.as_str(), "stock_quote");
         assert_eq!(MessageType::StockTrade.as_str(), "stock_trade");
         assert_eq!(MessageType::StockBar.as_str(), "stock_bar");
+        assert_eq!(MessageType::TradingStatus.as_str(), "trading_status");
         assert_eq!(MessageType::OptionQuote.as_str(), "option_quote");
         assert_eq!(MessageType::OptionTrade.as_str(), "option_trade");
         assert_eq!(MessageType::OrderUpdate.as_str(), "order_update");
@@ -26,6 +26,7 @@
 //!
 //! - **Domain**: Core streaming logic and data types
 //!   - `streaming`: Market data types (quotes, trades, bars)
+//!   - `microstructure`: Trade imbalance, tick direction, quote stability
 //!   - `subscription`: Subscription tracking and management
 //!
 //! - **Application**: Use cases and port definitions
@@ -72,19 +73,22 @@ pub mod infrastructure;
 // =============================================================================
 
 // Domain types
+pub use application::services::microstructure::MicrostructureService;
 pub use application::services::scanner::{
     ScannerService as ScannerAppService, ScannerStatusSnapshot,
 };
+pub use domain::microstructure::{MicrostructureParams, MicrostructureSignal, TickDirection};
 pub use domain::scanner::{ScannerAlertDomain, ScannerBar, ScannerParams, SignalType, SymbolState};
 pub use domain::subscription::{
-    ConsumerId, SubscriptionChanges, SubscriptionManager, SubscriptionStats, SubscriptionType,
-    Symbol, TotalSubscriptionStats,
+    ConsumerId, OverflowPolicy, SubscriptionChanges, SubscriptionLimit, SubscriptionLimitExceeded,
+    SubscriptionManager, SubscriptionStats, SubscriptionType, Symbol, TotalSubscriptionStats,
 };
 
 // Infrastructure config
 pub use infrastructure::config::{
-    BroadcastSettings, ConfigError, Credentials, DataFeed, Environment, ProxyConfig,
-    ServerSettings, WebSocketSettings,
+    BroadcastSettings, ConfigError, Credentials, DataFeed, DropPolicy, Environment,
+    OpraShardSettings, ProxyConfig, ServerSettings, StreamBufferSettings,
+    SubscriptionLimitSettings, TlsSettings, WebSocketSettings,
 };
 
 // Health server
@@ -100,9 +104,13 @@ pub use infrastructure::grpc::{
     proto::cream::v1 as proto,
     scanner_server::ScannerGrpcServer,
     server::{FeedState, StreamProxyServer, StreamProxyServerConfig},
+    stream_buffer::ConsumerLag,
 };
 pub use infrastructure::scanner::{ScannerConfigRepository, ScannerConfigRepositoryError};
 
+// Alpaca wire format and OPRA sharding
+pub use infrastructure::alpaca::{ShardStrategy, WireFormat};
+
 // Alpaca message types (for integration tests)
 pub use infrastructure::alpaca::messages::{
     OptionQuoteMessage, OptionTradeMessage, StockBarMessage, StockQuoteMessage, StockTradeMessage,
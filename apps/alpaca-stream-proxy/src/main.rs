@@ -20,6 +20,12 @@
 //! - `STREAM_PROXY_GRPC_PORT`: gRPC server port (default: 50052)
 //! - `STREAM_PROXY_HEALTH_PORT`: Health check HTTP port (default: 8082)
 //! - `STREAM_PROXY_METRICS_PORT`: Prometheus metrics port (default: 9090)
+//! - `STREAM_PROXY_TLS_CERT_PATH`: PEM certificate chain; enables TLS on the gRPC server
+//! - `STREAM_PROXY_TLS_KEY_PATH`: PEM private key (required with the certificate)
+//! - `STREAM_PROXY_TLS_CLIENT_CA_PATH`: PEM CA bundle; requires client certificates (mTLS)
+//! - `STREAM_PROXY_TLS_RELOAD_SECS`: Certificate change poll interval (default: 60)
+//! - `STREAM_PROXY_SIGNAL_WINDOW_SECS`: Microstructure signal window (default: 30)
+//! - `STREAM_PROXY_SIGNAL_INTERVAL_MS`: Minimum time between signals per symbol (default: 1000)
 //! - `OTEL_ENABLED`: Enable OpenTelemetry (default: true)
 //! - `OTEL_EXPORTER_OTLP_ENDPOINT`: OTLP endpoint (default: <http://localhost:4318>)
 //! - `OTEL_SERVICE_NAME`: Service name (default: cream-alpaca-stream-proxy)
@@ -30,24 +36,28 @@ use std::sync::Arc;
 use std::time::Duration;
 
 use alpaca_stream_proxy::application::ports::scanner::ScannerConfigPort;
+use alpaca_stream_proxy::application::services::microstructure::MicrostructureService;
 use alpaca_stream_proxy::application::services::scanner::ScannerService as ScannerAppService;
 use alpaca_stream_proxy::domain::scanner::ScannerParams;
 use alpaca_stream_proxy::infrastructure::alpaca::{
-    OpraClient, OpraClientConfig, OpraEvent, SipClient, SipClientConfig, SipEvent, TradingClient,
-    TradingClientConfig, TradingEvent,
+    HeartbeatConfig, OpraClientConfig, OpraEvent, OpraShardPool, SipClient, SipClientConfig,
+    SipEvent, TradingClient, TradingClientConfig, TradingEvent,
 };
 use alpaca_stream_proxy::infrastructure::broadcast::{BroadcastConfig, BroadcastHub};
 use alpaca_stream_proxy::infrastructure::grpc::proto::cream::v1::ConnectionState;
 use alpaca_stream_proxy::infrastructure::grpc::proto::cream::v1::scanner_service_server::ScannerServiceServer;
 use alpaca_stream_proxy::infrastructure::grpc::proto::cream::v1::stream_proxy_service_server::StreamProxyServiceServer;
 use alpaca_stream_proxy::infrastructure::grpc::{
-    ScannerGrpcServer,
+    GrpcHealthUpdater, ReloadingTlsAcceptor, ScannerGrpcServer,
     server::{StreamProxyServer, StreamProxyServerConfig},
 };
 use alpaca_stream_proxy::infrastructure::health::{HealthServer, HealthServerState};
 use alpaca_stream_proxy::infrastructure::scanner::ScannerConfigRepository;
 use alpaca_stream_proxy::infrastructure::telemetry;
-use alpaca_stream_proxy::{Environment, ProxyConfig, SubscriptionManager, init_metrics};
+use alpaca_stream_proxy::{
+    Environment, ProxyConfig, SubscriptionLimit, SubscriptionManager, init_metrics,
+};
+use tokio::net::TcpListener;
 use tokio::signal;
 use tokio::sync::mpsc;
 use tokio_util::sync::CancellationToken;
@@ -85,8 +95,23 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
     let broadcast_config = BroadcastConfig::from(config.broadcast.clone());
     let broadcast_hub = Arc::new(BroadcastHub::new(broadcast_config));
 
-    // Initialize subscription manager
-    let subscription_manager = Arc::new(SubscriptionManager::new());
+    // Initialize subscription managers, one per capped feed. The OPRA cap is
+    // per connection, so sharding multiplies it.
+    let limits = &config.subscription_limits;
+    let sip_subscriptions = Arc::new(
+        limits
+            .sip()
+            .map_or_else(SubscriptionManager::new, SubscriptionManager::with_limit),
+    );
+    let opra_subscriptions = Arc::new(limits.opra().map_or_else(
+        SubscriptionManager::new,
+        |limit| {
+            SubscriptionManager::with_limit(SubscriptionLimit {
+                max_symbols: limit.max_symbols.saturating_mul(config.opra_shards.shards),
+                ..limit
+            })
+        },
+    ));
 
     // Initialize scanner config port and service
     let scanner_config_port: Option<Arc<dyn ScannerConfigPort>> =
@@ -120,6 +145,13 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
         Arc::clone(&broadcast_hub),
     ));
 
+    // Initialize microstructure signals, computed from the SIP trade and
+    // quote channels
+    let microstructure_service = Arc::new(MicrostructureService::new(
+        Arc::clone(&broadcast_hub),
+        config.signals,
+    ));
+
     // Initialize gRPC server
     let grpc_environment = match config.environment {
         Environment::Paper => {
@@ -132,12 +164,16 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
     let grpc_server_config = StreamProxyServerConfig {
         version: env!("CARGO_PKG_VERSION").to_string(),
         environment: grpc_environment,
+        stream_buffer: config.stream_buffer.clone(),
     };
-    let grpc_server = Arc::new(StreamProxyServer::new(
-        grpc_server_config,
-        Arc::clone(&broadcast_hub),
-        Arc::clone(&subscription_manager),
-    ));
+    let grpc_server = Arc::new(
+        StreamProxyServer::new(
+            grpc_server_config,
+            Arc::clone(&broadcast_hub),
+            sip_subscriptions,
+        )
+        .with_opra_subscriptions(opra_subscriptions),
+    );
 
     // Initialize health server
     let health_state = Arc::new(HealthServerState::new(
@@ -157,34 +193,43 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
         config.credentials.api_secret(),
     )?;
 
-    // Create WebSocket client configurations
+    // Create WebSocket client configurations. Order updates are sparse, so
+    // only the market data feeds are torn down for going quiet.
+    let heartbeat = HeartbeatConfig::from_websocket_settings(&config.websocket);
+
     let sip_config = match config.environment {
         Environment::Paper => SipClientConfig::paper(credentials.clone(), config.feed.as_str()),
         Environment::Live => SipClientConfig::live(credentials.clone(), config.feed.as_str()),
-    };
+    }
+    .with_wire_format(config.wire_format)
+    .with_heartbeat(heartbeat.clone());
 
     let opra_config = match config.environment {
         Environment::Paper => OpraClientConfig::paper(credentials.clone()),
         Environment::Live => OpraClientConfig::live(credentials.clone()),
-    };
+    }
+    .with_wire_format(config.wire_format)
+    .with_heartbeat(heartbeat.clone());
 
     let trading_config = match config.environment {
         Environment::Paper => TradingClientConfig::paper(credentials.clone()),
         Environment::Live => TradingClientConfig::live(credentials.clone()),
-    };
+    }
+    .with_heartbeat(heartbeat.with_data_timeout(None));
 
     // Create event channels for WebSocket clients
     let (sip_tx, sip_rx) = mpsc::channel::<SipEvent>(1024);
-    let (opra_tx, opra_rx) = mpsc::channel::<OpraEvent>(4096);
     let (trading_tx, trading_rx) = mpsc::channel::<TradingEvent>(256);
 
     // Create WebSocket clients
     let sip_client = Arc::new(SipClient::new(sip_config, sip_tx, shutdown_token.clone()));
-    let opra_client = Arc::new(OpraClient::new(
-        opra_config,
-        opra_tx,
-        shutdown_token.clone(),
-    ));
+    let (opra_pool, opra_rxs) = OpraShardPool::new(
+        &opra_config,
+        config.opra_shards.shards,
+        config.opra_shards.strategy,
+        4096,
+        &shutdown_token,
+    );
     let trading_client = Arc::new(TradingClient::new(
         trading_config,
         trading_tx,
@@ -210,12 +255,15 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
         .await;
     });
 
-    // Spawn OPRA event handler
-    let opra_broadcast_hub = Arc::clone(&broadcast_hub);
-    let opra_feed_state = Arc::clone(&opra_state);
-    tokio::spawn(async move {
-        handle_opra_events(opra_rx, opra_broadcast_hub, opra_feed_state).await;
-    });
+    // Spawn one OPRA event handler per shard, all feeding the same hub
+    opra_state.set_shard_count(opra_pool.len());
+    for (shard, opra_rx) in opra_rxs.into_iter().enumerate() {
+        let opra_broadcast_hub = Arc::clone(&broadcast_hub);
+        let opra_feed_state = Arc::clone(&opra_state);
+        tokio::spawn(async move {
+            handle_opra_events(shard, opra_rx, opra_broadcast_hub, opra_feed_state).await;
+        });
+    }
 
     // Spawn Trading event handler
     let trading_broadcast_hub = Arc::clone(&broadcast_hub);
@@ -232,12 +280,7 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
         }
     });
 
-    let opra_client_clone = Arc::clone(&opra_client);
-    tokio::spawn(async move {
-        if let Err(e) = opra_client_clone.run().await {
-            tracing::error!(error = %e, "OPRA client error");
-        }
-    });
+    opra_pool.spawn();
 
     let trading_client_clone = Arc::clone(&trading_client);
     tokio::spawn(async move {
@@ -260,20 +303,58 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
         scanner_runner.run(scanner_shutdown).await;
     });
 
+    // Spawn microstructure signal loop
+    let microstructure_runner = Arc::clone(&microstructure_service);
+    let microstructure_shutdown = shutdown_token.clone();
+    tokio::spawn(async move {
+        microstructure_runner.run(microstructure_shutdown).await;
+    });
+
+    // Serve grpc.health.v1, driven by feed connectivity
+    let (health_reporter, grpc_health_service) = tonic_health::server::health_reporter();
+    let grpc_health_updater = GrpcHealthUpdater::new(health_reporter, Arc::clone(&grpc_server));
+    let grpc_health_shutdown = shutdown_token.clone();
+    tokio::spawn(async move {
+        grpc_health_updater.run(grpc_health_shutdown).await;
+    });
+
     // Spawn gRPC server
     let grpc_addr: SocketAddr = format!("0.0.0.0:{}", config.server.grpc_port).parse()?;
     let grpc_service = StreamProxyServiceServer::from_arc(grpc_server);
     let scanner_service = ScannerServiceServer::from_arc(scanner_grpc_server);
     let grpc_shutdown = shutdown_token.clone();
 
+    let grpc_tls = match config.server.tls.clone() {
+        Some(tls_settings) => {
+            let mutual = tls_settings.is_mutual();
+            let acceptor = Arc::new(ReloadingTlsAcceptor::new(tls_settings)?);
+            acceptor.start(shutdown_token.clone());
+            tracing::info!(mutual, "gRPC TLS enabled");
+            Some((acceptor, TcpListener::bind(grpc_addr).await?))
+        }
+        None => None,
+    };
+
     tokio::spawn(async move {
         tracing::info!(addr = %grpc_addr, "gRPC server listening");
-        if let Err(e) = Server::builder()
+        let router = Server::builder()
+            .add_service(grpc_health_service)
             .add_service(grpc_service)
-            .add_service(scanner_service)
-            .serve_with_shutdown(grpc_addr, grpc_shutdown.cancelled())
-            .await
-        {
+            .add_service(scanner_service);
+        let result = match grpc_tls {
+            Some((tls, listener)) => {
+                let incoming = tls.incoming(listener, grpc_shutdown.clone());
+                router
+                    .serve_with_incoming_shutdown(incoming, grpc_shutdown.cancelled())
+                    .await
+            }
+            None => {
+                router
+                    .serve_with_shutdown(grpc_addr, grpc_shutdown.cancelled())
+                    .await
+            }
+        };
+        if let Err(e) = result {
             tracing::error!(error = %e, "gRPC server error");
         }
         tracing::info!("gRPC server stopped");
@@ -325,6 +406,16 @@ async fn handle_sip_events(
                 feed_state.increment_messages();
                 scanner_service.handle_daily_bar(bar).await;
             }
+            SipEvent::Status(status) => {
+                feed_state.increment_messages();
+                tracing::info!(
+                    symbol = %status.symbol,
+                    status_code = ?status.status_code,
+                    reason_code = ?status.reason_code,
+                    "Trading status changed"
+                );
+                let _ = broadcast_hub.send_trading_status(status);
+            }
             SipEvent::Subscribed {
                 quotes,
                 trades,
@@ -350,8 +441,9 @@ async fn handle_sip_events(
     }
 }
 
-/// Handle events from the OPRA WebSocket client.
+/// Handle events from one OPRA shard's WebSocket client.
 async fn handle_opra_events(
+    shard: usize,
     mut rx: mpsc::Receiver<OpraEvent>,
     broadcast_hub: Arc<BroadcastHub>,
     feed_state: Arc<alpaca_stream_proxy::infrastructure::grpc::server::FeedState>,
@@ -359,39 +451,40 @@ async fn handle_opra_events(
     while let Some(event) = rx.recv().await {
         match event {
             OpraEvent::Connected => {
-                feed_state.set_state(ConnectionState::Connected);
-                tracing::info!("OPRA feed connected");
+                feed_state.set_shard_state(shard, ConnectionState::Connected);
+                tracing::info!(shard, "OPRA feed connected");
             }
             OpraEvent::Disconnected => {
-                feed_state.set_state(ConnectionState::Disconnected);
-                tracing::warn!("OPRA feed disconnected");
+                feed_state.set_shard_state(shard, ConnectionState::Disconnected);
+                tracing::warn!(shard, "OPRA feed disconnected");
             }
             OpraEvent::Reconnecting { attempt } => {
-                feed_state.set_state(ConnectionState::Reconnecting);
-                feed_state.increment_reconnect_attempts();
-                tracing::info!(attempt, "OPRA feed reconnecting");
+                feed_state.set_shard_state(shard, ConnectionState::Reconnecting);
+                feed_state.increment_shard_reconnect_attempts(shard);
+                tracing::info!(shard, attempt, "OPRA feed reconnecting");
             }
             OpraEvent::Quote(quote) => {
-                feed_state.increment_messages();
+                feed_state.increment_shard_messages(shard);
                 let _ = broadcast_hub.send_options_quote(quote);
             }
             OpraEvent::Trade(trade) => {
-                feed_state.increment_messages();
+                feed_state.increment_shard_messages(shard);
                 let _ = broadcast_hub.send_options_trade(trade);
             }
             OpraEvent::Subscribed { quotes, trades } => {
                 let count = quotes.len() + trades.len();
                 let count_i32 = i32::try_from(count).unwrap_or(i32::MAX);
-                feed_state.set_subscription_count(count_i32);
+                feed_state.set_shard_subscription_count(shard, count_i32);
                 tracing::debug!(
+                    shard,
                     quotes = quotes.len(),
                     trades = trades.len(),
                     "OPRA subscriptions updated"
                 );
             }
             OpraEvent::Error(msg) => {
-                feed_state.set_error(msg.clone());
-                tracing::error!(error = %msg, "OPRA feed error");
+                feed_state.set_shard_error(shard, &msg);
+                tracing::error!(shard, error = %msg, "OPRA feed error");
             }
         }
     }
@@ -445,9 +538,16 @@ fn log_config(config: &ProxyConfig) {
     tracing::info!(
         environment = config.environment.as_str(),
         feed = config.feed.as_str(),
+        wire_format = config.wire_format.as_str(),
         grpc_port = config.server.grpc_port,
         health_port = config.server.health_port,
         metrics_port = config.server.metrics_port,
+        sip_max_symbols = ?config.subscription_limits.sip_max_symbols,
+        opra_max_symbols = ?config.subscription_limits.opra_max_symbols,
+        subscription_overflow = config.subscription_limits.overflow.as_str(),
+        opra_shards = config.opra_shards.shards,
+        opra_shard_strategy = config.opra_shards.strategy.as_str(),
+        signal_window_secs = config.signals.window.as_secs(),
         "Configuration loaded"
     );
     tracing::debug!(
@@ -14,14 +14,17 @@ use tonic::Request;
 use tonic::transport::{Channel, Server};
 
 use alpaca_stream_proxy::{
-    BroadcastConfig, BroadcastHub, StockBarMessage, StockQuoteMessage, StockTradeMessage,
-    StreamProxyServer, StreamProxyServerConfig, SubscriptionManager,
+    BroadcastConfig, BroadcastHub, MicrostructureParams, MicrostructureService, StockBarMessage,
+    StockQuoteMessage, StockTradeMessage, StreamProxyServer, StreamProxyServerConfig,
+    SubscriptionManager,
     proto::{
         Environment, GetConnectionStatusRequest, StreamBarsRequest, StreamQuotesRequest,
-        StreamTradesRequest, stream_proxy_service_client::StreamProxyServiceClient,
+        StreamSignalsRequest, StreamTradesRequest, TickDirection,
+        stream_proxy_service_client::StreamProxyServiceClient,
         stream_proxy_service_server::StreamProxyServiceServer,
     },
 };
+use tokio_util::sync::CancellationToken;
 
 /// Start a test gRPC server on a random port and return the client.
 async fn setup_test_server() -> (
@@ -35,6 +38,7 @@ async fn setup_test_server() -> (
     let config = StreamProxyServerConfig {
         version: "test-0.0.1".to_string(),
         environment: Environment::Paper,
+        ..StreamProxyServerConfig::default()
     };
 
     let server = StreamProxyServer::new(config, Arc::clone(&broadcast_hub), subscription_manager);
@@ -274,6 +278,62 @@ async fn test_stream_bars_receives_broadcast_messages() {
     handle.abort();
 }
 
+// =============================================================================
+// Signal Streaming Tests
+// =============================================================================
+
+#[tokio::test]
+async fn test_stream_signals_from_trades_and_quotes() {
+    let (mut client, hub, handle) = setup_test_server().await;
+    let shutdown = CancellationToken::new();
+    let service = Arc::new(MicrostructureService::new(
+        Arc::clone(&hub),
+        MicrostructureParams {
+            publish_interval: Duration::ZERO,
+            ..MicrostructureParams::default()
+        },
+    ));
+    tokio::spawn(Arc::clone(&service).run(shutdown.clone()));
+
+    let mut stream = client
+        .stream_signals(Request::new(StreamSignalsRequest {
+            symbols: vec!["AAPL".to_string()],
+        }))
+        .await
+        .unwrap()
+        .into_inner();
+
+    tokio::time::sleep(Duration::from_millis(20)).await;
+
+    let _ = hub.send_stock_quote(make_test_quote("AAPL", 150.0, 150.10));
+    tokio::time::sleep(Duration::from_millis(20)).await;
+    let _ = hub.send_stock_trade(make_test_trade("MSFT", 400.0, 100));
+    let _ = hub.send_stock_trade(make_test_trade("AAPL", 150.08, 300));
+    let _ = hub.send_stock_trade(make_test_trade("AAPL", 150.09, 100));
+
+    let mut signals = Vec::new();
+    while signals.len() < 2 {
+        let received = timeout(Duration::from_secs(2), stream.message())
+            .await
+            .expect("timeout")
+            .expect("error")
+            .expect("no message");
+        signals.extend(received.signal);
+    }
+
+    assert!(signals.iter().all(|signal| signal.symbol == "AAPL"));
+    let last = signals.last().unwrap();
+    assert_eq!(last.tick_direction, TickDirection::Up as i32);
+    assert_eq!(last.buy_volume, 400);
+    assert_eq!(last.trade_count, 2);
+    assert!((last.trade_imbalance - 1.0).abs() < f64::EPSILON);
+    assert!(last.spread_bps.is_some());
+    assert_eq!(service.symbols_tracked(), 2);
+
+    shutdown.cancel();
+    handle.abort();
+}
+
 // =============================================================================
 // Multiple Consumer Tests
 // =============================================================================
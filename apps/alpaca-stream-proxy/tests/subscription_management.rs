@@ -15,9 +15,10 @@ use tonic::transport::{Channel, Server};
 
 use alpaca_stream_proxy::{
     BroadcastConfig, BroadcastHub, StockQuoteMessage, StreamProxyServer, StreamProxyServerConfig,
-    SubscriptionManager,
+    SubscriptionLimit, SubscriptionManager, SubscriptionType,
     proto::{
-        Environment, StreamQuotesRequest, stream_proxy_service_client::StreamProxyServiceClient,
+        Environment, StreamQuotesRequest, StreamSignalsRequest,
+        stream_proxy_service_client::StreamProxyServiceClient,
         stream_proxy_service_server::StreamProxyServiceServer,
     },
 };
@@ -27,13 +28,25 @@ async fn setup_test_server() -> (
     Arc<BroadcastHub>,
     Arc<SubscriptionManager>,
     tokio::task::JoinHandle<()>,
+) {
+    setup_test_server_with(SubscriptionManager::new()).await
+}
+
+async fn setup_test_server_with(
+    subscription_manager: SubscriptionManager,
+) -> (
+    StreamProxyServiceClient<Channel>,
+    Arc<BroadcastHub>,
+    Arc<SubscriptionManager>,
+    tokio::task::JoinHandle<()>,
 ) {
     let broadcast_hub = Arc::new(BroadcastHub::new(BroadcastConfig::default()));
-    let subscription_manager = Arc::new(SubscriptionManager::new());
+    let subscription_manager = Arc::new(subscription_manager);
 
     let config = StreamProxyServerConfig {
         version: "test-0.0.1".to_string(),
         environment: Environment::Paper,
+        ..StreamProxyServerConfig::default()
     };
 
     let server = StreamProxyServer::new(
@@ -165,6 +178,109 @@ async fn test_client_disconnect_decreases_receiver_count() {
     handle.abort();
 }
 
+// =============================================================================
+// Subscription Tracking Tests
+// =============================================================================
+
+#[tokio::test]
+async fn test_stream_symbols_tracked_until_disconnect() {
+    let (mut client, hub, sub_mgr, handle) = setup_test_server().await;
+
+    let stream = client
+        .stream_quotes(Request::new(StreamQuotesRequest {
+            symbols: vec!["AAPL".to_string(), "MSFT".to_string()],
+        }))
+        .await
+        .unwrap()
+        .into_inner();
+
+    let stats = sub_mgr.stats(SubscriptionType::Quotes);
+    assert_eq!(stats.symbol_count, 2);
+    assert_eq!(stats.consumer_count, 1);
+
+    // Drop the stream and trigger cleanup with a send
+    drop(stream);
+    tokio::time::sleep(Duration::from_millis(100)).await;
+    let _ = hub.send_stock_quote(make_test_quote("AAPL"));
+    tokio::time::sleep(Duration::from_millis(50)).await;
+
+    assert!(sub_mgr.active_symbols(SubscriptionType::Quotes).is_empty());
+
+    handle.abort();
+}
+
+#[tokio::test]
+async fn test_stream_over_symbol_cap_rejected() {
+    let (mut client, _hub, sub_mgr, handle) =
+        setup_test_server_with(SubscriptionManager::with_limit(SubscriptionLimit::new(1))).await;
+
+    let _stream = client
+        .stream_quotes(Request::new(StreamQuotesRequest {
+            symbols: vec!["AAPL".to_string()],
+        }))
+        .await
+        .unwrap();
+
+    let status = client
+        .stream_quotes(Request::new(StreamQuotesRequest {
+            symbols: vec!["MSFT".to_string()],
+        }))
+        .await
+        .unwrap_err();
+
+    assert_eq!(status.code(), tonic::Code::ResourceExhausted);
+    assert_eq!(
+        sub_mgr.active_symbols(SubscriptionType::Quotes),
+        vec!["AAPL".to_string()]
+    );
+
+    // Unfiltered streams hold no subscriptions and are unaffected by the cap
+    let _all = client
+        .stream_quotes(Request::new(StreamQuotesRequest { symbols: vec![] }))
+        .await
+        .unwrap();
+
+    handle.abort();
+}
+
+#[tokio::test]
+async fn test_signal_stream_over_symbol_cap_releases_every_type() {
+    let (mut client, _hub, sub_mgr, handle) =
+        setup_test_server_with(SubscriptionManager::with_limit(SubscriptionLimit::new(1))).await;
+
+    let _quotes = client
+        .stream_quotes(Request::new(StreamQuotesRequest {
+            symbols: vec!["AAPL".to_string()],
+        }))
+        .await
+        .unwrap();
+
+    // Trades have room for MSFT, quotes don't
+    let status = client
+        .stream_signals(Request::new(StreamSignalsRequest {
+            symbols: vec!["MSFT".to_string()],
+        }))
+        .await
+        .unwrap_err();
+
+    assert_eq!(status.code(), tonic::Code::ResourceExhausted);
+    assert!(sub_mgr.active_symbols(SubscriptionType::Trades).is_empty());
+
+    let _signals = client
+        .stream_signals(Request::new(StreamSignalsRequest {
+            symbols: vec!["AAPL".to_string()],
+        }))
+        .await
+        .unwrap();
+
+    assert_eq!(
+        sub_mgr.active_symbols(SubscriptionType::Trades),
+        vec!["AAPL".to_string()]
+    );
+
+    handle.abort();
+}
+
 // =============================================================================
 // Broadcast Stats Tests
 // =============================================================================
@@ -3,8 +3,9 @@
 use rust_decimal::Decimal;
 use serde::{Deserialize, Serialize};
 
+use crate::application::ports::DecisionContext;
 use crate::domain::order_execution::value_objects::{
-    OrderPurpose, OrderSide, OrderStatus, OrderType, TimeInForce,
+    OrderPurpose, OrderSide, OrderStatus, OrderType, OtoExit, TimeInForce,
 };
 use crate::domain::shared::{OrderId, Symbol, Timestamp};
 
@@ -23,10 +24,19 @@ pub struct CreateOrderDto {
     pub quantity: Decimal,
     /// Limit price.
     pub limit_price: Option<Decimal>,
+    /// Stop price (for stop orders).
+    #[serde(default)]
+    pub stop_price: Option<Decimal>,
     /// Time in force.
     pub time_in_force: TimeInForce,
     /// Purpose.
     pub purpose: OrderPurpose,
+    /// Decision that produced the order.
+    #[serde(default)]
+    pub decision: Option<DecisionContext>,
+    /// Exit the broker arms once the order fills (OTO).
+    #[serde(default)]
+    pub oto_exit: Option<OtoExit>,
 }
 
 impl CreateOrderDto {
@@ -69,6 +79,11 @@ pub struct OrderDto {
     pub time_in_force: TimeInForce,
     /// Purpose.
     pub purpose: OrderPurpose,
+    /// Strategy tag of the decision that produced the order.
+    #[serde(default)]
+    pub strategy_tag: Option<String>,
+    /// Broker IDs of child orders, such as an OTO exit.
+    pub child_order_ids: Vec<String>,
     /// Created at.
     pub created_at: Timestamp,
     /// Updated at.
@@ -100,6 +115,12 @@ impl OrderDto {
             status: order.status(),
             time_in_force: order.time_in_force(),
             purpose: partial_fill.order_purpose(),
+            strategy_tag: order.strategy_tag().map(ToString::to_string),
+            child_order_ids: order
+                .child_order_ids()
+                .iter()
+                .map(ToString::to_string)
+                .collect(),
             created_at: order.created_at(),
             updated_at: order.updated_at(),
         }
@@ -122,6 +143,9 @@ pub struct SubmitOrdersRequestDto {
     pub orders: Vec<CreateOrderDto>,
     /// Validate risk before submitting.
     pub validate_risk: bool,
+    /// Decision cycle the orders belong to.
+    #[serde(default)]
+    pub cycle_id: Option<String>,
 }
 
 /// Response DTO for submitting orders.
@@ -189,8 +213,11 @@ mod tests {
             order_type: OrderType::Market,
             quantity: Decimal::new(100, 0),
             limit_price: None,
+            stop_price: None,
             time_in_force: TimeInForce::Day,
             purpose: OrderPurpose::Entry,
+            decision: None,
+            oto_exit: None,
         };
 
         let (order_id, symbol) = dto.to_domain();
@@ -0,0 +1,80 @@
+//! Account Activity Port (Driven Port)
+//!
+//! Interface for reading the broker's account ledger (fills, fees,
+//! dividends) so locally estimated figures can be verified.
+
+use async_trait::async_trait;
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+
+use crate::domain::order_execution::value_objects::OrderSide;
+use crate::domain::shared::Timestamp;
+
+use super::BrokerError;
+
+/// Category of an account activity.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum ActivityKind {
+    /// Order fill (full or partial).
+    Fill,
+    /// Commission or regulatory fee.
+    Fee,
+    /// Dividend payment.
+    Dividend,
+    /// Any other ledger entry.
+    Other,
+}
+
+/// A single entry from the broker's account activity ledger.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AccountActivity {
+    /// Broker activity ID.
+    pub id: String,
+    /// Activity category.
+    pub kind: ActivityKind,
+    /// Raw broker activity type (e.g. `FILL`, `FEE`, `DIV`).
+    pub activity_type: String,
+    /// Symbol, if the activity relates to an instrument.
+    pub symbol: Option<String>,
+    /// Broker order ID, for fills.
+    pub order_id: Option<String>,
+    /// Fill side, for fills.
+    pub side: Option<OrderSide>,
+    /// Quantity (shares or contracts).
+    pub qty: Option<Decimal>,
+    /// Fill price, for fills.
+    pub price: Option<Decimal>,
+    /// Cash impact on the account (negative for debits such as fees).
+    pub net_amount: Option<Decimal>,
+    /// Free-text description from the broker.
+    pub description: Option<String>,
+    /// When the activity occurred.
+    pub occurred_at: Timestamp,
+}
+
+impl AccountActivity {
+    /// Fill notional (`qty * price`), if this is a priced fill.
+    #[must_use]
+    pub fn fill_notional(&self) -> Option<Decimal> {
+        match (self.kind, self.qty, self.price) {
+            (ActivityKind::Fill, Some(qty), Some(price)) => Some(qty * price),
+            _ => None,
+        }
+    }
+}
+
+/// Port for reading account activities from the broker.
+#[async_trait]
+pub trait AccountActivityPort: Send + Sync {
+    /// Get fill, fee, and dividend activities that occurred after `after`
+    /// (or all available activities when `None`), oldest first.
+    ///
+    /// # Errors
+    ///
+    /// Returns error if the broker request fails.
+    async fn get_activities(
+        &self,
+        after: Option<Timestamp>,
+    ) -> Result<Vec<AccountActivity>, BrokerError>;
+}
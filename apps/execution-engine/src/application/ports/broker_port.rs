@@ -7,9 +7,11 @@ use rust_decimal::Decimal;
 use serde::{Deserialize, Serialize};
 
 use crate::domain::order_execution::value_objects::{
-    OrderSide, OrderStatus, OrderType, TimeInForce,
+    OrderSide, OrderStatus, OrderType, OtoExit, TimeInForce,
+};
+use crate::domain::shared::{
+    BrokerId, ClassifiedError, ErrorKind, InstrumentId, OrderId, Symbol, Timestamp,
 };
-use crate::domain::shared::{BrokerId, InstrumentId, OrderId, Symbol};
 
 /// Request to submit an order to the broker.
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -32,6 +34,9 @@ pub struct SubmitOrderRequest {
     pub time_in_force: TimeInForce,
     /// Extended hours trading.
     pub extended_hours: bool,
+    /// Exit the broker arms once this order fills (OTO order class).
+    #[serde(default)]
+    pub oto_exit: Option<OtoExit>,
 }
 
 impl SubmitOrderRequest {
@@ -53,6 +58,7 @@ impl SubmitOrderRequest {
             stop_price: None,
             time_in_force: TimeInForce::Day,
             extended_hours: false,
+            oto_exit: None,
         }
     }
 
@@ -75,6 +81,7 @@ impl SubmitOrderRequest {
             stop_price: None,
             time_in_force: TimeInForce::Day,
             extended_hours: false,
+            oto_exit: None,
         }
     }
 
@@ -91,6 +98,13 @@ impl SubmitOrderRequest {
         self.extended_hours = true;
         self
     }
+
+    /// Arm `exit` once this order fills (OTO order class).
+    #[must_use]
+    pub const fn with_oto_exit(mut self, exit: OtoExit) -> Self {
+        self.oto_exit = Some(exit);
+        self
+    }
 }
 
 /// Request to cancel an order.
@@ -135,6 +149,23 @@ pub struct OrderAck {
     pub filled_qty: Decimal,
     /// Average fill price (if any).
     pub avg_fill_price: Option<Decimal>,
+    /// Broker IDs of child orders created with this one (e.g. an OTO exit).
+    #[serde(default)]
+    pub child_order_ids: Vec<BrokerId>,
+    /// Per-leg fills of a multi-leg order (empty otherwise).
+    #[serde(default)]
+    pub leg_fills: Vec<LegFill>,
+}
+
+/// Cumulative fill of one leg of a multi-leg order.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct LegFill {
+    /// Leg instrument symbol.
+    pub symbol: String,
+    /// Filled quantity.
+    pub filled_qty: Decimal,
+    /// Average fill price (if any).
+    pub avg_fill_price: Option<Decimal>,
 }
 
 /// Position information from the broker.
@@ -152,6 +183,44 @@ pub struct PositionInfo {
     pub unrealized_pnl: Decimal,
     /// Current price.
     pub current_price: Decimal,
+    /// When the position was opened, if the broker reports it.
+    #[serde(default)]
+    pub opened_at: Option<Timestamp>,
+}
+
+/// Tradability metadata for an asset.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[allow(clippy::struct_excessive_bools)]
+pub struct AssetInfo {
+    /// Asset symbol.
+    pub symbol: String,
+    /// Whether the asset is active (not delisted or halted indefinitely).
+    pub active: bool,
+    /// Whether the asset can be traded.
+    pub tradable: bool,
+    /// Whether the asset can be sold short.
+    pub shortable: bool,
+    /// Whether the asset is easy to borrow (false means hard-to-borrow).
+    pub easy_to_borrow: bool,
+    /// Whether fractional quantities are supported.
+    pub fractionable: bool,
+    /// Whether the asset can be bought on margin.
+    pub marginable: bool,
+}
+
+/// Market session state reported by the broker.
+///
+/// Reflects the exchange calendar, including holidays and early closes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct MarketClock {
+    /// Broker time the state was read at.
+    pub timestamp: Timestamp,
+    /// Whether the regular session is open.
+    pub is_open: bool,
+    /// Next session open.
+    pub next_open: Timestamp,
+    /// Next session close (the current session's close while open).
+    pub next_close: Timestamp,
 }
 
 /// Broker port error.
@@ -186,6 +255,14 @@ pub enum BrokerError {
     #[error("Rate limited by broker")]
     RateLimited,
 
+    /// Order writes refused because this instance is a standby.
+    #[error("Standby instance is read-only, send orders to the leader")]
+    NotLeader,
+
+    /// The broker rejected the engine's credentials.
+    #[error("Broker authentication failed")]
+    AuthenticationFailed,
+
     /// Unknown error.
     #[error("Broker error: {message}")]
     Unknown {
@@ -194,6 +271,33 @@ pub enum BrokerError {
     },
 }
 
+impl ClassifiedError for BrokerError {
+    fn code(&self) -> &str {
+        match self {
+            Self::ConnectionError { .. } => "BROKER_UNAVAILABLE",
+            Self::OrderRejected { .. } => "BROKER_REJECTED",
+            Self::OrderNotFound { .. } => "BROKER_ORDER_NOT_FOUND",
+            Self::InsufficientFunds => "INSUFFICIENT_FUNDS",
+            Self::RateLimited => "BROKER_RATE_LIMITED",
+            Self::NotLeader => "NOT_LEADER",
+            Self::AuthenticationFailed => "BROKER_AUTH_FAILED",
+            Self::Unknown { .. } => "BROKER_ERROR",
+        }
+    }
+
+    fn kind(&self) -> ErrorKind {
+        match self {
+            Self::ConnectionError { .. }
+            | Self::Unknown { .. }
+            | Self::NotLeader
+            | Self::AuthenticationFailed => ErrorKind::Unavailable,
+            Self::OrderRejected { .. } | Self::InsufficientFunds => ErrorKind::Rejected,
+            Self::OrderNotFound { .. } => ErrorKind::NotFound,
+            Self::RateLimited => ErrorKind::RateLimited,
+        }
+    }
+}
+
 /// Port for broker interactions.
 #[async_trait]
 pub trait BrokerPort: Send + Sync {
@@ -220,6 +324,28 @@ pub trait BrokerPort: Send + Sync {
 
     /// Get all positions.
     async fn get_all_positions(&self) -> Result<Vec<PositionInfo>, BrokerError>;
+
+    /// Get tradability metadata for an asset.
+    ///
+    /// Returns `None` if the broker does not know the asset or does not
+    /// expose asset metadata.
+    async fn get_asset(&self, _symbol: &Symbol) -> Result<Option<AssetInfo>, BrokerError> {
+        Ok(None)
+    }
+
+    /// List metadata for all active assets, for bulk cache refreshes.
+    ///
+    /// Returns an empty list if the broker does not expose asset metadata.
+    async fn list_assets(&self) -> Result<Vec<AssetInfo>, BrokerError> {
+        Ok(vec![])
+    }
+
+    /// Get the market session state.
+    ///
+    /// Returns `None` if the broker does not expose a market clock.
+    async fn get_market_clock(&self) -> Result<Option<MarketClock>, BrokerError> {
+        Ok(None)
+    }
 }
 
 #[cfg(test)]
@@ -0,0 +1,93 @@
+//! Clock Port
+//!
+//! Source of the current time for time-dependent application logic (plan
+//! windows, circuit breaker cooldowns, quote staleness). Production code uses
+//! [`SystemClock`]; tests and backtests use [`SimulatedClock`] so expiry and
+//! aging can be exercised by advancing time instead of sleeping.
+
+use std::fmt::Debug;
+use std::sync::{Arc, Mutex, PoisonError};
+use std::time::Duration;
+
+use crate::domain::shared::Timestamp;
+
+/// Port for reading the current time.
+pub trait Clock: Debug + Send + Sync {
+    /// Current time.
+    fn now(&self) -> Timestamp;
+}
+
+/// Wall clock backed by the system time.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct SystemClock;
+
+impl SystemClock {
+    /// Shared system clock, the default for injected clocks.
+    #[must_use]
+    pub fn shared() -> Arc<dyn Clock> {
+        Arc::new(Self)
+    }
+}
+
+impl Clock for SystemClock {
+    fn now(&self) -> Timestamp {
+        Timestamp::now()
+    }
+}
+
+/// Manually driven clock for tests and backtests.
+///
+/// Time only moves when [`set`](Self::set) or [`advance`](Self::advance) is
+/// called.
+#[derive(Debug)]
+pub struct SimulatedClock {
+    now: Mutex<Timestamp>,
+}
+
+impl SimulatedClock {
+    /// Create a clock stopped at `start`.
+    #[must_use]
+    pub const fn new(start: Timestamp) -> Self {
+        Self {
+            now: Mutex::new(start),
+        }
+    }
+
+    /// Jump to `now`.
+    pub fn set(&self, now: Timestamp) {
+        *self.now.lock().unwrap_or_else(PoisonError::into_inner) = now;
+    }
+
+    /// Move forward by `duration`.
+    pub fn advance(&self, duration: Duration) {
+        let mut now = self.now.lock().unwrap_or_else(PoisonError::into_inner);
+        let step = chrono::Duration::from_std(duration).unwrap_or(chrono::Duration::MAX);
+        if let Some(next) = now.as_datetime().checked_add_signed(step) {
+            *now = Timestamp::new(next);
+        }
+    }
+}
+
+impl Clock for SimulatedClock {
+    fn now(&self) -> Timestamp {
+        *self.now.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn simulated_clock_moves_only_when_told() {
+        let start = Timestamp::parse("2026-01-05T14:30:00Z").unwrap();
+        let clock = SimulatedClock::new(start);
+        assert_eq!(clock.now(), start);
+
+        clock.advance(Duration::from_secs(90));
+        assert_eq!(clock.now().duration_since(start).num_seconds(), 90);
+
+        clock.set(start);
+        assert_eq!(clock.now(), start);
+    }
+}
@@ -0,0 +1,19 @@
+//! Execution Metrics Port
+//!
+//! Order flow measurements for monitoring: orders accepted by the broker,
+//! fills, and each fill's slippage against the order's arrival price, all
+//! attributed to the strategy that produced the order.
+
+use std::fmt::Debug;
+
+use crate::domain::order_execution::aggregate::Order;
+use crate::domain::order_execution::value_objects::FillReport;
+
+/// Port for recording execution metrics.
+pub trait ExecutionMetricsPort: Debug + Send + Sync {
+    /// Record an order the broker accepted.
+    fn record_order_submitted(&self, order: &Order);
+
+    /// Record a fill applied to `order`.
+    fn record_fill(&self, order: &Order, fill: &FillReport);
+}
@@ -0,0 +1,122 @@
+//! Leader Lock Port (Driven Port)
+//!
+//! Interface for the lock that elects one engine instance per account as
+//! leader. The lock is held for as long as its holder stays connected, so a
+//! crashed leader releases it without any cleanup.
+
+use std::sync::{Arc, Mutex, MutexGuard, PoisonError};
+
+use async_trait::async_trait;
+
+use crate::domain::shared::{ClassifiedError, ErrorKind};
+
+/// Leader lock error.
+#[derive(Debug, Clone, thiserror::Error)]
+pub enum LeaderLockError {
+    /// The lock service could not be reached.
+    #[error("Leader lock unavailable: {message}")]
+    Unavailable {
+        /// Error details.
+        message: String,
+    },
+}
+
+impl ClassifiedError for LeaderLockError {
+    fn code(&self) -> &str {
+        match self {
+            Self::Unavailable { .. } => "LEADER_LOCK_UNAVAILABLE",
+        }
+    }
+
+    fn kind(&self) -> ErrorKind {
+        ErrorKind::Unavailable
+    }
+}
+
+/// Port for the leader election lock.
+#[async_trait]
+pub trait LeaderLockPort: Send + Sync {
+    /// Take the lock if it is free.
+    ///
+    /// Returns `true` if this instance now holds the lock.
+    async fn try_acquire(&self) -> Result<bool, LeaderLockError>;
+
+    /// Check that this instance still holds the lock.
+    async fn is_held(&self) -> Result<bool, LeaderLockError>;
+
+    /// Release the lock, if held.
+    async fn release(&self) -> Result<(), LeaderLockError>;
+}
+
+/// In-memory leader lock for testing.
+///
+/// Handles made with [`InMemoryLeaderLock::contender`] compete for the same
+/// lock, like engine instances sharing a database.
+#[derive(Debug)]
+pub struct InMemoryLeaderLock {
+    id: usize,
+    state: Arc<Mutex<SharedLock>>,
+}
+
+#[derive(Debug)]
+struct SharedLock {
+    holder: Option<usize>,
+    handles: usize,
+}
+
+impl Default for InMemoryLeaderLock {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl InMemoryLeaderLock {
+    /// Create a free lock.
+    #[must_use]
+    pub fn new() -> Self {
+        Self {
+            id: 0,
+            state: Arc::new(Mutex::new(SharedLock {
+                holder: None,
+                handles: 1,
+            })),
+        }
+    }
+
+    /// Another handle competing for the same lock.
+    #[must_use]
+    pub fn contender(&self) -> Self {
+        let mut state = self.lock();
+        state.handles += 1;
+        Self {
+            id: state.handles - 1,
+            state: Arc::clone(&self.state),
+        }
+    }
+
+    /// Take the lock away from whoever holds it, as when the holder's
+    /// connection drops.
+    pub fn revoke(&self) {
+        self.lock().holder = None;
+    }
+
+    fn lock(&self) -> MutexGuard<'_, SharedLock> {
+        self.state.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+}
+
+#[async_trait]
+impl LeaderLockPort for InMemoryLeaderLock {
+    async fn try_acquire(&self) -> Result<bool, LeaderLockError> {
+        Ok(*self.lock().holder.get_or_insert(self.id) == self.id)
+    }
+
+    async fn is_held(&self) -> Result<bool, LeaderLockError> {
+        Ok(self.lock().holder == Some(self.id))
+    }
+
+    async fn release(&self) -> Result<(), LeaderLockError> {
+        self.lock().holder.take_if(|id| *id == self.id);
+        Ok(())
+    }
+}
@@ -7,7 +7,7 @@ use async_trait::async_trait;
 use rust_decimal::Decimal;
 use serde::{Deserialize, Serialize};
 
-use crate::domain::shared::Timestamp;
+use crate::domain::shared::{ClassifiedError, ErrorKind, Timestamp};
 
 /// Market quote for a single symbol.
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
@@ -40,6 +40,51 @@ impl MarketQuote {
     }
 }
 
+/// Most recent trade of a symbol.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct LastTrade {
+    /// Symbol.
+    pub symbol: String,
+    /// Trade price.
+    pub price: Decimal,
+    /// Trade size.
+    pub size: i64,
+    /// When the trade occurred.
+    pub timestamp: Timestamp,
+}
+
+/// Daily OHLCV bar.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DailyBar {
+    /// Session date, as the bar's open time.
+    pub timestamp: Timestamp,
+    /// Open price.
+    pub open: Decimal,
+    /// High price.
+    pub high: Decimal,
+    /// Low price.
+    pub low: Decimal,
+    /// Close (or latest) price.
+    pub close: Decimal,
+    /// Volume.
+    pub volume: i64,
+    /// Volume-weighted average price.
+    pub vwap: Option<Decimal>,
+    /// Number of trades.
+    pub trade_count: Option<i64>,
+}
+
+/// Current and prior session bars of a symbol.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DailyBars {
+    /// Symbol.
+    pub symbol: String,
+    /// Current (or most recent) session.
+    pub today: Option<DailyBar>,
+    /// Session before `today`, whose close is the prior close.
+    pub previous: Option<DailyBar>,
+}
+
 /// Option type (call or put).
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 pub enum OptionType {
@@ -105,6 +150,86 @@ pub struct OptionChainData {
     pub as_of: Timestamp,
 }
 
+/// Filters for an option chain. Unset fields match every option.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct OptionChainFilter {
+    /// Expiration dates to include (YYYY-MM-DD, empty for all).
+    pub expirations: Vec<String>,
+    /// Earliest expiration (YYYY-MM-DD, inclusive).
+    pub min_expiration: Option<String>,
+    /// Latest expiration (YYYY-MM-DD, inclusive).
+    pub max_expiration: Option<String>,
+    /// Lowest strike.
+    pub min_strike: Option<Decimal>,
+    /// Highest strike.
+    pub max_strike: Option<Decimal>,
+    /// Strike range around the underlying price as a fraction (0.10 = +/-10%).
+    pub strike_range_pct: Option<Decimal>,
+    /// Only calls or only puts.
+    pub option_type: Option<OptionType>,
+    /// Minimum open interest.
+    pub min_open_interest: Option<i32>,
+    /// Minimum daily volume.
+    pub min_volume: Option<i64>,
+}
+
+impl OptionChainFilter {
+    /// Strike bounds combining the absolute range with the range around
+    /// `underlying_price`.
+    #[must_use]
+    pub fn strike_bounds(&self, underlying_price: Decimal) -> (Option<Decimal>, Option<Decimal>) {
+        let Some(pct) = self.strike_range_pct else {
+            return (self.min_strike, self.max_strike);
+        };
+        let band = underlying_price * pct;
+        let low = underlying_price - band;
+        let high = underlying_price + band;
+        (
+            Some(self.min_strike.map_or(low, |min| min.max(low))),
+            Some(self.max_strike.map_or(high, |max| max.min(high))),
+        )
+    }
+
+    /// Check if an option passes every filter.
+    ///
+    /// Options without a quote fail a volume filter.
+    #[must_use]
+    pub fn matches(&self, option: &OptionQuote, underlying_price: Decimal) -> bool {
+        let contract = &option.contract;
+        let expiration = contract.expiration.as_str();
+        let (min_strike, max_strike) = self.strike_bounds(underlying_price);
+
+        (self.expirations.is_empty() || self.expirations.iter().any(|e| e == expiration))
+            && self
+                .min_expiration
+                .as_deref()
+                .is_none_or(|min| expiration >= min)
+            && self
+                .max_expiration
+                .as_deref()
+                .is_none_or(|max| expiration <= max)
+            && min_strike.is_none_or(|min| contract.strike >= min)
+            && max_strike.is_none_or(|max| contract.strike <= max)
+            && self
+                .option_type
+                .is_none_or(|option_type| option_type == contract.option_type)
+            && self
+                .min_open_interest
+                .is_none_or(|min| option.open_interest >= min)
+            && self
+                .min_volume
+                .is_none_or(|min| option.quote.as_ref().is_some_and(|q| q.volume >= min))
+    }
+
+    /// Remove options that fail a filter.
+    pub fn apply(&self, chain: &mut OptionChainData) {
+        let underlying_price = chain.underlying_price;
+        chain
+            .options
+            .retain(|option| self.matches(option, underlying_price));
+    }
+}
+
 /// Market data error.
 #[derive(Debug, Clone, thiserror::Error)]
 pub enum MarketDataError {
@@ -148,6 +273,30 @@ pub enum MarketDataError {
     },
 }
 
+impl ClassifiedError for MarketDataError {
+    fn code(&self) -> &str {
+        match self {
+            Self::ConnectionError { .. } => "MARKET_DATA_UNAVAILABLE",
+            Self::AuthenticationFailed => "MARKET_DATA_AUTH_FAILED",
+            Self::SymbolNotFound { .. } => "SYMBOL_NOT_FOUND",
+            Self::DataUnavailable { .. } => "MARKET_DATA_MISSING",
+            Self::RateLimited { .. } => "MARKET_DATA_RATE_LIMITED",
+            Self::ApiError { .. } => "MARKET_DATA_ERROR",
+        }
+    }
+
+    fn kind(&self) -> ErrorKind {
+        match self {
+            Self::ConnectionError { .. } | Self::DataUnavailable { .. } | Self::ApiError { .. } => {
+                ErrorKind::Unavailable
+            }
+            Self::AuthenticationFailed => ErrorKind::Internal,
+            Self::SymbolNotFound { .. } => ErrorKind::NotFound,
+            Self::RateLimited { .. } => ErrorKind::RateLimited,
+        }
+    }
+}
+
 /// Port for fetching market data from external providers.
 ///
 /// This is a driven (secondary/outbound) port. The infrastructure layer
@@ -164,6 +313,43 @@ pub trait MarketDataPort: Send + Sync {
     ///
     /// Includes option contracts, quotes, and Greeks where available.
     async fn get_option_chain(&self, underlying: &str) -> Result<OptionChainData, MarketDataError>;
+
+    /// Get the latest trade for multiple symbols.
+    ///
+    /// Symbols without a trade are omitted. Providers without trade data
+    /// return `DataUnavailable`.
+    async fn get_latest_trades(
+        &self,
+        _symbols: &[String],
+    ) -> Result<Vec<LastTrade>, MarketDataError> {
+        Err(MarketDataError::DataUnavailable {
+            message: "Latest trades not supported by this provider".to_string(),
+        })
+    }
+
+    /// Get the current and prior daily bars for multiple symbols.
+    ///
+    /// Symbols without bars are omitted. Providers without bar data return
+    /// `DataUnavailable`.
+    async fn get_daily_bars(&self, _symbols: &[String]) -> Result<Vec<DailyBars>, MarketDataError> {
+        Err(MarketDataError::DataUnavailable {
+            message: "Daily bars not supported by this provider".to_string(),
+        })
+    }
+
+    /// Get the options of a chain that pass `filter`.
+    ///
+    /// The default fetches the whole chain and filters it; adapters should
+    /// push filters to the provider where it supports them.
+    async fn get_filtered_option_chain(
+        &self,
+        underlying: &str,
+        filter: &OptionChainFilter,
+    ) -> Result<OptionChainData, MarketDataError> {
+        let mut chain = self.get_option_chain(underlying).await?;
+        filter.apply(&mut chain);
+        Ok(chain)
+    }
 }
 
 #[cfg(test)]
@@ -187,4 +373,77 @@ mod tests {
         // (150 + 151) / 2 = 150.5
         assert_eq!(quote.mid(), Decimal::new(1505, 1));
     }
+
+    fn option(expiration: &str, strike: i64, option_type: OptionType, volume: i64) -> OptionQuote {
+        OptionQuote {
+            contract: OptionContract {
+                underlying: "SPX".to_string(),
+                expiration: expiration.to_string(),
+                strike: Decimal::new(strike, 0),
+                option_type,
+            },
+            quote: Some(MarketQuote {
+                symbol: "SPX".to_string(),
+                bid: Decimal::ONE,
+                ask: Decimal::TWO,
+                bid_size: 1,
+                ask_size: 1,
+                last: Decimal::ONE,
+                last_size: 1,
+                volume,
+                timestamp: Timestamp::now(),
+            }),
+            implied_volatility: None,
+            greeks: None,
+            open_interest: 100,
+        }
+    }
+
+    #[test]
+    fn strike_range_around_spot_narrows_absolute_range() {
+        let filter = OptionChainFilter {
+            min_strike: Some(Decimal::new(95, 0)),
+            strike_range_pct: Some(Decimal::new(10, 2)),
+            ..OptionChainFilter::default()
+        };
+
+        assert_eq!(
+            filter.strike_bounds(Decimal::new(100, 0)),
+            (Some(Decimal::new(95, 0)), Some(Decimal::new(110, 0)))
+        );
+        assert_eq!(
+            OptionChainFilter::default().strike_bounds(Decimal::new(100, 0)),
+            (None, None)
+        );
+    }
+
+    #[test]
+    fn filter_applies_every_criterion() {
+        let mut chain = OptionChainData {
+            underlying: "SPX".to_string(),
+            underlying_price: Decimal::new(100, 0),
+            options: vec![
+                option("2025-01-17", 100, OptionType::Call, 50),
+                option("2025-01-17", 100, OptionType::Put, 50),
+                option("2025-01-17", 150, OptionType::Call, 50),
+                option("2025-03-21", 100, OptionType::Call, 50),
+                option("2025-01-17", 105, OptionType::Call, 5),
+            ],
+            as_of: Timestamp::now(),
+        };
+        let filter = OptionChainFilter {
+            max_expiration: Some("2025-02-01".to_string()),
+            strike_range_pct: Some(Decimal::new(10, 2)),
+            option_type: Some(OptionType::Call),
+            min_open_interest: Some(100),
+            min_volume: Some(10),
+            ..OptionChainFilter::default()
+        };
+
+        filter.apply(&mut chain);
+
+        assert_eq!(chain.options.len(), 1);
+        assert_eq!(chain.options[0].contract.strike, Decimal::new(100, 0));
+        assert_eq!(chain.options[0].contract.option_type, OptionType::Call);
+    }
 }
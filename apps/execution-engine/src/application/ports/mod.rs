@@ -4,21 +4,58 @@
 //! - **Driver Ports** (Primary/Inbound): How the world uses our application
 //! - **Driven Ports** (Secondary/Outbound): How our application uses external systems
 
+mod account_activity_port;
 mod broker_port;
+mod clock_port;
 mod event_publisher_port;
+mod execution_metrics_port;
+mod leader_lock_port;
 mod market_data_port;
+mod oauth_port;
+mod order_update_port;
+mod outbox_port;
+mod position_lifecycle_repository_port;
 mod price_feed_port;
 mod quote_provider_port;
 mod risk_repository_port;
+mod scheduled_plan_repository_port;
+mod time_source_port;
+mod watchlist_repository_port;
 
+pub use account_activity_port::{AccountActivity, AccountActivityPort, ActivityKind};
 pub use broker_port::{
-    BrokerError, BrokerPort, CancelOrderRequest, OrderAck, PositionInfo, SubmitOrderRequest,
+    AssetInfo, BrokerError, BrokerPort, CancelOrderRequest, LegFill, MarketClock, OrderAck,
+    PositionInfo, SubmitOrderRequest,
 };
+pub use clock_port::{Clock, SimulatedClock, SystemClock};
 pub use event_publisher_port::{EventPublishError, EventPublisherPort, NoOpEventPublisher};
+pub use execution_metrics_port::ExecutionMetricsPort;
+pub use leader_lock_port::{InMemoryLeaderLock, LeaderLockError, LeaderLockPort};
 pub use market_data_port::{
-    MarketDataError, MarketDataPort, MarketQuote, OptionChainData, OptionContract, OptionGreeks,
-    OptionQuote, OptionType,
+    DailyBar, DailyBars, LastTrade, MarketDataError, MarketDataPort, MarketQuote, OptionChainData,
+    OptionChainFilter, OptionContract, OptionGreeks, OptionQuote, OptionType,
 };
-pub use price_feed_port::{PriceFeedError, PriceFeedPort, Quote};
+pub use oauth_port::{
+    InMemoryOAuthTokenRepository, OAuthAuthorizationPort, OAuthError, OAuthToken,
+    OAuthTokenRepositoryPort,
+};
+pub use order_update_port::{OrderUpdate, OrderUpdatePort, OrderUpdateTransport};
+pub use outbox_port::{
+    InMemoryOutboxRepository, OutboxEntry, OutboxError, OutboxQueue, OutboxRepositoryPort,
+};
+pub use position_lifecycle_repository_port::{
+    DecisionContext, InMemoryPositionLifecycleRepository, LifecycleError, LifecycleEvent,
+    LifecycleEventKind, PositionLifecycle, PositionLifecycleRepositoryPort, ThesisState,
+    TimeHorizon, TrackedOrder,
+};
+pub use price_feed_port::{BarTimeframe, Candle, PriceFeedError, PriceFeedPort, Quote};
 pub use quote_provider_port::QuoteProviderPort;
 pub use risk_repository_port::{InMemoryRiskRepository, RiskRepositoryPort};
+pub use scheduled_plan_repository_port::{
+    ExecutionWindow, InMemoryScheduledPlanRepository, ScheduleError, ScheduledPlan,
+    ScheduledPlanRepositoryPort,
+};
+pub use time_source_port::{ClockOffset, FixedTimeSource, TimeSourceError, TimeSourcePort};
+pub use watchlist_repository_port::{
+    InMemoryWatchlistRepository, Watchlist, WatchlistError, WatchlistRepositoryPort,
+};
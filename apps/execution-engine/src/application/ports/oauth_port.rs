@@ -0,0 +1,174 @@
+//! OAuth Ports (Driven Ports)
+//!
+//! Interfaces for connecting end users' broker accounts through OAuth: the
+//! broker's authorization server, which issues and refreshes access tokens,
+//! and the per-user store the tokens are kept in.
+
+use std::collections::HashMap;
+
+use async_trait::async_trait;
+use serde::{Deserialize, Serialize};
+
+use crate::domain::shared::{ClassifiedError, ErrorKind, Timestamp};
+
+/// OAuth error.
+#[derive(Debug, Clone, thiserror::Error)]
+pub enum OAuthError {
+    /// The authorization server refused the code or refresh token.
+    #[error("OAuth grant rejected: {message}")]
+    Rejected {
+        /// Error details.
+        message: String,
+    },
+
+    /// The authorization server could not be reached.
+    #[error("OAuth server unavailable: {message}")]
+    Unavailable {
+        /// Error details.
+        message: String,
+    },
+
+    /// Token storage failure.
+    #[error("OAuth token storage error: {message}")]
+    StorageError {
+        /// Error details.
+        message: String,
+    },
+}
+
+impl ClassifiedError for OAuthError {
+    fn code(&self) -> &str {
+        match self {
+            Self::Rejected { .. } => "OAUTH_GRANT_REJECTED",
+            Self::Unavailable { .. } => "OAUTH_UNAVAILABLE",
+            Self::StorageError { .. } => "OAUTH_TOKEN_STORE_ERROR",
+        }
+    }
+
+    fn kind(&self) -> ErrorKind {
+        match self {
+            Self::Rejected { .. } => ErrorKind::FailedPrecondition,
+            Self::Unavailable { .. } => ErrorKind::Unavailable,
+            Self::StorageError { .. } => ErrorKind::Internal,
+        }
+    }
+}
+
+/// An OAuth access token with its refresh token, if any.
+#[derive(Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct OAuthToken {
+    /// Bearer token sent with API requests.
+    pub access_token: String,
+    /// Token used to obtain a new access token.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub refresh_token: Option<String>,
+    /// Granted scopes, space separated.
+    #[serde(default)]
+    pub scope: String,
+    /// When the token was issued.
+    pub obtained_at: Timestamp,
+    /// When the access token expires (`None` if it does not).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub expires_at: Option<Timestamp>,
+}
+
+impl std::fmt::Debug for OAuthToken {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("OAuthToken")
+            .field("access_token", &"[REDACTED]")
+            .field(
+                "refresh_token",
+                &self.refresh_token.as_ref().map(|_| "[REDACTED]"),
+            )
+            .field("scope", &self.scope)
+            .field("obtained_at", &self.obtained_at)
+            .field("expires_at", &self.expires_at)
+            .finish()
+    }
+}
+
+/// Port for the broker's OAuth authorization server.
+#[async_trait]
+pub trait OAuthAuthorizationPort: Send + Sync {
+    /// URL the user is sent to in order to grant access, carrying `state`
+    /// back to the redirect URI.
+    fn authorize_url(&self, state: &str) -> String;
+
+    /// Exchange an authorization code for a token.
+    async fn exchange_code(&self, code: &str) -> Result<OAuthToken, OAuthError>;
+
+    /// Obtain a new access token with a refresh token.
+    async fn refresh(&self, refresh_token: &str) -> Result<OAuthToken, OAuthError>;
+}
+
+/// Port for per-user token storage.
+#[async_trait]
+pub trait OAuthTokenRepositoryPort: Send + Sync {
+    /// Insert or replace the token of `user_id`.
+    async fn save(&self, user_id: &str, token: &OAuthToken) -> Result<(), OAuthError>;
+
+    /// The token of `user_id`.
+    async fn find(&self, user_id: &str) -> Result<Option<OAuthToken>, OAuthError>;
+
+    /// Users with a stored token, sorted.
+    async fn users(&self) -> Result<Vec<String>, OAuthError>;
+
+    /// Delete the token of `user_id` (no-op if absent).
+    async fn delete(&self, user_id: &str) -> Result<(), OAuthError>;
+}
+
+/// In-memory implementation for testing.
+#[derive(Debug, Default)]
+pub struct InMemoryOAuthTokenRepository {
+    tokens: std::sync::RwLock<HashMap<String, OAuthToken>>,
+}
+
+impl InMemoryOAuthTokenRepository {
+    /// Create a new in-memory repository.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+#[async_trait]
+impl OAuthTokenRepositoryPort for InMemoryOAuthTokenRepository {
+    async fn save(&self, user_id: &str, token: &OAuthToken) -> Result<(), OAuthError> {
+        let mut tokens = self
+            .tokens
+            .write()
+            .unwrap_or_else(std::sync::PoisonError::into_inner);
+        tokens.insert(user_id.to_string(), token.clone());
+        drop(tokens);
+        Ok(())
+    }
+
+    async fn find(&self, user_id: &str) -> Result<Option<OAuthToken>, OAuthError> {
+        let tokens = self
+            .tokens
+            .read()
+            .unwrap_or_else(std::sync::PoisonError::into_inner);
+        Ok(tokens.get(user_id).cloned())
+    }
+
+    async fn users(&self) -> Result<Vec<String>, OAuthError> {
+        let tokens = self
+            .tokens
+            .read()
+            .unwrap_or_else(std::sync::PoisonError::into_inner);
+        let mut users: Vec<String> = tokens.keys().cloned().collect();
+        drop(tokens);
+        users.sort();
+        Ok(users)
+    }
+
+    async fn delete(&self, user_id: &str) -> Result<(), OAuthError> {
+        let mut tokens = self
+            .tokens
+            .write()
+            .unwrap_or_else(std::sync::PoisonError::into_inner);
+        tokens.remove(user_id);
+        drop(tokens);
+        Ok(())
+    }
+}
@@ -0,0 +1,52 @@
+//! Order Update Port (Driven Port)
+//!
+//! Interface for broker push streams of order status and fill updates, so
+//! fills land without waiting for the next reconciliation pass.
+
+use async_trait::async_trait;
+use serde::{Deserialize, Serialize};
+use tokio::sync::mpsc;
+use tokio_util::sync::CancellationToken;
+
+use super::OrderAck;
+
+/// Transport an order update arrived on.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum OrderUpdateTransport {
+    /// Broker trading WebSocket stream.
+    WebSocket,
+    /// Broker server-sent events endpoint.
+    Sse,
+}
+
+impl std::fmt::Display for OrderUpdateTransport {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::WebSocket => write!(f, "WEBSOCKET"),
+            Self::Sse => write!(f, "SSE"),
+        }
+    }
+}
+
+/// An order update pushed by the broker.
+#[derive(Debug, Clone)]
+pub struct OrderUpdate {
+    /// Transport the update arrived on.
+    pub transport: OrderUpdateTransport,
+    /// Raw broker event (e.g. `new`, `partial_fill`, `fill`, `canceled`).
+    pub event: String,
+    /// Order state after the event.
+    pub order: OrderAck,
+}
+
+/// Port for broker order update streams.
+#[async_trait]
+pub trait OrderUpdatePort: Send + Sync {
+    /// Transport of this stream.
+    fn transport(&self) -> OrderUpdateTransport;
+
+    /// Stream updates into `updates` until `shutdown` is cancelled or the
+    /// receiver is dropped, reconnecting after errors.
+    async fn run(&self, updates: mpsc::Sender<OrderUpdate>, shutdown: CancellationToken);
+}
@@ -0,0 +1,215 @@
+//! Event Outbox Port (Driven Port)
+//!
+//! Interface for the durable queue of order events awaiting delivery to the
+//! event sink. Events are appended when the state change that raised them
+//! is saved and removed only once the sink has accepted them, so an
+//! unreachable sink delays events instead of losing them.
+
+use std::sync::{Mutex, PoisonError};
+
+use async_trait::async_trait;
+use serde::{Deserialize, Serialize};
+
+use crate::domain::order_execution::events::OrderEvent;
+use crate::domain::shared::{ClassifiedError, ErrorKind, Timestamp};
+
+/// Outbox storage error.
+#[derive(Debug, Clone, thiserror::Error)]
+pub enum OutboxError {
+    /// Storage failure.
+    #[error("Event outbox storage error: {message}")]
+    StorageError {
+        /// Error details.
+        message: String,
+    },
+}
+
+impl ClassifiedError for OutboxError {
+    fn code(&self) -> &str {
+        match self {
+            Self::StorageError { .. } => "OUTBOX_STORE_ERROR",
+        }
+    }
+
+    fn kind(&self) -> ErrorKind {
+        ErrorKind::Internal
+    }
+}
+
+/// An event waiting in the outbox.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct OutboxEntry {
+    /// Position in the outbox; later events have higher IDs.
+    pub id: u64,
+    /// The event to deliver.
+    pub event: OrderEvent,
+    /// When the event was written to the outbox.
+    pub recorded_at: Timestamp,
+    /// Failed delivery attempts so far.
+    pub attempts: u32,
+    /// Earliest time of the next delivery attempt.
+    pub next_attempt_at: Timestamp,
+    /// Error from the last failed attempt.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub last_error: Option<String>,
+}
+
+/// Port for the event outbox.
+#[async_trait]
+pub trait OutboxRepositoryPort: Send + Sync {
+    /// Append `events`, in order, due for delivery at `now`.
+    async fn append(&self, events: Vec<OrderEvent>, now: Timestamp) -> Result<(), OutboxError>;
+
+    /// Up to `limit` undelivered entries, oldest first.
+    async fn pending(&self, limit: usize) -> Result<Vec<OutboxEntry>, OutboxError>;
+
+    /// Remove a delivered entry.
+    async fn mark_delivered(&self, id: u64) -> Result<(), OutboxError>;
+
+    /// Record a failed delivery and when to try again.
+    async fn record_failure(
+        &self,
+        id: u64,
+        error: &str,
+        next_attempt_at: Timestamp,
+    ) -> Result<(), OutboxError>;
+
+    /// Number of undelivered entries.
+    async fn depth(&self) -> Result<usize, OutboxError>;
+}
+
+/// Undelivered entries and the ID the next appended entry gets.
+///
+/// Shared by outbox implementations so they order and retry entries alike.
+#[derive(Debug, Clone)]
+pub struct OutboxQueue {
+    entries: Vec<OutboxEntry>,
+    next_id: u64,
+}
+
+impl Default for OutboxQueue {
+    fn default() -> Self {
+        Self::from_entries(Vec::new())
+    }
+}
+
+impl OutboxQueue {
+    /// Queue holding `entries`, continuing their numbering.
+    #[must_use]
+    pub fn from_entries(mut entries: Vec<OutboxEntry>) -> Self {
+        entries.sort_by_key(|e| e.id);
+        let next_id = entries.last().map_or(1, |e| e.id + 1);
+        Self { entries, next_id }
+    }
+
+    /// Undelivered entries, oldest first.
+    #[must_use]
+    pub fn entries(&self) -> &[OutboxEntry] {
+        &self.entries
+    }
+
+    /// Append `events` due at `now`.
+    pub fn append(&mut self, events: Vec<OrderEvent>, now: Timestamp) {
+        for event in events {
+            self.entries.push(OutboxEntry {
+                id: self.next_id,
+                event,
+                recorded_at: now,
+                attempts: 0,
+                next_attempt_at: now,
+                last_error: None,
+            });
+            self.next_id += 1;
+        }
+    }
+
+    /// Remove entry `id`, returning it if present.
+    pub fn remove(&mut self, id: u64) -> Option<OutboxEntry> {
+        let index = self.entries.iter().position(|e| e.id == id)?;
+        Some(self.entries.remove(index))
+    }
+
+    /// Record a failed attempt on entry `id`, returning its previous state.
+    pub fn record_failure(
+        &mut self,
+        id: u64,
+        error: &str,
+        next_attempt_at: Timestamp,
+    ) -> Option<OutboxEntry> {
+        let entry = self.entries.iter_mut().find(|e| e.id == id)?;
+        let previous = entry.clone();
+        entry.attempts += 1;
+        entry.next_attempt_at = next_attempt_at;
+        entry.last_error = Some(error.to_string());
+        Some(previous)
+    }
+
+    /// Put back an entry's earlier state after a failed write.
+    pub fn restore(&mut self, entry: OutboxEntry) {
+        match self.entries.binary_search_by_key(&entry.id, |e| e.id) {
+            Ok(index) => self.entries[index] = entry,
+            Err(index) => self.entries.insert(index, entry),
+        }
+    }
+
+    /// Drop entries from `id` on, undoing a failed append.
+    pub fn truncate_from(&mut self, id: u64) {
+        self.entries.retain(|e| e.id < id);
+        self.next_id = id;
+    }
+
+    /// ID the next appended entry gets.
+    #[must_use]
+    pub const fn next_id(&self) -> u64 {
+        self.next_id
+    }
+}
+
+/// In-memory event outbox for testing and deployments without an event sink.
+#[derive(Debug, Default)]
+pub struct InMemoryOutboxRepository {
+    queue: Mutex<OutboxQueue>,
+}
+
+impl InMemoryOutboxRepository {
+    /// Create an empty outbox.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    fn queue(&self) -> std::sync::MutexGuard<'_, OutboxQueue> {
+        self.queue.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+}
+
+#[async_trait]
+impl OutboxRepositoryPort for InMemoryOutboxRepository {
+    async fn append(&self, events: Vec<OrderEvent>, now: Timestamp) -> Result<(), OutboxError> {
+        self.queue().append(events, now);
+        Ok(())
+    }
+
+    async fn pending(&self, limit: usize) -> Result<Vec<OutboxEntry>, OutboxError> {
+        Ok(self.queue().entries().iter().take(limit).cloned().collect())
+    }
+
+    async fn mark_delivered(&self, id: u64) -> Result<(), OutboxError> {
+        self.queue().remove(id);
+        Ok(())
+    }
+
+    async fn record_failure(
+        &self,
+        id: u64,
+        error: &str,
+        next_attempt_at: Timestamp,
+    ) -> Result<(), OutboxError> {
+        self.queue().record_failure(id, error, next_attempt_at);
+        Ok(())
+    }
+
+    async fn depth(&self) -> Result<usize, OutboxError> {
+        Ok(self.queue().entries().len())
+    }
+}
@@ -0,0 +1,313 @@
+//! Position Lifecycle Repository Port (Driven Port)
+//!
+//! Interface for persisting why and when each position was opened, scaled
+//! and closed. A lifecycle runs from the fill that takes a symbol off flat to
+//! the fill that returns it to flat; every event links back to the order and
+//! fills that caused it and the decision context the order was submitted
+//! with.
+
+use std::collections::HashMap;
+
+use async_trait::async_trait;
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+
+use crate::domain::execution_tactics::StrategyFamily;
+use crate::domain::order_execution::value_objects::{OrderPurpose, OrderSide};
+use crate::domain::shared::{ClassifiedError, ErrorKind, Timestamp};
+use crate::domain::stop_enforcement::{HoldingPeriod, ScaleOutTarget};
+
+/// Lifecycle storage error.
+#[derive(Debug, Clone, thiserror::Error)]
+pub enum LifecycleError {
+    /// Storage failure.
+    #[error("Position lifecycle storage error: {message}")]
+    StorageError {
+        /// Error details.
+        message: String,
+    },
+}
+
+impl ClassifiedError for LifecycleError {
+    fn code(&self) -> &str {
+        match self {
+            Self::StorageError { .. } => "LIFECYCLE_STORE_ERROR",
+        }
+    }
+
+    fn kind(&self) -> ErrorKind {
+        match self {
+            Self::StorageError { .. } => ErrorKind::Internal,
+        }
+    }
+}
+
+/// Thesis state reported by the decision layer.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum ThesisState {
+    /// Watching, no position yet.
+    Watching,
+    /// Position entered.
+    Entered,
+    /// Adding to the position.
+    Adding,
+    /// Managing an established position.
+    Managing,
+    /// Exiting the position.
+    Exiting,
+    /// Thesis closed.
+    Closed,
+}
+
+/// How long the decision layer intends to hold a position.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum TimeHorizon {
+    /// Closed the same session.
+    Intraday,
+    /// Held for days.
+    Swing,
+    /// Held for weeks or longer.
+    Position,
+}
+
+/// Decision that produced an order.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DecisionContext {
+    /// Caller-assigned decision ID.
+    #[serde(default)]
+    pub decision_id: Option<String>,
+    /// Thesis state at decision time.
+    #[serde(default)]
+    pub thesis_state: Option<ThesisState>,
+    /// Intended holding period.
+    #[serde(default)]
+    pub time_horizon: Option<TimeHorizon>,
+    /// Strategy family, used to pick the execution profile.
+    #[serde(default)]
+    pub strategy_family: Option<StrategyFamily>,
+    /// Free-form strategy tag, carried onto the order for attribution.
+    #[serde(default)]
+    pub strategy_tag: Option<String>,
+    /// Maximum time the position may stay open before the stop monitor
+    /// exits it.
+    #[serde(default)]
+    pub max_holding_period: Option<HoldingPeriod>,
+    /// Take-profit ladder: parts of the position to exit before the final
+    /// target, nearest first.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub scale_out: Vec<ScaleOutTarget>,
+    /// Human-readable rationale.
+    #[serde(default)]
+    pub rationale: Option<String>,
+}
+
+/// Kind of lifecycle event.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum LifecycleEventKind {
+    /// Position opened from flat.
+    Opened,
+    /// Position increased.
+    ScaledIn,
+    /// Position reduced but still open.
+    ScaledOut,
+    /// Position returned to flat.
+    Closed,
+}
+
+/// One change to a position, caused by fills of a single order.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LifecycleEvent {
+    /// What happened to the position.
+    pub kind: LifecycleEventKind,
+    /// Order whose fills caused the change.
+    pub order_id: String,
+    /// Fills applied in this event.
+    pub fill_ids: Vec<String>,
+    /// Quantity filled (unsigned).
+    pub quantity: Decimal,
+    /// Average price of the fills.
+    pub price: Decimal,
+    /// Signed position after the event (positive = long).
+    pub position_after: Decimal,
+    /// Order purpose.
+    pub purpose: OrderPurpose,
+    /// Decision cycle that produced the order.
+    pub cycle_id: Option<String>,
+    /// Decision that produced the order.
+    pub decision: Option<DecisionContext>,
+    /// Time of the last fill in the event.
+    pub at: Timestamp,
+}
+
+/// A position from open to close.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PositionLifecycle {
+    /// Lifecycle ID.
+    pub lifecycle_id: String,
+    /// Symbol.
+    pub symbol: String,
+    /// Signed current quantity (positive = long, zero once closed).
+    pub quantity: Decimal,
+    /// When the position was opened.
+    pub opened_at: Timestamp,
+    /// When the position was closed.
+    pub closed_at: Option<Timestamp>,
+    /// Why the position was closed.
+    pub exit_reason: Option<String>,
+    /// Events in fill order; the first is always `Opened`.
+    pub events: Vec<LifecycleEvent>,
+}
+
+impl PositionLifecycle {
+    /// Open a lifecycle from its first event.
+    #[must_use]
+    pub fn open(symbol: impl Into<String>, event: LifecycleEvent) -> Self {
+        Self {
+            lifecycle_id: uuid::Uuid::new_v4().to_string(),
+            symbol: symbol.into(),
+            quantity: event.position_after,
+            opened_at: event.at,
+            closed_at: None,
+            exit_reason: None,
+            events: vec![event],
+        }
+    }
+
+    /// Whether the position is still held.
+    #[must_use]
+    pub const fn is_open(&self) -> bool {
+        self.closed_at.is_none()
+    }
+
+    /// The event that opened the position.
+    #[must_use]
+    pub fn entry(&self) -> Option<&LifecycleEvent> {
+        self.events.first()
+    }
+}
+
+/// An order whose fills are being folded into lifecycles.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TrackedOrder {
+    /// Order ID.
+    pub order_id: String,
+    /// Symbol.
+    pub symbol: String,
+    /// Side.
+    pub side: OrderSide,
+    /// Purpose.
+    pub purpose: OrderPurpose,
+    /// Decision cycle that produced the order.
+    pub cycle_id: Option<String>,
+    /// Decision that produced the order.
+    pub decision: Option<DecisionContext>,
+    /// Number of the order's fills already applied.
+    pub fills_applied: usize,
+}
+
+/// Port for persisting position lifecycles.
+#[async_trait]
+pub trait PositionLifecycleRepositoryPort: Send + Sync {
+    /// Save (insert or replace) a lifecycle.
+    async fn save(&self, lifecycle: &PositionLifecycle) -> Result<(), LifecycleError>;
+
+    /// Find the open lifecycle for a symbol.
+    async fn find_open(&self, symbol: &str) -> Result<Option<PositionLifecycle>, LifecycleError>;
+
+    /// List lifecycles ordered by `opened_at`, optionally for one symbol.
+    async fn list(&self, symbol: Option<&str>) -> Result<Vec<PositionLifecycle>, LifecycleError>;
+
+    /// Save (insert or replace) a tracked order.
+    async fn save_order(&self, order: &TrackedOrder) -> Result<(), LifecycleError>;
+
+    /// Find a tracked order by ID.
+    async fn find_order(&self, order_id: &str) -> Result<Option<TrackedOrder>, LifecycleError>;
+
+    /// Stop tracking an order.
+    async fn delete_order(&self, order_id: &str) -> Result<(), LifecycleError>;
+}
+
+/// In-memory implementation for testing.
+#[derive(Debug, Default)]
+pub struct InMemoryPositionLifecycleRepository {
+    lifecycles: std::sync::RwLock<HashMap<String, PositionLifecycle>>,
+    orders: std::sync::RwLock<HashMap<String, TrackedOrder>>,
+}
+
+impl InMemoryPositionLifecycleRepository {
+    /// Create a new in-memory repository.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+#[async_trait]
+impl PositionLifecycleRepositoryPort for InMemoryPositionLifecycleRepository {
+    async fn save(&self, lifecycle: &PositionLifecycle) -> Result<(), LifecycleError> {
+        let mut lifecycles = self
+            .lifecycles
+            .write()
+            .unwrap_or_else(std::sync::PoisonError::into_inner);
+        lifecycles.insert(lifecycle.lifecycle_id.clone(), lifecycle.clone());
+        drop(lifecycles);
+        Ok(())
+    }
+
+    async fn find_open(&self, symbol: &str) -> Result<Option<PositionLifecycle>, LifecycleError> {
+        let lifecycles = self
+            .lifecycles
+            .read()
+            .unwrap_or_else(std::sync::PoisonError::into_inner);
+        Ok(lifecycles
+            .values()
+            .find(|l| l.symbol == symbol && l.is_open())
+            .cloned())
+    }
+
+    async fn list(&self, symbol: Option<&str>) -> Result<Vec<PositionLifecycle>, LifecycleError> {
+        let lifecycles = self
+            .lifecycles
+            .read()
+            .unwrap_or_else(std::sync::PoisonError::into_inner);
+        let mut matching: Vec<PositionLifecycle> = lifecycles
+            .values()
+            .filter(|l| symbol.is_none_or(|s| l.symbol == s))
+            .cloned()
+            .collect();
+        drop(lifecycles);
+        matching.sort_by_key(|l| l.opened_at);
+        Ok(matching)
+    }
+
+    async fn save_order(&self, order: &TrackedOrder) -> Result<(), LifecycleError> {
+        let mut orders = self
+            .orders
+            .write()
+            .unwrap_or_else(std::sync::PoisonError::into_inner);
+        orders.insert(order.order_id.clone(), order.clone());
+        drop(orders);
+        Ok(())
+    }
+
+    async fn find_order(&self, order_id: &str) -> Result<Option<TrackedOrder>, LifecycleError> {
+        let orders = self
+            .orders
+            .read()
+            .unwrap_or_else(std::sync::PoisonError::into_inner);
+        Ok(orders.get(order_id).cloned())
+    }
+
+    async fn delete_order(&self, order_id: &str) -> Result<(), LifecycleError> {
+        let mut orders = self
+            .orders
+            .write()
+            .unwrap_or_else(std::sync::PoisonError::into_inner);
+        orders.remove(order_id);
+        drop(orders);
+        Ok(())
+    }
+}
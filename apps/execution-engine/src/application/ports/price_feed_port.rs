@@ -68,6 +68,73 @@ impl Quote {
     }
 }
 
+/// Timeframe of intraday bars built from the live feed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+pub enum BarTimeframe {
+    /// One-minute bars.
+    OneMinute,
+    /// Five-minute bars.
+    FiveMinutes,
+}
+
+impl BarTimeframe {
+    /// All supported timeframes, shortest first.
+    pub const ALL: [Self; 2] = [Self::OneMinute, Self::FiveMinutes];
+
+    /// Length of a bar in minutes.
+    #[must_use]
+    pub const fn minutes(self) -> u32 {
+        match self {
+            Self::OneMinute => 1,
+            Self::FiveMinutes => 5,
+        }
+    }
+
+    /// Timeframe of `minutes`-long bars, if supported.
+    #[must_use]
+    pub const fn from_minutes(minutes: i64) -> Option<Self> {
+        match minutes {
+            1 => Some(Self::OneMinute),
+            5 => Some(Self::FiveMinutes),
+            _ => None,
+        }
+    }
+
+    /// Length of a bar in seconds.
+    #[must_use]
+    pub const fn seconds(self) -> i64 {
+        match self {
+            Self::OneMinute => 60,
+            Self::FiveMinutes => 300,
+        }
+    }
+}
+
+/// OHLCV bar aggregated from trades.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Candle {
+    /// Symbol.
+    pub symbol: String,
+    /// Bar length.
+    pub timeframe: BarTimeframe,
+    /// Bar open time, aligned to the timeframe.
+    pub start: Timestamp,
+    /// First trade price.
+    pub open: Decimal,
+    /// Highest trade price.
+    pub high: Decimal,
+    /// Lowest trade price.
+    pub low: Decimal,
+    /// Last trade price.
+    pub close: Decimal,
+    /// Shares traded.
+    pub volume: i64,
+    /// Volume-weighted average price.
+    pub vwap: Decimal,
+    /// Number of trades.
+    pub trade_count: i64,
+}
+
 /// Price feed error.
 #[derive(Debug, Clone, thiserror::Error)]
 pub enum PriceFeedError {
@@ -115,6 +182,19 @@ pub trait PriceFeedPort: Send + Sync {
     /// Get the last known price for an instrument.
     async fn get_last_price(&self, instrument_id: &InstrumentId)
     -> Result<Decimal, PriceFeedError>;
+
+    /// Get up to `limit` of the latest completed bars for a symbol, oldest
+    /// first.
+    ///
+    /// Returns an empty list if the feed does not build bars.
+    async fn get_bars(
+        &self,
+        _symbol: &Symbol,
+        _timeframe: BarTimeframe,
+        _limit: usize,
+    ) -> Result<Vec<Candle>, PriceFeedError> {
+        Ok(vec![])
+    }
 }
 
 #[cfg(test)]
@@ -51,6 +51,12 @@ pub trait QuoteProviderPort: Send + Sync {
     /// Returns error if unsubscription fails.
     async fn unsubscribe_options_quotes(&self, symbols: &[String]) -> Result<(), WebSocketError>;
 
+    /// Stock symbols currently subscribed to.
+    fn stock_subscriptions(&self) -> Vec<String>;
+
+    /// Options symbols currently subscribed to.
+    fn options_subscriptions(&self) -> Vec<String>;
+
     /// Check if the quote provider is connected.
     fn is_connected(&self) -> bool;
 }
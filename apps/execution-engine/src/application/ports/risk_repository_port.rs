@@ -2,13 +2,17 @@
 //!
 //! Interface for persisting risk policies and retrieving risk context.
 
+use std::sync::Arc;
+
 use async_trait::async_trait;
 use rust_decimal::Decimal;
 
+use crate::application::services::ExposureCache;
+
 use crate::domain::risk_management::{
     aggregate::RiskPolicy,
     errors::RiskError,
-    value_objects::{Exposure, Greeks, RiskContext},
+    value_objects::{ConcentrationGroups, Exposure, Greeks, RiskContext},
 };
 use crate::domain::shared::{InstrumentId, Money};
 
@@ -49,6 +53,13 @@ pub trait RiskRepositoryPort: Send + Sync {
     /// Get day trade count (for PDT tracking).
     async fn get_day_trade_count(&self) -> Result<u32, RiskError>;
 
+    /// Get sector and correlation groupings for concentration checks.
+    ///
+    /// Defaults to no groupings, which disables concentration checks.
+    async fn get_concentration_groups(&self) -> Result<ConcentrationGroups, RiskError> {
+        Ok(ConcentrationGroups::default())
+    }
+
     /// Build a complete risk context for validation.
     async fn build_risk_context(&self) -> Result<RiskContext, RiskError>;
 }
@@ -57,6 +68,8 @@ pub trait RiskRepositoryPort: Send + Sync {
 #[derive(Debug, Default)]
 pub struct InMemoryRiskRepository {
     policies: std::sync::RwLock<std::collections::HashMap<String, RiskPolicy>>,
+    concentration_groups: ConcentrationGroups,
+    exposure: Option<Arc<ExposureCache>>,
 }
 
 impl InMemoryRiskRepository {
@@ -65,6 +78,20 @@ impl InMemoryRiskRepository {
     pub fn new() -> Self {
         Self::default()
     }
+
+    /// Set the sector and correlation groupings used for concentration checks.
+    #[must_use]
+    pub fn with_concentration_groups(mut self, groups: ConcentrationGroups) -> Self {
+        self.concentration_groups = groups;
+        self
+    }
+
+    /// Read positions and portfolio exposure from a live exposure cache.
+    #[must_use]
+    pub fn with_exposure_cache(mut self, exposure: Arc<ExposureCache>) -> Self {
+        self.exposure = Some(exposure);
+        self
+    }
 }
 
 #[async_trait]
@@ -119,7 +146,10 @@ impl RiskRepositoryPort for InMemoryRiskRepository {
     }
 
     async fn get_portfolio_exposure(&self) -> Result<Exposure, RiskError> {
-        Ok(Exposure::default())
+        Ok(self
+            .exposure
+            .as_ref()
+            .map_or_else(Exposure::default, |cache| cache.exposure()))
     }
 
     async fn get_instrument_exposure(
@@ -141,6 +171,10 @@ impl RiskRepositoryPort for InMemoryRiskRepository {
         Ok(0)
     }
 
+    async fn get_concentration_groups(&self) -> Result<ConcentrationGroups, RiskError> {
+        Ok(self.concentration_groups.clone())
+    }
+
     async fn build_risk_context(&self) -> Result<RiskContext, RiskError> {
         let buying_power = self.get_buying_power().await?;
         let mut context = RiskContext::new(
@@ -148,10 +182,16 @@ impl RiskRepositoryPort for InMemoryRiskRepository {
             Money::new(buying_power),
         );
         context.current_exposure = self.get_portfolio_exposure().await?;
+        if let Some(cache) = &self.exposure {
+            for position in cache.position_contexts() {
+                context.add_position(position.instrument_id.as_str().to_string(), position);
+            }
+        }
         context.current_greeks = self.get_portfolio_greeks().await?;
         let day_trade_count = self.get_day_trade_count().await?;
         let day_trades_used = u8::try_from(day_trade_count).unwrap_or(u8::MAX);
         context.day_trades_remaining = 3u8.saturating_sub(day_trades_used);
+        context.concentration_groups = self.get_concentration_groups().await?;
         Ok(context)
     }
 }
@@ -213,4 +253,52 @@ mod tests {
         assert_eq!(context.buying_power, Money::new(Decimal::new(100_000, 0)));
         assert_eq!(context.day_trades_remaining, 3);
     }
+
+    #[tokio::test]
+    async fn in_memory_build_risk_context_includes_concentration_groups() {
+        let mut groups = ConcentrationGroups::default();
+        groups
+            .sectors
+            .insert("AAPL".to_string(), "Technology".to_string());
+
+        let repo = InMemoryRiskRepository::new().with_concentration_groups(groups);
+        let context = repo.build_risk_context().await.unwrap();
+
+        assert_eq!(
+            context.concentration_groups.sector("AAPL"),
+            Some("Technology")
+        );
+    }
+
+    #[tokio::test]
+    async fn in_memory_build_risk_context_reads_exposure_cache() {
+        use crate::domain::order_execution::aggregate::{CreateOrderCommand, Order};
+        use crate::domain::order_execution::value_objects::{
+            OrderPurpose, OrderSide, OrderType, TimeInForce,
+        };
+        use crate::domain::shared::{Quantity, Symbol};
+
+        let order = Order::new(CreateOrderCommand {
+            symbol: Symbol::new("AAPL"),
+            side: OrderSide::Buy,
+            order_type: OrderType::Limit,
+            quantity: Quantity::from_i64(100),
+            limit_price: Some(Money::new(Decimal::new(150, 0))),
+            stop_price: None,
+            time_in_force: TimeInForce::Day,
+            purpose: OrderPurpose::Entry,
+            legs: vec![],
+        })
+        .unwrap();
+        let cache = Arc::new(ExposureCache::new());
+        cache.sync_order(&order);
+
+        let repo = InMemoryRiskRepository::new().with_exposure_cache(cache);
+        let context = repo.build_risk_context().await.unwrap();
+
+        assert_eq!(
+            context.current_exposure.gross,
+            Money::new(Decimal::new(15_000, 0))
+        );
+    }
 }
@@ -0,0 +1,311 @@
+//! Scheduled Plan Repository Port (Driven Port)
+//!
+//! Interface for persisting decision plans accepted for delayed execution.
+//! Only pending plans are stored; plans are removed once executed, missed,
+//! or canceled.
+
+use std::collections::HashMap;
+
+use async_trait::async_trait;
+use serde::{Deserialize, Serialize};
+
+use crate::application::dto::SubmitOrdersRequestDto;
+use crate::domain::shared::{ClassifiedError, ErrorKind, Timestamp};
+
+/// Scheduling error.
+#[derive(Debug, Clone, thiserror::Error)]
+pub enum ScheduleError {
+    /// Execution window is malformed.
+    #[error("Invalid execution window: {reason}")]
+    InvalidWindow {
+        /// Why the window is invalid.
+        reason: String,
+    },
+
+    /// Execution window has already closed.
+    #[error("Execution window missed for plan {plan_id}")]
+    WindowMissed {
+        /// The plan ID.
+        plan_id: String,
+    },
+
+    /// A plan with the same ID is already scheduled.
+    #[error("Plan already scheduled: {plan_id}")]
+    DuplicatePlan {
+        /// The duplicate plan ID.
+        plan_id: String,
+    },
+
+    /// Plan not found.
+    #[error("Scheduled plan not found: {plan_id}")]
+    NotFound {
+        /// The missing plan ID.
+        plan_id: String,
+    },
+
+    /// Storage failure.
+    #[error("Scheduled plan storage error: {message}")]
+    StorageError {
+        /// Error details.
+        message: String,
+    },
+}
+
+impl ClassifiedError for ScheduleError {
+    fn code(&self) -> &str {
+        match self {
+            Self::InvalidWindow { .. } => "INVALID_EXECUTION_WINDOW",
+            Self::WindowMissed { .. } => "EXECUTION_WINDOW_MISSED",
+            Self::DuplicatePlan { .. } => "DUPLICATE_PLAN",
+            Self::NotFound { .. } => "PLAN_NOT_FOUND",
+            Self::StorageError { .. } => "PLAN_STORE_ERROR",
+        }
+    }
+
+    fn kind(&self) -> ErrorKind {
+        match self {
+            Self::InvalidWindow { .. } => ErrorKind::InvalidArgument,
+            Self::WindowMissed { .. } => ErrorKind::FailedPrecondition,
+            Self::DuplicatePlan { .. } => ErrorKind::AlreadyExists,
+            Self::NotFound { .. } => ErrorKind::NotFound,
+            Self::StorageError { .. } => ErrorKind::Internal,
+        }
+    }
+}
+
+/// Time window in which a scheduled plan may be executed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ExecutionWindow {
+    /// Earliest time the plan may execute.
+    pub execute_at: Timestamp,
+    /// Time after which the plan is considered missed.
+    pub expires_at: Timestamp,
+}
+
+impl ExecutionWindow {
+    /// Create a window from explicit bounds.
+    ///
+    /// # Errors
+    ///
+    /// Returns `ScheduleError::InvalidWindow` if `expires_at` is not after `execute_at`.
+    pub fn new(execute_at: Timestamp, expires_at: Timestamp) -> Result<Self, ScheduleError> {
+        if expires_at <= execute_at {
+            return Err(ScheduleError::InvalidWindow {
+                reason: format!(
+                    "expires_at {} must be after execute_at {}",
+                    expires_at.to_rfc3339(),
+                    execute_at.to_rfc3339()
+                ),
+            });
+        }
+        Ok(Self {
+            execute_at,
+            expires_at,
+        })
+    }
+
+    /// Create a window starting at `execute_at` and lasting `window_secs` seconds.
+    ///
+    /// # Errors
+    ///
+    /// Returns `ScheduleError::InvalidWindow` if `window_secs` is zero or out of range.
+    pub fn starting_at(execute_at: Timestamp, window_secs: u64) -> Result<Self, ScheduleError> {
+        let secs = i64::try_from(window_secs).map_err(|_| ScheduleError::InvalidWindow {
+            reason: format!("window of {window_secs}s is out of range"),
+        })?;
+        let expires_at = execute_at
+            .as_datetime()
+            .checked_add_signed(chrono::Duration::seconds(secs))
+            .ok_or_else(|| ScheduleError::InvalidWindow {
+                reason: format!("window of {window_secs}s is out of range"),
+            })?;
+        Self::new(execute_at, Timestamp::new(expires_at))
+    }
+
+    /// Whether the plan should execute at `now`.
+    #[must_use]
+    pub fn is_due(&self, now: Timestamp) -> bool {
+        now >= self.execute_at && now <= self.expires_at
+    }
+
+    /// Whether the window has closed at `now`.
+    #[must_use]
+    pub fn is_missed(&self, now: Timestamp) -> bool {
+        now > self.expires_at
+    }
+}
+
+/// A decision plan accepted now for execution within a future window.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ScheduledPlan {
+    /// Plan ID (the originating request ID).
+    pub plan_id: String,
+    /// Cycle ID the plan belongs to.
+    pub cycle_id: String,
+    /// Orders to submit when the window opens.
+    pub request: SubmitOrdersRequestDto,
+    /// Execution window.
+    pub window: ExecutionWindow,
+    /// When the plan was accepted.
+    pub scheduled_at: Timestamp,
+}
+
+impl ScheduledPlan {
+    /// Create a new scheduled plan accepted now.
+    #[must_use]
+    pub fn new(
+        plan_id: impl Into<String>,
+        cycle_id: impl Into<String>,
+        request: SubmitOrdersRequestDto,
+        window: ExecutionWindow,
+    ) -> Self {
+        Self {
+            plan_id: plan_id.into(),
+            cycle_id: cycle_id.into(),
+            request,
+            window,
+            scheduled_at: Timestamp::now(),
+        }
+    }
+}
+
+/// Port for persisting pending scheduled plans.
+#[async_trait]
+pub trait ScheduledPlanRepositoryPort: Send + Sync {
+    /// Save (insert or replace) a pending plan.
+    async fn save(&self, plan: &ScheduledPlan) -> Result<(), ScheduleError>;
+
+    /// Find a pending plan by ID.
+    async fn find_by_id(&self, plan_id: &str) -> Result<Option<ScheduledPlan>, ScheduleError>;
+
+    /// List all pending plans ordered by `execute_at`.
+    async fn list_pending(&self) -> Result<Vec<ScheduledPlan>, ScheduleError>;
+
+    /// Remove a plan by ID.
+    async fn delete(&self, plan_id: &str) -> Result<(), ScheduleError>;
+}
+
+/// In-memory implementation for testing.
+#[derive(Debug, Default)]
+pub struct InMemoryScheduledPlanRepository {
+    plans: std::sync::RwLock<HashMap<String, ScheduledPlan>>,
+}
+
+impl InMemoryScheduledPlanRepository {
+    /// Create a new in-memory repository.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+#[async_trait]
+impl ScheduledPlanRepositoryPort for InMemoryScheduledPlanRepository {
+    async fn save(&self, plan: &ScheduledPlan) -> Result<(), ScheduleError> {
+        let mut plans = self
+            .plans
+            .write()
+            .unwrap_or_else(std::sync::PoisonError::into_inner);
+        plans.insert(plan.plan_id.clone(), plan.clone());
+        drop(plans);
+        Ok(())
+    }
+
+    async fn find_by_id(&self, plan_id: &str) -> Result<Option<ScheduledPlan>, ScheduleError> {
+        let plans = self
+            .plans
+            .read()
+            .unwrap_or_else(std::sync::PoisonError::into_inner);
+        Ok(plans.get(plan_id).cloned())
+    }
+
+    async fn list_pending(&self) -> Result<Vec<ScheduledPlan>, ScheduleError> {
+        let plans = self
+            .plans
+            .read()
+            .unwrap_or_else(std::sync::PoisonError::into_inner);
+        let mut pending: Vec<ScheduledPlan> = plans.values().cloned().collect();
+        drop(plans);
+        pending.sort_by_key(|p| p.window.execute_at);
+        Ok(pending)
+    }
+
+    async fn delete(&self, plan_id: &str) -> Result<(), ScheduleError> {
+        let mut plans = self
+            .plans
+            .write()
+            .unwrap_or_else(std::sync::PoisonError::into_inner);
+        plans.remove(plan_id);
+        drop(plans);
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn ts(s: &str) -> Timestamp {
+        Timestamp::parse(s).unwrap()
+    }
+
+    fn make_plan(plan_id: &str, execute_at: &str) -> ScheduledPlan {
+        ScheduledPlan::new(
+            plan_id,
+            "cycle-1",
+            SubmitOrdersRequestDto {
+                orders: vec![],
+                validate_risk: true,
+                cycle_id: None,
+            },
+            ExecutionWindow::starting_at(ts(execute_at), 300).unwrap(),
+        )
+    }
+
+    #[test]
+    fn execution_window_rejects_inverted_bounds() {
+        let result = ExecutionWindow::new(ts("2026-01-05T20:50:00Z"), ts("2026-01-05T20:45:00Z"));
+        assert!(matches!(result, Err(ScheduleError::InvalidWindow { .. })));
+
+        let result = ExecutionWindow::starting_at(ts("2026-01-05T20:50:00Z"), 0);
+        assert!(matches!(result, Err(ScheduleError::InvalidWindow { .. })));
+    }
+
+    #[test]
+    fn execution_window_due_and_missed() {
+        let window = ExecutionWindow::starting_at(ts("2026-01-05T20:50:00Z"), 300).unwrap();
+
+        assert!(!window.is_due(ts("2026-01-05T20:49:59Z")));
+        assert!(window.is_due(ts("2026-01-05T20:50:00Z")));
+        assert!(window.is_due(ts("2026-01-05T20:55:00Z")));
+        assert!(!window.is_missed(ts("2026-01-05T20:55:00Z")));
+        assert!(window.is_missed(ts("2026-01-05T20:55:01Z")));
+    }
+
+    #[tokio::test]
+    async fn in_memory_lists_pending_in_execution_order() {
+        let repo = InMemoryScheduledPlanRepository::new();
+        repo.save(&make_plan("late", "2026-01-05T20:50:00Z"))
+            .await
+            .unwrap();
+        repo.save(&make_plan("early", "2026-01-05T14:30:00Z"))
+            .await
+            .unwrap();
+
+        let pending = repo.list_pending().await.unwrap();
+        let ids: Vec<&str> = pending.iter().map(|p| p.plan_id.as_str()).collect();
+        assert_eq!(ids, vec!["early", "late"]);
+    }
+
+    #[tokio::test]
+    async fn in_memory_delete() {
+        let repo = InMemoryScheduledPlanRepository::new();
+        repo.save(&make_plan("plan-1", "2026-01-05T20:50:00Z"))
+            .await
+            .unwrap();
+
+        repo.delete("plan-1").await.unwrap();
+
+        assert!(repo.find_by_id("plan-1").await.unwrap().is_none());
+    }
+}
@@ -0,0 +1,97 @@
+//! Time Source Port (Driven Port)
+//!
+//! Interface for a reference clock the host clock is compared against.
+//! Order aging, protection windows and day boundaries all read the host
+//! clock, so a drifting host clock silently skews them.
+
+use std::sync::{Mutex, PoisonError};
+
+use async_trait::async_trait;
+
+use crate::domain::shared::{ClassifiedError, ErrorKind};
+
+/// Time source error.
+#[derive(Debug, Clone, thiserror::Error)]
+pub enum TimeSourceError {
+    /// No reference clock answered.
+    #[error("Time source unavailable: {message}")]
+    Unavailable {
+        /// Error details.
+        message: String,
+    },
+}
+
+impl ClassifiedError for TimeSourceError {
+    fn code(&self) -> &str {
+        match self {
+            Self::Unavailable { .. } => "TIME_SOURCE_UNAVAILABLE",
+        }
+    }
+
+    fn kind(&self) -> ErrorKind {
+        ErrorKind::Unavailable
+    }
+}
+
+/// Offset of the host clock from a reference clock.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ClockOffset {
+    /// Reference clock that answered.
+    pub source: String,
+    /// Reference time minus host time, in milliseconds. Positive when the
+    /// host clock is behind.
+    pub offset_ms: i64,
+    /// Network round trip of the measurement, in milliseconds.
+    pub round_trip_ms: i64,
+}
+
+/// Port for measuring the host clock against a reference clock.
+#[async_trait]
+pub trait TimeSourcePort: Send + Sync {
+    /// Measure the current offset.
+    async fn offset(&self) -> Result<ClockOffset, TimeSourceError>;
+}
+
+/// Time source with a settable offset for testing.
+#[derive(Debug)]
+pub struct FixedTimeSource {
+    offset: Mutex<Result<i64, String>>,
+}
+
+impl FixedTimeSource {
+    /// Create a source reporting `offset_ms`.
+    #[must_use]
+    pub const fn new(offset_ms: i64) -> Self {
+        Self {
+            offset: Mutex::new(Ok(offset_ms)),
+        }
+    }
+
+    /// Report `offset_ms` from now on.
+    pub fn set(&self, offset_ms: i64) {
+        *self.offset.lock().unwrap_or_else(PoisonError::into_inner) = Ok(offset_ms);
+    }
+
+    /// Fail with `message` from now on.
+    pub fn fail(&self, message: impl Into<String>) {
+        *self.offset.lock().unwrap_or_else(PoisonError::into_inner) = Err(message.into());
+    }
+}
+
+#[async_trait]
+impl TimeSourcePort for FixedTimeSource {
+    async fn offset(&self) -> Result<ClockOffset, TimeSourceError> {
+        let offset = self
+            .offset
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .clone();
+        offset
+            .map(|offset_ms| ClockOffset {
+                source: "fixed".to_string(),
+                offset_ms,
+                round_trip_ms: 0,
+            })
+            .map_err(|message| TimeSourceError::Unavailable { message })
+    }
+}
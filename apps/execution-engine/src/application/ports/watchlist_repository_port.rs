@@ -0,0 +1,148 @@
+//! Watchlist Repository Port (Driven Port)
+//!
+//! Interface for persisting named lists of symbols the engine keeps market
+//! data warm for, ahead of any plan trading them.
+
+use std::collections::HashMap;
+
+use async_trait::async_trait;
+use serde::{Deserialize, Serialize};
+
+use crate::domain::shared::{ClassifiedError, ErrorKind, Timestamp};
+
+/// Watchlist error.
+#[derive(Debug, Clone, thiserror::Error)]
+pub enum WatchlistError {
+    /// Watchlist name or symbol is malformed.
+    #[error("Invalid watchlist: {reason}")]
+    Invalid {
+        /// Why the request is invalid.
+        reason: String,
+    },
+
+    /// A watchlist with the same name exists.
+    #[error("Watchlist already exists: {name}")]
+    AlreadyExists {
+        /// The duplicate name.
+        name: String,
+    },
+
+    /// Watchlist not found.
+    #[error("Watchlist not found: {name}")]
+    NotFound {
+        /// The missing name.
+        name: String,
+    },
+
+    /// Storage failure.
+    #[error("Watchlist storage error: {message}")]
+    StorageError {
+        /// Error details.
+        message: String,
+    },
+}
+
+impl ClassifiedError for WatchlistError {
+    fn code(&self) -> &str {
+        match self {
+            Self::Invalid { .. } => "INVALID_WATCHLIST",
+            Self::AlreadyExists { .. } => "WATCHLIST_EXISTS",
+            Self::NotFound { .. } => "WATCHLIST_NOT_FOUND",
+            Self::StorageError { .. } => "WATCHLIST_STORE_ERROR",
+        }
+    }
+
+    fn kind(&self) -> ErrorKind {
+        match self {
+            Self::Invalid { .. } => ErrorKind::InvalidArgument,
+            Self::AlreadyExists { .. } => ErrorKind::AlreadyExists,
+            Self::NotFound { .. } => ErrorKind::NotFound,
+            Self::StorageError { .. } => ErrorKind::Internal,
+        }
+    }
+}
+
+/// A named list of symbols.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Watchlist {
+    /// Unique name.
+    pub name: String,
+    /// Symbols, upper case, sorted and without duplicates.
+    pub symbols: Vec<String>,
+    /// When the watchlist was created.
+    pub created_at: Timestamp,
+    /// When the symbols last changed.
+    pub updated_at: Timestamp,
+}
+
+/// Port for watchlist persistence.
+#[async_trait]
+pub trait WatchlistRepositoryPort: Send + Sync {
+    /// Insert or replace a watchlist.
+    async fn save(&self, watchlist: &Watchlist) -> Result<(), WatchlistError>;
+
+    /// Find a watchlist by name.
+    async fn find(&self, name: &str) -> Result<Option<Watchlist>, WatchlistError>;
+
+    /// All watchlists, by name.
+    async fn list(&self) -> Result<Vec<Watchlist>, WatchlistError>;
+
+    /// Delete a watchlist (no-op if absent).
+    async fn delete(&self, name: &str) -> Result<(), WatchlistError>;
+}
+
+/// In-memory implementation for testing.
+#[derive(Debug, Default)]
+pub struct InMemoryWatchlistRepository {
+    watchlists: std::sync::RwLock<HashMap<String, Watchlist>>,
+}
+
+impl InMemoryWatchlistRepository {
+    /// Create a new in-memory repository.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+#[async_trait]
+impl WatchlistRepositoryPort for InMemoryWatchlistRepository {
+    async fn save(&self, watchlist: &Watchlist) -> Result<(), WatchlistError> {
+        let mut watchlists = self
+            .watchlists
+            .write()
+            .unwrap_or_else(std::sync::PoisonError::into_inner);
+        watchlists.insert(watchlist.name.clone(), watchlist.clone());
+        drop(watchlists);
+        Ok(())
+    }
+
+    async fn find(&self, name: &str) -> Result<Option<Watchlist>, WatchlistError> {
+        let watchlists = self
+            .watchlists
+            .read()
+            .unwrap_or_else(std::sync::PoisonError::into_inner);
+        Ok(watchlists.get(name).cloned())
+    }
+
+    async fn list(&self) -> Result<Vec<Watchlist>, WatchlistError> {
+        let watchlists = self
+            .watchlists
+            .read()
+            .unwrap_or_else(std::sync::PoisonError::into_inner);
+        let mut all: Vec<Watchlist> = watchlists.values().cloned().collect();
+        drop(watchlists);
+        all.sort_by(|a, b| a.name.cmp(&b.name));
+        Ok(all)
+    }
+
+    async fn delete(&self, name: &str) -> Result<(), WatchlistError> {
+        let mut watchlists = self
+            .watchlists
+            .write()
+            .unwrap_or_else(std::sync::PoisonError::into_inner);
+        watchlists.remove(name);
+        drop(watchlists);
+        Ok(())
+    }
+}
@@ -0,0 +1,573 @@
+//! Asset Metadata Service
+//!
+//! Caches broker asset metadata and runs pre-trade checks that depend on it,
+//! so plans touching untradeable names are rejected before any order in the
+//! plan reaches the broker. The full asset list is reloaded daily; symbols
+//! missing from the cache fall back to a per-symbol lookup.
+
+use std::collections::HashMap;
+use std::sync::{Arc, RwLock};
+use std::time::{Duration, Instant};
+
+use rust_decimal::Decimal;
+use tokio_util::sync::CancellationToken;
+
+use crate::application::ports::{AssetInfo, BrokerError, BrokerPort};
+use crate::domain::order_execution::aggregate::Order;
+use crate::domain::order_execution::value_objects::OrderSide;
+use crate::domain::risk_management::value_objects::ConstraintViolation;
+use crate::domain::shared::{InstrumentId, Symbol};
+
+/// Violation code for short sells the broker cannot locate.
+pub const SHORT_NOT_AVAILABLE: &str = "SHORT_NOT_AVAILABLE";
+
+/// Violation code for inactive or non-tradable assets.
+pub const ASSET_NOT_TRADABLE: &str = "ASSET_NOT_TRADABLE";
+
+/// Violation code for fractional quantities on non-fractionable assets.
+pub const ASSET_NOT_FRACTIONABLE: &str = "ASSET_NOT_FRACTIONABLE";
+
+/// Violation code for leveraged trades in non-marginable assets.
+pub const ASSET_NOT_MARGINABLE: &str = "ASSET_NOT_MARGINABLE";
+
+/// Configuration for the asset metadata service.
+#[derive(Debug, Clone)]
+pub struct AssetMetadataConfig {
+    /// How long a cached asset lookup stays valid, and how often the full
+    /// asset list is refreshed (seconds).
+    pub cache_ttl_secs: u64,
+    /// Reject (rather than warn on) short sells of hard-to-borrow names.
+    pub reject_hard_to_borrow: bool,
+}
+
+impl Default for AssetMetadataConfig {
+    fn default() -> Self {
+        Self {
+            cache_ttl_secs: 86_400,
+            reject_hard_to_borrow: false,
+        }
+    }
+}
+
+/// Cached asset metadata with pre-trade checks.
+pub struct AssetMetadataService<B>
+where
+    B: BrokerPort,
+{
+    config: AssetMetadataConfig,
+    broker: Arc<B>,
+    cache: RwLock<HashMap<String, (Option<AssetInfo>, Instant)>>,
+}
+
+impl<B> AssetMetadataService<B>
+where
+    B: BrokerPort,
+{
+    /// Create a new service with default configuration.
+    #[must_use]
+    pub fn new(broker: Arc<B>) -> Self {
+        Self::with_config(AssetMetadataConfig::default(), broker)
+    }
+
+    /// Create with custom configuration.
+    #[must_use]
+    pub fn with_config(config: AssetMetadataConfig, broker: Arc<B>) -> Self {
+        Self {
+            config,
+            broker,
+            cache: RwLock::new(HashMap::new()),
+        }
+    }
+
+    /// Look up asset metadata, using the cache when fresh.
+    ///
+    /// # Errors
+    ///
+    /// Returns error if the broker lookup fails.
+    pub async fn asset(&self, symbol: &Symbol) -> Result<Option<AssetInfo>, BrokerError> {
+        let ttl = Duration::from_secs(self.config.cache_ttl_secs);
+        {
+            let cache = self
+                .cache
+                .read()
+                .unwrap_or_else(std::sync::PoisonError::into_inner);
+            if let Some((info, fetched_at)) = cache.get(symbol.as_str())
+                && fetched_at.elapsed() < ttl
+            {
+                return Ok(info.clone());
+            }
+        }
+
+        let info = self.broker.get_asset(symbol).await?;
+        self.cache
+            .write()
+            .unwrap_or_else(std::sync::PoisonError::into_inner)
+            .insert(symbol.as_str().to_string(), (info.clone(), Instant::now()));
+        Ok(info)
+    }
+
+    /// Reload the full asset list into the cache.
+    ///
+    /// Returns the number of assets loaded.
+    ///
+    /// # Errors
+    ///
+    /// Returns error if the broker listing fails.
+    pub async fn refresh(&self) -> Result<usize, BrokerError> {
+        let assets = self.broker.list_assets().await?;
+        let count = assets.len();
+        let now = Instant::now();
+
+        let mut cache = self
+            .cache
+            .write()
+            .unwrap_or_else(std::sync::PoisonError::into_inner);
+        for asset in assets {
+            cache.insert(asset.symbol.clone(), (Some(asset), now));
+        }
+        drop(cache);
+
+        Ok(count)
+    }
+
+    /// Check that every equity order in a plan targets a tradable asset.
+    ///
+    /// Produces `ASSET_NOT_TRADABLE` for inactive or non-tradable names,
+    /// `ASSET_NOT_FRACTIONABLE` for fractional quantities on whole-share
+    /// names, and `ASSET_NOT_MARGINABLE` for buys that exceed
+    /// `available_cash` (when known) in non-marginable names. Assets the
+    /// broker has no metadata for are skipped.
+    pub async fn check_tradability(
+        &self,
+        orders: &[Order],
+        available_cash: Option<Decimal>,
+    ) -> Vec<ConstraintViolation> {
+        let mut violations = Vec::new();
+        let mut buy_notional = Decimal::ZERO;
+
+        for (index, order) in orders.iter().enumerate() {
+            let symbol = order.symbol();
+            if !symbol.is_equity() {
+                continue;
+            }
+
+            let asset = match self.asset(symbol).await {
+                Ok(Some(asset)) => asset,
+                Ok(None) => continue,
+                Err(e) => {
+                    tracing::warn!(
+                        %symbol,
+                        error = %e,
+                        "Asset lookup failed, skipping tradability check"
+                    );
+                    continue;
+                }
+            };
+            let field_path = format!("orders[{index}]");
+            let qty = order.quantity().amount();
+
+            if !asset.active || !asset.tradable {
+                violations.push(
+                    ConstraintViolation::error(
+                        ASSET_NOT_TRADABLE,
+                        format!("{symbol} is not tradable"),
+                    )
+                    .with_instrument(symbol.as_str())
+                    .with_field_path(field_path),
+                );
+                continue;
+            }
+
+            if !qty.fract().is_zero() && !asset.fractionable {
+                violations.push(
+                    ConstraintViolation::error(
+                        ASSET_NOT_FRACTIONABLE,
+                        format!("{symbol} does not support fractional quantities"),
+                    )
+                    .with_instrument(symbol.as_str())
+                    .with_field_path(format!("{field_path}.quantity"))
+                    .with_observed(qty.to_string()),
+                );
+            }
+
+            if order.side() == OrderSide::Buy
+                && let Some(price) = order.limit_price()
+            {
+                buy_notional += qty * price.amount();
+                if let Some(cash) = available_cash
+                    && buy_notional > cash
+                    && !asset.marginable
+                {
+                    violations.push(
+                        ConstraintViolation::error(
+                            ASSET_NOT_MARGINABLE,
+                            format!("{symbol} is not marginable and the plan exceeds cash"),
+                        )
+                        .with_instrument(symbol.as_str())
+                        .with_field_path(field_path)
+                        .with_observed(buy_notional.to_string())
+                        .with_limit(cash.to_string()),
+                    );
+                }
+            }
+        }
+
+        violations
+    }
+
+    /// Check that every short sell in a plan can be located.
+    ///
+    /// A sell is treated as a short when the plan's total sell quantity for
+    /// an equity exceeds the current long position. Non-shortable names
+    /// produce an error; hard-to-borrow names produce a warning unless
+    /// `reject_hard_to_borrow` is set. Lookups that fail are logged and
+    /// skipped, leaving the final decision to the broker.
+    pub async fn check_short_availability(&self, orders: &[Order]) -> Vec<ConstraintViolation> {
+        let mut sell_qty: HashMap<&Symbol, Decimal> = HashMap::new();
+        for order in orders {
+            if order.side() == OrderSide::Sell && order.symbol().is_equity() {
+                *sell_qty.entry(order.symbol()).or_default() += order.quantity().amount();
+            }
+        }
+
+        let mut violations = Vec::new();
+        for (symbol, qty) in sell_qty {
+            let long_qty = match self
+                .broker
+                .get_position(&InstrumentId::new(symbol.as_str()))
+                .await
+            {
+                Ok(position) => position.unwrap_or_default().max(Decimal::ZERO),
+                Err(e) => {
+                    tracing::warn!(
+                        %symbol,
+                        error = %e,
+                        "Position lookup failed, skipping short check"
+                    );
+                    continue;
+                }
+            };
+            if qty <= long_qty {
+                continue;
+            }
+
+            let asset = match self.asset(symbol).await {
+                Ok(Some(asset)) => asset,
+                Ok(None) => continue,
+                Err(e) => {
+                    tracing::warn!(
+                        %symbol,
+                        error = %e,
+                        "Asset lookup failed, skipping short check"
+                    );
+                    continue;
+                }
+            };
+
+            let short_qty = qty - long_qty;
+            if !asset.marginable {
+                // Shorting always borrows on margin
+                violations.push(
+                    ConstraintViolation::error(
+                        ASSET_NOT_MARGINABLE,
+                        format!("{symbol} is not marginable and cannot be shorted"),
+                    )
+                    .with_instrument(symbol.as_str())
+                    .with_observed(short_qty.to_string()),
+                );
+            } else if !asset.shortable {
+                violations.push(
+                    ConstraintViolation::error(
+                        SHORT_NOT_AVAILABLE,
+                        format!("{symbol} is not shortable"),
+                    )
+                    .with_instrument(symbol.as_str())
+                    .with_observed(short_qty.to_string()),
+                );
+            } else if !asset.easy_to_borrow {
+                let message = format!("{symbol} is hard to borrow");
+                let violation = if self.config.reject_hard_to_borrow {
+                    ConstraintViolation::error(SHORT_NOT_AVAILABLE, message)
+                } else {
+                    ConstraintViolation::warning(SHORT_NOT_AVAILABLE, message)
+                };
+                violations.push(
+                    violation
+                        .with_instrument(symbol.as_str())
+                        .with_observed(short_qty.to_string()),
+                );
+            }
+        }
+
+        violations
+    }
+}
+
+impl<B> AssetMetadataService<B>
+where
+    B: BrokerPort + 'static,
+{
+    /// Start the periodic full refresh in a background task.
+    ///
+    /// The first refresh runs immediately.
+    pub fn start(self: &Arc<Self>, shutdown: CancellationToken) {
+        let service = Arc::clone(self);
+        let refresh_interval = Duration::from_secs(self.config.cache_ttl_secs.max(1));
+
+        tokio::spawn(async move {
+            let mut interval = tokio::time::interval(refresh_interval);
+
+            loop {
+                tokio::select! {
+                    _ = interval.tick() => {
+                        match service.refresh().await {
+                            Ok(count) => tracing::info!(count, "Asset metadata refreshed"),
+                            Err(e) => tracing::warn!(error = %e, "Asset metadata refresh failed"),
+                        }
+                    }
+                    () = shutdown.cancelled() => {
+                        tracing::info!("Asset metadata refresh shutting down");
+                        break;
+                    }
+                }
+            }
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::application::ports::{
+        CancelOrderRequest, OrderAck, PositionInfo, SubmitOrderRequest,
+    };
+    use crate::domain::order_execution::aggregate::CreateOrderCommand;
+    use crate::domain::order_execution::value_objects::{OrderPurpose, OrderType, TimeInForce};
+    use crate::domain::risk_management::value_objects::ViolationSeverity;
+    use crate::domain::shared::{BrokerId, Money, Quantity};
+    use async_trait::async_trait;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    struct MockBroker {
+        positions: HashMap<String, Decimal>,
+        assets: HashMap<String, AssetInfo>,
+        asset_lookups: AtomicUsize,
+    }
+
+    #[async_trait]
+    impl BrokerPort for MockBroker {
+        async fn submit_order(
+            &self,
+            _request: SubmitOrderRequest,
+        ) -> Result<OrderAck, BrokerError> {
+            Err(BrokerError::Unknown {
+                message: "Not implemented".to_string(),
+            })
+        }
+
+        async fn cancel_order(&self, _request: CancelOrderRequest) -> Result<(), BrokerError> {
+            Ok(())
+        }
+
+        async fn get_order(&self, broker_order_id: &BrokerId) -> Result<OrderAck, BrokerError> {
+            Err(BrokerError::OrderNotFound {
+                order_id: broker_order_id.to_string(),
+            })
+        }
+
+        async fn get_open_orders(&self) -> Result<Vec<OrderAck>, BrokerError> {
+            Ok(vec![])
+        }
+
+        async fn get_buying_power(&self) -> Result<Decimal, BrokerError> {
+            Ok(Decimal::new(100_000, 0))
+        }
+
+        async fn get_position(
+            &self,
+            instrument_id: &InstrumentId,
+        ) -> Result<Option<Decimal>, BrokerError> {
+            Ok(self.positions.get(instrument_id.as_str()).copied())
+        }
+
+        async fn get_all_positions(&self) -> Result<Vec<PositionInfo>, BrokerError> {
+            Ok(vec![])
+        }
+
+        async fn get_asset(&self, symbol: &Symbol) -> Result<Option<AssetInfo>, BrokerError> {
+            self.asset_lookups.fetch_add(1, Ordering::SeqCst);
+            Ok(self.assets.get(symbol.as_str()).cloned())
+        }
+
+        async fn list_assets(&self) -> Result<Vec<AssetInfo>, BrokerError> {
+            Ok(self.assets.values().cloned().collect())
+        }
+    }
+
+    fn asset(symbol: &str, shortable: bool, easy_to_borrow: bool) -> AssetInfo {
+        AssetInfo {
+            symbol: symbol.to_string(),
+            active: true,
+            tradable: true,
+            shortable,
+            easy_to_borrow,
+            fractionable: true,
+            marginable: true,
+        }
+    }
+
+    fn make_service(
+        positions: &[(&str, i64)],
+        assets: Vec<AssetInfo>,
+    ) -> AssetMetadataService<MockBroker> {
+        AssetMetadataService::new(Arc::new(MockBroker {
+            positions: positions
+                .iter()
+                .map(|(s, q)| ((*s).to_string(), Decimal::new(*q, 0)))
+                .collect(),
+            assets: assets.into_iter().map(|a| (a.symbol.clone(), a)).collect(),
+            asset_lookups: AtomicUsize::new(0),
+        }))
+    }
+
+    fn sell(symbol: &str, qty: i64) -> Order {
+        order(symbol, OrderSide::Sell, Decimal::new(qty, 0), None)
+    }
+
+    fn order(symbol: &str, side: OrderSide, qty: Decimal, limit_price: Option<i64>) -> Order {
+        Order::new(CreateOrderCommand {
+            symbol: Symbol::new(symbol),
+            side,
+            order_type: if limit_price.is_some() {
+                OrderType::Limit
+            } else {
+                OrderType::Market
+            },
+            quantity: Quantity::new(qty),
+            limit_price: limit_price.map(|p| Money::new(Decimal::new(p, 0))),
+            stop_price: None,
+            time_in_force: TimeInForce::Day,
+            purpose: OrderPurpose::Entry,
+            legs: vec![],
+        })
+        .unwrap()
+    }
+
+    #[tokio::test]
+    async fn closing_long_is_not_a_short() {
+        let service = make_service(&[("AAPL", 100)], vec![asset("AAPL", false, false)]);
+
+        let violations = service.check_short_availability(&[sell("AAPL", 100)]).await;
+
+        assert!(violations.is_empty());
+    }
+
+    #[tokio::test]
+    async fn non_shortable_short_is_rejected() {
+        let service = make_service(&[("XYZ", 50)], vec![asset("XYZ", false, false)]);
+
+        let violations = service.check_short_availability(&[sell("XYZ", 80)]).await;
+
+        assert_eq!(violations.len(), 1);
+        assert_eq!(violations[0].code, SHORT_NOT_AVAILABLE);
+        assert_eq!(violations[0].severity, ViolationSeverity::Error);
+        assert_eq!(violations[0].observed.as_deref(), Some("30"));
+    }
+
+    #[tokio::test]
+    async fn hard_to_borrow_short_warns() {
+        let service = make_service(&[], vec![asset("GME", true, false)]);
+
+        let violations = service.check_short_availability(&[sell("GME", 10)]).await;
+
+        assert_eq!(violations.len(), 1);
+        assert_eq!(violations[0].severity, ViolationSeverity::Warning);
+    }
+
+    #[tokio::test]
+    async fn easy_to_borrow_short_passes() {
+        let service = make_service(&[], vec![asset("SPY", true, true)]);
+
+        let violations = service.check_short_availability(&[sell("SPY", 10)]).await;
+
+        assert!(violations.is_empty());
+    }
+
+    #[tokio::test]
+    async fn asset_lookups_are_cached() {
+        let service = make_service(&[], vec![asset("SPY", true, true)]);
+
+        service.asset(&Symbol::new("SPY")).await.unwrap();
+        service.asset(&Symbol::new("SPY")).await.unwrap();
+
+        assert_eq!(service.broker.asset_lookups.load(Ordering::SeqCst), 1);
+    }
+
+    #[tokio::test]
+    async fn inactive_asset_is_not_tradable() {
+        let delisted = AssetInfo {
+            active: false,
+            ..asset("OLD", true, true)
+        };
+        let service = make_service(&[], vec![delisted]);
+
+        let violations = service
+            .check_tradability(&[order("OLD", OrderSide::Buy, Decimal::ONE, None)], None)
+            .await;
+
+        assert_eq!(violations.len(), 1);
+        assert_eq!(violations[0].code, ASSET_NOT_TRADABLE);
+        assert_eq!(violations[0].field_path.as_deref(), Some("orders[0]"));
+    }
+
+    #[tokio::test]
+    async fn fractional_qty_requires_fractionable() {
+        let whole_shares = AssetInfo {
+            fractionable: false,
+            ..asset("NVR", true, true)
+        };
+        let service = make_service(&[], vec![whole_shares]);
+
+        let fractional = order("NVR", OrderSide::Buy, Decimal::new(5, 1), None);
+        let whole = order("NVR", OrderSide::Buy, Decimal::ONE, None);
+        let violations = service.check_tradability(&[fractional, whole], None).await;
+
+        assert_eq!(violations.len(), 1);
+        assert_eq!(violations[0].code, ASSET_NOT_FRACTIONABLE);
+        assert_eq!(violations[0].observed.as_deref(), Some("0.5"));
+    }
+
+    #[tokio::test]
+    async fn leveraged_buy_requires_marginable() {
+        let cash_only = AssetInfo {
+            marginable: false,
+            ..asset("PENNY", true, true)
+        };
+        let service = make_service(&[], vec![cash_only]);
+        let buy = order("PENNY", OrderSide::Buy, Decimal::new(100, 0), Some(10));
+
+        let within_cash = service
+            .check_tradability(std::slice::from_ref(&buy), Some(Decimal::new(5_000, 0)))
+            .await;
+        let beyond_cash = service
+            .check_tradability(&[buy], Some(Decimal::new(500, 0)))
+            .await;
+
+        assert!(within_cash.is_empty());
+        assert_eq!(beyond_cash.len(), 1);
+        assert_eq!(beyond_cash[0].code, ASSET_NOT_MARGINABLE);
+    }
+
+    #[tokio::test]
+    async fn refresh_populates_cache() {
+        let service = make_service(
+            &[],
+            vec![asset("SPY", true, true), asset("QQQ", true, true)],
+        );
+
+        assert_eq!(service.refresh().await.unwrap(), 2);
+        service.asset(&Symbol::new("QQQ")).await.unwrap();
+
+        assert_eq!(service.broker.asset_lookups.load(Ordering::SeqCst), 0);
+    }
+}
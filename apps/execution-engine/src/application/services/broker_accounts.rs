@@ -0,0 +1,468 @@
+//! Broker Accounts
+//!
+//! End users connect their own broker accounts to the engine through the
+//! broker's OAuth authorization code flow. An admin requests an
+//! authorization URL for a user; the user grants access at the broker,
+//! which redirects back with a code and the one-time `state` issued with
+//! the URL; the code is exchanged for a token stored under the user.
+//!
+//! Broker adapters trading for a user ask the service for an access token
+//! before each request. Tokens close to expiry are refreshed first when the
+//! broker issued a refresh token.
+
+use std::collections::HashMap;
+use std::sync::{Arc, PoisonError};
+use std::time::Duration;
+
+use tokio::sync::Mutex;
+
+use crate::application::ports::{
+    Clock, OAuthAuthorizationPort, OAuthError, OAuthToken, OAuthTokenRepositoryPort, SystemClock,
+};
+use crate::domain::shared::{ClassifiedError, ErrorKind, Timestamp};
+
+/// Longest user ID.
+pub const MAX_USER_ID_LEN: usize = 64;
+
+/// How long an authorization URL can be completed.
+const AUTHORIZATION_TTL: Duration = Duration::from_secs(600);
+
+/// How long before expiry an access token is refreshed.
+const REFRESH_MARGIN: Duration = Duration::from_secs(60);
+
+/// Broker account error.
+#[derive(Debug, Clone, thiserror::Error)]
+pub enum BrokerAccountError {
+    /// The user ID is malformed.
+    #[error("Invalid user ID: {user_id:?}")]
+    InvalidUserId {
+        /// The rejected ID.
+        user_id: String,
+    },
+
+    /// The `state` was not issued by this engine, was already used or
+    /// expired.
+    #[error("Unknown or expired OAuth state")]
+    InvalidState,
+
+    /// The user has not connected an account.
+    #[error("No broker account connected for user {user_id}")]
+    NotConnected {
+        /// The user.
+        user_id: String,
+    },
+
+    /// OAuth server or token store failure.
+    #[error(transparent)]
+    OAuth(#[from] OAuthError),
+}
+
+impl ClassifiedError for BrokerAccountError {
+    fn code(&self) -> &str {
+        match self {
+            Self::InvalidUserId { .. } => "INVALID_USER_ID",
+            Self::InvalidState => "INVALID_OAUTH_STATE",
+            Self::NotConnected { .. } => "BROKER_ACCOUNT_NOT_CONNECTED",
+            Self::OAuth(e) => e.code(),
+        }
+    }
+
+    fn kind(&self) -> ErrorKind {
+        match self {
+            Self::InvalidUserId { .. } | Self::InvalidState => ErrorKind::InvalidArgument,
+            Self::NotConnected { .. } => ErrorKind::NotFound,
+            Self::OAuth(e) => e.kind(),
+        }
+    }
+}
+
+/// A connected broker account.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BrokerAccount {
+    /// The user the account belongs to.
+    pub user_id: String,
+    /// Granted scopes, space separated.
+    pub scope: String,
+    /// When the current token was issued.
+    pub token_obtained_at: Timestamp,
+    /// When the current access token expires (`None` if it does not).
+    pub token_expires_at: Option<Timestamp>,
+}
+
+impl BrokerAccount {
+    fn new(user_id: &str, token: &OAuthToken) -> Self {
+        Self {
+            user_id: user_id.to_string(),
+            scope: token.scope.clone(),
+            token_obtained_at: token.obtained_at,
+            token_expires_at: token.expires_at,
+        }
+    }
+}
+
+#[derive(Debug)]
+struct PendingAuthorization {
+    user_id: String,
+    expires_at: Timestamp,
+}
+
+/// Connects users' broker accounts and hands out their access tokens.
+pub struct BrokerAccountService {
+    authorizer: Arc<dyn OAuthAuthorizationPort>,
+    tokens: Arc<dyn OAuthTokenRepositoryPort>,
+    clock: Arc<dyn Clock>,
+    pending: std::sync::Mutex<HashMap<String, PendingAuthorization>>,
+    /// Serializes refreshes, so a refresh token is used once.
+    refreshes: Mutex<()>,
+}
+
+impl std::fmt::Debug for BrokerAccountService {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("BrokerAccountService")
+            .finish_non_exhaustive()
+    }
+}
+
+impl BrokerAccountService {
+    /// Create a service authorizing through `authorizer` and storing tokens
+    /// in `tokens`.
+    #[must_use]
+    pub fn new(
+        authorizer: Arc<dyn OAuthAuthorizationPort>,
+        tokens: Arc<dyn OAuthTokenRepositoryPort>,
+    ) -> Self {
+        Self {
+            authorizer,
+            tokens,
+            clock: SystemClock::shared(),
+            pending: std::sync::Mutex::new(HashMap::new()),
+            refreshes: Mutex::new(()),
+        }
+    }
+
+    /// Use `clock` instead of the system clock.
+    #[must_use]
+    pub fn with_clock(mut self, clock: Arc<dyn Clock>) -> Self {
+        self.clock = clock;
+        self
+    }
+
+    /// Start connecting the account of `user_id`, returning the URL the user
+    /// grants access at.
+    ///
+    /// # Errors
+    ///
+    /// Returns `BrokerAccountError::InvalidUserId` if the ID is malformed.
+    pub fn authorize_url(&self, user_id: &str) -> Result<String, BrokerAccountError> {
+        validate_user_id(user_id)?;
+        let state = uuid::Uuid::new_v4().simple().to_string();
+        let now = self.clock.now();
+        let expires_at = Timestamp::new(
+            now.as_datetime() + chrono::Duration::from_std(AUTHORIZATION_TTL).unwrap_or_default(),
+        );
+
+        let mut pending = self.pending.lock().unwrap_or_else(PoisonError::into_inner);
+        pending.retain(|_, p| p.expires_at > now);
+        pending.insert(
+            state.clone(),
+            PendingAuthorization {
+                user_id: user_id.to_string(),
+                expires_at,
+            },
+        );
+        drop(pending);
+        Ok(self.authorizer.authorize_url(&state))
+    }
+
+    /// Finish connecting an account with the `code` the broker redirected
+    /// back with.
+    ///
+    /// # Errors
+    ///
+    /// Returns error if `state` is unknown or expired, the broker rejects
+    /// the code, or the token cannot be stored.
+    pub async fn complete(
+        &self,
+        state: &str,
+        code: &str,
+    ) -> Result<BrokerAccount, BrokerAccountError> {
+        let now = self.clock.now();
+        let authorization = self
+            .pending
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .remove(state)
+            .filter(|p| p.expires_at > now)
+            .ok_or(BrokerAccountError::InvalidState)?;
+
+        let token = self.authorizer.exchange_code(code).await?;
+        self.tokens.save(&authorization.user_id, &token).await?;
+
+        tracing::info!(
+            target: "audit",
+            user_id = %authorization.user_id,
+            scope = %token.scope,
+            "Broker account connected"
+        );
+        Ok(BrokerAccount::new(&authorization.user_id, &token))
+    }
+
+    /// Connected accounts, by user.
+    ///
+    /// # Errors
+    ///
+    /// Returns error if the token store cannot be read.
+    pub async fn accounts(&self) -> Result<Vec<BrokerAccount>, BrokerAccountError> {
+        let mut accounts = Vec::new();
+        for user_id in self.tokens.users().await? {
+            if let Some(token) = self.tokens.find(&user_id).await? {
+                accounts.push(BrokerAccount::new(&user_id, &token));
+            }
+        }
+        Ok(accounts)
+    }
+
+    /// Forget the token of `user_id`.
+    ///
+    /// # Errors
+    ///
+    /// Returns error if the user has no connected account or the token store
+    /// cannot be written.
+    pub async fn disconnect(&self, user_id: &str) -> Result<(), BrokerAccountError> {
+        validate_user_id(user_id)?;
+        self.token(user_id).await?;
+        self.tokens.delete(user_id).await?;
+        tracing::info!(target: "audit", user_id = %user_id, "Broker account disconnected");
+        Ok(())
+    }
+
+    /// A current access token for `user_id`, refreshed first if it is about
+    /// to expire.
+    ///
+    /// # Errors
+    ///
+    /// Returns error if the user has no connected account, or the token has
+    /// expired and cannot be refreshed.
+    pub async fn access_token(&self, user_id: &str) -> Result<String, BrokerAccountError> {
+        let token = self.token(user_id).await?;
+        if !self.expiring(&token) {
+            return Ok(token.access_token);
+        }
+
+        let _guard = self.refreshes.lock().await;
+        // Another request may have refreshed the token while this one waited
+        let token = self.token(user_id).await?;
+        if !self.expiring(&token) {
+            return Ok(token.access_token);
+        }
+        let Some(refresh_token) = token.refresh_token.clone() else {
+            return Ok(token.access_token);
+        };
+
+        match self.authorizer.refresh(&refresh_token).await {
+            Ok(mut refreshed) => {
+                refreshed.refresh_token.get_or_insert(refresh_token);
+                self.tokens.save(user_id, &refreshed).await?;
+                tracing::info!(user_id = %user_id, "Broker account token refreshed");
+                Ok(refreshed.access_token)
+            }
+            Err(e) if token.expires_at.is_some_and(|at| at > self.clock.now()) => {
+                tracing::warn!(
+                    user_id = %user_id,
+                    error = %e,
+                    "Failed to refresh broker account token, using it until it expires"
+                );
+                Ok(token.access_token)
+            }
+            Err(e) => Err(e.into()),
+        }
+    }
+
+    async fn token(&self, user_id: &str) -> Result<OAuthToken, BrokerAccountError> {
+        self.tokens
+            .find(user_id)
+            .await?
+            .ok_or_else(|| BrokerAccountError::NotConnected {
+                user_id: user_id.to_string(),
+            })
+    }
+
+    fn expiring(&self, token: &OAuthToken) -> bool {
+        let margin = chrono::Duration::from_std(REFRESH_MARGIN).unwrap_or_default();
+        token
+            .expires_at
+            .is_some_and(|at| at.as_datetime() <= self.clock.now().as_datetime() + margin)
+    }
+}
+
+/// User IDs name token files, so only ASCII letters, digits, `-` and `_`
+/// are allowed.
+fn validate_user_id(user_id: &str) -> Result<(), BrokerAccountError> {
+    let valid = !user_id.is_empty()
+        && user_id.len() <= MAX_USER_ID_LEN
+        && user_id
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_');
+    if valid {
+        Ok(())
+    } else {
+        Err(BrokerAccountError::InvalidUserId {
+            user_id: user_id.to_string(),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::atomic::{AtomicU32, Ordering};
+
+    use async_trait::async_trait;
+
+    use super::*;
+    use crate::application::ports::{InMemoryOAuthTokenRepository, SimulatedClock};
+
+    fn now() -> Timestamp {
+        Timestamp::parse("2026-03-02T15:00:00Z").unwrap()
+    }
+
+    fn minutes(offset: i64) -> Timestamp {
+        Timestamp::new(now().as_datetime() + chrono::Duration::minutes(offset))
+    }
+
+    /// Issues `access-N` tokens valid until `30 * N` minutes past `now()`.
+    #[derive(Default)]
+    struct FakeAuthorizer {
+        issued: AtomicU32,
+        reject_refresh: bool,
+    }
+
+    impl FakeAuthorizer {
+        fn issue(&self) -> OAuthToken {
+            let n = self.issued.fetch_add(1, Ordering::SeqCst) + 1;
+            OAuthToken {
+                access_token: format!("access-{n}"),
+                refresh_token: (n == 1).then(|| "refresh".to_string()),
+                scope: "trading".to_string(),
+                obtained_at: now(),
+                expires_at: Some(minutes(30 * i64::from(n))),
+            }
+        }
+    }
+
+    #[async_trait]
+    impl OAuthAuthorizationPort for FakeAuthorizer {
+        fn authorize_url(&self, state: &str) -> String {
+            format!("https://broker.test/authorize?state={state}")
+        }
+
+        async fn exchange_code(&self, code: &str) -> Result<OAuthToken, OAuthError> {
+            if code == "good-code" {
+                Ok(self.issue())
+            } else {
+                Err(OAuthError::Rejected {
+                    message: "invalid_grant".to_string(),
+                })
+            }
+        }
+
+        async fn refresh(&self, refresh_token: &str) -> Result<OAuthToken, OAuthError> {
+            if self.reject_refresh || refresh_token != "refresh" {
+                return Err(OAuthError::Rejected {
+                    message: "invalid_grant".to_string(),
+                });
+            }
+            Ok(self.issue())
+        }
+    }
+
+    fn service(
+        authorizer: FakeAuthorizer,
+    ) -> (
+        BrokerAccountService,
+        Arc<SimulatedClock>,
+        Arc<InMemoryOAuthTokenRepository>,
+    ) {
+        let clock = Arc::new(SimulatedClock::new(now()));
+        let tokens = Arc::new(InMemoryOAuthTokenRepository::new());
+        let service = BrokerAccountService::new(
+            Arc::new(authorizer),
+            Arc::clone(&tokens) as Arc<dyn OAuthTokenRepositoryPort>,
+        )
+        .with_clock(Arc::clone(&clock) as Arc<dyn Clock>);
+        (service, clock, tokens)
+    }
+
+    fn state_of(url: &str) -> String {
+        url.split("state=").nth(1).unwrap().to_string()
+    }
+
+    #[tokio::test]
+    async fn connects_an_account_once_per_state() {
+        let (accounts, _, _) = service(FakeAuthorizer::default());
+
+        assert!(matches!(
+            accounts.authorize_url("../alice"),
+            Err(BrokerAccountError::InvalidUserId { .. })
+        ));
+        let state = state_of(&accounts.authorize_url("alice").unwrap());
+
+        let account = accounts.complete(&state, "good-code").await.unwrap();
+        assert_eq!(account.user_id, "alice");
+        assert_eq!(accounts.access_token("alice").await.unwrap(), "access-1");
+        assert!(matches!(
+            accounts.complete(&state, "good-code").await,
+            Err(BrokerAccountError::InvalidState)
+        ));
+
+        accounts.disconnect("alice").await.unwrap();
+        assert!(accounts.accounts().await.unwrap().is_empty());
+        assert!(matches!(
+            accounts.access_token("alice").await,
+            Err(BrokerAccountError::NotConnected { .. })
+        ));
+    }
+
+    #[tokio::test]
+    async fn authorization_expires() {
+        let (accounts, clock, _) = service(FakeAuthorizer::default());
+        let state = state_of(&accounts.authorize_url("alice").unwrap());
+
+        clock.set(minutes(11));
+        assert!(matches!(
+            accounts.complete(&state, "good-code").await,
+            Err(BrokerAccountError::InvalidState)
+        ));
+    }
+
+    #[tokio::test]
+    async fn expiring_tokens_are_refreshed() {
+        let (accounts, clock, tokens) = service(FakeAuthorizer::default());
+        let state = state_of(&accounts.authorize_url("alice").unwrap());
+        accounts.complete(&state, "good-code").await.unwrap();
+
+        clock.set(minutes(29));
+        assert_eq!(accounts.access_token("alice").await.unwrap(), "access-2");
+        // The refresh token is kept when the server does not rotate it
+        let stored = tokens.find("alice").await.unwrap().unwrap();
+        assert_eq!(stored.refresh_token.as_deref(), Some("refresh"));
+        assert_eq!(accounts.access_token("alice").await.unwrap(), "access-2");
+    }
+
+    #[tokio::test]
+    async fn failed_refresh_uses_token_until_expiry() {
+        let (accounts, clock, _) = service(FakeAuthorizer {
+            reject_refresh: true,
+            ..FakeAuthorizer::default()
+        });
+        let state = state_of(&accounts.authorize_url("alice").unwrap());
+        accounts.complete(&state, "good-code").await.unwrap();
+
+        clock.set(minutes(29));
+        assert_eq!(accounts.access_token("alice").await.unwrap(), "access-1");
+        clock.set(minutes(31));
+        assert!(matches!(
+            accounts.access_token("alice").await,
+            Err(BrokerAccountError::OAuth(OAuthError::Rejected { .. }))
+        ));
+    }
+}
@@ -0,0 +1,258 @@
+//! Candle Aggregator
+//!
+//! Builds 1-minute and 5-minute OHLCV bars from the live trade feed so
+//! tactics and clients can read intraday bars without a REST bar fetch.
+//! Trades are bucketed by their own timestamp; a bar is complete once the
+//! clock has passed its end, even if no later trade arrived to roll it.
+//! Trades for a bar that has already rolled over are dropped.
+
+use std::collections::{HashMap, VecDeque};
+use std::sync::{Arc, PoisonError, RwLock};
+
+use chrono::DateTime;
+use rust_decimal::Decimal;
+
+use crate::application::ports::{BarTimeframe, Candle, Clock, SystemClock};
+use crate::domain::shared::Timestamp;
+
+/// Configuration for the candle aggregator.
+#[derive(Debug, Clone)]
+pub struct CandleAggregatorConfig {
+    /// Completed bars kept per symbol and timeframe.
+    pub history: usize,
+}
+
+impl Default for CandleAggregatorConfig {
+    fn default() -> Self {
+        // One regular session of 1-minute bars
+        Self { history: 390 }
+    }
+}
+
+#[derive(Debug, Default)]
+struct Series {
+    completed: VecDeque<Candle>,
+    forming: Option<FormingBar>,
+}
+
+#[derive(Debug)]
+struct FormingBar {
+    candle: Candle,
+    notional: Decimal,
+}
+
+impl FormingBar {
+    fn new(symbol: &str, timeframe: BarTimeframe, start: Timestamp, price: Decimal) -> Self {
+        Self {
+            candle: Candle {
+                symbol: symbol.to_string(),
+                timeframe,
+                start,
+                open: price,
+                high: price,
+                low: price,
+                close: price,
+                volume: 0,
+                vwap: price,
+                trade_count: 0,
+            },
+            notional: Decimal::ZERO,
+        }
+    }
+
+    fn add(&mut self, price: Decimal, size: i64) {
+        let candle = &mut self.candle;
+        candle.high = candle.high.max(price);
+        candle.low = candle.low.min(price);
+        candle.close = price;
+        candle.volume += size;
+        candle.trade_count += 1;
+        self.notional += price * Decimal::from(size);
+        if candle.volume > 0 {
+            candle.vwap = self.notional / Decimal::from(candle.volume);
+        }
+    }
+}
+
+/// Live intraday bars by symbol.
+#[derive(Debug)]
+pub struct CandleAggregator {
+    config: CandleAggregatorConfig,
+    clock: Arc<dyn Clock>,
+    series: RwLock<HashMap<(String, BarTimeframe), Series>>,
+}
+
+impl CandleAggregator {
+    /// Create an aggregator with no bars.
+    #[must_use]
+    pub fn new(config: CandleAggregatorConfig) -> Self {
+        Self {
+            config,
+            clock: SystemClock::shared(),
+            series: RwLock::new(HashMap::new()),
+        }
+    }
+
+    /// Use `clock` to decide when a bar is complete.
+    #[must_use]
+    pub fn with_clock(mut self, clock: Arc<dyn Clock>) -> Self {
+        self.clock = clock;
+        self
+    }
+
+    /// Add a trade to the bars of every timeframe.
+    ///
+    /// Returns false if the trade was dropped because it has no positive
+    /// price or arrived after its bar rolled over.
+    pub fn record_trade(&self, symbol: &str, price: Decimal, size: i64, at: Timestamp) -> bool {
+        if price <= Decimal::ZERO || size < 0 {
+            return false;
+        }
+
+        let mut series = self.series.write().unwrap_or_else(PoisonError::into_inner);
+        // The shortest timeframe rolls first, so it decides what is late
+        let shortest = BarTimeframe::OneMinute;
+        let late = series
+            .get(&(symbol.to_string(), shortest))
+            .and_then(|entry| entry.forming.as_ref())
+            .is_some_and(|bar| bar.candle.start > bar_start(at, shortest));
+        if late {
+            return false;
+        }
+
+        for timeframe in BarTimeframe::ALL {
+            let start = bar_start(at, timeframe);
+            let entry = series.entry((symbol.to_string(), timeframe)).or_default();
+            match &mut entry.forming {
+                Some(bar) if bar.candle.start == start => bar.add(price, size),
+                forming => {
+                    if let Some(done) = forming.take() {
+                        entry.completed.push_back(done.candle);
+                        while entry.completed.len() > self.config.history {
+                            entry.completed.pop_front();
+                        }
+                    }
+                    let mut bar = FormingBar::new(symbol, timeframe, start, price);
+                    bar.add(price, size);
+                    *forming = Some(bar);
+                }
+            }
+        }
+        drop(series);
+        true
+    }
+
+    /// Up to `limit` of the latest completed bars of `symbol`, oldest first.
+    #[must_use]
+    pub fn bars(&self, symbol: &str, timeframe: BarTimeframe, limit: usize) -> Vec<Candle> {
+        let now = self.clock.now().unix_seconds();
+        let series = self.series.read().unwrap_or_else(PoisonError::into_inner);
+        let Some(entry) = series.get(&(symbol.to_string(), timeframe)) else {
+            return vec![];
+        };
+
+        let forming = entry
+            .forming
+            .as_ref()
+            .map(|bar| &bar.candle)
+            .filter(|candle| candle.start.unix_seconds() + timeframe.seconds() <= now);
+        let completed: Vec<&Candle> = entry.completed.iter().chain(forming).collect();
+        let skip = completed
+            .len()
+            .saturating_sub(limit.min(self.config.history));
+        let bars = completed.into_iter().skip(skip).cloned().collect();
+        drop(series);
+        bars
+    }
+
+    /// Forget the bars of symbols not in `keep`.
+    pub fn retain(&self, keep: &[String]) {
+        self.series
+            .write()
+            .unwrap_or_else(PoisonError::into_inner)
+            .retain(|(symbol, _), _| keep.contains(symbol));
+    }
+}
+
+/// Open time of the `timeframe` bar containing `at`.
+fn bar_start(at: Timestamp, timeframe: BarTimeframe) -> Timestamp {
+    let seconds = at.unix_seconds();
+    let start = seconds - seconds.rem_euclid(timeframe.seconds());
+    DateTime::from_timestamp(start, 0).map_or(at, Timestamp::new)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::application::ports::SimulatedClock;
+
+    fn at(time: &str) -> Timestamp {
+        Timestamp::parse(&format!("2026-03-02T{time}Z")).unwrap()
+    }
+
+    fn aggregator(now: &str) -> (CandleAggregator, Arc<SimulatedClock>) {
+        let clock = Arc::new(SimulatedClock::new(at(now)));
+        let aggregator = CandleAggregator::new(CandleAggregatorConfig { history: 3 })
+            .with_clock(Arc::clone(&clock) as Arc<dyn Clock>);
+        (aggregator, clock)
+    }
+
+    #[test]
+    fn builds_one_and_five_minute_bars() {
+        let (candles, clock) = aggregator("15:00:00");
+        candles.record_trade("AAPL", Decimal::new(100, 0), 100, at("14:30:05"));
+        candles.record_trade("AAPL", Decimal::new(102, 0), 100, at("14:30:40"));
+        candles.record_trade("AAPL", Decimal::new(99, 0), 200, at("14:30:59"));
+        candles.record_trade("AAPL", Decimal::new(101, 0), 100, at("14:31:10"));
+
+        let bars = candles.bars("AAPL", BarTimeframe::OneMinute, 10);
+        assert_eq!(bars.len(), 2);
+        let first = &bars[0];
+        assert_eq!(first.start, at("14:30:00"));
+        assert_eq!(
+            (first.open, first.high, first.low, first.close),
+            (
+                Decimal::new(100, 0),
+                Decimal::new(102, 0),
+                Decimal::new(99, 0),
+                Decimal::new(99, 0)
+            )
+        );
+        assert_eq!(first.volume, 400);
+        assert_eq!(first.trade_count, 3);
+        assert_eq!(first.vwap, Decimal::new(100, 0));
+
+        let five = candles.bars("AAPL", BarTimeframe::FiveMinutes, 10);
+        assert_eq!(five.len(), 1);
+        assert_eq!(five[0].start, at("14:30:00"));
+        assert_eq!(five[0].volume, 500);
+
+        // The forming bar is only served once its minute is over
+        clock.set(at("14:31:30"));
+        assert_eq!(candles.bars("AAPL", BarTimeframe::OneMinute, 10).len(), 1);
+        assert!(
+            candles
+                .bars("AAPL", BarTimeframe::FiveMinutes, 10)
+                .is_empty()
+        );
+    }
+
+    #[test]
+    fn keeps_history_and_drops_late_trades() {
+        let (candles, _clock) = aggregator("15:00:00");
+        for minute in 30..36 {
+            let time = format!("14:{minute}:00");
+            assert!(candles.record_trade("AAPL", Decimal::new(100, 0), 10, at(&time)));
+        }
+        assert!(!candles.record_trade("AAPL", Decimal::new(100, 0), 10, at("14:34:59")));
+        assert!(!candles.record_trade("AAPL", Decimal::ZERO, 10, at("14:35:30")));
+
+        let bars = candles.bars("AAPL", BarTimeframe::OneMinute, 10);
+        let starts: Vec<Timestamp> = bars.iter().map(|b| b.start).collect();
+        assert_eq!(starts, [at("14:33:00"), at("14:34:00"), at("14:35:00")]);
+        assert_eq!(candles.bars("AAPL", BarTimeframe::OneMinute, 1).len(), 1);
+
+        candles.retain(&[]);
+        assert!(candles.bars("AAPL", BarTimeframe::OneMinute, 10).is_empty());
+    }
+}
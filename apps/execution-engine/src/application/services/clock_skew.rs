@@ -0,0 +1,236 @@
+//! Clock Skew Monitor
+//!
+//! Order aging, protection windows and PDT day boundaries all assume the
+//! host clock is right. The monitor measures the host clock against a
+//! reference time source at startup and periodically, and raises an audit
+//! alert when the offset exceeds the threshold. With a kill switch attached
+//! (LIVE only, when configured) it also trips the switch, which stays
+//! tripped until an operator resets it after fixing time synchronization.
+
+use std::sync::{Arc, PoisonError, RwLock};
+use std::time::Duration;
+
+use serde::Serialize;
+use tokio_util::sync::CancellationToken;
+
+use super::KillSwitch;
+use crate::application::ports::{Clock, SystemClock, TimeSourcePort};
+use crate::domain::shared::Timestamp;
+
+/// Clock skew limits.
+#[derive(Debug, Clone)]
+pub struct ClockSkewConfig {
+    /// Largest tolerated offset from the reference clock.
+    pub max_skew: Duration,
+    /// Time between checks after the startup check.
+    pub interval: Duration,
+}
+
+impl Default for ClockSkewConfig {
+    fn default() -> Self {
+        Self {
+            max_skew: Duration::from_millis(500),
+            interval: Duration::from_secs(600),
+        }
+    }
+}
+
+/// Outcome of the latest clock check.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct ClockSyncStatus {
+    /// When the check ran.
+    pub checked_at: Timestamp,
+    /// Reference clock that answered.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub source: Option<String>,
+    /// Reference time minus host time, in milliseconds.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub offset_ms: Option<i64>,
+    /// Whether the offset exceeded the threshold.
+    pub skewed: bool,
+    /// Why the offset could not be measured.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+}
+
+/// Periodically checks the host clock against a reference time source.
+pub struct ClockSkewMonitor {
+    source: Arc<dyn TimeSourcePort>,
+    config: ClockSkewConfig,
+    kill_switch: Option<Arc<KillSwitch>>,
+    clock: Arc<dyn Clock>,
+    status: RwLock<Option<ClockSyncStatus>>,
+}
+
+impl std::fmt::Debug for ClockSkewMonitor {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ClockSkewMonitor")
+            .field("config", &self.config)
+            .finish_non_exhaustive()
+    }
+}
+
+impl ClockSkewMonitor {
+    /// Create a monitor measuring against `source`.
+    #[must_use]
+    pub fn new(source: Arc<dyn TimeSourcePort>, config: ClockSkewConfig) -> Self {
+        Self {
+            source,
+            config,
+            kill_switch: None,
+            clock: SystemClock::shared(),
+            status: RwLock::new(None),
+        }
+    }
+
+    /// Trip `kill_switch` when the clock is skewed.
+    #[must_use]
+    pub fn with_kill_switch(mut self, kill_switch: Arc<KillSwitch>) -> Self {
+        self.kill_switch = Some(kill_switch);
+        self
+    }
+
+    /// Use `clock` to stamp checks.
+    #[must_use]
+    pub fn with_clock(mut self, clock: Arc<dyn Clock>) -> Self {
+        self.clock = clock;
+        self
+    }
+
+    /// Outcome of the latest check, if one has run.
+    #[must_use]
+    pub fn status(&self) -> Option<ClockSyncStatus> {
+        self.status
+            .read()
+            .unwrap_or_else(PoisonError::into_inner)
+            .clone()
+    }
+
+    /// Measure the offset now, alerting (and halting) if it is too large.
+    pub async fn check(&self) -> ClockSyncStatus {
+        let checked_at = self.clock.now();
+        let max_skew_ms = i64::try_from(self.config.max_skew.as_millis()).unwrap_or(i64::MAX);
+
+        let status = match self.source.offset().await {
+            Ok(offset) => {
+                let skewed = offset.offset_ms.abs() > max_skew_ms;
+                if skewed {
+                    tracing::error!(
+                        target: "audit",
+                        source = %offset.source,
+                        offset_ms = offset.offset_ms,
+                        max_skew_ms,
+                        "Host clock skew exceeds threshold"
+                    );
+                    if let Some(kill_switch) = self.kill_switch.as_ref().filter(|k| !k.is_tripped())
+                    {
+                        kill_switch.trip(format!(
+                            "host clock is {}ms off {} (limit {max_skew_ms}ms)",
+                            offset.offset_ms, offset.source
+                        ));
+                    }
+                } else {
+                    tracing::debug!(
+                        source = %offset.source,
+                        offset_ms = offset.offset_ms,
+                        round_trip_ms = offset.round_trip_ms,
+                        "Host clock offset measured"
+                    );
+                }
+                ClockSyncStatus {
+                    checked_at,
+                    source: Some(offset.source),
+                    offset_ms: Some(offset.offset_ms),
+                    skewed,
+                    error: None,
+                }
+            }
+            Err(e) => {
+                tracing::warn!(error = %e, "Failed to measure host clock offset");
+                ClockSyncStatus {
+                    checked_at,
+                    source: None,
+                    offset_ms: None,
+                    skewed: false,
+                    error: Some(e.to_string()),
+                }
+            }
+        };
+
+        let previous = self
+            .status
+            .write()
+            .unwrap_or_else(PoisonError::into_inner)
+            .replace(status.clone());
+        if previous.is_some_and(|p| p.skewed) && status.offset_ms.is_some() && !status.skewed {
+            tracing::warn!(
+                target: "audit",
+                offset_ms = status.offset_ms,
+                "Host clock skew back within threshold"
+            );
+        }
+        status
+    }
+
+    /// Check every `interval` until shutdown.
+    pub fn start(self: &Arc<Self>, shutdown: CancellationToken) {
+        let monitor = Arc::clone(self);
+        tokio::spawn(async move {
+            loop {
+                tokio::select! {
+                    () = tokio::time::sleep(monitor.config.interval) => {
+                        monitor.check().await;
+                    }
+                    () = shutdown.cancelled() => break,
+                }
+            }
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::application::ports::FixedTimeSource;
+
+    #[tokio::test]
+    async fn skew_alerts_and_trips_kill_switch() {
+        let source = Arc::new(FixedTimeSource::new(120));
+        let kill_switch = Arc::new(KillSwitch::default());
+        let monitor = ClockSkewMonitor::new(
+            Arc::clone(&source) as Arc<dyn TimeSourcePort>,
+            ClockSkewConfig::default(),
+        )
+        .with_kill_switch(Arc::clone(&kill_switch));
+
+        let status = monitor.check().await;
+        assert_eq!(status.offset_ms, Some(120));
+        assert!(!status.skewed);
+        assert!(!kill_switch.is_tripped());
+
+        source.set(-1_500);
+        assert!(monitor.check().await.skewed);
+        assert!(kill_switch.status().unwrap().contains("-1500ms"));
+
+        // Trading stays halted once the clock recovers
+        source.set(10);
+        assert!(!monitor.check().await.skewed);
+        assert!(kill_switch.is_tripped());
+    }
+
+    #[tokio::test]
+    async fn unavailable_source_is_reported_without_halting() {
+        let source = Arc::new(FixedTimeSource::new(0));
+        source.fail("no reply");
+        let kill_switch = Arc::new(KillSwitch::default());
+        let monitor = ClockSkewMonitor::new(source, ClockSkewConfig::default())
+            .with_kill_switch(Arc::clone(&kill_switch));
+
+        assert!(monitor.status().is_none());
+        let status = monitor.check().await;
+        assert!(!status.skewed);
+        assert!(status.error.unwrap().contains("no reply"));
+        assert_eq!(monitor.status().unwrap().offset_ms, None);
+        assert!(!kill_switch.is_tripped());
+    }
+}
@@ -0,0 +1,709 @@
+//! Delta Hedger
+//!
+//! Optional service that keeps the delta of enabled underlyings inside a
+//! band by trading the underlying. Each pass reads broker positions, values
+//! option deltas off the underlying's option chain (provider Greeks, or
+//! Black-Scholes when missing), and submits a market order in the
+//! underlying when the net share-equivalent delta leaves the band.
+//!
+//! Every hedge attempt is logged on the `audit` target and kept in a
+//! bounded history. Hedges are skipped while the kill switch is tripped and
+//! for underlyings holding an option whose delta cannot be determined, and
+//! passes are skipped while the `auto_hedging` feature flag is off.
+
+use std::collections::{HashMap, VecDeque};
+use std::sync::{Arc, PoisonError, RwLock};
+use std::time::Duration;
+
+use rust_decimal::Decimal;
+use rust_decimal::prelude::Signed;
+use tokio_util::sync::CancellationToken;
+
+use super::{DEFAULT_RISK_FREE_RATE, Feature, FeatureFlags, KillSwitch, chain_options};
+use crate::application::ports::{
+    BrokerPort, Clock, MarketDataError, MarketDataPort, PositionInfo, SubmitOrderRequest,
+    SystemClock,
+};
+use crate::domain::order_execution::value_objects::OrderSide;
+use crate::domain::shared::{BrokerId, OrderId, Symbol, Timestamp};
+
+/// How far a hedge trades when delta leaves the band.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum HedgeSizing {
+    /// Trade delta all the way back to the target.
+    #[default]
+    ToTarget,
+    /// Trade delta back to the nearest edge of the band.
+    ToBand,
+}
+
+/// Hedging rule for one underlying.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct HedgeRule {
+    /// Allowed distance from the target, in shares of delta.
+    pub band: Decimal,
+    /// Target delta in shares.
+    pub target: Decimal,
+    /// Hedge sizing.
+    pub sizing: HedgeSizing,
+    /// Largest single hedge order in shares (`None` = unlimited).
+    pub max_shares: Option<Decimal>,
+}
+
+impl HedgeRule {
+    /// Hedge back to delta-neutral once delta exceeds `band` shares.
+    #[must_use]
+    pub const fn with_band(band: Decimal) -> Self {
+        Self {
+            band,
+            target: Decimal::ZERO,
+            sizing: HedgeSizing::ToTarget,
+            max_shares: None,
+        }
+    }
+
+    /// Signed share quantity that brings `delta` back within the rule
+    /// (positive = buy), or `None` if no hedge is needed.
+    #[must_use]
+    pub fn hedge_quantity(&self, delta: Decimal) -> Option<Decimal> {
+        let deviation = delta - self.target;
+        if deviation.abs() <= self.band {
+            return None;
+        }
+
+        let excess = match self.sizing {
+            HedgeSizing::ToTarget => deviation,
+            HedgeSizing::ToBand => deviation - self.band * deviation.signum(),
+        };
+        let mut shares = excess.abs().trunc();
+        if let Some(max) = self.max_shares {
+            shares = shares.min(max);
+        }
+        if shares.is_zero() {
+            return None;
+        }
+        Some(if excess.is_sign_positive() {
+            -shares
+        } else {
+            shares
+        })
+    }
+}
+
+/// Configuration for the delta hedger.
+#[derive(Debug, Clone)]
+pub struct DeltaHedgerConfig {
+    /// Hedged underlyings and their rules; others are left alone.
+    pub underlyings: HashMap<String, HedgeRule>,
+    /// Time between hedging passes.
+    pub interval: Duration,
+    /// Hedge records kept in memory.
+    pub history_limit: usize,
+}
+
+impl Default for DeltaHedgerConfig {
+    fn default() -> Self {
+        Self {
+            underlyings: HashMap::new(),
+            interval: Duration::from_secs(60),
+            history_limit: 500,
+        }
+    }
+}
+
+/// Net delta of one underlying, in shares.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DeltaExposure {
+    /// Underlying symbol.
+    pub underlying: String,
+    /// Shares of the underlying held.
+    pub share_delta: Decimal,
+    /// Share-equivalent delta of option positions.
+    pub option_delta: Decimal,
+}
+
+impl DeltaExposure {
+    /// Net delta.
+    #[must_use]
+    pub fn total(&self) -> Decimal {
+        self.share_delta + self.option_delta
+    }
+}
+
+/// Result of a hedge attempt.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum HedgeOutcome {
+    /// Order accepted by the broker.
+    Submitted {
+        /// Broker order ID.
+        broker_order_id: BrokerId,
+    },
+    /// Not sent because trading is disabled.
+    Blocked {
+        /// Kill switch reason.
+        reason: String,
+    },
+    /// Rejected or failed at the broker.
+    Failed {
+        /// Error details.
+        reason: String,
+    },
+}
+
+/// Audit record of a hedge attempt.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct HedgeRecord {
+    /// Hedged underlying.
+    pub underlying: String,
+    /// Net delta that triggered the hedge.
+    pub delta_before: Decimal,
+    /// Target delta of the rule.
+    pub target: Decimal,
+    /// Order side.
+    pub side: OrderSide,
+    /// Shares ordered.
+    pub quantity: Decimal,
+    /// Client order ID of the hedge order.
+    pub client_order_id: OrderId,
+    /// What happened.
+    pub outcome: HedgeOutcome,
+    /// When the hedge was attempted.
+    pub at: Timestamp,
+}
+
+/// Delta hedging errors.
+#[derive(Debug, thiserror::Error)]
+pub enum DeltaHedgeError {
+    /// Option deltas could not be determined.
+    #[error(transparent)]
+    MarketData(#[from] MarketDataError),
+
+    /// An option position has no delta in the chain.
+    #[error("No delta for option position {symbol}")]
+    MissingDelta {
+        /// Option symbol.
+        symbol: String,
+    },
+}
+
+/// Submits offsetting equity orders when underlying delta drifts.
+pub struct DeltaHedger<B, M>
+where
+    B: BrokerPort,
+    M: MarketDataPort,
+{
+    config: DeltaHedgerConfig,
+    broker: Arc<B>,
+    market_data: Arc<M>,
+    kill_switch: Option<Arc<KillSwitch>>,
+    feature_flags: Option<Arc<FeatureFlags>>,
+    clock: Arc<dyn Clock>,
+    risk_free_rate: f64,
+    history: RwLock<VecDeque<HedgeRecord>>,
+}
+
+impl<B, M> DeltaHedger<B, M>
+where
+    B: BrokerPort,
+    M: MarketDataPort,
+{
+    /// Create a hedger.
+    #[must_use]
+    pub fn new(config: DeltaHedgerConfig, broker: Arc<B>, market_data: Arc<M>) -> Self {
+        Self {
+            config,
+            broker,
+            market_data,
+            kill_switch: None,
+            feature_flags: None,
+            clock: SystemClock::shared(),
+            risk_free_rate: DEFAULT_RISK_FREE_RATE,
+            history: RwLock::new(VecDeque::new()),
+        }
+    }
+
+    /// Hold hedges while the kill switch is tripped.
+    #[must_use]
+    pub fn with_kill_switch(mut self, kill_switch: Arc<KillSwitch>) -> Self {
+        self.kill_switch = Some(kill_switch);
+        self
+    }
+
+    /// Skip passes while the `auto_hedging` flag is off.
+    #[must_use]
+    pub fn with_feature_flags(mut self, feature_flags: Arc<FeatureFlags>) -> Self {
+        self.feature_flags = Some(feature_flags);
+        self
+    }
+
+    /// Use `clock` to timestamp hedge records.
+    #[must_use]
+    pub fn with_clock(mut self, clock: Arc<dyn Clock>) -> Self {
+        self.clock = clock;
+        self
+    }
+
+    /// Check whether `underlying` is hedged.
+    #[must_use]
+    pub fn is_enabled(&self, underlying: &str) -> bool {
+        self.config.underlyings.contains_key(underlying)
+    }
+
+    /// Hedge attempts, oldest first.
+    #[must_use]
+    pub fn recent_hedges(&self) -> Vec<HedgeRecord> {
+        self.history
+            .read()
+            .unwrap_or_else(PoisonError::into_inner)
+            .iter()
+            .cloned()
+            .collect()
+    }
+
+    /// Net delta of `underlying` across `positions`.
+    ///
+    /// # Errors
+    ///
+    /// Returns error if the chain cannot be fetched or an option position
+    /// has no delta.
+    pub async fn exposure(
+        &self,
+        underlying: &str,
+        positions: &[PositionInfo],
+    ) -> Result<DeltaExposure, DeltaHedgeError> {
+        let share_delta = positions
+            .iter()
+            .filter(|p| p.symbol == underlying)
+            .map(|p| p.quantity)
+            .sum();
+        let options: Vec<&PositionInfo> = positions
+            .iter()
+            .filter(|p| option_underlying(&p.symbol) == Some(underlying))
+            .collect();
+
+        let mut option_delta = Decimal::ZERO;
+        if !options.is_empty() {
+            let chain = self.market_data.get_option_chain(underlying).await?;
+            let deltas: HashMap<String, (f64, u32)> = chain_options(&chain, self.risk_free_rate)
+                .into_iter()
+                .filter_map(|option| {
+                    let contract = option.contract;
+                    Some((
+                        contract.symbol().as_str().to_string(),
+                        (option.delta?, contract.multiplier()),
+                    ))
+                })
+                .collect();
+
+            for position in options {
+                let (delta, multiplier) = deltas
+                    .get(&position.symbol)
+                    .and_then(|&(delta, multiplier)| {
+                        Some((Decimal::try_from(delta).ok()?, multiplier))
+                    })
+                    .ok_or_else(|| DeltaHedgeError::MissingDelta {
+                        symbol: position.symbol.clone(),
+                    })?;
+                option_delta += position.quantity * delta * Decimal::from(multiplier);
+            }
+        }
+
+        Ok(DeltaExposure {
+            underlying: underlying.to_string(),
+            share_delta,
+            option_delta,
+        })
+    }
+
+    /// Check every enabled underlying and hedge those outside their band.
+    ///
+    /// Returns the hedges attempted in this pass.
+    pub async fn run_once(&self) -> Vec<HedgeRecord> {
+        if self.config.underlyings.is_empty()
+            || self
+                .feature_flags
+                .as_ref()
+                .is_some_and(|flags| !flags.is_enabled(Feature::AutoHedging))
+        {
+            return Vec::new();
+        }
+        let positions = match self.broker.get_all_positions().await {
+            Ok(positions) => positions,
+            Err(e) => {
+                tracing::warn!(error = %e, "Delta hedger could not read positions");
+                return Vec::new();
+            }
+        };
+
+        let mut hedges = Vec::new();
+        for (underlying, rule) in &self.config.underlyings {
+            let exposure = match self.exposure(underlying, &positions).await {
+                Ok(exposure) => exposure,
+                Err(e) => {
+                    tracing::warn!(
+                        underlying = %underlying,
+                        error = %e,
+                        "Skipping delta hedge"
+                    );
+                    continue;
+                }
+            };
+            let delta = exposure.total();
+            if let Some(quantity) = rule.hedge_quantity(delta) {
+                hedges.push(self.hedge(underlying, rule, delta, quantity).await);
+            }
+        }
+        hedges
+    }
+
+    /// Run hedging passes every interval until shutdown.
+    pub fn start(self: &Arc<Self>, shutdown: CancellationToken)
+    where
+        B: 'static,
+        M: 'static,
+    {
+        if self.config.underlyings.is_empty() {
+            return;
+        }
+
+        let hedger = Arc::clone(self);
+        let run_interval = self.config.interval.max(Duration::from_secs(1));
+
+        tokio::spawn(async move {
+            let mut interval = tokio::time::interval(run_interval);
+
+            loop {
+                tokio::select! {
+                    _ = interval.tick() => {
+                        let placed = hedger.run_once().await;
+                        if !placed.is_empty() {
+                            tracing::info!(hedges = placed.len(), "Delta hedges placed");
+                        }
+                    }
+                    () = shutdown.cancelled() => {
+                        tracing::info!("Delta hedger shutting down");
+                        break;
+                    }
+                }
+            }
+        });
+    }
+
+    async fn hedge(
+        &self,
+        underlying: &str,
+        rule: &HedgeRule,
+        delta: Decimal,
+        quantity: Decimal,
+    ) -> HedgeRecord {
+        let side = if quantity.is_sign_positive() {
+            OrderSide::Buy
+        } else {
+            OrderSide::Sell
+        };
+        let client_order_id = OrderId::generate();
+
+        let blocked = self.kill_switch.as_ref().and_then(|switch| switch.status());
+        let outcome = if let Some(reason) = blocked {
+            HedgeOutcome::Blocked { reason }
+        } else {
+            let request = SubmitOrderRequest::market(
+                client_order_id.clone(),
+                Symbol::new(underlying),
+                side,
+                quantity.abs(),
+            );
+            match self.broker.submit_order(request).await {
+                Ok(ack) => HedgeOutcome::Submitted {
+                    broker_order_id: ack.broker_order_id,
+                },
+                Err(e) => HedgeOutcome::Failed {
+                    reason: e.to_string(),
+                },
+            }
+        };
+
+        let record = HedgeRecord {
+            underlying: underlying.to_string(),
+            delta_before: delta,
+            target: rule.target,
+            side,
+            quantity: quantity.abs(),
+            client_order_id,
+            outcome,
+            at: self.clock.now(),
+        };
+        tracing::info!(
+            target: "audit",
+            underlying = %record.underlying,
+            delta_before = %record.delta_before,
+            target_delta = %record.target,
+            side = ?record.side,
+            quantity = %record.quantity,
+            client_order_id = %record.client_order_id,
+            outcome = ?record.outcome,
+            "Delta hedge"
+        );
+
+        let mut history = self.history.write().unwrap_or_else(PoisonError::into_inner);
+        history.push_back(record.clone());
+        while history.len() > self.config.history_limit {
+            history.pop_front();
+        }
+        drop(history);
+        record
+    }
+}
+
+/// Underlying of an OCC option symbol (compact or space-padded).
+fn option_underlying(symbol: &str) -> Option<&str> {
+    if !Symbol::new(symbol).is_option() {
+        return None;
+    }
+    symbol
+        .get(..symbol.len() - 15)
+        .map(str::trim_end)
+        .filter(|root| !root.is_empty())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::application::ports::{
+        BrokerError, MarketQuote, OptionChainData, OptionContract, OptionGreeks, OptionQuote,
+        OptionType, OrderAck, SimulatedClock,
+    };
+    use crate::domain::order_execution::value_objects::{Environment, OrderStatus};
+    use async_trait::async_trait;
+    use rust_decimal_macros::dec;
+    use std::sync::Mutex;
+
+    #[derive(Default)]
+    struct MockBroker {
+        positions: Vec<PositionInfo>,
+        orders: Mutex<Vec<SubmitOrderRequest>>,
+    }
+
+    #[async_trait]
+    impl BrokerPort for MockBroker {
+        async fn submit_order(&self, request: SubmitOrderRequest) -> Result<OrderAck, BrokerError> {
+            let ack = OrderAck {
+                broker_order_id: BrokerId::new("hedge-1"),
+                client_order_id: request.client_order_id.clone(),
+                status: OrderStatus::Accepted,
+                filled_qty: Decimal::ZERO,
+                avg_fill_price: None,
+                child_order_ids: Vec::new(),
+                leg_fills: Vec::new(),
+            };
+            self.orders.lock().unwrap().push(request);
+            Ok(ack)
+        }
+
+        async fn cancel_order(
+            &self,
+            _request: crate::application::ports::CancelOrderRequest,
+        ) -> Result<(), BrokerError> {
+            Ok(())
+        }
+
+        async fn get_order(&self, broker_order_id: &BrokerId) -> Result<OrderAck, BrokerError> {
+            Err(BrokerError::OrderNotFound {
+                order_id: broker_order_id.to_string(),
+            })
+        }
+
+        async fn get_open_orders(&self) -> Result<Vec<OrderAck>, BrokerError> {
+            Ok(vec![])
+        }
+
+        async fn get_buying_power(&self) -> Result<Decimal, BrokerError> {
+            Ok(dec!(100000))
+        }
+
+        async fn get_position(
+            &self,
+            _instrument_id: &crate::domain::shared::InstrumentId,
+        ) -> Result<Option<Decimal>, BrokerError> {
+            Ok(None)
+        }
+
+        async fn get_all_positions(&self) -> Result<Vec<PositionInfo>, BrokerError> {
+            Ok(self.positions.clone())
+        }
+    }
+
+    fn now() -> Timestamp {
+        Timestamp::parse("2025-02-03T15:00:00Z").unwrap()
+    }
+
+    struct MockMarketData;
+
+    #[async_trait]
+    impl MarketDataPort for MockMarketData {
+        async fn get_quotes(
+            &self,
+            _symbols: &[String],
+        ) -> Result<Vec<MarketQuote>, MarketDataError> {
+            Ok(vec![])
+        }
+
+        async fn get_option_chain(
+            &self,
+            underlying: &str,
+        ) -> Result<OptionChainData, MarketDataError> {
+            Ok(OptionChainData {
+                underlying: underlying.to_string(),
+                underlying_price: dec!(500),
+                options: vec![OptionQuote {
+                    contract: OptionContract {
+                        underlying: underlying.to_string(),
+                        expiration: "2025-02-21".to_string(),
+                        strike: dec!(500),
+                        option_type: OptionType::Call,
+                    },
+                    quote: Some(MarketQuote {
+                        symbol: "SPY250221C00500000".to_string(),
+                        bid: dec!(9.9),
+                        ask: dec!(10.1),
+                        bid_size: 1,
+                        ask_size: 1,
+                        last: dec!(10),
+                        last_size: 1,
+                        volume: 1,
+                        timestamp: now(),
+                    }),
+                    implied_volatility: None,
+                    greeks: Some(OptionGreeks {
+                        delta: Some(0.5),
+                        gamma: None,
+                        theta: None,
+                        vega: None,
+                        rho: None,
+                    }),
+                    open_interest: 0,
+                }],
+                as_of: now(),
+            })
+        }
+    }
+
+    fn position(symbol: &str, quantity: Decimal) -> PositionInfo {
+        PositionInfo {
+            symbol: symbol.to_string(),
+            quantity,
+            avg_entry_price: Decimal::ZERO,
+            market_value: Decimal::ZERO,
+            unrealized_pnl: Decimal::ZERO,
+            current_price: Decimal::ZERO,
+            opened_at: None,
+        }
+    }
+
+    fn hedger(
+        positions: Vec<PositionInfo>,
+        rule: HedgeRule,
+    ) -> DeltaHedger<MockBroker, MockMarketData> {
+        let config = DeltaHedgerConfig {
+            underlyings: HashMap::from([("SPY".to_string(), rule)]),
+            ..DeltaHedgerConfig::default()
+        };
+        let broker = MockBroker {
+            positions,
+            ..MockBroker::default()
+        };
+        DeltaHedger::new(config, Arc::new(broker), Arc::new(MockMarketData))
+            .with_clock(Arc::new(SimulatedClock::new(now())))
+    }
+
+    #[test]
+    fn hedge_quantity_follows_sizing() {
+        let mut rule = HedgeRule::with_band(dec!(50));
+        assert_eq!(rule.hedge_quantity(dec!(40)), None);
+        assert_eq!(rule.hedge_quantity(dec!(120.7)), Some(dec!(-120)));
+        assert_eq!(rule.hedge_quantity(dec!(-80)), Some(dec!(80)));
+
+        rule.sizing = HedgeSizing::ToBand;
+        assert_eq!(rule.hedge_quantity(dec!(120)), Some(dec!(-70)));
+
+        rule.max_shares = Some(dec!(25));
+        assert_eq!(rule.hedge_quantity(dec!(-200)), Some(dec!(25)));
+    }
+
+    #[test]
+    fn option_underlying_parses_occ_symbols() {
+        assert_eq!(option_underlying("SPY250221C00500000"), Some("SPY"));
+        assert_eq!(option_underlying("AAPL  240119P00150000"), Some("AAPL"));
+        assert_eq!(option_underlying("SPY"), None);
+    }
+
+    #[tokio::test]
+    async fn hedges_option_delta_outside_band() {
+        // Long 4 calls at 0.5 delta = 200 shares, less 50 shares held
+        let hedger = hedger(
+            vec![
+                position("SPY250221C00500000", dec!(4)),
+                position("SPY", dec!(50)),
+                position("QQQ", dec!(1000)),
+            ],
+            HedgeRule::with_band(dec!(100)),
+        );
+
+        let records = hedger.run_once().await;
+
+        assert_eq!(records.len(), 1);
+        assert_eq!(records[0].delta_before, dec!(250));
+        assert_eq!(records[0].side, OrderSide::Sell);
+        assert_eq!(records[0].at, now());
+        assert_eq!(records[0].quantity, dec!(250));
+        assert!(matches!(records[0].outcome, HedgeOutcome::Submitted { .. }));
+        assert_eq!(hedger.recent_hedges(), records);
+        assert_eq!(
+            hedger.broker.orders.lock().unwrap()[0].symbol.as_str(),
+            "SPY"
+        );
+    }
+
+    #[tokio::test]
+    async fn blocks_hedges_while_kill_switch_tripped() {
+        let kill_switch = Arc::new(KillSwitch::default());
+        kill_switch.trip("test");
+        let hedger = hedger(
+            vec![position("SPY", dec!(-500))],
+            HedgeRule::with_band(dec!(100)),
+        )
+        .with_kill_switch(kill_switch);
+
+        let records = hedger.run_once().await;
+
+        assert!(matches!(records[0].outcome, HedgeOutcome::Blocked { .. }));
+        assert!(hedger.broker.orders.lock().unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn skips_passes_while_auto_hedging_is_off() {
+        let flags = Arc::new(FeatureFlags::new(Environment::Live));
+        let hedger = hedger(
+            vec![position("SPY", dec!(-500))],
+            HedgeRule::with_band(dec!(100)),
+        )
+        .with_feature_flags(Arc::clone(&flags));
+
+        assert!(hedger.run_once().await.is_empty());
+        assert!(hedger.broker.orders.lock().unwrap().is_empty());
+
+        flags.set(Feature::AutoHedging, true, "rollout");
+        assert_eq!(hedger.run_once().await.len(), 1);
+    }
+
+    #[tokio::test]
+    async fn skips_underlying_with_unknown_option_delta() {
+        let hedger = hedger(
+            vec![position("SPY250321P00450000", dec!(-10))],
+            HedgeRule::with_band(dec!(10)),
+        );
+
+        assert!(hedger.run_once().await.is_empty());
+        assert!(hedger.recent_hedges().is_empty());
+    }
+}
@@ -0,0 +1,872 @@
+//! End-of-Day Scheduler
+//!
+//! Applies a per-time-horizon policy to open positions as the regular
+//! session nears its close: intraday positions can be flattened N minutes
+//! before the bell, DAY protective stops on longer holds can be re-placed as
+//! GTC so they survive the close, or positions can be left alone.
+//!
+//! Session times come from the broker's market clock, so holidays and early
+//! closes move the deadline with the exchange calendar. A position's horizon
+//! is the latest one stated on its lifecycle's decision context; positions
+//! without one use the `unspecified` policy. Each policy runs at most once
+//! per session, and every action is logged on the `audit` target. Nothing
+//! is applied while the `eod_policies` feature flag is off.
+
+use std::collections::HashSet;
+use std::sync::{Arc, Mutex, PoisonError};
+use std::time::Duration;
+
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use tokio_util::sync::CancellationToken;
+
+use super::{Feature, FeatureFlags, PositionLifecycleTracker};
+use crate::application::dto::{CreateOrderDto, SubmitOrdersRequestDto, SubmitOrdersResponseDto};
+use crate::application::ports::{
+    BrokerPort, Clock, DecisionContext, EventPublisherPort, MarketClock, PositionInfo,
+    RiskRepositoryPort, SystemClock, ThesisState, TimeHorizon,
+};
+use crate::application::use_cases::{CancelOrdersUseCase, SubmitOrdersUseCase};
+use crate::domain::order_execution::aggregate::Order;
+use crate::domain::order_execution::repository::OrderRepository;
+use crate::domain::order_execution::value_objects::{
+    CancelReason, OrderPurpose, OrderSide, OrderType, TimeInForce,
+};
+use crate::domain::shared::{OrderId, Timestamp};
+
+/// What to do with a position before the close.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum EodAction {
+    /// Leave the position and its orders untouched.
+    #[default]
+    Hold,
+    /// Cancel working orders and close the position at market.
+    Flatten,
+    /// Re-place DAY stop-loss orders as GTC.
+    ExtendStops,
+}
+
+/// End-of-day policy for one time horizon.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct EodPolicy {
+    /// Action to take.
+    pub action: EodAction,
+    /// How long before the close the action runs.
+    pub minutes_before_close: u32,
+}
+
+impl EodPolicy {
+    /// Leave positions alone.
+    #[must_use]
+    pub const fn hold() -> Self {
+        Self {
+            action: EodAction::Hold,
+            minutes_before_close: 0,
+        }
+    }
+
+    /// Flatten `minutes` before the close.
+    #[must_use]
+    pub const fn flatten(minutes: u32) -> Self {
+        Self {
+            action: EodAction::Flatten,
+            minutes_before_close: minutes,
+        }
+    }
+
+    /// Convert DAY stops to GTC `minutes` before the close.
+    #[must_use]
+    pub const fn extend_stops(minutes: u32) -> Self {
+        Self {
+            action: EodAction::ExtendStops,
+            minutes_before_close: minutes,
+        }
+    }
+}
+
+/// End-of-day policies by time horizon.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct EodPolicies {
+    /// Intraday positions.
+    pub intraday: EodPolicy,
+    /// Swing positions.
+    pub swing: EodPolicy,
+    /// Position (long-term) holdings.
+    pub position: EodPolicy,
+    /// Positions whose decision stated no horizon.
+    pub unspecified: EodPolicy,
+}
+
+impl Default for EodPolicies {
+    fn default() -> Self {
+        Self {
+            intraday: EodPolicy::flatten(10),
+            swing: EodPolicy::extend_stops(5),
+            position: EodPolicy::extend_stops(5),
+            unspecified: EodPolicy::hold(),
+        }
+    }
+}
+
+impl EodPolicies {
+    /// Policy for positions with `horizon`.
+    #[must_use]
+    pub const fn for_horizon(&self, horizon: Option<TimeHorizon>) -> &EodPolicy {
+        match horizon {
+            Some(TimeHorizon::Intraday) => &self.intraday,
+            Some(TimeHorizon::Swing) => &self.swing,
+            Some(TimeHorizon::Position) => &self.position,
+            None => &self.unspecified,
+        }
+    }
+
+    /// Whether any policy acts.
+    #[must_use]
+    pub fn is_active(&self) -> bool {
+        HORIZONS
+            .iter()
+            .any(|h| self.for_horizon(*h).action != EodAction::Hold)
+    }
+}
+
+/// Configuration for the end-of-day scheduler.
+#[derive(Debug, Clone)]
+pub struct EodSchedulerConfig {
+    /// Policies by horizon.
+    pub policies: EodPolicies,
+    /// How often the market clock is checked.
+    pub poll_interval: Duration,
+}
+
+impl Default for EodSchedulerConfig {
+    fn default() -> Self {
+        Self {
+            policies: EodPolicies::default(),
+            poll_interval: Duration::from_secs(30),
+        }
+    }
+}
+
+/// Audit record of the action taken on one position.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct EodRecord {
+    /// Symbol.
+    pub symbol: String,
+    /// Horizon the policy was chosen by.
+    pub horizon: Option<TimeHorizon>,
+    /// Action applied.
+    pub action: EodAction,
+    /// Orders canceled.
+    pub canceled: Vec<String>,
+    /// Orders submitted (exits or replacement stops).
+    pub submitted: Vec<String>,
+    /// Failures.
+    pub errors: Vec<String>,
+}
+
+impl EodRecord {
+    /// Check if the action completed without errors.
+    #[must_use]
+    pub const fn is_success(&self) -> bool {
+        self.errors.is_empty()
+    }
+}
+
+const HORIZONS: [Option<TimeHorizon>; 4] = [
+    Some(TimeHorizon::Intraday),
+    Some(TimeHorizon::Swing),
+    Some(TimeHorizon::Position),
+    None,
+];
+
+/// Runs end-of-day policies against open positions.
+pub struct EodScheduler<B, R, O, E>
+where
+    B: BrokerPort,
+    R: RiskRepositoryPort,
+    O: OrderRepository,
+    E: EventPublisherPort,
+{
+    config: EodSchedulerConfig,
+    broker: Arc<B>,
+    order_repo: Arc<O>,
+    submit_orders: Arc<SubmitOrdersUseCase<B, R, O, E>>,
+    cancel_orders: Arc<CancelOrdersUseCase<B, O, E>>,
+    lifecycle: Option<Arc<PositionLifecycleTracker>>,
+    feature_flags: Option<Arc<FeatureFlags>>,
+    clock: Arc<dyn Clock>,
+    /// Session closes each horizon has already been handled for.
+    handled: Mutex<HashSet<(Timestamp, Option<TimeHorizon>)>>,
+    /// GTC stops whose DAY original was canceled but which the broker has
+    /// not accepted yet (e.g. while the cancel is pending).
+    pending_stops: Mutex<Vec<SubmitOrdersRequestDto>>,
+}
+
+impl<B, R, O, E> EodScheduler<B, R, O, E>
+where
+    B: BrokerPort,
+    R: RiskRepositoryPort,
+    O: OrderRepository,
+    E: EventPublisherPort,
+{
+    /// Create a scheduler.
+    #[must_use]
+    pub fn new(
+        config: EodSchedulerConfig,
+        broker: Arc<B>,
+        order_repo: Arc<O>,
+        submit_orders: Arc<SubmitOrdersUseCase<B, R, O, E>>,
+        cancel_orders: Arc<CancelOrdersUseCase<B, O, E>>,
+    ) -> Self {
+        Self {
+            config,
+            broker,
+            order_repo,
+            submit_orders,
+            cancel_orders,
+            lifecycle: None,
+            feature_flags: None,
+            clock: SystemClock::shared(),
+            handled: Mutex::new(HashSet::new()),
+            pending_stops: Mutex::new(Vec::new()),
+        }
+    }
+
+    /// Read position horizons from their lifecycles.
+    #[must_use]
+    pub fn with_position_lifecycle(mut self, lifecycle: Arc<PositionLifecycleTracker>) -> Self {
+        self.lifecycle = Some(lifecycle);
+        self
+    }
+
+    /// Hold policies while the `eod_policies` flag is off.
+    #[must_use]
+    pub fn with_feature_flags(mut self, feature_flags: Arc<FeatureFlags>) -> Self {
+        self.feature_flags = Some(feature_flags);
+        self
+    }
+
+    /// Use `clock` instead of the system clock for the time to close.
+    #[must_use]
+    pub fn with_clock(mut self, clock: Arc<dyn Clock>) -> Self {
+        self.clock = clock;
+        self
+    }
+
+    /// Check the market clock and apply any policies that are due.
+    pub async fn run_once(&self) -> Vec<EodRecord> {
+        self.retry_pending_stops().await;
+
+        match self.broker.get_market_clock().await {
+            Ok(Some(market)) => self.run_at(&market, self.clock.now()).await,
+            Ok(None) => {
+                tracing::warn!("Broker has no market clock; end-of-day policies not applied");
+                Vec::new()
+            }
+            Err(e) => {
+                tracing::warn!(error = %e, "End-of-day scheduler could not read market clock");
+                Vec::new()
+            }
+        }
+    }
+
+    /// Apply the policies due at `now` within the session described by
+    /// `market`.
+    pub async fn run_at(&self, market: &MarketClock, now: Timestamp) -> Vec<EodRecord> {
+        if !market.is_open
+            || self
+                .feature_flags
+                .as_ref()
+                .is_some_and(|flags| !flags.is_enabled(Feature::EodPolicies))
+        {
+            return Vec::new();
+        }
+
+        let remaining = market.next_close.duration_since(now).num_seconds();
+        let due: Vec<Option<TimeHorizon>> = {
+            let handled = self.handled.lock().unwrap_or_else(PoisonError::into_inner);
+            HORIZONS
+                .into_iter()
+                .filter(|horizon| {
+                    let policy = self.config.policies.for_horizon(*horizon);
+                    policy.action != EodAction::Hold
+                        && remaining <= i64::from(policy.minutes_before_close) * 60
+                        && !handled.contains(&(market.next_close, *horizon))
+                })
+                .collect()
+        };
+        if due.is_empty() {
+            return Vec::new();
+        }
+
+        let positions = match self.broker.get_all_positions().await {
+            Ok(positions) => positions,
+            Err(e) => {
+                // Not marked handled, so the next poll retries
+                tracing::warn!(error = %e, "End-of-day scheduler could not read positions");
+                return Vec::new();
+            }
+        };
+        let active = match self.order_repo.find_active().await {
+            Ok(orders) => orders,
+            Err(e) => {
+                tracing::warn!(error = %e, "End-of-day scheduler could not read orders");
+                return Vec::new();
+            }
+        };
+        {
+            let mut handled = self.handled.lock().unwrap_or_else(PoisonError::into_inner);
+            handled.retain(|(close, _)| *close >= market.next_close);
+            handled.extend(due.iter().map(|horizon| (market.next_close, *horizon)));
+        }
+
+        let mut records = Vec::new();
+        for position in positions.iter().filter(|p| !p.quantity.is_zero()) {
+            let horizon = self.horizon(&position.symbol).await;
+            if !due.contains(&horizon) {
+                continue;
+            }
+            let policy = *self.config.policies.for_horizon(horizon);
+            let orders: Vec<&Order> = active
+                .iter()
+                .filter(|o| o.symbol().as_str() == position.symbol)
+                .collect();
+
+            let mut record = EodRecord {
+                symbol: position.symbol.clone(),
+                horizon,
+                action: policy.action,
+                canceled: vec![],
+                submitted: vec![],
+                errors: vec![],
+            };
+            match policy.action {
+                EodAction::Flatten => self.flatten(position, &orders, &policy, &mut record).await,
+                EodAction::ExtendStops => self.extend_stops(&orders, &mut record).await,
+                EodAction::Hold => continue,
+            }
+            if record.canceled.is_empty() && record.submitted.is_empty() && record.errors.is_empty()
+            {
+                continue;
+            }
+
+            tracing::info!(
+                target: "audit",
+                symbol = %record.symbol,
+                horizon = ?record.horizon,
+                action = ?record.action,
+                canceled = ?record.canceled,
+                submitted = ?record.submitted,
+                errors = ?record.errors,
+                minutes_to_close = remaining / 60,
+                "End-of-day action"
+            );
+            records.push(record);
+        }
+        records
+    }
+
+    /// Run the scheduler every poll interval until shutdown.
+    pub fn start(self: &Arc<Self>, shutdown: CancellationToken)
+    where
+        B: 'static,
+        R: 'static,
+        O: 'static,
+        E: 'static,
+    {
+        if !self.config.policies.is_active() {
+            return;
+        }
+
+        let scheduler = Arc::clone(self);
+        let poll_interval = self.config.poll_interval.max(Duration::from_secs(1));
+
+        tokio::spawn(async move {
+            let mut interval = tokio::time::interval(poll_interval);
+
+            loop {
+                tokio::select! {
+                    _ = interval.tick() => {
+                        scheduler.run_once().await;
+                    }
+                    () = shutdown.cancelled() => {
+                        tracing::info!("End-of-day scheduler shutting down");
+                        break;
+                    }
+                }
+            }
+        });
+    }
+
+    /// Latest horizon stated for the open position in `symbol`.
+    async fn horizon(&self, symbol: &str) -> Option<TimeHorizon> {
+        let lifecycle = self.lifecycle.as_ref()?;
+        match lifecycle.current(symbol).await {
+            Ok(current) => current?
+                .events
+                .iter()
+                .rev()
+                .find_map(|e| e.decision.as_ref()?.time_horizon),
+            Err(e) => {
+                tracing::warn!(symbol = %symbol, error = %e, "Could not read position lifecycle");
+                None
+            }
+        }
+    }
+
+    /// Cancel the symbol's working orders and close the position at market.
+    async fn flatten(
+        &self,
+        position: &PositionInfo,
+        orders: &[&Order],
+        policy: &EodPolicy,
+        record: &mut EodRecord,
+    ) {
+        for order in orders {
+            let reason = CancelReason::new(
+                "EOD_FLATTEN",
+                format!("Flattening {} before the close", position.symbol),
+            );
+            self.cancel(order, reason, record).await;
+        }
+
+        let side = if position.quantity > Decimal::ZERO {
+            OrderSide::Sell
+        } else {
+            OrderSide::Buy
+        };
+        let request = SubmitOrdersRequestDto {
+            orders: vec![CreateOrderDto {
+                client_order_id: OrderId::generate().to_string(),
+                symbol: position.symbol.clone(),
+                side,
+                order_type: OrderType::Market,
+                quantity: position.quantity.abs(),
+                limit_price: None,
+                stop_price: None,
+                time_in_force: TimeInForce::Day,
+                purpose: OrderPurpose::Exit,
+                decision: Some(DecisionContext {
+                    decision_id: None,
+                    thesis_state: Some(ThesisState::Exiting),
+                    time_horizon: record.horizon,
+                    strategy_family: None,
+                    strategy_tag: None,
+                    max_holding_period: None,
+                    scale_out: Vec::new(),
+                    rationale: Some(format!(
+                        "End-of-day flatten {} minutes before close",
+                        policy.minutes_before_close
+                    )),
+                }),
+                oto_exit: None,
+            }],
+            validate_risk: false,
+            cycle_id: None,
+        };
+        let response = self.submit_orders.execute(request).await;
+        collect_response(&response, record);
+    }
+
+    /// Re-place the symbol's DAY stop-loss orders as GTC.
+    ///
+    /// Each stop is canceled first so the position is never protected
+    /// twice; a replacement the broker does not accept yet is retried on
+    /// later passes.
+    async fn extend_stops(&self, orders: &[&Order], record: &mut EodRecord) {
+        for order in orders.iter().filter(|o| is_day_stop(o)) {
+            let reason = CancelReason::new("EOD_EXTEND_STOP", "Replacing DAY stop with GTC");
+            if !self.cancel(order, reason, record).await {
+                continue;
+            }
+
+            let request = SubmitOrdersRequestDto {
+                orders: vec![CreateOrderDto {
+                    client_order_id: OrderId::generate().to_string(),
+                    symbol: order.symbol().to_string(),
+                    side: order.side(),
+                    order_type: order.order_type(),
+                    quantity: order.partial_fill().leaves_qty().amount(),
+                    limit_price: order.limit_price().map(|p| p.amount()),
+                    stop_price: order.stop_price().map(|p| p.amount()),
+                    time_in_force: TimeInForce::Gtc,
+                    purpose: OrderPurpose::StopLoss,
+                    decision: None,
+                    oto_exit: None,
+                }],
+                validate_risk: false,
+                cycle_id: order.cycle_id().map(ToString::to_string),
+            };
+            let response = self.submit_orders.execute(request.clone()).await;
+            if response.submitted.is_empty() {
+                tracing::error!(
+                    symbol = %record.symbol,
+                    replaced = %order.id(),
+                    "GTC stop not accepted; position unprotected until retry"
+                );
+                self.pending_stops
+                    .lock()
+                    .unwrap_or_else(PoisonError::into_inner)
+                    .push(request);
+            }
+            collect_response(&response, record);
+        }
+    }
+
+    /// Resubmit GTC stops the broker rejected on an earlier pass.
+    async fn retry_pending_stops(&self) {
+        let pending = std::mem::take(
+            &mut *self
+                .pending_stops
+                .lock()
+                .unwrap_or_else(PoisonError::into_inner),
+        );
+        for request in pending {
+            let response = self.submit_orders.execute(request.clone()).await;
+            let symbol = request.orders.first().map(|o| o.symbol.clone());
+            if let Some(submitted) = response.submitted.first() {
+                tracing::info!(
+                    target: "audit",
+                    symbol = ?symbol,
+                    order_id = %submitted.order.order_id,
+                    "End-of-day GTC stop placed on retry"
+                );
+            } else {
+                self.pending_stops
+                    .lock()
+                    .unwrap_or_else(PoisonError::into_inner)
+                    .push(request);
+            }
+        }
+    }
+
+    async fn cancel(&self, order: &Order, reason: CancelReason, record: &mut EodRecord) -> bool {
+        let result = self
+            .cancel_orders
+            .cancel_by_client_id(order.id().as_str(), reason)
+            .await;
+        if result.success {
+            record.canceled.push(result.order_id);
+        } else {
+            record.errors.push(format!(
+                "cancel {}: {}",
+                result.order_id,
+                result.error.unwrap_or_default()
+            ));
+        }
+        result.success
+    }
+}
+
+/// Whether `order` is a DAY stop-loss that expires at the close.
+fn is_day_stop(order: &Order) -> bool {
+    order.partial_fill().order_purpose().is_protective()
+        && order.time_in_force() == TimeInForce::Day
+        && matches!(order.order_type(), OrderType::Stop | OrderType::StopLimit)
+}
+
+fn collect_response(response: &SubmitOrdersResponseDto, record: &mut EodRecord) {
+    record
+        .submitted
+        .extend(response.submitted.iter().map(|r| r.order.order_id.clone()));
+    record.errors.extend(
+        response
+            .rejected
+            .iter()
+            .filter_map(|r| r.error.clone())
+            .chain(response.risk_violations.iter().cloned()),
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::application::ports::{
+        BrokerError, CancelOrderRequest, InMemoryPositionLifecycleRepository,
+        InMemoryRiskRepository, NoOpEventPublisher, OrderAck, SubmitOrderRequest,
+    };
+    use crate::domain::order_execution::aggregate::CreateOrderCommand;
+    use crate::domain::order_execution::value_objects::{FillReport, OrderStatus};
+    use crate::domain::shared::{BrokerId, InstrumentId, Money, Quantity, Symbol};
+    use crate::infrastructure::persistence::InMemoryOrderRepository;
+    use async_trait::async_trait;
+    use rust_decimal_macros::dec;
+
+    #[derive(Default)]
+    struct MockBroker {
+        positions: Vec<PositionInfo>,
+        submitted: Mutex<Vec<SubmitOrderRequest>>,
+        canceled: Mutex<Vec<CancelOrderRequest>>,
+    }
+
+    #[async_trait]
+    impl BrokerPort for MockBroker {
+        async fn submit_order(&self, request: SubmitOrderRequest) -> Result<OrderAck, BrokerError> {
+            let ack = OrderAck {
+                broker_order_id: BrokerId::new(format!("broker-{}", request.client_order_id)),
+                client_order_id: request.client_order_id.clone(),
+                status: OrderStatus::Accepted,
+                filled_qty: Decimal::ZERO,
+                avg_fill_price: None,
+                child_order_ids: Vec::new(),
+                leg_fills: Vec::new(),
+            };
+            self.submitted.lock().unwrap().push(request);
+            Ok(ack)
+        }
+
+        async fn cancel_order(&self, request: CancelOrderRequest) -> Result<(), BrokerError> {
+            self.canceled.lock().unwrap().push(request);
+            Ok(())
+        }
+
+        async fn get_order(&self, broker_order_id: &BrokerId) -> Result<OrderAck, BrokerError> {
+            Err(BrokerError::OrderNotFound {
+                order_id: broker_order_id.to_string(),
+            })
+        }
+
+        async fn get_open_orders(&self) -> Result<Vec<OrderAck>, BrokerError> {
+            Ok(vec![])
+        }
+
+        async fn get_buying_power(&self) -> Result<Decimal, BrokerError> {
+            Ok(dec!(100000))
+        }
+
+        async fn get_position(
+            &self,
+            _instrument_id: &InstrumentId,
+        ) -> Result<Option<Decimal>, BrokerError> {
+            Ok(None)
+        }
+
+        async fn get_all_positions(&self) -> Result<Vec<PositionInfo>, BrokerError> {
+            Ok(self.positions.clone())
+        }
+    }
+
+    fn position(symbol: &str, quantity: Decimal) -> PositionInfo {
+        PositionInfo {
+            symbol: symbol.to_string(),
+            quantity,
+            avg_entry_price: dec!(100),
+            market_value: quantity * dec!(100),
+            unrealized_pnl: Decimal::ZERO,
+            current_price: dec!(100),
+            opened_at: None,
+        }
+    }
+
+    fn accepted_order(symbol: &str, order_type: OrderType, purpose: OrderPurpose) -> Order {
+        let mut order = Order::new(CreateOrderCommand {
+            symbol: Symbol::new(symbol),
+            side: OrderSide::Sell,
+            order_type,
+            quantity: Quantity::from_i64(100),
+            limit_price: (order_type == OrderType::Limit).then(|| Money::new(dec!(110))),
+            stop_price: (order_type == OrderType::Stop).then(|| Money::new(dec!(95))),
+            time_in_force: TimeInForce::Day,
+            purpose,
+            legs: vec![],
+        })
+        .unwrap();
+        order
+            .accept(BrokerId::new(format!("broker-{symbol}")))
+            .unwrap();
+        order
+    }
+
+    fn market_closing_in(minutes: i64) -> (MarketClock, Timestamp) {
+        let close = Timestamp::parse("2026-03-13T20:00:00Z").unwrap();
+        let now = Timestamp::new(close.as_datetime() - chrono::Duration::minutes(minutes));
+        let market = MarketClock {
+            timestamp: now,
+            is_open: true,
+            next_open: Timestamp::parse("2026-03-16T13:30:00Z").unwrap(),
+            next_close: close,
+        };
+        (market, now)
+    }
+
+    type TestScheduler = EodScheduler<
+        MockBroker,
+        InMemoryRiskRepository,
+        InMemoryOrderRepository,
+        NoOpEventPublisher,
+    >;
+
+    async fn make_scheduler(
+        broker: MockBroker,
+        orders: Vec<Order>,
+        horizons: &[(&str, TimeHorizon)],
+    ) -> (TestScheduler, Arc<MockBroker>) {
+        let broker = Arc::new(broker);
+        let order_repo = Arc::new(InMemoryOrderRepository::new());
+        for order in &orders {
+            order_repo.save(order).await.unwrap();
+        }
+        let event_publisher = Arc::new(NoOpEventPublisher);
+        let lifecycle = Arc::new(PositionLifecycleTracker::new(Arc::new(
+            InMemoryPositionLifecycleRepository::new(),
+        )));
+
+        // Open a lifecycle per symbol carrying its horizon
+        for (symbol, horizon) in horizons {
+            let mut entry = Order::new(CreateOrderCommand {
+                symbol: Symbol::new(*symbol),
+                side: OrderSide::Buy,
+                order_type: OrderType::Market,
+                quantity: Quantity::from_i64(100),
+                limit_price: None,
+                stop_price: None,
+                time_in_force: TimeInForce::Day,
+                purpose: OrderPurpose::Entry,
+                legs: vec![],
+            })
+            .unwrap();
+            entry.accept(BrokerId::new("broker-entry")).unwrap();
+            entry
+                .apply_fill(FillReport::new(
+                    format!("fill-{symbol}"),
+                    Quantity::from_i64(100),
+                    Money::new(dec!(100)),
+                    Timestamp::now(),
+                    "NYSE",
+                ))
+                .unwrap();
+            let decision = DecisionContext {
+                time_horizon: Some(*horizon),
+                ..DecisionContext::default()
+            };
+            lifecycle.track_order(&entry, Some(decision)).await.unwrap();
+        }
+
+        let submit_orders = Arc::new(SubmitOrdersUseCase::new(
+            Arc::clone(&broker),
+            Arc::new(InMemoryRiskRepository::new()),
+            Arc::clone(&order_repo),
+            Arc::clone(&event_publisher),
+        ));
+        let cancel_orders = Arc::new(CancelOrdersUseCase::new(
+            Arc::clone(&broker),
+            Arc::clone(&order_repo),
+            event_publisher,
+        ));
+        let scheduler = EodScheduler::new(
+            EodSchedulerConfig::default(),
+            Arc::clone(&broker),
+            order_repo,
+            submit_orders,
+            cancel_orders,
+        )
+        .with_position_lifecycle(lifecycle);
+        (scheduler, broker)
+    }
+
+    #[tokio::test]
+    async fn intraday_positions_are_flattened_once_per_session() {
+        let broker = MockBroker {
+            positions: vec![position("AAPL", dec!(100)), position("MSFT", dec!(50))],
+            ..MockBroker::default()
+        };
+        let stop = accepted_order("AAPL", OrderType::Stop, OrderPurpose::StopLoss);
+        let (scheduler, broker) = make_scheduler(
+            broker,
+            vec![stop],
+            &[
+                ("AAPL", TimeHorizon::Intraday),
+                ("MSFT", TimeHorizon::Swing),
+            ],
+        )
+        .await;
+
+        // Outside the 10-minute window nothing happens
+        let (market, now) = market_closing_in(15);
+        assert!(scheduler.run_at(&market, now).await.is_empty());
+
+        let (market, now) = market_closing_in(9);
+        let records = scheduler.run_at(&market, now).await;
+        assert_eq!(records.len(), 1);
+        assert_eq!(records[0].symbol, "AAPL");
+        assert_eq!(records[0].action, EodAction::Flatten);
+        assert_eq!(records[0].canceled.len(), 1);
+        assert_eq!(records[0].submitted.len(), 1);
+        assert!(records[0].is_success());
+
+        {
+            let submitted = broker.submitted.lock().unwrap();
+            assert_eq!(submitted.len(), 1);
+            assert_eq!(submitted[0].side, OrderSide::Sell);
+            assert_eq!(submitted[0].quantity, dec!(100));
+            assert_eq!(submitted[0].order_type, OrderType::Market);
+        }
+
+        // Later polls in the same session do not flatten again
+        let (market, now) = market_closing_in(8);
+        assert!(scheduler.run_at(&market, now).await.is_empty());
+    }
+
+    #[tokio::test]
+    async fn swing_day_stops_are_replaced_with_gtc() {
+        let broker = MockBroker {
+            positions: vec![position("MSFT", dec!(100))],
+            ..MockBroker::default()
+        };
+        let stop = accepted_order("MSFT", OrderType::Stop, OrderPurpose::StopLoss);
+        let target = accepted_order("MSFT", OrderType::Limit, OrderPurpose::TakeProfit);
+        let (scheduler, broker) =
+            make_scheduler(broker, vec![stop, target], &[("MSFT", TimeHorizon::Swing)]).await;
+
+        let (market, now) = market_closing_in(4);
+        let records = scheduler.run_at(&market, now).await;
+
+        assert_eq!(records.len(), 1);
+        assert_eq!(records[0].action, EodAction::ExtendStops);
+        assert_eq!(records[0].canceled.len(), 1);
+        assert_eq!(records[0].submitted.len(), 1);
+
+        let submitted = broker.submitted.lock().unwrap();
+        assert_eq!(submitted.len(), 1);
+        assert_eq!(submitted[0].time_in_force, TimeInForce::Gtc);
+        assert_eq!(submitted[0].order_type, OrderType::Stop);
+        assert_eq!(submitted[0].stop_price, Some(dec!(95)));
+        assert_eq!(broker.canceled.lock().unwrap().len(), 1);
+    }
+
+    #[tokio::test]
+    async fn closed_market_and_unspecified_horizon_are_left_alone() {
+        let broker = MockBroker {
+            positions: vec![position("TSLA", dec!(-20))],
+            ..MockBroker::default()
+        };
+        let (scheduler, broker) = make_scheduler(broker, vec![], &[]).await;
+
+        let (mut market, now) = market_closing_in(1);
+        assert!(scheduler.run_at(&market, now).await.is_empty());
+
+        market.is_open = false;
+        assert!(scheduler.run_at(&market, now).await.is_empty());
+        assert!(broker.submitted.lock().unwrap().is_empty());
+    }
+
+    #[test]
+    fn policies_select_by_horizon() {
+        let policies = EodPolicies::default();
+        assert_eq!(
+            policies.for_horizon(Some(TimeHorizon::Intraday)).action,
+            EodAction::Flatten
+        );
+        assert_eq!(policies.for_horizon(None).action, EodAction::Hold);
+        assert!(policies.is_active());
+
+        let idle = EodPolicies {
+            intraday: EodPolicy::hold(),
+            swing: EodPolicy::hold(),
+            position: EodPolicy::hold(),
+            unspecified: EodPolicy::hold(),
+        };
+        assert!(!idle.is_active());
+    }
+}
@@ -0,0 +1,351 @@
+//! Event Outbox
+//!
+//! Decouples recording order events from delivering them. Use cases publish
+//! to the [`EventOutbox`], which appends the events to a durable outbox
+//! alongside the order state change and returns. A background dispatcher
+//! forwards them to the event sink in order, removing each once the sink
+//! accepts it. When the sink fails, the event stays at the head of the
+//! outbox and is retried with exponential backoff; later events wait behind
+//! it so consumers never see them out of order.
+
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, PoisonError, RwLock};
+use std::time::Duration;
+
+use async_trait::async_trait;
+use tokio::sync::Notify;
+use tokio_util::sync::CancellationToken;
+
+use crate::application::ports::{
+    Clock, EventPublishError, EventPublisherPort, OutboxError, OutboxRepositoryPort, SystemClock,
+};
+use crate::domain::order_execution::events::OrderEvent;
+use crate::domain::shared::Timestamp;
+
+/// Configuration for outbox delivery.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct EventOutboxConfig {
+    /// Most entries read per dispatch pass.
+    pub batch_size: usize,
+    /// How often the dispatcher checks for events due a retry.
+    pub poll_interval: Duration,
+    /// Wait before the first retry of a failed event.
+    pub initial_backoff: Duration,
+    /// Longest wait between retries.
+    pub max_backoff: Duration,
+}
+
+impl Default for EventOutboxConfig {
+    fn default() -> Self {
+        Self {
+            batch_size: 100,
+            poll_interval: Duration::from_secs(1),
+            initial_backoff: Duration::from_secs(1),
+            max_backoff: Duration::from_secs(300),
+        }
+    }
+}
+
+/// Backlog and delivery counters of the outbox.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct OutboxStats {
+    /// Events waiting for delivery.
+    pub depth: usize,
+    /// When the oldest waiting event was recorded.
+    pub oldest_recorded_at: Option<Timestamp>,
+    /// Events delivered since startup.
+    pub delivered: u64,
+    /// Failed delivery attempts since startup.
+    pub failed_attempts: u64,
+    /// Error from the most recent failed attempt.
+    pub last_error: Option<String>,
+}
+
+/// Durable event publisher with background delivery.
+pub struct EventOutbox {
+    repo: Arc<dyn OutboxRepositoryPort>,
+    config: EventOutboxConfig,
+    clock: Arc<dyn Clock>,
+    wake: Notify,
+    delivered: AtomicU64,
+    failed_attempts: AtomicU64,
+    last_error: RwLock<Option<String>>,
+}
+
+impl std::fmt::Debug for EventOutbox {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("EventOutbox")
+            .field("config", &self.config)
+            .field("delivered", &self.delivered)
+            .field("failed_attempts", &self.failed_attempts)
+            .finish_non_exhaustive()
+    }
+}
+
+impl EventOutbox {
+    /// Create an outbox storing events in `repo`.
+    #[must_use]
+    pub fn new(repo: Arc<dyn OutboxRepositoryPort>) -> Self {
+        Self {
+            repo,
+            config: EventOutboxConfig::default(),
+            clock: SystemClock::shared(),
+            wake: Notify::new(),
+            delivered: AtomicU64::new(0),
+            failed_attempts: AtomicU64::new(0),
+            last_error: RwLock::new(None),
+        }
+    }
+
+    /// Use `config` for delivery.
+    #[must_use]
+    pub const fn with_config(mut self, config: EventOutboxConfig) -> Self {
+        self.config = config;
+        self
+    }
+
+    /// Use `clock` instead of the system clock.
+    #[must_use]
+    pub fn with_clock(mut self, clock: Arc<dyn Clock>) -> Self {
+        self.clock = clock;
+        self
+    }
+
+    /// Deliver due events to `sink`, oldest first.
+    ///
+    /// Stops at the first event that fails or is still backing off, so
+    /// events are never delivered out of order. Returns the number
+    /// delivered.
+    ///
+    /// # Errors
+    ///
+    /// Returns error if the outbox cannot be read or updated.
+    pub async fn dispatch(&self, sink: &dyn EventPublisherPort) -> Result<usize, OutboxError> {
+        let mut delivered = 0;
+        loop {
+            let batch = self.repo.pending(self.config.batch_size).await?;
+            if batch.is_empty() {
+                return Ok(delivered);
+            }
+
+            for entry in batch {
+                let now = self.clock.now();
+                if entry.next_attempt_at > now {
+                    return Ok(delivered);
+                }
+
+                if let Err(e) = sink.publish_order_event(entry.event.clone()).await {
+                    let backoff = self.backoff(entry.attempts);
+                    let step = chrono::Duration::from_std(backoff).unwrap_or(chrono::Duration::MAX);
+                    let next_attempt_at = now
+                        .as_datetime()
+                        .checked_add_signed(step)
+                        .map_or(now, Timestamp::new);
+                    self.failed_attempts.fetch_add(1, Ordering::Relaxed);
+                    *self
+                        .last_error
+                        .write()
+                        .unwrap_or_else(PoisonError::into_inner) = Some(e.to_string());
+                    tracing::warn!(
+                        outbox_id = entry.id,
+                        order_id = %entry.event.order_id(),
+                        event_type = entry.event.event_type(),
+                        attempts = entry.attempts + 1,
+                        retry_in_secs = backoff.as_secs(),
+                        error = %e,
+                        "Event delivery failed, will retry"
+                    );
+                    self.repo
+                        .record_failure(entry.id, &e.to_string(), next_attempt_at)
+                        .await?;
+                    return Ok(delivered);
+                }
+
+                self.repo.mark_delivered(entry.id).await?;
+                self.delivered.fetch_add(1, Ordering::Relaxed);
+                delivered += 1;
+            }
+        }
+    }
+
+    /// Deliver events to `sink` as they are recorded, retrying failures,
+    /// until `shutdown`.
+    ///
+    /// Events still undelivered at shutdown stay in the outbox for the next
+    /// start.
+    pub fn start(self: &Arc<Self>, sink: Arc<dyn EventPublisherPort>, shutdown: CancellationToken) {
+        let outbox = Arc::clone(self);
+        tokio::spawn(async move {
+            loop {
+                if let Err(e) = outbox.dispatch(sink.as_ref()).await {
+                    tracing::error!(error = %e, "Event outbox dispatch failed");
+                }
+                tokio::select! {
+                    () = outbox.wake.notified() => {}
+                    () = tokio::time::sleep(outbox.config.poll_interval) => {}
+                    () = shutdown.cancelled() => break,
+                }
+            }
+        });
+    }
+
+    /// Current backlog and delivery counters.
+    pub async fn stats(&self) -> OutboxStats {
+        let depth = self.repo.depth().await.unwrap_or_else(|e| {
+            tracing::warn!(error = %e, "Failed to read event outbox depth");
+            0
+        });
+        let oldest_recorded_at = self
+            .repo
+            .pending(1)
+            .await
+            .ok()
+            .and_then(|entries| entries.first().map(|e| e.recorded_at));
+
+        OutboxStats {
+            depth,
+            oldest_recorded_at,
+            delivered: self.delivered.load(Ordering::Relaxed),
+            failed_attempts: self.failed_attempts.load(Ordering::Relaxed),
+            last_error: self
+                .last_error
+                .read()
+                .unwrap_or_else(PoisonError::into_inner)
+                .clone(),
+        }
+    }
+
+    /// Wait before retrying an event that has failed `attempts` times
+    /// before this failure.
+    fn backoff(&self, attempts: u32) -> Duration {
+        self.config
+            .initial_backoff
+            .saturating_mul(2_u32.saturating_pow(attempts))
+            .min(self.config.max_backoff)
+    }
+}
+
+#[async_trait]
+impl EventPublisherPort for EventOutbox {
+    async fn publish_order_events(&self, events: Vec<OrderEvent>) -> Result<(), EventPublishError> {
+        if events.is_empty() {
+            return Ok(());
+        }
+        self.repo
+            .append(events, self.clock.now())
+            .await
+            .map_err(|e| EventPublishError::PublishFailed {
+                message: e.to_string(),
+            })?;
+        self.wake.notify_one();
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Mutex;
+    use std::sync::atomic::AtomicBool;
+
+    use super::*;
+    use crate::application::ports::{InMemoryOutboxRepository, SimulatedClock};
+    use crate::domain::order_execution::events::OrderSubmitted;
+    use crate::domain::order_execution::value_objects::OrderSide;
+    use crate::domain::shared::{OrderId, Quantity, Symbol};
+
+    #[derive(Default)]
+    struct RecordingSink {
+        down: AtomicBool,
+        received: Mutex<Vec<String>>,
+    }
+
+    #[async_trait]
+    impl EventPublisherPort for RecordingSink {
+        async fn publish_order_events(
+            &self,
+            events: Vec<OrderEvent>,
+        ) -> Result<(), EventPublishError> {
+            if self.down.load(Ordering::Relaxed) {
+                return Err(EventPublishError::ConnectionError {
+                    message: "connection refused".to_string(),
+                });
+            }
+            self.received
+                .lock()
+                .unwrap()
+                .extend(events.iter().map(|e| e.order_id().to_string()));
+            Ok(())
+        }
+    }
+
+    fn submitted(order_id: &str) -> OrderEvent {
+        OrderEvent::Submitted(OrderSubmitted {
+            order_id: OrderId::new(order_id),
+            symbol: Symbol::new("AAPL"),
+            side: OrderSide::Buy,
+            quantity: Quantity::from_i64(100),
+            limit_price: None,
+            occurred_at: Timestamp::parse("2026-03-02T15:00:00Z").unwrap(),
+        })
+    }
+
+    fn outbox(clock: &Arc<SimulatedClock>) -> EventOutbox {
+        EventOutbox::new(Arc::new(InMemoryOutboxRepository::new()))
+            .with_clock(Arc::clone(clock) as Arc<dyn Clock>)
+    }
+
+    #[tokio::test]
+    async fn delivers_recorded_events_in_order() {
+        let clock = Arc::new(SimulatedClock::new(Timestamp::now()));
+        let outbox = outbox(&clock);
+        let sink = RecordingSink::default();
+
+        outbox
+            .publish_order_events(vec![submitted("o-1"), submitted("o-2")])
+            .await
+            .unwrap();
+        assert_eq!(outbox.stats().await.depth, 2);
+
+        assert_eq!(outbox.dispatch(&sink).await.unwrap(), 2);
+        assert_eq!(*sink.received.lock().unwrap(), vec!["o-1", "o-2"]);
+        let stats = outbox.stats().await;
+        assert_eq!(stats.depth, 0);
+        assert_eq!(stats.delivered, 2);
+        assert!(stats.oldest_recorded_at.is_none());
+    }
+
+    #[tokio::test]
+    async fn failed_delivery_is_kept_and_retried_after_backoff() {
+        let clock = Arc::new(SimulatedClock::new(Timestamp::now()));
+        let outbox = outbox(&clock);
+        let sink = RecordingSink::default();
+        sink.down.store(true, Ordering::Relaxed);
+
+        outbox
+            .publish_order_events(vec![submitted("o-1"), submitted("o-2")])
+            .await
+            .unwrap();
+        assert_eq!(outbox.dispatch(&sink).await.unwrap(), 0);
+        let stats = outbox.stats().await;
+        assert_eq!(stats.depth, 2);
+        assert_eq!(stats.failed_attempts, 1);
+        assert!(stats.last_error.unwrap().contains("connection refused"));
+
+        // Sink is back, but the head event is still backing off
+        sink.down.store(false, Ordering::Relaxed);
+        assert_eq!(outbox.dispatch(&sink).await.unwrap(), 0);
+
+        clock.advance(Duration::from_secs(1));
+        assert_eq!(outbox.dispatch(&sink).await.unwrap(), 2);
+        assert_eq!(*sink.received.lock().unwrap(), vec!["o-1", "o-2"]);
+    }
+
+    #[test]
+    fn backoff_doubles_up_to_the_limit() {
+        let outbox = EventOutbox::new(Arc::new(InMemoryOutboxRepository::new()));
+
+        assert_eq!(outbox.backoff(0), Duration::from_secs(1));
+        assert_eq!(outbox.backoff(3), Duration::from_secs(8));
+        assert_eq!(outbox.backoff(40), Duration::from_secs(300));
+    }
+}
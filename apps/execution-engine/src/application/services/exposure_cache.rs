@@ -0,0 +1,116 @@
+//! Exposure Cache
+//!
+//! Shared live exposure of the account: broker positions plus the engine's
+//! working orders. Use cases feed it as orders are submitted, filled and
+//! cancelled, and the risk repository reads it when building a risk context,
+//! so portfolio limits account for what is already on the book without
+//! recomputing exposure per request.
+
+use std::sync::{PoisonError, RwLock};
+
+use rust_decimal::Decimal;
+
+use crate::application::ports::{BrokerError, BrokerPort};
+use crate::domain::order_execution::aggregate::Order;
+use crate::domain::risk_management::value_objects::{
+    Exposure, ExposureBook, PositionContext, SymbolPosition,
+};
+
+/// Thread-safe cache of live portfolio exposure.
+#[derive(Debug, Default)]
+pub struct ExposureCache {
+    book: RwLock<ExposureBook>,
+}
+
+impl ExposureCache {
+    /// Create an empty cache.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Reload positions from the broker.
+    ///
+    /// Working orders are kept; positions the broker no longer reports are
+    /// dropped.
+    ///
+    /// # Errors
+    ///
+    /// Returns error if positions cannot be fetched.
+    pub async fn refresh_positions<B: BrokerPort>(&self, broker: &B) -> Result<(), BrokerError> {
+        let positions = broker.get_all_positions().await?;
+        let mut book = self.book.write().unwrap_or_else(PoisonError::into_inner);
+        book.replace_positions(
+            positions
+                .iter()
+                .map(|p| (p.symbol.as_str(), p.quantity, p.current_price)),
+        );
+        for position in &positions {
+            book.set_cost(&position.symbol, position.avg_entry_price);
+        }
+        drop(book);
+        tracing::debug!(positions = positions.len(), "Exposure positions refreshed");
+        Ok(())
+    }
+
+    /// Apply an order's latest state (submission, fills, cancellation).
+    pub fn sync_order(&self, order: &Order) {
+        self.book
+            .write()
+            .unwrap_or_else(PoisonError::into_inner)
+            .sync_order(order);
+    }
+
+    /// Stop tracking a working order the broker no longer reports.
+    pub fn remove_order(&self, order_id: &str) {
+        self.book
+            .write()
+            .unwrap_or_else(PoisonError::into_inner)
+            .remove_order(order_id);
+    }
+
+    /// Current exposure including working orders.
+    #[must_use]
+    pub fn exposure(&self) -> Exposure {
+        self.book
+            .read()
+            .unwrap_or_else(PoisonError::into_inner)
+            .exposure()
+    }
+
+    /// P&L realized by fills since the engine started.
+    #[must_use]
+    pub fn realized_pnl(&self) -> Decimal {
+        self.book
+            .read()
+            .unwrap_or_else(PoisonError::into_inner)
+            .realized_pnl()
+    }
+
+    /// Held and working quantity in one symbol.
+    #[must_use]
+    pub fn symbol_position(&self, symbol: &str) -> SymbolPosition {
+        self.book
+            .read()
+            .unwrap_or_else(PoisonError::into_inner)
+            .symbol_position(symbol)
+    }
+
+    /// IDs of working orders in `symbol` or in options on it.
+    #[must_use]
+    pub fn working_order_ids(&self, symbol: &str) -> Vec<String> {
+        self.book
+            .read()
+            .unwrap_or_else(PoisonError::into_inner)
+            .working_order_ids(symbol)
+    }
+
+    /// Cached positions as risk context entries.
+    #[must_use]
+    pub fn position_contexts(&self) -> Vec<PositionContext> {
+        self.book
+            .read()
+            .unwrap_or_else(PoisonError::into_inner)
+            .position_contexts()
+    }
+}
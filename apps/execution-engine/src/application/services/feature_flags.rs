@@ -0,0 +1,226 @@
+//! Feature Flags
+//!
+//! Typed switches for optional behaviour that may need to be turned off
+//! without a redeploy. Each [`Feature`] has a default per trading
+//! environment, so a risky feature can ship dark in LIVE while running in
+//! PAPER. Defaults can be overridden at startup and changed at runtime
+//! through the admin API; every runtime change is logged on the `audit`
+//! target. Services read a flag at the point they act, so a change takes
+//! effect on their next pass.
+
+use std::collections::HashMap;
+use std::fmt;
+use std::str::FromStr;
+use std::sync::{PoisonError, RwLock};
+
+use crate::domain::order_execution::value_objects::Environment;
+
+/// A behaviour gated by a flag.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Feature {
+    /// Delta hedging passes submit orders in the underlying.
+    AutoHedging,
+    /// End-of-day policies flatten positions or re-place stops.
+    EodPolicies,
+    /// Submitted orders are simulated under the shadow execution profiles.
+    ShadowExecution,
+}
+
+impl Feature {
+    /// Every feature, in listing order.
+    pub const ALL: [Self; 3] = [Self::AutoHedging, Self::EodPolicies, Self::ShadowExecution];
+
+    /// Flag name used in the environment and the admin API.
+    #[must_use]
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::AutoHedging => "auto_hedging",
+            Self::EodPolicies => "eod_policies",
+            Self::ShadowExecution => "shadow_execution",
+        }
+    }
+
+    /// Whether the feature is on in `environment` without an override.
+    #[must_use]
+    pub const fn default_for(self, environment: Environment) -> bool {
+        match self {
+            // Submits orders on its own; opt in explicitly for LIVE
+            Self::AutoHedging => environment.is_paper(),
+            Self::EodPolicies | Self::ShadowExecution => true,
+        }
+    }
+}
+
+impl fmt::Display for Feature {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl FromStr for Feature {
+    type Err = UnknownFeature;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let name = s.trim().to_lowercase().replace('-', "_");
+        Self::ALL
+            .into_iter()
+            .find(|feature| feature.as_str() == name)
+            .ok_or_else(|| UnknownFeature(s.to_string()))
+    }
+}
+
+/// A flag name that matches no [`Feature`].
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+#[error("Unknown feature flag '{0}'")]
+pub struct UnknownFeature(pub String);
+
+/// State of one flag.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FeatureFlag {
+    /// The feature.
+    pub feature: Feature,
+    /// Whether it is on.
+    pub enabled: bool,
+    /// Whether it is on without an override.
+    pub default: bool,
+    /// Why it was overridden (`None` when following the default).
+    pub reason: Option<String>,
+}
+
+/// Flag values for the engine's trading environment.
+#[derive(Debug)]
+pub struct FeatureFlags {
+    environment: Environment,
+    overrides: RwLock<HashMap<Feature, (bool, String)>>,
+}
+
+impl Default for FeatureFlags {
+    fn default() -> Self {
+        Self::new(Environment::Paper)
+    }
+}
+
+impl FeatureFlags {
+    /// Flags at their defaults for `environment`.
+    #[must_use]
+    pub fn new(environment: Environment) -> Self {
+        Self {
+            environment,
+            overrides: RwLock::new(HashMap::new()),
+        }
+    }
+
+    /// Start with `overrides` applied.
+    #[must_use]
+    pub fn with_overrides(self, overrides: impl IntoIterator<Item = (Feature, bool)>) -> Self {
+        {
+            let mut current = self
+                .overrides
+                .write()
+                .unwrap_or_else(PoisonError::into_inner);
+            for (feature, enabled) in overrides {
+                current.insert(feature, (enabled, "set at startup".to_string()));
+            }
+        }
+        self
+    }
+
+    /// Check whether `feature` is on.
+    #[must_use]
+    pub fn is_enabled(&self, feature: Feature) -> bool {
+        self.overrides
+            .read()
+            .unwrap_or_else(PoisonError::into_inner)
+            .get(&feature)
+            .map_or_else(
+                || feature.default_for(self.environment),
+                |(enabled, _)| *enabled,
+            )
+    }
+
+    /// Turn `feature` on or off until reset.
+    pub fn set(&self, feature: Feature, enabled: bool, reason: impl Into<String>) {
+        let reason = reason.into();
+        tracing::info!(
+            target: "audit",
+            feature = %feature,
+            enabled,
+            reason = %reason,
+            "Feature flag overridden"
+        );
+        self.overrides
+            .write()
+            .unwrap_or_else(PoisonError::into_inner)
+            .insert(feature, (enabled, reason));
+    }
+
+    /// Return `feature` to its default.
+    pub fn reset(&self, feature: Feature) {
+        let removed = self
+            .overrides
+            .write()
+            .unwrap_or_else(PoisonError::into_inner)
+            .remove(&feature);
+        if removed.is_some() {
+            tracing::info!(
+                target: "audit",
+                feature = %feature,
+                enabled = feature.default_for(self.environment),
+                "Feature flag reset to default"
+            );
+        }
+    }
+
+    /// State of `feature`.
+    #[must_use]
+    pub fn flag(&self, feature: Feature) -> FeatureFlag {
+        let default = feature.default_for(self.environment);
+        let current = self
+            .overrides
+            .read()
+            .unwrap_or_else(PoisonError::into_inner)
+            .get(&feature)
+            .cloned();
+        FeatureFlag {
+            feature,
+            enabled: current.as_ref().map_or(default, |(enabled, _)| *enabled),
+            default,
+            reason: current.map(|(_, reason)| reason),
+        }
+    }
+
+    /// State of every flag, in [`Feature::ALL`] order.
+    #[must_use]
+    pub fn flags(&self) -> Vec<FeatureFlag> {
+        Feature::ALL.into_iter().map(|f| self.flag(f)).collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn defaults_depend_on_environment_until_overridden() {
+        let paper = FeatureFlags::new(Environment::Paper);
+        let live = FeatureFlags::new(Environment::Live)
+            .with_overrides([(Feature::ShadowExecution, false)]);
+
+        assert!(paper.is_enabled(Feature::AutoHedging));
+        assert!(!live.is_enabled(Feature::AutoHedging));
+        assert!(!live.is_enabled(Feature::ShadowExecution));
+        assert!(live.is_enabled(Feature::EodPolicies));
+
+        live.set(Feature::AutoHedging, true, "hedging rollout");
+        let flag = live.flag(Feature::AutoHedging);
+        assert!(flag.enabled && !flag.default);
+        assert_eq!(flag.reason.as_deref(), Some("hedging rollout"));
+
+        live.reset(Feature::AutoHedging);
+        assert!(!live.is_enabled(Feature::AutoHedging));
+        assert_eq!(live.flags().len(), Feature::ALL.len());
+
+        assert_eq!("Auto-Hedging".parse(), Ok(Feature::AutoHedging));
+        assert!("hedging".parse::<Feature>().is_err());
+    }
+}
@@ -0,0 +1,367 @@
+//! Feed Sanitizer
+//!
+//! Screens live quotes and trades before they reach stop evaluation, bar
+//! aggregation or tactic pricing. A tick is rejected when its price is zero
+//! or negative, when it is stamped too far in the future or the past, or
+//! when it moves further from the symbol's last accepted price than the
+//! configured limit. A genuine gap is not rejected forever: once enough
+//! consecutive ticks agree on the new level, it is accepted. Rejections are
+//! counted per symbol for the session stats.
+
+use std::collections::{BTreeMap, HashMap};
+use std::fmt;
+use std::sync::{Arc, PoisonError, RwLock};
+use std::time::Duration;
+
+use rust_decimal::Decimal;
+
+use crate::application::ports::{Clock, SystemClock};
+use crate::domain::shared::Timestamp;
+use crate::infrastructure::websocket::QuoteUpdate;
+
+/// Feed sanitation limits.
+#[derive(Debug, Clone)]
+pub struct FeedSanitizerConfig {
+    /// Largest move from the last accepted stock price, in percent
+    /// (`0` disables the check).
+    pub equity_max_move_pct: Decimal,
+    /// Largest move from the last accepted option price, in percent
+    /// (`0` disables the check).
+    pub option_max_move_pct: Decimal,
+    /// Consecutive out-of-range ticks that establish a new price level.
+    pub confirmations: u32,
+    /// How far ahead of the local clock a tick may be stamped.
+    pub max_future: Duration,
+    /// How old a tick may be on arrival.
+    pub max_age: Duration,
+}
+
+impl Default for FeedSanitizerConfig {
+    fn default() -> Self {
+        Self {
+            equity_max_move_pct: Decimal::from(10),
+            option_max_move_pct: Decimal::from(50),
+            confirmations: 3,
+            max_future: Duration::from_secs(5),
+            max_age: Duration::from_secs(300),
+        }
+    }
+}
+
+/// Why a tick was rejected.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TickRejection {
+    /// Zero or negative price.
+    NonPositivePrice,
+    /// Too far from the last accepted price.
+    Outlier,
+    /// Stamped too far in the future.
+    FromFuture,
+    /// Too old on arrival.
+    Stale,
+}
+
+impl fmt::Display for TickRejection {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::NonPositivePrice => write!(f, "NON_POSITIVE_PRICE"),
+            Self::Outlier => write!(f, "OUTLIER"),
+            Self::FromFuture => write!(f, "FUTURE_TIMESTAMP"),
+            Self::Stale => write!(f, "STALE"),
+        }
+    }
+}
+
+/// Ticks rejected for one symbol, by reason.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct RejectedTicks {
+    /// Zero or negative prices.
+    pub non_positive_price: u64,
+    /// Outliers.
+    pub outlier: u64,
+    /// Future timestamps.
+    pub future_timestamp: u64,
+    /// Stale ticks.
+    pub stale: u64,
+}
+
+impl RejectedTicks {
+    /// Total rejected ticks.
+    #[must_use]
+    pub const fn total(&self) -> u64 {
+        self.non_positive_price + self.outlier + self.future_timestamp + self.stale
+    }
+
+    const fn count(&mut self, rejection: TickRejection) {
+        match rejection {
+            TickRejection::NonPositivePrice => self.non_positive_price += 1,
+            TickRejection::Outlier => self.outlier += 1,
+            TickRejection::FromFuture => self.future_timestamp += 1,
+            TickRejection::Stale => self.stale += 1,
+        }
+    }
+}
+
+#[derive(Debug, Default)]
+struct SymbolState {
+    last_price: Option<Decimal>,
+    outlier_streak: u32,
+    rejected: RejectedTicks,
+}
+
+/// Screens live ticks and counts rejections.
+#[derive(Debug)]
+pub struct FeedSanitizer {
+    config: FeedSanitizerConfig,
+    clock: Arc<dyn Clock>,
+    symbols: RwLock<HashMap<String, SymbolState>>,
+}
+
+impl Default for FeedSanitizer {
+    fn default() -> Self {
+        Self::new(FeedSanitizerConfig::default())
+    }
+}
+
+impl FeedSanitizer {
+    /// Create a sanitizer with `config` limits.
+    #[must_use]
+    pub fn new(config: FeedSanitizerConfig) -> Self {
+        Self {
+            config,
+            clock: SystemClock::shared(),
+            symbols: RwLock::new(HashMap::new()),
+        }
+    }
+
+    /// Use `clock` to judge tick timestamps.
+    #[must_use]
+    pub fn with_clock(mut self, clock: Arc<dyn Clock>) -> Self {
+        self.clock = clock;
+        self
+    }
+
+    /// Screen a quote, judging moves by its mid (or ask, without a bid).
+    ///
+    /// # Errors
+    ///
+    /// Returns the reason the quote should be dropped.
+    pub fn check_quote(&self, quote: &QuoteUpdate) -> Result<(), TickRejection> {
+        // Options far out of the money legitimately quote a zero bid
+        let price = if quote.bid < Decimal::ZERO {
+            Decimal::ZERO
+        } else if quote.bid.is_zero() {
+            quote.ask
+        } else {
+            quote.mid_price()
+        };
+        self.check(
+            &quote.symbol,
+            price,
+            quote.is_option,
+            Timestamp::new(quote.timestamp),
+        )
+    }
+
+    /// Screen a stock trade.
+    ///
+    /// # Errors
+    ///
+    /// Returns the reason the trade should be dropped.
+    pub fn check_trade(
+        &self,
+        symbol: &str,
+        price: Decimal,
+        at: Timestamp,
+    ) -> Result<(), TickRejection> {
+        self.check(symbol, price, false, at)
+    }
+
+    /// Rejected ticks by symbol, for symbols with at least one rejection.
+    #[must_use]
+    pub fn rejections(&self) -> BTreeMap<String, RejectedTicks> {
+        self.symbols
+            .read()
+            .unwrap_or_else(PoisonError::into_inner)
+            .iter()
+            .filter(|(_, state)| state.rejected.total() > 0)
+            .map(|(symbol, state)| (symbol.clone(), state.rejected.clone()))
+            .collect()
+    }
+
+    fn check(
+        &self,
+        symbol: &str,
+        price: Decimal,
+        is_option: bool,
+        at: Timestamp,
+    ) -> Result<(), TickRejection> {
+        let now = self.clock.now();
+        let mut symbols = self.symbols.write().unwrap_or_else(PoisonError::into_inner);
+        let state = symbols.entry(symbol.to_string()).or_default();
+
+        let result = self.screen(state, price, is_option, at, now);
+        if let Err(rejection) = result {
+            state.rejected.count(rejection);
+            tracing::debug!(
+                symbol = %symbol,
+                price = %price,
+                last_price = ?state.last_price,
+                reason = %rejection,
+                "Dropped feed tick"
+            );
+        }
+        drop(symbols);
+        result
+    }
+
+    fn screen(
+        &self,
+        state: &mut SymbolState,
+        price: Decimal,
+        is_option: bool,
+        at: Timestamp,
+        now: Timestamp,
+    ) -> Result<(), TickRejection> {
+        if price <= Decimal::ZERO {
+            return Err(TickRejection::NonPositivePrice);
+        }
+        let ahead = at.duration_since(now).to_std().unwrap_or_default();
+        if ahead > self.config.max_future {
+            return Err(TickRejection::FromFuture);
+        }
+        let age = now.duration_since(at).to_std().unwrap_or_default();
+        if age > self.config.max_age {
+            return Err(TickRejection::Stale);
+        }
+
+        let max_move_pct = if is_option {
+            self.config.option_max_move_pct
+        } else {
+            self.config.equity_max_move_pct
+        };
+        if let Some(last) = state.last_price
+            && max_move_pct > Decimal::ZERO
+        {
+            let move_pct = ((price - last) / last).abs() * Decimal::ONE_HUNDRED;
+            if move_pct > max_move_pct {
+                state.outlier_streak += 1;
+                if state.outlier_streak < self.config.confirmations {
+                    return Err(TickRejection::Outlier);
+                }
+                tracing::info!(
+                    previous = %last,
+                    price = %price,
+                    ticks = state.outlier_streak,
+                    "Feed price level confirmed after consecutive outliers"
+                );
+            }
+        }
+
+        state.last_price = Some(price);
+        state.outlier_streak = 0;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::application::ports::SimulatedClock;
+
+    fn now() -> Timestamp {
+        Timestamp::parse("2026-03-02T15:00:00Z").unwrap()
+    }
+
+    fn sanitizer() -> FeedSanitizer {
+        FeedSanitizer::default().with_clock(Arc::new(SimulatedClock::new(now())))
+    }
+
+    fn seconds(offset: i64) -> Timestamp {
+        Timestamp::new(now().as_datetime() + chrono::Duration::seconds(offset))
+    }
+
+    #[test]
+    fn rejects_bad_prices_and_timestamps() {
+        let feed = sanitizer();
+
+        assert_eq!(
+            feed.check_trade("AAPL", Decimal::ZERO, now()),
+            Err(TickRejection::NonPositivePrice)
+        );
+        assert_eq!(
+            feed.check_trade("AAPL", Decimal::new(100, 0), seconds(60)),
+            Err(TickRejection::FromFuture)
+        );
+        assert_eq!(
+            feed.check_trade("AAPL", Decimal::new(100, 0), seconds(-600)),
+            Err(TickRejection::Stale)
+        );
+        assert!(
+            feed.check_trade("AAPL", Decimal::new(100, 0), now())
+                .is_ok()
+        );
+
+        let rejected = &feed.rejections()["AAPL"];
+        assert_eq!(rejected.total(), 3);
+        assert_eq!(rejected.stale, 1);
+    }
+
+    #[test]
+    fn outliers_are_dropped_until_confirmed() {
+        let feed = sanitizer();
+        assert!(
+            feed.check_trade("AAPL", Decimal::new(100, 0), now())
+                .is_ok()
+        );
+        assert!(
+            feed.check_trade("AAPL", Decimal::new(105, 0), now())
+                .is_ok()
+        );
+
+        // A single bad print is dropped and the level holds
+        assert_eq!(
+            feed.check_trade("AAPL", Decimal::new(10, 0), now()),
+            Err(TickRejection::Outlier)
+        );
+        assert!(
+            feed.check_trade("AAPL", Decimal::new(106, 0), now())
+                .is_ok()
+        );
+
+        // A real gap is accepted on the third consecutive tick
+        assert!(
+            feed.check_trade("AAPL", Decimal::new(80, 0), now())
+                .is_err()
+        );
+        assert!(
+            feed.check_trade("AAPL", Decimal::new(80, 0), now())
+                .is_err()
+        );
+        assert!(feed.check_trade("AAPL", Decimal::new(80, 0), now()).is_ok());
+        assert!(feed.check_trade("AAPL", Decimal::new(81, 0), now()).is_ok());
+        assert_eq!(feed.rejections()["AAPL"].outlier, 3);
+    }
+
+    #[test]
+    fn options_allow_larger_moves_and_zero_bids() {
+        let feed = sanitizer();
+        let quote = |bid: i64, ask: i64| QuoteUpdate {
+            symbol: "AAPL260320C00250000".to_string(),
+            bid: Decimal::new(bid, 2),
+            ask: Decimal::new(ask, 2),
+            bid_size: 1,
+            ask_size: 1,
+            timestamp: now().as_datetime(),
+            is_option: true,
+        };
+
+        assert!(feed.check_quote(&quote(0, 10)).is_ok());
+        assert!(feed.check_quote(&quote(10, 14)).is_ok());
+        assert_eq!(
+            feed.check_quote(&quote(0, 0)),
+            Err(TickRejection::NonPositivePrice)
+        );
+        assert!(feed.rejections().contains_key("AAPL260320C00250000"));
+    }
+}
@@ -0,0 +1,462 @@
+//! Feed Subscription Reconciler
+//!
+//! Keeps quote feed subscriptions in line with what the engine needs. Every
+//! pass collects the desired symbols (open positions, symbols of working
+//! orders, the configured watchlist and any [`WatchlistService`] lists),
+//! diffs them against the provider's active subscriptions, and subscribes or
+//! unsubscribes the difference. A watchlist change triggers a pass at once.
+//! Symbols picked up outside the reconciler, such as by the position
+//! monitor, are dropped once nothing needs them.
+//!
+//! A pass is skipped when positions or working orders cannot be read, so a
+//! broker outage never unsubscribes the symbols of open positions.
+
+use std::collections::BTreeSet;
+use std::sync::Arc;
+use std::time::Duration;
+
+use tokio_util::sync::CancellationToken;
+
+use super::WatchlistService;
+use crate::application::ports::{BrokerError, BrokerPort, QuoteProviderPort, WatchlistError};
+use crate::domain::order_execution::errors::OrderError;
+use crate::domain::order_execution::repository::OrderRepository;
+use crate::domain::shared::Symbol;
+
+/// Configuration for subscription reconciliation.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SubscriptionReconcilerConfig {
+    /// Time between reconciliation passes.
+    pub interval: Duration,
+    /// Symbols to keep subscribed regardless of positions and orders.
+    pub watchlist: Vec<String>,
+}
+
+impl Default for SubscriptionReconcilerConfig {
+    fn default() -> Self {
+        Self {
+            interval: Duration::from_secs(30),
+            watchlist: Vec::new(),
+        }
+    }
+}
+
+/// Subscription reconciliation errors.
+#[derive(Debug, thiserror::Error)]
+pub enum SubscriptionReconcileError {
+    /// Positions could not be read.
+    #[error(transparent)]
+    Broker(#[from] BrokerError),
+
+    /// Working orders could not be read.
+    #[error(transparent)]
+    Orders(#[from] OrderError),
+
+    /// Watchlists could not be read.
+    #[error(transparent)]
+    Watchlists(#[from] WatchlistError),
+}
+
+/// Subscriptions changed by a reconciliation pass.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct SubscriptionChanges {
+    /// Newly subscribed symbols.
+    pub subscribed: Vec<String>,
+    /// Dropped symbols.
+    pub unsubscribed: Vec<String>,
+}
+
+impl SubscriptionChanges {
+    /// Check if the pass changed nothing.
+    #[must_use]
+    pub const fn is_empty(&self) -> bool {
+        self.subscribed.is_empty() && self.unsubscribed.is_empty()
+    }
+}
+
+/// Reconciles quote feed subscriptions with the symbols the engine needs.
+pub struct SubscriptionReconciler<B, O, Q>
+where
+    B: BrokerPort,
+    O: OrderRepository,
+    Q: QuoteProviderPort,
+{
+    config: SubscriptionReconcilerConfig,
+    broker: Arc<B>,
+    order_repo: Arc<O>,
+    quote_provider: Arc<Q>,
+    watchlists: Option<Arc<WatchlistService>>,
+}
+
+impl<B, O, Q> SubscriptionReconciler<B, O, Q>
+where
+    B: BrokerPort,
+    O: OrderRepository,
+    Q: QuoteProviderPort,
+{
+    /// Create a reconciler.
+    pub const fn new(
+        config: SubscriptionReconcilerConfig,
+        broker: Arc<B>,
+        order_repo: Arc<O>,
+        quote_provider: Arc<Q>,
+    ) -> Self {
+        Self {
+            config,
+            broker,
+            order_repo,
+            quote_provider,
+            watchlists: None,
+        }
+    }
+
+    /// Keep the symbols of `watchlists` subscribed as well.
+    #[must_use]
+    pub fn with_watchlists(mut self, watchlists: Arc<WatchlistService>) -> Self {
+        self.watchlists = Some(watchlists);
+        self
+    }
+
+    /// Symbols that should be subscribed.
+    ///
+    /// # Errors
+    ///
+    /// Returns error if positions, working orders or watchlists cannot be
+    /// read.
+    pub async fn desired_symbols(&self) -> Result<BTreeSet<String>, SubscriptionReconcileError> {
+        let positions = self.broker.get_all_positions().await?;
+        let orders = self.order_repo.find_active().await?;
+        let watched = match &self.watchlists {
+            Some(watchlists) => watchlists.symbols().await?,
+            None => BTreeSet::new(),
+        };
+
+        Ok(positions
+            .into_iter()
+            .map(|p| p.symbol)
+            .chain(orders.iter().map(|o| o.symbol().as_str().to_string()))
+            .chain(self.config.watchlist.iter().cloned())
+            .chain(watched)
+            .map(|s| s.trim().to_uppercase())
+            .filter(|s| !s.is_empty())
+            .collect())
+    }
+
+    /// Run one reconciliation pass.
+    ///
+    /// A failed subscribe or unsubscribe call is logged and retried on the
+    /// next pass.
+    ///
+    /// # Errors
+    ///
+    /// Returns error if the desired symbols cannot be determined; nothing is
+    /// changed in that case.
+    pub async fn reconcile(&self) -> Result<SubscriptionChanges, SubscriptionReconcileError> {
+        let desired = self.desired_symbols().await?;
+        let (desired_options, desired_stocks): (BTreeSet<String>, BTreeSet<String>) = desired
+            .into_iter()
+            .partition(|s| Symbol::new(s.as_str()).is_option());
+        let active_stocks: BTreeSet<String> = self
+            .quote_provider
+            .stock_subscriptions()
+            .into_iter()
+            .collect();
+        let active_options: BTreeSet<String> = self
+            .quote_provider
+            .options_subscriptions()
+            .into_iter()
+            .collect();
+
+        let mut changes = SubscriptionChanges::default();
+        self.apply(&desired_stocks, &active_stocks, false, &mut changes)
+            .await;
+        self.apply(&desired_options, &active_options, true, &mut changes)
+            .await;
+
+        if !changes.is_empty() {
+            tracing::info!(
+                subscribed = ?changes.subscribed,
+                unsubscribed = ?changes.unsubscribed,
+                "Reconciled feed subscriptions"
+            );
+        }
+        Ok(changes)
+    }
+
+    /// Subscribe `desired - active` and unsubscribe `active - desired`.
+    async fn apply(
+        &self,
+        desired: &BTreeSet<String>,
+        active: &BTreeSet<String>,
+        options: bool,
+        changes: &mut SubscriptionChanges,
+    ) {
+        let added: Vec<String> = desired.difference(active).cloned().collect();
+        let removed: Vec<String> = active.difference(desired).cloned().collect();
+
+        if !added.is_empty() {
+            let result = if options {
+                self.quote_provider.subscribe_options_quotes(&added).await
+            } else {
+                self.quote_provider.subscribe_stock_quotes(&added).await
+            };
+            match result {
+                Ok(()) => changes.subscribed.extend(added),
+                Err(e) => tracing::warn!(symbols = ?added, error = %e, "Feed subscribe failed"),
+            }
+        }
+
+        if !removed.is_empty() {
+            let result = if options {
+                self.quote_provider
+                    .unsubscribe_options_quotes(&removed)
+                    .await
+            } else {
+                self.quote_provider.unsubscribe_stock_quotes(&removed).await
+            };
+            match result {
+                Ok(()) => changes.unsubscribed.extend(removed),
+                Err(e) => {
+                    tracing::warn!(symbols = ?removed, error = %e, "Feed unsubscribe failed");
+                }
+            }
+        }
+    }
+
+    /// Reconcile every `interval`, and whenever a watchlist changes, until
+    /// `shutdown`.
+    pub fn start(self: &Arc<Self>, shutdown: CancellationToken)
+    where
+        B: 'static,
+        O: 'static,
+        Q: 'static,
+    {
+        let reconciler = Arc::clone(self);
+        let run_interval = self.config.interval.max(Duration::from_secs(1));
+        let mut watchlist_changes = self.watchlists.as_ref().map(|w| w.changes());
+
+        tokio::spawn(async move {
+            let mut interval = tokio::time::interval(run_interval);
+
+            loop {
+                tokio::select! {
+                    _ = interval.tick() => {}
+                    Some(Ok(())) = async {
+                        match watchlist_changes.as_mut() {
+                            Some(changes) => Some(changes.changed().await),
+                            None => None,
+                        }
+                    } => {}
+                    () = shutdown.cancelled() => {
+                        tracing::info!("Feed subscription reconciler shutting down");
+                        break;
+                    }
+                }
+                if let Err(e) = reconciler.reconcile().await {
+                    tracing::warn!(error = %e, "Skipped feed subscription reconciliation");
+                }
+            }
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::atomic::{AtomicBool, Ordering};
+
+    use async_trait::async_trait;
+    use rust_decimal::Decimal;
+
+    use super::*;
+    use crate::application::ports::{
+        CancelOrderRequest, InMemoryWatchlistRepository, OrderAck, PositionInfo, SubmitOrderRequest,
+    };
+    use crate::domain::order_execution::aggregate::{CreateOrderCommand, Order};
+    use crate::domain::order_execution::value_objects::{
+        OrderPurpose, OrderSide, OrderType, TimeInForce,
+    };
+    use crate::domain::shared::{BrokerId, InstrumentId, Money, Quantity};
+    use crate::infrastructure::persistence::InMemoryOrderRepository;
+    use crate::infrastructure::stream_proxy::{ProxyQuoteManager, ProxyQuoteManagerConfig};
+
+    #[derive(Default)]
+    struct PositionsBroker {
+        positions: Vec<String>,
+        down: AtomicBool,
+    }
+
+    #[async_trait]
+    impl BrokerPort for PositionsBroker {
+        async fn submit_order(
+            &self,
+            _request: SubmitOrderRequest,
+        ) -> Result<OrderAck, BrokerError> {
+            Err(BrokerError::Unknown {
+                message: "Not implemented".to_string(),
+            })
+        }
+
+        async fn cancel_order(&self, _request: CancelOrderRequest) -> Result<(), BrokerError> {
+            Ok(())
+        }
+
+        async fn get_order(&self, _broker_order_id: &BrokerId) -> Result<OrderAck, BrokerError> {
+            Err(BrokerError::OrderNotFound {
+                order_id: "unknown".to_string(),
+            })
+        }
+
+        async fn get_open_orders(&self) -> Result<Vec<OrderAck>, BrokerError> {
+            Ok(vec![])
+        }
+
+        async fn get_buying_power(&self) -> Result<Decimal, BrokerError> {
+            Ok(Decimal::ZERO)
+        }
+
+        async fn get_position(
+            &self,
+            _instrument_id: &InstrumentId,
+        ) -> Result<Option<Decimal>, BrokerError> {
+            Ok(None)
+        }
+
+        async fn get_all_positions(&self) -> Result<Vec<PositionInfo>, BrokerError> {
+            if self.down.load(Ordering::Relaxed) {
+                return Err(BrokerError::ConnectionError {
+                    message: "connection refused".to_string(),
+                });
+            }
+            Ok(self
+                .positions
+                .iter()
+                .map(|symbol| PositionInfo {
+                    symbol: symbol.clone(),
+                    quantity: Decimal::ONE_HUNDRED,
+                    avg_entry_price: Decimal::ONE_HUNDRED,
+                    market_value: Decimal::ONE_HUNDRED,
+                    unrealized_pnl: Decimal::ZERO,
+                    current_price: Decimal::ONE_HUNDRED,
+                    opened_at: None,
+                })
+                .collect())
+        }
+    }
+
+    fn working_order(symbol: &str) -> Order {
+        let mut order = Order::new(CreateOrderCommand {
+            symbol: Symbol::new(symbol),
+            side: OrderSide::Buy,
+            order_type: OrderType::Limit,
+            quantity: Quantity::from_i64(1),
+            limit_price: Some(Money::new(Decimal::ONE)),
+            stop_price: None,
+            time_in_force: TimeInForce::Day,
+            purpose: OrderPurpose::Entry,
+            legs: vec![],
+        })
+        .unwrap();
+        order
+            .accept(BrokerId::new(format!("broker-{symbol}")))
+            .unwrap();
+        order
+    }
+
+    async fn reconciler(
+        broker: PositionsBroker,
+        orders: &[&str],
+        watchlist: &[&str],
+    ) -> SubscriptionReconciler<PositionsBroker, InMemoryOrderRepository, ProxyQuoteManager> {
+        let order_repo = InMemoryOrderRepository::new();
+        for symbol in orders {
+            order_repo.save(&working_order(symbol)).await.unwrap();
+        }
+        SubscriptionReconciler::new(
+            SubscriptionReconcilerConfig {
+                watchlist: watchlist.iter().map(ToString::to_string).collect(),
+                ..SubscriptionReconcilerConfig::default()
+            },
+            Arc::new(broker),
+            Arc::new(order_repo),
+            Arc::new(ProxyQuoteManager::new(
+                ProxyQuoteManagerConfig::default(),
+                CancellationToken::new(),
+            )),
+        )
+    }
+
+    #[tokio::test]
+    async fn subscribes_needed_symbols_and_drops_stale_ones() {
+        let broker = PositionsBroker {
+            positions: vec!["AAPL".to_string(), "AAPL260320C00150000".to_string()],
+            ..PositionsBroker::default()
+        };
+        let reconciler = reconciler(broker, &["MSFT"], &["spy"]).await;
+        let provider = Arc::clone(&reconciler.quote_provider);
+        provider
+            .subscribe_stock_quotes(&["TSLA".to_string()])
+            .await
+            .unwrap();
+
+        let changes = reconciler.reconcile().await.unwrap();
+
+        assert_eq!(
+            changes.subscribed,
+            vec!["AAPL", "MSFT", "SPY", "AAPL260320C00150000"]
+        );
+        assert_eq!(changes.unsubscribed, vec!["TSLA"]);
+        assert_eq!(
+            provider.options_subscriptions(),
+            vec!["AAPL260320C00150000"]
+        );
+        let mut stocks = provider.stock_subscriptions();
+        stocks.sort();
+        assert_eq!(stocks, vec!["AAPL", "MSFT", "SPY"]);
+
+        // Nothing to do once in line
+        assert!(reconciler.reconcile().await.unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn watchlist_symbols_are_subscribed() {
+        let watchlists = Arc::new(WatchlistService::new(Arc::new(
+            InMemoryWatchlistRepository::new(),
+        )));
+        watchlists
+            .create("index", &["SPY".to_string(), "QQQ".to_string()])
+            .await
+            .unwrap();
+        let reconciler = reconciler(PositionsBroker::default(), &[], &[])
+            .await
+            .with_watchlists(Arc::clone(&watchlists));
+
+        let changes = reconciler.reconcile().await.unwrap();
+        assert_eq!(changes.subscribed, vec!["QQQ", "SPY"]);
+
+        watchlists
+            .remove_symbols("index", &["QQQ".to_string()])
+            .await
+            .unwrap();
+        let changes = reconciler.reconcile().await.unwrap();
+        assert_eq!(changes.unsubscribed, vec!["QQQ"]);
+    }
+
+    #[tokio::test]
+    async fn broker_outage_leaves_subscriptions_alone() {
+        let broker = PositionsBroker {
+            positions: vec!["AAPL".to_string()],
+            ..PositionsBroker::default()
+        };
+        let reconciler = reconciler(broker, &[], &[]).await;
+        reconciler.reconcile().await.unwrap();
+
+        reconciler.broker.down.store(true, Ordering::Relaxed);
+        let result = reconciler.reconcile().await;
+
+        assert!(matches!(result, Err(SubscriptionReconcileError::Broker(_))));
+        assert_eq!(
+            reconciler.quote_provider.stock_subscriptions(),
+            vec!["AAPL"]
+        );
+    }
+}
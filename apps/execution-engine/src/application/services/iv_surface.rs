@@ -0,0 +1,252 @@
+//! IV Surface Service
+//!
+//! Builds implied volatility surfaces from option chain snapshots so every
+//! consumer prices options off the same smoothed volatilities. Each quote is
+//! priced at its bid/ask mid; quotes without a two-sided market are
+//! skipped.
+
+use std::sync::Arc;
+
+use chrono::NaiveDate;
+use rust_decimal::Decimal;
+use rust_decimal::prelude::ToPrimitive;
+
+use crate::application::ports::{
+    MarketDataError, MarketDataPort, OptionChainData, OptionChainFilter, OptionType,
+};
+use crate::domain::option_position::{IvQuote, IvSurface, OptionPositionError, OptionRight};
+use crate::domain::shared::{ClassifiedError, ErrorKind};
+
+/// Risk-free rate used when a request does not supply one.
+pub const DEFAULT_RISK_FREE_RATE: f64 = 0.04;
+
+/// IV surface construction errors.
+#[derive(Debug, thiserror::Error)]
+pub enum IvSurfaceError {
+    /// The option chain could not be fetched.
+    #[error(transparent)]
+    MarketData(#[from] MarketDataError),
+
+    /// The chain has no quotes a surface can be fitted to.
+    #[error(transparent)]
+    Surface(#[from] OptionPositionError),
+}
+
+impl ClassifiedError for IvSurfaceError {
+    fn code(&self) -> &str {
+        match self {
+            Self::MarketData(e) => e.code(),
+            Self::Surface(_) => "IV_SURFACE_UNAVAILABLE",
+        }
+    }
+
+    fn kind(&self) -> ErrorKind {
+        match self {
+            Self::MarketData(e) => e.kind(),
+            Self::Surface(_) => ErrorKind::FailedPrecondition,
+        }
+    }
+}
+
+/// Fits IV surfaces to option chains from a [`MarketDataPort`].
+pub struct IvSurfaceService<M>
+where
+    M: MarketDataPort,
+{
+    market_data: Arc<M>,
+    risk_free_rate: f64,
+}
+
+impl<M> IvSurfaceService<M>
+where
+    M: MarketDataPort,
+{
+    /// Create a service using [`DEFAULT_RISK_FREE_RATE`].
+    #[must_use]
+    pub const fn new(market_data: Arc<M>) -> Self {
+        Self {
+            market_data,
+            risk_free_rate: DEFAULT_RISK_FREE_RATE,
+        }
+    }
+
+    /// Use `rate` (annual, continuously compounded) by default.
+    #[must_use]
+    pub const fn with_risk_free_rate(mut self, rate: f64) -> Self {
+        self.risk_free_rate = rate;
+        self
+    }
+
+    /// Default risk-free rate.
+    #[must_use]
+    pub const fn risk_free_rate(&self) -> f64 {
+        self.risk_free_rate
+    }
+
+    /// Fit a surface to the options of `underlying` matching `filter`.
+    ///
+    /// # Errors
+    ///
+    /// Returns error if the chain cannot be fetched or has no usable quotes.
+    pub async fn surface(
+        &self,
+        underlying: &str,
+        filter: &OptionChainFilter,
+        rate: Option<f64>,
+    ) -> Result<IvSurface, IvSurfaceError> {
+        let chain = self
+            .market_data
+            .get_filtered_option_chain(underlying, filter)
+            .await?;
+        Ok(fit_chain(&chain, rate.unwrap_or(self.risk_free_rate))?)
+    }
+}
+
+/// Fit a surface to an option chain snapshot.
+///
+/// # Errors
+///
+/// Returns error if the chain has no usable quotes.
+pub fn fit_chain(chain: &OptionChainData, rate: f64) -> Result<IvSurface, OptionPositionError> {
+    let spot = chain.underlying_price.to_f64().unwrap_or_default();
+    let as_of = chain.as_of.as_datetime().date_naive();
+    IvSurface::fit(spot, rate, as_of, &iv_quotes(chain))
+}
+
+/// Mid-price quotes for the options with a two-sided market.
+#[must_use]
+pub fn iv_quotes(chain: &OptionChainData) -> Vec<IvQuote> {
+    chain
+        .options
+        .iter()
+        .filter_map(|option| {
+            let quote = option.quote.as_ref()?;
+            if quote.bid <= Decimal::ZERO || quote.ask < quote.bid {
+                return None;
+            }
+            let contract = &option.contract;
+            Some(IvQuote {
+                expiration: NaiveDate::parse_from_str(&contract.expiration, "%Y-%m-%d").ok()?,
+                strike: contract.strike.to_f64()?,
+                right: match contract.option_type {
+                    OptionType::Call => OptionRight::Call,
+                    OptionType::Put => OptionRight::Put,
+                },
+                price: ((quote.bid + quote.ask) / Decimal::TWO).to_f64()?,
+            })
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::application::ports::{MarketQuote, OptionContract, OptionQuote};
+    use crate::domain::option_position::services::black_scholes_price;
+    use crate::domain::shared::Timestamp;
+    use rust_decimal_macros::dec;
+
+    fn option(
+        expiration: &str,
+        strike: Decimal,
+        option_type: OptionType,
+        bid: Decimal,
+        ask: Decimal,
+    ) -> OptionQuote {
+        OptionQuote {
+            contract: OptionContract {
+                underlying: "SPY".to_string(),
+                expiration: expiration.to_string(),
+                strike,
+                option_type,
+            },
+            quote: Some(MarketQuote {
+                symbol: String::new(),
+                bid,
+                ask,
+                bid_size: 10,
+                ask_size: 10,
+                last: Decimal::ZERO,
+                last_size: 0,
+                volume: 0,
+                timestamp: Timestamp::now(),
+            }),
+            implied_volatility: None,
+            greeks: None,
+            open_interest: 0,
+        }
+    }
+
+    #[test]
+    fn quotes_use_mid_of_two_sided_markets() {
+        let chain = OptionChainData {
+            underlying: "SPY".to_string(),
+            underlying_price: dec!(500),
+            options: vec![
+                option(
+                    "2025-02-21",
+                    dec!(500),
+                    OptionType::Call,
+                    dec!(9.8),
+                    dec!(10.2),
+                ),
+                option(
+                    "2025-02-21",
+                    dec!(510),
+                    OptionType::Call,
+                    dec!(0),
+                    dec!(0.05),
+                ),
+                option("bad-date", dec!(490), OptionType::Put, dec!(5), dec!(5.2)),
+            ],
+            as_of: Timestamp::now(),
+        };
+
+        let quotes = iv_quotes(&chain);
+
+        assert_eq!(quotes.len(), 1);
+        assert!((quotes[0].price - 10.0).abs() < 1e-9);
+        assert_eq!(quotes[0].right, OptionRight::Call);
+    }
+
+    #[test]
+    fn fits_surface_to_chain() {
+        let as_of = Timestamp::parse("2025-01-02T15:00:00Z").unwrap();
+        let years = 30.0 / 365.0;
+        let options = [dec!(95), dec!(100), dec!(105)]
+            .into_iter()
+            .map(|strike| {
+                let right = if strike < dec!(100) {
+                    OptionRight::Put
+                } else {
+                    OptionRight::Call
+                };
+                let price = black_scholes_price(
+                    right,
+                    100.0,
+                    strike.to_f64().unwrap(),
+                    years,
+                    DEFAULT_RISK_FREE_RATE,
+                    0.2,
+                );
+                let price = Decimal::try_from(price).unwrap().round_dp(4);
+                let option_type = match right {
+                    OptionRight::Call => OptionType::Call,
+                    OptionRight::Put => OptionType::Put,
+                };
+                option("2025-02-01", strike, option_type, price, price)
+            })
+            .collect();
+        let chain = OptionChainData {
+            underlying: "SPY".to_string(),
+            underlying_price: dec!(100),
+            options,
+            as_of,
+        };
+
+        let surface = fit_chain(&chain, DEFAULT_RISK_FREE_RATE).unwrap();
+
+        assert_eq!(surface.slices().len(), 1);
+        assert!((surface.slices()[0].atm_volatility() - 0.2).abs() < 1e-3);
+    }
+}
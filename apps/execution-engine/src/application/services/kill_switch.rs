@@ -0,0 +1,376 @@
+//! Kill Switch
+//!
+//! Operator trip-wire that halts new order submission. The switch is engaged
+//! by the admin API, by the presence of a configured file, or at startup
+//! from the environment. Order paths call [`KillSwitch::check`] immediately
+//! before each broker submission, and rejected plans carry a
+//! [`TRADING_DISABLED`] violation.
+//!
+//! Protective exits (stop-loss and take-profit monitoring) are not blocked,
+//! so tripping the switch never strands a position without its stops. With
+//! `flatten_on_trip` set, the watcher started by [`KillSwitch::start`] also
+//! cancels open orders and closes every position at market whenever the
+//! switch goes from clear to tripped.
+
+use std::path::PathBuf;
+use std::sync::{Arc, PoisonError, RwLock};
+use std::time::Duration;
+
+use rust_decimal::Decimal;
+use tokio::sync::Notify;
+use tokio_util::sync::CancellationToken;
+
+use crate::application::ports::{BrokerPort, CancelOrderRequest, SubmitOrderRequest};
+use crate::domain::order_execution::value_objects::OrderSide;
+use crate::domain::risk_management::value_objects::ConstraintViolation;
+use crate::domain::shared::{OrderId, Symbol};
+
+/// Violation code for submissions blocked by the kill switch.
+pub const TRADING_DISABLED: &str = "TRADING_DISABLED";
+
+/// Configuration for the kill switch.
+#[derive(Debug, Clone)]
+pub struct KillSwitchConfig {
+    /// Trading is disabled while this file exists.
+    pub file_path: Option<PathBuf>,
+    /// Start with the switch engaged.
+    pub engaged: bool,
+    /// Cancel open orders and close all positions when tripped.
+    pub flatten_on_trip: bool,
+    /// How often the watcher checks the file (seconds).
+    pub poll_interval_secs: u64,
+}
+
+impl Default for KillSwitchConfig {
+    fn default() -> Self {
+        Self {
+            file_path: None,
+            engaged: false,
+            flatten_on_trip: false,
+            poll_interval_secs: 1,
+        }
+    }
+}
+
+/// Outcome of flattening the account.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct FlattenReport {
+    /// Open orders cancelled.
+    pub cancelled: usize,
+    /// Symbols with a closing order submitted.
+    pub closed: Vec<String>,
+    /// Failures, one per order or position.
+    pub errors: Vec<String>,
+}
+
+/// Shared kill switch state.
+#[derive(Debug)]
+pub struct KillSwitch {
+    config: KillSwitchConfig,
+    /// Reason the switch was tripped manually (`None` = not tripped).
+    manual: RwLock<Option<String>>,
+    changed: Notify,
+}
+
+impl Default for KillSwitch {
+    fn default() -> Self {
+        Self::new(KillSwitchConfig::default())
+    }
+}
+
+impl KillSwitch {
+    /// Create a kill switch.
+    #[must_use]
+    pub fn new(config: KillSwitchConfig) -> Self {
+        let manual = config.engaged.then(|| "engaged at startup".to_string());
+        Self {
+            config,
+            manual: RwLock::new(manual),
+            changed: Notify::new(),
+        }
+    }
+
+    /// Trip the switch.
+    pub fn trip(&self, reason: impl Into<String>) {
+        let reason = reason.into();
+        tracing::error!(%reason, "Kill switch tripped, trading disabled");
+        *self.manual.write().unwrap_or_else(PoisonError::into_inner) = Some(reason);
+        self.changed.notify_one();
+    }
+
+    /// Clear a manual trip.
+    ///
+    /// Trading stays disabled while the kill switch file exists.
+    pub fn reset(&self) {
+        *self.manual.write().unwrap_or_else(PoisonError::into_inner) = None;
+        tracing::warn!("Kill switch reset");
+        self.changed.notify_one();
+    }
+
+    /// Reason trading is disabled, or `None` if trading is allowed.
+    #[must_use]
+    pub fn status(&self) -> Option<String> {
+        let manual = self
+            .manual
+            .read()
+            .unwrap_or_else(PoisonError::into_inner)
+            .clone();
+        if manual.is_some() {
+            return manual;
+        }
+
+        self.config
+            .file_path
+            .as_ref()
+            .filter(|path| path.exists())
+            .map(|path| format!("kill switch file {} present", path.display()))
+    }
+
+    /// Check if trading is disabled.
+    #[must_use]
+    pub fn is_tripped(&self) -> bool {
+        self.status().is_some()
+    }
+
+    /// Check whether an order may be submitted.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`TRADING_DISABLED`] violation while the switch is tripped.
+    pub fn check(&self) -> Result<(), Box<ConstraintViolation>> {
+        self.status().map_or(Ok(()), |reason| {
+            Err(Box::new(ConstraintViolation::critical(
+                TRADING_DISABLED,
+                format!("Trading disabled: {reason}"),
+            )))
+        })
+    }
+
+    /// Cancel all open orders and close all positions at market.
+    pub async fn flatten<B: BrokerPort>(&self, broker: &B) -> FlattenReport {
+        let mut report = FlattenReport::default();
+
+        match broker.get_open_orders().await {
+            Ok(orders) => {
+                for order in orders {
+                    let request = CancelOrderRequest::by_broker_id(order.broker_order_id.clone());
+                    match broker.cancel_order(request).await {
+                        Ok(()) => report.cancelled += 1,
+                        Err(e) => report
+                            .errors
+                            .push(format!("cancel {}: {e}", order.broker_order_id)),
+                    }
+                }
+            }
+            Err(e) => report.errors.push(format!("list open orders: {e}")),
+        }
+
+        match broker.get_all_positions().await {
+            Ok(positions) => {
+                for position in positions.into_iter().filter(|p| !p.quantity.is_zero()) {
+                    let side = if position.quantity > Decimal::ZERO {
+                        OrderSide::Sell
+                    } else {
+                        OrderSide::Buy
+                    };
+                    let request = SubmitOrderRequest::market(
+                        OrderId::generate(),
+                        Symbol::new(&position.symbol),
+                        side,
+                        position.quantity.abs(),
+                    );
+                    match broker.submit_order(request).await {
+                        Ok(_) => report.closed.push(position.symbol),
+                        Err(e) => report
+                            .errors
+                            .push(format!("close {}: {e}", position.symbol)),
+                    }
+                }
+            }
+            Err(e) => report.errors.push(format!("list positions: {e}")),
+        }
+
+        report
+    }
+
+    /// Watch for trips from any source and flatten when configured.
+    ///
+    /// Does nothing unless `flatten_on_trip` is set.
+    pub fn start<B: BrokerPort + 'static>(
+        self: &Arc<Self>,
+        broker: Arc<B>,
+        shutdown: CancellationToken,
+    ) {
+        if !self.config.flatten_on_trip {
+            return;
+        }
+
+        let switch = Arc::clone(self);
+        let poll_interval = Duration::from_secs(self.config.poll_interval_secs.max(1));
+
+        tokio::spawn(async move {
+            // Engaged at startup counts as a trip
+            let mut was_tripped = false;
+
+            loop {
+                let tripped = switch.is_tripped();
+                if tripped && !was_tripped {
+                    let report = switch.flatten(broker.as_ref()).await;
+                    tracing::error!(
+                        cancelled = report.cancelled,
+                        closed = ?report.closed,
+                        errors = ?report.errors,
+                        "Kill switch flattened account"
+                    );
+                }
+                was_tripped = tripped;
+
+                tokio::select! {
+                    () = switch.changed.notified() => {}
+                    () = tokio::time::sleep(poll_interval) => {}
+                    () = shutdown.cancelled() => {
+                        tracing::info!("Kill switch watcher shutting down");
+                        break;
+                    }
+                }
+            }
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::application::ports::{BrokerError, OrderAck, PositionInfo};
+    use crate::domain::order_execution::value_objects::OrderStatus;
+    use crate::domain::shared::{BrokerId, InstrumentId};
+    use async_trait::async_trait;
+    use std::sync::Mutex;
+
+    #[derive(Default)]
+    struct MockBroker {
+        submitted: Mutex<Vec<SubmitOrderRequest>>,
+    }
+
+    #[async_trait]
+    impl BrokerPort for MockBroker {
+        async fn submit_order(&self, request: SubmitOrderRequest) -> Result<OrderAck, BrokerError> {
+            let ack = OrderAck {
+                broker_order_id: BrokerId::new("broker-1"),
+                client_order_id: request.client_order_id.clone(),
+                status: OrderStatus::Accepted,
+                filled_qty: Decimal::ZERO,
+                avg_fill_price: None,
+                child_order_ids: Vec::new(),
+                leg_fills: Vec::new(),
+            };
+            self.submitted.lock().unwrap().push(request);
+            Ok(ack)
+        }
+
+        async fn cancel_order(&self, _request: CancelOrderRequest) -> Result<(), BrokerError> {
+            Ok(())
+        }
+
+        async fn get_order(&self, broker_order_id: &BrokerId) -> Result<OrderAck, BrokerError> {
+            Err(BrokerError::OrderNotFound {
+                order_id: broker_order_id.to_string(),
+            })
+        }
+
+        async fn get_open_orders(&self) -> Result<Vec<OrderAck>, BrokerError> {
+            Ok(vec![OrderAck {
+                broker_order_id: BrokerId::new("open-1"),
+                client_order_id: OrderId::new("client-1"),
+                status: OrderStatus::Accepted,
+                filled_qty: Decimal::ZERO,
+                avg_fill_price: None,
+                child_order_ids: Vec::new(),
+                leg_fills: Vec::new(),
+            }])
+        }
+
+        async fn get_buying_power(&self) -> Result<Decimal, BrokerError> {
+            Ok(Decimal::ZERO)
+        }
+
+        async fn get_position(
+            &self,
+            _instrument_id: &InstrumentId,
+        ) -> Result<Option<Decimal>, BrokerError> {
+            Ok(None)
+        }
+
+        async fn get_all_positions(&self) -> Result<Vec<PositionInfo>, BrokerError> {
+            let position = |symbol: &str, quantity: i64| PositionInfo {
+                symbol: symbol.to_string(),
+                quantity: Decimal::from(quantity),
+                avg_entry_price: Decimal::ZERO,
+                market_value: Decimal::ZERO,
+                unrealized_pnl: Decimal::ZERO,
+                current_price: Decimal::ZERO,
+                opened_at: None,
+            };
+            Ok(vec![position("AAPL", 100), position("TSLA", -5)])
+        }
+    }
+
+    #[test]
+    fn trip_and_reset() {
+        let switch = KillSwitch::default();
+        assert!(switch.check().is_ok());
+
+        switch.trip("manual halt");
+        let violation = switch.check().unwrap_err();
+        assert_eq!(violation.code, TRADING_DISABLED);
+        assert!(violation.message.contains("manual halt"));
+
+        switch.reset();
+        assert!(!switch.is_tripped());
+    }
+
+    #[test]
+    fn file_presence_trips_switch() {
+        let path = std::env::temp_dir().join(format!("kill-switch-{}", OrderId::generate()));
+        let switch = KillSwitch::new(KillSwitchConfig {
+            file_path: Some(path.clone()),
+            ..KillSwitchConfig::default()
+        });
+        assert!(!switch.is_tripped());
+
+        std::fs::write(&path, "").unwrap();
+        assert!(switch.is_tripped());
+
+        // Reset only clears manual trips
+        switch.reset();
+        assert!(switch.is_tripped());
+
+        std::fs::remove_file(&path).unwrap();
+        assert!(!switch.is_tripped());
+    }
+
+    #[test]
+    fn engaged_at_startup() {
+        let switch = KillSwitch::new(KillSwitchConfig {
+            engaged: true,
+            ..KillSwitchConfig::default()
+        });
+        assert!(switch.is_tripped());
+    }
+
+    #[tokio::test]
+    async fn flatten_cancels_orders_and_closes_positions() {
+        let broker = MockBroker::default();
+        let report = KillSwitch::default().flatten(&broker).await;
+
+        assert_eq!(report.cancelled, 1);
+        assert_eq!(report.closed, vec!["AAPL", "TSLA"]);
+        assert!(report.errors.is_empty());
+
+        let submitted = broker.submitted.lock().unwrap();
+        assert_eq!(submitted[0].side, OrderSide::Sell);
+        assert_eq!(submitted[0].quantity, Decimal::from(100));
+        assert_eq!(submitted[1].side, OrderSide::Buy);
+        assert_eq!(submitted[1].quantity, Decimal::from(5));
+    }
+}
@@ -0,0 +1,264 @@
+//! Leader Election
+//!
+//! Keeps two engine instances on one account from both trading. The
+//! instance holding the leader lock is the leader; the other is a standby
+//! that serves queries and follows fills but does not write to the broker.
+//! Order paths call [`LeaderElection::check`] before submitting, and the
+//! broker adapter refuses order writes while the instance is a standby.
+//!
+//! A standby that acquires the lock does not trade at once. It waits out
+//! the takeover delay, long enough for a previous leader to notice it lost
+//! the lock and stop, confirms it still holds the lock, and runs the
+//! takeover check (a broker reconciliation) before promoting itself. A
+//! leader that loses the lock drops to standby on its next heartbeat.
+
+use std::fmt;
+use std::future::Future;
+use std::sync::Arc;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::time::Duration;
+
+use tokio_util::sync::CancellationToken;
+
+use crate::application::ports::LeaderLockPort;
+use crate::domain::risk_management::value_objects::ConstraintViolation;
+
+/// Violation code for plans submitted to a standby instance.
+pub const NOT_LEADER: &str = "NOT_LEADER";
+
+/// Configuration for leader election.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct LeaderElectionConfig {
+    /// How often the leader confirms the lock and a standby tries to take it.
+    pub heartbeat: Duration,
+    /// Wait between acquiring the lock and taking over.
+    pub takeover_delay: Duration,
+}
+
+impl Default for LeaderElectionConfig {
+    fn default() -> Self {
+        Self {
+            heartbeat: Duration::from_secs(5),
+            takeover_delay: Duration::from_secs(10),
+        }
+    }
+}
+
+/// Role of this instance.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Role {
+    /// Trades.
+    Leader,
+    /// Serves queries only.
+    Standby,
+}
+
+impl fmt::Display for Role {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Leader => write!(f, "LEADER"),
+            Self::Standby => write!(f, "STANDBY"),
+        }
+    }
+}
+
+/// Elects this instance leader through a shared lock.
+pub struct LeaderElection {
+    lock: Arc<dyn LeaderLockPort>,
+    config: LeaderElectionConfig,
+    leader: AtomicBool,
+}
+
+impl fmt::Debug for LeaderElection {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("LeaderElection")
+            .field("config", &self.config)
+            .field("role", &self.role())
+            .finish_non_exhaustive()
+    }
+}
+
+impl LeaderElection {
+    /// Create an election over `lock`. The instance starts as a standby.
+    #[must_use]
+    pub fn new(lock: Arc<dyn LeaderLockPort>, config: LeaderElectionConfig) -> Self {
+        Self {
+            lock,
+            config,
+            leader: AtomicBool::new(false),
+        }
+    }
+
+    /// Current role.
+    #[must_use]
+    pub fn role(&self) -> Role {
+        if self.is_leader() {
+            Role::Leader
+        } else {
+            Role::Standby
+        }
+    }
+
+    /// Check if this instance is the leader.
+    #[must_use]
+    pub fn is_leader(&self) -> bool {
+        self.leader.load(Ordering::Acquire)
+    }
+
+    /// Check whether this instance may submit orders.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`NOT_LEADER`] violation while this instance is a standby.
+    pub fn check(&self) -> Result<(), Box<ConstraintViolation>> {
+        if self.is_leader() {
+            return Ok(());
+        }
+        Err(Box::new(ConstraintViolation::critical(
+            NOT_LEADER,
+            "Standby instance is read-only, send plans to the leader",
+        )))
+    }
+
+    /// Run one election round and return the resulting role.
+    ///
+    /// A leader confirms it still holds the lock. A standby tries to take
+    /// the lock and, if it gets it, takes over once `takeover` succeeds.
+    pub async fn campaign<F, Fut>(&self, takeover: F) -> Role
+    where
+        F: FnOnce() -> Fut,
+        Fut: Future<Output = Result<(), String>>,
+    {
+        if self.is_leader() {
+            match self.lock.is_held().await {
+                Ok(true) => return Role::Leader,
+                Ok(false) => self.demote("leader lock lost").await,
+                Err(e) => self.demote(&e.to_string()).await,
+            }
+            return Role::Standby;
+        }
+
+        match self.lock.try_acquire().await {
+            Ok(true) => {}
+            Ok(false) => return Role::Standby,
+            Err(e) => {
+                tracing::warn!(error = %e, "Leader lock unavailable, staying standby");
+                return Role::Standby;
+            }
+        }
+
+        tracing::warn!(
+            delay_secs = self.config.takeover_delay.as_secs(),
+            "Leader lock acquired, waiting before takeover"
+        );
+        tokio::time::sleep(self.config.takeover_delay).await;
+        if !matches!(self.lock.is_held().await, Ok(true)) {
+            tracing::warn!("Leader lock lost during takeover delay, staying standby");
+            return Role::Standby;
+        }
+        if let Err(reason) = takeover().await {
+            tracing::warn!(%reason, "Takeover check failed, releasing leader lock");
+            self.release().await;
+            return Role::Standby;
+        }
+
+        self.leader.store(true, Ordering::Release);
+        tracing::warn!(target: "audit", role = %Role::Leader, "Promoted to leader, trading enabled");
+        Role::Leader
+    }
+
+    /// Campaign every heartbeat until `shutdown`, then release the lock.
+    pub fn start<F, Fut>(self: &Arc<Self>, takeover: F, shutdown: CancellationToken)
+    where
+        F: Fn() -> Fut + Send + Sync + 'static,
+        Fut: Future<Output = Result<(), String>> + Send,
+    {
+        let election = Arc::clone(self);
+        tokio::spawn(async move {
+            loop {
+                election.campaign(&takeover).await;
+                tokio::select! {
+                    () = tokio::time::sleep(election.config.heartbeat) => {}
+                    () = shutdown.cancelled() => break,
+                }
+            }
+            if election.is_leader() {
+                election.demote("shutting down").await;
+            }
+        });
+    }
+
+    async fn demote(&self, reason: &str) {
+        self.leader.store(false, Ordering::Release);
+        tracing::error!(target: "audit", role = %Role::Standby, %reason, "Demoted to standby, trading disabled");
+        self.release().await;
+    }
+
+    async fn release(&self) {
+        if let Err(e) = self.lock.release().await {
+            tracing::warn!(error = %e, "Failed to release leader lock");
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::application::ports::InMemoryLeaderLock;
+
+    fn election(lock: InMemoryLeaderLock) -> LeaderElection {
+        LeaderElection::new(
+            Arc::new(lock),
+            LeaderElectionConfig {
+                heartbeat: Duration::from_millis(10),
+                takeover_delay: Duration::ZERO,
+            },
+        )
+    }
+
+    async fn ok() -> Result<(), String> {
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn one_instance_leads_and_the_other_stands_by() {
+        let lock = InMemoryLeaderLock::new();
+        let standby = election(lock.contender());
+        let leader = election(lock);
+
+        assert_eq!(leader.campaign(ok).await, Role::Leader);
+        assert_eq!(standby.campaign(ok).await, Role::Standby);
+        assert!(leader.check().is_ok());
+        assert_eq!(standby.check().unwrap_err().code, NOT_LEADER);
+        // The leader keeps the lock across heartbeats
+        assert_eq!(leader.campaign(ok).await, Role::Leader);
+    }
+
+    #[tokio::test]
+    async fn standby_takes_over_when_the_leader_loses_the_lock() {
+        let lock = InMemoryLeaderLock::new();
+        let standby = election(lock.contender());
+        let revoker = lock.contender();
+        let leader = election(lock);
+        assert_eq!(leader.campaign(ok).await, Role::Leader);
+
+        revoker.revoke();
+        assert_eq!(standby.campaign(ok).await, Role::Leader);
+        assert_eq!(leader.campaign(ok).await, Role::Standby);
+        assert!(!leader.is_leader());
+    }
+
+    #[tokio::test]
+    async fn failed_takeover_check_releases_the_lock() {
+        let lock = InMemoryLeaderLock::new();
+        let other = election(lock.contender());
+        let instance = election(lock);
+
+        let role = instance
+            .campaign(|| async { Err("reconciliation failed".to_string()) })
+            .await;
+
+        assert_eq!(role, Role::Standby);
+        assert_eq!(other.campaign(ok).await, Role::Leader);
+    }
+}
@@ -0,0 +1,543 @@
+//! Leg Balance Monitor
+//!
+//! Watches working multi-leg orders for legs that fill out of step, such as
+//! the long leg of a spread filling while the short leg rests. Leg fills
+//! come from reconciliation. An order whose legs stay imbalanced for longer
+//! than `stuck_after` is handled once, according to [`LegRemediation`]:
+//! left to an operator, completed by re-pricing the lagging legs as market
+//! orders, or unwound by trading back the excess on the leading legs. Both
+//! remediations first cancel the rest of the order, so it cannot fill on
+//! top of the repair.
+//!
+//! Every handled imbalance is logged on the `audit` target and kept in a
+//! bounded history. Remediation orders are held while the kill switch is
+//! tripped.
+
+use std::collections::{HashMap, VecDeque};
+use std::sync::{Arc, PoisonError, RwLock};
+use std::time::Duration;
+
+use tokio_util::sync::CancellationToken;
+
+use super::KillSwitch;
+use crate::application::ports::{BrokerPort, CancelOrderRequest, Clock, SubmitOrderRequest};
+use crate::domain::order_execution::aggregate::Order;
+use crate::domain::order_execution::repository::OrderRepository;
+use crate::domain::order_execution::value_objects::{LegAdjustment, LegImbalance};
+use crate::domain::shared::{BrokerId, OrderId, Symbol, Timestamp};
+
+/// What to do with an order whose legs are stuck out of balance.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum LegRemediation {
+    /// Log the imbalance and leave the order alone.
+    #[default]
+    Alert,
+    /// Cancel the order and buy or sell the lagging legs at market.
+    Reprice,
+    /// Cancel the order and trade back the excess on the leading legs.
+    Unwind,
+}
+
+/// Configuration for the leg balance monitor.
+#[derive(Debug, Clone)]
+pub struct LegBalanceConfig {
+    /// How long legs may stay imbalanced before the order is handled.
+    pub stuck_after: Duration,
+    /// What to do once they are stuck.
+    pub remediation: LegRemediation,
+    /// Time between checks.
+    pub interval: Duration,
+    /// Records kept in memory.
+    pub history_limit: usize,
+}
+
+impl Default for LegBalanceConfig {
+    fn default() -> Self {
+        Self {
+            stuck_after: Duration::from_secs(30),
+            remediation: LegRemediation::Alert,
+            interval: Duration::from_secs(5),
+            history_limit: 500,
+        }
+    }
+}
+
+/// Result of handling a stuck imbalance.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum RemediationOutcome {
+    /// Logged only.
+    Alerted,
+    /// Order canceled and remediation orders accepted by the broker.
+    Submitted {
+        /// Broker order IDs of the remediation orders.
+        broker_order_ids: Vec<BrokerId>,
+    },
+    /// Not acted on because trading is disabled.
+    Blocked {
+        /// Kill switch reason.
+        reason: String,
+    },
+    /// The cancel or a remediation order failed at the broker.
+    Failed {
+        /// Error details.
+        reason: String,
+    },
+}
+
+/// Audit record of a stuck imbalance.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LegImbalanceRecord {
+    /// Multi-leg order.
+    pub order_id: OrderId,
+    /// Imbalance when the order was handled.
+    pub imbalance: LegImbalance,
+    /// Remediation applied.
+    pub remediation: LegRemediation,
+    /// What happened.
+    pub outcome: RemediationOutcome,
+    /// When the order was handled.
+    pub at: Timestamp,
+}
+
+/// Imbalance tracking for one order.
+#[derive(Debug, Clone, Copy)]
+struct Tracked {
+    since: Timestamp,
+    handled: bool,
+}
+
+/// Detects multi-leg orders with stuck legs and remediates them.
+pub struct LegBalanceMonitor<B, O>
+where
+    B: BrokerPort,
+    O: OrderRepository,
+{
+    config: LegBalanceConfig,
+    broker: Arc<B>,
+    order_repo: Arc<O>,
+    clock: Arc<dyn Clock>,
+    kill_switch: Option<Arc<KillSwitch>>,
+    tracked: RwLock<HashMap<OrderId, Tracked>>,
+    history: RwLock<VecDeque<LegImbalanceRecord>>,
+}
+
+impl<B, O> LegBalanceMonitor<B, O>
+where
+    B: BrokerPort,
+    O: OrderRepository,
+{
+    /// Create a monitor that times stuck legs by `clock`.
+    #[must_use]
+    pub fn new(
+        config: LegBalanceConfig,
+        broker: Arc<B>,
+        order_repo: Arc<O>,
+        clock: Arc<dyn Clock>,
+    ) -> Self {
+        Self {
+            config,
+            broker,
+            order_repo,
+            clock,
+            kill_switch: None,
+            tracked: RwLock::new(HashMap::new()),
+            history: RwLock::new(VecDeque::new()),
+        }
+    }
+
+    /// Hold remediation orders while the kill switch is tripped.
+    #[must_use]
+    pub fn with_kill_switch(mut self, kill_switch: Arc<KillSwitch>) -> Self {
+        self.kill_switch = Some(kill_switch);
+        self
+    }
+
+    /// Handled imbalances, oldest first.
+    #[must_use]
+    pub fn recent_imbalances(&self) -> Vec<LegImbalanceRecord> {
+        self.history
+            .read()
+            .unwrap_or_else(PoisonError::into_inner)
+            .iter()
+            .cloned()
+            .collect()
+    }
+
+    /// Check working multi-leg orders now.
+    pub async fn run_once(&self) -> Vec<LegImbalanceRecord> {
+        self.run_at(self.clock.now()).await
+    }
+
+    /// Check working multi-leg orders at `now`.
+    ///
+    /// Returns the imbalances handled in this pass.
+    async fn run_at(&self, now: Timestamp) -> Vec<LegImbalanceRecord> {
+        let orders = match self.order_repo.find_active().await {
+            Ok(orders) => orders,
+            Err(e) => {
+                tracing::warn!(error = %e, "Leg balance monitor could not load orders");
+                return Vec::new();
+            }
+        };
+
+        let imbalanced: Vec<(Order, LegImbalance)> = orders
+            .into_iter()
+            .filter_map(|order| {
+                let imbalance = order.leg_imbalance()?;
+                Some((order, imbalance))
+            })
+            .collect();
+
+        let due = self.stuck(imbalanced, now);
+        let mut records = Vec::with_capacity(due.len());
+        for (order, imbalance) in due {
+            records.push(self.remediate(&order, imbalance, now).await);
+        }
+        records
+    }
+
+    /// Track `imbalanced` orders and return those stuck for the first time
+    /// at `now`.
+    fn stuck(
+        &self,
+        imbalanced: Vec<(Order, LegImbalance)>,
+        now: Timestamp,
+    ) -> Vec<(Order, LegImbalance)> {
+        let stuck_after =
+            chrono::Duration::from_std(self.config.stuck_after).unwrap_or(chrono::Duration::MAX);
+        let mut tracked = self.tracked.write().unwrap_or_else(PoisonError::into_inner);
+        tracked.retain(|id, _| imbalanced.iter().any(|(order, _)| order.id() == id));
+        let stuck = imbalanced
+            .into_iter()
+            .filter(|(order, _)| {
+                let entry = tracked.entry(order.id().clone()).or_insert(Tracked {
+                    since: now,
+                    handled: false,
+                });
+                let due = !entry.handled && now.duration_since(entry.since) >= stuck_after;
+                entry.handled |= due;
+                due
+            })
+            .collect();
+        drop(tracked);
+        stuck
+    }
+
+    /// Run checks every interval until shutdown.
+    pub fn start(self: &Arc<Self>, shutdown: CancellationToken)
+    where
+        B: 'static,
+        O: 'static,
+    {
+        let monitor = Arc::clone(self);
+        let run_interval = self.config.interval.max(Duration::from_secs(1));
+
+        tokio::spawn(async move {
+            let mut interval = tokio::time::interval(run_interval);
+
+            loop {
+                tokio::select! {
+                    _ = interval.tick() => {
+                        let handled = monitor.run_once().await;
+                        if !handled.is_empty() {
+                            tracing::warn!(orders = handled.len(), "Stuck multi-leg orders handled");
+                        }
+                    }
+                    () = shutdown.cancelled() => {
+                        tracing::info!("Leg balance monitor shutting down");
+                        break;
+                    }
+                }
+            }
+        });
+    }
+
+    async fn remediate(
+        &self,
+        order: &Order,
+        imbalance: LegImbalance,
+        now: Timestamp,
+    ) -> LegImbalanceRecord {
+        let remediation = self.config.remediation;
+        let adjustments = match remediation {
+            LegRemediation::Alert => None,
+            LegRemediation::Reprice => Some(&imbalance.catch_up),
+            LegRemediation::Unwind => Some(&imbalance.unwind),
+        };
+        let blocked = self.kill_switch.as_ref().and_then(|switch| switch.status());
+
+        let outcome = match (adjustments, blocked) {
+            (None, _) => RemediationOutcome::Alerted,
+            (Some(_), Some(reason)) => RemediationOutcome::Blocked { reason },
+            (Some(adjustments), None) => self.cancel_and_trade(order, adjustments).await,
+        };
+
+        let record = LegImbalanceRecord {
+            order_id: order.id().clone(),
+            imbalance,
+            remediation,
+            outcome,
+            at: now,
+        };
+        tracing::warn!(
+            target: "audit",
+            order_id = %record.order_id,
+            max_fill_ratio = %record.imbalance.max_fill_ratio,
+            min_fill_ratio = %record.imbalance.min_fill_ratio,
+            remediation = ?record.remediation,
+            outcome = ?record.outcome,
+            "Multi-leg order legs stuck out of balance"
+        );
+
+        let mut history = self.history.write().unwrap_or_else(PoisonError::into_inner);
+        history.push_back(record.clone());
+        while history.len() > self.config.history_limit {
+            history.pop_front();
+        }
+        drop(history);
+        record
+    }
+
+    /// Cancel the rest of `order`, then submit `adjustments` at market.
+    async fn cancel_and_trade(
+        &self,
+        order: &Order,
+        adjustments: &[LegAdjustment],
+    ) -> RemediationOutcome {
+        let cancel = order.broker_order_id().map_or_else(
+            || CancelOrderRequest::by_client_id(order.id().clone()),
+            |id| CancelOrderRequest::by_broker_id(id.clone()),
+        );
+        if let Err(e) = self.broker.cancel_order(cancel).await {
+            return RemediationOutcome::Failed {
+                reason: format!("Cancel failed: {e}"),
+            };
+        }
+
+        let mut broker_order_ids = Vec::with_capacity(adjustments.len());
+        let mut errors = Vec::new();
+        for adjustment in adjustments {
+            let request = SubmitOrderRequest::market(
+                OrderId::generate(),
+                Symbol::new(adjustment.instrument_id.as_str()),
+                adjustment.side,
+                adjustment.quantity.amount(),
+            );
+            match self.broker.submit_order(request).await {
+                Ok(ack) => broker_order_ids.push(ack.broker_order_id),
+                Err(e) => errors.push(format!("leg {}: {e}", adjustment.leg_index)),
+            }
+        }
+
+        if errors.is_empty() {
+            RemediationOutcome::Submitted { broker_order_ids }
+        } else {
+            RemediationOutcome::Failed {
+                reason: errors.join("; "),
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::application::ports::{BrokerError, OrderAck, PositionInfo, SimulatedClock};
+    use crate::domain::order_execution::aggregate::{CreateOrderCommand, OrderLine};
+    use crate::domain::order_execution::value_objects::{
+        OrderPurpose, OrderSide, OrderStatus, OrderType, TimeInForce,
+    };
+    use crate::domain::shared::{InstrumentId, Money, Quantity};
+    use crate::infrastructure::persistence::InMemoryOrderRepository;
+    use async_trait::async_trait;
+    use rust_decimal::Decimal;
+    use std::sync::Mutex;
+
+    #[derive(Default)]
+    struct MockBroker {
+        canceled: Mutex<Vec<CancelOrderRequest>>,
+        submitted: Mutex<Vec<SubmitOrderRequest>>,
+    }
+
+    #[async_trait]
+    impl BrokerPort for MockBroker {
+        async fn submit_order(&self, request: SubmitOrderRequest) -> Result<OrderAck, BrokerError> {
+            let ack = OrderAck {
+                broker_order_id: BrokerId::new("repair-1"),
+                client_order_id: request.client_order_id.clone(),
+                status: OrderStatus::Accepted,
+                filled_qty: Decimal::ZERO,
+                avg_fill_price: None,
+                child_order_ids: Vec::new(),
+                leg_fills: Vec::new(),
+            };
+            self.submitted.lock().unwrap().push(request);
+            Ok(ack)
+        }
+
+        async fn cancel_order(&self, request: CancelOrderRequest) -> Result<(), BrokerError> {
+            self.canceled.lock().unwrap().push(request);
+            Ok(())
+        }
+
+        async fn get_order(&self, broker_order_id: &BrokerId) -> Result<OrderAck, BrokerError> {
+            Err(BrokerError::OrderNotFound {
+                order_id: broker_order_id.to_string(),
+            })
+        }
+
+        async fn get_open_orders(&self) -> Result<Vec<OrderAck>, BrokerError> {
+            Ok(vec![])
+        }
+
+        async fn get_buying_power(&self) -> Result<Decimal, BrokerError> {
+            Ok(Decimal::ZERO)
+        }
+
+        async fn get_position(
+            &self,
+            _instrument_id: &InstrumentId,
+        ) -> Result<Option<Decimal>, BrokerError> {
+            Ok(None)
+        }
+
+        async fn get_all_positions(&self) -> Result<Vec<PositionInfo>, BrokerError> {
+            Ok(vec![])
+        }
+    }
+
+    async fn legged_spread(order_repo: &InMemoryOrderRepository) -> Order {
+        let mut order = Order::new(CreateOrderCommand {
+            symbol: Symbol::new("SPY"),
+            side: OrderSide::Buy,
+            order_type: OrderType::Limit,
+            quantity: Quantity::from_i64(2),
+            limit_price: Some(Money::usd(1.50)),
+            stop_price: None,
+            time_in_force: TimeInForce::Day,
+            purpose: OrderPurpose::Entry,
+            legs: vec![
+                OrderLine::new(
+                    0,
+                    InstrumentId::new("SPY250620C00500000"),
+                    OrderSide::Buy,
+                    Quantity::from_i64(2),
+                ),
+                OrderLine::new(
+                    1,
+                    InstrumentId::new("SPY250620C00510000"),
+                    OrderSide::Sell,
+                    Quantity::from_i64(2),
+                ),
+            ],
+        })
+        .unwrap();
+        order.accept(BrokerId::new("spread-1")).unwrap();
+        order.sync_leg_fills(
+            &[(
+                InstrumentId::new("SPY250620C00500000"),
+                Quantity::from_i64(2),
+                Some(Money::usd(4.10)),
+            )],
+            "RECONCILE",
+        );
+        order_repo.save(&order).await.unwrap();
+        order
+    }
+
+    fn clock() -> Arc<SimulatedClock> {
+        Arc::new(SimulatedClock::new(
+            Timestamp::parse("2025-06-02T15:00:00Z").unwrap(),
+        ))
+    }
+
+    fn monitor(
+        remediation: LegRemediation,
+        broker: &Arc<MockBroker>,
+        order_repo: &Arc<InMemoryOrderRepository>,
+        clock: &Arc<SimulatedClock>,
+    ) -> LegBalanceMonitor<MockBroker, InMemoryOrderRepository> {
+        LegBalanceMonitor::new(
+            LegBalanceConfig {
+                remediation,
+                ..LegBalanceConfig::default()
+            },
+            Arc::clone(broker),
+            Arc::clone(order_repo),
+            Arc::clone(clock) as Arc<dyn Clock>,
+        )
+    }
+
+    #[tokio::test]
+    async fn alerts_once_after_legs_stay_stuck() {
+        let broker = Arc::new(MockBroker::default());
+        let order_repo = Arc::new(InMemoryOrderRepository::new());
+        legged_spread(&order_repo).await;
+        let clock = clock();
+        let monitor = monitor(LegRemediation::Alert, &broker, &order_repo, &clock);
+
+        assert!(monitor.run_once().await.is_empty());
+        clock.advance(Duration::from_secs(31));
+        let records = monitor.run_once().await;
+        assert_eq!(records.len(), 1);
+        assert_eq!(records[0].at, clock.now());
+        assert_eq!(records[0].outcome, RemediationOutcome::Alerted);
+        assert!(records[0].imbalance.is_legged_out());
+
+        // Handled orders are not reported again
+        assert!(monitor.run_once().await.is_empty());
+        assert_eq!(monitor.recent_imbalances().len(), 1);
+        assert!(broker.canceled.lock().unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn reprice_cancels_and_completes_lagging_legs() {
+        let broker = Arc::new(MockBroker::default());
+        let order_repo = Arc::new(InMemoryOrderRepository::new());
+        legged_spread(&order_repo).await;
+        let clock = clock();
+        let monitor = monitor(LegRemediation::Reprice, &broker, &order_repo, &clock);
+        let _ = monitor.run_once().await;
+
+        clock.advance(Duration::from_secs(31));
+        let records = monitor.run_once().await;
+        assert!(matches!(
+            records[0].outcome,
+            RemediationOutcome::Submitted { .. }
+        ));
+
+        let canceled = broker.canceled.lock().unwrap();
+        assert_eq!(canceled[0].broker_order_id, Some(BrokerId::new("spread-1")));
+        drop(canceled);
+        let submitted = broker.submitted.lock().unwrap();
+        assert_eq!(submitted.len(), 1);
+        assert_eq!(submitted[0].symbol.as_str(), "SPY250620C00510000");
+        assert_eq!(submitted[0].side, OrderSide::Sell);
+        assert_eq!(submitted[0].quantity, Decimal::TWO);
+        assert_eq!(submitted[0].order_type, OrderType::Market);
+    }
+
+    #[tokio::test]
+    async fn unwind_trades_back_leading_legs() {
+        let broker = Arc::new(MockBroker::default());
+        let order_repo = Arc::new(InMemoryOrderRepository::new());
+        legged_spread(&order_repo).await;
+        let monitor = LegBalanceMonitor::new(
+            LegBalanceConfig {
+                remediation: LegRemediation::Unwind,
+                stuck_after: Duration::ZERO,
+                ..LegBalanceConfig::default()
+            },
+            Arc::clone(&broker),
+            Arc::clone(&order_repo),
+            clock(),
+        );
+
+        let records = monitor.run_once().await;
+        assert_eq!(records.len(), 1);
+        let submitted = broker.submitted.lock().unwrap();
+        assert_eq!(submitted[0].symbol.as_str(), "SPY250620C00500000");
+        assert_eq!(submitted[0].side, OrderSide::Sell);
+        assert_eq!(submitted[0].quantity, Decimal::TWO);
+    }
+}
@@ -0,0 +1,478 @@
+//! Market Snapshot Service
+//!
+//! Assembles a combined per-symbol snapshot (NBBO quote, last trade, daily
+//! bar, prior close and, on request, an option chain summary) from the
+//! separate market data endpoints. The endpoints are queried concurrently
+//! and a failing one does not fail the snapshot: each symbol reports the
+//! parts it could not load, and the snapshot fails only if nothing at all
+//! could be loaded.
+
+use std::collections::HashMap;
+use std::fmt;
+use std::sync::Arc;
+
+use futures_util::future::join_all;
+use rust_decimal::Decimal;
+
+use crate::application::ports::{
+    DailyBar, LastTrade, MarketDataError, MarketDataPort, MarketQuote, OptionChainData, OptionType,
+};
+use crate::domain::shared::ClassifiedError;
+
+/// Part of a symbol snapshot, loaded from its own endpoint.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SnapshotComponent {
+    /// NBBO quote.
+    Quote,
+    /// Last trade.
+    Trade,
+    /// Current and prior daily bars.
+    DailyBar,
+    /// Option chain summary.
+    Options,
+    /// Intraday bars from the live feed.
+    Bars,
+}
+
+impl fmt::Display for SnapshotComponent {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Quote => write!(f, "QUOTE"),
+            Self::Trade => write!(f, "TRADE"),
+            Self::DailyBar => write!(f, "DAILY_BAR"),
+            Self::Options => write!(f, "OPTIONS"),
+            Self::Bars => write!(f, "BARS"),
+        }
+    }
+}
+
+/// A part of a symbol snapshot that could not be loaded.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SnapshotComponentError {
+    /// The missing part.
+    pub component: SnapshotComponent,
+    /// Stable error code.
+    pub code: String,
+    /// Error message.
+    pub message: String,
+}
+
+impl SnapshotComponentError {
+    fn from_error(component: SnapshotComponent, error: &MarketDataError) -> Self {
+        Self {
+            component,
+            code: error.code().to_string(),
+            message: error.to_string(),
+        }
+    }
+
+    fn missing(component: SnapshotComponent, symbol: &str) -> Self {
+        Self::from_error(
+            component,
+            &MarketDataError::DataUnavailable {
+                message: format!(
+                    "No {} data for {symbol}",
+                    component.to_string().to_lowercase()
+                ),
+            },
+        )
+    }
+}
+
+/// Summary of an underlying's option chain.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct OptionChainStats {
+    /// Contracts in the chain.
+    pub contracts: usize,
+    /// Nearest expiration (YYYY-MM-DD).
+    pub nearest_expiration: Option<String>,
+    /// Implied volatility at the strike nearest the underlying price, in
+    /// the nearest expiration.
+    pub atm_implied_volatility: Option<f64>,
+    /// Daily call volume.
+    pub call_volume: i64,
+    /// Daily put volume.
+    pub put_volume: i64,
+    /// Call open interest.
+    pub call_open_interest: i64,
+    /// Put open interest.
+    pub put_open_interest: i64,
+}
+
+impl OptionChainStats {
+    /// Summarize `chain`.
+    #[must_use]
+    pub fn of(chain: &OptionChainData) -> Self {
+        let mut stats = Self {
+            contracts: chain.options.len(),
+            nearest_expiration: chain
+                .options
+                .iter()
+                .map(|o| o.contract.expiration.clone())
+                .min(),
+            ..Self::default()
+        };
+
+        for option in &chain.options {
+            let volume = option.quote.as_ref().map_or(0, |q| q.volume);
+            let open_interest = i64::from(option.open_interest);
+            match option.contract.option_type {
+                OptionType::Call => {
+                    stats.call_volume += volume;
+                    stats.call_open_interest += open_interest;
+                }
+                OptionType::Put => {
+                    stats.put_volume += volume;
+                    stats.put_open_interest += open_interest;
+                }
+            }
+        }
+
+        // Average the call and put IV at the nearest strike of the front month
+        let front: Vec<_> = chain
+            .options
+            .iter()
+            .filter(|o| Some(&o.contract.expiration) == stats.nearest_expiration.as_ref())
+            .filter(|o| o.implied_volatility.is_some())
+            .collect();
+        let atm_strike = front
+            .iter()
+            .map(|o| o.contract.strike)
+            .min_by_key(|strike| (*strike - chain.underlying_price).abs());
+        if let Some(strike) = atm_strike {
+            let ivs: Vec<f64> = front
+                .iter()
+                .filter(|o| o.contract.strike == strike)
+                .filter_map(|o| o.implied_volatility)
+                .collect();
+            #[allow(clippy::cast_precision_loss)]
+            let mean = ivs.iter().sum::<f64>() / ivs.len() as f64;
+            stats.atm_implied_volatility = Some(mean);
+        }
+
+        stats
+    }
+}
+
+/// Combined market data for one symbol.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SymbolMarketSnapshot {
+    /// Symbol (upper case).
+    pub symbol: String,
+    /// NBBO quote.
+    pub quote: Option<MarketQuote>,
+    /// Last trade.
+    pub last_trade: Option<LastTrade>,
+    /// Current session's daily bar.
+    pub daily_bar: Option<DailyBar>,
+    /// Prior session's close.
+    pub prev_close: Option<Decimal>,
+    /// Option chain summary, when requested.
+    pub option_stats: Option<OptionChainStats>,
+    /// Parts that could not be loaded.
+    pub errors: Vec<SnapshotComponentError>,
+}
+
+impl SymbolMarketSnapshot {
+    /// Check if any market data was loaded for the symbol.
+    #[must_use]
+    pub const fn has_data(&self) -> bool {
+        self.quote.is_some()
+            || self.last_trade.is_some()
+            || self.daily_bar.is_some()
+            || self.prev_close.is_some()
+            || self.option_stats.is_some()
+    }
+}
+
+/// Builds combined snapshots from a [`MarketDataPort`].
+pub struct MarketSnapshotService<M>
+where
+    M: MarketDataPort,
+{
+    market_data: Arc<M>,
+}
+
+impl<M> MarketSnapshotService<M>
+where
+    M: MarketDataPort,
+{
+    /// Create a snapshot service over `market_data`.
+    pub const fn new(market_data: Arc<M>) -> Self {
+        Self { market_data }
+    }
+
+    /// Snapshot `symbols`, with option chain summaries if `include_options`.
+    ///
+    /// # Errors
+    ///
+    /// Returns the quote error if no data could be loaded for any symbol.
+    pub async fn snapshot(
+        &self,
+        symbols: &[String],
+        include_options: bool,
+    ) -> Result<Vec<SymbolMarketSnapshot>, MarketDataError> {
+        let symbols: Vec<String> = symbols.iter().map(|s| s.to_uppercase()).collect();
+
+        let option_chains = async {
+            if !include_options {
+                return Vec::new();
+            }
+            join_all(
+                symbols
+                    .iter()
+                    .map(|symbol| self.market_data.get_option_chain(symbol)),
+            )
+            .await
+        };
+        let (quotes, trades, bars, chains) = tokio::join!(
+            self.market_data.get_quotes(&symbols),
+            self.market_data.get_latest_trades(&symbols),
+            self.market_data.get_daily_bars(&symbols),
+            option_chains,
+        );
+
+        let mut snapshots: Vec<SymbolMarketSnapshot> = symbols
+            .iter()
+            .map(|symbol| SymbolMarketSnapshot {
+                symbol: symbol.clone(),
+                quote: None,
+                last_trade: None,
+                daily_bar: None,
+                prev_close: None,
+                option_stats: None,
+                errors: Vec::new(),
+            })
+            .collect();
+
+        let quote_error = quotes.as_ref().err().cloned();
+        fill(
+            &mut snapshots,
+            SnapshotComponent::Quote,
+            quotes,
+            |q| (q.symbol.to_uppercase(), q),
+            |s, q| s.quote = Some(q),
+        );
+        fill(
+            &mut snapshots,
+            SnapshotComponent::Trade,
+            trades,
+            |t| (t.symbol.to_uppercase(), t),
+            |s, t| s.last_trade = Some(t),
+        );
+        fill(
+            &mut snapshots,
+            SnapshotComponent::DailyBar,
+            bars,
+            |b| (b.symbol.to_uppercase(), b),
+            |s, b| {
+                s.daily_bar = b.today;
+                s.prev_close = b.previous.map(|p| p.close);
+            },
+        );
+        for (snapshot, chain) in snapshots.iter_mut().zip(chains) {
+            match chain {
+                Ok(chain) => snapshot.option_stats = Some(OptionChainStats::of(&chain)),
+                Err(e) => snapshot.errors.push(SnapshotComponentError::from_error(
+                    SnapshotComponent::Options,
+                    &e,
+                )),
+            }
+        }
+
+        if !symbols.is_empty() && !snapshots.iter().any(SymbolMarketSnapshot::has_data) {
+            return Err(
+                quote_error.unwrap_or_else(|| MarketDataError::DataUnavailable {
+                    message: "No market data available for requested symbols".to_string(),
+                }),
+            );
+        }
+
+        for snapshot in snapshots.iter().filter(|s| !s.errors.is_empty()) {
+            tracing::debug!(
+                symbol = %snapshot.symbol,
+                missing = ?snapshot.errors.iter().map(|e| e.component).collect::<Vec<_>>(),
+                "Partial market snapshot"
+            );
+        }
+        Ok(snapshots)
+    }
+}
+
+/// Place a batch endpoint's results into the snapshots, recording an error
+/// on every symbol if the batch failed and on each symbol it omitted.
+fn fill<T, V>(
+    snapshots: &mut [SymbolMarketSnapshot],
+    component: SnapshotComponent,
+    batch: Result<Vec<T>, MarketDataError>,
+    key: impl Fn(T) -> (String, V),
+    mut set: impl FnMut(&mut SymbolMarketSnapshot, V),
+) {
+    let mut by_symbol: HashMap<String, V> = match batch {
+        Ok(items) => items.into_iter().map(key).collect(),
+        Err(e) => {
+            for snapshot in snapshots.iter_mut() {
+                snapshot
+                    .errors
+                    .push(SnapshotComponentError::from_error(component, &e));
+            }
+            return;
+        }
+    };
+
+    for snapshot in snapshots.iter_mut() {
+        match by_symbol.remove(&snapshot.symbol) {
+            Some(value) => set(snapshot, value),
+            None => snapshot
+                .errors
+                .push(SnapshotComponentError::missing(component, &snapshot.symbol)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use async_trait::async_trait;
+
+    use super::*;
+    use crate::application::ports::{DailyBars, OptionContract, OptionQuote};
+    use crate::domain::shared::Timestamp;
+
+    struct PartialMarketData;
+
+    fn quote(symbol: &str) -> MarketQuote {
+        MarketQuote {
+            symbol: symbol.to_string(),
+            bid: Decimal::new(100, 0),
+            ask: Decimal::new(101, 0),
+            bid_size: 1,
+            ask_size: 1,
+            last: Decimal::ZERO,
+            last_size: 0,
+            volume: 0,
+            timestamp: Timestamp::now(),
+        }
+    }
+
+    fn bar(close: i64) -> DailyBar {
+        DailyBar {
+            timestamp: Timestamp::now(),
+            open: Decimal::new(close, 0),
+            high: Decimal::new(close, 0),
+            low: Decimal::new(close, 0),
+            close: Decimal::new(close, 0),
+            volume: 1000,
+            vwap: None,
+            trade_count: None,
+        }
+    }
+
+    fn option(expiration: &str, strike: i64, option_type: OptionType, iv: f64) -> OptionQuote {
+        OptionQuote {
+            contract: OptionContract {
+                underlying: "SPY".to_string(),
+                expiration: expiration.to_string(),
+                strike: Decimal::new(strike, 0),
+                option_type,
+            },
+            quote: Some(MarketQuote {
+                volume: 10,
+                ..quote("SPY")
+            }),
+            implied_volatility: Some(iv),
+            greeks: None,
+            open_interest: 100,
+        }
+    }
+
+    #[async_trait]
+    impl MarketDataPort for PartialMarketData {
+        async fn get_quotes(
+            &self,
+            symbols: &[String],
+        ) -> Result<Vec<MarketQuote>, MarketDataError> {
+            // Only SPY is quoted
+            Ok(symbols
+                .iter()
+                .filter(|s| *s == "SPY")
+                .map(|s| quote(s))
+                .collect())
+        }
+
+        async fn get_option_chain(
+            &self,
+            underlying: &str,
+        ) -> Result<OptionChainData, MarketDataError> {
+            Ok(chain(underlying))
+        }
+
+        async fn get_daily_bars(
+            &self,
+            symbols: &[String],
+        ) -> Result<Vec<DailyBars>, MarketDataError> {
+            Ok(symbols
+                .iter()
+                .map(|s| DailyBars {
+                    symbol: s.clone(),
+                    today: Some(bar(101)),
+                    previous: Some(bar(99)),
+                })
+                .collect())
+        }
+    }
+
+    #[tokio::test]
+    async fn combines_components_and_reports_missing_parts() {
+        let service = MarketSnapshotService::new(Arc::new(PartialMarketData));
+
+        let snapshots = service
+            .snapshot(&["spy".to_string(), "QQQ".to_string()], true)
+            .await
+            .unwrap();
+
+        let spy = &snapshots[0];
+        assert_eq!(spy.option_stats.as_ref().unwrap().contracts, 4);
+        assert_eq!(spy.symbol, "SPY");
+        assert!(spy.quote.is_some());
+        assert_eq!(spy.prev_close, Some(Decimal::new(99, 0)));
+        // Trades are not supported by the provider
+        assert_eq!(spy.errors.len(), 1);
+        assert_eq!(spy.errors[0].component, SnapshotComponent::Trade);
+
+        let qqq = &snapshots[1];
+        assert!(qqq.quote.is_none());
+        assert!(qqq.daily_bar.is_some());
+        assert!(
+            qqq.errors
+                .iter()
+                .any(|e| e.component == SnapshotComponent::Quote)
+        );
+    }
+
+    #[test]
+    fn option_stats_summarize_the_chain() {
+        let stats = OptionChainStats::of(&chain("SPY"));
+
+        assert_eq!(stats.contracts, 4);
+        assert_eq!(stats.nearest_expiration.as_deref(), Some("2026-03-20"));
+        assert_eq!(stats.call_volume, 20);
+        assert_eq!(stats.put_volume, 20);
+        assert_eq!(stats.put_open_interest, 200);
+        let atm = stats.atm_implied_volatility.unwrap();
+        assert!((atm - 0.22).abs() < 1e-9);
+    }
+
+    fn chain(underlying: &str) -> OptionChainData {
+        OptionChainData {
+            underlying: underlying.to_string(),
+            underlying_price: Decimal::new(101, 0),
+            options: vec![
+                option("2026-03-20", 100, OptionType::Call, 0.20),
+                option("2026-03-20", 100, OptionType::Put, 0.24),
+                option("2026-03-20", 110, OptionType::Call, 0.18),
+                option("2026-04-17", 100, OptionType::Put, 0.30),
+            ],
+            as_of: Timestamp::now(),
+        }
+    }
+}
@@ -4,9 +4,104 @@
 //! They differ from use cases in that they typically run as background tasks
 //! or provide long-running functionality.
 
+mod asset_metadata;
+mod broker_accounts;
+mod candles;
+mod clock_skew;
+mod delta_hedger;
+mod eod_scheduler;
+mod event_outbox;
+mod exposure_cache;
+mod feature_flags;
+mod feed_sanitizer;
+mod feed_subscriptions;
+mod iv_surface;
+mod kill_switch;
+mod leader_election;
+mod leg_balance;
+mod market_snapshot;
+mod option_chain_cache;
+mod order_updates;
+mod plan_progress;
+mod plan_scheduler;
+mod position_lifecycle;
 mod position_monitor;
+mod pre_open_check;
+mod quote_cache;
+mod readiness;
+mod realized_pnl;
+mod session_stats;
+mod shadow_execution;
+mod shutdown;
+mod spread_builder;
+mod stop_adjuster;
+mod trade_blotter;
+mod trading_halts;
+mod watchlists;
 
+pub use asset_metadata::{
+    ASSET_NOT_FRACTIONABLE, ASSET_NOT_MARGINABLE, ASSET_NOT_TRADABLE, AssetMetadataConfig,
+    AssetMetadataService, SHORT_NOT_AVAILABLE,
+};
+pub use broker_accounts::{
+    BrokerAccount, BrokerAccountError, BrokerAccountService, MAX_USER_ID_LEN,
+};
+pub use candles::{CandleAggregator, CandleAggregatorConfig};
+pub use clock_skew::{ClockSkewConfig, ClockSkewMonitor, ClockSyncStatus};
+pub use delta_hedger::{
+    DeltaExposure, DeltaHedgeError, DeltaHedger, DeltaHedgerConfig, HedgeOutcome, HedgeRecord,
+    HedgeRule, HedgeSizing,
+};
+pub use eod_scheduler::{
+    EodAction, EodPolicies, EodPolicy, EodRecord, EodScheduler, EodSchedulerConfig,
+};
+pub use event_outbox::{EventOutbox, EventOutboxConfig, OutboxStats};
+pub use exposure_cache::ExposureCache;
+pub use feature_flags::{Feature, FeatureFlag, FeatureFlags, UnknownFeature};
+pub use feed_sanitizer::{FeedSanitizer, FeedSanitizerConfig, RejectedTicks, TickRejection};
+pub use feed_subscriptions::{
+    SubscriptionChanges, SubscriptionReconcileError, SubscriptionReconciler,
+    SubscriptionReconcilerConfig,
+};
+pub use iv_surface::{
+    DEFAULT_RISK_FREE_RATE, IvSurfaceError, IvSurfaceService, fit_chain, iv_quotes,
+};
+pub use kill_switch::{FlattenReport, KillSwitch, KillSwitchConfig, TRADING_DISABLED};
+pub use leader_election::{LeaderElection, LeaderElectionConfig, NOT_LEADER, Role};
+pub use leg_balance::{
+    LegBalanceConfig, LegBalanceMonitor, LegImbalanceRecord, LegRemediation, RemediationOutcome,
+};
+pub use market_snapshot::{
+    MarketSnapshotService, OptionChainStats, SnapshotComponent, SnapshotComponentError,
+    SymbolMarketSnapshot,
+};
+pub use option_chain_cache::{CacheStats, OptionChainCache, OptionChainCacheConfig};
+pub use order_updates::{OrderUpdateCounts, OrderUpdateListener};
+pub use plan_progress::{
+    DecisionProgress, DecisionStatus, PlanProgress, PlanProgressService, PlanProgressSummary,
+};
+pub use plan_scheduler::{PlanOutcome, PlanSchedulerConfig, PlanSchedulerService};
+pub use position_lifecycle::PositionLifecycleTracker;
 pub use position_monitor::{
     CircuitBreaker, CircuitBreakerState, ExitResult, PositionMonitorConfig, PositionMonitorError,
     PositionMonitorService, SyncResult,
 };
+pub use pre_open_check::{
+    GapAlert, PreOpenCheck, PreOpenCheckConfig, PreOpenReport, ProtectionCheck, ProtectionStatus,
+};
+pub use quote_cache::{DEFAULT_QUOTE_CACHE_TTL, QuoteCache};
+pub use readiness::{DependencyCheck, ReadinessReport, ReadinessService};
+pub use realized_pnl::RealizedPnlService;
+pub use session_stats::{
+    BreakerStatus, CacheStatsSource, CacheStatus, DEFAULT_FEED_STALE_AFTER, FeedStatus,
+    OrderActivity, ReconciliationSummary, SessionSnapshot, SessionStatsService,
+};
+pub use shadow_execution::{
+    DEFAULT_SHADOW_CAPACITY, ShadowComparison, ShadowExecution, ShadowOrder, ShadowReport,
+};
+pub use shutdown::{InFlight, SHUTTING_DOWN, ShutdownCoordinator, ShutdownReport};
+pub use spread_builder::{SpreadBuildError, SpreadBuilderService, chain_options};
+pub use stop_adjuster::{StopAdjustError, StopAdjuster, StopAdjustment, StopAdjustmentReport};
+pub use trade_blotter::{BlotterError, BlotterFill, TradeBlotter};
+pub use trading_halts::{HALTED_SYMBOL, TradingHalt, TradingHalts, TradingStatusUpdate};
+pub use watchlists::{MAX_WATCHLIST_NAME_LEN, WatchlistService};
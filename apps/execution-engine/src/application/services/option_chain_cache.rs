@@ -0,0 +1,420 @@
+//! Option Chain Cache
+//!
+//! Serves option chains from memory so repeated requests for the same
+//! underlying do not each fetch contracts and snapshots from the provider.
+//! Chains are cached whole, keyed by underlying, and filtered per request.
+//!
+//! Underlyings requested within the active window are refreshed in the
+//! background every TTL, so actively traded chains stay warm; the rest
+//! expire and are evicted. A filtered request that misses is passed through
+//! to the provider (which can narrow the fetch) instead of loading the whole
+//! chain; the refresher loads the full chain on its next pass.
+
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, PoisonError, RwLock};
+use std::time::{Duration, Instant};
+
+use async_trait::async_trait;
+use tokio_util::sync::CancellationToken;
+
+use super::CacheStatsSource;
+use crate::application::ports::{
+    DailyBars, LastTrade, MarketDataError, MarketDataPort, MarketQuote, OptionChainData,
+    OptionChainFilter,
+};
+
+/// Configuration for the option chain cache.
+#[derive(Debug, Clone)]
+pub struct OptionChainCacheConfig {
+    /// How long a cached chain is served, and how often active chains are
+    /// refreshed.
+    pub ttl: Duration,
+    /// Underlyings requested within this window are refreshed in the
+    /// background; older ones are evicted.
+    pub active_window: Duration,
+}
+
+impl Default for OptionChainCacheConfig {
+    fn default() -> Self {
+        Self {
+            ttl: Duration::from_secs(60),
+            active_window: Duration::from_secs(900),
+        }
+    }
+}
+
+/// Cache counters since startup.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct CacheStats {
+    /// Entries currently cached.
+    pub entries: usize,
+    /// Requests served from the cache.
+    pub hits: u64,
+    /// Requests sent to the provider.
+    pub misses: u64,
+    /// Background refreshes that succeeded.
+    pub refreshes: u64,
+    /// Background refreshes that failed.
+    pub refresh_failures: u64,
+}
+
+struct CachedChain {
+    chain: OptionChainData,
+    fetched_at: Instant,
+}
+
+/// Caching decorator for a [`MarketDataPort`].
+pub struct OptionChainCache<M>
+where
+    M: MarketDataPort,
+{
+    config: OptionChainCacheConfig,
+    market_data: Arc<M>,
+    chains: RwLock<HashMap<String, CachedChain>>,
+    requested: RwLock<HashMap<String, Instant>>,
+    hits: AtomicU64,
+    misses: AtomicU64,
+    refreshes: AtomicU64,
+    refresh_failures: AtomicU64,
+}
+
+impl<M> OptionChainCache<M>
+where
+    M: MarketDataPort,
+{
+    /// Create a cache with default configuration.
+    #[must_use]
+    pub fn new(market_data: Arc<M>) -> Self {
+        Self::with_config(OptionChainCacheConfig::default(), market_data)
+    }
+
+    /// Create with custom configuration.
+    #[must_use]
+    pub fn with_config(config: OptionChainCacheConfig, market_data: Arc<M>) -> Self {
+        Self {
+            config,
+            market_data,
+            chains: RwLock::new(HashMap::new()),
+            requested: RwLock::new(HashMap::new()),
+            hits: AtomicU64::new(0),
+            misses: AtomicU64::new(0),
+            refreshes: AtomicU64::new(0),
+            refresh_failures: AtomicU64::new(0),
+        }
+    }
+
+    /// Current counters.
+    #[must_use]
+    pub fn stats(&self) -> CacheStats {
+        CacheStats {
+            entries: self
+                .chains
+                .read()
+                .unwrap_or_else(PoisonError::into_inner)
+                .len(),
+            hits: self.hits.load(Ordering::Relaxed),
+            misses: self.misses.load(Ordering::Relaxed),
+            refreshes: self.refreshes.load(Ordering::Relaxed),
+            refresh_failures: self.refresh_failures.load(Ordering::Relaxed),
+        }
+    }
+
+    /// Refresh chains for recently requested underlyings and evict the rest.
+    ///
+    /// Returns the number of chains refreshed. A failed refresh keeps the
+    /// previous chain until it expires.
+    pub async fn refresh_active(&self) -> usize {
+        let active: Vec<String> = {
+            let mut requested = self
+                .requested
+                .write()
+                .unwrap_or_else(PoisonError::into_inner);
+            requested.retain(|_, at| at.elapsed() < self.config.active_window);
+            requested.keys().cloned().collect()
+        };
+        self.chains
+            .write()
+            .unwrap_or_else(PoisonError::into_inner)
+            .retain(|underlying, _| active.contains(underlying));
+
+        let mut refreshed = 0;
+        for underlying in active {
+            match self.market_data.get_option_chain(&underlying).await {
+                Ok(chain) => {
+                    self.store(&underlying, chain);
+                    self.refreshes.fetch_add(1, Ordering::Relaxed);
+                    refreshed += 1;
+                }
+                Err(e) => {
+                    self.refresh_failures.fetch_add(1, Ordering::Relaxed);
+                    tracing::warn!(
+                        underlying = %underlying,
+                        error = %e,
+                        "Option chain refresh failed"
+                    );
+                }
+            }
+        }
+        refreshed
+    }
+
+    /// Refresh active chains every TTL until shutdown.
+    pub fn start(self: &Arc<Self>, shutdown: CancellationToken)
+    where
+        M: 'static,
+    {
+        let cache = Arc::clone(self);
+        let refresh_interval = self.config.ttl.max(Duration::from_secs(1));
+
+        tokio::spawn(async move {
+            let mut interval = tokio::time::interval(refresh_interval);
+
+            loop {
+                tokio::select! {
+                    _ = interval.tick() => {
+                        let refreshed = cache.refresh_active().await;
+                        if refreshed > 0 {
+                            tracing::debug!(refreshed, "Option chains refreshed");
+                        }
+                    }
+                    () = shutdown.cancelled() => {
+                        tracing::info!("Option chain refresh shutting down");
+                        break;
+                    }
+                }
+            }
+        });
+    }
+
+    /// Record a request and return the cached chain if still fresh.
+    fn lookup(&self, underlying: &str) -> Option<OptionChainData> {
+        self.requested
+            .write()
+            .unwrap_or_else(PoisonError::into_inner)
+            .insert(underlying.to_string(), Instant::now());
+
+        let chains = self.chains.read().unwrap_or_else(PoisonError::into_inner);
+        let cached = chains
+            .get(underlying)
+            .filter(|cached| cached.fetched_at.elapsed() < self.config.ttl)
+            .map(|cached| cached.chain.clone());
+        drop(chains);
+
+        let counter = if cached.is_some() {
+            &self.hits
+        } else {
+            &self.misses
+        };
+        counter.fetch_add(1, Ordering::Relaxed);
+        cached
+    }
+
+    fn store(&self, underlying: &str, chain: OptionChainData) {
+        self.chains
+            .write()
+            .unwrap_or_else(PoisonError::into_inner)
+            .insert(
+                underlying.to_string(),
+                CachedChain {
+                    chain,
+                    fetched_at: Instant::now(),
+                },
+            );
+    }
+}
+
+impl<M> CacheStatsSource for OptionChainCache<M>
+where
+    M: MarketDataPort,
+{
+    fn cache_stats(&self) -> CacheStats {
+        self.stats()
+    }
+}
+
+#[async_trait]
+impl<M> MarketDataPort for OptionChainCache<M>
+where
+    M: MarketDataPort,
+{
+    async fn get_quotes(&self, symbols: &[String]) -> Result<Vec<MarketQuote>, MarketDataError> {
+        self.market_data.get_quotes(symbols).await
+    }
+
+    async fn get_option_chain(&self, underlying: &str) -> Result<OptionChainData, MarketDataError> {
+        let underlying = underlying.to_uppercase();
+        if let Some(chain) = self.lookup(&underlying) {
+            return Ok(chain);
+        }
+
+        let chain = self.market_data.get_option_chain(&underlying).await?;
+        self.store(&underlying, chain.clone());
+        Ok(chain)
+    }
+
+    async fn get_filtered_option_chain(
+        &self,
+        underlying: &str,
+        filter: &OptionChainFilter,
+    ) -> Result<OptionChainData, MarketDataError> {
+        if *filter == OptionChainFilter::default() {
+            return self.get_option_chain(underlying).await;
+        }
+
+        let underlying = underlying.to_uppercase();
+        if let Some(mut chain) = self.lookup(&underlying) {
+            filter.apply(&mut chain);
+            return Ok(chain);
+        }
+
+        self.market_data
+            .get_filtered_option_chain(&underlying, filter)
+            .await
+    }
+
+    async fn get_latest_trades(
+        &self,
+        symbols: &[String],
+    ) -> Result<Vec<LastTrade>, MarketDataError> {
+        self.market_data.get_latest_trades(symbols).await
+    }
+
+    async fn get_daily_bars(&self, symbols: &[String]) -> Result<Vec<DailyBars>, MarketDataError> {
+        self.market_data.get_daily_bars(symbols).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::application::ports::{OptionContract, OptionQuote, OptionType};
+    use crate::domain::shared::Timestamp;
+    use rust_decimal::Decimal;
+    use std::sync::atomic::AtomicUsize;
+
+    #[derive(Default)]
+    struct CountingMarketData {
+        chain_calls: AtomicUsize,
+        filtered_calls: AtomicUsize,
+    }
+
+    #[async_trait]
+    impl MarketDataPort for CountingMarketData {
+        async fn get_quotes(
+            &self,
+            _symbols: &[String],
+        ) -> Result<Vec<MarketQuote>, MarketDataError> {
+            Ok(vec![])
+        }
+
+        async fn get_option_chain(
+            &self,
+            underlying: &str,
+        ) -> Result<OptionChainData, MarketDataError> {
+            self.chain_calls.fetch_add(1, Ordering::SeqCst);
+            let option = |option_type| OptionQuote {
+                contract: OptionContract {
+                    underlying: underlying.to_string(),
+                    expiration: "2025-01-17".to_string(),
+                    strike: Decimal::new(100, 0),
+                    option_type,
+                },
+                quote: None,
+                implied_volatility: None,
+                greeks: None,
+                open_interest: 0,
+            };
+            Ok(OptionChainData {
+                underlying: underlying.to_string(),
+                underlying_price: Decimal::new(100, 0),
+                options: vec![option(OptionType::Call), option(OptionType::Put)],
+                as_of: Timestamp::now(),
+            })
+        }
+
+        async fn get_filtered_option_chain(
+            &self,
+            underlying: &str,
+            filter: &OptionChainFilter,
+        ) -> Result<OptionChainData, MarketDataError> {
+            self.filtered_calls.fetch_add(1, Ordering::SeqCst);
+            let mut chain = self.get_option_chain(underlying).await?;
+            filter.apply(&mut chain);
+            Ok(chain)
+        }
+    }
+
+    fn calls_only() -> OptionChainFilter {
+        OptionChainFilter {
+            option_type: Some(OptionType::Call),
+            ..OptionChainFilter::default()
+        }
+    }
+
+    #[tokio::test]
+    async fn serves_repeat_requests_from_cache() {
+        let provider = Arc::new(CountingMarketData::default());
+        let cache = OptionChainCache::new(Arc::clone(&provider));
+
+        cache.get_option_chain("spy").await.unwrap();
+        cache.get_option_chain("SPY").await.unwrap();
+        let calls = cache
+            .get_filtered_option_chain("SPY", &calls_only())
+            .await
+            .unwrap();
+
+        assert_eq!(calls.options.len(), 1);
+        assert_eq!(provider.chain_calls.load(Ordering::SeqCst), 1);
+        assert_eq!(
+            cache.stats(),
+            CacheStats {
+                entries: 1,
+                hits: 2,
+                misses: 1,
+                refreshes: 0,
+                refresh_failures: 0,
+            }
+        );
+    }
+
+    #[tokio::test]
+    async fn filtered_miss_passes_through_and_refresher_loads_chain() {
+        let provider = Arc::new(CountingMarketData::default());
+        let cache = OptionChainCache::new(Arc::clone(&provider));
+
+        let chain = cache
+            .get_filtered_option_chain("QQQ", &calls_only())
+            .await
+            .unwrap();
+        assert_eq!(chain.options.len(), 1);
+        assert_eq!(provider.filtered_calls.load(Ordering::SeqCst), 1);
+        assert_eq!(cache.stats().entries, 0);
+
+        assert_eq!(cache.refresh_active().await, 1);
+        cache
+            .get_filtered_option_chain("QQQ", &calls_only())
+            .await
+            .unwrap();
+        assert_eq!(provider.filtered_calls.load(Ordering::SeqCst), 1);
+        assert_eq!(cache.stats().hits, 1);
+        assert_eq!(cache.stats().refreshes, 1);
+    }
+
+    #[tokio::test]
+    async fn evicts_underlyings_outside_active_window() {
+        let provider = Arc::new(CountingMarketData::default());
+        let config = OptionChainCacheConfig {
+            ttl: Duration::ZERO,
+            active_window: Duration::ZERO,
+        };
+        let cache = OptionChainCache::with_config(config, Arc::clone(&provider));
+
+        cache.get_option_chain("IWM").await.unwrap();
+        cache.get_option_chain("IWM").await.unwrap();
+        assert_eq!(cache.stats().misses, 2);
+
+        assert_eq!(cache.refresh_active().await, 0);
+        assert_eq!(cache.stats().entries, 0);
+    }
+}
@@ -0,0 +1,315 @@
+//! Order Update Listener
+//!
+//! Applies order status and fill updates pushed by the broker to local
+//! orders as they happen, instead of waiting for the next reconciliation
+//! pass. Each stream (the trading WebSocket, the SSE events endpoint, or
+//! both) feeds one channel; every update is merged through
+//! [`ReconcileUseCase::apply_broker_state`]. Merging is idempotent, so with
+//! both streams running the second copy of an update is a no-op and either
+//! stream alone keeps orders current if the other drops.
+//!
+//! Updates for orders this engine did not place are ignored.
+
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex, PoisonError};
+
+use serde::Serialize;
+use tokio::sync::mpsc;
+use tokio_util::sync::CancellationToken;
+
+use crate::application::ports::{BrokerPort, OrderUpdate, OrderUpdatePort, OrderUpdateTransport};
+use crate::application::use_cases::ReconcileUseCase;
+use crate::domain::order_execution::repository::OrderRepository;
+
+/// Channel capacity for updates from all streams.
+const UPDATE_CHANNEL_CAPACITY: usize = 256;
+
+/// Update counts for one transport.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize)]
+pub struct OrderUpdateCounts {
+    /// Updates received.
+    pub received: u64,
+    /// Updates that changed a local order.
+    pub applied: u64,
+    /// Updates for orders unknown locally, or that failed to apply.
+    pub ignored: u64,
+}
+
+impl OrderUpdateCounts {
+    /// Count an update: applied, a no-op, or ignored (`None`).
+    const fn record(&mut self, applied: Option<bool>) {
+        self.received += 1;
+        match applied {
+            Some(true) => self.applied += 1,
+            Some(false) => {}
+            None => self.ignored += 1,
+        }
+    }
+}
+
+/// Applies broker order updates from one or more streams.
+pub struct OrderUpdateListener<B, O>
+where
+    B: BrokerPort,
+    O: OrderRepository,
+{
+    reconcile: Arc<ReconcileUseCase<B, O>>,
+    counts: Mutex<HashMap<OrderUpdateTransport, OrderUpdateCounts>>,
+}
+
+impl<B, O> OrderUpdateListener<B, O>
+where
+    B: BrokerPort,
+    O: OrderRepository,
+{
+    /// Create a listener merging updates through `reconcile`.
+    #[must_use]
+    pub fn new(reconcile: Arc<ReconcileUseCase<B, O>>) -> Self {
+        Self {
+            reconcile,
+            counts: Mutex::new(HashMap::new()),
+        }
+    }
+
+    /// Update counts for `transport`.
+    #[must_use]
+    pub fn counts(&self, transport: OrderUpdateTransport) -> OrderUpdateCounts {
+        self.counts
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .get(&transport)
+            .copied()
+            .unwrap_or_default()
+    }
+
+    /// Apply one update. Returns whether it changed the local order.
+    pub async fn apply(&self, update: &OrderUpdate) -> bool {
+        let source = update.transport.to_string();
+        let applied = match self
+            .reconcile
+            .apply_broker_state(&update.order, &source)
+            .await
+        {
+            Ok(reconciliation) => {
+                let applied = !reconciliation.actions.is_empty();
+                if applied {
+                    tracing::info!(
+                        transport = %update.transport,
+                        event = %update.event,
+                        order_id = %reconciliation.order_id,
+                        broker_order_id = %reconciliation.broker_order_id,
+                        actions = ?reconciliation.actions,
+                        "Applied broker order update"
+                    );
+                }
+                Some(applied)
+            }
+            Err(e) => {
+                tracing::debug!(
+                    transport = %update.transport,
+                    event = %update.event,
+                    broker_order_id = %update.order.broker_order_id,
+                    error = %e,
+                    "Ignored broker order update"
+                );
+                None
+            }
+        };
+
+        self.counts
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .entry(update.transport)
+            .or_default()
+            .record(applied);
+        applied.unwrap_or(false)
+    }
+
+    /// Run `streams` and apply their updates until `shutdown` is cancelled.
+    pub fn start(
+        self: &Arc<Self>,
+        streams: Vec<Arc<dyn OrderUpdatePort>>,
+        shutdown: CancellationToken,
+    ) where
+        B: 'static,
+        O: 'static,
+    {
+        let (tx, mut rx) = mpsc::channel(UPDATE_CHANNEL_CAPACITY);
+        for stream in streams {
+            tracing::info!(transport = %stream.transport(), "Starting order update stream");
+            let tx = tx.clone();
+            let shutdown = shutdown.clone();
+            tokio::spawn(async move { stream.run(tx, shutdown).await });
+        }
+        drop(tx);
+
+        let listener = Arc::clone(self);
+        tokio::spawn(async move {
+            loop {
+                tokio::select! {
+                    update = rx.recv() => {
+                        let Some(update) = update else {
+                            tracing::warn!("All order update streams stopped");
+                            break;
+                        };
+                        listener.apply(&update).await;
+                    }
+                    () = shutdown.cancelled() => {
+                        tracing::info!("Order update listener shutting down");
+                        break;
+                    }
+                }
+            }
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::application::ports::{
+        BrokerError, CancelOrderRequest, OrderAck, PositionInfo, SubmitOrderRequest,
+    };
+    use crate::domain::order_execution::aggregate::{CreateOrderCommand, Order};
+    use crate::domain::order_execution::value_objects::{
+        OrderPurpose, OrderSide, OrderStatus, OrderType, TimeInForce,
+    };
+    use crate::domain::shared::{BrokerId, InstrumentId, Money, OrderId, Quantity, Symbol};
+    use crate::infrastructure::persistence::InMemoryOrderRepository;
+    use async_trait::async_trait;
+    use rust_decimal::Decimal;
+
+    struct NoBroker;
+
+    #[async_trait]
+    impl BrokerPort for NoBroker {
+        async fn submit_order(
+            &self,
+            _request: SubmitOrderRequest,
+        ) -> Result<OrderAck, BrokerError> {
+            unreachable!("updates never submit")
+        }
+
+        async fn cancel_order(&self, _request: CancelOrderRequest) -> Result<(), BrokerError> {
+            unreachable!("updates never cancel")
+        }
+
+        async fn get_order(&self, broker_order_id: &BrokerId) -> Result<OrderAck, BrokerError> {
+            Err(BrokerError::OrderNotFound {
+                order_id: broker_order_id.to_string(),
+            })
+        }
+
+        async fn get_open_orders(&self) -> Result<Vec<OrderAck>, BrokerError> {
+            Ok(vec![])
+        }
+
+        async fn get_buying_power(&self) -> Result<Decimal, BrokerError> {
+            Ok(Decimal::ZERO)
+        }
+
+        async fn get_position(
+            &self,
+            _instrument_id: &InstrumentId,
+        ) -> Result<Option<Decimal>, BrokerError> {
+            Ok(None)
+        }
+
+        async fn get_all_positions(&self) -> Result<Vec<PositionInfo>, BrokerError> {
+            Ok(vec![])
+        }
+    }
+
+    /// A streamed update filling `qty` of `broker-1` at 100.
+    fn fill(transport: OrderUpdateTransport, client_order_id: &OrderId, qty: i64) -> OrderUpdate {
+        OrderUpdate {
+            transport,
+            event: "partial_fill".to_string(),
+            order: OrderAck {
+                broker_order_id: BrokerId::new("broker-1"),
+                client_order_id: client_order_id.clone(),
+                status: OrderStatus::PartiallyFilled,
+                filled_qty: Decimal::from(qty),
+                avg_fill_price: Some(Decimal::new(100, 0)),
+                child_order_ids: Vec::new(),
+                leg_fills: Vec::new(),
+            },
+        }
+    }
+
+    async fn listener() -> (
+        OrderUpdateListener<NoBroker, InMemoryOrderRepository>,
+        Arc<InMemoryOrderRepository>,
+        OrderId,
+    ) {
+        let order_repo = Arc::new(InMemoryOrderRepository::new());
+        let mut order = Order::new(CreateOrderCommand {
+            symbol: Symbol::new("AAPL"),
+            side: OrderSide::Buy,
+            order_type: OrderType::Limit,
+            quantity: Quantity::from_i64(10),
+            limit_price: Some(Money::usd(100.0)),
+            stop_price: None,
+            time_in_force: TimeInForce::Day,
+            purpose: OrderPurpose::Entry,
+            legs: vec![],
+        })
+        .unwrap();
+        order.accept(BrokerId::new("broker-1")).unwrap();
+        order_repo.save(&order).await.unwrap();
+
+        let reconcile = Arc::new(ReconcileUseCase::new(
+            Arc::new(NoBroker),
+            Arc::clone(&order_repo),
+        ));
+        (
+            OrderUpdateListener::new(reconcile),
+            order_repo,
+            order.id().clone(),
+        )
+    }
+
+    #[tokio::test]
+    async fn duplicate_updates_across_streams_apply_once() {
+        let (listener, order_repo, order_id) = listener().await;
+
+        assert!(
+            listener
+                .apply(&fill(OrderUpdateTransport::Sse, &order_id, 4))
+                .await
+        );
+        assert!(
+            !listener
+                .apply(&fill(OrderUpdateTransport::WebSocket, &order_id, 4))
+                .await
+        );
+        assert!(
+            listener
+                .apply(&fill(OrderUpdateTransport::WebSocket, &order_id, 6))
+                .await
+        );
+
+        let order = order_repo.find_by_id(&order_id).await.unwrap().unwrap();
+        assert_eq!(order.partial_fill().cum_qty(), Quantity::from_i64(6));
+        assert_eq!(order.partial_fill().fills()[0].venue, "SSE");
+        assert_eq!(order.partial_fill().fills()[1].venue, "WEBSOCKET");
+        assert_eq!(
+            listener.counts(OrderUpdateTransport::WebSocket),
+            OrderUpdateCounts {
+                received: 2,
+                applied: 1,
+                ignored: 0,
+            }
+        );
+    }
+
+    #[tokio::test]
+    async fn updates_for_unknown_orders_are_ignored() {
+        let (listener, _, _) = listener().await;
+        let mut update = fill(OrderUpdateTransport::Sse, &OrderId::new("manual"), 1);
+        update.order.broker_order_id = BrokerId::new("placed-elsewhere");
+
+        assert!(!listener.apply(&update).await);
+        assert_eq!(listener.counts(OrderUpdateTransport::Sse).ignored, 1);
+    }
+}
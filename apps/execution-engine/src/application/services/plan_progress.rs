@@ -0,0 +1,404 @@
+//! Plan Progress
+//!
+//! Execution status of each decision in a decision cycle, assembled from the
+//! order store on request so the orchestrator can follow a plan from
+//! submission to completion. Decisions from plans still waiting for their
+//! execution window are reported as pending.
+
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use rust_decimal::Decimal;
+
+use crate::application::ports::ScheduledPlan;
+use crate::domain::order_execution::aggregate::Order;
+use crate::domain::order_execution::errors::OrderError;
+use crate::domain::order_execution::events::OrderEvent;
+use crate::domain::order_execution::repository::OrderRepository;
+use crate::domain::order_execution::value_objects::{OrderSide, OrderStatus};
+use crate::domain::shared::{CycleId, Timestamp};
+
+/// Terminal statuses searched for a cycle's completed orders.
+const TERMINAL_STATUSES: [OrderStatus; 4] = [
+    OrderStatus::Filled,
+    OrderStatus::Canceled,
+    OrderStatus::Rejected,
+    OrderStatus::Expired,
+];
+
+/// Where a decision's order stands.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DecisionStatus {
+    /// Not yet acknowledged by the broker (or waiting for its window).
+    Pending,
+    /// Working at the broker, possibly partially filled.
+    Working,
+    /// Completely filled.
+    Filled,
+    /// Canceled before filling completely.
+    Canceled,
+    /// Rejected by the broker.
+    Rejected,
+    /// Expired before filling completely.
+    Expired,
+}
+
+impl DecisionStatus {
+    /// Status of an order.
+    #[must_use]
+    pub const fn from_order_status(status: OrderStatus) -> Self {
+        match status {
+            OrderStatus::New | OrderStatus::PendingNew => Self::Pending,
+            OrderStatus::Accepted | OrderStatus::PartiallyFilled | OrderStatus::PendingCancel => {
+                Self::Working
+            }
+            OrderStatus::Filled => Self::Filled,
+            OrderStatus::Canceled => Self::Canceled,
+            OrderStatus::Rejected => Self::Rejected,
+            OrderStatus::Expired => Self::Expired,
+        }
+    }
+
+    /// Check if the decision can still change.
+    #[must_use]
+    pub const fn is_open(&self) -> bool {
+        matches!(self, Self::Pending | Self::Working)
+    }
+}
+
+/// Execution progress of one decision.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DecisionProgress {
+    /// Order ID.
+    pub order_id: String,
+    /// Symbol.
+    pub symbol: String,
+    /// Side.
+    pub side: OrderSide,
+    /// Status.
+    pub status: DecisionStatus,
+    /// Ordered quantity.
+    pub quantity: Decimal,
+    /// Filled quantity.
+    pub filled_qty: Decimal,
+    /// Filled share of the ordered quantity, in percent.
+    pub filled_pct: Decimal,
+    /// Average fill price (`None` until the first fill).
+    pub avg_fill_price: Option<Decimal>,
+    /// Why the order stopped short of a complete fill.
+    pub error: Option<String>,
+    /// Last state change (`None` for decisions not yet submitted).
+    pub updated_at: Option<Timestamp>,
+}
+
+impl DecisionProgress {
+    fn from_order(order: &Order) -> Self {
+        let partial_fill = order.partial_fill();
+        let quantity = order.quantity().amount();
+        let filled_qty = partial_fill.cum_qty().amount();
+        let status = DecisionStatus::from_order_status(order.status());
+
+        Self {
+            order_id: order.id().to_string(),
+            symbol: order.symbol().to_string(),
+            side: order.side(),
+            status,
+            quantity,
+            filled_qty,
+            filled_pct: filled_pct(filled_qty, quantity),
+            avg_fill_price: (!filled_qty.is_zero()).then(|| partial_fill.avg_px().amount()),
+            error: order_error(order, status),
+            updated_at: Some(order.updated_at()),
+        }
+    }
+}
+
+/// Decision counts by status.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct PlanProgressSummary {
+    /// Decisions not yet working.
+    pub pending: usize,
+    /// Decisions working at the broker.
+    pub working: usize,
+    /// Decisions completely filled.
+    pub filled: usize,
+    /// Decisions canceled, rejected or expired.
+    pub failed: usize,
+}
+
+/// Execution progress of a decision cycle.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PlanProgress {
+    /// Cycle ID.
+    pub cycle_id: String,
+    /// When the progress was assembled.
+    pub as_of: Timestamp,
+    /// Decisions, by order ID.
+    pub decisions: Vec<DecisionProgress>,
+    /// Decision counts by status.
+    pub summary: PlanProgressSummary,
+}
+
+impl PlanProgress {
+    /// Check if every decision has reached a final status.
+    #[must_use]
+    pub fn is_complete(&self) -> bool {
+        self.decisions.iter().all(|d| !d.status.is_open())
+    }
+}
+
+/// Aggregates order state into per-cycle execution progress.
+pub struct PlanProgressService<O>
+where
+    O: OrderRepository,
+{
+    order_repo: Arc<O>,
+}
+
+impl<O> PlanProgressService<O>
+where
+    O: OrderRepository,
+{
+    /// Create a progress service reading orders from `order_repo`.
+    #[must_use]
+    pub const fn new(order_repo: Arc<O>) -> Self {
+        Self { order_repo }
+    }
+
+    /// Progress of `cycle_id`, including decisions from `scheduled` plans
+    /// that have not been submitted yet.
+    ///
+    /// Returns `None` when the cycle has no orders and no scheduled plan.
+    ///
+    /// # Errors
+    ///
+    /// Returns error if the order store cannot be read.
+    pub async fn progress(
+        &self,
+        cycle_id: &CycleId,
+        scheduled: &[ScheduledPlan],
+    ) -> Result<Option<PlanProgress>, OrderError> {
+        // Keyed by order ID: an order that completes between the active and
+        // terminal queries shows up in both, and the terminal copy wins
+        let mut orders: HashMap<String, Order> = HashMap::new();
+        for order in self.order_repo.find_active().await? {
+            orders.insert(order.id().to_string(), order);
+        }
+        for status in TERMINAL_STATUSES {
+            for order in self.order_repo.find_by_status(status).await? {
+                orders.insert(order.id().to_string(), order);
+            }
+        }
+
+        let mut decisions: Vec<DecisionProgress> = orders
+            .values()
+            .filter(|o| o.cycle_id() == Some(cycle_id))
+            .map(DecisionProgress::from_order)
+            .collect();
+
+        let pending = scheduled
+            .iter()
+            .filter(|p| p.cycle_id == cycle_id.as_str())
+            .flat_map(|p| &p.request.orders)
+            .filter(|o| !decisions.iter().any(|d| d.order_id == o.client_order_id))
+            .map(|o| DecisionProgress {
+                order_id: o.client_order_id.clone(),
+                symbol: o.symbol.clone(),
+                side: o.side,
+                status: DecisionStatus::Pending,
+                quantity: o.quantity,
+                filled_qty: Decimal::ZERO,
+                filled_pct: Decimal::ZERO,
+                avg_fill_price: None,
+                error: None,
+                updated_at: None,
+            })
+            .collect::<Vec<_>>();
+        decisions.extend(pending);
+
+        if decisions.is_empty() {
+            return Ok(None);
+        }
+        decisions.sort_by(|a, b| a.order_id.cmp(&b.order_id));
+
+        let mut summary = PlanProgressSummary::default();
+        for decision in &decisions {
+            match decision.status {
+                DecisionStatus::Pending => summary.pending += 1,
+                DecisionStatus::Working => summary.working += 1,
+                DecisionStatus::Filled => summary.filled += 1,
+                DecisionStatus::Canceled | DecisionStatus::Rejected | DecisionStatus::Expired => {
+                    summary.failed += 1;
+                }
+            }
+        }
+
+        Ok(Some(PlanProgress {
+            cycle_id: cycle_id.to_string(),
+            as_of: Timestamp::now(),
+            decisions,
+            summary,
+        }))
+    }
+}
+
+fn filled_pct(filled_qty: Decimal, quantity: Decimal) -> Decimal {
+    if quantity.is_zero() {
+        return Decimal::ZERO;
+    }
+    (filled_qty / quantity * Decimal::ONE_HUNDRED).round_dp(2)
+}
+
+/// Reason an order ended without a complete fill.
+///
+/// Uses the reject or cancel reason while the event is still pending, and a
+/// generic description once events have been published.
+fn order_error(order: &Order, status: DecisionStatus) -> Option<String> {
+    let reason = order.pending_events().iter().rev().find_map(|e| match e {
+        OrderEvent::Rejected(r) => Some(r.reason.to_string()),
+        OrderEvent::Canceled(c) => Some(c.reason.to_string()),
+        _ => None,
+    });
+    match status {
+        DecisionStatus::Pending | DecisionStatus::Working | DecisionStatus::Filled => None,
+        DecisionStatus::Rejected => Some(reason.unwrap_or_else(|| "rejected by broker".into())),
+        DecisionStatus::Canceled => Some(reason.unwrap_or_else(|| "canceled".into())),
+        DecisionStatus::Expired => Some("expired".to_string()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::application::dto::{CreateOrderDto, SubmitOrdersRequestDto};
+    use crate::application::ports::ExecutionWindow;
+    use crate::domain::order_execution::aggregate::CreateOrderCommand;
+    use crate::domain::order_execution::value_objects::{
+        FillReport, OrderPurpose, OrderType, RejectReason, TimeInForce,
+    };
+    use crate::domain::shared::{BrokerId, Money, Quantity, Symbol};
+    use crate::infrastructure::persistence::InMemoryOrderRepository;
+
+    fn cycle_order(symbol: &str, cycle: &str) -> Order {
+        let mut order = Order::new(CreateOrderCommand {
+            symbol: Symbol::new(symbol),
+            side: OrderSide::Buy,
+            order_type: OrderType::Market,
+            quantity: Quantity::from_i64(10),
+            limit_price: None,
+            stop_price: None,
+            time_in_force: TimeInForce::Day,
+            purpose: OrderPurpose::Entry,
+            legs: vec![],
+        })
+        .unwrap();
+        order.tag_cycle(CycleId::new(cycle));
+        order
+    }
+
+    fn fill(qty: i64, price: f64) -> FillReport {
+        FillReport::new(
+            format!("fill-{qty}"),
+            Quantity::from_i64(qty),
+            Money::usd(price),
+            Timestamp::now(),
+            "NYSE",
+        )
+    }
+
+    #[tokio::test]
+    async fn progress_reports_each_decision() {
+        let order_repo = Arc::new(InMemoryOrderRepository::new());
+
+        let mut partial = cycle_order("AAPL", "cycle-1");
+        partial.accept(BrokerId::new("broker-1")).unwrap();
+        partial.apply_fill(fill(4, 150.0)).unwrap();
+        order_repo.add(partial);
+
+        let mut rejected = cycle_order("MSFT", "cycle-1");
+        rejected
+            .reject(RejectReason::new("40310000", "insufficient buying power"))
+            .unwrap();
+        order_repo.add(rejected);
+
+        order_repo.add(cycle_order("NVDA", "cycle-0"));
+
+        let service = PlanProgressService::new(order_repo);
+        let progress = service
+            .progress(&CycleId::new("cycle-1"), &[])
+            .await
+            .unwrap()
+            .unwrap();
+
+        assert_eq!(progress.decisions.len(), 2);
+        let aapl = progress
+            .decisions
+            .iter()
+            .find(|d| d.symbol == "AAPL")
+            .unwrap();
+        assert_eq!(aapl.status, DecisionStatus::Working);
+        assert_eq!(aapl.filled_pct, Decimal::from(40));
+        assert_eq!(aapl.avg_fill_price, Some(Decimal::from(150)));
+
+        let msft = progress
+            .decisions
+            .iter()
+            .find(|d| d.symbol == "MSFT")
+            .unwrap();
+        assert_eq!(msft.status, DecisionStatus::Rejected);
+        assert!(
+            msft.error
+                .as_ref()
+                .unwrap()
+                .contains("insufficient buying power")
+        );
+
+        assert_eq!(progress.summary.working, 1);
+        assert_eq!(progress.summary.failed, 1);
+        assert!(!progress.is_complete());
+    }
+
+    #[tokio::test]
+    async fn scheduled_decisions_are_pending() {
+        let service = PlanProgressService::new(Arc::new(InMemoryOrderRepository::new()));
+        let plan = ScheduledPlan::new(
+            "plan-1",
+            "cycle-2",
+            SubmitOrdersRequestDto {
+                orders: vec![CreateOrderDto {
+                    client_order_id: "cycle-2-SPY".to_string(),
+                    symbol: "SPY".to_string(),
+                    side: OrderSide::Sell,
+                    order_type: OrderType::Market,
+                    quantity: Decimal::from(5),
+                    limit_price: None,
+                    stop_price: None,
+                    time_in_force: TimeInForce::Day,
+                    purpose: OrderPurpose::Exit,
+                    decision: None,
+                    oto_exit: None,
+                }],
+                validate_risk: true,
+                cycle_id: Some("cycle-2".to_string()),
+            },
+            ExecutionWindow::starting_at(Timestamp::now(), 60).unwrap(),
+        );
+
+        let progress = service
+            .progress(&CycleId::new("cycle-2"), std::slice::from_ref(&plan))
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(progress.summary.pending, 1);
+        assert_eq!(progress.decisions[0].order_id, "cycle-2-SPY");
+        assert!(progress.decisions[0].updated_at.is_none());
+
+        assert!(
+            service
+                .progress(&CycleId::new("cycle-3"), &[plan])
+                .await
+                .unwrap()
+                .is_none()
+        );
+    }
+}
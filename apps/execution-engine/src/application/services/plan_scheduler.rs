@@ -0,0 +1,559 @@
+//! Plan Scheduler Service
+//!
+//! Holds decision plans that were accepted for delayed execution (e.g., a
+//! 15:50 ET rebalance) and submits them through `SubmitOrdersUseCase` once
+//! their execution window opens. Pending plans are persisted through
+//! `ScheduledPlanRepositoryPort`, so they survive restarts; plans whose window
+//! closed while the engine was down are canceled rather than executed late.
+
+use std::sync::Arc;
+use std::time::Duration;
+
+use tokio_util::sync::CancellationToken;
+
+use crate::application::dto::SubmitOrdersResponseDto;
+use crate::application::ports::{
+    BrokerPort, Clock, EventPublisherPort, ExecutionWindow, RiskRepositoryPort, ScheduleError,
+    ScheduledPlan, ScheduledPlanRepositoryPort, SystemClock,
+};
+use crate::application::use_cases::SubmitOrdersUseCase;
+use crate::domain::order_execution::repository::OrderRepository;
+use crate::domain::shared::Timestamp;
+
+/// Configuration for the plan scheduler.
+#[derive(Debug, Clone)]
+pub struct PlanSchedulerConfig {
+    /// How often pending plans are checked (milliseconds).
+    pub poll_interval_ms: u64,
+    /// Window length used when a request only provides `execute_at` (seconds).
+    pub default_window_secs: u64,
+}
+
+impl Default for PlanSchedulerConfig {
+    fn default() -> Self {
+        Self {
+            poll_interval_ms: 1_000,
+            default_window_secs: 300,
+        }
+    }
+}
+
+/// Outcome of processing a due or expired plan.
+#[derive(Debug, Clone)]
+pub enum PlanOutcome {
+    /// Plan was submitted within its window.
+    Executed {
+        /// Plan ID.
+        plan_id: String,
+        /// Submission result.
+        response: SubmitOrdersResponseDto,
+    },
+    /// Plan window closed before it could execute; plan was canceled.
+    Missed {
+        /// Plan ID.
+        plan_id: String,
+        /// When the window closed.
+        expires_at: Timestamp,
+    },
+}
+
+/// Scheduler for delayed decision plan execution.
+pub struct PlanSchedulerService<B, R, O, E, S>
+where
+    B: BrokerPort,
+    R: RiskRepositoryPort,
+    O: OrderRepository,
+    E: EventPublisherPort,
+    S: ScheduledPlanRepositoryPort,
+{
+    /// Configuration.
+    config: PlanSchedulerConfig,
+    /// Use case used to submit plans when due.
+    submit_orders: Arc<SubmitOrdersUseCase<B, R, O, E>>,
+    /// Persistent store of pending plans.
+    plan_repo: Arc<S>,
+    /// Time source for window checks.
+    clock: Arc<dyn Clock>,
+    /// Cancellation token for graceful shutdown.
+    shutdown: CancellationToken,
+}
+
+impl<B, R, O, E, S> PlanSchedulerService<B, R, O, E, S>
+where
+    B: BrokerPort,
+    R: RiskRepositoryPort,
+    O: OrderRepository,
+    E: EventPublisherPort,
+    S: ScheduledPlanRepositoryPort,
+{
+    /// Create a new plan scheduler with default configuration.
+    #[must_use]
+    pub fn new(
+        submit_orders: Arc<SubmitOrdersUseCase<B, R, O, E>>,
+        plan_repo: Arc<S>,
+        shutdown: CancellationToken,
+    ) -> Self {
+        Self::with_config(
+            PlanSchedulerConfig::default(),
+            submit_orders,
+            plan_repo,
+            shutdown,
+        )
+    }
+
+    /// Create with custom configuration.
+    #[must_use]
+    pub fn with_config(
+        config: PlanSchedulerConfig,
+        submit_orders: Arc<SubmitOrdersUseCase<B, R, O, E>>,
+        plan_repo: Arc<S>,
+        shutdown: CancellationToken,
+    ) -> Self {
+        Self {
+            config,
+            submit_orders,
+            plan_repo,
+            clock: SystemClock::shared(),
+            shutdown,
+        }
+    }
+
+    /// Use `clock` instead of the system clock for window checks.
+    #[must_use]
+    pub fn with_clock(mut self, clock: Arc<dyn Clock>) -> Self {
+        self.clock = clock;
+        self
+    }
+
+    /// Build an execution window using the configured default length.
+    ///
+    /// # Errors
+    ///
+    /// Returns `ScheduleError::InvalidWindow` if the default length is invalid.
+    pub fn default_window(&self, execute_at: Timestamp) -> Result<ExecutionWindow, ScheduleError> {
+        ExecutionWindow::starting_at(execute_at, self.config.default_window_secs)
+    }
+
+    /// Accept a plan for later execution.
+    ///
+    /// # Errors
+    ///
+    /// Returns error if the window has already closed, the plan ID is already
+    /// scheduled, or the plan cannot be persisted.
+    pub async fn schedule(&self, plan: ScheduledPlan) -> Result<(), ScheduleError> {
+        if plan.window.is_missed(self.clock.now()) {
+            return Err(ScheduleError::WindowMissed {
+                plan_id: plan.plan_id,
+            });
+        }
+
+        if self.plan_repo.find_by_id(&plan.plan_id).await?.is_some() {
+            return Err(ScheduleError::DuplicatePlan {
+                plan_id: plan.plan_id,
+            });
+        }
+
+        self.plan_repo.save(&plan).await?;
+
+        tracing::info!(
+            plan_id = %plan.plan_id,
+            cycle_id = %plan.cycle_id,
+            execute_at = %plan.window.execute_at.to_rfc3339(),
+            expires_at = %plan.window.expires_at.to_rfc3339(),
+            orders = plan.request.orders.len(),
+            "Plan scheduled"
+        );
+
+        Ok(())
+    }
+
+    /// Cancel a pending plan.
+    ///
+    /// # Errors
+    ///
+    /// Returns `ScheduleError::NotFound` if no pending plan has this ID.
+    pub async fn cancel(&self, plan_id: &str) -> Result<ScheduledPlan, ScheduleError> {
+        let plan =
+            self.plan_repo
+                .find_by_id(plan_id)
+                .await?
+                .ok_or_else(|| ScheduleError::NotFound {
+                    plan_id: plan_id.to_string(),
+                })?;
+
+        self.plan_repo.delete(plan_id).await?;
+
+        tracing::info!(plan_id = %plan_id, "Scheduled plan canceled");
+
+        Ok(plan)
+    }
+
+    /// List pending plans ordered by execution time.
+    ///
+    /// # Errors
+    ///
+    /// Returns error if the plan store cannot be read.
+    pub async fn pending(&self) -> Result<Vec<ScheduledPlan>, ScheduleError> {
+        self.plan_repo.list_pending().await
+    }
+
+    /// Execute due plans and cancel missed ones as of `now`.
+    pub async fn run_due(&self, now: Timestamp) -> Vec<PlanOutcome> {
+        process_plans(&self.submit_orders, self.plan_repo.as_ref(), now).await
+    }
+}
+
+impl<B, R, O, E, S> PlanSchedulerService<B, R, O, E, S>
+where
+    B: BrokerPort + 'static,
+    R: RiskRepositoryPort + 'static,
+    O: OrderRepository + 'static,
+    E: EventPublisherPort + 'static,
+    S: ScheduledPlanRepositoryPort + 'static,
+{
+    /// Start the scheduling loop.
+    ///
+    /// The first pass runs immediately, so plans persisted before a restart
+    /// are executed or canceled as soon as the engine comes up.
+    pub fn start(&self) {
+        let submit_orders = Arc::clone(&self.submit_orders);
+        let plan_repo = Arc::clone(&self.plan_repo);
+        let clock = Arc::clone(&self.clock);
+        let shutdown = self.shutdown.clone();
+        let poll_interval = Duration::from_millis(self.config.poll_interval_ms);
+
+        tokio::spawn(async move {
+            let mut interval = tokio::time::interval(poll_interval);
+
+            loop {
+                tokio::select! {
+                    _ = interval.tick() => {
+                        process_plans(&submit_orders, plan_repo.as_ref(), clock.now()).await;
+                    }
+                    () = shutdown.cancelled() => {
+                        tracing::info!("Plan scheduler shutting down");
+                        break;
+                    }
+                }
+            }
+        });
+    }
+}
+
+/// Process all pending plans against `now`.
+///
+/// Plans are removed from the store before submission so that a crash
+/// mid-submission cannot cause the same plan to execute twice.
+async fn process_plans<B, R, O, E, S>(
+    submit_orders: &SubmitOrdersUseCase<B, R, O, E>,
+    plan_repo: &S,
+    now: Timestamp,
+) -> Vec<PlanOutcome>
+where
+    B: BrokerPort,
+    R: RiskRepositoryPort,
+    O: OrderRepository,
+    E: EventPublisherPort,
+    S: ScheduledPlanRepositoryPort,
+{
+    // Left pending for the next leader (or restart) to run or miss
+    if !submit_orders.is_accepting_plans() {
+        return vec![];
+    }
+
+    let pending = match plan_repo.list_pending().await {
+        Ok(plans) => plans,
+        Err(e) => {
+            tracing::warn!(error = %e, "Failed to load scheduled plans");
+            return vec![];
+        }
+    };
+
+    let mut outcomes = Vec::new();
+
+    for plan in pending {
+        if plan.window.is_missed(now) {
+            if let Err(e) = plan_repo.delete(&plan.plan_id).await {
+                tracing::warn!(plan_id = %plan.plan_id, error = %e, "Failed to remove missed plan");
+                continue;
+            }
+
+            tracing::warn!(
+                plan_id = %plan.plan_id,
+                expires_at = %plan.window.expires_at.to_rfc3339(),
+                "Execution window missed, plan canceled"
+            );
+
+            outcomes.push(PlanOutcome::Missed {
+                plan_id: plan.plan_id,
+                expires_at: plan.window.expires_at,
+            });
+        } else if plan.window.is_due(now) {
+            if let Err(e) = plan_repo.delete(&plan.plan_id).await {
+                tracing::warn!(plan_id = %plan.plan_id, error = %e, "Failed to claim due plan");
+                continue;
+            }
+
+            let response = submit_orders.execute(plan.request).await;
+
+            tracing::info!(
+                plan_id = %plan.plan_id,
+                success = response.success,
+                submitted = response.submitted.len(),
+                rejected = response.rejected.len(),
+                "Scheduled plan executed"
+            );
+
+            outcomes.push(PlanOutcome::Executed {
+                plan_id: plan.plan_id,
+                response,
+            });
+        }
+    }
+
+    outcomes
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::application::dto::{CreateOrderDto, SubmitOrdersRequestDto};
+    use crate::application::ports::{
+        BrokerError, CancelOrderRequest, InMemoryRiskRepository, InMemoryScheduledPlanRepository,
+        NoOpEventPublisher, OrderAck, PositionInfo, SimulatedClock, SubmitOrderRequest,
+    };
+    use crate::domain::order_execution::value_objects::{
+        OrderPurpose, OrderSide, OrderStatus, OrderType, TimeInForce,
+    };
+    use crate::domain::shared::{BrokerId, InstrumentId};
+    use crate::infrastructure::persistence::InMemoryOrderRepository;
+    use async_trait::async_trait;
+    use rust_decimal::Decimal;
+
+    struct MockBroker;
+
+    #[async_trait]
+    impl BrokerPort for MockBroker {
+        async fn submit_order(&self, request: SubmitOrderRequest) -> Result<OrderAck, BrokerError> {
+            Ok(OrderAck {
+                broker_order_id: BrokerId::new("broker-1"),
+                client_order_id: request.client_order_id,
+                status: OrderStatus::Accepted,
+                filled_qty: Decimal::ZERO,
+                avg_fill_price: None,
+                child_order_ids: Vec::new(),
+                leg_fills: Vec::new(),
+            })
+        }
+
+        async fn cancel_order(&self, _request: CancelOrderRequest) -> Result<(), BrokerError> {
+            Ok(())
+        }
+
+        async fn get_order(&self, broker_order_id: &BrokerId) -> Result<OrderAck, BrokerError> {
+            Err(BrokerError::OrderNotFound {
+                order_id: broker_order_id.to_string(),
+            })
+        }
+
+        async fn get_open_orders(&self) -> Result<Vec<OrderAck>, BrokerError> {
+            Ok(vec![])
+        }
+
+        async fn get_buying_power(&self) -> Result<Decimal, BrokerError> {
+            Ok(Decimal::new(100_000, 0))
+        }
+
+        async fn get_position(
+            &self,
+            _instrument_id: &InstrumentId,
+        ) -> Result<Option<Decimal>, BrokerError> {
+            Ok(None)
+        }
+
+        async fn get_all_positions(&self) -> Result<Vec<PositionInfo>, BrokerError> {
+            Ok(vec![])
+        }
+    }
+
+    type TestScheduler = PlanSchedulerService<
+        MockBroker,
+        InMemoryRiskRepository,
+        InMemoryOrderRepository,
+        NoOpEventPublisher,
+        InMemoryScheduledPlanRepository,
+    >;
+
+    fn make_scheduler() -> (TestScheduler, Arc<InMemoryScheduledPlanRepository>) {
+        let submit_orders = Arc::new(SubmitOrdersUseCase::new(
+            Arc::new(MockBroker),
+            Arc::new(InMemoryRiskRepository::new()),
+            Arc::new(InMemoryOrderRepository::new()),
+            Arc::new(NoOpEventPublisher),
+        ));
+        let plan_repo = Arc::new(InMemoryScheduledPlanRepository::new());
+        let scheduler = PlanSchedulerService::new(
+            submit_orders,
+            Arc::clone(&plan_repo),
+            CancellationToken::new(),
+        );
+        (scheduler, plan_repo)
+    }
+
+    fn ts(s: &str) -> Timestamp {
+        Timestamp::parse(s).unwrap()
+    }
+
+    fn make_plan(plan_id: &str, window: ExecutionWindow) -> ScheduledPlan {
+        ScheduledPlan::new(
+            plan_id,
+            "cycle-1",
+            SubmitOrdersRequestDto {
+                orders: vec![CreateOrderDto {
+                    client_order_id: format!("{plan_id}-AAPL"),
+                    symbol: "AAPL".to_string(),
+                    side: OrderSide::Buy,
+                    order_type: OrderType::Market,
+                    quantity: Decimal::new(10, 0),
+                    limit_price: None,
+                    stop_price: None,
+                    time_in_force: TimeInForce::Day,
+                    purpose: OrderPurpose::Entry,
+                    decision: None,
+                    oto_exit: None,
+                }],
+                validate_risk: false,
+                cycle_id: None,
+            },
+            window,
+        )
+    }
+
+    fn future_window() -> ExecutionWindow {
+        let execute_at =
+            Timestamp::new(Timestamp::now().as_datetime() + chrono::Duration::hours(1));
+        ExecutionWindow::starting_at(execute_at, 300).unwrap()
+    }
+
+    #[tokio::test]
+    async fn schedule_persists_pending_plan() {
+        let (scheduler, plan_repo) = make_scheduler();
+
+        scheduler
+            .schedule(make_plan("plan-1", future_window()))
+            .await
+            .unwrap();
+
+        assert!(plan_repo.find_by_id("plan-1").await.unwrap().is_some());
+        assert_eq!(scheduler.pending().await.unwrap().len(), 1);
+    }
+
+    #[tokio::test]
+    async fn schedule_rejects_missed_window() {
+        let (scheduler, _) = make_scheduler();
+        let window = ExecutionWindow::starting_at(ts("2020-01-02T20:50:00Z"), 300).unwrap();
+
+        let result = scheduler.schedule(make_plan("plan-1", window)).await;
+
+        assert!(matches!(result, Err(ScheduleError::WindowMissed { .. })));
+    }
+
+    #[tokio::test]
+    async fn schedule_rejects_duplicate_plan() {
+        let (scheduler, _) = make_scheduler();
+        scheduler
+            .schedule(make_plan("plan-1", future_window()))
+            .await
+            .unwrap();
+
+        let result = scheduler
+            .schedule(make_plan("plan-1", future_window()))
+            .await;
+
+        assert!(matches!(result, Err(ScheduleError::DuplicatePlan { .. })));
+    }
+
+    #[tokio::test]
+    async fn simulated_clock_drives_window_checks() {
+        let clock = Arc::new(SimulatedClock::new(ts("2020-01-02T20:45:00Z")));
+        let (scheduler, plan_repo) = make_scheduler();
+        let scheduler = scheduler.with_clock(Arc::clone(&clock) as Arc<dyn Clock>);
+        let window = ExecutionWindow::starting_at(ts("2020-01-02T20:50:00Z"), 300).unwrap();
+
+        scheduler
+            .schedule(make_plan("plan-1", window))
+            .await
+            .unwrap();
+
+        clock.advance(Duration::from_secs(11 * 60));
+        let result = scheduler.schedule(make_plan("plan-2", window)).await;
+        assert!(matches!(result, Err(ScheduleError::WindowMissed { .. })));
+
+        let outcomes = scheduler.run_due(clock.now()).await;
+        assert!(
+            matches!(&outcomes[..], [PlanOutcome::Missed { plan_id, .. }] if plan_id == "plan-1")
+        );
+        assert!(plan_repo.list_pending().await.unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn run_due_executes_plan_inside_window() {
+        let (scheduler, plan_repo) = make_scheduler();
+        let window = ExecutionWindow::starting_at(ts("2026-01-05T20:50:00Z"), 300).unwrap();
+        plan_repo.save(&make_plan("plan-1", window)).await.unwrap();
+
+        let outcomes = scheduler.run_due(ts("2026-01-05T20:51:00Z")).await;
+
+        assert_eq!(outcomes.len(), 1);
+        assert!(matches!(
+            &outcomes[0],
+            PlanOutcome::Executed { plan_id, response } if plan_id == "plan-1" && response.success
+        ));
+        assert!(plan_repo.list_pending().await.unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn run_due_leaves_future_plan_pending() {
+        let (scheduler, plan_repo) = make_scheduler();
+        let window = ExecutionWindow::starting_at(ts("2026-01-05T20:50:00Z"), 300).unwrap();
+        plan_repo.save(&make_plan("plan-1", window)).await.unwrap();
+
+        let outcomes = scheduler.run_due(ts("2026-01-05T20:00:00Z")).await;
+
+        assert!(outcomes.is_empty());
+        assert_eq!(plan_repo.list_pending().await.unwrap().len(), 1);
+    }
+
+    #[tokio::test]
+    async fn run_due_cancels_missed_plan() {
+        let (scheduler, plan_repo) = make_scheduler();
+        let window = ExecutionWindow::starting_at(ts("2026-01-05T20:50:00Z"), 300).unwrap();
+        plan_repo.save(&make_plan("plan-1", window)).await.unwrap();
+
+        let outcomes = scheduler.run_due(ts("2026-01-05T21:00:00Z")).await;
+
+        assert!(matches!(
+            &outcomes[0],
+            PlanOutcome::Missed { plan_id, .. } if plan_id == "plan-1"
+        ));
+        assert!(plan_repo.list_pending().await.unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn cancel_removes_pending_plan() {
+        let (scheduler, plan_repo) = make_scheduler();
+        scheduler
+            .schedule(make_plan("plan-1", future_window()))
+            .await
+            .unwrap();
+
+        let canceled = scheduler.cancel("plan-1").await.unwrap();
+
+        assert_eq!(canceled.plan_id, "plan-1");
+        assert!(plan_repo.list_pending().await.unwrap().is_empty());
+        assert!(matches!(
+            scheduler.cancel("plan-1").await,
+            Err(ScheduleError::NotFound { .. })
+        ));
+    }
+}
@@ -0,0 +1,487 @@
+//! Position Lifecycle Tracker
+//!
+//! Folds order fills into per-symbol position lifecycles so the decision
+//! layer can ask why and when each position was entered. Orders submitted
+//! with a [`DecisionContext`] carry it onto the events their fills produce;
+//! orders the engine places itself (e.g. stop exits) are adopted without
+//! one the first time they fill.
+
+use std::collections::HashSet;
+use std::sync::Arc;
+
+use rust_decimal::Decimal;
+use rust_decimal::prelude::Signed;
+
+use crate::application::ports::{
+    DecisionContext, LifecycleError, LifecycleEvent, LifecycleEventKind, PositionLifecycle,
+    PositionLifecycleRepositoryPort, TrackedOrder,
+};
+use crate::domain::order_execution::aggregate::Order;
+use crate::domain::order_execution::value_objects::FillReport;
+use crate::domain::shared::Timestamp;
+
+/// Records position lifecycles from order fills.
+pub struct PositionLifecycleTracker {
+    repo: Arc<dyn PositionLifecycleRepositoryPort>,
+}
+
+impl PositionLifecycleTracker {
+    /// Create a tracker backed by `repo`.
+    #[must_use]
+    pub const fn new(repo: Arc<dyn PositionLifecycleRepositoryPort>) -> Self {
+        Self { repo }
+    }
+
+    /// Start tracking a submitted order and apply any fills it already has.
+    ///
+    /// # Errors
+    ///
+    /// Returns error if the repository fails.
+    pub async fn track_order(
+        &self,
+        order: &Order,
+        decision: Option<DecisionContext>,
+    ) -> Result<(), LifecycleError> {
+        let mut tracked = tracked_order(order);
+        tracked.decision = decision;
+        tracked.fills_applied = self.recorded_fills(order).await?;
+        self.repo.save_order(&tracked).await?;
+        self.record_fills(order).await
+    }
+
+    /// Fold the order's fills that have not been applied yet into the
+    /// lifecycle of its symbol.
+    ///
+    /// # Errors
+    ///
+    /// Returns error if the repository fails.
+    pub async fn record_fills(&self, order: &Order) -> Result<(), LifecycleError> {
+        let order_id = order.id().as_str();
+        let fills = order.partial_fill().fills();
+
+        let (mut tracked, adopted) = match self.repo.find_order(order_id).await? {
+            Some(tracked) => (tracked, false),
+            None if fills.is_empty() => return Ok(()),
+            None => {
+                let mut tracked = tracked_order(order);
+                tracked.fills_applied = self.recorded_fills(order).await?;
+                (tracked, true)
+            }
+        };
+
+        let new_fills = fills.get(tracked.fills_applied..).unwrap_or_default();
+        if !new_fills.is_empty() {
+            self.apply(&tracked, new_fills).await?;
+            tracked.fills_applied = fills.len();
+        }
+
+        if order.status().is_terminal() {
+            self.repo.delete_order(order_id).await
+        } else if adopted || !new_fills.is_empty() {
+            self.repo.save_order(&tracked).await
+        } else {
+            Ok(())
+        }
+    }
+
+    /// Lifecycles of currently held positions.
+    ///
+    /// # Errors
+    ///
+    /// Returns error if the repository fails.
+    pub async fn open_positions(&self) -> Result<Vec<PositionLifecycle>, LifecycleError> {
+        let mut lifecycles = self.repo.list(None).await?;
+        lifecycles.retain(PositionLifecycle::is_open);
+        Ok(lifecycles)
+    }
+
+    /// Open lifecycle for `symbol`, if a position is held.
+    ///
+    /// # Errors
+    ///
+    /// Returns error if the repository fails.
+    pub async fn current(&self, symbol: &str) -> Result<Option<PositionLifecycle>, LifecycleError> {
+        self.repo.find_open(symbol).await
+    }
+
+    /// All lifecycles, open and closed, optionally for one symbol.
+    ///
+    /// # Errors
+    ///
+    /// Returns error if the repository fails.
+    pub async fn history(
+        &self,
+        symbol: Option<&str>,
+    ) -> Result<Vec<PositionLifecycle>, LifecycleError> {
+        self.repo.list(symbol).await
+    }
+
+    /// Tracking state of an order whose fills are still being followed.
+    ///
+    /// # Errors
+    ///
+    /// Returns error if the repository fails.
+    pub async fn find_tracked(
+        &self,
+        order_id: &str,
+    ) -> Result<Option<TrackedOrder>, LifecycleError> {
+        self.repo.find_order(order_id).await
+    }
+
+    /// Restore lifecycles and tracked orders exported from another instance.
+    ///
+    /// # Errors
+    ///
+    /// Returns error if the repository fails.
+    pub async fn restore(
+        &self,
+        lifecycles: &[PositionLifecycle],
+        orders: &[TrackedOrder],
+    ) -> Result<(), LifecycleError> {
+        for lifecycle in lifecycles {
+            self.repo.save(lifecycle).await?;
+        }
+        for order in orders {
+            self.repo.save_order(order).await?;
+        }
+        Ok(())
+    }
+
+    /// Number of the order's leading fills already on a lifecycle, so an
+    /// order no longer tracked (e.g. done before a repeat call) is not
+    /// applied twice.
+    async fn recorded_fills(&self, order: &Order) -> Result<usize, LifecycleError> {
+        let order_id = order.id().as_str();
+        let lifecycles = self.repo.list(Some(order.symbol().as_str())).await?;
+        let recorded: HashSet<&str> = lifecycles
+            .iter()
+            .flat_map(|l| &l.events)
+            .filter(|e| e.order_id == order_id)
+            .flat_map(|e| e.fill_ids.iter().map(String::as_str))
+            .collect();
+        Ok(order
+            .partial_fill()
+            .fills()
+            .iter()
+            .take_while(|f| recorded.contains(f.fill_id.as_str()))
+            .count())
+    }
+
+    /// Apply a batch of fills of one order to the symbol's position.
+    async fn apply(
+        &self,
+        tracked: &TrackedOrder,
+        fills: &[FillReport],
+    ) -> Result<(), LifecycleError> {
+        let quantity: Decimal = fills.iter().map(|f| f.quantity.amount()).sum();
+        if quantity.is_zero() {
+            return Ok(());
+        }
+        let notional: Decimal = fills
+            .iter()
+            .map(|f| f.quantity.amount() * f.price.amount())
+            .sum();
+        let price = notional / quantity;
+        let at = fills.last().map_or_else(Timestamp::now, |f| f.timestamp);
+        let fill_ids: Vec<String> = fills.iter().map(|f| f.fill_id.clone()).collect();
+        let signed = quantity * Decimal::from(tracked.side.sign());
+
+        let event = |kind, quantity, position_after| LifecycleEvent {
+            kind,
+            order_id: tracked.order_id.clone(),
+            fill_ids: fill_ids.clone(),
+            quantity,
+            price,
+            position_after,
+            purpose: tracked.purpose,
+            cycle_id: tracked.cycle_id.clone(),
+            decision: tracked.decision.clone(),
+            at,
+        };
+
+        let Some(mut lifecycle) = self.repo.find_open(&tracked.symbol).await? else {
+            let lifecycle = PositionLifecycle::open(
+                &tracked.symbol,
+                event(LifecycleEventKind::Opened, quantity, signed),
+            );
+            log_opened(&lifecycle);
+            return self.repo.save(&lifecycle).await;
+        };
+
+        let before = lifecycle.quantity;
+        let after = before + signed;
+
+        if before.signum() == signed.signum() {
+            lifecycle
+                .events
+                .push(event(LifecycleEventKind::ScaledIn, quantity, after));
+        } else if after.is_zero() || after.signum() == before.signum() {
+            let kind = if after.is_zero() {
+                LifecycleEventKind::Closed
+            } else {
+                LifecycleEventKind::ScaledOut
+            };
+            lifecycle.events.push(event(kind, quantity, after));
+        } else {
+            // Reversal: close the old position and open the remainder
+            lifecycle.events.push(event(
+                LifecycleEventKind::Closed,
+                before.abs(),
+                Decimal::ZERO,
+            ));
+            lifecycle.quantity = Decimal::ZERO;
+            close(&mut lifecycle, tracked, at);
+            self.repo.save(&lifecycle).await?;
+
+            let reversed = PositionLifecycle::open(
+                &tracked.symbol,
+                event(LifecycleEventKind::Opened, after.abs(), after),
+            );
+            log_opened(&reversed);
+            return self.repo.save(&reversed).await;
+        }
+
+        lifecycle.quantity = after;
+        if after.is_zero() {
+            close(&mut lifecycle, tracked, at);
+        }
+        self.repo.save(&lifecycle).await
+    }
+}
+
+fn tracked_order(order: &Order) -> TrackedOrder {
+    TrackedOrder {
+        order_id: order.id().to_string(),
+        symbol: order.symbol().to_string(),
+        side: order.side(),
+        purpose: order.partial_fill().order_purpose(),
+        cycle_id: order.cycle_id().map(ToString::to_string),
+        decision: None,
+        fills_applied: 0,
+    }
+}
+
+/// Mark the lifecycle closed; the exit reason is the closing order's purpose
+/// plus the decision rationale, if any.
+fn close(lifecycle: &mut PositionLifecycle, tracked: &TrackedOrder, at: Timestamp) {
+    let rationale = tracked
+        .decision
+        .as_ref()
+        .and_then(|d| d.rationale.as_deref())
+        .filter(|r| !r.is_empty());
+    lifecycle.closed_at = Some(at);
+    lifecycle.exit_reason = Some(rationale.map_or_else(
+        || tracked.purpose.to_string(),
+        |rationale| format!("{}: {rationale}", tracked.purpose),
+    ));
+
+    tracing::info!(
+        target: "audit",
+        lifecycle_id = %lifecycle.lifecycle_id,
+        symbol = %lifecycle.symbol,
+        order_id = %tracked.order_id,
+        exit_reason = lifecycle.exit_reason.as_deref().unwrap_or_default(),
+        "Position closed"
+    );
+}
+
+fn log_opened(lifecycle: &PositionLifecycle) {
+    let Some(entry) = lifecycle.entry() else {
+        return;
+    };
+    tracing::info!(
+        target: "audit",
+        lifecycle_id = %lifecycle.lifecycle_id,
+        symbol = %lifecycle.symbol,
+        order_id = %entry.order_id,
+        cycle_id = entry.cycle_id.as_deref().unwrap_or_default(),
+        strategy_tag = entry
+            .decision
+            .as_ref()
+            .and_then(|d| d.strategy_tag.as_deref())
+            .unwrap_or_default(),
+        quantity = %lifecycle.quantity,
+        "Position opened"
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::application::ports::{InMemoryPositionLifecycleRepository, ThesisState};
+    use crate::domain::order_execution::aggregate::CreateOrderCommand;
+    use crate::domain::order_execution::value_objects::{
+        OrderPurpose, OrderSide, OrderType, TimeInForce,
+    };
+    use crate::domain::shared::{BrokerId, CycleId, Money, Quantity, Symbol};
+    use rust_decimal_macros::dec;
+
+    fn order(side: OrderSide, qty: Decimal, purpose: OrderPurpose) -> Order {
+        let mut order = Order::new(CreateOrderCommand {
+            symbol: Symbol::new("AAPL"),
+            side,
+            order_type: OrderType::Market,
+            quantity: Quantity::new(qty),
+            limit_price: None,
+            stop_price: None,
+            time_in_force: TimeInForce::Day,
+            purpose,
+            legs: vec![],
+        })
+        .unwrap();
+        order.tag_cycle(CycleId::new("cycle-7"));
+        order.accept(BrokerId::new("broker-1")).unwrap();
+        order
+    }
+
+    fn fill(order: &mut Order, qty: Decimal, price: Decimal) {
+        let n = order.partial_fill().fills().len() + 1;
+        order
+            .apply_fill(FillReport::new(
+                format!("{}-{n}", order.id()),
+                Quantity::new(qty),
+                Money::new(price),
+                Timestamp::now(),
+                "TEST",
+            ))
+            .unwrap();
+    }
+
+    fn decision(thesis_state: ThesisState, rationale: &str) -> DecisionContext {
+        DecisionContext {
+            decision_id: Some("decision-1".to_string()),
+            thesis_state: Some(thesis_state),
+            time_horizon: None,
+            strategy_family: None,
+            strategy_tag: None,
+            max_holding_period: None,
+            scale_out: Vec::new(),
+            rationale: Some(rationale.to_string()),
+        }
+    }
+
+    fn tracker() -> PositionLifecycleTracker {
+        PositionLifecycleTracker::new(Arc::new(InMemoryPositionLifecycleRepository::new()))
+    }
+
+    #[tokio::test]
+    async fn entry_scale_and_exit_form_one_lifecycle() {
+        let tracker = tracker();
+
+        let mut entry = order(OrderSide::Buy, dec!(100), OrderPurpose::Entry);
+        tracker
+            .track_order(&entry, Some(decision(ThesisState::Entered, "breakout")))
+            .await
+            .unwrap();
+        fill(&mut entry, dec!(60), dec!(150));
+        tracker.record_fills(&entry).await.unwrap();
+        fill(&mut entry, dec!(40), dec!(151));
+        tracker.record_fills(&entry).await.unwrap();
+
+        let mut trim = order(OrderSide::Sell, dec!(50), OrderPurpose::ScaleOut);
+        fill(&mut trim, dec!(50), dec!(155));
+        tracker.track_order(&trim, None).await.unwrap();
+
+        let open = tracker.current("AAPL").await.unwrap().unwrap();
+        assert_eq!(open.quantity, dec!(50));
+        let kinds: Vec<_> = open.events.iter().map(|e| e.kind).collect();
+        assert_eq!(
+            kinds,
+            vec![
+                LifecycleEventKind::Opened,
+                LifecycleEventKind::ScaledIn,
+                LifecycleEventKind::ScaledOut
+            ]
+        );
+        let entry_event = open.entry().unwrap();
+        assert_eq!(entry_event.cycle_id.as_deref(), Some("cycle-7"));
+        assert_eq!(
+            entry_event.decision.as_ref().unwrap().rationale.as_deref(),
+            Some("breakout")
+        );
+
+        let mut exit = order(OrderSide::Sell, dec!(50), OrderPurpose::StopLoss);
+        tracker
+            .track_order(
+                &exit,
+                Some(decision(ThesisState::Exiting, "thesis invalidated")),
+            )
+            .await
+            .unwrap();
+        fill(&mut exit, dec!(50), dec!(145));
+        tracker.record_fills(&exit).await.unwrap();
+
+        assert!(tracker.open_positions().await.unwrap().is_empty());
+        let closed = &tracker.history(Some("AAPL")).await.unwrap()[0];
+        assert!(!closed.is_open());
+        assert_eq!(
+            closed.exit_reason.as_deref(),
+            Some("STOP_LOSS: thesis invalidated")
+        );
+        assert_eq!(closed.events.last().unwrap().fill_ids.len(), 1);
+    }
+
+    #[tokio::test]
+    async fn fills_are_applied_once() {
+        let tracker = tracker();
+        let mut entry = order(OrderSide::Buy, dec!(100), OrderPurpose::Entry);
+        fill(&mut entry, dec!(100), dec!(150));
+
+        tracker.record_fills(&entry).await.unwrap();
+        tracker.record_fills(&entry).await.unwrap();
+
+        let open = tracker.current("AAPL").await.unwrap().unwrap();
+        assert_eq!(open.quantity, dec!(100));
+        assert_eq!(open.events.len(), 1);
+        assert!(open.entry().unwrap().decision.is_none());
+    }
+
+    #[tokio::test]
+    async fn retracking_skips_recorded_fills() {
+        let tracker = tracker();
+        let mut entry = order(OrderSide::Buy, dec!(100), OrderPurpose::Entry);
+        fill(&mut entry, dec!(60), dec!(150));
+        tracker
+            .track_order(&entry, Some(decision(ThesisState::Entered, "breakout")))
+            .await
+            .unwrap();
+        fill(&mut entry, dec!(40), dec!(151));
+        tracker.record_fills(&entry).await.unwrap();
+        assert!(
+            tracker
+                .find_tracked(entry.id().as_str())
+                .await
+                .unwrap()
+                .is_none()
+        );
+
+        tracker
+            .track_order(&entry, Some(decision(ThesisState::Entered, "breakout")))
+            .await
+            .unwrap();
+        tracker.record_fills(&entry).await.unwrap();
+
+        let open = tracker.current("AAPL").await.unwrap().unwrap();
+        assert_eq!(open.quantity, dec!(100));
+        assert_eq!(open.events.len(), 2);
+    }
+
+    #[tokio::test]
+    async fn reversal_closes_and_reopens() {
+        let tracker = tracker();
+        let mut long = order(OrderSide::Buy, dec!(100), OrderPurpose::Entry);
+        fill(&mut long, dec!(100), dec!(150));
+        tracker.record_fills(&long).await.unwrap();
+
+        let mut flip = order(OrderSide::Sell, dec!(150), OrderPurpose::Entry);
+        fill(&mut flip, dec!(150), dec!(149));
+        tracker.record_fills(&flip).await.unwrap();
+
+        let history = tracker.history(Some("AAPL")).await.unwrap();
+        assert_eq!(history.len(), 2);
+        assert_eq!(history.iter().filter(|l| l.is_open()).count(), 1);
+        let short = tracker.current("AAPL").await.unwrap().unwrap();
+        assert_eq!(short.quantity, dec!(-50));
+        assert_eq!(short.entry().unwrap().quantity, dec!(50));
+    }
+}
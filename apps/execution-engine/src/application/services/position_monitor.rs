@@ -6,8 +6,8 @@
 
 use std::collections::HashMap;
 use std::sync::Arc;
-use std::sync::atomic::{AtomicU8, AtomicU32, Ordering};
-use std::time::{Duration, Instant};
+use std::sync::atomic::{AtomicBool, AtomicU8, AtomicU32, AtomicU64, Ordering};
+use std::time::Duration;
 
 use parking_lot::{Mutex, RwLock};
 use rust_decimal::Decimal;
@@ -15,9 +15,11 @@ use thiserror::Error;
 use tokio::sync::broadcast;
 use tokio_util::sync::CancellationToken;
 
-use crate::application::ports::{BrokerPort, PriceFeedPort, QuoteProviderPort, SubmitOrderRequest};
+use crate::application::ports::{
+    BrokerPort, Clock, PriceFeedPort, QuoteProviderPort, SubmitOrderRequest, SystemClock,
+};
 use crate::domain::order_execution::value_objects::OrderSide;
-use crate::domain::shared::{InstrumentId, OrderId, Symbol};
+use crate::domain::shared::{InstrumentId, OrderId, Symbol, Timestamp};
 use crate::domain::stop_enforcement::{
     MonitoredPosition, PositionDirection, PriceMonitor, StopsConfig, TriggerResult,
 };
@@ -79,6 +81,26 @@ pub struct ExitResult {
     pub error: Option<String>,
 }
 
+impl ExitResult {
+    fn failed(
+        position_id: &OrderId,
+        symbol: &str,
+        trigger_type: &str,
+        trigger_price: Decimal,
+        error: &str,
+    ) -> Self {
+        Self {
+            position_id: position_id.to_string(),
+            symbol: symbol.to_string(),
+            exit_order_id: None,
+            trigger_type: trigger_type.to_string(),
+            trigger_price,
+            success: false,
+            error: Some(error.to_string()),
+        }
+    }
+}
+
 /// Position monitor errors.
 #[derive(Debug, Error)]
 pub enum PositionMonitorError {
@@ -132,6 +154,24 @@ pub enum CircuitBreakerState {
     HalfOpen = 2,
 }
 
+impl CircuitBreakerState {
+    /// `CLOSED`, `OPEN` or `HALF_OPEN`.
+    #[must_use]
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::Closed => "CLOSED",
+            Self::Open => "OPEN",
+            Self::HalfOpen => "HALF_OPEN",
+        }
+    }
+}
+
+impl std::fmt::Display for CircuitBreakerState {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
 impl From<u8> for CircuitBreakerState {
     fn from(value: u8) -> Self {
         match value {
@@ -143,13 +183,24 @@ impl From<u8> for CircuitBreakerState {
 }
 
 /// Circuit breaker for exit order failures.
+///
+/// Opens after `failure_threshold` consecutive failures and half-opens once
+/// `open_duration` has passed. Operators can also trip it by hand, which
+/// holds it open until [`CircuitBreaker::reset`]. Every state change is
+/// counted and emitted as an audit event.
 #[derive(Debug)]
 pub struct CircuitBreaker {
     failure_count: AtomicU32,
-    last_failure: Mutex<Option<Instant>>,
+    last_failure: Mutex<Option<Timestamp>>,
     state: AtomicU8,
+    manually_open: AtomicBool,
+    total_successes: AtomicU64,
+    total_failures: AtomicU64,
+    state_changes: AtomicU64,
+    last_state_change: Mutex<Option<Timestamp>>,
     failure_threshold: u32,
     open_duration: Duration,
+    clock: Arc<dyn Clock>,
 }
 
 impl CircuitBreaker {
@@ -160,33 +211,53 @@ impl CircuitBreaker {
 
     /// Create a new circuit breaker.
     #[must_use]
-    #[allow(clippy::missing_const_for_fn)]
     pub fn new() -> Self {
         Self {
             failure_count: AtomicU32::new(0),
             last_failure: Mutex::new(None),
             state: AtomicU8::new(CircuitBreakerState::Closed as u8),
+            manually_open: AtomicBool::new(false),
+            total_successes: AtomicU64::new(0),
+            total_failures: AtomicU64::new(0),
+            state_changes: AtomicU64::new(0),
+            last_state_change: Mutex::new(None),
             failure_threshold: Self::DEFAULT_FAILURE_THRESHOLD,
             open_duration: Self::DEFAULT_OPEN_DURATION,
+            clock: SystemClock::shared(),
         }
     }
 
     /// Create with custom parameters.
     #[must_use]
-    #[allow(clippy::missing_const_for_fn)]
     pub fn with_params(failure_threshold: u32, open_duration: Duration) -> Self {
         Self {
             failure_count: AtomicU32::new(0),
             last_failure: Mutex::new(None),
             state: AtomicU8::new(CircuitBreakerState::Closed as u8),
+            manually_open: AtomicBool::new(false),
+            total_successes: AtomicU64::new(0),
+            total_failures: AtomicU64::new(0),
+            state_changes: AtomicU64::new(0),
+            last_state_change: Mutex::new(None),
             failure_threshold,
             open_duration,
+            clock: SystemClock::shared(),
         }
     }
 
+    /// Use `clock` instead of the system clock for the open duration.
+    #[must_use]
+    pub fn with_clock(mut self, clock: Arc<dyn Clock>) -> Self {
+        self.clock = clock;
+        self
+    }
+
     /// Check if execution is allowed.
     #[must_use]
     pub fn can_execute(&self) -> bool {
+        if self.manually_open.load(Ordering::SeqCst) {
+            return false;
+        }
         let state = CircuitBreakerState::from(self.state.load(Ordering::SeqCst));
 
         match state {
@@ -194,11 +265,12 @@ impl CircuitBreaker {
             CircuitBreakerState::Open => {
                 let last = self.last_failure.lock();
                 if let Some(last_failure) = *last
-                    && last_failure.elapsed() >= self.open_duration
+                    && self.clock.now().duration_since(last_failure)
+                        >= chrono::Duration::from_std(self.open_duration)
+                            .unwrap_or(chrono::Duration::MAX)
                 {
                     drop(last);
-                    self.state
-                        .store(CircuitBreakerState::HalfOpen as u8, Ordering::SeqCst);
+                    self.transition(CircuitBreakerState::HalfOpen, "open duration elapsed");
                     return true;
                 }
                 false
@@ -208,19 +280,23 @@ impl CircuitBreaker {
 
     /// Record a successful execution.
     pub fn record_success(&self) {
+        self.total_successes.fetch_add(1, Ordering::SeqCst);
         self.failure_count.store(0, Ordering::SeqCst);
-        self.state
-            .store(CircuitBreakerState::Closed as u8, Ordering::SeqCst);
+        // A call already in flight must not close a manually tripped breaker
+        if !self.manually_open.load(Ordering::SeqCst) {
+            self.transition(CircuitBreakerState::Closed, "call succeeded");
+        }
     }
 
     /// Record a failed execution.
     pub fn record_failure(&self) {
+        self.total_failures.fetch_add(1, Ordering::SeqCst);
         let count = self.failure_count.fetch_add(1, Ordering::SeqCst) + 1;
-        *self.last_failure.lock() = Some(Instant::now());
+        *self.last_failure.lock() = Some(self.clock.now());
 
-        if count >= self.failure_threshold {
-            self.state
-                .store(CircuitBreakerState::Open as u8, Ordering::SeqCst);
+        if count >= self.failure_threshold
+            && self.transition(CircuitBreakerState::Open, "consecutive failures")
+        {
             tracing::warn!(
                 failure_count = count,
                 "Circuit breaker opened after {} consecutive failures",
@@ -229,6 +305,42 @@ impl CircuitBreaker {
         }
     }
 
+    /// Open the breaker until [`CircuitBreaker::reset`], regardless of the
+    /// open duration.
+    pub fn trip(&self, reason: &str) {
+        self.manually_open.store(true, Ordering::SeqCst);
+        self.transition(CircuitBreakerState::Open, reason);
+    }
+
+    /// Close the breaker and clear the consecutive failure count, including
+    /// after a manual trip.
+    pub fn reset(&self) {
+        self.manually_open.store(false, Ordering::SeqCst);
+        self.failure_count.store(0, Ordering::SeqCst);
+        self.transition(CircuitBreakerState::Closed, "manual reset");
+    }
+
+    /// Move to `to`, counting and logging the change. Returns whether the
+    /// state changed.
+    fn transition(&self, to: CircuitBreakerState, reason: &str) -> bool {
+        let from = CircuitBreakerState::from(self.state.swap(to as u8, Ordering::SeqCst));
+        if from == to {
+            return false;
+        }
+
+        let changes = self.state_changes.fetch_add(1, Ordering::SeqCst) + 1;
+        *self.last_state_change.lock() = Some(self.clock.now());
+        tracing::info!(
+            target: "audit",
+            from = %from,
+            to = %to,
+            reason,
+            state_changes = changes,
+            "Circuit breaker state changed"
+        );
+        true
+    }
+
     /// Get current state.
     #[must_use]
     pub fn state(&self) -> CircuitBreakerState {
@@ -240,6 +352,47 @@ impl CircuitBreaker {
     pub fn failure_count(&self) -> u32 {
         self.failure_count.load(Ordering::SeqCst)
     }
+
+    /// Whether the breaker was tripped by hand and is held open.
+    #[must_use]
+    pub fn is_manually_open(&self) -> bool {
+        self.manually_open.load(Ordering::SeqCst)
+    }
+
+    /// Successful calls recorded since startup.
+    #[must_use]
+    pub fn total_successes(&self) -> u64 {
+        self.total_successes.load(Ordering::SeqCst)
+    }
+
+    /// Failed calls recorded since startup.
+    #[must_use]
+    pub fn total_failures(&self) -> u64 {
+        self.total_failures.load(Ordering::SeqCst)
+    }
+
+    /// Share of recorded calls that failed, from 0 to 1 (0 with no calls).
+    #[must_use]
+    pub fn failure_rate(&self) -> Decimal {
+        let failures = self.total_failures();
+        let total = failures + self.total_successes();
+        if total == 0 {
+            return Decimal::ZERO;
+        }
+        Decimal::from(failures) / Decimal::from(total)
+    }
+
+    /// State changes since startup.
+    #[must_use]
+    pub fn state_changes(&self) -> u64 {
+        self.state_changes.load(Ordering::SeqCst)
+    }
+
+    /// When the state last changed.
+    #[must_use]
+    pub fn last_state_change(&self) -> Option<Timestamp> {
+        *self.last_state_change.lock()
+    }
 }
 
 impl Default for CircuitBreaker {
@@ -269,6 +422,8 @@ where
     symbol_positions: Arc<RwLock<HashMap<String, Vec<OrderId>>>>,
     /// Circuit breaker for exit order failures.
     circuit_breaker: Arc<CircuitBreaker>,
+    /// Time source for quote staleness and circuit breaker cooldown.
+    clock: Arc<dyn Clock>,
     /// Cancellation token for graceful shutdown.
     shutdown: CancellationToken,
     /// Exit result sender for notifications.
@@ -299,6 +454,7 @@ where
             monitor: Arc::new(RwLock::new(PriceMonitor::new())),
             symbol_positions: Arc::new(RwLock::new(HashMap::new())),
             circuit_breaker: Arc::new(CircuitBreaker::new()),
+            clock: SystemClock::shared(),
             shutdown,
             exit_tx,
         }
@@ -325,11 +481,21 @@ where
             ))),
             symbol_positions: Arc::new(RwLock::new(HashMap::new())),
             circuit_breaker: Arc::new(CircuitBreaker::new()),
+            clock: SystemClock::shared(),
             shutdown,
             exit_tx,
         }
     }
 
+    /// Use `clock` instead of the system clock for quote staleness and the
+    /// circuit breaker cooldown.
+    #[must_use]
+    pub fn with_clock(mut self, clock: Arc<dyn Clock>) -> Self {
+        self.circuit_breaker = Arc::new(CircuitBreaker::new().with_clock(Arc::clone(&clock)));
+        self.clock = clock;
+        self
+    }
+
     /// Start the monitoring loop.
     ///
     /// This spawns background tasks for:
@@ -365,6 +531,7 @@ where
         let circuit_breaker = Arc::clone(&self.circuit_breaker);
         let exit_tx = self.exit_tx.clone();
         let shutdown = self.shutdown.clone();
+        let clock = Arc::clone(&self.clock);
         let max_quote_age = Duration::from_secs(self.config.max_quote_age_secs);
 
         tokio::spawn(async move {
@@ -374,7 +541,7 @@ where
                         match result {
                             Ok(quote) => {
                                 // Skip stale quotes
-                                if quote.is_stale(max_quote_age) {
+                                if quote.is_stale_at(clock.now(), max_quote_age) {
                                     tracing::debug!(
                                         symbol = %quote.symbol,
                                         "Skipping stale quote"
@@ -384,11 +551,13 @@ where
 
                                 // Process the quote
                                 let triggers = {
-                                    let monitor_guard = monitor.read();
+                                    let mut monitor_guard = monitor.write();
                                     let instrument_id = InstrumentId::new(&quote.symbol);
                                     // Use mid price for trigger checks
                                     let price = quote.mid_price();
-                                    monitor_guard.check_price(&instrument_id, price)
+                                    let now = clock.now();
+                                    monitor_guard.tighten_expired_stops(&instrument_id, price, now);
+                                    monitor_guard.check_price_at(&instrument_id, price, now)
                                 };
 
                                 // Execute triggers
@@ -447,6 +616,7 @@ where
         let quote_provider = Arc::clone(&self.quote_provider);
         let exit_tx = self.exit_tx.clone();
         let shutdown = self.shutdown.clone();
+        let clock = Arc::clone(&self.clock);
         let polling_interval = Duration::from_millis(self.config.polling_interval_ms);
 
         tokio::spawn(async move {
@@ -477,8 +647,10 @@ where
                                     let instrument_id = InstrumentId::new(quote.symbol.as_str());
 
                                     let triggers = {
-                                        let monitor_guard = monitor.read();
-                                        monitor_guard.check_price(&instrument_id, quote.bid)
+                                        let mut monitor_guard = monitor.write();
+                                        let now = clock.now();
+                                        monitor_guard.tighten_expired_stops(&instrument_id, quote.bid, now);
+                                        monitor_guard.check_price_at(&instrument_id, quote.bid, now)
                                     };
 
                                     for (position_id, trigger) in triggers {
@@ -660,6 +832,18 @@ where
         self.circuit_breaker.state()
     }
 
+    /// Shared handle to the exit order circuit breaker.
+    #[must_use]
+    pub fn circuit_breaker(&self) -> Arc<CircuitBreaker> {
+        Arc::clone(&self.circuit_breaker)
+    }
+
+    /// Shared handle to the monitored positions and their levels.
+    #[must_use]
+    pub fn price_monitor(&self) -> Arc<RwLock<PriceMonitor>> {
+        Arc::clone(&self.monitor)
+    }
+
     /// Sync positions from broker on startup.
     ///
     /// This fetches open positions from the broker and registers them
@@ -696,20 +880,8 @@ async fn execute_exit<B: BrokerPort>(
     symbol: &str,
     trigger: &TriggerResult,
 ) -> ExitResult {
-    let (trigger_type, trigger_price) = match trigger {
-        TriggerResult::StopLoss { price, .. } => ("stop_loss", *price),
-        TriggerResult::TakeProfit { price, .. } => ("take_profit", *price),
-        TriggerResult::None => {
-            return ExitResult {
-                position_id: position_id.to_string(),
-                symbol: symbol.to_string(),
-                exit_order_id: None,
-                trigger_type: "none".to_string(),
-                trigger_price: Decimal::ZERO,
-                success: false,
-                error: Some("No trigger".to_string()),
-            };
-        }
+    let Some((trigger_type, trigger_price, scale_out_quantity)) = exit_trigger(trigger) else {
+        return ExitResult::failed(position_id, symbol, "none", Decimal::ZERO, "No trigger");
     };
 
     // Get position direction and quantity for exit order
@@ -720,25 +892,27 @@ async fn execute_exit<B: BrokerPort>(
                 PositionDirection::Long => OrderSide::Sell,
                 PositionDirection::Short => OrderSide::Buy,
             };
-            (side, p.quantity())
+            (side, scale_out_quantity.unwrap_or_else(|| p.quantity()))
         })
     }
     .unzip();
 
     let (Some(exit_side), Some(quantity)) = (exit_side, quantity) else {
-        return ExitResult {
-            position_id: position_id.to_string(),
-            symbol: symbol.to_string(),
-            exit_order_id: None,
-            trigger_type: trigger_type.to_string(),
+        return ExitResult::failed(
+            position_id,
+            symbol,
+            trigger_type,
             trigger_price,
-            success: false,
-            error: Some("Position not found".to_string()),
-        };
+            "Position not found",
+        );
     };
 
     // Build exit order
-    let exit_order_id = format!("exit-{position_id}-{trigger_type}");
+    let exit_order_id = if scale_out_quantity.is_some() {
+        format!("exit-{position_id}-{trigger_type}-{trigger_price}")
+    } else {
+        format!("exit-{position_id}-{trigger_type}")
+    };
     let request = SubmitOrderRequest::market(
         OrderId::new(&exit_order_id),
         Symbol::new(symbol),
@@ -758,20 +932,14 @@ async fn execute_exit<B: BrokerPort>(
     match broker.submit_order(request).await {
         Ok(_ack) => {
             circuit_breaker.record_success();
-
-            // Remove position from monitoring
-            {
-                let mut monitor_guard = monitor.write();
-                monitor_guard.remove_position(position_id);
-            }
-
-            // Update symbol tracking
-            {
-                let mut positions = symbol_positions.write();
-                if let Some(pos_list) = positions.get_mut(symbol) {
-                    pos_list.retain(|id| id != position_id);
-                }
-            }
+            release_exited(
+                monitor,
+                symbol_positions,
+                position_id,
+                symbol,
+                trigger_price,
+                scale_out_quantity.is_some(),
+            );
 
             tracing::info!(
                 position_id = %position_id,
@@ -799,15 +967,59 @@ async fn execute_exit<B: BrokerPort>(
                 "Exit order failed"
             );
 
-            ExitResult {
-                position_id: position_id.to_string(),
-                symbol: symbol.to_string(),
-                exit_order_id: None,
-                trigger_type: trigger_type.to_string(),
+            ExitResult::failed(
+                position_id,
+                symbol,
+                trigger_type,
                 trigger_price,
-                success: false,
-                error: Some(e.to_string()),
-            }
+                &e.to_string(),
+            )
+        }
+    }
+}
+
+/// Trigger type, price and scale-out quantity of a fired trigger.
+///
+/// Scale-outs exit part of the position; every other trigger exits all of
+/// it.
+const fn exit_trigger(trigger: &TriggerResult) -> Option<(&'static str, Decimal, Option<Decimal>)> {
+    match trigger {
+        TriggerResult::StopLoss { price, .. } => Some(("stop_loss", *price, None)),
+        TriggerResult::TakeProfit { price, .. } => Some(("take_profit", *price, None)),
+        TriggerResult::ScaleOut {
+            price, quantity, ..
+        } => Some(("scale_out", *price, Some(*quantity))),
+        TriggerResult::TimeExit { price, .. } => Some(("time_exit", *price, None)),
+        TriggerResult::None => None,
+    }
+}
+
+/// Stop monitoring a position once an exit leaves nothing open.
+fn release_exited(
+    monitor: &Arc<RwLock<PriceMonitor>>,
+    symbol_positions: &Arc<RwLock<HashMap<String, Vec<OrderId>>>>,
+    position_id: &OrderId,
+    symbol: &str,
+    trigger_price: Decimal,
+    scale_out: bool,
+) {
+    let closed = {
+        let mut monitor_guard = monitor.write();
+        let closed = !scale_out
+            || monitor_guard
+                .record_scale_out(position_id, trigger_price)
+                .is_none_or(|remaining| remaining <= Decimal::ZERO);
+        if closed {
+            monitor_guard.remove_position(position_id);
+        }
+        closed
+    };
+
+    // Update symbol tracking
+    if closed {
+        let mut positions = symbol_positions.write();
+        if let Some(pos_list) = positions.get_mut(symbol) {
+            pos_list.retain(|id| id != position_id);
         }
     }
 }
@@ -815,6 +1027,7 @@ async fn execute_exit<B: BrokerPort>(
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::application::ports::SimulatedClock;
 
     #[test]
     fn circuit_breaker_new() {
@@ -840,6 +1053,23 @@ mod tests {
         assert!(!cb.can_execute());
     }
 
+    #[test]
+    fn circuit_breaker_half_opens_after_open_duration() {
+        let clock = Arc::new(SimulatedClock::new(Timestamp::now()));
+        let cb = CircuitBreaker::with_params(1, Duration::from_secs(60))
+            .with_clock(Arc::clone(&clock) as Arc<dyn Clock>);
+
+        cb.record_failure();
+        assert!(!cb.can_execute());
+
+        clock.advance(Duration::from_secs(59));
+        assert!(!cb.can_execute());
+
+        clock.advance(Duration::from_secs(1));
+        assert!(cb.can_execute());
+        assert_eq!(cb.state(), CircuitBreakerState::HalfOpen);
+    }
+
     #[test]
     fn circuit_breaker_resets_on_success() {
         let cb = CircuitBreaker::with_params(3, Duration::from_secs(60));
@@ -853,6 +1083,49 @@ mod tests {
         assert_eq!(cb.state(), CircuitBreakerState::Closed);
     }
 
+    #[test]
+    fn circuit_breaker_manual_trip_holds_until_reset() {
+        let clock = Arc::new(SimulatedClock::new(Timestamp::now()));
+        let cb = CircuitBreaker::with_params(3, Duration::from_secs(60))
+            .with_clock(Arc::clone(&clock) as Arc<dyn Clock>);
+
+        cb.trip("broker maintenance");
+        assert_eq!(cb.state(), CircuitBreakerState::Open);
+        assert!(cb.is_manually_open());
+
+        // Neither the open duration nor an in-flight success closes it
+        clock.advance(Duration::from_secs(120));
+        cb.record_success();
+        assert!(!cb.can_execute());
+        assert_eq!(cb.state(), CircuitBreakerState::Open);
+
+        cb.reset();
+        assert!(cb.can_execute());
+        assert_eq!(cb.state(), CircuitBreakerState::Closed);
+        assert!(!cb.is_manually_open());
+    }
+
+    #[test]
+    fn circuit_breaker_counts_calls_and_state_changes() {
+        let cb = CircuitBreaker::with_params(2, Duration::from_secs(60));
+        assert_eq!(cb.failure_rate(), Decimal::ZERO);
+        assert!(cb.last_state_change().is_none());
+
+        cb.record_success();
+        cb.record_failure();
+        cb.record_failure();
+        cb.record_failure();
+        assert_eq!(cb.total_successes(), 1);
+        assert_eq!(cb.total_failures(), 3);
+        assert_eq!(cb.failure_rate(), Decimal::new(75, 2));
+        // Closed -> Open once, however many failures follow
+        assert_eq!(cb.state_changes(), 1);
+
+        cb.reset();
+        assert_eq!(cb.state_changes(), 2);
+        assert!(cb.last_state_change().is_some());
+    }
+
     #[test]
     fn position_monitor_config_default() {
         let config = PositionMonitorConfig::default();
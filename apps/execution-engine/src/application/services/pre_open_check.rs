@@ -0,0 +1,889 @@
+//! Pre-Open Check
+//!
+//! Overnight gap-risk scan run shortly before the regular session opens.
+//!
+//! For every open position the scan verifies that a protective stop is still
+//! working at the broker: stop-losses the engine tracks are checked against
+//! the broker's order status, and a stop the broker has expired, canceled or
+//! lost is re-submitted as GTC at the same level (an expired DAY stop is the
+//! usual case). Positions with no known stop level are reported as
+//! unprotected.
+//!
+//! Pre-market quotes are then compared against the stop levels. A long whose
+//! bid is already at or below its stop (or a short whose ask is at or above
+//! it) will trigger at the open and fill through the stop; these are raised
+//! as gap alerts on the `audit` target with the expected slippage.
+
+use std::collections::{HashMap, HashSet};
+use std::sync::{Arc, Mutex, PoisonError};
+use std::time::Duration;
+
+use rust_decimal::Decimal;
+use tokio_util::sync::CancellationToken;
+
+use crate::application::dto::{CreateOrderDto, SubmitOrdersRequestDto};
+use crate::application::ports::{
+    BrokerError, BrokerPort, Clock, EventPublisherPort, MarketClock, MarketDataPort, MarketQuote,
+    PositionInfo, RiskRepositoryPort, SystemClock,
+};
+use crate::application::use_cases::SubmitOrdersUseCase;
+use crate::domain::order_execution::aggregate::Order;
+use crate::domain::order_execution::repository::OrderRepository;
+use crate::domain::order_execution::value_objects::{
+    OrderPurpose, OrderSide, OrderStatus, TimeInForce,
+};
+use crate::domain::shared::{Money, OrderId, Quantity, Timestamp};
+
+/// Source recorded on orders corrected from the broker's view.
+const PRE_OPEN_SOURCE: &str = "pre_open_check";
+
+/// Expired stops older than this are assumed to belong to an earlier position.
+const EXPIRED_STOP_LOOKBACK_DAYS: i64 = 4;
+
+/// Configuration for the pre-open check.
+#[derive(Debug, Clone)]
+pub struct PreOpenCheckConfig {
+    /// How long before the open the scan runs; zero disables it.
+    pub lead_time: Duration,
+    /// Re-submit stops missing at the broker.
+    pub resubmit_missing: bool,
+    /// How often the market clock is checked.
+    pub poll_interval: Duration,
+}
+
+impl Default for PreOpenCheckConfig {
+    fn default() -> Self {
+        Self {
+            lead_time: Duration::from_secs(30 * 60),
+            resubmit_missing: true,
+            poll_interval: Duration::from_secs(60),
+        }
+    }
+}
+
+/// Protection state of one position.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ProtectionStatus {
+    /// A working stop is confirmed at the broker.
+    Verified,
+    /// A missing stop was re-submitted.
+    Resubmitted,
+    /// A missing stop could not be re-submitted.
+    ResubmitFailed,
+    /// No stop level is known for the position.
+    Unprotected,
+}
+
+/// Protective stop check for one position.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ProtectionCheck {
+    /// Symbol.
+    pub symbol: String,
+    /// Signed position quantity.
+    pub position: Decimal,
+    /// Outcome.
+    pub status: ProtectionStatus,
+    /// Level of the nearest stop, if any.
+    pub stop_price: Option<Decimal>,
+    /// Quantity covered by working or re-submitted stops.
+    pub covered_quantity: Decimal,
+    /// Stop orders found missing at the broker.
+    pub missing_orders: Vec<String>,
+    /// Replacement stop order, if one was submitted.
+    pub replacement_order: Option<String>,
+}
+
+/// Expected gap through a stop at the open.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GapAlert {
+    /// Symbol.
+    pub symbol: String,
+    /// Signed position quantity.
+    pub position: Decimal,
+    /// Stop level.
+    pub stop_price: Decimal,
+    /// Pre-market bid (longs) or ask (shorts).
+    pub quote_price: Decimal,
+    /// Loss beyond the stop if the position exits at the quote.
+    pub expected_slippage: Decimal,
+}
+
+/// Result of one pre-open scan.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PreOpenReport {
+    /// Session the scan ran ahead of.
+    pub next_open: Timestamp,
+    /// Protection checks by position.
+    pub checks: Vec<ProtectionCheck>,
+    /// Positions expected to gap through their stops.
+    pub gap_alerts: Vec<GapAlert>,
+    /// Failures.
+    pub errors: Vec<String>,
+}
+
+impl PreOpenReport {
+    /// Check if every position is protected and none is expected to gap.
+    #[must_use]
+    pub fn is_clear(&self) -> bool {
+        self.gap_alerts.is_empty()
+            && self.errors.is_empty()
+            && self.checks.iter().all(|c| {
+                matches!(
+                    c.status,
+                    ProtectionStatus::Verified | ProtectionStatus::Resubmitted
+                )
+            })
+    }
+}
+
+/// Verifies protective stops and gap risk ahead of the open.
+pub struct PreOpenCheck<B, R, O, E, M>
+where
+    B: BrokerPort,
+    R: RiskRepositoryPort,
+    O: OrderRepository,
+    E: EventPublisherPort,
+    M: MarketDataPort,
+{
+    config: PreOpenCheckConfig,
+    broker: Arc<B>,
+    order_repo: Arc<O>,
+    submit_orders: Arc<SubmitOrdersUseCase<B, R, O, E>>,
+    market_data: Arc<M>,
+    clock: Arc<dyn Clock>,
+    /// Session opens already scanned for.
+    handled: Mutex<HashSet<Timestamp>>,
+}
+
+impl<B, R, O, E, M> PreOpenCheck<B, R, O, E, M>
+where
+    B: BrokerPort,
+    R: RiskRepositoryPort,
+    O: OrderRepository,
+    E: EventPublisherPort,
+    M: MarketDataPort,
+{
+    /// Create a pre-open check.
+    #[must_use]
+    pub fn new(
+        config: PreOpenCheckConfig,
+        broker: Arc<B>,
+        order_repo: Arc<O>,
+        submit_orders: Arc<SubmitOrdersUseCase<B, R, O, E>>,
+        market_data: Arc<M>,
+    ) -> Self {
+        Self {
+            config,
+            broker,
+            order_repo,
+            submit_orders,
+            market_data,
+            clock: SystemClock::shared(),
+            handled: Mutex::new(HashSet::new()),
+        }
+    }
+
+    /// Use `clock` instead of the system clock for the time to open.
+    #[must_use]
+    pub fn with_clock(mut self, clock: Arc<dyn Clock>) -> Self {
+        self.clock = clock;
+        self
+    }
+
+    /// Check the market clock and scan if the open is within the lead time.
+    pub async fn run_once(&self) -> Option<PreOpenReport> {
+        match self.broker.get_market_clock().await {
+            Ok(Some(market)) => self.run_at(&market, self.clock.now()).await,
+            Ok(None) => {
+                tracing::warn!("Broker has no market clock; pre-open check not run");
+                None
+            }
+            Err(e) => {
+                tracing::warn!(error = %e, "Pre-open check could not read market clock");
+                None
+            }
+        }
+    }
+
+    /// Scan once per session while the market is closed and `now` is within
+    /// the lead time of `market.next_open`.
+    pub async fn run_at(&self, market: &MarketClock, now: Timestamp) -> Option<PreOpenReport> {
+        if market.is_open {
+            return None;
+        }
+        let until_open = market.next_open.duration_since(now).to_std().ok()?;
+        if until_open > self.config.lead_time {
+            return None;
+        }
+        if self
+            .handled
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .contains(&market.next_open)
+        {
+            return None;
+        }
+
+        let report = match self.scan(market.next_open, now).await {
+            Ok(report) => report,
+            Err(e) => {
+                // Not marked handled, so the next poll retries
+                tracing::warn!(error = %e, "Pre-open check could not read positions");
+                return None;
+            }
+        };
+        {
+            let mut handled = self.handled.lock().unwrap_or_else(PoisonError::into_inner);
+            handled.retain(|open| *open >= market.next_open);
+            handled.insert(market.next_open);
+        }
+
+        tracing::info!(
+            target: "audit",
+            next_open = %market.next_open,
+            positions = report.checks.len(),
+            gap_alerts = report.gap_alerts.len(),
+            errors = report.errors.len(),
+            clear = report.is_clear(),
+            "Pre-open check complete"
+        );
+        Some(report)
+    }
+
+    /// Run the check every poll interval until shutdown.
+    pub fn start(self: &Arc<Self>, shutdown: CancellationToken)
+    where
+        B: 'static,
+        R: 'static,
+        O: 'static,
+        E: 'static,
+        M: 'static,
+    {
+        if self.config.lead_time.is_zero() {
+            return;
+        }
+
+        let check = Arc::clone(self);
+        let poll_interval = self.config.poll_interval.max(Duration::from_secs(1));
+
+        tokio::spawn(async move {
+            let mut interval = tokio::time::interval(poll_interval);
+
+            loop {
+                tokio::select! {
+                    _ = interval.tick() => {
+                        check.run_once().await;
+                    }
+                    () = shutdown.cancelled() => {
+                        tracing::info!("Pre-open check shutting down");
+                        break;
+                    }
+                }
+            }
+        });
+    }
+
+    async fn scan(
+        &self,
+        next_open: Timestamp,
+        now: Timestamp,
+    ) -> Result<PreOpenReport, BrokerError> {
+        let positions: Vec<PositionInfo> = self
+            .broker
+            .get_all_positions()
+            .await?
+            .into_iter()
+            .filter(|p| !p.quantity.is_zero())
+            .collect();
+
+        let mut report = PreOpenReport {
+            next_open,
+            checks: Vec::new(),
+            gap_alerts: Vec::new(),
+            errors: Vec::new(),
+        };
+        let active = self.order_repo.find_active().await.unwrap_or_else(|e| {
+            report.errors.push(format!("load active orders: {e}"));
+            Vec::new()
+        });
+        let cutoff = now.as_datetime() - chrono::Duration::days(EXPIRED_STOP_LOOKBACK_DAYS);
+        let expired: Vec<Order> = self
+            .order_repo
+            .find_by_status(OrderStatus::Expired)
+            .await
+            .unwrap_or_else(|e| {
+                report.errors.push(format!("load expired orders: {e}"));
+                Vec::new()
+            })
+            .into_iter()
+            .filter(|o| o.updated_at().as_datetime() >= cutoff)
+            .collect();
+
+        for position in &positions {
+            let check = self
+                .check_position(position, &active, &expired, &mut report.errors)
+                .await;
+            if check.status != ProtectionStatus::Verified {
+                tracing::warn!(
+                    target: "audit",
+                    symbol = %check.symbol,
+                    position = %check.position,
+                    status = ?check.status,
+                    stop_price = ?check.stop_price,
+                    missing = ?check.missing_orders,
+                    replacement = ?check.replacement_order,
+                    "Pre-open protective stop check"
+                );
+            }
+            report.checks.push(check);
+        }
+
+        let symbols: Vec<String> = report
+            .checks
+            .iter()
+            .filter(|c| c.stop_price.is_some())
+            .map(|c| c.symbol.clone())
+            .collect();
+        if !symbols.is_empty() {
+            match self.market_data.get_quotes(&symbols).await {
+                Ok(quotes) => {
+                    let quotes: HashMap<&str, &MarketQuote> =
+                        quotes.iter().map(|q| (q.symbol.as_str(), q)).collect();
+                    report.gap_alerts = report
+                        .checks
+                        .iter()
+                        .filter_map(|c| gap_alert(c, quotes.get(c.symbol.as_str())?))
+                        .collect();
+                }
+                Err(e) => report.errors.push(format!("pre-market quotes: {e}")),
+            }
+        }
+
+        for alert in &report.gap_alerts {
+            tracing::warn!(
+                target: "audit",
+                symbol = %alert.symbol,
+                position = %alert.position,
+                stop_price = %alert.stop_price,
+                quote_price = %alert.quote_price,
+                expected_slippage = %alert.expected_slippage,
+                "Position expected to gap through its stop at the open"
+            );
+        }
+        Ok(report)
+    }
+
+    /// Verify the stops protecting `position`, re-submitting a missing one.
+    async fn check_position(
+        &self,
+        position: &PositionInfo,
+        active: &[Order],
+        expired: &[Order],
+        errors: &mut Vec<String>,
+    ) -> ProtectionCheck {
+        let side = if position.quantity > Decimal::ZERO {
+            OrderSide::Sell
+        } else {
+            OrderSide::Buy
+        };
+        let is_stop = |o: &&Order| {
+            o.symbol().as_str() == position.symbol
+                && o.side() == side
+                && o.partial_fill().order_purpose() == OrderPurpose::StopLoss
+                && o.stop_price().is_some()
+        };
+
+        let mut check = ProtectionCheck {
+            symbol: position.symbol.clone(),
+            position: position.quantity,
+            status: ProtectionStatus::Unprotected,
+            stop_price: None,
+            covered_quantity: Decimal::ZERO,
+            missing_orders: Vec::new(),
+            replacement_order: None,
+        };
+        let mut verified = Vec::new();
+        let mut missing = Vec::new();
+        for stop in active.iter().filter(is_stop) {
+            match self.verify(stop).await {
+                Ok(true) => verified.push(stop),
+                Ok(false) => missing.push(stop),
+                Err(e) => errors.push(format!("verify stop {}: {e}", stop.id())),
+            }
+        }
+
+        // An expired DAY stop is the usual reason a position is left bare
+        let template = missing
+            .iter()
+            .copied()
+            .chain(expired.iter().filter(is_stop))
+            .max_by_key(|o| o.updated_at());
+        check.missing_orders = missing.iter().map(|o| o.id().to_string()).collect();
+        check.covered_quantity = verified
+            .iter()
+            .map(|o| o.partial_fill().leaves_qty().amount())
+            .sum();
+        check.stop_price = nearest_stop(side, verified.iter().copied());
+        if !verified.is_empty() {
+            check.status = ProtectionStatus::Verified;
+        }
+
+        let uncovered = position.quantity.abs() - check.covered_quantity;
+        if let Some(template) = template
+            && uncovered > Decimal::ZERO
+            && (verified.is_empty() || !missing.is_empty())
+        {
+            if !self.config.resubmit_missing {
+                check.status = ProtectionStatus::ResubmitFailed;
+                check.stop_price = check
+                    .stop_price
+                    .or_else(|| template.stop_price().map(|p| p.amount()));
+                errors.push(format!(
+                    "{}: stop missing and re-submission disabled",
+                    position.symbol
+                ));
+                return check;
+            }
+
+            match self.resubmit(template, uncovered).await {
+                Ok(order_id) => {
+                    check.status = ProtectionStatus::Resubmitted;
+                    check.covered_quantity += uncovered;
+                    check.replacement_order = Some(order_id);
+                    check.stop_price = nearest_stop(
+                        side,
+                        verified.iter().copied().chain(std::iter::once(template)),
+                    );
+                }
+                Err(e) => {
+                    check.status = ProtectionStatus::ResubmitFailed;
+                    check.stop_price = nearest_stop(
+                        side,
+                        verified.iter().copied().chain(std::iter::once(template)),
+                    );
+                    errors.push(format!("{}: re-submit stop: {e}", position.symbol));
+                }
+            }
+        }
+        check
+    }
+
+    /// Whether `stop` is still working at the broker.
+    ///
+    /// A stop the broker has ended is synced into the local order so it is
+    /// no longer counted as active.
+    async fn verify(&self, stop: &Order) -> Result<bool, String> {
+        let Some(broker_id) = stop.broker_order_id() else {
+            // Never acknowledged by the broker
+            return Ok(false);
+        };
+        let ack = match self.broker.get_order(broker_id).await {
+            Ok(ack) => ack,
+            Err(BrokerError::OrderNotFound { .. }) => return Ok(false),
+            Err(e) => return Err(e.to_string()),
+        };
+        if !ack.status.is_terminal() {
+            return Ok(true);
+        }
+
+        let mut order = stop.clone();
+        order
+            .sync_from_broker(
+                ack.status,
+                Quantity::new(ack.filled_qty),
+                ack.avg_fill_price.map(Money::new),
+                PRE_OPEN_SOURCE,
+            )
+            .map_err(|e| e.to_string())?;
+        self.order_repo
+            .save(&order)
+            .await
+            .map_err(|e| e.to_string())?;
+        Ok(false)
+    }
+
+    /// Submit a GTC copy of `template` for `quantity`.
+    async fn resubmit(&self, template: &Order, quantity: Decimal) -> Result<String, String> {
+        let request = SubmitOrdersRequestDto {
+            orders: vec![CreateOrderDto {
+                client_order_id: OrderId::generate().to_string(),
+                symbol: template.symbol().to_string(),
+                side: template.side(),
+                order_type: template.order_type(),
+                quantity,
+                limit_price: template.limit_price().map(|p| p.amount()),
+                stop_price: template.stop_price().map(|p| p.amount()),
+                time_in_force: TimeInForce::Gtc,
+                purpose: OrderPurpose::StopLoss,
+                decision: None,
+                oto_exit: None,
+            }],
+            validate_risk: false,
+            cycle_id: template.cycle_id().map(ToString::to_string),
+        };
+
+        let response = self.submit_orders.execute(request).await;
+        if let Some(submitted) = response.submitted.first() {
+            return Ok(submitted.order.order_id.clone());
+        }
+        Err(response
+            .rejected
+            .iter()
+            .filter_map(|r| r.error.clone())
+            .chain(response.risk_violations)
+            .next()
+            .unwrap_or_else(|| "rejected".to_string()))
+    }
+}
+
+/// Level of the stop that triggers first for a position closed by `side`.
+fn nearest_stop<'a>(side: OrderSide, stops: impl Iterator<Item = &'a Order>) -> Option<Decimal> {
+    let prices = stops.filter_map(|o| o.stop_price().map(|p| p.amount()));
+    match side {
+        OrderSide::Sell => prices.max(),
+        OrderSide::Buy => prices.min(),
+    }
+}
+
+/// Gap alert if the pre-market quote is already through the stop.
+fn gap_alert(check: &ProtectionCheck, quote: &MarketQuote) -> Option<GapAlert> {
+    let stop_price = check.stop_price?;
+    let long = check.position > Decimal::ZERO;
+    let quoted = if long { quote.bid } else { quote.ask };
+    let quote_price = if quoted > Decimal::ZERO {
+        quoted
+    } else {
+        quote.last
+    };
+    if quote_price <= Decimal::ZERO {
+        return None;
+    }
+
+    let through = if long {
+        stop_price - quote_price
+    } else {
+        quote_price - stop_price
+    };
+    (through >= Decimal::ZERO).then(|| GapAlert {
+        symbol: check.symbol.clone(),
+        position: check.position,
+        stop_price,
+        quote_price,
+        expected_slippage: through * check.position.abs(),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::application::ports::{
+        CancelOrderRequest, InMemoryRiskRepository, MarketDataError, NoOpEventPublisher,
+        OptionChainData, OrderAck, SubmitOrderRequest,
+    };
+    use crate::domain::order_execution::aggregate::CreateOrderCommand;
+    use crate::domain::order_execution::value_objects::OrderType;
+    use crate::domain::shared::{BrokerId, InstrumentId, Symbol};
+    use crate::infrastructure::persistence::InMemoryOrderRepository;
+    use async_trait::async_trait;
+    use rust_decimal_macros::dec;
+
+    #[derive(Default)]
+    struct MockBroker {
+        positions: Vec<PositionInfo>,
+        broker_status: HashMap<String, OrderStatus>,
+        submitted: Mutex<Vec<SubmitOrderRequest>>,
+    }
+
+    #[async_trait]
+    impl BrokerPort for MockBroker {
+        async fn submit_order(&self, request: SubmitOrderRequest) -> Result<OrderAck, BrokerError> {
+            let ack = OrderAck {
+                broker_order_id: BrokerId::new(format!("broker-{}", request.client_order_id)),
+                client_order_id: request.client_order_id.clone(),
+                status: OrderStatus::Accepted,
+                filled_qty: Decimal::ZERO,
+                avg_fill_price: None,
+                child_order_ids: Vec::new(),
+                leg_fills: Vec::new(),
+            };
+            self.submitted.lock().unwrap().push(request);
+            Ok(ack)
+        }
+
+        async fn cancel_order(&self, _request: CancelOrderRequest) -> Result<(), BrokerError> {
+            Ok(())
+        }
+
+        async fn get_order(&self, broker_order_id: &BrokerId) -> Result<OrderAck, BrokerError> {
+            let status = self
+                .broker_status
+                .get(broker_order_id.as_str())
+                .copied()
+                .ok_or_else(|| BrokerError::OrderNotFound {
+                    order_id: broker_order_id.to_string(),
+                })?;
+            Ok(OrderAck {
+                broker_order_id: broker_order_id.clone(),
+                client_order_id: OrderId::new("client"),
+                status,
+                filled_qty: Decimal::ZERO,
+                avg_fill_price: None,
+                child_order_ids: Vec::new(),
+                leg_fills: Vec::new(),
+            })
+        }
+
+        async fn get_open_orders(&self) -> Result<Vec<OrderAck>, BrokerError> {
+            Ok(vec![])
+        }
+
+        async fn get_buying_power(&self) -> Result<Decimal, BrokerError> {
+            Ok(dec!(100000))
+        }
+
+        async fn get_position(
+            &self,
+            _instrument_id: &InstrumentId,
+        ) -> Result<Option<Decimal>, BrokerError> {
+            Ok(None)
+        }
+
+        async fn get_all_positions(&self) -> Result<Vec<PositionInfo>, BrokerError> {
+            Ok(self.positions.clone())
+        }
+    }
+
+    struct MockMarketData {
+        quotes: Vec<MarketQuote>,
+    }
+
+    #[async_trait]
+    impl MarketDataPort for MockMarketData {
+        async fn get_quotes(
+            &self,
+            _symbols: &[String],
+        ) -> Result<Vec<MarketQuote>, MarketDataError> {
+            Ok(self.quotes.clone())
+        }
+
+        async fn get_option_chain(
+            &self,
+            underlying: &str,
+        ) -> Result<OptionChainData, MarketDataError> {
+            Err(MarketDataError::SymbolNotFound {
+                symbol: underlying.to_string(),
+            })
+        }
+    }
+
+    fn position(symbol: &str, quantity: Decimal) -> PositionInfo {
+        PositionInfo {
+            symbol: symbol.to_string(),
+            quantity,
+            avg_entry_price: dec!(100),
+            market_value: quantity * dec!(100),
+            unrealized_pnl: Decimal::ZERO,
+            current_price: dec!(100),
+            opened_at: None,
+        }
+    }
+
+    fn quote(symbol: &str, bid: Decimal, ask: Decimal) -> MarketQuote {
+        MarketQuote {
+            symbol: symbol.to_string(),
+            bid,
+            ask,
+            bid_size: 100,
+            ask_size: 100,
+            last: bid,
+            last_size: 100,
+            volume: 1000,
+            timestamp: Timestamp::now(),
+        }
+    }
+
+    fn stop(symbol: &str, stop_price: Decimal, broker_id: &str) -> Order {
+        let mut order = Order::new(CreateOrderCommand {
+            symbol: Symbol::new(symbol),
+            side: OrderSide::Sell,
+            order_type: OrderType::Stop,
+            quantity: Quantity::from_i64(100),
+            limit_price: None,
+            stop_price: Some(Money::new(stop_price)),
+            time_in_force: TimeInForce::Day,
+            purpose: OrderPurpose::StopLoss,
+            legs: vec![],
+        })
+        .unwrap();
+        order.accept(BrokerId::new(broker_id)).unwrap();
+        order
+    }
+
+    fn market_opening_in(minutes: i64) -> (MarketClock, Timestamp) {
+        let open = Timestamp::parse("2026-03-16T13:30:00Z").unwrap();
+        let now = Timestamp::new(open.as_datetime() - chrono::Duration::minutes(minutes));
+        let market = MarketClock {
+            timestamp: now,
+            is_open: false,
+            next_open: open,
+            next_close: Timestamp::parse("2026-03-16T20:00:00Z").unwrap(),
+        };
+        (market, now)
+    }
+
+    type TestCheck = PreOpenCheck<
+        MockBroker,
+        InMemoryRiskRepository,
+        InMemoryOrderRepository,
+        NoOpEventPublisher,
+        MockMarketData,
+    >;
+
+    async fn make_check(
+        broker: MockBroker,
+        orders: Vec<Order>,
+        quotes: Vec<MarketQuote>,
+    ) -> (TestCheck, Arc<MockBroker>, Arc<InMemoryOrderRepository>) {
+        let broker = Arc::new(broker);
+        let order_repo = Arc::new(InMemoryOrderRepository::new());
+        for order in &orders {
+            order_repo.save(order).await.unwrap();
+        }
+        let submit_orders = Arc::new(SubmitOrdersUseCase::new(
+            Arc::clone(&broker),
+            Arc::new(InMemoryRiskRepository::new()),
+            Arc::clone(&order_repo),
+            Arc::new(NoOpEventPublisher),
+        ));
+        let check = PreOpenCheck::new(
+            PreOpenCheckConfig::default(),
+            Arc::clone(&broker),
+            Arc::clone(&order_repo),
+            submit_orders,
+            Arc::new(MockMarketData { quotes }),
+        );
+        (check, broker, order_repo)
+    }
+
+    #[tokio::test]
+    async fn working_stop_is_verified_without_alert() {
+        let broker = MockBroker {
+            positions: vec![position("AAPL", dec!(100))],
+            broker_status: HashMap::from([("broker-aapl".to_string(), OrderStatus::Accepted)]),
+            ..MockBroker::default()
+        };
+        let (check, broker, _) = make_check(
+            broker,
+            vec![stop("AAPL", dec!(95), "broker-aapl")],
+            vec![quote("AAPL", dec!(99.50), dec!(99.60))],
+        )
+        .await;
+
+        let (market, now) = market_opening_in(20);
+        let report = check.run_at(&market, now).await.unwrap();
+
+        assert_eq!(report.checks[0].status, ProtectionStatus::Verified);
+        assert_eq!(report.checks[0].stop_price, Some(dec!(95)));
+        assert_eq!(report.checks[0].covered_quantity, dec!(100));
+        assert!(report.is_clear());
+        assert!(broker.submitted.lock().unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn expired_stop_is_resubmitted_and_gap_is_alerted() {
+        let broker = MockBroker {
+            positions: vec![position("AAPL", dec!(100))],
+            broker_status: HashMap::from([("broker-aapl".to_string(), OrderStatus::Expired)]),
+            ..MockBroker::default()
+        };
+        let (check, broker, order_repo) = make_check(
+            broker,
+            vec![stop("AAPL", dec!(95), "broker-aapl")],
+            vec![quote("AAPL", dec!(92), dec!(92.10))],
+        )
+        .await;
+
+        let (market, now) = market_opening_in(20);
+        let report = check.run_at(&market, now).await.unwrap();
+
+        let protection = &report.checks[0];
+        assert_eq!(protection.status, ProtectionStatus::Resubmitted);
+        assert_eq!(protection.missing_orders.len(), 1);
+        assert!(protection.replacement_order.is_some());
+        {
+            let submitted = broker.submitted.lock().unwrap();
+            assert_eq!(submitted.len(), 1);
+            assert_eq!(submitted[0].time_in_force, TimeInForce::Gtc);
+            assert_eq!(submitted[0].stop_price, Some(dec!(95)));
+            assert_eq!(submitted[0].quantity, dec!(100));
+        }
+
+        // The expired original is no longer active locally
+        let active = order_repo.find_active().await.unwrap();
+        assert_eq!(active.len(), 1);
+        assert_eq!(active[0].time_in_force(), TimeInForce::Gtc);
+
+        assert_eq!(report.gap_alerts.len(), 1);
+        assert_eq!(report.gap_alerts[0].quote_price, dec!(92));
+        assert_eq!(report.gap_alerts[0].expected_slippage, dec!(300));
+        assert!(!report.is_clear());
+    }
+
+    #[tokio::test]
+    async fn position_without_known_stop_is_unprotected() {
+        let broker = MockBroker {
+            positions: vec![position("TSLA", dec!(-20))],
+            ..MockBroker::default()
+        };
+        let (check, broker, _) = make_check(broker, vec![], vec![]).await;
+
+        let (market, now) = market_opening_in(5);
+        let report = check.run_at(&market, now).await.unwrap();
+
+        assert_eq!(report.checks[0].status, ProtectionStatus::Unprotected);
+        assert!(report.gap_alerts.is_empty());
+        assert!(!report.is_clear());
+        assert!(broker.submitted.lock().unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn runs_once_within_lead_time() {
+        let broker = MockBroker {
+            positions: vec![position("AAPL", dec!(100))],
+            ..MockBroker::default()
+        };
+        let (check, _, _) = make_check(broker, vec![], vec![]).await;
+
+        let (market, now) = market_opening_in(45);
+        assert!(check.run_at(&market, now).await.is_none());
+
+        let (market, now) = market_opening_in(25);
+        assert!(check.run_at(&market, now).await.is_some());
+
+        let (market, now) = market_opening_in(10);
+        assert!(check.run_at(&market, now).await.is_none());
+
+        let (mut market, now) = market_opening_in(10);
+        market.is_open = true;
+        assert!(check.run_at(&market, now).await.is_none());
+    }
+
+    #[test]
+    fn gap_alert_for_short_uses_ask() {
+        let check = ProtectionCheck {
+            symbol: "TSLA".to_string(),
+            position: dec!(-20),
+            status: ProtectionStatus::Verified,
+            stop_price: Some(dec!(210)),
+            covered_quantity: dec!(20),
+            missing_orders: vec![],
+            replacement_order: None,
+        };
+
+        let alert = gap_alert(&check, &quote("TSLA", dec!(214), dec!(215))).unwrap();
+        assert_eq!(alert.quote_price, dec!(215));
+        assert_eq!(alert.expected_slippage, dec!(100));
+
+        assert!(gap_alert(&check, &quote("TSLA", dec!(205), dec!(206))).is_none());
+    }
+}
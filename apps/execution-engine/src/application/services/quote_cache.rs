@@ -0,0 +1,136 @@
+//! Quote Cache
+//!
+//! Holds recent quotes keyed by symbol so a plan that trades the same
+//! symbols cycle after cycle does not refetch a quote for every order.
+//! Entries are served until they are older than the TTL; expired entries
+//! are dropped on the next insert.
+
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{PoisonError, RwLock};
+use std::time::{Duration, Instant};
+
+use super::{CacheStats, CacheStatsSource};
+use crate::application::ports::Quote;
+
+/// Default time a cached quote is served.
+pub const DEFAULT_QUOTE_CACHE_TTL: Duration = Duration::from_secs(2);
+
+struct CachedQuote {
+    quote: Quote,
+    fetched_at: Instant,
+}
+
+/// Short-lived cache of quotes by symbol.
+pub struct QuoteCache {
+    ttl: Duration,
+    quotes: RwLock<HashMap<String, CachedQuote>>,
+    hits: AtomicU64,
+    misses: AtomicU64,
+}
+
+impl QuoteCache {
+    /// Create a cache serving quotes for `ttl`.
+    #[must_use]
+    pub fn new(ttl: Duration) -> Self {
+        Self {
+            ttl,
+            quotes: RwLock::new(HashMap::new()),
+            hits: AtomicU64::new(0),
+            misses: AtomicU64::new(0),
+        }
+    }
+
+    /// Cached quote for `symbol`, if still fresh.
+    pub fn get(&self, symbol: &str) -> Option<Quote> {
+        let quotes = self.quotes.read().unwrap_or_else(PoisonError::into_inner);
+        let cached = quotes
+            .get(symbol)
+            .filter(|cached| cached.fetched_at.elapsed() < self.ttl)
+            .map(|cached| cached.quote.clone());
+        drop(quotes);
+
+        let counter = if cached.is_some() {
+            &self.hits
+        } else {
+            &self.misses
+        };
+        counter.fetch_add(1, Ordering::Relaxed);
+        cached
+    }
+
+    /// Store a freshly fetched quote.
+    pub fn insert(&self, quote: Quote) {
+        let mut quotes = self.quotes.write().unwrap_or_else(PoisonError::into_inner);
+        quotes.retain(|_, cached| cached.fetched_at.elapsed() < self.ttl);
+        quotes.insert(
+            quote.symbol.as_str().to_string(),
+            CachedQuote {
+                quote,
+                fetched_at: Instant::now(),
+            },
+        );
+    }
+
+    /// Current counters.
+    #[must_use]
+    pub fn stats(&self) -> CacheStats {
+        CacheStats {
+            entries: self
+                .quotes
+                .read()
+                .unwrap_or_else(PoisonError::into_inner)
+                .len(),
+            hits: self.hits.load(Ordering::Relaxed),
+            misses: self.misses.load(Ordering::Relaxed),
+            ..CacheStats::default()
+        }
+    }
+}
+
+impl CacheStatsSource for QuoteCache {
+    fn cache_stats(&self) -> CacheStats {
+        self.stats()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::shared::Symbol;
+    use rust_decimal::Decimal;
+
+    fn quote(symbol: &str) -> Quote {
+        Quote::new(
+            Symbol::new(symbol),
+            Decimal::new(100, 0),
+            Decimal::new(10001, 2),
+            Decimal::ONE,
+            Decimal::ONE,
+        )
+    }
+
+    #[test]
+    fn quote_cache_serves_fresh_quotes() {
+        let cache = QuoteCache::new(Duration::from_secs(60));
+
+        assert!(cache.get("AAPL").is_none());
+        cache.insert(quote("AAPL"));
+
+        assert_eq!(cache.get("AAPL").unwrap().symbol, Symbol::new("AAPL"));
+        let stats = cache.stats();
+        assert_eq!((stats.entries, stats.hits, stats.misses), (1, 1, 1));
+    }
+
+    #[test]
+    fn quote_cache_expires_after_ttl() {
+        let cache = QuoteCache::new(Duration::ZERO);
+        cache.insert(quote("AAPL"));
+
+        assert!(cache.get("AAPL").is_none());
+
+        // Expired entries are dropped when the next quote is stored
+        cache.insert(quote("MSFT"));
+        assert_eq!(cache.stats().entries, 1);
+    }
+}
@@ -0,0 +1,243 @@
+//! Readiness Service
+//!
+//! Tracks whether the engine is ready to accept trading traffic, as opposed
+//! to merely running. The engine is ready once startup recovery has
+//! completed and its broker and persistence dependencies respond. The
+//! latest host clock offset is reported alongside, without affecting
+//! readiness; a skewed clock halts trading through the kill switch instead.
+
+use std::sync::Arc;
+use std::sync::atomic::{AtomicBool, Ordering};
+
+use serde::{Deserialize, Serialize};
+
+use super::{ClockSkewMonitor, ClockSyncStatus};
+use crate::application::ports::{BrokerPort, ScheduledPlanRepositoryPort};
+
+/// Result of a single dependency check.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DependencyCheck {
+    /// Whether the dependency responded.
+    pub ok: bool,
+    /// Error message if the check failed.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+}
+
+impl DependencyCheck {
+    fn from_result<T, E: std::fmt::Display>(result: Result<T, E>) -> Self {
+        match result {
+            Ok(_) => Self {
+                ok: true,
+                error: None,
+            },
+            Err(e) => Self {
+                ok: false,
+                error: Some(e.to_string()),
+            },
+        }
+    }
+}
+
+/// Readiness report across all dependencies.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct ReadinessReport {
+    /// Whether startup recovery and reconciliation have completed.
+    pub recovered: bool,
+    /// Broker connectivity.
+    pub broker: DependencyCheck,
+    /// Persistence (scheduled plan store) reachability.
+    pub persistence: DependencyCheck,
+    /// Latest host clock check (`None` before the first check or without
+    /// a clock skew monitor).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub clock_sync: Option<ClockSyncStatus>,
+}
+
+impl ReadinessReport {
+    /// Check if the engine can accept traffic.
+    #[must_use]
+    pub const fn is_ready(&self) -> bool {
+        self.recovered && self.broker.ok && self.persistence.ok
+    }
+}
+
+/// Dependency-aware readiness checks.
+pub struct ReadinessService<B, S>
+where
+    B: BrokerPort,
+    S: ScheduledPlanRepositoryPort,
+{
+    broker: Arc<B>,
+    plan_repo: Arc<S>,
+    clock_skew: Option<Arc<ClockSkewMonitor>>,
+    recovered: AtomicBool,
+}
+
+impl<B, S> ReadinessService<B, S>
+where
+    B: BrokerPort,
+    S: ScheduledPlanRepositoryPort,
+{
+    /// Create a new readiness service. The engine starts not recovered.
+    #[must_use]
+    pub const fn new(broker: Arc<B>, plan_repo: Arc<S>) -> Self {
+        Self {
+            broker,
+            plan_repo,
+            clock_skew: None,
+            recovered: AtomicBool::new(false),
+        }
+    }
+
+    /// Report the latest host clock check of `monitor`.
+    #[must_use]
+    pub fn with_clock_skew(mut self, monitor: Arc<ClockSkewMonitor>) -> Self {
+        self.clock_skew = Some(monitor);
+        self
+    }
+
+    /// Record that startup recovery and reconciliation have completed.
+    pub fn mark_recovered(&self) {
+        self.recovered.store(true, Ordering::Release);
+    }
+
+    /// Check if startup recovery has completed.
+    #[must_use]
+    pub fn is_recovered(&self) -> bool {
+        self.recovered.load(Ordering::Acquire)
+    }
+
+    /// Run all readiness checks.
+    pub async fn check(&self) -> ReadinessReport {
+        let (broker, persistence) = tokio::join!(
+            self.broker.get_buying_power(),
+            self.plan_repo.list_pending()
+        );
+
+        ReadinessReport {
+            recovered: self.is_recovered(),
+            broker: DependencyCheck::from_result(broker),
+            persistence: DependencyCheck::from_result(persistence),
+            clock_sync: self.clock_skew.as_ref().and_then(|m| m.status()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::application::ports::{
+        BrokerError, CancelOrderRequest, InMemoryScheduledPlanRepository, OrderAck, PositionInfo,
+        SubmitOrderRequest,
+    };
+    use crate::domain::shared::{BrokerId, InstrumentId};
+    use async_trait::async_trait;
+    use rust_decimal::Decimal;
+
+    struct MockBroker {
+        healthy: bool,
+    }
+
+    #[async_trait]
+    impl BrokerPort for MockBroker {
+        async fn submit_order(
+            &self,
+            _request: SubmitOrderRequest,
+        ) -> Result<OrderAck, BrokerError> {
+            Err(BrokerError::Unknown {
+                message: "Not implemented".to_string(),
+            })
+        }
+
+        async fn cancel_order(&self, _request: CancelOrderRequest) -> Result<(), BrokerError> {
+            Ok(())
+        }
+
+        async fn get_order(&self, _broker_order_id: &BrokerId) -> Result<OrderAck, BrokerError> {
+            Err(BrokerError::OrderNotFound {
+                order_id: "unknown".to_string(),
+            })
+        }
+
+        async fn get_open_orders(&self) -> Result<Vec<OrderAck>, BrokerError> {
+            Ok(vec![])
+        }
+
+        async fn get_buying_power(&self) -> Result<Decimal, BrokerError> {
+            if self.healthy {
+                Ok(Decimal::new(100_000, 0))
+            } else {
+                Err(BrokerError::ConnectionError {
+                    message: "connection refused".to_string(),
+                })
+            }
+        }
+
+        async fn get_position(
+            &self,
+            _instrument_id: &InstrumentId,
+        ) -> Result<Option<Decimal>, BrokerError> {
+            Ok(None)
+        }
+
+        async fn get_all_positions(&self) -> Result<Vec<PositionInfo>, BrokerError> {
+            Ok(vec![])
+        }
+    }
+
+    fn make_service(
+        healthy: bool,
+    ) -> ReadinessService<MockBroker, InMemoryScheduledPlanRepository> {
+        ReadinessService::new(
+            Arc::new(MockBroker { healthy }),
+            Arc::new(InMemoryScheduledPlanRepository::new()),
+        )
+    }
+
+    #[tokio::test]
+    async fn not_ready_until_recovered() {
+        let service = make_service(true);
+
+        let report = service.check().await;
+        assert!(!report.recovered);
+        assert!(report.broker.ok);
+        assert!(report.persistence.ok);
+        assert!(!report.is_ready());
+
+        service.mark_recovered();
+        assert!(service.check().await.is_ready());
+    }
+
+    #[tokio::test]
+    async fn not_ready_when_broker_unreachable() {
+        let service = make_service(false);
+        service.mark_recovered();
+
+        let report = service.check().await;
+        assert!(!report.is_ready());
+        assert!(!report.broker.ok);
+        assert!(report.broker.error.is_some());
+    }
+
+    #[tokio::test]
+    async fn reports_clock_offset_without_affecting_readiness() {
+        use crate::application::ports::FixedTimeSource;
+        use crate::application::services::ClockSkewConfig;
+
+        let monitor = Arc::new(ClockSkewMonitor::new(
+            Arc::new(FixedTimeSource::new(2_000)),
+            ClockSkewConfig::default(),
+        ));
+        let service = make_service(true).with_clock_skew(Arc::clone(&monitor));
+        service.mark_recovered();
+        assert!(service.check().await.clock_sync.is_none());
+
+        monitor.check().await;
+        let report = service.check().await;
+        assert!(report.is_ready());
+        let clock_sync = report.clock_sync.unwrap();
+        assert_eq!(clock_sync.offset_ms, Some(2_000));
+        assert!(clock_sync.skewed);
+    }
+}
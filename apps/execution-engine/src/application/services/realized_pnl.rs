@@ -0,0 +1,384 @@
+//! Realized P&L
+//!
+//! Replays every fill in the order store through a tax lot book, so each
+//! closing fill is matched to the lots it closes (FIFO or LIFO) and every
+//! closed lot carries its realized gain. Unlike the exposure book's running
+//! average-cost figure, the result is per lot and suitable for tax records.
+//!
+//! The order store is replayed once, on the first query; reconciliation then
+//! feeds each order's new fills into the cached book via
+//! [`RealizedPnlService::sync_order`].
+//!
+//! Positions the broker reports at startup beyond what the recorded fills
+//! account for (opened before the engine kept an order history) are seeded
+//! as opening lots, so closing them realizes a gain against the broker's
+//! average entry price instead of opening a lot on the other side.
+
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use rust_decimal::Decimal;
+use tokio::sync::Mutex;
+
+use crate::application::ports::PositionInfo;
+use crate::domain::order_execution::aggregate::Order;
+use crate::domain::order_execution::errors::OrderError;
+use crate::domain::order_execution::repository::OrderRepository;
+use crate::domain::order_execution::value_objects::{
+    LotMatching, OPENING_FILL_ID, OrderSide, OrderStatus, TaxLot, TaxLotBook,
+};
+use crate::domain::shared::Symbol;
+
+/// Computes realized P&L from the fills in the order store.
+pub struct RealizedPnlService<O: OrderRepository> {
+    order_repo: Arc<O>,
+    matching: LotMatching,
+    state: Mutex<LotState>,
+}
+
+/// Seeded lots and the book built from them and the fills seen so far.
+#[derive(Default)]
+struct LotState {
+    /// Lots seeded from broker positions.
+    opening: Vec<(Symbol, TaxLot)>,
+    /// Book and fills applied per order ID; `None` until first queried.
+    book: Option<(TaxLotBook, HashMap<String, usize>)>,
+}
+
+impl<O: OrderRepository> RealizedPnlService<O> {
+    /// Create a service over `order_repo` that matches lots first in, first out.
+    #[must_use]
+    pub fn new(order_repo: Arc<O>) -> Self {
+        Self {
+            order_repo,
+            matching: LotMatching::Fifo,
+            state: Mutex::new(LotState::default()),
+        }
+    }
+
+    /// Match closing fills to lots with `matching`.
+    #[must_use]
+    pub const fn with_lot_matching(mut self, matching: LotMatching) -> Self {
+        self.matching = matching;
+        self
+    }
+
+    /// Lot matching method.
+    #[must_use]
+    pub const fn lot_matching(&self) -> LotMatching {
+        self.matching
+    }
+
+    /// Open and closed lots after every fill so far.
+    ///
+    /// # Errors
+    ///
+    /// Returns error if the order store cannot be read.
+    pub async fn lots(&self) -> Result<TaxLotBook, OrderError> {
+        let mut state = self.state.lock().await;
+        if let Some((book, _)) = &state.book {
+            return Ok(book.clone());
+        }
+        let (book, applied) = self.replay(&state.opening).await?;
+        state.book = Some((book.clone(), applied));
+        drop(state);
+        Ok(book)
+    }
+
+    /// Apply fills of `order` not yet in the book.
+    ///
+    /// Fed by reconciliation after the order is saved. Before the first
+    /// query there is no book yet, and the replay picks the fills up.
+    pub async fn sync_order(&self, order: &Order) {
+        let mut state = self.state.lock().await;
+        let Some((book, applied)) = &mut state.book else {
+            return;
+        };
+        let fills = order.partial_fill().fills();
+        let seen = applied.entry(order.id().to_string()).or_default();
+        for fill in fills.get(*seen..).unwrap_or_default() {
+            book.apply(order.symbol(), order.side(), fill);
+        }
+        *seen = fills.len();
+        drop(state);
+    }
+
+    /// Seed an opening lot for each of `positions` the recorded fills do not
+    /// account for, replacing any lots seeded before.
+    ///
+    /// Call once at startup, before new fills arrive. The lot covers the
+    /// difference between the broker quantity and the recorded open
+    /// quantity, at the broker's average entry price, opened when the
+    /// broker reports the position was opened (unknown if it does not). A
+    /// position smaller than the recorded lots is left as recorded.
+    ///
+    /// # Errors
+    ///
+    /// Returns error if the order store cannot be read.
+    pub async fn seed_opening_lots(&self, positions: &[PositionInfo]) -> Result<(), OrderError> {
+        let mut state = self.state.lock().await;
+        let (recorded, _) = self.replay(&[]).await?;
+
+        let mut opening = Vec::new();
+        for position in positions {
+            let unrecorded = position.quantity - recorded.open_quantity(&position.symbol);
+            if unrecorded.is_zero() {
+                continue;
+            }
+            if unrecorded.is_sign_positive() != position.quantity.is_sign_positive() {
+                tracing::warn!(
+                    symbol = %position.symbol,
+                    broker_quantity = %position.quantity,
+                    recorded_quantity = %recorded.open_quantity(&position.symbol),
+                    "Broker position is smaller than the recorded tax lots"
+                );
+                continue;
+            }
+            let side = if unrecorded > Decimal::ZERO {
+                OrderSide::Buy
+            } else {
+                OrderSide::Sell
+            };
+            opening.push((
+                Symbol::new(&position.symbol),
+                TaxLot {
+                    fill_id: OPENING_FILL_ID.to_string(),
+                    opened_at: position.opened_at,
+                    side,
+                    quantity: unrecorded.abs(),
+                    price: position.avg_entry_price,
+                },
+            ));
+        }
+
+        state.opening = opening;
+        state.book = None;
+        drop(state);
+        Ok(())
+    }
+
+    /// Replay every fill onto a book seeded with `opening`, returning it
+    /// with the number of fills applied per order ID.
+    ///
+    /// Undated lots are seeded first; dated ones as the replay reaches
+    /// their `opened_at`.
+    async fn replay(
+        &self,
+        opening: &[(Symbol, TaxLot)],
+    ) -> Result<(TaxLotBook, HashMap<String, usize>), OrderError> {
+        let mut orders = Vec::new();
+        for status in OrderStatus::ALL {
+            orders.extend(self.order_repo.find_by_status(status).await?);
+        }
+
+        let mut fills: Vec<_> = orders
+            .iter()
+            .flat_map(|order| {
+                order
+                    .partial_fill()
+                    .fills()
+                    .iter()
+                    .map(move |fill| (order, fill))
+            })
+            .collect();
+        fills.sort_by(|(_, a), (_, b)| {
+            a.timestamp
+                .cmp(&b.timestamp)
+                .then_with(|| a.fill_id.cmp(&b.fill_id))
+        });
+
+        let mut opening: Vec<_> = opening.iter().collect();
+        opening.sort_by_key(|(_, lot)| lot.opened_at);
+        let mut opening = opening.into_iter().peekable();
+
+        let mut book = TaxLotBook::new(self.matching);
+        for (order, fill) in fills {
+            while let Some((symbol, lot)) =
+                opening.next_if(|(_, lot)| lot.opened_at <= Some(fill.timestamp))
+            {
+                book.seed(symbol, lot.clone());
+            }
+            book.apply(order.symbol(), order.side(), fill);
+        }
+        for (symbol, lot) in opening {
+            book.seed(symbol, lot.clone());
+        }
+
+        let applied = orders
+            .iter()
+            .map(|order| (order.id().to_string(), order.partial_fill().fills().len()))
+            .collect();
+        Ok((book, applied))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::order_execution::aggregate::{CreateOrderCommand, Order};
+    use crate::domain::order_execution::value_objects::{
+        FillReport, OrderPurpose, OrderSide, OrderType, TimeInForce,
+    };
+    use crate::domain::shared::{BrokerId, Money, Quantity, Symbol, Timestamp};
+    use crate::infrastructure::persistence::InMemoryOrderRepository;
+    use rust_decimal_macros::dec;
+
+    fn filled(side: OrderSide, fill_id: &str, price: i64, at: &str) -> Order {
+        let mut order = Order::new(CreateOrderCommand {
+            symbol: Symbol::new("AAPL"),
+            side,
+            order_type: OrderType::Market,
+            quantity: Quantity::from_i64(10),
+            limit_price: None,
+            stop_price: None,
+            time_in_force: TimeInForce::Day,
+            purpose: OrderPurpose::Entry,
+            legs: vec![],
+        })
+        .unwrap();
+        order.accept(BrokerId::new(format!("b-{fill_id}"))).unwrap();
+        order
+            .apply_fill(FillReport::new(
+                fill_id,
+                Quantity::from_i64(10),
+                Money::new(price.into()),
+                Timestamp::parse(at).unwrap(),
+                "TEST",
+            ))
+            .unwrap();
+        order
+    }
+
+    #[tokio::test]
+    async fn fills_are_replayed_in_time_order() {
+        let order_repo = Arc::new(InMemoryOrderRepository::new());
+        // Saved out of order; the sell closes whichever buy matching picks
+        for order in [
+            filled(OrderSide::Sell, "s-1", 130, "2026-03-04T15:00:00Z"),
+            filled(OrderSide::Buy, "b-2", 110, "2026-03-03T15:00:00Z"),
+            filled(OrderSide::Buy, "b-1", 100, "2026-03-02T15:00:00Z"),
+        ] {
+            order_repo.save(&order).await.unwrap();
+        }
+
+        let fifo = RealizedPnlService::new(Arc::clone(&order_repo))
+            .lots()
+            .await
+            .unwrap();
+        assert_eq!(fifo.closed_lots()[0].open_fill_id, "b-1");
+        assert_eq!(fifo.total_realized_pnl(), dec!(300));
+
+        let lifo = RealizedPnlService::new(order_repo)
+            .with_lot_matching(LotMatching::Lifo)
+            .lots()
+            .await
+            .unwrap();
+        assert_eq!(lifo.closed_lots()[0].open_fill_id, "b-2");
+        assert_eq!(lifo.total_realized_pnl(), dec!(200));
+    }
+
+    #[tokio::test]
+    async fn position_held_before_startup_is_closed_against_its_opening_lot() {
+        let order_repo = Arc::new(InMemoryOrderRepository::new());
+        order_repo
+            .save(&filled(OrderSide::Buy, "b-1", 110, "2026-03-02T15:00:00Z"))
+            .await
+            .unwrap();
+        let service = RealizedPnlService::new(Arc::clone(&order_repo));
+
+        // The broker holds 10 shares more than the recorded fills bought
+        let position = PositionInfo {
+            symbol: "AAPL".to_string(),
+            quantity: dec!(20),
+            avg_entry_price: dec!(100),
+            market_value: dec!(2400),
+            unrealized_pnl: dec!(400),
+            current_price: dec!(120),
+            opened_at: None,
+        };
+        service.seed_opening_lots(&[position]).await.unwrap();
+
+        for order in [
+            filled(OrderSide::Sell, "s-1", 120, "2026-03-03T15:00:00Z"),
+            filled(OrderSide::Sell, "s-2", 130, "2026-03-04T15:00:00Z"),
+        ] {
+            order_repo.save(&order).await.unwrap();
+        }
+
+        let lots = service.lots().await.unwrap();
+        assert_eq!(lots.closed_lots()[0].open_fill_id, OPENING_FILL_ID);
+        assert_eq!(lots.closed_lots()[0].opened_at, None);
+        assert_eq!(lots.closed_lots()[0].realized_pnl, dec!(200));
+        assert_eq!(lots.closed_lots()[1].open_fill_id, "b-1");
+        assert_eq!(lots.total_realized_pnl(), dec!(400));
+        assert!(lots.open_lots("AAPL").is_empty());
+    }
+
+    #[tokio::test]
+    async fn dated_opening_lot_is_matched_by_its_open_date() {
+        let order_repo = Arc::new(InMemoryOrderRepository::new());
+        for order in [
+            filled(OrderSide::Buy, "b-1", 110, "2026-03-02T15:00:00Z"),
+            filled(OrderSide::Buy, "b-2", 120, "2026-03-04T15:00:00Z"),
+        ] {
+            order_repo.save(&order).await.unwrap();
+        }
+        // The broker bought 10 more shares on 3/3, between the recorded buys
+        let position = PositionInfo {
+            symbol: "AAPL".to_string(),
+            quantity: dec!(20),
+            avg_entry_price: dec!(100),
+            market_value: dec!(2600),
+            unrealized_pnl: dec!(600),
+            current_price: dec!(130),
+            opened_at: Some(Timestamp::parse("2026-03-03T15:00:00Z").unwrap()),
+        };
+        let sell = filled(OrderSide::Sell, "s-1", 130, "2026-03-05T15:00:00Z");
+        order_repo.save(&sell).await.unwrap();
+
+        let fifo = RealizedPnlService::new(Arc::clone(&order_repo));
+        fifo.seed_opening_lots(std::slice::from_ref(&position))
+            .await
+            .unwrap();
+        let lots = fifo.lots().await.unwrap();
+        assert_eq!(lots.closed_lots()[0].open_fill_id, "b-1");
+        let open: Vec<_> = lots
+            .open_lots("AAPL")
+            .iter()
+            .map(|lot| lot.fill_id.as_str())
+            .collect();
+        assert_eq!(open, [OPENING_FILL_ID, "b-2"]);
+
+        let lifo = RealizedPnlService::new(order_repo).with_lot_matching(LotMatching::Lifo);
+        lifo.seed_opening_lots(&[position]).await.unwrap();
+        let lots = lifo.lots().await.unwrap();
+        assert_eq!(lots.closed_lots()[0].open_fill_id, "b-2");
+        let open: Vec<_> = lots
+            .open_lots("AAPL")
+            .iter()
+            .map(|lot| lot.fill_id.as_str())
+            .collect();
+        assert_eq!(open, ["b-1", OPENING_FILL_ID]);
+    }
+
+    #[tokio::test]
+    async fn synced_fills_extend_the_cached_book() {
+        let order_repo = Arc::new(InMemoryOrderRepository::new());
+        let buy = filled(OrderSide::Buy, "b-1", 100, "2026-03-02T15:00:00Z");
+        order_repo.save(&buy).await.unwrap();
+        let service = RealizedPnlService::new(Arc::clone(&order_repo));
+        assert!(service.lots().await.unwrap().closed_lots().is_empty());
+
+        // Already replayed: syncing it again applies nothing
+        service.sync_order(&buy).await;
+        let sell = filled(OrderSide::Sell, "s-1", 130, "2026-03-03T15:00:00Z");
+        order_repo.save(&sell).await.unwrap();
+        service.sync_order(&sell).await;
+        service.sync_order(&sell).await;
+
+        let lots = service.lots().await.unwrap();
+        assert_eq!(lots.closed_lots().len(), 1);
+        assert_eq!(lots.total_realized_pnl(), dec!(300));
+        assert!(lots.open_lots("AAPL").is_empty());
+    }
+}
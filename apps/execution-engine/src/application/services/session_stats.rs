@@ -0,0 +1,515 @@
+//! Session Stats
+//!
+//! At-a-glance view of the engine's trading session for operators: order
+//! activity today, live exposure and realized P&L, circuit breaker states,
+//! quote feed freshness and rejected ticks, cache statistics, the event
+//! outbox backlog, and the outcome of the last reconciliation. Other
+//! components report into the service; the snapshot is assembled on request.
+
+use std::collections::BTreeMap;
+use std::sync::{Arc, PoisonError, RwLock};
+use std::time::Duration;
+
+use rust_decimal::Decimal;
+use tokio::sync::broadcast::{self, error::RecvError};
+use tokio_util::sync::CancellationToken;
+
+use super::{
+    CacheStats, CircuitBreaker, CircuitBreakerState, EventOutbox, ExposureCache, FeedSanitizer,
+    OutboxStats, RejectedTicks,
+};
+use crate::application::ports::{Clock, SystemClock};
+use crate::application::use_cases::ReconciliationResult;
+use crate::domain::order_execution::aggregate::Order;
+use crate::domain::order_execution::repository::OrderRepository;
+use crate::domain::order_execution::value_objects::OrderStatus;
+use crate::domain::risk_management::value_objects::Exposure;
+use crate::domain::shared::Timestamp;
+use crate::infrastructure::websocket::QuoteUpdate;
+
+/// Feeds without an update for this long are reported stale.
+pub const DEFAULT_FEED_STALE_AFTER: Duration = Duration::from_secs(30);
+
+/// Order activity for the current UTC day.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct OrderActivity {
+    /// Orders accepted by the broker today.
+    pub submitted_today: usize,
+    /// Orders completely filled today.
+    pub filled_today: usize,
+    /// Orders still working at the broker.
+    pub working: usize,
+}
+
+/// State of a registered circuit breaker.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BreakerStatus {
+    /// Breaker name.
+    pub name: String,
+    /// Current state.
+    pub state: CircuitBreakerState,
+    /// Consecutive failures recorded.
+    pub failure_count: u32,
+    /// Whether an operator tripped the breaker and holds it open.
+    pub manually_open: bool,
+    /// Successful calls since startup.
+    pub total_successes: u64,
+    /// Failed calls since startup.
+    pub total_failures: u64,
+    /// Share of calls since startup that failed, from 0 to 1.
+    pub failure_rate: Decimal,
+    /// State changes since startup.
+    pub state_changes: u64,
+    /// When the state last changed.
+    pub last_state_change: Option<Timestamp>,
+}
+
+impl BreakerStatus {
+    /// Status of `breaker`, reported as `name`.
+    #[must_use]
+    pub fn of(name: impl Into<String>, breaker: &CircuitBreaker) -> Self {
+        Self {
+            name: name.into(),
+            state: breaker.state(),
+            failure_count: breaker.failure_count(),
+            manually_open: breaker.is_manually_open(),
+            total_successes: breaker.total_successes(),
+            total_failures: breaker.total_failures(),
+            failure_rate: breaker.failure_rate(),
+            state_changes: breaker.state_changes(),
+            last_state_change: breaker.last_state_change(),
+        }
+    }
+}
+
+/// Freshness of a market data feed.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FeedStatus {
+    /// Feed name.
+    pub name: String,
+    /// When the last update arrived.
+    pub last_update_at: Timestamp,
+    /// Seconds since the last update.
+    pub age_secs: i64,
+    /// Whether the feed has gone quiet for longer than the threshold.
+    pub stale: bool,
+}
+
+/// A cache that reports hit and refresh counters.
+pub trait CacheStatsSource: Send + Sync {
+    /// Current counters.
+    fn cache_stats(&self) -> CacheStats;
+}
+
+/// Counters of a registered cache.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CacheStatus {
+    /// Cache name.
+    pub name: String,
+    /// Counters since startup.
+    pub stats: CacheStats,
+}
+
+/// Outcome of the most recent reconciliation.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ReconciliationSummary {
+    /// When the reconciliation finished.
+    pub completed_at: Timestamp,
+    /// Orders checked.
+    pub checked: usize,
+    /// Orders with mismatches.
+    pub mismatches: usize,
+    /// Orders reconciled.
+    pub reconciled: usize,
+    /// Errors encountered.
+    pub errors: Vec<String>,
+}
+
+/// Point-in-time view of the session.
+#[derive(Debug, Clone)]
+pub struct SessionSnapshot {
+    /// When the snapshot was taken.
+    pub as_of: Timestamp,
+    /// Order activity (`None` if the order store could not be read).
+    pub orders: Option<OrderActivity>,
+    /// Live exposure (`None` without an exposure cache).
+    pub exposure: Option<Exposure>,
+    /// P&L realized since the engine started (`None` without an exposure cache).
+    pub realized_pnl: Option<Decimal>,
+    /// Registered circuit breakers, by name.
+    pub circuit_breakers: Vec<BreakerStatus>,
+    /// Feeds that have delivered at least one update, by name.
+    pub feeds: Vec<FeedStatus>,
+    /// Ticks dropped by the feed sanitizer, by symbol.
+    pub rejected_ticks: BTreeMap<String, RejectedTicks>,
+    /// Registered caches, by name.
+    pub caches: Vec<CacheStatus>,
+    /// Event outbox backlog (`None` without an outbox).
+    pub event_outbox: Option<OutboxStats>,
+    /// Last reconciliation, if one has run.
+    pub last_reconciliation: Option<ReconciliationSummary>,
+}
+
+/// Collects session state reported by other components.
+pub struct SessionStatsService<O>
+where
+    O: OrderRepository,
+{
+    order_repo: Arc<O>,
+    exposure: Option<Arc<ExposureCache>>,
+    event_outbox: Option<Arc<EventOutbox>>,
+    feed_sanitizer: Option<Arc<FeedSanitizer>>,
+    circuit_breakers: RwLock<BTreeMap<String, Arc<CircuitBreaker>>>,
+    feeds: RwLock<BTreeMap<String, Timestamp>>,
+    caches: RwLock<BTreeMap<String, Arc<dyn CacheStatsSource>>>,
+    last_reconciliation: RwLock<Option<ReconciliationSummary>>,
+    feed_stale_after: Duration,
+    clock: Arc<dyn Clock>,
+}
+
+impl<O> SessionStatsService<O>
+where
+    O: OrderRepository + 'static,
+{
+    /// Create a stats service reading orders from `order_repo`.
+    #[must_use]
+    pub fn new(order_repo: Arc<O>) -> Self {
+        Self {
+            order_repo,
+            exposure: None,
+            event_outbox: None,
+            feed_sanitizer: None,
+            circuit_breakers: RwLock::new(BTreeMap::new()),
+            feeds: RwLock::new(BTreeMap::new()),
+            caches: RwLock::new(BTreeMap::new()),
+            last_reconciliation: RwLock::new(None),
+            feed_stale_after: DEFAULT_FEED_STALE_AFTER,
+            clock: SystemClock::shared(),
+        }
+    }
+
+    /// Report exposure and realized P&L from a live exposure cache.
+    #[must_use]
+    pub fn with_exposure_cache(mut self, exposure: Arc<ExposureCache>) -> Self {
+        self.exposure = Some(exposure);
+        self
+    }
+
+    /// Report the backlog of an event outbox.
+    #[must_use]
+    pub fn with_event_outbox(mut self, outbox: Arc<EventOutbox>) -> Self {
+        self.event_outbox = Some(outbox);
+        self
+    }
+
+    /// Report the ticks dropped by a feed sanitizer.
+    #[must_use]
+    pub fn with_feed_sanitizer(mut self, sanitizer: Arc<FeedSanitizer>) -> Self {
+        self.feed_sanitizer = Some(sanitizer);
+        self
+    }
+
+    /// Report feeds stale after `duration` without an update.
+    #[must_use]
+    pub const fn with_feed_stale_after(mut self, duration: Duration) -> Self {
+        self.feed_stale_after = duration;
+        self
+    }
+
+    /// Use `clock` instead of the system clock.
+    #[must_use]
+    pub fn with_clock(mut self, clock: Arc<dyn Clock>) -> Self {
+        self.clock = clock;
+        self
+    }
+
+    /// Include a circuit breaker in the snapshot.
+    pub fn register_circuit_breaker(&self, name: impl Into<String>, breaker: Arc<CircuitBreaker>) {
+        self.circuit_breakers
+            .write()
+            .unwrap_or_else(PoisonError::into_inner)
+            .insert(name.into(), breaker);
+    }
+
+    /// Registered circuit breaker called `name`.
+    #[must_use]
+    pub fn circuit_breaker(&self, name: &str) -> Option<Arc<CircuitBreaker>> {
+        self.circuit_breakers
+            .read()
+            .unwrap_or_else(PoisonError::into_inner)
+            .get(name)
+            .cloned()
+    }
+
+    /// Status of every registered circuit breaker, by name.
+    #[must_use]
+    pub fn circuit_breakers(&self) -> Vec<BreakerStatus> {
+        self.circuit_breakers
+            .read()
+            .unwrap_or_else(PoisonError::into_inner)
+            .iter()
+            .map(|(name, breaker)| BreakerStatus::of(name.clone(), breaker))
+            .collect()
+    }
+
+    /// Include a cache's counters in the snapshot.
+    pub fn register_cache(&self, name: impl Into<String>, cache: Arc<dyn CacheStatsSource>) {
+        self.caches
+            .write()
+            .unwrap_or_else(PoisonError::into_inner)
+            .insert(name.into(), cache);
+    }
+
+    /// Record that `feed` delivered an update now.
+    pub fn record_feed_update(&self, feed: &str) {
+        let now = self.clock.now();
+        self.feeds
+            .write()
+            .unwrap_or_else(PoisonError::into_inner)
+            .insert(feed.to_string(), now);
+    }
+
+    /// Record the outcome of a reconciliation.
+    pub fn record_reconciliation(&self, result: &ReconciliationResult) {
+        let summary = ReconciliationSummary {
+            completed_at: self.clock.now(),
+            checked: result.total_checked,
+            mismatches: result.mismatches,
+            reconciled: result.reconciled,
+            errors: result.errors.clone(),
+        };
+        *self
+            .last_reconciliation
+            .write()
+            .unwrap_or_else(PoisonError::into_inner) = Some(summary);
+    }
+
+    /// Track the freshness of a quote stream as `feed` until shutdown.
+    pub fn watch_feed(
+        self: &Arc<Self>,
+        feed: impl Into<String>,
+        mut updates: broadcast::Receiver<QuoteUpdate>,
+        shutdown: CancellationToken,
+    ) {
+        let stats = Arc::clone(self);
+        let feed = feed.into();
+
+        tokio::spawn(async move {
+            loop {
+                tokio::select! {
+                    update = updates.recv() => match update {
+                        // A lagging receiver still means quotes are flowing
+                        Ok(_) | Err(RecvError::Lagged(_)) => stats.record_feed_update(&feed),
+                        Err(RecvError::Closed) => break,
+                    },
+                    () = shutdown.cancelled() => break,
+                }
+            }
+        });
+    }
+
+    /// Assemble the current session snapshot.
+    pub async fn snapshot(&self) -> SessionSnapshot {
+        let now = self.clock.now();
+        let event_outbox = match &self.event_outbox {
+            Some(outbox) => Some(outbox.stats().await),
+            None => None,
+        };
+
+        SessionSnapshot {
+            as_of: now,
+            orders: self.order_activity(now).await,
+            exposure: self.exposure.as_ref().map(|e| e.exposure()),
+            realized_pnl: self.exposure.as_ref().map(|e| e.realized_pnl()),
+            circuit_breakers: self.circuit_breakers(),
+            feeds: self
+                .feeds
+                .read()
+                .unwrap_or_else(PoisonError::into_inner)
+                .iter()
+                .map(|(name, last_update_at)| {
+                    let age = now.duration_since(*last_update_at);
+                    FeedStatus {
+                        name: name.clone(),
+                        last_update_at: *last_update_at,
+                        age_secs: age.num_seconds(),
+                        stale: age.to_std().unwrap_or_default() > self.feed_stale_after,
+                    }
+                })
+                .collect(),
+            rejected_ticks: self
+                .feed_sanitizer
+                .as_ref()
+                .map(|s| s.rejections())
+                .unwrap_or_default(),
+            caches: self
+                .caches
+                .read()
+                .unwrap_or_else(PoisonError::into_inner)
+                .iter()
+                .map(|(name, cache)| CacheStatus {
+                    name: name.clone(),
+                    stats: cache.cache_stats(),
+                })
+                .collect(),
+            event_outbox,
+            last_reconciliation: self
+                .last_reconciliation
+                .read()
+                .unwrap_or_else(PoisonError::into_inner)
+                .clone(),
+        }
+    }
+
+    /// Count today's orders across active and terminal states.
+    async fn order_activity(&self, now: Timestamp) -> Option<OrderActivity> {
+        let mut orders = match self.order_repo.find_active().await {
+            Ok(orders) => orders,
+            Err(e) => {
+                tracing::warn!(error = %e, "Failed to load active orders for session stats");
+                return None;
+            }
+        };
+        for status in [
+            OrderStatus::Filled,
+            OrderStatus::Canceled,
+            OrderStatus::Rejected,
+            OrderStatus::Expired,
+        ] {
+            match self.order_repo.find_by_status(status).await {
+                Ok(found) => orders.extend(found),
+                Err(e) => {
+                    tracing::warn!(error = %e, ?status, "Failed to load orders for session stats");
+                    return None;
+                }
+            }
+        }
+
+        Some(count_activity(&orders, now))
+    }
+}
+
+/// Count orders submitted and filled on the UTC day of `now`.
+fn count_activity(orders: &[Order], now: Timestamp) -> OrderActivity {
+    let today = now.as_datetime().date_naive();
+    let is_today = |ts: Timestamp| ts.as_datetime().date_naive() == today;
+
+    let mut activity = OrderActivity::default();
+    for order in orders.iter().filter(|o| o.broker_order_id().is_some()) {
+        if is_today(order.created_at()) {
+            activity.submitted_today += 1;
+        }
+        if order.status() == OrderStatus::Filled
+            && order.partial_fill().last_fill_at().is_some_and(is_today)
+        {
+            activity.filled_today += 1;
+        }
+        if !order.status().is_terminal() {
+            activity.working += 1;
+        }
+    }
+    activity
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::application::ports::SimulatedClock;
+    use crate::domain::order_execution::aggregate::CreateOrderCommand;
+    use crate::domain::order_execution::value_objects::{
+        FillReport, OrderPurpose, OrderSide, OrderType, TimeInForce,
+    };
+    use crate::domain::shared::{BrokerId, Money, Quantity, Symbol};
+    use crate::infrastructure::persistence::InMemoryOrderRepository;
+
+    struct FixedCache;
+
+    impl CacheStatsSource for FixedCache {
+        fn cache_stats(&self) -> CacheStats {
+            CacheStats {
+                entries: 1,
+                hits: 3,
+                misses: 1,
+                ..CacheStats::default()
+            }
+        }
+    }
+
+    fn accepted_order(symbol: &str) -> Order {
+        let mut order = Order::new(CreateOrderCommand {
+            symbol: Symbol::new(symbol),
+            side: OrderSide::Buy,
+            order_type: OrderType::Market,
+            quantity: Quantity::from_i64(10),
+            limit_price: None,
+            stop_price: None,
+            time_in_force: TimeInForce::Day,
+            purpose: OrderPurpose::Entry,
+            legs: vec![],
+        })
+        .unwrap();
+        order
+            .accept(BrokerId::new(format!("broker-{symbol}")))
+            .unwrap();
+        order
+    }
+
+    #[tokio::test]
+    async fn snapshot_counts_orders_and_reports_components() {
+        let order_repo = Arc::new(InMemoryOrderRepository::new());
+        let mut filled = accepted_order("AAPL");
+        filled
+            .apply_fill(FillReport::new(
+                "fill-1",
+                Quantity::from_i64(10),
+                Money::usd(150.0),
+                Timestamp::now(),
+                "NYSE",
+            ))
+            .unwrap();
+        order_repo.add(filled);
+        order_repo.add(accepted_order("MSFT"));
+
+        let clock = Arc::new(SimulatedClock::new(Timestamp::now()));
+        let sanitizer = Arc::new(FeedSanitizer::default().with_clock(Arc::clone(&clock) as _));
+        sanitizer
+            .check_trade("AAPL", Decimal::ZERO, Timestamp::now())
+            .unwrap_err();
+        let stats = SessionStatsService::new(order_repo)
+            .with_exposure_cache(Arc::new(ExposureCache::new()))
+            .with_feed_sanitizer(sanitizer)
+            .with_clock(Arc::clone(&clock) as Arc<dyn Clock>);
+        let breaker = Arc::new(CircuitBreaker::new());
+        breaker.record_failure();
+        stats.register_circuit_breaker("exit_orders", breaker);
+        stats.record_feed_update("quotes");
+        stats.register_cache("option_chains", Arc::new(FixedCache));
+        stats.record_reconciliation(&ReconciliationResult {
+            total_checked: 2,
+            mismatches: 1,
+            reconciled: 1,
+            order_results: vec![],
+            errors: vec![],
+        });
+
+        clock.advance(Duration::from_secs(45));
+        let snapshot = stats.snapshot().await;
+
+        assert_eq!(
+            snapshot.orders,
+            Some(OrderActivity {
+                submitted_today: 2,
+                filled_today: 1,
+                working: 1,
+            })
+        );
+        assert_eq!(snapshot.realized_pnl, Some(Decimal::ZERO));
+        assert_eq!(snapshot.circuit_breakers[0].failure_count, 1);
+        assert_eq!(snapshot.feeds[0].age_secs, 45);
+        assert!(snapshot.feeds[0].stale);
+        assert_eq!(snapshot.rejected_ticks["AAPL"].non_positive_price, 1);
+        assert_eq!(snapshot.caches[0].name, "option_chains");
+        assert_eq!(snapshot.caches[0].stats.hits, 3);
+        assert_eq!(snapshot.last_reconciliation.unwrap().mismatches, 1);
+    }
+}
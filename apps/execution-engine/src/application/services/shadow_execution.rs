@@ -0,0 +1,403 @@
+//! Shadow Execution
+//!
+//! Evaluates a second set of execution profiles alongside the live ones so
+//! a tactic change can be A/B tested in LIVE without risking capital. For
+//! every submitted order the shadow configuration selects its own tactic,
+//! and a would-be fill is simulated against the quote seen at submission.
+//! Nothing is sent to the broker. The report compares those simulated fills
+//! with the fills the live order actually got.
+//!
+//! Fills are simulated at the touch: a passive limit fills at its posted
+//! price, an aggressive limit pays the far side of the spread, and
+//! scheduled tactics (TWAP, VWAP, iceberg, adaptive) fill at the arrival
+//! mid. Slippage is measured against the arrival mid, in basis points, with
+//! positive values a cost.
+
+use std::collections::VecDeque;
+use std::sync::{Mutex, PoisonError, RwLock};
+
+use rust_decimal::Decimal;
+
+use crate::application::ports::Quote;
+use crate::domain::execution_tactics::{
+    ExecutionProfile, ExecutionProfiles, PassiveLimitConfig, StrategyFamily, TacticType,
+};
+use crate::domain::order_execution::aggregate::Order;
+use crate::domain::order_execution::errors::OrderError;
+use crate::domain::order_execution::repository::OrderRepository;
+use crate::domain::order_execution::value_objects::{OrderPurpose, OrderSide};
+use crate::domain::shared::{OrderId, Timestamp};
+
+/// Default number of shadowed orders kept for the report.
+pub const DEFAULT_SHADOW_CAPACITY: usize = 1000;
+
+/// A live order and what the shadow configuration would have done with it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ShadowOrder {
+    /// Live order ID.
+    pub order_id: String,
+    /// Symbol.
+    pub symbol: String,
+    /// Side.
+    pub side: OrderSide,
+    /// Purpose.
+    pub purpose: OrderPurpose,
+    /// Ordered quantity.
+    pub quantity: Decimal,
+    /// Tactic selected by the live profile.
+    pub live_tactic: TacticType,
+    /// Tactic selected by the shadow profile.
+    pub shadow_tactic: TacticType,
+    /// Quote mid at submission.
+    pub arrival_mid: Decimal,
+    /// Simulated fill price of the shadow tactic.
+    pub shadow_price: Decimal,
+    /// When the order was submitted.
+    pub recorded_at: Timestamp,
+}
+
+/// A shadowed order with its realized fill.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ShadowComparison {
+    /// The shadowed order.
+    pub order: ShadowOrder,
+    /// Filled quantity of the live order.
+    pub filled_qty: Decimal,
+    /// Average fill price of the live order (`None` until the first fill).
+    pub realized_price: Option<Decimal>,
+    /// Live slippage against the arrival mid, in basis points.
+    pub realized_slippage_bps: Option<Decimal>,
+    /// Shadow slippage against the arrival mid, in basis points.
+    pub shadow_slippage_bps: Decimal,
+}
+
+/// Realized versus shadow execution.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ShadowReport {
+    /// When the report was assembled.
+    pub as_of: Timestamp,
+    /// Shadowed orders, oldest first.
+    pub orders: Vec<ShadowComparison>,
+    /// Orders with a realized fill to compare against.
+    pub compared: usize,
+    /// Mean live slippage over compared orders, in basis points.
+    pub avg_realized_slippage_bps: Option<Decimal>,
+    /// Mean shadow slippage over compared orders, in basis points.
+    pub avg_shadow_slippage_bps: Option<Decimal>,
+    /// Compared orders where the shadow fill was cheaper.
+    pub shadow_better: usize,
+}
+
+/// Runs a shadow execution configuration next to the live one.
+pub struct ShadowExecution {
+    profiles: RwLock<ExecutionProfiles>,
+    capacity: usize,
+    orders: Mutex<VecDeque<ShadowOrder>>,
+}
+
+impl ShadowExecution {
+    /// Shadow the live configuration with `profiles`.
+    #[must_use]
+    pub const fn new(profiles: ExecutionProfiles) -> Self {
+        Self {
+            profiles: RwLock::new(profiles),
+            capacity: DEFAULT_SHADOW_CAPACITY,
+            orders: Mutex::new(VecDeque::new()),
+        }
+    }
+
+    /// Keep at most `capacity` shadowed orders, dropping the oldest.
+    #[must_use]
+    pub fn with_capacity(mut self, capacity: usize) -> Self {
+        self.capacity = capacity.max(1);
+        self
+    }
+
+    /// Shadow later orders with `profiles` (e.g. after a config reload).
+    pub fn set_profiles(&self, profiles: ExecutionProfiles) {
+        *self
+            .profiles
+            .write()
+            .unwrap_or_else(PoisonError::into_inner) = profiles;
+    }
+
+    /// Simulate the shadow tactic for a submitted order.
+    ///
+    /// `live` is the profile the order was submitted under and `quote` the
+    /// quote seen at submission. Order size relative to volume is not known
+    /// here, so both tactics are selected as for a small order.
+    pub fn record(
+        &self,
+        order: &Order,
+        family: Option<StrategyFamily>,
+        live: &ExecutionProfile,
+        quote: &Quote,
+    ) {
+        let spread_bps = quote.spread_bps();
+        let purpose = order.partial_fill().order_purpose();
+        let live_tactic = live.select_tactic(purpose, Decimal::ZERO, spread_bps);
+        let shadow_tactic = self
+            .profiles
+            .read()
+            .unwrap_or_else(PoisonError::into_inner)
+            .for_family(family)
+            .select_tactic(purpose, Decimal::ZERO, spread_bps);
+
+        let shadow = ShadowOrder {
+            order_id: order.id().to_string(),
+            symbol: order.symbol().to_string(),
+            side: order.side(),
+            purpose,
+            quantity: order.quantity().amount(),
+            live_tactic,
+            shadow_tactic,
+            arrival_mid: quote.mid(),
+            shadow_price: simulated_fill_price(shadow_tactic, order.side(), quote),
+            recorded_at: Timestamp::now(),
+        };
+        tracing::debug!(
+            order_id = %shadow.order_id,
+            symbol = %shadow.symbol,
+            live_tactic = %live_tactic,
+            shadow_tactic = %shadow_tactic,
+            shadow_price = %shadow.shadow_price,
+            "Shadow execution simulated"
+        );
+
+        let mut orders = self.orders.lock().unwrap_or_else(PoisonError::into_inner);
+        if orders.len() >= self.capacity {
+            orders.pop_front();
+        }
+        orders.push_back(shadow);
+    }
+
+    /// Number of shadowed orders held.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.orders
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .len()
+    }
+
+    /// Check if no order has been shadowed yet.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Compare shadowed orders with their realized fills.
+    ///
+    /// Averages cover only orders that have filled, so both sides are
+    /// measured over the same orders.
+    ///
+    /// # Errors
+    ///
+    /// Returns error if the order store cannot be read.
+    pub async fn report<O: OrderRepository>(
+        &self,
+        order_repo: &O,
+    ) -> Result<ShadowReport, OrderError> {
+        let shadowed: Vec<ShadowOrder> = self
+            .orders
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .iter()
+            .cloned()
+            .collect();
+
+        let mut report = ShadowReport {
+            as_of: Timestamp::now(),
+            orders: Vec::with_capacity(shadowed.len()),
+            compared: 0,
+            avg_realized_slippage_bps: None,
+            avg_shadow_slippage_bps: None,
+            shadow_better: 0,
+        };
+        let mut realized_total = Decimal::ZERO;
+        let mut shadow_total = Decimal::ZERO;
+        for shadow in shadowed {
+            let live = order_repo
+                .find_by_id(&OrderId::new(&shadow.order_id))
+                .await?;
+            let filled_qty = live
+                .as_ref()
+                .map_or(Decimal::ZERO, |o| o.partial_fill().cum_qty().amount());
+            let realized_price = live
+                .filter(|_| !filled_qty.is_zero())
+                .map(|o| o.partial_fill().avg_px().amount());
+            let realized_slippage_bps = realized_price
+                .and_then(|price| shadow.side.slippage_bps(shadow.arrival_mid, price));
+            let shadow_slippage_bps = shadow
+                .side
+                .slippage_bps(shadow.arrival_mid, shadow.shadow_price)
+                .unwrap_or_default();
+
+            if let Some(realized) = realized_slippage_bps {
+                report.compared += 1;
+                realized_total += realized;
+                shadow_total += shadow_slippage_bps;
+                if shadow_slippage_bps < realized {
+                    report.shadow_better += 1;
+                }
+            }
+            report.orders.push(ShadowComparison {
+                order: shadow,
+                filled_qty,
+                realized_price,
+                realized_slippage_bps,
+                shadow_slippage_bps,
+            });
+        }
+
+        if report.compared > 0 {
+            let compared = Decimal::from(report.compared);
+            report.avg_realized_slippage_bps = Some((realized_total / compared).round_dp(2));
+            report.avg_shadow_slippage_bps = Some((shadow_total / compared).round_dp(2));
+        }
+        Ok(report)
+    }
+}
+
+/// Price a tactic would fill at against `quote`.
+fn simulated_fill_price(tactic: TacticType, side: OrderSide, quote: &Quote) -> Decimal {
+    let passive = PassiveLimitConfig::default();
+    match (tactic, side) {
+        (TacticType::PassiveLimit, OrderSide::Buy) => {
+            passive.calculate_buy_price(quote.bid, quote.ask)
+        }
+        (TacticType::PassiveLimit, OrderSide::Sell) => {
+            passive.calculate_sell_price(quote.bid, quote.ask)
+        }
+        (TacticType::AggressiveLimit, OrderSide::Buy) => quote.ask,
+        (TacticType::AggressiveLimit, OrderSide::Sell) => quote.bid,
+        (
+            TacticType::Iceberg
+            | TacticType::Twap
+            | TacticType::Vwap
+            | TacticType::Adaptive
+            | TacticType::NetLadder,
+            _,
+        ) => quote.mid(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::execution_tactics::{TacticUrgency, UrgencyMapping};
+    use crate::domain::order_execution::aggregate::CreateOrderCommand;
+    use crate::domain::order_execution::value_objects::{FillReport, OrderType, TimeInForce};
+    use crate::domain::shared::{BrokerId, Money, Quantity, Symbol};
+    use crate::infrastructure::persistence::InMemoryOrderRepository;
+    use rust_decimal_macros::dec;
+
+    fn buy(symbol: &str) -> Order {
+        let mut order = Order::new(CreateOrderCommand {
+            symbol: Symbol::new(symbol),
+            side: OrderSide::Buy,
+            order_type: OrderType::Limit,
+            quantity: Quantity::from_i64(100),
+            limit_price: Some(Money::new(dec!(100.10))),
+            stop_price: None,
+            time_in_force: TimeInForce::Day,
+            purpose: OrderPurpose::Entry,
+            legs: vec![],
+        })
+        .unwrap();
+        order.accept(BrokerId::new(format!("b-{symbol}"))).unwrap();
+        order
+    }
+
+    fn profile(tactic: TacticType) -> ExecutionProfile {
+        ExecutionProfile {
+            default_tactic: tactic,
+            urgency: UrgencyMapping {
+                entry: TacticUrgency::Normal,
+                exit: TacticUrgency::High,
+            },
+            ..ExecutionProfile::default()
+        }
+    }
+
+    fn quote(symbol: &str) -> Quote {
+        Quote::new(
+            Symbol::new(symbol),
+            dec!(99.90),
+            dec!(100.10),
+            dec!(500),
+            dec!(500),
+        )
+    }
+
+    #[test]
+    fn simulated_fills_sit_at_the_touch() {
+        let quote = quote("AAPL");
+        let price = |tactic, side| simulated_fill_price(tactic, side, &quote);
+
+        assert_eq!(price(TacticType::PassiveLimit, OrderSide::Buy), dec!(99.90));
+        assert_eq!(
+            price(TacticType::PassiveLimit, OrderSide::Sell),
+            dec!(100.10)
+        );
+        assert_eq!(
+            price(TacticType::AggressiveLimit, OrderSide::Buy),
+            dec!(100.10)
+        );
+        assert_eq!(
+            price(TacticType::AggressiveLimit, OrderSide::Sell),
+            dec!(99.90)
+        );
+        assert_eq!(price(TacticType::Twap, OrderSide::Buy), dec!(100));
+    }
+
+    #[tokio::test]
+    async fn report_compares_realized_and_shadow_fills() {
+        let shadow =
+            ShadowExecution::new(ExecutionProfiles::new(profile(TacticType::PassiveLimit)));
+        let live = profile(TacticType::AggressiveLimit);
+        let order_repo = InMemoryOrderRepository::new();
+
+        let mut filled = buy("AAPL");
+        shadow.record(&filled, None, &live, &quote("AAPL"));
+        filled
+            .apply_fill(FillReport::new(
+                "f-1",
+                Quantity::from_i64(100),
+                Money::new(dec!(100.10)),
+                Timestamp::now(),
+                "TEST",
+            ))
+            .unwrap();
+        order_repo.save(&filled).await.unwrap();
+
+        let working = buy("MSFT");
+        shadow.record(&working, None, &live, &quote("MSFT"));
+        order_repo.save(&working).await.unwrap();
+
+        let report = shadow.report(&order_repo).await.unwrap();
+
+        assert_eq!(report.orders.len(), 2);
+        assert_eq!(report.compared, 1);
+        assert_eq!(report.shadow_better, 1);
+        assert_eq!(report.avg_realized_slippage_bps, Some(dec!(10)));
+        assert_eq!(report.avg_shadow_slippage_bps, Some(dec!(-10)));
+
+        let first = &report.orders[0];
+        assert_eq!(first.order.live_tactic, TacticType::AggressiveLimit);
+        assert_eq!(first.order.shadow_tactic, TacticType::PassiveLimit);
+        assert_eq!(first.realized_price, Some(dec!(100.10)));
+        assert!(report.orders[1].realized_price.is_none());
+    }
+
+    #[test]
+    fn oldest_orders_are_dropped_past_capacity() {
+        let shadow = ShadowExecution::new(ExecutionProfiles::default()).with_capacity(1);
+        let live = ExecutionProfile::default();
+
+        shadow.record(&buy("AAPL"), None, &live, &quote("AAPL"));
+        shadow.record(&buy("MSFT"), None, &live, &quote("MSFT"));
+
+        assert_eq!(shadow.len(), 1);
+    }
+}
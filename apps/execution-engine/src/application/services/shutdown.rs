@@ -0,0 +1,231 @@
+//! Shutdown Coordinator
+//!
+//! Drains order submission on shutdown. Each plan holds an [`InFlight`]
+//! guard from admission until its last broker call returns. Once
+//! [`ShutdownCoordinator::drain`] starts, new plans are rejected with a
+//! [`SHUTTING_DOWN`] violation, and the coordinator waits (up to a timeout)
+//! for the plans already admitted to finish, so the process does not exit
+//! between a broker submission and recording its acknowledgement.
+
+use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+use std::time::Duration;
+
+use tokio::sync::Notify;
+
+use crate::domain::order_execution::aggregate::Order;
+use crate::domain::order_execution::repository::OrderRepository;
+use crate::domain::risk_management::value_objects::ConstraintViolation;
+
+/// Violation code for plans submitted while the engine is shutting down.
+pub const SHUTTING_DOWN: &str = "SHUTTING_DOWN";
+
+/// State of the engine at the end of a drain.
+#[derive(Debug, Clone, Default)]
+pub struct ShutdownReport {
+    /// Whether every in-flight plan finished before the timeout.
+    pub drained: bool,
+    /// Plans still in flight when the timeout expired.
+    pub in_flight: usize,
+    /// Orders still working at the broker.
+    pub working_orders: Vec<Order>,
+}
+
+/// Tracks in-flight plans and stops admitting new ones on shutdown.
+#[derive(Debug, Default)]
+pub struct ShutdownCoordinator {
+    draining: AtomicBool,
+    in_flight: AtomicUsize,
+    idle: Notify,
+}
+
+/// Admission of one plan; the plan stays in flight until this is dropped.
+#[derive(Debug)]
+pub struct InFlight<'a> {
+    coordinator: &'a ShutdownCoordinator,
+}
+
+impl Drop for InFlight<'_> {
+    fn drop(&mut self) {
+        if self.coordinator.in_flight.fetch_sub(1, Ordering::AcqRel) == 1 {
+            self.coordinator.idle.notify_waiters();
+        }
+    }
+}
+
+impl ShutdownCoordinator {
+    /// Create a coordinator that admits plans.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Admit a plan.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`SHUTTING_DOWN`] violation once draining has started.
+    pub fn admit(&self) -> Result<InFlight<'_>, Box<ConstraintViolation>> {
+        // Counted before the check, so a drain that starts in between waits
+        // for this plan rather than missing it
+        self.in_flight.fetch_add(1, Ordering::AcqRel);
+        let guard = InFlight { coordinator: self };
+        if self.is_draining() {
+            return Err(Box::new(ConstraintViolation::critical(
+                SHUTTING_DOWN,
+                "Engine is shutting down, not accepting new plans",
+            )));
+        }
+        Ok(guard)
+    }
+
+    /// Stop admitting plans.
+    pub fn stop_accepting(&self) {
+        if !self.draining.swap(true, Ordering::AcqRel) {
+            tracing::info!(
+                in_flight = self.in_flight(),
+                "Shutdown started, no longer accepting plans"
+            );
+        }
+    }
+
+    /// Check if plans are no longer admitted.
+    #[must_use]
+    pub fn is_draining(&self) -> bool {
+        self.draining.load(Ordering::Acquire)
+    }
+
+    /// Plans admitted and not yet finished.
+    #[must_use]
+    pub fn in_flight(&self) -> usize {
+        self.in_flight.load(Ordering::Acquire)
+    }
+
+    /// Stop admitting plans and wait up to `timeout` for in-flight plans to
+    /// finish.
+    ///
+    /// Returns `true` if nothing is left in flight.
+    pub async fn drain(&self, timeout: Duration) -> bool {
+        self.stop_accepting();
+        let idle = async {
+            loop {
+                let notified = self.idle.notified();
+                if self.in_flight() == 0 {
+                    return;
+                }
+                notified.await;
+            }
+        };
+        tokio::time::timeout(timeout, idle).await.is_ok()
+    }
+
+    /// Drain, then log the orders left working at the broker.
+    pub async fn shutdown<O: OrderRepository>(
+        &self,
+        order_repo: &O,
+        timeout: Duration,
+    ) -> ShutdownReport {
+        let drained = self.drain(timeout).await;
+        let in_flight = self.in_flight();
+        if !drained {
+            tracing::warn!(
+                in_flight,
+                timeout_secs = timeout.as_secs(),
+                "Shutdown timeout expired with plans still in flight"
+            );
+        }
+
+        let working_orders = order_repo.find_active().await.unwrap_or_else(|e| {
+            tracing::warn!(error = %e, "Failed to list working orders at shutdown");
+            Vec::new()
+        });
+        for order in &working_orders {
+            tracing::info!(
+                order_id = %order.id(),
+                broker_order_id = order.broker_order_id().map(ToString::to_string),
+                symbol = %order.symbol(),
+                side = %order.side(),
+                status = %order.status(),
+                filled = %order.partial_fill().cum_qty(),
+                quantity = %order.quantity(),
+                "Order still working at shutdown"
+            );
+        }
+        tracing::info!(
+            drained,
+            in_flight,
+            working_orders = working_orders.len(),
+            "Shutdown summary"
+        );
+
+        ShutdownReport {
+            drained,
+            in_flight,
+            working_orders,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use super::*;
+    use crate::infrastructure::persistence::InMemoryOrderRepository;
+
+    #[test]
+    fn rejects_plans_once_draining() {
+        let coordinator = ShutdownCoordinator::new();
+        let admitted = coordinator.admit().unwrap();
+        assert_eq!(coordinator.in_flight(), 1);
+
+        coordinator.stop_accepting();
+        let violation = coordinator.admit().unwrap_err();
+        assert_eq!(violation.code, SHUTTING_DOWN);
+        assert_eq!(coordinator.in_flight(), 1);
+
+        drop(admitted);
+        assert_eq!(coordinator.in_flight(), 0);
+    }
+
+    #[tokio::test]
+    async fn drain_waits_for_in_flight_plans() {
+        let coordinator = Arc::new(ShutdownCoordinator::new());
+        let (admitted_tx, admitted_rx) = tokio::sync::oneshot::channel();
+        let (release_tx, release_rx) = tokio::sync::oneshot::channel::<()>();
+        let plan = {
+            let coordinator = Arc::clone(&coordinator);
+            tokio::spawn(async move {
+                let _guard = coordinator.admit().unwrap();
+                admitted_tx.send(()).unwrap();
+                release_rx.await.unwrap();
+            })
+        };
+        admitted_rx.await.unwrap();
+
+        let drain = {
+            let coordinator = Arc::clone(&coordinator);
+            tokio::spawn(async move { coordinator.drain(Duration::from_secs(5)).await })
+        };
+        tokio::task::yield_now().await;
+        assert!(coordinator.is_draining());
+        assert!(!drain.is_finished());
+
+        release_tx.send(()).unwrap();
+        plan.await.unwrap();
+        assert!(drain.await.unwrap());
+    }
+
+    #[tokio::test]
+    async fn shutdown_reports_timeout_and_working_orders() {
+        let coordinator = ShutdownCoordinator::new();
+        let _stuck = coordinator.admit().unwrap();
+
+        let report = coordinator
+            .shutdown(&InMemoryOrderRepository::new(), Duration::from_millis(10))
+            .await;
+
+        assert!(!report.drained);
+        assert_eq!(report.in_flight, 1);
+        assert!(report.working_orders.is_empty());
+    }
+}
@@ -0,0 +1,280 @@
+//! Spread Builder Service
+//!
+//! Fetches the option chain for a spread intent and hands its quoted
+//! contracts to the domain [`SpreadBuilder`]. Contracts without a provider
+//! delta get one from Black-Scholes, using the provider's implied volatility
+//! or the volatility implied by the quote mid.
+
+use std::sync::Arc;
+
+use chrono::{Days, NaiveDate, Utc};
+use rust_decimal::Decimal;
+use rust_decimal::prelude::ToPrimitive;
+
+use super::DEFAULT_RISK_FREE_RATE;
+use crate::application::ports::{
+    MarketDataError, MarketDataPort, OptionChainData, OptionChainFilter, OptionType,
+};
+use crate::domain::option_position::services::{
+    black_scholes_delta, implied_volatility, years_between,
+};
+use crate::domain::option_position::{
+    ChainOption, OptionContract, OptionPositionError, OptionRight, SpreadBuilder, SpreadIntent,
+    SpreadQuote, SpreadStrategy,
+};
+use crate::domain::shared::{ClassifiedError, ErrorKind, Symbol};
+
+/// Spread construction errors.
+#[derive(Debug, thiserror::Error)]
+pub enum SpreadBuildError {
+    /// The option chain could not be fetched.
+    #[error(transparent)]
+    MarketData(#[from] MarketDataError),
+
+    /// The intent cannot be built from the chain.
+    #[error(transparent)]
+    Spread(#[from] OptionPositionError),
+}
+
+impl ClassifiedError for SpreadBuildError {
+    fn code(&self) -> &str {
+        match self {
+            Self::MarketData(e) => e.code(),
+            Self::Spread(_) => "SPREAD_NOT_BUILDABLE",
+        }
+    }
+
+    fn kind(&self) -> ErrorKind {
+        match self {
+            Self::MarketData(e) => e.kind(),
+            Self::Spread(_) => ErrorKind::FailedPrecondition,
+        }
+    }
+}
+
+/// Builds spreads from intents against a [`MarketDataPort`].
+pub struct SpreadBuilderService<M>
+where
+    M: MarketDataPort,
+{
+    market_data: Arc<M>,
+    risk_free_rate: f64,
+}
+
+impl<M> SpreadBuilderService<M>
+where
+    M: MarketDataPort,
+{
+    /// Create a service using [`DEFAULT_RISK_FREE_RATE`] for computed deltas.
+    #[must_use]
+    pub const fn new(market_data: Arc<M>) -> Self {
+        Self {
+            market_data,
+            risk_free_rate: DEFAULT_RISK_FREE_RATE,
+        }
+    }
+
+    /// Use `rate` (annual, continuously compounded) for computed deltas.
+    #[must_use]
+    pub const fn with_risk_free_rate(mut self, rate: f64) -> Self {
+        self.risk_free_rate = rate;
+        self
+    }
+
+    /// Select, validate and price the legs for `intent`.
+    ///
+    /// # Errors
+    ///
+    /// Returns error if the chain cannot be fetched or the intent cannot be
+    /// built from it.
+    pub async fn build(&self, intent: &SpreadIntent) -> Result<SpreadQuote, SpreadBuildError> {
+        let filter = chain_filter(intent, Utc::now().date_naive());
+        let chain = self
+            .market_data
+            .get_filtered_option_chain(&intent.underlying, &filter)
+            .await?;
+
+        let as_of = chain.as_of.as_datetime().date_naive();
+        Ok(SpreadBuilder::build(
+            intent,
+            chain.underlying_price,
+            as_of,
+            &chain_options(&chain, self.risk_free_rate),
+        )?)
+    }
+}
+
+/// Chain filter covering the intent's DTE range.
+fn chain_filter(intent: &SpreadIntent, today: NaiveDate) -> OptionChainFilter {
+    let date = |days: u32| {
+        today
+            .checked_add_days(Days::new(days.into()))
+            .map(|date| date.format("%Y-%m-%d").to_string())
+    };
+    OptionChainFilter {
+        min_expiration: date(intent.min_dte),
+        max_expiration: date(intent.max_dte),
+        option_type: match intent.strategy {
+            SpreadStrategy::Vertical(OptionRight::Call) => Some(OptionType::Call),
+            SpreadStrategy::Vertical(OptionRight::Put) => Some(OptionType::Put),
+            _ => None,
+        },
+        ..OptionChainFilter::default()
+    }
+}
+
+/// Quoted contracts of `chain` with their deltas.
+#[must_use]
+pub fn chain_options(chain: &OptionChainData, rate: f64) -> Vec<ChainOption> {
+    let spot = chain.underlying_price.to_f64().unwrap_or_default();
+    let as_of = chain.as_of.as_datetime().date_naive();
+
+    chain
+        .options
+        .iter()
+        .filter_map(|option| {
+            let quote = option.quote.as_ref()?;
+            let contract = &option.contract;
+            let expiration = NaiveDate::parse_from_str(&contract.expiration, "%Y-%m-%d").ok()?;
+            let right = match contract.option_type {
+                OptionType::Call => OptionRight::Call,
+                OptionType::Put => OptionRight::Put,
+            };
+            let symbol = if quote.symbol.is_empty() {
+                occ_symbol(&chain.underlying, expiration, right, contract.strike)
+            } else {
+                quote.symbol.clone()
+            };
+
+            let years = years_between(as_of, expiration);
+            let strike = contract.strike.to_f64()?;
+            let delta = option
+                .greeks
+                .as_ref()
+                .and_then(|greeks| greeks.delta)
+                .or_else(|| {
+                    let mid = ((quote.bid + quote.ask) / Decimal::TWO).to_f64()?;
+                    let volatility = option
+                        .implied_volatility
+                        .or_else(|| implied_volatility(right, mid, spot, strike, years, rate))?;
+                    Some(black_scholes_delta(
+                        right, spot, strike, years, rate, volatility,
+                    ))
+                });
+
+            Some(ChainOption {
+                contract: OptionContract::new(
+                    Symbol::new(symbol),
+                    chain.underlying.clone(),
+                    contract.strike,
+                    expiration,
+                    right,
+                ),
+                bid: quote.bid,
+                ask: quote.ask,
+                delta,
+            })
+        })
+        .collect()
+}
+
+/// Compact OCC symbol, e.g. `AAPL250117C00150000`.
+fn occ_symbol(
+    underlying: &str,
+    expiration: NaiveDate,
+    right: OptionRight,
+    strike: Decimal,
+) -> String {
+    let right = match right {
+        OptionRight::Call => 'C',
+        OptionRight::Put => 'P',
+    };
+    let strike = (strike * Decimal::ONE_THOUSAND)
+        .to_u64()
+        .unwrap_or_default();
+    format!(
+        "{underlying}{}{right}{strike:08}",
+        expiration.format("%y%m%d")
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::application::ports::{MarketQuote, OptionGreeks, OptionQuote};
+    use crate::domain::option_position::PositionSide;
+    use crate::domain::shared::Timestamp;
+    use rust_decimal_macros::dec;
+
+    fn option(strike: Decimal, symbol: &str, delta: Option<f64>) -> OptionQuote {
+        OptionQuote {
+            contract: crate::application::ports::OptionContract {
+                underlying: "AAPL".to_string(),
+                expiration: "2025-02-21".to_string(),
+                strike,
+                option_type: OptionType::Call,
+            },
+            quote: Some(MarketQuote {
+                symbol: symbol.to_string(),
+                bid: dec!(4.90),
+                ask: dec!(5.10),
+                bid_size: 10,
+                ask_size: 10,
+                last: Decimal::ZERO,
+                last_size: 0,
+                volume: 0,
+                timestamp: Timestamp::now(),
+            }),
+            implied_volatility: None,
+            greeks: delta.map(|delta| OptionGreeks {
+                delta: Some(delta),
+                gamma: None,
+                theta: None,
+                vega: None,
+                rho: None,
+            }),
+            open_interest: 0,
+        }
+    }
+
+    #[test]
+    fn chain_options_fill_symbols_and_deltas() {
+        let chain = OptionChainData {
+            underlying: "AAPL".to_string(),
+            underlying_price: dec!(150),
+            options: vec![
+                option(dec!(150), "AAPL250221C00150000", Some(0.52)),
+                option(dec!(152.5), "", None),
+            ],
+            as_of: Timestamp::parse("2025-01-21T15:00:00Z").unwrap(),
+        };
+
+        let options = chain_options(&chain, DEFAULT_RISK_FREE_RATE);
+
+        assert_eq!(options.len(), 2);
+        assert_eq!(options[0].delta, Some(0.52));
+        assert_eq!(options[1].contract.symbol().as_str(), "AAPL250221C00152500");
+        let delta = options[1].delta.unwrap();
+        assert!(delta > 0.3 && delta < 0.6, "{delta}");
+    }
+
+    #[test]
+    fn chain_filter_covers_dte_range() {
+        let intent = SpreadIntent {
+            underlying: "SPY".to_string(),
+            strategy: SpreadStrategy::Vertical(OptionRight::Put),
+            side: PositionSide::Short,
+            target_delta: 0.3,
+            min_dte: 30,
+            max_dte: 45,
+            wing_width: Some(dec!(5)),
+            quantity: Decimal::ONE,
+        };
+
+        let filter = chain_filter(&intent, NaiveDate::from_ymd_opt(2025, 1, 2).unwrap());
+
+        assert_eq!(filter.min_expiration.as_deref(), Some("2025-02-01"));
+        assert_eq!(filter.max_expiration.as_deref(), Some("2025-02-16"));
+        assert_eq!(filter.option_type, Some(OptionType::Put));
+    }
+}
@@ -0,0 +1,565 @@
+//! Stop Adjuster
+//!
+//! Moves a position's stop-loss and take-profit levels after entry, so the
+//! decision layer can tighten stops intraday without resubmitting a plan.
+//!
+//! The levels tracked by the position monitor are updated in place. A
+//! broker-held protective stop for the symbol is canceled and re-placed at
+//! the new stop price, keeping its side, remaining quantity, time in force
+//! and cycle. Take-profit levels are enforced by the monitor only.
+
+use std::sync::Arc;
+
+use parking_lot::RwLock;
+use rust_decimal::Decimal;
+use thiserror::Error;
+
+use super::KillSwitch;
+use crate::application::dto::{CreateOrderDto, SubmitOrdersRequestDto};
+use crate::application::ports::{
+    BrokerPort, DecisionContext, EventPublisherPort, RiskRepositoryPort, ThesisState,
+};
+use crate::application::use_cases::{CancelOrdersUseCase, SubmitOrdersUseCase};
+use crate::domain::order_execution::aggregate::Order;
+use crate::domain::order_execution::repository::OrderRepository;
+use crate::domain::order_execution::value_objects::{CancelReason, OrderPurpose, OrderType};
+use crate::domain::shared::{ClassifiedError, ErrorKind, InstrumentId, OrderId};
+use crate::domain::stop_enforcement::{PriceMonitor, StopEnforcementError};
+
+/// New levels for a position. Unset levels are left unchanged.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct StopAdjustment {
+    /// New stop-loss price.
+    pub stop_loss: Option<Decimal>,
+    /// New take-profit price.
+    pub take_profit: Option<Decimal>,
+    /// Why the levels are moving (recorded on replacement orders).
+    pub reason: Option<String>,
+}
+
+/// Outcome of adjusting a position's levels.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct StopAdjustmentReport {
+    /// Symbol adjusted.
+    pub symbol: String,
+    /// Monitored positions whose levels changed.
+    pub monitored: Vec<String>,
+    /// Protective stop orders canceled.
+    pub canceled: Vec<String>,
+    /// Replacement stop orders submitted.
+    pub submitted: Vec<String>,
+    /// Errors replacing broker orders.
+    pub errors: Vec<String>,
+}
+
+impl StopAdjustmentReport {
+    /// Whether every broker order was replaced without error.
+    #[must_use]
+    pub const fn is_success(&self) -> bool {
+        self.errors.is_empty()
+    }
+}
+
+/// Stop adjustment errors.
+#[derive(Debug, Error)]
+pub enum StopAdjustError {
+    /// Neither a stop nor a target was given.
+    #[error("at least one of stop_loss or take_profit is required")]
+    NothingToAdjust,
+
+    /// Nothing tracks the symbol's levels.
+    #[error("no monitored position or protective stop for {symbol}")]
+    PositionNotFound {
+        /// Symbol.
+        symbol: String,
+    },
+
+    /// The new levels are invalid for a monitored position.
+    #[error(transparent)]
+    InvalidLevels(#[from] StopEnforcementError),
+
+    /// Working orders could not be read.
+    #[error("order store error: {message}")]
+    OrderStore {
+        /// Error details.
+        message: String,
+    },
+}
+
+impl ClassifiedError for StopAdjustError {
+    fn code(&self) -> &str {
+        match self {
+            Self::NothingToAdjust => "NOTHING_TO_ADJUST",
+            Self::PositionNotFound { .. } => "POSITION_NOT_FOUND",
+            Self::InvalidLevels(_) => "INVALID_LEVELS",
+            Self::OrderStore { .. } => "ORDER_STORE_ERROR",
+        }
+    }
+
+    fn kind(&self) -> ErrorKind {
+        match self {
+            Self::NothingToAdjust | Self::InvalidLevels(_) => ErrorKind::InvalidArgument,
+            Self::PositionNotFound { .. } => ErrorKind::NotFound,
+            Self::OrderStore { .. } => ErrorKind::Internal,
+        }
+    }
+}
+
+/// Adjusts stop and target levels for open positions.
+pub struct StopAdjuster<B, R, O, E>
+where
+    B: BrokerPort,
+    R: RiskRepositoryPort,
+    O: OrderRepository,
+    E: EventPublisherPort,
+{
+    order_repo: Arc<O>,
+    submit_orders: Arc<SubmitOrdersUseCase<B, R, O, E>>,
+    cancel_orders: Arc<CancelOrdersUseCase<B, O, E>>,
+    monitor: Option<Arc<RwLock<PriceMonitor>>>,
+    kill_switch: Option<Arc<KillSwitch>>,
+}
+
+impl<B, R, O, E> StopAdjuster<B, R, O, E>
+where
+    B: BrokerPort,
+    R: RiskRepositoryPort,
+    O: OrderRepository,
+    E: EventPublisherPort,
+{
+    /// Create a stop adjuster that only replaces broker-held stops.
+    #[must_use]
+    pub const fn new(
+        order_repo: Arc<O>,
+        submit_orders: Arc<SubmitOrdersUseCase<B, R, O, E>>,
+        cancel_orders: Arc<CancelOrdersUseCase<B, O, E>>,
+    ) -> Self {
+        Self {
+            order_repo,
+            submit_orders,
+            cancel_orders,
+            monitor: None,
+            kill_switch: None,
+        }
+    }
+
+    /// Also update the levels tracked by the position monitor.
+    #[must_use]
+    pub fn with_price_monitor(mut self, monitor: Arc<RwLock<PriceMonitor>>) -> Self {
+        self.monitor = Some(monitor);
+        self
+    }
+
+    /// Leave broker stops in place while the kill switch is tripped.
+    ///
+    /// A tripped switch rejects the replacement, so canceling first would
+    /// leave the position without a broker stop.
+    #[must_use]
+    pub fn with_kill_switch(mut self, kill_switch: Arc<KillSwitch>) -> Self {
+        self.kill_switch = Some(kill_switch);
+        self
+    }
+
+    /// Apply `adjustment` to the position in `symbol`.
+    ///
+    /// Monitored levels change first, so a replacement the broker does not
+    /// accept leaves the position guarded by the monitor at the new stop.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if nothing is adjusted, the levels are invalid for a
+    /// monitored position, or neither the monitor nor the broker tracks a
+    /// level for the symbol. Failures replacing broker orders are listed in
+    /// the report instead.
+    pub async fn adjust(
+        &self,
+        symbol: &str,
+        adjustment: &StopAdjustment,
+    ) -> Result<StopAdjustmentReport, StopAdjustError> {
+        if adjustment.stop_loss.is_none() && adjustment.take_profit.is_none() {
+            return Err(StopAdjustError::NothingToAdjust);
+        }
+
+        let stops: Vec<Order> = self
+            .order_repo
+            .find_active()
+            .await
+            .map_err(|e| StopAdjustError::OrderStore {
+                message: e.to_string(),
+            })?
+            .into_iter()
+            .filter(|order| order.symbol().as_str() == symbol && is_protective_stop(order))
+            .collect();
+
+        let monitored = match &self.monitor {
+            Some(monitor) => monitor.write().adjust_levels(
+                &InstrumentId::new(symbol),
+                adjustment.stop_loss,
+                adjustment.take_profit,
+            )?,
+            None => vec![],
+        };
+
+        let replace = adjustment.stop_loss.filter(|_| !stops.is_empty());
+        if monitored.is_empty() && replace.is_none() {
+            return Err(StopAdjustError::PositionNotFound {
+                symbol: symbol.to_string(),
+            });
+        }
+
+        let mut report = StopAdjustmentReport {
+            symbol: symbol.to_string(),
+            monitored: monitored.iter().map(ToString::to_string).collect(),
+            ..StopAdjustmentReport::default()
+        };
+
+        if let Some(stop_price) = replace {
+            let blocked = self
+                .kill_switch
+                .as_ref()
+                .and_then(|switch| switch.check().err());
+            if let Some(violation) = blocked {
+                report
+                    .errors
+                    .push(format!("broker stops left in place: {violation}"));
+            } else {
+                for order in &stops {
+                    self.replace_stop(order, stop_price, adjustment, &mut report)
+                        .await;
+                }
+            }
+        }
+
+        tracing::info!(
+            target: "audit",
+            symbol,
+            stop_loss = ?adjustment.stop_loss,
+            take_profit = ?adjustment.take_profit,
+            reason = ?adjustment.reason,
+            monitored = report.monitored.len(),
+            replaced = report.submitted.len(),
+            errors = report.errors.len(),
+            "Position stops adjusted"
+        );
+        Ok(report)
+    }
+
+    /// Cancel `order` and re-place it at `stop_price`.
+    ///
+    /// The old stop is canceled first so the position is never protected
+    /// twice.
+    async fn replace_stop(
+        &self,
+        order: &Order,
+        stop_price: Decimal,
+        adjustment: &StopAdjustment,
+        report: &mut StopAdjustmentReport,
+    ) {
+        let reason = CancelReason::new(
+            "STOP_ADJUSTED",
+            adjustment
+                .reason
+                .clone()
+                .unwrap_or_else(|| format!("Moving stop to {stop_price}")),
+        );
+        let canceled = self
+            .cancel_orders
+            .cancel_by_client_id(order.id().as_str(), reason)
+            .await;
+        if !canceled.success {
+            report.errors.push(format!(
+                "cancel {}: {}",
+                canceled.order_id,
+                canceled.error.unwrap_or_default()
+            ));
+            return;
+        }
+        report.canceled.push(canceled.order_id);
+
+        // A stop-limit keeps the same distance between stop and limit
+        let old_stop = order.stop_price().map_or(stop_price, |p| p.amount());
+        let limit_price = order
+            .limit_price()
+            .map(|p| p.amount() + (stop_price - old_stop));
+
+        let request = SubmitOrdersRequestDto {
+            orders: vec![CreateOrderDto {
+                client_order_id: OrderId::generate().to_string(),
+                symbol: order.symbol().to_string(),
+                side: order.side(),
+                order_type: order.order_type(),
+                quantity: order.partial_fill().leaves_qty().amount(),
+                limit_price,
+                stop_price: Some(stop_price),
+                time_in_force: order.time_in_force(),
+                purpose: OrderPurpose::StopLoss,
+                decision: Some(DecisionContext {
+                    thesis_state: Some(ThesisState::Managing),
+                    rationale: adjustment.reason.clone(),
+                    ..DecisionContext::default()
+                }),
+                oto_exit: None,
+            }],
+            validate_risk: false,
+            cycle_id: order.cycle_id().map(ToString::to_string),
+        };
+        let response = self.submit_orders.execute(request).await;
+        if let Some(submitted) = response.submitted.first() {
+            report.submitted.push(submitted.order.order_id.clone());
+        } else {
+            tracing::error!(
+                symbol = %order.symbol(),
+                replaced = %order.id(),
+                "Replacement stop not accepted; position has no broker stop"
+            );
+            report.errors.extend(
+                response
+                    .rejected
+                    .iter()
+                    .filter_map(|r| r.error.clone())
+                    .chain(response.risk_violations),
+            );
+            if report.errors.is_empty() {
+                report
+                    .errors
+                    .push(format!("replacement for {} not accepted", order.id()));
+            }
+        }
+    }
+}
+
+/// Whether `order` is a working protective stop.
+const fn is_protective_stop(order: &Order) -> bool {
+    order.partial_fill().order_purpose().is_protective()
+        && matches!(order.order_type(), OrderType::Stop | OrderType::StopLimit)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::application::ports::{
+        BrokerError, CancelOrderRequest, InMemoryRiskRepository, NoOpEventPublisher, OrderAck,
+        PositionInfo, SubmitOrderRequest,
+    };
+    use crate::domain::order_execution::aggregate::CreateOrderCommand;
+    use crate::domain::order_execution::value_objects::{OrderSide, OrderStatus, TimeInForce};
+    use crate::domain::shared::{BrokerId, Money, Quantity, Symbol};
+    use crate::domain::stop_enforcement::{MonitoredPosition, StopTargetLevels};
+    use crate::infrastructure::persistence::InMemoryOrderRepository;
+    use async_trait::async_trait;
+    use parking_lot::Mutex;
+    use rust_decimal_macros::dec;
+
+    #[derive(Default)]
+    struct MockBroker {
+        submitted: Mutex<Vec<SubmitOrderRequest>>,
+        canceled: Mutex<Vec<CancelOrderRequest>>,
+    }
+
+    #[async_trait]
+    impl BrokerPort for MockBroker {
+        async fn submit_order(&self, request: SubmitOrderRequest) -> Result<OrderAck, BrokerError> {
+            let ack = OrderAck {
+                broker_order_id: BrokerId::new(format!("broker-{}", request.client_order_id)),
+                client_order_id: request.client_order_id.clone(),
+                status: OrderStatus::Accepted,
+                filled_qty: Decimal::ZERO,
+                avg_fill_price: None,
+                child_order_ids: Vec::new(),
+                leg_fills: Vec::new(),
+            };
+            self.submitted.lock().push(request);
+            Ok(ack)
+        }
+
+        async fn cancel_order(&self, request: CancelOrderRequest) -> Result<(), BrokerError> {
+            self.canceled.lock().push(request);
+            Ok(())
+        }
+
+        async fn get_order(&self, broker_order_id: &BrokerId) -> Result<OrderAck, BrokerError> {
+            Err(BrokerError::OrderNotFound {
+                order_id: broker_order_id.to_string(),
+            })
+        }
+
+        async fn get_open_orders(&self) -> Result<Vec<OrderAck>, BrokerError> {
+            Ok(vec![])
+        }
+
+        async fn get_buying_power(&self) -> Result<Decimal, BrokerError> {
+            Ok(dec!(100000))
+        }
+
+        async fn get_position(
+            &self,
+            _instrument_id: &InstrumentId,
+        ) -> Result<Option<Decimal>, BrokerError> {
+            Ok(None)
+        }
+
+        async fn get_all_positions(&self) -> Result<Vec<PositionInfo>, BrokerError> {
+            Ok(vec![])
+        }
+    }
+
+    fn accepted_stop(symbol: &str, order_type: OrderType) -> Order {
+        let mut order = Order::new(CreateOrderCommand {
+            symbol: Symbol::new(symbol),
+            side: OrderSide::Sell,
+            order_type,
+            quantity: Quantity::from_i64(100),
+            limit_price: (order_type == OrderType::StopLimit).then(|| Money::new(dec!(94.5))),
+            stop_price: Some(Money::new(dec!(95))),
+            time_in_force: TimeInForce::Gtc,
+            purpose: OrderPurpose::StopLoss,
+            legs: vec![],
+        })
+        .unwrap();
+        order
+            .accept(BrokerId::new(format!("broker-{symbol}")))
+            .unwrap();
+        order
+    }
+
+    fn monitor_with(symbol: &str) -> Arc<RwLock<PriceMonitor>> {
+        let mut monitor = PriceMonitor::new();
+        monitor.add_position(MonitoredPosition::new(
+            OrderId::new(format!("pos-{symbol}")),
+            InstrumentId::new(symbol),
+            dec!(100),
+            StopTargetLevels::for_long(dec!(100), dec!(95), dec!(110)),
+        ));
+        Arc::new(RwLock::new(monitor))
+    }
+
+    type TestAdjuster = StopAdjuster<
+        MockBroker,
+        InMemoryRiskRepository,
+        InMemoryOrderRepository,
+        NoOpEventPublisher,
+    >;
+
+    async fn make_adjuster(orders: Vec<Order>) -> (TestAdjuster, Arc<MockBroker>) {
+        let broker = Arc::new(MockBroker::default());
+        let order_repo = Arc::new(InMemoryOrderRepository::new());
+        for order in &orders {
+            order_repo.save(order).await.unwrap();
+        }
+        let event_publisher = Arc::new(NoOpEventPublisher);
+        let submit_orders = Arc::new(SubmitOrdersUseCase::new(
+            Arc::clone(&broker),
+            Arc::new(InMemoryRiskRepository::new()),
+            Arc::clone(&order_repo),
+            Arc::clone(&event_publisher),
+        ));
+        let cancel_orders = Arc::new(CancelOrdersUseCase::new(
+            Arc::clone(&broker),
+            Arc::clone(&order_repo),
+            event_publisher,
+        ));
+        (
+            StopAdjuster::new(order_repo, submit_orders, cancel_orders),
+            broker,
+        )
+    }
+
+    fn tighten(stop_loss: Decimal) -> StopAdjustment {
+        StopAdjustment {
+            stop_loss: Some(stop_loss),
+            reason: Some("earnings tomorrow".to_string()),
+            ..StopAdjustment::default()
+        }
+    }
+
+    #[tokio::test]
+    async fn tightened_stop_replaces_broker_order_and_monitor_levels() {
+        let (adjuster, broker) = make_adjuster(vec![
+            accepted_stop("AAPL", OrderType::StopLimit),
+            accepted_stop("MSFT", OrderType::Stop),
+        ])
+        .await;
+        let monitor = monitor_with("AAPL");
+        let adjuster = adjuster.with_price_monitor(Arc::clone(&monitor));
+
+        let report = adjuster.adjust("AAPL", &tighten(dec!(98))).await.unwrap();
+
+        assert!(report.is_success());
+        assert_eq!(report.monitored, ["pos-AAPL"]);
+        assert_eq!(report.canceled.len(), 1);
+        assert_eq!(report.submitted.len(), 1);
+        assert_eq!(broker.canceled.lock().len(), 1);
+        {
+            let submitted = broker.submitted.lock();
+            assert_eq!(submitted.len(), 1);
+            assert_eq!(submitted[0].symbol.as_str(), "AAPL");
+            assert_eq!(submitted[0].order_type, OrderType::StopLimit);
+            assert_eq!(submitted[0].stop_price, Some(dec!(98)));
+            assert_eq!(submitted[0].limit_price, Some(dec!(97.5)));
+            assert_eq!(submitted[0].quantity, dec!(100));
+            assert_eq!(submitted[0].time_in_force, TimeInForce::Gtc);
+        }
+
+        let levels = monitor
+            .read()
+            .get_position(&OrderId::new("pos-AAPL"))
+            .unwrap()
+            .levels()
+            .clone();
+        assert_eq!(levels.stop_loss, dec!(98));
+        assert_eq!(levels.take_profit, dec!(110));
+    }
+
+    #[tokio::test]
+    async fn target_only_updates_monitor_without_touching_broker() {
+        let (adjuster, broker) = make_adjuster(vec![accepted_stop("AAPL", OrderType::Stop)]).await;
+        let adjuster = adjuster.with_price_monitor(monitor_with("AAPL"));
+        let adjustment = StopAdjustment {
+            take_profit: Some(dec!(120)),
+            ..StopAdjustment::default()
+        };
+
+        let report = adjuster.adjust("AAPL", &adjustment).await.unwrap();
+
+        assert_eq!(report.monitored.len(), 1);
+        assert!(report.canceled.is_empty());
+        assert!(broker.submitted.lock().is_empty());
+    }
+
+    #[tokio::test]
+    async fn invalid_or_untracked_adjustments_are_rejected() {
+        let (adjuster, broker) = make_adjuster(vec![accepted_stop("AAPL", OrderType::Stop)]).await;
+        let adjuster = adjuster.with_price_monitor(monitor_with("AAPL"));
+
+        let crossed = adjuster.adjust("AAPL", &tighten(dec!(111))).await;
+        assert!(matches!(crossed, Err(StopAdjustError::InvalidLevels(_))));
+
+        let untracked = adjuster.adjust("TSLA", &tighten(dec!(200))).await;
+        assert!(matches!(
+            untracked,
+            Err(StopAdjustError::PositionNotFound { .. })
+        ));
+
+        let empty = adjuster.adjust("AAPL", &StopAdjustment::default()).await;
+        assert!(matches!(empty, Err(StopAdjustError::NothingToAdjust)));
+
+        assert!(broker.canceled.lock().is_empty());
+        assert!(broker.submitted.lock().is_empty());
+    }
+
+    #[tokio::test]
+    async fn tripped_kill_switch_leaves_broker_stop_in_place() {
+        let (adjuster, broker) = make_adjuster(vec![accepted_stop("AAPL", OrderType::Stop)]).await;
+        let kill_switch = Arc::new(KillSwitch::default());
+        kill_switch.trip("test");
+        let adjuster = adjuster
+            .with_price_monitor(monitor_with("AAPL"))
+            .with_kill_switch(kill_switch);
+
+        let report = adjuster.adjust("AAPL", &tighten(dec!(98))).await.unwrap();
+
+        assert!(!report.is_success());
+        assert_eq!(report.monitored.len(), 1);
+        assert!(broker.canceled.lock().is_empty());
+    }
+}
@@ -0,0 +1,307 @@
+//! Trade Blotter
+//!
+//! Broker-agnostic list of every fill in a time range, for tax and
+//! accounting reconciliation. Fills come from the order store; the decision
+//! behind each order (decision ID, strategy family) comes from the position
+//! lifecycle records, which persist across restarts. Closing fills carry
+//! the P&L of the tax lots they close.
+
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use rust_decimal::Decimal;
+use thiserror::Error;
+
+use super::{PositionLifecycleTracker, RealizedPnlService};
+use crate::application::ports::{DecisionContext, LifecycleError};
+use crate::domain::execution_tactics::StrategyFamily;
+use crate::domain::order_execution::errors::OrderError;
+use crate::domain::order_execution::repository::OrderRepository;
+use crate::domain::order_execution::value_objects::{
+    LiquidityType, OrderPurpose, OrderSide, OrderStatus,
+};
+use crate::domain::shared::{ClassifiedError, ErrorKind, Timestamp};
+
+/// One fill on the blotter.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BlotterFill {
+    /// When the fill occurred.
+    pub timestamp: Timestamp,
+    /// Venue fill ID.
+    pub fill_id: String,
+    /// Internal order ID.
+    pub order_id: String,
+    /// Broker order ID.
+    pub broker_order_id: Option<String>,
+    /// Symbol.
+    pub symbol: String,
+    /// Side.
+    pub side: OrderSide,
+    /// Quantity filled, in shares or contracts.
+    pub quantity: Decimal,
+    /// Fill price, per share.
+    pub price: Decimal,
+    /// Quantity times price times the contract multiplier.
+    pub gross_amount: Decimal,
+    /// Commission charged.
+    pub commission: Decimal,
+    /// Venue where the fill occurred.
+    pub venue: String,
+    /// Whether the fill added or removed liquidity.
+    pub liquidity: Option<LiquidityType>,
+    /// Order purpose.
+    pub purpose: OrderPurpose,
+    /// Decision cycle that produced the order.
+    pub cycle_id: Option<String>,
+    /// Decision that produced the order.
+    pub decision_id: Option<String>,
+    /// Strategy family of the decision.
+    pub strategy_family: Option<StrategyFamily>,
+    /// Strategy tag of the order.
+    pub strategy_tag: Option<String>,
+    /// P&L realized on the lots the fill closed (`None` if it only opened).
+    pub realized_pnl: Option<Decimal>,
+}
+
+/// Trade blotter errors.
+#[derive(Debug, Error)]
+pub enum BlotterError {
+    /// Orders could not be read.
+    #[error(transparent)]
+    Orders(#[from] OrderError),
+
+    /// Position lifecycles could not be read.
+    #[error(transparent)]
+    Lifecycles(#[from] LifecycleError),
+}
+
+impl ClassifiedError for BlotterError {
+    fn code(&self) -> &str {
+        match self {
+            Self::Orders(_) => "ORDER_STORE_ERROR",
+            Self::Lifecycles(e) => e.code(),
+        }
+    }
+
+    fn kind(&self) -> ErrorKind {
+        ErrorKind::Internal
+    }
+}
+
+/// Assembles trade blotters from the order store.
+pub struct TradeBlotter<O: OrderRepository> {
+    order_repo: Arc<O>,
+    pnl: Arc<RealizedPnlService<O>>,
+    lifecycle: Option<Arc<PositionLifecycleTracker>>,
+}
+
+impl<O: OrderRepository> TradeBlotter<O> {
+    /// Create a blotter over `order_repo`.
+    #[must_use]
+    pub fn new(order_repo: Arc<O>) -> Self {
+        Self {
+            pnl: Arc::new(RealizedPnlService::new(Arc::clone(&order_repo))),
+            order_repo,
+            lifecycle: None,
+        }
+    }
+
+    /// Compute the P&L of closing fills with `pnl`, sharing its lot
+    /// matching method and opening lots.
+    #[must_use]
+    pub fn with_realized_pnl(mut self, pnl: Arc<RealizedPnlService<O>>) -> Self {
+        self.pnl = pnl;
+        self
+    }
+
+    /// Tag fills with the decision recorded in position lifecycles.
+    #[must_use]
+    pub fn with_position_lifecycle(mut self, lifecycle: Arc<PositionLifecycleTracker>) -> Self {
+        self.lifecycle = Some(lifecycle);
+        self
+    }
+
+    /// Fills at or after `from` and before `to`, oldest first.
+    ///
+    /// # Errors
+    ///
+    /// Returns error if the order store or lifecycle records cannot be read.
+    pub async fn fills(
+        &self,
+        from: Timestamp,
+        to: Timestamp,
+    ) -> Result<Vec<BlotterFill>, BlotterError> {
+        let decisions = self.decisions().await?;
+        let mut realized: HashMap<String, Decimal> = HashMap::new();
+        for lot in self.pnl.lots().await?.closed_lots() {
+            *realized.entry(lot.close_fill_id.clone()).or_default() += lot.realized_pnl;
+        }
+
+        let mut fills = Vec::new();
+        for status in OrderStatus::ALL {
+            for order in self.order_repo.find_by_status(status).await? {
+                let order_id = order.id().to_string();
+                let decision = decisions.get(&order_id);
+                let multiplier = order.symbol().multiplier();
+                for fill in order.partial_fill().fills() {
+                    if fill.timestamp < from || fill.timestamp >= to {
+                        continue;
+                    }
+                    let quantity = fill.quantity.amount();
+                    let price = fill.price.amount();
+                    fills.push(BlotterFill {
+                        timestamp: fill.timestamp,
+                        fill_id: fill.fill_id.clone(),
+                        order_id: order_id.clone(),
+                        broker_order_id: order.broker_order_id().map(ToString::to_string),
+                        symbol: order.symbol().to_string(),
+                        side: order.side(),
+                        quantity,
+                        price,
+                        gross_amount: quantity * price * multiplier,
+                        commission: fill.commission.map_or(Decimal::ZERO, |c| c.amount()),
+                        venue: fill.venue.clone(),
+                        liquidity: fill.liquidity,
+                        purpose: order.partial_fill().order_purpose(),
+                        cycle_id: order.cycle_id().map(ToString::to_string),
+                        decision_id: decision.and_then(|d| d.decision_id.clone()),
+                        strategy_family: decision.and_then(|d| d.strategy_family),
+                        strategy_tag: order.strategy_tag().map(ToString::to_string),
+                        realized_pnl: realized.get(&fill.fill_id).copied(),
+                    });
+                }
+            }
+        }
+
+        fills.sort_by(|a, b| {
+            a.timestamp
+                .cmp(&b.timestamp)
+                .then_with(|| a.fill_id.cmp(&b.fill_id))
+        });
+        Ok(fills)
+    }
+
+    /// Decision context of every order with a recorded lifecycle event.
+    async fn decisions(&self) -> Result<HashMap<String, DecisionContext>, LifecycleError> {
+        let Some(lifecycle) = &self.lifecycle else {
+            return Ok(HashMap::new());
+        };
+        Ok(lifecycle
+            .history(None)
+            .await?
+            .into_iter()
+            .flat_map(|l| l.events)
+            .filter_map(|event| event.decision.map(|d| (event.order_id, d)))
+            .collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::application::ports::{InMemoryPositionLifecycleRepository, ThesisState};
+    use crate::domain::order_execution::aggregate::{CreateOrderCommand, Order};
+    use crate::domain::order_execution::value_objects::{FillReport, OrderType, TimeInForce};
+    use crate::domain::shared::{BrokerId, Money, Quantity, Symbol};
+    use crate::infrastructure::persistence::InMemoryOrderRepository;
+    use rust_decimal_macros::dec;
+
+    fn filled(symbol: &str, side: OrderSide, fills: &[(&str, i64, Decimal, Timestamp)]) -> Order {
+        let mut order = Order::new(CreateOrderCommand {
+            symbol: Symbol::new(symbol),
+            side,
+            order_type: OrderType::Market,
+            quantity: Quantity::from_i64(10),
+            limit_price: None,
+            stop_price: None,
+            time_in_force: TimeInForce::Day,
+            purpose: OrderPurpose::Entry,
+            legs: vec![],
+        })
+        .unwrap();
+        order
+            .accept(BrokerId::new(format!("b-{symbol}-{side}")))
+            .unwrap();
+        for (fill_id, qty, price, at) in fills {
+            order
+                .apply_fill(
+                    FillReport::new(
+                        *fill_id,
+                        Quantity::from_i64(*qty),
+                        Money::new(*price),
+                        *at,
+                        "TEST",
+                    )
+                    .with_commission(Money::new(dec!(0.10))),
+                )
+                .unwrap();
+        }
+        order
+    }
+
+    fn at(rfc3339: &str) -> Timestamp {
+        Timestamp::parse(rfc3339).unwrap()
+    }
+
+    #[tokio::test]
+    async fn blotter_lists_fills_in_range_with_decisions() {
+        let order_repo = Arc::new(InMemoryOrderRepository::new());
+        let lifecycle = Arc::new(PositionLifecycleTracker::new(Arc::new(
+            InMemoryPositionLifecycleRepository::new(),
+        )));
+
+        let equity = filled(
+            "AAPL",
+            OrderSide::Buy,
+            &[
+                ("f-2", 6, dec!(150), at("2026-03-02T15:00:00Z")),
+                ("f-3", 4, dec!(151), at("2026-03-03T15:00:00Z")),
+            ],
+        );
+        let option = filled(
+            "AAPL260320C00150000",
+            OrderSide::Buy,
+            &[("f-1", 2, dec!(2.50), at("2026-03-02T14:00:00Z"))],
+        );
+        let decision = DecisionContext {
+            decision_id: Some("d-1".to_string()),
+            thesis_state: Some(ThesisState::Entered),
+            time_horizon: None,
+            strategy_family: Some(StrategyFamily::EquityLong),
+            strategy_tag: None,
+            max_holding_period: None,
+            scale_out: Vec::new(),
+            rationale: None,
+        };
+        lifecycle
+            .track_order(&equity, Some(decision))
+            .await
+            .unwrap();
+        order_repo.save(&equity).await.unwrap();
+        order_repo.save(&option).await.unwrap();
+        let close = filled(
+            "AAPL",
+            OrderSide::Sell,
+            &[("f-4", 4, dec!(155), at("2026-03-02T16:00:00Z"))],
+        );
+        order_repo.save(&close).await.unwrap();
+
+        let blotter = TradeBlotter::new(order_repo).with_position_lifecycle(lifecycle);
+        let fills = blotter
+            .fills(at("2026-03-02T00:00:00Z"), at("2026-03-03T00:00:00Z"))
+            .await
+            .unwrap();
+
+        assert_eq!(fills.len(), 3);
+        assert_eq!(fills[0].fill_id, "f-1");
+        assert_eq!(fills[0].gross_amount, dec!(500));
+        assert_eq!(fills[0].decision_id, None);
+        assert_eq!(fills[1].fill_id, "f-2");
+        assert_eq!(fills[1].commission, dec!(0.10));
+        assert_eq!(fills[1].decision_id.as_deref(), Some("d-1"));
+        assert_eq!(fills[1].strategy_family, Some(StrategyFamily::EquityLong));
+        assert_eq!(fills[1].realized_pnl, None);
+        assert_eq!(fills[2].fill_id, "f-4");
+        assert_eq!(fills[2].realized_pnl, Some(dec!(20)));
+    }
+}
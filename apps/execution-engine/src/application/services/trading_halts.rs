@@ -0,0 +1,302 @@
+//! Trading Halts
+//!
+//! Per-symbol trading status fed by the stream proxy's SIP status channel.
+//! A halt or volatility pause (including a limit up/limit down pause) marks
+//! the symbol halted until a resumption arrives. While halted, new orders in
+//! the symbol, or in options on it, are rejected with a [`HALTED_SYMBOL`]
+//! violation, and working orders already in the symbol are flagged in the
+//! audit log so operators can decide whether to pull them.
+//!
+//! Stop-loss orders are not blocked, so a halt never prevents a position
+//! from being protected ahead of the reopening.
+
+use std::collections::HashMap;
+use std::sync::{Arc, PoisonError, RwLock};
+
+use crate::application::services::ExposureCache;
+use crate::domain::order_execution::aggregate::Order;
+use crate::domain::order_execution::value_objects::OrderPurpose;
+use crate::domain::risk_management::value_objects::ConstraintViolation;
+use crate::domain::shared::{Symbol, Timestamp};
+
+/// Violation code for orders in a halted symbol.
+pub const HALTED_SYMBOL: &str = "HALTED_SYMBOL";
+
+/// SIP status codes that stop trading: trading halt (CTA `2`, UTP `H`) and
+/// volatility trading pause (`P`).
+const HALT_CODES: [&str; 3] = ["2", "H", "P"];
+
+/// SIP status codes that resume trading (CTA `3`, UTP `T`).
+const RESUME_CODES: [&str; 2] = ["3", "T"];
+
+/// Trading status change for one symbol.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TradingStatusUpdate {
+    /// Symbol.
+    pub symbol: String,
+    /// SIP status code (e.g. `H` halted, `T` trading).
+    pub status_code: String,
+    /// Status description.
+    pub status_message: String,
+    /// Reason code (e.g. `LUDP` for a limit up/limit down pause).
+    pub reason_code: String,
+    /// Reason description.
+    pub reason_message: String,
+    /// When the status took effect.
+    pub timestamp: Timestamp,
+}
+
+/// Active halt on a symbol.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TradingHalt {
+    /// Symbol.
+    pub symbol: String,
+    /// Status code that started the halt.
+    pub status_code: String,
+    /// Reason code.
+    pub reason_code: String,
+    /// Human-readable reason.
+    pub reason: String,
+    /// When the halt started.
+    pub since: Timestamp,
+    /// Working orders in the symbol when the halt started.
+    pub flagged_orders: Vec<String>,
+}
+
+impl TradingHalt {
+    /// Check if the halt is a limit up/limit down pause.
+    #[must_use]
+    pub fn is_luld_pause(&self) -> bool {
+        self.reason_code.eq_ignore_ascii_case("LUDP")
+    }
+}
+
+/// Shared trading halt state.
+#[derive(Debug, Default)]
+pub struct TradingHalts {
+    halted: RwLock<HashMap<String, TradingHalt>>,
+    exposure: Option<Arc<ExposureCache>>,
+}
+
+impl TradingHalts {
+    /// Create an empty halt registry.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Flag working orders from `exposure` when a symbol halts.
+    #[must_use]
+    pub fn with_exposure_cache(mut self, exposure: Arc<ExposureCache>) -> Self {
+        self.exposure = Some(exposure);
+        self
+    }
+
+    /// Apply a status change.
+    ///
+    /// Returns the halt when `update` starts one; statuses that neither halt
+    /// nor resume trading (imbalances, quote resumption) are ignored.
+    pub fn apply(&self, update: &TradingStatusUpdate) -> Option<TradingHalt> {
+        let code = update.status_code.trim().to_uppercase();
+
+        if RESUME_CODES.contains(&code.as_str()) {
+            let resumed = self
+                .halted
+                .write()
+                .unwrap_or_else(PoisonError::into_inner)
+                .remove(&update.symbol);
+            if let Some(halt) = resumed {
+                tracing::info!(
+                    target: "audit",
+                    symbol = %halt.symbol,
+                    halted_since = %halt.since,
+                    "Trading resumed"
+                );
+            }
+            return None;
+        }
+        if !HALT_CODES.contains(&code.as_str()) {
+            return None;
+        }
+
+        let flagged_orders = self
+            .exposure
+            .as_ref()
+            .map(|exposure| exposure.working_order_ids(&update.symbol))
+            .unwrap_or_default();
+        let reason = [&update.reason_message, &update.status_message]
+            .into_iter()
+            .find(|r| !r.trim().is_empty())
+            .cloned()
+            .unwrap_or_else(|| "trading halted".to_string());
+        let halt = TradingHalt {
+            symbol: update.symbol.clone(),
+            status_code: code,
+            reason_code: update.reason_code.clone(),
+            reason,
+            since: update.timestamp,
+            flagged_orders,
+        };
+
+        tracing::warn!(
+            target: "audit",
+            symbol = %halt.symbol,
+            status_code = %halt.status_code,
+            reason_code = %halt.reason_code,
+            reason = %halt.reason,
+            luld = halt.is_luld_pause(),
+            working_orders = ?halt.flagged_orders,
+            "Trading halted"
+        );
+        self.halted
+            .write()
+            .unwrap_or_else(PoisonError::into_inner)
+            .insert(halt.symbol.clone(), halt.clone());
+        Some(halt)
+    }
+
+    /// Active halt on `symbol`, or on its underlying for an option.
+    #[must_use]
+    pub fn halt(&self, symbol: &str) -> Option<TradingHalt> {
+        let halted = self.halted.read().unwrap_or_else(PoisonError::into_inner);
+        halted.get(symbol).cloned().or_else(|| {
+            let underlying = Symbol::new(symbol).underlying();
+            halted.get(underlying.as_str()).cloned()
+        })
+    }
+
+    /// All active halts, by symbol.
+    #[must_use]
+    pub fn halted(&self) -> Vec<TradingHalt> {
+        let mut halts: Vec<TradingHalt> = self
+            .halted
+            .read()
+            .unwrap_or_else(PoisonError::into_inner)
+            .values()
+            .cloned()
+            .collect();
+        halts.sort_by(|a, b| a.symbol.cmp(&b.symbol));
+        halts
+    }
+
+    /// Violations for orders (or legs) in halted symbols.
+    ///
+    /// Stop-loss orders are allowed through.
+    #[must_use]
+    pub fn check(&self, orders: &[Order]) -> Vec<ConstraintViolation> {
+        let mut violations = Vec::new();
+        for order in orders {
+            if order.partial_fill().order_purpose() == OrderPurpose::StopLoss {
+                continue;
+            }
+            let symbols = std::iter::once(order.symbol().as_str())
+                .chain(order.legs().iter().map(|l| l.instrument_id().as_str()));
+            for symbol in symbols {
+                if let Some(halt) = self.halt(symbol) {
+                    violations.push(
+                        ConstraintViolation::error(
+                            HALTED_SYMBOL,
+                            format!(
+                                "{} is halted since {}: {}",
+                                halt.symbol, halt.since, halt.reason
+                            ),
+                        )
+                        .with_instrument(symbol),
+                    );
+                }
+            }
+        }
+        violations
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::order_execution::aggregate::CreateOrderCommand;
+    use crate::domain::order_execution::value_objects::{OrderSide, OrderType, TimeInForce};
+    use crate::domain::shared::{BrokerId, Money, Quantity};
+    use rust_decimal_macros::dec;
+
+    fn status(symbol: &str, code: &str, reason_code: &str) -> TradingStatusUpdate {
+        TradingStatusUpdate {
+            symbol: symbol.to_string(),
+            status_code: code.to_string(),
+            status_message: String::new(),
+            reason_code: reason_code.to_string(),
+            reason_message: String::new(),
+            timestamp: Timestamp::now(),
+        }
+    }
+
+    fn order(symbol: &str, purpose: OrderPurpose) -> Order {
+        Order::new(CreateOrderCommand {
+            symbol: Symbol::new(symbol),
+            side: OrderSide::Buy,
+            order_type: OrderType::Limit,
+            quantity: Quantity::from_i64(10),
+            limit_price: Some(Money::new(dec!(5))),
+            stop_price: None,
+            time_in_force: TimeInForce::Day,
+            purpose,
+            legs: vec![],
+        })
+        .unwrap()
+    }
+
+    #[test]
+    fn halt_blocks_orders_until_resumed() {
+        let halts = TradingHalts::new();
+
+        let halt = halts.apply(&status("AAPL", "H", "LUDP")).unwrap();
+        assert!(halt.is_luld_pause());
+
+        let violations = halts.check(&[order("AAPL", OrderPurpose::Entry)]);
+        assert_eq!(violations.len(), 1);
+        assert_eq!(violations[0].code, HALTED_SYMBOL);
+
+        // Quote resumption alone does not reopen trading
+        assert!(halts.apply(&status("AAPL", "Q", "")).is_none());
+        assert!(halts.halt("AAPL").is_some());
+
+        assert!(halts.apply(&status("AAPL", "T", "")).is_none());
+        assert!(
+            halts
+                .check(&[order("AAPL", OrderPurpose::Entry)])
+                .is_empty()
+        );
+    }
+
+    #[test]
+    fn options_follow_underlying_halt_and_stops_pass() {
+        let halts = TradingHalts::new();
+        halts.apply(&status("AAPL", "2", "T1"));
+
+        let option = order("AAPL250117C00150000", OrderPurpose::Entry);
+        assert_eq!(halts.check(&[option]).len(), 1);
+        assert!(
+            halts
+                .check(&[order("AAPL", OrderPurpose::StopLoss)])
+                .is_empty()
+        );
+        assert!(
+            halts
+                .check(&[order("MSFT", OrderPurpose::Entry)])
+                .is_empty()
+        );
+    }
+
+    #[test]
+    fn halt_flags_working_orders() {
+        let exposure = Arc::new(ExposureCache::new());
+        let mut working = order("AAPL", OrderPurpose::Entry);
+        working.accept(BrokerId::new("broker-1")).unwrap();
+        exposure.sync_order(&working);
+
+        let halts = TradingHalts::new().with_exposure_cache(exposure);
+        let halt = halts.apply(&status("AAPL", "H", "")).unwrap();
+
+        assert_eq!(halt.flagged_orders, vec![working.id().to_string()]);
+        assert_eq!(halts.halted().len(), 1);
+    }
+}
@@ -0,0 +1,336 @@
+//! Watchlists
+//!
+//! Named lists of symbols to keep market data warm for before a plan trades
+//! them. Watchlist symbols are subscribed on the quote feed alongside
+//! positions and working orders, so snapshots and stop monitoring have data
+//! from the first order. Changes are written to the audit log and wake the
+//! feed subscription reconciler.
+
+use std::collections::BTreeSet;
+use std::sync::Arc;
+
+use tokio::sync::{Mutex, watch};
+
+use crate::application::ports::{
+    Clock, SystemClock, Watchlist, WatchlistError, WatchlistRepositoryPort,
+};
+use crate::domain::shared::Symbol;
+
+/// Longest watchlist name.
+pub const MAX_WATCHLIST_NAME_LEN: usize = 64;
+
+/// Manages watchlists.
+pub struct WatchlistService {
+    repo: Arc<dyn WatchlistRepositoryPort>,
+    clock: Arc<dyn Clock>,
+    /// Serializes read-modify-write updates.
+    updates: Mutex<()>,
+    changes: watch::Sender<u64>,
+}
+
+impl std::fmt::Debug for WatchlistService {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("WatchlistService").finish_non_exhaustive()
+    }
+}
+
+impl WatchlistService {
+    /// Create a service storing watchlists in `repo`.
+    #[must_use]
+    pub fn new(repo: Arc<dyn WatchlistRepositoryPort>) -> Self {
+        Self {
+            repo,
+            clock: SystemClock::shared(),
+            updates: Mutex::new(()),
+            changes: watch::Sender::new(0),
+        }
+    }
+
+    /// Use `clock` instead of the system clock.
+    #[must_use]
+    pub fn with_clock(mut self, clock: Arc<dyn Clock>) -> Self {
+        self.clock = clock;
+        self
+    }
+
+    /// All watchlists, by name.
+    ///
+    /// # Errors
+    ///
+    /// Returns error if the store cannot be read.
+    pub async fn list(&self) -> Result<Vec<Watchlist>, WatchlistError> {
+        self.repo.list().await
+    }
+
+    /// The watchlist called `name`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `WatchlistError::NotFound` if there is none.
+    pub async fn get(&self, name: &str) -> Result<Watchlist, WatchlistError> {
+        self.repo
+            .find(name)
+            .await?
+            .ok_or_else(|| WatchlistError::NotFound {
+                name: name.to_string(),
+            })
+    }
+
+    /// Create a watchlist.
+    ///
+    /// # Errors
+    ///
+    /// Returns error if the name or a symbol is invalid, the name is taken,
+    /// or the store cannot be written.
+    pub async fn create(
+        &self,
+        name: &str,
+        symbols: &[String],
+    ) -> Result<Watchlist, WatchlistError> {
+        validate_name(name)?;
+        let symbols = normalize(symbols)?;
+
+        let _guard = self.updates.lock().await;
+        if self.repo.find(name).await?.is_some() {
+            return Err(WatchlistError::AlreadyExists {
+                name: name.to_string(),
+            });
+        }
+        let now = self.clock.now();
+        let watchlist = Watchlist {
+            name: name.to_string(),
+            symbols: symbols.into_iter().collect(),
+            created_at: now,
+            updated_at: now,
+        };
+        self.repo.save(&watchlist).await?;
+
+        tracing::info!(
+            target: "audit",
+            watchlist = %watchlist.name,
+            symbols = ?watchlist.symbols,
+            "Watchlist created"
+        );
+        self.notify();
+        Ok(watchlist)
+    }
+
+    /// Add `symbols` to a watchlist.
+    ///
+    /// # Errors
+    ///
+    /// Returns error if a symbol is invalid, the watchlist does not exist,
+    /// or the store cannot be written.
+    pub async fn add_symbols(
+        &self,
+        name: &str,
+        symbols: &[String],
+    ) -> Result<Watchlist, WatchlistError> {
+        let added = normalize(symbols)?;
+        self.update(name, "Watchlist symbols added", &added, |current| {
+            current.extend(added.iter().cloned());
+        })
+        .await
+    }
+
+    /// Remove `symbols` from a watchlist.
+    ///
+    /// # Errors
+    ///
+    /// Returns error if the watchlist does not exist or the store cannot be
+    /// written.
+    pub async fn remove_symbols(
+        &self,
+        name: &str,
+        symbols: &[String],
+    ) -> Result<Watchlist, WatchlistError> {
+        let removed: BTreeSet<String> = symbols
+            .iter()
+            .map(|s| Symbol::new(s.as_str()).into_inner())
+            .collect();
+        self.update(name, "Watchlist symbols removed", &removed, |current| {
+            current.retain(|s| !removed.contains(s));
+        })
+        .await
+    }
+
+    /// Delete a watchlist.
+    ///
+    /// # Errors
+    ///
+    /// Returns error if the watchlist does not exist or the store cannot be
+    /// written.
+    pub async fn delete(&self, name: &str) -> Result<(), WatchlistError> {
+        let _guard = self.updates.lock().await;
+        let watchlist = self.get(name).await?;
+        self.repo.delete(name).await?;
+
+        tracing::info!(
+            target: "audit",
+            watchlist = %watchlist.name,
+            symbols = ?watchlist.symbols,
+            "Watchlist deleted"
+        );
+        self.notify();
+        Ok(())
+    }
+
+    /// Symbols on any watchlist.
+    ///
+    /// # Errors
+    ///
+    /// Returns error if the store cannot be read.
+    pub async fn symbols(&self) -> Result<BTreeSet<String>, WatchlistError> {
+        Ok(self
+            .repo
+            .list()
+            .await?
+            .into_iter()
+            .flat_map(|w| w.symbols)
+            .collect())
+    }
+
+    /// Receiver that is marked changed whenever a watchlist changes.
+    #[must_use]
+    pub fn changes(&self) -> watch::Receiver<u64> {
+        self.changes.subscribe()
+    }
+
+    async fn update(
+        &self,
+        name: &str,
+        action: &str,
+        symbols: &BTreeSet<String>,
+        apply: impl FnOnce(&mut BTreeSet<String>),
+    ) -> Result<Watchlist, WatchlistError> {
+        let _guard = self.updates.lock().await;
+        let mut watchlist = self.get(name).await?;
+        let mut current: BTreeSet<String> = watchlist.symbols.iter().cloned().collect();
+        apply(&mut current);
+        let current: Vec<String> = current.into_iter().collect();
+        if current == watchlist.symbols {
+            return Ok(watchlist);
+        }
+
+        watchlist.symbols = current;
+        watchlist.updated_at = self.clock.now();
+        self.repo.save(&watchlist).await?;
+
+        tracing::info!(
+            target: "audit",
+            watchlist = %watchlist.name,
+            symbols = ?symbols,
+            "{action}"
+        );
+        self.notify();
+        Ok(watchlist)
+    }
+
+    fn notify(&self) {
+        self.changes.send_modify(|generation| *generation += 1);
+    }
+}
+
+fn validate_name(name: &str) -> Result<(), WatchlistError> {
+    let valid = !name.is_empty()
+        && name.len() <= MAX_WATCHLIST_NAME_LEN
+        && name
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || matches!(c, '_' | '-' | '.'));
+    if valid {
+        Ok(())
+    } else {
+        Err(WatchlistError::Invalid {
+            reason: format!(
+                "name must be 1 to {MAX_WATCHLIST_NAME_LEN} letters, digits, '_', '-' or '.'"
+            ),
+        })
+    }
+}
+
+/// Canonicalize and validate `symbols`.
+fn normalize(symbols: &[String]) -> Result<BTreeSet<String>, WatchlistError> {
+    symbols
+        .iter()
+        .map(|s| {
+            let symbol = Symbol::new(s.as_str());
+            symbol.validate().map_err(|e| WatchlistError::Invalid {
+                reason: format!("'{s}': {e}"),
+            })?;
+            Ok(symbol.into_inner())
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::application::ports::InMemoryWatchlistRepository;
+
+    fn service() -> WatchlistService {
+        WatchlistService::new(Arc::new(InMemoryWatchlistRepository::new()))
+    }
+
+    fn symbols(symbols: &[&str]) -> Vec<String> {
+        symbols.iter().map(ToString::to_string).collect()
+    }
+
+    #[tokio::test]
+    async fn create_add_and_remove_symbols() {
+        let service = service();
+        let mut changes = service.changes();
+
+        let created = service
+            .create("tech", &symbols(&["msft", "AAPL", "MSFT"]))
+            .await
+            .unwrap();
+        assert_eq!(created.symbols, vec!["AAPL", "MSFT"]);
+        assert!(changes.has_changed().unwrap());
+        changes.borrow_and_update();
+
+        let updated = service
+            .add_symbols("tech", &symbols(&["NVDA"]))
+            .await
+            .unwrap();
+        assert_eq!(updated.symbols, vec!["AAPL", "MSFT", "NVDA"]);
+
+        let updated = service
+            .remove_symbols("tech", &symbols(&["aapl"]))
+            .await
+            .unwrap();
+        assert_eq!(updated.symbols, vec!["MSFT", "NVDA"]);
+
+        service
+            .create("index", &symbols(&["SPY", "MSFT"]))
+            .await
+            .unwrap();
+        let all: Vec<String> = service.symbols().await.unwrap().into_iter().collect();
+        assert_eq!(all, vec!["MSFT", "NVDA", "SPY"]);
+    }
+
+    #[tokio::test]
+    async fn rejects_duplicates_unknown_lists_and_bad_symbols() {
+        let service = service();
+        service.create("tech", &symbols(&["AAPL"])).await.unwrap();
+
+        assert!(matches!(
+            service.create("tech", &[]).await,
+            Err(WatchlistError::AlreadyExists { .. })
+        ));
+        assert!(matches!(
+            service.add_symbols("missing", &symbols(&["AAPL"])).await,
+            Err(WatchlistError::NotFound { .. })
+        ));
+        assert!(matches!(
+            service.add_symbols("tech", &symbols(&["BRK$B"])).await,
+            Err(WatchlistError::Invalid { .. })
+        ));
+        assert!(matches!(
+            service.create("bad name", &[]).await,
+            Err(WatchlistError::Invalid { .. })
+        ));
+
+        service.delete("tech").await.unwrap();
+        assert!(service.list().await.unwrap().is_empty());
+    }
+}
@@ -2,11 +2,18 @@
 
 use std::sync::Arc;
 
+use futures::future::join_all;
+
 use crate::application::ports::{BrokerPort, CancelOrderRequest, EventPublisherPort};
+use crate::application::services::ExposureCache;
+use crate::domain::order_execution::errors::OrderError;
 use crate::domain::order_execution::repository::OrderRepository;
 use crate::domain::order_execution::value_objects::CancelReason;
 use crate::domain::shared::OrderId;
 
+/// Broker cancel requests in flight at once during a bulk cancel.
+const MAX_CONCURRENT_CANCELS: usize = 8;
+
 /// Result of canceling an order.
 #[derive(Debug, Clone)]
 pub struct CancelResult {
@@ -28,6 +35,7 @@ where
     broker: Arc<B>,
     order_repo: Arc<O>,
     event_publisher: Arc<E>,
+    exposure: Option<Arc<ExposureCache>>,
 }
 
 impl<B, O, E> CancelOrdersUseCase<B, O, E>
@@ -42,9 +50,17 @@ where
             broker,
             order_repo,
             event_publisher,
+            exposure: None,
         }
     }
 
+    /// Release cancelled orders from working exposure.
+    #[must_use]
+    pub fn with_exposure_cache(mut self, exposure: Arc<ExposureCache>) -> Self {
+        self.exposure = Some(exposure);
+        self
+    }
+
     /// Cancel a single order by client ID.
     pub async fn cancel_by_client_id(
         &self,
@@ -108,6 +124,9 @@ where
         if let Err(e) = self.order_repo.save(&order).await {
             tracing::error!("Failed to save canceled order: {}", e);
         }
+        if let Some(exposure) = &self.exposure {
+            exposure.sync_order(&order);
+        }
 
         // 6. Publish events
         let events = order.drain_events();
@@ -123,33 +142,53 @@ where
     }
 
     /// Cancel multiple orders.
+    ///
+    /// Broker requests go out in batches of [`MAX_CONCURRENT_CANCELS`];
+    /// results are in the order of `order_ids`.
     pub async fn cancel_orders(
         &self,
         order_ids: &[String],
         reason: CancelReason,
     ) -> Vec<CancelResult> {
-        let mut results = Vec::new();
-
-        for order_id in order_ids {
-            let result = self.cancel_by_client_id(order_id, reason.clone()).await;
-            results.push(result);
+        let mut results = Vec::with_capacity(order_ids.len());
+        for batch in order_ids.chunks(MAX_CONCURRENT_CANCELS) {
+            let cancels = batch
+                .iter()
+                .map(|order_id| self.cancel_by_client_id(order_id, reason.clone()));
+            results.extend(join_all(cancels).await);
         }
-
         results
     }
 
+    /// IDs of working orders, optionally only those from decision cycle
+    /// `cycle_id`.
+    ///
+    /// # Errors
+    ///
+    /// Returns error if the order store cannot be read.
+    pub async fn open_order_ids(&self, cycle_id: Option<&str>) -> Result<Vec<String>, OrderError> {
+        Ok(self
+            .order_repo
+            .find_active()
+            .await?
+            .iter()
+            .filter(|order| {
+                cycle_id.is_none_or(|cycle| order.cycle_id().is_some_and(|c| c.as_str() == cycle))
+            })
+            .map(|order| order.id().to_string())
+            .collect())
+    }
+
     /// Cancel all open orders.
     pub async fn cancel_all(&self, reason: CancelReason) -> Vec<CancelResult> {
-        let open_orders = match self.order_repo.find_active().await {
-            Ok(orders) => orders,
+        let order_ids = match self.open_order_ids(None).await {
+            Ok(order_ids) => order_ids,
             Err(e) => {
                 tracing::error!("Failed to load open orders: {}", e);
                 return vec![];
             }
         };
 
-        let order_ids: Vec<String> = open_orders.iter().map(|o| o.id().to_string()).collect();
-
         self.cancel_orders(&order_ids, reason).await
     }
 }
@@ -160,11 +199,10 @@ mod tests {
     use super::*;
     use crate::application::ports::{BrokerError, NoOpEventPublisher, OrderAck};
     use crate::domain::order_execution::aggregate::{CreateOrderCommand, Order};
-    use crate::domain::order_execution::errors::OrderError;
     use crate::domain::order_execution::value_objects::{
         OrderPurpose, OrderSide, OrderStatus, OrderType, TimeInForce,
     };
-    use crate::domain::shared::{BrokerId, Money, Quantity, Symbol};
+    use crate::domain::shared::{BrokerId, CycleId, Money, Quantity, Symbol};
     use async_trait::async_trait;
     use rust_decimal::Decimal;
     use std::collections::HashMap;
@@ -402,6 +440,34 @@ mod tests {
         assert!(results.iter().all(|r| r.success));
     }
 
+    #[tokio::test]
+    async fn open_order_ids_filter_by_cycle() {
+        let broker = Arc::new(MockBroker { should_fail: false });
+        let order_repo = Arc::new(MockOrderRepo::new());
+        let event_publisher = Arc::new(NoOpEventPublisher);
+
+        let mut planned = create_open_order("order-1");
+        planned.tag_cycle(CycleId::new("cycle-1"));
+        let planned_id = planned.id().to_string();
+        order_repo.add_order(planned);
+        order_repo.add_order(create_open_order("order-2"));
+
+        let use_case = CancelOrdersUseCase::new(broker, order_repo, event_publisher);
+
+        assert_eq!(use_case.open_order_ids(None).await.unwrap().len(), 2);
+        assert_eq!(
+            use_case.open_order_ids(Some("cycle-1")).await.unwrap(),
+            vec![planned_id]
+        );
+        assert!(
+            use_case
+                .open_order_ids(Some("cycle-2"))
+                .await
+                .unwrap()
+                .is_empty()
+        );
+    }
+
     #[tokio::test]
     async fn cancel_terminal_order() {
         let broker = Arc::new(MockBroker { should_fail: false });
@@ -0,0 +1,428 @@
+//! Cycle Transition Use Case
+//!
+//! When a plan from a new decision cycle arrives, working orders tagged with
+//! an earlier cycle are cleaned up so stale intents cannot execute hours
+//! after the decision that produced them.
+
+use std::sync::{Arc, RwLock};
+
+use serde::{Deserialize, Serialize};
+
+use crate::application::ports::{BrokerPort, EventPublisherPort};
+use crate::application::use_cases::CancelOrdersUseCase;
+use crate::domain::order_execution::repository::OrderRepository;
+use crate::domain::order_execution::value_objects::{CancelReason, OrderPurpose};
+use crate::domain::shared::CycleId;
+
+/// What to do with working orders left over from a previous cycle.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum StaleOrderAction {
+    /// Cancel the order at the broker.
+    #[default]
+    Cancel,
+    /// Keep the order working and re-tag it with the new cycle.
+    Reconfirm,
+    /// Leave the order untouched.
+    Ignore,
+}
+
+/// Configuration for cycle transitions.
+#[derive(Debug, Clone)]
+pub struct CycleTransitionConfig {
+    /// Action applied to stale working orders.
+    pub action: StaleOrderAction,
+    /// Order purposes that are never touched (protective orders outlive cycles).
+    pub exempt_purposes: Vec<OrderPurpose>,
+}
+
+impl Default for CycleTransitionConfig {
+    fn default() -> Self {
+        Self {
+            action: StaleOrderAction::Cancel,
+            exempt_purposes: vec![
+                OrderPurpose::StopLoss,
+                OrderPurpose::TakeProfit,
+                OrderPurpose::BracketLeg,
+            ],
+        }
+    }
+}
+
+/// Result of a cycle transition.
+#[derive(Debug, Clone)]
+pub struct CycleTransitionResult {
+    /// The cycle being transitioned to.
+    pub new_cycle: String,
+    /// Orders canceled.
+    pub canceled: Vec<String>,
+    /// Orders re-tagged with the new cycle.
+    pub reconfirmed: Vec<String>,
+    /// Stale orders left untouched (exempt or ignored).
+    pub skipped: Vec<String>,
+    /// Any errors that occurred.
+    pub errors: Vec<String>,
+}
+
+impl CycleTransitionResult {
+    /// Check if the transition completed without errors.
+    #[must_use]
+    pub const fn is_success(&self) -> bool {
+        self.errors.is_empty()
+    }
+}
+
+/// Use case for cleaning up working orders when a new cycle begins.
+pub struct CycleTransitionUseCase<B, O, E>
+where
+    B: BrokerPort,
+    O: OrderRepository,
+    E: EventPublisherPort,
+{
+    config: CycleTransitionConfig,
+    cancel_orders: Arc<CancelOrdersUseCase<B, O, E>>,
+    order_repo: Arc<O>,
+    current_cycle: RwLock<Option<CycleId>>,
+}
+
+impl<B, O, E> CycleTransitionUseCase<B, O, E>
+where
+    B: BrokerPort,
+    O: OrderRepository,
+    E: EventPublisherPort,
+{
+    /// Create a new `CycleTransitionUseCase` with default configuration.
+    #[must_use]
+    pub fn new(cancel_orders: Arc<CancelOrdersUseCase<B, O, E>>, order_repo: Arc<O>) -> Self {
+        Self::with_config(CycleTransitionConfig::default(), cancel_orders, order_repo)
+    }
+
+    /// Create with custom configuration.
+    #[must_use]
+    pub const fn with_config(
+        config: CycleTransitionConfig,
+        cancel_orders: Arc<CancelOrdersUseCase<B, O, E>>,
+        order_repo: Arc<O>,
+    ) -> Self {
+        Self {
+            config,
+            cancel_orders,
+            order_repo,
+            current_cycle: RwLock::new(None),
+        }
+    }
+
+    /// Get the most recently seen cycle.
+    #[must_use]
+    pub fn current_cycle(&self) -> Option<CycleId> {
+        self.current_cycle
+            .read()
+            .unwrap_or_else(std::sync::PoisonError::into_inner)
+            .clone()
+    }
+
+    /// Hook invoked when a plan arrives.
+    ///
+    /// Runs a transition only when `cycle_id` differs from the current cycle;
+    /// returns `None` for further plans within the same cycle.
+    pub async fn on_plan_received(&self, cycle_id: &CycleId) -> Option<CycleTransitionResult> {
+        {
+            let mut current = self
+                .current_cycle
+                .write()
+                .unwrap_or_else(std::sync::PoisonError::into_inner);
+            if current.as_ref() == Some(cycle_id) {
+                return None;
+            }
+            *current = Some(cycle_id.clone());
+        }
+
+        let result = self.transition_to(cycle_id).await;
+
+        tracing::info!(
+            cycle_id = %cycle_id,
+            canceled = result.canceled.len(),
+            reconfirmed = result.reconfirmed.len(),
+            skipped = result.skipped.len(),
+            errors = result.errors.len(),
+            "Cycle transition completed"
+        );
+
+        Some(result)
+    }
+
+    /// Apply the configured action to working orders from other cycles.
+    ///
+    /// Orders without a cycle tag are never touched.
+    pub async fn transition_to(&self, new_cycle: &CycleId) -> CycleTransitionResult {
+        let mut result = CycleTransitionResult {
+            new_cycle: new_cycle.to_string(),
+            canceled: vec![],
+            reconfirmed: vec![],
+            skipped: vec![],
+            errors: vec![],
+        };
+
+        let active = match self.order_repo.find_active().await {
+            Ok(orders) => orders,
+            Err(e) => {
+                result
+                    .errors
+                    .push(format!("Failed to load active orders: {e}"));
+                return result;
+            }
+        };
+
+        for mut order in active {
+            let Some(order_cycle) = order.cycle_id() else {
+                continue;
+            };
+            if order_cycle == new_cycle {
+                continue;
+            }
+
+            let order_id = order.id().to_string();
+            let purpose = order.partial_fill().order_purpose();
+
+            if self.config.exempt_purposes.contains(&purpose) {
+                result.skipped.push(order_id);
+                continue;
+            }
+
+            match self.config.action {
+                StaleOrderAction::Cancel => {
+                    let reason = CancelReason::new(
+                        "CYCLE_SUPERSEDED",
+                        format!("Superseded by cycle {new_cycle}"),
+                    );
+                    let cancel = self
+                        .cancel_orders
+                        .cancel_by_client_id(&order_id, reason)
+                        .await;
+                    if cancel.success {
+                        result.canceled.push(order_id);
+                    } else {
+                        result.errors.push(format!(
+                            "Failed to cancel {order_id}: {}",
+                            cancel.error.unwrap_or_default()
+                        ));
+                    }
+                }
+                StaleOrderAction::Reconfirm => {
+                    order.tag_cycle(new_cycle.clone());
+                    if let Err(e) = self.order_repo.save(&order).await {
+                        result
+                            .errors
+                            .push(format!("Failed to reconfirm {order_id}: {e}"));
+                    } else {
+                        result.reconfirmed.push(order_id);
+                    }
+                }
+                StaleOrderAction::Ignore => result.skipped.push(order_id),
+            }
+        }
+
+        result
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::application::ports::{
+        BrokerError, CancelOrderRequest, NoOpEventPublisher, OrderAck, PositionInfo,
+        SubmitOrderRequest,
+    };
+    use crate::domain::order_execution::aggregate::{CreateOrderCommand, Order};
+    use crate::domain::order_execution::value_objects::{
+        OrderSide, OrderStatus, OrderType, TimeInForce,
+    };
+    use crate::domain::shared::{BrokerId, InstrumentId, OrderId, Quantity, Symbol};
+    use crate::infrastructure::persistence::InMemoryOrderRepository;
+    use async_trait::async_trait;
+    use rust_decimal::Decimal;
+
+    struct MockBroker;
+
+    #[async_trait]
+    impl BrokerPort for MockBroker {
+        async fn submit_order(
+            &self,
+            _request: SubmitOrderRequest,
+        ) -> Result<OrderAck, BrokerError> {
+            Err(BrokerError::Unknown {
+                message: "Not implemented".to_string(),
+            })
+        }
+
+        async fn cancel_order(&self, _request: CancelOrderRequest) -> Result<(), BrokerError> {
+            Ok(())
+        }
+
+        async fn get_order(&self, broker_order_id: &BrokerId) -> Result<OrderAck, BrokerError> {
+            Err(BrokerError::OrderNotFound {
+                order_id: broker_order_id.to_string(),
+            })
+        }
+
+        async fn get_open_orders(&self) -> Result<Vec<OrderAck>, BrokerError> {
+            Ok(vec![])
+        }
+
+        async fn get_buying_power(&self) -> Result<Decimal, BrokerError> {
+            Ok(Decimal::new(100_000, 0))
+        }
+
+        async fn get_position(
+            &self,
+            _instrument_id: &InstrumentId,
+        ) -> Result<Option<Decimal>, BrokerError> {
+            Ok(None)
+        }
+
+        async fn get_all_positions(&self) -> Result<Vec<PositionInfo>, BrokerError> {
+            Ok(vec![])
+        }
+    }
+
+    fn create_order(cycle: Option<&str>, purpose: OrderPurpose) -> Order {
+        let command = CreateOrderCommand {
+            symbol: Symbol::new("AAPL"),
+            side: OrderSide::Buy,
+            order_type: OrderType::Market,
+            quantity: Quantity::from_i64(100),
+            limit_price: None,
+            stop_price: None,
+            time_in_force: TimeInForce::Day,
+            purpose,
+            legs: vec![],
+        };
+        let mut order = Order::new(command).unwrap();
+        order.accept(BrokerId::new("broker-1")).unwrap();
+        if let Some(cycle) = cycle {
+            order.tag_cycle(CycleId::new(cycle));
+        }
+        order
+    }
+
+    fn make_use_case(
+        config: CycleTransitionConfig,
+        order_repo: &Arc<InMemoryOrderRepository>,
+    ) -> CycleTransitionUseCase<MockBroker, InMemoryOrderRepository, NoOpEventPublisher> {
+        let cancel_orders = Arc::new(CancelOrdersUseCase::new(
+            Arc::new(MockBroker),
+            Arc::clone(order_repo),
+            Arc::new(NoOpEventPublisher),
+        ));
+        CycleTransitionUseCase::with_config(config, cancel_orders, Arc::clone(order_repo))
+    }
+
+    async fn status_of(repo: &InMemoryOrderRepository, id: &OrderId) -> OrderStatus {
+        repo.find_by_id(id).await.unwrap().unwrap().status()
+    }
+
+    #[tokio::test]
+    async fn transition_cancels_orders_from_previous_cycle() {
+        let order_repo = Arc::new(InMemoryOrderRepository::new());
+        let stale = create_order(Some("cycle-1"), OrderPurpose::Entry);
+        let current = create_order(Some("cycle-2"), OrderPurpose::Entry);
+        let untagged = create_order(None, OrderPurpose::Entry);
+        let (stale_id, current_id, untagged_id) = (
+            stale.id().clone(),
+            current.id().clone(),
+            untagged.id().clone(),
+        );
+        order_repo.add(stale);
+        order_repo.add(current);
+        order_repo.add(untagged);
+
+        let use_case = make_use_case(CycleTransitionConfig::default(), &order_repo);
+        let result = use_case.transition_to(&CycleId::new("cycle-2")).await;
+
+        assert!(result.is_success());
+        assert_eq!(result.canceled, vec![stale_id.to_string()]);
+        assert_eq!(
+            status_of(&order_repo, &stale_id).await,
+            OrderStatus::Canceled
+        );
+        assert_eq!(
+            status_of(&order_repo, &current_id).await,
+            OrderStatus::Accepted
+        );
+        assert_eq!(
+            status_of(&order_repo, &untagged_id).await,
+            OrderStatus::Accepted
+        );
+    }
+
+    #[tokio::test]
+    async fn transition_skips_exempt_purposes() {
+        let order_repo = Arc::new(InMemoryOrderRepository::new());
+        let stop = create_order(Some("cycle-1"), OrderPurpose::StopLoss);
+        let stop_id = stop.id().clone();
+        order_repo.add(stop);
+
+        let use_case = make_use_case(CycleTransitionConfig::default(), &order_repo);
+        let result = use_case.transition_to(&CycleId::new("cycle-2")).await;
+
+        assert!(result.canceled.is_empty());
+        assert_eq!(result.skipped, vec![stop_id.to_string()]);
+        assert_eq!(
+            status_of(&order_repo, &stop_id).await,
+            OrderStatus::Accepted
+        );
+    }
+
+    #[tokio::test]
+    async fn transition_reconfirms_when_configured() {
+        let order_repo = Arc::new(InMemoryOrderRepository::new());
+        let stale = create_order(Some("cycle-1"), OrderPurpose::Entry);
+        let stale_id = stale.id().clone();
+        order_repo.add(stale);
+
+        let config = CycleTransitionConfig {
+            action: StaleOrderAction::Reconfirm,
+            ..CycleTransitionConfig::default()
+        };
+        let use_case = make_use_case(config, &order_repo);
+        let result = use_case.transition_to(&CycleId::new("cycle-2")).await;
+
+        assert_eq!(result.reconfirmed, vec![stale_id.to_string()]);
+        let saved = order_repo.find_by_id(&stale_id).await.unwrap().unwrap();
+        assert_eq!(saved.status(), OrderStatus::Accepted);
+        assert_eq!(saved.cycle_id().unwrap().as_str(), "cycle-2");
+    }
+
+    #[tokio::test]
+    async fn on_plan_received_runs_once_per_cycle() {
+        let order_repo = Arc::new(InMemoryOrderRepository::new());
+        let use_case = make_use_case(CycleTransitionConfig::default(), &order_repo);
+
+        assert!(
+            use_case
+                .on_plan_received(&CycleId::new("cycle-1"))
+                .await
+                .is_some()
+        );
+        assert!(
+            use_case
+                .on_plan_received(&CycleId::new("cycle-1"))
+                .await
+                .is_none()
+        );
+        assert!(
+            use_case
+                .on_plan_received(&CycleId::new("cycle-2"))
+                .await
+                .is_some()
+        );
+        assert_eq!(use_case.current_cycle(), Some(CycleId::new("cycle-2")));
+    }
+
+    #[test]
+    fn stale_order_action_serde() {
+        let action: StaleOrderAction = serde_json::from_str("\"RECONFIRM\"").unwrap();
+        assert_eq!(action, StaleOrderAction::Reconfirm);
+        assert_eq!(StaleOrderAction::default(), StaleOrderAction::Cancel);
+    }
+}
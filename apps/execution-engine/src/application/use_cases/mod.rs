@@ -3,15 +3,26 @@
 //! Use cases orchestrate domain logic to fulfill application requirements.
 
 mod cancel_orders;
+mod cycle_transition;
 mod monitor_stops;
 mod reconcile;
+mod reconcile_fees;
 mod roll_option;
+mod state_bundle;
 mod submit_orders;
 mod validate_risk;
 
 pub use cancel_orders::CancelOrdersUseCase;
+pub use cycle_transition::{
+    CycleTransitionConfig, CycleTransitionResult, CycleTransitionUseCase, StaleOrderAction,
+};
 pub use monitor_stops::MonitorStopsUseCase;
-pub use reconcile::ReconcileUseCase;
+pub use reconcile::{ReconcileUseCase, ReconciliationResult};
+pub use reconcile_fees::{FeeDiscrepancy, FeeReconciliationResult, ReconcileFeesUseCase};
 pub use roll_option::{RollOptionRequest, RollOptionResponse, RollOptionUseCase};
+pub use state_bundle::{
+    STATE_BUNDLE_VERSION, SignedStateBundle, StateBundle, StateBundleError, StateBundleUseCase,
+    StateConflict, StateConflictKind, StateImportReport,
+};
 pub use submit_orders::SubmitOrdersUseCase;
 pub use validate_risk::ValidateRiskUseCase;
@@ -4,7 +4,7 @@ use std::sync::Arc;
 
 use crate::application::ports::{BrokerPort, PriceFeedPort};
 use crate::domain::order_execution::value_objects::{OrderPurpose, OrderSide};
-use crate::domain::shared::{OrderId, Symbol};
+use crate::domain::shared::{OrderId, Symbol, Timestamp};
 use crate::domain::stop_enforcement::{
     MonitoredPosition, PriceMonitor, StopsConfig, TriggerResult,
 };
@@ -95,18 +95,37 @@ where
                 }
             };
 
-            // Check for triggers
-            let triggers = self.monitor.check_price(&instrument_id, price);
+            // Tighten stops on expired holding periods, then check for triggers
+            let now = Timestamp::now();
+            self.monitor
+                .tighten_expired_stops(&instrument_id, price, now);
+            let triggers = self.monitor.check_price_at(&instrument_id, price, now);
 
             // Process triggers
             for (position_id, trigger) in triggers {
                 let result = match &trigger {
                     TriggerResult::StopLoss { price, .. } => {
-                        self.process_trigger(&position_id, &symbol, *price, "stop_loss")
+                        self.process_trigger(&position_id, &symbol, *price, "stop_loss", None)
                             .await
                     }
                     TriggerResult::TakeProfit { price, .. } => {
-                        self.process_trigger(&position_id, &symbol, *price, "take_profit")
+                        self.process_trigger(&position_id, &symbol, *price, "take_profit", None)
+                            .await
+                    }
+                    TriggerResult::ScaleOut {
+                        price, quantity, ..
+                    } => {
+                        self.process_trigger(
+                            &position_id,
+                            &symbol,
+                            *price,
+                            "scale_out",
+                            Some(*quantity),
+                        )
+                        .await
+                    }
+                    TriggerResult::TimeExit { price, .. } => {
+                        self.process_trigger(&position_id, &symbol, *price, "time_exit", None)
                             .await
                     }
                     TriggerResult::None => continue,
@@ -120,12 +139,16 @@ where
     }
 
     /// Process a trigger and submit exit order.
+    ///
+    /// `scale_out_quantity` exits only part of the position; `None` exits
+    /// all of it.
     async fn process_trigger(
         &mut self,
         position_id: &OrderId,
         symbol: &Symbol,
         trigger_price: rust_decimal::Decimal,
         trigger_type: &str,
+        scale_out_quantity: Option<rust_decimal::Decimal>,
     ) -> StopTriggerResult {
         // Get position info for exit order
         let Some(position) = self.monitor.get_position(position_id) else {
@@ -138,7 +161,7 @@ where
             };
         };
 
-        let quantity = position.quantity();
+        let quantity = scale_out_quantity.unwrap_or_else(|| position.quantity());
 
         // Determine exit order parameters based on trigger type
         let (exit_side, _purpose) = match trigger_type {
@@ -148,7 +171,11 @@ where
         };
 
         // Build and submit exit order
-        let exit_order_id = format!("exit-{position_id}");
+        let exit_order_id = if scale_out_quantity.is_some() {
+            format!("exit-{position_id}-{trigger_type}-{trigger_price}")
+        } else {
+            format!("exit-{position_id}")
+        };
         let request = crate::application::ports::SubmitOrderRequest::market(
             OrderId::new(&exit_order_id),
             symbol.clone(),
@@ -158,8 +185,15 @@ where
 
         match self.broker.submit_order(request).await {
             Ok(_ack) => {
-                // Deactivate the position
-                self.monitor.remove_position(position_id);
+                // Deactivate the position once nothing is left open
+                let closed = scale_out_quantity.is_none()
+                    || self
+                        .monitor
+                        .record_scale_out(position_id, trigger_price)
+                        .is_none_or(|remaining| remaining <= rust_decimal::Decimal::ZERO);
+                if closed {
+                    self.monitor.remove_position(position_id);
+                }
 
                 StopTriggerResult {
                     position_id: position_id.to_string(),
@@ -198,7 +232,7 @@ mod tests {
     use crate::application::ports::{BrokerError, OrderAck, PriceFeedError, Quote};
     use crate::domain::order_execution::value_objects::OrderStatus;
     use crate::domain::shared::{BrokerId, InstrumentId};
-    use crate::domain::stop_enforcement::StopTargetLevels;
+    use crate::domain::stop_enforcement::{HoldingPeriod, ScaleOutTarget, StopTargetLevels};
     use async_trait::async_trait;
     use rust_decimal::Decimal;
     use std::collections::HashMap;
@@ -233,6 +267,8 @@ mod tests {
                 status: OrderStatus::Accepted,
                 filled_qty: Decimal::ZERO,
                 avg_fill_price: None,
+                child_order_ids: Vec::new(),
+                leg_fills: Vec::new(),
             })
         }
 
@@ -406,6 +442,68 @@ mod tests {
         assert!(results[0].exit_order_id.is_some());
     }
 
+    #[tokio::test]
+    async fn monitor_stops_time_exit_trigger() {
+        let broker = Arc::new(MockBroker::new());
+        let price_feed = Arc::new(MockPriceFeed::new());
+        price_feed.set_price("AAPL", Decimal::new(101, 0)); // Between stop and target
+
+        let mut use_case = MonitorStopsUseCase::new(broker.clone(), price_feed);
+        let opened_at = Timestamp::new(Timestamp::now().as_datetime() - chrono::Duration::hours(2));
+        use_case.add_position(
+            create_long_position("pos-1", "AAPL")
+                .with_holding_period(opened_at, HoldingPeriod::close_after(3_600)),
+        );
+
+        let results = use_case.check_and_trigger().await;
+        assert_eq!(results.len(), 1);
+        assert_eq!(results[0].trigger_type, "time_exit");
+        assert_eq!(results[0].trigger_price, Decimal::new(101, 0));
+        assert!(results[0].exit_order_id.is_some());
+    }
+
+    #[tokio::test]
+    async fn monitor_stops_scale_out_trigger() {
+        let broker = Arc::new(MockBroker::new());
+        let price_feed = Arc::new(MockPriceFeed::new());
+        price_feed.set_price("AAPL", Decimal::new(106, 0)); // Past T1, below T2
+
+        let mut use_case = MonitorStopsUseCase::new(broker.clone(), price_feed.clone());
+        let ladder = vec![
+            ScaleOutTarget::new(Decimal::new(105, 0), Decimal::new(50, 2)),
+            ScaleOutTarget::new(Decimal::new(110, 0), Decimal::new(50, 2)),
+        ];
+        use_case.add_position(
+            create_long_position("pos-1", "AAPL")
+                .with_scale_out(ladder)
+                .unwrap(),
+        );
+
+        let results = use_case.check_and_trigger().await;
+        assert_eq!(results.len(), 1);
+        assert_eq!(results[0].trigger_type, "scale_out");
+        assert_eq!(results[0].trigger_price, Decimal::new(105, 0));
+        assert_eq!(use_case.active_count(), 1);
+
+        // Same price again does not scale out twice
+        assert!(use_case.check_and_trigger().await.is_empty());
+
+        price_feed.set_price("AAPL", Decimal::new(111, 0));
+        let results = use_case.check_and_trigger().await;
+        assert_eq!(results.len(), 1);
+        assert_eq!(results[0].trigger_type, "take_profit");
+        assert_eq!(use_case.active_count(), 0);
+
+        let quantities: Vec<_> = broker
+            .submitted_orders
+            .read()
+            .unwrap_or_else(std::sync::PoisonError::into_inner)
+            .iter()
+            .map(|o| o.quantity)
+            .collect();
+        assert_eq!(quantities, [Decimal::new(50, 0), Decimal::new(50, 0)]);
+    }
+
     #[tokio::test]
     async fn monitor_stops_active_count() {
         let broker = Arc::new(MockBroker::new());
@@ -510,6 +608,8 @@ mod tests {
                 status: OrderStatus::Accepted,
                 filled_qty: Decimal::ZERO,
                 avg_fill_price: None,
+                child_order_ids: Vec::new(),
+                leg_fills: Vec::new(),
             })
         }
 
@@ -4,10 +4,19 @@ use std::sync::Arc;
 
 use rust_decimal::Decimal;
 
-use crate::application::ports::BrokerPort;
+use crate::application::ports::{
+    BrokerPort, EventPublisherPort, ExecutionMetricsPort, LifecycleError, OrderAck,
+};
+use crate::application::services::{ExposureCache, PositionLifecycleTracker, RealizedPnlService};
+use crate::domain::order_execution::aggregate::Order;
+use crate::domain::order_execution::errors::OrderError;
+use crate::domain::order_execution::events::{OrderEvent, StateCorrection};
 use crate::domain::order_execution::repository::OrderRepository;
-use crate::domain::order_execution::value_objects::{FillReport, OrderStatus};
-use crate::domain::shared::{BrokerId, Money, Quantity, Timestamp};
+use crate::domain::order_execution::value_objects::OrderStatus;
+use crate::domain::shared::{BrokerId, InstrumentId, Money, Quantity};
+
+/// Source tag recorded on fills and corrections produced by reconciliation.
+const RECONCILE_SOURCE: &str = "RECONCILE";
 
 /// Reconciliation result for a single order.
 #[derive(Debug, Clone)]
@@ -30,6 +39,8 @@ pub struct OrderReconciliation {
     pub qty_match: bool,
     /// Actions taken.
     pub actions: Vec<String>,
+    /// Field-level corrections applied to local state.
+    pub corrections: Vec<StateCorrection>,
 }
 
 /// Overall reconciliation result.
@@ -63,6 +74,11 @@ where
 {
     broker: Arc<B>,
     order_repo: Arc<O>,
+    exposure: Option<Arc<ExposureCache>>,
+    lifecycle: Option<Arc<PositionLifecycleTracker>>,
+    realized_pnl: Option<Arc<RealizedPnlService<O>>>,
+    event_publisher: Option<Arc<dyn EventPublisherPort>>,
+    metrics: Option<Arc<dyn ExecutionMetricsPort>>,
 }
 
 impl<B, O> ReconcileUseCase<B, O>
@@ -72,7 +88,50 @@ where
 {
     /// Create a new `ReconcileUseCase`.
     pub const fn new(broker: Arc<B>, order_repo: Arc<O>) -> Self {
-        Self { broker, order_repo }
+        Self {
+            broker,
+            order_repo,
+            exposure: None,
+            lifecycle: None,
+            realized_pnl: None,
+            event_publisher: None,
+            metrics: None,
+        }
+    }
+
+    /// Keep a live exposure cache in step with reconciled fills and positions.
+    #[must_use]
+    pub fn with_exposure_cache(mut self, exposure: Arc<ExposureCache>) -> Self {
+        self.exposure = Some(exposure);
+        self
+    }
+
+    /// Fold reconciled fills into position lifecycles.
+    #[must_use]
+    pub fn with_position_lifecycle(mut self, lifecycle: Arc<PositionLifecycleTracker>) -> Self {
+        self.lifecycle = Some(lifecycle);
+        self
+    }
+
+    /// Fold reconciled fills into the realized P&L tax lots.
+    #[must_use]
+    pub fn with_realized_pnl(mut self, realized_pnl: Arc<RealizedPnlService<O>>) -> Self {
+        self.realized_pnl = Some(realized_pnl);
+        self
+    }
+
+    /// Publish fills and corrections found by reconciliation.
+    #[must_use]
+    pub fn with_event_publisher(mut self, event_publisher: Arc<dyn EventPublisherPort>) -> Self {
+        self.event_publisher = Some(event_publisher);
+        self
+    }
+
+    /// Record fills found by reconciliation in execution metrics.
+    #[must_use]
+    pub fn with_metrics(mut self, metrics: Arc<dyn ExecutionMetricsPort>) -> Self {
+        self.metrics = Some(metrics);
+        self
     }
 
     /// Execute full reconciliation.
@@ -141,6 +200,7 @@ where
                 broker_filled_qty: broker_filled,
                 qty_match: false,
                 actions: vec![],
+                corrections: vec![],
             };
 
             // Check status match
@@ -151,44 +211,20 @@ where
 
             // Apply corrections
             if let Some(broker_ack) = broker_order {
-                // Update filled quantity if different
-                if !reconciliation.qty_match && broker_ack.filled_qty > local_filled {
-                    let fill_qty = broker_ack.filled_qty - local_filled;
-                    let fill_price = broker_ack.avg_fill_price.unwrap_or(Decimal::ZERO);
-
-                    let fill_report = FillReport::new(
-                        format!("reconcile-{}", order.id()),
-                        Quantity::new(fill_qty),
-                        Money::new(fill_price),
-                        Timestamp::now(),
-                        "RECONCILE",
-                    );
-
-                    if let Err(e) = order.apply_fill(fill_report) {
-                        result.errors.push(format!(
-                            "Failed to apply fill to {}: {}",
-                            order.id(),
-                            e
-                        ));
-                    } else {
-                        reconciliation
-                            .actions
-                            .push(format!("Applied fill: {fill_qty} @ {fill_price}"));
-                    }
-                }
-
-                // Save updated order
-                if !reconciliation.actions.is_empty() {
-                    if let Err(e) = self.order_repo.save(&order).await {
-                        result.errors.push(format!(
-                            "Failed to save reconciled order {}: {}",
-                            order.id(),
-                            e
-                        ));
-                    } else {
-                        result.reconciled += 1;
-                    }
+                let corrected = self
+                    .correct_order(
+                        &mut order,
+                        broker_ack,
+                        &mut reconciliation,
+                        &mut result.errors,
+                    )
+                    .await;
+                if corrected {
+                    result.reconciled += 1;
                 }
+            } else if let Some(exposure) = &self.exposure {
+                // No longer open at the broker
+                exposure.remove_order(order.id().as_str());
             }
 
             if !reconciliation.status_match || !reconciliation.qty_match {
@@ -198,9 +234,60 @@ where
             result.order_results.push(reconciliation);
         }
 
+        // 5. Broker positions are authoritative once fills are merged
+        if let Some(exposure) = &self.exposure
+            && let Err(e) = exposure.refresh_positions(self.broker.as_ref()).await
+        {
+            result
+                .errors
+                .push(format!("Failed to refresh exposure positions: {e}"));
+        }
+
         result
     }
 
+    /// Merge the broker state of an order found by a reconciliation pass,
+    /// saving and publishing any corrections.
+    ///
+    /// Returns whether corrections were saved.
+    async fn correct_order(
+        &self,
+        order: &mut Order,
+        broker_ack: &OrderAck,
+        reconciliation: &mut OrderReconciliation,
+        errors: &mut Vec<String>,
+    ) -> bool {
+        let events = self
+            .merge_broker_state(order, broker_ack, RECONCILE_SOURCE, reconciliation)
+            .unwrap_or_else(|e| {
+                errors.push(format!("Failed to apply fill to {}: {e}", order.id()));
+                Vec::new()
+            });
+
+        // Save updated order
+        let mut saved = false;
+        if !reconciliation.actions.is_empty() {
+            if let Err(e) = self.order_repo.save(order).await {
+                errors.push(format!(
+                    "Failed to save reconciled order {}: {}",
+                    order.id(),
+                    e
+                ));
+            } else {
+                saved = true;
+                self.publish(events).await;
+            }
+        }
+        if let Err(e) = self.sync_tracking(order).await {
+            errors.push(format!(
+                "Failed to record lifecycle fills for {}: {}",
+                order.id(),
+                e
+            ));
+        }
+        saved
+    }
+
     /// Reconcile a single order by ID.
     ///
     /// # Errors
@@ -217,6 +304,26 @@ where
             .await
             .map_err(|e| format!("Failed to get broker order: {e}"))?;
 
+        self.apply_broker_state(&broker_ack, RECONCILE_SOURCE).await
+    }
+
+    /// Merge broker order state pushed by an order update stream into the
+    /// local order, attributing any fills to `source`.
+    ///
+    /// Merging is idempotent, so the same update arriving on more than one
+    /// stream (or again from reconciliation) records no further actions.
+    ///
+    /// # Errors
+    ///
+    /// Returns error if the order is unknown locally or repository
+    /// operations fail.
+    pub async fn apply_broker_state(
+        &self,
+        broker_ack: &OrderAck,
+        source: &str,
+    ) -> Result<OrderReconciliation, String> {
+        let broker_order_id = &broker_ack.broker_order_id;
+
         // Find local order
         let mut order = self
             .order_repo
@@ -237,50 +344,124 @@ where
             broker_filled_qty: broker_ack.filled_qty,
             qty_match: broker_ack.filled_qty == local_filled,
             actions: vec![],
+            corrections: vec![],
         };
 
-        // Apply fill correction if needed
-        if !reconciliation.qty_match && broker_ack.filled_qty > local_filled {
-            let fill_qty = broker_ack.filled_qty - local_filled;
-            let fill_price = broker_ack.avg_fill_price.unwrap_or(Decimal::ZERO);
+        let events = self
+            .merge_broker_state(&mut order, broker_ack, source, &mut reconciliation)
+            .map_err(|e| format!("Failed to apply fill: {e}"))?;
 
-            let fill_report = FillReport::new(
-                format!("reconcile-{}", order.id()),
-                Quantity::new(fill_qty),
-                Money::new(fill_price),
-                Timestamp::now(),
-                "RECONCILE",
-            );
-
-            order
-                .apply_fill(fill_report)
-                .map_err(|e| format!("Failed to apply fill: {e}"))?;
-
-            reconciliation
-                .actions
-                .push(format!("Applied fill: {fill_qty} @ {fill_price}"));
-
-            // Save
+        if !reconciliation.actions.is_empty() {
             self.order_repo
                 .save(&order)
                 .await
                 .map_err(|e| format!("Failed to save order: {e}"))?;
+            self.publish(events).await;
         }
+        self.sync_tracking(&order)
+            .await
+            .map_err(|e| format!("Failed to record lifecycle fills: {e}"))?;
 
         Ok(reconciliation)
     }
+
+    /// Push a reconciled order into the exposure cache, realized P&L and
+    /// position lifecycles.
+    async fn sync_tracking(&self, order: &Order) -> Result<(), LifecycleError> {
+        if let Some(exposure) = &self.exposure {
+            exposure.sync_order(order);
+        }
+        if let Some(realized_pnl) = &self.realized_pnl {
+            realized_pnl.sync_order(order).await;
+        }
+        if let Some(lifecycle) = &self.lifecycle {
+            lifecycle.record_fills(order).await?;
+        }
+        Ok(())
+    }
+
+    /// Publish events raised by a saved reconciliation, if a publisher is set.
+    async fn publish(&self, events: Vec<OrderEvent>) {
+        if let Some(publisher) = &self.event_publisher
+            && let Err(e) = publisher.publish_order_events(events).await
+        {
+            tracing::error!("Failed to publish reconciliation events: {}", e);
+        }
+    }
+
+    /// Merge broker state into the local order, preserving local fill history.
+    ///
+    /// Records an action per applied fill and field correction, and returns
+    /// the events raised.
+    fn merge_broker_state(
+        &self,
+        order: &mut Order,
+        broker_ack: &OrderAck,
+        source: &str,
+        reconciliation: &mut OrderReconciliation,
+    ) -> Result<Vec<OrderEvent>, OrderError> {
+        let fills_before = order.partial_fill().fills().len();
+
+        let mut corrections = order.sync_from_broker(
+            broker_ack.status,
+            Quantity::new(broker_ack.filled_qty),
+            broker_ack.avg_fill_price.map(Money::new),
+            source,
+        )?;
+        let leg_fills: Vec<_> = broker_ack
+            .leg_fills
+            .iter()
+            .map(|leg| {
+                (
+                    InstrumentId::new(&leg.symbol),
+                    Quantity::new(leg.filled_qty),
+                    leg.avg_fill_price.map(Money::new),
+                )
+            })
+            .collect();
+        corrections.extend(order.sync_leg_fills(&leg_fills, source));
+
+        for fill in &order.partial_fill().fills()[fills_before..] {
+            reconciliation.actions.push(format!(
+                "Applied fill: {} @ {}",
+                fill.quantity.amount(),
+                fill.price.amount()
+            ));
+            if let Some(metrics) = &self.metrics {
+                metrics.record_fill(order, fill);
+            }
+        }
+        for correction in &corrections {
+            reconciliation.actions.push(format!(
+                "Corrected {}: {} -> {}",
+                correction.field, correction.previous, correction.corrected
+            ));
+        }
+        reconciliation.corrections = corrections;
+
+        let events = order.drain_events();
+        for event in &events {
+            tracing::info!(
+                order_id = %event.order_id(),
+                event_type = event.event_type(),
+                "Reconciliation event"
+            );
+        }
+
+        Ok(events)
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::application::ports::{BrokerError, OrderAck};
-    use crate::domain::order_execution::aggregate::{CreateOrderCommand, Order};
-    use crate::domain::order_execution::errors::OrderError;
+    use crate::application::ports::{BrokerError, LegFill};
+    use crate::domain::order_execution::aggregate::{CreateOrderCommand, OrderLine};
+    use crate::domain::order_execution::value_objects::FillReport;
     use crate::domain::order_execution::value_objects::{
         OrderPurpose, OrderSide, OrderType, TimeInForce,
     };
-    use crate::domain::shared::{InstrumentId, OrderId, Symbol};
+    use crate::domain::shared::{InstrumentId, OrderId, Symbol, Timestamp};
     use async_trait::async_trait;
     use std::collections::HashMap;
     use std::sync::RwLock;
@@ -478,6 +659,8 @@ mod tests {
             status: OrderStatus::Accepted,
             filled_qty: Decimal::ZERO,
             avg_fill_price: None,
+            child_order_ids: Vec::new(),
+            leg_fills: Vec::new(),
         }];
 
         let broker = Arc::new(MockBroker::new(broker_orders));
@@ -492,6 +675,67 @@ mod tests {
         assert!(result.is_success());
     }
 
+    #[tokio::test]
+    async fn reconcile_tracks_leg_fills() {
+        let mut order = Order::new(CreateOrderCommand {
+            symbol: Symbol::new("SPY"),
+            side: OrderSide::Buy,
+            order_type: OrderType::Limit,
+            quantity: Quantity::from_i64(2),
+            limit_price: Some(Money::usd(1.50)),
+            stop_price: None,
+            time_in_force: TimeInForce::Day,
+            purpose: OrderPurpose::Entry,
+            legs: vec![
+                OrderLine::new(
+                    0,
+                    InstrumentId::new("SPY250620C00500000"),
+                    OrderSide::Buy,
+                    Quantity::from_i64(2),
+                ),
+                OrderLine::new(
+                    1,
+                    InstrumentId::new("SPY250620C00510000"),
+                    OrderSide::Sell,
+                    Quantity::from_i64(2),
+                ),
+            ],
+        })
+        .unwrap();
+        order.accept(BrokerId::new("broker-1")).unwrap();
+        let order_id = order.id().clone();
+
+        let broker_orders = vec![OrderAck {
+            broker_order_id: BrokerId::new("broker-1"),
+            client_order_id: order_id.clone(),
+            status: OrderStatus::Accepted,
+            filled_qty: Decimal::ZERO,
+            avg_fill_price: None,
+            child_order_ids: Vec::new(),
+            leg_fills: vec![LegFill {
+                symbol: "SPY250620C00500000".to_string(),
+                filled_qty: Decimal::TWO,
+                avg_fill_price: Some(Decimal::new(410, 2)),
+            }],
+        }];
+
+        let broker = Arc::new(MockBroker::new(broker_orders));
+        let order_repo = Arc::new(MockOrderRepo::new());
+        order_repo.add_order(order);
+
+        let use_case = ReconcileUseCase::new(broker, Arc::clone(&order_repo));
+        let result = use_case.execute().await;
+
+        assert_eq!(result.reconciled, 1);
+        assert_eq!(
+            result.order_results[0].corrections[0].field,
+            "legs[0].filled_qty"
+        );
+        let saved = order_repo.find_by_id(&order_id).await.unwrap().unwrap();
+        assert_eq!(saved.legs()[0].filled_quantity(), Quantity::from_i64(2));
+        assert!(saved.leg_imbalance().unwrap().is_legged_out());
+    }
+
     #[tokio::test]
     async fn reconcile_with_fill_mismatch() {
         let order = create_order_with_broker("broker-1");
@@ -503,6 +747,8 @@ mod tests {
             status: OrderStatus::Filled,
             filled_qty: Decimal::new(100, 0),
             avg_fill_price: Some(Decimal::new(150, 0)),
+            child_order_ids: Vec::new(),
+            leg_fills: Vec::new(),
         }];
 
         let broker = Arc::new(MockBroker::new(broker_orders));
@@ -567,6 +813,8 @@ mod tests {
             status: OrderStatus::Canceled, // Different from local Accepted
             filled_qty: Decimal::ZERO,
             avg_fill_price: None,
+            child_order_ids: Vec::new(),
+            leg_fills: Vec::new(),
         }];
 
         let broker = Arc::new(MockBroker::new(broker_orders));
@@ -622,6 +870,8 @@ mod tests {
             status: OrderStatus::Accepted,
             filled_qty: Decimal::ZERO,
             avg_fill_price: None,
+            child_order_ids: Vec::new(),
+            leg_fills: Vec::new(),
         }];
 
         let broker = Arc::new(MockBroker::new(broker_orders));
@@ -650,6 +900,8 @@ mod tests {
             status: OrderStatus::PartiallyFilled,
             filled_qty: Decimal::new(50, 0), // Broker shows 50 filled
             avg_fill_price: Some(Decimal::new(150, 0)),
+            child_order_ids: Vec::new(),
+            leg_fills: Vec::new(),
         }];
 
         let broker = Arc::new(MockBroker::new(broker_orders));
@@ -665,6 +917,82 @@ mod tests {
         assert!(reconciliation.actions[0].contains("Applied fill: 50"));
     }
 
+    #[tokio::test]
+    async fn reconciled_fills_are_written_to_the_outbox() {
+        use crate::application::ports::InMemoryOutboxRepository;
+        use crate::application::services::EventOutbox;
+
+        let order = create_order_with_broker("broker-1");
+        let broker_id = BrokerId::new("broker-1");
+        let broker_orders = vec![OrderAck {
+            broker_order_id: broker_id.clone(),
+            client_order_id: order.id().clone(),
+            status: OrderStatus::Filled,
+            filled_qty: Decimal::new(100, 0),
+            avg_fill_price: Some(Decimal::new(150, 0)),
+            child_order_ids: Vec::new(),
+            leg_fills: Vec::new(),
+        }];
+        let order_repo = Arc::new(MockOrderRepo::new());
+        order_repo.add_order(order);
+        let outbox = Arc::new(EventOutbox::new(Arc::new(InMemoryOutboxRepository::new())));
+
+        let use_case = ReconcileUseCase::new(Arc::new(MockBroker::new(broker_orders)), order_repo)
+            .with_event_publisher(Arc::clone(&outbox) as Arc<dyn EventPublisherPort>);
+        use_case.reconcile_order(&broker_id).await.unwrap();
+
+        assert!(outbox.stats().await.depth > 0);
+    }
+
+    #[tokio::test]
+    async fn reconcile_merges_broker_fills_with_local_history() {
+        let mut order = create_order_with_broker("broker-1");
+        order
+            .apply_fill(FillReport::new(
+                "fill-local",
+                Quantity::from_i64(40),
+                Money::new(Decimal::new(150, 0)),
+                Timestamp::now(),
+                "NYSE",
+            ))
+            .unwrap();
+        let order_id = order.id().clone();
+        let broker_id = BrokerId::new("broker-1");
+
+        let broker_orders = vec![OrderAck {
+            broker_order_id: broker_id.clone(),
+            client_order_id: order_id.clone(),
+            status: OrderStatus::PartiallyFilled,
+            filled_qty: Decimal::new(60, 0),
+            avg_fill_price: Some(Decimal::new(151, 0)),
+            child_order_ids: Vec::new(),
+            leg_fills: Vec::new(),
+        }];
+
+        let broker = Arc::new(MockBroker::new(broker_orders));
+        let order_repo = Arc::new(MockOrderRepo::new());
+        order_repo.add_order(order);
+
+        let use_case = ReconcileUseCase::new(broker, Arc::clone(&order_repo));
+        let reconciliation = use_case.reconcile_order(&broker_id).await.unwrap();
+
+        assert_eq!(reconciliation.actions[0], "Applied fill: 20 @ 153");
+        assert_eq!(reconciliation.corrections.len(), 2);
+        assert_eq!(reconciliation.corrections[0].field, "cum_qty");
+        assert_eq!(reconciliation.corrections[1].field, "avg_px");
+
+        let saved = order_repo.find_by_id(&order_id).await.unwrap().unwrap();
+        let fills = saved.partial_fill().fills();
+        assert_eq!(fills.len(), 2);
+        assert_eq!(fills[0].fill_id, "fill-local");
+        assert_eq!(saved.partial_fill().cum_qty(), Quantity::from_i64(60));
+        assert_eq!(
+            saved.partial_fill().avg_px(),
+            Money::new(Decimal::new(151, 0))
+        );
+        assert!(saved.pending_events().is_empty());
+    }
+
     #[tokio::test]
     async fn reconcile_single_order_not_found_locally() {
         let broker_id = BrokerId::new("broker-1");
@@ -675,6 +1003,8 @@ mod tests {
             status: OrderStatus::Accepted,
             filled_qty: Decimal::ZERO,
             avg_fill_price: None,
+            child_order_ids: Vec::new(),
+            leg_fills: Vec::new(),
         }];
 
         let broker = Arc::new(MockBroker::new(broker_orders));
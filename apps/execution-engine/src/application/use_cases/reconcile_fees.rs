@@ -0,0 +1,343 @@
+//! Reconcile Fees Use Case
+//!
+//! Verifies locally estimated regulatory fees, plus any exchange and clearing
+//! fees from a configured `FeeSchedule`, against the fees the broker actually
+//! charged, as reported by its account activity ledger.
+
+use std::collections::BTreeMap;
+use std::sync::Arc;
+
+use chrono::NaiveDate;
+use rust_decimal::Decimal;
+
+use crate::application::ports::{AccountActivity, AccountActivityPort, ActivityKind, BrokerError};
+use crate::domain::order_execution::services::{
+    FeeAssetClass, FeeSchedule, RegulatoryFeeCalculator,
+};
+use crate::domain::shared::{Symbol, Timestamp};
+
+/// Estimated and charged fees for one symbol on one trade date.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FeeDiscrepancy {
+    /// Trade date.
+    pub date: NaiveDate,
+    /// Symbol (empty if the broker did not attribute the fee).
+    pub symbol: String,
+    /// Fees estimated from fills.
+    pub estimated: Decimal,
+    /// Fees actually charged.
+    pub actual: Decimal,
+}
+
+impl FeeDiscrepancy {
+    /// Charged minus estimated (positive when charged more than expected).
+    #[must_use]
+    pub fn difference(&self) -> Decimal {
+        self.actual - self.estimated
+    }
+}
+
+/// Fee reconciliation result.
+#[derive(Debug, Clone, Default)]
+pub struct FeeReconciliationResult {
+    /// Fill activities examined.
+    pub fills_checked: usize,
+    /// Total estimated fees.
+    pub estimated_total: Decimal,
+    /// Total fees charged.
+    pub actual_total: Decimal,
+    /// Total dividends received.
+    pub dividends_total: Decimal,
+    /// Symbol/date buckets where estimate and charge differ beyond tolerance.
+    pub discrepancies: Vec<FeeDiscrepancy>,
+}
+
+impl FeeReconciliationResult {
+    /// Check if all fees matched their estimates.
+    #[must_use]
+    pub const fn is_clean(&self) -> bool {
+        self.discrepancies.is_empty()
+    }
+}
+
+/// Use case for reconciling estimated fees against broker-reported fees.
+pub struct ReconcileFeesUseCase<A>
+where
+    A: AccountActivityPort,
+{
+    activities: Arc<A>,
+    calculator: RegulatoryFeeCalculator,
+    fee_schedule: FeeSchedule,
+    tolerance: Decimal,
+}
+
+impl<A> ReconcileFeesUseCase<A>
+where
+    A: AccountActivityPort,
+{
+    /// Create a new `ReconcileFeesUseCase` with the default fee schedule and
+    /// a one-cent tolerance.
+    #[must_use]
+    pub fn new(activities: Arc<A>) -> Self {
+        Self::with_calculator(
+            activities,
+            RegulatoryFeeCalculator::default(),
+            Decimal::new(1, 2),
+        )
+    }
+
+    /// Create with a custom fee calculator and tolerance.
+    #[must_use]
+    pub fn with_calculator(
+        activities: Arc<A>,
+        calculator: RegulatoryFeeCalculator,
+        tolerance: Decimal,
+    ) -> Self {
+        Self {
+            activities,
+            calculator,
+            fee_schedule: FeeSchedule::default(),
+            tolerance,
+        }
+    }
+
+    /// Include exchange and clearing fees from `fee_schedule` in estimates.
+    #[must_use]
+    pub fn with_fee_schedule(mut self, fee_schedule: FeeSchedule) -> Self {
+        self.fee_schedule = fee_schedule;
+        self
+    }
+
+    /// Fetch activities after `after` and reconcile them.
+    ///
+    /// # Errors
+    ///
+    /// Returns error if account activities cannot be fetched.
+    pub async fn execute(
+        &self,
+        after: Option<Timestamp>,
+    ) -> Result<FeeReconciliationResult, BrokerError> {
+        let activities = self.activities.get_activities(after).await?;
+        let result = self.reconcile(&activities);
+
+        for discrepancy in &result.discrepancies {
+            tracing::warn!(
+                date = %discrepancy.date,
+                symbol = %discrepancy.symbol,
+                estimated = %discrepancy.estimated,
+                actual = %discrepancy.actual,
+                difference = %discrepancy.difference(),
+                "Fee discrepancy"
+            );
+        }
+
+        tracing::info!(
+            fills = result.fills_checked,
+            estimated_total = %result.estimated_total,
+            actual_total = %result.actual_total,
+            dividends_total = %result.dividends_total,
+            discrepancies = result.discrepancies.len(),
+            "Fee reconciliation completed"
+        );
+
+        Ok(result)
+    }
+
+    /// Reconcile a set of activities.
+    ///
+    /// Fees are compared per symbol and trade date, since brokers post
+    /// regulatory fees as separate ledger entries rather than per fill.
+    #[must_use]
+    pub fn reconcile(&self, activities: &[AccountActivity]) -> FeeReconciliationResult {
+        let mut result = FeeReconciliationResult::default();
+        let mut buckets: BTreeMap<(NaiveDate, String), (Decimal, Decimal)> = BTreeMap::new();
+
+        for activity in activities {
+            let key = (
+                activity.occurred_at.as_datetime().date_naive(),
+                activity.symbol.clone().unwrap_or_default(),
+            );
+
+            match activity.kind {
+                ActivityKind::Fill => {
+                    let (Some(side), Some(qty), Some(price)) =
+                        (activity.side, activity.qty, activity.price)
+                    else {
+                        continue;
+                    };
+                    result.fills_checked += 1;
+                    let asset_class = if Symbol::new(&key.1).is_option() {
+                        FeeAssetClass::Option
+                    } else {
+                        FeeAssetClass::Equity
+                    };
+                    // The activity ledger does not report venues, so only
+                    // venue-independent schedule rules apply here
+                    let estimate = self.calculator.estimate(side, qty, price)
+                        + self
+                            .fee_schedule
+                            .estimate(asset_class, None, side, qty, key.0);
+                    result.estimated_total += estimate;
+                    buckets.entry(key).or_default().0 += estimate;
+                }
+                ActivityKind::Fee => {
+                    // Fees are debits; record them as positive charges
+                    let charged = -activity.net_amount.unwrap_or_default();
+                    result.actual_total += charged;
+                    buckets.entry(key).or_default().1 += charged;
+                }
+                ActivityKind::Dividend => {
+                    result.dividends_total += activity.net_amount.unwrap_or_default();
+                }
+                ActivityKind::Other => {}
+            }
+        }
+
+        result.discrepancies = buckets
+            .into_iter()
+            .filter(|(_, (estimated, actual))| (*actual - *estimated).abs() > self.tolerance)
+            .map(|((date, symbol), (estimated, actual))| FeeDiscrepancy {
+                date,
+                symbol,
+                estimated,
+                actual,
+            })
+            .collect();
+
+        result
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::order_execution::value_objects::OrderSide;
+    use async_trait::async_trait;
+
+    struct MockActivities {
+        activities: Vec<AccountActivity>,
+    }
+
+    #[async_trait]
+    impl AccountActivityPort for MockActivities {
+        async fn get_activities(
+            &self,
+            _after: Option<Timestamp>,
+        ) -> Result<Vec<AccountActivity>, BrokerError> {
+            Ok(self.activities.clone())
+        }
+    }
+
+    fn activity(kind: ActivityKind, symbol: &str, time: &str) -> AccountActivity {
+        AccountActivity {
+            id: format!("{symbol}-{time}"),
+            kind,
+            activity_type: String::new(),
+            symbol: Some(symbol.to_string()),
+            order_id: None,
+            side: None,
+            qty: None,
+            price: None,
+            net_amount: None,
+            description: None,
+            occurred_at: Timestamp::parse(time).unwrap(),
+        }
+    }
+
+    fn sell_fill(symbol: &str, qty: i64, price: i64) -> AccountActivity {
+        AccountActivity {
+            side: Some(OrderSide::Sell),
+            qty: Some(Decimal::new(qty, 0)),
+            price: Some(Decimal::new(price, 0)),
+            ..activity(ActivityKind::Fill, symbol, "2024-01-15T15:00:00Z")
+        }
+    }
+
+    fn fee(symbol: &str, amount: Decimal) -> AccountActivity {
+        AccountActivity {
+            net_amount: Some(-amount),
+            ..activity(ActivityKind::Fee, symbol, "2024-01-15T00:00:00Z")
+        }
+    }
+
+    fn make_use_case(activities: Vec<AccountActivity>) -> ReconcileFeesUseCase<MockActivities> {
+        ReconcileFeesUseCase::new(Arc::new(MockActivities { activities }))
+    }
+
+    #[tokio::test]
+    async fn matching_fees_are_clean() {
+        // 100 @ 150 sell: SEC 0.42 + TAF 0.02
+        let use_case = make_use_case(vec![
+            sell_fill("AAPL", 100, 150),
+            fee("AAPL", Decimal::new(44, 2)),
+        ]);
+
+        let result = use_case.execute(None).await.unwrap();
+
+        assert!(result.is_clean());
+        assert_eq!(result.fills_checked, 1);
+        assert_eq!(result.estimated_total, Decimal::new(44, 2));
+        assert_eq!(result.actual_total, Decimal::new(44, 2));
+    }
+
+    #[tokio::test]
+    async fn mismatched_fees_are_recorded() {
+        let use_case = make_use_case(vec![
+            sell_fill("AAPL", 100, 150),
+            fee("AAPL", Decimal::new(144, 2)),
+            fee("MSFT", Decimal::new(5, 2)),
+        ]);
+
+        let result = use_case.execute(None).await.unwrap();
+
+        assert_eq!(result.discrepancies.len(), 2);
+        assert_eq!(result.discrepancies[0].symbol, "AAPL");
+        assert_eq!(result.discrepancies[0].difference(), Decimal::ONE);
+        assert_eq!(result.discrepancies[1].symbol, "MSFT");
+        assert_eq!(result.discrepancies[1].estimated, Decimal::ZERO);
+    }
+
+    #[test]
+    fn fee_schedule_adds_option_clearing_fees() {
+        let schedule = FeeSchedule::from_yaml(
+            r#"
+rules:
+  - name: occ_clearing
+    asset_class: OPTION
+    effective_from: 2024-01-01
+    tiers: [{ min_qty: "0", per_unit: "0.02" }]
+"#,
+        )
+        .unwrap();
+        let use_case = make_use_case(vec![]).with_fee_schedule(schedule);
+        let buy = |symbol: &str| AccountActivity {
+            side: Some(OrderSide::Buy),
+            qty: Some(Decimal::new(10, 0)),
+            price: Some(Decimal::new(250, 2)),
+            ..activity(ActivityKind::Fill, symbol, "2024-01-15T15:00:00Z")
+        };
+
+        let result = use_case.reconcile(&[
+            buy("AAPL240119C00150000"),
+            buy("AAPL"),
+            fee("AAPL240119C00150000", Decimal::new(20, 2)),
+        ]);
+
+        assert!(result.is_clean());
+        assert_eq!(result.estimated_total, Decimal::new(20, 2));
+    }
+
+    #[test]
+    fn dividends_are_totaled() {
+        let use_case = make_use_case(vec![]);
+        let dividend = AccountActivity {
+            net_amount: Some(Decimal::new(1250, 2)),
+            ..activity(ActivityKind::Dividend, "AAPL", "2024-02-15T00:00:00Z")
+        };
+
+        let result = use_case.reconcile(&[dividend]);
+
+        assert!(result.is_clean());
+        assert_eq!(result.dividends_total, Decimal::new(1250, 2));
+    }
+}
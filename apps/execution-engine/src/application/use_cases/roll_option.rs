@@ -11,6 +11,7 @@ use crate::application::dto::{OrderDto, OrderResponseDto};
 use crate::application::ports::{
     BrokerPort, EventPublisherPort, RiskRepositoryPort, SubmitOrderRequest,
 };
+use crate::application::services::KillSwitch;
 use crate::domain::order_execution::aggregate::{CreateOrderCommand, Order};
 use crate::domain::order_execution::errors::OrderError;
 use crate::domain::order_execution::repository::OrderRepository;
@@ -112,6 +113,7 @@ where
     risk_repo: Arc<R>,
     order_repo: Arc<O>,
     event_publisher: Arc<E>,
+    kill_switch: Option<Arc<KillSwitch>>,
 }
 
 impl<B, R, O, E> RollOptionUseCase<B, R, O, E>
@@ -133,15 +135,27 @@ where
             risk_repo,
             order_repo,
             event_publisher,
+            kill_switch: None,
         }
     }
 
+    /// Refuse rolls while the kill switch is tripped.
+    #[must_use]
+    pub fn with_kill_switch(mut self, kill_switch: Arc<KillSwitch>) -> Self {
+        self.kill_switch = Some(kill_switch);
+        self
+    }
+
     /// Execute the roll option use case.
     ///
     /// This creates two orders:
     /// 1. A sell-to-close order for the current position
     /// 2. A buy-to-open order for the new position
     pub async fn execute(&self, request: RollOptionRequest) -> RollOptionResponse {
+        if let Err(violation) = self.check_kill_switch() {
+            return RollOptionResponse::risk_rejected(vec![violation]);
+        }
+
         // Validate symbols are different
         if request.close_symbol == request.open_symbol {
             return RollOptionResponse::failed(
@@ -307,8 +321,17 @@ where
         }
     }
 
+    /// Check the kill switch, if configured.
+    fn check_kill_switch(&self) -> Result<(), String> {
+        self.kill_switch
+            .as_ref()
+            .map_or(Ok(()), |switch| switch.check().map_err(|v| v.to_string()))
+    }
+
     /// Submit order to broker.
     async fn submit_to_broker(&self, order: &mut Order) -> Result<(), String> {
+        self.check_kill_switch()?;
+
         let request = SubmitOrderRequest {
             client_order_id: order.id().clone(),
             symbol: order.symbol().clone(),
@@ -319,6 +342,7 @@ where
             stop_price: order.stop_price().map(|m| m.amount()),
             time_in_force: order.time_in_force(),
             extended_hours: false,
+            oto_exit: None,
         };
 
         match self.broker.submit_order(request).await {
@@ -390,6 +414,8 @@ mod tests {
                 status: OrderStatus::Accepted,
                 filled_qty: Decimal::ZERO,
                 avg_fill_price: None,
+                child_order_ids: Vec::new(),
+                leg_fills: Vec::new(),
             })
         }
 
@@ -628,6 +654,25 @@ mod tests {
         assert!(response.error.unwrap().contains("positive"));
     }
 
+    #[tokio::test]
+    async fn roll_option_blocked_by_kill_switch() {
+        let broker = Arc::new(MockBroker::new());
+        let risk_repo = Arc::new(InMemoryRiskRepository::new());
+        let order_repo = Arc::new(MockOrderRepo::new());
+        let event_publisher = Arc::new(NoOpEventPublisher);
+        let kill_switch = Arc::new(KillSwitch::default());
+        kill_switch.trip("operator halt");
+
+        let use_case = RollOptionUseCase::new(broker, risk_repo, order_repo, event_publisher)
+            .with_kill_switch(kill_switch);
+
+        let response = use_case.execute(create_roll_request()).await;
+
+        assert!(!response.ok);
+        assert!(response.close_order.is_none());
+        assert!(response.risk_violations[0].contains("TRADING_DISABLED"));
+    }
+
     #[tokio::test]
     async fn roll_option_close_fails() {
         let broker = Arc::new(MockBroker::fail_close());
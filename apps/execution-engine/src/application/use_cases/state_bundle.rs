@@ -0,0 +1,687 @@
+//! State Bundle Use Case
+//!
+//! Exports the engine's local state (open position lifecycles with their
+//! thesis metadata, and working orders) as a signed JSON bundle, and imports
+//! such a bundle on another instance, e.g. when moving PAPER state to a new
+//! host.
+//!
+//! The bundle is signed with HMAC-SHA256 over its canonical JSON, so an
+//! import only accepts bundles exported with the same key. Before anything
+//! is written, the bundle is checked against the broker: positions must
+//! match what the broker holds and orders must still be open there. Any
+//! conflict blocks the import unless it is forced.
+
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use hmac::{Hmac, Mac};
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use sha2::Sha256;
+use thiserror::Error;
+
+use crate::application::ports::{BrokerError, BrokerPort, PositionLifecycle, TrackedOrder};
+use crate::application::services::PositionLifecycleTracker;
+use crate::domain::order_execution::aggregate::Order;
+use crate::domain::order_execution::repository::OrderRepository;
+use crate::domain::order_execution::value_objects::Environment;
+use crate::domain::shared::{ClassifiedError, ErrorKind, Timestamp};
+
+/// Bundle format version written by this engine.
+pub const STATE_BUNDLE_VERSION: u32 = 1;
+
+/// Local engine state at export time.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct StateBundle {
+    /// Bundle format version.
+    pub version: u32,
+    /// Environment the state was exported from.
+    pub environment: Environment,
+    /// Export time.
+    pub exported_at: Timestamp,
+    /// Open position lifecycles, with the decision context of each event.
+    pub positions: Vec<PositionLifecycle>,
+    /// Working orders.
+    pub open_orders: Vec<Order>,
+    /// Lifecycle tracking state (and thesis metadata) of the working orders.
+    pub tracked_orders: Vec<TrackedOrder>,
+}
+
+/// A [`StateBundle`] as JSON with its signature.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SignedStateBundle {
+    /// The bundle.
+    pub bundle: serde_json::Value,
+    /// Hex HMAC-SHA256 of the bundle's canonical JSON.
+    pub signature: String,
+}
+
+/// Kind of disagreement between a bundle and the importing instance.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum StateConflictKind {
+    /// The broker holds a different quantity than the bundle's position.
+    PositionMismatch,
+    /// A different lifecycle is already open locally for the symbol.
+    PositionExists,
+    /// The order is already in the local order store.
+    OrderExists,
+    /// The broker does not know the order.
+    OrderNotAtBroker,
+    /// The order is done at the broker.
+    OrderNotOpen,
+}
+
+impl StateConflictKind {
+    /// Machine-readable code.
+    #[must_use]
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::PositionMismatch => "POSITION_MISMATCH",
+            Self::PositionExists => "POSITION_EXISTS",
+            Self::OrderExists => "ORDER_EXISTS",
+            Self::OrderNotAtBroker => "ORDER_NOT_AT_BROKER",
+            Self::OrderNotOpen => "ORDER_NOT_OPEN",
+        }
+    }
+}
+
+/// One conflict found while checking a bundle.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct StateConflict {
+    /// What disagrees.
+    pub kind: StateConflictKind,
+    /// Symbol or order ID the conflict is about.
+    pub subject: String,
+    /// Details.
+    pub message: String,
+}
+
+/// Outcome of an import.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct StateImportReport {
+    /// Whether anything was written (false if conflicts blocked the import).
+    pub applied: bool,
+    /// Position lifecycles restored.
+    pub positions: usize,
+    /// Orders restored.
+    pub orders: usize,
+    /// Conflicts with broker or local state.
+    pub conflicts: Vec<StateConflict>,
+}
+
+/// State export and import errors.
+#[derive(Debug, Error)]
+pub enum StateBundleError {
+    /// The signature does not match the bundle.
+    #[error("State bundle signature is invalid")]
+    InvalidSignature,
+
+    /// The bundle was written by an incompatible engine.
+    #[error("Unsupported state bundle version {version}")]
+    UnsupportedVersion {
+        /// Version found in the bundle.
+        version: u32,
+    },
+
+    /// The bundle does not parse.
+    #[error("Invalid state bundle: {message}")]
+    Malformed {
+        /// Error details.
+        message: String,
+    },
+
+    /// The bundle comes from another trading environment.
+    #[error("State bundle is from {bundle}, this engine runs {engine}")]
+    EnvironmentMismatch {
+        /// Environment of the bundle.
+        bundle: Environment,
+        /// Environment of this engine.
+        engine: Environment,
+    },
+
+    /// Local state could not be read or written.
+    #[error("State store error: {message}")]
+    Storage {
+        /// Error details.
+        message: String,
+    },
+
+    /// Broker state could not be read.
+    #[error(transparent)]
+    Broker(#[from] BrokerError),
+}
+
+impl ClassifiedError for StateBundleError {
+    fn code(&self) -> &str {
+        match self {
+            Self::InvalidSignature => "INVALID_SIGNATURE",
+            Self::UnsupportedVersion { .. } => "UNSUPPORTED_VERSION",
+            Self::Malformed { .. } => "INVALID_BUNDLE",
+            Self::EnvironmentMismatch { .. } => "ENVIRONMENT_MISMATCH",
+            Self::Storage { .. } => "STATE_STORE_ERROR",
+            Self::Broker(_) => "BROKER_UNAVAILABLE",
+        }
+    }
+
+    fn kind(&self) -> ErrorKind {
+        match self {
+            Self::InvalidSignature | Self::UnsupportedVersion { .. } | Self::Malformed { .. } => {
+                ErrorKind::InvalidArgument
+            }
+            Self::EnvironmentMismatch { .. } => ErrorKind::FailedPrecondition,
+            Self::Storage { .. } => ErrorKind::Internal,
+            Self::Broker(_) => ErrorKind::Unavailable,
+        }
+    }
+}
+
+impl StateBundleError {
+    fn storage(e: impl std::fmt::Display) -> Self {
+        Self::Storage {
+            message: e.to_string(),
+        }
+    }
+}
+
+/// Use case for exporting and importing local state.
+pub struct StateBundleUseCase<B, O>
+where
+    B: BrokerPort,
+    O: OrderRepository,
+{
+    broker: Arc<B>,
+    order_repo: Arc<O>,
+    lifecycle: Arc<PositionLifecycleTracker>,
+    environment: Environment,
+    key: Vec<u8>,
+}
+
+impl<B, O> StateBundleUseCase<B, O>
+where
+    B: BrokerPort,
+    O: OrderRepository,
+{
+    /// Create a use case signing bundles with `key`.
+    pub fn new(
+        broker: Arc<B>,
+        order_repo: Arc<O>,
+        lifecycle: Arc<PositionLifecycleTracker>,
+        environment: Environment,
+        key: impl Into<Vec<u8>>,
+    ) -> Self {
+        Self {
+            broker,
+            order_repo,
+            lifecycle,
+            environment,
+            key: key.into(),
+        }
+    }
+
+    /// Export open positions, working orders and their thesis metadata.
+    ///
+    /// # Errors
+    ///
+    /// Returns error if local state cannot be read.
+    pub async fn export(&self) -> Result<SignedStateBundle, StateBundleError> {
+        let positions = self
+            .lifecycle
+            .open_positions()
+            .await
+            .map_err(StateBundleError::storage)?;
+        let open_orders = self
+            .order_repo
+            .find_active()
+            .await
+            .map_err(StateBundleError::storage)?;
+        let mut tracked_orders = Vec::new();
+        for order in &open_orders {
+            if let Some(tracked) = self
+                .lifecycle
+                .find_tracked(order.id().as_str())
+                .await
+                .map_err(StateBundleError::storage)?
+            {
+                tracked_orders.push(tracked);
+            }
+        }
+
+        tracing::info!(
+            target: "audit",
+            positions = positions.len(),
+            orders = open_orders.len(),
+            "State bundle exported"
+        );
+        let bundle = StateBundle {
+            version: STATE_BUNDLE_VERSION,
+            environment: self.environment,
+            exported_at: Timestamp::now(),
+            positions,
+            open_orders,
+            tracked_orders,
+        };
+        let bundle = serde_json::to_value(&bundle).map_err(|e| StateBundleError::Malformed {
+            message: e.to_string(),
+        })?;
+        Ok(SignedStateBundle {
+            signature: hex::encode(self.mac(&bundle).finalize().into_bytes()),
+            bundle,
+        })
+    }
+
+    /// Verify a bundle, check it against the broker and restore it.
+    ///
+    /// Conflicts leave local state untouched unless `force` is set; a
+    /// forced import still skips positions and orders that already exist
+    /// locally.
+    ///
+    /// # Errors
+    ///
+    /// Returns error if the bundle is unsigned, malformed or from another
+    /// environment, or if broker or local state cannot be read.
+    pub async fn import(
+        &self,
+        signed: SignedStateBundle,
+        force: bool,
+    ) -> Result<StateImportReport, StateBundleError> {
+        let bundle = self.verify(signed)?;
+
+        let mut report = StateImportReport {
+            conflicts: self.conflicts(&bundle).await?,
+            ..StateImportReport::default()
+        };
+        if !report.conflicts.is_empty() && !force {
+            tracing::warn!(
+                target: "audit",
+                conflicts = report.conflicts.len(),
+                "State bundle import blocked by conflicts"
+            );
+            return Ok(report);
+        }
+
+        let exists = |kind, subject: &str| {
+            report
+                .conflicts
+                .iter()
+                .any(|c| c.kind == kind && c.subject == subject)
+        };
+        let positions: Vec<PositionLifecycle> = bundle
+            .positions
+            .into_iter()
+            .filter(|l| !exists(StateConflictKind::PositionExists, &l.symbol))
+            .collect();
+        let orders: Vec<Order> = bundle
+            .open_orders
+            .into_iter()
+            .filter(|o| !exists(StateConflictKind::OrderExists, o.id().as_str()))
+            .collect();
+        let tracked: Vec<TrackedOrder> = bundle
+            .tracked_orders
+            .into_iter()
+            .filter(|t| orders.iter().any(|o| o.id().as_str() == t.order_id))
+            .collect();
+
+        for order in &orders {
+            self.order_repo
+                .save(order)
+                .await
+                .map_err(StateBundleError::storage)?;
+        }
+        self.lifecycle
+            .restore(&positions, &tracked)
+            .await
+            .map_err(StateBundleError::storage)?;
+
+        report.applied = true;
+        report.positions = positions.len();
+        report.orders = orders.len();
+        tracing::info!(
+            target: "audit",
+            positions = report.positions,
+            orders = report.orders,
+            conflicts = report.conflicts.len(),
+            forced = force,
+            "State bundle imported"
+        );
+        Ok(report)
+    }
+
+    /// Check the signature, version and environment of a bundle.
+    fn verify(&self, signed: SignedStateBundle) -> Result<StateBundle, StateBundleError> {
+        let signature =
+            hex::decode(&signed.signature).map_err(|_| StateBundleError::InvalidSignature)?;
+        self.mac(&signed.bundle)
+            .verify_slice(&signature)
+            .map_err(|_| StateBundleError::InvalidSignature)?;
+
+        let bundle: StateBundle =
+            serde_json::from_value(signed.bundle).map_err(|e| StateBundleError::Malformed {
+                message: e.to_string(),
+            })?;
+        if bundle.version != STATE_BUNDLE_VERSION {
+            return Err(StateBundleError::UnsupportedVersion {
+                version: bundle.version,
+            });
+        }
+        if bundle.environment != self.environment {
+            return Err(StateBundleError::EnvironmentMismatch {
+                bundle: bundle.environment,
+                engine: self.environment,
+            });
+        }
+        Ok(bundle)
+    }
+
+    /// Compare a bundle with broker and local state.
+    async fn conflicts(
+        &self,
+        bundle: &StateBundle,
+    ) -> Result<Vec<StateConflict>, StateBundleError> {
+        let mut conflicts = Vec::new();
+        let mut conflict = |kind, subject: &str, message: String| {
+            conflicts.push(StateConflict {
+                kind,
+                subject: subject.to_string(),
+                message,
+            });
+        };
+
+        let held: HashMap<String, Decimal> = self
+            .broker
+            .get_all_positions()
+            .await?
+            .into_iter()
+            .map(|p| (p.symbol, p.quantity))
+            .collect();
+        for lifecycle in &bundle.positions {
+            let symbol = lifecycle.symbol.as_str();
+            if let Some(local) = self
+                .lifecycle
+                .current(symbol)
+                .await
+                .map_err(StateBundleError::storage)?
+                && local.lifecycle_id != lifecycle.lifecycle_id
+            {
+                conflict(
+                    StateConflictKind::PositionExists,
+                    symbol,
+                    format!("lifecycle {} is already open", local.lifecycle_id),
+                );
+            }
+            let broker_quantity = held.get(symbol).copied().unwrap_or_default();
+            if broker_quantity != lifecycle.quantity {
+                conflict(
+                    StateConflictKind::PositionMismatch,
+                    symbol,
+                    format!(
+                        "bundle holds {}, broker holds {broker_quantity}",
+                        lifecycle.quantity
+                    ),
+                );
+            }
+        }
+
+        for order in &bundle.open_orders {
+            let order_id = order.id().as_str();
+            if self
+                .order_repo
+                .exists(order.id())
+                .await
+                .map_err(StateBundleError::storage)?
+            {
+                conflict(
+                    StateConflictKind::OrderExists,
+                    order_id,
+                    "order is already stored".to_string(),
+                );
+            }
+            let Some(broker_order_id) = order.broker_order_id() else {
+                conflict(
+                    StateConflictKind::OrderNotAtBroker,
+                    order_id,
+                    "order was never accepted by the broker".to_string(),
+                );
+                continue;
+            };
+            match self.broker.get_order(broker_order_id).await {
+                Ok(ack) if ack.status.is_terminal() => conflict(
+                    StateConflictKind::OrderNotOpen,
+                    order_id,
+                    format!("order is {} at the broker", ack.status),
+                ),
+                Ok(_) => {}
+                Err(BrokerError::OrderNotFound { .. }) => conflict(
+                    StateConflictKind::OrderNotAtBroker,
+                    order_id,
+                    format!("broker does not know order {broker_order_id}"),
+                ),
+                Err(e) => return Err(e.into()),
+            }
+        }
+        Ok(conflicts)
+    }
+
+    /// MAC over the canonical (key-sorted) JSON of a bundle.
+    #[allow(clippy::expect_used)]
+    fn mac(&self, bundle: &serde_json::Value) -> Hmac<Sha256> {
+        let mut mac =
+            Hmac::<Sha256>::new_from_slice(&self.key).expect("HMAC accepts keys of any length");
+        mac.update(bundle.to_string().as_bytes());
+        mac
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::application::ports::{
+        CancelOrderRequest, InMemoryPositionLifecycleRepository, OrderAck, PositionInfo,
+        SubmitOrderRequest,
+    };
+    use crate::domain::order_execution::aggregate::CreateOrderCommand;
+    use crate::domain::order_execution::value_objects::{
+        FillReport, OrderPurpose, OrderSide, OrderStatus, OrderType, TimeInForce,
+    };
+    use crate::domain::shared::{BrokerId, InstrumentId, Money, OrderId, Quantity, Symbol};
+    use crate::infrastructure::persistence::InMemoryOrderRepository;
+    use async_trait::async_trait;
+    use rust_decimal_macros::dec;
+
+    #[derive(Default)]
+    struct MockBroker {
+        positions: Vec<PositionInfo>,
+        open_orders: Vec<String>,
+    }
+
+    #[async_trait]
+    impl BrokerPort for MockBroker {
+        async fn submit_order(
+            &self,
+            _request: SubmitOrderRequest,
+        ) -> Result<OrderAck, BrokerError> {
+            unreachable!("imports never submit")
+        }
+
+        async fn cancel_order(&self, _request: CancelOrderRequest) -> Result<(), BrokerError> {
+            Ok(())
+        }
+
+        async fn get_order(&self, broker_order_id: &BrokerId) -> Result<OrderAck, BrokerError> {
+            if !self.open_orders.contains(&broker_order_id.to_string()) {
+                return Err(BrokerError::OrderNotFound {
+                    order_id: broker_order_id.to_string(),
+                });
+            }
+            Ok(OrderAck {
+                broker_order_id: broker_order_id.clone(),
+                client_order_id: OrderId::new("client"),
+                status: OrderStatus::Accepted,
+                filled_qty: Decimal::ZERO,
+                avg_fill_price: None,
+                child_order_ids: Vec::new(),
+                leg_fills: Vec::new(),
+            })
+        }
+
+        async fn get_open_orders(&self) -> Result<Vec<OrderAck>, BrokerError> {
+            Ok(vec![])
+        }
+
+        async fn get_buying_power(&self) -> Result<Decimal, BrokerError> {
+            Ok(dec!(100000))
+        }
+
+        async fn get_position(
+            &self,
+            _instrument_id: &InstrumentId,
+        ) -> Result<Option<Decimal>, BrokerError> {
+            Ok(None)
+        }
+
+        async fn get_all_positions(&self) -> Result<Vec<PositionInfo>, BrokerError> {
+            Ok(self.positions.clone())
+        }
+    }
+
+    fn position(symbol: &str, quantity: Decimal) -> PositionInfo {
+        PositionInfo {
+            symbol: symbol.to_string(),
+            quantity,
+            avg_entry_price: dec!(100),
+            market_value: quantity * dec!(100),
+            unrealized_pnl: Decimal::ZERO,
+            current_price: dec!(100),
+            opened_at: None,
+        }
+    }
+
+    fn order(symbol: &str, broker_id: &str) -> Order {
+        let mut order = Order::new(CreateOrderCommand {
+            symbol: Symbol::new(symbol),
+            side: OrderSide::Buy,
+            order_type: OrderType::Limit,
+            quantity: Quantity::from_i64(100),
+            limit_price: Some(Money::new(dec!(100))),
+            stop_price: None,
+            time_in_force: TimeInForce::Gtc,
+            purpose: OrderPurpose::Entry,
+            legs: vec![],
+        })
+        .unwrap();
+        order.accept(BrokerId::new(broker_id)).unwrap();
+        order
+    }
+
+    fn use_case(
+        broker: MockBroker,
+    ) -> (
+        StateBundleUseCase<MockBroker, InMemoryOrderRepository>,
+        Arc<InMemoryOrderRepository>,
+        Arc<PositionLifecycleTracker>,
+    ) {
+        let order_repo = Arc::new(InMemoryOrderRepository::new());
+        let lifecycle = Arc::new(PositionLifecycleTracker::new(Arc::new(
+            InMemoryPositionLifecycleRepository::new(),
+        )));
+        let use_case = StateBundleUseCase::new(
+            Arc::new(broker),
+            Arc::clone(&order_repo),
+            Arc::clone(&lifecycle),
+            Environment::Paper,
+            "bundle-key",
+        );
+        (use_case, order_repo, lifecycle)
+    }
+
+    /// A source instance holding 50 AAPL and working an MSFT order.
+    async fn exported() -> SignedStateBundle {
+        let (source, order_repo, lifecycle) = use_case(MockBroker::default());
+
+        let mut filled = order("AAPL", "broker-fill");
+        filled
+            .apply_fill(FillReport::new(
+                "fill-1",
+                Quantity::from_i64(50),
+                Money::new(dec!(100)),
+                Timestamp::now(),
+                "TEST",
+            ))
+            .unwrap();
+        lifecycle.track_order(&filled, None).await.unwrap();
+
+        let working = order("MSFT", "broker-open");
+        order_repo.save(&working).await.unwrap();
+        lifecycle.track_order(&working, None).await.unwrap();
+
+        source.export().await.unwrap()
+    }
+
+    #[tokio::test]
+    async fn state_bundle_round_trips_to_matching_broker() {
+        let signed = exported().await;
+        assert_eq!(signed.bundle["positions"][0]["symbol"], "AAPL");
+
+        let (target, order_repo, lifecycle) = use_case(MockBroker {
+            positions: vec![position("AAPL", dec!(50))],
+            open_orders: vec!["broker-open".to_string()],
+        });
+        let report = target.import(signed, false).await.unwrap();
+
+        assert!(report.applied, "{:?}", report.conflicts);
+        assert_eq!((report.positions, report.orders), (1, 1));
+        assert_eq!(order_repo.find_active().await.unwrap().len(), 1);
+        assert_eq!(
+            lifecycle.current("AAPL").await.unwrap().unwrap().quantity,
+            dec!(50)
+        );
+    }
+
+    #[tokio::test]
+    async fn state_bundle_conflicts_block_unless_forced() {
+        let signed = exported().await;
+        let broker = || MockBroker {
+            positions: vec![position("AAPL", dec!(80))],
+            open_orders: vec![],
+        };
+
+        let (target, order_repo, _) = use_case(broker());
+        let report = target.import(signed.clone(), false).await.unwrap();
+        assert!(!report.applied);
+        let kinds: Vec<_> = report.conflicts.iter().map(|c| c.kind).collect();
+        assert_eq!(
+            kinds,
+            vec![
+                StateConflictKind::PositionMismatch,
+                StateConflictKind::OrderNotAtBroker
+            ]
+        );
+        assert!(order_repo.find_active().await.unwrap().is_empty());
+
+        let (target, order_repo, _) = use_case(broker());
+        let report = target.import(signed, true).await.unwrap();
+        assert!(report.applied);
+        assert_eq!(order_repo.find_active().await.unwrap().len(), 1);
+    }
+
+    #[tokio::test]
+    async fn state_bundle_rejects_tampering_and_other_environments() {
+        let mut signed = exported().await;
+        let (target, _, _) = use_case(MockBroker::default());
+
+        let mut tampered = signed.clone();
+        tampered.bundle["positions"][0]["quantity"] = "5000".into();
+        assert!(matches!(
+            target.import(tampered, true).await,
+            Err(StateBundleError::InvalidSignature)
+        ));
+
+        signed.bundle["environment"] = "LIVE".into();
+        let (source, _, _) = use_case(MockBroker::default());
+        signed.signature = hex::encode(source.mac(&signed.bundle).finalize().into_bytes());
+        assert!(matches!(
+            target.import(signed, true).await,
+            Err(StateBundleError::EnvironmentMismatch { .. })
+        ));
+    }
+}
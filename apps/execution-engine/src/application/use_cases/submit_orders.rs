@@ -1,18 +1,34 @@
 //! Submit Orders Use Case
 
-use std::sync::Arc;
+use std::collections::{HashMap, HashSet};
+use std::sync::{Arc, PoisonError, RwLock};
+use std::time::Duration;
+
+use rust_decimal::Decimal;
 
 use crate::application::dto::{
     CreateOrderDto, OrderDto, OrderResponseDto, SubmitOrdersRequestDto, SubmitOrdersResponseDto,
 };
 use crate::application::ports::{
-    BrokerPort, EventPublisherPort, RiskRepositoryPort, SubmitOrderRequest,
+    BrokerPort, EventPublisherPort, ExecutionMetricsPort, PriceFeedPort, Quote, RiskRepositoryPort,
+    SubmitOrderRequest,
+};
+use crate::application::services::{
+    AssetMetadataService, ExposureCache, Feature, FeatureFlags, InFlight, KillSwitch,
+    LeaderElection, PositionLifecycleTracker, QuoteCache, ShadowExecution, ShutdownCoordinator,
+    TradingHalts,
 };
+use crate::domain::execution_tactics::{ExecutionProfile, ExecutionProfiles, StrategyFamily};
 use crate::domain::order_execution::aggregate::{CreateOrderCommand, Order};
 use crate::domain::order_execution::errors::OrderError;
 use crate::domain::order_execution::repository::OrderRepository;
-use crate::domain::risk_management::services::RiskValidationService;
-use crate::domain::shared::{Money, Quantity, Symbol};
+use crate::domain::order_execution::value_objects::{OrderPurpose, OrderType};
+use crate::domain::risk_management::services::{PositionNetting, RiskValidationService};
+use crate::domain::risk_management::value_objects::{
+    InstrumentUniverse, PriceSanityLimits, SymbolPosition, TickSizeRules, UniverseMembership,
+    ViolationSeverity,
+};
+use crate::domain::shared::{CycleId, Money, Quantity, Symbol};
 
 /// Use case for submitting orders to the broker.
 pub struct SubmitOrdersUseCase<B, R, O, E>
@@ -26,6 +42,24 @@ where
     risk_repo: Arc<R>,
     order_repo: Arc<O>,
     event_publisher: Arc<E>,
+    asset_metadata: Arc<AssetMetadataService<B>>,
+    exposure: Option<Arc<ExposureCache>>,
+    kill_switch: Option<Arc<KillSwitch>>,
+    shutdown: Option<Arc<ShutdownCoordinator>>,
+    leader_election: Option<Arc<LeaderElection>>,
+    lifecycle: Option<Arc<PositionLifecycleTracker>>,
+    trading_halts: Option<Arc<TradingHalts>>,
+    execution_profiles: Option<Arc<RwLock<ExecutionProfiles>>>,
+    price_feed: Option<Arc<dyn PriceFeedPort>>,
+    quote_cache: Option<Arc<QuoteCache>>,
+    latency_budget: Option<Duration>,
+    shadow: Option<Arc<ShadowExecution>>,
+    feature_flags: Option<Arc<FeatureFlags>>,
+    price_sanity: Option<PriceSanityLimits>,
+    tick_sizes: TickSizeRules,
+    instruments: InstrumentUniverse,
+    membership: Option<Arc<UniverseMembership>>,
+    metrics: Option<Arc<dyn ExecutionMetricsPort>>,
 }
 
 impl<B, R, O, E> SubmitOrdersUseCase<B, R, O, E>
@@ -36,22 +70,240 @@ where
     E: EventPublisherPort,
 {
     /// Create a new `SubmitOrdersUseCase`.
-    pub const fn new(
+    pub fn new(
         broker: Arc<B>,
         risk_repo: Arc<R>,
         order_repo: Arc<O>,
         event_publisher: Arc<E>,
     ) -> Self {
         Self {
+            asset_metadata: Arc::new(AssetMetadataService::new(Arc::clone(&broker))),
             broker,
             risk_repo,
             order_repo,
             event_publisher,
+            exposure: None,
+            kill_switch: None,
+            shutdown: None,
+            leader_election: None,
+            lifecycle: None,
+            trading_halts: None,
+            execution_profiles: None,
+            price_feed: None,
+            quote_cache: None,
+            latency_budget: None,
+            shadow: None,
+            feature_flags: None,
+            price_sanity: None,
+            tick_sizes: TickSizeRules::default(),
+            instruments: InstrumentUniverse::default(),
+            membership: None,
+            metrics: None,
         }
     }
 
+    /// Use a shared asset metadata service (e.g. one refreshed in the background).
+    #[must_use]
+    pub fn with_asset_metadata(mut self, asset_metadata: Arc<AssetMetadataService<B>>) -> Self {
+        self.asset_metadata = asset_metadata;
+        self
+    }
+
+    /// Record submitted orders as working exposure.
+    ///
+    /// Also enables netting of scale-in and scale-out orders against the
+    /// cached position.
+    #[must_use]
+    pub fn with_exposure_cache(mut self, exposure: Arc<ExposureCache>) -> Self {
+        self.exposure = Some(exposure);
+        self
+    }
+
+    /// Refuse submissions while the kill switch is tripped.
+    #[must_use]
+    pub fn with_kill_switch(mut self, kill_switch: Arc<KillSwitch>) -> Self {
+        self.kill_switch = Some(kill_switch);
+        self
+    }
+
+    /// Refuse plans once shutdown starts, and hold shutdown until admitted
+    /// plans finish.
+    #[must_use]
+    pub fn with_shutdown(mut self, shutdown: Arc<ShutdownCoordinator>) -> Self {
+        self.shutdown = Some(shutdown);
+        self
+    }
+
+    /// Refuse plans while this instance is a standby.
+    #[must_use]
+    pub fn with_leader_election(mut self, leader_election: Arc<LeaderElection>) -> Self {
+        self.leader_election = Some(leader_election);
+        self
+    }
+
+    /// Check if new plans are admitted: the engine is not shutting down
+    /// and, with leader election, is the leader.
+    #[must_use]
+    pub fn is_accepting_plans(&self) -> bool {
+        let draining = self
+            .shutdown
+            .as_ref()
+            .is_some_and(|shutdown| shutdown.is_draining());
+        let standby = self
+            .leader_election
+            .as_ref()
+            .is_some_and(|election| !election.is_leader());
+        !draining && !standby
+    }
+
+    /// Reject orders in symbols that are halted or paused.
+    #[must_use]
+    pub fn with_trading_halts(mut self, trading_halts: Arc<TradingHalts>) -> Self {
+        self.trading_halts = Some(trading_halts);
+        self
+    }
+
+    /// Check orders against the execution profile of their strategy family.
+    ///
+    /// The profiles are shared so a config reload can replace them.
+    #[must_use]
+    pub fn with_execution_profiles(mut self, profiles: Arc<RwLock<ExecutionProfiles>>) -> Self {
+        self.execution_profiles = Some(profiles);
+        self
+    }
+
+    /// Quote source for execution profile spread checks and price sanity
+    /// checks.
+    #[must_use]
+    pub fn with_price_feed(mut self, price_feed: Arc<dyn PriceFeedPort>) -> Self {
+        self.price_feed = Some(price_feed);
+        self
+    }
+
+    /// Reuse recent quotes across submissions instead of refetching them
+    /// for every order.
+    #[must_use]
+    pub fn with_quote_cache(mut self, quote_cache: Arc<QuoteCache>) -> Self {
+        self.quote_cache = Some(quote_cache);
+        self
+    }
+
+    /// Give up on a pre-submission quote lookup after `budget`.
+    ///
+    /// A slow data API then degrades the order to its profile's default
+    /// tactic instead of delaying it, which matters most for urgent exits.
+    #[must_use]
+    pub const fn with_latency_budget(mut self, budget: Duration) -> Self {
+        self.latency_budget = Some(budget);
+        self
+    }
+
+    /// Simulate a second execution configuration for every submitted order.
+    ///
+    /// Requires execution profiles and a price feed; orders are then quoted
+    /// before submission even when their profile has no spread limit.
+    #[must_use]
+    pub fn with_shadow_execution(mut self, shadow: Arc<ShadowExecution>) -> Self {
+        self.shadow = Some(shadow);
+        self
+    }
+
+    /// Record submitted orders in execution metrics.
+    #[must_use]
+    pub fn with_metrics(mut self, metrics: Arc<dyn ExecutionMetricsPort>) -> Self {
+        self.metrics = Some(metrics);
+        self
+    }
+
+    /// Gate optional behaviour (shadow execution) on `feature_flags`.
+    #[must_use]
+    pub fn with_feature_flags(mut self, feature_flags: Arc<FeatureFlags>) -> Self {
+        self.feature_flags = Some(feature_flags);
+        self
+    }
+
+    /// Check whether `feature` is on (always, without feature flags).
+    fn enabled(&self, feature: Feature) -> bool {
+        self.feature_flags
+            .as_ref()
+            .is_none_or(|flags| flags.is_enabled(feature))
+    }
+
+    /// Shadow execution to record submitted orders with, if it is on.
+    fn active_shadow(&self) -> Option<&Arc<ShadowExecution>> {
+        self.shadow
+            .as_ref()
+            .filter(|_| self.enabled(Feature::ShadowExecution))
+    }
+
+    /// Reject limit prices far from the market and clamp stop prices on
+    /// the wrong side of it.
+    ///
+    /// Requires a price feed; orders without a quote are not checked.
+    #[must_use]
+    pub const fn with_price_sanity(mut self, limits: PriceSanityLimits) -> Self {
+        self.price_sanity = Some(limits);
+        self
+    }
+
+    /// Handle off-tick prices with `rules` instead of rounding them.
+    #[must_use]
+    pub const fn with_tick_size_rules(mut self, rules: TickSizeRules) -> Self {
+        self.tick_sizes = rules;
+        self
+    }
+
+    /// Reject new positions in instruments outside `universe`.
+    ///
+    /// Exits are allowed through, so a position opened before an
+    /// instrument was blocked can still be closed.
+    #[must_use]
+    pub fn with_instrument_universe(mut self, universe: InstrumentUniverse) -> Self {
+        self.instruments = universe;
+        self
+    }
+
+    /// Reject new positions in instruments that are not members of
+    /// `membership` on the current (UTC) date, in addition to the instrument
+    /// universe.
+    #[must_use]
+    pub fn with_universe_membership(mut self, membership: Arc<UniverseMembership>) -> Self {
+        self.membership = Some(membership);
+        self
+    }
+
+    /// Record submitted orders and their decision context on position
+    /// lifecycles.
+    #[must_use]
+    pub fn with_position_lifecycle(mut self, lifecycle: Arc<PositionLifecycleTracker>) -> Self {
+        self.lifecycle = Some(lifecycle);
+        self
+    }
+
     /// Execute the use case.
-    pub async fn execute(&self, request: SubmitOrdersRequestDto) -> SubmitOrdersResponseDto {
+    pub async fn execute(&self, mut request: SubmitOrdersRequestDto) -> SubmitOrdersResponseDto {
+        // Held until every broker call for the plan has returned
+        let _in_flight = match self.admit() {
+            Ok(in_flight) => in_flight,
+            Err(violation) => return SubmitOrdersResponseDto::risk_rejected(vec![violation]),
+        };
+
+        // Size scale orders against the live position
+        if let Err(violations) = self
+            .net_positions(&mut request.orders, request.validate_risk)
+            .await
+        {
+            return SubmitOrdersResponseDto::risk_rejected(violations);
+        }
+
+        let quotes = self.quote_orders(&request.orders).await;
+        if let Err(violations) = self.check_price_sanity(&mut request.orders, &quotes) {
+            return SubmitOrdersResponseDto::risk_rejected(violations);
+        }
+        if let Err(violations) = self.check_tick_sizes(&mut request.orders) {
+            return SubmitOrdersResponseDto::risk_rejected(violations);
+        }
+
         // 1. Create domain orders
         let orders_result: Result<Vec<Order>, OrderError> =
             request.orders.iter().map(Self::create_order).collect();
@@ -63,31 +315,54 @@ where
             }
         };
 
-        // 2. Validate risk if requested
-        if request.validate_risk
-            && let Err(violations) = self.validate_risk(&orders).await
-        {
+        // Tag orders with the decision cycle that produced them
+        if let Some(cycle_id) = &request.cycle_id {
+            for order in &mut orders {
+                order.tag_cycle(CycleId::new(cycle_id));
+            }
+        }
+
+        // The instrument universe is enforced even without risk validation
+        if let Err(violations) = self.check_instrument_universe(&orders) {
+            return SubmitOrdersResponseDto::risk_rejected(violations);
+        }
+
+        // Halts are a venue constraint, enforced even without risk validation
+        if let Some(halts) = &self.trading_halts {
+            let violations = halts.check(&orders);
+            if !violations.is_empty() {
+                return SubmitOrdersResponseDto::risk_rejected(
+                    violations.iter().map(ToString::to_string).collect(),
+                );
+            }
+        }
+
+        if let Err(violations) = self.check_execution_profiles(&request.orders, &quotes) {
             return SubmitOrdersResponseDto::risk_rejected(violations);
         }
 
+        // 2. Validate risk and asset constraints if requested
+        if request.validate_risk {
+            if let Err(violations) = self.validate_risk(&orders).await {
+                return SubmitOrdersResponseDto::risk_rejected(violations);
+            }
+            if let Err(violations) = self.check_assets(&orders).await {
+                return SubmitOrdersResponseDto::risk_rejected(violations);
+            }
+        }
+
         // 3. Submit orders to broker
         let mut submitted = Vec::new();
         let mut rejected = Vec::new();
 
-        for order in &mut orders {
+        for (order, dto) in orders.iter_mut().zip(&request.orders) {
+            // Fills are measured against the quote the order was checked with
+            if let Some(price) = quotes.get(&dto.client_order_id).and_then(reference_price) {
+                order.record_arrival_price(Money::new(price));
+            }
             match self.submit_to_broker(order).await {
                 Ok(()) => {
-                    // Save to repository
-                    if let Err(e) = self.order_repo.save(order).await {
-                        tracing::error!("Failed to save order: {}", e);
-                    }
-
-                    // Publish events
-                    let events = order.drain_events();
-                    if let Err(e) = self.event_publisher.publish_order_events(events).await {
-                        tracing::error!("Failed to publish events: {}", e);
-                    }
-
+                    self.record_submitted(order, dto, &quotes).await;
                     submitted.push(OrderResponseDto {
                         order: OrderDto::from_order(order),
                         error: None,
@@ -105,6 +380,48 @@ where
         SubmitOrdersResponseDto::partial(submitted, rejected)
     }
 
+    /// Log, store, track and publish an order the broker accepted.
+    async fn record_submitted(
+        &self,
+        order: &mut Order,
+        dto: &CreateOrderDto,
+        quotes: &HashMap<String, Quote>,
+    ) {
+        tracing::info!(
+            target: "audit",
+            order_id = %order.id(),
+            symbol = %order.symbol(),
+            side = %order.side(),
+            quantity = %order.quantity().amount(),
+            cycle_id = order.cycle_id().map(CycleId::as_str).unwrap_or_default(),
+            strategy_tag = order.strategy_tag().unwrap_or_default(),
+            "Order submitted"
+        );
+        if let Some(metrics) = &self.metrics {
+            metrics.record_order_submitted(order);
+        }
+
+        // Save to repository
+        if let Err(e) = self.order_repo.save(order).await {
+            tracing::error!("Failed to save order: {}", e);
+        }
+        if let Some(exposure) = &self.exposure {
+            exposure.sync_order(order);
+        }
+        if let Some(lifecycle) = &self.lifecycle
+            && let Err(e) = lifecycle.track_order(order, dto.decision.clone()).await
+        {
+            tracing::error!("Failed to track order lifecycle: {}", e);
+        }
+        self.shadow_order(order, dto, quotes);
+
+        // Publish events
+        let events = order.drain_events();
+        if let Err(e) = self.event_publisher.publish_order_events(events).await {
+            tracing::error!("Failed to publish events: {}", e);
+        }
+    }
+
     /// Create a domain Order from DTO.
     fn create_order(dto: &CreateOrderDto) -> Result<Order, OrderError> {
         let command = CreateOrderCommand {
@@ -113,13 +430,89 @@ where
             order_type: dto.order_type,
             quantity: Quantity::new(dto.quantity),
             limit_price: dto.limit_price.map(Money::new),
-            stop_price: None,
+            stop_price: dto.stop_price.map(Money::new),
             time_in_force: dto.time_in_force,
             purpose: dto.purpose,
             legs: vec![],
         };
 
-        Order::new(command)
+        let strategy_tag = dto
+            .decision
+            .as_ref()
+            .and_then(|d| d.strategy_tag.as_deref());
+        let mut order = match strategy_tag {
+            Some(tag) => Order::new_tagged(command, tag)?,
+            None => Order::new(command)?,
+        };
+        if let Some(exit) = dto.oto_exit {
+            order.arm_oto_exit(exit)?;
+        }
+        Ok(order)
+    }
+
+    /// Trim scale-outs to the size still available and reject scale-ins
+    /// that would breach per-instrument caps.
+    ///
+    /// Earlier orders in the plan count as working, so two scale-outs cannot
+    /// both sell the same shares. Caps come from the active risk policy and
+    /// are only enforced when risk validation is requested.
+    async fn net_positions(
+        &self,
+        orders: &mut [CreateOrderDto],
+        enforce_limits: bool,
+    ) -> Result<(), Vec<String>> {
+        let Some(exposure) = &self.exposure else {
+            return Ok(());
+        };
+        if !orders
+            .iter()
+            .any(|o| matches!(o.purpose, OrderPurpose::ScaleIn | OrderPurpose::ScaleOut))
+        {
+            return Ok(());
+        }
+
+        let mut netting = PositionNetting::new();
+        if enforce_limits && let Ok(Some(policy)) = self.risk_repo.find_active_policy().await {
+            netting = netting.with_limits(policy.limits().per_instrument.clone());
+        }
+
+        let mut positions: HashMap<String, SymbolPosition> = HashMap::new();
+        let mut violations = Vec::new();
+        for dto in orders.iter_mut() {
+            let position = positions
+                .entry(dto.symbol.clone())
+                .or_insert_with(|| exposure.symbol_position(&dto.symbol));
+            match netting.net(
+                &dto.symbol,
+                dto.purpose,
+                dto.side,
+                dto.quantity,
+                dto.limit_price,
+                *position,
+            ) {
+                Ok(quantity) => {
+                    if quantity < dto.quantity {
+                        tracing::info!(
+                            target: "audit",
+                            symbol = %dto.symbol,
+                            client_order_id = %dto.client_order_id,
+                            requested = %dto.quantity,
+                            netted = %quantity,
+                            "Scale-out trimmed to available position"
+                        );
+                        dto.quantity = quantity;
+                    }
+                    *position = position.with_working(dto.side, dto.quantity);
+                }
+                Err(violation) => violations.push(violation.to_string()),
+            }
+        }
+
+        if violations.is_empty() {
+            Ok(())
+        } else {
+            Err(violations)
+        }
     }
 
     /// Validate orders against risk limits.
@@ -151,8 +544,483 @@ where
         }
     }
 
+    /// Reject the plan if any order targets an untradable asset or a short
+    /// sell cannot be located.
+    ///
+    /// Warnings (e.g. hard-to-borrow names) are logged and do not block
+    /// submission.
+    async fn check_assets(&self, orders: &[Order]) -> Result<(), Vec<String>> {
+        // A zero balance means the risk context has no cash figure
+        let available_cash = self
+            .risk_repo
+            .build_risk_context()
+            .await
+            .ok()
+            .map(|ctx| ctx.cash.amount())
+            .filter(|cash| *cash > Decimal::ZERO);
+
+        let mut violations = self
+            .asset_metadata
+            .check_tradability(orders, available_cash)
+            .await;
+        violations.extend(self.asset_metadata.check_short_availability(orders).await);
+
+        let (errors, warnings): (Vec<_>, Vec<_>) = violations
+            .into_iter()
+            .partition(|v| v.severity != ViolationSeverity::Warning);
+
+        for warning in &warnings {
+            tracing::warn!(violation = %warning, "Asset constraint warning");
+        }
+
+        if errors.is_empty() {
+            Ok(())
+        } else {
+            Err(errors.iter().map(ToString::to_string).collect())
+        }
+    }
+
+    /// Quote the orders that a pre-submission check needs a quote for.
+    ///
+    /// Quotes for the whole batch are prefetched in one call. Stop-losses
+    /// only use prefetched quotes so protection is never held back by a
+    /// lookup. Once a lookup overruns the latency budget, the rest of the
+    /// batch skips the quote lookup and uses the default tactic.
+    ///
+    /// Returns the quotes by client order ID.
+    async fn quote_orders(&self, orders: &[CreateOrderDto]) -> HashMap<String, Quote> {
+        let needs_quote = |dto: &CreateOrderDto| {
+            let priced = dto.limit_price.is_some() || dto.stop_price.is_some();
+            let family = dto.decision.as_ref().and_then(|d| d.strategy_family);
+            let profiled = self.execution_profile(family).is_some_and(|profile| {
+                profile.max_spread_bps.is_some() || self.active_shadow().is_some()
+            });
+            (self.price_sanity.is_some() && priced)
+                || (profiled && dto.purpose != OrderPurpose::StopLoss)
+        };
+
+        let symbols: HashSet<&str> = orders
+            .iter()
+            .filter(|dto| needs_quote(dto))
+            .map(|dto| dto.symbol.as_str())
+            .collect();
+        if symbols.is_empty() {
+            return HashMap::new();
+        }
+        let (prefetched, mut degraded) = match self.prefetch_quotes(symbols).await {
+            Ok(quotes) => (quotes, false),
+            Err(budget) => {
+                tracing::warn!(
+                    budget_ms = budget.as_millis(),
+                    "Latency budget exceeded prefetching quotes"
+                );
+                (HashMap::new(), true)
+            }
+        };
+
+        let mut quotes = HashMap::new();
+        for dto in orders.iter().filter(|dto| needs_quote(dto)) {
+            let quote = if dto.purpose == OrderPurpose::StopLoss {
+                prefetched.get(&dto.symbol).cloned()
+            } else {
+                match self
+                    .budgeted_quote(&dto.symbol, &prefetched, degraded)
+                    .await
+                {
+                    Ok(quote) => quote,
+                    Err(budget) => {
+                        degraded = true;
+                        let family = dto.decision.as_ref().and_then(|d| d.strategy_family);
+                        tracing::warn!(
+                            target: "audit",
+                            symbol = %dto.symbol,
+                            client_order_id = %dto.client_order_id,
+                            purpose = ?dto.purpose,
+                            budget_ms = budget.as_millis(),
+                            tactic = ?self
+                                .execution_profile(family)
+                                .map(|profile| profile.default_tactic),
+                            "Latency budget exceeded, quote checks skipped and default tactic used"
+                        );
+                        None
+                    }
+                }
+            };
+            if let Some(quote) = quote {
+                quotes.insert(dto.client_order_id.clone(), quote);
+            }
+        }
+        quotes
+    }
+
+    /// Reject limit prices too far from the market and clamp stop prices on
+    /// the wrong side of it.
+    ///
+    /// A limit is measured against the quote mid, or against the stop price
+    /// for a stop-limit. Stop-losses are never rejected, only clamped; a
+    /// stop-limit's limit moves with its clamped stop. Orders without a
+    /// quote are not checked.
+    fn check_price_sanity(
+        &self,
+        orders: &mut [CreateOrderDto],
+        quotes: &HashMap<String, Quote>,
+    ) -> Result<(), Vec<String>> {
+        let Some(limits) = &self.price_sanity else {
+            return Ok(());
+        };
+
+        let mut violations = Vec::new();
+        for dto in orders.iter_mut() {
+            let Some(quote) = quotes.get(&dto.client_order_id) else {
+                continue;
+            };
+
+            if let Some(stop_price) = dto.stop_price
+                && let Some(clamped) =
+                    PriceSanityLimits::clamp_stop(dto.side, stop_price, quote.bid, quote.ask)
+            {
+                dto.stop_price = Some(clamped);
+                if dto.order_type == OrderType::StopLimit {
+                    dto.limit_price = dto.limit_price.map(|limit| limit + clamped - stop_price);
+                }
+                tracing::warn!(
+                    target: "audit",
+                    symbol = %dto.symbol,
+                    client_order_id = %dto.client_order_id,
+                    side = ?dto.side,
+                    stop_price = %stop_price,
+                    clamped = %clamped,
+                    bid = %quote.bid,
+                    ask = %quote.ask,
+                    "Stop price on the wrong side of the market clamped"
+                );
+            }
+
+            if dto.purpose == OrderPurpose::StopLoss {
+                continue;
+            }
+            let reference = match (dto.order_type, dto.stop_price) {
+                (OrderType::StopLimit, Some(stop_price)) => Some(stop_price),
+                _ => reference_price(quote),
+            };
+            if let (Some(limit_price), Some(reference)) = (dto.limit_price, reference)
+                && let Some(violation) = limits.check_limit(&quote.symbol, limit_price, reference)
+            {
+                violations.push(violation.to_string());
+            }
+        }
+
+        if violations.is_empty() {
+            Ok(())
+        } else {
+            Err(violations)
+        }
+    }
+
+    /// Put limit and stop prices on the tick grid and reject fractional
+    /// option contracts.
+    ///
+    /// Tick and lot sizes are a venue constraint, enforced even without
+    /// risk validation. Rounded prices are logged to the audit target.
+    /// Reject entries (or legs) in instruments outside the universe.
+    fn check_instrument_universe(&self, orders: &[Order]) -> Result<(), Vec<String>> {
+        let today = chrono::Utc::now().date_naive();
+        let mut violations = Vec::new();
+        for (index, order) in orders.iter().enumerate() {
+            if order.partial_fill().order_purpose().is_exit() {
+                continue;
+            }
+            let symbols = std::iter::once(order.symbol().clone()).chain(
+                order
+                    .legs()
+                    .iter()
+                    .map(|leg| Symbol::new(leg.instrument_id().as_str())),
+            );
+            for symbol in symbols {
+                let violation = self.instruments.check(&symbol).or_else(|| {
+                    self.membership
+                        .as_ref()
+                        .and_then(|membership| membership.check(&symbol, today))
+                });
+                if let Some(violation) = violation {
+                    violations.push(
+                        violation
+                            .with_field_path(format!("orders[{index}].symbol"))
+                            .to_string(),
+                    );
+                }
+            }
+        }
+        if violations.is_empty() {
+            Ok(())
+        } else {
+            Err(violations)
+        }
+    }
+
+    fn check_tick_sizes(&self, orders: &mut [CreateOrderDto]) -> Result<(), Vec<String>> {
+        let mut violations = Vec::new();
+        for (index, dto) in orders.iter_mut().enumerate() {
+            let symbol = Symbol::new(&dto.symbol);
+            if let Some(violation) = TickSizeRules::check_lot(&symbol, dto.quantity) {
+                violations.push(
+                    violation
+                        .with_field_path(format!("orders[{index}].quantity"))
+                        .to_string(),
+                );
+            }
+
+            let limit_price = dto
+                .limit_price
+                .map(|price| {
+                    self.tick_sizes
+                        .conform_limit(&symbol, dto.side, price)
+                        .map_err(|v| {
+                            v.with_field_path(format!("orders[{index}].limit_price"))
+                                .to_string()
+                        })
+                })
+                .transpose();
+            let stop_price = dto
+                .stop_price
+                .map(|price| {
+                    self.tick_sizes
+                        .conform_stop(&symbol, dto.side, price)
+                        .map_err(|v| {
+                            v.with_field_path(format!("orders[{index}].stop_price"))
+                                .to_string()
+                        })
+                })
+                .transpose();
+            match (limit_price, stop_price) {
+                (Ok(limit_price), Ok(stop_price)) => {
+                    if limit_price != dto.limit_price || stop_price != dto.stop_price {
+                        tracing::info!(
+                            target: "audit",
+                            symbol = %dto.symbol,
+                            client_order_id = %dto.client_order_id,
+                            limit_price = ?dto.limit_price,
+                            stop_price = ?dto.stop_price,
+                            rounded_limit_price = ?limit_price,
+                            rounded_stop_price = ?stop_price,
+                            "Off-tick prices rounded"
+                        );
+                    }
+                    dto.limit_price = limit_price;
+                    dto.stop_price = stop_price;
+                }
+                (limit_price, stop_price) => {
+                    violations.extend([limit_price.err(), stop_price.err()].into_iter().flatten());
+                }
+            }
+        }
+
+        if violations.is_empty() {
+            Ok(())
+        } else {
+            Err(violations)
+        }
+    }
+
+    /// Check order types and spreads against each order's execution profile.
+    ///
+    /// Stop-losses skip the spread check so protection is never held back,
+    /// and an order without a quote is only checked for its order type.
+    fn check_execution_profiles(
+        &self,
+        orders: &[CreateOrderDto],
+        quotes: &HashMap<String, Quote>,
+    ) -> Result<(), Vec<String>> {
+        if self.execution_profiles.is_none() {
+            return Ok(());
+        }
+
+        let mut violations = Vec::new();
+        for dto in orders {
+            let family = dto.decision.as_ref().and_then(|d| d.strategy_family);
+            let Some(profile) = self.execution_profile(family) else {
+                continue;
+            };
+            let spread_bps = quotes
+                .get(&dto.client_order_id)
+                .filter(|_| dto.purpose != OrderPurpose::StopLoss)
+                .and_then(Quote::spread_bps);
+
+            tracing::debug!(
+                symbol = %dto.symbol,
+                strategy_family = ?family,
+                tactic = %profile.default_tactic,
+                urgency = ?profile.urgency.for_purpose(dto.purpose),
+                spread_bps = ?spread_bps,
+                "Execution profile applied"
+            );
+            violations.extend(
+                profile
+                    .check(&dto.symbol, dto.order_type, spread_bps)
+                    .iter()
+                    .map(ToString::to_string),
+            );
+        }
+
+        if violations.is_empty() {
+            Ok(())
+        } else {
+            Err(violations)
+        }
+    }
+
+    /// Simulate the shadow configuration for a submitted order.
+    ///
+    /// Stop-losses, and orders submitted without a quote, are not shadowed.
+    fn shadow_order(&self, order: &Order, dto: &CreateOrderDto, quotes: &HashMap<String, Quote>) {
+        if dto.purpose == OrderPurpose::StopLoss {
+            return;
+        }
+        let family = dto.decision.as_ref().and_then(|d| d.strategy_family);
+        let (Some(shadow), Some(profile), Some(quote)) = (
+            self.active_shadow(),
+            self.execution_profile(family),
+            quotes.get(&dto.client_order_id),
+        ) else {
+            return;
+        };
+        shadow.record(order, family, &profile, quote);
+    }
+
+    /// The current execution profile for `family`, if profiles are set.
+    fn execution_profile(&self, family: Option<StrategyFamily>) -> Option<ExecutionProfile> {
+        self.execution_profiles.as_ref().map(|profiles| {
+            profiles
+                .read()
+                .unwrap_or_else(PoisonError::into_inner)
+                .for_family(family)
+                .clone()
+        })
+    }
+
+    /// Fetch quotes for `symbols` that are not cached, in one call.
+    ///
+    /// A failed fetch is logged and leaves the orders to look up their own
+    /// quotes. Returns the latency budget as the error if the fetch ran
+    /// over it.
+    async fn prefetch_quotes(
+        &self,
+        symbols: HashSet<&str>,
+    ) -> Result<HashMap<String, Quote>, Duration> {
+        let mut quotes = HashMap::new();
+        let Some(price_feed) = &self.price_feed else {
+            return Ok(quotes);
+        };
+
+        let mut missing = Vec::new();
+        for symbol in symbols {
+            match self
+                .quote_cache
+                .as_ref()
+                .and_then(|cache| cache.get(symbol))
+            {
+                Some(quote) => {
+                    quotes.insert(symbol.to_string(), quote);
+                }
+                None => missing.push(Symbol::new(symbol)),
+            }
+        }
+        if missing.is_empty() {
+            return Ok(quotes);
+        }
+
+        let fetch = price_feed.get_quotes(&missing);
+        let fetched = match self.latency_budget {
+            Some(budget) => tokio::time::timeout(budget, fetch)
+                .await
+                .map_err(|_| budget)?,
+            None => fetch.await,
+        };
+        match fetched {
+            Ok(fetched) => {
+                for quote in fetched {
+                    if let Some(cache) = &self.quote_cache {
+                        cache.insert(quote.clone());
+                    }
+                    quotes.insert(quote.symbol.as_str().to_string(), quote);
+                }
+            }
+            Err(e) => {
+                tracing::warn!(symbols = missing.len(), error = %e, "Quote prefetch failed");
+            }
+        }
+        Ok(quotes)
+    }
+
+    /// Quote lookup bounded by the latency budget.
+    ///
+    /// Prefetched quotes are used as is. Returns the budget as the error if
+    /// the lookup ran over it, or was skipped because an earlier one in the
+    /// batch did.
+    async fn budgeted_quote(
+        &self,
+        symbol: &str,
+        prefetched: &HashMap<String, Quote>,
+        degraded: bool,
+    ) -> Result<Option<Quote>, Duration> {
+        if let Some(quote) = prefetched.get(symbol) {
+            return Ok(Some(quote.clone()));
+        }
+        let Some(budget) = self.latency_budget else {
+            return Ok(self.quote(symbol).await);
+        };
+        if degraded {
+            return Err(budget);
+        }
+        tokio::time::timeout(budget, self.quote(symbol))
+            .await
+            .map_err(|_| budget)
+    }
+
+    /// Current quote of `symbol`, if available.
+    async fn quote(&self, symbol: &str) -> Option<Quote> {
+        let price_feed = self.price_feed.as_ref()?;
+        match price_feed.get_quote(&Symbol::new(symbol)).await {
+            Ok(quote) => {
+                if let Some(cache) = &self.quote_cache {
+                    cache.insert(quote.clone());
+                }
+                Some(quote)
+            }
+            Err(e) => {
+                tracing::warn!(symbol, error = %e, "Quote unavailable for pre-submission checks");
+                None
+            }
+        }
+    }
+
+    /// Admit the plan past the shutdown coordinator, leader election and
+    /// kill switch, if configured.
+    fn admit(&self) -> Result<Option<InFlight<'_>>, String> {
+        let in_flight = self
+            .shutdown
+            .as_ref()
+            .map(|shutdown| shutdown.admit().map_err(|v| v.to_string()))
+            .transpose()?;
+        if let Some(election) = &self.leader_election {
+            election.check().map_err(|v| v.to_string())?;
+        }
+        self.check_kill_switch()?;
+        Ok(in_flight)
+    }
+
+    /// Check the kill switch, if configured.
+    fn check_kill_switch(&self) -> Result<(), String> {
+        self.kill_switch
+            .as_ref()
+            .map_or(Ok(()), |switch| switch.check().map_err(|v| v.to_string()))
+    }
+
     /// Submit order to broker.
     async fn submit_to_broker(&self, order: &mut Order) -> Result<(), String> {
+        // Re-checked per order so a trip mid-plan stops the remaining orders
+        self.check_kill_switch()?;
+
         let request = SubmitOrderRequest {
             client_order_id: order.id().clone(),
             symbol: order.symbol().clone(),
@@ -163,12 +1031,30 @@ where
             stop_price: order.stop_price().map(|m| m.amount()),
             time_in_force: order.time_in_force(),
             extended_hours: false,
+            oto_exit: order.oto_exit().copied(),
         };
 
-        match self.broker.submit_order(request).await {
-            Ok(ack) => order.accept(ack.broker_order_id).map_err(|e| e.to_string()),
-            Err(e) => Err(e.to_string()),
-        }
+        let ack = self
+            .broker
+            .submit_order(request)
+            .await
+            .map_err(|e| e.to_string())?;
+        order
+            .accept(ack.broker_order_id)
+            .map_err(|e| e.to_string())?;
+        order.record_child_orders(ack.child_order_ids);
+        Ok(())
+    }
+}
+
+/// Price a limit is measured against: the mid of a two-sided quote, or
+/// whichever side is present.
+fn reference_price(quote: &Quote) -> Option<Decimal> {
+    match (quote.bid > Decimal::ZERO, quote.ask > Decimal::ZERO) {
+        (true, true) => Some(quote.mid()),
+        (true, false) => Some(quote.bid),
+        (false, true) => Some(quote.ask),
+        (false, false) => None,
     }
 }
 
@@ -176,13 +1062,21 @@ where
 mod tests {
     use super::*;
     use crate::application::ports::{
-        BrokerError, EventPublishError, InMemoryRiskRepository, NoOpEventPublisher, OrderAck,
+        BrokerError, DecisionContext, EventPublishError, InMemoryRiskRepository,
+        NoOpEventPublisher, OrderAck,
     };
+    use crate::application::services::TradingStatusUpdate;
+    use crate::domain::execution_tactics::{ExecutionProfile, StrategyFamily, TacticType};
     use crate::domain::order_execution::errors::OrderError;
     use crate::domain::order_execution::value_objects::{
-        OrderPurpose, OrderSide, OrderStatus, OrderType, TimeInForce,
+        Environment, FillReport, OrderSide, OrderStatus, OrderType, TimeInForce,
     };
-    use crate::domain::shared::{BrokerId, OrderId};
+    use crate::domain::risk_management::value_objects::{InstrumentFilter, MembershipPeriod};
+    use crate::domain::shared::{BrokerId, OrderId, Timestamp};
+    use crate::infrastructure::chaos::{
+        FaultInjectingPriceFeed, FaultInjector, FaultKind, FaultRule,
+    };
+    use crate::infrastructure::price_feed::MockPriceFeed;
     use async_trait::async_trait;
     use rust_decimal::Decimal;
     use std::collections::HashMap;
@@ -207,6 +1101,8 @@ mod tests {
                 status: OrderStatus::Accepted,
                 filled_qty: Decimal::ZERO,
                 avg_fill_price: None,
+                child_order_ids: Vec::new(),
+                leg_fills: Vec::new(),
             })
         }
 
@@ -243,6 +1139,24 @@ mod tests {
         ) -> Result<Vec<crate::application::ports::PositionInfo>, BrokerError> {
             Ok(vec![])
         }
+
+        async fn get_asset(
+            &self,
+            symbol: &crate::domain::shared::Symbol,
+        ) -> Result<Option<crate::application::ports::AssetInfo>, BrokerError> {
+            // XYZ is a non-shortable name; everything else is unknown
+            Ok(
+                (symbol.as_str() == "XYZ").then(|| crate::application::ports::AssetInfo {
+                    symbol: "XYZ".to_string(),
+                    active: true,
+                    tradable: true,
+                    shortable: false,
+                    easy_to_borrow: false,
+                    fractionable: false,
+                    marginable: true,
+                }),
+            )
+        }
     }
 
     // Mock order repository
@@ -334,8 +1248,11 @@ mod tests {
             order_type: OrderType::Market,
             quantity: Decimal::new(100, 0),
             limit_price: None,
+            stop_price: None,
             time_in_force: TimeInForce::Day,
             purpose: OrderPurpose::Entry,
+            decision: None,
+            oto_exit: None,
         }
     }
 
@@ -351,6 +1268,7 @@ mod tests {
         let request = SubmitOrdersRequestDto {
             orders: vec![create_order_dto()],
             validate_risk: false,
+            cycle_id: None,
         };
 
         let response = use_case.execute(request).await;
@@ -359,6 +1277,116 @@ mod tests {
         assert!(response.rejected.is_empty());
     }
 
+    #[tokio::test]
+    async fn submit_orders_carries_strategy_tag() {
+        let broker = Arc::new(MockBroker { should_fail: false });
+        let risk_repo = Arc::new(InMemoryRiskRepository::new());
+        let order_repo = Arc::new(MockOrderRepo::new());
+        let event_publisher = Arc::new(NoOpEventPublisher);
+
+        let use_case = SubmitOrdersUseCase::new(broker, risk_repo, order_repo, event_publisher);
+
+        let request = SubmitOrdersRequestDto {
+            orders: vec![CreateOrderDto {
+                decision: Some(DecisionContext {
+                    strategy_tag: Some("momentum".to_string()),
+                    ..DecisionContext::default()
+                }),
+                ..create_order_dto()
+            }],
+            validate_risk: false,
+            cycle_id: None,
+        };
+
+        let response = use_case.execute(request).await;
+
+        let order = &response.submitted[0].order;
+        assert_eq!(order.strategy_tag.as_deref(), Some("momentum"));
+        assert!(order.order_id.starts_with("momentum-"));
+    }
+
+    #[tokio::test]
+    async fn submit_orders_tags_cycle() {
+        let broker = Arc::new(MockBroker { should_fail: false });
+        let risk_repo = Arc::new(InMemoryRiskRepository::new());
+        let order_repo = Arc::new(MockOrderRepo::new());
+        let event_publisher = Arc::new(NoOpEventPublisher);
+
+        let use_case =
+            SubmitOrdersUseCase::new(broker, risk_repo, Arc::clone(&order_repo), event_publisher);
+
+        let request = SubmitOrdersRequestDto {
+            orders: vec![create_order_dto()],
+            validate_risk: false,
+            cycle_id: Some("cycle-42".to_string()),
+        };
+
+        let response = use_case.execute(request).await;
+
+        let order_id = OrderId::new(&response.submitted[0].order.order_id);
+        let saved = order_repo.find_by_id(&order_id).await.unwrap().unwrap();
+        assert_eq!(saved.cycle_id().unwrap().as_str(), "cycle-42");
+    }
+
+    #[tokio::test]
+    async fn submit_orders_rejects_unavailable_short() {
+        let broker = Arc::new(MockBroker { should_fail: false });
+        let risk_repo = Arc::new(InMemoryRiskRepository::new());
+        let order_repo = Arc::new(MockOrderRepo::new());
+        let event_publisher = Arc::new(NoOpEventPublisher);
+
+        let use_case = SubmitOrdersUseCase::new(broker, risk_repo, order_repo, event_publisher);
+
+        let request = SubmitOrdersRequestDto {
+            orders: vec![
+                create_order_dto(),
+                CreateOrderDto {
+                    client_order_id: "test-order-2".to_string(),
+                    symbol: "XYZ".to_string(),
+                    side: OrderSide::Sell,
+                    ..create_order_dto()
+                },
+            ],
+            validate_risk: true,
+            cycle_id: None,
+        };
+
+        let response = use_case.execute(request).await;
+
+        assert!(response.submitted.is_empty());
+        assert!(!response.success);
+        assert!(
+            response.risk_violations[0].contains("SHORT_NOT_AVAILABLE"),
+            "{:?}",
+            response.risk_violations
+        );
+    }
+
+    #[tokio::test]
+    async fn submit_orders_rejects_fractional_qty_on_whole_share_asset() {
+        let broker = Arc::new(MockBroker { should_fail: false });
+        let risk_repo = Arc::new(InMemoryRiskRepository::new());
+        let order_repo = Arc::new(MockOrderRepo::new());
+        let event_publisher = Arc::new(NoOpEventPublisher);
+
+        let use_case = SubmitOrdersUseCase::new(broker, risk_repo, order_repo, event_publisher);
+
+        let request = SubmitOrdersRequestDto {
+            orders: vec![CreateOrderDto {
+                symbol: "XYZ".to_string(),
+                quantity: Decimal::new(5, 1),
+                ..create_order_dto()
+            }],
+            validate_risk: true,
+            cycle_id: None,
+        };
+
+        let response = use_case.execute(request).await;
+
+        assert!(response.submitted.is_empty());
+        assert!(response.risk_violations[0].contains("ASSET_NOT_FRACTIONABLE"));
+    }
+
     #[tokio::test]
     async fn submit_orders_broker_rejection() {
         let broker = Arc::new(MockBroker { should_fail: true });
@@ -371,6 +1399,7 @@ mod tests {
         let request = SubmitOrdersRequestDto {
             orders: vec![create_order_dto()],
             validate_risk: false,
+            cycle_id: None,
         };
 
         let response = use_case.execute(request).await;
@@ -379,6 +1408,498 @@ mod tests {
         assert!(!response.rejected.is_empty());
     }
 
+    #[tokio::test]
+    async fn submit_orders_blocked_by_kill_switch() {
+        let broker = Arc::new(MockBroker { should_fail: false });
+        let risk_repo = Arc::new(InMemoryRiskRepository::new());
+        let order_repo = Arc::new(MockOrderRepo::new());
+        let event_publisher = Arc::new(NoOpEventPublisher);
+        let kill_switch = Arc::new(KillSwitch::default());
+        kill_switch.trip("operator halt");
+
+        let use_case = SubmitOrdersUseCase::new(broker, risk_repo, order_repo, event_publisher)
+            .with_kill_switch(kill_switch);
+
+        let request = SubmitOrdersRequestDto {
+            orders: vec![create_order_dto()],
+            validate_risk: false,
+            cycle_id: None,
+        };
+
+        let response = use_case.execute(request).await;
+
+        assert!(response.submitted.is_empty());
+        assert!(!response.success);
+        assert!(response.risk_violations[0].contains("TRADING_DISABLED"));
+    }
+
+    #[tokio::test]
+    async fn submit_orders_rejected_while_shutting_down() {
+        let broker = Arc::new(MockBroker { should_fail: false });
+        let risk_repo = Arc::new(InMemoryRiskRepository::new());
+        let order_repo = Arc::new(MockOrderRepo::new());
+        let event_publisher = Arc::new(NoOpEventPublisher);
+        let shutdown = Arc::new(ShutdownCoordinator::new());
+
+        let use_case = SubmitOrdersUseCase::new(broker, risk_repo, order_repo, event_publisher)
+            .with_shutdown(Arc::clone(&shutdown));
+        let request = SubmitOrdersRequestDto {
+            orders: vec![create_order_dto()],
+            validate_risk: false,
+            cycle_id: None,
+        };
+
+        assert!(use_case.execute(request.clone()).await.success);
+        assert_eq!(shutdown.in_flight(), 0);
+
+        shutdown.stop_accepting();
+        let response = use_case.execute(request).await;
+
+        assert!(response.submitted.is_empty());
+        assert!(response.risk_violations[0].contains("SHUTTING_DOWN"));
+        assert!(!use_case.is_accepting_plans());
+    }
+
+    #[tokio::test]
+    async fn submit_orders_rejects_halted_symbol() {
+        let broker = Arc::new(MockBroker { should_fail: false });
+        let risk_repo = Arc::new(InMemoryRiskRepository::new());
+        let order_repo = Arc::new(MockOrderRepo::new());
+        let event_publisher = Arc::new(NoOpEventPublisher);
+        let halts = Arc::new(TradingHalts::new());
+        halts.apply(&TradingStatusUpdate {
+            symbol: "AAPL".to_string(),
+            status_code: "H".to_string(),
+            status_message: "Trading Halt".to_string(),
+            reason_code: "LUDP".to_string(),
+            reason_message: "Volatility Trading Pause".to_string(),
+            timestamp: Timestamp::now(),
+        });
+
+        let use_case = SubmitOrdersUseCase::new(broker, risk_repo, order_repo, event_publisher)
+            .with_trading_halts(Arc::clone(&halts));
+
+        let request = SubmitOrdersRequestDto {
+            orders: vec![create_order_dto()],
+            validate_risk: false,
+            cycle_id: None,
+        };
+        let response = use_case.execute(request.clone()).await;
+
+        assert!(response.submitted.is_empty());
+        assert!(response.risk_violations[0].contains("HALTED_SYMBOL"));
+
+        halts.apply(&TradingStatusUpdate {
+            symbol: "AAPL".to_string(),
+            status_code: "T".to_string(),
+            status_message: "Trading Resumption".to_string(),
+            reason_code: String::new(),
+            reason_message: String::new(),
+            timestamp: Timestamp::now(),
+        });
+        let response = use_case.execute(request).await;
+
+        assert_eq!(response.submitted.len(), 1);
+    }
+
+    #[tokio::test]
+    async fn submit_orders_enforces_instrument_universe() {
+        let broker = Arc::new(MockBroker { should_fail: false });
+        let risk_repo = Arc::new(InMemoryRiskRepository::new());
+        let order_repo = Arc::new(MockOrderRepo::new());
+        let event_publisher = Arc::new(NoOpEventPublisher);
+        let universe = InstrumentUniverse {
+            allow: InstrumentFilter::default(),
+            block: InstrumentFilter {
+                symbols: vec!["AAPL".to_string()],
+                ..InstrumentFilter::default()
+            },
+        };
+
+        let use_case = SubmitOrdersUseCase::new(broker, risk_repo, order_repo, event_publisher)
+            .with_instrument_universe(universe);
+
+        let response = use_case
+            .execute(SubmitOrdersRequestDto {
+                orders: vec![create_order_dto()],
+                validate_risk: false,
+                cycle_id: None,
+            })
+            .await;
+        assert!(response.submitted.is_empty());
+        assert!(response.risk_violations[0].contains("SYMBOL_BLOCKED"));
+
+        // Closing a position in a blocked symbol is still allowed
+        let exit = CreateOrderDto {
+            side: OrderSide::Sell,
+            purpose: OrderPurpose::Exit,
+            ..create_order_dto()
+        };
+        let response = use_case
+            .execute(SubmitOrdersRequestDto {
+                orders: vec![exit],
+                validate_risk: false,
+                cycle_id: None,
+            })
+            .await;
+        assert_eq!(response.submitted.len(), 1);
+    }
+
+    #[tokio::test]
+    async fn submit_orders_enforces_universe_membership() {
+        let broker = Arc::new(MockBroker { should_fail: false });
+        let risk_repo = Arc::new(InMemoryRiskRepository::new());
+        let order_repo = Arc::new(MockOrderRepo::new());
+        let event_publisher = Arc::new(NoOpEventPublisher);
+        let today = chrono::Utc::now().date_naive();
+        let mut membership = UniverseMembership::new("sp500");
+        membership.add(
+            "AAPL",
+            MembershipPeriod {
+                added: today - chrono::Days::new(30),
+                removed: Some(today),
+            },
+        );
+        membership.add(
+            "MSFT",
+            MembershipPeriod {
+                added: today - chrono::Days::new(30),
+                removed: None,
+            },
+        );
+
+        let use_case = SubmitOrdersUseCase::new(broker, risk_repo, order_repo, event_publisher)
+            .with_universe_membership(Arc::new(membership));
+
+        // AAPL left the universe today
+        let response = use_case
+            .execute(SubmitOrdersRequestDto {
+                orders: vec![create_order_dto()],
+                validate_risk: false,
+                cycle_id: None,
+            })
+            .await;
+        assert!(response.submitted.is_empty());
+        assert!(response.risk_violations[0].contains("SYMBOL_NOT_IN_UNIVERSE"));
+
+        let member = CreateOrderDto {
+            client_order_id: "test-order-2".to_string(),
+            symbol: "MSFT".to_string(),
+            ..create_order_dto()
+        };
+        let response = use_case
+            .execute(SubmitOrdersRequestDto {
+                orders: vec![member],
+                validate_risk: false,
+                cycle_id: None,
+            })
+            .await;
+        assert_eq!(response.submitted.len(), 1);
+    }
+
+    #[tokio::test]
+    async fn submit_orders_applies_strategy_family_profile() {
+        let broker = Arc::new(MockBroker { should_fail: false });
+        let risk_repo = Arc::new(InMemoryRiskRepository::new());
+        let order_repo = Arc::new(MockOrderRepo::new());
+        let event_publisher = Arc::new(NoOpEventPublisher);
+        let price_feed = Arc::new(MockPriceFeed::new());
+        // 0.01 spread on a $1 quote is ~100 bps
+        price_feed.set_price("WIDE", Decimal::ONE);
+        let profiles = ExecutionProfiles::default().with_profile(
+            StrategyFamily::VerticalSpread,
+            ExecutionProfile {
+                max_spread_bps: Some(Decimal::from(50)),
+                allowed_order_types: vec![OrderType::Limit],
+                ..ExecutionProfile::default()
+            },
+        );
+
+        let use_case = SubmitOrdersUseCase::new(broker, risk_repo, order_repo, event_publisher)
+            .with_execution_profiles(Arc::new(RwLock::new(profiles)))
+            .with_price_feed(price_feed);
+
+        let spread_decision = Some(DecisionContext {
+            strategy_family: Some(StrategyFamily::VerticalSpread),
+            ..DecisionContext::default()
+        });
+        let request = |symbol: &str, order_type: OrderType| SubmitOrdersRequestDto {
+            orders: vec![CreateOrderDto {
+                symbol: symbol.to_string(),
+                order_type,
+                limit_price: (order_type == OrderType::Limit).then_some(Decimal::ONE),
+                decision: spread_decision.clone(),
+                ..create_order_dto()
+            }],
+            validate_risk: false,
+            cycle_id: None,
+        };
+
+        let response = use_case.execute(request("AAPL", OrderType::Market)).await;
+        assert!(response.risk_violations[0].contains("ORDER_TYPE_NOT_ALLOWED"));
+
+        let response = use_case.execute(request("WIDE", OrderType::Limit)).await;
+        assert!(response.risk_violations[0].contains("SPREAD_TOO_WIDE"));
+
+        let response = use_case.execute(request("AAPL", OrderType::Limit)).await;
+        assert_eq!(response.submitted.len(), 1);
+
+        // Orders without a family use the unrestricted default profile
+        let response = use_case
+            .execute(SubmitOrdersRequestDto {
+                orders: vec![create_order_dto()],
+                validate_risk: false,
+                cycle_id: None,
+            })
+            .await;
+        assert_eq!(response.submitted.len(), 1);
+    }
+
+    #[tokio::test]
+    async fn submit_orders_reuses_cached_quotes() {
+        let broker = Arc::new(MockBroker { should_fail: false });
+        let risk_repo = Arc::new(InMemoryRiskRepository::new());
+        let order_repo = Arc::new(MockOrderRepo::new());
+        let event_publisher = Arc::new(NoOpEventPublisher);
+        let price_feed = Arc::new(MockPriceFeed::new());
+        price_feed.set_price("WIDE", Decimal::ONE);
+        let quote_cache = Arc::new(QuoteCache::new(Duration::from_secs(60)));
+        let profiles = ExecutionProfiles::default().with_profile(
+            StrategyFamily::VerticalSpread,
+            ExecutionProfile {
+                max_spread_bps: Some(Decimal::from(50)),
+                ..ExecutionProfile::default()
+            },
+        );
+
+        let use_case = SubmitOrdersUseCase::new(broker, risk_repo, order_repo, event_publisher)
+            .with_execution_profiles(Arc::new(RwLock::new(profiles)))
+            .with_price_feed(Arc::clone(&price_feed) as Arc<dyn PriceFeedPort>)
+            .with_quote_cache(Arc::clone(&quote_cache));
+
+        let request = || SubmitOrdersRequestDto {
+            orders: vec![CreateOrderDto {
+                symbol: "WIDE".to_string(),
+                decision: Some(DecisionContext {
+                    strategy_family: Some(StrategyFamily::VerticalSpread),
+                    ..DecisionContext::default()
+                }),
+                ..create_order_dto()
+            }],
+            validate_risk: false,
+            cycle_id: None,
+        };
+
+        let response = use_case.execute(request()).await;
+        assert!(response.risk_violations[0].contains("SPREAD_TOO_WIDE"));
+        assert_eq!(quote_cache.stats().entries, 1);
+
+        // The next cycle is checked against the cached quote, not the feed
+        price_feed.set_price("WIDE", Decimal::from(100));
+        let response = use_case.execute(request()).await;
+        assert!(response.risk_violations[0].contains("SPREAD_TOO_WIDE"));
+        assert_eq!(quote_cache.stats().hits, 1);
+    }
+
+    #[tokio::test]
+    async fn submit_orders_shadows_quoted_orders() {
+        let broker = Arc::new(MockBroker { should_fail: false });
+        let risk_repo = Arc::new(InMemoryRiskRepository::new());
+        let order_repo = Arc::new(MockOrderRepo::new());
+        let event_publisher = Arc::new(NoOpEventPublisher);
+        let price_feed = Arc::new(MockPriceFeed::new());
+        price_feed.set_price("AAPL", Decimal::from(100));
+        let shadow = Arc::new(ShadowExecution::new(ExecutionProfiles::new(
+            ExecutionProfile {
+                default_tactic: TacticType::AggressiveLimit,
+                ..ExecutionProfile::default()
+            },
+        )));
+
+        let use_case = SubmitOrdersUseCase::new(broker, risk_repo, order_repo, event_publisher)
+            .with_execution_profiles(Arc::new(RwLock::new(ExecutionProfiles::default())))
+            .with_price_feed(price_feed as Arc<dyn PriceFeedPort>)
+            .with_shadow_execution(Arc::clone(&shadow));
+
+        let request = SubmitOrdersRequestDto {
+            orders: vec![
+                create_order_dto(),
+                CreateOrderDto {
+                    client_order_id: "test-stop-1".to_string(),
+                    side: OrderSide::Sell,
+                    order_type: OrderType::Stop,
+                    stop_price: Some(Decimal::from(95)),
+                    purpose: OrderPurpose::StopLoss,
+                    ..create_order_dto()
+                },
+            ],
+            validate_risk: false,
+            cycle_id: None,
+        };
+
+        let response = use_case.execute(request).await;
+        assert_eq!(response.submitted.len(), 2);
+
+        // Stop-losses are submitted unquoted, so only the entry is shadowed
+        assert_eq!(shadow.len(), 1);
+    }
+
+    #[tokio::test]
+    async fn submit_orders_rejects_limit_prices_far_from_the_market() {
+        let broker = Arc::new(MockBroker { should_fail: false });
+        let risk_repo = Arc::new(InMemoryRiskRepository::new());
+        let order_repo = Arc::new(MockOrderRepo::new());
+        let event_publisher = Arc::new(NoOpEventPublisher);
+        let price_feed = Arc::new(MockPriceFeed::new());
+        price_feed.set_price("AAPL", Decimal::from(100));
+
+        let use_case = SubmitOrdersUseCase::new(broker, risk_repo, order_repo, event_publisher)
+            .with_price_feed(price_feed as Arc<dyn PriceFeedPort>)
+            .with_price_sanity(PriceSanityLimits::default());
+
+        let limit = |price| SubmitOrdersRequestDto {
+            orders: vec![CreateOrderDto {
+                order_type: OrderType::Limit,
+                limit_price: Some(price),
+                ..create_order_dto()
+            }],
+            validate_risk: false,
+            cycle_id: None,
+        };
+
+        let response = use_case.execute(limit(Decimal::from(1000))).await;
+        assert!(!response.success);
+        assert!(response.risk_violations[0].contains("PRICE_SANITY"));
+
+        let response = use_case.execute(limit(Decimal::from(101))).await;
+        assert_eq!(response.submitted.len(), 1);
+    }
+
+    #[tokio::test]
+    async fn submit_orders_clamps_stops_on_the_wrong_side_of_the_market() {
+        let broker = Arc::new(MockBroker { should_fail: false });
+        let risk_repo = Arc::new(InMemoryRiskRepository::new());
+        let order_repo = Arc::new(MockOrderRepo::new());
+        let event_publisher = Arc::new(NoOpEventPublisher);
+        let price_feed = Arc::new(MockPriceFeed::new());
+        price_feed.set_price("AAPL", Decimal::from(100));
+
+        let use_case =
+            SubmitOrdersUseCase::new(broker, risk_repo, Arc::clone(&order_repo), event_publisher)
+                .with_price_feed(price_feed as Arc<dyn PriceFeedPort>)
+                .with_price_sanity(PriceSanityLimits::default());
+
+        let request = SubmitOrdersRequestDto {
+            orders: vec![CreateOrderDto {
+                side: OrderSide::Sell,
+                order_type: OrderType::Stop,
+                stop_price: Some(Decimal::from(105)),
+                purpose: OrderPurpose::StopLoss,
+                ..create_order_dto()
+            }],
+            validate_risk: false,
+            cycle_id: None,
+        };
+
+        // A sell stop above the bid would trigger on arrival
+        let response = use_case.execute(request).await;
+        assert_eq!(response.submitted.len(), 1);
+        let order = order_repo.find_active().await.unwrap().remove(0);
+        assert_eq!(order.stop_price(), Some(Money::new(Decimal::new(9999, 2))));
+    }
+
+    #[tokio::test]
+    async fn submit_orders_enforces_tick_and_lot_sizes() {
+        let broker = Arc::new(MockBroker { should_fail: false });
+        let risk_repo = Arc::new(InMemoryRiskRepository::new());
+        let order_repo = Arc::new(MockOrderRepo::new());
+        let event_publisher = Arc::new(NoOpEventPublisher);
+
+        let use_case = SubmitOrdersUseCase::new(broker, risk_repo, order_repo, event_publisher);
+        let submit = |dto| SubmitOrdersRequestDto {
+            orders: vec![dto],
+            validate_risk: false,
+            cycle_id: None,
+        };
+
+        // Sub-penny buy limits round down onto the penny grid
+        let response = use_case
+            .execute(submit(CreateOrderDto {
+                order_type: OrderType::Limit,
+                limit_price: Some(Decimal::new(150_005, 3)),
+                ..create_order_dto()
+            }))
+            .await;
+        assert_eq!(
+            response.submitted[0].order.limit_price,
+            Some(Decimal::from(150))
+        );
+
+        let response = use_case
+            .execute(submit(CreateOrderDto {
+                symbol: "AAPL250117C00150000".to_string(),
+                quantity: Decimal::new(15, 1),
+                ..create_order_dto()
+            }))
+            .await;
+        assert!(response.risk_violations[0].contains("INVALID_LOT_SIZE"));
+    }
+
+    #[tokio::test]
+    async fn submit_orders_degrades_when_quote_exceeds_latency_budget() {
+        let broker = Arc::new(MockBroker { should_fail: false });
+        let risk_repo = Arc::new(InMemoryRiskRepository::new());
+        let order_repo = Arc::new(MockOrderRepo::new());
+        let event_publisher = Arc::new(NoOpEventPublisher);
+        let feed = MockPriceFeed::new();
+        feed.set_price("WIDE", Decimal::ONE);
+        let faults = FaultInjector::new(
+            "feed",
+            vec![FaultRule::new(
+                &["get_quote", "get_quotes"],
+                FaultKind::Latency { delay_ms: 500 },
+            )],
+            Some(1),
+            Environment::Paper,
+        )
+        .unwrap();
+        let profiles = ExecutionProfiles::default().with_profile(
+            StrategyFamily::VerticalSpread,
+            ExecutionProfile {
+                max_spread_bps: Some(Decimal::from(50)),
+                ..ExecutionProfile::default()
+            },
+        );
+
+        let use_case = SubmitOrdersUseCase::new(broker, risk_repo, order_repo, event_publisher)
+            .with_execution_profiles(Arc::new(RwLock::new(profiles)))
+            .with_price_feed(Arc::new(FaultInjectingPriceFeed::new(feed, faults)))
+            .with_latency_budget(Duration::from_millis(20));
+
+        let order = |id: &str| CreateOrderDto {
+            client_order_id: id.to_string(),
+            symbol: "WIDE".to_string(),
+            decision: Some(DecisionContext {
+                strategy_family: Some(StrategyFamily::VerticalSpread),
+                ..DecisionContext::default()
+            }),
+            ..create_order_dto()
+        };
+        let start = std::time::Instant::now();
+        let response = use_case
+            .execute(SubmitOrdersRequestDto {
+                orders: vec![order("order-1"), order("order-2")],
+                validate_risk: false,
+                cycle_id: None,
+            })
+            .await;
+
+        // The wide spread goes unseen rather than holding the orders back,
+        // and the batch waits out the budget only once
+        assert_eq!(response.submitted.len(), 2);
+        assert!(start.elapsed() < Duration::from_millis(500));
+    }
+
     #[tokio::test]
     async fn submit_orders_invalid_order_dto() {
         let broker = Arc::new(MockBroker { should_fail: false });
@@ -396,13 +1917,17 @@ mod tests {
             order_type: OrderType::Limit,
             quantity: Decimal::new(100, 0),
             limit_price: Some(Decimal::new(-10, 0)), // Invalid negative price
+            stop_price: None,
             time_in_force: TimeInForce::Day,
             purpose: OrderPurpose::Entry,
+            decision: None,
+            oto_exit: None,
         };
 
         let request = SubmitOrdersRequestDto {
             orders: vec![invalid_dto],
             validate_risk: false,
+            cycle_id: None,
         };
 
         let response = use_case.execute(request).await;
@@ -424,6 +1949,7 @@ mod tests {
         let request = SubmitOrdersRequestDto {
             orders: vec![create_order_dto()],
             validate_risk: true, // Enable risk validation
+            cycle_id: None,
         };
 
         let response = use_case.execute(request).await;
@@ -515,6 +2041,7 @@ mod tests {
         let request = SubmitOrdersRequestDto {
             orders: vec![create_order_dto()],
             validate_risk: true,
+            cycle_id: None,
         };
 
         let response = use_case.execute(request).await;
@@ -599,6 +2126,7 @@ mod tests {
         let request = SubmitOrdersRequestDto {
             orders: vec![create_order_dto()],
             validate_risk: true,
+            cycle_id: None,
         };
 
         let response = use_case.execute(request).await;
@@ -659,6 +2187,7 @@ mod tests {
         let request = SubmitOrdersRequestDto {
             orders: vec![create_order_dto()],
             validate_risk: false,
+            cycle_id: None,
         };
 
         let response = use_case.execute(request).await;
@@ -694,6 +2223,7 @@ mod tests {
         let request = SubmitOrdersRequestDto {
             orders: vec![create_order_dto()],
             validate_risk: false,
+            cycle_id: None,
         };
 
         let response = use_case.execute(request).await;
@@ -701,4 +2231,104 @@ mod tests {
         // Publish error is logged but order is still reported as submitted
         assert!(!response.submitted.is_empty());
     }
+
+    /// Exposure cache holding a filled long position in `symbol`.
+    fn exposure_holding(symbol: &str, quantity: i64) -> Arc<ExposureCache> {
+        let mut order = Order::new(CreateOrderCommand {
+            symbol: Symbol::new(symbol),
+            side: OrderSide::Buy,
+            order_type: OrderType::Market,
+            quantity: Quantity::from_i64(quantity),
+            limit_price: None,
+            stop_price: None,
+            time_in_force: TimeInForce::Day,
+            purpose: OrderPurpose::Entry,
+            legs: vec![],
+        })
+        .unwrap();
+        order.accept(BrokerId::new("broker-entry")).unwrap();
+        order
+            .apply_fill(FillReport::new(
+                "fill-entry",
+                Quantity::from_i64(quantity),
+                Money::usd(40.0),
+                Timestamp::now(),
+                "NYSE",
+            ))
+            .unwrap();
+
+        let exposure = Arc::new(ExposureCache::new());
+        exposure.sync_order(&order);
+        exposure
+    }
+
+    #[tokio::test]
+    async fn submit_orders_trims_scale_outs_to_position() {
+        let broker = Arc::new(MockBroker { should_fail: false });
+        let risk_repo = Arc::new(InMemoryRiskRepository::new());
+        let order_repo = Arc::new(MockOrderRepo::new());
+        let event_publisher = Arc::new(NoOpEventPublisher);
+
+        let use_case = SubmitOrdersUseCase::new(broker, risk_repo, order_repo, event_publisher)
+            .with_exposure_cache(exposure_holding("AAPL", 100));
+
+        let scale_out = CreateOrderDto {
+            side: OrderSide::Sell,
+            quantity: Decimal::new(60, 0),
+            purpose: OrderPurpose::ScaleOut,
+            ..create_order_dto()
+        };
+        let request = SubmitOrdersRequestDto {
+            orders: vec![
+                scale_out.clone(),
+                CreateOrderDto {
+                    client_order_id: "test-order-2".to_string(),
+                    ..scale_out
+                },
+            ],
+            validate_risk: false,
+            cycle_id: None,
+        };
+
+        let response = use_case.execute(request).await;
+
+        // The second scale-out only gets what the first left over
+        let quantities: Vec<Decimal> = response
+            .submitted
+            .iter()
+            .map(|r| r.order.quantity)
+            .collect();
+        assert_eq!(quantities, vec![Decimal::new(60, 0), Decimal::new(40, 0)]);
+    }
+
+    #[tokio::test]
+    async fn submit_orders_rejects_scale_in_over_instrument_cap() {
+        let broker = Arc::new(MockBroker { should_fail: false });
+        let risk_repo = Arc::new(RiskRepoWithPolicyButFailingContext);
+        let order_repo = Arc::new(MockOrderRepo::new());
+        let event_publisher = Arc::new(NoOpEventPublisher);
+
+        let use_case = SubmitOrdersUseCase::new(broker, risk_repo, order_repo, event_publisher)
+            .with_exposure_cache(exposure_holding("AAPL", 900));
+
+        // 900 held + 200 exceeds the default 1000-unit cap
+        let request = SubmitOrdersRequestDto {
+            orders: vec![CreateOrderDto {
+                quantity: Decimal::new(200, 0),
+                purpose: OrderPurpose::ScaleIn,
+                ..create_order_dto()
+            }],
+            validate_risk: true,
+            cycle_id: None,
+        };
+
+        let response = use_case.execute(request).await;
+
+        assert!(response.submitted.is_empty());
+        assert!(
+            response.risk_violations[0].contains("SCALE_IN_UNITS_EXCEEDED"),
+            "{:?}",
+            response.risk_violations
+        );
+    }
 }
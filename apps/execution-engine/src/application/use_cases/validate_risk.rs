@@ -8,7 +8,9 @@ use crate::application::dto::{
 use crate::application::ports::RiskRepositoryPort;
 use crate::domain::order_execution::aggregate::Order;
 use crate::domain::order_execution::repository::OrderRepository;
+use crate::domain::risk_management::errors::RiskError;
 use crate::domain::risk_management::services::RiskValidationService;
+use crate::domain::risk_management::value_objects::ConcentrationGroups;
 use crate::domain::shared::OrderId;
 
 /// Use case for validating orders against risk limits.
@@ -34,6 +36,15 @@ where
         }
     }
 
+    /// Sector and correlation groupings for concentration checks.
+    ///
+    /// # Errors
+    ///
+    /// Returns error if the reference data cannot be loaded.
+    pub async fn concentration_groups(&self) -> Result<ConcentrationGroups, RiskError> {
+        self.risk_repo.get_concentration_groups().await
+    }
+
     /// Execute the use case.
     ///
     /// # Errors
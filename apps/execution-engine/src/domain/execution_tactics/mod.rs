@@ -8,9 +8,14 @@ pub mod services;
 pub mod value_objects;
 
 pub use errors::TacticError;
-pub use services::{AdaptiveExecutor, IcebergExecutor, TacticSelector, TwapExecutor, VwapExecutor};
+pub use services::{
+    AdaptiveExecutor, IcebergExecutor, NetPriceLadder, PacingController, TacticSelector,
+    TwapExecutor, VwapExecutor,
+};
 pub use value_objects::{
-    AdaptiveConfig, AggressiveLimitConfig, IcebergConfig, IcebergPeak, MarketContext, MarketState,
-    PassiveLimitConfig, SliceType, SubTactic, TacticConfig, TacticSelectionContext, TacticType,
-    TacticUrgency, TwapConfig, TwapSlice, Urgency, VwapConfig, VwapSlice,
+    AdaptiveConfig, AggressiveLimitConfig, ExecutionProfile, ExecutionProfiles, IcebergConfig,
+    IcebergPeak, MarketContext, MarketState, NetLadderConfig, ORDER_TYPE_NOT_ALLOWED, PaceDecision,
+    PacingConfig, PassiveLimitConfig, SPREAD_TOO_WIDE, SliceType, StrategyFamily, SubTactic,
+    TacticConfig, TacticSelectionContext, TacticType, TacticUrgency, TwapConfig, TwapSlice,
+    Urgency, UrgencyMapping, VolatilityBand, VwapConfig, VwapSlice,
 };
@@ -2,11 +2,12 @@
 
 use rust_decimal::Decimal;
 
-use crate::domain::execution_tactics::value_objects::{IcebergConfig, IcebergPeak};
+use crate::domain::execution_tactics::value_objects::{IcebergConfig, IcebergPeak, PaceDecision};
 
 /// Iceberg executor for hidden order execution.
 ///
 /// Shows only a small visible portion of the total order, replenishing on fills.
+/// Replenishment can be slowed, paused or completed with [`Self::pace`].
 #[derive(Debug, Clone)]
 pub struct IcebergExecutor {
     /// Total hidden quantity.
@@ -17,6 +18,12 @@ pub struct IcebergExecutor {
     filled_qty: Decimal,
     /// Peak number.
     peak_number: usize,
+    /// Volatility multiplier on the replenish interval.
+    pace_multiplier: Decimal,
+    /// Replenishment is held by a volatility pause.
+    paused: bool,
+    /// A peak was withheld during the pause.
+    held: bool,
     /// Configuration.
     config: IcebergConfig,
 }
@@ -32,6 +39,9 @@ impl IcebergExecutor {
             display_qty,
             filled_qty: Decimal::ZERO,
             peak_number: 0,
+            pace_multiplier: Decimal::ONE,
+            paused: false,
+            held: false,
             config,
         }
     }
@@ -52,6 +62,10 @@ impl IcebergExecutor {
         if self.is_complete() {
             return None;
         }
+        if self.paused {
+            self.held = true;
+            return None;
+        }
 
         let remaining = self.remaining_qty();
         let next_display = self.display_qty.min(remaining);
@@ -59,6 +73,59 @@ impl IcebergExecutor {
         Some(IcebergPeak::new(next_display, self.peak_number))
     }
 
+    /// Apply a volatility pace to the peaks not yet shown.
+    ///
+    /// `Slow` shows smaller peaks (the display size divided by the
+    /// multiplier, at least one unit) and stretches the replenish interval by
+    /// the multiplier; `Normal` restores both. `Pause` withholds the next peak
+    /// after a fill. `Complete` makes the next peak everything that remains.
+    /// Returns the peak withheld by a pause once replenishment resumes.
+    #[must_use]
+    pub fn pace(&mut self, decision: PaceDecision) -> Option<IcebergPeak> {
+        let display_size = Decimal::from(self.config.display_size);
+        match decision {
+            PaceDecision::Normal => {
+                self.pace_multiplier = Decimal::ONE;
+                self.display_qty = display_size;
+            }
+            PaceDecision::Slow(multiplier) => {
+                self.pace_multiplier = multiplier.max(Decimal::ONE);
+                self.display_qty = (display_size / self.pace_multiplier)
+                    .floor()
+                    .max(Decimal::ONE);
+            }
+            PaceDecision::Pause => {
+                self.paused = true;
+                return None;
+            }
+            PaceDecision::Complete => {
+                self.pace_multiplier = Decimal::ONE;
+                self.display_qty = self.remaining_qty();
+            }
+        }
+
+        self.paused = false;
+        if !std::mem::take(&mut self.held) || self.is_complete() {
+            return None;
+        }
+        let quantity = self.display_qty.min(self.remaining_qty());
+        Some(IcebergPeak::new(quantity, self.peak_number))
+    }
+
+    /// Check if replenishment is held by a volatility pause.
+    #[must_use]
+    pub const fn is_paused(&self) -> bool {
+        self.paused
+    }
+
+    /// Minimum time before showing the next peak (milliseconds), stretched by
+    /// the current pace.
+    #[must_use]
+    pub fn replenish_interval_ms(&self) -> u64 {
+        let interval = Decimal::from(self.config.min_interval_ms) * self.pace_multiplier;
+        u64::try_from(interval.trunc()).unwrap_or(u64::MAX)
+    }
+
     /// Get the remaining quantity to execute.
     #[must_use]
     pub fn remaining_qty(&self) -> Decimal {
@@ -221,6 +288,44 @@ mod tests {
         assert!(executor.is_complete());
     }
 
+    #[test]
+    fn iceberg_executor_slow_pace_shrinks_peaks() {
+        let config = test_config();
+        let mut executor = IcebergExecutor::new(Decimal::new(500, 0), config);
+
+        assert!(
+            executor
+                .pace(PaceDecision::Slow(Decimal::new(3, 0)))
+                .is_none()
+        );
+        assert_eq!(executor.display_qty(), Decimal::new(33, 0));
+        assert_eq!(executor.replenish_interval_ms(), 1500);
+
+        let next = executor.on_fill(Decimal::new(100, 0)).unwrap();
+        assert_eq!(next.quantity, Decimal::new(33, 0));
+
+        let _ = executor.pace(PaceDecision::Normal);
+        assert_eq!(executor.display_qty(), Decimal::new(100, 0));
+        assert_eq!(executor.replenish_interval_ms(), 500);
+    }
+
+    #[test]
+    fn iceberg_executor_pause_withholds_next_peak() {
+        let config = test_config();
+        let mut executor = IcebergExecutor::new(Decimal::new(500, 0), config);
+
+        let _ = executor.pace(PaceDecision::Pause);
+        assert!(executor.on_fill(Decimal::new(100, 0)).is_none());
+        assert!(executor.is_paused());
+        assert!(!executor.is_complete());
+
+        // Exit escalation resumes with everything that remains
+        let peak = executor.pace(PaceDecision::Complete).unwrap();
+        assert_eq!(peak.quantity, Decimal::new(400, 0));
+        assert_eq!(peak.peak_number, 1);
+        assert!(executor.pace(PaceDecision::Complete).is_none());
+    }
+
     #[test]
     fn iceberg_executor_no_peak_when_complete() {
         let config = test_config();
@@ -2,12 +2,16 @@
 
 mod adaptive_executor;
 mod iceberg_executor;
+mod net_price_ladder;
+mod pacing_controller;
 mod tactic_selector;
 mod twap_executor;
 mod vwap_executor;
 
 pub use adaptive_executor::AdaptiveExecutor;
 pub use iceberg_executor::IcebergExecutor;
+pub use net_price_ladder::NetPriceLadder;
+pub use pacing_controller::PacingController;
 pub use tactic_selector::TacticSelector;
 pub use twap_executor::TwapExecutor;
 pub use vwap_executor::VwapExecutor;
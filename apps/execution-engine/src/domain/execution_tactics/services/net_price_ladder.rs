@@ -0,0 +1,207 @@
+//! Net-Price Ladder Domain Service
+
+use chrono::{DateTime, Utc};
+use rust_decimal::Decimal;
+
+use crate::domain::execution_tactics::value_objects::NetLadderConfig;
+
+/// Net-price ladder for multi-leg spread orders.
+///
+/// Starts the net limit at the spread's mid price and walks it toward the
+/// natural price one step per interval until the order fills or the
+/// configured maximum concession is reached. Prices follow the spread
+/// convention (debit positive, credit negative), so moving toward natural
+/// pays more for a debit and collects less for a credit.
+#[derive(Debug, Clone)]
+pub struct NetPriceLadder {
+    /// Net mid price per spread.
+    mid_price: Decimal,
+    /// Net natural price per spread.
+    natural_price: Decimal,
+    /// Current concession (0 = mid, 1 = natural).
+    concession: Decimal,
+    /// Steps taken so far.
+    steps: u32,
+    /// When the limit last moved (or the ladder started).
+    last_step_at: DateTime<Utc>,
+    /// Whether the order filled.
+    filled: bool,
+    /// Configuration.
+    config: NetLadderConfig,
+}
+
+impl NetPriceLadder {
+    /// Create a new ladder starting at mid at `started_at`.
+    #[must_use]
+    pub const fn new(
+        mid_price: Decimal,
+        natural_price: Decimal,
+        config: NetLadderConfig,
+        started_at: DateTime<Utc>,
+    ) -> Self {
+        Self {
+            mid_price,
+            natural_price,
+            concession: Decimal::ZERO,
+            steps: 0,
+            last_step_at: started_at,
+            filled: false,
+            config,
+        }
+    }
+
+    /// Current net limit price, rounded to cents.
+    #[must_use]
+    pub fn limit_price(&self) -> Decimal {
+        self.price_at(self.concession)
+    }
+
+    /// Net limit price at the maximum concession, rounded to cents.
+    #[must_use]
+    pub fn max_limit_price(&self) -> Decimal {
+        self.price_at(self.config.max_concession_pct)
+    }
+
+    /// Move the limit one step toward natural if an interval has passed
+    /// since the last step.
+    ///
+    /// Returns the new limit price, or `None` if the order filled, the
+    /// ladder is exhausted, or the next step is not due yet.
+    pub fn step(&mut self, now: DateTime<Utc>) -> Option<Decimal> {
+        if self.filled || self.is_exhausted() || now - self.last_step_at < self.config.interval() {
+            return None;
+        }
+        self.concession =
+            (self.concession + self.config.step_pct).min(self.config.max_concession_pct);
+        self.steps += 1;
+        self.last_step_at = now;
+        Some(self.limit_price())
+    }
+
+    /// Record that the order filled; the ladder stops walking.
+    pub const fn record_fill(&mut self) {
+        self.filled = true;
+    }
+
+    /// Check if the order filled.
+    #[must_use]
+    pub const fn is_filled(&self) -> bool {
+        self.filled
+    }
+
+    /// Check if the limit reached the maximum concession (or cannot move).
+    #[must_use]
+    pub fn is_exhausted(&self) -> bool {
+        self.config.step_pct <= Decimal::ZERO || self.concession >= self.config.max_concession_pct
+    }
+
+    /// Check if the ladder is done, filled or exhausted.
+    #[must_use]
+    pub fn is_complete(&self) -> bool {
+        self.filled || self.is_exhausted()
+    }
+
+    /// Get the current concession (0 = mid, 1 = natural).
+    #[must_use]
+    pub const fn concession(&self) -> Decimal {
+        self.concession
+    }
+
+    /// Get the number of steps taken.
+    #[must_use]
+    pub const fn steps(&self) -> u32 {
+        self.steps
+    }
+
+    /// Get the configuration.
+    #[must_use]
+    pub const fn config(&self) -> &NetLadderConfig {
+        &self.config
+    }
+
+    fn price_at(&self, concession: Decimal) -> Decimal {
+        (self.mid_price + (self.natural_price - self.mid_price) * concession).round_dp(2)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeDelta;
+
+    fn start() -> DateTime<Utc> {
+        DateTime::from_timestamp(1_700_000_000, 0).unwrap()
+    }
+
+    #[test]
+    fn debit_ladder_walks_up_to_natural() {
+        // Debit spread: mid 1.50, natural 1.70
+        let mut ladder = NetPriceLadder::new(
+            Decimal::new(150, 2),
+            Decimal::new(170, 2),
+            NetLadderConfig::default(),
+            start(),
+        );
+        assert_eq!(ladder.limit_price(), Decimal::new(150, 2));
+        assert_eq!(ladder.max_limit_price(), Decimal::new(170, 2));
+
+        // Not due before the interval
+        assert_eq!(ladder.step(start() + TimeDelta::seconds(14)), None);
+
+        let mut prices = vec![];
+        let mut now = start();
+        for _ in 0..6 {
+            now += TimeDelta::seconds(15);
+            prices.extend(ladder.step(now));
+        }
+        assert_eq!(
+            prices,
+            vec![
+                Decimal::new(155, 2),
+                Decimal::new(160, 2),
+                Decimal::new(165, 2),
+                Decimal::new(170, 2),
+            ]
+        );
+        assert_eq!(ladder.steps(), 4);
+        assert!(ladder.is_exhausted());
+        assert!(ladder.is_complete());
+    }
+
+    #[test]
+    fn credit_ladder_concedes_toward_smaller_credit() {
+        // Credit condor: mid -2.00, natural -1.80
+        let config = NetLadderConfig::new(Decimal::new(5, 1), 10, Decimal::new(5, 1));
+        let mut ladder = NetPriceLadder::new(
+            Decimal::new(-200, 2),
+            Decimal::new(-180, 2),
+            config,
+            start(),
+        );
+
+        assert_eq!(ladder.max_limit_price(), Decimal::new(-190, 2));
+        assert_eq!(
+            ladder.step(start() + TimeDelta::seconds(10)),
+            Some(Decimal::new(-190, 2))
+        );
+        assert!(ladder.is_exhausted());
+        assert_eq!(ladder.step(start() + TimeDelta::seconds(20)), None);
+    }
+
+    #[test]
+    fn fill_stops_the_ladder() {
+        let mut ladder = NetPriceLadder::new(
+            Decimal::new(150, 2),
+            Decimal::new(170, 2),
+            NetLadderConfig::default(),
+            start(),
+        );
+        ladder.step(start() + TimeDelta::seconds(15));
+        ladder.record_fill();
+
+        assert!(ladder.is_filled());
+        assert!(!ladder.is_exhausted());
+        assert_eq!(ladder.step(start() + TimeDelta::seconds(30)), None);
+        assert_eq!(ladder.limit_price(), Decimal::new(155, 2));
+    }
+}
@@ -0,0 +1,221 @@
+//! Pacing Controller Domain Service
+
+use std::collections::VecDeque;
+
+use rust_decimal::Decimal;
+
+use crate::domain::execution_tactics::value_objects::{PaceDecision, PacingConfig};
+use crate::domain::order_execution::value_objects::OrderPurpose;
+
+/// Basis points per unit.
+const BPS: Decimal = Decimal::from_parts(10_000, 0, 0, false, 0);
+
+/// Pacing controller for volatility-aware child-order release.
+///
+/// Tracks realized volatility of the mid price and the quoted spread from the
+/// quotes passed to [`observe`](Self::observe), and maps them onto the
+/// configured volatility bands. TWAP and Iceberg executors apply the
+/// resulting [`PaceDecision`] to their release schedule; the caller driving
+/// them feeds quotes in, since the engine has no child-order release loop.
+#[derive(Debug, Clone)]
+pub struct PacingController {
+    /// Configuration.
+    config: PacingConfig,
+    /// Recent mid prices, oldest first.
+    mids: VecDeque<Decimal>,
+    /// Latest quoted spread (BPS of mid).
+    spread_bps: Decimal,
+}
+
+impl PacingController {
+    /// Create a new pacing controller.
+    #[must_use]
+    pub fn new(config: PacingConfig) -> Self {
+        Self {
+            mids: VecDeque::with_capacity(config.window + 1),
+            config,
+            spread_bps: Decimal::ZERO,
+        }
+    }
+
+    /// Record a quote from the feed. Crossed or empty quotes are ignored.
+    pub fn observe(&mut self, bid: Decimal, ask: Decimal) {
+        if bid <= Decimal::ZERO || ask < bid {
+            return;
+        }
+        let mid = (bid + ask) / Decimal::TWO;
+        self.spread_bps = (ask - bid) / mid * BPS;
+        self.mids.push_back(mid);
+        while self.mids.len() > self.config.window + 1 {
+            self.mids.pop_front();
+        }
+    }
+
+    /// Realized volatility of mid-price returns over the window (BPS per
+    /// sample, root mean square).
+    #[must_use]
+    pub fn realized_volatility_bps(&self) -> Decimal {
+        let returns: Vec<Decimal> = self
+            .mids
+            .iter()
+            .zip(self.mids.iter().skip(1))
+            .map(|(prev, next)| (next - prev) / prev * BPS)
+            .collect();
+        if returns.is_empty() {
+            return Decimal::ZERO;
+        }
+        let mean_square =
+            returns.iter().map(|r| r * r).sum::<Decimal>() / Decimal::from(returns.len());
+        sqrt(mean_square)
+    }
+
+    /// Latest quoted spread (BPS of mid).
+    #[must_use]
+    pub const fn spread_bps(&self) -> Decimal {
+        self.spread_bps
+    }
+
+    /// Pace for an order with `purpose` under current conditions.
+    ///
+    /// Exits escalate to [`PaceDecision::Complete`] instead of slowing when
+    /// `escalate_exits` is set, so a position is not left open while the
+    /// market moves.
+    #[must_use]
+    pub fn decision(&self, purpose: OrderPurpose) -> PaceDecision {
+        let volatility = self.realized_volatility_bps();
+        let pace = self
+            .config
+            .bands
+            .iter()
+            .rev()
+            .find(|band| band.applies(volatility, self.spread_bps))
+            .map_or(PaceDecision::Normal, |band| band.pace);
+
+        match pace {
+            PaceDecision::Normal => PaceDecision::Normal,
+            _ if purpose.is_exit() && self.config.escalate_exits => PaceDecision::Complete,
+            pace => pace,
+        }
+    }
+
+    /// Get the configuration.
+    #[must_use]
+    pub const fn config(&self) -> &PacingConfig {
+        &self.config
+    }
+}
+
+/// Square root by Newton's method.
+fn sqrt(value: Decimal) -> Decimal {
+    if value <= Decimal::ZERO {
+        return Decimal::ZERO;
+    }
+    let mut guess = if value > Decimal::ONE {
+        value / Decimal::TWO
+    } else {
+        Decimal::ONE
+    };
+    for _ in 0..32 {
+        let next = (guess + value / guess) / Decimal::TWO;
+        if next == guess {
+            break;
+        }
+        guess = next;
+    }
+    guess
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::execution_tactics::value_objects::VolatilityBand;
+
+    fn controller() -> PacingController {
+        PacingController::new(PacingConfig::default())
+    }
+
+    #[test]
+    fn calm_quotes_keep_normal_pace() {
+        let mut pacing = controller();
+        for _ in 0..5 {
+            pacing.observe(Decimal::new(9999, 2), Decimal::new(10001, 2));
+        }
+
+        assert_eq!(pacing.realized_volatility_bps(), Decimal::ZERO);
+        assert_eq!(pacing.spread_bps(), Decimal::new(2, 0));
+        assert_eq!(pacing.decision(OrderPurpose::Entry), PaceDecision::Normal);
+    }
+
+    #[test]
+    fn wide_spread_slows_entries_and_escalates_exits() {
+        let mut pacing = controller();
+        pacing.observe(Decimal::new(9985, 2), Decimal::new(10015, 2));
+
+        assert_eq!(pacing.spread_bps(), Decimal::new(30, 0));
+        assert_eq!(
+            pacing.decision(OrderPurpose::Entry),
+            PaceDecision::Slow(Decimal::TWO)
+        );
+        assert_eq!(
+            pacing.decision(OrderPurpose::StopLoss),
+            PaceDecision::Complete
+        );
+
+        let config = PacingConfig {
+            escalate_exits: false,
+            ..PacingConfig::default()
+        };
+        let mut pacing = PacingController::new(config);
+        pacing.observe(Decimal::new(9985, 2), Decimal::new(10015, 2));
+        assert_eq!(
+            pacing.decision(OrderPurpose::Exit),
+            PaceDecision::Slow(Decimal::TWO)
+        );
+    }
+
+    #[test]
+    fn swinging_mids_pause_releases() {
+        let mut pacing = controller();
+        // Alternating 50 bps moves on a tight spread
+        for i in 0..6 {
+            let mid = if i % 2 == 0 {
+                Decimal::new(100, 0)
+            } else {
+                Decimal::new(1005, 1)
+            };
+            pacing.observe(mid - Decimal::new(1, 2), mid + Decimal::new(1, 2));
+        }
+
+        assert!(pacing.realized_volatility_bps() > Decimal::new(40, 0));
+        assert_eq!(pacing.decision(OrderPurpose::Entry), PaceDecision::Pause);
+    }
+
+    #[test]
+    fn window_drops_old_returns() {
+        let config = PacingConfig::new(
+            2,
+            vec![VolatilityBand::pause(
+                Decimal::new(40, 0),
+                Decimal::new(75, 0),
+            )],
+            false,
+        );
+        let mut pacing = PacingController::new(config);
+        pacing.observe(Decimal::new(9999, 2), Decimal::new(10001, 2));
+        pacing.observe(Decimal::new(10099, 2), Decimal::new(10101, 2));
+        assert_eq!(pacing.decision(OrderPurpose::Entry), PaceDecision::Pause);
+
+        for _ in 0..2 {
+            pacing.observe(Decimal::new(10099, 2), Decimal::new(10101, 2));
+        }
+        assert_eq!(pacing.realized_volatility_bps(), Decimal::ZERO);
+        assert_eq!(pacing.decision(OrderPurpose::Entry), PaceDecision::Normal);
+    }
+
+    #[test]
+    fn sqrt_converges() {
+        assert_eq!(sqrt(Decimal::new(2500, 0)), Decimal::new(50, 0));
+        assert_eq!(sqrt(Decimal::new(25, 2)), Decimal::new(5, 1));
+        assert_eq!(sqrt(Decimal::ZERO), Decimal::ZERO);
+    }
+}
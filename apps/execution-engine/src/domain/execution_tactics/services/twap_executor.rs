@@ -1,13 +1,14 @@
 //! TWAP Executor Domain Service
 
-use chrono::{DateTime, Utc};
+use chrono::{DateTime, TimeDelta, Utc};
 use rust_decimal::Decimal;
 
-use crate::domain::execution_tactics::value_objects::{TwapConfig, TwapSlice};
+use crate::domain::execution_tactics::value_objects::{PaceDecision, TwapConfig, TwapSlice};
 
 /// TWAP executor for time-weighted average price execution.
 ///
 /// Splits a large order into equal-sized slices distributed evenly across a time window.
+/// The remaining schedule can be slowed, paused or collapsed with [`Self::pace`].
 #[derive(Debug, Clone)]
 pub struct TwapExecutor {
     /// Total quantity to execute.
@@ -18,6 +19,12 @@ pub struct TwapExecutor {
     executed_slices: usize,
     /// Quantity per slice.
     qty_per_slice: Decimal,
+    /// Quantity released so far.
+    released_qty: Decimal,
+    /// Releases are held by a volatility pause.
+    paused: bool,
+    /// Remaining quantity was merged into a final slice.
+    escalated: bool,
     /// Start time of execution.
     start_time: DateTime<Utc>,
     /// Execution schedule.
@@ -40,6 +47,9 @@ impl TwapExecutor {
             num_slices,
             executed_slices: 0,
             qty_per_slice,
+            released_qty: Decimal::ZERO,
+            paused: false,
+            escalated: false,
             start_time,
             schedule,
             config,
@@ -62,6 +72,9 @@ impl TwapExecutor {
             num_slices,
             executed_slices: 0,
             qty_per_slice,
+            released_qty: Decimal::ZERO,
+            paused: false,
+            escalated: false,
             start_time,
             schedule,
             config,
@@ -71,7 +84,7 @@ impl TwapExecutor {
     /// Returns the next slice to execute, if any remain and it's time.
     #[must_use]
     pub fn next_slice(&mut self) -> Option<TwapSlice> {
-        if self.executed_slices >= self.num_slices {
+        if self.paused || self.executed_slices >= self.num_slices {
             return None;
         }
 
@@ -83,8 +96,14 @@ impl TwapExecutor {
             return None;
         }
 
-        let slice = TwapSlice::new(self.qty_per_slice, self.executed_slices, scheduled_time);
+        let quantity = if self.escalated {
+            self.remaining_qty()
+        } else {
+            self.qty_per_slice
+        };
+        let slice = TwapSlice::new(quantity, self.executed_slices, scheduled_time);
 
+        self.released_qty += quantity;
         self.executed_slices += 1;
         Some(slice)
     }
@@ -92,7 +111,7 @@ impl TwapExecutor {
     /// Check if there's a slice ready to execute now.
     #[must_use]
     pub fn has_ready_slice(&self) -> bool {
-        if self.executed_slices >= self.num_slices {
+        if self.paused || self.executed_slices >= self.num_slices {
             return false;
         }
 
@@ -104,8 +123,59 @@ impl TwapExecutor {
     /// Get the remaining quantity to execute.
     #[must_use]
     pub fn remaining_qty(&self) -> Decimal {
-        let executed_qty = self.qty_per_slice * Decimal::from(self.executed_slices);
-        self.total_qty - executed_qty
+        self.total_qty - self.released_qty
+    }
+
+    /// Apply a volatility pace to the slices not yet released.
+    ///
+    /// `Slow` re-spaces the remaining slices at the configured interval times
+    /// the multiplier, counted from the last released slice, and `Normal`
+    /// restores the configured spacing; slowing can move slices past the
+    /// execution window. `Pause` holds releases until the next other
+    /// decision. `Complete` merges what remains into one slice due now.
+    pub fn pace(&mut self, decision: PaceDecision) {
+        if self.is_complete() {
+            return;
+        }
+        self.paused = decision == PaceDecision::Pause;
+        match decision {
+            PaceDecision::Normal => self.respace(Decimal::ONE),
+            PaceDecision::Slow(multiplier) => self.respace(multiplier.max(Decimal::ONE)),
+            PaceDecision::Pause => {}
+            PaceDecision::Complete => {
+                self.escalated = true;
+                self.num_slices = self.executed_slices + 1;
+                self.schedule.truncate(self.num_slices);
+                let next = &mut self.schedule[self.executed_slices];
+                *next = (*next).min(Utc::now());
+            }
+        }
+    }
+
+    /// Check if releases are held by a volatility pause.
+    #[must_use]
+    pub const fn is_paused(&self) -> bool {
+        self.paused
+    }
+
+    /// Re-space unreleased slices at `multiplier` times the slice interval.
+    fn respace(&mut self, multiplier: Decimal) {
+        let interval_ms =
+            Decimal::from(self.config.slice_interval_seconds) * Decimal::from(1000) * multiplier;
+        let (anchor, first) = match self.executed_slices.checked_sub(1) {
+            Some(last) => (self.schedule[last], 1),
+            None => (self.start_time, 0),
+        };
+        for (k, slot) in self.schedule[self.executed_slices..].iter_mut().enumerate() {
+            let offset_ms = (interval_ms * Decimal::from(k + first)).trunc();
+            if let Some(at) = i64::try_from(offset_ms)
+                .ok()
+                .and_then(TimeDelta::try_milliseconds)
+                .and_then(|offset| anchor.checked_add_signed(offset))
+            {
+                *slot = at;
+            }
+        }
     }
 
     /// Check if execution is complete.
@@ -273,6 +343,53 @@ mod tests {
         assert_eq!(executor.remaining_qty(), Decimal::ZERO);
     }
 
+    #[test]
+    fn twap_executor_slow_pace_stretches_remaining_schedule() {
+        let config = test_config();
+        let start_time = Utc::now() - TimeDelta::seconds(30);
+        let mut executor = TwapExecutor::with_start_time(Decimal::new(300, 0), config, start_time);
+        let _ = executor.next_slice();
+
+        executor.pace(PaceDecision::Slow(Decimal::new(3, 0)));
+        assert_eq!(executor.schedule()[1], start_time + TimeDelta::seconds(60));
+        assert_eq!(executor.schedule()[2], start_time + TimeDelta::seconds(120));
+        assert!(!executor.has_ready_slice());
+
+        // Calm again: back to 20s spacing, so slice 1 is due
+        executor.pace(PaceDecision::Normal);
+        assert_eq!(executor.schedule()[1], start_time + TimeDelta::seconds(20));
+        assert!(executor.has_ready_slice());
+    }
+
+    #[test]
+    fn twap_executor_pause_holds_releases() {
+        let config = test_config();
+        let start_time = Utc::now() - TimeDelta::minutes(2);
+        let mut executor = TwapExecutor::with_start_time(Decimal::new(300, 0), config, start_time);
+
+        executor.pace(PaceDecision::Pause);
+        assert!(executor.is_paused());
+        assert!(executor.next_slice().is_none());
+        assert!(!executor.is_complete());
+
+        executor.pace(PaceDecision::Normal);
+        assert!(executor.next_slice().is_some());
+    }
+
+    #[test]
+    fn twap_executor_complete_releases_remaining_quantity() {
+        let config = test_config();
+        let mut executor = TwapExecutor::with_start_time(Decimal::new(300, 0), config, Utc::now());
+        let _ = executor.next_slice();
+
+        executor.pace(PaceDecision::Complete);
+        let slice = executor.next_slice().unwrap();
+        assert_eq!(slice.quantity, Decimal::new(200, 0));
+        assert_eq!(slice.slice_number, 1);
+        assert!(executor.is_complete());
+        assert_eq!(executor.remaining_qty(), Decimal::ZERO);
+    }
+
     #[test]
     fn twap_executor_window_not_ended_when_fresh() {
         let config = test_config();
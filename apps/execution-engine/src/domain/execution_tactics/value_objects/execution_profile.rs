@@ -0,0 +1,361 @@
+//! Execution Profiles
+//!
+//! Execution behavior per strategy family: the tactic to fall back on, how
+//! urgent entries and exits are, the widest spread worth trading into, and
+//! which order types the family may use. Families without a profile use the
+//! default profile.
+
+use std::collections::HashMap;
+use std::fmt;
+
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+
+use super::{MarketState, TacticSelectionContext, TacticType, TacticUrgency};
+use crate::domain::execution_tactics::services::TacticSelector;
+use crate::domain::order_execution::value_objects::{OrderPurpose, OrderType};
+use crate::domain::risk_management::value_objects::ConstraintViolation;
+
+/// Violation code for an order type the strategy family may not use.
+pub const ORDER_TYPE_NOT_ALLOWED: &str = "ORDER_TYPE_NOT_ALLOWED";
+
+/// Violation code for a spread wider than the strategy family tolerates.
+pub const SPREAD_TOO_WIDE: &str = "SPREAD_TOO_WIDE";
+
+/// Strategy family of a decision.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum StrategyFamily {
+    /// Long stock.
+    EquityLong,
+    /// Short stock.
+    EquityShort,
+    /// Long single options.
+    OptionLong,
+    /// Short single options.
+    OptionShort,
+    /// Vertical spreads.
+    VerticalSpread,
+    /// Iron condors and butterflies.
+    IronCondor,
+    /// Straddles.
+    Straddle,
+    /// Strangles.
+    Strangle,
+    /// Calendar spreads.
+    CalendarSpread,
+}
+
+impl fmt::Display for StrategyFamily {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            Self::EquityLong => "EQUITY_LONG",
+            Self::EquityShort => "EQUITY_SHORT",
+            Self::OptionLong => "OPTION_LONG",
+            Self::OptionShort => "OPTION_SHORT",
+            Self::VerticalSpread => "VERTICAL_SPREAD",
+            Self::IronCondor => "IRON_CONDOR",
+            Self::Straddle => "STRADDLE",
+            Self::Strangle => "STRANGLE",
+            Self::CalendarSpread => "CALENDAR_SPREAD",
+        };
+        write!(f, "{name}")
+    }
+}
+
+/// Urgency by order purpose.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct UrgencyMapping {
+    /// Entries and scale-ins.
+    pub entry: TacticUrgency,
+    /// Exits, scale-outs and take-profits.
+    pub exit: TacticUrgency,
+}
+
+impl Default for UrgencyMapping {
+    fn default() -> Self {
+        Self {
+            entry: TacticUrgency::Normal,
+            exit: TacticUrgency::High,
+        }
+    }
+}
+
+impl UrgencyMapping {
+    /// Urgency for an order with `purpose`.
+    ///
+    /// Stop-losses are always high urgency.
+    #[must_use]
+    pub const fn for_purpose(&self, purpose: OrderPurpose) -> TacticUrgency {
+        match purpose {
+            OrderPurpose::Entry | OrderPurpose::ScaleIn => self.entry,
+            OrderPurpose::Exit
+            | OrderPurpose::ScaleOut
+            | OrderPurpose::TakeProfit
+            | OrderPurpose::BracketLeg => self.exit,
+            OrderPurpose::StopLoss => TacticUrgency::High,
+        }
+    }
+}
+
+/// Execution behavior for one strategy family.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ExecutionProfile {
+    /// Tactic for entries and exits when size and market state do not
+    /// call for another.
+    pub default_tactic: TacticType,
+    /// Urgency by order purpose.
+    pub urgency: UrgencyMapping,
+    /// Widest spread to trade into, in basis points of the mid (no limit
+    /// when unset).
+    pub max_spread_bps: Option<Decimal>,
+    /// Order types the family may use (any when empty).
+    pub allowed_order_types: Vec<OrderType>,
+}
+
+impl Default for ExecutionProfile {
+    fn default() -> Self {
+        Self {
+            default_tactic: TacticType::PassiveLimit,
+            urgency: UrgencyMapping::default(),
+            max_spread_bps: None,
+            allowed_order_types: Vec::new(),
+        }
+    }
+}
+
+impl ExecutionProfile {
+    /// Tactic selector using this profile's default tactic.
+    #[must_use]
+    pub const fn selector(&self) -> TacticSelector {
+        TacticSelector::new(
+            self.default_tactic,
+            self.default_tactic,
+            TacticType::AggressiveLimit,
+        )
+    }
+
+    /// Select a tactic for an order, with urgency from this profile.
+    ///
+    /// A spread wider than `max_spread_bps` counts as a wide-spread market.
+    #[must_use]
+    pub fn select_tactic(
+        &self,
+        purpose: OrderPurpose,
+        size_pct_adv: Decimal,
+        spread_bps: Option<Decimal>,
+    ) -> TacticType {
+        let market_state = if spread_bps.is_some_and(|s| !self.spread_within(s)) {
+            MarketState::WideSpread
+        } else {
+            MarketState::Normal
+        };
+        self.selector().select(&TacticSelectionContext::new(
+            size_pct_adv,
+            self.urgency.for_purpose(purpose),
+            market_state,
+            purpose,
+        ))
+    }
+
+    /// Check if the profile allows `order_type`.
+    #[must_use]
+    pub fn allows(&self, order_type: OrderType) -> bool {
+        self.allowed_order_types.is_empty() || self.allowed_order_types.contains(&order_type)
+    }
+
+    /// Check if a spread is within tolerance.
+    #[must_use]
+    pub fn spread_within(&self, spread_bps: Decimal) -> bool {
+        self.max_spread_bps.is_none_or(|max| spread_bps <= max)
+    }
+
+    /// Violations for an order in `symbol` against this profile.
+    ///
+    /// `spread_bps` is the current quoted spread, when known.
+    #[must_use]
+    pub fn check(
+        &self,
+        symbol: &str,
+        order_type: OrderType,
+        spread_bps: Option<Decimal>,
+    ) -> Vec<ConstraintViolation> {
+        let mut violations = Vec::new();
+        if !self.allows(order_type) {
+            violations.push(
+                ConstraintViolation::error(
+                    ORDER_TYPE_NOT_ALLOWED,
+                    format!("{order_type:?} orders are not allowed for this strategy family"),
+                )
+                .with_instrument(symbol),
+            );
+        }
+        if let (Some(spread), Some(max)) = (spread_bps, self.max_spread_bps)
+            && spread > max
+        {
+            violations.push(
+                ConstraintViolation::error(
+                    SPREAD_TOO_WIDE,
+                    format!(
+                        "{symbol} spread {} bps exceeds {max} bps",
+                        spread.round_dp(1)
+                    ),
+                )
+                .with_instrument(symbol),
+            );
+        }
+        violations
+    }
+}
+
+/// Execution profiles by strategy family.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ExecutionProfiles {
+    default: ExecutionProfile,
+    families: HashMap<StrategyFamily, ExecutionProfile>,
+}
+
+impl ExecutionProfiles {
+    /// Create profiles where every family uses `default`.
+    #[must_use]
+    pub fn new(default: ExecutionProfile) -> Self {
+        Self {
+            default,
+            families: HashMap::new(),
+        }
+    }
+
+    /// Use `profile` for `family`.
+    #[must_use]
+    pub fn with_profile(mut self, family: StrategyFamily, profile: ExecutionProfile) -> Self {
+        self.families.insert(family, profile);
+        self
+    }
+
+    /// Profile for `family`, or the default profile.
+    #[must_use]
+    pub fn for_family(&self, family: Option<StrategyFamily>) -> &ExecutionProfile {
+        family
+            .and_then(|f| self.families.get(&f))
+            .unwrap_or(&self.default)
+    }
+
+    /// Number of families with their own profile.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.families.len()
+    }
+
+    /// Check if every family uses the default profile.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.families.is_empty()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn spread_profile() -> ExecutionProfile {
+        ExecutionProfile {
+            default_tactic: TacticType::AggressiveLimit,
+            urgency: UrgencyMapping {
+                entry: TacticUrgency::Low,
+                exit: TacticUrgency::High,
+            },
+            max_spread_bps: Some(Decimal::from(200)),
+            allowed_order_types: vec![OrderType::Limit],
+        }
+    }
+
+    #[test]
+    fn families_fall_back_to_default() {
+        let profiles = ExecutionProfiles::default()
+            .with_profile(StrategyFamily::VerticalSpread, spread_profile());
+
+        assert_eq!(
+            profiles
+                .for_family(Some(StrategyFamily::VerticalSpread))
+                .default_tactic,
+            TacticType::AggressiveLimit
+        );
+        assert_eq!(
+            profiles.for_family(Some(StrategyFamily::EquityLong)),
+            &ExecutionProfile::default()
+        );
+        assert_eq!(profiles.for_family(None), &ExecutionProfile::default());
+    }
+
+    #[test]
+    fn check_rejects_disallowed_type_and_wide_spread() {
+        let profile = spread_profile();
+
+        assert!(
+            profile
+                .check("SPY", OrderType::Limit, Some(Decimal::from(150)))
+                .is_empty()
+        );
+
+        let violations = profile.check("SPY", OrderType::Market, Some(Decimal::from(250)));
+        let codes: Vec<&str> = violations.iter().map(|v| v.code.as_str()).collect();
+        assert_eq!(codes, vec![ORDER_TYPE_NOT_ALLOWED, SPREAD_TOO_WIDE]);
+
+        // Any type and spread when unrestricted
+        assert!(
+            ExecutionProfile::default()
+                .check("SPY", OrderType::Market, Some(Decimal::from(900)))
+                .is_empty()
+        );
+    }
+
+    #[test]
+    fn select_tactic_uses_profile_urgency_and_default() {
+        let profile = spread_profile();
+        let small = Decimal::new(5, 3);
+
+        // Low urgency small entry stays passive
+        assert_eq!(
+            profile.select_tactic(OrderPurpose::Entry, small, None),
+            TacticType::PassiveLimit
+        );
+        // High urgency small exit crosses the spread
+        assert_eq!(
+            profile.select_tactic(OrderPurpose::Exit, small, None),
+            TacticType::AggressiveLimit
+        );
+        // A spread past tolerance is treated as a wide-spread market
+        assert_eq!(
+            profile.select_tactic(OrderPurpose::Exit, small, Some(Decimal::from(300))),
+            TacticType::PassiveLimit
+        );
+
+        // Otherwise the profile default applies
+        let twap = ExecutionProfile {
+            default_tactic: TacticType::Twap,
+            ..ExecutionProfile::default()
+        };
+        assert_eq!(
+            twap.select_tactic(OrderPurpose::Entry, small, None),
+            TacticType::Twap
+        );
+    }
+
+    #[test]
+    fn urgency_mapping_for_purpose() {
+        let mapping = UrgencyMapping::default();
+        assert_eq!(
+            mapping.for_purpose(OrderPurpose::ScaleIn),
+            TacticUrgency::Normal
+        );
+        assert_eq!(
+            mapping.for_purpose(OrderPurpose::TakeProfit),
+            TacticUrgency::High
+        );
+        assert_eq!(
+            mapping.for_purpose(OrderPurpose::StopLoss),
+            TacticUrgency::High
+        );
+    }
+}
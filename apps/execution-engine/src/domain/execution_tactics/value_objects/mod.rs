@@ -2,8 +2,11 @@
 
 mod adaptive_config;
 mod aggressive_limit_config;
+mod execution_profile;
 mod iceberg_config;
 mod market_context;
+mod net_ladder_config;
+mod pacing_config;
 mod passive_limit_config;
 mod slices;
 mod tactic_config;
@@ -13,10 +16,16 @@ mod vwap_config;
 
 pub use adaptive_config::{AdaptiveConfig, Urgency};
 pub use aggressive_limit_config::AggressiveLimitConfig;
+pub use execution_profile::{
+    ExecutionProfile, ExecutionProfiles, ORDER_TYPE_NOT_ALLOWED, SPREAD_TOO_WIDE, StrategyFamily,
+    UrgencyMapping,
+};
 pub use iceberg_config::IcebergConfig;
 pub use market_context::{
     MarketContext, MarketState, SubTactic, TacticSelectionContext, TacticUrgency,
 };
+pub use net_ladder_config::NetLadderConfig;
+pub use pacing_config::{PaceDecision, PacingConfig, VolatilityBand};
 pub use passive_limit_config::PassiveLimitConfig;
 pub use slices::{IcebergPeak, SliceType, TwapSlice, VwapSlice};
 pub use tactic_config::TacticConfig;
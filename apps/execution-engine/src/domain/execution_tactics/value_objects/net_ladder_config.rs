@@ -0,0 +1,85 @@
+//! Net-Price Ladder Tactic Configuration
+
+use chrono::TimeDelta;
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+
+/// Configuration for the `NET_LADDER` tactic.
+///
+/// Concessions are fractions of the distance from the spread's mid price to
+/// its natural price: 0 is mid, 1 is natural.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct NetLadderConfig {
+    /// Concession added at each step.
+    pub step_pct: Decimal,
+    /// Time between steps (seconds).
+    pub interval_seconds: u32,
+    /// Largest concession the ladder walks to.
+    pub max_concession_pct: Decimal,
+}
+
+impl Default for NetLadderConfig {
+    fn default() -> Self {
+        Self {
+            step_pct: Decimal::new(25, 2),
+            interval_seconds: 15,
+            max_concession_pct: Decimal::ONE,
+        }
+    }
+}
+
+impl NetLadderConfig {
+    /// Create a new net-price ladder configuration.
+    #[must_use]
+    pub const fn new(
+        step_pct: Decimal,
+        interval_seconds: u32,
+        max_concession_pct: Decimal,
+    ) -> Self {
+        Self {
+            step_pct,
+            interval_seconds,
+            max_concession_pct,
+        }
+    }
+
+    /// Time between steps.
+    #[must_use]
+    pub fn interval(&self) -> TimeDelta {
+        TimeDelta::seconds(i64::from(self.interval_seconds))
+    }
+
+    /// Number of steps from mid to the largest concession.
+    #[must_use]
+    pub fn max_steps(&self) -> u32 {
+        if self.step_pct <= Decimal::ZERO {
+            return 0;
+        }
+        (self.max_concession_pct / self.step_pct)
+            .ceil()
+            .try_into()
+            .unwrap_or(u32::MAX)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn net_ladder_config_default() {
+        let config = NetLadderConfig::default();
+        assert_eq!(config.step_pct, Decimal::new(25, 2));
+        assert_eq!(config.interval(), TimeDelta::seconds(15));
+        assert_eq!(config.max_steps(), 4);
+    }
+
+    #[test]
+    fn max_steps_rounds_up_partial_steps() {
+        let config = NetLadderConfig::new(Decimal::new(3, 1), 10, Decimal::new(75, 2));
+        assert_eq!(config.max_steps(), 3);
+
+        let stalled = NetLadderConfig::new(Decimal::ZERO, 10, Decimal::ONE);
+        assert_eq!(stalled.max_steps(), 0);
+    }
+}
@@ -0,0 +1,115 @@
+//! Volatility Pacing Configuration
+
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+
+/// How child-order release is paced.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum PaceDecision {
+    /// Release on the tactic's own schedule.
+    Normal,
+    /// Stretch the time between releases by the multiplier.
+    Slow(Decimal),
+    /// Hold releases until conditions improve.
+    Pause,
+    /// Release everything that remains now (exits only).
+    Complete,
+}
+
+/// Market conditions that trigger a pace.
+///
+/// A band applies when realized volatility or the quoted spread reaches its
+/// threshold.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct VolatilityBand {
+    /// Realized volatility per sample at which the band applies (BPS).
+    pub min_volatility_bps: Decimal,
+    /// Quoted spread at which the band applies (BPS).
+    pub min_spread_bps: Decimal,
+    /// Pace while the band applies; never [`PaceDecision::Complete`].
+    pub pace: PaceDecision,
+}
+
+impl VolatilityBand {
+    /// Create a band that slows releases by `multiplier`.
+    #[must_use]
+    pub const fn slow(
+        min_volatility_bps: Decimal,
+        min_spread_bps: Decimal,
+        multiplier: Decimal,
+    ) -> Self {
+        Self {
+            min_volatility_bps,
+            min_spread_bps,
+            pace: PaceDecision::Slow(multiplier),
+        }
+    }
+
+    /// Create a band that pauses releases.
+    #[must_use]
+    pub const fn pause(min_volatility_bps: Decimal, min_spread_bps: Decimal) -> Self {
+        Self {
+            min_volatility_bps,
+            min_spread_bps,
+            pace: PaceDecision::Pause,
+        }
+    }
+
+    /// Check if the band applies to the observed conditions.
+    #[must_use]
+    pub fn applies(&self, volatility_bps: Decimal, spread_bps: Decimal) -> bool {
+        volatility_bps >= self.min_volatility_bps || spread_bps >= self.min_spread_bps
+    }
+}
+
+/// Configuration for volatility-aware pacing of TWAP and Iceberg releases.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PacingConfig {
+    /// Number of mid-price returns in the realized volatility window.
+    pub window: usize,
+    /// Bands from calmest to most volatile; the last one that applies wins.
+    pub bands: Vec<VolatilityBand>,
+    /// Release exits in full instead of slowing them when a band applies.
+    pub escalate_exits: bool,
+}
+
+impl Default for PacingConfig {
+    fn default() -> Self {
+        Self {
+            window: 20,
+            bands: vec![
+                VolatilityBand::slow(Decimal::new(15, 0), Decimal::new(25, 0), Decimal::TWO),
+                VolatilityBand::pause(Decimal::new(40, 0), Decimal::new(75, 0)),
+            ],
+            escalate_exits: true,
+        }
+    }
+}
+
+impl PacingConfig {
+    /// Create a new pacing configuration.
+    #[must_use]
+    pub const fn new(window: usize, bands: Vec<VolatilityBand>, escalate_exits: bool) -> Self {
+        Self {
+            window,
+            bands,
+            escalate_exits,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn band_applies_on_either_threshold() {
+        let band = VolatilityBand::slow(Decimal::new(15, 0), Decimal::new(25, 0), Decimal::TWO);
+
+        assert!(!band.applies(Decimal::new(14, 0), Decimal::new(24, 0)));
+        assert!(band.applies(Decimal::new(15, 0), Decimal::ZERO));
+        assert!(band.applies(Decimal::ZERO, Decimal::new(30, 0)));
+        assert_eq!(PacingConfig::default().bands.len(), 2);
+    }
+}
@@ -3,8 +3,8 @@
 use serde::{Deserialize, Serialize};
 
 use super::{
-    AdaptiveConfig, AggressiveLimitConfig, IcebergConfig, PassiveLimitConfig, TacticType,
-    TwapConfig, VwapConfig,
+    AdaptiveConfig, AggressiveLimitConfig, IcebergConfig, NetLadderConfig, PassiveLimitConfig,
+    TacticType, TwapConfig, VwapConfig,
 };
 
 /// Unified tactic configuration.
@@ -30,6 +30,9 @@ pub struct TacticConfig {
     /// ADAPTIVE configuration.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub adaptive: Option<AdaptiveConfig>,
+    /// `NET_LADDER` configuration.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub net_ladder: Option<NetLadderConfig>,
 }
 
 impl TacticConfig {
@@ -44,6 +47,7 @@ impl TacticConfig {
             twap: None,
             vwap: None,
             adaptive: None,
+            net_ladder: None,
         }
     }
 
@@ -58,6 +62,7 @@ impl TacticConfig {
             twap: None,
             vwap: None,
             adaptive: None,
+            net_ladder: None,
         }
     }
 
@@ -72,6 +77,7 @@ impl TacticConfig {
             twap: None,
             vwap: None,
             adaptive: None,
+            net_ladder: None,
         }
     }
 
@@ -86,6 +92,7 @@ impl TacticConfig {
             twap: Some(config),
             vwap: None,
             adaptive: None,
+            net_ladder: None,
         }
     }
 
@@ -100,6 +107,7 @@ impl TacticConfig {
             twap: None,
             vwap: Some(config),
             adaptive: None,
+            net_ladder: None,
         }
     }
 
@@ -114,6 +122,22 @@ impl TacticConfig {
             twap: None,
             vwap: None,
             adaptive: Some(config),
+            net_ladder: None,
+        }
+    }
+
+    /// Create a `NET_LADDER` tactic configuration.
+    #[must_use]
+    pub const fn net_ladder(config: NetLadderConfig) -> Self {
+        Self {
+            tactic: TacticType::NetLadder,
+            passive_limit: None,
+            aggressive_limit: None,
+            iceberg: None,
+            twap: None,
+            vwap: None,
+            adaptive: None,
+            net_ladder: Some(config),
         }
     }
 }
@@ -169,6 +193,14 @@ mod tests {
         assert!(config.adaptive.is_some());
     }
 
+    #[test]
+    fn tactic_config_net_ladder() {
+        let config = TacticConfig::net_ladder(NetLadderConfig::default());
+        assert_eq!(config.tactic, TacticType::NetLadder);
+        assert!(config.net_ladder.is_some());
+        assert!(config.adaptive.is_none());
+    }
+
     #[test]
     fn tactic_config_serde_skips_none_fields() {
         let config = TacticConfig::passive_limit(PassiveLimitConfig::default());
@@ -19,6 +19,8 @@ pub enum TacticType {
     Vwap,
     /// Dynamically switch between passive and aggressive based on conditions.
     Adaptive,
+    /// Walk a multi-leg net limit from mid toward natural.
+    NetLadder,
 }
 
 impl fmt::Display for TacticType {
@@ -30,6 +32,7 @@ impl fmt::Display for TacticType {
             Self::Twap => write!(f, "TWAP"),
             Self::Vwap => write!(f, "VWAP"),
             Self::Adaptive => write!(f, "ADAPTIVE"),
+            Self::NetLadder => write!(f, "NET_LADDER"),
         }
     }
 }
@@ -46,6 +49,7 @@ mod tests {
         assert_eq!(TacticType::Twap.to_string(), "TWAP");
         assert_eq!(TacticType::Vwap.to_string(), "VWAP");
         assert_eq!(TacticType::Adaptive.to_string(), "ADAPTIVE");
+        assert_eq!(TacticType::NetLadder.to_string(), "NET_LADDER");
     }
 
     #[test]
@@ -33,6 +33,13 @@ pub enum OptionPositionError {
         message: String,
     },
 
+    /// Volatility surface could not be built.
+    #[error("Invalid volatility surface: {message}")]
+    InvalidSurface {
+        /// Error details.
+        message: String,
+    },
+
     /// Greeks calculation error.
     #[error("Greeks calculation error: {message}")]
     GreeksError {
@@ -3,12 +3,18 @@
 //! This module handles options-specific position tracking, including:
 //! - Multi-leg spread tracking (verticals, butterflies, iron condors)
 //! - Portfolio Greeks aggregation
+//! - Black-Scholes pricing and implied volatility surfaces
 //! - Options-specific order construction
 
 pub mod errors;
+pub mod services;
 pub mod value_objects;
 
 pub use errors::OptionPositionError;
+pub use services::{
+    ChainOption, IvPoint, IvQuote, IvSlice, IvSurface, OptionsOrderValidator, SpreadBuilder,
+    SpreadIntent, SpreadQuote, SpreadStrategy,
+};
 pub use value_objects::{
     Leg, LegType, OptionContract, OptionPosition, OptionRight, OptionSpread, PositionSide,
     SpreadType,
@@ -0,0 +1,165 @@
+//! Black-Scholes pricing and implied volatility.
+//!
+//! European pricing without dividends; rates are annual and continuously
+//! compounded, time is in years.
+
+use std::f64::consts::SQRT_2;
+
+use crate::domain::option_position::value_objects::OptionRight;
+
+/// Lowest volatility the solver returns.
+pub const MIN_VOLATILITY: f64 = 0.0001;
+
+/// Highest volatility the solver returns.
+pub const MAX_VOLATILITY: f64 = 5.0;
+
+/// Solver stops once the volatility bracket is narrower than this.
+const VOLATILITY_TOLERANCE: f64 = 1e-7;
+
+const MAX_ITERATIONS: usize = 100;
+
+/// Standard normal cumulative distribution.
+#[must_use]
+pub fn norm_cdf(x: f64) -> f64 {
+    0.5 * (1.0 + libm::erf(x / SQRT_2))
+}
+
+/// Theoretical option price.
+///
+/// Returns the discounted intrinsic value when `years` or `volatility` is
+/// not positive.
+#[must_use]
+pub fn black_scholes_price(
+    right: OptionRight,
+    spot: f64,
+    strike: f64,
+    years: f64,
+    rate: f64,
+    volatility: f64,
+) -> f64 {
+    let discounted_strike = strike * (-rate * years.max(0.0)).exp();
+    if years <= 0.0 || volatility <= 0.0 {
+        return match right {
+            OptionRight::Call => (spot - discounted_strike).max(0.0),
+            OptionRight::Put => (discounted_strike - spot).max(0.0),
+        };
+    }
+
+    let vol_sqrt_t = volatility * years.sqrt();
+    let drift = (0.5 * volatility).mul_add(volatility, rate);
+    let d1 = drift.mul_add(years, (spot / strike).ln()) / vol_sqrt_t;
+    let d2 = d1 - vol_sqrt_t;
+
+    match right {
+        OptionRight::Call => spot.mul_add(norm_cdf(d1), -discounted_strike * norm_cdf(d2)),
+        OptionRight::Put => discounted_strike.mul_add(norm_cdf(-d2), -spot * norm_cdf(-d1)),
+    }
+}
+
+/// Option delta per unit of underlying.
+///
+/// Returns the expiry delta (1, 0 or -1) when `years` or `volatility` is not
+/// positive.
+#[must_use]
+pub fn black_scholes_delta(
+    right: OptionRight,
+    spot: f64,
+    strike: f64,
+    years: f64,
+    rate: f64,
+    volatility: f64,
+) -> f64 {
+    let call_delta = if years <= 0.0 || volatility <= 0.0 {
+        if spot > strike { 1.0 } else { 0.0 }
+    } else {
+        let vol_sqrt_t = volatility * years.sqrt();
+        let drift = (0.5 * volatility).mul_add(volatility, rate);
+        norm_cdf(drift.mul_add(years, (spot / strike).ln()) / vol_sqrt_t)
+    };
+
+    match right {
+        OptionRight::Call => call_delta,
+        OptionRight::Put => call_delta - 1.0,
+    }
+}
+
+/// Volatility at which the Black-Scholes price equals `price`.
+///
+/// Returns `None` if the inputs are not positive or the price lies outside
+/// the prices reachable between [`MIN_VOLATILITY`] and [`MAX_VOLATILITY`]
+/// (e.g. below intrinsic value).
+#[must_use]
+pub fn implied_volatility(
+    right: OptionRight,
+    price: f64,
+    spot: f64,
+    strike: f64,
+    years: f64,
+    rate: f64,
+) -> Option<f64> {
+    if price.is_nan() || price <= 0.0 || spot <= 0.0 || strike <= 0.0 || years <= 0.0 {
+        return None;
+    }
+
+    let price_at = |volatility| black_scholes_price(right, spot, strike, years, rate, volatility);
+    let (mut low, mut high) = (MIN_VOLATILITY, MAX_VOLATILITY);
+    if price < price_at(low) || price > price_at(high) {
+        return None;
+    }
+
+    // Price is increasing in volatility, so bisection always converges
+    for _ in 0..MAX_ITERATIONS {
+        let mid = 0.5 * (low + high);
+        if price_at(mid) < price {
+            low = mid;
+        } else {
+            high = mid;
+        }
+        if high - low < VOLATILITY_TOLERANCE {
+            break;
+        }
+    }
+    Some(0.5 * (low + high))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn prices_match_reference_values() {
+        // Hull, Options, Futures and Other Derivatives, example 15.6
+        let call = black_scholes_price(OptionRight::Call, 42.0, 40.0, 0.5, 0.1, 0.2);
+        let put = black_scholes_price(OptionRight::Put, 42.0, 40.0, 0.5, 0.1, 0.2);
+
+        assert!((call - 4.76).abs() < 0.005);
+        assert!((put - 0.81).abs() < 0.005);
+    }
+
+    #[test]
+    fn delta_matches_reference_values() {
+        // Hull, example 19.1: 0.522 for the call
+        let call = black_scholes_delta(OptionRight::Call, 49.0, 50.0, 0.3846, 0.05, 0.2);
+        let put = black_scholes_delta(OptionRight::Put, 49.0, 50.0, 0.3846, 0.05, 0.2);
+
+        assert!((call - 0.522).abs() < 0.001);
+        assert!((put - (call - 1.0)).abs() < 1e-12);
+    }
+
+    #[test]
+    fn implied_volatility_round_trips() {
+        for right in [OptionRight::Call, OptionRight::Put] {
+            for strike in [80.0, 100.0, 120.0] {
+                let price = black_scholes_price(right, 100.0, strike, 0.25, 0.04, 0.35);
+                let iv = implied_volatility(right, price, 100.0, strike, 0.25, 0.04).unwrap();
+                assert!((iv - 0.35).abs() < 1e-5, "{right} {strike}: {iv}");
+            }
+        }
+    }
+
+    #[test]
+    fn implied_volatility_rejects_prices_below_intrinsic() {
+        assert!(implied_volatility(OptionRight::Call, 5.0, 120.0, 100.0, 0.25, 0.0).is_none());
+        assert!(implied_volatility(OptionRight::Put, 0.0, 100.0, 100.0, 0.25, 0.0).is_none());
+    }
+}
@@ -0,0 +1,417 @@
+//! Implied volatility surface.
+//!
+//! Each expiration is fitted on its own. Quote prices are inverted to
+//! implied volatility, preferring the out-of-the-money side at each strike,
+//! and a least-squares quadratic in log-moneyness `ln(K / F)` smooths the
+//! smile. Between expirations the surface interpolates total variance
+//! (`σ² · t`) linearly in time; outside the quoted strikes and expirations
+//! it extrapolates flat.
+
+use chrono::NaiveDate;
+
+use super::black_scholes::{MIN_VOLATILITY, implied_volatility};
+use crate::domain::option_position::errors::OptionPositionError;
+use crate::domain::option_position::value_objects::OptionRight;
+
+const DAYS_PER_YEAR: f64 = 365.0;
+
+/// Highest polynomial degree fitted to a smile.
+const SMILE_DEGREE: usize = 2;
+
+/// An option price to fit.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct IvQuote {
+    /// Expiration date.
+    pub expiration: NaiveDate,
+    /// Strike price.
+    pub strike: f64,
+    /// Call or put.
+    pub right: OptionRight,
+    /// Option price, usually the bid/ask mid.
+    pub price: f64,
+}
+
+/// A quote's implied volatility and the smoothed value at its strike.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct IvPoint {
+    /// Strike price.
+    pub strike: f64,
+    /// Call or put.
+    pub right: OptionRight,
+    /// Volatility implied by the quote.
+    pub market_iv: f64,
+    /// Volatility of the fitted smile.
+    pub fitted_iv: f64,
+}
+
+/// Fitted smile for one expiration.
+#[derive(Debug, Clone, PartialEq)]
+pub struct IvSlice {
+    /// Expiration date.
+    pub expiration: NaiveDate,
+    /// Time to expiration in years.
+    pub years: f64,
+    /// Forward price of the underlying at expiration.
+    pub forward: f64,
+    /// Quotes used in the fit, by strike.
+    pub points: Vec<IvPoint>,
+    /// Smile coefficients, constant term first.
+    coefficients: Vec<f64>,
+    /// Lowest and highest log-moneyness quoted.
+    moneyness_range: (f64, f64),
+}
+
+impl IvSlice {
+    /// Smoothed volatility at `strike`.
+    #[must_use]
+    pub fn volatility(&self, strike: f64) -> f64 {
+        self.volatility_at_moneyness((strike / self.forward).ln())
+    }
+
+    /// Smoothed volatility at the forward.
+    #[must_use]
+    pub fn atm_volatility(&self) -> f64 {
+        self.volatility_at_moneyness(0.0)
+    }
+
+    fn volatility_at_moneyness(&self, moneyness: f64) -> f64 {
+        let (low, high) = self.moneyness_range;
+        polynomial(&self.coefficients, moneyness.clamp(low, high)).max(MIN_VOLATILITY)
+    }
+}
+
+/// Implied volatility by strike and expiration for one underlying.
+#[derive(Debug, Clone, PartialEq)]
+pub struct IvSurface {
+    spot: f64,
+    rate: f64,
+    as_of: NaiveDate,
+    slices: Vec<IvSlice>,
+}
+
+impl IvSurface {
+    /// Fit a surface to option prices.
+    ///
+    /// `rate` is the annual continuously compounded risk-free rate.
+    /// Expirations on or before `as_of` and quotes whose price implies no
+    /// volatility are skipped.
+    ///
+    /// # Errors
+    ///
+    /// Returns error if `spot` is not positive or no expiration has a usable
+    /// quote.
+    pub fn fit(
+        spot: f64,
+        rate: f64,
+        as_of: NaiveDate,
+        quotes: &[IvQuote],
+    ) -> Result<Self, OptionPositionError> {
+        if spot.is_nan() || spot <= 0.0 {
+            return Err(OptionPositionError::InvalidSurface {
+                message: format!("underlying price must be positive, got {spot}"),
+            });
+        }
+
+        let mut expirations: Vec<NaiveDate> = quotes.iter().map(|q| q.expiration).collect();
+        expirations.sort_unstable();
+        expirations.dedup();
+
+        let slices: Vec<IvSlice> = expirations
+            .into_iter()
+            .filter_map(|expiration| {
+                let years = years_between(as_of, expiration);
+                let quotes = quotes.iter().filter(|q| q.expiration == expiration);
+                fit_slice(spot, rate, expiration, years, quotes)
+            })
+            .collect();
+
+        if slices.is_empty() {
+            return Err(OptionPositionError::InvalidSurface {
+                message: "no quotes with a solvable implied volatility".to_string(),
+            });
+        }
+
+        Ok(Self {
+            spot,
+            rate,
+            as_of,
+            slices,
+        })
+    }
+
+    /// Underlying price the surface was fitted at.
+    #[must_use]
+    pub const fn spot(&self) -> f64 {
+        self.spot
+    }
+
+    /// Risk-free rate the surface was fitted with.
+    #[must_use]
+    pub const fn rate(&self) -> f64 {
+        self.rate
+    }
+
+    /// Date the surface was fitted for.
+    #[must_use]
+    pub const fn as_of(&self) -> NaiveDate {
+        self.as_of
+    }
+
+    /// Fitted expirations, nearest first.
+    #[must_use]
+    pub fn slices(&self) -> &[IvSlice] {
+        &self.slices
+    }
+
+    /// Smoothed volatility for `strike` expiring on `expiration`.
+    ///
+    /// Returns `None` for expirations on or before the surface date.
+    #[must_use]
+    pub fn volatility(&self, strike: f64, expiration: NaiveDate) -> Option<f64> {
+        let years = years_between(self.as_of, expiration);
+        if years <= 0.0 {
+            return None;
+        }
+        let moneyness = (strike / (self.spot * (self.rate * years).exp())).ln();
+
+        let after = self.slices.iter().position(|s| s.years >= years);
+        let volatility = match after {
+            Some(0) => self.slices[0].volatility_at_moneyness(moneyness),
+            None => self.slices[self.slices.len() - 1].volatility_at_moneyness(moneyness),
+            Some(i) => {
+                let (near, far) = (&self.slices[i - 1], &self.slices[i]);
+                let near_variance = near.volatility_at_moneyness(moneyness).powi(2) * near.years;
+                let far_variance = far.volatility_at_moneyness(moneyness).powi(2) * far.years;
+                let weight = (years - near.years) / (far.years - near.years);
+                let variance = weight.mul_add(far_variance - near_variance, near_variance);
+                (variance / years).sqrt()
+            }
+        };
+        Some(volatility)
+    }
+}
+
+/// Years from `as_of` to `expiration`, counting calendar days.
+#[must_use]
+pub fn years_between(as_of: NaiveDate, expiration: NaiveDate) -> f64 {
+    #[allow(clippy::cast_precision_loss)]
+    let days = (expiration - as_of).num_days() as f64;
+    days / DAYS_PER_YEAR
+}
+
+fn fit_slice<'a>(
+    spot: f64,
+    rate: f64,
+    expiration: NaiveDate,
+    years: f64,
+    quotes: impl Iterator<Item = &'a IvQuote>,
+) -> Option<IvSlice> {
+    if years <= 0.0 {
+        return None;
+    }
+    let forward = spot * (rate * years).exp();
+
+    let solved: Vec<IvPoint> = quotes
+        .filter_map(|q| {
+            implied_volatility(q.right, q.price, spot, q.strike, years, rate).map(|iv| IvPoint {
+                strike: q.strike,
+                right: q.right,
+                market_iv: iv,
+                fitted_iv: iv,
+            })
+        })
+        .collect();
+
+    // In-the-money quotes are less liquid; use them only for strikes
+    // without an out-of-the-money quote
+    let out_of_the_money = |p: &IvPoint| match p.right {
+        OptionRight::Call => p.strike >= forward,
+        OptionRight::Put => p.strike <= forward,
+    };
+    let mut points: Vec<IvPoint> = solved
+        .iter()
+        .filter(|p| {
+            out_of_the_money(p)
+                || !solved.iter().any(|other| {
+                    other.strike.total_cmp(&p.strike).is_eq() && out_of_the_money(other)
+                })
+        })
+        .copied()
+        .collect();
+    if points.is_empty() {
+        return None;
+    }
+    points.sort_by(|a, b| a.strike.total_cmp(&b.strike));
+
+    let samples: Vec<(f64, f64)> = points
+        .iter()
+        .map(|p| ((p.strike / forward).ln(), p.market_iv))
+        .collect();
+    let coefficients = (0..=SMILE_DEGREE.min(samples.len() - 1))
+        .rev()
+        .find_map(|degree| least_squares(&samples, degree))?;
+    let moneyness_range = samples
+        .iter()
+        .fold((f64::INFINITY, f64::NEG_INFINITY), |(low, high), (k, _)| {
+            (low.min(*k), high.max(*k))
+        });
+
+    let mut slice = IvSlice {
+        expiration,
+        years,
+        forward,
+        points,
+        coefficients,
+        moneyness_range,
+    };
+    let fitted: Vec<f64> = slice
+        .points
+        .iter()
+        .map(|p| slice.volatility(p.strike))
+        .collect();
+    for (point, fitted_iv) in slice.points.iter_mut().zip(fitted) {
+        point.fitted_iv = fitted_iv;
+    }
+    Some(slice)
+}
+
+/// Evaluate a polynomial with coefficients ordered constant term first.
+fn polynomial(coefficients: &[f64], x: f64) -> f64 {
+    coefficients
+        .iter()
+        .rev()
+        .fold(0.0, |acc, coefficient| acc.mul_add(x, *coefficient))
+}
+
+/// Least-squares polynomial fit of `(x, y)` samples.
+///
+/// Returns `None` if the samples cannot determine a polynomial of `degree`
+/// (e.g. fewer distinct `x` values than coefficients).
+fn least_squares(samples: &[(f64, f64)], degree: usize) -> Option<Vec<f64>> {
+    let size = degree + 1;
+
+    // Normal equations: A[i][j] = Σ x^(i+j), b[i] = Σ y·x^i
+    let mut matrix = vec![vec![0.0; size + 1]; size];
+    for &(x, y) in samples {
+        let powers: Vec<f64> = (0..2 * size)
+            .scan(1.0, |p, _| {
+                let current = *p;
+                *p *= x;
+                Some(current)
+            })
+            .collect();
+        for (i, row) in matrix.iter_mut().enumerate() {
+            for (j, cell) in row.iter_mut().take(size).enumerate() {
+                *cell += powers[i + j];
+            }
+            row[size] = y.mul_add(powers[i], row[size]);
+        }
+    }
+
+    // Gaussian elimination with partial pivoting
+    for column in 0..size {
+        let pivot = (column..size)
+            .max_by(|&a, &b| matrix[a][column].abs().total_cmp(&matrix[b][column].abs()))?;
+        if matrix[pivot][column].abs() < 1e-12 {
+            return None;
+        }
+        matrix.swap(column, pivot);
+        let pivot_row = matrix[column].clone();
+        for row in matrix.iter_mut().skip(column + 1) {
+            let factor = row[column] / pivot_row[column];
+            for (cell, pivot_cell) in row.iter_mut().zip(&pivot_row).skip(column) {
+                *cell = (-factor).mul_add(*pivot_cell, *cell);
+            }
+        }
+    }
+
+    let mut coefficients = vec![0.0; size];
+    for (row, equation) in matrix.iter().enumerate().rev() {
+        let known: f64 = equation[row + 1..size]
+            .iter()
+            .zip(&coefficients[row + 1..])
+            .map(|(a, c)| a * c)
+            .sum();
+        coefficients[row] = (equation[size] - known) / equation[row];
+    }
+    Some(coefficients)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::option_position::services::black_scholes_price;
+
+    const SPOT: f64 = 100.0;
+    const RATE: f64 = 0.04;
+
+    fn as_of() -> NaiveDate {
+        NaiveDate::from_ymd_opt(2025, 1, 2).unwrap()
+    }
+
+    fn quotes(days: i64, smile: impl Fn(f64) -> f64) -> Vec<IvQuote> {
+        let expiration = as_of() + chrono::Duration::days(days);
+        let years = years_between(as_of(), expiration);
+        let forward = SPOT * (RATE * years).exp();
+
+        [80.0, 90.0, 95.0, 100.0, 105.0, 110.0, 120.0]
+            .into_iter()
+            .flat_map(|strike| {
+                let volatility = smile((strike / forward).ln());
+                [OptionRight::Call, OptionRight::Put].map(|right| IvQuote {
+                    expiration,
+                    strike,
+                    right,
+                    price: black_scholes_price(right, SPOT, strike, years, RATE, volatility),
+                })
+            })
+            .collect()
+    }
+
+    #[test]
+    fn recovers_quadratic_smile() {
+        let smile = |k| polynomial(&[0.25, -0.1, 0.5], k);
+        let surface = IvSurface::fit(SPOT, RATE, as_of(), &quotes(30, smile)).unwrap();
+
+        let slice = &surface.slices()[0];
+        assert_eq!(slice.points.len(), 7);
+        assert!((slice.atm_volatility() - 0.25).abs() < 1e-4);
+        for point in &slice.points {
+            assert!((point.fitted_iv - point.market_iv).abs() < 1e-4);
+        }
+    }
+
+    #[test]
+    fn interpolates_total_variance_between_expirations() {
+        let mut all = quotes(30, |_| 0.2);
+        all.extend(quotes(90, |_| 0.3));
+        let surface = IvSurface::fit(SPOT, RATE, as_of(), &all).unwrap();
+
+        let volatility = |days| {
+            surface
+                .volatility(100.0, as_of() + chrono::Duration::days(days))
+                .unwrap()
+        };
+        // sqrt((0.2² · 30 + 0.3² · 90) / 2 / 60)
+        assert!((volatility(60) - 0.278_388).abs() < 1e-4);
+        assert!((volatility(10) - 0.2).abs() < 1e-4);
+        assert!((volatility(365) - 0.3).abs() < 1e-4);
+        assert!(surface.volatility(100.0, as_of()).is_none());
+    }
+
+    #[test]
+    fn rejects_chain_without_usable_quotes() {
+        let expired = IvQuote {
+            expiration: as_of(),
+            strike: 100.0,
+            right: OptionRight::Call,
+            price: 1.0,
+        };
+
+        assert!(matches!(
+            IvSurface::fit(SPOT, RATE, as_of(), &[expired]),
+            Err(OptionPositionError::InvalidSurface { .. })
+        ));
+        assert!(IvSurface::fit(0.0, RATE, as_of(), &quotes(30, |_| 0.2)).is_err());
+    }
+}
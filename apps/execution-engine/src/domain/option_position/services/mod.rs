@@ -0,0 +1,14 @@
+//! Option Position Domain Services
+
+mod black_scholes;
+mod iv_surface;
+mod options_order_validator;
+mod spread_builder;
+
+pub use black_scholes::{
+    MAX_VOLATILITY, MIN_VOLATILITY, black_scholes_delta, black_scholes_price, implied_volatility,
+    norm_cdf,
+};
+pub use iv_surface::{IvPoint, IvQuote, IvSlice, IvSurface, years_between};
+pub use options_order_validator::OptionsOrderValidator;
+pub use spread_builder::{ChainOption, SpreadBuilder, SpreadIntent, SpreadQuote, SpreadStrategy};
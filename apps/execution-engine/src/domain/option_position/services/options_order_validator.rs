@@ -0,0 +1,248 @@
+//! Options order validation.
+//!
+//! Structural checks on a multi-leg order before it is priced or sent:
+//! leg counts, shared underlying and expiration, and the strike and side
+//! layout each spread type requires.
+
+use std::collections::HashSet;
+
+use rust_decimal::Decimal;
+
+use crate::domain::option_position::errors::OptionPositionError;
+use crate::domain::option_position::value_objects::{Leg, OptionRight, OptionSpread, SpreadType};
+
+/// Validates multi-leg option orders.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct OptionsOrderValidator;
+
+impl OptionsOrderValidator {
+    /// Check that `spread` is a well-formed order of its spread type.
+    ///
+    /// # Errors
+    ///
+    /// Returns error describing the first problem found.
+    pub fn validate(spread: &OptionSpread) -> Result<(), OptionPositionError> {
+        let legs = spread.legs();
+        if !spread.is_valid() {
+            return Err(invalid_spread(format!(
+                "legs must be non-empty and all on {}",
+                spread.underlying()
+            )));
+        }
+
+        let expected = spread.spread_type().typical_leg_count();
+        if expected != 0 && legs.len() != expected {
+            return Err(invalid_spread(format!(
+                "{} requires {expected} legs, got {}",
+                spread.spread_type(),
+                legs.len()
+            )));
+        }
+
+        let mut contracts = HashSet::new();
+        for leg in legs {
+            if leg.quantity() <= Decimal::ZERO {
+                return Err(OptionPositionError::InvalidLeg {
+                    message: format!("{} quantity must be positive", leg.contract().symbol()),
+                });
+            }
+            let contract = leg.contract();
+            if !contracts.insert((contract.expiration(), contract.strike(), contract.right())) {
+                return Err(OptionPositionError::InvalidLeg {
+                    message: format!("{} appears more than once", contract.symbol()),
+                });
+            }
+        }
+
+        if !spread.spread_type().is_multi_expiry()
+            && legs
+                .iter()
+                .any(|leg| leg.contract().expiration() != legs[0].contract().expiration())
+        {
+            return Err(invalid_spread(format!(
+                "{} legs must share one expiration",
+                spread.spread_type()
+            )));
+        }
+
+        match spread.spread_type() {
+            SpreadType::Vertical => validate_vertical(legs),
+            SpreadType::Straddle | SpreadType::Strangle => {
+                validate_straddle(legs, spread.spread_type() == SpreadType::Straddle)
+            }
+            SpreadType::IronCondor | SpreadType::IronButterfly => {
+                validate_iron(legs, spread.spread_type() == SpreadType::IronButterfly)
+            }
+            _ => Ok(()),
+        }
+    }
+}
+
+const fn invalid_spread(message: String) -> OptionPositionError {
+    OptionPositionError::InvalidSpread { message }
+}
+
+/// Same right, different strikes, one long and one short.
+fn validate_vertical(legs: &[Leg]) -> Result<(), OptionPositionError> {
+    let (a, b) = (&legs[0], &legs[1]);
+    if a.contract().right() != b.contract().right() {
+        return Err(invalid_spread(
+            "vertical legs must both be calls or both be puts".to_string(),
+        ));
+    }
+    if a.contract().strike() == b.contract().strike() {
+        return Err(invalid_spread(
+            "vertical legs must have different strikes".to_string(),
+        ));
+    }
+    if a.side() == b.side() {
+        return Err(invalid_spread(
+            "vertical must buy one leg and sell the other".to_string(),
+        ));
+    }
+    Ok(())
+}
+
+/// One call and one put on the same side; a straddle shares the strike, a
+/// strangle puts the put strike below the call strike.
+fn validate_straddle(legs: &[Leg], same_strike: bool) -> Result<(), OptionPositionError> {
+    let (put, call) = put_and_call(&legs[0], &legs[1])
+        .ok_or_else(|| invalid_spread("requires one call and one put".to_string()))?;
+    if put.side() != call.side() {
+        return Err(invalid_spread(
+            "call and put must both be bought or both be sold".to_string(),
+        ));
+    }
+
+    let (put_strike, call_strike) = (put.contract().strike(), call.contract().strike());
+    if same_strike && put_strike != call_strike {
+        return Err(invalid_spread(
+            "straddle call and put must share a strike".to_string(),
+        ));
+    }
+    if !same_strike && put_strike >= call_strike {
+        return Err(invalid_spread(
+            "strangle put strike must be below the call strike".to_string(),
+        ));
+    }
+    Ok(())
+}
+
+/// A put spread below a call spread, with the inner (body) strikes on one
+/// side and the outer (wing) strikes on the other. An iron butterfly's body
+/// strikes meet.
+fn validate_iron(legs: &[Leg], meeting_body: bool) -> Result<(), OptionPositionError> {
+    let mut puts: Vec<&Leg> = legs
+        .iter()
+        .filter(|leg| leg.contract().right() == OptionRight::Put)
+        .collect();
+    let mut calls: Vec<&Leg> = legs
+        .iter()
+        .filter(|leg| leg.contract().right() == OptionRight::Call)
+        .collect();
+    if puts.len() != 2 || calls.len() != 2 {
+        return Err(invalid_spread(
+            "requires two puts and two calls".to_string(),
+        ));
+    }
+    puts.sort_by_key(|leg| leg.contract().strike());
+    calls.sort_by_key(|leg| leg.contract().strike());
+    let [put_wing, put_body] = [puts[0], puts[1]];
+    let [call_body, call_wing] = [calls[0], calls[1]];
+
+    let (put_top, call_bottom) = (put_body.contract().strike(), call_body.contract().strike());
+    if meeting_body && put_top != call_bottom {
+        return Err(invalid_spread(
+            "iron butterfly body strikes must match".to_string(),
+        ));
+    }
+    if !meeting_body && put_top >= call_bottom {
+        return Err(invalid_spread(
+            "iron condor put strikes must be below the call strikes".to_string(),
+        ));
+    }
+
+    let body_side = put_body.side();
+    if call_body.side() != body_side
+        || put_wing.side() == body_side
+        || call_wing.side() == body_side
+    {
+        return Err(invalid_spread(
+            "wings must be on the opposite side of the body".to_string(),
+        ));
+    }
+    Ok(())
+}
+
+const fn put_and_call<'a>(a: &'a Leg, b: &'a Leg) -> Option<(&'a Leg, &'a Leg)> {
+    match (a.contract().right(), b.contract().right()) {
+        (OptionRight::Put, OptionRight::Call) => Some((a, b)),
+        (OptionRight::Call, OptionRight::Put) => Some((b, a)),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::option_position::value_objects::{LegType, OptionContract, PositionSide};
+    use crate::domain::shared::Symbol;
+    use chrono::NaiveDate;
+
+    fn leg(right: OptionRight, strike: i64, side: PositionSide, day: u32) -> Leg {
+        let contract = OptionContract::new(
+            Symbol::new(format!("SPY2502{day:02}{right}{strike}")),
+            "SPY",
+            Decimal::new(strike, 0),
+            NaiveDate::from_ymd_opt(2025, 2, day).unwrap(),
+            right,
+        );
+        Leg::new(contract, side, Decimal::ONE, LegType::Body)
+    }
+
+    fn condor(put_body: PositionSide, put_wing: PositionSide) -> OptionSpread {
+        OptionSpread::new(
+            SpreadType::IronCondor,
+            vec![
+                leg(OptionRight::Put, 480, put_wing, 21),
+                leg(OptionRight::Put, 490, put_body, 21),
+                leg(OptionRight::Call, 510, PositionSide::Short, 21),
+                leg(OptionRight::Call, 520, PositionSide::Long, 21),
+            ],
+            "SPY",
+        )
+    }
+
+    #[test]
+    fn accepts_well_formed_iron_condor() {
+        assert!(
+            OptionsOrderValidator::validate(&condor(PositionSide::Short, PositionSide::Long))
+                .is_ok()
+        );
+    }
+
+    #[test]
+    fn rejects_wings_on_body_side() {
+        let err =
+            OptionsOrderValidator::validate(&condor(PositionSide::Short, PositionSide::Short))
+                .unwrap_err();
+        assert!(err.to_string().contains("wings"));
+    }
+
+    #[test]
+    fn rejects_mixed_expirations_and_leg_counts() {
+        let vertical = OptionSpread::vertical(
+            leg(OptionRight::Call, 500, PositionSide::Long, 21),
+            leg(OptionRight::Call, 510, PositionSide::Short, 28),
+            "SPY",
+        );
+        assert!(OptionsOrderValidator::validate(&vertical).is_err());
+
+        let short = OptionSpread::new(
+            SpreadType::IronCondor,
+            vec![leg(OptionRight::Put, 490, PositionSide::Short, 21)],
+            "SPY",
+        );
+        assert!(OptionsOrderValidator::validate(&short).is_err());
+    }
+}
@@ -0,0 +1,487 @@
+//! Spread construction from high-level intents.
+//!
+//! Turns an intent such as "short 30-delta iron condor, 30-45 DTE, $5 wings"
+//! into concrete legs picked from an option chain, validated and priced off
+//! the current quotes.
+//!
+//! The expiration is the one inside the DTE range closest to its midpoint.
+//! Body legs are picked by delta (or at the money for straddles and iron
+//! butterflies) and take the intent's side; wings sit `wing_width` further
+//! out of the money on the opposite side. Prices are per spread: positive is
+//! a debit, negative a credit.
+
+use chrono::NaiveDate;
+use rust_decimal::Decimal;
+
+use super::OptionsOrderValidator;
+use crate::domain::option_position::errors::OptionPositionError;
+use crate::domain::option_position::value_objects::{
+    Leg, LegType, OptionContract, OptionRight, OptionSpread, PositionSide, SpreadType,
+};
+
+/// Spread shapes the builder can construct.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SpreadStrategy {
+    /// Two strikes of the same right; the wing is further out of the money.
+    Vertical(OptionRight),
+    /// Delta-picked put and call spreads.
+    IronCondor,
+    /// At-the-money body with put and call wings.
+    IronButterfly,
+    /// At-the-money call and put.
+    Straddle,
+    /// Delta-picked call and put.
+    Strangle,
+}
+
+impl SpreadStrategy {
+    /// Spread type of the resulting order.
+    #[must_use]
+    pub const fn spread_type(&self) -> SpreadType {
+        match self {
+            Self::Vertical(_) => SpreadType::Vertical,
+            Self::IronCondor => SpreadType::IronCondor,
+            Self::IronButterfly => SpreadType::IronButterfly,
+            Self::Straddle => SpreadType::Straddle,
+            Self::Strangle => SpreadType::Strangle,
+        }
+    }
+
+    /// Whether the strategy has wings and so needs a wing width.
+    #[must_use]
+    pub const fn has_wings(&self) -> bool {
+        matches!(
+            self,
+            Self::Vertical(_) | Self::IronCondor | Self::IronButterfly
+        )
+    }
+
+    /// Whether the body strikes are picked by delta rather than at the money.
+    #[must_use]
+    pub const fn uses_delta(&self) -> bool {
+        matches!(self, Self::Vertical(_) | Self::IronCondor | Self::Strangle)
+    }
+}
+
+/// What to build.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SpreadIntent {
+    /// Underlying symbol.
+    pub underlying: String,
+    /// Spread shape.
+    pub strategy: SpreadStrategy,
+    /// Side of the body legs; `Short` sells the body (e.g. a credit condor).
+    pub side: PositionSide,
+    /// Absolute delta of the body legs (0.30 for 30-delta), ignored for
+    /// at-the-money strategies.
+    pub target_delta: f64,
+    /// Shortest days to expiration.
+    pub min_dte: u32,
+    /// Longest days to expiration.
+    pub max_dte: u32,
+    /// Distance from body to wing strikes.
+    pub wing_width: Option<Decimal>,
+    /// Contracts per leg.
+    pub quantity: Decimal,
+}
+
+/// A quoted contract the builder can choose from.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ChainOption {
+    /// The contract.
+    pub contract: OptionContract,
+    /// Best bid.
+    pub bid: Decimal,
+    /// Best ask.
+    pub ask: Decimal,
+    /// Delta, if known.
+    pub delta: Option<f64>,
+}
+
+impl ChainOption {
+    fn is_quoted(&self) -> bool {
+        self.ask > Decimal::ZERO && self.ask >= self.bid && self.bid >= Decimal::ZERO
+    }
+
+    fn mid(&self) -> Decimal {
+        (self.bid + self.ask) / Decimal::TWO
+    }
+}
+
+/// A built spread with its prices.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SpreadQuote {
+    /// The validated spread.
+    pub spread: OptionSpread,
+    /// Net mid price per spread, rounded to cents.
+    pub mid_price: Decimal,
+    /// Net price per spread paying the ask and selling at the bid.
+    pub natural_price: Decimal,
+}
+
+/// Builds spreads from intents.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct SpreadBuilder;
+
+impl SpreadBuilder {
+    /// Pick legs for `intent` from `chain` and price them.
+    ///
+    /// # Errors
+    ///
+    /// Returns error if the intent is malformed, the chain has no suitable
+    /// contracts, or the resulting spread fails validation.
+    pub fn build(
+        intent: &SpreadIntent,
+        spot: Decimal,
+        as_of: NaiveDate,
+        chain: &[ChainOption],
+    ) -> Result<SpreadQuote, OptionPositionError> {
+        validate_intent(intent)?;
+        let expiration = select_expiration(intent, as_of, chain)?;
+        let options: Vec<&ChainOption> = chain
+            .iter()
+            .filter(|option| option.contract.expiration() == expiration && option.is_quoted())
+            .collect();
+        let picker = Picker {
+            options: &options,
+            intent,
+        };
+        let body = intent.side;
+        let wing = opposite(body);
+
+        let legs = match intent.strategy {
+            SpreadStrategy::Vertical(right) => {
+                let primary = picker.by_delta(right)?;
+                let secondary = picker.wing(primary, right)?;
+                vec![
+                    picker.leg(primary, body, LegType::Primary),
+                    picker.leg(secondary, wing, LegType::Secondary),
+                ]
+            }
+            SpreadStrategy::IronCondor => {
+                let put = picker.by_delta(OptionRight::Put)?;
+                let call = picker.by_delta(OptionRight::Call)?;
+                picker.iron(put, call)?
+            }
+            SpreadStrategy::IronButterfly => {
+                let (put, call) = picker.at_the_money(spot)?;
+                picker.iron(put, call)?
+            }
+            SpreadStrategy::Straddle => {
+                let (put, call) = picker.at_the_money(spot)?;
+                vec![
+                    picker.leg(put, body, LegType::Primary),
+                    picker.leg(call, body, LegType::Primary),
+                ]
+            }
+            SpreadStrategy::Strangle => {
+                let put = picker.by_delta(OptionRight::Put)?;
+                let call = picker.by_delta(OptionRight::Call)?;
+                vec![
+                    picker.leg(put, body, LegType::Primary),
+                    picker.leg(call, body, LegType::Primary),
+                ]
+            }
+        };
+
+        let (mid_price, natural_price) = price(&legs);
+        let spread = OptionSpread::new(
+            intent.strategy.spread_type(),
+            legs.into_iter().map(|(leg, _)| leg).collect(),
+            intent.underlying.clone(),
+        );
+        OptionsOrderValidator::validate(&spread)?;
+
+        Ok(SpreadQuote {
+            spread,
+            mid_price: mid_price.round_dp(2),
+            natural_price,
+        })
+    }
+}
+
+fn invalid_spread(message: impl Into<String>) -> OptionPositionError {
+    OptionPositionError::InvalidSpread {
+        message: message.into(),
+    }
+}
+
+const fn opposite(side: PositionSide) -> PositionSide {
+    match side {
+        PositionSide::Long => PositionSide::Short,
+        PositionSide::Short => PositionSide::Long,
+    }
+}
+
+fn validate_intent(intent: &SpreadIntent) -> Result<(), OptionPositionError> {
+    if intent.quantity <= Decimal::ZERO {
+        return Err(invalid_spread("quantity must be positive"));
+    }
+    if intent.min_dte > intent.max_dte {
+        return Err(invalid_spread("min DTE must not exceed max DTE"));
+    }
+    let delta = intent.target_delta;
+    if intent.strategy.uses_delta() && (delta.is_nan() || delta <= 0.0 || delta >= 1.0) {
+        return Err(invalid_spread("target delta must be between 0 and 1"));
+    }
+    if intent.strategy.has_wings() && intent.wing_width.is_none_or(|width| width <= Decimal::ZERO) {
+        return Err(invalid_spread(format!(
+            "{} requires a positive wing width",
+            intent.strategy.spread_type()
+        )));
+    }
+    Ok(())
+}
+
+/// The expiration within the DTE range closest to its midpoint, earliest on
+/// ties.
+fn select_expiration(
+    intent: &SpreadIntent,
+    as_of: NaiveDate,
+    chain: &[ChainOption],
+) -> Result<NaiveDate, OptionPositionError> {
+    let midpoint = i64::from(intent.min_dte) + i64::from(intent.max_dte);
+    chain
+        .iter()
+        .map(|option| option.contract.expiration())
+        .filter(|expiration| {
+            let dte = (*expiration - as_of).num_days();
+            dte >= i64::from(intent.min_dte) && dte <= i64::from(intent.max_dte)
+        })
+        .min_by_key(|expiration| {
+            let dte = (*expiration - as_of).num_days();
+            ((2 * dte - midpoint).abs(), *expiration)
+        })
+        .ok_or_else(|| {
+            invalid_spread(format!(
+                "no {} expiration between {} and {} DTE",
+                intent.underlying, intent.min_dte, intent.max_dte
+            ))
+        })
+}
+
+/// Leg selection over the quoted contracts of one expiration.
+struct Picker<'a> {
+    options: &'a [&'a ChainOption],
+    intent: &'a SpreadIntent,
+}
+
+impl<'a> Picker<'a> {
+    fn of_right(&self, right: OptionRight) -> impl Iterator<Item = &'a ChainOption> + '_ {
+        self.options
+            .iter()
+            .copied()
+            .filter(move |option| option.contract.right() == right)
+    }
+
+    fn by_delta(&self, right: OptionRight) -> Result<&'a ChainOption, OptionPositionError> {
+        let target = self.intent.target_delta;
+        self.of_right(right)
+            .filter_map(|option| Some((option, (option.delta?.abs() - target).abs())))
+            .min_by(|(_, a), (_, b)| a.total_cmp(b))
+            .map(|(option, _)| option)
+            .ok_or_else(|| invalid_spread(format!("no {right} with a known delta")))
+    }
+
+    /// Call and put at the strike nearest `spot` quoted for both rights.
+    fn at_the_money(
+        &self,
+        spot: Decimal,
+    ) -> Result<(&'a ChainOption, &'a ChainOption), OptionPositionError> {
+        self.of_right(OptionRight::Put)
+            .filter_map(|put| {
+                let call = self
+                    .of_right(OptionRight::Call)
+                    .find(|call| call.contract.strike() == put.contract.strike())?;
+                Some((put, call))
+            })
+            .min_by_key(|(put, _)| (put.contract.strike() - spot).abs())
+            .ok_or_else(|| invalid_spread("no strike quoted for both calls and puts"))
+    }
+
+    /// The contract further out of the money than `body` nearest to the
+    /// wing width away.
+    fn wing(
+        &self,
+        body: &ChainOption,
+        right: OptionRight,
+    ) -> Result<&'a ChainOption, OptionPositionError> {
+        let width = self.intent.wing_width.unwrap_or_default();
+        let strike = body.contract.strike();
+        let target = match right {
+            OptionRight::Call => strike + width,
+            OptionRight::Put => strike - width,
+        };
+        self.of_right(right)
+            .filter(|option| match right {
+                OptionRight::Call => option.contract.strike() > strike,
+                OptionRight::Put => option.contract.strike() < strike,
+            })
+            .min_by_key(|option| (option.contract.strike() - target).abs())
+            .ok_or_else(|| invalid_spread(format!("no {right} wing beyond {strike}")))
+    }
+
+    /// Put and call bodies with their wings, in strike order.
+    fn iron(
+        &self,
+        put: &'a ChainOption,
+        call: &'a ChainOption,
+    ) -> Result<Vec<(Leg, &'a ChainOption)>, OptionPositionError> {
+        let put_wing = self.wing(put, OptionRight::Put)?;
+        let call_wing = self.wing(call, OptionRight::Call)?;
+        let body = self.intent.side;
+        let wing = opposite(body);
+        Ok(vec![
+            self.leg(put_wing, wing, LegType::Wing),
+            self.leg(put, body, LegType::Body),
+            self.leg(call, body, LegType::Body),
+            self.leg(call_wing, wing, LegType::Wing),
+        ])
+    }
+
+    fn leg(
+        &self,
+        option: &'a ChainOption,
+        side: PositionSide,
+        kind: LegType,
+    ) -> (Leg, &'a ChainOption) {
+        (
+            Leg::new(option.contract.clone(), side, self.intent.quantity, kind),
+            option,
+        )
+    }
+}
+
+/// Net (mid, natural) price per spread.
+fn price(legs: &[(Leg, &ChainOption)]) -> (Decimal, Decimal) {
+    legs.iter().fold(
+        (Decimal::ZERO, Decimal::ZERO),
+        |(mid, natural), (leg, option)| match leg.side() {
+            PositionSide::Long => (mid + option.mid(), natural + option.ask),
+            PositionSide::Short => (mid - option.mid(), natural - option.bid),
+        },
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::shared::Symbol;
+    use rust_decimal_macros::dec;
+
+    fn as_of() -> NaiveDate {
+        NaiveDate::from_ymd_opt(2025, 1, 2).unwrap()
+    }
+
+    /// SPY at 500 with strikes 470-530 for two expirations. Deltas fall
+    /// 0.05 per 5 points away from the money.
+    fn chain() -> Vec<ChainOption> {
+        let mut chain = Vec::new();
+        for expiration in [
+            NaiveDate::from_ymd_opt(2025, 1, 17).unwrap(),
+            NaiveDate::from_ymd_opt(2025, 2, 7).unwrap(),
+        ] {
+            for step in -6_i32..=6 {
+                let strike = dec!(500) + Decimal::from(step * 5);
+                let distance = f64::from(step) * 0.05;
+                for (right, delta) in [
+                    (OptionRight::Call, 0.5 - distance),
+                    (OptionRight::Put, -0.5 - distance),
+                ] {
+                    let premium = Decimal::try_from(delta.abs() * 10.0).unwrap().round_dp(2);
+                    chain.push(ChainOption {
+                        contract: OptionContract::new(
+                            Symbol::new(format!("SPY{expiration}{right}{strike}")),
+                            "SPY",
+                            strike,
+                            expiration,
+                            right,
+                        ),
+                        bid: premium - dec!(0.05),
+                        ask: premium + dec!(0.05),
+                        delta: Some(delta),
+                    });
+                }
+            }
+        }
+        chain
+    }
+
+    fn intent(strategy: SpreadStrategy) -> SpreadIntent {
+        SpreadIntent {
+            underlying: "SPY".to_string(),
+            strategy,
+            side: PositionSide::Short,
+            target_delta: 0.30,
+            min_dte: 30,
+            max_dte: 45,
+            wing_width: Some(dec!(5)),
+            quantity: dec!(2),
+        }
+    }
+
+    fn strikes(quote: &SpreadQuote) -> Vec<Decimal> {
+        quote
+            .spread
+            .legs()
+            .iter()
+            .map(|leg| leg.contract().strike())
+            .collect()
+    }
+
+    #[test]
+    fn builds_short_iron_condor_for_credit() {
+        let quote = SpreadBuilder::build(
+            &intent(SpreadStrategy::IronCondor),
+            dec!(500),
+            as_of(),
+            &chain(),
+        )
+        .unwrap();
+
+        assert_eq!(quote.spread.spread_type(), SpreadType::IronCondor);
+        assert_eq!(
+            strikes(&quote),
+            vec![dec!(475), dec!(480), dec!(520), dec!(525)]
+        );
+        assert!(quote.spread.legs().iter().all(|leg| {
+            leg.contract().expiration() == NaiveDate::from_ymd_opt(2025, 2, 7).unwrap()
+                && leg.quantity() == dec!(2)
+        }));
+        // Short 3.00 bodies, long 2.50 wings
+        assert_eq!(quote.mid_price, dec!(-1.00));
+        assert_eq!(quote.natural_price, dec!(-0.80));
+    }
+
+    #[test]
+    fn builds_long_vertical_and_straddle() {
+        let mut vertical = intent(SpreadStrategy::Vertical(OptionRight::Call));
+        vertical.side = PositionSide::Long;
+        let quote = SpreadBuilder::build(&vertical, dec!(500), as_of(), &chain()).unwrap();
+        assert_eq!(strikes(&quote), vec![dec!(520), dec!(525)]);
+        assert_eq!(quote.mid_price, dec!(0.50));
+
+        let quote = SpreadBuilder::build(
+            &intent(SpreadStrategy::Straddle),
+            dec!(502),
+            as_of(),
+            &chain(),
+        )
+        .unwrap();
+        assert_eq!(strikes(&quote), vec![dec!(500), dec!(500)]);
+        assert_eq!(quote.mid_price, dec!(-10.00));
+    }
+
+    #[test]
+    fn rejects_unbuildable_intents() {
+        let mut no_wings = intent(SpreadStrategy::IronCondor);
+        no_wings.wing_width = None;
+        assert!(SpreadBuilder::build(&no_wings, dec!(500), as_of(), &chain()).is_err());
+
+        let mut far = intent(SpreadStrategy::Strangle);
+        far.min_dte = 60;
+        far.max_dte = 90;
+        let err = SpreadBuilder::build(&far, dec!(500), as_of(), &chain()).unwrap_err();
+        assert!(err.to_string().contains("no SPY expiration"));
+    }
+}
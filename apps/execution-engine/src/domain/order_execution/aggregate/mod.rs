@@ -5,5 +5,8 @@
 mod order;
 mod order_line;
 
-pub use order::{CreateOrderCommand, Order, ReconstitutedOrderParams};
+pub use order::{
+    CreateOrderCommand, MAX_STRATEGY_TAG_LEN, Order, ReconstitutedOrderParams,
+    is_valid_strategy_tag,
+};
 pub use order_line::OrderLine;
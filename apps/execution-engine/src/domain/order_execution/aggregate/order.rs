@@ -3,19 +3,37 @@
 //! The Order aggregate manages the complete lifecycle of an order,
 //! following FIX protocol semantics for state transitions and partial fills.
 
+use rust_decimal::Decimal;
 use serde::{Deserialize, Serialize};
 
 use super::OrderLine;
 use crate::domain::order_execution::errors::OrderError;
 use crate::domain::order_execution::events::{
     OrderAccepted, OrderCanceled, OrderEvent, OrderFilled, OrderPartiallyFilled, OrderRejected,
-    OrderSubmitted,
+    OrderStateCorrected, OrderSubmitted, StateCorrection,
 };
 use crate::domain::order_execution::value_objects::{
-    CancelReason, FillReport, OrderPurpose, OrderSide, OrderStatus, OrderType, PartialFillState,
-    RejectReason, TimeInForce,
+    CancelReason, FillReport, LegAdjustment, LegImbalance, OrderPurpose, OrderSide, OrderStatus,
+    OrderType, OtoExit, PartialFillState, RejectReason, TimeInForce,
 };
-use crate::domain::shared::{BrokerId, Money, OrderId, Quantity, Symbol, Timestamp};
+use crate::domain::shared::{
+    BrokerId, CycleId, InstrumentId, Money, OrderId, Quantity, Symbol, Timestamp,
+};
+
+/// Longest strategy tag accepted, so a tagged order ID fits the broker's
+/// client order ID.
+pub const MAX_STRATEGY_TAG_LEN: usize = 32;
+
+/// Whether `tag` can tag an order: 1 to [`MAX_STRATEGY_TAG_LEN`] ASCII
+/// letters, digits, `_`, `-` or `.`.
+#[must_use]
+pub fn is_valid_strategy_tag(tag: &str) -> bool {
+    !tag.is_empty()
+        && tag.len() <= MAX_STRATEGY_TAG_LEN
+        && tag
+            .bytes()
+            .all(|b| b.is_ascii_alphanumeric() || matches!(b, b'_' | b'-' | b'.'))
+}
 
 /// Parameters for reconstituting an Order from storage.
 ///
@@ -45,8 +63,18 @@ pub struct ReconstitutedOrderParams {
     pub partial_fill: PartialFillState,
     /// Broker-assigned order ID.
     pub broker_order_id: Option<BrokerId>,
+    /// Decision cycle that produced the order.
+    pub cycle_id: Option<CycleId>,
+    /// Strategy tag of the decision that produced the order.
+    pub strategy_tag: Option<String>,
+    /// Market price when the order was submitted.
+    pub arrival_price: Option<Money>,
     /// Order legs for multi-leg orders.
     pub legs: Vec<OrderLine>,
+    /// Exit armed on fill, for OTO entries.
+    pub oto_exit: Option<OtoExit>,
+    /// Broker IDs of the child orders the broker created.
+    pub child_order_ids: Vec<BrokerId>,
     /// Creation timestamp.
     pub created_at: Timestamp,
     /// Last update timestamp.
@@ -158,7 +186,17 @@ pub struct Order {
     status: OrderStatus,
     partial_fill: PartialFillState,
     broker_order_id: Option<BrokerId>,
+    #[serde(default)]
+    cycle_id: Option<CycleId>,
+    #[serde(default)]
+    strategy_tag: Option<String>,
+    #[serde(default)]
+    arrival_price: Option<Money>,
     legs: Vec<OrderLine>,
+    #[serde(default)]
+    oto_exit: Option<OtoExit>,
+    #[serde(default)]
+    child_order_ids: Vec<BrokerId>,
     #[serde(skip)]
     events: Vec<OrderEvent>,
     created_at: Timestamp,
@@ -174,9 +212,38 @@ impl Order {
     ///
     /// Returns error if command validation fails.
     pub fn new(cmd: CreateOrderCommand) -> Result<Self, OrderError> {
+        Self::create(cmd, OrderId::generate(), None)
+    }
+
+    /// Create a new order for the strategy tagged `strategy_tag`.
+    ///
+    /// The tag prefixes the order ID, which is also the client order ID the
+    /// broker sees, so orders can be attributed in broker reports too.
+    ///
+    /// # Errors
+    ///
+    /// Returns error if the tag or the command is invalid.
+    pub fn new_tagged(cmd: CreateOrderCommand, strategy_tag: &str) -> Result<Self, OrderError> {
+        if !is_valid_strategy_tag(strategy_tag) {
+            return Err(OrderError::InvalidParameters {
+                field: "strategy_tag".to_string(),
+                message: format!(
+                    "Strategy tag must be 1 to {MAX_STRATEGY_TAG_LEN} letters, digits, '_', '-' or '.'"
+                ),
+            });
+        }
+        let id = OrderId::new(format!("{strategy_tag}-{}", OrderId::generate()));
+        Self::create(cmd, id, Some(strategy_tag.to_string()))
+    }
+
+    /// Create an order with `id`, emitting `OrderSubmitted`.
+    fn create(
+        cmd: CreateOrderCommand,
+        id: OrderId,
+        strategy_tag: Option<String>,
+    ) -> Result<Self, OrderError> {
         cmd.validate()?;
 
-        let id = OrderId::generate();
         let now = Timestamp::now();
 
         let mut order = Self {
@@ -191,7 +258,12 @@ impl Order {
             status: OrderStatus::New,
             partial_fill: PartialFillState::new(id.clone(), cmd.quantity, cmd.purpose),
             broker_order_id: None,
+            cycle_id: None,
+            strategy_tag,
+            arrival_price: None,
             legs: cmd.legs,
+            oto_exit: None,
+            child_order_ids: Vec::new(),
             events: Vec::new(),
             created_at: now,
             updated_at: now,
@@ -228,7 +300,12 @@ impl Order {
             status: params.status,
             partial_fill: params.partial_fill,
             broker_order_id: params.broker_order_id,
+            cycle_id: params.cycle_id,
+            strategy_tag: params.strategy_tag,
+            arrival_price: params.arrival_price,
             legs: params.legs,
+            oto_exit: params.oto_exit,
+            child_order_ids: params.child_order_ids,
             events: Vec::new(),
             created_at: params.created_at,
             updated_at: params.updated_at,
@@ -305,12 +382,52 @@ impl Order {
         self.broker_order_id.as_ref()
     }
 
+    /// Get the decision cycle that produced the order.
+    #[must_use]
+    pub const fn cycle_id(&self) -> Option<&CycleId> {
+        self.cycle_id.as_ref()
+    }
+
+    /// Get the strategy tag of the decision that produced the order.
+    #[must_use]
+    pub fn strategy_tag(&self) -> Option<&str> {
+        self.strategy_tag.as_deref()
+    }
+
+    /// Get the market price when the order was submitted, if it was quoted.
+    #[must_use]
+    pub const fn arrival_price(&self) -> Option<Money> {
+        self.arrival_price
+    }
+
+    /// Slippage of `fill` against the arrival price, in basis points.
+    ///
+    /// Returns `None` for orders submitted without a quote.
+    #[must_use]
+    pub fn fill_slippage_bps(&self, fill: &FillReport) -> Option<Decimal> {
+        let arrival = self.arrival_price?;
+        self.side
+            .slippage_bps(arrival.amount(), fill.price.amount())
+    }
+
     /// Get the order legs.
     #[must_use]
     pub fn legs(&self) -> &[OrderLine] {
         &self.legs
     }
 
+    /// Get the exit armed on fill, if this is an OTO entry.
+    #[must_use]
+    pub const fn oto_exit(&self) -> Option<&OtoExit> {
+        self.oto_exit.as_ref()
+    }
+
+    /// Get the broker IDs of the child orders (e.g. an OTO exit).
+    #[must_use]
+    pub fn child_order_ids(&self) -> &[BrokerId] {
+        &self.child_order_ids
+    }
+
     /// Check if this is a multi-leg order.
     #[must_use]
     pub const fn is_multi_leg(&self) -> bool {
@@ -506,6 +623,260 @@ impl Order {
         Ok(())
     }
 
+    /// Merge the broker's view of this order into local state.
+    ///
+    /// Local fill history is preserved. Any quantity the broker reports beyond
+    /// local `CumQty` is applied as a single fill priced so the recomputed
+    /// `AvgPx` matches the broker's average. Terminal broker statuses
+    /// (canceled, expired, rejected) are adopted without discarding fills.
+    ///
+    /// Generates an `OrderStateCorrected` event listing every changed field.
+    ///
+    /// # Errors
+    ///
+    /// Returns error if the missing quantity cannot be applied as a fill.
+    pub fn sync_from_broker(
+        &mut self,
+        broker_status: OrderStatus,
+        broker_cum_qty: Quantity,
+        broker_avg_px: Option<Money>,
+        source: &str,
+    ) -> Result<Vec<StateCorrection>, OrderError> {
+        let prev_status = self.status;
+        let prev_cum_qty = self.partial_fill.cum_qty();
+        let prev_avg_px = self.partial_fill.avg_px();
+        let mut corrections = Vec::new();
+
+        if broker_cum_qty > prev_cum_qty {
+            let missing_qty = broker_cum_qty - prev_cum_qty;
+            let broker_avg = broker_avg_px.unwrap_or(prev_avg_px);
+
+            // Price the missing quantity so that VWAP over all fills equals the
+            // broker's average: (broker_avg * broker_cum - local_avg * local_cum) / missing
+            let missing_notional = Money::new(
+                broker_avg.amount() * broker_cum_qty.amount()
+                    - prev_avg_px.amount() * prev_cum_qty.amount(),
+            );
+            let fill_price = if missing_notional.is_positive() {
+                Money::new(missing_notional.amount() / missing_qty.amount())
+            } else {
+                broker_avg
+            };
+
+            let fill_id = format!(
+                "{}-{}-{}",
+                source.to_lowercase(),
+                self.id,
+                self.partial_fill.fills().len() + 1
+            );
+            self.apply_fill(FillReport::new(
+                fill_id,
+                missing_qty,
+                fill_price,
+                Timestamp::now(),
+                source,
+            ))?;
+
+            corrections.push(StateCorrection::new(
+                "cum_qty",
+                &prev_cum_qty,
+                &self.partial_fill.cum_qty(),
+            ));
+            if self.partial_fill.avg_px() != prev_avg_px {
+                corrections.push(StateCorrection::new(
+                    "avg_px",
+                    &prev_avg_px,
+                    &self.partial_fill.avg_px(),
+                ));
+            }
+        }
+
+        let adopt_terminal = matches!(
+            broker_status,
+            OrderStatus::Canceled | OrderStatus::Expired | OrderStatus::Rejected
+        );
+        if adopt_terminal && !self.status.is_terminal() && self.status != broker_status {
+            self.status = broker_status;
+            self.updated_at = Timestamp::now();
+
+            for leg in &mut self.legs {
+                if broker_status == OrderStatus::Rejected {
+                    leg.reject();
+                } else {
+                    leg.cancel();
+                }
+            }
+        }
+
+        if self.status != prev_status {
+            corrections.push(StateCorrection::new("status", &prev_status, &self.status));
+        }
+
+        if !corrections.is_empty() {
+            self.events
+                .push(OrderEvent::StateCorrected(OrderStateCorrected {
+                    order_id: self.id.clone(),
+                    source: source.to_string(),
+                    corrections: corrections.clone(),
+                    occurred_at: self.updated_at,
+                }));
+        }
+
+        Ok(corrections)
+    }
+
+    /// Merge the broker's per-leg cumulative fills into the legs.
+    ///
+    /// Each entry is an instrument, its cumulative filled quantity and its
+    /// average fill price. Legs only move forward; instruments that match no
+    /// leg are ignored. Generates an `OrderStateCorrected` event listing every
+    /// changed leg.
+    pub fn sync_leg_fills(
+        &mut self,
+        leg_fills: &[(InstrumentId, Quantity, Option<Money>)],
+        source: &str,
+    ) -> Vec<StateCorrection> {
+        let mut corrections = Vec::new();
+        for (instrument_id, filled_qty, avg_fill_price) in leg_fills {
+            let Some(leg) = self
+                .legs
+                .iter_mut()
+                .find(|leg| leg.instrument_id() == instrument_id)
+            else {
+                continue;
+            };
+            let prev_filled = leg.filled_quantity();
+            if leg.sync_fill(*filled_qty, *avg_fill_price) {
+                corrections.push(StateCorrection::new(
+                    format!("legs[{}].filled_qty", leg.leg_index()),
+                    &prev_filled,
+                    &leg.filled_quantity(),
+                ));
+            }
+        }
+
+        if !corrections.is_empty() {
+            self.updated_at = Timestamp::now();
+            self.events
+                .push(OrderEvent::StateCorrected(OrderStateCorrected {
+                    order_id: self.id.clone(),
+                    source: source.to_string(),
+                    corrections: corrections.clone(),
+                    occurred_at: self.updated_at,
+                }));
+        }
+        corrections
+    }
+
+    /// Imbalance between the legs' fills, or `None` while every leg has
+    /// filled the same share of its quantity.
+    ///
+    /// Adjustments are in whole units; a leg that is off by less than one
+    /// unit gets none.
+    #[must_use]
+    pub fn leg_imbalance(&self) -> Option<LegImbalance> {
+        let max_fill_ratio = self.legs.iter().map(OrderLine::fill_ratio).max()?;
+        let min_fill_ratio = self.legs.iter().map(OrderLine::fill_ratio).min()?;
+        if max_fill_ratio == min_fill_ratio {
+            return None;
+        }
+
+        let adjustment = |leg: &OrderLine, side: OrderSide, quantity: Decimal| {
+            let quantity = Quantity::new(quantity).round_down();
+            quantity.is_positive().then(|| LegAdjustment {
+                leg_index: leg.leg_index(),
+                instrument_id: leg.instrument_id().clone(),
+                side,
+                quantity,
+            })
+        };
+        let catch_up = self
+            .legs
+            .iter()
+            .filter_map(|leg| {
+                let target = leg.quantity().amount() * max_fill_ratio;
+                adjustment(leg, leg.side(), target - leg.filled_quantity().amount())
+            })
+            .collect();
+        let unwind = self
+            .legs
+            .iter()
+            .filter_map(|leg| {
+                let target = leg.quantity().amount() * min_fill_ratio;
+                adjustment(
+                    leg,
+                    leg.side().opposite(),
+                    leg.filled_quantity().amount() - target,
+                )
+            })
+            .collect();
+
+        Some(LegImbalance {
+            max_fill_ratio,
+            min_fill_ratio,
+            catch_up,
+            unwind,
+        })
+    }
+
+    /// Tag the order with the decision cycle it belongs to.
+    ///
+    /// Set on submission, and again when a later cycle re-confirms a working order.
+    pub fn tag_cycle(&mut self, cycle_id: CycleId) {
+        self.cycle_id = Some(cycle_id);
+        self.updated_at = Timestamp::now();
+    }
+
+    /// Record the market price at submission, which fills are measured
+    /// against for slippage.
+    pub fn record_arrival_price(&mut self, price: Money) {
+        self.arrival_price = Some(price);
+        self.updated_at = Timestamp::now();
+    }
+
+    /// Arm an exit the broker submits once this order fills (OTO).
+    ///
+    /// # Errors
+    ///
+    /// Returns error if the order was already sent to the broker, or the exit
+    /// is on the wrong side of the entry's limit or stop price.
+    pub fn arm_oto_exit(&mut self, exit: OtoExit) -> Result<(), OrderError> {
+        if self.status != OrderStatus::New {
+            return Err(OrderError::InvalidParameters {
+                field: "oto_exit".to_string(),
+                message: format!("cannot arm an exit on a {} order", self.status),
+            });
+        }
+
+        let entry_price = self.limit_price.or(self.stop_price).map(|p| p.amount());
+        let valid = entry_price.map_or_else(
+            || exit.trigger_price() > Decimal::ZERO,
+            |price| exit.is_valid_for(self.side, price),
+        );
+        if !valid {
+            return Err(OrderError::InvalidParameters {
+                field: "oto_exit".to_string(),
+                message: format!(
+                    "exit at {} is on the wrong side of the {} entry",
+                    exit.trigger_price(),
+                    self.side
+                ),
+            });
+        }
+
+        self.oto_exit = Some(exit);
+        self.updated_at = Timestamp::now();
+        Ok(())
+    }
+
+    /// Record the child orders the broker created for this order.
+    pub fn record_child_orders(&mut self, child_order_ids: Vec<BrokerId>) {
+        if !child_order_ids.is_empty() {
+            self.child_order_ids = child_order_ids;
+            self.updated_at = Timestamp::now();
+        }
+    }
+
     // ========================================================================
     // Events
     // ========================================================================
@@ -919,7 +1290,12 @@ mod tests {
             status: OrderStatus::Accepted,
             partial_fill,
             broker_order_id: Some(BrokerId::new("broker-recon")),
+            cycle_id: Some(CycleId::new("cycle-recon")),
+            strategy_tag: Some("momentum".to_string()),
+            arrival_price: None,
             legs: vec![],
+            oto_exit: None,
+            child_order_ids: vec![],
             created_at,
             updated_at,
         });
@@ -928,9 +1304,60 @@ mod tests {
         assert_eq!(order.symbol().as_str(), "AAPL");
         assert_eq!(order.status(), OrderStatus::Accepted);
         assert_eq!(order.broker_order_id().unwrap().as_str(), "broker-recon");
+        assert_eq!(order.cycle_id().unwrap().as_str(), "cycle-recon");
+        assert_eq!(order.strategy_tag(), Some("momentum"));
         assert!(order.pending_events().is_empty()); // Reconstituted orders have no events
     }
 
+    #[test]
+    fn order_arm_oto_exit() {
+        let mut order = Order::new(make_create_command()).unwrap();
+
+        let wrong_side = order.arm_oto_exit(OtoExit::stop_loss(Decimal::new(160, 0)));
+        assert!(matches!(
+            wrong_side,
+            Err(OrderError::InvalidParameters { .. })
+        ));
+
+        let exit = OtoExit::stop_loss(Decimal::new(140, 0));
+        order.arm_oto_exit(exit).unwrap();
+        assert_eq!(order.oto_exit(), Some(&exit));
+
+        order.accept(BrokerId::new("broker-1")).unwrap();
+        order.record_child_orders(vec![BrokerId::new("broker-1-stop")]);
+        assert_eq!(order.child_order_ids()[0].as_str(), "broker-1-stop");
+
+        // Too late once the broker has the order
+        assert!(order.arm_oto_exit(exit).is_err());
+    }
+
+    #[test]
+    fn order_new_tagged() {
+        let order = Order::new_tagged(make_create_command(), "momentum.v2").unwrap();
+        assert_eq!(order.strategy_tag(), Some("momentum.v2"));
+        assert!(order.id().as_str().starts_with("momentum.v2-"));
+
+        for tag in ["", "has space", &"x".repeat(MAX_STRATEGY_TAG_LEN + 1)] {
+            assert!(matches!(
+                Order::new_tagged(make_create_command(), tag),
+                Err(OrderError::InvalidParameters { .. })
+            ));
+        }
+    }
+
+    #[test]
+    fn order_tag_cycle() {
+        let mut order = Order::new(make_create_command()).unwrap();
+        assert!(order.cycle_id().is_none());
+
+        order.tag_cycle(CycleId::new("cycle-1"));
+        assert_eq!(order.cycle_id().unwrap().as_str(), "cycle-1");
+
+        // Re-confirmation by a later cycle moves the tag forward
+        order.tag_cycle(CycleId::new("cycle-2"));
+        assert_eq!(order.cycle_id().unwrap().as_str(), "cycle-2");
+    }
+
     #[test]
     fn order_multi_leg_accept_updates_legs() {
         let mut cmd = make_create_command();
@@ -960,6 +1387,62 @@ mod tests {
         }
     }
 
+    #[test]
+    fn order_leg_fills_sync_and_expose_imbalance() {
+        let mut cmd = make_create_command();
+        cmd.legs = vec![
+            OrderLine::new(
+                0,
+                "AAPL250117P00190000".into(),
+                OrderSide::Buy,
+                Quantity::from_i64(10),
+            ),
+            OrderLine::new(
+                1,
+                "AAPL250117P00185000".into(),
+                OrderSide::Sell,
+                Quantity::from_i64(10),
+            ),
+        ];
+        let mut order = Order::new(cmd).unwrap();
+        order.accept(BrokerId::new("broker-123")).unwrap();
+        order.drain_events();
+        assert!(order.leg_imbalance().is_none());
+
+        let corrections = order.sync_leg_fills(
+            &[
+                (
+                    "AAPL250117P00190000".into(),
+                    Quantity::from_i64(10),
+                    Some(Money::usd(3.10)),
+                ),
+                ("MSFT".into(), Quantity::from_i64(5), None),
+            ],
+            "RECONCILE",
+        );
+        assert_eq!(corrections.len(), 1);
+        assert_eq!(corrections[0].field, "legs[0].filled_qty");
+        assert_eq!(order.drain_events().len(), 1);
+
+        let imbalance = order.leg_imbalance().unwrap();
+        assert!(imbalance.is_legged_out());
+        assert_eq!(imbalance.catch_up.len(), 1);
+        assert_eq!(imbalance.catch_up[0].leg_index, 1);
+        assert_eq!(imbalance.catch_up[0].side, OrderSide::Sell);
+        assert_eq!(imbalance.catch_up[0].quantity, Quantity::from_i64(10));
+        assert_eq!(imbalance.unwind[0].leg_index, 0);
+        assert_eq!(imbalance.unwind[0].side, OrderSide::Sell);
+
+        // Replaying the same report changes nothing
+        let replay = [(
+            "AAPL250117P00190000".into(),
+            Quantity::from_i64(10),
+            Some(Money::usd(3.10)),
+        )];
+        assert!(order.sync_leg_fills(&replay, "RECONCILE").is_empty());
+        assert!(order.drain_events().is_empty());
+    }
+
     #[test]
     fn order_cancel_from_pending_cancel() {
         let mut order = Order::new(make_create_command()).unwrap();
@@ -1136,6 +1619,90 @@ mod tests {
         assert_eq!(order.status(), OrderStatus::Rejected);
     }
 
+    #[test]
+    fn order_sync_from_broker_preserves_local_fills() {
+        let mut order = Order::new(make_create_command()).unwrap();
+        order.accept(BrokerId::new("broker-123")).unwrap();
+        order.apply_fill(make_fill(40, 150.00)).unwrap();
+        order.drain_events();
+
+        // Broker reports 60 filled at an average of 151.00
+        let corrections = order
+            .sync_from_broker(
+                OrderStatus::PartiallyFilled,
+                Quantity::from_i64(60),
+                Some(Money::usd(151.00)),
+                "RECONCILE",
+            )
+            .unwrap();
+
+        let fills = order.partial_fill().fills();
+        assert_eq!(fills.len(), 2);
+        assert_eq!(fills[0].fill_id, "fill-40");
+        assert_eq!(fills[1].quantity, Quantity::from_i64(20));
+        // 60 * 151 - 40 * 150 = 3060 over 20 shares
+        assert_eq!(fills[1].price, Money::usd(153.00));
+        assert_eq!(order.partial_fill().cum_qty(), Quantity::from_i64(60));
+        assert_eq!(order.partial_fill().avg_px(), Money::usd(151.00));
+        assert!(order.partial_fill().verify_fix_invariant());
+
+        let fields: Vec<&str> = corrections.iter().map(|c| c.field.as_str()).collect();
+        assert_eq!(fields, vec!["cum_qty", "avg_px"]);
+
+        let event = order.pending_events().last().unwrap();
+        match event {
+            OrderEvent::StateCorrected(e) => {
+                assert_eq!(e.source, "RECONCILE");
+                assert_eq!(e.corrections, corrections);
+            }
+            other => panic!("expected StateCorrected, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn order_sync_from_broker_adopts_terminal_status_keeping_fills() {
+        let mut order = Order::new(make_create_command()).unwrap();
+        order.accept(BrokerId::new("broker-123")).unwrap();
+        order.apply_fill(make_fill(30, 150.00)).unwrap();
+        order.drain_events();
+
+        let corrections = order
+            .sync_from_broker(
+                OrderStatus::Canceled,
+                Quantity::from_i64(30),
+                Some(Money::usd(150.00)),
+                "RECONCILE",
+            )
+            .unwrap();
+
+        assert_eq!(order.status(), OrderStatus::Canceled);
+        assert_eq!(order.partial_fill().cum_qty(), Quantity::from_i64(30));
+        assert_eq!(order.partial_fill().fills().len(), 1);
+        assert_eq!(corrections.len(), 1);
+        assert_eq!(corrections[0].field, "status");
+        assert_eq!(corrections[0].previous, "PARTIALLY_FILLED");
+        assert_eq!(corrections[0].corrected, "CANCELED");
+    }
+
+    #[test]
+    fn order_sync_from_broker_no_changes_emits_nothing() {
+        let mut order = Order::new(make_create_command()).unwrap();
+        order.accept(BrokerId::new("broker-123")).unwrap();
+        order.drain_events();
+
+        let corrections = order
+            .sync_from_broker(
+                OrderStatus::Accepted,
+                Quantity::from_i64(0),
+                None,
+                "RECONCILE",
+            )
+            .unwrap();
+
+        assert!(corrections.is_empty());
+        assert!(order.pending_events().is_empty());
+    }
+
     #[test]
     fn order_state_transition_coverage() {
         let cmd = make_create_command();
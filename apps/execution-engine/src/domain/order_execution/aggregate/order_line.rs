@@ -104,6 +104,37 @@ impl OrderLine {
         }
     }
 
+    /// Bring the leg up to a cumulative fill reported by the broker.
+    ///
+    /// Quantity beyond the local fill is applied as one fill priced so the
+    /// average matches `avg_fill_price` (or the current average when unset).
+    /// Returns whether the leg changed.
+    pub fn sync_fill(&mut self, filled_qty: Quantity, avg_fill_price: Option<Money>) -> bool {
+        if filled_qty <= self.filled_quantity {
+            return false;
+        }
+        let missing_qty = filled_qty - self.filled_quantity;
+        let broker_avg = avg_fill_price.unwrap_or(self.avg_fill_price);
+        let missing_notional = broker_avg.amount() * filled_qty.amount()
+            - self.avg_fill_price.amount() * self.filled_quantity.amount();
+        let fill_price = if missing_notional > rust_decimal::Decimal::ZERO {
+            Money::new(missing_notional / missing_qty.amount())
+        } else {
+            broker_avg
+        };
+        self.apply_fill(missing_qty, fill_price);
+        true
+    }
+
+    /// Share of the leg's quantity that has filled.
+    #[must_use]
+    pub fn fill_ratio(&self) -> rust_decimal::Decimal {
+        if self.quantity.is_zero() {
+            return rust_decimal::Decimal::ONE;
+        }
+        self.filled_quantity.amount() / self.quantity.amount()
+    }
+
     /// Mark the leg as accepted.
     pub const fn accept(&mut self) {
         self.status = OrderStatus::Accepted;
@@ -160,6 +191,25 @@ mod tests {
         assert_eq!(line.status(), OrderStatus::Filled);
     }
 
+    #[test]
+    fn order_line_sync_fill_matches_broker_average() {
+        let mut line = OrderLine::new(
+            0,
+            InstrumentId::new("AAPL"),
+            OrderSide::Buy,
+            Quantity::from_i64(10),
+        );
+        line.apply_fill(Quantity::from_i64(4), Money::usd(2.00));
+
+        assert!(line.sync_fill(Quantity::from_i64(10), Some(Money::usd(2.60))));
+        assert_eq!(line.filled_quantity(), Quantity::from_i64(10));
+        assert_eq!(line.avg_fill_price(), Money::usd(2.60));
+        assert_eq!(line.fill_ratio(), rust_decimal::Decimal::ONE);
+
+        // Stale reports never roll a leg back
+        assert!(!line.sync_fill(Quantity::from_i64(4), None));
+    }
+
     #[test]
     fn order_line_accept() {
         let mut line = OrderLine::new(
@@ -3,6 +3,7 @@
 use std::fmt;
 
 use super::value_objects::OrderStatus;
+use crate::domain::shared::{ClassifiedError, ErrorKind};
 
 /// Errors that can occur in order execution.
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -64,6 +65,12 @@ pub enum OrderError {
         /// Order ID.
         order_id: String,
     },
+
+    /// Order storage failed.
+    StorageError {
+        /// Error message.
+        message: String,
+    },
 }
 
 impl fmt::Display for OrderError {
@@ -102,12 +109,44 @@ impl fmt::Display for OrderError {
             Self::DuplicateOrderId { order_id } => {
                 write!(f, "Duplicate order ID: {order_id}")
             }
+            Self::StorageError { message } => {
+                write!(f, "Order storage error: {message}")
+            }
         }
     }
 }
 
 impl std::error::Error for OrderError {}
 
+impl ClassifiedError for OrderError {
+    fn code(&self) -> &str {
+        match self {
+            Self::InvalidStateTransition { .. } => "INVALID_STATE_TRANSITION",
+            Self::CannotFill { .. } => "ORDER_NOT_FILLABLE",
+            Self::CannotCancel { .. } => "ORDER_NOT_CANCELABLE",
+            Self::FillExceedsRemaining { .. } => "FILL_EXCEEDS_REMAINING",
+            Self::FixInvariantViolation { .. } => "FIX_INVARIANT_VIOLATION",
+            Self::InvalidParameters { .. } => "INVALID_ORDER_PARAMETERS",
+            Self::NotFound { .. } => "ORDER_NOT_FOUND",
+            Self::DuplicateOrderId { .. } => "DUPLICATE_ORDER_ID",
+            Self::StorageError { .. } => "ORDER_STORAGE_ERROR",
+        }
+    }
+
+    fn kind(&self) -> ErrorKind {
+        match self {
+            Self::InvalidStateTransition { .. }
+            | Self::CannotFill { .. }
+            | Self::CannotCancel { .. }
+            | Self::FillExceedsRemaining { .. } => ErrorKind::FailedPrecondition,
+            Self::FixInvariantViolation { .. } | Self::StorageError { .. } => ErrorKind::Internal,
+            Self::InvalidParameters { .. } => ErrorKind::InvalidArgument,
+            Self::NotFound { .. } => ErrorKind::NotFound,
+            Self::DuplicateOrderId { .. } => ErrorKind::AlreadyExists,
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -200,4 +239,18 @@ mod tests {
         assert!(msg.contains("ord-dup"));
         assert!(msg.contains("Duplicate"));
     }
+
+    #[test]
+    fn order_error_codes() {
+        let err = OrderError::DuplicateOrderId {
+            order_id: "ord-dup".to_string(),
+        };
+        assert_eq!(err.code(), "DUPLICATE_ORDER_ID");
+        assert_eq!(err.kind(), ErrorKind::AlreadyExists);
+        let err = OrderError::CannotCancel {
+            status: OrderStatus::Filled,
+        };
+        assert_eq!(err.code(), "ORDER_NOT_CANCELABLE");
+        assert_eq!(err.kind(), ErrorKind::FailedPrecondition);
+    }
 }
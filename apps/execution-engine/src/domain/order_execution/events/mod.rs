@@ -23,6 +23,8 @@ pub enum OrderEvent {
     Canceled(OrderCanceled),
     /// Order rejected by broker.
     Rejected(OrderRejected),
+    /// Order state corrected from broker during reconciliation.
+    StateCorrected(OrderStateCorrected),
 }
 
 impl OrderEvent {
@@ -36,6 +38,7 @@ impl OrderEvent {
             Self::Filled(e) => &e.order_id,
             Self::Canceled(e) => &e.order_id,
             Self::Rejected(e) => &e.order_id,
+            Self::StateCorrected(e) => &e.order_id,
         }
     }
 
@@ -49,6 +52,7 @@ impl OrderEvent {
             Self::Filled(e) => e.occurred_at,
             Self::Canceled(e) => e.occurred_at,
             Self::Rejected(e) => e.occurred_at,
+            Self::StateCorrected(e) => e.occurred_at,
         }
     }
 
@@ -62,6 +66,7 @@ impl OrderEvent {
             Self::Filled(_) => "ORDER_FILLED",
             Self::Canceled(_) => "ORDER_CANCELED",
             Self::Rejected(_) => "ORDER_REJECTED",
+            Self::StateCorrected(_) => "ORDER_STATE_CORRECTED",
         }
     }
 }
@@ -150,6 +155,48 @@ pub struct OrderRejected {
     pub occurred_at: Timestamp,
 }
 
+/// A single field correction applied to local order state.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct StateCorrection {
+    /// Corrected field (e.g., `status`, `cum_qty`, `avg_px`).
+    pub field: String,
+    /// Local value before the correction.
+    pub previous: String,
+    /// Value after the correction.
+    pub corrected: String,
+}
+
+impl StateCorrection {
+    /// Create a new field correction.
+    #[must_use]
+    pub fn new(
+        field: impl Into<String>,
+        previous: &impl ToString,
+        corrected: &impl ToString,
+    ) -> Self {
+        Self {
+            field: field.into(),
+            previous: previous.to_string(),
+            corrected: corrected.to_string(),
+        }
+    }
+}
+
+/// Event: Local order state corrected to match the broker.
+///
+/// Audit record describing exactly which fields reconciliation changed.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct OrderStateCorrected {
+    /// Order ID.
+    pub order_id: OrderId,
+    /// Source of the correction (e.g., "RECONCILE").
+    pub source: String,
+    /// Field-level corrections that were applied.
+    pub corrections: Vec<StateCorrection>,
+    /// When the event occurred.
+    pub occurred_at: Timestamp,
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -409,5 +456,14 @@ mod tests {
             occurred_at: ts,
         });
         assert_eq!(rejected.event_type(), "ORDER_REJECTED");
+
+        let corrected = OrderEvent::StateCorrected(OrderStateCorrected {
+            order_id: OrderId::new("ord-1"),
+            source: "RECONCILE".to_string(),
+            corrections: vec![StateCorrection::new("status", &"ACCEPTED", &"CANCELED")],
+            occurred_at: ts,
+        });
+        assert_eq!(corrected.event_type(), "ORDER_STATE_CORRECTED");
+        assert_eq!(corrected.order_id().as_str(), "ord-1");
     }
 }
@@ -20,10 +20,12 @@ pub use aggregate::{Order, OrderLine};
 pub use errors::OrderError;
 pub use events::{
     OrderAccepted, OrderCanceled, OrderEvent, OrderFilled, OrderPartiallyFilled, OrderRejected,
-    OrderSubmitted,
+    OrderStateCorrected, OrderSubmitted, StateCorrection,
+};
+pub use repository::{OrderPage, OrderQuery, OrderRepository};
+pub use services::{
+    FeeAssetClass, FeeSchedule, FeeScheduleError, OrderStateMachine, RegulatoryFeeCalculator,
 };
-pub use repository::OrderRepository;
-pub use services::OrderStateMachine;
 pub use value_objects::{
     CancelReason, ExecutionAck, FillReport, OrderPurpose, OrderSide, OrderStatus, OrderType,
     PartialFillState, PartialFillTimeoutAction, PartialFillTimeoutConfig, RejectReason,
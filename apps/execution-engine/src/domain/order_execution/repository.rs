@@ -8,7 +8,100 @@ use async_trait::async_trait;
 use super::aggregate::Order;
 use super::errors::OrderError;
 use super::value_objects::OrderStatus;
-use crate::domain::shared::{BrokerId, OrderId};
+use crate::domain::shared::{BrokerId, CycleId, OrderId, Symbol, Timestamp};
+
+/// Orders returned by a query when no limit is given.
+pub const DEFAULT_ORDER_QUERY_LIMIT: usize = 100;
+
+/// Most orders a single query may return.
+pub const MAX_ORDER_QUERY_LIMIT: usize = 1000;
+
+/// Filters and page of an order history query.
+///
+/// Unset filters match every order. Matches are ordered newest first.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct OrderQuery {
+    /// Created at or after.
+    pub from: Option<Timestamp>,
+    /// Created before.
+    pub to: Option<Timestamp>,
+    /// Instrument symbol.
+    pub symbol: Option<Symbol>,
+    /// Any of these statuses (empty = all).
+    pub statuses: Vec<OrderStatus>,
+    /// Decision cycle that produced the order.
+    pub cycle_id: Option<CycleId>,
+    /// Matches to skip.
+    pub offset: usize,
+    /// Matches to return.
+    pub limit: usize,
+}
+
+impl Default for OrderQuery {
+    fn default() -> Self {
+        Self {
+            from: None,
+            to: None,
+            symbol: None,
+            statuses: Vec::new(),
+            cycle_id: None,
+            offset: 0,
+            limit: DEFAULT_ORDER_QUERY_LIMIT,
+        }
+    }
+}
+
+impl OrderQuery {
+    /// Whether `order` passes every filter.
+    #[must_use]
+    pub fn matches(&self, order: &Order) -> bool {
+        let created_at = order.created_at();
+        self.from.is_none_or(|from| created_at >= from)
+            && self.to.is_none_or(|to| created_at < to)
+            && self.symbol.as_ref().is_none_or(|s| order.symbol() == s)
+            && (self.statuses.is_empty() || self.statuses.contains(&order.status()))
+            && self
+                .cycle_id
+                .as_ref()
+                .is_none_or(|c| order.cycle_id() == Some(c))
+    }
+
+    /// Filter, sort and page `orders`.
+    #[must_use]
+    pub fn page(&self, orders: impl IntoIterator<Item = Order>) -> OrderPage {
+        let mut matched: Vec<Order> = orders.into_iter().filter(|o| self.matches(o)).collect();
+        matched.sort_by(|a, b| {
+            b.created_at()
+                .cmp(&a.created_at())
+                .then_with(|| a.id().as_str().cmp(b.id().as_str()))
+        });
+        let total = matched.len();
+        let orders = matched
+            .into_iter()
+            .skip(self.offset)
+            .take(self.limit)
+            .collect();
+        OrderPage { orders, total }
+    }
+}
+
+/// One page of an order history query.
+#[derive(Debug, Clone)]
+pub struct OrderPage {
+    /// Orders on this page, newest first.
+    pub orders: Vec<Order>,
+    /// Orders matching the filters across all pages.
+    pub total: usize,
+}
+
+impl OrderPage {
+    /// Offset of the next page, if there is one.
+    #[must_use]
+    pub fn next_offset(&self, query: &OrderQuery) -> Option<usize> {
+        let next = query.offset + self.orders.len();
+        (next < self.total && !self.orders.is_empty()).then_some(next)
+    }
+}
 
 /// Repository trait for Order persistence.
 ///
@@ -65,6 +158,22 @@ pub trait OrderRepository: Send + Sync {
     ///
     /// Returns error if query fails.
     async fn exists(&self, id: &OrderId) -> Result<bool, OrderError>;
+
+    /// Find orders matching `query`, newest first.
+    ///
+    /// The default implementation loads every order by status; stores with
+    /// an index should override it.
+    ///
+    /// # Errors
+    ///
+    /// Returns error if query fails.
+    async fn query(&self, query: &OrderQuery) -> Result<OrderPage, OrderError> {
+        let mut orders = Vec::new();
+        for status in OrderStatus::ALL {
+            orders.extend(self.find_by_status(status).await?);
+        }
+        Ok(query.page(orders))
+    }
 }
 
 #[cfg(test)]
@@ -261,6 +370,41 @@ mod tests {
         assert!(!repo.exists(&id).await.unwrap());
     }
 
+    #[tokio::test]
+    async fn repository_query_filters_and_pages() {
+        let repo = InMemoryOrderRepository::new();
+        for cycle in ["cycle-1", "cycle-1", "cycle-2"] {
+            let mut order = make_order();
+            order.tag_cycle(CycleId::new(cycle));
+            repo.save(&order).await.unwrap();
+        }
+        let mut other = make_order();
+        other.accept(BrokerId::new("broker-1")).unwrap();
+        repo.save(&other).await.unwrap();
+
+        let query = OrderQuery {
+            cycle_id: Some(CycleId::new("cycle-1")),
+            limit: 1,
+            ..OrderQuery::default()
+        };
+        let page = repo.query(&query).await.unwrap();
+        assert_eq!((page.orders.len(), page.total), (1, 2));
+        assert_eq!(page.next_offset(&query), Some(1));
+
+        let query = OrderQuery { offset: 1, ..query };
+        let page = repo.query(&query).await.unwrap();
+        assert_eq!(page.next_offset(&query), None);
+
+        let accepted = OrderQuery {
+            statuses: vec![OrderStatus::Accepted],
+            symbol: Some(Symbol::new("aapl")),
+            ..OrderQuery::default()
+        };
+        let page = repo.query(&accepted).await.unwrap();
+        assert_eq!(page.orders[0].id(), other.id());
+        assert_eq!(page.total, 1);
+    }
+
     #[tokio::test]
     async fn repository_delete_not_found() {
         let repo = InMemoryOrderRepository::new();
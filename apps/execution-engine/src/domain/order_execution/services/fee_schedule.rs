@@ -0,0 +1,337 @@
+//! Fee Schedule
+//!
+//! Configurable table of per-unit fees that vary by venue, asset class and
+//! date, such as option exchange fees and OCC clearing fees. Complements
+//! [`RegulatoryFeeCalculator`](super::RegulatoryFeeCalculator), which covers
+//! the statutory SEC and FINRA fees. Schedules are loaded from YAML:
+//!
+//! ```yaml
+//! rules:
+//!   - name: occ_clearing
+//!     asset_class: OPTION
+//!     effective_from: 2024-01-01
+//!     tiers:
+//!       - { min_qty: "0", per_unit: "0.055" }
+//!       - { min_qty: "500", per_unit: "0.045" }
+//!     max_per_execution: "55.00"
+//!   - name: exchange
+//!     asset_class: OPTION
+//!     effective_from: 2024-01-01
+//!     tiers: [{ min_qty: "0", per_unit: "0.50" }]
+//!   - name: exchange
+//!     asset_class: OPTION
+//!     venue: CBOE
+//!     effective_from: 2024-07-01
+//!     tiers: [{ min_qty: "0", per_unit: "0.44" }]
+//! ```
+//!
+//! Rules sharing a `name` are alternatives: for each name, a rule for the
+//! execution's venue overrides one without a venue, and among those the
+//! latest `effective_from` on or before the trade date wins. The tier with
+//! the highest `min_qty` not above the execution quantity sets the rate for
+//! every unit.
+
+use chrono::NaiveDate;
+use rust_decimal::{Decimal, RoundingStrategy};
+use serde::{Deserialize, Serialize};
+
+use crate::domain::order_execution::value_objects::OrderSide;
+
+/// Asset class a fee rule applies to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum FeeAssetClass {
+    /// Stocks and ETFs, charged per share.
+    Equity,
+    /// Listed options, charged per contract.
+    Option,
+}
+
+/// Per-unit rate applying from a minimum execution quantity.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct FeeTier {
+    /// Smallest quantity (shares or contracts) this tier applies to.
+    pub min_qty: Decimal,
+    /// Fee per share or contract.
+    pub per_unit: Decimal,
+}
+
+/// A single row of the fee schedule.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct FeeRule {
+    /// Fee name; rules with the same name override each other.
+    pub name: String,
+    /// Asset class charged.
+    pub asset_class: FeeAssetClass,
+    /// Venue the rule is specific to (`None` = any venue).
+    #[serde(default)]
+    pub venue: Option<String>,
+    /// Sides charged (empty = both).
+    #[serde(default)]
+    pub sides: Vec<OrderSide>,
+    /// First trade date the rule applies to.
+    pub effective_from: NaiveDate,
+    /// Quantity tiers, in ascending `min_qty` order.
+    pub tiers: Vec<FeeTier>,
+    /// Minimum fee per execution.
+    #[serde(default)]
+    pub min_per_execution: Option<Decimal>,
+    /// Maximum fee per execution.
+    #[serde(default)]
+    pub max_per_execution: Option<Decimal>,
+}
+
+impl FeeRule {
+    fn applies_to(&self, asset_class: FeeAssetClass, venue: Option<&str>, side: OrderSide) -> bool {
+        self.asset_class == asset_class
+            && (self.sides.is_empty() || self.sides.contains(&side))
+            && self
+                .venue
+                .as_deref()
+                .is_none_or(|v| venue.is_some_and(|venue| v.eq_ignore_ascii_case(venue)))
+    }
+
+    fn fee(&self, qty: Decimal) -> Decimal {
+        let qty = qty.abs();
+        let rate = self
+            .tiers
+            .iter()
+            .rev()
+            .find(|tier| tier.min_qty <= qty)
+            .map_or(Decimal::ZERO, |tier| tier.per_unit);
+
+        let mut fee = qty * rate;
+        if let Some(min) = self.min_per_execution {
+            fee = fee.max(min);
+        }
+        if let Some(max) = self.max_per_execution {
+            fee = fee.min(max);
+        }
+        fee.round_dp_with_strategy(2, RoundingStrategy::AwayFromZero)
+    }
+}
+
+/// Fee schedule configuration errors.
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+pub enum FeeScheduleError {
+    /// The schedule is not valid YAML or does not match the format.
+    #[error("Invalid fee schedule: {message}")]
+    Parse {
+        /// Error details.
+        message: String,
+    },
+
+    /// A rule is internally inconsistent.
+    #[error("Invalid fee rule '{name}': {message}")]
+    InvalidRule {
+        /// Rule name.
+        name: String,
+        /// Error details.
+        message: String,
+    },
+}
+
+/// Table of venue- and date-dependent execution fees.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct FeeSchedule {
+    rules: Vec<FeeRule>,
+}
+
+impl FeeSchedule {
+    /// Create a schedule from rules.
+    ///
+    /// # Errors
+    ///
+    /// Returns error if a rule has no tiers, unsorted tiers, negative
+    /// rates, or a minimum above its maximum.
+    pub fn new(rules: Vec<FeeRule>) -> Result<Self, FeeScheduleError> {
+        for rule in &rules {
+            let invalid = |message: &str| FeeScheduleError::InvalidRule {
+                name: rule.name.clone(),
+                message: message.to_string(),
+            };
+            if rule.tiers.is_empty() {
+                return Err(invalid("at least one tier is required"));
+            }
+            if rule.tiers.windows(2).any(|w| w[0].min_qty >= w[1].min_qty) {
+                return Err(invalid("tiers must be in ascending min_qty order"));
+            }
+            if rule
+                .tiers
+                .iter()
+                .any(|t| t.per_unit.is_sign_negative() || t.min_qty.is_sign_negative())
+            {
+                return Err(invalid("tier values must not be negative"));
+            }
+            if let (Some(min), Some(max)) = (rule.min_per_execution, rule.max_per_execution)
+                && min > max
+            {
+                return Err(invalid("min_per_execution exceeds max_per_execution"));
+            }
+        }
+
+        Ok(Self { rules })
+    }
+
+    /// Parse a schedule from YAML.
+    ///
+    /// # Errors
+    ///
+    /// Returns error if the YAML is malformed or a rule is invalid.
+    pub fn from_yaml(yaml: &str) -> Result<Self, FeeScheduleError> {
+        let parsed: Self = serde_yaml_bw::from_str(yaml).map_err(|e| FeeScheduleError::Parse {
+            message: e.to_string(),
+        })?;
+        Self::new(parsed.rules)
+    }
+
+    /// Configured rules.
+    #[must_use]
+    pub fn rules(&self) -> &[FeeRule] {
+        &self.rules
+    }
+
+    /// Fees charged for one execution, by rule name.
+    #[must_use]
+    pub fn fees(
+        &self,
+        asset_class: FeeAssetClass,
+        venue: Option<&str>,
+        side: OrderSide,
+        qty: Decimal,
+        trade_date: NaiveDate,
+    ) -> Vec<(&str, Decimal)> {
+        // Venue-specific rules beat defaults; later effective dates beat earlier
+        let rank = |rule: &FeeRule| (rule.venue.is_some(), rule.effective_from);
+        let mut selected: Vec<&FeeRule> = Vec::new();
+
+        for rule in self.rules.iter().filter(|rule| {
+            rule.effective_from <= trade_date && rule.applies_to(asset_class, venue, side)
+        }) {
+            match selected.iter_mut().find(|s| s.name == rule.name) {
+                Some(current) if rank(rule) > rank(current) => *current = rule,
+                Some(_) => {}
+                None => selected.push(rule),
+            }
+        }
+
+        selected
+            .into_iter()
+            .map(|rule| (rule.name.as_str(), rule.fee(qty)))
+            .collect()
+    }
+
+    /// Total fees charged for one execution.
+    #[must_use]
+    pub fn estimate(
+        &self,
+        asset_class: FeeAssetClass,
+        venue: Option<&str>,
+        side: OrderSide,
+        qty: Decimal,
+        trade_date: NaiveDate,
+    ) -> Decimal {
+        self.fees(asset_class, venue, side, qty, trade_date)
+            .into_iter()
+            .map(|(_, fee)| fee)
+            .sum()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rust_decimal_macros::dec;
+
+    const SCHEDULE: &str = r#"
+rules:
+  - name: occ_clearing
+    asset_class: OPTION
+    effective_from: 2024-01-01
+    tiers:
+      - { min_qty: "0", per_unit: "0.055" }
+      - { min_qty: "500", per_unit: "0.045" }
+    max_per_execution: "55.00"
+  - name: exchange
+    asset_class: OPTION
+    effective_from: 2024-01-01
+    tiers: [{ min_qty: "0", per_unit: "0.50" }]
+  - name: exchange
+    asset_class: OPTION
+    venue: CBOE
+    effective_from: 2024-07-01
+    tiers: [{ min_qty: "0", per_unit: "0.44" }]
+"#;
+
+    fn date(s: &str) -> NaiveDate {
+        NaiveDate::parse_from_str(s, "%Y-%m-%d").unwrap()
+    }
+
+    #[test]
+    fn venue_override_applies_from_effective_date() {
+        let schedule = FeeSchedule::from_yaml(SCHEDULE).unwrap();
+        let fee = |venue, day| {
+            schedule.estimate(
+                FeeAssetClass::Option,
+                venue,
+                OrderSide::Buy,
+                dec!(10),
+                date(day),
+            )
+        };
+
+        // 10 * 0.055 = 0.55 clearing + 10 * exchange rate
+        assert_eq!(fee(Some("CBOE"), "2024-06-30"), dec!(5.55));
+        assert_eq!(fee(Some("CBOE"), "2024-07-01"), dec!(4.95));
+        assert_eq!(fee(Some("ISE"), "2024-07-01"), dec!(5.55));
+        assert_eq!(fee(None, "2023-12-31"), Decimal::ZERO);
+    }
+
+    #[test]
+    fn tiers_and_caps_apply_per_execution() {
+        let schedule = FeeSchedule::from_yaml(SCHEDULE).unwrap();
+        let clearing = |qty| {
+            schedule
+                .fees(
+                    FeeAssetClass::Option,
+                    None,
+                    OrderSide::Sell,
+                    qty,
+                    date("2024-03-01"),
+                )
+                .into_iter()
+                .find(|(name, _)| *name == "occ_clearing")
+                .map(|(_, fee)| fee)
+        };
+
+        assert_eq!(clearing(dec!(499)), Some(dec!(27.45)));
+        assert_eq!(clearing(dec!(500)), Some(dec!(22.50)));
+        assert_eq!(clearing(dec!(5000)), Some(dec!(55.00)));
+        assert!(
+            schedule
+                .fees(
+                    FeeAssetClass::Equity,
+                    None,
+                    OrderSide::Sell,
+                    dec!(100),
+                    date("2024-03-01")
+                )
+                .is_empty()
+        );
+    }
+
+    #[test]
+    fn rejects_unsorted_tiers() {
+        let yaml = r#"
+rules:
+  - name: bad
+    asset_class: EQUITY
+    effective_from: 2024-01-01
+    tiers: [{ min_qty: "100", per_unit: "0.01" }, { min_qty: "0", per_unit: "0.02" }]
+"#;
+        assert!(matches!(
+            FeeSchedule::from_yaml(yaml),
+            Err(FeeScheduleError::InvalidRule { .. })
+        ));
+    }
+}
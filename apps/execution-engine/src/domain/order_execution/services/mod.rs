@@ -2,6 +2,10 @@
 //!
 //! Stateless business logic that doesn't fit in aggregates.
 
+mod fee_schedule;
 mod order_state_machine;
+mod regulatory_fee_calculator;
 
+pub use fee_schedule::{FeeAssetClass, FeeRule, FeeSchedule, FeeScheduleError, FeeTier};
 pub use order_state_machine::OrderStateMachine;
+pub use regulatory_fee_calculator::RegulatoryFeeCalculator;
@@ -0,0 +1,104 @@
+//! Regulatory Fee Calculator
+//!
+//! Estimates the pass-through regulatory fees charged on equity sales:
+//! the SEC Section 31 fee and the FINRA Trading Activity Fee (TAF).
+
+use rust_decimal::{Decimal, RoundingStrategy};
+
+use crate::domain::order_execution::value_objects::OrderSide;
+
+/// Estimates regulatory fees for a single execution.
+///
+/// Both fees apply to sells only and are rounded up to the nearest cent
+/// per execution, matching how brokers pass them through.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RegulatoryFeeCalculator {
+    /// SEC Section 31 fee rate per dollar of sale proceeds.
+    pub sec_fee_rate: Decimal,
+    /// FINRA TAF per share sold.
+    pub taf_per_share: Decimal,
+    /// FINRA TAF cap per execution.
+    pub taf_max_per_trade: Decimal,
+}
+
+impl Default for RegulatoryFeeCalculator {
+    fn default() -> Self {
+        Self {
+            // $27.80 per million
+            sec_fee_rate: Decimal::new(278, 7),
+            taf_per_share: Decimal::new(166, 6),
+            taf_max_per_trade: Decimal::new(830, 2),
+        }
+    }
+}
+
+impl RegulatoryFeeCalculator {
+    /// Estimated SEC fee for a sale.
+    #[must_use]
+    pub fn sec_fee(&self, side: OrderSide, qty: Decimal, price: Decimal) -> Decimal {
+        if side != OrderSide::Sell {
+            return Decimal::ZERO;
+        }
+        round_up_to_cent(qty.abs() * price * self.sec_fee_rate)
+    }
+
+    /// Estimated FINRA TAF for a sale.
+    #[must_use]
+    pub fn taf(&self, side: OrderSide, qty: Decimal) -> Decimal {
+        if side != OrderSide::Sell {
+            return Decimal::ZERO;
+        }
+        round_up_to_cent((qty.abs() * self.taf_per_share).min(self.taf_max_per_trade))
+    }
+
+    /// Total estimated regulatory fees for an execution.
+    #[must_use]
+    pub fn estimate(&self, side: OrderSide, qty: Decimal, price: Decimal) -> Decimal {
+        self.sec_fee(side, qty, price) + self.taf(side, qty)
+    }
+}
+
+fn round_up_to_cent(amount: Decimal) -> Decimal {
+    amount.round_dp_with_strategy(2, RoundingStrategy::AwayFromZero)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn buys_are_free() {
+        let calc = RegulatoryFeeCalculator::default();
+        assert_eq!(
+            calc.estimate(OrderSide::Buy, Decimal::new(100, 0), Decimal::new(150, 0)),
+            Decimal::ZERO
+        );
+    }
+
+    #[test]
+    fn sell_fees_round_up_to_cent() {
+        let calc = RegulatoryFeeCalculator::default();
+        // 100 * 150 * 0.0000278 = 0.417 -> 0.42; 100 * 0.000166 = 0.0166 -> 0.02
+        assert_eq!(
+            calc.sec_fee(OrderSide::Sell, Decimal::new(100, 0), Decimal::new(150, 0)),
+            Decimal::new(42, 2)
+        );
+        assert_eq!(
+            calc.taf(OrderSide::Sell, Decimal::new(100, 0)),
+            Decimal::new(2, 2)
+        );
+        assert_eq!(
+            calc.estimate(OrderSide::Sell, Decimal::new(100, 0), Decimal::new(150, 0)),
+            Decimal::new(44, 2)
+        );
+    }
+
+    #[test]
+    fn taf_is_capped() {
+        let calc = RegulatoryFeeCalculator::default();
+        assert_eq!(
+            calc.taf(OrderSide::Sell, Decimal::new(1_000_000, 0)),
+            Decimal::new(830, 2)
+        );
+    }
+}
@@ -0,0 +1,68 @@
+//! Leg imbalance of a multi-leg order.
+
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+
+use super::OrderSide;
+use crate::domain::shared::{InstrumentId, Quantity};
+
+/// A trade in one leg that moves it back in line with the others.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct LegAdjustment {
+    /// Leg index (0-based).
+    pub leg_index: u32,
+    /// Instrument of the leg.
+    pub instrument_id: InstrumentId,
+    /// Side to trade.
+    pub side: OrderSide,
+    /// Quantity to trade.
+    pub quantity: Quantity,
+}
+
+/// Legs of a multi-leg order filled in different proportions.
+///
+/// A leg's fill ratio is its filled quantity over its quantity. The order is
+/// balanced while every leg has the same ratio.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct LegImbalance {
+    /// Highest fill ratio across the legs.
+    pub max_fill_ratio: Decimal,
+    /// Lowest fill ratio across the legs.
+    pub min_fill_ratio: Decimal,
+    /// Trades (on the leg's side) that bring each lagging leg up to the
+    /// highest ratio.
+    pub catch_up: Vec<LegAdjustment>,
+    /// Trades (against the leg's side) that bring each leading leg down to
+    /// the lowest ratio.
+    pub unwind: Vec<LegAdjustment>,
+}
+
+impl LegImbalance {
+    /// Check if some leg filled completely while another has not filled.
+    #[must_use]
+    pub fn is_legged_out(&self) -> bool {
+        self.max_fill_ratio >= Decimal::ONE && self.min_fill_ratio.is_zero()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn legged_out_needs_a_full_and_an_empty_leg() {
+        let imbalance = LegImbalance {
+            max_fill_ratio: Decimal::ONE,
+            min_fill_ratio: Decimal::ZERO,
+            catch_up: vec![],
+            unwind: vec![],
+        };
+        assert!(imbalance.is_legged_out());
+
+        let partial = LegImbalance {
+            min_fill_ratio: Decimal::new(5, 1),
+            ..imbalance
+        };
+        assert!(!partial.is_legged_out());
+    }
+}
@@ -4,20 +4,26 @@
 
 mod execution_ack;
 mod fill_report;
+mod leg_imbalance;
 mod order_purpose;
 mod order_side;
 mod order_status;
 mod order_type;
+mod oto_exit;
 mod partial_fill;
 mod reasons;
+mod tax_lot;
 mod time_in_force;
 
-pub use execution_ack::ExecutionAck;
-pub use fill_report::FillReport;
+pub use execution_ack::{Environment, ExecutionAck};
+pub use fill_report::{FillReport, LiquidityType};
+pub use leg_imbalance::{LegAdjustment, LegImbalance};
 pub use order_purpose::OrderPurpose;
 pub use order_side::OrderSide;
 pub use order_status::OrderStatus;
 pub use order_type::OrderType;
+pub use oto_exit::OtoExit;
 pub use partial_fill::{PartialFillState, PartialFillTimeoutAction, PartialFillTimeoutConfig};
 pub use reasons::{CancelReason, RejectReason};
+pub use tax_lot::{ClosedLot, LotMatching, OPENING_FILL_ID, TaxLot, TaxLotBook};
 pub use time_in_force::TimeInForce;
@@ -1,5 +1,6 @@
 //! Order side (buy or sell).
 
+use rust_decimal::Decimal;
 use serde::{Deserialize, Serialize};
 use std::fmt;
 
@@ -33,6 +34,22 @@ impl OrderSide {
             Self::Sell => -1,
         }
     }
+
+    /// Cost of filling at `price` relative to `reference`, in basis points.
+    /// Positive when the fill is worse than the reference.
+    ///
+    /// Returns `None` for a zero reference.
+    #[must_use]
+    pub fn slippage_bps(&self, reference: Decimal, price: Decimal) -> Option<Decimal> {
+        if reference.is_zero() {
+            return None;
+        }
+        let cost = match self {
+            Self::Buy => price - reference,
+            Self::Sell => reference - price,
+        };
+        Some((cost / reference * Decimal::from(10_000)).round_dp(2))
+    }
 }
 
 impl fmt::Display for OrderSide {
@@ -60,6 +77,22 @@ mod tests {
         assert_eq!(OrderSide::Sell.sign(), -1);
     }
 
+    #[test]
+    fn slippage_is_positive_when_the_fill_is_worse() {
+        assert_eq!(
+            OrderSide::Buy.slippage_bps(Decimal::from(100), Decimal::new(10005, 2)),
+            Some(Decimal::from(5))
+        );
+        assert_eq!(
+            OrderSide::Sell.slippage_bps(Decimal::from(100), Decimal::new(10005, 2)),
+            Some(Decimal::from(-5))
+        );
+        assert_eq!(
+            OrderSide::Buy.slippage_bps(Decimal::ZERO, Decimal::ONE),
+            None
+        );
+    }
+
     #[test]
     fn order_side_display() {
         assert_eq!(format!("{}", OrderSide::Buy), "BUY");
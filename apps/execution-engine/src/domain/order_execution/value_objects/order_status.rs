@@ -38,6 +38,19 @@ pub enum OrderStatus {
 }
 
 impl OrderStatus {
+    /// Every status, in lifecycle order.
+    pub const ALL: [Self; 9] = [
+        Self::New,
+        Self::PendingNew,
+        Self::Accepted,
+        Self::PartiallyFilled,
+        Self::Filled,
+        Self::PendingCancel,
+        Self::Canceled,
+        Self::Rejected,
+        Self::Expired,
+    ];
+
     /// Returns true if the order is in a terminal state.
     #[must_use]
     pub const fn is_terminal(&self) -> bool {
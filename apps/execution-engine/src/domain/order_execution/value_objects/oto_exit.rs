@@ -0,0 +1,125 @@
+//! One-triggers-other (OTO) exit leg.
+
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+
+use super::OrderSide;
+
+/// Exit an OTO entry arms with the broker once it fills.
+///
+/// An OTO carries only a stop or only a target; an entry with both is a
+/// bracket.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "kind", rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum OtoExit {
+    /// Limit order at the profit target.
+    TakeProfit {
+        /// Target price.
+        limit_price: Decimal,
+    },
+    /// Stop order, or stop-limit when `limit_price` is set.
+    StopLoss {
+        /// Stop trigger price.
+        stop_price: Decimal,
+        /// Limit price once the stop triggers.
+        #[serde(default)]
+        limit_price: Option<Decimal>,
+    },
+}
+
+impl OtoExit {
+    /// Create a take-profit exit.
+    #[must_use]
+    pub const fn take_profit(limit_price: Decimal) -> Self {
+        Self::TakeProfit { limit_price }
+    }
+
+    /// Create a stop-loss exit.
+    #[must_use]
+    pub const fn stop_loss(stop_price: Decimal) -> Self {
+        Self::StopLoss {
+            stop_price,
+            limit_price: None,
+        }
+    }
+
+    /// Exit for a stop or a target; `None` unless exactly one is given.
+    #[must_use]
+    pub const fn from_levels(
+        stop_loss: Option<Decimal>,
+        take_profit: Option<Decimal>,
+    ) -> Option<Self> {
+        match (stop_loss, take_profit) {
+            (Some(stop_price), None) => Some(Self::stop_loss(stop_price)),
+            (None, Some(limit_price)) => Some(Self::take_profit(limit_price)),
+            _ => None,
+        }
+    }
+
+    /// Price at which the exit triggers.
+    #[must_use]
+    pub const fn trigger_price(&self) -> Decimal {
+        match self {
+            Self::TakeProfit { limit_price } => *limit_price,
+            Self::StopLoss { stop_price, .. } => *stop_price,
+        }
+    }
+
+    /// Check the exit sits on the right side of `entry_price` for an entry
+    /// on `side`: a buy's target above and stop below, a sell's the reverse.
+    #[must_use]
+    pub fn is_valid_for(&self, side: OrderSide, entry_price: Decimal) -> bool {
+        let price = self.trigger_price();
+        if price <= Decimal::ZERO {
+            return false;
+        }
+        match (self, side) {
+            (Self::TakeProfit { .. }, OrderSide::Buy)
+            | (Self::StopLoss { .. }, OrderSide::Sell) => price > entry_price,
+            (Self::TakeProfit { .. }, OrderSide::Sell)
+            | (Self::StopLoss { .. }, OrderSide::Buy) => price < entry_price,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn oto_exit_from_levels() {
+        let price = Decimal::new(95, 0);
+
+        assert_eq!(
+            OtoExit::from_levels(Some(price), None),
+            Some(OtoExit::stop_loss(price))
+        );
+        assert_eq!(
+            OtoExit::from_levels(None, Some(price)),
+            Some(OtoExit::take_profit(price))
+        );
+        assert_eq!(OtoExit::from_levels(None, None), None);
+        assert_eq!(OtoExit::from_levels(Some(price), Some(price)), None);
+    }
+
+    #[test]
+    fn oto_exit_is_valid_for() {
+        let entry = Decimal::new(100, 0);
+
+        assert!(OtoExit::stop_loss(Decimal::new(95, 0)).is_valid_for(OrderSide::Buy, entry));
+        assert!(!OtoExit::stop_loss(Decimal::new(105, 0)).is_valid_for(OrderSide::Buy, entry));
+        assert!(OtoExit::take_profit(Decimal::new(90, 0)).is_valid_for(OrderSide::Sell, entry));
+        assert!(!OtoExit::take_profit(Decimal::ZERO).is_valid_for(OrderSide::Sell, entry));
+    }
+
+    #[test]
+    fn oto_exit_serde() {
+        let exit = OtoExit::stop_loss(Decimal::new(95, 0));
+        let json = serde_json::to_value(exit).unwrap();
+        assert_eq!(json["kind"], "STOP_LOSS");
+
+        let parsed: OtoExit =
+            serde_json::from_str(r#"{"kind": "TAKE_PROFIT", "limit_price": "110"}"#).unwrap();
+        assert_eq!(parsed, OtoExit::take_profit(Decimal::new(110, 0)));
+    }
+}
@@ -0,0 +1,347 @@
+//! Tax Lots
+//!
+//! Open lots per symbol and the realized gain of every lot a fill closes.
+//! A closing fill consumes open lots oldest first (FIFO) or newest first
+//! (LIFO); a fill larger than the position closes it and opens a lot on
+//! the other side for the remainder.
+//!
+//! A position held before the engine recorded its fills is seeded as one
+//! opening lot at the broker's average entry price (see [`OPENING_FILL_ID`]).
+//! Open lots are kept in `opened_at` order; a seeded lot whose open date the
+//! broker does not report has none and sorts before every dated lot, as it
+//! predates the recorded fills.
+
+use std::collections::HashMap;
+
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+
+use super::{FillReport, OrderSide};
+use crate::domain::shared::{Symbol, Timestamp};
+
+/// `fill_id` of a lot seeded from a broker position rather than opened by a
+/// recorded fill. Its basis is the broker's average entry price.
+pub const OPENING_FILL_ID: &str = "OPENING";
+
+/// Order in which closing fills consume open lots.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum LotMatching {
+    /// Oldest lot first.
+    #[default]
+    Fifo,
+    /// Newest lot first.
+    Lifo,
+}
+
+/// An open lot.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TaxLot {
+    /// Fill that opened the lot.
+    pub fill_id: String,
+    /// When the lot was opened; `None` for a seeded lot whose open date is
+    /// unknown.
+    pub opened_at: Option<Timestamp>,
+    /// `Buy` for a long lot, `Sell` for a short one.
+    pub side: OrderSide,
+    /// Quantity still open, in shares or contracts.
+    pub quantity: Decimal,
+    /// Opening price, per share.
+    pub price: Decimal,
+}
+
+/// A lot, or the part of one, closed by a fill.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ClosedLot {
+    /// Symbol.
+    pub symbol: String,
+    /// `Buy` for a long lot, `Sell` for a short one.
+    pub side: OrderSide,
+    /// Quantity closed, in shares or contracts.
+    pub quantity: Decimal,
+    /// Fill that opened the lot.
+    pub open_fill_id: String,
+    /// When the lot was opened; `None` if unknown.
+    pub opened_at: Option<Timestamp>,
+    /// Opening price, per share.
+    pub open_price: Decimal,
+    /// Fill that closed the lot.
+    pub close_fill_id: String,
+    /// When the lot was closed.
+    pub closed_at: Timestamp,
+    /// Closing price, per share.
+    pub close_price: Decimal,
+    /// Gain on the closed quantity, times the contract multiplier.
+    pub realized_pnl: Decimal,
+}
+
+/// Open lots of every symbol and the lots closed so far.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct TaxLotBook {
+    matching: LotMatching,
+    /// Open lots per symbol in `opened_at` order, all on the same side.
+    open: HashMap<String, Vec<TaxLot>>,
+    closed: Vec<ClosedLot>,
+}
+
+impl TaxLotBook {
+    /// Create an empty book that matches lots with `matching`.
+    #[must_use]
+    pub fn new(matching: LotMatching) -> Self {
+        Self {
+            matching,
+            ..Self::default()
+        }
+    }
+
+    /// Lot matching method.
+    #[must_use]
+    pub const fn matching(&self) -> LotMatching {
+        self.matching
+    }
+
+    /// Add `lot` to the open lots of `symbol` in `opened_at` order.
+    ///
+    /// Seeds a position opened before the recorded fills: a dated lot must
+    /// be seeded before the fills that follow its `opened_at` are applied,
+    /// an undated one before any fill of `symbol`.
+    pub fn seed(&mut self, symbol: &Symbol, lot: TaxLot) {
+        let lots = self.open.entry(symbol.to_string()).or_default();
+        let index = lots.partition_point(|open| open.opened_at <= lot.opened_at);
+        lots.insert(index, lot);
+    }
+
+    /// Apply a fill on the `side` of `symbol`, returning the lots it closed.
+    ///
+    /// Fills must be applied in the order they occurred.
+    pub fn apply(&mut self, symbol: &Symbol, side: OrderSide, fill: &FillReport) -> &[ClosedLot] {
+        let start = self.closed.len();
+        let price = fill.price.amount();
+        let multiplier = symbol.multiplier();
+        let lots = self.open.entry(symbol.to_string()).or_default();
+        let mut remaining = fill.quantity.amount();
+
+        while remaining > Decimal::ZERO {
+            let index = match self.matching {
+                LotMatching::Fifo => 0,
+                LotMatching::Lifo => lots.len().saturating_sub(1),
+            };
+            let Some(lot) = lots.get_mut(index).filter(|lot| lot.side != side) else {
+                break;
+            };
+
+            let quantity = remaining.min(lot.quantity);
+            let gain = match lot.side {
+                OrderSide::Buy => price - lot.price,
+                OrderSide::Sell => lot.price - price,
+            };
+            self.closed.push(ClosedLot {
+                symbol: symbol.to_string(),
+                side: lot.side,
+                quantity,
+                open_fill_id: lot.fill_id.clone(),
+                opened_at: lot.opened_at,
+                open_price: lot.price,
+                close_fill_id: fill.fill_id.clone(),
+                closed_at: fill.timestamp,
+                close_price: price,
+                realized_pnl: gain * quantity * multiplier,
+            });
+
+            lot.quantity -= quantity;
+            remaining -= quantity;
+            if lot.quantity.is_zero() {
+                lots.remove(index);
+            }
+        }
+
+        if remaining > Decimal::ZERO {
+            lots.push(TaxLot {
+                fill_id: fill.fill_id.clone(),
+                opened_at: Some(fill.timestamp),
+                side,
+                quantity: remaining,
+                price,
+            });
+        }
+        if lots.is_empty() {
+            self.open.remove(symbol.as_str());
+        }
+
+        &self.closed[start..]
+    }
+
+    /// Open lots of `symbol`, oldest first.
+    #[must_use]
+    pub fn open_lots(&self, symbol: &str) -> &[TaxLot] {
+        self.open.get(symbol).map_or(&[], Vec::as_slice)
+    }
+
+    /// Net open quantity of `symbol`: positive when long, negative when short.
+    #[must_use]
+    pub fn open_quantity(&self, symbol: &str) -> Decimal {
+        self.open_lots(symbol)
+            .iter()
+            .map(|lot| match lot.side {
+                OrderSide::Buy => lot.quantity,
+                OrderSide::Sell => -lot.quantity,
+            })
+            .sum()
+    }
+
+    /// Every lot closed so far, in the order they were closed.
+    #[must_use]
+    pub fn closed_lots(&self) -> &[ClosedLot] {
+        &self.closed
+    }
+
+    /// P&L realized in `symbol`.
+    #[must_use]
+    pub fn realized_pnl(&self, symbol: &str) -> Decimal {
+        self.closed
+            .iter()
+            .filter(|lot| lot.symbol == symbol)
+            .map(|lot| lot.realized_pnl)
+            .sum()
+    }
+
+    /// P&L realized across all symbols.
+    #[must_use]
+    pub fn total_realized_pnl(&self) -> Decimal {
+        self.closed.iter().map(|lot| lot.realized_pnl).sum()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::shared::{Money, Quantity};
+    use rust_decimal_macros::dec;
+
+    fn at(day: u32) -> Timestamp {
+        Timestamp::parse(&format!("2026-03-{day:02}T15:00:00Z")).unwrap()
+    }
+
+    fn fill(fill_id: &str, quantity: i64, price: Decimal, day: u32) -> FillReport {
+        FillReport::new(
+            fill_id,
+            Quantity::from_i64(quantity),
+            Money::new(price),
+            at(day),
+            "TEST",
+        )
+    }
+
+    fn book(matching: LotMatching) -> TaxLotBook {
+        let aapl = Symbol::new("AAPL");
+        let mut book = TaxLotBook::new(matching);
+        book.apply(&aapl, OrderSide::Buy, &fill("b-1", 10, dec!(100), 2));
+        book.apply(&aapl, OrderSide::Buy, &fill("b-2", 10, dec!(110), 3));
+        book
+    }
+
+    #[test]
+    fn fifo_closes_the_oldest_lot_first() {
+        let mut book = book(LotMatching::Fifo);
+
+        let closed = book.apply(
+            &Symbol::new("AAPL"),
+            OrderSide::Sell,
+            &fill("s-1", 15, dec!(120), 4),
+        );
+
+        assert_eq!(closed.len(), 2);
+        assert_eq!(closed[0].open_fill_id, "b-1");
+        assert_eq!(closed[0].quantity, dec!(10));
+        assert_eq!(closed[0].realized_pnl, dec!(200));
+        assert_eq!(closed[1].open_fill_id, "b-2");
+        assert_eq!(closed[1].quantity, dec!(5));
+        assert_eq!(closed[1].realized_pnl, dec!(50));
+        assert_eq!(book.realized_pnl("AAPL"), dec!(250));
+        assert_eq!(book.open_lots("AAPL")[0].quantity, dec!(5));
+        assert_eq!(book.open_lots("AAPL")[0].price, dec!(110));
+    }
+
+    #[test]
+    fn lifo_closes_the_newest_lot_first() {
+        let mut book = book(LotMatching::Lifo);
+
+        book.apply(
+            &Symbol::new("AAPL"),
+            OrderSide::Sell,
+            &fill("s-1", 15, dec!(120), 4),
+        );
+
+        assert_eq!(book.closed_lots()[0].open_fill_id, "b-2");
+        assert_eq!(book.realized_pnl("AAPL"), dec!(200));
+        assert_eq!(book.open_lots("AAPL")[0].fill_id, "b-1");
+        assert_eq!(book.open_lots("AAPL")[0].quantity, dec!(5));
+    }
+
+    fn opening(quantity: Decimal, price: Decimal, day: Option<u32>) -> TaxLot {
+        TaxLot {
+            fill_id: OPENING_FILL_ID.to_string(),
+            opened_at: day.map(at),
+            side: OrderSide::Buy,
+            quantity,
+            price,
+        }
+    }
+
+    #[test]
+    fn seeded_lots_are_ordered_by_open_date() {
+        let aapl = Symbol::new("AAPL");
+        let sell = fill("s-1", 5, dec!(120), 4);
+
+        // Undated: opened before every recorded fill
+        let mut fifo = book(LotMatching::Fifo);
+        fifo.seed(&aapl, opening(dec!(5), dec!(90), None));
+        assert_eq!(fifo.open_lots("AAPL")[0].fill_id, OPENING_FILL_ID);
+        fifo.apply(&aapl, OrderSide::Sell, &sell);
+        assert_eq!(fifo.closed_lots()[0].open_fill_id, OPENING_FILL_ID);
+        assert_eq!(fifo.closed_lots()[0].opened_at, None);
+        assert_eq!(fifo.realized_pnl("AAPL"), dec!(150));
+
+        let mut lifo = book(LotMatching::Lifo);
+        lifo.seed(&aapl, opening(dec!(5), dec!(90), None));
+        lifo.apply(&aapl, OrderSide::Sell, &sell);
+        assert_eq!(lifo.closed_lots()[0].open_fill_id, "b-2");
+
+        // Dated between the two recorded buys
+        let mut fifo = TaxLotBook::new(LotMatching::Fifo);
+        fifo.apply(&aapl, OrderSide::Buy, &fill("b-1", 10, dec!(100), 1));
+        fifo.seed(&aapl, opening(dec!(5), dec!(90), Some(2)));
+        fifo.apply(&aapl, OrderSide::Buy, &fill("b-2", 10, dec!(110), 3));
+        let ids: Vec<_> = fifo
+            .open_lots("AAPL")
+            .iter()
+            .map(|lot| lot.fill_id.as_str())
+            .collect();
+        assert_eq!(ids, ["b-1", OPENING_FILL_ID, "b-2"]);
+
+        fifo.apply(&aapl, OrderSide::Sell, &fill("s-1", 12, dec!(120), 4));
+        assert_eq!(fifo.closed_lots()[0].open_fill_id, "b-1");
+        assert_eq!(fifo.closed_lots()[1].open_fill_id, OPENING_FILL_ID);
+        assert_eq!(fifo.closed_lots()[1].quantity, dec!(2));
+        assert_eq!(fifo.closed_lots()[1].opened_at, Some(at(2)));
+    }
+
+    #[test]
+    fn oversized_close_flips_to_a_short_lot() {
+        let option = Symbol::new("AAPL260320C00150000");
+        let mut book = TaxLotBook::new(LotMatching::Fifo);
+        book.apply(&option, OrderSide::Buy, &fill("b-1", 2, dec!(2.50), 2));
+
+        let closed = book.apply(&option, OrderSide::Sell, &fill("s-1", 3, dec!(3), 3));
+        assert_eq!(closed[0].realized_pnl, dec!(100));
+        let short = &book.open_lots(option.as_str())[0];
+        assert_eq!(short.side, OrderSide::Sell);
+        assert_eq!(short.quantity, dec!(1));
+
+        let closed = book.apply(&option, OrderSide::Buy, &fill("b-2", 1, dec!(3.50), 4));
+        assert_eq!(closed[0].side, OrderSide::Sell);
+        assert_eq!(closed[0].realized_pnl, dec!(-50));
+        assert!(book.open_lots(option.as_str()).is_empty());
+        assert_eq!(book.total_realized_pnl(), dec!(50));
+    }
+}
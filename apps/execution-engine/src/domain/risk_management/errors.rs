@@ -2,6 +2,8 @@
 
 use std::fmt;
 
+use crate::domain::shared::{ClassifiedError, ErrorKind};
+
 /// Errors that can occur in risk validation.
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum RiskError {
@@ -80,6 +82,28 @@ impl fmt::Display for RiskError {
 
 impl std::error::Error for RiskError {}
 
+impl ClassifiedError for RiskError {
+    fn code(&self) -> &str {
+        match self {
+            Self::ConstraintViolation { code, .. } => code,
+            Self::InsufficientBuyingPower { .. } => "INSUFFICIENT_BUYING_POWER",
+            Self::PdtViolation { .. } => "PDT_VIOLATION",
+            Self::PolicyNotFound { .. } => "RISK_POLICY_NOT_FOUND",
+            Self::InvalidConfiguration { .. } => "INVALID_RISK_CONFIGURATION",
+        }
+    }
+
+    fn kind(&self) -> ErrorKind {
+        match self {
+            Self::ConstraintViolation { .. }
+            | Self::InsufficientBuyingPower { .. }
+            | Self::PdtViolation { .. } => ErrorKind::Rejected,
+            Self::PolicyNotFound { .. } => ErrorKind::NotFound,
+            Self::InvalidConfiguration { .. } => ErrorKind::InvalidArgument,
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -136,6 +160,21 @@ mod tests {
         assert!(msg.contains("between 0 and 1"));
     }
 
+    #[test]
+    fn risk_error_codes() {
+        let err = RiskError::ConstraintViolation {
+            code: "MAX_NOTIONAL".to_string(),
+            message: "Exceeds limit".to_string(),
+        };
+        assert_eq!(err.code(), "MAX_NOTIONAL");
+        assert_eq!(err.kind(), ErrorKind::Rejected);
+        let err = RiskError::PolicyNotFound {
+            policy_id: "policy-abc".to_string(),
+        };
+        assert_eq!(err.code(), "RISK_POLICY_NOT_FOUND");
+        assert_eq!(err.kind(), ErrorKind::NotFound);
+    }
+
     #[test]
     fn risk_error_is_std_error() {
         let err: Box<dyn std::error::Error> = Box::new(RiskError::PolicyNotFound {
@@ -18,6 +18,8 @@ pub use aggregate::RiskPolicy;
 pub use errors::RiskError;
 pub use services::RiskValidationService;
 pub use value_objects::{
-    ConstraintResult, ConstraintViolation, Exposure, ExposureLimits, Greeks, OptionsLimits,
-    PerInstrumentLimits, PortfolioLimits, RiskContext, SizingLimits, ViolationSeverity,
+    ConcentrationGroups, ConcentrationLimits, ConstraintResult, ConstraintViolation, Exposure,
+    ExposureBook, ExposureLimits, Greeks, InstrumentUniverse, OffTickAction, OptionsLimits,
+    PerInstrumentLimits, PortfolioLimits, PriceSanityLimits, RiskContext, SizingLimits,
+    TickSizeRules, UniverseMembership, ViolationSeverity,
 };
@@ -0,0 +1,176 @@
+//! Day Trade Projection
+//!
+//! Predicts which orders in a plan would create new day trades, given the
+//! current positions and how much of each was opened today. Orders are
+//! applied in plan order, so a plan that opens and closes the same symbol
+//! counts as a day trade even when no position exists yet.
+
+use std::collections::HashMap;
+
+use rust_decimal::Decimal;
+use rust_decimal::prelude::Signed;
+
+use crate::domain::order_execution::aggregate::Order;
+use crate::domain::order_execution::value_objects::OrderSide;
+use crate::domain::risk_management::value_objects::RiskContext;
+
+/// An order projected to complete a day trade.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ProjectedDayTrade {
+    /// Index of the order in the plan.
+    pub order_index: usize,
+    /// Symbol traded.
+    pub symbol: String,
+}
+
+/// Projected day trades for a plan.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct DayTradeProjection {
+    /// Orders that would each complete one day trade, in plan order.
+    pub day_trades: Vec<ProjectedDayTrade>,
+}
+
+#[derive(Debug, Clone, Copy, Default)]
+struct SymbolState {
+    position: Decimal,
+    opened_today: Decimal,
+}
+
+impl DayTradeProjection {
+    /// Project the day trades `orders` would create against `context`.
+    ///
+    /// Closing quantity is matched against today's openings first, so any
+    /// close that touches a same-day opening counts as a day trade.
+    #[must_use]
+    pub fn project(orders: &[Order], context: &RiskContext) -> Self {
+        let mut states: HashMap<&str, SymbolState> = HashMap::new();
+        let mut day_trades = Vec::new();
+
+        for (order_index, order) in orders.iter().enumerate() {
+            let symbol = order.symbol().as_str();
+            let state = states.entry(symbol).or_insert_with(|| {
+                context
+                    .get_position(symbol)
+                    .map_or_else(SymbolState::default, |p| SymbolState {
+                        position: p.quantity.amount(),
+                        opened_today: p.opened_today.amount(),
+                    })
+            });
+
+            let qty = order.quantity().amount();
+            let delta = match order.side() {
+                OrderSide::Buy => qty,
+                OrderSide::Sell => -qty,
+            };
+
+            let closing = if state.position.is_zero()
+                || state.position.is_sign_positive() == delta.is_sign_positive()
+            {
+                Decimal::ZERO
+            } else {
+                delta.abs().min(state.position.abs())
+            };
+
+            if closing > Decimal::ZERO {
+                let closed_today = closing.min(state.opened_today.abs());
+                if closed_today > Decimal::ZERO {
+                    day_trades.push(ProjectedDayTrade {
+                        order_index,
+                        symbol: symbol.to_string(),
+                    });
+                    state.opened_today -= closed_today * state.opened_today.signum();
+                }
+            }
+
+            let opening = delta.abs() - closing;
+            state.position += delta;
+            if state.position.is_zero() {
+                state.opened_today = Decimal::ZERO;
+            } else if opening > Decimal::ZERO {
+                state.opened_today += opening * delta.signum();
+            }
+        }
+
+        Self { day_trades }
+    }
+
+    /// Number of projected day trades.
+    #[must_use]
+    pub const fn count(&self) -> usize {
+        self.day_trades.len()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::order_execution::aggregate::CreateOrderCommand;
+    use crate::domain::order_execution::value_objects::{OrderPurpose, OrderType, TimeInForce};
+    use crate::domain::risk_management::value_objects::PositionContext;
+    use crate::domain::shared::{InstrumentId, Money, Quantity, Symbol};
+
+    fn order(symbol: &str, side: OrderSide, qty: i64) -> Order {
+        Order::new(CreateOrderCommand {
+            symbol: Symbol::new(symbol),
+            side,
+            order_type: OrderType::Market,
+            quantity: Quantity::from_i64(qty),
+            limit_price: None,
+            stop_price: None,
+            time_in_force: TimeInForce::Day,
+            purpose: OrderPurpose::Entry,
+            legs: vec![],
+        })
+        .unwrap()
+    }
+
+    fn context_with(symbol: &str, qty: i64, opened_today: i64) -> RiskContext {
+        let mut context = RiskContext::default();
+        context.add_position(
+            symbol,
+            PositionContext::new(
+                InstrumentId::new(symbol),
+                Quantity::from_i64(qty),
+                Money::usd(0.0),
+                Money::usd(0.0),
+            )
+            .with_opened_today(Quantity::from_i64(opened_today)),
+        );
+        context
+    }
+
+    #[test]
+    fn closing_overnight_position_is_not_a_day_trade() {
+        let context = context_with("AAPL", 100, 0);
+        let projection =
+            DayTradeProjection::project(&[order("AAPL", OrderSide::Sell, 100)], &context);
+
+        assert_eq!(projection.count(), 0);
+    }
+
+    #[test]
+    fn closing_same_day_opening_is_a_day_trade() {
+        let context = context_with("AAPL", 150, 50);
+        let projection =
+            DayTradeProjection::project(&[order("AAPL", OrderSide::Sell, 10)], &context);
+
+        assert_eq!(projection.count(), 1);
+        assert_eq!(projection.day_trades[0].symbol, "AAPL");
+    }
+
+    #[test]
+    fn round_trip_within_plan_is_a_day_trade() {
+        let orders = [
+            order("MSFT", OrderSide::Buy, 10),
+            order("MSFT", OrderSide::Sell, 10),
+            order("MSFT", OrderSide::Sell, 5),
+            order("MSFT", OrderSide::Buy, 5),
+        ];
+        let projection = DayTradeProjection::project(&orders, &RiskContext::default());
+
+        // Buy/sell round trip, then short/cover round trip
+        assert_eq!(projection.count(), 2);
+        assert_eq!(projection.day_trades[0].order_index, 1);
+        assert_eq!(projection.day_trades[1].order_index, 3);
+    }
+}
@@ -1,5 +1,9 @@
 //! Risk Management Domain Services
 
+mod day_trade_projection;
+mod position_netting;
 mod risk_validation_service;
 
+pub use day_trade_projection::{DayTradeProjection, ProjectedDayTrade};
+pub use position_netting::PositionNetting;
 pub use risk_validation_service::RiskValidationService;
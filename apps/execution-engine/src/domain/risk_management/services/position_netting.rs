@@ -0,0 +1,332 @@
+//! Position Netting
+//!
+//! Sizes scale-in and scale-out orders against what is already held and
+//! working in the symbol. A scale-out is trimmed to the quantity not yet
+//! committed to other reducing orders, so it can never flip the position;
+//! a scale-in is rejected when the position it would build, counting
+//! working orders on the same side, breaches the per-instrument caps.
+//! Orders with any other purpose pass through unchanged.
+
+use rust_decimal::Decimal;
+
+use crate::domain::order_execution::value_objects::{OrderPurpose, OrderSide};
+use crate::domain::risk_management::value_objects::{
+    ConstraintViolation, PerInstrumentLimits, SymbolPosition,
+};
+use crate::domain::shared::Symbol;
+
+/// Nets scale orders against the live position.
+#[derive(Debug, Clone, Default)]
+pub struct PositionNetting {
+    limits: Option<PerInstrumentLimits>,
+}
+
+impl PositionNetting {
+    /// Create a netting service without per-instrument caps.
+    #[must_use]
+    pub const fn new() -> Self {
+        Self { limits: None }
+    }
+
+    /// Reject scale-ins that would breach `limits`.
+    #[must_use]
+    pub const fn with_limits(mut self, limits: PerInstrumentLimits) -> Self {
+        self.limits = Some(limits);
+        self
+    }
+
+    /// Quantity to submit for an order of `quantity` against `position`.
+    ///
+    /// Market orders (no `limit_price`) skip the notional cap.
+    ///
+    /// # Errors
+    ///
+    /// Returns a violation if a scale-out has nothing left to reduce or
+    /// would add to the position, or if a scale-in opposes the position or
+    /// breaches a cap.
+    pub fn net(
+        &self,
+        symbol: &str,
+        purpose: OrderPurpose,
+        side: OrderSide,
+        quantity: Decimal,
+        limit_price: Option<Decimal>,
+        position: SymbolPosition,
+    ) -> Result<Decimal, Box<ConstraintViolation>> {
+        match purpose {
+            OrderPurpose::ScaleOut => Self::net_scale_out(symbol, side, quantity, position),
+            OrderPurpose::ScaleIn => {
+                self.check_scale_in(symbol, side, quantity, limit_price, position)
+            }
+            _ => Ok(quantity),
+        }
+    }
+
+    fn net_scale_out(
+        symbol: &str,
+        side: OrderSide,
+        quantity: Decimal,
+        position: SymbolPosition,
+    ) -> Result<Decimal, Box<ConstraintViolation>> {
+        let held = position.quantity;
+        if held.is_zero() {
+            return Err(Box::new(
+                ConstraintViolation::error(
+                    "SCALE_OUT_NO_POSITION",
+                    "Scale-out with no position to reduce",
+                )
+                .with_instrument(symbol),
+            ));
+        }
+        if held.is_sign_positive() == (side == OrderSide::Buy) {
+            return Err(Box::new(
+                ConstraintViolation::error(
+                    "SCALE_OUT_WRONG_SIDE",
+                    format!("{side} scale-out would add to a position of {held}"),
+                )
+                .with_instrument(symbol)
+                .with_observed(held.to_string()),
+            ));
+        }
+
+        let available = held.abs() - position.working(side);
+        if available <= Decimal::ZERO {
+            return Err(Box::new(
+                ConstraintViolation::error(
+                    "SCALE_OUT_NOTHING_AVAILABLE",
+                    format!(
+                        "Position of {} is already covered by working {side} orders",
+                        held.abs()
+                    ),
+                )
+                .with_instrument(symbol)
+                .with_observed(position.working(side).to_string())
+                .with_limit(held.abs().to_string()),
+            ));
+        }
+
+        Ok(quantity.min(available))
+    }
+
+    fn check_scale_in(
+        &self,
+        symbol: &str,
+        side: OrderSide,
+        quantity: Decimal,
+        limit_price: Option<Decimal>,
+        position: SymbolPosition,
+    ) -> Result<Decimal, Box<ConstraintViolation>> {
+        let held = position.quantity;
+        if !held.is_zero() && held.is_sign_positive() != (side == OrderSide::Buy) {
+            return Err(Box::new(
+                ConstraintViolation::error(
+                    "SCALE_IN_WRONG_SIDE",
+                    format!("{side} scale-in would reduce a position of {held}"),
+                )
+                .with_instrument(symbol)
+                .with_observed(held.to_string()),
+            ));
+        }
+
+        let Some(limits) = &self.limits else {
+            return Ok(quantity);
+        };
+
+        let projected = held.abs() + position.working(side) + quantity;
+        if projected > Decimal::from(limits.max_units) {
+            return Err(Box::new(
+                ConstraintViolation::error(
+                    "SCALE_IN_UNITS_EXCEEDED",
+                    format!(
+                        "Scale-in would build a position of {projected} > {} units",
+                        limits.max_units
+                    ),
+                )
+                .with_instrument(symbol)
+                .with_observed(projected.to_string())
+                .with_limit(limits.max_units.to_string()),
+            ));
+        }
+
+        if let Some(price) = limit_price {
+            let notional = price * projected * Symbol::new(symbol).multiplier();
+            if notional > limits.max_notional() {
+                return Err(Box::new(
+                    ConstraintViolation::error(
+                        "SCALE_IN_NOTIONAL_EXCEEDED",
+                        format!(
+                            "Scale-in would build a position worth ${notional:.2} > ${:.2}",
+                            limits.max_notional()
+                        ),
+                    )
+                    .with_instrument(symbol)
+                    .with_observed(format!("${notional:.2}"))
+                    .with_limit(format!("${:.2}", limits.max_notional())),
+                ));
+            }
+        }
+
+        Ok(quantity)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rust_decimal_macros::dec;
+
+    fn long(quantity: Decimal) -> SymbolPosition {
+        SymbolPosition {
+            quantity,
+            ..SymbolPosition::default()
+        }
+    }
+
+    #[test]
+    fn scale_out_is_trimmed_to_available_size() {
+        let netting = PositionNetting::new();
+        let position = long(dec!(100)).with_working(OrderSide::Sell, dec!(30));
+
+        let quantity = netting
+            .net(
+                "AAPL",
+                OrderPurpose::ScaleOut,
+                OrderSide::Sell,
+                dec!(100),
+                None,
+                position,
+            )
+            .unwrap();
+        assert_eq!(quantity, dec!(70));
+
+        let quantity = netting
+            .net(
+                "AAPL",
+                OrderPurpose::ScaleOut,
+                OrderSide::Sell,
+                dec!(50),
+                None,
+                position,
+            )
+            .unwrap();
+        assert_eq!(quantity, dec!(50));
+    }
+
+    #[test]
+    fn scale_out_without_available_size_is_rejected() {
+        let netting = PositionNetting::new();
+
+        let flat = netting.net(
+            "AAPL",
+            OrderPurpose::ScaleOut,
+            OrderSide::Sell,
+            dec!(10),
+            None,
+            SymbolPosition::default(),
+        );
+        assert_eq!(flat.unwrap_err().code, "SCALE_OUT_NO_POSITION");
+
+        let wrong_side = netting.net(
+            "AAPL",
+            OrderPurpose::ScaleOut,
+            OrderSide::Buy,
+            dec!(10),
+            None,
+            long(dec!(100)),
+        );
+        assert_eq!(wrong_side.unwrap_err().code, "SCALE_OUT_WRONG_SIDE");
+
+        let covered = netting.net(
+            "AAPL",
+            OrderPurpose::ScaleOut,
+            OrderSide::Sell,
+            dec!(10),
+            None,
+            long(dec!(100)).with_working(OrderSide::Sell, dec!(100)),
+        );
+        assert_eq!(covered.unwrap_err().code, "SCALE_OUT_NOTHING_AVAILABLE");
+    }
+
+    #[test]
+    fn scale_out_of_short_buys_back() {
+        let netting = PositionNetting::new();
+        let quantity = netting
+            .net(
+                "TSLA",
+                OrderPurpose::ScaleOut,
+                OrderSide::Buy,
+                dec!(50),
+                None,
+                long(dec!(-20)),
+            )
+            .unwrap();
+        assert_eq!(quantity, dec!(20));
+    }
+
+    #[test]
+    fn scale_in_counts_existing_position_against_caps() {
+        let netting = PositionNetting::new().with_limits(PerInstrumentLimits {
+            max_units: 500,
+            max_notional_cents: 5_000_000,
+            max_pct_equity_bps: 1000,
+        });
+        let position = long(dec!(200)).with_working(OrderSide::Buy, dec!(100));
+
+        // 200 held + 100 working + 100 = 400 units, $40,000
+        let ok = netting.net(
+            "AAPL",
+            OrderPurpose::ScaleIn,
+            OrderSide::Buy,
+            dec!(100),
+            Some(dec!(100)),
+            position,
+        );
+        assert_eq!(ok.unwrap(), dec!(100));
+
+        let units = netting.net(
+            "AAPL",
+            OrderPurpose::ScaleIn,
+            OrderSide::Buy,
+            dec!(300),
+            None,
+            position,
+        );
+        assert_eq!(units.unwrap_err().code, "SCALE_IN_UNITS_EXCEEDED");
+
+        let notional = netting.net(
+            "AAPL",
+            OrderPurpose::ScaleIn,
+            OrderSide::Buy,
+            dec!(100),
+            Some(dec!(150)),
+            position,
+        );
+        assert_eq!(notional.unwrap_err().code, "SCALE_IN_NOTIONAL_EXCEEDED");
+
+        let wrong_side = netting.net(
+            "AAPL",
+            OrderPurpose::ScaleIn,
+            OrderSide::Sell,
+            dec!(10),
+            None,
+            position,
+        );
+        assert_eq!(wrong_side.unwrap_err().code, "SCALE_IN_WRONG_SIDE");
+    }
+
+    #[test]
+    fn other_purposes_pass_through() {
+        let netting = PositionNetting::new();
+        let quantity = netting
+            .net(
+                "AAPL",
+                OrderPurpose::Exit,
+                OrderSide::Sell,
+                dec!(500),
+                None,
+                long(dec!(100)),
+            )
+            .unwrap();
+        assert_eq!(quantity, dec!(500));
+    }
+}
@@ -2,15 +2,19 @@
 //!
 //! Orchestrates all risk checks against orders.
 
+use std::collections::{HashMap, HashSet};
+
 use rust_decimal::Decimal;
 
 use crate::domain::order_execution::aggregate::Order;
 use crate::domain::order_execution::value_objects::OrderSide;
 use crate::domain::risk_management::aggregate::RiskPolicy;
 use crate::domain::risk_management::value_objects::{
-    ConstraintResult, ConstraintViolation, RiskContext,
+    ConcentrationGroups, ConstraintResult, ConstraintViolation, PositionContext, RiskContext,
 };
 
+use super::DayTradeProjection;
+
 /// Risk Validation Service - validates orders against risk constraints.
 pub struct RiskValidationService {
     policy: RiskPolicy,
@@ -44,6 +48,10 @@ impl RiskValidationService {
         let portfolio_result = self.validate_portfolio(orders, context);
         result.merge(portfolio_result);
 
+        // Sector and correlation concentration checks
+        let concentration_result = self.validate_concentration(orders, context);
+        result.merge(concentration_result);
+
         // Options Greeks checks
         let options_result = self.validate_options_greeks(orders, context);
         result.merge(options_result);
@@ -99,8 +107,9 @@ impl RiskValidationService {
         }
 
         // Check max notional (estimate)
+        let multiplier = order.symbol().multiplier();
         if let Some(limit_price) = order.limit_price() {
-            let notional = limit_price.amount() * new_qty.abs();
+            let notional = limit_price.amount() * new_qty.abs() * multiplier;
             if notional > limits.max_notional() {
                 result.add_violation(
                     ConstraintViolation::error(
@@ -122,7 +131,7 @@ impl RiskValidationService {
         if context.equity.amount() > Decimal::ZERO
             && let Some(limit_price) = order.limit_price()
         {
-            let notional = limit_price.amount() * order_qty;
+            let notional = limit_price.amount() * order_qty * multiplier;
             let pct_equity = notional / context.equity.amount();
             if pct_equity > limits.max_pct_equity() {
                 result.add_violation(
@@ -148,25 +157,33 @@ impl RiskValidationService {
         let mut result = ConstraintResult::success();
         let limits = &self.policy.limits().portfolio;
 
-        // Calculate total new notional from orders
-        let mut total_buy_notional = Decimal::ZERO;
-        let mut total_sell_notional = Decimal::ZERO;
-
+        // Calculate signed new notional per symbol from orders
+        let mut deltas: HashMap<&str, Decimal> = HashMap::new();
         for order in orders {
             if let Some(limit_price) = order.limit_price() {
-                let notional = limit_price.amount() * order.quantity().amount();
-                match order.side() {
-                    OrderSide::Buy => total_buy_notional += notional,
-                    OrderSide::Sell => total_sell_notional += notional,
-                }
+                let notional =
+                    limit_price.amount() * order.quantity().amount() * order.symbol().multiplier();
+                *deltas.entry(order.symbol().as_str()).or_default() += match order.side() {
+                    OrderSide::Buy => notional,
+                    OrderSide::Sell => -notional,
+                };
             }
         }
 
-        // Add to current exposure
-        let current_gross = context.current_exposure.gross.amount();
-        let current_net = context.current_exposure.net.amount();
-        let new_gross = current_gross + total_buy_notional + total_sell_notional;
-        let new_net = current_net + total_buy_notional - total_sell_notional;
+        // Apply as deltas against held positions, so reducing a position
+        // lowers gross exposure instead of adding to it
+        let mut gross_delta = Decimal::ZERO;
+        let mut net_delta = Decimal::ZERO;
+        for (symbol, delta) in deltas {
+            let held = context
+                .get_position(symbol)
+                .map_or(Decimal::ZERO, signed_market_value);
+            gross_delta += (held + delta).abs() - held.abs();
+            net_delta += delta;
+        }
+
+        let new_gross = context.current_exposure.gross.amount() + gross_delta;
+        let new_net = context.current_exposure.net.amount() + net_delta;
 
         // Check gross notional
         if new_gross > limits.max_gross_notional() {
@@ -218,6 +235,105 @@ impl RiskValidationService {
         result
     }
 
+    /// Validate sector and correlation bucket concentration.
+    ///
+    /// Only groups containing a symbol from `orders` are checked, and only
+    /// when the orders increase the group's gross exposure, so reducing an
+    /// already concentrated group is always allowed.
+    #[must_use]
+    pub fn validate_concentration(
+        &self,
+        orders: &[Order],
+        context: &RiskContext,
+    ) -> ConstraintResult {
+        let mut result = ConstraintResult::success();
+        let groups = &context.concentration_groups;
+        let equity = context.equity.amount();
+        if groups.is_empty() || equity <= Decimal::ZERO {
+            return result;
+        }
+        let limits = &self.policy.limits().concentration;
+
+        let current: HashMap<&str, Decimal> = context
+            .positions
+            .iter()
+            .map(|(symbol, p)| (symbol.as_str(), signed_market_value(p)))
+            .collect();
+        let mut projected = current.clone();
+        for order in orders {
+            let symbol = order.symbol().as_str();
+            // Market orders are priced at the position's mark when available;
+            // the mark comes from market value and already includes the
+            // contract multiplier
+            let price = order
+                .limit_price()
+                .map(|p| p.amount() * order.symbol().multiplier())
+                .or_else(|| context.get_position(symbol).and_then(mark_price));
+            let Some(price) = price else {
+                continue;
+            };
+            let notional = price * order.quantity().amount();
+            *projected.entry(symbol).or_default() += match order.side() {
+                OrderSide::Buy => notional,
+                OrderSide::Sell => -notional,
+            };
+        }
+
+        let checks: [(&str, &str, Decimal, GroupLookup); 2] = [
+            (
+                "SECTOR_CONCENTRATION_EXCEEDED",
+                "Sector",
+                limits.max_sector_pct_equity(),
+                ConcentrationGroups::sector,
+            ),
+            (
+                "CORRELATION_CONCENTRATION_EXCEEDED",
+                "Correlation bucket",
+                limits.max_correlation_group_pct_equity(),
+                ConcentrationGroups::correlation_group,
+            ),
+        ];
+
+        for (code, label, limit_pct, lookup) in checks {
+            let group_of = |symbol: &str| lookup(groups, symbol);
+            let touched: HashSet<&str> = orders
+                .iter()
+                .filter_map(|order| group_of(order.symbol().as_str()))
+                .collect();
+            let before = gross_by_group(&current, group_of);
+            let after = gross_by_group(&projected, group_of);
+
+            let mut breached: Vec<_> = touched
+                .into_iter()
+                .filter_map(|group| {
+                    let new_gross = after.get(group).copied().unwrap_or_default();
+                    let old_gross = before.get(group).copied().unwrap_or_default();
+                    let pct = new_gross / equity;
+                    (pct > limit_pct && new_gross > old_gross).then_some((group, pct))
+                })
+                .collect();
+            breached.sort_unstable_by_key(|(group, _)| *group);
+
+            for (group, pct) in breached {
+                result.add_violation(
+                    ConstraintViolation::error(
+                        code,
+                        format!(
+                            "{label} '{group}' exposure would exceed limit: {:.1}% > {:.1}%",
+                            pct * Decimal::from(100),
+                            limit_pct * Decimal::from(100)
+                        ),
+                    )
+                    .with_field_path(group)
+                    .with_observed(format!("{:.1}%", pct * Decimal::from(100)))
+                    .with_limit(format!("{:.1}%", limit_pct * Decimal::from(100))),
+                );
+            }
+        }
+
+        result
+    }
+
     /// Validate options Greeks constraints.
     #[must_use]
     pub fn validate_options_greeks(
@@ -294,7 +410,9 @@ impl RiskValidationService {
         for order in orders {
             if order.side() == OrderSide::Buy {
                 if let Some(limit_price) = order.limit_price() {
-                    required += limit_price.amount() * order.quantity().amount();
+                    required += limit_price.amount()
+                        * order.quantity().amount()
+                        * order.symbol().multiplier();
                 } else {
                     // For market orders, we'd need current price - skip for now
                 }
@@ -329,30 +447,37 @@ impl RiskValidationService {
             return result;
         }
 
-        // Count potential day trades
-        let mut potential_day_trades = 0u8;
-        for order in orders {
-            // A day trade is a round-trip in the same day
-            // If we have a position and are closing it, that's a day trade
-            if let Some(pos) = context.get_position(order.symbol().as_str()) {
-                let closing = match order.side() {
-                    OrderSide::Buy => pos.is_short(),
-                    OrderSide::Sell => pos.is_long(),
-                };
-                if closing {
-                    potential_day_trades += 1;
-                }
-            }
-        }
+        let projection = DayTradeProjection::project(orders, context);
+        let remaining = usize::from(context.day_trades_remaining);
 
-        if potential_day_trades > context.day_trades_remaining {
-            result.add_violation(ConstraintViolation::error(
-                "PDT_VIOLATION",
-                format!(
-                    "Would exceed day trade limit: {} day trades, {} remaining",
-                    potential_day_trades, context.day_trades_remaining
-                ),
-            ));
+        // Orders past the remaining allowance would trip PDT; the last
+        // allowed day trade is flagged so the caller can hold it back
+        for (n, trade) in projection.day_trades.iter().enumerate() {
+            let violation = if n >= remaining {
+                ConstraintViolation::error(
+                    "PDT_VIOLATION",
+                    format!(
+                        "Would exceed day trade limit: {} day trades, {} remaining",
+                        projection.count(),
+                        context.day_trades_remaining
+                    ),
+                )
+            } else if n + 1 == remaining {
+                ConstraintViolation::warning(
+                    "PDT_LAST_DAY_TRADE",
+                    "Uses the last remaining day trade",
+                )
+            } else {
+                continue;
+            };
+
+            result.add_violation(
+                violation
+                    .with_instrument(&trade.symbol)
+                    .with_field_path(format!("decisions[{}]", trade.order_index))
+                    .with_observed(projection.count().to_string())
+                    .with_limit(context.day_trades_remaining.to_string()),
+            );
         }
 
         result
@@ -370,13 +495,42 @@ impl RiskValidationService {
     }
 }
 
+/// Maps a symbol to its group within [`ConcentrationGroups`].
+type GroupLookup = for<'g> fn(&'g ConcentrationGroups, &str) -> Option<&'g str>;
+
+/// Position market value, signed by direction.
+fn signed_market_value(position: &PositionContext) -> Decimal {
+    let value = position.market_value.amount().abs();
+    if position.is_short() { -value } else { value }
+}
+
+/// Per-unit mark implied by a position's market value.
+fn mark_price(position: &PositionContext) -> Option<Decimal> {
+    let qty = position.quantity.amount().abs();
+    (!qty.is_zero()).then(|| position.market_value.amount().abs() / qty)
+}
+
+/// Gross exposure per group.
+fn gross_by_group<'a>(
+    notionals: &HashMap<&str, Decimal>,
+    group_of: impl Fn(&str) -> Option<&'a str>,
+) -> HashMap<&'a str, Decimal> {
+    let mut gross: HashMap<&str, Decimal> = HashMap::new();
+    for (symbol, notional) in notionals {
+        if let Some(group) = group_of(symbol) {
+            *gross.entry(group).or_default() += notional.abs();
+        }
+    }
+    gross
+}
+
 #[cfg(test)]
 mod tests {
     #![allow(clippy::default_trait_access)]
     use super::*;
     use crate::domain::order_execution::aggregate::{CreateOrderCommand, Order};
     use crate::domain::order_execution::value_objects::{OrderPurpose, OrderType, TimeInForce};
-    use crate::domain::risk_management::value_objects::PositionContext;
+    use crate::domain::risk_management::value_objects::{Exposure, PositionContext};
     use crate::domain::shared::{InstrumentId, Money, Quantity, Symbol};
 
     fn make_order(symbol: &str, side: OrderSide, qty: i64, price: f64) -> Order {
@@ -446,6 +600,22 @@ mod tests {
         );
     }
 
+    #[test]
+    fn validate_per_instrument_notional_counts_option_multiplier() {
+        let service = RiskValidationService::with_default_policy();
+        // 100 contracts at $6 control $60,000 > $50,000 default
+        let order = make_order("AAPL250117C00150000", OrderSide::Buy, 100, 6.0);
+        let context = make_context(1_000_000.0, 2_000_000.0);
+
+        let result = service.validate_per_instrument(&order, &context);
+        assert!(
+            result
+                .violations
+                .iter()
+                .any(|v| v.code == "PER_INSTRUMENT_NOTIONAL_EXCEEDED")
+        );
+    }
+
     #[test]
     fn validate_portfolio_gross_exceeded() {
         let service = RiskValidationService::with_default_policy();
@@ -494,7 +664,8 @@ mod tests {
                 Quantity::from_i64(100), // Long position
                 Money::usd(15000.0),
                 Money::usd(14000.0),
-            ),
+            )
+            .with_opened_today(Quantity::from_i64(100)), // Bought today
         );
 
         let result = service.validate_pdt(&[order], &context);
@@ -686,6 +857,51 @@ mod tests {
         assert!(result.passed); // No violation when not restricted
     }
 
+    #[test]
+    fn validate_pdt_allows_closing_overnight_position() {
+        let service = RiskValidationService::with_default_policy();
+        let order = make_order("AAPL", OrderSide::Sell, 100, 150.0);
+
+        let mut context = make_context(20_000.0, 40_000.0);
+        context.pdt_status = crate::domain::risk_management::value_objects::PdtStatus::Restricted;
+        context.day_trades_remaining = 0;
+        context.add_position(
+            "AAPL",
+            PositionContext::new(
+                InstrumentId::new("AAPL"),
+                Quantity::from_i64(100), // Held overnight
+                Money::usd(15000.0),
+                Money::usd(14000.0),
+            ),
+        );
+
+        let result = service.validate_pdt(&[order], &context);
+        assert!(result.passed);
+    }
+
+    #[test]
+    fn validate_pdt_projects_round_trips_in_plan() {
+        let service = RiskValidationService::with_default_policy();
+        let orders = [
+            make_order("AAPL", OrderSide::Buy, 10, 150.0),
+            make_order("AAPL", OrderSide::Sell, 10, 151.0),
+            make_order("MSFT", OrderSide::Buy, 10, 400.0),
+            make_order("MSFT", OrderSide::Sell, 10, 401.0),
+        ];
+
+        let mut context = make_context(20_000.0, 40_000.0);
+        context.pdt_status = crate::domain::risk_management::value_objects::PdtStatus::Restricted;
+        context.day_trades_remaining = 1;
+
+        let result = service.validate_pdt(&orders, &context);
+        assert!(!result.passed);
+        assert_eq!(result.warnings()[0].code, "PDT_LAST_DAY_TRADE");
+        let errors = result.errors();
+        assert_eq!(errors.len(), 1);
+        assert_eq!(errors[0].instrument_id.as_deref(), Some("MSFT"));
+        assert_eq!(errors[0].field_path.as_deref(), Some("decisions[3]"));
+    }
+
     #[test]
     fn validate_pdt_opening_trade() {
         let service = RiskValidationService::with_default_policy();
@@ -732,6 +948,50 @@ mod tests {
         assert!(result.passed);
     }
 
+    #[test]
+    fn validate_portfolio_counts_existing_exposure() {
+        let service = RiskValidationService::with_default_policy();
+        let mut context = make_context(1_000_000.0, 2_000_000.0);
+        context.current_exposure =
+            Exposure::from_long_short(Money::usd(240_000.0), Money::usd(210_000.0));
+
+        let small = make_order("AAPL", OrderSide::Buy, 100, 150.0); // $15,000
+        assert!(service.validate_portfolio(&[small], &context).passed);
+
+        let large = make_order("AAPL", OrderSide::Buy, 400, 150.0); // $60,000
+        let result = service.validate_portfolio(&[large], &context);
+        assert!(
+            result
+                .violations
+                .iter()
+                .any(|v| v.code == "PORTFOLIO_GROSS_NOTIONAL_EXCEEDED")
+        );
+    }
+
+    #[test]
+    fn validate_portfolio_reducing_position_lowers_gross() {
+        let service = RiskValidationService::with_default_policy();
+        let mut context = make_context(1_000_000.0, 2_000_000.0);
+        context.current_exposure =
+            Exposure::from_long_short(Money::usd(250_000.0), Money::usd(245_000.0));
+        context.add_position(
+            "AAPL",
+            PositionContext::new(
+                InstrumentId::new("AAPL"),
+                Quantity::from_i64(100),
+                Money::usd(15_000.0),
+                Money::usd(14_000.0),
+            ),
+        );
+
+        let sell = make_order("AAPL", OrderSide::Sell, 100, 150.0);
+        assert!(service.validate_portfolio(&[sell], &context).passed);
+
+        // Selling through the position opens a short that counts again
+        let flip = make_order("AAPL", OrderSide::Sell, 250, 150.0);
+        assert!(!service.validate_portfolio(&[flip], &context).passed);
+    }
+
     fn make_market_order(symbol: &str, side: OrderSide, qty: i64) -> Order {
         Order::new(CreateOrderCommand {
             symbol: Symbol::new(symbol),
@@ -807,4 +1067,93 @@ mod tests {
         let result = service.validate_portfolio(&[order], &context);
         assert!(result.passed);
     }
+
+    fn context_with_groups() -> RiskContext {
+        let mut context = make_context(100_000.0, 200_000.0);
+        for (symbol, sector, bucket) in [
+            ("AAPL", "Technology", "mega_cap_tech"),
+            ("MSFT", "Technology", "mega_cap_tech"),
+            ("XOM", "Energy", "oil"),
+        ] {
+            context
+                .concentration_groups
+                .sectors
+                .insert(symbol.to_string(), sector.to_string());
+            context
+                .concentration_groups
+                .correlation_groups
+                .insert(symbol.to_string(), bucket.to_string());
+        }
+        context.add_position(
+            "AAPL",
+            PositionContext::new(
+                InstrumentId::new("AAPL"),
+                Quantity::from_i64(100),
+                Money::usd(15_000.0),
+                Money::usd(14_000.0),
+            ),
+        );
+        context
+    }
+
+    #[test]
+    fn validate_concentration_sector_exceeded() {
+        let service = RiskValidationService::with_default_policy();
+        // 15k AAPL + 20k MSFT = 35% Technology and mega_cap_tech (limits 30% / 40%)
+        let order = make_order("MSFT", OrderSide::Buy, 50, 400.0);
+
+        let result = service.validate_concentration(&[order], &context_with_groups());
+        assert!(!result.passed);
+        let errors = result.errors();
+        assert_eq!(errors.len(), 1);
+        assert_eq!(errors[0].code, "SECTOR_CONCENTRATION_EXCEEDED");
+        assert_eq!(errors[0].field_path.as_deref(), Some("Technology"));
+        assert_eq!(errors[0].observed.as_deref(), Some("35.0%"));
+    }
+
+    #[test]
+    fn validate_concentration_correlation_exceeded() {
+        let service = RiskValidationService::with_default_policy();
+        let mut context = context_with_groups();
+        // Put MSFT in another sector so only the correlation bucket breaches
+        context
+            .concentration_groups
+            .sectors
+            .insert("MSFT".to_string(), "Software".to_string());
+        let order = make_order("MSFT", OrderSide::Buy, 75, 400.0);
+
+        let result = service.validate_concentration(&[order], &context);
+        let errors = result.errors();
+        assert_eq!(errors.len(), 1);
+        assert_eq!(errors[0].code, "CORRELATION_CONCENTRATION_EXCEEDED");
+        assert_eq!(errors[0].field_path.as_deref(), Some("mega_cap_tech"));
+    }
+
+    #[test]
+    fn validate_concentration_allows_reducing_and_unrelated_orders() {
+        let service = RiskValidationService::with_default_policy();
+        let mut context = context_with_groups();
+        context.add_position(
+            "MSFT",
+            PositionContext::new(
+                InstrumentId::new("MSFT"),
+                Quantity::from_i64(100),
+                Money::usd(40_000.0),
+                Money::usd(38_000.0),
+            ),
+        );
+
+        // Technology is already over its limit, but selling reduces it
+        let sell = make_market_order("MSFT", OrderSide::Sell, 10);
+        assert!(service.validate_concentration(&[sell], &context).passed);
+
+        // Orders outside the concentrated group are unaffected
+        let energy = make_order("XOM", OrderSide::Buy, 100, 110.0);
+        assert!(service.validate_concentration(&[energy], &context).passed);
+
+        // Without reference data nothing is checked
+        let buy = make_order("MSFT", OrderSide::Buy, 100, 400.0);
+        context.concentration_groups = ConcentrationGroups::default();
+        assert!(service.validate_concentration(&[buy], &context).passed);
+    }
 }
@@ -0,0 +1,53 @@
+//! Concentration groups for sector and correlation limits.
+
+use std::collections::HashMap;
+
+use serde::{Deserialize, Serialize};
+
+/// Reference data grouping symbols for concentration checks.
+///
+/// Symbols missing from a map are not counted toward any group.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ConcentrationGroups {
+    /// Symbol to sector or industry.
+    #[serde(default)]
+    pub sectors: HashMap<String, String>,
+    /// Symbol to correlation bucket (symbols that move together).
+    #[serde(default)]
+    pub correlation_groups: HashMap<String, String>,
+}
+
+impl ConcentrationGroups {
+    /// Sector of `symbol`, if known.
+    #[must_use]
+    pub fn sector(&self, symbol: &str) -> Option<&str> {
+        self.sectors.get(symbol).map(String::as_str)
+    }
+
+    /// Correlation bucket of `symbol`, if known.
+    #[must_use]
+    pub fn correlation_group(&self, symbol: &str) -> Option<&str> {
+        self.correlation_groups.get(symbol).map(String::as_str)
+    }
+
+    /// Check if no symbols are grouped.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.sectors.is_empty() && self.correlation_groups.is_empty()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_partial_mapping() {
+        let groups: ConcentrationGroups =
+            serde_json::from_str(r#"{"sectors": {"AAPL": "Technology"}}"#).unwrap();
+
+        assert_eq!(groups.sector("AAPL"), Some("Technology"));
+        assert_eq!(groups.correlation_group("AAPL"), None);
+        assert!(!groups.is_empty());
+    }
+}
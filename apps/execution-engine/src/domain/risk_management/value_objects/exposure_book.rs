@@ -0,0 +1,523 @@
+//! Exposure Book
+//!
+//! Live exposure state built from positions and working orders. The book
+//! keeps a per-symbol contribution and running long/short totals, so each
+//! position snapshot, order update or fill only recomputes the symbol it
+//! touches instead of the whole portfolio.
+//!
+//! Working orders are counted conservatively: a working buy adds to the
+//! symbol's long side and a working sell adds to its short side, but an
+//! order that would only flatten a position does not reduce exposure until
+//! it fills.
+//!
+//! Fills also move each position's average cost, so closing fills realize
+//! P&L against it.
+
+use std::collections::HashMap;
+
+use rust_decimal::Decimal;
+use rust_decimal::prelude::Signed;
+use serde::{Deserialize, Serialize};
+
+use super::{Exposure, PositionContext};
+use crate::domain::order_execution::aggregate::Order;
+use crate::domain::order_execution::value_objects::{OrderPurpose, OrderSide};
+use crate::domain::shared::{InstrumentId, Money, Quantity, Symbol};
+
+/// Position held in the book.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+struct BookPosition {
+    /// Signed quantity (negative for short).
+    quantity: Decimal,
+    /// Latest mark or fill price.
+    price: Decimal,
+    /// Average entry price.
+    cost: Decimal,
+}
+
+/// Fills of one order already applied to `positions`.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+struct AppliedFill {
+    quantity: Decimal,
+    notional: Decimal,
+}
+
+/// Unfilled remainder of an active order.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+struct WorkingOrder {
+    symbol: String,
+    side: OrderSide,
+    leaves_qty: Decimal,
+    price: Option<Decimal>,
+    #[serde(default)]
+    purpose: OrderPurpose,
+}
+
+/// Held and working quantity in one symbol.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct SymbolPosition {
+    /// Signed position (negative for short).
+    pub quantity: Decimal,
+    /// Unfilled quantity of working buys.
+    pub working_buy: Decimal,
+    /// Unfilled quantity of working sells.
+    pub working_sell: Decimal,
+}
+
+impl SymbolPosition {
+    /// Unfilled quantity working on `side`.
+    #[must_use]
+    pub const fn working(&self, side: OrderSide) -> Decimal {
+        match side {
+            OrderSide::Buy => self.working_buy,
+            OrderSide::Sell => self.working_sell,
+        }
+    }
+
+    /// Count another `quantity` working on `side`.
+    #[must_use]
+    pub fn with_working(mut self, side: OrderSide, quantity: Decimal) -> Self {
+        match side {
+            OrderSide::Buy => self.working_buy += quantity,
+            OrderSide::Sell => self.working_sell += quantity,
+        }
+        self
+    }
+}
+
+/// Cached long/short exposure of one symbol.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+struct Contribution {
+    long: Decimal,
+    short: Decimal,
+}
+
+/// Incrementally maintained portfolio exposure.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ExposureBook {
+    positions: HashMap<String, BookPosition>,
+    working: HashMap<String, WorkingOrder>,
+    /// Fills per order already applied to `positions`.
+    applied_fills: HashMap<String, AppliedFill>,
+    contributions: HashMap<String, Contribution>,
+    long: Decimal,
+    short: Decimal,
+    realized_pnl: Decimal,
+}
+
+impl ExposureBook {
+    /// Create an empty book.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Replace the position for `symbol` with a broker snapshot.
+    ///
+    /// The average cost is kept while the position stays on the same side;
+    /// otherwise it starts at `price` until [`set_cost`](Self::set_cost).
+    pub fn set_position(&mut self, symbol: &str, quantity: Decimal, price: Decimal) {
+        if quantity.is_zero() {
+            self.positions.remove(symbol);
+        } else {
+            let cost = self
+                .positions
+                .get(symbol)
+                .filter(|p| p.quantity.is_sign_positive() == quantity.is_sign_positive())
+                .map_or(price, |p| p.cost);
+            self.positions.insert(
+                symbol.to_string(),
+                BookPosition {
+                    quantity,
+                    price,
+                    cost,
+                },
+            );
+        }
+        self.refresh(symbol);
+    }
+
+    /// Set the average entry price of the position in `symbol`.
+    pub fn set_cost(&mut self, symbol: &str, cost: Decimal) {
+        if let Some(position) = self.positions.get_mut(symbol) {
+            position.cost = cost;
+        }
+    }
+
+    /// Replace all positions with a broker snapshot of
+    /// `(symbol, quantity, price)`.
+    pub fn replace_positions<'a>(
+        &mut self,
+        snapshot: impl IntoIterator<Item = (&'a str, Decimal, Decimal)>,
+    ) {
+        let mut stale: Vec<String> = self.positions.keys().cloned().collect();
+        for (symbol, quantity, price) in snapshot {
+            stale.retain(|s| s != symbol);
+            self.set_position(symbol, quantity, price);
+        }
+        for symbol in stale {
+            self.set_position(&symbol, Decimal::ZERO, Decimal::ZERO);
+        }
+    }
+
+    /// Update the mark price for `symbol`.
+    pub fn mark(&mut self, symbol: &str, price: Decimal) {
+        if let Some(position) = self.positions.get_mut(symbol) {
+            position.price = price;
+            self.refresh(symbol);
+        }
+    }
+
+    /// Apply an order's current state.
+    ///
+    /// New fills since the last sync move into the position, and the
+    /// unfilled remainder is tracked as working until the order is terminal.
+    /// Syncing the same state twice has no effect.
+    pub fn sync_order(&mut self, order: &Order) {
+        let order_id = order.id().to_string();
+        let symbol = order.symbol().as_str();
+        let fill_state = order.partial_fill();
+
+        let filled = fill_state.cum_qty().amount();
+        let applied = self
+            .applied_fills
+            .get(&order_id)
+            .copied()
+            .unwrap_or_default();
+        let new_fill = filled - applied.quantity;
+        if new_fill > Decimal::ZERO {
+            let notional: Decimal = fill_state
+                .fills()
+                .iter()
+                .map(|f| f.quantity.amount() * f.price.amount())
+                .sum();
+            let price = (notional - applied.notional) / new_fill;
+            let signed = match order.side() {
+                OrderSide::Buy => new_fill,
+                OrderSide::Sell => -new_fill,
+            };
+            self.apply_fill(symbol, signed, price);
+            self.applied_fills.insert(
+                order_id.clone(),
+                AppliedFill {
+                    quantity: filled,
+                    notional,
+                },
+            );
+        }
+
+        let leaves = fill_state.leaves_qty().amount();
+        if order.status().is_terminal() || leaves <= Decimal::ZERO {
+            self.working.remove(&order_id);
+        } else {
+            self.working.insert(
+                order_id,
+                WorkingOrder {
+                    symbol: symbol.to_string(),
+                    side: order.side(),
+                    leaves_qty: leaves,
+                    price: order.limit_price().map(|p| p.amount()),
+                    purpose: fill_state.order_purpose(),
+                },
+            );
+        }
+
+        self.refresh(symbol);
+    }
+
+    /// Stop tracking a working order the broker no longer reports.
+    pub fn remove_order(&mut self, order_id: &str) {
+        if let Some(order) = self.working.remove(order_id) {
+            self.refresh(&order.symbol);
+        }
+    }
+
+    /// P&L realized by closing fills applied to the book.
+    #[must_use]
+    pub const fn realized_pnl(&self) -> Decimal {
+        self.realized_pnl
+    }
+
+    /// Current exposure including working orders.
+    #[must_use]
+    pub fn exposure(&self) -> Exposure {
+        Exposure::from_long_short(Money::new(self.long), Money::new(self.short))
+    }
+
+    /// Held and working quantity in `symbol`.
+    ///
+    /// Stops, take-profits and bracket legs are left out of the working
+    /// quantity: they only trigger on price and are sized to the whole
+    /// position, so they do not consume what a scale-out may sell.
+    #[must_use]
+    pub fn symbol_position(&self, symbol: &str) -> SymbolPosition {
+        let quantity = self
+            .positions
+            .get(symbol)
+            .map_or(Decimal::ZERO, |p| p.quantity);
+        self.working
+            .values()
+            .filter(|o| {
+                o.symbol == symbol
+                    && !matches!(
+                        o.purpose,
+                        OrderPurpose::StopLoss
+                            | OrderPurpose::TakeProfit
+                            | OrderPurpose::BracketLeg
+                    )
+            })
+            .fold(
+                SymbolPosition {
+                    quantity,
+                    ..SymbolPosition::default()
+                },
+                |position, o| position.with_working(o.side, o.leaves_qty),
+            )
+    }
+
+    /// IDs of working orders in `symbol` or in options on it, sorted.
+    #[must_use]
+    pub fn working_order_ids(&self, symbol: &str) -> Vec<String> {
+        let mut ids: Vec<String> = self
+            .working
+            .iter()
+            .filter(|(_, o)| {
+                o.symbol == symbol || Symbol::new(o.symbol.as_str()).underlying().as_str() == symbol
+            })
+            .map(|(id, _)| id.clone())
+            .collect();
+        ids.sort();
+        ids
+    }
+
+    /// Positions as risk context entries, valued at their latest price.
+    #[must_use]
+    pub fn position_contexts(&self) -> Vec<PositionContext> {
+        self.positions
+            .iter()
+            .map(|(symbol, position)| {
+                let value = Money::new(
+                    position.quantity * position.price * Symbol::new(symbol).multiplier(),
+                );
+                PositionContext::new(
+                    InstrumentId::new(symbol),
+                    Quantity::new(position.quantity),
+                    value,
+                    value,
+                )
+            })
+            .collect()
+    }
+
+    /// Move a signed fill into the position, realizing P&L on the part that
+    /// closes it.
+    fn apply_fill(&mut self, symbol: &str, signed_qty: Decimal, price: Decimal) {
+        let position = self.positions.entry(symbol.to_string()).or_default();
+        let held = position.quantity;
+
+        if held.is_zero() || held.is_sign_positive() == signed_qty.is_sign_positive() {
+            let total = held.abs() + signed_qty.abs();
+            position.cost = (held.abs() * position.cost + signed_qty.abs() * price) / total;
+        } else {
+            let closed = signed_qty.abs().min(held.abs());
+            self.realized_pnl +=
+                closed * (price - position.cost) * held.signum() * Symbol::new(symbol).multiplier();
+            if signed_qty.abs() > held.abs() {
+                // Flipped sides: the remainder opens at the fill price
+                position.cost = price;
+            }
+        }
+
+        position.quantity += signed_qty;
+        position.price = price;
+        if position.quantity.is_zero() {
+            self.positions.remove(symbol);
+        }
+    }
+
+    /// Recompute the contribution of `symbol` and adjust the totals.
+    fn refresh(&mut self, symbol: &str) {
+        let previous = self.contributions.remove(symbol).unwrap_or_default();
+        self.long -= previous.long;
+        self.short -= previous.short;
+
+        let position = self.positions.get(symbol).copied().unwrap_or_default();
+        let multiplier = Symbol::new(symbol).multiplier();
+        let position_value = position.quantity * position.price * multiplier;
+        let (mut buys, mut sells) = (Decimal::ZERO, Decimal::ZERO);
+        for order in self.working.values().filter(|o| o.symbol == symbol) {
+            // Market orders are valued at the position's mark
+            let price = order.price.unwrap_or(position.price) * multiplier;
+            match order.side {
+                OrderSide::Buy => buys += order.leaves_qty * price,
+                OrderSide::Sell => sells += order.leaves_qty * price,
+            }
+        }
+
+        let contribution = Contribution {
+            long: (position_value + buys).max(Decimal::ZERO),
+            short: (sells - position_value).max(Decimal::ZERO),
+        };
+        if contribution != Contribution::default() {
+            self.long += contribution.long;
+            self.short += contribution.short;
+            self.contributions.insert(symbol.to_string(), contribution);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::order_execution::aggregate::CreateOrderCommand;
+    use crate::domain::order_execution::value_objects::{FillReport, OrderType, TimeInForce};
+    use crate::domain::shared::{BrokerId, Symbol, Timestamp};
+    use rust_decimal_macros::dec;
+
+    fn limit_order(symbol: &str, side: OrderSide, qty: i64, price: Decimal) -> Order {
+        let mut order = Order::new(CreateOrderCommand {
+            symbol: Symbol::new(symbol),
+            side,
+            order_type: OrderType::Limit,
+            quantity: Quantity::from_i64(qty),
+            limit_price: Some(Money::new(price)),
+            stop_price: None,
+            time_in_force: TimeInForce::Day,
+            purpose: OrderPurpose::Entry,
+            legs: vec![],
+        })
+        .unwrap();
+        order.accept(BrokerId::new("broker-1")).unwrap();
+        order
+    }
+
+    fn fill(order: &mut Order, qty: i64, price: Decimal) {
+        order
+            .apply_fill(FillReport::new(
+                format!("fill-{qty}"),
+                Quantity::from_i64(qty),
+                Money::new(price),
+                Timestamp::now(),
+                "NYSE",
+            ))
+            .unwrap();
+    }
+
+    #[test]
+    fn positions_and_working_orders_both_count() {
+        let mut book = ExposureBook::new();
+        book.set_position("AAPL", dec!(100), dec!(150));
+        book.set_position("TSLA", dec!(-10), dec!(200));
+        book.sync_order(&limit_order("MSFT", OrderSide::Buy, 10, dec!(400)));
+
+        let exposure = book.exposure();
+        assert_eq!(exposure.long.amount(), dec!(19000));
+        assert_eq!(exposure.short.amount(), dec!(2000));
+        assert_eq!(exposure.gross.amount(), dec!(21000));
+        assert_eq!(exposure.net.amount(), dec!(17000));
+    }
+
+    #[test]
+    fn options_are_valued_per_contract() {
+        let mut book = ExposureBook::new();
+        book.set_position("AAPL250117C00150000", dec!(2), dec!(3.50));
+        book.sync_order(&limit_order(
+            "AAPL250117P00140000",
+            OrderSide::Buy,
+            1,
+            dec!(1.20),
+        ));
+
+        assert_eq!(book.exposure().long.amount(), dec!(820));
+        assert_eq!(book.position_contexts()[0].market_value.amount(), dec!(700));
+    }
+
+    #[test]
+    fn fills_move_from_working_to_position_once() {
+        let mut book = ExposureBook::new();
+        let mut order = limit_order("AAPL", OrderSide::Buy, 100, dec!(150));
+        book.sync_order(&order);
+        assert_eq!(book.exposure().gross.amount(), dec!(15000));
+
+        fill(&mut order, 40, dec!(149));
+        book.sync_order(&order);
+        book.sync_order(&order);
+        // 40 filled at 149 plus 60 working at 150
+        assert_eq!(book.exposure().gross.amount(), dec!(14960));
+        assert_eq!(book.position_contexts()[0].quantity, Quantity::from_i64(40));
+
+        fill(&mut order, 60, dec!(151));
+        book.sync_order(&order);
+        assert_eq!(book.exposure().gross.amount(), dec!(15100));
+    }
+
+    #[test]
+    fn closing_order_counts_only_after_fill() {
+        let mut book = ExposureBook::new();
+        book.set_position("AAPL", dec!(100), dec!(150));
+        let mut order = limit_order("AAPL", OrderSide::Sell, 100, dec!(150));
+
+        book.sync_order(&order);
+        assert_eq!(book.exposure().gross.amount(), dec!(15000));
+
+        fill(&mut order, 100, dec!(150));
+        book.sync_order(&order);
+        assert_eq!(book.exposure(), Exposure::default());
+        assert!(book.position_contexts().is_empty());
+    }
+
+    #[test]
+    fn closing_fills_realize_against_average_cost() {
+        let mut book = ExposureBook::new();
+        book.set_position("AAPL", dec!(100), dec!(155));
+        book.set_cost("AAPL", dec!(150));
+
+        let mut add = limit_order("AAPL", OrderSide::Buy, 100, dec!(160));
+        fill(&mut add, 100, dec!(160));
+        book.sync_order(&add);
+        assert_eq!(book.realized_pnl(), Decimal::ZERO);
+
+        // Average cost is now 155; selling 150 at 165 realizes 1500
+        let mut close = limit_order("AAPL", OrderSide::Sell, 150, dec!(165));
+        fill(&mut close, 150, dec!(165));
+        book.sync_order(&close);
+        book.sync_order(&close);
+
+        assert_eq!(book.realized_pnl(), dec!(1500));
+        assert_eq!(book.position_contexts()[0].quantity, Quantity::from_i64(50));
+    }
+
+    #[test]
+    fn symbol_position_ignores_protective_orders() {
+        let mut book = ExposureBook::new();
+        book.set_position("AAPL", dec!(100), dec!(150));
+        book.sync_order(&limit_order("AAPL", OrderSide::Sell, 30, dec!(160)));
+        book.sync_order(&limit_order("MSFT", OrderSide::Sell, 10, dec!(400)));
+
+        let mut stop = Order::new(CreateOrderCommand {
+            symbol: Symbol::new("AAPL"),
+            side: OrderSide::Sell,
+            order_type: OrderType::Stop,
+            quantity: Quantity::from_i64(100),
+            limit_price: None,
+            stop_price: Some(Money::new(dec!(140))),
+            time_in_force: TimeInForce::Gtc,
+            purpose: OrderPurpose::StopLoss,
+            legs: vec![],
+        })
+        .unwrap();
+        stop.accept(BrokerId::new("broker-2")).unwrap();
+        book.sync_order(&stop);
+
+        let position = book.symbol_position("AAPL");
+        assert_eq!(position.quantity, dec!(100));
+        assert_eq!(position.working_sell, dec!(30));
+        assert_eq!(position.working_buy, Decimal::ZERO);
+        assert_eq!(
+            position
+                .with_working(OrderSide::Sell, dec!(20))
+                .working(OrderSide::Sell),
+            dec!(50)
+        );
+    }
+}
@@ -162,6 +162,38 @@ impl SizingLimits {
     }
 }
 
+/// Sector and correlation concentration limits.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ConcentrationLimits {
+    /// Maximum gross exposure per sector as % of equity (basis points).
+    pub max_sector_pct_equity_bps: u32,
+    /// Maximum gross exposure per correlation bucket as % of equity (basis points).
+    pub max_correlation_group_pct_equity_bps: u32,
+}
+
+impl Default for ConcentrationLimits {
+    fn default() -> Self {
+        Self {
+            max_sector_pct_equity_bps: 3000,            // 30%
+            max_correlation_group_pct_equity_bps: 4000, // 40%
+        }
+    }
+}
+
+impl ConcentrationLimits {
+    /// Get max sector percent of equity as Decimal.
+    #[must_use]
+    pub fn max_sector_pct_equity(&self) -> Decimal {
+        Decimal::new(i64::from(self.max_sector_pct_equity_bps), 4)
+    }
+
+    /// Get max correlation bucket percent of equity as Decimal.
+    #[must_use]
+    pub fn max_correlation_group_pct_equity(&self) -> Decimal {
+        Decimal::new(i64::from(self.max_correlation_group_pct_equity_bps), 4)
+    }
+}
+
 /// Complete exposure limits configuration.
 #[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
 pub struct ExposureLimits {
@@ -173,6 +205,9 @@ pub struct ExposureLimits {
     pub options: OptionsLimits,
     /// Sizing sanity limits.
     pub sizing: SizingLimits,
+    /// Sector and correlation concentration limits.
+    #[serde(default)]
+    pub concentration: ConcentrationLimits,
 }
 
 #[cfg(test)]
@@ -211,6 +246,16 @@ mod tests {
         assert_eq!(limits.sanity_threshold_multiplier(), Decimal::new(30, 1)); // 3.0
     }
 
+    #[test]
+    fn concentration_limits_default() {
+        let limits = ConcentrationLimits::default();
+        assert_eq!(limits.max_sector_pct_equity(), Decimal::new(3000, 4)); // 30%
+        assert_eq!(
+            limits.max_correlation_group_pct_equity(),
+            Decimal::new(4000, 4)
+        ); // 40%
+    }
+
     #[test]
     fn exposure_limits_default() {
         let limits = ExposureLimits::default();
@@ -0,0 +1,274 @@
+//! Instrument Universe
+//!
+//! Instruments the engine may trade. An allowlist, when set, names every
+//! tradable instrument; a blocklist takes instruments out. Both match exact
+//! symbols, `*`/`?` wildcard patterns and asset classes. An option matches a
+//! symbol or pattern through its own OCC symbol or its underlying, so
+//! blocking `GME` also blocks GME options.
+
+use std::fmt;
+
+use serde::{Deserialize, Serialize};
+
+use super::ConstraintViolation;
+use crate::domain::shared::Symbol;
+
+/// Violation code for an instrument outside the allowlist.
+pub const SYMBOL_NOT_ALLOWED: &str = "SYMBOL_NOT_ALLOWED";
+
+/// Violation code for an instrument on the blocklist.
+pub const SYMBOL_BLOCKED: &str = "SYMBOL_BLOCKED";
+
+/// Broad class of an instrument.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum AssetClass {
+    /// Stocks and ETFs.
+    Equity,
+    /// Listed options.
+    Option,
+}
+
+impl AssetClass {
+    /// Class of `symbol`.
+    #[must_use]
+    pub fn of(symbol: &Symbol) -> Self {
+        if symbol.is_option() {
+            Self::Option
+        } else {
+            Self::Equity
+        }
+    }
+}
+
+impl fmt::Display for AssetClass {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Equity => write!(f, "EQUITY"),
+            Self::Option => write!(f, "OPTION"),
+        }
+    }
+}
+
+/// Instruments named by symbol, pattern or asset class.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct InstrumentFilter {
+    /// Exact symbols, normalized like order symbols (`brk-b` is `BRK.B`).
+    #[serde(default)]
+    pub symbols: Vec<String>,
+    /// Wildcard patterns: `*` matches any run of characters, `?` one.
+    #[serde(default)]
+    pub patterns: Vec<String>,
+    /// Asset classes.
+    #[serde(default)]
+    pub asset_classes: Vec<AssetClass>,
+}
+
+impl InstrumentFilter {
+    /// Check if the filter names nothing.
+    #[must_use]
+    pub const fn is_empty(&self) -> bool {
+        self.symbols.is_empty() && self.patterns.is_empty() && self.asset_classes.is_empty()
+    }
+
+    /// Symbol or pattern entry matching `symbol` or its underlying.
+    fn matching_name(&self, symbol: &Symbol) -> Option<&str> {
+        let underlying = symbol.underlying();
+        let names = [symbol.as_str(), underlying.as_str()];
+        let exact = self
+            .symbols
+            .iter()
+            .find(|entry| names.contains(&Symbol::new(entry.as_str()).as_str()));
+        let pattern = || {
+            self.patterns
+                .iter()
+                .find(|pattern| names.iter().any(|name| wildcard_match(pattern, name)))
+        };
+        exact.or_else(pattern).map(String::as_str)
+    }
+
+    const fn has_names(&self) -> bool {
+        !self.symbols.is_empty() || !self.patterns.is_empty()
+    }
+}
+
+/// Allowlist and blocklist of tradable instruments.
+///
+/// An empty allowlist allows everything. Within the allowlist, an
+/// instrument must match a symbol or pattern (when any are listed) and be
+/// of a listed asset class (when any are listed). The blocklist rejects an
+/// instrument that matches any of its entries, and wins over the allowlist.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct InstrumentUniverse {
+    /// Instruments that may be traded.
+    #[serde(default)]
+    pub allow: InstrumentFilter,
+    /// Instruments that may not be traded.
+    #[serde(default)]
+    pub block: InstrumentFilter,
+}
+
+impl InstrumentUniverse {
+    /// Check if every instrument may be traded.
+    #[must_use]
+    pub const fn is_unrestricted(&self) -> bool {
+        self.allow.is_empty() && self.block.is_empty()
+    }
+
+    /// Check that `symbol` may be traded.
+    ///
+    /// Returns a [`SYMBOL_BLOCKED`] violation for a blocklisted instrument
+    /// and a [`SYMBOL_NOT_ALLOWED`] violation for one outside the allowlist.
+    #[must_use]
+    pub fn check(&self, symbol: &Symbol) -> Option<ConstraintViolation> {
+        let class = AssetClass::of(symbol);
+
+        if let Some(entry) = self.block.matching_name(symbol) {
+            return Some(
+                ConstraintViolation::error(
+                    SYMBOL_BLOCKED,
+                    format!("{symbol} is blocked by instrument blocklist entry {entry}"),
+                )
+                .with_instrument(symbol.as_str())
+                .with_limit(entry),
+            );
+        }
+        if self.block.asset_classes.contains(&class) {
+            return Some(
+                ConstraintViolation::error(
+                    SYMBOL_BLOCKED,
+                    format!("{symbol} is blocked: {class} instruments are on the blocklist"),
+                )
+                .with_instrument(symbol.as_str())
+                .with_observed(class.to_string()),
+            );
+        }
+
+        if self.allow.has_names() && self.allow.matching_name(symbol).is_none() {
+            return Some(
+                ConstraintViolation::error(
+                    SYMBOL_NOT_ALLOWED,
+                    format!("{symbol} is not on the instrument allowlist"),
+                )
+                .with_instrument(symbol.as_str()),
+            );
+        }
+        if !self.allow.asset_classes.is_empty() && !self.allow.asset_classes.contains(&class) {
+            let allowed: Vec<String> = self
+                .allow
+                .asset_classes
+                .iter()
+                .map(ToString::to_string)
+                .collect();
+            return Some(
+                ConstraintViolation::error(
+                    SYMBOL_NOT_ALLOWED,
+                    format!("{symbol} is an {class} instrument, which the allowlist excludes"),
+                )
+                .with_instrument(symbol.as_str())
+                .with_observed(class.to_string())
+                .with_limit(allowed.join(",")),
+            );
+        }
+
+        None
+    }
+}
+
+/// Match `text` against a `*`/`?` wildcard `pattern`, ignoring ASCII case.
+fn wildcard_match(pattern: &str, text: &str) -> bool {
+    let pattern = pattern.as_bytes();
+    let text = text.as_bytes();
+    let (mut p, mut t) = (0, 0);
+    // Position after the last `*` and the text position it was tried at
+    let mut backtrack = None;
+
+    while t < text.len() {
+        match pattern.get(p) {
+            Some(b'*') => {
+                p += 1;
+                backtrack = Some((p, t));
+            }
+            Some(&c) if c == b'?' || c.eq_ignore_ascii_case(&text[t]) => {
+                p += 1;
+                t += 1;
+            }
+            _ => match backtrack {
+                Some((star_p, star_t)) => {
+                    p = star_p;
+                    t = star_t + 1;
+                    backtrack = Some((star_p, star_t + 1));
+                }
+                None => return false,
+            },
+        }
+    }
+    pattern[p..].iter().all(|&c| c == b'*')
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn universe(json: &str) -> InstrumentUniverse {
+        serde_json::from_str(json).unwrap()
+    }
+
+    fn code(universe: &InstrumentUniverse, symbol: &str) -> Option<String> {
+        universe.check(&Symbol::new(symbol)).map(|v| v.code)
+    }
+
+    #[test]
+    fn wildcards_match_runs_and_single_characters() {
+        assert!(wildcard_match("SPY", "SPY"));
+        assert!(wildcard_match("SP*", "SPY"));
+        assert!(wildcard_match("*", ""));
+        assert!(wildcard_match("q?q", "QQQ"));
+        assert!(wildcard_match("*X*L", "SOXXL"));
+        assert!(!wildcard_match("SP?", "SP"));
+        assert!(!wildcard_match("SPY", "SPYG"));
+        assert!(!wildcard_match("*Q", "QQQA"));
+    }
+
+    #[test]
+    fn empty_universe_allows_everything() {
+        let universe = InstrumentUniverse::default();
+
+        assert!(universe.is_unrestricted());
+        assert_eq!(code(&universe, "AAPL"), None);
+        assert_eq!(code(&universe, "AAPL250117C00150000"), None);
+    }
+
+    #[test]
+    fn allowlist_restricts_names_and_asset_classes() {
+        let universe = universe(
+            r#"{"allow": {"symbols": ["spy", "brk-b"], "patterns": ["XL?"], "asset_classes": ["EQUITY"]}}"#,
+        );
+
+        assert_eq!(code(&universe, "SPY"), None);
+        assert_eq!(code(&universe, "BRK.B"), None);
+        assert_eq!(code(&universe, "XLF"), None);
+        assert_eq!(code(&universe, "SPYG").as_deref(), Some(SYMBOL_NOT_ALLOWED));
+        // SPY options match through the underlying but are not equities
+        let violation = universe.check(&Symbol::new("SPY250117C00450000")).unwrap();
+        assert_eq!(violation.code, SYMBOL_NOT_ALLOWED);
+        assert_eq!(violation.observed.as_deref(), Some("OPTION"));
+    }
+
+    #[test]
+    fn blocklist_wins_over_allowlist() {
+        let universe = universe(
+            r#"{"allow": {"patterns": ["*"]}, "block": {"symbols": ["GME"], "patterns": ["*3X"], "asset_classes": ["OPTION"]}}"#,
+        );
+
+        assert_eq!(code(&universe, "AAPL"), None);
+        let violation = universe.check(&Symbol::new("GME")).unwrap();
+        assert_eq!(violation.code, SYMBOL_BLOCKED);
+        assert_eq!(violation.limit.as_deref(), Some("GME"));
+        assert_eq!(code(&universe, "TQQ3X").as_deref(), Some(SYMBOL_BLOCKED));
+        assert_eq!(
+            code(&universe, "AAPL250117C00150000").as_deref(),
+            Some(SYMBOL_BLOCKED)
+        );
+    }
+}
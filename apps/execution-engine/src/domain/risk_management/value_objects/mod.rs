@@ -1,15 +1,30 @@
 //! Risk Management Value Objects
 
+mod concentration_groups;
 mod constraint_result;
 mod exposure;
+mod exposure_book;
 mod exposure_limits;
 mod greeks;
+mod instrument_universe;
+mod price_sanity;
 mod risk_context;
+mod tick_size;
+mod universe_membership;
 
+pub use concentration_groups::ConcentrationGroups;
 pub use constraint_result::{ConstraintResult, ConstraintViolation, ViolationSeverity};
 pub use exposure::Exposure;
+pub use exposure_book::{ExposureBook, SymbolPosition};
 pub use exposure_limits::{
-    ExposureLimits, OptionsLimits, PerInstrumentLimits, PortfolioLimits, SizingLimits,
+    ConcentrationLimits, ExposureLimits, OptionsLimits, PerInstrumentLimits, PortfolioLimits,
+    SizingLimits,
 };
 pub use greeks::Greeks;
+pub use instrument_universe::{
+    AssetClass, InstrumentFilter, InstrumentUniverse, SYMBOL_BLOCKED, SYMBOL_NOT_ALLOWED,
+};
+pub use price_sanity::{PRICE_SANITY, PriceSanityLimits};
 pub use risk_context::{PdtStatus, PendingOrderContext, PositionContext, RiskContext};
+pub use tick_size::{INVALID_LOT_SIZE, OFF_TICK_PRICE, OffTickAction, TickSizeRules};
+pub use universe_membership::{MembershipPeriod, SYMBOL_NOT_IN_UNIVERSE, UniverseMembership};
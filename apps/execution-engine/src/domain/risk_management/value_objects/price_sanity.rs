@@ -0,0 +1,147 @@
+//! Price Sanity Limits
+//!
+//! Fat-finger protection: how far an order's limit price may sit from the
+//! market before it is rejected, by asset class, and where a stop price on
+//! the wrong side of the market is moved to.
+
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+
+use super::ConstraintViolation;
+use crate::domain::order_execution::value_objects::OrderSide;
+use crate::domain::shared::Symbol;
+
+/// Violation code for a limit price too far from the market.
+pub const PRICE_SANITY: &str = "PRICE_SANITY";
+
+/// Distance a clamped stop is placed past the touch.
+const STOP_CLAMP_OFFSET: Decimal = Decimal::from_parts(1, 0, 0, false, 2);
+
+/// Largest distance between a limit price and the market, by asset class.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PriceSanityLimits {
+    /// Equities, in percent of the reference price; zero disables the check.
+    pub equity_max_deviation_pct: Decimal,
+    /// Options, in percent of the reference price; zero disables the check.
+    pub option_max_deviation_pct: Decimal,
+}
+
+impl Default for PriceSanityLimits {
+    fn default() -> Self {
+        Self {
+            equity_max_deviation_pct: Decimal::from(10),
+            option_max_deviation_pct: Decimal::from(50),
+        }
+    }
+}
+
+impl PriceSanityLimits {
+    /// Largest deviation allowed for `symbol`, in percent.
+    #[must_use]
+    pub fn max_deviation_pct(&self, symbol: &Symbol) -> Decimal {
+        if symbol.is_option() {
+            self.option_max_deviation_pct
+        } else {
+            self.equity_max_deviation_pct
+        }
+    }
+
+    /// Check a limit price against the market's `reference` price.
+    ///
+    /// Returns a [`PRICE_SANITY`] violation if the limit is further from the
+    /// reference than the asset class allows, in either direction.
+    #[must_use]
+    pub fn check_limit(
+        &self,
+        symbol: &Symbol,
+        limit_price: Decimal,
+        reference: Decimal,
+    ) -> Option<ConstraintViolation> {
+        let max = self.max_deviation_pct(symbol);
+        if max.is_zero() || reference <= Decimal::ZERO {
+            return None;
+        }
+        let deviation = ((limit_price - reference).abs() / reference) * Decimal::ONE_HUNDRED;
+        (deviation > max).then(|| {
+            ConstraintViolation::error(
+                PRICE_SANITY,
+                format!(
+                    "{symbol} limit {limit_price} is {}% from the market {reference}, above {max}%",
+                    deviation.round_dp(1)
+                ),
+            )
+            .with_instrument(symbol.as_str())
+            .with_observed(deviation.round_dp(2).to_string())
+            .with_limit(max.to_string())
+        })
+    }
+
+    /// Move a stop price that is on the wrong side of the market.
+    ///
+    /// A sell stop at or above the bid, or a buy stop at or below the ask,
+    /// would trigger on arrival. It is moved one cent past the touch
+    /// instead. Returns `None` if the stop is already on the right side.
+    #[must_use]
+    pub fn clamp_stop(
+        side: OrderSide,
+        stop_price: Decimal,
+        bid: Decimal,
+        ask: Decimal,
+    ) -> Option<Decimal> {
+        match side {
+            OrderSide::Sell if bid > STOP_CLAMP_OFFSET && stop_price >= bid => {
+                Some(bid - STOP_CLAMP_OFFSET)
+            }
+            OrderSide::Buy if ask > Decimal::ZERO && stop_price <= ask => {
+                Some(ask + STOP_CLAMP_OFFSET)
+            }
+            _ => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rust_decimal_macros::dec;
+
+    #[test]
+    fn limit_prices_far_from_the_market_are_rejected() {
+        let limits = PriceSanityLimits::default();
+        let equity = Symbol::new("AAPL");
+
+        assert!(limits.check_limit(&equity, dec!(109), dec!(100)).is_none());
+        assert!(limits.check_limit(&equity, dec!(91), dec!(100)).is_none());
+
+        let violation = limits.check_limit(&equity, dec!(1000), dec!(100)).unwrap();
+        assert_eq!(violation.code, PRICE_SANITY);
+        assert_eq!(violation.observed.as_deref(), Some("900"));
+        assert!(limits.check_limit(&equity, dec!(80), dec!(100)).is_some());
+
+        // Options tolerate a wider band
+        let option = Symbol::new("AAPL250117C00150000");
+        assert!(limits.check_limit(&option, dec!(1.40), dec!(1)).is_none());
+        assert!(limits.check_limit(&option, dec!(2), dec!(1)).is_some());
+
+        let disabled = PriceSanityLimits {
+            equity_max_deviation_pct: Decimal::ZERO,
+            ..limits
+        };
+        assert!(
+            disabled
+                .check_limit(&equity, dec!(1000), dec!(100))
+                .is_none()
+        );
+    }
+
+    #[test]
+    fn stops_on_the_wrong_side_are_clamped() {
+        let clamp =
+            |side, stop| PriceSanityLimits::clamp_stop(side, stop, dec!(99.90), dec!(100.10));
+
+        assert_eq!(clamp(OrderSide::Sell, dec!(95)), None);
+        assert_eq!(clamp(OrderSide::Sell, dec!(101)), Some(dec!(99.89)));
+        assert_eq!(clamp(OrderSide::Buy, dec!(105)), None);
+        assert_eq!(clamp(OrderSide::Buy, dec!(99)), Some(dec!(100.11)));
+    }
+}
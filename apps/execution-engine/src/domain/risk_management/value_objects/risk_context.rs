@@ -4,7 +4,7 @@ use std::collections::HashMap;
 
 use serde::{Deserialize, Serialize};
 
-use super::{Exposure, Greeks};
+use super::{ConcentrationGroups, Exposure, Greeks};
 use crate::domain::shared::{InstrumentId, Money, Quantity};
 
 /// Context for risk validation.
@@ -28,6 +28,9 @@ pub struct RiskContext {
     pub pdt_status: PdtStatus,
     /// Day trades remaining (if PDT restricted).
     pub day_trades_remaining: u8,
+    /// Sector and correlation groupings for concentration checks.
+    #[serde(default)]
+    pub concentration_groups: ConcentrationGroups,
 }
 
 impl RiskContext {
@@ -44,6 +47,7 @@ impl RiskContext {
             pending_orders: HashMap::new(),
             pdt_status: PdtStatus::NotApplicable,
             day_trades_remaining: 0,
+            concentration_groups: ConcentrationGroups::default(),
         }
     }
 
@@ -67,6 +71,26 @@ impl RiskContext {
         self.positions.get(instrument_id)
     }
 
+    /// Exposure of the positions in this context.
+    ///
+    /// Used when positions are supplied by the caller rather than read from
+    /// a live exposure cache.
+    #[must_use]
+    pub fn position_exposure(&self) -> Exposure {
+        let (long, short) =
+            self.positions
+                .values()
+                .fold((Money::ZERO, Money::ZERO), |(long, short), p| {
+                    let value = Money::new(p.market_value.amount().abs());
+                    if p.is_short() {
+                        (long, short + value)
+                    } else {
+                        (long + value, short)
+                    }
+                });
+        Exposure::from_long_short(long, short)
+    }
+
     /// Calculate total pending notional.
     #[must_use]
     pub fn total_pending_notional(&self) -> Money {
@@ -91,6 +115,10 @@ pub struct PositionContext {
     pub unrealized_pnl: Money,
     /// Greeks (for options).
     pub greeks: Option<Greeks>,
+    /// Portion of `quantity` opened during the current session (same sign).
+    /// Closing it today counts as a day trade.
+    #[serde(default)]
+    pub opened_today: Quantity,
 }
 
 impl PositionContext {
@@ -110,6 +138,7 @@ impl PositionContext {
             cost_basis,
             unrealized_pnl,
             greeks: None,
+            opened_today: Quantity::ZERO,
         }
     }
 
@@ -120,6 +149,13 @@ impl PositionContext {
         self
     }
 
+    /// Record how much of the position was opened today.
+    #[must_use]
+    pub const fn with_opened_today(mut self, opened_today: Quantity) -> Self {
+        self.opened_today = opened_today;
+        self
+    }
+
     /// Check if this is a long position.
     #[must_use]
     pub fn is_long(&self) -> bool {
@@ -195,6 +231,33 @@ mod tests {
         assert_eq!(ctx.positions.len(), 1);
     }
 
+    #[test]
+    fn risk_context_position_exposure() {
+        let mut ctx = RiskContext::new(Money::usd(100_000.0), Money::usd(200_000.0));
+        ctx.add_position(
+            "AAPL",
+            PositionContext::new(
+                InstrumentId::new("AAPL"),
+                Quantity::from_i64(100),
+                Money::usd(15_000.0),
+                Money::usd(14_000.0),
+            ),
+        );
+        ctx.add_position(
+            "TSLA",
+            PositionContext::new(
+                InstrumentId::new("TSLA"),
+                Quantity::from_i64(-10),
+                Money::usd(-2_000.0),
+                Money::usd(-2_100.0),
+            ),
+        );
+
+        let exposure = ctx.position_exposure();
+        assert_eq!(exposure.gross, Money::usd(17_000.0));
+        assert_eq!(exposure.net, Money::usd(13_000.0));
+    }
+
     #[test]
     fn position_context_unrealized_pnl() {
         let pos = PositionContext::new(
@@ -0,0 +1,254 @@
+//! Tick Size Rules
+//!
+//! Price increments and lot sizes the venues accept. Equities quote in
+//! pennies, or hundredths of a cent under $1; standard options quote in
+//! nickels under $3 and dimes above. Options trade in whole contracts.
+
+use rust_decimal::{Decimal, RoundingStrategy};
+use serde::{Deserialize, Serialize};
+
+use super::ConstraintViolation;
+use crate::domain::order_execution::value_objects::OrderSide;
+use crate::domain::shared::Symbol;
+
+/// Violation code for a price off the instrument's tick grid.
+pub const OFF_TICK_PRICE: &str = "OFF_TICK_PRICE";
+
+/// Violation code for a quantity that is not a whole lot.
+pub const INVALID_LOT_SIZE: &str = "INVALID_LOT_SIZE";
+
+/// Options at or above this premium quote in dimes instead of nickels.
+const OPTION_DIME_THRESHOLD: Decimal = Decimal::from_parts(3, 0, 0, false, 0);
+
+/// What to do with a price off the tick grid.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum OffTickAction {
+    /// Round onto the grid.
+    #[default]
+    Round,
+    /// Reject the order with [`OFF_TICK_PRICE`].
+    Reject,
+}
+
+/// Tick and lot size enforcement.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TickSizeRules {
+    /// What to do with an off-tick price.
+    pub off_tick: OffTickAction,
+}
+
+impl TickSizeRules {
+    /// Create rules that handle off-tick prices with `off_tick`.
+    #[must_use]
+    pub const fn new(off_tick: OffTickAction) -> Self {
+        Self { off_tick }
+    }
+
+    /// Smallest price increment of `symbol` at `price`.
+    #[must_use]
+    pub fn tick_size(symbol: &Symbol, price: Decimal) -> Decimal {
+        match (
+            symbol.is_option(),
+            price < OPTION_DIME_THRESHOLD,
+            price < Decimal::ONE,
+        ) {
+            (true, true, _) => Decimal::new(5, 2),
+            (true, false, _) => Decimal::new(10, 2),
+            (false, _, true) => Decimal::new(1, 4),
+            (false, _, false) => Decimal::new(1, 2),
+        }
+    }
+
+    /// Put a limit price on the tick grid.
+    ///
+    /// Rounds toward the passive side, so a buy never pays more and a sell
+    /// never receives less than asked.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`OFF_TICK_PRICE`] violation if the price is off the grid
+    /// and off-tick prices are rejected, or if it is a buy below one tick.
+    pub fn conform_limit(
+        &self,
+        symbol: &Symbol,
+        side: OrderSide,
+        price: Decimal,
+    ) -> Result<Decimal, Box<ConstraintViolation>> {
+        let toward_zero = side == OrderSide::Buy;
+        self.conform(symbol, "limit_price", price, toward_zero)
+    }
+
+    /// Put a stop price on the tick grid.
+    ///
+    /// Rounds away from the market, so a stop never triggers earlier than
+    /// asked.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`OFF_TICK_PRICE`] violation if the price is off the grid
+    /// and off-tick prices are rejected, or if it is a sell below one tick.
+    pub fn conform_stop(
+        &self,
+        symbol: &Symbol,
+        side: OrderSide,
+        price: Decimal,
+    ) -> Result<Decimal, Box<ConstraintViolation>> {
+        let toward_zero = side == OrderSide::Sell;
+        self.conform(symbol, "stop_price", price, toward_zero)
+    }
+
+    /// Check that `quantity` is a whole lot of `symbol`.
+    ///
+    /// Options trade in whole contracts; fractional equity quantities are
+    /// left to the asset's fractionable flag.
+    #[must_use]
+    pub fn check_lot(symbol: &Symbol, quantity: Decimal) -> Option<ConstraintViolation> {
+        (symbol.is_option() && !quantity.fract().is_zero()).then(|| {
+            ConstraintViolation::error(
+                INVALID_LOT_SIZE,
+                format!(
+                    "{symbol} trades in whole contracts of {} shares",
+                    symbol.multiplier()
+                ),
+            )
+            .with_instrument(symbol.as_str())
+            .with_observed(quantity.to_string())
+        })
+    }
+
+    fn conform(
+        self,
+        symbol: &Symbol,
+        field: &str,
+        price: Decimal,
+        toward_zero: bool,
+    ) -> Result<Decimal, Box<ConstraintViolation>> {
+        let tick = Self::tick_size(symbol, price);
+        if (price % tick).is_zero() {
+            return Ok(price);
+        }
+        let off_tick = |reason: &str| {
+            Box::new(
+                ConstraintViolation::error(
+                    OFF_TICK_PRICE,
+                    format!("{symbol} {field} {price} {reason} {tick}"),
+                )
+                .with_instrument(symbol.as_str())
+                .with_observed(price.to_string())
+                .with_limit(tick.to_string()),
+            )
+        };
+        if self.off_tick == OffTickAction::Reject {
+            return Err(off_tick("is not a multiple of"));
+        }
+
+        let strategy = if toward_zero {
+            RoundingStrategy::ToZero
+        } else {
+            RoundingStrategy::AwayFromZero
+        };
+        let ticks = (price / tick).round_dp_with_strategy(0, strategy);
+        // Rounding down a sub-tick price would leave nothing to trade at, and
+        // rounding it up would cross the price asked
+        if ticks.is_zero() {
+            return Err(off_tick("is below one tick of"));
+        }
+        Ok((ticks * tick).normalize())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rust_decimal_macros::dec;
+
+    #[test]
+    fn tick_size_depends_on_instrument_and_price() {
+        let equity = Symbol::new("AAPL");
+        let option = Symbol::new("AAPL250117C00150000");
+
+        assert_eq!(TickSizeRules::tick_size(&equity, dec!(150)), dec!(0.01));
+        assert_eq!(TickSizeRules::tick_size(&equity, dec!(0.50)), dec!(0.0001));
+        assert_eq!(TickSizeRules::tick_size(&option, dec!(2.50)), dec!(0.05));
+        assert_eq!(TickSizeRules::tick_size(&option, dec!(4.20)), dec!(0.10));
+    }
+
+    #[test]
+    fn off_tick_prices_round_to_the_safe_side() {
+        let rules = TickSizeRules::default();
+        let equity = Symbol::new("AAPL");
+        let option = Symbol::new("AAPL250117C00150000");
+
+        let limit = |symbol, side, price| rules.conform_limit(symbol, side, price).unwrap();
+        assert_eq!(limit(&equity, OrderSide::Buy, dec!(150.00)), dec!(150.00));
+        assert_eq!(limit(&equity, OrderSide::Buy, dec!(150.005)), dec!(150));
+        assert_eq!(limit(&equity, OrderSide::Sell, dec!(150.005)), dec!(150.01));
+        assert_eq!(limit(&equity, OrderSide::Buy, dec!(0.12345)), dec!(0.1234));
+        assert_eq!(limit(&option, OrderSide::Buy, dec!(2.53)), dec!(2.5));
+        assert_eq!(limit(&option, OrderSide::Sell, dec!(4.22)), dec!(4.3));
+
+        let stop = |side, price| rules.conform_stop(&option, side, price).unwrap();
+        assert_eq!(stop(OrderSide::Sell, dec!(1.99)), dec!(1.95));
+        assert_eq!(stop(OrderSide::Buy, dec!(2.01)), dec!(2.05));
+    }
+
+    #[test]
+    fn sub_tick_prices_only_round_to_the_safe_side() {
+        let rules = TickSizeRules::default();
+        let equity = Symbol::new("AAPL");
+
+        let violation = rules
+            .conform_limit(&equity, OrderSide::Buy, dec!(0.00005))
+            .unwrap_err();
+        assert_eq!(violation.code, OFF_TICK_PRICE);
+        assert_eq!(violation.limit.as_deref(), Some("0.0001"));
+        assert_eq!(
+            rules
+                .conform_limit(&equity, OrderSide::Sell, dec!(0.00005))
+                .unwrap(),
+            dec!(0.0001)
+        );
+
+        assert!(
+            rules
+                .conform_stop(&equity, OrderSide::Sell, dec!(0.00005))
+                .is_err()
+        );
+        assert_eq!(
+            rules
+                .conform_stop(&equity, OrderSide::Buy, dec!(0.00005))
+                .unwrap(),
+            dec!(0.0001)
+        );
+    }
+
+    #[test]
+    fn off_tick_prices_can_be_rejected() {
+        let rules = TickSizeRules::new(OffTickAction::Reject);
+        let equity = Symbol::new("AAPL");
+
+        assert!(
+            rules
+                .conform_limit(&equity, OrderSide::Buy, dec!(150.01))
+                .is_ok()
+        );
+        let violation = rules
+            .conform_stop(&equity, OrderSide::Sell, dec!(150.005))
+            .unwrap_err();
+        assert_eq!(violation.code, OFF_TICK_PRICE);
+        assert_eq!(violation.limit.as_deref(), Some("0.01"));
+    }
+
+    #[test]
+    fn options_trade_in_whole_contracts() {
+        let option = Symbol::new("AAPL250117C00150000");
+
+        assert!(TickSizeRules::check_lot(&option, dec!(2)).is_none());
+        assert_eq!(
+            TickSizeRules::check_lot(&option, dec!(1.5)).unwrap().code,
+            INVALID_LOT_SIZE
+        );
+        assert!(TickSizeRules::check_lot(&Symbol::new("AAPL"), dec!(1.5)).is_none());
+    }
+}
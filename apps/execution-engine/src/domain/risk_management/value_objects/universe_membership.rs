@@ -0,0 +1,178 @@
+//! Universe Membership
+//!
+//! Point-in-time constituents of an index universe such as the S&P 500.
+//! Each symbol keeps every period it was a member, so the constituents on a
+//! past date can be rebuilt without survivorship bias (names since removed
+//! are still members on the dates they were in the index), and entries can
+//! be checked against today's membership. An option is a member when its
+//! underlying is.
+
+use std::collections::BTreeMap;
+
+use chrono::NaiveDate;
+use serde::{Deserialize, Serialize};
+
+use super::ConstraintViolation;
+use crate::domain::shared::Symbol;
+
+/// Violation code for an instrument that is not a universe member.
+pub const SYMBOL_NOT_IN_UNIVERSE: &str = "SYMBOL_NOT_IN_UNIVERSE";
+
+/// A period a symbol was a member of the universe.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct MembershipPeriod {
+    /// First day of membership.
+    pub added: NaiveDate,
+    /// First day the symbol was no longer a member (`None` if it still is).
+    pub removed: Option<NaiveDate>,
+}
+
+impl MembershipPeriod {
+    /// Check if the symbol was a member on `date`.
+    #[must_use]
+    pub fn contains(&self, date: NaiveDate) -> bool {
+        self.added <= date && self.removed.is_none_or(|removed| date < removed)
+    }
+}
+
+/// Membership history of a named universe.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct UniverseMembership {
+    name: String,
+    periods: BTreeMap<String, Vec<MembershipPeriod>>,
+}
+
+impl UniverseMembership {
+    /// Create an empty universe called `name`.
+    #[must_use]
+    pub fn new(name: impl Into<String>) -> Self {
+        Self {
+            name: name.into(),
+            periods: BTreeMap::new(),
+        }
+    }
+
+    /// Universe name, used in violation messages.
+    #[must_use]
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Record that `symbol` was a member during `period`.
+    pub fn add(&mut self, symbol: &str, period: MembershipPeriod) {
+        self.periods
+            .entry(Symbol::new(symbol).as_str().to_string())
+            .or_default()
+            .push(period);
+    }
+
+    /// Number of symbols that were ever members.
+    #[must_use]
+    pub fn symbol_count(&self) -> usize {
+        self.periods.len()
+    }
+
+    /// Check if `symbol`, or the underlying of an option, was a member on
+    /// `date`.
+    #[must_use]
+    pub fn is_member(&self, symbol: &Symbol, date: NaiveDate) -> bool {
+        let member = |name: &str| {
+            self.periods
+                .get(name)
+                .is_some_and(|periods| periods.iter().any(|p| p.contains(date)))
+        };
+        member(symbol.as_str()) || member(symbol.underlying().as_str())
+    }
+
+    /// Symbols that were members on `date`, sorted.
+    #[must_use]
+    pub fn members_on(&self, date: NaiveDate) -> Vec<&str> {
+        self.periods
+            .iter()
+            .filter(|(_, periods)| periods.iter().any(|p| p.contains(date)))
+            .map(|(symbol, _)| symbol.as_str())
+            .collect()
+    }
+
+    /// Check that `symbol` was a member on `date`.
+    ///
+    /// Returns a [`SYMBOL_NOT_IN_UNIVERSE`] violation otherwise.
+    #[must_use]
+    pub fn check(&self, symbol: &Symbol, date: NaiveDate) -> Option<ConstraintViolation> {
+        if self.is_member(symbol, date) {
+            return None;
+        }
+        Some(
+            ConstraintViolation::error(
+                SYMBOL_NOT_IN_UNIVERSE,
+                format!(
+                    "{symbol} is not a member of the {} universe on {date}",
+                    self.name
+                ),
+            )
+            .with_instrument(symbol.as_str())
+            .with_limit(self.name.as_str()),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn date(s: &str) -> NaiveDate {
+        NaiveDate::parse_from_str(s, "%Y-%m-%d").unwrap()
+    }
+
+    fn sp500() -> UniverseMembership {
+        let mut universe = UniverseMembership::new("sp500");
+        universe.add(
+            "AAPL",
+            MembershipPeriod {
+                added: date("1982-11-30"),
+                removed: None,
+            },
+        );
+        universe.add(
+            "BBBY",
+            MembershipPeriod {
+                added: date("2007-03-01"),
+                removed: Some(date("2018-07-02")),
+            },
+        );
+        universe.add(
+            "TSLA",
+            MembershipPeriod {
+                added: date("2020-12-21"),
+                removed: None,
+            },
+        );
+        universe
+    }
+
+    #[test]
+    fn members_are_point_in_time() {
+        let universe = sp500();
+
+        assert_eq!(universe.members_on(date("2015-06-01")), ["AAPL", "BBBY"]);
+        assert_eq!(universe.members_on(date("2021-01-04")), ["AAPL", "TSLA"]);
+        // Removal date is exclusive
+        assert!(universe.is_member(&Symbol::new("BBBY"), date("2018-06-29")));
+        assert!(!universe.is_member(&Symbol::new("BBBY"), date("2018-07-02")));
+        assert_eq!(universe.symbol_count(), 3);
+    }
+
+    #[test]
+    fn options_follow_their_underlying() {
+        let universe = sp500();
+        let today = date("2026-03-02");
+
+        assert!(
+            universe
+                .check(&Symbol::new("AAPL260320C00200000"), today)
+                .is_none()
+        );
+        let violation = universe.check(&Symbol::new("BBBY"), today).unwrap();
+        assert_eq!(violation.code, SYMBOL_NOT_IN_UNIVERSE);
+    }
+}
@@ -1,7 +1,96 @@
 //! Domain errors for the execution engine.
+//!
+//! Also defines the error taxonomy shared by every layer: each error an API
+//! client can see implements [`ClassifiedError`], giving it a stable
+//! machine-readable code and an [`ErrorKind`]. The HTTP and gRPC adapters
+//! map the kind to a status code, so clients branch on the code and never
+//! need to parse the message.
 
 use std::fmt;
 
+/// Broad category of an error, independent of transport.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ErrorKind {
+    /// The request is well-formed but a field is invalid.
+    InvalidArgument,
+    /// The request could not be parsed.
+    MalformedRequest,
+    /// The request body has an unsupported content type.
+    UnsupportedMediaType,
+    /// The request body is too large.
+    PayloadTooLarge,
+    /// The caller could not be identified.
+    Unauthenticated,
+    /// The caller is not allowed to perform the operation.
+    PermissionDenied,
+    /// The referenced resource does not exist.
+    NotFound,
+    /// The resource to create already exists.
+    AlreadyExists,
+    /// The request conflicts with concurrent work or current state.
+    Conflict,
+    /// The system is not in a state that allows the operation.
+    FailedPrecondition,
+    /// A risk check, the broker or a business rule rejected the request.
+    Rejected,
+    /// The operation is disabled in this deployment.
+    Disabled,
+    /// Too many requests; retry later.
+    RateLimited,
+    /// A dependency (broker, market data) is unreachable; retry later.
+    Unavailable,
+    /// An internal failure.
+    Internal,
+}
+
+impl ErrorKind {
+    /// Whether retrying the same request later may succeed.
+    #[must_use]
+    pub const fn is_retryable(self) -> bool {
+        matches!(self, Self::RateLimited | Self::Unavailable)
+    }
+}
+
+impl fmt::Display for ErrorKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            Self::InvalidArgument => "INVALID_ARGUMENT",
+            Self::MalformedRequest => "MALFORMED_REQUEST",
+            Self::UnsupportedMediaType => "UNSUPPORTED_MEDIA_TYPE",
+            Self::PayloadTooLarge => "PAYLOAD_TOO_LARGE",
+            Self::Unauthenticated => "UNAUTHENTICATED",
+            Self::PermissionDenied => "PERMISSION_DENIED",
+            Self::NotFound => "NOT_FOUND",
+            Self::AlreadyExists => "ALREADY_EXISTS",
+            Self::Conflict => "CONFLICT",
+            Self::FailedPrecondition => "FAILED_PRECONDITION",
+            Self::Rejected => "REJECTED",
+            Self::Disabled => "DISABLED",
+            Self::RateLimited => "RATE_LIMITED",
+            Self::Unavailable => "UNAVAILABLE",
+            Self::Internal => "INTERNAL",
+        };
+        write!(f, "{name}")
+    }
+}
+
+/// An error with a stable code and kind.
+///
+/// Codes are `SCREAMING_SNAKE_CASE` and never change meaning once
+/// published; messages may change at any time.
+pub trait ClassifiedError: std::error::Error {
+    /// Machine-readable error code.
+    fn code(&self) -> &str;
+
+    /// Category of the error.
+    fn kind(&self) -> ErrorKind;
+
+    /// Whether retrying the same request later may succeed.
+    fn is_retryable(&self) -> bool {
+        self.kind().is_retryable()
+    }
+}
+
 /// Domain-level errors that can occur in business logic.
 ///
 /// These errors are independent of infrastructure concerns.
@@ -115,6 +204,32 @@ impl fmt::Display for DomainError {
 
 impl std::error::Error for DomainError {}
 
+impl ClassifiedError for DomainError {
+    fn code(&self) -> &str {
+        match self {
+            Self::InvalidStateTransition { .. } => "INVALID_STATE_TRANSITION",
+            Self::InvalidValue { .. } => "INVALID_VALUE",
+            Self::BusinessRuleViolation { .. } => "BUSINESS_RULE_VIOLATION",
+            Self::NotFound { .. } => "NOT_FOUND",
+            Self::InvariantViolation { .. } => "INVARIANT_VIOLATION",
+            Self::ConstraintViolation { code, .. } => code,
+            Self::FixProtocolError { .. } => "FIX_PROTOCOL_ERROR",
+        }
+    }
+
+    fn kind(&self) -> ErrorKind {
+        match self {
+            Self::InvalidStateTransition { .. } => ErrorKind::FailedPrecondition,
+            Self::InvalidValue { .. } => ErrorKind::InvalidArgument,
+            Self::BusinessRuleViolation { .. } | Self::ConstraintViolation { .. } => {
+                ErrorKind::Rejected
+            }
+            Self::NotFound { .. } => ErrorKind::NotFound,
+            Self::InvariantViolation { .. } | Self::FixProtocolError { .. } => ErrorKind::Internal,
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -196,6 +311,22 @@ mod tests {
         assert!(msg.contains("39=8"));
     }
 
+    #[test]
+    fn domain_error_classification() {
+        let err = DomainError::ConstraintViolation {
+            code: "MAX_NOTIONAL".to_string(),
+            message: "Exceeds $50,000 limit".to_string(),
+        };
+        assert_eq!(err.code(), "MAX_NOTIONAL");
+        assert_eq!(err.kind(), ErrorKind::Rejected);
+        assert!(!err.is_retryable());
+        assert!(ErrorKind::Unavailable.is_retryable());
+        assert_eq!(
+            ErrorKind::FailedPrecondition.to_string(),
+            "FAILED_PRECONDITION"
+        );
+    }
+
     #[test]
     fn domain_error_is_std_error() {
         let err: Box<dyn std::error::Error> = Box::new(DomainError::InvalidValue {
@@ -5,7 +5,7 @@
 pub mod errors;
 pub mod value_objects;
 
-pub use errors::DomainError;
+pub use errors::{ClassifiedError, DomainError, ErrorKind};
 pub use value_objects::{
     BrokerId, CycleId, DecisionId, InstrumentId, Money, OrderId, PlanId, Quantity, Symbol,
     Timestamp,
@@ -1,14 +1,20 @@
 //! Symbol value object for instrument identifiers.
 
+use rust_decimal::Decimal;
 use serde::{Deserialize, Serialize};
 use std::fmt;
 
 use crate::domain::shared::DomainError;
 
+/// Shares of the underlying covered by one standard option contract.
+const OPTION_MULTIPLIER: Decimal = Decimal::from_parts(100, 0, 0, false, 0);
+
 /// A trading symbol (ticker or OCC option symbol).
 ///
 /// Examples:
 /// - Equity: "AAPL", "MSFT", "GOOGL"
+/// - Class share: "BRK.B"
+/// - Preferred: "BAC.PRL"
 /// - Option: "AAPL250117P00190000" (OCC format)
 #[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
 #[serde(transparent)]
@@ -17,10 +23,63 @@ pub struct Symbol(String);
 impl Symbol {
     /// Create a new Symbol.
     ///
-    /// The symbol is normalized to uppercase.
+    /// The symbol is normalized to its canonical notation (see
+    /// [`Symbol::normalize`]).
     #[must_use]
     pub fn new(value: impl Into<String>) -> Self {
-        Self(value.into().to_uppercase())
+        Self(Self::normalize(&value.into()))
+    }
+
+    /// Canonical notation of a raw symbol.
+    ///
+    /// Trims and uppercases the symbol and maps the common notations of the
+    /// same instrument onto one, so it has a single identity in state and
+    /// reconciliation:
+    /// - Class shares: `BRK-B`, `BRK/B` → `BRK.B`
+    /// - Preferreds: `BAC-PL`, `BAC/PRL`, `BAC.PR.L`, `BACpL` → `BAC.PRL`
+    /// - OCC options: the space-padded root of `AAPL  250117P00190000` is
+    ///   unpadded to `AAPL250117P00190000`
+    ///
+    /// Anything else is only trimmed and uppercased; [`Symbol::validate`]
+    /// rejects what is still malformed.
+    #[must_use]
+    pub fn normalize(raw: &str) -> String {
+        let raw = raw.trim();
+
+        let compact: String = raw
+            .chars()
+            .filter(|c| !c.is_whitespace())
+            .collect::<String>()
+            .to_uppercase();
+        if is_occ(&compact) {
+            return compact;
+        }
+
+        // CQS marks preferreds with a lowercase `p`: `BACpL`
+        if let Some((root, series)) = raw.split_once('p')
+            && !root.is_empty()
+            && root.chars().all(|c| c.is_ascii_uppercase())
+            && series.chars().all(|c| c.is_ascii_uppercase())
+        {
+            return format!("{root}.PR{series}");
+        }
+
+        let upper = raw.to_uppercase();
+        let Some((root, suffix)) = upper.split_once(['.', '-', '/']) else {
+            return upper;
+        };
+        let suffix: String = suffix
+            .chars()
+            .filter(|c| !matches!(c, '.' | '-' | '/'))
+            .collect();
+        // `PR` + series, or `P` + a one-letter series, is a preferred
+        suffix
+            .strip_prefix("PR")
+            .or_else(|| suffix.strip_prefix('P').filter(|s| s.len() == 1))
+            .map_or_else(
+                || format!("{root}.{suffix}"),
+                |series| format!("{root}.PR{series}"),
+            )
     }
 
     /// Get the symbol string.
@@ -44,27 +103,7 @@ impl Symbol {
     /// - Price: 8 digits (strike × 1000)
     #[must_use]
     pub fn is_option(&self) -> bool {
-        let s = &self.0;
-        if s.len() < 15 || s.len() > 21 {
-            return false;
-        }
-
-        // Find the date/type/price portion (last 15 characters for standard OCC)
-        // Check for P or C in the expected position
-        let len = s.len();
-        if len >= 15 {
-            let type_pos = len - 9;
-            let type_char = s.chars().nth(type_pos);
-            if type_char == Some('P') || type_char == Some('C') {
-                // Check that the last 8 chars are digits (strike price)
-                let price_part = &s[len - 8..];
-                let date_part = &s[type_pos - 6..type_pos];
-                return price_part.chars().all(|c| c.is_ascii_digit())
-                    && date_part.chars().all(|c| c.is_ascii_digit());
-            }
-        }
-
-        false
+        is_occ(&self.0)
     }
 
     /// Check if this is an equity symbol.
@@ -73,6 +112,18 @@ impl Symbol {
         !self.is_option()
     }
 
+    /// Units of the underlying one unit of this instrument represents.
+    ///
+    /// Standard equity options cover 100 shares; everything else is 1.
+    #[must_use]
+    pub fn multiplier(&self) -> Decimal {
+        if self.is_option() {
+            OPTION_MULTIPLIER
+        } else {
+            Decimal::ONE
+        }
+    }
+
     /// Extract the underlying symbol from an option.
     ///
     /// Returns the full symbol if it's not an option.
@@ -106,8 +157,16 @@ impl Symbol {
             });
         }
 
-        // Only alphanumeric characters allowed
-        if !self.0.chars().all(|c| c.is_ascii_alphanumeric()) {
+        // Only alphanumeric characters, and a dot between root and suffix
+        let dotted = self.0.split('.').count() - 1;
+        if !self
+            .0
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || c == '.')
+            || dotted > 1
+            || self.0.starts_with('.')
+            || self.0.ends_with('.')
+        {
             return Err(DomainError::InvalidValue {
                 field: "symbol".to_string(),
                 message: "Symbol contains invalid characters".to_string(),
@@ -118,6 +177,33 @@ impl Symbol {
     }
 }
 
+/// Check for an unpadded OCC option symbol.
+///
+/// OCC format: `{ROOT}{YY}{MM}{DD}{P/C}{PRICE}`
+///
+/// Raw client input reaches this from [`Symbol::normalize`]; anything
+/// non-ASCII is rejected before the byte offsets below are sliced.
+fn is_occ(s: &str) -> bool {
+    if !s.is_ascii() || s.len() < 15 || s.len() > 21 {
+        return false;
+    }
+
+    // Find the date/type/price portion (last 15 characters for standard OCC)
+    // Check for P or C in the expected position
+    let len = s.len();
+    let type_pos = len - 9;
+    let type_char = s.chars().nth(type_pos);
+    if type_char == Some('P') || type_char == Some('C') {
+        // Check that the last 8 chars are digits (strike price)
+        let price_part = &s[len - 8..];
+        let date_part = &s[type_pos - 6..type_pos];
+        return price_part.chars().all(|c| c.is_ascii_digit())
+            && date_part.chars().all(|c| c.is_ascii_digit());
+    }
+
+    false
+}
+
 impl fmt::Display for Symbol {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         write!(f, "{}", self.0)
@@ -181,6 +267,15 @@ mod tests {
         assert!(!Symbol::new("SHORT").is_option());
     }
 
+    #[test]
+    fn symbol_multiplier() {
+        assert_eq!(Symbol::new("AAPL").multiplier(), Decimal::ONE);
+        assert_eq!(
+            Symbol::new("AAPL250117P00190000").multiplier(),
+            Decimal::from(100)
+        );
+    }
+
     #[test]
     fn symbol_underlying_from_option() {
         let opt = Symbol::new("AAPL250117P00190000");
@@ -221,6 +316,56 @@ mod tests {
     fn symbol_validate_valid() {
         assert!(Symbol::new("AAPL").validate().is_ok());
         assert!(Symbol::new("AAPL250117P00190000").validate().is_ok());
+        assert!(Symbol::new("BRK.B").validate().is_ok());
+        assert!(Symbol::new("BAC.PRL").validate().is_ok());
+    }
+
+    #[test]
+    fn symbol_validate_rejects_stray_dots() {
+        assert!(Symbol::new("BRK.").validate().is_err());
+        assert!(Symbol::new(".BRK").validate().is_err());
+    }
+
+    #[test]
+    fn symbol_normalizes_class_shares() {
+        for raw in ["BRK.B", "BRK-B", "BRK/B", "brk.b", " BRK-B "] {
+            assert_eq!(Symbol::new(raw).as_str(), "BRK.B", "{raw}");
+        }
+        assert_eq!(Symbol::new("BRK-B"), Symbol::new("BRK/B"));
+        assert!(Symbol::new("BRK-B").is_equity());
+    }
+
+    #[test]
+    fn symbol_normalizes_preferreds() {
+        for raw in [
+            "BAC.PRL", "BAC-PL", "BAC/PRL", "BAC.PR.L", "BAC-PRL", "BACpL",
+        ] {
+            assert_eq!(Symbol::new(raw).as_str(), "BAC.PRL", "{raw}");
+        }
+        // A lone `P` is a class, not a preferred
+        assert_eq!(Symbol::new("XYZ-P").as_str(), "XYZ.P");
+    }
+
+    #[test]
+    fn symbol_normalizes_padded_occ() {
+        let padded = Symbol::new("AAPL  250117P00190000");
+        assert_eq!(padded.as_str(), "AAPL250117P00190000");
+        assert!(padded.is_option());
+        assert_eq!(padded.underlying().as_str(), "AAPL");
+    }
+
+    #[test]
+    fn symbol_non_ascii_is_not_option() {
+        for raw in [
+            "ÉAAACCCCCCCCCCC",
+            "ÉAPL250117P00190000",
+            "AAPL250117P0019000É",
+        ] {
+            let symbol = Symbol::new(raw);
+            assert!(!symbol.is_option(), "{raw}");
+            assert_eq!(symbol.underlying(), symbol, "{raw}");
+            assert!(symbol.validate().is_err(), "{raw}");
+        }
     }
 
     #[test]
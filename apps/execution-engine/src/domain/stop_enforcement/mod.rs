@@ -11,6 +11,7 @@ pub mod value_objects;
 pub use errors::StopEnforcementError;
 pub use services::PriceMonitor;
 pub use value_objects::{
-    MonitoredPosition, PositionDirection, RiskLevelDenomination, SameBarPriority, StopTargetLevels,
-    StopsConfig, TriggerResult,
+    HoldingPeriod, HoldingPeriodAction, MonitoredPosition, PositionDirection,
+    RiskLevelDenomination, SameBarPriority, ScaleOutTarget, StopTargetLevels, StopsConfig,
+    TriggerResult,
 };
@@ -4,8 +4,10 @@ use rust_decimal::Decimal;
 use std::collections::HashMap;
 
 use crate::domain::shared::{InstrumentId, OrderId, Timestamp};
+use crate::domain::stop_enforcement::errors::StopEnforcementError;
 use crate::domain::stop_enforcement::value_objects::{
-    MonitoredPosition, PositionDirection, StopTargetLevels, StopsConfig, TriggerResult,
+    HoldingPeriodAction, MonitoredPosition, PositionDirection, StopTargetLevels, StopsConfig,
+    TriggerResult,
 };
 
 /// Price monitor for real-time stop/target enforcement.
@@ -61,6 +63,85 @@ impl PriceMonitor {
         self.positions.get(position_id.as_str())
     }
 
+    /// Move the stop and/or target of every active position on an
+    /// instrument.
+    ///
+    /// All positions are validated before any is changed. Returns the IDs
+    /// of the positions updated (empty if none are monitored).
+    ///
+    /// # Errors
+    ///
+    /// Returns `InvalidLevels` if the new levels are invalid for any of the
+    /// positions.
+    pub fn adjust_levels(
+        &mut self,
+        instrument_id: &InstrumentId,
+        stop_loss: Option<Decimal>,
+        take_profit: Option<Decimal>,
+    ) -> Result<Vec<OrderId>, StopEnforcementError> {
+        let adjusted = self
+            .positions
+            .values()
+            .filter(|p| p.is_active() && p.instrument_id() == instrument_id)
+            .map(|p| {
+                p.levels()
+                    .adjusted(stop_loss, take_profit)
+                    .map(|levels| (p.position_id().clone(), levels))
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Ok(adjusted
+            .into_iter()
+            .filter_map(|(position_id, levels)| {
+                let position = self.positions.get_mut(position_id.as_str())?;
+                position.update_levels(levels);
+                Some(position_id)
+            })
+            .collect())
+    }
+
+    /// Tighten the stop of every active position on an instrument whose
+    /// holding period has expired with a `TightenStop` action.
+    ///
+    /// The holding period is cleared once acted on, so each position is
+    /// tightened at most once. Returns the IDs of the positions whose stop
+    /// moved.
+    pub fn tighten_expired_stops(
+        &mut self,
+        instrument_id: &InstrumentId,
+        price: Decimal,
+        now: Timestamp,
+    ) -> Vec<OrderId> {
+        let mut tightened = Vec::new();
+
+        for position in self.positions.values_mut() {
+            if !position.is_active()
+                || position.instrument_id() != instrument_id
+                || !position.is_holding_expired(now)
+            {
+                continue;
+            }
+            let Some(period) = position.holding_period().copied() else {
+                continue;
+            };
+            if !matches!(period.on_expiry, HoldingPeriodAction::TightenStop { .. }) {
+                continue;
+            }
+
+            let levels = position.levels();
+            let new_levels = period
+                .tightened_stop(levels.direction, price, levels.stop_loss)
+                .and_then(|stop| levels.adjusted(Some(stop), None).ok());
+            if let Some(new_levels) = new_levels {
+                position.update_levels(new_levels);
+                tightened.push(position.position_id().clone());
+            }
+            position.clear_holding_period();
+        }
+
+        tightened
+    }
+
     /// Check a price update against all monitored positions.
     ///
     /// Returns a list of (`position_id`, `trigger_result`) for any triggers.
@@ -70,7 +151,22 @@ impl PriceMonitor {
         instrument_id: &InstrumentId,
         price: Decimal,
     ) -> Vec<(OrderId, TriggerResult)> {
-        let timestamp = Timestamp::now();
+        self.check_price_at(instrument_id, price, Timestamp::now())
+    }
+
+    /// Check a price update observed at `now` against all monitored
+    /// positions.
+    ///
+    /// Stop and final target take precedence, then any scale-out targets
+    /// reached; a position that has outlived a `Close` holding period
+    /// otherwise triggers a `TimeExit` at `price`.
+    #[must_use]
+    pub fn check_price_at(
+        &self,
+        instrument_id: &InstrumentId,
+        price: Decimal,
+        now: Timestamp,
+    ) -> Vec<(OrderId, TriggerResult)> {
         let mut triggers = Vec::new();
 
         for position in self.positions.values() {
@@ -78,7 +174,27 @@ impl PriceMonitor {
                 continue;
             }
 
-            let result = Self::check_price_trigger(price, position.levels(), timestamp);
+            let mut result = Self::check_price_trigger(price, position.levels(), now);
+            if result == TriggerResult::None
+                && let Some((target, quantity)) = position.scale_out_at(price)
+            {
+                result = TriggerResult::ScaleOut {
+                    price: target,
+                    quantity,
+                    timestamp: now,
+                };
+            }
+            if result == TriggerResult::None
+                && position.is_holding_expired(now)
+                && position
+                    .holding_period()
+                    .is_some_and(|p| p.on_expiry == HoldingPeriodAction::Close)
+            {
+                result = TriggerResult::TimeExit {
+                    price,
+                    timestamp: now,
+                };
+            }
             if result != TriggerResult::None {
                 triggers.push((position.position_id().clone(), result));
             }
@@ -87,6 +203,16 @@ impl PriceMonitor {
         triggers
     }
 
+    /// Record a scale-out at `price` as filled.
+    ///
+    /// Returns the quantity still open, or `None` if the position is not
+    /// monitored.
+    pub fn record_scale_out(&mut self, position_id: &OrderId, price: Decimal) -> Option<Decimal> {
+        let position = self.positions.get_mut(position_id.as_str())?;
+        position.record_scale_out(price);
+        Some(position.quantity())
+    }
+
     /// Check if a price triggers stop or target.
     fn check_price_trigger(
         price: Decimal,
@@ -154,6 +280,7 @@ impl PriceMonitor {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::domain::stop_enforcement::value_objects::{HoldingPeriod, ScaleOutTarget};
 
     fn make_long_position(position_id: &str, instrument_id: &str) -> MonitoredPosition {
         let levels = StopTargetLevels::for_long(
@@ -302,6 +429,159 @@ mod tests {
         assert_eq!(triggers.len(), 2);
     }
 
+    #[test]
+    fn price_monitor_adjust_levels() {
+        let mut monitor = PriceMonitor::new();
+        monitor.add_position(make_long_position("pos-1", "AAPL"));
+        monitor.add_position(make_long_position("pos-2", "MSFT"));
+
+        let updated = monitor
+            .adjust_levels(&InstrumentId::new("AAPL"), Some(Decimal::new(98, 0)), None)
+            .unwrap();
+        assert_eq!(updated, vec![OrderId::new("pos-1")]);
+
+        // Tightened stop now triggers where the old one would not
+        let triggers = monitor.check_price(&InstrumentId::new("AAPL"), Decimal::new(97, 0));
+        assert_eq!(triggers.len(), 1);
+        assert!(triggers[0].1.is_stop_loss());
+        let triggers = monitor.check_price(&InstrumentId::new("MSFT"), Decimal::new(97, 0));
+        assert!(triggers.is_empty());
+    }
+
+    #[test]
+    fn price_monitor_adjust_levels_rejects_crossed_levels() {
+        let mut monitor = PriceMonitor::new();
+        monitor.add_position(make_long_position("pos-1", "AAPL"));
+
+        let result =
+            monitor.adjust_levels(&InstrumentId::new("AAPL"), Some(Decimal::new(120, 0)), None);
+        assert!(matches!(
+            result,
+            Err(StopEnforcementError::InvalidLevels { .. })
+        ));
+
+        let position = monitor.get_position(&OrderId::new("pos-1")).unwrap();
+        assert_eq!(position.levels().stop_loss, Decimal::new(95, 0));
+        assert!(
+            monitor
+                .adjust_levels(&InstrumentId::new("TSLA"), Some(Decimal::new(1, 0)), None)
+                .unwrap()
+                .is_empty()
+        );
+    }
+
+    fn at(offset_secs: i64) -> Timestamp {
+        let start = Timestamp::parse("2026-03-13T14:30:00Z").unwrap();
+        Timestamp::new(start.as_datetime() + chrono::Duration::seconds(offset_secs))
+    }
+
+    #[test]
+    fn price_monitor_time_exit() {
+        let mut monitor = PriceMonitor::new();
+        monitor.add_position(
+            make_long_position("pos-1", "AAPL")
+                .with_holding_period(at(0), HoldingPeriod::close_after(3_600)),
+        );
+        let aapl = InstrumentId::new("AAPL");
+
+        assert!(
+            monitor
+                .check_price_at(&aapl, Decimal::new(101, 0), at(3_599))
+                .is_empty()
+        );
+
+        let triggers = monitor.check_price_at(&aapl, Decimal::new(101, 0), at(3_600));
+        assert_eq!(triggers.len(), 1);
+        assert_eq!(
+            triggers[0].1,
+            TriggerResult::TimeExit {
+                price: Decimal::new(101, 0),
+                timestamp: at(3_600),
+            }
+        );
+
+        // Stop takes precedence over the expired holding period
+        let triggers = monitor.check_price_at(&aapl, Decimal::new(94, 0), at(3_600));
+        assert!(triggers[0].1.is_stop_loss());
+    }
+
+    #[test]
+    fn price_monitor_tighten_expired_stops() {
+        let mut monitor = PriceMonitor::new();
+        monitor.add_position(
+            make_long_position("pos-1", "AAPL")
+                .with_holding_period(at(0), HoldingPeriod::tighten_after(60, Decimal::new(1, 2))),
+        );
+        let aapl = InstrumentId::new("AAPL");
+
+        assert!(
+            monitor
+                .tighten_expired_stops(&aapl, Decimal::new(105, 0), at(59))
+                .is_empty()
+        );
+        let tightened = monitor.tighten_expired_stops(&aapl, Decimal::new(105, 0), at(60));
+        assert_eq!(tightened, vec![OrderId::new("pos-1")]);
+
+        let position = monitor.get_position(&OrderId::new("pos-1")).unwrap();
+        assert_eq!(position.levels().stop_loss, Decimal::new(10395, 2));
+        assert!(position.holding_period().is_none());
+
+        // A tightened position never time-exits, it rides the new stop
+        assert!(
+            monitor
+                .check_price_at(&aapl, Decimal::new(104, 0), at(120))
+                .is_empty()
+        );
+        assert!(
+            monitor
+                .tighten_expired_stops(&aapl, Decimal::new(108, 0), at(120))
+                .is_empty()
+        );
+    }
+
+    #[test]
+    fn price_monitor_scale_out() {
+        let mut monitor = PriceMonitor::new();
+        let ladder = vec![
+            ScaleOutTarget::new(Decimal::new(104, 0), Decimal::new(50, 2)),
+            ScaleOutTarget::new(Decimal::new(107, 0), Decimal::new(50, 2)),
+        ];
+        monitor.add_position(
+            make_long_position("pos-1", "AAPL")
+                .with_scale_out(ladder)
+                .unwrap(),
+        );
+        let aapl = InstrumentId::new("AAPL");
+        let pos_1 = OrderId::new("pos-1");
+
+        let triggers = monitor.check_price_at(&aapl, Decimal::new(105, 0), at(0));
+        assert_eq!(
+            triggers[0].1,
+            TriggerResult::ScaleOut {
+                price: Decimal::new(104, 0),
+                quantity: Decimal::new(50, 0),
+                timestamp: at(0),
+            }
+        );
+        assert_eq!(
+            monitor.record_scale_out(&pos_1, Decimal::new(105, 0)),
+            Some(Decimal::new(50, 0))
+        );
+        assert!(
+            monitor
+                .check_price_at(&aapl, Decimal::new(105, 0), at(1))
+                .is_empty()
+        );
+
+        // Final target beats the last rung and exits what is left
+        let triggers = monitor.check_price_at(&aapl, Decimal::new(110, 0), at(2));
+        assert!(triggers[0].1.is_take_profit());
+        assert_eq!(
+            monitor.get_position(&pos_1).unwrap().quantity(),
+            Decimal::new(50, 0)
+        );
+    }
+
     #[test]
     fn price_monitor_with_config() {
         let config = StopsConfig::fast_monitoring();
@@ -0,0 +1,154 @@
+//! Holding Period Value Object
+
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+
+use crate::domain::shared::Timestamp;
+
+use super::PositionDirection;
+
+/// What happens once a position outlives its maximum holding period.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "action", rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum HoldingPeriodAction {
+    /// Close the position at market.
+    #[default]
+    Close,
+    /// Move the stop to `distance` (a fraction of price) from the price at
+    /// expiry, if that is tighter than the current stop.
+    TightenStop {
+        /// Distance of the new stop from the price, e.g. `0.005` for 0.5%.
+        distance: Decimal,
+    },
+}
+
+/// Maximum time a position may stay open.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct HoldingPeriod {
+    /// Seconds after entry the position may stay open.
+    pub max_holding_secs: u64,
+    /// What to do once it has been open longer.
+    #[serde(default)]
+    pub on_expiry: HoldingPeriodAction,
+}
+
+impl HoldingPeriod {
+    /// Close at market after `max_holding_secs`.
+    #[must_use]
+    pub const fn close_after(max_holding_secs: u64) -> Self {
+        Self {
+            max_holding_secs,
+            on_expiry: HoldingPeriodAction::Close,
+        }
+    }
+
+    /// Tighten the stop to `distance` from the price after `max_holding_secs`.
+    #[must_use]
+    pub const fn tighten_after(max_holding_secs: u64, distance: Decimal) -> Self {
+        Self {
+            max_holding_secs,
+            on_expiry: HoldingPeriodAction::TightenStop { distance },
+        }
+    }
+
+    /// Check whether a position opened at `opened_at` has expired by `now`.
+    #[must_use]
+    pub fn is_expired(&self, opened_at: Timestamp, now: Timestamp) -> bool {
+        now.duration_since(opened_at)
+            .to_std()
+            .is_ok_and(|held| held.as_secs() >= self.max_holding_secs)
+    }
+
+    /// Stop price a `TightenStop` expiry moves to at `price`.
+    ///
+    /// Returns `None` for `Close`, or if the new stop would not be tighter
+    /// than `current_stop`.
+    #[must_use]
+    pub fn tightened_stop(
+        &self,
+        direction: PositionDirection,
+        price: Decimal,
+        current_stop: Decimal,
+    ) -> Option<Decimal> {
+        let HoldingPeriodAction::TightenStop { distance } = self.on_expiry else {
+            return None;
+        };
+        match direction {
+            PositionDirection::Long => {
+                Some(price * (Decimal::ONE - distance)).filter(|stop| *stop > current_stop)
+            }
+            PositionDirection::Short => {
+                Some(price * (Decimal::ONE + distance)).filter(|stop| *stop < current_stop)
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn at(offset_secs: i64) -> Timestamp {
+        let start = Timestamp::parse("2026-03-13T14:30:00Z").unwrap();
+        Timestamp::new(start.as_datetime() + chrono::Duration::seconds(offset_secs))
+    }
+
+    #[test]
+    fn holding_period_expiry() {
+        let period = HoldingPeriod::close_after(3_600);
+
+        assert!(!period.is_expired(at(0), at(3_599)));
+        assert!(period.is_expired(at(0), at(3_600)));
+        // Clock behind the entry never expires
+        assert!(!period.is_expired(at(10), at(0)));
+    }
+
+    #[test]
+    fn holding_period_tightened_stop() {
+        let period = HoldingPeriod::tighten_after(60, Decimal::new(1, 2));
+
+        assert_eq!(
+            period.tightened_stop(
+                PositionDirection::Long,
+                Decimal::new(100, 0),
+                Decimal::new(95, 0)
+            ),
+            Some(Decimal::new(99, 0))
+        );
+        assert_eq!(
+            period.tightened_stop(
+                PositionDirection::Short,
+                Decimal::new(100, 0),
+                Decimal::new(105, 0)
+            ),
+            Some(Decimal::new(101, 0))
+        );
+        // Never loosens
+        assert_eq!(
+            period.tightened_stop(
+                PositionDirection::Long,
+                Decimal::new(100, 0),
+                Decimal::new(99, 0)
+            ),
+            None
+        );
+        assert_eq!(
+            HoldingPeriod::close_after(60).tightened_stop(
+                PositionDirection::Long,
+                Decimal::new(100, 0),
+                Decimal::new(95, 0)
+            ),
+            None
+        );
+    }
+
+    #[test]
+    fn holding_period_serde() {
+        let period = HoldingPeriod::tighten_after(86_400, Decimal::new(5, 3));
+        let json = serde_json::to_value(period).unwrap();
+        assert_eq!(json["on_expiry"]["action"], "TIGHTEN_STOP");
+
+        let parsed: HoldingPeriod = serde_json::from_str(r#"{"max_holding_secs": 3600}"#).unwrap();
+        assert_eq!(parsed, HoldingPeriod::close_after(3_600));
+    }
+}
@@ -1,11 +1,15 @@
 //! Stop Enforcement Value Objects
 
+mod holding_period;
 mod monitored_position;
+mod scale_out;
 mod stop_config;
 mod stop_target_levels;
 mod trigger_result;
 
+pub use holding_period::{HoldingPeriod, HoldingPeriodAction};
 pub use monitored_position::MonitoredPosition;
+pub use scale_out::ScaleOutTarget;
 pub use stop_config::{RiskLevelDenomination, SameBarPriority, StopsConfig};
 pub use stop_target_levels::{PositionDirection, StopTargetLevels};
 pub use trigger_result::TriggerResult;
@@ -2,9 +2,11 @@
 
 use rust_decimal::Decimal;
 
-use crate::domain::shared::{InstrumentId, OrderId};
+use crate::domain::shared::{InstrumentId, OrderId, Timestamp};
 
-use super::StopTargetLevels;
+use crate::domain::stop_enforcement::errors::StopEnforcementError;
+
+use super::{HoldingPeriod, ScaleOutTarget, StopTargetLevels};
 
 /// Position being monitored for stop/target triggers.
 #[derive(Debug, Clone)]
@@ -13,12 +15,18 @@ pub struct MonitoredPosition {
     position_id: OrderId,
     /// Instrument being monitored.
     instrument_id: InstrumentId,
-    /// Position quantity (shares or contracts).
+    /// Remaining position quantity (shares or contracts).
     quantity: Decimal,
+    /// Quantity when monitoring started; scale-out fractions apply to it.
+    initial_quantity: Decimal,
     /// Stop/target levels.
     levels: StopTargetLevels,
     /// Whether monitoring is active.
     active: bool,
+    /// When the position opened and how long it may stay open.
+    holding: Option<(Timestamp, HoldingPeriod)>,
+    /// Scale-out targets not yet reached, nearest first.
+    scale_out: Vec<ScaleOutTarget>,
 }
 
 impl MonitoredPosition {
@@ -34,11 +42,104 @@ impl MonitoredPosition {
             position_id,
             instrument_id,
             quantity,
+            initial_quantity: quantity,
             levels,
             active: true,
+            holding: None,
+            scale_out: Vec::new(),
         }
     }
 
+    /// Exit the position in parts at `targets` before the final take-profit.
+    ///
+    /// # Errors
+    ///
+    /// Returns `InvalidLevels` if the ladder does not fit the levels.
+    pub fn with_scale_out(
+        mut self,
+        targets: Vec<ScaleOutTarget>,
+    ) -> Result<Self, StopEnforcementError> {
+        ScaleOutTarget::validate_ladder(&targets, &self.levels)?;
+        self.scale_out = targets;
+        Ok(self)
+    }
+
+    /// Get the scale-out targets not yet reached.
+    #[must_use]
+    pub fn scale_out_targets(&self) -> &[ScaleOutTarget] {
+        &self.scale_out
+    }
+
+    /// Scale-out due at `price`, as `(target price, quantity)`.
+    ///
+    /// Covers every target reached, so a gap through several rungs exits
+    /// them together at the furthest one. Leg quantities are rounded down to
+    /// whole units and capped at the remaining quantity; returns `None` if
+    /// no target is reached or the legs round to nothing.
+    #[must_use]
+    pub fn scale_out_at(&self, price: Decimal) -> Option<(Decimal, Decimal)> {
+        let direction = self.levels.direction;
+        let reached = self
+            .scale_out
+            .iter()
+            .take_while(|target| target.is_reached(direction, price));
+
+        let (target_price, quantity) =
+            reached.fold((None, Decimal::ZERO), |(_, quantity), target| {
+                (
+                    Some(target.price),
+                    quantity + (self.initial_quantity * target.fraction).floor(),
+                )
+            });
+        let quantity = quantity.min(self.quantity);
+        target_price
+            .filter(|_| quantity > Decimal::ZERO)
+            .map(|p| (p, quantity))
+    }
+
+    /// Record the scale-out at `price` as filled.
+    ///
+    /// Drops the targets reached and reduces the remaining quantity.
+    /// Returns the quantity exited.
+    pub fn record_scale_out(&mut self, price: Decimal) -> Decimal {
+        let exited = self.scale_out_at(price).map_or(Decimal::ZERO, |(_, q)| q);
+        let direction = self.levels.direction;
+        self.scale_out
+            .retain(|target| !target.is_reached(direction, price));
+        self.quantity -= exited;
+        exited
+    }
+
+    /// Limit how long the position, opened at `opened_at`, may stay open.
+    #[must_use]
+    pub const fn with_holding_period(
+        mut self,
+        opened_at: Timestamp,
+        period: HoldingPeriod,
+    ) -> Self {
+        self.holding = Some((opened_at, period));
+        self
+    }
+
+    /// Get the maximum holding period, if any.
+    #[must_use]
+    pub fn holding_period(&self) -> Option<&HoldingPeriod> {
+        self.holding.as_ref().map(|(_, period)| period)
+    }
+
+    /// Check whether the holding period has expired by `now`.
+    #[must_use]
+    pub fn is_holding_expired(&self, now: Timestamp) -> bool {
+        self.holding
+            .as_ref()
+            .is_some_and(|(opened_at, period)| period.is_expired(*opened_at, now))
+    }
+
+    /// Stop enforcing the holding period (after it has been acted on).
+    pub const fn clear_holding_period(&mut self) {
+        self.holding = None;
+    }
+
     /// Get the position ID.
     #[must_use]
     pub const fn position_id(&self) -> &OrderId {
@@ -133,6 +234,81 @@ mod tests {
         assert!(position.is_active());
     }
 
+    #[test]
+    fn monitored_position_holding_period() {
+        let opened_at = Timestamp::parse("2026-03-13T14:30:00Z").unwrap();
+        let later = Timestamp::parse("2026-03-13T15:30:00Z").unwrap();
+        let mut position = MonitoredPosition::new(
+            OrderId::new("pos-1"),
+            InstrumentId::new("AAPL"),
+            Decimal::new(100, 0),
+            test_levels(),
+        );
+        assert!(!position.is_holding_expired(later));
+
+        position = position.with_holding_period(opened_at, HoldingPeriod::close_after(3_600));
+        assert!(!position.is_holding_expired(opened_at));
+        assert!(position.is_holding_expired(later));
+
+        position.clear_holding_period();
+        assert!(position.holding_period().is_none());
+        assert!(!position.is_holding_expired(later));
+    }
+
+    #[test]
+    fn monitored_position_scale_out() {
+        let ladder = vec![
+            ScaleOutTarget::new(Decimal::new(105, 0), Decimal::new(50, 2)),
+            ScaleOutTarget::new(Decimal::new(108, 0), Decimal::new(25, 2)),
+        ];
+        let mut position = MonitoredPosition::new(
+            OrderId::new("pos-1"),
+            InstrumentId::new("AAPL"),
+            Decimal::new(10, 0),
+            test_levels(),
+        )
+        .with_scale_out(ladder)
+        .unwrap();
+
+        assert_eq!(position.scale_out_at(Decimal::new(104, 0)), None);
+        assert_eq!(
+            position.scale_out_at(Decimal::new(106, 0)),
+            Some((Decimal::new(105, 0), Decimal::new(5, 0)))
+        );
+        // Gapping through both rungs exits them together (2.5 rounds down)
+        assert_eq!(
+            position.scale_out_at(Decimal::new(109, 0)),
+            Some((Decimal::new(108, 0), Decimal::new(7, 0)))
+        );
+
+        assert_eq!(
+            position.record_scale_out(Decimal::new(106, 0)),
+            Decimal::new(5, 0)
+        );
+        assert_eq!(position.quantity(), Decimal::new(5, 0));
+        assert_eq!(position.scale_out_targets().len(), 1);
+        assert_eq!(
+            position.scale_out_at(Decimal::new(108, 0)),
+            Some((Decimal::new(108, 0), Decimal::new(2, 0)))
+        );
+    }
+
+    #[test]
+    fn monitored_position_rejects_invalid_scale_out() {
+        let position = MonitoredPosition::new(
+            OrderId::new("pos-1"),
+            InstrumentId::new("AAPL"),
+            Decimal::new(10, 0),
+            test_levels(),
+        );
+
+        let result = position.with_scale_out(vec![ScaleOutTarget::new(
+            Decimal::new(95, 0),
+            Decimal::new(50, 2),
+        )]);
+        assert!(result.is_err());
+    }
+
     #[test]
     fn monitored_position_update_levels() {
         let mut position = MonitoredPosition::new(
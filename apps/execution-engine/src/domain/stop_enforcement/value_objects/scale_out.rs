@@ -0,0 +1,158 @@
+//! Scale-Out Target Value Object
+
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+
+use crate::domain::stop_enforcement::errors::StopEnforcementError;
+
+use super::{PositionDirection, StopTargetLevels};
+
+/// One rung of a take-profit ladder: exit `fraction` of the original
+/// position once price reaches `price`.
+///
+/// Whatever the ladder does not cover rides to the final take-profit.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ScaleOutTarget {
+    /// Price at which to scale out.
+    pub price: Decimal,
+    /// Fraction of the original quantity to exit, in `(0, 1]`.
+    pub fraction: Decimal,
+}
+
+impl ScaleOutTarget {
+    /// Create a new scale-out target.
+    #[must_use]
+    pub const fn new(price: Decimal, fraction: Decimal) -> Self {
+        Self { price, fraction }
+    }
+
+    /// Check whether `price` has reached this target.
+    #[must_use]
+    pub fn is_reached(&self, direction: PositionDirection, price: Decimal) -> bool {
+        match direction {
+            PositionDirection::Long => price >= self.price,
+            PositionDirection::Short => price <= self.price,
+        }
+    }
+
+    /// Validate a ladder against the position's levels.
+    ///
+    /// Targets must be ordered nearest-first, sit between the entry and the
+    /// final take-profit, and their fractions must add up to at most 1.
+    ///
+    /// # Errors
+    ///
+    /// Returns `InvalidLevels` describing the first problem found.
+    pub fn validate_ladder(
+        targets: &[Self],
+        levels: &StopTargetLevels,
+    ) -> Result<(), StopEnforcementError> {
+        let invalid = |message: String| Err(StopEnforcementError::InvalidLevels { message });
+
+        let mut total = Decimal::ZERO;
+        let mut previous: Option<Decimal> = None;
+        for target in targets {
+            if target.fraction <= Decimal::ZERO || target.fraction > Decimal::ONE {
+                return invalid(format!(
+                    "scale-out fraction {} must be in (0, 1]",
+                    target.fraction
+                ));
+            }
+            total += target.fraction;
+
+            let (in_range, after_previous) = match levels.direction {
+                PositionDirection::Long => (
+                    target.price > levels.entry_price && target.price <= levels.take_profit,
+                    previous.is_none_or(|p| target.price > p),
+                ),
+                PositionDirection::Short => (
+                    target.price < levels.entry_price && target.price >= levels.take_profit,
+                    previous.is_none_or(|p| target.price < p),
+                ),
+            };
+            if !in_range {
+                return invalid(format!(
+                    "scale-out target {} must be between entry {} and target {}",
+                    target.price, levels.entry_price, levels.take_profit
+                ));
+            }
+            if !after_previous {
+                return invalid(format!(
+                    "scale-out target {} must be further from entry than the one before",
+                    target.price
+                ));
+            }
+            previous = Some(target.price);
+        }
+
+        if total > Decimal::ONE {
+            return invalid(format!("scale-out fractions add up to {total}, above 1"));
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn long_levels() -> StopTargetLevels {
+        StopTargetLevels::for_long(
+            Decimal::new(100, 0),
+            Decimal::new(95, 0),
+            Decimal::new(120, 0),
+        )
+    }
+
+    fn target(price: i64, fraction_pct: i64) -> ScaleOutTarget {
+        ScaleOutTarget::new(Decimal::new(price, 0), Decimal::new(fraction_pct, 2))
+    }
+
+    #[test]
+    fn scale_out_target_is_reached() {
+        let t = target(110, 50);
+        assert!(t.is_reached(PositionDirection::Long, Decimal::new(110, 0)));
+        assert!(!t.is_reached(PositionDirection::Long, Decimal::new(109, 0)));
+        assert!(t.is_reached(PositionDirection::Short, Decimal::new(109, 0)));
+    }
+
+    #[test]
+    fn scale_out_validate_ladder() {
+        let levels = long_levels();
+
+        assert!(ScaleOutTarget::validate_ladder(&[], &levels).is_ok());
+        assert!(
+            ScaleOutTarget::validate_ladder(&[target(110, 50), target(120, 50)], &levels).is_ok()
+        );
+
+        // Out of order, beyond the target, behind the entry, over 100%
+        for ladder in [
+            vec![target(115, 50), target(110, 50)],
+            vec![target(125, 50)],
+            vec![target(100, 50)],
+            vec![target(105, 60), target(110, 60)],
+            vec![target(110, 0)],
+        ] {
+            assert!(matches!(
+                ScaleOutTarget::validate_ladder(&ladder, &levels),
+                Err(StopEnforcementError::InvalidLevels { .. })
+            ));
+        }
+    }
+
+    #[test]
+    fn scale_out_validate_ladder_short() {
+        let levels = StopTargetLevels::for_short(
+            Decimal::new(100, 0),
+            Decimal::new(105, 0),
+            Decimal::new(80, 0),
+        );
+
+        assert!(
+            ScaleOutTarget::validate_ladder(&[target(90, 50), target(85, 25)], &levels).is_ok()
+        );
+        assert!(
+            ScaleOutTarget::validate_ladder(&[target(85, 50), target(90, 25)], &levels).is_err()
+        );
+    }
+}
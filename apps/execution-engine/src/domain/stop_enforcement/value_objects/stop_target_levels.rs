@@ -5,6 +5,7 @@ use serde::{Deserialize, Serialize};
 
 use super::RiskLevelDenomination;
 use crate::domain::order_execution::value_objects::OrderSide;
+use crate::domain::stop_enforcement::errors::StopEnforcementError;
 
 /// Position direction for stop/target calculation.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
@@ -110,6 +111,40 @@ impl StopTargetLevels {
         Some(self.reward() / risk)
     }
 
+    /// Copy of these levels with a new stop and/or target.
+    ///
+    /// Entry price and direction are kept. The stop may move past the entry
+    /// (a stop trailed into profit) but must stay on the losing side of the
+    /// target.
+    ///
+    /// # Errors
+    ///
+    /// Returns `InvalidLevels` if the stop would sit at or beyond the target.
+    pub fn adjusted(
+        &self,
+        stop_loss: Option<Decimal>,
+        take_profit: Option<Decimal>,
+    ) -> Result<Self, StopEnforcementError> {
+        let mut levels = self.clone();
+        levels.stop_loss = stop_loss.unwrap_or(self.stop_loss);
+        levels.take_profit = take_profit.unwrap_or(self.take_profit);
+
+        let ordered = match levels.direction {
+            PositionDirection::Long => levels.stop_loss < levels.take_profit,
+            PositionDirection::Short => levels.stop_loss > levels.take_profit,
+        };
+        if !ordered {
+            return Err(StopEnforcementError::InvalidLevels {
+                message: format!(
+                    "stop {} must be on the losing side of target {} for a {:?} position",
+                    levels.stop_loss, levels.take_profit, levels.direction
+                ),
+            });
+        }
+
+        Ok(levels)
+    }
+
     /// Validate that levels are sensible for the direction.
     #[must_use]
     pub fn is_valid(&self) -> bool {
@@ -174,6 +209,32 @@ mod tests {
         assert_eq!(levels.denomination, RiskLevelDenomination::OptionPrice);
     }
 
+    #[test]
+    fn stop_target_levels_adjusted() {
+        let levels = StopTargetLevels::for_long(
+            Decimal::new(100, 0),
+            Decimal::new(95, 0),
+            Decimal::new(110, 0),
+        );
+
+        // Trailing the stop above entry is allowed
+        let trailed = levels.adjusted(Some(Decimal::new(102, 0)), None).unwrap();
+        assert_eq!(trailed.stop_loss, Decimal::new(102, 0));
+        assert_eq!(trailed.take_profit, Decimal::new(110, 0));
+        assert_eq!(trailed.entry_price, Decimal::new(100, 0));
+
+        assert!(levels.adjusted(Some(Decimal::new(110, 0)), None).is_err());
+        assert!(levels.adjusted(None, Some(Decimal::new(94, 0))).is_err());
+
+        let short = StopTargetLevels::for_short(
+            Decimal::new(100, 0),
+            Decimal::new(105, 0),
+            Decimal::new(90, 0),
+        );
+        assert!(short.adjusted(Some(Decimal::new(98, 0)), None).is_ok());
+        assert!(short.adjusted(Some(Decimal::new(89, 0)), None).is_err());
+    }
+
     #[test]
     fn stop_target_levels_risk_long() {
         let levels = StopTargetLevels::for_long(
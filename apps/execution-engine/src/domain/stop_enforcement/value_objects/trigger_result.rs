@@ -22,6 +22,22 @@ pub enum TriggerResult {
         /// Timestamp of trigger.
         timestamp: Timestamp,
     },
+    /// A scale-out target was reached; exit part of the position.
+    ScaleOut {
+        /// Furthest scale-out target reached.
+        price: Decimal,
+        /// Quantity to exit.
+        quantity: Decimal,
+        /// Timestamp of trigger.
+        timestamp: Timestamp,
+    },
+    /// Maximum holding period elapsed.
+    TimeExit {
+        /// Price when the holding period expired.
+        price: Decimal,
+        /// Timestamp of trigger.
+        timestamp: Timestamp,
+    },
 }
 
 impl TriggerResult {
@@ -43,6 +59,25 @@ impl TriggerResult {
         }
     }
 
+    /// Create a scale-out trigger.
+    #[must_use]
+    pub fn scale_out(price: Decimal, quantity: Decimal) -> Self {
+        Self::ScaleOut {
+            price,
+            quantity,
+            timestamp: Timestamp::now(),
+        }
+    }
+
+    /// Create a time-based exit trigger.
+    #[must_use]
+    pub fn time_exit(price: Decimal) -> Self {
+        Self::TimeExit {
+            price,
+            timestamp: Timestamp::now(),
+        }
+    }
+
     /// Check if any trigger occurred.
     #[must_use]
     pub const fn is_triggered(&self) -> bool {
@@ -60,6 +95,18 @@ impl TriggerResult {
     pub const fn is_take_profit(&self) -> bool {
         matches!(self, Self::TakeProfit { .. })
     }
+
+    /// Check if a scale-out target was reached.
+    #[must_use]
+    pub const fn is_scale_out(&self) -> bool {
+        matches!(self, Self::ScaleOut { .. })
+    }
+
+    /// Check if the holding period expired.
+    #[must_use]
+    pub const fn is_time_exit(&self) -> bool {
+        matches!(self, Self::TimeExit { .. })
+    }
 }
 
 #[cfg(test)]
@@ -101,4 +148,21 @@ mod tests {
             panic!("Expected TakeProfit");
         }
     }
+
+    #[test]
+    fn trigger_result_scale_out() {
+        let result = TriggerResult::scale_out(Decimal::new(105, 0), Decimal::new(50, 0));
+        assert!(result.is_triggered());
+        assert!(result.is_scale_out());
+        assert!(!result.is_take_profit());
+    }
+
+    #[test]
+    fn trigger_result_time_exit() {
+        let result = TriggerResult::time_exit(Decimal::new(102, 0));
+        assert!(result.is_triggered());
+        assert!(result.is_time_exit());
+        assert!(!result.is_stop_loss());
+        assert!(!result.is_take_profit());
+    }
 }
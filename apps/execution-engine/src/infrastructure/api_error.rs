@@ -0,0 +1,220 @@
+//! API Errors
+//!
+//! Puts the [`ClassifiedError`] taxonomy on the wire. An [`ApiError`]
+//! carries a stable code, an [`ErrorKind`], a message and optional
+//! structured details. HTTP renders it as an [`ApiErrorResponse`] with the
+//! status from [`http_status`]; gRPC as a `Status` with the code from
+//! [`grpc_code`] and a `google.rpc.ErrorInfo` detail whose `reason` is the
+//! error code. Both transports share these tables, so a failure is
+//! classified the same way whichever API reports it.
+
+use std::collections::HashMap;
+use std::fmt;
+
+use axum::Json;
+use axum::http::StatusCode;
+use axum::response::{IntoResponse, Response};
+use tonic::{Code, Status};
+use tonic_types::{ErrorDetails, StatusExt};
+
+use super::http::ApiErrorResponse;
+use crate::domain::order_execution::errors::OrderError;
+use crate::domain::shared::{ClassifiedError, ErrorKind};
+
+/// `ErrorInfo.domain` of every gRPC error.
+pub const ERROR_DOMAIN: &str = "execution.cream";
+
+/// Code for a request with invalid fields.
+pub const VALIDATION_FAILED: &str = "VALIDATION_FAILED";
+
+/// Code for a failure to read or write the order store.
+pub const ORDER_STORE_ERROR: &str = "ORDER_STORE_ERROR";
+
+/// HTTP status for errors of `kind`.
+#[must_use]
+pub const fn http_status(kind: ErrorKind) -> StatusCode {
+    match kind {
+        ErrorKind::InvalidArgument | ErrorKind::Rejected => StatusCode::UNPROCESSABLE_ENTITY,
+        ErrorKind::MalformedRequest => StatusCode::BAD_REQUEST,
+        ErrorKind::UnsupportedMediaType => StatusCode::UNSUPPORTED_MEDIA_TYPE,
+        ErrorKind::PayloadTooLarge => StatusCode::PAYLOAD_TOO_LARGE,
+        ErrorKind::Unauthenticated => StatusCode::UNAUTHORIZED,
+        ErrorKind::PermissionDenied => StatusCode::FORBIDDEN,
+        ErrorKind::NotFound | ErrorKind::Disabled => StatusCode::NOT_FOUND,
+        ErrorKind::AlreadyExists | ErrorKind::Conflict | ErrorKind::FailedPrecondition => {
+            StatusCode::CONFLICT
+        }
+        ErrorKind::RateLimited => StatusCode::TOO_MANY_REQUESTS,
+        ErrorKind::Unavailable => StatusCode::SERVICE_UNAVAILABLE,
+        ErrorKind::Internal => StatusCode::INTERNAL_SERVER_ERROR,
+    }
+}
+
+/// gRPC status code for errors of `kind`.
+#[must_use]
+pub const fn grpc_code(kind: ErrorKind) -> Code {
+    match kind {
+        ErrorKind::InvalidArgument
+        | ErrorKind::MalformedRequest
+        | ErrorKind::UnsupportedMediaType => Code::InvalidArgument,
+        ErrorKind::PayloadTooLarge | ErrorKind::RateLimited => Code::ResourceExhausted,
+        ErrorKind::Unauthenticated => Code::Unauthenticated,
+        ErrorKind::PermissionDenied => Code::PermissionDenied,
+        ErrorKind::NotFound => Code::NotFound,
+        ErrorKind::AlreadyExists => Code::AlreadyExists,
+        ErrorKind::Conflict => Code::Aborted,
+        ErrorKind::FailedPrecondition | ErrorKind::Rejected => Code::FailedPrecondition,
+        ErrorKind::Disabled => Code::Unimplemented,
+        ErrorKind::Unavailable => Code::Unavailable,
+        ErrorKind::Internal => Code::Internal,
+    }
+}
+
+/// An error as reported to API clients.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ApiError {
+    /// Category of the error.
+    pub kind: ErrorKind,
+    /// Machine-readable error code.
+    pub code: String,
+    /// Human-readable message.
+    pub message: String,
+    /// Structured details, such as the conflicts that blocked an import.
+    pub details: Option<serde_json::Value>,
+}
+
+impl ApiError {
+    /// Create an error with `code` and `message`.
+    #[must_use]
+    pub fn new(kind: ErrorKind, code: impl Into<String>, message: impl Into<String>) -> Self {
+        Self {
+            kind,
+            code: code.into(),
+            message: message.into(),
+            details: None,
+        }
+    }
+
+    /// A [`VALIDATION_FAILED`] error.
+    #[must_use]
+    pub fn invalid_argument(message: impl Into<String>) -> Self {
+        Self::new(ErrorKind::InvalidArgument, VALIDATION_FAILED, message)
+    }
+
+    /// An [`ORDER_STORE_ERROR`] for a failed order store read or write.
+    ///
+    /// Store failures are internal whatever the [`OrderError`] variant, so
+    /// a failed lookup is not reported as a missing order.
+    #[must_use]
+    pub fn order_store(error: &OrderError) -> Self {
+        Self::new(ErrorKind::Internal, ORDER_STORE_ERROR, error.to_string())
+    }
+
+    /// Report `error` under its own code and kind.
+    #[must_use]
+    pub fn from_error<E: ClassifiedError + ?Sized>(error: &E) -> Self {
+        Self::new(error.kind(), error.code(), error.to_string())
+    }
+
+    /// Prefix the message with what was being done, e.g. `Failed to get
+    /// positions`.
+    #[must_use]
+    pub fn with_context(mut self, context: &str) -> Self {
+        self.message = format!("{context}: {}", self.message);
+        self
+    }
+
+    /// Attach structured details.
+    #[must_use]
+    pub fn with_details(mut self, details: serde_json::Value) -> Self {
+        self.details = Some(details);
+        self
+    }
+
+    /// Whether retrying the same request later may succeed.
+    #[must_use]
+    pub const fn is_retryable(&self) -> bool {
+        self.kind.is_retryable()
+    }
+}
+
+impl fmt::Display for ApiError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "[{}] {}", self.code, self.message)
+    }
+}
+
+impl From<ApiError> for ApiErrorResponse {
+    fn from(error: ApiError) -> Self {
+        Self {
+            retryable: error.is_retryable(),
+            code: error.code,
+            message: error.message,
+            details: error.details,
+            errors: vec![],
+        }
+    }
+}
+
+impl IntoResponse for ApiError {
+    fn into_response(self) -> Response {
+        (http_status(self.kind), Json(ApiErrorResponse::from(self))).into_response()
+    }
+}
+
+impl From<ApiError> for Status {
+    fn from(error: ApiError) -> Self {
+        let mut metadata = HashMap::from([
+            ("kind".to_string(), error.kind.to_string()),
+            ("retryable".to_string(), error.is_retryable().to_string()),
+        ]);
+        if let Some(details) = &error.details {
+            metadata.insert("details".to_string(), details.to_string());
+        }
+        Self::with_error_details(
+            grpc_code(error.kind),
+            error.message,
+            ErrorDetails::with_error_info(error.code, ERROR_DOMAIN, metadata),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::application::ports::BrokerError;
+
+    #[test]
+    fn http_response_carries_code_and_status() {
+        let error = ApiError::from_error(&OrderError::NotFound {
+            order_id: "ord-1".to_string(),
+        });
+
+        assert_eq!(http_status(error.kind), StatusCode::NOT_FOUND);
+        let body = ApiErrorResponse::from(error);
+        assert_eq!(body.code, "ORDER_NOT_FOUND");
+        assert_eq!(body.message, "Order not found: ord-1");
+        assert!(!body.retryable);
+    }
+
+    #[test]
+    fn grpc_status_carries_error_info() {
+        let status = Status::from(
+            ApiError::from_error(&BrokerError::RateLimited)
+                .with_context("Failed to get positions")
+                .with_details(serde_json::json!({"retry_after_secs": 5})),
+        );
+
+        assert_eq!(status.code(), Code::ResourceExhausted);
+        assert_eq!(
+            status.message(),
+            "Failed to get positions: Rate limited by broker"
+        );
+        let info = status.get_details_error_info().unwrap();
+        assert_eq!(info.reason, "BROKER_RATE_LIMITED");
+        assert_eq!(info.domain, ERROR_DOMAIN);
+        assert_eq!(info.metadata["kind"], "RATE_LIMITED");
+        assert_eq!(info.metadata["retryable"], "true");
+        assert_eq!(info.metadata["details"], r#"{"retry_after_secs":5}"#);
+    }
+}
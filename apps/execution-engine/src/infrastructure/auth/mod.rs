@@ -0,0 +1,402 @@
+//! API Authentication
+//!
+//! API-key authentication with role-based authorization, shared by the HTTP
+//! and gRPC adapters. Keys are loaded from a JSON file:
+//!
+//! ```json
+//! [
+//!   { "name": "dashboard", "key": "...", "role": "READ_ONLY" },
+//!   { "name": "worker", "key": "...", "role": "TRADER", "environments": ["PAPER"] }
+//! ]
+//! ```
+//!
+//! A key with no `environments` is valid in every environment. Clients send
+//! the key as `Authorization: Bearer <key>` or `x-api-key: <key>`.
+
+use std::path::Path;
+
+use axum::http::HeaderMap;
+use axum::http::header::AUTHORIZATION;
+use serde::{Deserialize, Serialize};
+
+use crate::domain::order_execution::value_objects::Environment;
+use crate::domain::shared::{ClassifiedError, ErrorKind};
+
+/// Header carrying an API key as an alternative to `Authorization`.
+pub const API_KEY_HEADER: &str = "x-api-key";
+
+/// Access level granted to an API key. Each role includes the ones below it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum Role {
+    /// Query orders, positions, constraints, and market data.
+    ReadOnly,
+    /// Submit and cancel orders.
+    Trader,
+    /// Administrative operations.
+    Admin,
+}
+
+impl Role {
+    /// Check if this role grants `required`.
+    #[must_use]
+    pub fn permits(self, required: Self) -> bool {
+        self >= required
+    }
+}
+
+/// A configured API key.
+#[derive(Debug, Clone, Deserialize)]
+pub struct ApiKeyEntry {
+    /// Key name, used in logs and audit trails.
+    pub name: String,
+    /// Secret key value.
+    pub key: String,
+    /// Granted role.
+    pub role: Role,
+    /// Environments the key is valid in (empty = all).
+    #[serde(default)]
+    pub environments: Vec<Environment>,
+}
+
+impl ApiKeyEntry {
+    /// Check if the key may be used in `environment`.
+    #[must_use]
+    pub fn allows(&self, environment: Environment) -> bool {
+        self.environments.is_empty() || self.environments.contains(&environment)
+    }
+}
+
+/// An authenticated caller.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Principal {
+    /// Key name.
+    pub name: String,
+    /// Granted role.
+    pub role: Role,
+}
+
+/// Authentication or authorization failure.
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+pub enum AuthError {
+    /// No API key was presented.
+    #[error("Missing API key")]
+    MissingCredentials,
+
+    /// The presented API key is unknown.
+    #[error("Invalid API key")]
+    InvalidCredentials,
+
+    /// The key is not valid in the engine's environment.
+    #[error("API key '{name}' is not valid in {environment}")]
+    WrongEnvironment {
+        /// Key name.
+        name: String,
+        /// Engine environment.
+        environment: Environment,
+    },
+
+    /// The key's role does not permit the operation.
+    #[error("API key '{name}' lacks the {required:?} role")]
+    Forbidden {
+        /// Key name.
+        name: String,
+        /// Role the operation requires.
+        required: Role,
+    },
+}
+
+impl AuthError {
+    /// Whether the caller was identified but denied (as opposed to not
+    /// identified at all).
+    #[must_use]
+    pub const fn is_forbidden(&self) -> bool {
+        matches!(self, Self::WrongEnvironment { .. } | Self::Forbidden { .. })
+    }
+}
+
+impl ClassifiedError for AuthError {
+    fn code(&self) -> &str {
+        if self.is_forbidden() {
+            "FORBIDDEN"
+        } else {
+            "UNAUTHENTICATED"
+        }
+    }
+
+    fn kind(&self) -> ErrorKind {
+        if self.is_forbidden() {
+            ErrorKind::PermissionDenied
+        } else {
+            ErrorKind::Unauthenticated
+        }
+    }
+}
+
+/// API key configuration errors.
+#[derive(Debug, thiserror::Error)]
+pub enum ApiKeyConfigError {
+    /// The key file could not be read.
+    #[error("Failed to read API keys from {path}: {message}")]
+    Read {
+        /// File path.
+        path: String,
+        /// Error details.
+        message: String,
+    },
+
+    /// The key file is not valid JSON.
+    #[error("Invalid API key file {path}: {message}")]
+    Parse {
+        /// File path.
+        path: String,
+        /// Error details.
+        message: String,
+    },
+
+    /// A key is empty or appears more than once.
+    #[error("Invalid API key '{name}': {message}")]
+    InvalidKey {
+        /// Key name.
+        name: String,
+        /// Error details.
+        message: String,
+    },
+}
+
+/// Authenticates API keys and checks their roles.
+#[derive(Debug, Clone)]
+pub struct ApiKeyAuthenticator {
+    environment: Environment,
+    keys: Vec<ApiKeyEntry>,
+}
+
+impl ApiKeyAuthenticator {
+    /// Create an authenticator for the given environment.
+    ///
+    /// # Errors
+    ///
+    /// Returns error if a key is empty or duplicated.
+    pub fn new(
+        environment: Environment,
+        keys: Vec<ApiKeyEntry>,
+    ) -> Result<Self, ApiKeyConfigError> {
+        for (i, entry) in keys.iter().enumerate() {
+            let invalid = |message: &str| ApiKeyConfigError::InvalidKey {
+                name: entry.name.clone(),
+                message: message.to_string(),
+            };
+            if entry.key.trim().is_empty() {
+                return Err(invalid("key is empty"));
+            }
+            if keys[..i].iter().any(|other| other.key == entry.key) {
+                return Err(invalid("key is duplicated"));
+            }
+        }
+
+        Ok(Self { environment, keys })
+    }
+
+    /// Load keys from a JSON file.
+    ///
+    /// # Errors
+    ///
+    /// Returns error if the file cannot be read or parsed, or contains
+    /// invalid keys.
+    pub fn from_file(path: &Path, environment: Environment) -> Result<Self, ApiKeyConfigError> {
+        let contents = std::fs::read_to_string(path).map_err(|e| ApiKeyConfigError::Read {
+            path: path.display().to_string(),
+            message: e.to_string(),
+        })?;
+        let keys = serde_json::from_str(&contents).map_err(|e| ApiKeyConfigError::Parse {
+            path: path.display().to_string(),
+            message: e.to_string(),
+        })?;
+        Self::new(environment, keys)
+    }
+
+    /// Number of configured keys.
+    #[must_use]
+    pub const fn len(&self) -> usize {
+        self.keys.len()
+    }
+
+    /// Check if no keys are configured.
+    #[must_use]
+    pub const fn is_empty(&self) -> bool {
+        self.keys.is_empty()
+    }
+
+    /// Identify the caller presenting `key`.
+    ///
+    /// # Errors
+    ///
+    /// Returns error if the key is missing, unknown, or not valid in this
+    /// environment.
+    pub fn authenticate(&self, key: Option<&str>) -> Result<Principal, AuthError> {
+        let key = key.ok_or(AuthError::MissingCredentials)?;
+
+        // Compare against every key so timing does not reveal which matched
+        let mut matched = None;
+        for entry in &self.keys {
+            if constant_time_eq(entry.key.as_bytes(), key.as_bytes()) {
+                matched = Some(entry);
+            }
+        }
+        let entry = matched.ok_or(AuthError::InvalidCredentials)?;
+
+        if !entry.allows(self.environment) {
+            return Err(AuthError::WrongEnvironment {
+                name: entry.name.clone(),
+                environment: self.environment,
+            });
+        }
+
+        Ok(Principal {
+            name: entry.name.clone(),
+            role: entry.role,
+        })
+    }
+
+    /// Identify the caller and check it holds `required`.
+    ///
+    /// # Errors
+    ///
+    /// Returns error if authentication fails or the role is insufficient.
+    pub fn authorize(&self, key: Option<&str>, required: Role) -> Result<Principal, AuthError> {
+        let principal = self.authenticate(key)?;
+        if !principal.role.permits(required) {
+            return Err(AuthError::Forbidden {
+                name: principal.name,
+                required,
+            });
+        }
+        Ok(principal)
+    }
+}
+
+/// Extract an API key from `Authorization: Bearer` or `x-api-key` headers.
+#[must_use]
+pub fn extract_api_key(headers: &HeaderMap) -> Option<&str> {
+    headers
+        .get(AUTHORIZATION)
+        .and_then(|v| v.to_str().ok())
+        .and_then(|v| v.strip_prefix("Bearer "))
+        .or_else(|| headers.get(API_KEY_HEADER).and_then(|v| v.to_str().ok()))
+        .map(str::trim)
+        .filter(|v| !v.is_empty())
+}
+
+fn constant_time_eq(a: &[u8], b: &[u8]) -> bool {
+    if a.len() != b.len() {
+        return false;
+    }
+    a.iter().zip(b).fold(0u8, |acc, (x, y)| acc | (x ^ y)) == 0
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn entry(name: &str, key: &str, role: Role, environments: Vec<Environment>) -> ApiKeyEntry {
+        ApiKeyEntry {
+            name: name.to_string(),
+            key: key.to_string(),
+            role,
+            environments,
+        }
+    }
+
+    fn authenticator(environment: Environment) -> ApiKeyAuthenticator {
+        ApiKeyAuthenticator::new(
+            environment,
+            vec![
+                entry("dashboard", "read-key", Role::ReadOnly, vec![]),
+                entry(
+                    "worker",
+                    "trade-key",
+                    Role::Trader,
+                    vec![Environment::Paper],
+                ),
+                entry("ops", "admin-key", Role::Admin, vec![]),
+            ],
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn roles_are_hierarchical() {
+        assert!(Role::Admin.permits(Role::Trader));
+        assert!(Role::Trader.permits(Role::ReadOnly));
+        assert!(!Role::ReadOnly.permits(Role::Trader));
+    }
+
+    #[test]
+    fn authorizes_by_role() {
+        let auth = authenticator(Environment::Paper);
+
+        assert!(auth.authorize(Some("read-key"), Role::ReadOnly).is_ok());
+        assert!(auth.authorize(Some("trade-key"), Role::Trader).is_ok());
+        assert!(auth.authorize(Some("admin-key"), Role::Trader).is_ok());
+        assert!(matches!(
+            auth.authorize(Some("read-key"), Role::Trader),
+            Err(AuthError::Forbidden { .. })
+        ));
+    }
+
+    #[test]
+    fn rejects_missing_and_unknown_keys() {
+        let auth = authenticator(Environment::Paper);
+
+        assert_eq!(auth.authenticate(None), Err(AuthError::MissingCredentials));
+        assert_eq!(
+            auth.authenticate(Some("nope")),
+            Err(AuthError::InvalidCredentials)
+        );
+    }
+
+    #[test]
+    fn paper_key_rejected_in_live() {
+        let auth = authenticator(Environment::Live);
+
+        let err = auth.authenticate(Some("trade-key")).unwrap_err();
+        assert!(err.is_forbidden());
+        assert!(matches!(err, AuthError::WrongEnvironment { .. }));
+        assert!(auth.authenticate(Some("admin-key")).is_ok());
+    }
+
+    #[test]
+    fn rejects_duplicate_keys() {
+        let result = ApiKeyAuthenticator::new(
+            Environment::Paper,
+            vec![
+                entry("a", "same", Role::ReadOnly, vec![]),
+                entry("b", "same", Role::Trader, vec![]),
+            ],
+        );
+        assert!(matches!(result, Err(ApiKeyConfigError::InvalidKey { .. })));
+    }
+
+    #[test]
+    fn extracts_bearer_and_header_keys() {
+        let mut headers = HeaderMap::new();
+        assert_eq!(extract_api_key(&headers), None);
+
+        headers.insert(API_KEY_HEADER, "header-key".parse().unwrap());
+        assert_eq!(extract_api_key(&headers), Some("header-key"));
+
+        headers.insert(AUTHORIZATION, "Bearer bearer-key".parse().unwrap());
+        assert_eq!(extract_api_key(&headers), Some("bearer-key"));
+    }
+
+    #[test]
+    fn parses_key_file() {
+        let json =
+            r#"[{"name": "worker", "key": "k", "role": "TRADER", "environments": ["PAPER"]}]"#;
+        let keys: Vec<ApiKeyEntry> = serde_json::from_str(json).unwrap();
+
+        assert_eq!(keys[0].role, Role::Trader);
+        assert!(keys[0].allows(Environment::Paper));
+        assert!(!keys[0].allows(Environment::Live));
+    }
+}
@@ -1,27 +1,42 @@
 //! Alpaca broker adapter implementing `BrokerPort`.
 
+use std::sync::Arc;
+
 use async_trait::async_trait;
+use parking_lot::RwLock;
 use rust_decimal::Decimal;
 
 use crate::application::ports::{
-    BrokerError, BrokerPort, CancelOrderRequest, OrderAck, PositionInfo, SubmitOrderRequest,
+    AccountActivity, AccountActivityPort, AssetInfo, BrokerError, BrokerPort, CancelOrderRequest,
+    MarketClock, OrderAck, PositionInfo, SubmitOrderRequest,
 };
-use crate::domain::order_execution::value_objects::{OrderSide, OrderType, TimeInForce};
-use crate::domain::shared::{BrokerId, InstrumentId};
+use crate::application::services::BrokerAccountService;
+use crate::domain::order_execution::value_objects::{OrderSide, OrderType, OtoExit, TimeInForce};
+use crate::domain::shared::{BrokerId, InstrumentId, Symbol, Timestamp};
 
 use super::api_types::{
-    AlpacaAccountResponse, AlpacaOrderRequest, AlpacaOrderResponse, AlpacaPositionResponse,
+    AlpacaAccountResponse, AlpacaActivityResponse, AlpacaAssetResponse, AlpacaClockResponse,
+    AlpacaOrderRequest, AlpacaOrderResponse, AlpacaPositionResponse, AlpacaStopLoss,
+    AlpacaTakeProfit,
 };
 use super::config::{AlpacaConfig, AlpacaEnvironment};
 use super::error::AlpacaError;
 use super::http_client::AlpacaHttpClient;
+use crate::infrastructure::secrets::AlpacaCredentials;
+
+/// Activity types requested from the account activities endpoint.
+const ACTIVITY_TYPES: &str = "FILL,FEE,CFEE,PTC,DIV";
+
+/// Maximum page size supported by the account activities endpoint.
+const ACTIVITY_PAGE_SIZE: usize = 100;
 
 /// Alpaca Markets broker adapter.
 ///
-/// Implements `BrokerPort` for the Alpaca Markets API.
+/// Implements `BrokerPort` for the Alpaca Markets API. Clones share the
+/// HTTP client, so rotated credentials apply to every clone.
 #[derive(Debug, Clone)]
 pub struct AlpacaBrokerAdapter {
-    client: AlpacaHttpClient,
+    client: Arc<RwLock<Arc<AlpacaHttpClient>>>,
     environment: AlpacaEnvironment,
 }
 
@@ -34,7 +49,25 @@ impl AlpacaBrokerAdapter {
     pub fn new(config: &AlpacaConfig) -> Result<Self, AlpacaError> {
         let client = AlpacaHttpClient::new(config)?;
         Ok(Self {
-            client,
+            client: Arc::new(RwLock::new(Arc::new(client))),
+            environment: config.environment,
+        })
+    }
+
+    /// Create an adapter trading the account `user_id` connected over
+    /// OAuth, authenticating with the user's bearer token.
+    ///
+    /// # Errors
+    ///
+    /// Returns error if HTTP client creation fails.
+    pub fn with_oauth(
+        config: &AlpacaConfig,
+        accounts: Arc<BrokerAccountService>,
+        user_id: impl Into<String>,
+    ) -> Result<Self, AlpacaError> {
+        let client = AlpacaHttpClient::with_oauth(config, accounts, user_id)?;
+        Ok(Self {
+            client: Arc::new(RwLock::new(Arc::new(client))),
             environment: config.environment,
         })
     }
@@ -45,6 +78,24 @@ impl AlpacaBrokerAdapter {
         self.environment.is_live()
     }
 
+    /// Re-create the HTTP client with new credentials.
+    ///
+    /// Requests already in flight finish with the previous client.
+    ///
+    /// # Errors
+    ///
+    /// Returns error if either credential is empty.
+    pub fn rotate_credentials(&self, credentials: &AlpacaCredentials) -> Result<(), AlpacaError> {
+        let rotated = self.client().with_credentials(credentials)?;
+        *self.client.write() = Arc::new(rotated);
+        Ok(())
+    }
+
+    /// The current HTTP client.
+    fn client(&self) -> Arc<AlpacaHttpClient> {
+        Arc::clone(&self.client.read())
+    }
+
     /// Convert `SubmitOrderRequest` to Alpaca API format.
     fn to_alpaca_order_request(request: &SubmitOrderRequest) -> AlpacaOrderRequest {
         let side = match request.side {
@@ -68,6 +119,26 @@ impl AlpacaBrokerAdapter {
             TimeInForce::Cls => "cls",
         };
 
+        let (take_profit, stop_loss) = match request.oto_exit {
+            Some(OtoExit::TakeProfit { limit_price }) => (
+                Some(AlpacaTakeProfit {
+                    limit_price: limit_price.to_string(),
+                }),
+                None,
+            ),
+            Some(OtoExit::StopLoss {
+                stop_price,
+                limit_price,
+            }) => (
+                None,
+                Some(AlpacaStopLoss {
+                    stop_price: stop_price.to_string(),
+                    limit_price: limit_price.map(|p| p.to_string()),
+                }),
+            ),
+            None => (None, None),
+        };
+
         AlpacaOrderRequest {
             symbol: request.symbol.as_str().to_string(),
             qty: Some(request.quantity.to_string()),
@@ -83,6 +154,9 @@ impl AlpacaBrokerAdapter {
             } else {
                 None
             },
+            order_class: request.oto_exit.map(|_| "oto".to_string()),
+            take_profit,
+            stop_loss,
         }
     }
 }
@@ -111,7 +185,7 @@ impl BrokerPort for AlpacaBrokerAdapter {
         );
 
         let response: AlpacaOrderResponse = self
-            .client
+            .client()
             .post("/v2/orders", alpaca_request)
             .await
             .map_err(BrokerError::from)?;
@@ -130,13 +204,13 @@ impl BrokerPort for AlpacaBrokerAdapter {
         // Prefer broker order ID if available, otherwise use client order ID
         if let Some(broker_id) = &request.broker_order_id {
             tracing::info!(broker_order_id = %broker_id, "Canceling order by broker ID");
-            self.client
+            self.client()
                 .delete(&format!("/v2/orders/{}", broker_id.as_str()))
                 .await
                 .map_err(BrokerError::from)
         } else if let Some(client_id) = &request.client_order_id {
             tracing::info!(client_order_id = %client_id, "Canceling order by client ID");
-            self.client
+            self.client()
                 .delete(&format!(
                     "/v2/orders:by_client_order_id?client_order_id={}",
                     client_id.as_str()
@@ -153,7 +227,7 @@ impl BrokerPort for AlpacaBrokerAdapter {
 
     async fn get_order(&self, broker_order_id: &BrokerId) -> Result<OrderAck, BrokerError> {
         let response: AlpacaOrderResponse = self
-            .client
+            .client()
             .get(&format!("/v2/orders/{}", broker_order_id.as_str()))
             .await
             .map_err(BrokerError::from)?;
@@ -163,7 +237,7 @@ impl BrokerPort for AlpacaBrokerAdapter {
 
     async fn get_open_orders(&self) -> Result<Vec<OrderAck>, BrokerError> {
         let responses: Vec<AlpacaOrderResponse> = self
-            .client
+            .client()
             .get("/v2/orders?status=open")
             .await
             .map_err(BrokerError::from)?;
@@ -176,7 +250,7 @@ impl BrokerPort for AlpacaBrokerAdapter {
 
     async fn get_buying_power(&self) -> Result<Decimal, BrokerError> {
         let account: AlpacaAccountResponse = self
-            .client
+            .client()
             .get("/v2/account")
             .await
             .map_err(BrokerError::from)?;
@@ -194,7 +268,7 @@ impl BrokerPort for AlpacaBrokerAdapter {
         instrument_id: &InstrumentId,
     ) -> Result<Option<Decimal>, BrokerError> {
         let result: Result<AlpacaPositionResponse, AlpacaError> = self
-            .client
+            .client()
             .get(&format!("/v2/positions/{}", instrument_id.as_str()))
             .await;
 
@@ -212,7 +286,7 @@ impl BrokerPort for AlpacaBrokerAdapter {
 
     async fn get_all_positions(&self) -> Result<Vec<PositionInfo>, BrokerError> {
         let positions: Vec<AlpacaPositionResponse> = self
-            .client
+            .client()
             .get("/v2/positions")
             .await
             .map_err(BrokerError::from)?;
@@ -243,16 +317,114 @@ impl BrokerPort for AlpacaBrokerAdapter {
                     })?;
 
                 Ok(PositionInfo {
-                    symbol: p.symbol,
+                    symbol: Symbol::normalize(&p.symbol),
                     quantity,
                     avg_entry_price,
                     market_value,
                     unrealized_pnl,
                     current_price,
+                    // Alpaca does not report when a position was opened
+                    opened_at: None,
                 })
             })
             .collect()
     }
+
+    async fn get_asset(&self, symbol: &Symbol) -> Result<Option<AssetInfo>, BrokerError> {
+        let result: Result<AlpacaAssetResponse, AlpacaError> = self
+            .client()
+            .get(&format!("/v2/assets/{}", symbol.as_str()))
+            .await;
+
+        match result {
+            Ok(asset) => Ok(Some(asset.to_asset_info())),
+            Err(AlpacaError::OrderNotFound { .. }) => Ok(None),
+            Err(e) => Err(BrokerError::from(e)),
+        }
+    }
+
+    async fn list_assets(&self) -> Result<Vec<AssetInfo>, BrokerError> {
+        let assets: Vec<AlpacaAssetResponse> = self
+            .client()
+            .get("/v2/assets?status=active&asset_class=us_equity")
+            .await
+            .map_err(BrokerError::from)?;
+
+        Ok(assets
+            .iter()
+            .map(AlpacaAssetResponse::to_asset_info)
+            .collect())
+    }
+
+    async fn get_market_clock(&self) -> Result<Option<MarketClock>, BrokerError> {
+        let clock: AlpacaClockResponse = self
+            .client()
+            .get("/v2/clock")
+            .await
+            .map_err(BrokerError::from)?;
+
+        clock
+            .to_market_clock()
+            .map(Some)
+            .ok_or_else(|| BrokerError::Unknown {
+                message: format!("Unparseable market clock: {clock:?}"),
+            })
+    }
+}
+
+#[async_trait]
+impl AccountActivityPort for AlpacaBrokerAdapter {
+    async fn get_activities(
+        &self,
+        after: Option<Timestamp>,
+    ) -> Result<Vec<AccountActivity>, BrokerError> {
+        let mut activities = Vec::new();
+        let mut page_token: Option<String> = None;
+
+        loop {
+            let mut query = vec![
+                format!("activity_types={ACTIVITY_TYPES}"),
+                "direction=asc".to_string(),
+                format!("page_size={ACTIVITY_PAGE_SIZE}"),
+            ];
+            if let Some(after) = after {
+                // `Z` suffix keeps the value free of `+`, which would need URL encoding
+                let after = after
+                    .as_datetime()
+                    .to_rfc3339_opts(chrono::SecondsFormat::Secs, true);
+                query.push(format!("after={after}"));
+            }
+            if let Some(token) = &page_token {
+                query.push(format!("page_token={token}"));
+            }
+
+            let page: Vec<AlpacaActivityResponse> = self
+                .client()
+                .get(&format!("/v2/account/activities?{}", query.join("&")))
+                .await
+                .map_err(BrokerError::from)?;
+            let page_len = page.len();
+            page_token = page.last().map(|a| a.id.clone());
+
+            for response in &page {
+                if let Some(activity) = response.to_account_activity() {
+                    activities.push(activity);
+                } else {
+                    tracing::warn!(
+                        activity_id = %response.id,
+                        activity_type = %response.activity_type,
+                        "Skipping account activity without a parseable time"
+                    );
+                }
+            }
+
+            if page_len < ACTIVITY_PAGE_SIZE {
+                break;
+            }
+        }
+
+        Ok(activities)
+    }
 }
 
 #[cfg(test)]
@@ -329,4 +501,57 @@ mod tests {
 
         assert_eq!(alpaca_request.time_in_force, "gtc");
     }
+
+    #[test]
+    fn to_alpaca_order_request_oto_stop() {
+        let request = SubmitOrderRequest::limit(
+            OrderId::new("test-order"),
+            Symbol::new("AAPL"),
+            OrderSide::Buy,
+            Decimal::new(100, 0),
+            Decimal::new(150, 0),
+        )
+        .with_oto_exit(OtoExit::stop_loss(Decimal::new(140, 0)));
+
+        let alpaca_request = AlpacaBrokerAdapter::to_alpaca_order_request(&request);
+        let json = serde_json::to_value(&alpaca_request).unwrap();
+
+        assert_eq!(json["order_class"], "oto");
+        assert_eq!(json["stop_loss"]["stop_price"], "140");
+        assert!(json["stop_loss"].get("limit_price").is_none());
+        assert!(json.get("take_profit").is_none());
+    }
+
+    #[test]
+    fn to_alpaca_order_request_oto_target() {
+        let request = SubmitOrderRequest::market(
+            OrderId::new("test-order"),
+            Symbol::new("AAPL"),
+            OrderSide::Sell,
+            Decimal::new(100, 0),
+        )
+        .with_oto_exit(OtoExit::take_profit(Decimal::new(90, 0)));
+
+        let json =
+            serde_json::to_value(AlpacaBrokerAdapter::to_alpaca_order_request(&request)).unwrap();
+
+        assert_eq!(json["order_class"], "oto");
+        assert_eq!(json["take_profit"]["limit_price"], "90");
+        assert!(json.get("stop_loss").is_none());
+    }
+
+    #[test]
+    fn to_alpaca_order_request_simple_has_no_order_class() {
+        let request = SubmitOrderRequest::market(
+            OrderId::new("test-order"),
+            Symbol::new("AAPL"),
+            OrderSide::Buy,
+            Decimal::new(100, 0),
+        );
+
+        let json =
+            serde_json::to_value(AlpacaBrokerAdapter::to_alpaca_order_request(&request)).unwrap();
+
+        assert!(json.get("order_class").is_none());
+    }
 }
@@ -5,9 +5,11 @@
 use rust_decimal::Decimal;
 use serde::{Deserialize, Serialize};
 
-use crate::application::ports::OrderAck;
-use crate::domain::order_execution::value_objects::OrderStatus;
-use crate::domain::shared::{BrokerId, OrderId};
+use crate::application::ports::{
+    AccountActivity, ActivityKind, AssetInfo, LegFill, MarketClock, OrderAck,
+};
+use crate::domain::order_execution::value_objects::{OrderSide, OrderStatus};
+use crate::domain::shared::{BrokerId, OrderId, Symbol, Timestamp};
 
 // ============================================================================
 // Order Request Types
@@ -43,6 +45,32 @@ pub struct AlpacaOrderRequest {
     /// Extended hours trading.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub extended_hours: Option<bool>,
+    /// Order class (`oto` for an entry that arms one exit on fill).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub order_class: Option<String>,
+    /// Take-profit leg of an advanced order.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub take_profit: Option<AlpacaTakeProfit>,
+    /// Stop-loss leg of an advanced order.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub stop_loss: Option<AlpacaStopLoss>,
+}
+
+/// Take-profit leg for Alpaca advanced orders.
+#[derive(Debug, Clone, Serialize)]
+pub struct AlpacaTakeProfit {
+    /// Limit price of the exit.
+    pub limit_price: String,
+}
+
+/// Stop-loss leg for Alpaca advanced orders.
+#[derive(Debug, Clone, Serialize)]
+pub struct AlpacaStopLoss {
+    /// Stop trigger price.
+    pub stop_price: String,
+    /// Limit price once triggered (stop-limit exit).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub limit_price: Option<String>,
 }
 
 // ============================================================================
@@ -92,6 +120,12 @@ pub struct AlpacaOrderResponse {
     /// Filled timestamp.
     #[serde(default)]
     pub filled_at: Option<String>,
+    /// Order class (`simple`, `oto`, `bracket`, `mleg`, ...).
+    #[serde(default)]
+    pub order_class: Option<String>,
+    /// Child orders of an advanced order (`null` for simple orders).
+    #[serde(default)]
+    pub legs: Option<Vec<Self>>,
 }
 
 impl AlpacaOrderResponse {
@@ -104,10 +138,38 @@ impl AlpacaOrderResponse {
             status: parse_order_status(&self.status),
             filled_qty: self.filled_qty.parse().unwrap_or(Decimal::ZERO),
             avg_fill_price: self.filled_avg_price.as_ref().and_then(|p| p.parse().ok()),
+            child_order_ids: self
+                .legs
+                .iter()
+                .flatten()
+                .map(|leg| BrokerId::new(&leg.id))
+                .collect(),
+            leg_fills: if self.order_class.as_deref() == Some("mleg") {
+                self.legs
+                    .iter()
+                    .flatten()
+                    .map(|leg| LegFill {
+                        symbol: leg.symbol.clone(),
+                        filled_qty: leg.filled_qty.parse().unwrap_or(Decimal::ZERO),
+                        avg_fill_price: leg.filled_avg_price.as_ref().and_then(|p| p.parse().ok()),
+                    })
+                    .collect()
+            } else {
+                Vec::new()
+            },
         }
     }
 }
 
+/// Order event from the trading stream or the SSE events endpoint.
+#[derive(Debug, Clone, Deserialize)]
+pub struct AlpacaTradeEvent {
+    /// Event type (`new`, `partial_fill`, `fill`, `canceled`, ...).
+    pub event: String,
+    /// Order state after the event.
+    pub order: AlpacaOrderResponse,
+}
+
 // ============================================================================
 // Account Types
 // ============================================================================
@@ -160,6 +222,174 @@ pub struct AlpacaPositionResponse {
     pub unrealized_pl: String,
 }
 
+// ============================================================================
+// Asset Types
+// ============================================================================
+
+/// Asset response from Alpaca's `/v2/assets` endpoint.
+#[derive(Debug, Clone, Deserialize)]
+#[allow(clippy::struct_excessive_bools)]
+pub struct AlpacaAssetResponse {
+    /// Symbol.
+    pub symbol: String,
+    /// Asset status (`active` or `inactive`).
+    pub status: String,
+    /// Tradable on Alpaca.
+    pub tradable: bool,
+    /// Shortable.
+    #[serde(default)]
+    pub shortable: bool,
+    /// Easy to borrow.
+    #[serde(default)]
+    pub easy_to_borrow: bool,
+    /// Fractional trading supported.
+    #[serde(default)]
+    pub fractionable: bool,
+    /// Marginable.
+    #[serde(default)]
+    pub marginable: bool,
+}
+
+impl AlpacaAssetResponse {
+    /// Convert to `AssetInfo`.
+    #[must_use]
+    pub fn to_asset_info(&self) -> AssetInfo {
+        AssetInfo {
+            symbol: Symbol::normalize(&self.symbol),
+            active: self.status.eq_ignore_ascii_case("active"),
+            tradable: self.tradable,
+            shortable: self.shortable,
+            easy_to_borrow: self.easy_to_borrow,
+            fractionable: self.fractionable,
+            marginable: self.marginable,
+        }
+    }
+}
+
+// ============================================================================
+// Account Activity Types
+// ============================================================================
+
+/// Account activity from Alpaca's `/v2/account/activities` endpoint.
+///
+/// Trade activities (`FILL`) carry `transaction_time`, price, and side;
+/// non-trade activities (`FEE`, `DIV`, ...) carry `date` and `net_amount`.
+#[derive(Debug, Clone, Deserialize)]
+pub struct AlpacaActivityResponse {
+    /// Activity ID (also used as the pagination token).
+    pub id: String,
+    /// Activity type (e.g. `FILL`, `FEE`, `DIV`).
+    pub activity_type: String,
+    /// Symbol.
+    #[serde(default)]
+    pub symbol: Option<String>,
+    /// Broker order ID (fills only).
+    #[serde(default)]
+    pub order_id: Option<String>,
+    /// Side (fills only).
+    #[serde(default)]
+    pub side: Option<String>,
+    /// Quantity.
+    #[serde(default)]
+    pub qty: Option<String>,
+    /// Fill price (fills only).
+    #[serde(default)]
+    pub price: Option<String>,
+    /// Net cash amount (non-trade activities only).
+    #[serde(default)]
+    pub net_amount: Option<String>,
+    /// Description (non-trade activities only).
+    #[serde(default)]
+    pub description: Option<String>,
+    /// Execution time (fills only).
+    #[serde(default)]
+    pub transaction_time: Option<String>,
+    /// Settlement date, `YYYY-MM-DD` (non-trade activities only).
+    #[serde(default)]
+    pub date: Option<String>,
+}
+
+impl AlpacaActivityResponse {
+    /// Convert to `AccountActivity`.
+    ///
+    /// Returns `None` if the activity has no parseable time.
+    #[must_use]
+    pub fn to_account_activity(&self) -> Option<AccountActivity> {
+        let occurred_at = match (&self.transaction_time, &self.date) {
+            (Some(time), _) => Timestamp::parse(time).ok()?,
+            (None, Some(date)) => {
+                let date = chrono::NaiveDate::parse_from_str(date, "%Y-%m-%d").ok()?;
+                Timestamp::new(date.and_hms_opt(0, 0, 0)?.and_utc())
+            }
+            (None, None) => return None,
+        };
+
+        let kind = match self.activity_type.to_uppercase().as_str() {
+            "FILL" => ActivityKind::Fill,
+            "FEE" | "CFEE" | "PTC" => ActivityKind::Fee,
+            "DIV" | "DIVCGL" | "DIVCGS" | "DIVNRA" | "DIVROC" | "DIVTXEX" => ActivityKind::Dividend,
+            _ => ActivityKind::Other,
+        };
+
+        let side = self
+            .side
+            .as_deref()
+            .and_then(|s| match s.to_lowercase().as_str() {
+                "buy" => Some(OrderSide::Buy),
+                "sell" | "sell_short" => Some(OrderSide::Sell),
+                _ => None,
+            });
+
+        Some(AccountActivity {
+            id: self.id.clone(),
+            kind,
+            activity_type: self.activity_type.clone(),
+            symbol: self.symbol.as_deref().map(Symbol::normalize),
+            order_id: self.order_id.clone(),
+            side,
+            qty: self.qty.as_ref().and_then(|q| q.parse().ok()),
+            price: self.price.as_ref().and_then(|p| p.parse().ok()),
+            net_amount: self.net_amount.as_ref().and_then(|a| a.parse().ok()),
+            description: self.description.clone(),
+            occurred_at,
+        })
+    }
+}
+
+// ============================================================================
+// Clock Types
+// ============================================================================
+
+/// Market clock from Alpaca's `/v2/clock` endpoint.
+///
+/// Times are RFC 3339 in exchange local time (e.g. `-04:00`).
+#[derive(Debug, Clone, Deserialize)]
+pub struct AlpacaClockResponse {
+    /// Current broker time.
+    pub timestamp: String,
+    /// Whether the market is open.
+    pub is_open: bool,
+    /// Next market open.
+    pub next_open: String,
+    /// Next market close.
+    pub next_close: String,
+}
+
+impl AlpacaClockResponse {
+    /// Convert to `MarketClock`.
+    ///
+    /// Returns `None` if any time cannot be parsed.
+    #[must_use]
+    pub fn to_market_clock(&self) -> Option<MarketClock> {
+        Some(MarketClock {
+            timestamp: Timestamp::parse(&self.timestamp).ok()?,
+            is_open: self.is_open,
+            next_open: Timestamp::parse(&self.next_open).ok()?,
+            next_close: Timestamp::parse(&self.next_close).ok()?,
+        })
+    }
+}
+
 // ============================================================================
 // Error Types
 // ============================================================================
@@ -206,9 +436,9 @@ pub struct AlpacaSnapshot {
 #[derive(Debug, Clone, Deserialize)]
 pub struct AlpacaQuote {
     /// Bid price.
-    pub bp: f64,
+    pub bp: Decimal,
     /// Ask price.
-    pub ap: f64,
+    pub ap: Decimal,
     /// Bid size.
     pub bs: i32,
     /// Ask size.
@@ -222,7 +452,7 @@ pub struct AlpacaQuote {
 #[derive(Debug, Clone, Deserialize)]
 pub struct AlpacaTrade {
     /// Trade price.
-    pub p: f64,
+    pub p: Decimal,
     /// Trade size.
     pub s: i32,
     /// Timestamp.
@@ -233,13 +463,13 @@ pub struct AlpacaTrade {
 #[derive(Debug, Clone, Deserialize)]
 pub struct AlpacaBar {
     /// Open price.
-    pub o: f64,
+    pub o: Decimal,
     /// High price.
-    pub h: f64,
+    pub h: Decimal,
     /// Low price.
-    pub l: f64,
+    pub l: Decimal,
     /// Close price.
-    pub c: f64,
+    pub c: Decimal,
     /// Volume.
     pub v: i64,
 }
@@ -265,15 +495,18 @@ pub struct AlpacaOptionSnapshot {
     /// Implied volatility.
     #[serde(rename = "impliedVolatility")]
     pub implied_volatility: Option<f64>,
+    /// Current day's bar.
+    #[serde(rename = "dailyBar")]
+    pub daily_bar: Option<AlpacaBar>,
 }
 
 /// Option quote from Alpaca.
 #[derive(Debug, Clone, Deserialize)]
 pub struct AlpacaOptionQuote {
     /// Bid price.
-    pub bp: f64,
+    pub bp: Decimal,
     /// Ask price.
-    pub ap: f64,
+    pub ap: Decimal,
     /// Bid size.
     pub bs: i32,
     /// Ask size.
@@ -287,7 +520,7 @@ pub struct AlpacaOptionQuote {
 #[derive(Debug, Clone, Deserialize)]
 pub struct AlpacaOptionTrade {
     /// Trade price.
-    pub p: f64,
+    pub p: Decimal,
     /// Trade size.
     pub s: i32,
     /// Timestamp.
@@ -477,6 +710,8 @@ mod tests {
             updated_at: "2024-01-15T10:05:00Z".to_string(),
             submitted_at: "2024-01-15T10:00:00Z".to_string(),
             filled_at: None,
+            order_class: None,
+            legs: None,
         };
 
         let ack = response.to_order_ack();
@@ -485,6 +720,151 @@ mod tests {
         assert_eq!(ack.status, OrderStatus::PartiallyFilled);
         assert_eq!(ack.filled_qty, Decimal::new(50, 0));
         assert_eq!(ack.avg_fill_price, Some(Decimal::new(15025, 2)));
+        assert!(ack.child_order_ids.is_empty());
+    }
+
+    #[test]
+    fn alpaca_oto_order_response_tracks_child() {
+        let response: AlpacaOrderResponse = serde_json::from_str(
+            r#"{
+                "id": "parent-1",
+                "client_order_id": "client-1",
+                "symbol": "AAPL",
+                "qty": "10",
+                "filled_qty": "0",
+                "status": "new",
+                "side": "buy",
+                "type": "limit",
+                "time_in_force": "gtc",
+                "limit_price": "150",
+                "order_class": "oto",
+                "created_at": "2024-01-15T10:00:00Z",
+                "updated_at": "2024-01-15T10:00:00Z",
+                "submitted_at": "2024-01-15T10:00:00Z",
+                "legs": [{
+                    "id": "child-1",
+                    "client_order_id": "client-1-stop",
+                    "symbol": "AAPL",
+                    "qty": "10",
+                    "filled_qty": "0",
+                    "status": "held",
+                    "side": "sell",
+                    "type": "stop",
+                    "time_in_force": "gtc",
+                    "stop_price": "140",
+                    "created_at": "2024-01-15T10:00:00Z",
+                    "updated_at": "2024-01-15T10:00:00Z",
+                    "submitted_at": "2024-01-15T10:00:00Z",
+                    "legs": null
+                }]
+            }"#,
+        )
+        .unwrap();
+
+        let ack = response.to_order_ack();
+        assert_eq!(ack.child_order_ids, [BrokerId::new("child-1")]);
+        assert!(ack.leg_fills.is_empty());
+    }
+
+    #[test]
+    fn alpaca_mleg_order_response_tracks_leg_fills() {
+        let leg = |id: &str, symbol: &str, filled: &str, price: &str| {
+            format!(
+                r#"{{"id": "{id}", "client_order_id": "{id}", "symbol": "{symbol}",
+                "qty": "2", "filled_qty": "{filled}", "filled_avg_price": {price},
+                "status": "new", "side": "buy", "type": "limit", "time_in_force": "day",
+                "created_at": "2024-01-15T10:00:00Z", "updated_at": "2024-01-15T10:00:00Z",
+                "submitted_at": "2024-01-15T10:00:00Z"}}"#
+            )
+        };
+        let json = format!(
+            r#"{{"id": "parent-1", "client_order_id": "client-1", "symbol": "",
+            "qty": "2", "filled_qty": "0", "status": "partially_filled", "side": "buy",
+            "type": "limit", "time_in_force": "day", "order_class": "mleg",
+            "created_at": "2024-01-15T10:00:00Z", "updated_at": "2024-01-15T10:00:00Z",
+            "submitted_at": "2024-01-15T10:00:00Z", "legs": [{}, {}]}}"#,
+            leg("leg-1", "SPY250620C00500000", "2", r#""4.10""#),
+            leg("leg-2", "SPY250620C00510000", "0", "null"),
+        );
+        let response: AlpacaOrderResponse = serde_json::from_str(&json).unwrap();
+
+        let ack = response.to_order_ack();
+        assert_eq!(ack.leg_fills.len(), 2);
+        assert_eq!(ack.leg_fills[0].symbol, "SPY250620C00500000");
+        assert_eq!(ack.leg_fills[0].filled_qty, Decimal::TWO);
+        assert_eq!(ack.leg_fills[0].avg_fill_price, Some(Decimal::new(410, 2)));
+        assert_eq!(ack.leg_fills[1].avg_fill_price, None);
+    }
+
+    #[test]
+    fn alpaca_asset_to_asset_info() {
+        let response: AlpacaAssetResponse = serde_json::from_str(
+            r#"{
+                "id": "b0b6dd9d-8b9b-48a9-ba46-b9d54906e415",
+                "class": "us_equity",
+                "exchange": "NASDAQ",
+                "symbol": "GME",
+                "status": "active",
+                "tradable": true,
+                "marginable": true,
+                "shortable": true,
+                "easy_to_borrow": false,
+                "fractionable": true
+            }"#,
+        )
+        .unwrap();
+
+        let info = response.to_asset_info();
+        assert!(info.active);
+        assert!(info.shortable);
+        assert!(!info.easy_to_borrow);
+    }
+
+    #[test]
+    fn alpaca_fill_activity_to_account_activity() {
+        let response: AlpacaActivityResponse = serde_json::from_str(
+            r#"{
+                "id": "20240115100000000::8e8b",
+                "activity_type": "FILL",
+                "transaction_time": "2024-01-15T15:00:00Z",
+                "type": "fill",
+                "price": "150.25",
+                "qty": "10",
+                "side": "sell",
+                "symbol": "AAPL",
+                "order_id": "broker-123"
+            }"#,
+        )
+        .unwrap();
+
+        let activity = response.to_account_activity().unwrap();
+        assert_eq!(activity.kind, ActivityKind::Fill);
+        assert_eq!(activity.side, Some(OrderSide::Sell));
+        assert_eq!(activity.order_id.as_deref(), Some("broker-123"));
+        assert_eq!(activity.fill_notional(), Some(Decimal::new(150_250, 2)));
+    }
+
+    #[test]
+    fn alpaca_fee_activity_to_account_activity() {
+        let response: AlpacaActivityResponse = serde_json::from_str(
+            r#"{
+                "id": "20240115000000000::1a2b",
+                "activity_type": "FEE",
+                "date": "2024-01-15",
+                "net_amount": "-0.02",
+                "symbol": "AAPL",
+                "description": "REG/TAF fee"
+            }"#,
+        )
+        .unwrap();
+
+        let activity = response.to_account_activity().unwrap();
+        assert_eq!(activity.kind, ActivityKind::Fee);
+        assert_eq!(activity.net_amount, Some(Decimal::new(-2, 2)));
+        assert_eq!(
+            activity.occurred_at,
+            Timestamp::parse("2024-01-15T00:00:00Z").unwrap()
+        );
     }
 
     #[test]
@@ -528,4 +908,29 @@ mod tests {
     fn parse_occ_symbol_invalid_type() {
         assert!(ParsedOptionContract::from_occ_symbol("AAPL  240119X00150000").is_none());
     }
+
+    #[test]
+    fn alpaca_clock_to_market_clock() {
+        let json = r#"{
+            "timestamp": "2026-03-13T15:50:00.123456789-04:00",
+            "is_open": true,
+            "next_open": "2026-03-16T09:30:00-04:00",
+            "next_close": "2026-03-13T16:00:00-04:00"
+        }"#;
+        let response: AlpacaClockResponse = serde_json::from_str(json).unwrap();
+        let clock = response.to_market_clock().unwrap();
+
+        assert!(clock.is_open);
+        assert_eq!(
+            clock.next_close,
+            Timestamp::parse("2026-03-13T20:00:00Z").unwrap()
+        );
+        assert_eq!(
+            clock
+                .next_close
+                .duration_since(clock.timestamp)
+                .num_minutes(),
+            9
+        );
+    }
 }
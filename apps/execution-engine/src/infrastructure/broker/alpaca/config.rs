@@ -2,6 +2,8 @@
 
 use std::time::Duration;
 
+use crate::infrastructure::secrets::{AlpacaCredentials, REDACTED};
+
 /// Environment for Alpaca API.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum AlpacaEnvironment {
@@ -44,7 +46,7 @@ impl std::fmt::Display for AlpacaEnvironment {
 }
 
 /// Configuration for the Alpaca broker adapter.
-#[derive(Debug, Clone)]
+#[derive(Clone)]
 pub struct AlpacaConfig {
     /// API key.
     pub api_key: String,
@@ -56,6 +58,25 @@ pub struct AlpacaConfig {
     pub timeout: Duration,
     /// Retry policy configuration.
     pub retry: RetryConfig,
+    /// Adapt `retry` per endpoint to recent outcomes (static if `None`).
+    pub adaptive_retry: Option<AdaptiveRetryConfig>,
+    /// Base URL for both the trading and data APIs, replacing the
+    /// environment's hosts (used to point the adapters at a mock server).
+    pub base_url: Option<String>,
+}
+
+impl std::fmt::Debug for AlpacaConfig {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("AlpacaConfig")
+            .field("api_key", &REDACTED)
+            .field("api_secret", &REDACTED)
+            .field("environment", &self.environment)
+            .field("timeout", &self.timeout)
+            .field("retry", &self.retry)
+            .field("adaptive_retry", &self.adaptive_retry)
+            .field("base_url", &self.base_url)
+            .finish()
+    }
 }
 
 impl AlpacaConfig {
@@ -68,9 +89,30 @@ impl AlpacaConfig {
             environment,
             timeout: Duration::from_secs(30),
             retry: RetryConfig::default(),
+            adaptive_retry: None,
+            base_url: None,
         }
     }
 
+    /// Create a configuration from provider credentials.
+    #[must_use]
+    pub fn from_credentials(
+        credentials: &AlpacaCredentials,
+        environment: AlpacaEnvironment,
+    ) -> Self {
+        Self::new(
+            credentials.api_key.expose().to_string(),
+            credentials.api_secret.expose().to_string(),
+            environment,
+        )
+    }
+
+    /// The API credentials.
+    #[must_use]
+    pub fn credentials(&self) -> AlpacaCredentials {
+        AlpacaCredentials::new(self.api_key.clone(), self.api_secret.clone())
+    }
+
     /// Set the HTTP timeout.
     #[must_use]
     pub const fn with_timeout(mut self, timeout: Duration) -> Self {
@@ -85,16 +127,34 @@ impl AlpacaConfig {
         self
     }
 
+    /// Adapt the retry configuration per endpoint to recent outcomes.
+    #[must_use]
+    pub const fn with_adaptive_retry(mut self, adaptive: AdaptiveRetryConfig) -> Self {
+        self.adaptive_retry = Some(adaptive);
+        self
+    }
+
+    /// Serve both APIs from `base_url` instead of Alpaca's hosts.
+    #[must_use]
+    pub fn with_base_url(mut self, base_url: impl Into<String>) -> Self {
+        self.base_url = Some(base_url.into());
+        self
+    }
+
     /// Get the trading API base URL.
     #[must_use]
-    pub const fn trading_base_url(&self) -> &'static str {
-        self.environment.trading_base_url()
+    pub fn trading_base_url(&self) -> &str {
+        self.base_url
+            .as_deref()
+            .unwrap_or_else(|| self.environment.trading_base_url())
     }
 
     /// Get the data API base URL.
     #[must_use]
-    pub const fn data_base_url(&self) -> &'static str {
-        self.environment.data_base_url()
+    pub fn data_base_url(&self) -> &str {
+        self.base_url
+            .as_deref()
+            .unwrap_or_else(|| self.environment.data_base_url())
     }
 }
 
@@ -122,6 +182,40 @@ impl Default for RetryConfig {
     }
 }
 
+/// Adaptive retry configuration.
+///
+/// Each endpoint's success rate over recent attempts scales its retry
+/// budget: at or above `healthy_success_rate` requests get the configured
+/// attempts and backoff; at or below `failing_success_rate` they are not
+/// retried; in between the retries shrink and the backoff grows with the
+/// rate. Rate limits count as failures, and the longest `Retry-After` seen
+/// in the window is a floor on the backoff.
+#[derive(Debug, Clone, PartialEq)]
+pub struct AdaptiveRetryConfig {
+    /// How long an attempt's outcome counts.
+    pub window: Duration,
+    /// Most outcomes kept per endpoint.
+    pub max_samples: usize,
+    /// Outcomes needed before an endpoint's retries adapt.
+    pub min_samples: usize,
+    /// Success rate at which the configured retries apply in full.
+    pub healthy_success_rate: f64,
+    /// Success rate at or below which requests are not retried.
+    pub failing_success_rate: f64,
+}
+
+impl Default for AdaptiveRetryConfig {
+    fn default() -> Self {
+        Self {
+            window: Duration::from_secs(300),
+            max_samples: 100,
+            min_samples: 10,
+            healthy_success_rate: 0.9,
+            failing_success_rate: 0.5,
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     #![allow(clippy::float_cmp)]
@@ -153,6 +247,19 @@ mod tests {
         assert!(!config.environment.is_live());
     }
 
+    #[test]
+    fn config_debug_redacts_credentials() {
+        let config = AlpacaConfig::new(
+            "PKTEST123".to_string(),
+            "hunter2".to_string(),
+            AlpacaEnvironment::Paper,
+        );
+        let debug = format!("{config:?}");
+        assert!(!debug.contains("PKTEST123"));
+        assert!(!debug.contains("hunter2"));
+        assert!(debug.contains("Paper"));
+    }
+
     #[test]
     fn config_with_timeout() {
         let config = AlpacaConfig::new(
@@ -201,6 +308,19 @@ mod tests {
         assert!(config.data_base_url().contains("data.alpaca"));
     }
 
+    #[test]
+    fn config_with_base_url_overrides_both_apis() {
+        let config = AlpacaConfig::new(
+            "key".to_string(),
+            "secret".to_string(),
+            AlpacaEnvironment::Paper,
+        )
+        .with_base_url("http://127.0.0.1:8080");
+        assert_eq!(config.trading_base_url(), "http://127.0.0.1:8080");
+        assert_eq!(config.data_base_url(), "http://127.0.0.1:8080");
+        assert!(!config.environment.is_live());
+    }
+
     #[test]
     fn environment_display() {
         assert_eq!(format!("{}", AlpacaEnvironment::Paper), "PAPER");
@@ -87,9 +87,7 @@ impl From<AlpacaError> for BrokerError {
             AlpacaError::OrderRejected(msg) | AlpacaError::InvalidOrder(msg) => {
                 Self::OrderRejected { reason: msg }
             }
-            AlpacaError::AuthenticationFailed => Self::Unknown {
-                message: "Authentication failed".to_string(),
-            },
+            AlpacaError::AuthenticationFailed => Self::AuthenticationFailed,
             AlpacaError::Forbidden(msg) => Self::OrderRejected { reason: msg },
             AlpacaError::RateLimited { .. } => Self::RateLimited,
             AlpacaError::EnvironmentMismatch { expected, actual } => Self::Unknown {
@@ -118,7 +116,7 @@ mod tests {
     fn alpaca_error_to_broker_error_auth() {
         let err = AlpacaError::AuthenticationFailed;
         let broker_err: BrokerError = err.into();
-        assert!(matches!(broker_err, BrokerError::Unknown { .. }));
+        assert!(matches!(broker_err, BrokerError::AuthenticationFailed));
     }
 
     #[test]
@@ -1,24 +1,76 @@
 //! HTTP client wrapper with retry logic.
-
+//!
+//! Network errors, 408 and 5xx responses are retried with exponential
+//! backoff and 429s after their `Retry-After`. Order placement and position
+//! closes are only retried when the broker cannot have acted on them: on a
+//! 429 or when the connection was never made. With adaptive retry, each
+//! endpoint's attempts and backoff follow its recent outcomes.
+
+use std::sync::Arc;
 use std::time::Duration;
 
-use reqwest::{Client, StatusCode};
+use reqwest::{Client, RequestBuilder, StatusCode};
 use serde::Serialize;
 use serde::de::DeserializeOwned;
 
 use super::api_types::AlpacaErrorResponse;
 use super::config::{AlpacaConfig, RetryConfig};
 use super::error::AlpacaError;
+use super::retry::{AdaptiveRetry, Idempotency, Outcome, endpoint};
+use crate::application::ports::OAuthError;
+use crate::application::services::{BrokerAccountError, BrokerAccountService};
+use crate::infrastructure::secrets::{AlpacaCredentials, REDACTED};
+
+/// How requests authenticate.
+#[derive(Clone)]
+enum Auth {
+    /// `APCA-API-KEY-ID` and `APCA-API-SECRET-KEY` headers.
+    ApiKey { api_key: String, api_secret: String },
+    /// OAuth bearer token of a connected user's account.
+    OAuth {
+        accounts: Arc<BrokerAccountService>,
+        user_id: String,
+    },
+}
+
+impl std::fmt::Debug for Auth {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::ApiKey { .. } => f
+                .debug_struct("ApiKey")
+                .field("api_key", &REDACTED)
+                .field("api_secret", &REDACTED)
+                .finish(),
+            Self::OAuth { user_id, .. } => f
+                .debug_struct("OAuth")
+                .field("user_id", user_id)
+                .finish_non_exhaustive(),
+        }
+    }
+}
 
 /// HTTP client for Alpaca API with retry logic.
-#[derive(Debug, Clone)]
+#[derive(Clone)]
 pub struct AlpacaHttpClient {
     client: Client,
-    api_key: String,
-    api_secret: String,
+    auth: Auth,
     trading_base_url: String,
     data_base_url: String,
     retry_config: RetryConfig,
+    /// Outcome history shared by clones (`None` for static retries).
+    adaptive_retry: Option<Arc<AdaptiveRetry>>,
+}
+
+impl std::fmt::Debug for AlpacaHttpClient {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("AlpacaHttpClient")
+            .field("auth", &self.auth)
+            .field("trading_base_url", &self.trading_base_url)
+            .field("data_base_url", &self.data_base_url)
+            .field("retry_config", &self.retry_config)
+            .field("adaptive_retry", &self.adaptive_retry.is_some())
+            .finish_non_exhaustive()
+    }
 }
 
 impl AlpacaHttpClient {
@@ -28,6 +80,35 @@ impl AlpacaHttpClient {
             return Err(AlpacaError::AuthenticationFailed);
         }
 
+        Self::build(
+            config,
+            Auth::ApiKey {
+                api_key: config.api_key.clone(),
+                api_secret: config.api_secret.clone(),
+            },
+        )
+    }
+
+    /// Create a client authenticating with the OAuth token of `user_id`
+    /// instead of the config's API key.
+    ///
+    /// # Errors
+    ///
+    /// Returns error if the user ID is empty.
+    pub fn with_oauth(
+        config: &AlpacaConfig,
+        accounts: Arc<BrokerAccountService>,
+        user_id: impl Into<String>,
+    ) -> Result<Self, AlpacaError> {
+        let user_id = user_id.into();
+        if user_id.is_empty() {
+            return Err(AlpacaError::AuthenticationFailed);
+        }
+
+        Self::build(config, Auth::OAuth { accounts, user_id })
+    }
+
+    fn build(config: &AlpacaConfig, auth: Auth) -> Result<Self, AlpacaError> {
         let client = Client::builder()
             .timeout(config.timeout)
             .build()
@@ -35,11 +116,33 @@ impl AlpacaHttpClient {
 
         Ok(Self {
             client,
-            api_key: config.api_key.clone(),
-            api_secret: config.api_secret.clone(),
+            auth,
             trading_base_url: config.trading_base_url().to_string(),
             data_base_url: config.data_base_url().to_string(),
             retry_config: config.retry.clone(),
+            adaptive_retry: config
+                .adaptive_retry
+                .clone()
+                .map(|adaptive| Arc::new(AdaptiveRetry::new(adaptive))),
+        })
+    }
+
+    /// Create a client with the same settings and different credentials.
+    ///
+    /// # Errors
+    ///
+    /// Returns error if either credential is empty.
+    pub fn with_credentials(&self, credentials: &AlpacaCredentials) -> Result<Self, AlpacaError> {
+        if credentials.api_key.is_empty() || credentials.api_secret.is_empty() {
+            return Err(AlpacaError::AuthenticationFailed);
+        }
+
+        Ok(Self {
+            auth: Auth::ApiKey {
+                api_key: credentials.api_key.expose().to_string(),
+                api_secret: credentials.api_secret.expose().to_string(),
+            },
+            ..self.clone()
         })
     }
 
@@ -86,14 +189,28 @@ impl AlpacaHttpClient {
         body: Option<serde_json::Value>,
     ) -> Result<T, AlpacaError> {
         let url = format!("{base_url}{path}");
-        let mut backoff = ExponentialBackoff::new(&self.retry_config);
+        let endpoint = endpoint(method, path);
+        let idempotency = Idempotency::of(method, path);
+        let mut backoff = ExponentialBackoff::new(&self.retry_policy(&endpoint));
 
         loop {
-            let request = self.build_request(method, &url, body.as_ref())?;
+            let request = self
+                .authenticate(self.build_request(method, &url, body.as_ref())?)
+                .await?;
 
             let response = match request.send().await {
                 Ok(resp) => resp,
                 Err(e) => {
+                    self.record(&endpoint, Outcome::Failed);
+                    // The broker may have the request unless it was never sent
+                    if idempotency == Idempotency::NonIdempotent && !e.is_connect() {
+                        tracing::warn!(
+                            error = %e,
+                            endpoint = %endpoint,
+                            "Network error on a non-idempotent request, not retrying"
+                        );
+                        return Err(AlpacaError::Network(e.to_string()));
+                    }
                     if let Some(delay) = backoff.next_backoff() {
                         tracing::warn!(
                             error = %e,
@@ -111,14 +228,35 @@ impl AlpacaHttpClient {
             };
 
             let status = response.status();
+            let retry_after = response
+                .headers()
+                .get("Retry-After")
+                .and_then(|v| v.to_str().ok())
+                .and_then(|v| v.parse::<u64>().ok());
+            self.record(
+                &endpoint,
+                match categorize_status(status) {
+                    ErrorCategory::RateLimited => {
+                        Outcome::RateLimited(retry_after.map(Duration::from_secs))
+                    }
+                    ErrorCategory::Retryable => Outcome::Failed,
+                    ErrorCategory::NonRetryable => Outcome::Answered,
+                },
+            );
 
             if status.is_success() {
                 return Self::parse_success_response(response).await;
             }
 
-            match self
-                .handle_error_response(response, status, path, &mut backoff)
-                .await
+            match Self::handle_error_response(
+                response,
+                status,
+                path,
+                retry_after,
+                idempotency,
+                &mut backoff,
+            )
+            .await
             {
                 ErrorAction::Retry(delay) => {
                     tokio::time::sleep(delay).await;
@@ -128,13 +266,39 @@ impl AlpacaHttpClient {
         }
     }
 
-    /// Build a request with authentication headers.
+    /// Retry configuration for the next request to `endpoint`.
+    fn retry_policy(&self, endpoint: &str) -> RetryConfig {
+        let Some(adaptive) = &self.adaptive_retry else {
+            return self.retry_config.clone();
+        };
+        let policy = adaptive.policy(endpoint, &self.retry_config);
+        if policy.max_attempts != self.retry_config.max_attempts
+            || policy.initial_backoff != self.retry_config.initial_backoff
+        {
+            tracing::debug!(
+                endpoint = %endpoint,
+                max_attempts = policy.max_attempts,
+                initial_backoff_ms = policy.initial_backoff.as_millis(),
+                "Adapted retry policy"
+            );
+        }
+        policy
+    }
+
+    /// Record an attempt's outcome for adaptive retry.
+    fn record(&self, endpoint: &str, outcome: Outcome) {
+        if let Some(adaptive) = &self.adaptive_retry {
+            adaptive.record(endpoint, outcome);
+        }
+    }
+
+    /// Build a request without authentication.
     fn build_request(
         &self,
         method: &str,
         url: &str,
         body: Option<&serde_json::Value>,
-    ) -> Result<reqwest::RequestBuilder, AlpacaError> {
+    ) -> Result<RequestBuilder, AlpacaError> {
         let request = match method {
             "GET" => self.client.get(url),
             "POST" => {
@@ -148,9 +312,29 @@ impl AlpacaHttpClient {
             _ => return Err(AlpacaError::Http(format!("Unsupported method: {method}"))),
         };
 
-        Ok(request
-            .header("APCA-API-KEY-ID", &self.api_key)
-            .header("APCA-API-SECRET-KEY", &self.api_secret))
+        Ok(request)
+    }
+
+    /// Add the API key headers or the user's bearer token.
+    async fn authenticate(&self, request: RequestBuilder) -> Result<RequestBuilder, AlpacaError> {
+        match &self.auth {
+            Auth::ApiKey {
+                api_key,
+                api_secret,
+            } => Ok(request
+                .header("APCA-API-KEY-ID", api_key)
+                .header("APCA-API-SECRET-KEY", api_secret)),
+            Auth::OAuth { accounts, user_id } => match accounts.access_token(user_id).await {
+                Ok(token) => Ok(request.bearer_auth(token)),
+                Err(BrokerAccountError::OAuth(OAuthError::Unavailable { message })) => {
+                    Err(AlpacaError::Network(message))
+                }
+                Err(e) => {
+                    tracing::warn!(user_id = %user_id, error = %e, "No usable OAuth token");
+                    Err(AlpacaError::AuthenticationFailed)
+                }
+            },
+        }
     }
 
     /// Parse a successful response body.
@@ -168,18 +352,13 @@ impl AlpacaHttpClient {
 
     /// Handle an error response and determine retry behavior.
     async fn handle_error_response(
-        &self,
         response: reqwest::Response,
         status: StatusCode,
         path: &str,
+        retry_after: Option<u64>,
+        idempotency: Idempotency,
         backoff: &mut ExponentialBackoff,
     ) -> ErrorAction {
-        let retry_after = response
-            .headers()
-            .get("Retry-After")
-            .and_then(|v| v.to_str().ok())
-            .and_then(|v| v.parse::<u64>().ok());
-
         let error_body = response.text().await.unwrap_or_default();
         let (error_code, error_message) = Self::parse_error_body(&error_body, status);
 
@@ -187,6 +366,21 @@ impl AlpacaHttpClient {
             ErrorCategory::RateLimited => {
                 Self::handle_rate_limited(retry_after, backoff, &error_code)
             }
+            // A rate-limited request was not accepted, but a failed one may
+            // have been
+            ErrorCategory::Retryable if idempotency == Idempotency::NonIdempotent => {
+                tracing::warn!(
+                    code = %error_code,
+                    message = %error_message,
+                    "Retryable error on a non-idempotent request, not retrying"
+                );
+                ErrorAction::Fail(Self::map_non_retryable_error(
+                    status,
+                    path,
+                    error_code,
+                    error_message,
+                ))
+            }
             ErrorCategory::Retryable => {
                 Self::handle_retryable(backoff, &error_code, &error_message)
             }
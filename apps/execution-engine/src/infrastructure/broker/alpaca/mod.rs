@@ -2,16 +2,27 @@
 //!
 //! Production-grade implementation of `BrokerPort` for Alpaca Markets API with:
 //! - Full HTTP API integration
-//! - Retry logic with exponential backoff
+//! - Retry logic with exponential backoff, optionally adapted per endpoint
+//!   to recent outcomes, that never repeats a possibly accepted order
 //! - Environment-aware safety checks (PAPER vs LIVE)
 //! - Multi-leg options support
+//! - API key or per-user OAuth bearer token authentication
+//! - Order update streams over the trading WebSocket or SSE events endpoint
 
 mod adapter;
 pub mod api_types;
 mod config;
 mod error;
 mod http_client;
+mod oauth;
+mod order_updates;
+mod retry;
 
 pub use adapter::AlpacaBrokerAdapter;
-pub use config::{AlpacaConfig, AlpacaEnvironment};
+pub use config::{AdaptiveRetryConfig, AlpacaConfig, AlpacaEnvironment, RetryConfig};
 pub use error::AlpacaError;
+pub use oauth::{
+    AlpacaOAuthClient, AlpacaOAuthConfig, DEFAULT_OAUTH_AUTHORIZE_URL, DEFAULT_OAUTH_SCOPE,
+    DEFAULT_OAUTH_TOKEN_URL,
+};
+pub use order_updates::AlpacaOrderUpdateStream;
@@ -0,0 +1,274 @@
+//! Alpaca OAuth client.
+//!
+//! Lets end users grant the engine access to their own Alpaca accounts with
+//! the authorization code flow. Tokens are used as `Authorization: Bearer`
+//! headers instead of the `APCA-API-*` key headers.
+
+use std::time::Duration;
+
+use async_trait::async_trait;
+use reqwest::{Client, Url};
+use serde::Deserialize;
+
+use super::error::AlpacaError;
+use crate::application::ports::{OAuthAuthorizationPort, OAuthError, OAuthToken};
+use crate::domain::shared::Timestamp;
+use crate::infrastructure::secrets::Secret;
+
+/// Page users grant access at.
+pub const DEFAULT_OAUTH_AUTHORIZE_URL: &str = "https://app.alpaca.markets/oauth/authorize";
+
+/// Endpoint codes and refresh tokens are exchanged at.
+pub const DEFAULT_OAUTH_TOKEN_URL: &str = "https://api.alpaca.markets/oauth/token";
+
+/// Scopes requested by default.
+pub const DEFAULT_OAUTH_SCOPE: &str = "account:write trading";
+
+/// OAuth application registered with Alpaca.
+#[derive(Debug, Clone)]
+pub struct AlpacaOAuthConfig {
+    /// OAuth client ID.
+    pub client_id: String,
+    /// OAuth client secret.
+    pub client_secret: Secret,
+    /// Redirect URI registered for the application.
+    pub redirect_uri: String,
+    /// Requested scopes, space separated.
+    pub scope: String,
+    /// Authorization page.
+    pub authorize_url: String,
+    /// Token endpoint.
+    pub token_url: String,
+    /// HTTP request timeout.
+    pub timeout: Duration,
+}
+
+impl AlpacaOAuthConfig {
+    /// Configuration for Alpaca's OAuth endpoints.
+    #[must_use]
+    pub fn new(
+        client_id: impl Into<String>,
+        client_secret: impl Into<String>,
+        redirect_uri: impl Into<String>,
+    ) -> Self {
+        Self {
+            client_id: client_id.into(),
+            client_secret: Secret::new(client_secret),
+            redirect_uri: redirect_uri.into(),
+            scope: DEFAULT_OAUTH_SCOPE.to_string(),
+            authorize_url: DEFAULT_OAUTH_AUTHORIZE_URL.to_string(),
+            token_url: DEFAULT_OAUTH_TOKEN_URL.to_string(),
+            timeout: Duration::from_secs(30),
+        }
+    }
+
+    /// Request `scope` instead of the default scopes.
+    #[must_use]
+    pub fn with_scope(mut self, scope: impl Into<String>) -> Self {
+        self.scope = scope.into();
+        self
+    }
+
+    /// Exchange codes at `token_url` (used to point at a mock server).
+    #[must_use]
+    pub fn with_token_url(mut self, token_url: impl Into<String>) -> Self {
+        self.token_url = token_url.into();
+        self
+    }
+}
+
+/// Token endpoint response.
+#[derive(Debug, Deserialize)]
+struct TokenResponse {
+    access_token: String,
+    #[serde(default)]
+    refresh_token: Option<String>,
+    #[serde(default)]
+    scope: String,
+    #[serde(default)]
+    expires_in: Option<u64>,
+}
+
+/// Token endpoint error body, in OAuth or Alpaca form.
+#[derive(Debug, Deserialize)]
+struct TokenError {
+    #[serde(default, alias = "message")]
+    error_description: Option<String>,
+    #[serde(default)]
+    error: Option<String>,
+}
+
+/// Client for Alpaca's OAuth endpoints.
+#[derive(Debug, Clone)]
+pub struct AlpacaOAuthClient {
+    client: Client,
+    config: AlpacaOAuthConfig,
+    authorize_url: Url,
+}
+
+impl AlpacaOAuthClient {
+    /// Create a client for `config`.
+    ///
+    /// # Errors
+    ///
+    /// Returns error if the client ID, secret or redirect URI is empty, the
+    /// authorization URL is invalid, or the HTTP client cannot be built.
+    pub fn new(config: AlpacaOAuthConfig) -> Result<Self, AlpacaError> {
+        if config.client_id.is_empty()
+            || config.client_secret.is_empty()
+            || config.redirect_uri.is_empty()
+        {
+            return Err(AlpacaError::AuthenticationFailed);
+        }
+        let authorize_url = Url::parse(&config.authorize_url)
+            .map_err(|e| AlpacaError::Http(format!("invalid OAuth authorize URL: {e}")))?;
+        let client = Client::builder()
+            .timeout(config.timeout)
+            .build()
+            .map_err(|e| AlpacaError::Network(e.to_string()))?;
+
+        Ok(Self {
+            client,
+            config,
+            authorize_url,
+        })
+    }
+
+    /// Post a grant to the token endpoint.
+    async fn grant(&self, params: &[(&str, &str)]) -> Result<OAuthToken, OAuthError> {
+        let mut form = vec![
+            ("client_id", self.config.client_id.as_str()),
+            ("client_secret", self.config.client_secret.expose()),
+        ];
+        form.extend_from_slice(params);
+
+        let response = self
+            .client
+            .post(&self.config.token_url)
+            .header("Content-Type", "application/x-www-form-urlencoded")
+            .body(form_encode(&form))
+            .send()
+            .await
+            .map_err(|e| OAuthError::Unavailable {
+                message: e.to_string(),
+            })?;
+
+        let status = response.status();
+        let body = response.text().await.map_err(|e| OAuthError::Unavailable {
+            message: e.to_string(),
+        })?;
+        if status.is_server_error() {
+            return Err(OAuthError::Unavailable {
+                message: format!("token endpoint returned {status}"),
+            });
+        }
+        if !status.is_success() {
+            let message = serde_json::from_str::<TokenError>(&body)
+                .ok()
+                .and_then(|e| e.error_description.or(e.error))
+                .unwrap_or_else(|| status.to_string());
+            return Err(OAuthError::Rejected { message });
+        }
+
+        let token: TokenResponse =
+            serde_json::from_str(&body).map_err(|e| OAuthError::Rejected {
+                message: format!("invalid token response: {e}"),
+            })?;
+        let obtained_at = Timestamp::now();
+        let expires_at = token
+            .expires_in
+            .and_then(|secs| chrono::Duration::try_seconds(i64::try_from(secs).ok()?))
+            .and_then(|ttl| obtained_at.as_datetime().checked_add_signed(ttl))
+            .map(Timestamp::new);
+        Ok(OAuthToken {
+            access_token: token.access_token,
+            refresh_token: token.refresh_token,
+            scope: token.scope,
+            obtained_at,
+            expires_at,
+        })
+    }
+}
+
+#[async_trait]
+impl OAuthAuthorizationPort for AlpacaOAuthClient {
+    fn authorize_url(&self, state: &str) -> String {
+        let mut url = self.authorize_url.clone();
+        url.query_pairs_mut()
+            .append_pair("response_type", "code")
+            .append_pair("client_id", &self.config.client_id)
+            .append_pair("redirect_uri", &self.config.redirect_uri)
+            .append_pair("scope", &self.config.scope)
+            .append_pair("state", state);
+        url.to_string()
+    }
+
+    async fn exchange_code(&self, code: &str) -> Result<OAuthToken, OAuthError> {
+        self.grant(&[
+            ("grant_type", "authorization_code"),
+            ("code", code),
+            ("redirect_uri", &self.config.redirect_uri),
+        ])
+        .await
+    }
+
+    async fn refresh(&self, refresh_token: &str) -> Result<OAuthToken, OAuthError> {
+        self.grant(&[
+            ("grant_type", "refresh_token"),
+            ("refresh_token", refresh_token),
+        ])
+        .await
+    }
+}
+
+/// `application/x-www-form-urlencoded` body of `params`.
+fn form_encode(params: &[(&str, &str)]) -> String {
+    params
+        .iter()
+        .map(|(name, value)| format!("{}={}", percent_encode(name), percent_encode(value)))
+        .collect::<Vec<_>>()
+        .join("&")
+}
+
+fn percent_encode(value: &str) -> String {
+    value
+        .bytes()
+        .map(|b| match b {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'.' | b'_' | b'~' => {
+                char::from(b).to_string()
+            }
+            _ => format!("%{b:02X}"),
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn authorize_url_carries_client_and_state() {
+        let client = AlpacaOAuthClient::new(AlpacaOAuthConfig::new(
+            "client-1",
+            "secret",
+            "https://engine.example/callback",
+        ))
+        .unwrap();
+
+        let url = Url::parse(&client.authorize_url("abc123")).unwrap();
+        let query: Vec<(String, String)> = url.query_pairs().into_owned().collect();
+        assert!(url.as_str().starts_with(DEFAULT_OAUTH_AUTHORIZE_URL));
+        assert!(query.contains(&("client_id".to_string(), "client-1".to_string())));
+        assert!(query.contains(&(
+            "redirect_uri".to_string(),
+            "https://engine.example/callback".to_string()
+        )));
+        assert!(query.contains(&("state".to_string(), "abc123".to_string())));
+
+        assert_eq!(
+            form_encode(&[("scope", "account:write trading"), ("code", "a+b")]),
+            "scope=account%3Awrite%20trading&code=a%2Bb"
+        );
+        assert!(AlpacaOAuthClient::new(AlpacaOAuthConfig::new("", "secret", "uri")).is_err());
+    }
+}
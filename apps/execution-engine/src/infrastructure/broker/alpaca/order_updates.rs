@@ -0,0 +1,428 @@
+//! Alpaca order update streams.
+//!
+//! Alpaca pushes order status and fill events on two independent
+//! transports, both carrying the same `{event, order}` payload:
+//!
+//! - the trading WebSocket (`/stream`, `trade_updates`), authenticated with
+//!   an `auth` message, and
+//! - the server-sent events endpoint (`/v2/events/trades`), authenticated
+//!   with the API key headers and resumed from the last event ID after a
+//!   reconnect.
+//!
+//! Either can feed the order update listener on its own, or both can run
+//! side by side as redundant paths.
+
+use std::time::Duration;
+
+use async_trait::async_trait;
+use futures_util::{SinkExt, StreamExt};
+use parking_lot::RwLock;
+use reqwest::Client;
+use serde::Deserialize;
+use serde_json::json;
+use tokio::sync::mpsc;
+use tokio_tungstenite::{connect_async, tungstenite::Message};
+use tokio_util::sync::CancellationToken;
+
+use super::api_types::AlpacaTradeEvent;
+use super::config::AlpacaConfig;
+use super::error::AlpacaError;
+use crate::application::ports::{OrderUpdate, OrderUpdatePort, OrderUpdateTransport};
+use crate::infrastructure::secrets::AlpacaCredentials;
+use crate::infrastructure::websocket::ReconnectPolicy;
+
+/// Timeout for the WebSocket authorization reply.
+const AUTH_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// First reconnect backoff.
+const INITIAL_BACKOFF: Duration = Duration::from_millis(500);
+
+/// Longest reconnect backoff.
+const MAX_BACKOFF: Duration = Duration::from_secs(30);
+
+/// Alpaca order update stream over one transport.
+pub struct AlpacaOrderUpdateStream {
+    transport: OrderUpdateTransport,
+    credentials: RwLock<AlpacaCredentials>,
+    trading_base_url: String,
+    client: Client,
+}
+
+impl std::fmt::Debug for AlpacaOrderUpdateStream {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("AlpacaOrderUpdateStream")
+            .field("transport", &self.transport)
+            .field("trading_base_url", &self.trading_base_url)
+            .finish_non_exhaustive()
+    }
+}
+
+impl AlpacaOrderUpdateStream {
+    /// Create a stream over `transport` for the account in `config`.
+    ///
+    /// # Errors
+    ///
+    /// Returns error if the credentials are empty or the HTTP client cannot
+    /// be built.
+    pub fn new(
+        config: &AlpacaConfig,
+        transport: OrderUpdateTransport,
+    ) -> Result<Self, AlpacaError> {
+        if config.api_key.is_empty() || config.api_secret.is_empty() {
+            return Err(AlpacaError::AuthenticationFailed);
+        }
+        // No overall timeout: the events response never completes
+        let client = Client::builder()
+            .connect_timeout(config.timeout)
+            .build()
+            .map_err(|e| AlpacaError::Network(e.to_string()))?;
+
+        Ok(Self {
+            transport,
+            credentials: RwLock::new(config.credentials()),
+            trading_base_url: config.trading_base_url().trim_end_matches('/').to_string(),
+            client,
+        })
+    }
+
+    /// Use new credentials from the next connection on.
+    ///
+    /// # Errors
+    ///
+    /// Returns error if the credentials are empty.
+    pub fn rotate_credentials(&self, credentials: &AlpacaCredentials) -> Result<(), AlpacaError> {
+        if credentials.api_key.is_empty() || credentials.api_secret.is_empty() {
+            return Err(AlpacaError::AuthenticationFailed);
+        }
+        *self.credentials.write() = credentials.clone();
+        Ok(())
+    }
+
+    /// URL of the trading WebSocket.
+    #[must_use]
+    pub fn stream_url(&self) -> String {
+        let url = &self.trading_base_url;
+        let url = url
+            .strip_prefix("https://")
+            .map(|host| format!("wss://{host}"))
+            .or_else(|| {
+                url.strip_prefix("http://")
+                    .map(|host| format!("ws://{host}"))
+            })
+            .unwrap_or_else(|| url.clone());
+        format!("{url}/stream")
+    }
+
+    /// URL of the SSE trade events endpoint.
+    #[must_use]
+    pub fn events_url(&self) -> String {
+        format!("{}/v2/events/trades", self.trading_base_url)
+    }
+
+    /// Connect once over the WebSocket and forward updates until it closes.
+    async fn run_websocket(
+        &self,
+        updates: &mpsc::Sender<OrderUpdate>,
+        reconnect: &mut ReconnectPolicy,
+    ) -> Result<(), AlpacaError> {
+        let (mut ws, _) = connect_async(self.stream_url())
+            .await
+            .map_err(|e| AlpacaError::Network(e.to_string()))?;
+
+        let auth = {
+            let credentials = self.credentials.read();
+            json!({
+                "action": "auth",
+                "key": credentials.api_key.expose(),
+                "secret": credentials.api_secret.expose(),
+            })
+        };
+        send(&mut ws, &auth).await?;
+        let authorized = tokio::time::timeout(AUTH_TIMEOUT, next_message(&mut ws))
+            .await
+            .map_err(|_| AlpacaError::Network("authorization timed out".to_string()))??
+            .is_some_and(|message| {
+                message.stream == "authorization" && message.data["status"] == "authorized"
+            });
+        if !authorized {
+            return Err(AlpacaError::AuthenticationFailed);
+        }
+        let listen = json!({ "action": "listen", "data": { "streams": ["trade_updates"] } });
+        send(&mut ws, &listen).await?;
+
+        tracing::info!(transport = %self.transport, "Order update stream connected");
+        reconnect.reset();
+
+        while let Some(message) = next_message(&mut ws).await? {
+            if message.stream != "trade_updates" {
+                continue;
+            }
+            match serde_json::from_value::<AlpacaTradeEvent>(message.data) {
+                Ok(event) => self.forward(updates, &event).await?,
+                Err(e) => tracing::warn!(error = %e, "Unparseable trade update"),
+            }
+        }
+        Ok(())
+    }
+
+    /// Connect once to the events endpoint and forward updates until the
+    /// response ends, remembering the last event ID to resume from.
+    async fn run_sse(
+        &self,
+        updates: &mpsc::Sender<OrderUpdate>,
+        reconnect: &mut ReconnectPolicy,
+        last_event_id: &mut Option<String>,
+    ) -> Result<(), AlpacaError> {
+        let mut request = {
+            let credentials = self.credentials.read();
+            self.client
+                .get(self.events_url())
+                .header("APCA-API-KEY-ID", credentials.api_key.expose())
+                .header("APCA-API-SECRET-KEY", credentials.api_secret.expose())
+                .header("Accept", "text/event-stream")
+        };
+        if let Some(id) = last_event_id.as_deref() {
+            request = request.header("Last-Event-ID", id);
+        }
+        let mut response = request
+            .send()
+            .await
+            .map_err(|e| AlpacaError::Network(e.to_string()))?;
+        let status = response.status();
+        if status == reqwest::StatusCode::UNAUTHORIZED {
+            return Err(AlpacaError::AuthenticationFailed);
+        }
+        if !status.is_success() {
+            return Err(AlpacaError::Http(format!(
+                "events endpoint returned {status}"
+            )));
+        }
+
+        tracing::info!(transport = %self.transport, "Order update stream connected");
+        reconnect.reset();
+
+        let mut parser = SseParser::default();
+        while let Some(chunk) = response
+            .chunk()
+            .await
+            .map_err(|e| AlpacaError::Network(e.to_string()))?
+        {
+            for event in parser.push(&chunk) {
+                if event.id.is_some() {
+                    last_event_id.clone_from(&event.id);
+                }
+                match serde_json::from_str::<AlpacaTradeEvent>(&event.data) {
+                    Ok(event) => self.forward(updates, &event).await?,
+                    Err(e) => tracing::warn!(error = %e, "Unparseable trade event"),
+                }
+            }
+        }
+        Ok(())
+    }
+
+    async fn forward(
+        &self,
+        updates: &mpsc::Sender<OrderUpdate>,
+        event: &AlpacaTradeEvent,
+    ) -> Result<(), AlpacaError> {
+        let update = OrderUpdate {
+            transport: self.transport,
+            event: event.event.clone(),
+            order: event.order.to_order_ack(),
+        };
+        updates
+            .send(update)
+            .await
+            .map_err(|_| AlpacaError::Network("order update receiver dropped".to_string()))
+    }
+}
+
+#[async_trait]
+impl OrderUpdatePort for AlpacaOrderUpdateStream {
+    fn transport(&self) -> OrderUpdateTransport {
+        self.transport
+    }
+
+    async fn run(&self, updates: mpsc::Sender<OrderUpdate>, shutdown: CancellationToken) {
+        let mut reconnect =
+            ReconnectPolicy::with_params(INITIAL_BACKOFF, MAX_BACKOFF, 2.0, u32::MAX);
+        let mut last_event_id = None;
+
+        loop {
+            let connection = async {
+                match self.transport {
+                    OrderUpdateTransport::WebSocket => {
+                        self.run_websocket(&updates, &mut reconnect).await
+                    }
+                    OrderUpdateTransport::Sse => {
+                        self.run_sse(&updates, &mut reconnect, &mut last_event_id)
+                            .await
+                    }
+                }
+            };
+            let result = tokio::select! {
+                result = connection => result,
+                () = shutdown.cancelled() => break,
+            };
+            if updates.is_closed() {
+                break;
+            }
+            match result {
+                Ok(()) => tracing::warn!(transport = %self.transport, "Order update stream ended"),
+                Err(e) => {
+                    tracing::warn!(transport = %self.transport, error = %e, "Order update stream error");
+                }
+            }
+
+            let backoff = reconnect.next_backoff().unwrap_or(MAX_BACKOFF);
+            tokio::select! {
+                () = tokio::time::sleep(backoff) => {}
+                () = shutdown.cancelled() => break,
+            }
+        }
+        tracing::info!(transport = %self.transport, "Order update stream stopped");
+    }
+}
+
+/// A message on the trading WebSocket.
+#[derive(Debug, Deserialize)]
+struct StreamMessage {
+    stream: String,
+    #[serde(default)]
+    data: serde_json::Value,
+}
+
+type WsStream =
+    tokio_tungstenite::WebSocketStream<tokio_tungstenite::MaybeTlsStream<tokio::net::TcpStream>>;
+
+async fn send(ws: &mut WsStream, message: &serde_json::Value) -> Result<(), AlpacaError> {
+    ws.send(Message::Text(message.to_string().into()))
+        .await
+        .map_err(|e| AlpacaError::Network(e.to_string()))
+}
+
+/// Next JSON message, skipping control frames; `None` once the socket closes.
+async fn next_message(ws: &mut WsStream) -> Result<Option<StreamMessage>, AlpacaError> {
+    while let Some(frame) = ws.next().await {
+        let text = match frame.map_err(|e| AlpacaError::Network(e.to_string()))? {
+            Message::Text(text) => text.to_string(),
+            // The trading stream sends JSON in binary frames
+            Message::Binary(bytes) => String::from_utf8_lossy(&bytes).into_owned(),
+            Message::Close(_) => return Ok(None),
+            _ => continue,
+        };
+        match serde_json::from_str(&text) {
+            Ok(message) => return Ok(Some(message)),
+            Err(e) => tracing::warn!(error = %e, "Unparseable stream message"),
+        }
+    }
+    Ok(None)
+}
+
+/// A dispatched server-sent event.
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct SseEvent {
+    /// Last event ID seen, if any.
+    id: Option<String>,
+    /// Data lines joined by newlines.
+    data: String,
+}
+
+/// Incremental `text/event-stream` parser.
+#[derive(Debug, Default)]
+struct SseParser {
+    /// Bytes of the current incomplete line.
+    line: Vec<u8>,
+    /// Data of the event being built.
+    data: Option<String>,
+    /// Last event ID seen.
+    id: Option<String>,
+}
+
+impl SseParser {
+    /// Feed a chunk, returning the events it completes.
+    fn push(&mut self, chunk: &[u8]) -> Vec<SseEvent> {
+        let mut events = Vec::new();
+        for &byte in chunk {
+            if byte != b'\n' {
+                self.line.push(byte);
+                continue;
+            }
+            let line = std::mem::take(&mut self.line);
+            let line = String::from_utf8_lossy(&line);
+            let line = line.strip_suffix('\r').unwrap_or(&line);
+            if line.is_empty() {
+                if let Some(data) = self.data.take() {
+                    events.push(SseEvent {
+                        id: self.id.clone(),
+                        data,
+                    });
+                }
+                continue;
+            }
+            if line.starts_with(':') {
+                continue;
+            }
+            let (field, value) = line.split_once(':').unwrap_or((line, ""));
+            let value = value.strip_prefix(' ').unwrap_or(value);
+            match field {
+                "data" => match &mut self.data {
+                    Some(data) => {
+                        data.push('\n');
+                        data.push_str(value);
+                    }
+                    None => self.data = Some(value.to_string()),
+                },
+                "id" => self.id = Some(value.to_string()),
+                _ => {}
+            }
+        }
+        events
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::infrastructure::broker::alpaca::AlpacaEnvironment;
+
+    #[test]
+    fn sse_parser_handles_split_chunks_and_comments() {
+        let mut parser = SseParser::default();
+        assert!(
+            parser
+                .push(b": heartbeat\n\nid: 7\ndata: {\"a\":")
+                .is_empty()
+        );
+        let events = parser.push(b"1}\r\n\r\ndata: x\ndata: y\n\n");
+
+        assert_eq!(
+            events,
+            vec![
+                SseEvent {
+                    id: Some("7".to_string()),
+                    data: "{\"a\":1}".to_string(),
+                },
+                SseEvent {
+                    id: Some("7".to_string()),
+                    data: "x\ny".to_string(),
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn urls_follow_the_trading_host() {
+        let config = AlpacaConfig::new("key".into(), "secret".into(), AlpacaEnvironment::Paper);
+        let stream = AlpacaOrderUpdateStream::new(&config, OrderUpdateTransport::Sse).unwrap();
+        assert_eq!(stream.stream_url(), "wss://paper-api.alpaca.markets/stream");
+        assert_eq!(
+            stream.events_url(),
+            "https://paper-api.alpaca.markets/v2/events/trades"
+        );
+
+        let local = config.with_base_url("http://127.0.0.1:8080/");
+        let stream = AlpacaOrderUpdateStream::new(&local, OrderUpdateTransport::WebSocket).unwrap();
+        assert_eq!(stream.stream_url(), "ws://127.0.0.1:8080/stream");
+    }
+}
@@ -0,0 +1,326 @@
+//! Retry policy inputs: call idempotency and per-endpoint outcome history.
+
+use std::collections::{HashMap, VecDeque};
+use std::sync::{Mutex, PoisonError};
+use std::time::{Duration, Instant};
+
+use super::config::{AdaptiveRetryConfig, RetryConfig};
+
+/// Lowest success rate the backoff scales by.
+const MIN_BACKOFF_RATE: f64 = 0.1;
+
+/// Whether a call may be repeated safely.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Idempotency {
+    /// Repeating the call has no further effect (reads, cancels).
+    Idempotent,
+    /// Repeating the call may act twice (order placement, closing
+    /// positions), so it is not retried once the broker may have it.
+    NonIdempotent,
+}
+
+impl Idempotency {
+    /// Classify a trading API call.
+    pub fn of(method: &str, path: &str) -> Self {
+        let closes_position = method == "DELETE" && path.starts_with("/v2/positions");
+        if method == "POST" || closes_position {
+            Self::NonIdempotent
+        } else {
+            Self::Idempotent
+        }
+    }
+}
+
+/// Outcome of one attempt.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Outcome {
+    /// The broker answered, successfully or with a non-retryable error.
+    Answered,
+    /// Network error or retryable status.
+    Failed,
+    /// Rate limited, with the `Retry-After` if given.
+    RateLimited(Option<Duration>),
+}
+
+/// Stats key for a call: the query is dropped and ID segments (order IDs,
+/// symbols) are collapsed, so `/v2/orders/<id>` calls share one history.
+pub fn endpoint(method: &str, path: &str) -> String {
+    let path = path.split('?').next().unwrap_or(path);
+    let mut index = 0;
+    let template: Vec<&str> = path
+        .split('/')
+        .map(|segment| {
+            if segment.is_empty() {
+                return segment;
+            }
+            index += 1;
+            let is_id = segment
+                .chars()
+                .any(|c| c.is_ascii_digit() || c.is_ascii_uppercase() || c == '-');
+            // Version and collection segments are kept
+            if index > 2 && is_id { "{id}" } else { segment }
+        })
+        .collect();
+    format!("{method} {}", template.join("/"))
+}
+
+#[derive(Debug, Clone, Copy)]
+struct Sample {
+    at: Instant,
+    outcome: Outcome,
+}
+
+/// Recent outcomes of one endpoint.
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct EndpointStats {
+    samples: usize,
+    success_rate: f64,
+    /// Longest `Retry-After` in the window.
+    retry_after: Option<Duration>,
+}
+
+/// Outcomes of one endpoint, oldest first.
+#[derive(Debug, Default)]
+struct History(VecDeque<Sample>);
+
+impl History {
+    fn push(&mut self, outcome: Outcome, config: &AdaptiveRetryConfig) {
+        let now = Instant::now();
+        self.0.push_back(Sample { at: now, outcome });
+        self.prune(now, config);
+    }
+
+    #[allow(clippy::cast_precision_loss)]
+    fn stats(&mut self, config: &AdaptiveRetryConfig) -> Option<EndpointStats> {
+        self.prune(Instant::now(), config);
+        if self.0.is_empty() {
+            return None;
+        }
+
+        let answered = self
+            .0
+            .iter()
+            .filter(|s| s.outcome == Outcome::Answered)
+            .count();
+        let retry_after = self
+            .0
+            .iter()
+            .filter_map(|s| match s.outcome {
+                Outcome::RateLimited(retry_after) => retry_after,
+                _ => None,
+            })
+            .max();
+        Some(EndpointStats {
+            samples: self.0.len(),
+            success_rate: answered as f64 / self.0.len() as f64,
+            retry_after,
+        })
+    }
+
+    fn prune(&mut self, now: Instant, config: &AdaptiveRetryConfig) {
+        while self.0.len() > config.max_samples
+            || self
+                .0
+                .front()
+                .is_some_and(|s| now.duration_since(s.at) > config.window)
+        {
+            self.0.pop_front();
+        }
+    }
+}
+
+/// Per-endpoint outcome history driving the adaptive retry policy.
+#[derive(Debug)]
+pub struct AdaptiveRetry {
+    config: AdaptiveRetryConfig,
+    endpoints: Mutex<HashMap<String, History>>,
+}
+
+impl AdaptiveRetry {
+    /// Create with an empty history.
+    pub fn new(config: AdaptiveRetryConfig) -> Self {
+        Self {
+            config,
+            endpoints: Mutex::new(HashMap::new()),
+        }
+    }
+
+    /// Record an attempt's outcome.
+    pub fn record(&self, endpoint: &str, outcome: Outcome) {
+        self.endpoints
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .entry(endpoint.to_string())
+            .or_default()
+            .push(outcome, &self.config);
+    }
+
+    /// Retry configuration for the next request to `endpoint`, adapted from
+    /// `base`.
+    #[allow(
+        clippy::cast_possible_truncation,
+        clippy::cast_precision_loss,
+        clippy::cast_sign_loss
+    )]
+    pub fn policy(&self, endpoint: &str, base: &RetryConfig) -> RetryConfig {
+        let stats = self
+            .endpoints
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .get_mut(endpoint)
+            .and_then(|history| history.stats(&self.config));
+        let Some(stats) = stats.filter(|stats| stats.samples >= self.config.min_samples) else {
+            return base.clone();
+        };
+
+        let healthy = self.config.healthy_success_rate;
+        let failing = self.config.failing_success_rate;
+        let rate = stats.success_rate;
+        let max_attempts = if rate >= healthy {
+            base.max_attempts
+        } else if rate <= failing {
+            1
+        } else {
+            let retries = f64::from(base.max_attempts.saturating_sub(1)) * (rate - failing)
+                / (healthy - failing);
+            1 + retries.round() as u32
+        };
+
+        let scale = (healthy / rate.max(MIN_BACKOFF_RATE)).max(1.0);
+        let initial_backoff = base
+            .initial_backoff
+            .mul_f64(scale)
+            .max(stats.retry_after.unwrap_or_default())
+            .min(base.max_backoff);
+
+        RetryConfig {
+            max_attempts,
+            initial_backoff,
+            ..base.clone()
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::float_cmp)]
+    use super::*;
+
+    const ENDPOINT: &str = "GET /v2/account";
+
+    fn adaptive() -> AdaptiveRetry {
+        AdaptiveRetry::new(AdaptiveRetryConfig {
+            max_samples: 10,
+            min_samples: 10,
+            ..AdaptiveRetryConfig::default()
+        })
+    }
+
+    fn base() -> RetryConfig {
+        RetryConfig {
+            max_attempts: 5,
+            initial_backoff: Duration::from_millis(100),
+            max_backoff: Duration::from_secs(10),
+            multiplier: 2.0,
+        }
+    }
+
+    fn record(adaptive: &AdaptiveRetry, answered: usize, failed: usize) {
+        for _ in 0..answered {
+            adaptive.record(ENDPOINT, Outcome::Answered);
+        }
+        for _ in 0..failed {
+            adaptive.record(ENDPOINT, Outcome::Failed);
+        }
+    }
+
+    #[test]
+    fn order_placement_is_not_idempotent() {
+        assert_eq!(
+            Idempotency::of("POST", "/v2/orders"),
+            Idempotency::NonIdempotent
+        );
+        assert_eq!(
+            Idempotency::of("DELETE", "/v2/positions/AAPL"),
+            Idempotency::NonIdempotent
+        );
+        assert_eq!(
+            Idempotency::of("DELETE", "/v2/orders/abc-123"),
+            Idempotency::Idempotent
+        );
+        assert_eq!(
+            Idempotency::of("GET", "/v2/orders"),
+            Idempotency::Idempotent
+        );
+    }
+
+    #[test]
+    fn endpoint_collapses_ids_and_drops_query() {
+        assert_eq!(
+            endpoint("GET", "/v2/orders/61e69015-8549-4bfd-b9c3-01e75843f47d"),
+            "GET /v2/orders/{id}"
+        );
+        assert_eq!(
+            endpoint("GET", "/v2/positions/AAPL"),
+            "GET /v2/positions/{id}"
+        );
+        assert_eq!(
+            endpoint("GET", "/v2/orders?status=open&limit=500"),
+            "GET /v2/orders"
+        );
+        assert_eq!(
+            endpoint("GET", "/v2/account/activities"),
+            "GET /v2/account/activities"
+        );
+    }
+
+    #[test]
+    fn too_few_samples_keep_the_base_policy() {
+        let adaptive = adaptive();
+        record(&adaptive, 0, 9);
+
+        let policy = adaptive.policy(ENDPOINT, &base());
+
+        assert_eq!(policy.max_attempts, 5);
+        assert_eq!(policy.initial_backoff, Duration::from_millis(100));
+    }
+
+    #[test]
+    fn retries_shrink_as_the_success_rate_falls() {
+        let adaptive = adaptive();
+
+        record(&adaptive, 9, 1);
+        let healthy = adaptive.policy(ENDPOINT, &base());
+        assert_eq!(healthy.max_attempts, 5);
+        assert_eq!(healthy.initial_backoff, Duration::from_millis(100));
+
+        // 70%, half way from failing to healthy: half of the four retries
+        record(&adaptive, 0, 2);
+        let degraded = adaptive.policy(ENDPOINT, &base());
+        assert_eq!(degraded.max_attempts, 3);
+        assert_eq!(
+            degraded.initial_backoff,
+            Duration::from_millis(100).mul_f64(0.9 / 0.7)
+        );
+
+        record(&adaptive, 0, 3);
+        let failing = adaptive.policy(ENDPOINT, &base());
+        assert_eq!(failing.max_attempts, 1);
+        assert_eq!(
+            failing.initial_backoff,
+            Duration::from_millis(100).mul_f64(0.9 / 0.4)
+        );
+    }
+
+    #[test]
+    fn retry_after_sets_the_backoff_floor() {
+        let adaptive = adaptive();
+        record(&adaptive, 9, 0);
+        adaptive.record(ENDPOINT, Outcome::RateLimited(Some(Duration::from_secs(2))));
+
+        let policy = adaptive.policy(ENDPOINT, &base());
+
+        assert_eq!(policy.max_attempts, 5);
+        assert_eq!(policy.initial_backoff, Duration::from_secs(2));
+    }
+}
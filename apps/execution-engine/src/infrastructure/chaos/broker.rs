@@ -0,0 +1,286 @@
+//! Fault-injecting broker adapter.
+
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use rust_decimal::Decimal;
+
+use super::injector::{FaultInjector, Injection};
+use crate::application::ports::{
+    AccountActivity, AccountActivityPort, AssetInfo, BrokerError, BrokerPort, CancelOrderRequest,
+    MarketClock, OrderAck, PositionInfo, SubmitOrderRequest,
+};
+use crate::domain::shared::{BrokerId, InstrumentId, Symbol, Timestamp};
+use crate::infrastructure::broker::AlpacaError;
+use crate::infrastructure::secrets::{AlpacaCredentials, CredentialSink};
+
+/// Broker adapter wrapper that injects faults before delegating.
+///
+/// Injected failures surface as [`BrokerError::ConnectionError`], the same
+/// error the Alpaca adapter returns once its retries are exhausted. Without
+/// an injector every call passes straight through.
+#[derive(Debug, Clone)]
+pub struct FaultInjectingBrokerAdapter<B> {
+    inner: B,
+    faults: Option<Arc<FaultInjector>>,
+}
+
+impl<B> FaultInjectingBrokerAdapter<B> {
+    /// Wrap `inner`, injecting faults from `faults`.
+    #[must_use]
+    pub fn new(inner: B, faults: FaultInjector) -> Self {
+        Self {
+            inner,
+            faults: Some(Arc::new(faults)),
+        }
+    }
+
+    /// Wrap `inner` without injecting anything.
+    #[must_use]
+    pub const fn passthrough(inner: B) -> Self {
+        Self {
+            inner,
+            faults: None,
+        }
+    }
+
+    /// The wrapped adapter.
+    #[must_use]
+    pub const fn inner(&self) -> &B {
+        &self.inner
+    }
+
+    /// The fault injector, if faults are enabled.
+    #[must_use]
+    pub fn faults(&self) -> Option<&FaultInjector> {
+        self.faults.as_deref()
+    }
+}
+
+impl<B: Sync> FaultInjectingBrokerAdapter<B> {
+    async fn inject(&self, operation: &str) -> Result<Injection, BrokerError> {
+        match &self.faults {
+            Some(faults) => faults
+                .inject(operation)
+                .await
+                .map_err(|message| BrokerError::ConnectionError { message }),
+            None => Ok(Injection::default()),
+        }
+    }
+}
+
+#[async_trait]
+impl<B: BrokerPort> BrokerPort for FaultInjectingBrokerAdapter<B> {
+    async fn submit_order(&self, request: SubmitOrderRequest) -> Result<OrderAck, BrokerError> {
+        self.inject("submit_order").await?;
+        self.inner.submit_order(request).await
+    }
+
+    async fn cancel_order(&self, request: CancelOrderRequest) -> Result<(), BrokerError> {
+        self.inject("cancel_order").await?;
+        self.inner.cancel_order(request).await
+    }
+
+    async fn get_order(&self, broker_order_id: &BrokerId) -> Result<OrderAck, BrokerError> {
+        self.inject("get_order").await?;
+        self.inner.get_order(broker_order_id).await
+    }
+
+    async fn get_open_orders(&self) -> Result<Vec<OrderAck>, BrokerError> {
+        let injection = self.inject("get_open_orders").await?;
+        Ok(injection.truncate(self.inner.get_open_orders().await?))
+    }
+
+    async fn get_buying_power(&self) -> Result<Decimal, BrokerError> {
+        self.inject("get_buying_power").await?;
+        self.inner.get_buying_power().await
+    }
+
+    async fn get_position(
+        &self,
+        instrument_id: &InstrumentId,
+    ) -> Result<Option<Decimal>, BrokerError> {
+        self.inject("get_position").await?;
+        self.inner.get_position(instrument_id).await
+    }
+
+    async fn get_all_positions(&self) -> Result<Vec<PositionInfo>, BrokerError> {
+        let injection = self.inject("get_all_positions").await?;
+        Ok(injection.truncate(self.inner.get_all_positions().await?))
+    }
+
+    async fn get_asset(&self, symbol: &Symbol) -> Result<Option<AssetInfo>, BrokerError> {
+        self.inject("get_asset").await?;
+        self.inner.get_asset(symbol).await
+    }
+
+    async fn list_assets(&self) -> Result<Vec<AssetInfo>, BrokerError> {
+        let injection = self.inject("list_assets").await?;
+        Ok(injection.truncate(self.inner.list_assets().await?))
+    }
+
+    async fn get_market_clock(&self) -> Result<Option<MarketClock>, BrokerError> {
+        self.inject("get_market_clock").await?;
+        self.inner.get_market_clock().await
+    }
+}
+
+#[async_trait]
+impl<B: AccountActivityPort> AccountActivityPort for FaultInjectingBrokerAdapter<B> {
+    async fn get_activities(
+        &self,
+        after: Option<Timestamp>,
+    ) -> Result<Vec<AccountActivity>, BrokerError> {
+        let injection = self.inject("get_activities").await?;
+        Ok(injection.truncate(self.inner.get_activities(after).await?))
+    }
+}
+
+impl<B: CredentialSink> CredentialSink for FaultInjectingBrokerAdapter<B> {
+    fn name(&self) -> &'static str {
+        self.inner.name()
+    }
+
+    fn rotate_credentials(&self, credentials: &AlpacaCredentials) -> Result<(), AlpacaError> {
+        self.inner.rotate_credentials(credentials)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    use super::*;
+    use crate::domain::order_execution::value_objects::{Environment, OrderSide, OrderStatus};
+    use crate::domain::shared::OrderId;
+    use crate::infrastructure::chaos::{FaultKind, FaultRule};
+
+    #[derive(Default)]
+    struct CountingBroker {
+        calls: AtomicUsize,
+    }
+
+    #[async_trait]
+    impl BrokerPort for CountingBroker {
+        async fn submit_order(&self, request: SubmitOrderRequest) -> Result<OrderAck, BrokerError> {
+            self.calls.fetch_add(1, Ordering::SeqCst);
+            Ok(OrderAck {
+                broker_order_id: BrokerId::new("broker-1"),
+                client_order_id: request.client_order_id,
+                status: OrderStatus::New,
+                filled_qty: Decimal::ZERO,
+                avg_fill_price: None,
+                child_order_ids: Vec::new(),
+                leg_fills: Vec::new(),
+            })
+        }
+
+        async fn cancel_order(&self, _request: CancelOrderRequest) -> Result<(), BrokerError> {
+            Ok(())
+        }
+
+        async fn get_order(&self, _broker_order_id: &BrokerId) -> Result<OrderAck, BrokerError> {
+            Err(BrokerError::OrderNotFound {
+                order_id: "broker-1".to_string(),
+            })
+        }
+
+        async fn get_open_orders(&self) -> Result<Vec<OrderAck>, BrokerError> {
+            Ok(vec![])
+        }
+
+        async fn get_buying_power(&self) -> Result<Decimal, BrokerError> {
+            Ok(Decimal::ZERO)
+        }
+
+        async fn get_position(
+            &self,
+            _instrument_id: &InstrumentId,
+        ) -> Result<Option<Decimal>, BrokerError> {
+            Ok(None)
+        }
+
+        async fn get_all_positions(&self) -> Result<Vec<PositionInfo>, BrokerError> {
+            Ok((0..4)
+                .map(|i| PositionInfo {
+                    symbol: format!("SYM{i}"),
+                    quantity: Decimal::ONE,
+                    avg_entry_price: Decimal::ONE,
+                    market_value: Decimal::ONE,
+                    unrealized_pnl: Decimal::ZERO,
+                    current_price: Decimal::ONE,
+                    opened_at: None,
+                })
+                .collect())
+        }
+    }
+
+    fn order() -> SubmitOrderRequest {
+        SubmitOrderRequest::market(
+            OrderId::new("ord-1"),
+            Symbol::new("AAPL"),
+            OrderSide::Buy,
+            Decimal::ONE,
+        )
+    }
+
+    fn adapter(rules: Vec<FaultRule>) -> FaultInjectingBrokerAdapter<CountingBroker> {
+        let faults = FaultInjector::new("broker", rules, Some(1), Environment::Paper).unwrap();
+        FaultInjectingBrokerAdapter::new(CountingBroker::default(), faults)
+    }
+
+    #[tokio::test]
+    async fn injected_error_does_not_reach_the_broker() {
+        let adapter = adapter(vec![FaultRule::new(
+            &["submit_order"],
+            FaultKind::ServerError {
+                status: 502,
+                burst: 1,
+            },
+        )]);
+
+        let result = adapter.submit_order(order()).await;
+
+        assert!(matches!(
+            result,
+            Err(BrokerError::ConnectionError { ref message }) if message.contains("502")
+        ));
+        assert_eq!(adapter.inner().calls.load(Ordering::SeqCst), 0);
+    }
+
+    #[tokio::test]
+    async fn partial_response_drops_positions() {
+        let adapter = adapter(vec![FaultRule::new(
+            &["get_all_positions"],
+            FaultKind::PartialResponse { keep_ratio: 0.5 },
+        )]);
+
+        let positions = adapter.get_all_positions().await.unwrap();
+
+        assert_eq!(positions.len(), 2);
+    }
+
+    #[tokio::test]
+    async fn latency_delays_then_delegates() {
+        let adapter = adapter(vec![FaultRule::new(
+            &["submit_order"],
+            FaultKind::Latency { delay_ms: 50 },
+        )]);
+        let start = std::time::Instant::now();
+
+        adapter.submit_order(order()).await.unwrap();
+
+        assert!(start.elapsed() >= std::time::Duration::from_millis(50));
+        assert_eq!(adapter.inner().calls.load(Ordering::SeqCst), 1);
+    }
+
+    #[tokio::test]
+    async fn passthrough_never_injects() {
+        let adapter = FaultInjectingBrokerAdapter::passthrough(CountingBroker::default());
+
+        adapter.submit_order(order()).await.unwrap();
+
+        assert!(adapter.faults().is_none());
+        assert_eq!(adapter.get_all_positions().await.unwrap().len(), 4);
+    }
+}
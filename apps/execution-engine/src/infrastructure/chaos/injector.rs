@@ -0,0 +1,329 @@
+//! Fault injector shared by the chaos adapter wrappers.
+
+use std::sync::Arc;
+use std::time::Duration;
+
+use parking_lot::Mutex;
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+use rust_decimal::Decimal;
+use rust_decimal::prelude::{FromPrimitive, ToPrimitive};
+
+use super::scenario::{ChaosError, FaultKind, FaultRule};
+use crate::application::ports::{Clock, SystemClock};
+use crate::domain::order_execution::value_objects::Environment;
+use crate::domain::shared::Timestamp;
+
+/// Faults decided for one call.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct Injection {
+    /// Fraction of a list result to keep, if a partial response fired.
+    pub keep_ratio: Option<f64>,
+}
+
+impl Injection {
+    /// Drop the tail of `items` if a partial response fired.
+    #[must_use]
+    pub fn truncate<T>(&self, mut items: Vec<T>) -> Vec<T> {
+        if let Some(ratio) = self.keep_ratio {
+            let keep = Decimal::from_f64(ratio)
+                .and_then(|ratio| (Decimal::from(items.len()) * ratio).floor().to_usize())
+                .unwrap_or(items.len());
+            items.truncate(keep);
+        }
+        items
+    }
+}
+
+#[derive(Debug, Default)]
+struct RuleState {
+    calls: u64,
+    injections: u64,
+    burst_remaining: u32,
+}
+
+#[derive(Debug)]
+struct InjectorState {
+    rng: StdRng,
+    rules: Vec<RuleState>,
+    disconnected_until: Option<Timestamp>,
+    injections: u64,
+}
+
+/// What a call should suffer before (or instead of) reaching the adapter.
+#[derive(Debug, Default)]
+struct Plan {
+    delay: Duration,
+    error: Option<String>,
+    injection: Injection,
+}
+
+/// Applies one adapter's fault rules to its calls.
+///
+/// Rule timing (`start_after_secs`, disconnect windows) follows the injected
+/// [`Clock`], so backtests driven by a simulated clock see faults at the same
+/// simulated times on every run. Latency is always real.
+#[derive(Debug)]
+pub struct FaultInjector {
+    target: &'static str,
+    rules: Vec<FaultRule>,
+    state: Mutex<InjectorState>,
+    clock: Arc<dyn Clock>,
+    started: Timestamp,
+}
+
+impl FaultInjector {
+    /// Create an injector for `target` (`broker` or `feed`).
+    ///
+    /// # Errors
+    ///
+    /// Returns error in LIVE; fault injection is for PAPER and backtests only.
+    pub fn new(
+        target: &'static str,
+        rules: Vec<FaultRule>,
+        seed: Option<u64>,
+        environment: Environment,
+    ) -> Result<Self, ChaosError> {
+        if environment.is_live() {
+            return Err(ChaosError::LiveEnvironment);
+        }
+
+        let rng = seed.map_or_else(StdRng::from_os_rng, StdRng::seed_from_u64);
+        let clock = SystemClock::shared();
+        Ok(Self {
+            target,
+            state: Mutex::new(InjectorState {
+                rng,
+                rules: rules.iter().map(|_| RuleState::default()).collect(),
+                disconnected_until: None,
+                injections: 0,
+            }),
+            rules,
+            started: clock.now(),
+            clock,
+        })
+    }
+
+    /// Use `clock` instead of the system clock for rule timing.
+    ///
+    /// The scenario starts at the clock's current time.
+    #[must_use]
+    pub fn with_clock(mut self, clock: Arc<dyn Clock>) -> Self {
+        self.started = clock.now();
+        self.clock = clock;
+        self
+    }
+
+    /// Number of rules.
+    #[must_use]
+    pub const fn len(&self) -> usize {
+        self.rules.len()
+    }
+
+    /// Check if there are no rules.
+    #[must_use]
+    pub const fn is_empty(&self) -> bool {
+        self.rules.is_empty()
+    }
+
+    /// Faults injected so far.
+    #[must_use]
+    pub fn injections(&self) -> u64 {
+        self.state.lock().injections
+    }
+
+    /// Apply the rules to a call to `operation`.
+    ///
+    /// Sleeps for any injected latency first.
+    ///
+    /// # Errors
+    ///
+    /// Returns the error message if the call must fail without reaching the
+    /// adapter.
+    pub async fn inject(&self, operation: &str) -> Result<Injection, String> {
+        let plan = self.plan(operation);
+        if !plan.delay.is_zero() {
+            tokio::time::sleep(plan.delay).await;
+        }
+        plan.error.map_or(Ok(plan.injection), Err)
+    }
+
+    fn plan(&self, operation: &str) -> Plan {
+        let now = self.clock.now();
+        let elapsed = now
+            .duration_since(self.started)
+            .to_std()
+            .unwrap_or_default();
+        let mut plan = Plan::default();
+
+        let mut state = self.state.lock();
+        if let Some(until) = state.disconnected_until {
+            if now < until {
+                plan.error = Some(format!("Injected disconnect ({} {operation})", self.target));
+                return plan;
+            }
+            state.disconnected_until = None;
+        }
+
+        let InjectorState {
+            rng,
+            rules,
+            disconnected_until,
+            injections,
+        } = &mut *state;
+        for (rule, rule_state) in self.rules.iter().zip(rules.iter_mut()) {
+            if !rule.matches(operation) {
+                continue;
+            }
+            rule_state.calls += 1;
+
+            // A burst already under way fails this call without re-rolling
+            if rule_state.burst_remaining > 0 {
+                rule_state.burst_remaining -= 1;
+                if let FaultKind::ServerError { status, .. } = rule.fault {
+                    plan.error = Some(format!("Injected HTTP {status} ({operation})"));
+                }
+                continue;
+            }
+
+            let eligible = rule_state.calls > rule.after_calls
+                && elapsed >= Duration::from_secs(rule.start_after_secs)
+                && rule
+                    .max_injections
+                    .is_none_or(|max| rule_state.injections < max);
+            if !eligible || !rng.random_bool(rule.probability) {
+                continue;
+            }
+
+            rule_state.injections += 1;
+            *injections += 1;
+            tracing::warn!(
+                target_adapter = self.target,
+                operation,
+                fault = rule.fault.name(),
+                "Injecting fault"
+            );
+
+            match rule.fault {
+                FaultKind::Latency { .. } => plan.delay += rule.fault.duration(),
+                FaultKind::ServerError { status, burst } => {
+                    rule_state.burst_remaining = burst - 1;
+                    plan.error = Some(format!("Injected HTTP {status} ({operation})"));
+                }
+                FaultKind::PartialResponse { keep_ratio } => {
+                    plan.injection.keep_ratio = Some(
+                        plan.injection
+                            .keep_ratio
+                            .map_or(keep_ratio, |kept| kept.min(keep_ratio)),
+                    );
+                }
+                FaultKind::Disconnect { .. } => {
+                    let outage = chrono::Duration::from_std(rule.fault.duration())
+                        .unwrap_or(chrono::Duration::MAX);
+                    *disconnected_until = now
+                        .as_datetime()
+                        .checked_add_signed(outage)
+                        .map(Timestamp::new);
+                    plan.error = Some(format!("Injected disconnect ({} {operation})", self.target));
+                }
+            }
+        }
+        drop(state);
+
+        plan
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::application::ports::SimulatedClock;
+
+    fn injector(rules: Vec<FaultRule>) -> FaultInjector {
+        FaultInjector::new("broker", rules, Some(1), Environment::Paper).unwrap()
+    }
+
+    #[test]
+    fn refuses_live() {
+        let result = FaultInjector::new("broker", vec![], None, Environment::Live);
+
+        assert!(matches!(result, Err(ChaosError::LiveEnvironment)));
+    }
+
+    #[tokio::test]
+    async fn server_error_burst_fails_consecutive_calls() {
+        let mut rule = FaultRule::new(
+            &["get_open_orders"],
+            FaultKind::ServerError {
+                status: 503,
+                burst: 3,
+            },
+        );
+        rule.after_calls = 1;
+        rule.max_injections = Some(1);
+        let injector = injector(vec![rule]);
+
+        let results: Vec<bool> = [
+            "get_open_orders",
+            "get_open_orders",
+            "submit_order",
+            "get_open_orders",
+            "get_open_orders",
+            "get_open_orders",
+        ]
+        .iter()
+        .map(|op| injector.plan(op).error.is_some())
+        .collect();
+
+        assert_eq!(results, [false, true, false, true, true, false]);
+        assert_eq!(injector.injections(), 1);
+    }
+
+    #[test]
+    fn disconnect_fails_every_operation_until_it_ends() {
+        let clock = Arc::new(SimulatedClock::new(Timestamp::now()));
+        let mut rule = FaultRule::new(&["get_order"], FaultKind::Disconnect { duration_ms: 5_000 });
+        rule.start_after_secs = 30;
+        rule.max_injections = Some(1);
+        let injector = injector(vec![rule]).with_clock(Arc::clone(&clock) as Arc<dyn Clock>);
+
+        assert!(injector.plan("get_order").error.is_none());
+
+        clock.advance(Duration::from_secs(30));
+        assert!(injector.plan("get_order").error.is_some());
+        assert!(injector.plan("submit_order").error.is_some());
+
+        clock.advance(Duration::from_secs(5));
+        assert!(injector.plan("submit_order").error.is_none());
+        assert!(injector.plan("get_order").error.is_none());
+    }
+
+    #[test]
+    fn partial_response_truncates_lists() {
+        let injector = injector(vec![FaultRule::new(
+            &["get_all_positions"],
+            FaultKind::PartialResponse { keep_ratio: 0.5 },
+        )]);
+
+        let injection = injector.plan("get_all_positions").injection;
+
+        assert_eq!(injection.truncate(vec![1, 2, 3, 4, 5]), vec![1, 2]);
+        assert_eq!(Injection::default().truncate(vec![1, 2, 3]), vec![1, 2, 3]);
+    }
+
+    #[test]
+    fn seeded_probability_replays() {
+        let mut rule = FaultRule::new(&[], FaultKind::Latency { delay_ms: 10 });
+        rule.probability = 0.5;
+        let run = || {
+            let injector = injector(vec![rule.clone()]);
+            (0..32)
+                .map(|_| !injector.plan("get_quote").delay.is_zero())
+                .collect::<Vec<_>>()
+        };
+
+        let first = run();
+        assert_eq!(first, run());
+        assert!(first.contains(&true) && first.contains(&false));
+    }
+}
@@ -0,0 +1,28 @@
+//! Chaos Testing
+//!
+//! Fault injection for the broker and price feed adapters, so the circuit
+//! breaker, reconciliation, and mass-cancel paths can be exercised in PAPER
+//! and backtests before they matter in LIVE.
+//!
+//! [`FaultInjectingBrokerAdapter`] and [`FaultInjectingPriceFeed`] wrap an
+//! adapter and apply the rules of a [`ChaosScenario`] file to each call:
+//!
+//! - `LATENCY`: delay the call
+//! - `SERVER_ERROR`: fail a burst of calls as if the API returned a 5xx
+//! - `PARTIAL_RESPONSE`: drop part of a list result
+//! - `DISCONNECT`: fail every call on the adapter for a while
+//!
+//! Injectors refuse to start in LIVE. The engine loads a scenario from
+//! `CHAOS_SCENARIO_PATH`; without one the wrappers pass calls through.
+
+mod broker;
+mod injector;
+mod price_feed;
+mod scenario;
+
+pub use broker::FaultInjectingBrokerAdapter;
+pub use injector::{FaultInjector, Injection};
+pub use price_feed::FaultInjectingPriceFeed;
+pub use scenario::{
+    BROKER_OPERATIONS, ChaosError, ChaosScenario, FEED_OPERATIONS, FaultKind, FaultRule,
+};
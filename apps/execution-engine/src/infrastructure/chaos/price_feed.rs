@@ -0,0 +1,166 @@
+//! Fault-injecting price feed.
+
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use rust_decimal::Decimal;
+
+use super::injector::{FaultInjector, Injection};
+use crate::application::ports::{BarTimeframe, Candle, PriceFeedError, PriceFeedPort, Quote};
+use crate::domain::shared::{InstrumentId, Symbol};
+use crate::infrastructure::broker::AlpacaError;
+use crate::infrastructure::secrets::{AlpacaCredentials, CredentialSink};
+
+/// Price feed wrapper that injects faults before delegating.
+///
+/// Injected failures surface as [`PriceFeedError::ConnectionError`]. A
+/// partial response drops quotes from `get_quotes` and bars from
+/// `get_bars`, as if some data were missing. Without an injector every call
+/// passes straight through.
+#[derive(Debug, Clone)]
+pub struct FaultInjectingPriceFeed<P> {
+    inner: P,
+    faults: Option<Arc<FaultInjector>>,
+}
+
+impl<P> FaultInjectingPriceFeed<P> {
+    /// Wrap `inner`, injecting faults from `faults`.
+    #[must_use]
+    pub fn new(inner: P, faults: FaultInjector) -> Self {
+        Self {
+            inner,
+            faults: Some(Arc::new(faults)),
+        }
+    }
+
+    /// Wrap `inner` without injecting anything.
+    #[must_use]
+    pub const fn passthrough(inner: P) -> Self {
+        Self {
+            inner,
+            faults: None,
+        }
+    }
+
+    /// The wrapped feed.
+    #[must_use]
+    pub const fn inner(&self) -> &P {
+        &self.inner
+    }
+
+    /// The fault injector, if faults are enabled.
+    #[must_use]
+    pub fn faults(&self) -> Option<&FaultInjector> {
+        self.faults.as_deref()
+    }
+}
+
+impl<P: Sync> FaultInjectingPriceFeed<P> {
+    async fn inject(&self, operation: &str) -> Result<Injection, PriceFeedError> {
+        match &self.faults {
+            Some(faults) => faults
+                .inject(operation)
+                .await
+                .map_err(|message| PriceFeedError::ConnectionError { message }),
+            None => Ok(Injection::default()),
+        }
+    }
+}
+
+#[async_trait]
+impl<P: PriceFeedPort> PriceFeedPort for FaultInjectingPriceFeed<P> {
+    async fn get_quote(&self, symbol: &Symbol) -> Result<Quote, PriceFeedError> {
+        self.inject("get_quote").await?;
+        self.inner.get_quote(symbol).await
+    }
+
+    async fn get_quotes(&self, symbols: &[Symbol]) -> Result<Vec<Quote>, PriceFeedError> {
+        let injection = self.inject("get_quotes").await?;
+        Ok(injection.truncate(self.inner.get_quotes(symbols).await?))
+    }
+
+    async fn subscribe(&self, symbol: &Symbol) -> Result<(), PriceFeedError> {
+        self.inject("subscribe").await?;
+        self.inner.subscribe(symbol).await
+    }
+
+    async fn unsubscribe(&self, symbol: &Symbol) -> Result<(), PriceFeedError> {
+        self.inject("unsubscribe").await?;
+        self.inner.unsubscribe(symbol).await
+    }
+
+    async fn get_last_price(
+        &self,
+        instrument_id: &InstrumentId,
+    ) -> Result<Decimal, PriceFeedError> {
+        self.inject("get_last_price").await?;
+        self.inner.get_last_price(instrument_id).await
+    }
+
+    async fn get_bars(
+        &self,
+        symbol: &Symbol,
+        timeframe: BarTimeframe,
+        limit: usize,
+    ) -> Result<Vec<Candle>, PriceFeedError> {
+        let injection = self.inject("get_bars").await?;
+        Ok(injection.truncate(self.inner.get_bars(symbol, timeframe, limit).await?))
+    }
+}
+
+impl<P: CredentialSink> CredentialSink for FaultInjectingPriceFeed<P> {
+    fn name(&self) -> &'static str {
+        self.inner.name()
+    }
+
+    fn rotate_credentials(&self, credentials: &AlpacaCredentials) -> Result<(), AlpacaError> {
+        self.inner.rotate_credentials(credentials)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::order_execution::value_objects::Environment;
+    use crate::infrastructure::chaos::{FaultKind, FaultRule};
+    use crate::infrastructure::price_feed::MockPriceFeed;
+
+    fn feed(rules: Vec<FaultRule>) -> FaultInjectingPriceFeed<MockPriceFeed> {
+        let faults = FaultInjector::new("feed", rules, Some(1), Environment::Paper).unwrap();
+        FaultInjectingPriceFeed::new(MockPriceFeed::new(), faults)
+    }
+
+    #[tokio::test]
+    async fn disconnect_fails_every_call() {
+        let feed = feed(vec![FaultRule::new(
+            &["get_quote"],
+            FaultKind::Disconnect {
+                duration_ms: 60_000,
+            },
+        )]);
+
+        assert!(feed.get_quote(&Symbol::new("AAPL")).await.is_err());
+        assert!(matches!(
+            feed.get_last_price(&InstrumentId::new("AAPL")).await,
+            Err(PriceFeedError::ConnectionError { .. })
+        ));
+        assert_eq!(feed.faults().unwrap().injections(), 1);
+    }
+
+    #[tokio::test]
+    async fn partial_response_drops_quotes() {
+        let feed = feed(vec![FaultRule::new(
+            &["get_quotes"],
+            FaultKind::PartialResponse { keep_ratio: 0.25 },
+        )]);
+        let symbols: Vec<_> = ["AAPL", "MSFT", "SPY", "QQQ"]
+            .into_iter()
+            .map(Symbol::new)
+            .collect();
+
+        let quotes = feed.get_quotes(&symbols).await.unwrap();
+
+        assert_eq!(quotes.len(), 1);
+        assert!(feed.get_quote(&Symbol::new("AAPL")).await.is_ok());
+    }
+}
@@ -0,0 +1,375 @@
+//! Chaos scenario files.
+
+use std::path::Path;
+use std::time::Duration;
+
+use serde::Deserialize;
+
+use super::injector::FaultInjector;
+use crate::domain::order_execution::value_objects::Environment;
+
+/// Broker operations a rule can target.
+pub const BROKER_OPERATIONS: [&str; 11] = [
+    "submit_order",
+    "cancel_order",
+    "get_order",
+    "get_open_orders",
+    "get_buying_power",
+    "get_position",
+    "get_all_positions",
+    "get_asset",
+    "list_assets",
+    "get_market_clock",
+    "get_activities",
+];
+
+/// Price feed operations a rule can target.
+pub const FEED_OPERATIONS: [&str; 6] = [
+    "get_quote",
+    "get_quotes",
+    "subscribe",
+    "unsubscribe",
+    "get_last_price",
+    "get_bars",
+];
+
+/// Chaos scenario errors.
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+pub enum ChaosError {
+    /// The scenario file could not be read.
+    #[error("Failed to read chaos scenario {path}: {message}")]
+    Read {
+        /// File path.
+        path: String,
+        /// Error details.
+        message: String,
+    },
+
+    /// The scenario file is not valid JSON or does not match the format.
+    #[error("Invalid chaos scenario {path}: {message}")]
+    Parse {
+        /// File path.
+        path: String,
+        /// Error details.
+        message: String,
+    },
+
+    /// A rule is out of range or targets an unknown operation.
+    #[error("Invalid chaos rule {target}[{index}]: {message}")]
+    InvalidRule {
+        /// Adapter the rule belongs to (`broker` or `feed`).
+        target: &'static str,
+        /// Position of the rule in its list.
+        index: usize,
+        /// Error details.
+        message: String,
+    },
+
+    /// Fault injection was requested in LIVE.
+    #[error("Fault injection is not allowed in LIVE")]
+    LiveEnvironment,
+}
+
+/// A fault to inject.
+#[derive(Debug, Clone, Copy, PartialEq, Deserialize)]
+#[serde(tag = "kind", rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum FaultKind {
+    /// Delay the call before forwarding it.
+    Latency {
+        /// Added delay in milliseconds.
+        delay_ms: u64,
+    },
+    /// Fail the call as if the API returned a 5xx, without forwarding it.
+    ServerError {
+        /// HTTP status reported in the error.
+        #[serde(default = "default_status")]
+        status: u16,
+        /// Consecutive matching calls that fail once the rule fires.
+        #[serde(default = "default_burst")]
+        burst: u32,
+    },
+    /// Drop part of a list result (open orders, positions, assets,
+    /// activities, quotes). Ignored by calls that return a single item.
+    PartialResponse {
+        /// Fraction of items kept, rounded down.
+        keep_ratio: f64,
+    },
+    /// Fail every call on the adapter, whatever its operation, for a while.
+    Disconnect {
+        /// Outage length in milliseconds.
+        duration_ms: u64,
+    },
+}
+
+const fn default_status() -> u16 {
+    503
+}
+
+const fn default_burst() -> u32 {
+    1
+}
+
+const fn default_probability() -> f64 {
+    1.0
+}
+
+impl FaultKind {
+    /// Fault name for logs.
+    #[must_use]
+    pub const fn name(&self) -> &'static str {
+        match self {
+            Self::Latency { .. } => "latency",
+            Self::ServerError { .. } => "server_error",
+            Self::PartialResponse { .. } => "partial_response",
+            Self::Disconnect { .. } => "disconnect",
+        }
+    }
+
+    /// Delay of a latency fault, or outage length of a disconnect.
+    #[must_use]
+    pub const fn duration(&self) -> Duration {
+        match self {
+            Self::Latency { delay_ms } => Duration::from_millis(*delay_ms),
+            Self::Disconnect { duration_ms } => Duration::from_millis(*duration_ms),
+            Self::ServerError { .. } | Self::PartialResponse { .. } => Duration::ZERO,
+        }
+    }
+}
+
+/// When and where a fault is injected.
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct FaultRule {
+    /// Operations the rule applies to (empty = all).
+    #[serde(default)]
+    pub operations: Vec<String>,
+    /// Fault to inject.
+    pub fault: FaultKind,
+    /// Chance that an eligible call fires the rule.
+    #[serde(default = "default_probability")]
+    pub probability: f64,
+    /// Matching calls to let through before the rule becomes eligible.
+    #[serde(default)]
+    pub after_calls: u64,
+    /// Seconds after start before the rule becomes eligible.
+    #[serde(default)]
+    pub start_after_secs: u64,
+    /// Maximum times the rule fires (unlimited if absent).
+    #[serde(default)]
+    pub max_injections: Option<u64>,
+}
+
+impl FaultRule {
+    /// Create a rule that fires on every call to `operations`.
+    #[must_use]
+    pub fn new(operations: &[&str], fault: FaultKind) -> Self {
+        Self {
+            operations: operations.iter().map(ToString::to_string).collect(),
+            fault,
+            probability: 1.0,
+            after_calls: 0,
+            start_after_secs: 0,
+            max_injections: None,
+        }
+    }
+
+    /// Whether the rule applies to `operation`.
+    #[must_use]
+    pub fn matches(&self, operation: &str) -> bool {
+        self.operations.is_empty() || self.operations.iter().any(|op| op == operation)
+    }
+
+    fn validate(
+        &self,
+        target: &'static str,
+        index: usize,
+        known: &[&str],
+    ) -> Result<(), ChaosError> {
+        let invalid = |message: String| ChaosError::InvalidRule {
+            target,
+            index,
+            message,
+        };
+
+        if let Some(op) = self
+            .operations
+            .iter()
+            .find(|op| !known.contains(&op.as_str()))
+        {
+            return Err(invalid(format!("unknown operation '{op}'")));
+        }
+        if !(0.0..=1.0).contains(&self.probability) {
+            return Err(invalid("probability must be between 0 and 1".to_string()));
+        }
+        match self.fault {
+            FaultKind::PartialResponse { keep_ratio } if !(0.0..=1.0).contains(&keep_ratio) => {
+                Err(invalid("keep_ratio must be between 0 and 1".to_string()))
+            }
+            FaultKind::ServerError { status, .. } if !(500..=599).contains(&status) => {
+                Err(invalid("status must be a 5xx code".to_string()))
+            }
+            FaultKind::ServerError { burst: 0, .. } => {
+                Err(invalid("burst must be at least 1".to_string()))
+            }
+            _ => Ok(()),
+        }
+    }
+}
+
+/// Fault rules for the broker and price feed adapters.
+///
+/// ```json
+/// {
+///   "seed": 7,
+///   "broker": [
+///     { "operations": ["submit_order"], "fault": { "kind": "LATENCY", "delay_ms": 1500 }, "probability": 0.2 },
+///     { "operations": ["get_open_orders"], "fault": { "kind": "SERVER_ERROR", "burst": 5 }, "after_calls": 10 },
+///     { "fault": { "kind": "DISCONNECT", "duration_ms": 30000 }, "start_after_secs": 600, "max_injections": 1 }
+///   ],
+///   "feed": [
+///     { "operations": ["get_quotes"], "fault": { "kind": "PARTIAL_RESPONSE", "keep_ratio": 0.5 } }
+///   ]
+/// }
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Deserialize)]
+pub struct ChaosScenario {
+    /// RNG seed, so probabilistic rules replay identically (random if absent).
+    #[serde(default)]
+    pub seed: Option<u64>,
+    /// Rules for the broker adapter.
+    #[serde(default)]
+    pub broker: Vec<FaultRule>,
+    /// Rules for the price feed adapter.
+    #[serde(default)]
+    pub feed: Vec<FaultRule>,
+}
+
+impl ChaosScenario {
+    /// Load a scenario from a JSON file.
+    ///
+    /// # Errors
+    ///
+    /// Returns error if the file cannot be read or parsed, or a rule is
+    /// invalid.
+    pub fn from_file(path: &Path) -> Result<Self, ChaosError> {
+        let contents = std::fs::read_to_string(path).map_err(|e| ChaosError::Read {
+            path: path.display().to_string(),
+            message: e.to_string(),
+        })?;
+        let scenario: Self = serde_json::from_str(&contents).map_err(|e| ChaosError::Parse {
+            path: path.display().to_string(),
+            message: e.to_string(),
+        })?;
+        scenario.validate()?;
+        Ok(scenario)
+    }
+
+    /// Injector for the broker adapter's rules.
+    ///
+    /// # Errors
+    ///
+    /// Returns error in LIVE.
+    pub fn broker_injector(&self, environment: Environment) -> Result<FaultInjector, ChaosError> {
+        FaultInjector::new("broker", self.broker.clone(), self.seed, environment)
+    }
+
+    /// Injector for the price feed's rules.
+    ///
+    /// # Errors
+    ///
+    /// Returns error in LIVE.
+    pub fn feed_injector(&self, environment: Environment) -> Result<FaultInjector, ChaosError> {
+        FaultInjector::new("feed", self.feed.clone(), self.seed, environment)
+    }
+
+    /// Check every rule.
+    ///
+    /// # Errors
+    ///
+    /// Returns error for the first rule that is out of range or targets an
+    /// operation its adapter does not have.
+    pub fn validate(&self) -> Result<(), ChaosError> {
+        for (index, rule) in self.broker.iter().enumerate() {
+            rule.validate("broker", index, &BROKER_OPERATIONS)?;
+        }
+        for (index, rule) in self.feed.iter().enumerate() {
+            rule.validate("feed", index, &FEED_OPERATIONS)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_scenario_with_defaults() {
+        let scenario: ChaosScenario = serde_json::from_str(
+            r#"{
+                "seed": 7,
+                "broker": [
+                    { "operations": ["get_open_orders"], "fault": { "kind": "SERVER_ERROR" } },
+                    { "fault": { "kind": "DISCONNECT", "duration_ms": 500 }, "max_injections": 1 }
+                ]
+            }"#,
+        )
+        .unwrap();
+
+        assert_eq!(scenario.seed, Some(7));
+        assert!(scenario.feed.is_empty());
+        assert_eq!(
+            scenario.broker[0].fault,
+            FaultKind::ServerError {
+                status: 503,
+                burst: 1
+            }
+        );
+        assert!((scenario.broker[0].probability - 1.0).abs() < f64::EPSILON);
+        assert!(scenario.broker[1].matches("submit_order"));
+        assert_eq!(
+            scenario.broker[1].fault.duration(),
+            Duration::from_millis(500)
+        );
+        assert!(scenario.validate().is_ok());
+    }
+
+    #[test]
+    fn rejects_unknown_operation() {
+        let scenario = ChaosScenario {
+            feed: vec![FaultRule::new(
+                &["submit_order"],
+                FaultKind::Latency { delay_ms: 10 },
+            )],
+            ..ChaosScenario::default()
+        };
+
+        assert!(matches!(
+            scenario.validate(),
+            Err(ChaosError::InvalidRule {
+                target: "feed",
+                index: 0,
+                ..
+            })
+        ));
+    }
+
+    #[test]
+    fn rejects_out_of_range_values() {
+        let mut rule = FaultRule::new(&[], FaultKind::PartialResponse { keep_ratio: 1.5 });
+        let scenario = |rule: FaultRule| ChaosScenario {
+            broker: vec![rule],
+            ..ChaosScenario::default()
+        };
+        assert!(scenario(rule.clone()).validate().is_err());
+
+        rule.fault = FaultKind::ServerError {
+            status: 404,
+            burst: 1,
+        };
+        assert!(scenario(rule.clone()).validate().is_err());
+
+        rule.fault = FaultKind::Latency { delay_ms: 10 };
+        rule.probability = -0.1;
+        assert!(scenario(rule).validate().is_err());
+    }
+}
@@ -169,6 +169,8 @@ mod tests {
                 status: OrderStatus::Accepted,
                 filled_qty: Decimal::ZERO,
                 avg_fill_price: None,
+                child_order_ids: Vec::new(),
+                leg_fills: Vec::new(),
             })
         }
 
@@ -0,0 +1,1155 @@
+//! Engine Configuration File
+//!
+//! Parses the settings the engine reads from `config.yaml`. Values may
+//! reference environment variables as `${NAME}` or `${NAME:-default}`; a
+//! variable with no default must be set. Profile overlays such as
+//! `live.yaml` are merged over the file when it is loaded (see
+//! [`layers`](super::layers)).
+//!
+//! Settings fall into two groups:
+//! - **Structural** (`server.*`, `environment.mode`,
+//!   `constraints.instruments`, `observability.logging.file`, turning
+//!   `execution.shadow` on or off): bound at startup and only changed by a
+//!   restart.
+//! - **Tunable** (constraint limits, log level, execution profiles, the
+//!   reconciliation interval): applied at runtime by
+//!   [`ConfigReloader`](super::ConfigReloader).
+//!
+//! Sections the engine does not read are ignored.
+
+use std::collections::{HashMap, HashSet};
+use std::path::Path;
+use std::sync::LazyLock;
+use std::time::Duration;
+
+use regex::{Captures, Regex};
+use rust_decimal::Decimal;
+use rust_decimal::prelude::ToPrimitive;
+use serde::Deserialize;
+
+use super::layers::{load_layers, profile_from_env, redact};
+use crate::domain::execution_tactics::{
+    ExecutionProfile, ExecutionProfiles, StrategyFamily, TacticType, UrgencyMapping,
+};
+use crate::domain::order_execution::value_objects::OrderType;
+use crate::domain::risk_management::value_objects::{
+    ExposureLimits, InstrumentFilter, InstrumentUniverse,
+};
+use crate::domain::shared::Symbol;
+
+/// Log levels accepted by `observability.logging.level`.
+pub const LOG_LEVELS: [&str; 5] = ["trace", "debug", "info", "warn", "error"];
+
+#[allow(clippy::expect_used)]
+static VARIABLE: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r"\$\{([A-Za-z_][A-Za-z0-9_]*)(?::-([^}]*))?\}")
+        .expect("static variable pattern is valid")
+});
+
+/// Configuration file errors.
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+pub enum ConfigFileError {
+    /// The file could not be read.
+    #[error("Failed to read {path}: {message}")]
+    Read {
+        /// File path.
+        path: String,
+        /// Error details.
+        message: String,
+    },
+
+    /// A referenced environment variable is unset and has no default.
+    #[error("Environment variable {name} is not set")]
+    MissingVariable {
+        /// Variable name.
+        name: String,
+    },
+
+    /// The file is not valid YAML or does not match the format.
+    #[error("Invalid config file: {message}")]
+    Parse {
+        /// Error details.
+        message: String,
+    },
+
+    /// A setting is out of range.
+    #[error("Invalid {field}: {message}")]
+    Invalid {
+        /// Dotted path of the setting.
+        field: String,
+        /// Error details.
+        message: String,
+    },
+}
+
+/// Server bindings.
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+pub struct ServerSettings {
+    /// HTTP server port.
+    pub http_port: u16,
+    /// gRPC server port.
+    pub grpc_port: u16,
+    /// Bind address.
+    pub bind_address: String,
+}
+
+/// Trading environment.
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+pub struct EnvironmentSettings {
+    /// PAPER or LIVE.
+    pub mode: String,
+}
+
+/// Per-instrument limits, in dollars and fractions of equity.
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+pub struct PerInstrumentSettings {
+    /// Maximum notional value per instrument (USD).
+    pub max_notional: Decimal,
+    /// Maximum shares or contracts per instrument.
+    pub max_units: u32,
+    /// Maximum fraction of equity per instrument (0.10 = 10%).
+    pub max_equity_pct: Decimal,
+}
+
+/// Portfolio-level limits.
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+pub struct PortfolioSettings {
+    /// Maximum gross notional (USD).
+    pub max_gross_notional: Decimal,
+    /// Maximum net notional (USD).
+    pub max_net_notional: Decimal,
+    /// Maximum gross exposure as a multiple of equity.
+    pub max_leverage: Decimal,
+}
+
+/// Options Greeks limits.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Deserialize)]
+pub struct OptionsSettings {
+    /// Maximum portfolio gamma.
+    pub max_portfolio_gamma: Option<Decimal>,
+    /// Maximum portfolio vega (USD).
+    pub max_portfolio_vega: Option<Decimal>,
+    /// Maximum portfolio theta (USD per day, zero or negative).
+    pub max_portfolio_theta: Option<Decimal>,
+}
+
+/// Sector and correlation concentration limits.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Deserialize)]
+pub struct ConcentrationSettings {
+    /// Maximum gross exposure per sector as a fraction of equity.
+    pub max_sector_pct: Option<Decimal>,
+    /// Maximum gross exposure per correlation bucket as a fraction of equity.
+    pub max_correlation_pct: Option<Decimal>,
+}
+
+/// Instrument allowlists and blocklists by environment.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Deserialize)]
+pub struct InstrumentSettings {
+    /// Instruments tradable in PAPER mode (everything when unset).
+    #[serde(default)]
+    pub paper: InstrumentUniverse,
+    /// Instruments tradable in LIVE mode (everything when unset).
+    #[serde(default)]
+    pub live: InstrumentUniverse,
+}
+
+/// Risk constraint limits.
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+pub struct ConstraintSettings {
+    /// Per-instrument limits.
+    pub per_instrument: PerInstrumentSettings,
+    /// Portfolio-level limits.
+    pub portfolio: PortfolioSettings,
+    /// Options limits (unset values keep the defaults).
+    #[serde(default)]
+    pub options: OptionsSettings,
+    /// Concentration limits (unset values keep the defaults).
+    #[serde(default)]
+    pub concentration: ConcentrationSettings,
+    /// Tradable instruments (structural; unset allows everything).
+    #[serde(default)]
+    pub instruments: InstrumentSettings,
+}
+
+/// Execution profile for a strategy family.
+///
+/// Unset values fall back to `execution.default`.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Deserialize)]
+pub struct ExecutionProfileSettings {
+    /// Tactic when order size and market state do not call for another.
+    #[serde(default)]
+    pub tactic: Option<TacticType>,
+    /// Urgency of entries and exits.
+    #[serde(default)]
+    pub urgency: Option<UrgencyMapping>,
+    /// Widest spread to trade into (basis points of the mid).
+    #[serde(default)]
+    pub max_spread_bps: Option<Decimal>,
+    /// Order types the family may use (any when empty).
+    #[serde(default)]
+    pub order_types: Option<Vec<OrderType>>,
+}
+
+impl ExecutionProfileSettings {
+    fn resolve(&self, base: &ExecutionProfile) -> ExecutionProfile {
+        ExecutionProfile {
+            default_tactic: self.tactic.unwrap_or(base.default_tactic),
+            urgency: self.urgency.unwrap_or(base.urgency),
+            max_spread_bps: self.max_spread_bps.or(base.max_spread_bps),
+            allowed_order_types: self
+                .order_types
+                .clone()
+                .unwrap_or_else(|| base.allowed_order_types.clone()),
+        }
+    }
+}
+
+/// Execution profiles by strategy family.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Deserialize)]
+pub struct ExecutionSettings {
+    /// Profile for families without their own.
+    #[serde(default)]
+    pub default: ExecutionProfileSettings,
+    /// Profiles by strategy family (e.g. `VERTICAL_SPREAD`).
+    #[serde(default)]
+    pub profiles: HashMap<StrategyFamily, ExecutionProfileSettings>,
+    /// Alternative profiles simulated next to the live ones.
+    #[serde(default)]
+    pub shadow: Option<ShadowExecutionSettings>,
+}
+
+/// Execution profiles evaluated in shadow mode.
+///
+/// Unset values fall back to the live profile of the same family.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Deserialize)]
+pub struct ShadowExecutionSettings {
+    /// Overrides for every family.
+    #[serde(default)]
+    pub default: ExecutionProfileSettings,
+    /// Overrides by strategy family.
+    #[serde(default)]
+    pub profiles: HashMap<StrategyFamily, ExecutionProfileSettings>,
+}
+
+/// Log file line format.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum LogFileFormat {
+    /// One JSON object per line.
+    #[default]
+    Json,
+    /// Human-readable text.
+    Text,
+}
+
+/// Rotating log file settings.
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+pub struct FileLoggingSettings {
+    /// Directory for log files (created if missing).
+    pub directory: String,
+    /// Line format.
+    #[serde(default)]
+    pub format: LogFileFormat,
+    /// Total size of application log files to keep (MB).
+    #[serde(default = "default_max_total_mb")]
+    pub max_total_mb: u64,
+    /// Total size of audit log files to keep (MB).
+    #[serde(default = "default_audit_max_total_mb")]
+    pub audit_max_total_mb: u64,
+}
+
+const fn default_max_total_mb() -> u64 {
+    1024
+}
+
+const fn default_audit_max_total_mb() -> u64 {
+    4096
+}
+
+/// Logging settings.
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+pub struct LoggingSettings {
+    /// Log level for engine targets.
+    #[serde(default = "default_log_level")]
+    pub level: String,
+    /// Rotating log files (console only when unset).
+    #[serde(default)]
+    pub file: Option<FileLoggingSettings>,
+}
+
+impl Default for LoggingSettings {
+    fn default() -> Self {
+        Self {
+            level: default_log_level(),
+            file: None,
+        }
+    }
+}
+
+fn default_log_level() -> String {
+    "info".to_string()
+}
+
+/// Observability settings.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Deserialize)]
+pub struct ObservabilitySettings {
+    /// Logging settings.
+    #[serde(default)]
+    pub logging: LoggingSettings,
+}
+
+/// Periodic broker reconciliation.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Deserialize)]
+pub struct ReconciliationSettings {
+    /// Seconds between reconciliation passes (none when zero).
+    #[serde(default)]
+    pub interval_secs: u64,
+}
+
+/// A tunable setting whose value differs between two files.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ConfigChange {
+    /// Dotted path of the setting.
+    pub field: &'static str,
+    /// Previous value.
+    pub old: String,
+    /// New value.
+    pub new: String,
+}
+
+/// Settings read from `config.yaml`.
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+pub struct EngineFileConfig {
+    /// Server bindings (structural).
+    pub server: ServerSettings,
+    /// Trading environment (structural).
+    pub environment: EnvironmentSettings,
+    /// Risk constraint limits (tunable).
+    pub constraints: ConstraintSettings,
+    /// Observability settings (tunable).
+    #[serde(default)]
+    pub observability: ObservabilitySettings,
+    /// Execution profiles (tunable; turning shadow mode on or off is
+    /// structural).
+    #[serde(default)]
+    pub execution: ExecutionSettings,
+    /// Broker reconciliation (tunable).
+    #[serde(default)]
+    pub reconciliation: ReconciliationSettings,
+}
+
+impl EngineFileConfig {
+    /// Read and validate a config file merged with the overlays of the
+    /// profile selected by the process environment, resolving variables
+    /// from the process environment.
+    ///
+    /// # Errors
+    ///
+    /// Returns error if a file cannot be read or the result is invalid.
+    pub fn load(path: &Path) -> Result<Self, ConfigFileError> {
+        Self::from_value(Self::load_value(path)?)
+    }
+
+    /// The merged config at `path` as YAML with secrets redacted, as
+    /// [`load`](Self::load) sees it.
+    ///
+    /// # Errors
+    ///
+    /// Returns error if a file cannot be read or the result is invalid.
+    pub fn effective_yaml(path: &Path) -> Result<String, ConfigFileError> {
+        let mut value = Self::load_value(path)?;
+        Self::from_value(value.clone())?;
+        redact(&mut value);
+        serde_yaml_bw::to_string(&value).map_err(|e| ConfigFileError::Parse {
+            message: e.to_string(),
+        })
+    }
+
+    fn load_value(path: &Path) -> Result<serde_yaml_bw::Value, ConfigFileError> {
+        load_layers(path, &profile_from_env(), |name| std::env::var(name).ok())
+    }
+
+    fn from_value(value: serde_yaml_bw::Value) -> Result<Self, ConfigFileError> {
+        let config: Self =
+            serde_yaml_bw::from_value(value).map_err(|e| ConfigFileError::Parse {
+                message: e.to_string(),
+            })?;
+        config.validate()?;
+        Ok(config)
+    }
+
+    /// Parse and validate a config file, resolving variables with `lookup`.
+    ///
+    /// # Errors
+    ///
+    /// Returns error if a variable is missing or the YAML is invalid.
+    pub fn from_yaml(
+        yaml: &str,
+        lookup: impl Fn(&str) -> Option<String>,
+    ) -> Result<Self, ConfigFileError> {
+        let yaml = interpolate(yaml, lookup)?;
+        let config: Self = serde_yaml_bw::from_str(&yaml).map_err(|e| ConfigFileError::Parse {
+            message: e.to_string(),
+        })?;
+        config.validate()?;
+        Ok(config)
+    }
+
+    fn validate(&self) -> Result<(), ConfigFileError> {
+        let mode = self.environment.mode.to_uppercase();
+        if mode != "PAPER" && mode != "LIVE" {
+            return Err(invalid("environment.mode", "must be PAPER or LIVE"));
+        }
+
+        let level = self.observability.logging.level.to_lowercase();
+        if !LOG_LEVELS.contains(&level.as_str()) {
+            return Err(invalid(
+                "observability.logging.level",
+                "must be one of trace, debug, info, warn, error",
+            ));
+        }
+
+        if let Some(file) = &self.observability.logging.file {
+            if file.directory.trim().is_empty() {
+                return Err(invalid(
+                    "observability.logging.file.directory",
+                    "must not be empty",
+                ));
+            }
+            if file.max_total_mb == 0 {
+                return Err(invalid(
+                    "observability.logging.file.max_total_mb",
+                    "must be positive",
+                ));
+            }
+            if file.audit_max_total_mb == 0 {
+                return Err(invalid(
+                    "observability.logging.file.audit_max_total_mb",
+                    "must be positive",
+                ));
+            }
+        }
+
+        let instruments = &self.constraints.instruments;
+        check_instruments("constraints.instruments.paper", &instruments.paper)?;
+        check_instruments("constraints.instruments.live", &instruments.live)?;
+
+        self.execution_profiles()?;
+        self.shadow_execution_profiles()?;
+        self.exposure_limits().map(|_| ())
+    }
+
+    /// Log level, lowercased.
+    #[must_use]
+    pub fn log_level(&self) -> String {
+        self.observability.logging.level.to_lowercase()
+    }
+
+    /// Time between periodic reconciliation passes (zero when off).
+    #[must_use]
+    pub const fn reconcile_interval(&self) -> Duration {
+        Duration::from_secs(self.reconciliation.interval_secs)
+    }
+
+    /// Exposure limits described by the `constraints` section.
+    ///
+    /// Limits the file does not set keep their defaults.
+    ///
+    /// # Errors
+    ///
+    /// Returns error if a limit is negative or out of range.
+    pub fn exposure_limits(&self) -> Result<ExposureLimits, ConfigFileError> {
+        let constraints = &self.constraints;
+        let mut limits = ExposureLimits::default();
+
+        let per_instrument = &constraints.per_instrument;
+        limits.per_instrument.max_units = per_instrument.max_units;
+        limits.per_instrument.max_notional_cents = cents(
+            "constraints.per_instrument.max_notional",
+            per_instrument.max_notional,
+        )?;
+        limits.per_instrument.max_pct_equity_bps = bps(
+            "constraints.per_instrument.max_equity_pct",
+            per_instrument.max_equity_pct,
+        )?;
+
+        let portfolio = &constraints.portfolio;
+        limits.portfolio.max_gross_notional_cents = cents(
+            "constraints.portfolio.max_gross_notional",
+            portfolio.max_gross_notional,
+        )?;
+        limits.portfolio.max_net_notional_cents = cents(
+            "constraints.portfolio.max_net_notional",
+            portfolio.max_net_notional,
+        )?;
+        limits.portfolio.max_pct_equity_gross_bps =
+            bps("constraints.portfolio.max_leverage", portfolio.max_leverage)?;
+
+        let options = &constraints.options;
+        if let Some(gamma) = options.max_portfolio_gamma {
+            limits.options.max_gamma_scaled = scaled(
+                "constraints.options.max_portfolio_gamma",
+                gamma,
+                Decimal::ONE_THOUSAND,
+            )?;
+        }
+        if let Some(vega) = options.max_portfolio_vega {
+            limits.options.max_vega_cents = cents("constraints.options.max_portfolio_vega", vega)?;
+        }
+        if let Some(theta) = options.max_portfolio_theta {
+            if theta.is_sign_positive() && !theta.is_zero() {
+                return Err(invalid(
+                    "constraints.options.max_portfolio_theta",
+                    "must be zero or negative",
+                ));
+            }
+            limits.options.max_theta_cents = (theta * Decimal::ONE_HUNDRED)
+                .round()
+                .to_i64()
+                .ok_or_else(|| {
+                    invalid("constraints.options.max_portfolio_theta", "out of range")
+                })?;
+        }
+
+        let concentration = &constraints.concentration;
+        if let Some(pct) = concentration.max_sector_pct {
+            limits.concentration.max_sector_pct_equity_bps =
+                bps("constraints.concentration.max_sector_pct", pct)?;
+        }
+        if let Some(pct) = concentration.max_correlation_pct {
+            limits.concentration.max_correlation_group_pct_equity_bps =
+                bps("constraints.concentration.max_correlation_pct", pct)?;
+        }
+
+        Ok(limits)
+    }
+
+    /// Instruments tradable in the configured environment.
+    #[must_use]
+    pub fn instrument_universe(&self) -> &InstrumentUniverse {
+        let instruments = &self.constraints.instruments;
+        if self.environment.mode.eq_ignore_ascii_case("LIVE") {
+            &instruments.live
+        } else {
+            &instruments.paper
+        }
+    }
+
+    /// Execution profiles described by the `execution` section.
+    ///
+    /// # Errors
+    ///
+    /// Returns error if a spread tolerance is not positive.
+    pub fn execution_profiles(&self) -> Result<ExecutionProfiles, ConfigFileError> {
+        let execution = &self.execution;
+        check_spread("execution.default.max_spread_bps", &execution.default)?;
+        let default = execution.default.resolve(&ExecutionProfile::default());
+
+        let mut profiles = ExecutionProfiles::new(default.clone());
+        for (family, settings) in &execution.profiles {
+            check_spread(
+                &format!("execution.profiles.{family}.max_spread_bps"),
+                settings,
+            )?;
+            profiles = profiles.with_profile(*family, settings.resolve(&default));
+        }
+        Ok(profiles)
+    }
+
+    /// Shadow profiles described by `execution.shadow`, if present.
+    ///
+    /// Each family's shadow profile is its live profile with the shadow
+    /// `default` overrides applied, then the family's own.
+    ///
+    /// # Errors
+    ///
+    /// Returns error if a spread tolerance is not positive.
+    pub fn shadow_execution_profiles(&self) -> Result<Option<ExecutionProfiles>, ConfigFileError> {
+        let Some(shadow) = &self.execution.shadow else {
+            return Ok(None);
+        };
+        let live = self.execution_profiles()?;
+        check_spread("execution.shadow.default.max_spread_bps", &shadow.default)?;
+        for (family, settings) in &shadow.profiles {
+            check_spread(
+                &format!("execution.shadow.profiles.{family}.max_spread_bps"),
+                settings,
+            )?;
+        }
+
+        let resolve = |family: Option<StrategyFamily>| {
+            let base = shadow.default.resolve(live.for_family(family));
+            family
+                .and_then(|f| shadow.profiles.get(&f))
+                .map_or_else(|| base.clone(), |settings| settings.resolve(&base))
+        };
+        let mut profiles = ExecutionProfiles::new(resolve(None));
+        let families: HashSet<StrategyFamily> = self
+            .execution
+            .profiles
+            .keys()
+            .chain(shadow.profiles.keys())
+            .copied()
+            .collect();
+        for family in families {
+            profiles = profiles.with_profile(family, resolve(Some(family)));
+        }
+        Ok(Some(profiles))
+    }
+
+    /// Structural settings that differ from `other`.
+    #[must_use]
+    pub fn restart_required(&self, other: &Self) -> Vec<&'static str> {
+        let mut fields = Vec::new();
+        if self.server.http_port != other.server.http_port {
+            fields.push("server.http_port");
+        }
+        if self.server.grpc_port != other.server.grpc_port {
+            fields.push("server.grpc_port");
+        }
+        if self.server.bind_address != other.server.bind_address {
+            fields.push("server.bind_address");
+        }
+        if !self
+            .environment
+            .mode
+            .eq_ignore_ascii_case(&other.environment.mode)
+        {
+            fields.push("environment.mode");
+        }
+        if self.constraints.instruments != other.constraints.instruments {
+            fields.push("constraints.instruments");
+        }
+        if self.observability.logging.file != other.observability.logging.file {
+            fields.push("observability.logging.file");
+        }
+        if self.execution.shadow.is_some() != other.execution.shadow.is_some() {
+            fields.push("execution.shadow");
+        }
+        fields
+    }
+
+    /// Tunable settings that differ from `other`.
+    #[must_use]
+    pub fn changes(&self, other: &Self) -> Vec<ConfigChange> {
+        self.tunables()
+            .into_iter()
+            .zip(other.tunables())
+            .filter(|((_, old), (_, new))| old != new)
+            .map(|((field, old), (_, new))| ConfigChange { field, old, new })
+            .collect()
+    }
+
+    fn tunables(&self) -> Vec<(&'static str, String)> {
+        let optional =
+            |value: Option<Decimal>| value.map_or_else(|| "-".to_string(), |v| v.to_string());
+        let constraints = &self.constraints;
+        let execution = &self.execution;
+        let shadow = execution.shadow.as_ref();
+        vec![
+            (
+                "constraints.per_instrument.max_notional",
+                constraints.per_instrument.max_notional.to_string(),
+            ),
+            (
+                "constraints.per_instrument.max_units",
+                constraints.per_instrument.max_units.to_string(),
+            ),
+            (
+                "constraints.per_instrument.max_equity_pct",
+                constraints.per_instrument.max_equity_pct.to_string(),
+            ),
+            (
+                "constraints.portfolio.max_gross_notional",
+                constraints.portfolio.max_gross_notional.to_string(),
+            ),
+            (
+                "constraints.portfolio.max_net_notional",
+                constraints.portfolio.max_net_notional.to_string(),
+            ),
+            (
+                "constraints.portfolio.max_leverage",
+                constraints.portfolio.max_leverage.to_string(),
+            ),
+            (
+                "constraints.options.max_portfolio_gamma",
+                optional(constraints.options.max_portfolio_gamma),
+            ),
+            (
+                "constraints.options.max_portfolio_vega",
+                optional(constraints.options.max_portfolio_vega),
+            ),
+            (
+                "constraints.options.max_portfolio_theta",
+                optional(constraints.options.max_portfolio_theta),
+            ),
+            (
+                "constraints.concentration.max_sector_pct",
+                optional(constraints.concentration.max_sector_pct),
+            ),
+            (
+                "constraints.concentration.max_correlation_pct",
+                optional(constraints.concentration.max_correlation_pct),
+            ),
+            ("observability.logging.level", self.log_level()),
+            (
+                "reconciliation.interval_secs",
+                self.reconciliation.interval_secs.to_string(),
+            ),
+            ("execution.default", describe_profile(&execution.default)),
+            ("execution.profiles", describe_profiles(&execution.profiles)),
+            (
+                "execution.shadow.default",
+                shadow.map_or_else(|| "-".to_string(), |s| describe_profile(&s.default)),
+            ),
+            (
+                "execution.shadow.profiles",
+                shadow.map_or_else(|| "-".to_string(), |s| describe_profiles(&s.profiles)),
+            ),
+        ]
+    }
+}
+
+/// Replace `${NAME}` and `${NAME:-default}` references.
+pub(super) fn interpolate(
+    yaml: &str,
+    lookup: impl Fn(&str) -> Option<String>,
+) -> Result<String, ConfigFileError> {
+    let mut missing = None;
+    let resolved = VARIABLE.replace_all(yaml, |caps: &Captures<'_>| {
+        let name = &caps[1];
+        match (lookup(name).filter(|v| !v.is_empty()), caps.get(2)) {
+            (Some(value), _) => value,
+            (None, Some(default)) => default.as_str().to_string(),
+            (None, None) => {
+                missing.get_or_insert_with(|| name.to_string());
+                String::new()
+            }
+        }
+    });
+
+    missing.map_or_else(
+        || Ok(resolved.into_owned()),
+        |name| Err(ConfigFileError::MissingVariable { name }),
+    )
+}
+
+fn check_instruments(field: &str, universe: &InstrumentUniverse) -> Result<(), ConfigFileError> {
+    check_filter(&format!("{field}.allow"), &universe.allow)?;
+    check_filter(&format!("{field}.block"), &universe.block)
+}
+
+fn check_filter(field: &str, filter: &InstrumentFilter) -> Result<(), ConfigFileError> {
+    if filter
+        .symbols
+        .iter()
+        .any(|symbol| Symbol::new(symbol).validate().is_err())
+    {
+        return Err(invalid(
+            &format!("{field}.symbols"),
+            "must be valid ticker or OCC symbols",
+        ));
+    }
+    let valid_pattern = |pattern: &str| {
+        !pattern.is_empty()
+            && pattern
+                .chars()
+                .all(|c| c.is_ascii_alphanumeric() || matches!(c, '.' | '*' | '?'))
+    };
+    if !filter.patterns.iter().all(|p| valid_pattern(p)) {
+        return Err(invalid(
+            &format!("{field}.patterns"),
+            "may only contain letters, digits, '.', '*' and '?'",
+        ));
+    }
+    Ok(())
+}
+
+fn check_spread(field: &str, settings: &ExecutionProfileSettings) -> Result<(), ConfigFileError> {
+    match settings.max_spread_bps {
+        Some(bps) if bps <= Decimal::ZERO => Err(invalid(field, "must be positive")),
+        _ => Ok(()),
+    }
+}
+
+/// One-line summary of the values a profile sets, for audit entries.
+fn describe_profile(settings: &ExecutionProfileSettings) -> String {
+    let mut parts = Vec::new();
+    if let Some(tactic) = settings.tactic {
+        parts.push(format!("tactic={tactic}"));
+    }
+    if let Some(urgency) = settings.urgency {
+        parts.push(format!("urgency={:?}/{:?}", urgency.entry, urgency.exit));
+    }
+    if let Some(bps) = settings.max_spread_bps {
+        parts.push(format!("max_spread_bps={bps}"));
+    }
+    if let Some(types) = &settings.order_types {
+        let types: Vec<String> = types.iter().map(ToString::to_string).collect();
+        parts.push(format!("order_types=[{}]", types.join(",")));
+    }
+    if parts.is_empty() {
+        "-".to_string()
+    } else {
+        parts.join(" ")
+    }
+}
+
+/// Profiles by family, sorted so unchanged maps compare equal.
+fn describe_profiles(profiles: &HashMap<StrategyFamily, ExecutionProfileSettings>) -> String {
+    let mut entries: Vec<String> = profiles
+        .iter()
+        .map(|(family, settings)| format!("{family}: {}", describe_profile(settings)))
+        .collect();
+    entries.sort();
+    if entries.is_empty() {
+        "-".to_string()
+    } else {
+        entries.join("; ")
+    }
+}
+
+fn invalid(field: &str, message: &str) -> ConfigFileError {
+    ConfigFileError::Invalid {
+        field: field.to_string(),
+        message: message.to_string(),
+    }
+}
+
+fn scaled<T: TryFrom<i64>>(
+    field: &str,
+    value: Decimal,
+    scale: Decimal,
+) -> Result<T, ConfigFileError> {
+    if value.is_sign_negative() {
+        return Err(invalid(field, "must not be negative"));
+    }
+    (value * scale)
+        .round()
+        .to_i64()
+        .and_then(|v| T::try_from(v).ok())
+        .ok_or_else(|| invalid(field, "out of range"))
+}
+
+fn cents(field: &str, value: Decimal) -> Result<i64, ConfigFileError> {
+    scaled(field, value, Decimal::ONE_HUNDRED)
+}
+
+fn bps(field: &str, value: Decimal) -> Result<u32, ConfigFileError> {
+    scaled(field, value, Decimal::from(10_000))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::execution_tactics::TacticUrgency;
+    use crate::domain::risk_management::value_objects::AssetClass;
+
+    const CONFIG: &str = r#"
+server:
+  http_port: 50051
+  grpc_port: 50053
+  bind_address: "0.0.0.0"
+feeds:
+  alpaca:
+    api_key: "${ALPACA_KEY:-}"
+constraints:
+  per_instrument:
+    max_notional: 50000
+    max_units: 1000
+    max_equity_pct: 0.10
+  portfolio:
+    max_gross_notional: 500000
+    max_net_notional: 200000
+    max_leverage: 2.0
+  options:
+    max_portfolio_gamma: 50.0
+    max_portfolio_theta: -500.0
+observability:
+  logging:
+    level: "info"
+environment:
+  mode: ${CREAM_ENV:-PAPER}
+"#;
+
+    fn parse(yaml: &str) -> Result<EngineFileConfig, ConfigFileError> {
+        EngineFileConfig::from_yaml(yaml, |_| None)
+    }
+
+    #[test]
+    fn parses_shipped_layout() {
+        let config = parse(CONFIG).unwrap();
+        assert_eq!(config.server.http_port, 50051);
+        assert_eq!(config.environment.mode, "PAPER");
+
+        let limits = config.exposure_limits().unwrap();
+        assert_eq!(limits.per_instrument.max_notional_cents, 5_000_000);
+        assert_eq!(limits.per_instrument.max_pct_equity_bps, 1000);
+        assert_eq!(limits.portfolio.max_net_notional_cents, 20_000_000);
+        assert_eq!(limits.portfolio.max_pct_equity_gross_bps, 20_000);
+        assert_eq!(limits.options.max_gamma_scaled, 50_000);
+        assert_eq!(limits.options.max_theta_cents, -50_000);
+        assert_eq!(
+            limits.options.max_vega_cents,
+            ExposureLimits::default().options.max_vega_cents
+        );
+    }
+
+    #[test]
+    fn interpolates_environment_variables() {
+        let yaml = CONFIG.replace("bind_address: \"0.0.0.0\"", "bind_address: \"${BIND}\"");
+        let config = EngineFileConfig::from_yaml(&yaml, |name| match name {
+            "CREAM_ENV" => Some("LIVE".to_string()),
+            "BIND" => Some("127.0.0.1".to_string()),
+            _ => None,
+        })
+        .unwrap();
+        assert_eq!(config.environment.mode, "LIVE");
+        assert_eq!(config.server.bind_address, "127.0.0.1");
+
+        assert_eq!(
+            parse(&yaml).unwrap_err(),
+            ConfigFileError::MissingVariable {
+                name: "BIND".to_string()
+            }
+        );
+    }
+
+    #[test]
+    fn rejects_invalid_settings() {
+        let err = parse(&CONFIG.replace("max_notional: 50000", "max_notional: -1")).unwrap_err();
+        assert!(
+            matches!(err, ConfigFileError::Invalid { field, .. } if field == "constraints.per_instrument.max_notional")
+        );
+
+        let err = parse(&CONFIG.replace("level: \"info\"", "level: \"loud\"")).unwrap_err();
+        assert!(
+            matches!(err, ConfigFileError::Invalid { field, .. } if field == "observability.logging.level")
+        );
+
+        let err = parse(&CONFIG.replace(
+            "level: \"info\"",
+            "level: \"info\"\n    file:\n      directory: \"logs\"\n      max_total_mb: 0",
+        ))
+        .unwrap_err();
+        assert!(
+            matches!(err, ConfigFileError::Invalid { field, .. } if field == "observability.logging.file.max_total_mb")
+        );
+    }
+
+    #[test]
+    fn parses_file_logging() {
+        let config = parse(&CONFIG.replace(
+            "level: \"info\"",
+            "level: \"info\"\n    file:\n      directory: \"/var/log/cream\"",
+        ))
+        .unwrap();
+
+        let file = config.observability.logging.file.as_ref().unwrap();
+        assert_eq!(file.directory, "/var/log/cream");
+        assert_eq!(file.format, LogFileFormat::Json);
+        assert_eq!(file.max_total_mb, 1024);
+
+        assert_eq!(
+            parse(CONFIG).unwrap().restart_required(&config),
+            vec!["observability.logging.file"]
+        );
+    }
+
+    #[test]
+    fn parses_execution_profiles() {
+        let yaml = format!(
+            "{CONFIG}execution:
+  default:
+    tactic: PASSIVE_LIMIT
+    max_spread_bps: 50
+  profiles:
+    VERTICAL_SPREAD:
+      tactic: AGGRESSIVE_LIMIT
+      urgency:
+        entry: high
+      max_spread_bps: 300
+      order_types: [LIMIT]
+"
+        );
+        let config = parse(&yaml).unwrap();
+        let profiles = config.execution_profiles().unwrap();
+
+        let spread = profiles.for_family(Some(StrategyFamily::VerticalSpread));
+        assert_eq!(spread.default_tactic, TacticType::AggressiveLimit);
+        assert_eq!(spread.urgency.entry, TacticUrgency::High);
+        assert_eq!(spread.urgency.exit, TacticUrgency::High);
+        assert_eq!(spread.max_spread_bps, Some(Decimal::from(300)));
+        assert_eq!(spread.allowed_order_types, vec![OrderType::Limit]);
+
+        // Families without a profile use the default section
+        let equity = profiles.for_family(Some(StrategyFamily::EquityLong));
+        assert_eq!(equity.default_tactic, TacticType::PassiveLimit);
+        assert_eq!(equity.max_spread_bps, Some(Decimal::from(50)));
+        assert!(equity.allowed_order_types.is_empty());
+
+        // Profiles are tunable
+        let current = parse(CONFIG).unwrap();
+        assert!(current.restart_required(&config).is_empty());
+        let changes = current.changes(&config);
+        assert_eq!(
+            changes.iter().map(|c| c.field).collect::<Vec<_>>(),
+            vec!["execution.default", "execution.profiles"]
+        );
+        assert_eq!(
+            changes[1].new,
+            "VERTICAL_SPREAD: tactic=AGGRESSIVE_LIMIT urgency=High/High max_spread_bps=300 order_types=[LIMIT]"
+        );
+
+        assert!(config.shadow_execution_profiles().unwrap().is_none());
+
+        let err = parse(&yaml.replace("max_spread_bps: 300", "max_spread_bps: 0")).unwrap_err();
+        assert!(
+            matches!(err, ConfigFileError::Invalid { field, .. } if field == "execution.profiles.VERTICAL_SPREAD.max_spread_bps")
+        );
+    }
+
+    #[test]
+    fn parses_shadow_execution_profiles() {
+        let yaml = format!(
+            "{CONFIG}execution:
+  default:
+    tactic: PASSIVE_LIMIT
+    max_spread_bps: 50
+  profiles:
+    VERTICAL_SPREAD:
+      tactic: AGGRESSIVE_LIMIT
+      order_types: [LIMIT]
+  shadow:
+    default:
+      urgency:
+        entry: high
+    profiles:
+      STRADDLE:
+        tactic: AGGRESSIVE_LIMIT
+"
+        );
+        let config = parse(&yaml).unwrap();
+        let shadow = config.shadow_execution_profiles().unwrap().unwrap();
+
+        // Shadow overrides apply on top of each family's live profile
+        let spread = shadow.for_family(Some(StrategyFamily::VerticalSpread));
+        assert_eq!(spread.default_tactic, TacticType::AggressiveLimit);
+        assert_eq!(spread.urgency.entry, TacticUrgency::High);
+        assert_eq!(spread.allowed_order_types, vec![OrderType::Limit]);
+
+        let straddle = shadow.for_family(Some(StrategyFamily::Straddle));
+        assert_eq!(straddle.default_tactic, TacticType::AggressiveLimit);
+        assert_eq!(straddle.max_spread_bps, Some(Decimal::from(50)));
+
+        let equity = shadow.for_family(Some(StrategyFamily::EquityLong));
+        assert_eq!(equity.default_tactic, TacticType::PassiveLimit);
+        assert_eq!(equity.urgency.entry, TacticUrgency::High);
+
+        // Turning shadow mode on or off needs a restart; changing it does not
+        let live_only = parse(&yaml[..yaml.find("  shadow:").unwrap()]).unwrap();
+        assert_eq!(
+            live_only.restart_required(&config),
+            vec!["execution.shadow"]
+        );
+        let retuned = parse(&yaml.replace("entry: high", "entry: low")).unwrap();
+        assert!(config.restart_required(&retuned).is_empty());
+    }
+
+    #[test]
+    fn parses_instrument_lists_per_environment() {
+        let yaml = CONFIG.replace(
+            "  options:\n",
+            "  instruments:
+    paper:
+      block:
+        asset_classes: [OPTION]
+    live:
+      allow:
+        symbols: [SPY, QQQ]
+        patterns: [\"XL?\"]
+      block:
+        symbols: [GME]
+  options:\n",
+        );
+        let paper = parse(&yaml).unwrap();
+        assert_eq!(
+            paper.instrument_universe().block.asset_classes,
+            vec![AssetClass::Option]
+        );
+        assert!(paper.instrument_universe().allow.is_empty());
+
+        let live = EngineFileConfig::from_yaml(&yaml, |name| {
+            (name == "CREAM_ENV").then(|| "LIVE".to_string())
+        })
+        .unwrap();
+        let universe = live.instrument_universe();
+        assert_eq!(universe.allow.symbols, vec!["SPY", "QQQ"]);
+        assert_eq!(universe.block.symbols, vec!["GME"]);
+
+        assert_eq!(
+            parse(CONFIG).unwrap().restart_required(&paper),
+            vec!["constraints.instruments"]
+        );
+
+        let err = parse(&yaml.replace("\"XL?\"", "\"XL[A-Z]\"")).unwrap_err();
+        assert!(
+            matches!(err, ConfigFileError::Invalid { field, .. } if field == "constraints.instruments.live.allow.patterns")
+        );
+    }
+
+    #[test]
+    fn applies_profile_overlays() {
+        let dir = tempfile::tempdir().unwrap();
+        let base = dir.path().join("config.yaml");
+        std::fs::write(&base, CONFIG).unwrap();
+        std::fs::write(
+            dir.path().join("live.yaml"),
+            "environment:\n  mode: LIVE\nconstraints:\n  per_instrument:\n    max_equity_pct: 0.05\n",
+        )
+        .unwrap();
+
+        let live =
+            EngineFileConfig::from_value(load_layers(&base, "live", |_| None).unwrap()).unwrap();
+        assert_eq!(live.environment.mode, "LIVE");
+        let limits = live.exposure_limits().unwrap();
+        assert_eq!(limits.per_instrument.max_pct_equity_bps, 500);
+        assert_eq!(limits.per_instrument.max_notional_cents, 5_000_000);
+        assert_eq!(limits.portfolio.max_pct_equity_gross_bps, 20_000);
+
+        let paper =
+            EngineFileConfig::from_value(load_layers(&base, "paper", |_| None).unwrap()).unwrap();
+        assert_eq!(paper, parse(CONFIG).unwrap());
+    }
+
+    #[test]
+    fn separates_structural_and_tunable_changes() {
+        let current = parse(CONFIG).unwrap();
+        let updated = parse(&format!(
+            "{}reconciliation:\n  interval_secs: 90\n",
+            CONFIG
+                .replace("http_port: 50051", "http_port: 8080")
+                .replace("max_units: 1000", "max_units: 500")
+        ))
+        .unwrap();
+
+        assert_eq!(current.restart_required(&updated), vec!["server.http_port"]);
+        assert_eq!(
+            current.changes(&updated),
+            vec![
+                ConfigChange {
+                    field: "constraints.per_instrument.max_units",
+                    old: "1000".to_string(),
+                    new: "500".to_string(),
+                },
+                ConfigChange {
+                    field: "reconciliation.interval_secs",
+                    old: "0".to_string(),
+                    new: "90".to_string(),
+                },
+            ]
+        );
+        assert_eq!(current.reconcile_interval(), Duration::ZERO);
+        assert_eq!(updated.reconcile_interval(), Duration::from_secs(90));
+    }
+}
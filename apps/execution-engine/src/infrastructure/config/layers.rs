@@ -0,0 +1,264 @@
+//! Layered Configuration Files
+//!
+//! The file at `CONFIG_PATH` is the base layer. A profile overlay next to
+//! it, `<profile>.yaml` (`paper.yaml`, `live.yaml`, `backtest.yaml`), is
+//! merged on top when it exists. The profile is `CONFIG_PROFILE`, else
+//! `CREAM_ENV` in lower case, else `paper`.
+//!
+//! An overlay inherits from another overlay with a top-level
+//! `extends: <file>` (relative to the overlay), so `live.yaml` can start
+//! from `paper.yaml`; every chain ends at the base file. Mappings are merged
+//! key by key; any other value, sequences included, replaces the one below
+//! it.
+//!
+//! Precedence, lowest first: base file, inherited overlays, profile
+//! overlay. `${NAME}` references are resolved in each file before merging,
+//! so a variable takes effect unless a later layer sets the same key.
+
+use std::path::{Path, PathBuf};
+use std::sync::LazyLock;
+
+use regex::Regex;
+use serde_yaml_bw::{Mapping, Value};
+
+use super::engine_file::{ConfigFileError, interpolate};
+use crate::infrastructure::secrets::REDACTED;
+
+/// Top-level key naming the file an overlay inherits from.
+const EXTENDS: &str = "extends";
+
+/// Key words whose values are redacted by [`redact`].
+const SECRET_WORDS: [&str; 5] = ["key", "secret", "token", "password", "credentials"];
+
+/// Profile used when neither `CONFIG_PROFILE` nor `CREAM_ENV` is set.
+pub const DEFAULT_PROFILE: &str = "paper";
+
+#[allow(clippy::expect_used)]
+static URL_USERINFO: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"://[^/@\s]+@").expect("static userinfo pattern is valid"));
+
+/// Overlay profile selected by the process environment.
+#[must_use]
+pub fn profile_from_env() -> String {
+    ["CONFIG_PROFILE", "CREAM_ENV"]
+        .iter()
+        .find_map(|name| std::env::var(name).ok().filter(|v| !v.trim().is_empty()))
+        .map_or_else(
+            || DEFAULT_PROFILE.to_string(),
+            |profile| profile.trim().to_lowercase(),
+        )
+}
+
+/// Merge the base file at `path` with the overlays of `profile`, resolving
+/// variables with `lookup`.
+///
+/// # Errors
+///
+/// Returns error if a file cannot be read or parsed, a variable is missing,
+/// or the `extends` chain is invalid.
+pub fn load_layers(
+    path: &Path,
+    profile: &str,
+    lookup: impl Fn(&str) -> Option<String>,
+) -> Result<Value, ConfigFileError> {
+    let mut merged = read_layer(path, &lookup)?;
+    if let Some(mapping) = merged.as_mapping_mut() {
+        mapping.remove(EXTENDS);
+    }
+
+    let base = identity(path);
+    let mut seen = vec![base.clone()];
+    let mut overlays = Vec::new();
+    let mut next = Some(sibling(path, &format!("{profile}.yaml"))).filter(|p| p.is_file());
+    while let Some(overlay) = next {
+        let id = identity(&overlay);
+        if id == base {
+            break;
+        }
+        if seen.contains(&id) {
+            return Err(ConfigFileError::Invalid {
+                field: EXTENDS.to_string(),
+                message: format!("{} is inherited twice", overlay.display()),
+            });
+        }
+        seen.push(id);
+
+        let mut layer = read_layer(&overlay, &lookup)?;
+        next = match layer.as_mapping_mut().and_then(|m| m.remove(EXTENDS)) {
+            None => None,
+            Some(Value::String(parent, _)) => Some(sibling(&overlay, &parent)),
+            Some(_) => {
+                return Err(ConfigFileError::Invalid {
+                    field: EXTENDS.to_string(),
+                    message: format!("must be a file name in {}", overlay.display()),
+                });
+            }
+        };
+        overlays.push(layer);
+    }
+
+    for layer in overlays.into_iter().rev() {
+        merge(&mut merged, layer);
+    }
+    Ok(merged)
+}
+
+/// Replace secret values in `value` with `[REDACTED]`.
+///
+/// Secrets are values under keys containing the word `key`, `secret`,
+/// `token`, `password` or `credentials` (`api_key`, `client_secret`), and
+/// the user info of URLs.
+pub fn redact(value: &mut Value) {
+    match value {
+        Value::Mapping(mapping) => {
+            for (key, value) in mapping.iter_mut() {
+                let secret = key.as_str().is_some_and(|key| {
+                    key.to_lowercase()
+                        .split(['_', '-', '.'])
+                        .any(|word| SECRET_WORDS.contains(&word))
+                });
+                match value {
+                    Value::String(s, _) if secret && !s.is_empty() => {
+                        *value = Value::String(REDACTED.to_string(), None);
+                    }
+                    Value::Number(..) if secret => {
+                        *value = Value::String(REDACTED.to_string(), None);
+                    }
+                    _ => redact(value),
+                }
+            }
+        }
+        Value::Sequence(items) => items.iter_mut().for_each(redact),
+        Value::String(s, _) if URL_USERINFO.is_match(s) => {
+            *s = URL_USERINFO
+                .replace_all(s, format!("://{REDACTED}@"))
+                .into_owned();
+        }
+        _ => {}
+    }
+}
+
+/// Interpolated and parsed contents of one layer.
+fn read_layer(
+    path: &Path,
+    lookup: &impl Fn(&str) -> Option<String>,
+) -> Result<Value, ConfigFileError> {
+    let yaml = std::fs::read_to_string(path).map_err(|e| ConfigFileError::Read {
+        path: path.display().to_string(),
+        message: e.to_string(),
+    })?;
+    let yaml = interpolate(&yaml, lookup)?;
+    let layer: Option<Value> =
+        serde_yaml_bw::from_str(&yaml).map_err(|e| ConfigFileError::Parse {
+            message: format!("{}: {e}", path.display()),
+        })?;
+    match layer {
+        None | Some(Value::Null(_)) => Ok(Value::Mapping(Mapping::new())),
+        Some(layer @ Value::Mapping(_)) => Ok(layer),
+        Some(_) => Err(ConfigFileError::Parse {
+            message: format!("{}: must be a mapping", path.display()),
+        }),
+    }
+}
+
+/// Merge `overlay` into `base`.
+fn merge(base: &mut Value, overlay: Value) {
+    match (base, overlay) {
+        (Value::Mapping(base), Value::Mapping(overlay)) => {
+            for (key, value) in overlay {
+                match base.get_mut(&key) {
+                    Some(existing) => merge(existing, value),
+                    None => {
+                        base.insert(key, value);
+                    }
+                }
+            }
+        }
+        (base, overlay) => *base = overlay,
+    }
+}
+
+/// File `name` in the directory of `path`.
+fn sibling(path: &Path, name: &str) -> PathBuf {
+    path.parent()
+        .map_or_else(|| PathBuf::from(name), |dir| dir.join(name))
+}
+
+/// Canonical form of `path`, for comparing layers.
+fn identity(path: &Path) -> PathBuf {
+    std::fs::canonicalize(path).unwrap_or_else(|_| path.to_path_buf())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn write(dir: &Path, name: &str, yaml: &str) -> PathBuf {
+        let path = dir.join(name);
+        std::fs::write(&path, yaml).unwrap();
+        path
+    }
+
+    #[test]
+    fn overlays_merge_over_the_base_in_order() {
+        let dir = tempfile::tempdir().unwrap();
+        let base = write(
+            dir.path(),
+            "base.yaml",
+            "server:\n  http_port: 50051\n  bind_address: \"${BIND:-0.0.0.0}\"\n\
+             limits: [1, 2]\nfeeds:\n  api_key: \"${KEY}\"\n  db: \"postgres://u:p@db/cream\"\n",
+        );
+        write(dir.path(), "paper.yaml", "server:\n  http_port: 8080\n");
+        write(
+            dir.path(),
+            "live.yaml",
+            "extends: paper.yaml\nlimits: [3]\nserver:\n  bind_address: \"${LIVE_BIND}\"\n",
+        );
+        let lookup = |name: &str| match name {
+            "KEY" => Some("pk-live".to_string()),
+            "LIVE_BIND" => Some("10.0.0.1".to_string()),
+            _ => None,
+        };
+
+        let paper = load_layers(&base, "paper", lookup).unwrap();
+        assert_eq!(paper["server"]["http_port"].as_u64(), Some(8080));
+        assert_eq!(paper["server"]["bind_address"].as_str(), Some("0.0.0.0"));
+
+        let mut live = load_layers(&base, "live", lookup).unwrap();
+        assert_eq!(live["server"]["http_port"].as_u64(), Some(8080));
+        assert_eq!(live["server"]["bind_address"].as_str(), Some("10.0.0.1"));
+        assert_eq!(live["limits"][0].as_u64(), Some(3));
+        assert!(live.get(EXTENDS).is_none());
+
+        // No overlay for the profile leaves the base as is
+        let backtest = load_layers(&base, "backtest", lookup).unwrap();
+        assert_eq!(backtest["server"]["http_port"].as_u64(), Some(50051));
+
+        redact(&mut live);
+        assert_eq!(live["feeds"]["api_key"].as_str(), Some(REDACTED));
+        assert_eq!(
+            live["feeds"]["db"].as_str(),
+            Some("postgres://[REDACTED]@db/cream")
+        );
+        assert_eq!(live["server"]["bind_address"].as_str(), Some("10.0.0.1"));
+    }
+
+    #[test]
+    fn rejects_extends_cycles() {
+        let dir = tempfile::tempdir().unwrap();
+        let base = write(dir.path(), "config.yaml", "a: 1\n");
+        write(dir.path(), "live.yaml", "extends: paper.yaml\n");
+        write(dir.path(), "paper.yaml", "extends: live.yaml\n");
+
+        assert!(matches!(
+            load_layers(&base, "live", |_| None),
+            Err(ConfigFileError::Invalid { .. })
+        ));
+        // Extending the base explicitly is the same as not extending
+        write(dir.path(), "paper.yaml", "extends: config.yaml\na: 2\n");
+        assert_eq!(
+            load_layers(&base, "paper", |_| None).unwrap()["a"].as_u64(),
+            Some(2)
+        );
+    }
+}
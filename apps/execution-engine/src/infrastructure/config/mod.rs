@@ -1,7 +1,21 @@
 //! Infrastructure Configuration
 //!
-//! Configuration types and dependency injection container.
+//! Configuration types, `config.yaml` profile overlays and reloader,
+//! universe membership files, and the dependency injection container.
 
 mod container;
+mod engine_file;
+mod layers;
+mod reloader;
+mod universe_csv;
 
 pub use container::Container;
+pub use engine_file::{
+    ConcentrationSettings, ConfigChange, ConfigFileError, ConstraintSettings, EngineFileConfig,
+    EnvironmentSettings, ExecutionProfileSettings, ExecutionSettings, FileLoggingSettings,
+    LOG_LEVELS, LogFileFormat, LoggingSettings, ObservabilitySettings, OptionsSettings,
+    PerInstrumentSettings, PortfolioSettings, ServerSettings, ShadowExecutionSettings,
+};
+pub use layers::{DEFAULT_PROFILE, load_layers, profile_from_env, redact};
+pub use reloader::{CONFIG_POLICY_ID, ConfigReloadError, ConfigReloader, LogLevelSetter};
+pub use universe_csv::{UniverseCsvError, load_universe_csv, parse_universe_csv};
@@ -0,0 +1,479 @@
+//! Config Reloader
+//!
+//! Applies tunable settings from `config.yaml` while the engine runs.
+//! Reloads are triggered by SIGHUP or the admin API. A reload that changes
+//! a structural setting is rejected as a whole and nothing is applied.
+//!
+//! Constraint limits are applied as the active [`RiskPolicy`] with ID
+//! [`CONFIG_POLICY_ID`]; the log level is applied through a setter installed
+//! by the binary; execution profiles replace the ones the submit path and
+//! shadow execution check orders against; the reconciliation interval is
+//! published to the periodic reconciliation task through
+//! [`ConfigReloader::reconcile_interval`]. Every applied change is logged on
+//! the `audit` target.
+
+use std::path::PathBuf;
+use std::sync::{Arc, PoisonError, RwLock};
+use std::time::Duration;
+
+use tokio::sync::{Mutex, watch};
+
+use super::engine_file::{ConfigChange, ConfigFileError, EngineFileConfig};
+use crate::application::ports::RiskRepositoryPort;
+use crate::application::services::ShadowExecution;
+use crate::domain::execution_tactics::ExecutionProfiles;
+use crate::domain::risk_management::aggregate::RiskPolicy;
+use crate::domain::shared::{ClassifiedError, ErrorKind};
+
+/// ID of the risk policy built from the config file.
+pub const CONFIG_POLICY_ID: &str = "config";
+
+/// Applies a new log level (e.g. `debug`).
+pub type LogLevelSetter = Arc<dyn Fn(&str) -> Result<(), String> + Send + Sync>;
+
+/// Config reload errors.
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+pub enum ConfigReloadError {
+    /// The new file is unreadable or invalid.
+    #[error(transparent)]
+    File(#[from] ConfigFileError),
+
+    /// The new file changes settings that are bound at startup.
+    #[error("Changing {} requires a restart", fields.join(", "))]
+    RestartRequired {
+        /// Dotted paths of the structural settings that changed.
+        fields: Vec<String>,
+    },
+
+    /// A setting could not be applied.
+    #[error("Failed to apply {field}: {message}")]
+    Apply {
+        /// Dotted path of the setting.
+        field: String,
+        /// Error details.
+        message: String,
+    },
+}
+
+impl ClassifiedError for ConfigReloadError {
+    fn code(&self) -> &str {
+        match self {
+            Self::File(_) => "CONFIG_INVALID",
+            Self::RestartRequired { .. } => "RESTART_REQUIRED",
+            Self::Apply { .. } => "CONFIG_APPLY_FAILED",
+        }
+    }
+
+    fn kind(&self) -> ErrorKind {
+        match self {
+            Self::File(_) => ErrorKind::InvalidArgument,
+            Self::RestartRequired { .. } => ErrorKind::FailedPrecondition,
+            Self::Apply { .. } => ErrorKind::Internal,
+        }
+    }
+}
+
+/// Reloads `config.yaml` and applies tunable settings.
+pub struct ConfigReloader<R: RiskRepositoryPort> {
+    path: PathBuf,
+    risk_repo: Arc<R>,
+    log_level: Option<LogLevelSetter>,
+    execution_profiles: Option<Arc<RwLock<ExecutionProfiles>>>,
+    shadow: Option<Arc<ShadowExecution>>,
+    reconcile_interval: watch::Sender<Duration>,
+    /// Settings currently in effect; held for the whole reload.
+    current: Mutex<EngineFileConfig>,
+}
+
+impl<R: RiskRepositoryPort> std::fmt::Debug for ConfigReloader<R> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ConfigReloader")
+            .field("path", &self.path)
+            .finish_non_exhaustive()
+    }
+}
+
+impl<R: RiskRepositoryPort> ConfigReloader<R> {
+    /// Read the config file.
+    ///
+    /// Call [`Self::activate`] to apply it.
+    ///
+    /// # Errors
+    ///
+    /// Returns error if the file cannot be read or is invalid.
+    pub fn open(path: impl Into<PathBuf>, risk_repo: Arc<R>) -> Result<Self, ConfigFileError> {
+        let path = path.into();
+        let config = EngineFileConfig::load(&path)?;
+        Ok(Self::new(path, config, risk_repo))
+    }
+
+    /// Create a reloader for settings that are already parsed.
+    #[must_use]
+    pub fn new(path: impl Into<PathBuf>, config: EngineFileConfig, risk_repo: Arc<R>) -> Self {
+        Self {
+            path: path.into(),
+            risk_repo,
+            log_level: None,
+            execution_profiles: None,
+            shadow: None,
+            reconcile_interval: watch::Sender::new(config.reconcile_interval()),
+            current: Mutex::new(config),
+        }
+    }
+
+    /// Apply log level changes through `setter`.
+    #[must_use]
+    pub fn with_log_level(mut self, setter: LogLevelSetter) -> Self {
+        self.log_level = Some(setter);
+        self
+    }
+
+    /// Replace the execution profiles in `profiles` when they change.
+    #[must_use]
+    pub fn with_execution_profiles(mut self, profiles: Arc<RwLock<ExecutionProfiles>>) -> Self {
+        self.execution_profiles = Some(profiles);
+        self
+    }
+
+    /// Replace the profiles `shadow` simulates when they change.
+    #[must_use]
+    pub fn with_shadow_execution(mut self, shadow: Arc<ShadowExecution>) -> Self {
+        self.shadow = Some(shadow);
+        self
+    }
+
+    /// Time between periodic reconciliation passes, updated on reload
+    /// (zero when off).
+    #[must_use]
+    pub fn reconcile_interval(&self) -> watch::Receiver<Duration> {
+        self.reconcile_interval.subscribe()
+    }
+
+    /// Path of the config file.
+    #[must_use]
+    pub const fn path(&self) -> &PathBuf {
+        &self.path
+    }
+
+    /// Settings currently in effect.
+    pub async fn current(&self) -> EngineFileConfig {
+        self.current.lock().await.clone()
+    }
+
+    /// Apply the settings read at startup.
+    ///
+    /// # Errors
+    ///
+    /// Returns error if a setting cannot be applied.
+    pub async fn activate(&self) -> Result<(), ConfigReloadError> {
+        let current = self.current.lock().await;
+        self.apply_limits(&current).await?;
+        self.apply_log_level(&current)?;
+        self.apply_execution_profiles(&current)?;
+        self.reconcile_interval
+            .send_replace(current.reconcile_interval());
+        drop(current);
+        Ok(())
+    }
+
+    /// Re-read the config file and apply tunable settings that changed.
+    ///
+    /// `source` names the trigger in audit entries (e.g. `SIGHUP`).
+    ///
+    /// # Errors
+    ///
+    /// Returns error if the file is invalid, changes a structural setting,
+    /// or a setting cannot be applied.
+    pub async fn reload(&self, source: &str) -> Result<Vec<ConfigChange>, ConfigReloadError> {
+        let mut current = self.current.lock().await;
+
+        let result = self.reload_locked(&mut current).await;
+        match &result {
+            Ok(changes) => {
+                for change in changes {
+                    tracing::info!(
+                        target: "audit",
+                        source,
+                        path = %self.path.display(),
+                        field = change.field,
+                        old = %change.old,
+                        new = %change.new,
+                        "Config setting changed"
+                    );
+                }
+                tracing::info!(source, changes = changes.len(), "Config reloaded");
+            }
+            Err(e) => tracing::warn!(
+                target: "audit",
+                source,
+                path = %self.path.display(),
+                error = %e,
+                "Config reload rejected"
+            ),
+        }
+        drop(current);
+
+        result
+    }
+
+    async fn reload_locked(
+        &self,
+        current: &mut EngineFileConfig,
+    ) -> Result<Vec<ConfigChange>, ConfigReloadError> {
+        let updated = EngineFileConfig::load(&self.path)?;
+
+        let structural = current.restart_required(&updated);
+        if !structural.is_empty() {
+            return Err(ConfigReloadError::RestartRequired {
+                fields: structural.into_iter().map(str::to_string).collect(),
+            });
+        }
+
+        let changes = current.changes(&updated);
+        if changes.iter().any(|c| c.field.starts_with("constraints.")) {
+            self.apply_limits(&updated).await?;
+        }
+        if changes
+            .iter()
+            .any(|c| c.field == "observability.logging.level")
+        {
+            self.apply_log_level(&updated)?;
+        }
+        if changes.iter().any(|c| c.field.starts_with("execution.")) {
+            self.apply_execution_profiles(&updated)?;
+        }
+        if changes
+            .iter()
+            .any(|c| c.field == "reconciliation.interval_secs")
+        {
+            self.reconcile_interval
+                .send_replace(updated.reconcile_interval());
+        }
+
+        *current = updated;
+        Ok(changes)
+    }
+
+    async fn apply_limits(&self, config: &EngineFileConfig) -> Result<(), ConfigReloadError> {
+        let apply = |e: &dyn std::fmt::Display| ConfigReloadError::Apply {
+            field: "constraints".to_string(),
+            message: e.to_string(),
+        };
+        let limits = config.exposure_limits()?;
+
+        // Only one policy may be active
+        for mut other in self
+            .risk_repo
+            .list_policies()
+            .await
+            .map_err(|e| apply(&e))?
+        {
+            if other.is_active() && other.id() != CONFIG_POLICY_ID {
+                other.deactivate();
+                self.risk_repo
+                    .save_policy(&other)
+                    .await
+                    .map_err(|e| apply(&e))?;
+            }
+        }
+
+        let mut policy = self
+            .risk_repo
+            .find_policy_by_id(CONFIG_POLICY_ID)
+            .await
+            .map_err(|e| apply(&e))?
+            .unwrap_or_else(|| RiskPolicy::new(CONFIG_POLICY_ID, "config.yaml", limits.clone()));
+        policy.update_limits(limits);
+        policy.activate();
+
+        self.risk_repo
+            .save_policy(&policy)
+            .await
+            .map_err(|e| apply(&e))
+    }
+
+    fn apply_execution_profiles(&self, config: &EngineFileConfig) -> Result<(), ConfigReloadError> {
+        if let Some(profiles) = &self.execution_profiles {
+            *profiles.write().unwrap_or_else(PoisonError::into_inner) =
+                config.execution_profiles()?;
+        }
+        if let (Some(shadow), Some(profiles)) = (&self.shadow, config.shadow_execution_profiles()?)
+        {
+            shadow.set_profiles(profiles);
+        }
+        Ok(())
+    }
+
+    fn apply_log_level(&self, config: &EngineFileConfig) -> Result<(), ConfigReloadError> {
+        let Some(setter) = &self.log_level else {
+            return Ok(());
+        };
+        setter(&config.log_level()).map_err(|message| ConfigReloadError::Apply {
+            field: "observability.logging.level".to_string(),
+            message,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::application::ports::InMemoryRiskRepository;
+    use crate::domain::execution_tactics::{StrategyFamily, TacticType};
+    use std::sync::Mutex as StdMutex;
+
+    const CONFIG: &str = r#"
+server:
+  http_port: 50051
+  grpc_port: 50053
+  bind_address: "0.0.0.0"
+constraints:
+  per_instrument:
+    max_notional: 50000
+    max_units: 1000
+    max_equity_pct: 0.10
+  portfolio:
+    max_gross_notional: 500000
+    max_net_notional: 200000
+    max_leverage: 2.0
+observability:
+  logging:
+    level: "info"
+environment:
+  mode: PAPER
+"#;
+
+    struct Fixture {
+        _dir: tempfile::TempDir,
+        path: PathBuf,
+        repo: Arc<InMemoryRiskRepository>,
+        levels: Arc<StdMutex<Vec<String>>>,
+        reloader: ConfigReloader<InMemoryRiskRepository>,
+    }
+
+    async fn fixture() -> Fixture {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("config.yaml");
+        std::fs::write(&path, CONFIG).unwrap();
+
+        let repo = Arc::new(InMemoryRiskRepository::new());
+        let mut default_policy = RiskPolicy::default_policy();
+        default_policy.activate();
+        repo.save_policy(&default_policy).await.unwrap();
+
+        let levels = Arc::new(StdMutex::new(Vec::new()));
+        let recorded = Arc::clone(&levels);
+        let reloader = ConfigReloader::open(&path, Arc::clone(&repo))
+            .unwrap()
+            .with_log_level(Arc::new(move |level: &str| {
+                recorded.lock().unwrap().push(level.to_string());
+                Ok(())
+            }));
+        reloader.activate().await.unwrap();
+
+        Fixture {
+            _dir: dir,
+            path,
+            repo,
+            levels,
+            reloader,
+        }
+    }
+
+    #[tokio::test]
+    async fn activate_installs_config_policy() {
+        let fixture = fixture().await;
+
+        let active = fixture.repo.find_active_policy().await.unwrap().unwrap();
+        assert_eq!(active.id(), CONFIG_POLICY_ID);
+        assert_eq!(active.limits().portfolio.max_net_notional_cents, 20_000_000);
+        assert_eq!(*fixture.levels.lock().unwrap(), vec!["info"]);
+    }
+
+    #[tokio::test]
+    async fn reload_applies_tunable_changes() {
+        let fixture = fixture().await;
+        let interval = fixture.reloader.reconcile_interval();
+        assert_eq!(*interval.borrow(), Duration::ZERO);
+        std::fs::write(
+            &fixture.path,
+            format!(
+                "{}reconciliation:\n  interval_secs: 30\n",
+                CONFIG
+                    .replace("max_units: 1000", "max_units: 250")
+                    .replace("level: \"info\"", "level: \"debug\"")
+            ),
+        )
+        .unwrap();
+
+        let changes = fixture.reloader.reload("test").await.unwrap();
+        assert_eq!(changes.len(), 3);
+        assert_eq!(*interval.borrow(), Duration::from_secs(30));
+
+        let active = fixture.repo.find_active_policy().await.unwrap().unwrap();
+        assert_eq!(active.limits().per_instrument.max_units, 250);
+        assert_eq!(*fixture.levels.lock().unwrap(), vec!["info", "debug"]);
+    }
+
+    #[tokio::test]
+    async fn reload_replaces_execution_profiles() {
+        let fixture = fixture().await;
+        let profiles = Arc::new(RwLock::new(ExecutionProfiles::default()));
+        let reloader = ConfigReloader::open(&fixture.path, Arc::clone(&fixture.repo))
+            .unwrap()
+            .with_execution_profiles(Arc::clone(&profiles));
+        reloader.activate().await.unwrap();
+        std::fs::write(
+            &fixture.path,
+            format!(
+                "{CONFIG}execution:
+  profiles:
+    VERTICAL_SPREAD:
+      tactic: AGGRESSIVE_LIMIT
+"
+            ),
+        )
+        .unwrap();
+
+        let changes = reloader.reload("test").await.unwrap();
+        assert_eq!(changes.len(), 1);
+        assert_eq!(changes[0].field, "execution.profiles");
+
+        let profiles = profiles.read().unwrap();
+        let spread = profiles.for_family(Some(StrategyFamily::VerticalSpread));
+        assert_eq!(spread.default_tactic, TacticType::AggressiveLimit);
+        assert_eq!(
+            profiles.for_family(None).default_tactic,
+            TacticType::PassiveLimit
+        );
+    }
+
+    #[tokio::test]
+    async fn reload_rejects_structural_changes() {
+        let fixture = fixture().await;
+        std::fs::write(
+            &fixture.path,
+            CONFIG
+                .replace("grpc_port: 50053", "grpc_port: 9000")
+                .replace("mode: PAPER", "mode: LIVE")
+                .replace("max_units: 1000", "max_units: 250"),
+        )
+        .unwrap();
+
+        let err = fixture.reloader.reload("test").await.unwrap_err();
+        assert_eq!(
+            err,
+            ConfigReloadError::RestartRequired {
+                fields: vec![
+                    "server.grpc_port".to_string(),
+                    "environment.mode".to_string()
+                ]
+            }
+        );
+
+        // Nothing applied
+        let active = fixture.repo.find_active_policy().await.unwrap().unwrap();
+        assert_eq!(active.limits().per_instrument.max_units, 1000);
+        assert_eq!(fixture.reloader.current().await.server.grpc_port, 50053);
+    }
+}
@@ -0,0 +1,139 @@
+//! Universe Membership Files
+//!
+//! Reads point-in-time index membership from CSV with a header row and one
+//! row per membership period:
+//!
+//! ```csv
+//! symbol,added,removed
+//! AAPL,1982-11-30,
+//! BBBY,2007-03-01,2018-07-02
+//! ```
+//!
+//! `removed` is the first day the symbol was no longer a member and is left
+//! empty for current members. A symbol that left and rejoined has a row per
+//! period. The universe is named after the file stem (`sp500.csv` is
+//! `sp500`).
+
+use std::io::Read;
+use std::path::Path;
+
+use chrono::NaiveDate;
+use serde::Deserialize;
+use thiserror::Error;
+
+use crate::domain::risk_management::value_objects::{MembershipPeriod, UniverseMembership};
+
+/// Universe file errors.
+#[derive(Debug, Error)]
+pub enum UniverseCsvError {
+    /// The file could not be read.
+    #[error("Failed to read universe file {path}: {source}")]
+    Io {
+        /// File path.
+        path: String,
+        /// Underlying error.
+        source: std::io::Error,
+    },
+
+    /// A row is malformed.
+    #[error("Invalid universe row: {0}")]
+    Csv(#[from] csv::Error),
+
+    /// A period ends before it starts.
+    #[error("Universe row for {symbol} is removed on {removed}, before it was added on {added}")]
+    InvalidPeriod {
+        /// Symbol of the row.
+        symbol: String,
+        /// First day of membership.
+        added: NaiveDate,
+        /// First day after membership.
+        removed: NaiveDate,
+    },
+}
+
+#[derive(Debug, Deserialize)]
+struct MembershipRow {
+    symbol: String,
+    added: NaiveDate,
+    removed: Option<NaiveDate>,
+}
+
+/// Load the universe in the CSV file at `path`.
+///
+/// # Errors
+///
+/// Returns error if the file cannot be read or a row is invalid.
+pub fn load_universe_csv(path: &Path) -> Result<UniverseMembership, UniverseCsvError> {
+    let file = std::fs::File::open(path).map_err(|source| UniverseCsvError::Io {
+        path: path.display().to_string(),
+        source,
+    })?;
+    let name = path
+        .file_stem()
+        .and_then(|stem| stem.to_str())
+        .unwrap_or("configured");
+    parse_universe_csv(name, file)
+}
+
+/// Parse a universe called `name` from CSV.
+///
+/// # Errors
+///
+/// Returns error if a row is invalid.
+pub fn parse_universe_csv(
+    name: &str,
+    reader: impl Read,
+) -> Result<UniverseMembership, UniverseCsvError> {
+    let mut universe = UniverseMembership::new(name);
+    let mut rows = csv::ReaderBuilder::new()
+        .trim(csv::Trim::All)
+        .from_reader(reader);
+    for row in rows.deserialize() {
+        let row: MembershipRow = row?;
+        if let Some(removed) = row.removed
+            && removed < row.added
+        {
+            return Err(UniverseCsvError::InvalidPeriod {
+                symbol: row.symbol,
+                added: row.added,
+                removed,
+            });
+        }
+        universe.add(
+            &row.symbol,
+            MembershipPeriod {
+                added: row.added,
+                removed: row.removed,
+            },
+        );
+    }
+    Ok(universe)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::shared::Symbol;
+
+    #[test]
+    fn parses_periods_and_rejects_inverted_ones() {
+        let csv = "symbol,added,removed\n\
+                   AAPL,1982-11-30,\n\
+                   brk-b,2010-02-16,\n\
+                   BBBY,2007-03-01,2018-07-02\n";
+        let universe = parse_universe_csv("sp500", csv.as_bytes()).unwrap();
+        let date = NaiveDate::from_ymd_opt(2026, 3, 2).unwrap();
+
+        assert_eq!(universe.name(), "sp500");
+        assert_eq!(universe.members_on(date), ["AAPL", "BRK.B"]);
+        assert!(!universe.is_member(&Symbol::new("BBBY"), date));
+
+        let inverted = "symbol,added,removed\nBBBY,2018-07-02,2007-03-01\n";
+        assert!(matches!(
+            parse_universe_csv("sp500", inverted.as_bytes()),
+            Err(UniverseCsvError::InvalidPeriod { .. })
+        ));
+        let malformed = "symbol,added\nAAPL,yesterday\n";
+        assert!(parse_universe_csv("sp500", malformed.as_bytes()).is_err());
+    }
+}
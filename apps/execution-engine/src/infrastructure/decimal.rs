@@ -0,0 +1,54 @@
+//! Decimal Conversion at Wire Boundaries
+//!
+//! Prices are `Decimal` everywhere inside the engine. Protobuf messages and
+//! some third-party SDK types carry `f64`; adapters convert through these
+//! helpers at the point the value enters or leaves the engine, never in
+//! pricing logic.
+
+use std::str::FromStr;
+
+use rust_decimal::Decimal;
+use rust_decimal::prelude::ToPrimitive;
+
+/// Convert a wire `f64` to `Decimal`.
+///
+/// Uses the shortest representation that round-trips, so `185.52` becomes
+/// exactly `185.52` rather than the nearest binary fraction. Returns `None`
+/// for NaN, infinities and values outside `Decimal`'s range.
+#[must_use]
+pub fn decimal_from_f64(value: f64) -> Option<Decimal> {
+    if !value.is_finite() {
+        return None;
+    }
+    Decimal::from_str(&value.to_string()).ok()
+}
+
+/// Convert a `Decimal` to `f64` for wire formats that require it.
+#[must_use]
+pub fn decimal_to_f64(value: Decimal) -> f64 {
+    value.to_f64().unwrap_or_default()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rust_decimal_macros::dec;
+
+    #[test]
+    fn from_f64_is_exact_for_quoted_prices() {
+        assert_eq!(decimal_from_f64(185.52), Some(dec!(185.52)));
+        assert_eq!(decimal_from_f64(0.1), Some(dec!(0.1)));
+        assert_eq!(decimal_from_f64(0.1).unwrap().to_string(), "0.1");
+        assert_eq!(decimal_from_f64(f64::NAN), None);
+        assert_eq!(decimal_from_f64(f64::INFINITY), None);
+    }
+
+    #[test]
+    fn to_f64_round_trips() {
+        assert!((decimal_to_f64(dec!(185.52)) - 185.52).abs() < f64::EPSILON);
+        assert_eq!(
+            decimal_from_f64(decimal_to_f64(dec!(2.84))),
+            Some(dec!(2.84))
+        );
+    }
+}
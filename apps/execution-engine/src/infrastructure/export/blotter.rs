@@ -0,0 +1,336 @@
+//! Trade Blotter Files
+//!
+//! Writes a trade blotter as CSV or Parquet. Both carry the same columns;
+//! CSV writes decimals as exact strings and timestamps as RFC 3339, Parquet
+//! stores decimals as `DECIMAL(38, 9)` and timestamps as UTC microseconds.
+
+use std::sync::Arc;
+
+use arrow_array::{ArrayRef, Decimal128Array, RecordBatch, StringArray, TimestampMicrosecondArray};
+use arrow_schema::{ArrowError, DataType, Field, Schema, TimeUnit};
+use parquet::arrow::ArrowWriter;
+use parquet::basic::Compression;
+use parquet::errors::ParquetError;
+use parquet::file::properties::WriterProperties;
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+
+use crate::application::services::BlotterFill;
+use crate::domain::order_execution::value_objects::LiquidityType;
+use crate::domain::shared::{ClassifiedError, ErrorKind};
+
+/// Decimal places kept for quantities, prices and amounts in Parquet.
+const DECIMAL_SCALE: i8 = 9;
+
+/// Blotter columns, in file order.
+const COLUMNS: [&str; 18] = [
+    "timestamp",
+    "fill_id",
+    "order_id",
+    "broker_order_id",
+    "symbol",
+    "side",
+    "quantity",
+    "price",
+    "gross_amount",
+    "commission",
+    "venue",
+    "liquidity",
+    "purpose",
+    "cycle_id",
+    "decision_id",
+    "strategy_family",
+    "strategy_tag",
+    "realized_pnl",
+];
+
+/// File format of a blotter export.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum BlotterFormat {
+    /// Comma-separated values with a header row.
+    #[default]
+    Csv,
+    /// Apache Parquet, Snappy-compressed.
+    Parquet,
+}
+
+impl BlotterFormat {
+    /// MIME type of the encoded file.
+    #[must_use]
+    pub const fn content_type(self) -> &'static str {
+        match self {
+            Self::Csv => "text/csv",
+            Self::Parquet => "application/vnd.apache.parquet",
+        }
+    }
+
+    /// File name extension.
+    #[must_use]
+    pub const fn extension(self) -> &'static str {
+        match self {
+            Self::Csv => "csv",
+            Self::Parquet => "parquet",
+        }
+    }
+}
+
+/// Blotter encoding errors.
+#[derive(Debug, Error)]
+pub enum BlotterEncodeError {
+    /// CSV writing failed.
+    #[error("CSV encoding failed: {0}")]
+    Csv(#[from] csv::Error),
+
+    /// Building the Arrow batch failed.
+    #[error("Arrow encoding failed: {0}")]
+    Arrow(#[from] ArrowError),
+
+    /// Parquet writing failed.
+    #[error("Parquet encoding failed: {0}")]
+    Parquet(#[from] ParquetError),
+}
+
+impl ClassifiedError for BlotterEncodeError {
+    fn code(&self) -> &'static str {
+        "EXPORT_FAILED"
+    }
+
+    fn kind(&self) -> ErrorKind {
+        ErrorKind::Internal
+    }
+}
+
+/// Encode `fills` in `format`.
+///
+/// # Errors
+///
+/// Returns error if the file cannot be written.
+pub fn encode_blotter(
+    fills: &[BlotterFill],
+    format: BlotterFormat,
+) -> Result<Vec<u8>, BlotterEncodeError> {
+    match format {
+        BlotterFormat::Csv => encode_csv(fills),
+        BlotterFormat::Parquet => encode_parquet(fills),
+    }
+}
+
+fn encode_csv(fills: &[BlotterFill]) -> Result<Vec<u8>, BlotterEncodeError> {
+    let mut writer = csv::Writer::from_writer(Vec::new());
+    writer.write_record(COLUMNS)?;
+    for fill in fills {
+        writer.write_record([
+            fill.timestamp.to_rfc3339(),
+            fill.fill_id.clone(),
+            fill.order_id.clone(),
+            fill.broker_order_id.clone().unwrap_or_default(),
+            fill.symbol.clone(),
+            fill.side.to_string(),
+            fill.quantity.to_string(),
+            fill.price.to_string(),
+            fill.gross_amount.to_string(),
+            fill.commission.to_string(),
+            fill.venue.clone(),
+            liquidity(fill).unwrap_or_default(),
+            fill.purpose.to_string(),
+            fill.cycle_id.clone().unwrap_or_default(),
+            fill.decision_id.clone().unwrap_or_default(),
+            strategy_family(fill).unwrap_or_default(),
+            fill.strategy_tag.clone().unwrap_or_default(),
+            fill.realized_pnl.map(|p| p.to_string()).unwrap_or_default(),
+        ])?;
+    }
+    writer
+        .into_inner()
+        .map_err(|e| BlotterEncodeError::Csv(e.into_error().into()))
+}
+
+fn encode_parquet(fills: &[BlotterFill]) -> Result<Vec<u8>, BlotterEncodeError> {
+    let text = |name: &str| Field::new(name, DataType::Utf8, false);
+    let optional_text = |name: &str| Field::new(name, DataType::Utf8, true);
+    let decimal = |name: &str| Field::new(name, DataType::Decimal128(38, DECIMAL_SCALE), false);
+    let optional_decimal =
+        |name: &str| Field::new(name, DataType::Decimal128(38, DECIMAL_SCALE), true);
+    let schema = Arc::new(Schema::new(vec![
+        Field::new(
+            COLUMNS[0],
+            DataType::Timestamp(TimeUnit::Microsecond, Some("UTC".into())),
+            false,
+        ),
+        text(COLUMNS[1]),
+        text(COLUMNS[2]),
+        optional_text(COLUMNS[3]),
+        text(COLUMNS[4]),
+        text(COLUMNS[5]),
+        decimal(COLUMNS[6]),
+        decimal(COLUMNS[7]),
+        decimal(COLUMNS[8]),
+        decimal(COLUMNS[9]),
+        text(COLUMNS[10]),
+        optional_text(COLUMNS[11]),
+        text(COLUMNS[12]),
+        optional_text(COLUMNS[13]),
+        optional_text(COLUMNS[14]),
+        optional_text(COLUMNS[15]),
+        optional_text(COLUMNS[16]),
+        optional_decimal(COLUMNS[17]),
+    ]));
+
+    let strings = |f: fn(&BlotterFill) -> Option<String>| -> ArrayRef {
+        Arc::new(fills.iter().map(f).collect::<StringArray>())
+    };
+    let decimals = |f: fn(&BlotterFill) -> Option<Decimal>| -> Result<ArrayRef, ArrowError> {
+        let values = fills.iter().map(|fill| {
+            f(fill).map(|mut value| {
+                value.rescale(u32::from(DECIMAL_SCALE.unsigned_abs()));
+                value.mantissa()
+            })
+        });
+        Ok(Arc::new(
+            values
+                .collect::<Decimal128Array>()
+                .with_precision_and_scale(38, DECIMAL_SCALE)?,
+        ))
+    };
+    let timestamps = TimestampMicrosecondArray::from_iter_values(
+        fills
+            .iter()
+            .map(|fill| fill.timestamp.as_datetime().timestamp_micros()),
+    )
+    .with_timezone("UTC");
+
+    let batch = RecordBatch::try_new(
+        Arc::clone(&schema),
+        vec![
+            Arc::new(timestamps),
+            strings(|f| Some(f.fill_id.clone())),
+            strings(|f| Some(f.order_id.clone())),
+            strings(|f| f.broker_order_id.clone()),
+            strings(|f| Some(f.symbol.clone())),
+            strings(|f| Some(f.side.to_string())),
+            decimals(|f| Some(f.quantity))?,
+            decimals(|f| Some(f.price))?,
+            decimals(|f| Some(f.gross_amount))?,
+            decimals(|f| Some(f.commission))?,
+            strings(|f| Some(f.venue.clone())),
+            strings(liquidity),
+            strings(|f| Some(f.purpose.to_string())),
+            strings(|f| f.cycle_id.clone()),
+            strings(|f| f.decision_id.clone()),
+            strings(strategy_family),
+            strings(|f| f.strategy_tag.clone()),
+            decimals(|f| f.realized_pnl)?,
+        ],
+    )?;
+
+    let properties = WriterProperties::builder()
+        .set_compression(Compression::SNAPPY)
+        .build();
+    let mut buffer = Vec::new();
+    let mut writer = ArrowWriter::try_new(&mut buffer, schema, Some(properties))?;
+    writer.write(&batch)?;
+    writer.close()?;
+    Ok(buffer)
+}
+
+fn liquidity(fill: &BlotterFill) -> Option<String> {
+    let name = match fill.liquidity? {
+        LiquidityType::Maker => "MAKER",
+        LiquidityType::Taker => "TAKER",
+        LiquidityType::Unknown => "UNKNOWN",
+    };
+    Some(name.to_string())
+}
+
+fn strategy_family(fill: &BlotterFill) -> Option<String> {
+    fill.strategy_family.map(|f| f.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::execution_tactics::StrategyFamily;
+    use crate::domain::order_execution::value_objects::{OrderPurpose, OrderSide};
+    use crate::domain::shared::Timestamp;
+    use arrow_array::Array;
+    use parquet::arrow::arrow_reader::ParquetRecordBatchReaderBuilder;
+    use rust_decimal_macros::dec;
+    use std::io::Write;
+
+    fn fill() -> BlotterFill {
+        BlotterFill {
+            timestamp: Timestamp::parse("2026-03-02T15:00:00Z").unwrap(),
+            fill_id: "f-1".to_string(),
+            order_id: "o-1".to_string(),
+            broker_order_id: Some("b-1".to_string()),
+            symbol: "AAPL".to_string(),
+            side: OrderSide::Buy,
+            quantity: dec!(10),
+            price: dec!(150.25),
+            gross_amount: dec!(1502.5),
+            commission: dec!(0.10),
+            venue: "TEST".to_string(),
+            liquidity: None,
+            purpose: OrderPurpose::Entry,
+            cycle_id: Some("cycle-1".to_string()),
+            decision_id: None,
+            strategy_family: Some(StrategyFamily::EquityLong),
+            strategy_tag: Some("momentum".to_string()),
+            realized_pnl: None,
+        }
+    }
+
+    #[test]
+    fn csv_blotter_has_a_header_and_a_row_per_fill() {
+        let csv =
+            String::from_utf8(encode_blotter(&[fill()], BlotterFormat::Csv).unwrap()).unwrap();
+        let mut lines = csv.lines();
+
+        assert_eq!(lines.next().unwrap(), COLUMNS.join(","));
+        assert_eq!(
+            lines.next().unwrap(),
+            "2026-03-02T15:00:00+00:00,f-1,o-1,b-1,AAPL,BUY,10,150.25,1502.5,0.10,TEST,,ENTRY,cycle-1,,EQUITY_LONG,momentum,"
+        );
+        assert!(lines.next().is_none());
+    }
+
+    #[test]
+    fn parquet_blotter_round_trips() {
+        let closing = BlotterFill {
+            side: OrderSide::Sell,
+            realized_pnl: Some(dec!(25)),
+            ..fill()
+        };
+        let bytes = encode_blotter(&[fill(), closing], BlotterFormat::Parquet).unwrap();
+
+        let mut file = tempfile::tempfile().unwrap();
+        file.write_all(&bytes).unwrap();
+        let mut reader = ParquetRecordBatchReaderBuilder::try_new(file)
+            .unwrap()
+            .build()
+            .unwrap();
+        let batch = reader.next().unwrap().unwrap();
+        assert_eq!(batch.num_rows(), 2);
+        assert_eq!(batch.num_columns(), COLUMNS.len());
+
+        let price = batch
+            .column_by_name("price")
+            .unwrap()
+            .as_any()
+            .downcast_ref::<Decimal128Array>()
+            .unwrap();
+        assert_eq!(price.value_as_string(0), "150.250000000");
+        let decision = batch.column_by_name("decision_id").unwrap();
+        assert!(decision.is_null(0));
+        let realized = batch
+            .column_by_name("realized_pnl")
+            .unwrap()
+            .as_any()
+            .downcast_ref::<Decimal128Array>()
+            .unwrap();
+        assert!(realized.is_null(0));
+        assert_eq!(realized.value_as_string(1), "25.000000000");
+    }
+}
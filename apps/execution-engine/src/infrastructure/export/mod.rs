@@ -0,0 +1,7 @@
+//! Export Adapters
+//!
+//! File encodings of reports handed to systems outside the engine.
+
+pub mod blotter;
+
+pub use blotter::{BlotterEncodeError, BlotterFormat, encode_blotter};
@@ -0,0 +1,135 @@
+//! gRPC Authentication Layer
+//!
+//! Tower layer that authorizes each gRPC call by method before it reaches
+//! the service. Health checks are exempt; methods not listed here require
+//! the admin role so new RPCs are closed by default.
+
+use std::sync::Arc;
+use std::task::{Context, Poll};
+
+use futures::future::{Either, Ready, ready};
+use tonic::Status;
+use tonic::codegen::http;
+use tower::{Layer, Service};
+
+use crate::infrastructure::api_error::ApiError;
+use crate::infrastructure::auth::{ApiKeyAuthenticator, Role, extract_api_key};
+
+/// Role required to call a gRPC method, or `None` if it is unauthenticated.
+#[must_use]
+pub fn required_role(path: &str) -> Option<Role> {
+    if path.starts_with("/grpc.health.v1.Health/") {
+        return None;
+    }
+
+    Some(match path {
+        "/cream.v1.ExecutionService/SubmitOrder" | "/cream.v1.ExecutionService/CancelOrder" => {
+            Role::Trader
+        }
+        "/cream.v1.ExecutionService/CheckConstraints"
+        | "/cream.v1.ExecutionService/GetOrderState"
+        | "/cream.v1.ExecutionService/StreamExecutions"
+        | "/cream.v1.ExecutionService/GetAccountState"
+        | "/cream.v1.ExecutionService/GetPositions"
+        | "/cream.v1.MarketDataService/SubscribeMarketData"
+        | "/cream.v1.MarketDataService/GetSnapshot"
+        | "/cream.v1.MarketDataService/GetOptionChain" => Role::ReadOnly,
+        _ => Role::Admin,
+    })
+}
+
+/// Layer that authenticates gRPC calls with API keys.
+#[derive(Clone)]
+pub struct GrpcAuthLayer {
+    authenticator: Option<Arc<ApiKeyAuthenticator>>,
+}
+
+impl GrpcAuthLayer {
+    /// Create the layer. Calls pass through unchecked when no authenticator
+    /// is configured.
+    #[must_use]
+    pub const fn new(authenticator: Option<Arc<ApiKeyAuthenticator>>) -> Self {
+        Self { authenticator }
+    }
+}
+
+impl<S> Layer<S> for GrpcAuthLayer {
+    type Service = GrpcAuthService<S>;
+
+    fn layer(&self, inner: S) -> Self::Service {
+        GrpcAuthService {
+            inner,
+            authenticator: self.authenticator.clone(),
+        }
+    }
+}
+
+/// Service produced by [`GrpcAuthLayer`].
+#[derive(Clone)]
+pub struct GrpcAuthService<S> {
+    inner: S,
+    authenticator: Option<Arc<ApiKeyAuthenticator>>,
+}
+
+impl<S, ReqBody, ResBody> Service<http::Request<ReqBody>> for GrpcAuthService<S>
+where
+    S: Service<http::Request<ReqBody>, Response = http::Response<ResBody>>,
+    ResBody: Default,
+{
+    type Response = S::Response;
+    type Error = S::Error;
+    type Future = Either<S::Future, Ready<Result<Self::Response, Self::Error>>>;
+
+    fn poll_ready(&mut self, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        self.inner.poll_ready(cx)
+    }
+
+    fn call(&mut self, mut request: http::Request<ReqBody>) -> Self::Future {
+        if let Some(authenticator) = &self.authenticator
+            && let Some(required) = required_role(request.uri().path())
+        {
+            match authenticator.authorize(extract_api_key(request.headers()), required) {
+                Ok(principal) => {
+                    request.extensions_mut().insert(principal);
+                }
+                Err(e) => {
+                    tracing::warn!(
+                        method = %request.uri().path(),
+                        error = %e,
+                        "gRPC call rejected"
+                    );
+                    let status = Status::from(ApiError::from_error(&e));
+                    return Either::Right(ready(Ok(status.into_http())));
+                }
+            }
+        }
+
+        Either::Left(self.inner.call(request))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn trading_methods_require_trader() {
+        assert_eq!(
+            required_role("/cream.v1.ExecutionService/SubmitOrder"),
+            Some(Role::Trader)
+        );
+        assert_eq!(
+            required_role("/cream.v1.ExecutionService/GetPositions"),
+            Some(Role::ReadOnly)
+        );
+    }
+
+    #[test]
+    fn health_is_exempt_and_unknown_methods_require_admin() {
+        assert_eq!(required_role("/grpc.health.v1.Health/Check"), None);
+        assert_eq!(
+            required_role("/cream.v1.ExecutionService/Unknown"),
+            Some(Role::Admin)
+        );
+    }
+}
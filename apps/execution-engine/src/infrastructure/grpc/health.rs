@@ -0,0 +1,103 @@
+//! gRPC Health Service
+//!
+//! Serves the standard `grpc.health.v1.Health` protocol so Kubernetes gRPC
+//! probes and health clients can check the engine without the HTTP endpoint.
+//!
+//! Per-service status follows the dependency each service fronts:
+//!
+//! - `cream.v1.ExecutionService` is serving while the broker responds
+//! - `cream.v1.MarketDataService` is serving while market data responds
+//! - the overall (`""`) status mirrors `ExecutionService`
+
+use std::sync::Arc;
+use std::time::Duration;
+
+use tokio_util::sync::CancellationToken;
+use tonic_health::ServingStatus;
+use tonic_health::server::HealthReporter;
+
+use super::proto::cream::v1::{execution_service_server, market_data_service_server};
+use crate::application::ports::{BrokerPort, MarketDataPort};
+
+/// How often dependencies are probed.
+const PROBE_INTERVAL: Duration = Duration::from_secs(15);
+
+/// Symbol used to probe market data availability.
+const PROBE_SYMBOL: &str = "SPY";
+
+/// Keeps `grpc.health.v1` serving status in sync with broker and market
+/// data availability.
+pub struct GrpcHealthUpdater<B, M> {
+    reporter: HealthReporter,
+    broker: Arc<B>,
+    market_data: Arc<M>,
+}
+
+impl<B, M> GrpcHealthUpdater<B, M>
+where
+    B: BrokerPort,
+    M: MarketDataPort,
+{
+    /// Create a new updater.
+    #[must_use]
+    pub const fn new(reporter: HealthReporter, broker: Arc<B>, market_data: Arc<M>) -> Self {
+        Self {
+            reporter,
+            broker,
+            market_data,
+        }
+    }
+
+    /// Probe dependencies until cancelled, then mark all services as not
+    /// serving so clients drain before the server stops.
+    pub async fn run(self, shutdown: CancellationToken) {
+        let mut interval = tokio::time::interval(PROBE_INTERVAL);
+        interval.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Skip);
+
+        loop {
+            tokio::select! {
+                _ = interval.tick() => self.update().await,
+                () = shutdown.cancelled() => break,
+            }
+        }
+
+        self.set_all(ServingStatus::NotServing).await;
+    }
+
+    async fn update(&self) {
+        let execution_status = match self.broker.get_buying_power().await {
+            Ok(_) => ServingStatus::Serving,
+            Err(e) => {
+                tracing::warn!(error = %e, "Broker health probe failed");
+                ServingStatus::NotServing
+            }
+        };
+
+        let symbols = [PROBE_SYMBOL.to_string()];
+        let market_data_status = match self.market_data.get_quotes(&symbols).await {
+            Ok(_) => ServingStatus::Serving,
+            Err(e) => {
+                tracing::warn!(error = %e, "Market data health probe failed");
+                ServingStatus::NotServing
+            }
+        };
+
+        self.reporter
+            .set_service_status(execution_service_server::SERVICE_NAME, execution_status)
+            .await;
+        self.reporter
+            .set_service_status(market_data_service_server::SERVICE_NAME, market_data_status)
+            .await;
+        self.reporter.set_service_status("", execution_status).await;
+    }
+
+    async fn set_all(&self, status: ServingStatus) {
+        self.reporter
+            .set_service_status(execution_service_server::SERVICE_NAME, status)
+            .await;
+        self.reporter
+            .set_service_status(market_data_service_server::SERVICE_NAME, status)
+            .await;
+        self.reporter.set_service_status("", status).await;
+    }
+}
@@ -7,14 +7,35 @@ use tokio::sync::mpsc;
 use tokio_stream::{Stream, wrappers::ReceiverStream};
 use tonic::{Request, Response, Status};
 
+use super::invalid_argument;
 use super::proto::cream::v1::{
-    GetOptionChainRequest, GetOptionChainResponse, GetSnapshotRequest, GetSnapshotResponse,
-    MarketSnapshot, MarketStatus, OptionChain, Quote, SubscribeMarketDataRequest,
-    SubscribeMarketDataResponse, SymbolSnapshot,
+    Action, Bar, BuildSpreadRequest, BuildSpreadResponse, Decision, Direction, GetIvSurfaceRequest,
+    GetIvSurfaceResponse, GetOptionChainRequest, GetOptionChainResponse, GetSnapshotRequest,
+    GetSnapshotResponse, Instrument, InstrumentType, IvSurfacePoint, IvSurfaceSlice,
+    MarketSnapshot, MarketStatus, OptionChain, OptionLeg, OrderPlan, OrderType, PositionIntent,
+    Quote, RiskDenomination, RiskLevels, Size, SizeUnit, SnapshotError, StrategyFamily,
+    SubscribeMarketDataRequest, SubscribeMarketDataResponse, SymbolSnapshot, TimeInForce,
     market_data_service_server::{MarketDataService, MarketDataServiceServer},
 };
 
-use crate::application::ports::{MarketDataPort, MarketQuote, OptionType};
+use crate::application::ports::{
+    BarTimeframe, Candle, Clock, DailyBar, MarketDataPort, MarketQuote, OptionChainFilter,
+    OptionQuote, OptionType, PriceFeedPort, SystemClock,
+};
+use crate::application::services::{
+    IvSurfaceError, IvSurfaceService, MarketSnapshotService, OptionChainStats, SnapshotComponent,
+    SpreadBuildError, SpreadBuilderService, SymbolMarketSnapshot,
+};
+use crate::domain::execution_tactics::{NetLadderConfig, NetPriceLadder, TacticType};
+use crate::domain::option_position::{
+    Leg, OptionRight, PositionSide, SpreadIntent, SpreadQuote, SpreadStrategy, SpreadType,
+};
+use crate::domain::shared::{Symbol, Timestamp};
+use crate::infrastructure::api_error::ApiError;
+use crate::infrastructure::decimal::{decimal_from_f64, decimal_to_f64};
+
+/// Largest page of options `GetOptionChain` returns.
+const MAX_OPTION_PAGE_SIZE: usize = 1000;
 
 /// gRPC `MarketDataService` adapter.
 pub struct MarketDataServiceAdapter<M>
@@ -22,6 +43,12 @@ where
     M: MarketDataPort,
 {
     market_data: Arc<M>,
+    iv_surface: IvSurfaceService<M>,
+    spread_builder: SpreadBuilderService<M>,
+    snapshots: MarketSnapshotService<M>,
+    price_feed: Option<Arc<dyn PriceFeedPort>>,
+    net_ladder: NetLadderConfig,
+    clock: Arc<dyn Clock>,
 }
 
 impl<M> MarketDataServiceAdapter<M>
@@ -29,19 +56,77 @@ where
     M: MarketDataPort,
 {
     /// Create a new `MarketDataService` adapter.
-    pub const fn new(market_data: Arc<M>) -> Self {
-        Self { market_data }
+    pub fn new(market_data: Arc<M>) -> Self {
+        Self {
+            iv_surface: IvSurfaceService::new(Arc::clone(&market_data)),
+            spread_builder: SpreadBuilderService::new(Arc::clone(&market_data)),
+            snapshots: MarketSnapshotService::new(Arc::clone(&market_data)),
+            market_data,
+            price_feed: None,
+            net_ladder: NetLadderConfig::default(),
+            clock: SystemClock::shared(),
+        }
+    }
+
+    /// Serve intraday bars from `price_feed`.
+    #[must_use]
+    pub fn with_price_feed(mut self, price_feed: Arc<dyn PriceFeedPort>) -> Self {
+        self.price_feed = Some(price_feed);
+        self
+    }
+
+    /// Plan built spreads with the net-price ladder in `config`.
+    #[must_use]
+    pub const fn with_net_ladder(mut self, config: NetLadderConfig) -> Self {
+        self.net_ladder = config;
+        self
+    }
+
+    /// Use `clock` instead of the system clock.
+    #[must_use]
+    pub fn with_clock(mut self, clock: Arc<dyn Clock>) -> Self {
+        self.clock = clock;
+        self
+    }
+
+    /// Latest completed bar of `symbol` in each of `timeframes`.
+    async fn latest_bars(
+        &self,
+        symbol: &str,
+        timeframes: &[BarTimeframe],
+    ) -> Result<Vec<Candle>, SnapshotError> {
+        let Some(price_feed) = &self.price_feed else {
+            return Ok(vec![]);
+        };
+        let mut bars = Vec::with_capacity(timeframes.len());
+        for &timeframe in timeframes {
+            let latest = price_feed
+                .get_bars(&Symbol::new(symbol), timeframe, 1)
+                .await
+                .map_err(|e| SnapshotError {
+                    component: SnapshotComponent::Bars.to_string(),
+                    code: "BARS_UNAVAILABLE".to_string(),
+                    message: e.to_string(),
+                })?;
+            bars.extend(latest);
+        }
+        Ok(bars)
     }
 }
 
-/// Create a `MarketDataService` gRPC server.
+/// Create a `MarketDataService` gRPC server serving intraday bars from
+/// `price_feed` and planning built spreads with the `net_ladder` ladder.
 pub fn create_market_data_service<M>(
     market_data: Arc<M>,
+    price_feed: Arc<dyn PriceFeedPort>,
+    net_ladder: NetLadderConfig,
 ) -> MarketDataServiceServer<MarketDataServiceAdapter<M>>
 where
     M: MarketDataPort + 'static,
 {
-    let service = MarketDataServiceAdapter::new(market_data);
+    let service = MarketDataServiceAdapter::new(market_data)
+        .with_price_feed(price_feed)
+        .with_net_ladder(net_ladder);
     MarketDataServiceServer::new(service)
 }
 
@@ -58,10 +143,20 @@ where
         request: Request<SubscribeMarketDataRequest>,
     ) -> Result<Response<Self::SubscribeMarketDataStream>, Status> {
         let req = request.into_inner();
+        let timeframes = bar_timeframes(&req.bar_timeframes)?;
         let symbols = req.symbols;
 
         tracing::info!(symbols = ?symbols, "Market data subscription started");
 
+        // Latest live bars go out after the quotes
+        let mut bars = Vec::new();
+        for symbol in &symbols {
+            match self.latest_bars(symbol, &timeframes).await {
+                Ok(latest) => bars.extend(latest),
+                Err(e) => tracing::warn!(symbol = %symbol, error = %e.message, "No live bars"),
+            }
+        }
+
         let (tx, rx) = mpsc::channel(128);
         let market_data = Arc::clone(&self.market_data);
 
@@ -77,14 +172,28 @@ where
                             ),
                         };
                         if tx.send(Ok(response)).await.is_err() {
-                            break;
+                            return;
+                        }
+                    }
+                    for bar in &bars {
+                        let response = SubscribeMarketDataResponse {
+                            update: Some(
+                                super::proto::cream::v1::subscribe_market_data_response::Update::Bar(
+                                    convert_candle(bar),
+                                ),
+                            ),
+                        };
+                        if tx.send(Ok(response)).await.is_err() {
+                            return;
                         }
                     }
                 }
                 Err(e) => {
                     tracing::warn!(error = %e, "Failed to fetch quotes for stream");
                     let _ = tx
-                        .send(Err(Status::internal(format!("Market data error: {e}"))))
+                        .send(Err(ApiError::from_error(&e)
+                            .with_context("Market data error")
+                            .into()))
                         .await;
                 }
             }
@@ -99,30 +208,34 @@ where
         request: Request<GetSnapshotRequest>,
     ) -> Result<Response<GetSnapshotResponse>, Status> {
         let req = request.into_inner();
+        // Without named timeframes, all live bar timeframes are included
+        let timeframes = match bar_timeframes(&req.bar_timeframes)? {
+            _ if !req.include_bars => vec![],
+            named if named.is_empty() => BarTimeframe::ALL.to_vec(),
+            named => named,
+        };
         let symbols = req.symbols;
 
-        tracing::debug!(symbols = ?symbols, "Getting market snapshot");
+        tracing::debug!(
+            symbols = ?symbols,
+            include_options = req.include_options,
+            "Getting market snapshot"
+        );
 
-        let quotes = self
-            .market_data
-            .get_quotes(&symbols)
+        let snapshots = self
+            .snapshots
+            .snapshot(&symbols, req.include_options)
             .await
-            .map_err(|e| Status::internal(format!("Failed to get quotes: {e}")))?;
+            .map_err(|e| ApiError::from_error(&e).with_context("Failed to get market snapshot"))?;
 
-        let symbol_snapshots: Vec<SymbolSnapshot> = quotes
-            .iter()
-            .map(|q| SymbolSnapshot {
-                symbol: q.symbol.clone(),
-                quote: Some(convert_quote(q)),
-                bars: vec![],
-                market_status: MarketStatus::Open.into(),
-                day_high: 0.0,
-                day_low: 0.0,
-                prev_close: 0.0,
-                open: 0.0,
-                as_of: Some(prost_types::Timestamp::from(std::time::SystemTime::now())),
-            })
-            .collect();
+        let mut symbol_snapshots: Vec<SymbolSnapshot> =
+            snapshots.into_iter().map(convert_symbol_snapshot).collect();
+        for snapshot in &mut symbol_snapshots {
+            match self.latest_bars(&snapshot.symbol, &timeframes).await {
+                Ok(bars) => snapshot.bars = bars.iter().map(convert_candle).collect(),
+                Err(e) => snapshot.errors.push(e),
+            }
+        }
 
         let snapshot = MarketSnapshot {
             environment: super::proto::cream::v1::Environment::Paper.into(),
@@ -142,30 +255,33 @@ where
         request: Request<GetOptionChainRequest>,
     ) -> Result<Response<GetOptionChainResponse>, Status> {
         let req = request.into_inner();
+        let filter = option_chain_filter(&req)?;
         let underlying = req.underlying;
 
         tracing::debug!(underlying = %underlying, "Getting option chain");
 
-        let chain_data = self
+        let mut chain_data = self
             .market_data
-            .get_option_chain(&underlying)
+            .get_filtered_option_chain(&underlying, &filter)
             .await
-            .map_err(|e| Status::internal(format!("Failed to get option chain: {e}")))?;
+            .map_err(|e| ApiError::from_error(&e).with_context("Failed to get option chain"))?;
 
-        let underlying_price: f64 = chain_data
-            .underlying_price
-            .to_string()
-            .parse()
-            .unwrap_or(0.0);
+        let underlying_price = decimal_to_f64(chain_data.underlying_price);
 
-        let options: Vec<super::proto::cream::v1::OptionQuote> = chain_data
+        // Stable order so page tokens address the same options across calls
+        chain_data
             .options
+            .sort_by(|a, b| option_order(a).cmp(&option_order(b)));
+        let total_count = i32::try_from(chain_data.options.len()).unwrap_or(i32::MAX);
+        let (page, next_page_token) = paginate(chain_data.options, req.page_size, &req.page_token)?;
+
+        let options: Vec<super::proto::cream::v1::OptionQuote> = page
             .iter()
             .map(|opt| {
                 let contract = super::proto::cream::v1::OptionContract {
                     underlying: opt.contract.underlying.clone(),
                     expiration: opt.contract.expiration.clone(),
-                    strike: opt.contract.strike.to_string().parse().unwrap_or(0.0),
+                    strike: decimal_to_f64(opt.contract.strike),
                     option_type: match opt.contract.option_type {
                         OptionType::Call => super::proto::cream::v1::OptionType::Call.into(),
                         OptionType::Put => super::proto::cream::v1::OptionType::Put.into(),
@@ -195,19 +311,536 @@ where
             as_of: Some(prost_types::Timestamp::from(std::time::SystemTime::now())),
         };
 
-        Ok(Response::new(GetOptionChainResponse { chain: Some(chain) }))
+        Ok(Response::new(GetOptionChainResponse {
+            chain: Some(chain),
+            next_page_token,
+            total_count,
+        }))
+    }
+
+    async fn get_iv_surface(
+        &self,
+        request: Request<GetIvSurfaceRequest>,
+    ) -> Result<Response<GetIvSurfaceResponse>, Status> {
+        let req = request.into_inner();
+        let filter = option_chain_filter(&GetOptionChainRequest {
+            underlying: req.underlying.clone(),
+            min_expiration: req.min_expiration,
+            max_expiration: req.max_expiration,
+            ..GetOptionChainRequest::default()
+        })?;
+        if req.risk_free_rate.is_some_and(|rate| !rate.is_finite()) {
+            return Err(invalid_argument("risk_free_rate must be finite"));
+        }
+
+        tracing::debug!(underlying = %req.underlying, "Building IV surface");
+
+        let surface = self
+            .iv_surface
+            .surface(&req.underlying, &filter, req.risk_free_rate)
+            .await
+            .map_err(|e| match &e {
+                IvSurfaceError::MarketData(inner) => {
+                    ApiError::from_error(inner).with_context("Failed to get option chain")
+                }
+                IvSurfaceError::Surface(_) => ApiError::from_error(&e),
+            })?;
+
+        let slices = surface
+            .slices()
+            .iter()
+            .map(|slice| IvSurfaceSlice {
+                expiration: slice.expiration.format("%Y-%m-%d").to_string(),
+                years_to_expiry: slice.years,
+                forward: slice.forward,
+                atm_iv: slice.atm_volatility(),
+                points: slice
+                    .points
+                    .iter()
+                    .map(|point| IvSurfacePoint {
+                        strike: point.strike,
+                        option_type: match point.right {
+                            OptionRight::Call => super::proto::cream::v1::OptionType::Call.into(),
+                            OptionRight::Put => super::proto::cream::v1::OptionType::Put.into(),
+                        },
+                        market_iv: point.market_iv,
+                        fitted_iv: point.fitted_iv,
+                    })
+                    .collect(),
+            })
+            .collect();
+
+        Ok(Response::new(GetIvSurfaceResponse {
+            underlying: req.underlying,
+            underlying_price: surface.spot(),
+            risk_free_rate: surface.rate(),
+            slices,
+            as_of: Some(prost_types::Timestamp::from(std::time::SystemTime::now())),
+        }))
     }
+
+    async fn build_spread(
+        &self,
+        request: Request<BuildSpreadRequest>,
+    ) -> Result<Response<BuildSpreadResponse>, Status> {
+        let req = request.into_inner();
+        let intent = spread_intent(&req)?;
+
+        tracing::debug!(
+            underlying = %intent.underlying,
+            strategy = ?intent.strategy,
+            "Building spread"
+        );
+
+        let quote = self
+            .spread_builder
+            .build(&intent)
+            .await
+            .map_err(|e| match &e {
+                SpreadBuildError::MarketData(inner) => {
+                    ApiError::from_error(inner).with_context("Failed to get option chain")
+                }
+                SpreadBuildError::Spread(_) => ApiError::from_error(&e),
+            })?;
+
+        Ok(Response::new(BuildSpreadResponse {
+            decision: Some(spread_decision(
+                &quote,
+                intent.side,
+                req.quantity,
+                &self.net_ladder,
+                self.clock.now(),
+            )),
+            mid_price: decimal_to_f64(quote.mid_price),
+            natural_price: decimal_to_f64(quote.natural_price),
+        }))
+    }
+}
+
+/// Build the spread intent from a `BuildSpread` request.
+fn spread_intent(req: &BuildSpreadRequest) -> Result<SpreadIntent, Status> {
+    use super::proto::cream::v1::SpreadStrategy as Strategy;
+
+    let strategy = match Strategy::try_from(req.strategy) {
+        Ok(Strategy::CallVertical) => SpreadStrategy::Vertical(OptionRight::Call),
+        Ok(Strategy::PutVertical) => SpreadStrategy::Vertical(OptionRight::Put),
+        Ok(Strategy::IronCondor) => SpreadStrategy::IronCondor,
+        Ok(Strategy::IronButterfly) => SpreadStrategy::IronButterfly,
+        Ok(Strategy::Straddle) => SpreadStrategy::Straddle,
+        Ok(Strategy::Strangle) => SpreadStrategy::Strangle,
+        Ok(Strategy::Unspecified) | Err(_) => {
+            return Err(invalid_argument("strategy is required"));
+        }
+    };
+    let side = match Direction::try_from(req.direction) {
+        Ok(Direction::Long) => PositionSide::Long,
+        Ok(Direction::Short) => PositionSide::Short,
+        _ => return Err(invalid_argument("direction must be LONG or SHORT")),
+    };
+    if req.underlying.is_empty() {
+        return Err(invalid_argument("underlying is required"));
+    }
+    if req.quantity <= 0 {
+        return Err(invalid_argument("quantity must be positive"));
+    }
+    let wing_width = req
+        .wing_width
+        .map(|width| {
+            decimal_from_f64(width)
+                .filter(|width| width.is_sign_positive() && !width.is_zero())
+                .ok_or_else(|| invalid_argument("wing_width must be positive"))
+        })
+        .transpose()?;
+
+    Ok(SpreadIntent {
+        underlying: req.underlying.to_uppercase(),
+        strategy,
+        side,
+        target_delta: req.target_delta,
+        min_dte: req.min_dte,
+        max_dte: req.max_dte,
+        wing_width,
+        quantity: rust_decimal::Decimal::from(req.quantity),
+    })
+}
+
+/// A decision for a built spread, priced at mid with a net-price ladder plan
+/// starting at `now`: the caller working the order walks the limit toward
+/// natural per `ladder`.
+fn spread_decision(
+    quote: &SpreadQuote,
+    side: PositionSide,
+    quantity: i32,
+    ladder: &NetLadderConfig,
+    now: Timestamp,
+) -> Decision {
+    let spread = &quote.spread;
+    let legs: Vec<OptionLeg> = spread.legs().iter().map(option_leg).collect();
+    let instrument = legs.first().map(|leg| Instrument {
+        instrument_id: leg.symbol.clone(),
+        instrument_type: InstrumentType::Option.into(),
+        option_contract: leg.contract.clone(),
+    });
+    let (action, direction, target_position_quantity) = match side {
+        PositionSide::Long => (Action::Buy, Direction::Long, quantity),
+        PositionSide::Short => (Action::Sell, Direction::Short, -quantity),
+    };
+    let strategy_family = match spread.spread_type() {
+        SpreadType::Vertical => StrategyFamily::VerticalSpread,
+        SpreadType::IronCondor | SpreadType::IronButterfly => StrategyFamily::IronCondor,
+        SpreadType::Straddle => StrategyFamily::Straddle,
+        SpreadType::Strangle => StrategyFamily::Strangle,
+        _ => StrategyFamily::Unspecified,
+    };
+    let mid_price = decimal_to_f64(quote.mid_price);
+
+    Decision {
+        instrument,
+        action: action.into(),
+        size: Some(Size {
+            quantity,
+            unit: SizeUnit::Contracts.into(),
+            target_position_quantity,
+        }),
+        order_plan: Some(OrderPlan {
+            entry_order_type: OrderType::Limit.into(),
+            entry_limit_price: Some(mid_price.abs()),
+            exit_order_type: OrderType::Limit.into(),
+            time_in_force: TimeInForce::Day.into(),
+            execution_tactic: Some(TacticType::NetLadder.to_string()),
+            execution_params: Some(net_ladder_params(quote, ladder, now)),
+        }),
+        risk_levels: spread_risk_levels(quote),
+        strategy_family: strategy_family.into(),
+        rationale: format!(
+            "{} on {} at {:.2} net",
+            spread.spread_type(),
+            spread.underlying(),
+            mid_price
+        ),
+        direction: direction.into(),
+        legs,
+        net_limit_price: Some(mid_price),
+        ..Decision::default()
+    }
+}
+
+/// Ladder parameters for a spread order plan: the natural price, the limit at
+/// the maximum concession, and the step schedule starting at `started_at`.
+fn net_ladder_params(
+    quote: &SpreadQuote,
+    config: &NetLadderConfig,
+    started_at: Timestamp,
+) -> prost_types::Struct {
+    let ladder = NetPriceLadder::new(
+        quote.mid_price,
+        quote.natural_price,
+        config.clone(),
+        started_at.as_datetime(),
+    );
+    let fields = [
+        ("started_at", started_at.to_rfc3339().into()),
+        ("natural_price", decimal_to_f64(quote.natural_price).into()),
+        (
+            "max_limit_price",
+            decimal_to_f64(ladder.max_limit_price()).into(),
+        ),
+        ("step_pct", decimal_to_f64(config.step_pct).into()),
+        ("interval_seconds", config.interval_seconds.into()),
+        (
+            "max_concession_pct",
+            decimal_to_f64(config.max_concession_pct).into(),
+        ),
+    ];
+    prost_types::Struct {
+        fields: fields
+            .into_iter()
+            .map(|(name, value)| (name.to_string(), value))
+            .collect(),
+    }
+}
+
+/// Spread value bounds for defined-risk spreads: the spread is worth between
+/// zero and its widest wing, so a debit spread is stopped at zero and a
+/// credit spread at the full width. Undefined-risk spreads are left to the
+/// caller.
+fn spread_risk_levels(quote: &SpreadQuote) -> Option<RiskLevels> {
+    if !quote.spread.is_defined_risk() {
+        return None;
+    }
+    let width = [OptionRight::Put, OptionRight::Call]
+        .into_iter()
+        .filter_map(|right| {
+            let strikes: Vec<_> = quote
+                .spread
+                .legs()
+                .iter()
+                .filter(|leg| leg.contract().right() == right)
+                .map(|leg| leg.contract().strike())
+                .collect();
+            Some(*strikes.iter().max()? - *strikes.iter().min()?)
+        })
+        .max()
+        .map(decimal_to_f64)?;
+    let (stop_loss_level, take_profit_level) = if quote.mid_price.is_sign_negative() {
+        (width, 0.0)
+    } else {
+        (0.0, width)
+    };
+    Some(RiskLevels {
+        stop_loss_level,
+        take_profit_level,
+        denomination: RiskDenomination::OptionPrice.into(),
+    })
+}
+
+/// Convert a spread leg to a proto `OptionLeg` with a unit ratio.
+fn option_leg(leg: &Leg) -> OptionLeg {
+    let contract = leg.contract();
+    let (ratio_qty, position_intent) = match leg.side() {
+        PositionSide::Long => (1, PositionIntent::BuyToOpen),
+        PositionSide::Short => (-1, PositionIntent::SellToOpen),
+    };
+    OptionLeg {
+        symbol: contract.symbol().as_str().to_string(),
+        ratio_qty,
+        position_intent: position_intent.into(),
+        contract: Some(super::proto::cream::v1::OptionContract {
+            underlying: contract.underlying().to_string(),
+            expiration: contract.expiration().format("%Y-%m-%d").to_string(),
+            strike: decimal_to_f64(contract.strike()),
+            option_type: match contract.right() {
+                OptionRight::Call => super::proto::cream::v1::OptionType::Call.into(),
+                OptionRight::Put => super::proto::cream::v1::OptionType::Put.into(),
+            },
+        }),
+    }
+}
+
+/// Build the chain filter from a `GetOptionChain` request.
+fn option_chain_filter(req: &GetOptionChainRequest) -> Result<OptionChainFilter, Status> {
+    let date = |field: &str, value: &str| {
+        chrono::NaiveDate::parse_from_str(value, "%Y-%m-%d")
+            .map(|_| value.to_string())
+            .map_err(|_| invalid_argument(format!("{field} must be YYYY-MM-DD")))
+    };
+    let non_negative = |field: &str, value: f64| {
+        decimal_from_f64(value)
+            .filter(|v| !v.is_sign_negative())
+            .ok_or_else(|| invalid_argument(format!("{field} must be non-negative")))
+    };
+
+    let option_type = match super::proto::cream::v1::OptionType::try_from(req.option_type) {
+        Ok(super::proto::cream::v1::OptionType::Unspecified) => None,
+        Ok(super::proto::cream::v1::OptionType::Call) => Some(OptionType::Call),
+        Ok(super::proto::cream::v1::OptionType::Put) => Some(OptionType::Put),
+        Err(_) => return Err(invalid_argument("unknown option_type")),
+    };
+
+    let filter = OptionChainFilter {
+        expirations: req
+            .expirations
+            .iter()
+            .map(|e| date("expirations", e))
+            .collect::<Result<_, _>>()?,
+        min_expiration: req
+            .min_expiration
+            .as_deref()
+            .map(|e| date("min_expiration", e))
+            .transpose()?,
+        max_expiration: req
+            .max_expiration
+            .as_deref()
+            .map(|e| date("max_expiration", e))
+            .transpose()?,
+        min_strike: req
+            .min_strike
+            .map(|v| non_negative("min_strike", v))
+            .transpose()?,
+        max_strike: req
+            .max_strike
+            .map(|v| non_negative("max_strike", v))
+            .transpose()?,
+        strike_range_pct: req
+            .strike_range_pct
+            .map(|v| non_negative("strike_range_pct", v))
+            .transpose()?,
+        option_type,
+        min_open_interest: req.min_open_interest,
+        min_volume: req.min_volume,
+    };
+
+    if let (Some(min), Some(max)) = (filter.min_strike, filter.max_strike)
+        && min > max
+    {
+        return Err(invalid_argument("min_strike exceeds max_strike"));
+    }
+    if let (Some(min), Some(max)) = (&filter.min_expiration, &filter.max_expiration)
+        && min > max
+    {
+        return Err(invalid_argument("min_expiration is after max_expiration"));
+    }
+
+    Ok(filter)
+}
+
+/// Sort key: expiration, strike, then calls before puts.
+const fn option_order(option: &OptionQuote) -> (&str, rust_decimal::Decimal, u8) {
+    let contract = &option.contract;
+    let option_type = match contract.option_type {
+        OptionType::Call => 0,
+        OptionType::Put => 1,
+    };
+    (contract.expiration.as_str(), contract.strike, option_type)
+}
+
+/// Select one page of `items`.
+///
+/// A `page_size` of zero returns everything from the token onward. The token
+/// is the offset of the page's first item; the returned token is empty on
+/// the last page.
+fn paginate<T>(
+    items: Vec<T>,
+    page_size: i32,
+    page_token: &str,
+) -> Result<(Vec<T>, String), Status> {
+    let page_size = usize::try_from(page_size)
+        .map_err(|_| invalid_argument("page_size must be non-negative"))?;
+    let offset = if page_token.is_empty() {
+        0
+    } else {
+        page_token
+            .parse::<usize>()
+            .ok()
+            .filter(|offset| *offset <= items.len())
+            .ok_or_else(|| invalid_argument("invalid page_token"))?
+    };
+
+    let page_size = if page_size == 0 {
+        items.len()
+    } else {
+        page_size.min(MAX_OPTION_PAGE_SIZE)
+    };
+    let end = offset.saturating_add(page_size).min(items.len());
+    let next_page_token = if end < items.len() {
+        end.to_string()
+    } else {
+        String::new()
+    };
+
+    let page = items.into_iter().skip(offset).take(end - offset).collect();
+    Ok((page, next_page_token))
 }
 
 /// Convert a `MarketQuote` to a proto `Quote`.
+fn convert_symbol_snapshot(snapshot: SymbolMarketSnapshot) -> SymbolSnapshot {
+    let mut quote = snapshot.quote.as_ref().map(convert_quote);
+    if let Some(trade) = &snapshot.last_trade {
+        let quote = quote.get_or_insert_with(|| Quote {
+            symbol: snapshot.symbol.clone(),
+            ..Quote::default()
+        });
+        quote.last = decimal_to_f64(trade.price);
+        quote.last_size = i32::try_from(trade.size).unwrap_or(i32::MAX);
+    }
+    let bar = snapshot.daily_bar.as_ref();
+
+    SymbolSnapshot {
+        quote,
+        bars: vec![],
+        market_status: MarketStatus::Open.into(),
+        day_high: bar.map_or(0.0, |b| decimal_to_f64(b.high)),
+        day_low: bar.map_or(0.0, |b| decimal_to_f64(b.low)),
+        prev_close: snapshot.prev_close.map_or(0.0, decimal_to_f64),
+        open: bar.map_or(0.0, |b| decimal_to_f64(b.open)),
+        as_of: Some(prost_types::Timestamp::from(std::time::SystemTime::now())),
+        daily_bar: bar.map(|b| convert_daily_bar(&snapshot.symbol, b)),
+        last_trade_at: snapshot
+            .last_trade
+            .as_ref()
+            .map(|t| proto_timestamp(t.timestamp)),
+        option_stats: snapshot.option_stats.as_ref().map(convert_option_stats),
+        errors: snapshot
+            .errors
+            .iter()
+            .map(|e| SnapshotError {
+                component: e.component.to_string(),
+                code: e.code.clone(),
+                message: e.message.clone(),
+            })
+            .collect(),
+        symbol: snapshot.symbol,
+    }
+}
+
+fn convert_daily_bar(symbol: &str, bar: &DailyBar) -> Bar {
+    Bar {
+        symbol: symbol.to_string(),
+        timestamp: Some(proto_timestamp(bar.timestamp)),
+        timeframe_minutes: 1440,
+        open: decimal_to_f64(bar.open),
+        high: decimal_to_f64(bar.high),
+        low: decimal_to_f64(bar.low),
+        close: decimal_to_f64(bar.close),
+        volume: bar.volume,
+        vwap: bar.vwap.map(decimal_to_f64),
+        trade_count: bar
+            .trade_count
+            .map(|n| i32::try_from(n).unwrap_or(i32::MAX)),
+    }
+}
+
+fn convert_candle(candle: &Candle) -> Bar {
+    Bar {
+        symbol: candle.symbol.clone(),
+        timestamp: Some(proto_timestamp(candle.start)),
+        timeframe_minutes: i32::try_from(candle.timeframe.minutes()).unwrap_or(i32::MAX),
+        open: decimal_to_f64(candle.open),
+        high: decimal_to_f64(candle.high),
+        low: decimal_to_f64(candle.low),
+        close: decimal_to_f64(candle.close),
+        volume: candle.volume,
+        vwap: Some(decimal_to_f64(candle.vwap)),
+        trade_count: Some(i32::try_from(candle.trade_count).unwrap_or(i32::MAX)),
+    }
+}
+
+/// Parse requested bar timeframes in minutes.
+fn bar_timeframes(minutes: &[i32]) -> Result<Vec<BarTimeframe>, Status> {
+    minutes
+        .iter()
+        .map(|&m| {
+            BarTimeframe::from_minutes(i64::from(m)).ok_or_else(|| {
+                invalid_argument(format!("unsupported bar timeframe {m}; use 1 or 5 minutes"))
+            })
+        })
+        .collect()
+}
+
+fn convert_option_stats(stats: &OptionChainStats) -> super::proto::cream::v1::OptionChainStats {
+    super::proto::cream::v1::OptionChainStats {
+        contracts: i32::try_from(stats.contracts).unwrap_or(i32::MAX),
+        nearest_expiration: stats.nearest_expiration.clone().unwrap_or_default(),
+        atm_implied_volatility: stats.atm_implied_volatility,
+        call_volume: stats.call_volume,
+        put_volume: stats.put_volume,
+        call_open_interest: stats.call_open_interest,
+        put_open_interest: stats.put_open_interest,
+    }
+}
+
+fn proto_timestamp(ts: crate::domain::shared::Timestamp) -> prost_types::Timestamp {
+    prost_types::Timestamp::from(std::time::SystemTime::from(ts.as_datetime()))
+}
+
 fn convert_quote(quote: &MarketQuote) -> Quote {
     Quote {
         symbol: quote.symbol.clone(),
-        bid: quote.bid.to_string().parse().unwrap_or(0.0),
-        ask: quote.ask.to_string().parse().unwrap_or(0.0),
+        bid: decimal_to_f64(quote.bid),
+        ask: decimal_to_f64(quote.ask),
         bid_size: quote.bid_size,
         ask_size: quote.ask_size,
-        last: quote.last.to_string().parse().unwrap_or(0.0),
+        last: decimal_to_f64(quote.last),
         last_size: quote.last_size,
         volume: quote.volume,
         timestamp: Some(prost_types::Timestamp::from(std::time::SystemTime::now())),
@@ -218,10 +851,11 @@ fn convert_quote(quote: &MarketQuote) -> Quote {
 mod tests {
     use super::*;
     use crate::application::ports::{
-        MarketDataError, OptionChainData, OptionContract, OptionGreeks, OptionQuote,
+        MarketDataError, OptionChainData, OptionContract, OptionGreeks, OptionQuote, SimulatedClock,
     };
     use async_trait::async_trait;
     use rust_decimal::Decimal;
+    use tonic_types::StatusExt;
 
     struct MockMarketData;
 
@@ -318,6 +952,7 @@ mod tests {
             symbols: vec!["AAPL".to_string(), "MSFT".to_string()],
             include_bars: false,
             bar_timeframes: vec![],
+            include_options: false,
         });
 
         let response = service.get_snapshot(request).await.unwrap();
@@ -328,6 +963,35 @@ mod tests {
         assert_eq!(snapshot.symbols.len(), 2);
     }
 
+    #[tokio::test]
+    async fn get_snapshot_reports_missing_parts() {
+        let service = MarketDataServiceAdapter::new(Arc::new(MockMarketData));
+
+        let request = Request::new(GetSnapshotRequest {
+            symbols: vec!["AAPL".to_string()],
+            include_options: true,
+            ..GetSnapshotRequest::default()
+        });
+
+        let snapshot = service
+            .get_snapshot(request)
+            .await
+            .unwrap()
+            .into_inner()
+            .snapshot
+            .unwrap();
+        let aapl = &snapshot.symbols[0];
+        assert!(aapl.quote.is_some());
+        let stats = aapl.option_stats.as_ref().unwrap();
+        assert_eq!(stats.contracts, 1);
+        assert_eq!(stats.nearest_expiration, "2025-01-17");
+        assert_eq!(stats.call_open_interest, 500);
+        // The mock serves neither trades nor daily bars
+        let missing: Vec<&str> = aapl.errors.iter().map(|e| e.component.as_str()).collect();
+        assert_eq!(missing, vec!["TRADE", "DAILY_BAR"]);
+        assert!(aapl.daily_bar.is_none());
+    }
+
     #[tokio::test]
     async fn get_option_chain_success() {
         let market_data = Arc::new(MockMarketData);
@@ -338,6 +1002,7 @@ mod tests {
             expirations: vec![],
             min_strike: None,
             max_strike: None,
+            ..GetOptionChainRequest::default()
         });
 
         let response = service.get_option_chain(request).await.unwrap();
@@ -381,12 +1046,17 @@ mod tests {
             symbols: vec!["AAPL".to_string()],
             include_bars: false,
             bar_timeframes: vec![],
+            include_options: false,
         });
 
         let result = service.get_snapshot(request).await;
         assert!(result.is_err());
         let status = result.unwrap_err();
-        assert_eq!(status.code(), tonic::Code::Internal);
+        assert_eq!(status.code(), tonic::Code::Unavailable);
+        assert_eq!(
+            status.get_details_error_info().unwrap().reason,
+            "MARKET_DATA_UNAVAILABLE"
+        );
     }
 
     #[tokio::test]
@@ -399,17 +1069,368 @@ mod tests {
             expirations: vec![],
             min_strike: None,
             max_strike: None,
+            ..GetOptionChainRequest::default()
         });
 
         let result = service.get_option_chain(request).await;
         assert!(result.is_err());
         let status = result.unwrap_err();
-        assert_eq!(status.code(), tonic::Code::Internal);
+        assert_eq!(status.code(), tonic::Code::Unavailable);
+    }
+
+    #[tokio::test]
+    async fn get_iv_surface_reports_unfittable_chain() {
+        let market_data = Arc::new(MockMarketData);
+        let service = MarketDataServiceAdapter::new(market_data);
+
+        let response = service
+            .get_iv_surface(Request::new(GetIvSurfaceRequest {
+                underlying: "AAPL".to_string(),
+                ..GetIvSurfaceRequest::default()
+            }))
+            .await;
+
+        // The mock chain expired before today, so nothing can be fitted
+        assert_eq!(
+            response.unwrap_err().code(),
+            tonic::Code::FailedPrecondition
+        );
+    }
+
+    #[tokio::test]
+    async fn get_iv_surface_rejects_invalid_request() {
+        let market_data = Arc::new(MockMarketData);
+        let service = MarketDataServiceAdapter::new(market_data);
+
+        let status = service
+            .get_iv_surface(Request::new(GetIvSurfaceRequest {
+                underlying: "AAPL".to_string(),
+                min_expiration: Some("soon".to_string()),
+                ..GetIvSurfaceRequest::default()
+            }))
+            .await
+            .unwrap_err();
+        assert_eq!(status.code(), tonic::Code::InvalidArgument);
+
+        let status = service
+            .get_iv_surface(Request::new(GetIvSurfaceRequest {
+                underlying: "AAPL".to_string(),
+                risk_free_rate: Some(f64::NAN),
+                ..GetIvSurfaceRequest::default()
+            }))
+            .await
+            .unwrap_err();
+        assert_eq!(status.code(), tonic::Code::InvalidArgument);
+    }
+
+    /// Chain 35 days out around 100, strikes 85-115, deltas 0.1 apart.
+    struct SpreadMarketData;
+
+    #[async_trait]
+    impl MarketDataPort for SpreadMarketData {
+        async fn get_quotes(
+            &self,
+            _symbols: &[String],
+        ) -> Result<Vec<MarketQuote>, MarketDataError> {
+            Ok(vec![])
+        }
+
+        async fn get_option_chain(
+            &self,
+            underlying: &str,
+        ) -> Result<OptionChainData, MarketDataError> {
+            let expiration = (chrono::Utc::now().date_naive() + chrono::Days::new(35))
+                .format("%Y-%m-%d")
+                .to_string();
+            let mut options = Vec::new();
+            for step in -3_i32..=3 {
+                let strike = Decimal::from(100 + step * 5);
+                for (option_type, delta) in [
+                    (OptionType::Call, f64::from(step).mul_add(-0.1, 0.5)),
+                    (OptionType::Put, f64::from(step).mul_add(-0.1, -0.5)),
+                ] {
+                    let premium = Decimal::try_from(delta.abs() * 4.0).unwrap().round_dp(2);
+                    options.push(OptionQuote {
+                        contract: OptionContract {
+                            underlying: underlying.to_string(),
+                            expiration: expiration.clone(),
+                            strike,
+                            option_type,
+                        },
+                        quote: Some(MarketQuote {
+                            symbol: String::new(),
+                            bid: premium - Decimal::new(5, 2),
+                            ask: premium + Decimal::new(5, 2),
+                            bid_size: 10,
+                            ask_size: 10,
+                            last: premium,
+                            last_size: 1,
+                            volume: 100,
+                            timestamp: crate::domain::shared::Timestamp::now(),
+                        }),
+                        implied_volatility: None,
+                        greeks: Some(OptionGreeks {
+                            delta: Some(delta),
+                            gamma: None,
+                            theta: None,
+                            vega: None,
+                            rho: None,
+                        }),
+                        open_interest: 100,
+                    });
+                }
+            }
+            Ok(OptionChainData {
+                underlying: underlying.to_string(),
+                underlying_price: Decimal::new(100, 0),
+                options,
+                as_of: crate::domain::shared::Timestamp::now(),
+            })
+        }
+    }
+
+    fn iron_condor_request() -> BuildSpreadRequest {
+        BuildSpreadRequest {
+            underlying: "spy".to_string(),
+            strategy: super::super::proto::cream::v1::SpreadStrategy::IronCondor.into(),
+            direction: Direction::Short.into(),
+            target_delta: 0.3,
+            min_dte: 30,
+            max_dte: 45,
+            wing_width: Some(5.0),
+            quantity: 2,
+        }
+    }
+
+    #[tokio::test]
+    async fn build_spread_returns_iron_condor_decision() {
+        let built_at = Timestamp::parse("2026-03-02T15:00:00Z").unwrap();
+        let service = MarketDataServiceAdapter::new(Arc::new(SpreadMarketData))
+            .with_clock(Arc::new(SimulatedClock::new(built_at)));
+
+        let inner = service
+            .build_spread(Request::new(iron_condor_request()))
+            .await
+            .unwrap()
+            .into_inner();
+        let decision = inner.decision.unwrap();
+
+        // Short 1.20 bodies at 90/110, long 0.80 wings at 85/115
+        assert!((inner.mid_price + 0.8).abs() < 1e-9);
+        assert_eq!(decision.net_limit_price, Some(inner.mid_price));
+        assert_eq!(
+            decision
+                .legs
+                .iter()
+                .map(|l| l.ratio_qty)
+                .collect::<Vec<_>>(),
+            vec![1, -1, -1, 1]
+        );
+        assert!(decision.legs[0].symbol.starts_with("SPY"));
+        assert!(decision.legs[0].symbol.ends_with("P00085000"));
+        assert_eq!(
+            decision.strategy_family,
+            i32::from(StrategyFamily::IronCondor)
+        );
+        assert_eq!(decision.action, i32::from(Action::Sell));
+        assert_eq!(decision.size.unwrap().target_position_quantity, -2);
+        let order_plan = decision.order_plan.unwrap();
+        assert_eq!(order_plan.entry_limit_price, Some(0.8));
+        assert_eq!(order_plan.execution_tactic.as_deref(), Some("NET_LADDER"));
+        let params = order_plan.execution_params.unwrap().fields;
+        let number = |name: &str| match params[name].kind {
+            Some(prost_types::value::Kind::NumberValue(value)) => value,
+            ref other => panic!("{name} is not a number: {other:?}"),
+        };
+        assert_eq!(
+            params["started_at"].kind,
+            Some(prost_types::value::Kind::StringValue(built_at.to_rfc3339()))
+        );
+        // The default ladder walks all the way to natural
+        assert!((number("natural_price") - inner.natural_price).abs() < 1e-9);
+        assert!((number("max_limit_price") - inner.natural_price).abs() < 1e-9);
+        assert!((number("interval_seconds") - 15.0).abs() < f64::EPSILON);
+        let risk = decision.risk_levels.unwrap();
+        assert!((risk.stop_loss_level - 5.0).abs() < f64::EPSILON);
+        assert!(risk.take_profit_level.abs() < f64::EPSILON);
+    }
+
+    #[tokio::test]
+    async fn build_spread_rejects_bad_requests() {
+        let service = MarketDataServiceAdapter::new(Arc::new(SpreadMarketData));
+
+        let unspecified = BuildSpreadRequest {
+            strategy: 0,
+            ..iron_condor_request()
+        };
+        let status = service
+            .build_spread(Request::new(unspecified))
+            .await
+            .unwrap_err();
+        assert_eq!(status.code(), tonic::Code::InvalidArgument);
+
+        let no_quantity = BuildSpreadRequest {
+            quantity: 0,
+            ..iron_condor_request()
+        };
+        let status = service
+            .build_spread(Request::new(no_quantity))
+            .await
+            .unwrap_err();
+        assert_eq!(status.code(), tonic::Code::InvalidArgument);
+
+        let too_far = BuildSpreadRequest {
+            min_dte: 60,
+            max_dte: 90,
+            ..iron_condor_request()
+        };
+        let status = service
+            .build_spread(Request::new(too_far))
+            .await
+            .unwrap_err();
+        assert_eq!(status.code(), tonic::Code::FailedPrecondition);
     }
 
     #[test]
     fn create_market_data_service_test() {
         let market_data = Arc::new(MockMarketData);
-        let _server = create_market_data_service(market_data);
+        let price_feed = Arc::new(crate::infrastructure::price_feed::MockPriceFeed::new());
+        let _server =
+            create_market_data_service(market_data, price_feed, NetLadderConfig::default());
+    }
+
+    #[tokio::test]
+    async fn get_snapshot_includes_live_bars() {
+        use crate::application::services::{CandleAggregator, CandleAggregatorConfig};
+        use crate::infrastructure::broker::alpaca::{AlpacaConfig, AlpacaEnvironment};
+        use crate::infrastructure::price_feed::AlpacaPriceFeedAdapter;
+
+        let candles = Arc::new(CandleAggregator::new(CandleAggregatorConfig::default()));
+        let at = crate::domain::shared::Timestamp::parse("2026-03-02T14:31:00Z").unwrap();
+        candles.record_trade("AAPL", Decimal::new(150, 0), 100, at);
+        let config = AlpacaConfig::new(
+            "key".to_string(),
+            "secret".to_string(),
+            AlpacaEnvironment::Paper,
+        );
+        let price_feed = AlpacaPriceFeedAdapter::new(&config)
+            .unwrap()
+            .with_candles(candles);
+        let service = MarketDataServiceAdapter::new(Arc::new(MockMarketData))
+            .with_price_feed(Arc::new(price_feed));
+
+        let request = |bar_timeframes: Vec<i32>| {
+            Request::new(GetSnapshotRequest {
+                symbols: vec!["AAPL".to_string()],
+                include_bars: true,
+                bar_timeframes,
+                include_options: false,
+            })
+        };
+
+        let snapshot = service
+            .get_snapshot(request(vec![]))
+            .await
+            .unwrap()
+            .into_inner()
+            .snapshot
+            .unwrap();
+        let bars = &snapshot.symbols[0].bars;
+        let timeframes: Vec<i32> = bars.iter().map(|b| b.timeframe_minutes).collect();
+        assert_eq!(timeframes, [1, 5]);
+        assert!((bars[0].close - 150.0).abs() < f64::EPSILON);
+        assert_eq!(bars[1].volume, 100);
+
+        let status = service.get_snapshot(request(vec![15])).await.unwrap_err();
+        assert_eq!(status.code(), tonic::Code::InvalidArgument);
+    }
+
+    #[test]
+    fn paginate_walks_pages_with_tokens() {
+        let items: Vec<u32> = (0..5).collect();
+
+        let (page, token) = paginate(items.clone(), 2, "").unwrap();
+        assert_eq!((page, token.as_str()), (vec![0, 1], "2"));
+
+        let (page, token) = paginate(items.clone(), 2, "4").unwrap();
+        assert_eq!((page, token.as_str()), (vec![4], ""));
+
+        let (page, token) = paginate(items.clone(), 0, "").unwrap();
+        assert_eq!((page.len(), token.as_str()), (5, ""));
+
+        assert_eq!(
+            paginate(items.clone(), 2, "9").unwrap_err().code(),
+            tonic::Code::InvalidArgument
+        );
+        assert_eq!(
+            paginate(items, -1, "").unwrap_err().code(),
+            tonic::Code::InvalidArgument
+        );
+    }
+
+    #[test]
+    fn option_chain_filter_from_request() {
+        let request = GetOptionChainRequest {
+            underlying: "SPX".to_string(),
+            option_type: super::super::proto::cream::v1::OptionType::Put.into(),
+            max_expiration: Some("2025-02-21".to_string()),
+            strike_range_pct: Some(0.05),
+            min_open_interest: Some(100),
+            ..GetOptionChainRequest::default()
+        };
+
+        let filter = option_chain_filter(&request).unwrap();
+        assert_eq!(filter.option_type, Some(OptionType::Put));
+        assert_eq!(filter.max_expiration.as_deref(), Some("2025-02-21"));
+        assert_eq!(filter.strike_range_pct, Some(Decimal::new(5, 2)));
+        assert_eq!(filter.min_open_interest, Some(100));
+
+        let invalid = GetOptionChainRequest {
+            min_expiration: Some("02/21/2025".to_string()),
+            ..GetOptionChainRequest::default()
+        };
+        assert_eq!(
+            option_chain_filter(&invalid).unwrap_err().code(),
+            tonic::Code::InvalidArgument
+        );
+
+        let inverted = GetOptionChainRequest {
+            min_strike: Some(200.0),
+            max_strike: Some(100.0),
+            ..GetOptionChainRequest::default()
+        };
+        assert!(option_chain_filter(&inverted).is_err());
+    }
+
+    #[tokio::test]
+    async fn get_option_chain_filters_and_pages() {
+        let service = MarketDataServiceAdapter::new(Arc::new(MockMarketData));
+
+        let request = Request::new(GetOptionChainRequest {
+            underlying: "AAPL".to_string(),
+            option_type: super::super::proto::cream::v1::OptionType::Put.into(),
+            ..GetOptionChainRequest::default()
+        });
+        let inner = service
+            .get_option_chain(request)
+            .await
+            .unwrap()
+            .into_inner();
+        assert!(inner.chain.unwrap().options.is_empty());
+        assert_eq!(inner.total_count, 0);
+
+        let request = Request::new(GetOptionChainRequest {
+            underlying: "AAPL".to_string(),
+            page_size: 1,
+            ..GetOptionChainRequest::default()
+        });
+        let inner = service
+            .get_option_chain(request)
+            .await
+            .unwrap()
+            .into_inner();
+        assert_eq!(inner.chain.unwrap().options.len(), 1);
+        assert_eq!(inner.total_count, 1);
+        assert!(inner.next_page_token.is_empty());
     }
 }
@@ -2,11 +2,26 @@
 //!
 //! Tonic-based gRPC service that delegates to application use cases.
 
+mod auth;
+mod health;
 mod market_data_service;
 mod service;
+mod tls;
 
+pub use auth::{GrpcAuthLayer, GrpcAuthService, required_role};
+pub use health::GrpcHealthUpdater;
 pub use market_data_service::{MarketDataServiceAdapter, create_market_data_service};
 pub use service::{ExecutionServiceAdapter, create_execution_service};
+pub use tls::{GrpcTlsConfig, ReloadingTlsAcceptor, TlsError};
+
+use tonic::Status;
+
+use super::api_error::ApiError;
+
+/// `INVALID_ARGUMENT` status with a `VALIDATION_FAILED` error code.
+fn invalid_argument(message: impl Into<String>) -> Status {
+    ApiError::invalid_argument(message).into()
+}
 
 /// Include generated protobuf code.
 #[allow(
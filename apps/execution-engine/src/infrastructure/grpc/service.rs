@@ -7,31 +7,52 @@ use tokio::sync::mpsc;
 use tokio_stream::{Stream, wrappers::ReceiverStream};
 use tonic::{Request, Response, Status};
 
+use super::invalid_argument;
 use super::proto::cream::v1::{
-    AccountState, CancelOrderRequest, CancelOrderResponse, CheckConstraintsRequest,
-    CheckConstraintsResponse, GetAccountStateRequest, GetAccountStateResponse,
-    GetOrderStateRequest, GetOrderStateResponse, GetPositionsRequest, GetPositionsResponse,
-    StreamExecutionsRequest, StreamExecutionsResponse, SubmitOrderRequest, SubmitOrderResponse,
+    AccountState, BulkCancelResponse, CancelAllRequest, CancelOrderRequest, CancelOrderResponse,
+    CancelPlanRequest, CheckConstraintsRequest, CheckConstraintsResponse, ClosedLot,
+    GetAccountStateRequest, GetAccountStateResponse, GetOrderStateRequest, GetOrderStateResponse,
+    GetPositionLifecyclesRequest, GetPositionLifecyclesResponse, GetPositionsRequest,
+    GetPositionsResponse, OrderCancelResult, OrderFill, OrderState, QueryOrdersRequest,
+    QueryOrdersResponse, ScaleOutTarget as ProtoScaleOutTarget, StreamExecutionsRequest,
+    StreamExecutionsResponse, SubmitOrderRequest, SubmitOrderResponse,
     execution_service_server::{ExecutionService, ExecutionServiceServer},
 };
 
 use crate::application::dto::{CreateOrderDto, SubmitOrdersRequestDto};
-use crate::application::ports::{BrokerPort, EventPublisherPort, RiskRepositoryPort};
+use crate::application::ports::{
+    BrokerPort, DecisionContext, EventPublisherPort, LifecycleEventKind, PositionLifecycle,
+    RiskRepositoryPort, ThesisState, TimeHorizon,
+};
+use crate::application::services::{PositionLifecycleTracker, RealizedPnlService};
 use crate::application::use_cases::{
     CancelOrdersUseCase, SubmitOrdersUseCase, ValidateRiskUseCase,
 };
-use crate::domain::order_execution::aggregate::{CreateOrderCommand, Order};
-use crate::domain::order_execution::repository::OrderRepository;
+use crate::domain::execution_tactics::StrategyFamily;
+use crate::domain::order_execution::aggregate::{
+    CreateOrderCommand, MAX_STRATEGY_TAG_LEN, Order, is_valid_strategy_tag,
+};
+use crate::domain::order_execution::errors::OrderError;
+use crate::domain::order_execution::repository::{
+    DEFAULT_ORDER_QUERY_LIMIT, MAX_ORDER_QUERY_LIMIT, OrderQuery, OrderRepository,
+};
 use crate::domain::order_execution::value_objects::{
-    CancelReason, OrderPurpose, OrderSide, OrderType, TimeInForce,
+    CancelReason, ClosedLot as DomainClosedLot, OrderPurpose, OrderSide, OrderType, OtoExit,
+    TimeInForce,
 };
 use crate::domain::risk_management::aggregate::RiskPolicy;
 use crate::domain::risk_management::services::RiskValidationService;
 use crate::domain::risk_management::value_objects::{
-    ExposureLimits, OptionsLimits, PdtStatus, PerInstrumentLimits, PortfolioLimits,
-    PositionContext, RiskContext, SizingLimits, ViolationSeverity as DomainSeverity,
+    ConcentrationLimits, ExposureLimits, OptionsLimits, PdtStatus, PerInstrumentLimits,
+    PortfolioLimits, PositionContext, RiskContext, SizingLimits,
+    ViolationSeverity as DomainSeverity,
+};
+use crate::domain::shared::{
+    CycleId, ErrorKind, InstrumentId, Money, OrderId, Quantity, Symbol, Timestamp,
 };
-use crate::domain::shared::{InstrumentId, Money, OrderId, Quantity, Symbol};
+use crate::domain::stop_enforcement::{HoldingPeriod, ScaleOutTarget};
+use crate::infrastructure::api_error::ApiError;
+use crate::infrastructure::decimal::{decimal_from_f64, decimal_to_f64};
 
 /// gRPC `ExecutionService` adapter.
 pub struct ExecutionServiceAdapter<B, R, O, E>
@@ -42,11 +63,12 @@ where
     E: EventPublisherPort,
 {
     submit_orders: Arc<SubmitOrdersUseCase<B, R, O, E>>,
-    #[allow(dead_code)]
     validate_risk: Arc<ValidateRiskUseCase<R, O>>,
     cancel_orders: Arc<CancelOrdersUseCase<B, O, E>>,
     order_repo: Arc<O>,
     broker: Arc<B>,
+    lifecycle: Option<Arc<PositionLifecycleTracker>>,
+    realized_pnl: Arc<RealizedPnlService<O>>,
 }
 
 impl<B, R, O, E> ExecutionServiceAdapter<B, R, O, E>
@@ -57,7 +79,7 @@ where
     E: EventPublisherPort,
 {
     /// Create a new `ExecutionService` adapter.
-    pub const fn new(
+    pub fn new(
         submit_orders: Arc<SubmitOrdersUseCase<B, R, O, E>>,
         validate_risk: Arc<ValidateRiskUseCase<R, O>>,
         cancel_orders: Arc<CancelOrdersUseCase<B, O, E>>,
@@ -68,10 +90,74 @@ where
             submit_orders,
             validate_risk,
             cancel_orders,
+            realized_pnl: Arc::new(RealizedPnlService::new(Arc::clone(&order_repo))),
             order_repo,
             broker,
+            lifecycle: None,
         }
     }
+
+    /// Serve position lifecycles from `lifecycle`.
+    #[must_use]
+    pub fn with_position_lifecycle(mut self, lifecycle: Arc<PositionLifecycleTracker>) -> Self {
+        self.lifecycle = Some(lifecycle);
+        self
+    }
+
+    /// Report realized P&L from `realized_pnl`, sharing its lot matching
+    /// method and opening lots.
+    #[must_use]
+    pub fn with_realized_pnl(mut self, realized_pnl: Arc<RealizedPnlService<O>>) -> Self {
+        self.realized_pnl = realized_pnl;
+        self
+    }
+
+    /// Cancel every working order, or those from decision cycle `cycle_id`.
+    async fn cancel_working(
+        &self,
+        cycle_id: Option<&str>,
+        reason: Option<String>,
+    ) -> Result<BulkCancelResponse, Status> {
+        let reason =
+            reason.map_or_else(CancelReason::user_requested, |r| CancelReason::new(&r, &r));
+        let order_ids = self
+            .cancel_orders
+            .open_order_ids(cycle_id)
+            .await
+            .map_err(|e| ApiError::order_store(&e).with_context("Failed to load open orders"))?;
+
+        let results: Vec<OrderCancelResult> = self
+            .cancel_orders
+            .cancel_orders(&order_ids, reason.clone())
+            .await
+            .into_iter()
+            .map(|r| OrderCancelResult {
+                order_id: r.order_id,
+                accepted: r.success,
+                error_message: r.error,
+            })
+            .collect();
+        let cancelled = results.iter().filter(|r| r.accepted).count();
+        let failed = results.len() - cancelled;
+
+        tracing::info!(
+            target: "audit",
+            cycle_id = cycle_id.unwrap_or("*"),
+            reason = %reason,
+            requested = results.len(),
+            cancelled,
+            failed,
+            "Bulk cancel"
+        );
+
+        let count = |n: usize| i32::try_from(n).unwrap_or(i32::MAX);
+        Ok(BulkCancelResponse {
+            requested: count(results.len()),
+            cancelled: count(cancelled),
+            failed: count(failed),
+            results,
+        })
+    }
 }
 
 /// Create an `ExecutionService` gRPC server.
@@ -81,6 +167,8 @@ pub fn create_execution_service<B, R, O, E>(
     cancel_orders: Arc<CancelOrdersUseCase<B, O, E>>,
     order_repo: Arc<O>,
     broker: Arc<B>,
+    lifecycle: Arc<PositionLifecycleTracker>,
+    realized_pnl: Arc<RealizedPnlService<O>>,
 ) -> ExecutionServiceServer<ExecutionServiceAdapter<B, R, O, E>>
 where
     B: BrokerPort + 'static,
@@ -94,7 +182,9 @@ where
         cancel_orders,
         order_repo,
         broker,
-    );
+    )
+    .with_position_lifecycle(lifecycle)
+    .with_realized_pnl(realized_pnl);
     ExecutionServiceServer::new(service)
 }
 
@@ -116,7 +206,7 @@ where
 
         let decision_plan = req
             .decision_plan
-            .ok_or_else(|| Status::invalid_argument("decision_plan is required"))?;
+            .ok_or_else(|| invalid_argument("decision_plan is required"))?;
 
         // Log constraints if provided
         if let Some(ref c) = req.constraints {
@@ -124,6 +214,7 @@ where
                 max_positions = c.max_positions,
                 max_risk_per_trade_bps = c.max_risk_per_trade_bps,
                 max_sector_exposure_bps = c.max_sector_exposure_bps,
+                max_correlation_bps = c.max_correlation_bps,
                 "Runtime constraints received"
             );
         }
@@ -155,6 +246,15 @@ where
             }
         }
 
+        // Existing positions count toward portfolio limits
+        risk_context.current_exposure = risk_context.position_exposure();
+
+        // Sector and correlation groups come from reference data, not the request
+        match self.validate_risk.concentration_groups().await {
+            Ok(groups) => risk_context.concentration_groups = groups,
+            Err(e) => tracing::warn!(error = %e, "Concentration groups unavailable"),
+        }
+
         // Convert decisions to domain Orders for validation
         let orders: Vec<Order> = decision_plan
             .decisions
@@ -225,25 +325,58 @@ where
 
         let instrument = req
             .instrument
-            .ok_or_else(|| Status::invalid_argument("instrument is required"))?;
+            .ok_or_else(|| invalid_argument("instrument is required"))?;
+
+        if req.exit_stop_loss.is_some() && req.exit_take_profit.is_some() {
+            return Err(invalid_argument(
+                "only one of exit_stop_loss and exit_take_profit may be set",
+            ));
+        }
+        if let Some(tag) = &req.strategy_tag
+            && !is_valid_strategy_tag(tag)
+        {
+            return Err(invalid_argument(format!(
+                "strategy_tag must be 1 to {MAX_STRATEGY_TAG_LEN} letters, digits, '_', '-' or '.'"
+            )));
+        }
+
+        let thesis_state = convert_proto_thesis_state(req.thesis_state);
 
         // Create order DTO
         let order_dto = CreateOrderDto {
             client_order_id: req.client_order_id.clone(),
-            symbol: instrument.instrument_id.clone(),
+            symbol: Symbol::normalize(&instrument.instrument_id),
             side: convert_proto_side(req.side),
             order_type: convert_proto_order_type(req.order_type),
             quantity: rust_decimal::Decimal::from(req.quantity),
-            limit_price: req
-                .limit_price
-                .and_then(rust_decimal::Decimal::from_f64_retain),
+            limit_price: req.limit_price.and_then(decimal_from_f64),
+            stop_price: None,
             time_in_force: TimeInForce::Day,
-            purpose: OrderPurpose::Entry,
+            purpose: purpose_for_thesis(thesis_state),
+            decision: Some(DecisionContext {
+                decision_id: req.decision_id.clone(),
+                thesis_state,
+                time_horizon: convert_proto_time_horizon(req.time_horizon),
+                strategy_family: convert_proto_strategy_family(req.strategy_family),
+                strategy_tag: req.strategy_tag.clone(),
+                max_holding_period: convert_proto_holding_period(
+                    req.max_holding_secs,
+                    req.holding_stop_distance,
+                ),
+                scale_out: convert_proto_scale_out(&req.scale_out_targets),
+                rationale: req.rationale.clone(),
+            })
+            .filter(|d| *d != DecisionContext::default()),
+            oto_exit: OtoExit::from_levels(
+                req.exit_stop_loss.and_then(decimal_from_f64),
+                req.exit_take_profit.and_then(decimal_from_f64),
+            ),
         };
 
         let submit_request = SubmitOrdersRequestDto {
             orders: vec![order_dto],
             validate_risk: true,
+            cycle_id: Some(req.cycle_id.clone()).filter(|c| !c.is_empty()),
         };
 
         let result = self.submit_orders.execute(submit_request).await;
@@ -307,9 +440,9 @@ where
             .broker
             .get_buying_power()
             .await
-            .map_err(|e| Status::internal(format!("Failed to get buying power: {e}")))?;
+            .map_err(|e| ApiError::from_error(&e).with_context("Failed to get buying power"))?;
 
-        let bp_f64: f64 = buying_power.to_string().parse().unwrap_or(0.0);
+        let bp_f64 = decimal_to_f64(buying_power);
 
         let account_state = AccountState {
             account_id: "default".to_string(),
@@ -338,23 +471,26 @@ where
             .broker
             .get_all_positions()
             .await
-            .map_err(|e| Status::internal(format!("Failed to get positions: {e}")))?;
+            .map_err(|e| ApiError::from_error(&e).with_context("Failed to get positions"))?;
+        let lots = self
+            .realized_pnl
+            .lots()
+            .await
+            .map_err(|e| ApiError::order_store(&e).with_context("Failed to read fills"))?;
 
         let positions: Vec<super::proto::cream::v1::Position> = broker_positions
             .into_iter()
             .map(|p| {
                 let quantity: i32 = p.quantity.to_string().parse().unwrap_or(0);
-                let avg_entry_price: f64 = p.avg_entry_price.to_string().parse().unwrap_or(0.0);
-                let market_value: f64 = p.market_value.to_string().parse().unwrap_or(0.0);
-                let unrealized_pnl: f64 = p.unrealized_pnl.to_string().parse().unwrap_or(0.0);
-                let cost_basis = avg_entry_price * f64::from(quantity.abs());
-                let unrealized_pnl_pct = if cost_basis == 0.0 {
-                    0.0
+                let cost_basis = p.avg_entry_price * p.quantity.abs();
+                let unrealized_pnl_pct = if cost_basis.is_zero() {
+                    rust_decimal::Decimal::ZERO
                 } else {
-                    unrealized_pnl / cost_basis
+                    p.unrealized_pnl / cost_basis
                 };
 
                 let is_option = is_occ_symbol(&p.symbol);
+                let realized_pnl = lots.realized_pnl(&p.symbol);
                 let instrument_type = if is_option {
                     super::proto::cream::v1::InstrumentType::Option
                 } else {
@@ -368,11 +504,12 @@ where
                         option_contract: None,
                     }),
                     quantity,
-                    avg_entry_price,
-                    market_value,
-                    unrealized_pnl,
-                    unrealized_pnl_pct,
-                    cost_basis,
+                    avg_entry_price: decimal_to_f64(p.avg_entry_price),
+                    market_value: decimal_to_f64(p.market_value),
+                    unrealized_pnl: decimal_to_f64(p.unrealized_pnl),
+                    unrealized_pnl_pct: decimal_to_f64(unrealized_pnl_pct),
+                    cost_basis: decimal_to_f64(cost_basis),
+                    realized_pnl: decimal_to_f64(realized_pnl),
                 }
             })
             .collect();
@@ -380,6 +517,37 @@ where
         Ok(Response::new(GetPositionsResponse {
             positions,
             as_of: Some(prost_types::Timestamp::from(std::time::SystemTime::now())),
+            realized_pnl: decimal_to_f64(lots.total_realized_pnl()),
+            closed_lots: lots.closed_lots().iter().map(convert_closed_lot).collect(),
+        }))
+    }
+
+    async fn get_position_lifecycles(
+        &self,
+        request: Request<GetPositionLifecyclesRequest>,
+    ) -> Result<Response<GetPositionLifecyclesResponse>, Status> {
+        let req = request.into_inner();
+        let lifecycle = self.lifecycle.as_ref().ok_or_else(|| {
+            ApiError::new(
+                ErrorKind::Disabled,
+                "LIFECYCLE_TRACKING_DISABLED",
+                "Position lifecycle tracking is not enabled",
+            )
+        })?;
+
+        let mut lifecycles = if req.include_closed {
+            lifecycle.history(None).await
+        } else {
+            lifecycle.open_positions().await
+        }
+        .map_err(|e| ApiError::from_error(&e).with_context("Failed to get position lifecycles"))?;
+        if !req.symbols.is_empty() {
+            let symbols: Vec<String> = req.symbols.iter().map(|s| Symbol::normalize(s)).collect();
+            lifecycles.retain(|l| symbols.contains(&l.symbol));
+        }
+
+        Ok(Response::new(GetPositionLifecyclesResponse {
+            lifecycles: lifecycles.iter().map(convert_lifecycle_to_proto).collect(),
         }))
     }
 
@@ -414,28 +582,72 @@ where
                     order_type: convert_to_proto_order_type(dto.order_type),
                     requested_quantity: dto.quantity.to_string().parse().unwrap_or(0),
                     filled_quantity: dto.filled_qty.to_string().parse().unwrap_or(0),
-                    avg_fill_price: dto
-                        .avg_fill_price
-                        .map_or(0.0, |p| p.to_string().parse().unwrap_or(0.0)),
-                    limit_price: dto
-                        .limit_price
-                        .map(|p| p.to_string().parse().unwrap_or(0.0)),
+                    avg_fill_price: dto.avg_fill_price.map_or(0.0, decimal_to_f64),
+                    limit_price: dto.limit_price.map(decimal_to_f64),
                     stop_price: None,
                     submitted_at: Some(prost_types::Timestamp::from(std::time::SystemTime::now())),
                     last_update_at: Some(
                         prost_types::Timestamp::from(std::time::SystemTime::now()),
                     ),
                     status_message: String::new(),
+                    child_order_ids: dto.child_order_ids,
                 };
 
                 Ok(Response::new(response))
             }
-            Ok(None) => Err(Status::not_found(format!(
-                "Order not found: {}",
-                req.order_id
-            ))),
-            Err(e) => Err(Status::internal(format!("Failed to get order: {e}"))),
+            Ok(None) => Err(ApiError::from_error(&OrderError::NotFound {
+                order_id: req.order_id,
+            })
+            .into()),
+            Err(e) => Err(ApiError::order_store(&e)
+                .with_context("Failed to get order")
+                .into()),
+        }
+    }
+
+    async fn query_orders(
+        &self,
+        request: Request<QueryOrdersRequest>,
+    ) -> Result<Response<QueryOrdersResponse>, Status> {
+        let req = request.into_inner();
+        let limit = req.limit.map_or(DEFAULT_ORDER_QUERY_LIMIT, |l| l as usize);
+        if !(1..=MAX_ORDER_QUERY_LIMIT).contains(&limit) {
+            return Err(invalid_argument(format!(
+                "limit must be between 1 and {MAX_ORDER_QUERY_LIMIT}"
+            )));
+        }
+        let mut statuses = Vec::new();
+        for status in &req.statuses {
+            statuses.extend(
+                convert_proto_status(*status)
+                    .ok_or_else(|| invalid_argument(format!("Unknown order status: {status}")))?,
+            );
         }
+        let query = OrderQuery {
+            from: req.from.map(convert_proto_timestamp).transpose()?,
+            to: req.to.map(convert_proto_timestamp).transpose()?,
+            symbol: req.symbol.as_deref().map(Symbol::new),
+            statuses,
+            cycle_id: req.cycle_id.as_deref().map(CycleId::new),
+            offset: req.offset as usize,
+            limit,
+        };
+
+        let page = self
+            .order_repo
+            .query(&query)
+            .await
+            .map_err(|e| ApiError::order_store(&e).with_context("Failed to query orders"))?;
+        let count = |n: usize| u32::try_from(n).unwrap_or(u32::MAX);
+        Ok(Response::new(QueryOrdersResponse {
+            orders: page
+                .orders
+                .iter()
+                .map(convert_order_to_proto_state)
+                .collect(),
+            total: count(page.total),
+            next_offset: page.next_offset(&query).map(count),
+        }))
     }
 
     async fn cancel_order(
@@ -462,6 +674,27 @@ where
 
         Ok(Response::new(response))
     }
+
+    async fn cancel_all(
+        &self,
+        request: Request<CancelAllRequest>,
+    ) -> Result<Response<BulkCancelResponse>, Status> {
+        let req = request.into_inner();
+        Ok(Response::new(self.cancel_working(None, req.reason).await?))
+    }
+
+    async fn cancel_plan(
+        &self,
+        request: Request<CancelPlanRequest>,
+    ) -> Result<Response<BulkCancelResponse>, Status> {
+        let req = request.into_inner();
+        if req.cycle_id.is_empty() {
+            return Err(invalid_argument("cycle_id is required"));
+        }
+        Ok(Response::new(
+            self.cancel_working(Some(&req.cycle_id), req.reason).await?,
+        ))
+    }
 }
 
 fn build_risk_context_from_account(account: &super::proto::cream::v1::AccountState) -> RiskContext {
@@ -507,7 +740,10 @@ fn convert_decision_to_order(d: &super::proto::cream::v1::Decision) -> Option<Or
         legs: vec![],
     };
 
-    Order::new(command).ok()
+    match &d.strategy_tag {
+        Some(tag) => Order::new_tagged(command, tag).ok(),
+        None => Order::new(command).ok(),
+    }
 }
 
 fn build_risk_policy_from_constraints(
@@ -534,11 +770,24 @@ fn build_risk_policy_from_constraints(
             max_theta_cents: c.max_theta_cents,
         };
 
+        let defaults = ConcentrationLimits::default();
+        let concentration = ConcentrationLimits {
+            max_sector_pct_equity_bps: u32::try_from(c.max_sector_exposure_bps)
+                .ok()
+                .filter(|&bps| bps > 0)
+                .unwrap_or(defaults.max_sector_pct_equity_bps),
+            max_correlation_group_pct_equity_bps: u32::try_from(c.max_correlation_bps)
+                .ok()
+                .filter(|&bps| bps > 0)
+                .unwrap_or(defaults.max_correlation_group_pct_equity_bps),
+        };
+
         let limits = ExposureLimits {
             per_instrument,
             portfolio,
             options,
             sizing: SizingLimits::default(),
+            concentration,
         };
 
         RiskPolicy::new("runtime", "Runtime Constraints", limits)
@@ -642,6 +891,159 @@ fn convert_proto_side(side: i32) -> OrderSide {
     }
 }
 
+fn convert_proto_thesis_state(state: i32) -> Option<ThesisState> {
+    use super::proto::cream::v1::ThesisState as ProtoThesisState;
+
+    match ProtoThesisState::try_from(state).ok()? {
+        ProtoThesisState::Unspecified => None,
+        ProtoThesisState::Watching => Some(ThesisState::Watching),
+        ProtoThesisState::Entered => Some(ThesisState::Entered),
+        ProtoThesisState::Adding => Some(ThesisState::Adding),
+        ProtoThesisState::Managing => Some(ThesisState::Managing),
+        ProtoThesisState::Exiting => Some(ThesisState::Exiting),
+        ProtoThesisState::Closed => Some(ThesisState::Closed),
+    }
+}
+
+fn convert_to_proto_thesis_state(state: Option<ThesisState>) -> i32 {
+    use super::proto::cream::v1::ThesisState as ProtoThesisState;
+
+    match state {
+        None => ProtoThesisState::Unspecified,
+        Some(ThesisState::Watching) => ProtoThesisState::Watching,
+        Some(ThesisState::Entered) => ProtoThesisState::Entered,
+        Some(ThesisState::Adding) => ProtoThesisState::Adding,
+        Some(ThesisState::Managing) => ProtoThesisState::Managing,
+        Some(ThesisState::Exiting) => ProtoThesisState::Exiting,
+        Some(ThesisState::Closed) => ProtoThesisState::Closed,
+    }
+    .into()
+}
+
+fn convert_proto_time_horizon(horizon: i32) -> Option<TimeHorizon> {
+    use super::proto::cream::v1::TimeHorizon as ProtoTimeHorizon;
+
+    match ProtoTimeHorizon::try_from(horizon).ok()? {
+        ProtoTimeHorizon::Unspecified => None,
+        ProtoTimeHorizon::Intraday => Some(TimeHorizon::Intraday),
+        ProtoTimeHorizon::Swing => Some(TimeHorizon::Swing),
+        ProtoTimeHorizon::Position => Some(TimeHorizon::Position),
+    }
+}
+
+fn convert_proto_strategy_family(family: i32) -> Option<StrategyFamily> {
+    use super::proto::cream::v1::StrategyFamily as ProtoStrategyFamily;
+
+    match ProtoStrategyFamily::try_from(family).ok()? {
+        ProtoStrategyFamily::Unspecified => None,
+        ProtoStrategyFamily::EquityLong => Some(StrategyFamily::EquityLong),
+        ProtoStrategyFamily::EquityShort => Some(StrategyFamily::EquityShort),
+        ProtoStrategyFamily::OptionLong => Some(StrategyFamily::OptionLong),
+        ProtoStrategyFamily::OptionShort => Some(StrategyFamily::OptionShort),
+        ProtoStrategyFamily::VerticalSpread => Some(StrategyFamily::VerticalSpread),
+        ProtoStrategyFamily::IronCondor => Some(StrategyFamily::IronCondor),
+        ProtoStrategyFamily::Straddle => Some(StrategyFamily::Straddle),
+        ProtoStrategyFamily::Strangle => Some(StrategyFamily::Strangle),
+        ProtoStrategyFamily::CalendarSpread => Some(StrategyFamily::CalendarSpread),
+    }
+}
+
+fn convert_proto_holding_period(
+    max_holding_secs: Option<u64>,
+    holding_stop_distance: Option<f64>,
+) -> Option<HoldingPeriod> {
+    let secs = max_holding_secs.filter(|secs| *secs > 0)?;
+    let distance = holding_stop_distance
+        .and_then(decimal_from_f64)
+        .filter(|d| *d > rust_decimal::Decimal::ZERO && *d < rust_decimal::Decimal::ONE);
+    Some(distance.map_or_else(
+        || HoldingPeriod::close_after(secs),
+        |distance| HoldingPeriod::tighten_after(secs, distance),
+    ))
+}
+
+fn convert_proto_scale_out(targets: &[ProtoScaleOutTarget]) -> Vec<ScaleOutTarget> {
+    targets
+        .iter()
+        .filter_map(|t| {
+            Some(ScaleOutTarget::new(
+                decimal_from_f64(t.price)?,
+                decimal_from_f64(t.fraction)?,
+            ))
+        })
+        .collect()
+}
+
+fn convert_to_proto_time_horizon(horizon: Option<TimeHorizon>) -> i32 {
+    use super::proto::cream::v1::TimeHorizon as ProtoTimeHorizon;
+
+    match horizon {
+        None => ProtoTimeHorizon::Unspecified,
+        Some(TimeHorizon::Intraday) => ProtoTimeHorizon::Intraday,
+        Some(TimeHorizon::Swing) => ProtoTimeHorizon::Swing,
+        Some(TimeHorizon::Position) => ProtoTimeHorizon::Position,
+    }
+    .into()
+}
+
+/// Order purpose implied by the decision's thesis state.
+const fn purpose_for_thesis(state: Option<ThesisState>) -> OrderPurpose {
+    match state {
+        Some(ThesisState::Adding) => OrderPurpose::ScaleIn,
+        Some(ThesisState::Exiting | ThesisState::Closed) => OrderPurpose::Exit,
+        _ => OrderPurpose::Entry,
+    }
+}
+
+fn convert_lifecycle_to_proto(
+    lifecycle: &PositionLifecycle,
+) -> super::proto::cream::v1::PositionLifecycle {
+    use super::proto::cream::v1::{
+        LifecycleEventKind as ProtoEventKind, PositionLifecycle as ProtoLifecycle,
+        PositionLifecycleEvent,
+    };
+
+    let timestamp =
+        |ts: Timestamp| prost_types::Timestamp::from(std::time::SystemTime::from(ts.as_datetime()));
+
+    ProtoLifecycle {
+        lifecycle_id: lifecycle.lifecycle_id.clone(),
+        symbol: lifecycle.symbol.clone(),
+        quantity: decimal_to_f64(lifecycle.quantity),
+        opened_at: Some(timestamp(lifecycle.opened_at)),
+        closed_at: lifecycle.closed_at.map(timestamp),
+        exit_reason: lifecycle.exit_reason.clone(),
+        events: lifecycle
+            .events
+            .iter()
+            .map(|event| {
+                let decision = event.decision.clone().unwrap_or_default();
+                let kind = match event.kind {
+                    LifecycleEventKind::Opened => ProtoEventKind::Opened,
+                    LifecycleEventKind::ScaledIn => ProtoEventKind::ScaledIn,
+                    LifecycleEventKind::ScaledOut => ProtoEventKind::ScaledOut,
+                    LifecycleEventKind::Closed => ProtoEventKind::Closed,
+                };
+                PositionLifecycleEvent {
+                    kind: kind.into(),
+                    order_id: event.order_id.clone(),
+                    fill_ids: event.fill_ids.clone(),
+                    quantity: decimal_to_f64(event.quantity),
+                    price: decimal_to_f64(event.price),
+                    position_after: decimal_to_f64(event.position_after),
+                    purpose: event.purpose.to_string(),
+                    cycle_id: event.cycle_id.clone(),
+                    decision_id: decision.decision_id,
+                    thesis_state: convert_to_proto_thesis_state(decision.thesis_state),
+                    rationale: decision.rationale,
+                    at: Some(timestamp(event.at)),
+                    time_horizon: convert_to_proto_time_horizon(decision.time_horizon),
+                }
+            })
+            .collect(),
+    }
+}
+
 fn convert_proto_order_type(order_type: i32) -> OrderType {
     use super::proto::cream::v1::OrderType as ProtoOrderType;
     match ProtoOrderType::try_from(order_type) {
@@ -667,6 +1069,123 @@ fn convert_to_proto_status(
     }
 }
 
+/// Domain statuses reported as a proto status.
+fn convert_proto_status(
+    status: i32,
+) -> Option<Vec<crate::domain::order_execution::value_objects::OrderStatus>> {
+    use super::proto::cream::v1::OrderStatus as ProtoStatus;
+    use crate::domain::order_execution::value_objects::OrderStatus;
+    let statuses = match ProtoStatus::try_from(status).ok()? {
+        ProtoStatus::Unspecified => return None,
+        ProtoStatus::New => vec![OrderStatus::New],
+        ProtoStatus::Pending => vec![OrderStatus::PendingNew, OrderStatus::PendingCancel],
+        ProtoStatus::Accepted => vec![OrderStatus::Accepted],
+        ProtoStatus::PartialFill => vec![OrderStatus::PartiallyFilled],
+        ProtoStatus::Filled => vec![OrderStatus::Filled],
+        ProtoStatus::Cancelled => vec![OrderStatus::Canceled, OrderStatus::Expired],
+        ProtoStatus::Rejected => vec![OrderStatus::Rejected],
+        ProtoStatus::Expired => vec![OrderStatus::Expired],
+    };
+    Some(statuses)
+}
+
+fn convert_proto_timestamp(ts: prost_types::Timestamp) -> Result<Timestamp, Status> {
+    u32::try_from(ts.nanos)
+        .ok()
+        .and_then(|nanos| chrono::DateTime::from_timestamp(ts.seconds, nanos))
+        .map(Timestamp::new)
+        .ok_or_else(|| invalid_argument("Invalid timestamp"))
+}
+
+fn convert_order_to_proto_state(order: &Order) -> OrderState {
+    use super::proto::cream::v1::{Instrument, InstrumentType};
+
+    let timestamp =
+        |ts: Timestamp| prost_types::Timestamp::from(std::time::SystemTime::from(ts.as_datetime()));
+    let whole = |qty: rust_decimal::Decimal| qty.to_string().parse().unwrap_or(0);
+    let partial_fill = order.partial_fill();
+    let instrument_type = if order.symbol().is_option() {
+        InstrumentType::Option
+    } else {
+        InstrumentType::Equity
+    };
+
+    OrderState {
+        order_id: order.id().to_string(),
+        broker_order_id: order
+            .broker_order_id()
+            .map(ToString::to_string)
+            .unwrap_or_default(),
+        client_order_id: order.id().to_string(),
+        is_multi_leg: order.is_multi_leg(),
+        legs: vec![],
+        status: convert_to_proto_status(order.status()),
+        side: convert_to_proto_side(order.side()),
+        order_type: convert_to_proto_order_type(order.order_type()),
+        instrument: Some(Instrument {
+            instrument_id: order.symbol().to_string(),
+            instrument_type: instrument_type.into(),
+            option_contract: None,
+        }),
+        requested_quantity: whole(order.quantity().amount()),
+        filled_quantity: whole(partial_fill.cum_qty().amount()),
+        avg_fill_price: decimal_to_f64(partial_fill.avg_px().amount()),
+        limit_price: order.limit_price().map(|p| decimal_to_f64(p.amount())),
+        stop_price: order.stop_price().map(|p| decimal_to_f64(p.amount())),
+        time_in_force: convert_to_proto_time_in_force(order.time_in_force()),
+        submitted_at: Some(timestamp(order.created_at())),
+        last_update_at: Some(timestamp(order.updated_at())),
+        commission: decimal_to_f64(
+            partial_fill
+                .fills()
+                .iter()
+                .filter_map(|f| f.commission)
+                .map(|c| c.amount())
+                .sum(),
+        ),
+        cycle_id: order
+            .cycle_id()
+            .map(ToString::to_string)
+            .unwrap_or_default(),
+        status_message: String::new(),
+        child_order_ids: order
+            .child_order_ids()
+            .iter()
+            .map(ToString::to_string)
+            .collect(),
+        fills: partial_fill
+            .fills()
+            .iter()
+            .map(|fill| OrderFill {
+                fill_id: fill.fill_id.clone(),
+                quantity: decimal_to_f64(fill.quantity.amount()),
+                price: decimal_to_f64(fill.price.amount()),
+                venue: fill.venue.clone(),
+                commission: fill.commission.map_or(0.0, |c| decimal_to_f64(c.amount())),
+                timestamp: Some(timestamp(fill.timestamp)),
+            })
+            .collect(),
+        strategy_tag: order.strategy_tag().unwrap_or_default().to_string(),
+    }
+}
+
+fn convert_closed_lot(lot: &DomainClosedLot) -> ClosedLot {
+    let timestamp =
+        |ts: Timestamp| prost_types::Timestamp::from(std::time::SystemTime::from(ts.as_datetime()));
+    ClosedLot {
+        symbol: lot.symbol.clone(),
+        side: convert_to_proto_side(lot.side),
+        quantity: decimal_to_f64(lot.quantity),
+        open_fill_id: lot.open_fill_id.clone(),
+        opened_at: lot.opened_at.map(timestamp),
+        open_price: decimal_to_f64(lot.open_price),
+        close_fill_id: lot.close_fill_id.clone(),
+        closed_at: Some(timestamp(lot.closed_at)),
+        close_price: decimal_to_f64(lot.close_price),
+        realized_pnl: decimal_to_f64(lot.realized_pnl),
+    }
+}
+
 fn convert_to_proto_side(side: OrderSide) -> i32 {
     use super::proto::cream::v1::OrderSide as ProtoSide;
     match side {
@@ -684,6 +1203,19 @@ fn convert_to_proto_order_type(order_type: OrderType) -> i32 {
     }
 }
 
+fn convert_to_proto_time_in_force(time_in_force: TimeInForce) -> i32 {
+    use super::proto::cream::v1::TimeInForce as ProtoTimeInForce;
+    match time_in_force {
+        TimeInForce::Day => ProtoTimeInForce::Day,
+        TimeInForce::Gtc => ProtoTimeInForce::Gtc,
+        TimeInForce::Ioc => ProtoTimeInForce::Ioc,
+        TimeInForce::Fok => ProtoTimeInForce::Fok,
+        TimeInForce::Opg => ProtoTimeInForce::Opg,
+        TimeInForce::Cls => ProtoTimeInForce::Cls,
+    }
+    .into()
+}
+
 #[cfg(test)]
 mod tests {
     #![allow(
@@ -694,13 +1226,13 @@ mod tests {
     use super::*;
     use crate::application::ports::{BrokerError, OrderAck, PositionInfo};
     use crate::domain::order_execution::aggregate::Order;
-    use crate::domain::order_execution::errors::OrderError;
     use crate::domain::order_execution::value_objects::OrderStatus;
     use crate::domain::shared::BrokerId;
     use async_trait::async_trait;
     use rust_decimal::Decimal;
     use std::collections::HashMap;
     use std::sync::RwLock;
+    use tonic_types::StatusExt;
 
     #[allow(dead_code)]
     struct MockBroker;
@@ -717,6 +1249,8 @@ mod tests {
                 status: OrderStatus::Accepted,
                 filled_qty: Decimal::ZERO,
                 avg_fill_price: None,
+                child_order_ids: Vec::new(),
+                leg_fills: Vec::new(),
             })
         }
 
@@ -1090,6 +1624,62 @@ mod tests {
         assert!(inner.as_of.is_some());
     }
 
+    #[tokio::test]
+    async fn get_positions_reports_realized_pnl_per_lot() {
+        use crate::domain::order_execution::value_objects::{FillReport, LotMatching};
+
+        let service = create_test_service();
+        let lifo = RealizedPnlService::new(Arc::clone(&service.order_repo))
+            .with_lot_matching(LotMatching::Lifo);
+        let service = service.with_realized_pnl(Arc::new(lifo));
+        for (side, fill_id, price, at) in [
+            (OrderSide::Buy, "b-1", 100, "2026-03-02T15:00:00Z"),
+            (OrderSide::Buy, "b-2", 110, "2026-03-03T15:00:00Z"),
+            (OrderSide::Sell, "s-1", 120, "2026-03-04T15:00:00Z"),
+        ] {
+            let mut order = Order::new(CreateOrderCommand {
+                symbol: Symbol::new("AAPL"),
+                side,
+                order_type: OrderType::Market,
+                quantity: Quantity::new(Decimal::new(10, 0)),
+                limit_price: None,
+                stop_price: None,
+                time_in_force: TimeInForce::Day,
+                purpose: OrderPurpose::Entry,
+                legs: vec![],
+            })
+            .unwrap();
+            order.accept(BrokerId::new(fill_id)).unwrap();
+            order
+                .apply_fill(FillReport::new(
+                    fill_id,
+                    Quantity::new(Decimal::new(10, 0)),
+                    Money::new(Decimal::new(price, 0)),
+                    Timestamp::parse(at).unwrap(),
+                    "TEST",
+                ))
+                .unwrap();
+            service.order_repo.save(&order).await.unwrap();
+        }
+
+        let inner = service
+            .get_positions(Request::new(GetPositionsRequest {
+                account_id: None,
+                symbols: vec![],
+            }))
+            .await
+            .unwrap()
+            .into_inner();
+
+        assert_eq!(inner.realized_pnl, 100.0);
+        assert_eq!(inner.closed_lots.len(), 1);
+        let lot = &inner.closed_lots[0];
+        assert_eq!(lot.open_fill_id, "b-2");
+        assert_eq!(lot.close_fill_id, "s-1");
+        assert_eq!(lot.quantity, 10.0);
+        assert_eq!(lot.open_price, 110.0);
+    }
+
     #[tokio::test]
     async fn get_order_state_not_found() {
         let service = create_test_service();
@@ -1199,6 +1789,17 @@ mod tests {
             limit_price: None,
             time_in_force: super::super::proto::cream::v1::TimeInForce::Day.into(),
             cycle_id: "cycle-123".to_string(),
+            decision_id: None,
+            thesis_state: 0,
+            rationale: None,
+            time_horizon: 0,
+            strategy_family: 0,
+            max_holding_secs: None,
+            holding_stop_distance: None,
+            scale_out_targets: vec![],
+            exit_stop_loss: None,
+            exit_take_profit: None,
+            strategy_tag: None,
         });
 
         let response = service.submit_order(request).await.unwrap();
@@ -1221,6 +1822,17 @@ mod tests {
             limit_price: None,
             time_in_force: 1,
             cycle_id: "cycle-123".to_string(),
+            decision_id: None,
+            thesis_state: 0,
+            rationale: None,
+            time_horizon: 0,
+            strategy_family: 0,
+            max_holding_secs: None,
+            holding_stop_distance: None,
+            scale_out_targets: vec![],
+            exit_stop_loss: None,
+            exit_take_profit: None,
+            strategy_tag: None,
         });
 
         let result = service.submit_order(request).await;
@@ -1301,6 +1913,7 @@ mod tests {
                     bearish_factors: vec![],
                     legs: vec![],
                     net_limit_price: None,
+                    strategy_tag: None,
                 }],
                 portfolio_notes: None,
             }),
@@ -1362,12 +1975,179 @@ mod tests {
             submit_orders,
             validate_risk,
             cancel_orders,
-            order_repo,
+            Arc::clone(&order_repo),
             broker,
+            Arc::new(PositionLifecycleTracker::new(Arc::new(
+                crate::application::ports::InMemoryPositionLifecycleRepository::new(),
+            ))),
+            Arc::new(RealizedPnlService::new(order_repo)),
         );
         // Successfully created server
     }
 
+    #[tokio::test]
+    async fn get_position_lifecycles_returns_open_positions() {
+        use crate::application::ports::InMemoryPositionLifecycleRepository;
+        use crate::domain::order_execution::value_objects::FillReport;
+
+        let lifecycle = Arc::new(PositionLifecycleTracker::new(Arc::new(
+            InMemoryPositionLifecycleRepository::new(),
+        )));
+        let service = create_test_service().with_position_lifecycle(Arc::clone(&lifecycle));
+
+        let mut order = Order::new(CreateOrderCommand {
+            symbol: Symbol::new("AAPL"),
+            side: OrderSide::Buy,
+            order_type: OrderType::Market,
+            quantity: Quantity::new(Decimal::new(100, 0)),
+            limit_price: None,
+            stop_price: None,
+            time_in_force: TimeInForce::Day,
+            purpose: OrderPurpose::Entry,
+            legs: vec![],
+        })
+        .unwrap();
+        order.accept(BrokerId::new("broker-1")).unwrap();
+        order
+            .apply_fill(FillReport::new(
+                "fill-1",
+                Quantity::new(Decimal::new(100, 0)),
+                Money::new(Decimal::new(150, 0)),
+                Timestamp::now(),
+                "TEST",
+            ))
+            .unwrap();
+        let decision = DecisionContext {
+            decision_id: Some("decision-1".to_string()),
+            thesis_state: Some(ThesisState::Entered),
+            time_horizon: Some(TimeHorizon::Swing),
+            strategy_family: None,
+            strategy_tag: None,
+            max_holding_period: None,
+            scale_out: Vec::new(),
+            rationale: Some("breakout".to_string()),
+        };
+        lifecycle.track_order(&order, Some(decision)).await.unwrap();
+
+        let response = service
+            .get_position_lifecycles(Request::new(GetPositionLifecyclesRequest {
+                symbols: vec!["AAPL".to_string()],
+                include_closed: false,
+            }))
+            .await
+            .unwrap()
+            .into_inner();
+
+        assert_eq!(response.lifecycles.len(), 1);
+        let entry = &response.lifecycles[0].events[0];
+        assert_eq!(entry.decision_id.as_deref(), Some("decision-1"));
+        assert_eq!(entry.rationale.as_deref(), Some("breakout"));
+        assert_eq!(
+            entry.time_horizon,
+            i32::from(super::super::proto::cream::v1::TimeHorizon::Swing)
+        );
+        assert_eq!(entry.fill_ids, vec!["fill-1".to_string()]);
+        assert!((response.lifecycles[0].quantity - 100.0).abs() < f64::EPSILON);
+    }
+
+    #[tokio::test]
+    async fn query_orders_filters_and_pages() {
+        use super::super::proto::cream::v1::OrderStatus as ProtoStatus;
+        use crate::domain::order_execution::value_objects::FillReport;
+
+        let service = create_test_service();
+        for symbol in ["AAPL", "AAPL", "MSFT"] {
+            let mut order = Order::new(CreateOrderCommand {
+                symbol: Symbol::new(symbol),
+                side: OrderSide::Buy,
+                order_type: OrderType::Market,
+                quantity: Quantity::new(Decimal::new(100, 0)),
+                limit_price: None,
+                stop_price: None,
+                time_in_force: TimeInForce::Gtc,
+                purpose: OrderPurpose::Entry,
+                legs: vec![],
+            })
+            .unwrap();
+            order.tag_cycle(CycleId::new("cycle-1"));
+            order
+                .accept(BrokerId::new(format!("broker-{symbol}")))
+                .unwrap();
+            order
+                .apply_fill(
+                    FillReport::new(
+                        "fill-1",
+                        Quantity::new(Decimal::new(40, 0)),
+                        Money::new(Decimal::new(150, 0)),
+                        Timestamp::now(),
+                        "TEST",
+                    )
+                    .with_commission(Money::new(Decimal::new(35, 2))),
+                )
+                .unwrap();
+            service.order_repo.save(&order).await.unwrap();
+        }
+
+        let response = service
+            .query_orders(Request::new(QueryOrdersRequest {
+                symbol: Some("aapl".to_string()),
+                statuses: vec![ProtoStatus::PartialFill.into()],
+                cycle_id: Some("cycle-1".to_string()),
+                limit: Some(1),
+                ..QueryOrdersRequest::default()
+            }))
+            .await
+            .unwrap()
+            .into_inner();
+
+        assert_eq!(response.total, 2);
+        assert_eq!(response.next_offset, Some(1));
+        let order = &response.orders[0];
+        assert_eq!(order.cycle_id, "cycle-1");
+        assert_eq!(order.filled_quantity, 40);
+        assert_eq!(
+            order.time_in_force,
+            i32::from(super::super::proto::cream::v1::TimeInForce::Gtc)
+        );
+        assert_eq!(order.fills[0].fill_id, "fill-1");
+        assert!((order.commission - 0.35).abs() < f64::EPSILON);
+
+        let result = service
+            .query_orders(Request::new(QueryOrdersRequest {
+                limit: Some(5000),
+                ..QueryOrdersRequest::default()
+            }))
+            .await;
+        assert_eq!(result.unwrap_err().code(), tonic::Code::InvalidArgument);
+    }
+
+    #[tokio::test]
+    async fn get_position_lifecycles_requires_tracker() {
+        let service = create_test_service();
+
+        let result = service
+            .get_position_lifecycles(Request::new(GetPositionLifecyclesRequest::default()))
+            .await;
+
+        let status = result.unwrap_err();
+        assert_eq!(status.code(), tonic::Code::Unimplemented);
+        assert_eq!(
+            status.get_details_error_info().unwrap().reason,
+            "LIFECYCLE_TRACKING_DISABLED"
+        );
+    }
+
+    #[test]
+    fn thesis_state_sets_purpose() {
+        use super::super::proto::cream::v1::ThesisState as ProtoThesisState;
+
+        let purpose =
+            |state: ProtoThesisState| purpose_for_thesis(convert_proto_thesis_state(state.into()));
+        assert_eq!(purpose(ProtoThesisState::Unspecified), OrderPurpose::Entry);
+        assert_eq!(purpose(ProtoThesisState::Adding), OrderPurpose::ScaleIn);
+        assert_eq!(purpose(ProtoThesisState::Exiting), OrderPurpose::Exit);
+    }
+
     // Broker that fails get_buying_power
     struct FailingBroker;
 
@@ -1474,7 +2254,10 @@ mod tests {
 
         assert!(result.is_err());
         let status = result.unwrap_err();
-        assert_eq!(status.code(), tonic::Code::Internal);
+        assert_eq!(status.code(), tonic::Code::Unavailable);
+        let info = status.get_details_error_info().unwrap();
+        assert_eq!(info.reason, "BROKER_UNAVAILABLE");
+        assert_eq!(info.metadata["retryable"], "true");
     }
 
     #[tokio::test]
@@ -1496,6 +2279,17 @@ mod tests {
             limit_price: Some(150.50),
             time_in_force: super::super::proto::cream::v1::TimeInForce::Day.into(),
             cycle_id: "cycle-456".to_string(),
+            decision_id: None,
+            thesis_state: 0,
+            rationale: None,
+            time_horizon: 0,
+            strategy_family: 0,
+            max_holding_secs: None,
+            holding_stop_distance: None,
+            scale_out_targets: vec![],
+            exit_stop_loss: None,
+            exit_take_profit: None,
+            strategy_tag: None,
         });
 
         let response = service.submit_order(request).await.unwrap();
@@ -1505,6 +2299,94 @@ mod tests {
         assert_eq!(inner.client_order_id, "client-order-limit");
     }
 
+    fn open_order(cycle_id: &str) -> Order {
+        let mut order = Order::new(CreateOrderCommand {
+            symbol: Symbol::new("AAPL"),
+            side: OrderSide::Buy,
+            order_type: OrderType::Limit,
+            quantity: Quantity::new(Decimal::new(100, 0)),
+            limit_price: Some(Money::usd(150.0)),
+            stop_price: None,
+            time_in_force: TimeInForce::Day,
+            purpose: OrderPurpose::Entry,
+            legs: vec![],
+        })
+        .unwrap();
+        order.tag_cycle(CycleId::new(cycle_id));
+        order
+    }
+
+    #[tokio::test]
+    async fn cancel_plan_cancels_only_the_cycle() {
+        let service = create_test_service();
+        for cycle_id in ["cycle-1", "cycle-1", "cycle-2"] {
+            service
+                .order_repo
+                .save(&open_order(cycle_id))
+                .await
+                .unwrap();
+        }
+
+        let response = service
+            .cancel_plan(Request::new(CancelPlanRequest {
+                cycle_id: "cycle-1".to_string(),
+                reason: None,
+            }))
+            .await
+            .unwrap()
+            .into_inner();
+
+        assert_eq!(response.requested, 2);
+        assert_eq!(response.cancelled, 2);
+        assert_eq!(response.failed, 0);
+        let other = service
+            .order_repo
+            .find_active()
+            .await
+            .unwrap()
+            .into_iter()
+            .find(|o| o.cycle_id().is_some_and(|c| c.as_str() == "cycle-2"))
+            .unwrap();
+        assert!(!other.status().is_terminal());
+
+        let status = service
+            .cancel_plan(Request::new(CancelPlanRequest::default()))
+            .await
+            .unwrap_err();
+        assert_eq!(status.code(), tonic::Code::InvalidArgument);
+    }
+
+    #[tokio::test]
+    async fn cancel_all_reports_failed_cancels() {
+        let service = create_failing_broker_service();
+        for cycle_id in ["cycle-1", "cycle-2"] {
+            service
+                .order_repo
+                .save(&open_order(cycle_id))
+                .await
+                .unwrap();
+        }
+
+        let response = service
+            .cancel_all(Request::new(CancelAllRequest {
+                reason: Some("EOD".to_string()),
+            }))
+            .await
+            .unwrap()
+            .into_inner();
+
+        assert_eq!(response.requested, 2);
+        assert_eq!(response.cancelled, 0);
+        assert_eq!(response.failed, 2);
+        assert!(
+            response.results[0]
+                .error_message
+                .as_deref()
+                .unwrap()
+                .contains("Broker cancel failed")
+        );
+    }
+
     #[tokio::test]
     async fn cancel_order_with_existing_order() {
         use crate::domain::order_execution::aggregate::{CreateOrderCommand, Order};
@@ -1718,6 +2600,7 @@ mod tests {
                         bearish_factors: vec![],
                         legs: vec![],
                         net_limit_price: None,
+                        strategy_tag: None,
                     },
                     Decision {
                         instrument: Some(Instrument {
@@ -1759,6 +2642,7 @@ mod tests {
                         bearish_factors: vec![],
                         legs: vec![],
                         net_limit_price: None,
+                        strategy_tag: None,
                     },
                 ],
                 portfolio_notes: Some("Test portfolio".to_string()),
@@ -1847,6 +2731,7 @@ mod tests {
                     bearish_factors: vec![],
                     legs: vec![],
                     net_limit_price: None,
+                    strategy_tag: None,
                 }],
                 portfolio_notes: None,
             }),
@@ -0,0 +1,423 @@
+//! gRPC Server TLS
+//!
+//! Terminates TLS, optionally with client certificate verification (mTLS),
+//! in front of the tonic server. Certificate, key, and client CA files are
+//! polled for changes so rotated certificates are picked up by new
+//! connections without a restart; established connections keep their
+//! session.
+//!
+//! # Environment Variables
+//!
+//! - `GRPC_TLS_CERT_PATH`: PEM server certificate chain (enables TLS)
+//! - `GRPC_TLS_KEY_PATH`: PEM server private key (required with cert)
+//! - `GRPC_TLS_CLIENT_CA_PATH`: PEM CA bundle; when set, clients must
+//!   present a certificate signed by it
+//! - `GRPC_TLS_RELOAD_SECS`: Certificate change poll interval (default: 60)
+
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, RwLock};
+use std::time::{Duration, SystemTime};
+
+use rustls::pki_types::pem::PemObject;
+use rustls::pki_types::{CertificateDer, PrivateKeyDer};
+use rustls::server::WebPkiClientVerifier;
+use rustls::{RootCertStore, ServerConfig};
+use tokio::net::{TcpListener, TcpStream};
+use tokio::sync::mpsc;
+use tokio_rustls::TlsAcceptor;
+use tokio_rustls::server::TlsStream;
+use tokio_stream::wrappers::ReceiverStream;
+use tokio_util::sync::CancellationToken;
+
+/// Default certificate change poll interval.
+const DEFAULT_RELOAD_SECS: u64 = 60;
+
+/// Maximum time allowed for a TLS handshake.
+const HANDSHAKE_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// TLS configuration for a gRPC server.
+#[derive(Debug, Clone)]
+pub struct GrpcTlsConfig {
+    /// PEM certificate chain path.
+    pub cert_path: PathBuf,
+    /// PEM private key path.
+    pub key_path: PathBuf,
+    /// PEM client CA bundle path; enables mutual TLS when set.
+    pub client_ca_path: Option<PathBuf>,
+    /// How often certificate files are checked for changes.
+    pub reload_interval: Duration,
+}
+
+impl GrpcTlsConfig {
+    /// Load TLS configuration from environment variables.
+    ///
+    /// Returns `None` when `GRPC_TLS_CERT_PATH` is unset (plaintext).
+    ///
+    /// # Errors
+    ///
+    /// Returns error if the certificate is set without a key.
+    pub fn from_env() -> Result<Option<Self>, TlsError> {
+        let Some(cert_path) = non_empty_env("GRPC_TLS_CERT_PATH") else {
+            return Ok(None);
+        };
+        let key_path = non_empty_env("GRPC_TLS_KEY_PATH").ok_or_else(|| TlsError::Config {
+            message: "GRPC_TLS_KEY_PATH is required when GRPC_TLS_CERT_PATH is set".to_string(),
+        })?;
+        let reload_secs = std::env::var("GRPC_TLS_RELOAD_SECS")
+            .ok()
+            .and_then(|v| v.parse().ok())
+            .unwrap_or(DEFAULT_RELOAD_SECS);
+
+        Ok(Some(Self {
+            cert_path: cert_path.into(),
+            key_path: key_path.into(),
+            client_ca_path: non_empty_env("GRPC_TLS_CLIENT_CA_PATH").map(PathBuf::from),
+            reload_interval: Duration::from_secs(reload_secs.max(1)),
+        }))
+    }
+
+    /// Whether client certificates are required.
+    #[must_use]
+    pub const fn is_mutual(&self) -> bool {
+        self.client_ca_path.is_some()
+    }
+
+    fn paths(&self) -> impl Iterator<Item = &Path> {
+        [
+            Some(&self.cert_path),
+            Some(&self.key_path),
+            self.client_ca_path.as_ref(),
+        ]
+        .into_iter()
+        .flatten()
+        .map(PathBuf::as_path)
+    }
+}
+
+fn non_empty_env(key: &str) -> Option<String> {
+    std::env::var(key).ok().filter(|v| !v.trim().is_empty())
+}
+
+/// TLS setup errors.
+#[derive(Debug, thiserror::Error)]
+pub enum TlsError {
+    /// Invalid configuration.
+    #[error("TLS configuration error: {message}")]
+    Config {
+        /// Error details.
+        message: String,
+    },
+
+    /// A PEM file could not be read or parsed.
+    #[error("Failed to load {path}: {message}")]
+    Pem {
+        /// File path.
+        path: String,
+        /// Error details.
+        message: String,
+    },
+
+    /// The certificates or key were rejected by rustls.
+    #[error("Invalid TLS material: {message}")]
+    Invalid {
+        /// Error details.
+        message: String,
+    },
+}
+
+/// TLS acceptor whose certificates can be reloaded while serving.
+pub struct ReloadingTlsAcceptor {
+    config: GrpcTlsConfig,
+    server_config: RwLock<Arc<ServerConfig>>,
+    modified: RwLock<Vec<Option<SystemTime>>>,
+}
+
+impl ReloadingTlsAcceptor {
+    /// Load certificates and create the acceptor.
+    ///
+    /// # Errors
+    ///
+    /// Returns error if the certificate, key, or client CA cannot be loaded.
+    pub fn new(config: GrpcTlsConfig) -> Result<Self, TlsError> {
+        let server_config = load_server_config(&config)?;
+        let modified = modified_times(&config);
+        Ok(Self {
+            config,
+            server_config: RwLock::new(Arc::new(server_config)),
+            modified: RwLock::new(modified),
+        })
+    }
+
+    /// Acceptor for the current certificates.
+    #[must_use]
+    pub fn acceptor(&self) -> TlsAcceptor {
+        let server_config = self
+            .server_config
+            .read()
+            .unwrap_or_else(std::sync::PoisonError::into_inner);
+        TlsAcceptor::from(Arc::clone(&server_config))
+    }
+
+    /// Reload certificates if any file changed since the last load.
+    ///
+    /// Returns `true` if new certificates were installed. On error the
+    /// previous certificates stay in use.
+    ///
+    /// # Errors
+    ///
+    /// Returns error if changed files cannot be loaded.
+    pub fn reload_if_changed(&self) -> Result<bool, TlsError> {
+        let current = modified_times(&self.config);
+        {
+            let modified = self
+                .modified
+                .read()
+                .unwrap_or_else(std::sync::PoisonError::into_inner);
+            if *modified == current {
+                return Ok(false);
+            }
+        }
+
+        let server_config = load_server_config(&self.config)?;
+        *self
+            .server_config
+            .write()
+            .unwrap_or_else(std::sync::PoisonError::into_inner) = Arc::new(server_config);
+        *self
+            .modified
+            .write()
+            .unwrap_or_else(std::sync::PoisonError::into_inner) = current;
+        Ok(true)
+    }
+
+    /// Start polling certificate files for changes.
+    pub fn start(self: &Arc<Self>, shutdown: CancellationToken) {
+        let acceptor = Arc::clone(self);
+        tokio::spawn(async move {
+            let mut interval = tokio::time::interval(acceptor.config.reload_interval);
+            interval.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Skip);
+
+            loop {
+                tokio::select! {
+                    _ = interval.tick() => match acceptor.reload_if_changed() {
+                        Ok(true) => tracing::info!(
+                            cert = %acceptor.config.cert_path.display(),
+                            "Reloaded gRPC TLS certificates"
+                        ),
+                        Ok(false) => {}
+                        Err(e) => tracing::error!(
+                            error = %e,
+                            "Failed to reload gRPC TLS certificates, keeping current"
+                        ),
+                    },
+                    () = shutdown.cancelled() => break,
+                }
+            }
+        });
+    }
+
+    /// Accept TCP connections on `listener` and yield completed TLS streams.
+    ///
+    /// Handshakes run concurrently so a slow or failing client cannot stall
+    /// other connections; failed handshakes are logged and dropped.
+    #[must_use]
+    pub fn incoming(
+        self: &Arc<Self>,
+        listener: TcpListener,
+        shutdown: CancellationToken,
+    ) -> ReceiverStream<Result<TlsStream<TcpStream>, std::io::Error>> {
+        let (tx, rx) = mpsc::channel(64);
+        let acceptor = Arc::clone(self);
+
+        tokio::spawn(async move {
+            loop {
+                let (stream, peer) = tokio::select! {
+                    accepted = listener.accept() => match accepted {
+                        Ok(accepted) => accepted,
+                        Err(e) => {
+                            tracing::warn!(error = %e, "gRPC TCP accept failed");
+                            continue;
+                        }
+                    },
+                    () = shutdown.cancelled() => break,
+                };
+
+                let tls = acceptor.acceptor();
+                let tx = tx.clone();
+                tokio::spawn(async move {
+                    match tokio::time::timeout(HANDSHAKE_TIMEOUT, tls.accept(stream)).await {
+                        Ok(Ok(stream)) => {
+                            let _ = tx.send(Ok(stream)).await;
+                        }
+                        Ok(Err(e)) => {
+                            tracing::debug!(%peer, error = %e, "gRPC TLS handshake failed");
+                        }
+                        Err(_) => {
+                            tracing::debug!(%peer, "gRPC TLS handshake timed out");
+                        }
+                    }
+                });
+            }
+        });
+
+        ReceiverStream::new(rx)
+    }
+}
+
+fn modified_times(config: &GrpcTlsConfig) -> Vec<Option<SystemTime>> {
+    config
+        .paths()
+        .map(|path| std::fs::metadata(path).and_then(|m| m.modified()).ok())
+        .collect()
+}
+
+fn load_certs(path: &Path) -> Result<Vec<CertificateDer<'static>>, TlsError> {
+    let certs = CertificateDer::pem_file_iter(path)
+        .and_then(Iterator::collect::<Result<Vec<_>, _>>)
+        .map_err(|e| pem_error(path, &e))?;
+    if certs.is_empty() {
+        return Err(TlsError::Pem {
+            path: path.display().to_string(),
+            message: "no certificates found".to_string(),
+        });
+    }
+    Ok(certs)
+}
+
+fn load_server_config(config: &GrpcTlsConfig) -> Result<ServerConfig, TlsError> {
+    let certs = load_certs(&config.cert_path)?;
+    let key = PrivateKeyDer::from_pem_file(&config.key_path)
+        .map_err(|e| pem_error(&config.key_path, &e))?;
+
+    // Pin ring rather than relying on a process-wide default provider
+    let provider = Arc::new(rustls::crypto::ring::default_provider());
+    let builder = ServerConfig::builder_with_provider(Arc::clone(&provider))
+        .with_safe_default_protocol_versions()
+        .map_err(invalid)?;
+    let builder = match &config.client_ca_path {
+        Some(ca_path) => {
+            let mut roots = RootCertStore::empty();
+            for cert in load_certs(ca_path)? {
+                roots.add(cert).map_err(invalid)?;
+            }
+            let verifier = WebPkiClientVerifier::builder_with_provider(Arc::new(roots), provider)
+                .build()
+                .map_err(invalid)?;
+            builder.with_client_cert_verifier(verifier)
+        }
+        None => builder.with_no_client_auth(),
+    };
+
+    let mut server_config = builder.with_single_cert(certs, key).map_err(invalid)?;
+    server_config.alpn_protocols = vec![b"h2".to_vec()];
+    Ok(server_config)
+}
+
+fn pem_error(path: &Path, error: &impl std::fmt::Display) -> TlsError {
+    TlsError::Pem {
+        path: path.display().to_string(),
+        message: error.to_string(),
+    }
+}
+
+fn invalid(error: impl std::fmt::Display) -> TlsError {
+    TlsError::Invalid {
+        message: error.to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn write_cert(dir: &Path, name: &str) -> (PathBuf, PathBuf) {
+        let generated = rcgen::generate_simple_self_signed(vec!["localhost".to_string()]).unwrap();
+        let cert_path = dir.join(format!("{name}.crt"));
+        let key_path = dir.join(format!("{name}.key"));
+        std::fs::write(&cert_path, generated.cert.pem()).unwrap();
+        std::fs::write(&key_path, generated.signing_key.serialize_pem()).unwrap();
+        (cert_path, key_path)
+    }
+
+    fn make_config(cert_path: PathBuf, key_path: PathBuf) -> GrpcTlsConfig {
+        GrpcTlsConfig {
+            cert_path,
+            key_path,
+            client_ca_path: None,
+            reload_interval: Duration::from_secs(1),
+        }
+    }
+
+    #[test]
+    fn loads_server_certificate() {
+        let dir = tempfile::tempdir().unwrap();
+        let (cert_path, key_path) = write_cert(dir.path(), "server");
+
+        let acceptor = ReloadingTlsAcceptor::new(make_config(cert_path, key_path)).unwrap();
+
+        assert!(!acceptor.reload_if_changed().unwrap());
+    }
+
+    #[test]
+    fn loads_client_ca_for_mutual_tls() {
+        let dir = tempfile::tempdir().unwrap();
+        let (cert_path, key_path) = write_cert(dir.path(), "server");
+        let (ca_path, _) = write_cert(dir.path(), "ca");
+        let config = GrpcTlsConfig {
+            client_ca_path: Some(ca_path),
+            ..make_config(cert_path, key_path)
+        };
+
+        assert!(config.is_mutual());
+        assert!(ReloadingTlsAcceptor::new(config).is_ok());
+    }
+
+    #[test]
+    fn rejects_missing_key() {
+        let dir = tempfile::tempdir().unwrap();
+        let (cert_path, _) = write_cert(dir.path(), "server");
+
+        let result = ReloadingTlsAcceptor::new(make_config(cert_path, dir.path().join("none.key")));
+
+        assert!(matches!(result, Err(TlsError::Pem { .. })));
+    }
+
+    #[test]
+    fn reloads_rotated_certificate() {
+        let dir = tempfile::tempdir().unwrap();
+        let (cert_path, key_path) = write_cert(dir.path(), "server");
+        let acceptor = ReloadingTlsAcceptor::new(make_config(cert_path.clone(), key_path)).unwrap();
+
+        // Force a distinct mtime regardless of filesystem timestamp resolution
+        write_cert(dir.path(), "server");
+        let later = SystemTime::now() + Duration::from_secs(5);
+        std::fs::File::options()
+            .write(true)
+            .open(&cert_path)
+            .unwrap()
+            .set_modified(later)
+            .unwrap();
+
+        assert!(acceptor.reload_if_changed().unwrap());
+        assert!(!acceptor.reload_if_changed().unwrap());
+    }
+
+    #[test]
+    fn failed_reload_keeps_previous_certificate() {
+        let dir = tempfile::tempdir().unwrap();
+        let (cert_path, key_path) = write_cert(dir.path(), "server");
+        let acceptor = ReloadingTlsAcceptor::new(make_config(cert_path.clone(), key_path)).unwrap();
+
+        std::fs::write(&cert_path, "not a certificate").unwrap();
+        let later = SystemTime::now() + Duration::from_secs(5);
+        std::fs::File::options()
+            .write(true)
+            .open(&cert_path)
+            .unwrap()
+            .set_modified(later)
+            .unwrap();
+
+        assert!(acceptor.reload_if_changed().is_err());
+        let _ = acceptor.acceptor();
+    }
+}
@@ -0,0 +1,218 @@
+//! Broker adapter that only writes on the leader.
+
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use rust_decimal::Decimal;
+
+use crate::application::ports::{
+    AccountActivity, AccountActivityPort, AssetInfo, BrokerError, BrokerPort, CancelOrderRequest,
+    MarketClock, OrderAck, PositionInfo, SubmitOrderRequest,
+};
+use crate::application::services::LeaderElection;
+use crate::domain::shared::{BrokerId, InstrumentId, Symbol, Timestamp};
+use crate::infrastructure::broker::AlpacaError;
+use crate::infrastructure::secrets::{AlpacaCredentials, CredentialSink};
+
+/// Broker adapter wrapper that refuses order writes on a standby.
+///
+/// Submissions and cancellations fail with [`BrokerError::NotLeader`] while
+/// the election has not made this instance leader; reads always pass
+/// through, so a standby still serves queries and follows fills. Without an
+/// election every call passes straight through.
+#[derive(Debug, Clone)]
+pub struct LeaderGatedBroker<B> {
+    inner: B,
+    election: Option<Arc<LeaderElection>>,
+}
+
+impl<B> LeaderGatedBroker<B> {
+    /// Wrap `inner`, writing only while `election` says this is the leader.
+    #[must_use]
+    pub const fn new(inner: B, election: Arc<LeaderElection>) -> Self {
+        Self {
+            inner,
+            election: Some(election),
+        }
+    }
+
+    /// Wrap `inner` for a single-instance deployment.
+    #[must_use]
+    pub const fn passthrough(inner: B) -> Self {
+        Self {
+            inner,
+            election: None,
+        }
+    }
+
+    /// The wrapped adapter.
+    #[must_use]
+    pub const fn inner(&self) -> &B {
+        &self.inner
+    }
+
+    fn check_leader(&self) -> Result<(), BrokerError> {
+        match &self.election {
+            Some(election) if !election.is_leader() => Err(BrokerError::NotLeader),
+            _ => Ok(()),
+        }
+    }
+}
+
+#[async_trait]
+impl<B: BrokerPort> BrokerPort for LeaderGatedBroker<B> {
+    async fn submit_order(&self, request: SubmitOrderRequest) -> Result<OrderAck, BrokerError> {
+        self.check_leader()?;
+        self.inner.submit_order(request).await
+    }
+
+    async fn cancel_order(&self, request: CancelOrderRequest) -> Result<(), BrokerError> {
+        self.check_leader()?;
+        self.inner.cancel_order(request).await
+    }
+
+    async fn get_order(&self, broker_order_id: &BrokerId) -> Result<OrderAck, BrokerError> {
+        self.inner.get_order(broker_order_id).await
+    }
+
+    async fn get_open_orders(&self) -> Result<Vec<OrderAck>, BrokerError> {
+        self.inner.get_open_orders().await
+    }
+
+    async fn get_buying_power(&self) -> Result<Decimal, BrokerError> {
+        self.inner.get_buying_power().await
+    }
+
+    async fn get_position(
+        &self,
+        instrument_id: &InstrumentId,
+    ) -> Result<Option<Decimal>, BrokerError> {
+        self.inner.get_position(instrument_id).await
+    }
+
+    async fn get_all_positions(&self) -> Result<Vec<PositionInfo>, BrokerError> {
+        self.inner.get_all_positions().await
+    }
+
+    async fn get_asset(&self, symbol: &Symbol) -> Result<Option<AssetInfo>, BrokerError> {
+        self.inner.get_asset(symbol).await
+    }
+
+    async fn list_assets(&self) -> Result<Vec<AssetInfo>, BrokerError> {
+        self.inner.list_assets().await
+    }
+
+    async fn get_market_clock(&self) -> Result<Option<MarketClock>, BrokerError> {
+        self.inner.get_market_clock().await
+    }
+}
+
+#[async_trait]
+impl<B: AccountActivityPort> AccountActivityPort for LeaderGatedBroker<B> {
+    async fn get_activities(
+        &self,
+        after: Option<Timestamp>,
+    ) -> Result<Vec<AccountActivity>, BrokerError> {
+        self.inner.get_activities(after).await
+    }
+}
+
+impl<B: CredentialSink> CredentialSink for LeaderGatedBroker<B> {
+    fn name(&self) -> &'static str {
+        self.inner.name()
+    }
+
+    fn rotate_credentials(&self, credentials: &AlpacaCredentials) -> Result<(), AlpacaError> {
+        self.inner.rotate_credentials(credentials)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::application::ports::{InMemoryLeaderLock, LeaderLockPort};
+    use crate::application::services::LeaderElectionConfig;
+    use crate::domain::order_execution::value_objects::{OrderSide, OrderStatus};
+    use crate::domain::shared::OrderId;
+    use std::time::Duration;
+
+    struct AcceptingBroker;
+
+    #[async_trait]
+    impl BrokerPort for AcceptingBroker {
+        async fn submit_order(&self, request: SubmitOrderRequest) -> Result<OrderAck, BrokerError> {
+            Ok(OrderAck {
+                broker_order_id: BrokerId::new("broker-1"),
+                client_order_id: request.client_order_id,
+                status: OrderStatus::New,
+                filled_qty: Decimal::ZERO,
+                avg_fill_price: None,
+                child_order_ids: Vec::new(),
+                leg_fills: Vec::new(),
+            })
+        }
+
+        async fn cancel_order(&self, _request: CancelOrderRequest) -> Result<(), BrokerError> {
+            Ok(())
+        }
+
+        async fn get_order(&self, _broker_order_id: &BrokerId) -> Result<OrderAck, BrokerError> {
+            Err(BrokerError::OrderNotFound {
+                order_id: "broker-1".to_string(),
+            })
+        }
+
+        async fn get_open_orders(&self) -> Result<Vec<OrderAck>, BrokerError> {
+            Ok(vec![])
+        }
+
+        async fn get_buying_power(&self) -> Result<Decimal, BrokerError> {
+            Ok(Decimal::ONE_HUNDRED)
+        }
+
+        async fn get_position(
+            &self,
+            _instrument_id: &InstrumentId,
+        ) -> Result<Option<Decimal>, BrokerError> {
+            Ok(None)
+        }
+
+        async fn get_all_positions(&self) -> Result<Vec<PositionInfo>, BrokerError> {
+            Ok(vec![])
+        }
+    }
+
+    #[tokio::test]
+    async fn standby_reads_but_does_not_write() {
+        let lock = InMemoryLeaderLock::new();
+        let leader = lock.contender();
+        assert!(leader.try_acquire().await.unwrap());
+        let election = Arc::new(LeaderElection::new(
+            Arc::new(lock),
+            LeaderElectionConfig {
+                heartbeat: Duration::from_millis(10),
+                takeover_delay: Duration::ZERO,
+            },
+        ));
+        let broker = LeaderGatedBroker::new(AcceptingBroker, Arc::clone(&election));
+        let request = SubmitOrderRequest::market(
+            OrderId::new("ord-1"),
+            Symbol::new("AAPL"),
+            OrderSide::Buy,
+            Decimal::ONE,
+        );
+
+        assert!(matches!(
+            broker.submit_order(request.clone()).await,
+            Err(BrokerError::NotLeader)
+        ));
+        assert_eq!(
+            broker.get_buying_power().await.unwrap(),
+            Decimal::ONE_HUNDRED
+        );
+
+        leader.release().await.unwrap();
+        election.campaign(|| async { Ok(()) }).await;
+        assert!(broker.submit_order(request).await.is_ok());
+    }
+}
@@ -0,0 +1,16 @@
+//! High Availability
+//!
+//! Leader election for running a standby engine next to the leader on the
+//! same account.
+//!
+//! [`PostgresAdvisoryLock`] implements the leader lock as a Postgres
+//! session-level advisory lock, held on a dedicated connection; if the
+//! leader dies or loses its connection, Postgres releases the lock.
+//! [`LeaderGatedBroker`] wraps the broker adapter and refuses order writes
+//! while the instance is a standby, so no code path can trade from it.
+
+mod gated_broker;
+mod postgres_lock;
+
+pub use gated_broker::LeaderGatedBroker;
+pub use postgres_lock::PostgresAdvisoryLock;
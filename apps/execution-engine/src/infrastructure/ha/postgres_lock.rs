@@ -0,0 +1,101 @@
+//! Postgres advisory lock leader lock.
+
+use async_trait::async_trait;
+use sqlx::{Connection, PgConnection};
+use tokio::sync::Mutex;
+
+use crate::application::ports::{LeaderLockError, LeaderLockPort};
+
+/// Leader lock held as a session-level Postgres advisory lock.
+///
+/// The lock lives as long as the connection, so the connection must reach
+/// Postgres directly rather than through a transaction-pooling proxy.
+pub struct PostgresAdvisoryLock {
+    url: String,
+    key: i64,
+    connection: Mutex<Option<PgConnection>>,
+}
+
+impl std::fmt::Debug for PostgresAdvisoryLock {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        // The URL carries the database password
+        f.debug_struct("PostgresAdvisoryLock")
+            .field("key", &self.key)
+            .finish_non_exhaustive()
+    }
+}
+
+impl PostgresAdvisoryLock {
+    /// Create a lock on advisory lock `key` in the database at `url`.
+    ///
+    /// Nothing is connected until the lock is first tried.
+    #[must_use]
+    pub fn new(url: impl Into<String>, key: i64) -> Self {
+        Self {
+            url: url.into(),
+            key,
+            connection: Mutex::new(None),
+        }
+    }
+}
+
+#[async_trait]
+impl LeaderLockPort for PostgresAdvisoryLock {
+    async fn try_acquire(&self) -> Result<bool, LeaderLockError> {
+        let mut connection = self.connection.lock().await;
+        let conn = match connection.as_mut() {
+            Some(conn) => conn,
+            None => connection.insert(
+                PgConnection::connect(&self.url)
+                    .await
+                    .map_err(|e| unavailable(&e))?,
+            ),
+        };
+
+        let acquired = sqlx::query_scalar::<_, bool>("SELECT pg_try_advisory_lock($1)")
+            .bind(self.key)
+            .fetch_one(conn)
+            .await;
+        if acquired.is_err() {
+            // Start over on a fresh session next time
+            *connection = None;
+        }
+        drop(connection);
+        acquired.map_err(|e| unavailable(&e))
+    }
+
+    async fn is_held(&self) -> Result<bool, LeaderLockError> {
+        let mut connection = self.connection.lock().await;
+        let Some(conn) = connection.as_mut() else {
+            return Ok(false);
+        };
+
+        // Session locks are only released by unlocking or by the session
+        // ending, so a live session still holds the lock
+        let alive = sqlx::query("SELECT 1").execute(conn).await;
+        if alive.is_err() {
+            *connection = None;
+        }
+        drop(connection);
+        alive.map(|_| true).map_err(|e| unavailable(&e))
+    }
+
+    async fn release(&self) -> Result<(), LeaderLockError> {
+        let Some(mut conn) = self.connection.lock().await.take() else {
+            return Ok(());
+        };
+        let unlocked = sqlx::query_scalar::<_, bool>("SELECT pg_advisory_unlock($1)")
+            .bind(self.key)
+            .fetch_one(&mut conn)
+            .await;
+        // Closing the session releases the lock even if the unlock failed
+        let _ = conn.close().await;
+        unlocked.map(|_| ()).map_err(|e| unavailable(&e))
+    }
+}
+
+fn unavailable(error: &sqlx::Error) -> LeaderLockError {
+    LeaderLockError::Unavailable {
+        message: error.to_string(),
+    }
+}
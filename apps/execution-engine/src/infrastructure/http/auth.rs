@@ -0,0 +1,59 @@
+//! HTTP authentication middleware.
+
+use std::sync::Arc;
+
+use axum::{
+    extract::{Request, State},
+    middleware::Next,
+    response::{IntoResponse, Response},
+};
+
+use crate::infrastructure::api_error::ApiError;
+use crate::infrastructure::auth::{ApiKeyAuthenticator, Role, extract_api_key};
+
+/// Role requirement for a group of routes.
+#[derive(Clone)]
+pub struct RouteAuth {
+    authenticator: Option<Arc<ApiKeyAuthenticator>>,
+    required: Role,
+}
+
+impl RouteAuth {
+    /// Require `required` on routes guarded by this value. Authentication is
+    /// skipped when no authenticator is configured.
+    #[must_use]
+    pub const fn new(authenticator: Option<Arc<ApiKeyAuthenticator>>, required: Role) -> Self {
+        Self {
+            authenticator,
+            required,
+        }
+    }
+}
+
+/// Reject requests whose API key does not grant the route's role.
+///
+/// On success the caller's `Principal` is added to request extensions.
+pub async fn require_role(
+    State(route): State<RouteAuth>,
+    mut request: Request,
+    next: Next,
+) -> Response {
+    let Some(authenticator) = &route.authenticator else {
+        return next.run(request).await;
+    };
+
+    match authenticator.authorize(extract_api_key(request.headers()), route.required) {
+        Ok(principal) => {
+            request.extensions_mut().insert(principal);
+            next.run(request).await
+        }
+        Err(e) => {
+            tracing::warn!(
+                path = %request.uri().path(),
+                error = %e,
+                "HTTP request rejected"
+            );
+            ApiError::from_error(&e).into_response()
+        }
+    }
+}
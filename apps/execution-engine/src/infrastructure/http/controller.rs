@@ -6,36 +6,67 @@ use std::sync::Arc;
 
 use axum::{
     Json, Router,
-    extract::State,
-    http::StatusCode,
-    response::IntoResponse,
-    routing::{get, post},
+    extract::{Path, Query, State},
+    http::{StatusCode, header},
+    middleware,
+    response::{IntoResponse, Response},
+    routing::{delete, get, post},
 };
 
 use crate::application::dto::{CreateOrderDto, OrderDto, SubmitOrdersRequestDto};
-use crate::application::ports::{BrokerPort, EventPublisherPort, RiskRepositoryPort};
+use crate::application::ports::{
+    BrokerPort, EventPublisherPort, ExecutionWindow, RiskRepositoryPort, ScheduledPlan,
+    ScheduledPlanRepositoryPort, Watchlist, WatchlistError,
+};
+use crate::application::services::{
+    BreakerStatus, BrokerAccountService, DecisionStatus, Feature, FeatureFlags, KillSwitch,
+    PlanProgressService, PlanSchedulerService, ReadinessService, SessionStatsService,
+    ShadowExecution, StopAdjuster, StopAdjustment, TradeBlotter, WatchlistService,
+};
 use crate::application::use_cases::{
-    CancelOrdersUseCase, SubmitOrdersUseCase, ValidateRiskUseCase,
+    CancelOrdersUseCase, CycleTransitionUseCase, SignedStateBundle, StateBundleUseCase,
+    SubmitOrdersUseCase, ValidateRiskUseCase,
 };
 use crate::domain::order_execution::repository::OrderRepository;
 use crate::domain::order_execution::value_objects::CancelReason;
-use crate::domain::shared::OrderId;
-
+use crate::domain::shared::{CycleId, ErrorKind, OrderId, Symbol, Timestamp};
+use crate::infrastructure::api_error::ApiError;
+use crate::infrastructure::auth::{ApiKeyAuthenticator, Role};
+use crate::infrastructure::config::ConfigReloader;
+use crate::infrastructure::export::encode_blotter;
+use crate::infrastructure::metrics::get_metrics_handle;
+
+use super::auth::{RouteAuth, require_role};
+use super::idempotency::{IdempotencyStore, idempotency};
+use super::openapi;
 use super::request::{
-    CancelOrdersRequest, CheckConstraintsRequest, GetOrderStateRequest, SubmitOrdersRequest,
+    AdjustStopsRequest, CancelOrdersRequest, CheckConstraintsRequest, CircuitBreakerRequest,
+    CreateWatchlistRequest, ExportBlotterRequest, FeatureFlagRequest, GetOrderStateRequest,
+    ImportStateRequest, KillSwitchRequest, OAuthCallbackQuery, QueryOrdersRequest,
+    SubmitOrdersRequest, WatchlistSymbolsRequest,
 };
 use super::response::{
-    CancelOrdersResponse, CancelResult, CheckConstraintsResponse, GetOrderStateResponse,
-    HealthResponse, OrderConstraintResult, OrderResponse, SubmitOrdersResponse, ViolationResponse,
+    AdjustStopsResponse, ApiErrorResponse, BrokerAccountResponse, BrokerAccountsResponse,
+    BrokerAuthorizationResponse, CacheResponse, CancelOrdersResponse, CancelResult,
+    CheckConstraintsResponse, CircuitBreakerResponse, CircuitBreakersResponse, ClockSyncResponse,
+    ConfigChangeResponse, ConfigReloadResponse, DecisionProgressResponse, EventOutboxResponse,
+    ExposureResponse, FeatureFlagResponse, FeatureFlagsResponse, FeedResponse,
+    GetOrderStateResponse, HealthResponse, KillSwitchResponse, OrderActivityResponse,
+    OrderConstraintResult, OrderHistoryResponse, OrderResponse, PlanProgressResponse,
+    ReadinessResponse, ReconciliationResponse, RejectedTicksResponse, SessionStatsResponse,
+    ShadowExecutionResponse, StateBundleResponse, StateConflictResponse, StateImportResponse,
+    SubmitOrdersResponse, ViolationResponse, WatchlistResponse, WatchlistsResponse,
 };
+use super::validation::ValidJson;
 
 /// Application state shared across handlers.
-pub struct AppState<B, R, O, E>
+pub struct AppState<B, R, O, E, S>
 where
     B: BrokerPort,
     R: RiskRepositoryPort,
     O: OrderRepository,
     E: EventPublisherPort,
+    S: ScheduledPlanRepositoryPort,
 {
     /// Use case for submitting orders.
     pub submit_orders: Arc<SubmitOrdersUseCase<B, R, O, E>>,
@@ -43,54 +74,194 @@ where
     pub validate_risk: Arc<ValidateRiskUseCase<R, O>>,
     /// Use case for canceling orders.
     pub cancel_orders: Arc<CancelOrdersUseCase<B, O, E>>,
+    /// Cleans up working orders from previous cycles when a new plan arrives.
+    pub cycle_transition: Arc<CycleTransitionUseCase<B, O, E>>,
+    /// Scheduler for plans with a delayed execution window.
+    pub plan_scheduler: Arc<PlanSchedulerService<B, R, O, E, S>>,
+    /// Dependency-aware readiness checks.
+    pub readiness: Arc<ReadinessService<B, S>>,
+    /// Operator trip-wire that disables trading.
+    pub kill_switch: Arc<KillSwitch>,
+    /// Feature flags, changed at runtime through the admin API.
+    pub feature_flags: Arc<FeatureFlags>,
+    /// Session activity, exposure and component health.
+    pub stats: Arc<SessionStatsService<O>>,
+    /// Per-cycle execution progress.
+    pub plan_progress: Arc<PlanProgressService<O>>,
+    /// Fills for accounting exports.
+    pub trade_blotter: Arc<TradeBlotter<O>>,
+    /// Moves stop and target levels of open positions.
+    pub stop_adjuster: Arc<StopAdjuster<B, R, O, E>>,
+    /// Shadow execution configuration (`None` = no `execution.shadow`).
+    pub shadow_execution: Option<Arc<ShadowExecution>>,
+    /// Reloads tunable settings from `config.yaml` (`None` = no config file).
+    pub config_reloader: Option<Arc<ConfigReloader<R>>>,
+    /// Signed state export and import (`None` = no `STATE_BUNDLE_KEY`).
+    pub state_bundle: Option<Arc<StateBundleUseCase<B, O>>>,
+    /// Symbols kept subscribed on the quote feed.
+    pub watchlists: Arc<WatchlistService>,
+    /// Broker accounts connected over OAuth (`None` = no OAuth client).
+    pub broker_accounts: Option<Arc<BrokerAccountService>>,
     /// Order repository for queries.
     pub order_repo: Arc<O>,
+    /// API key authenticator (`None` disables authentication).
+    pub auth: Option<Arc<ApiKeyAuthenticator>>,
+    /// Stored responses for requests carrying an `Idempotency-Key`.
+    pub idempotency: Arc<IdempotencyStore>,
     /// Application version.
     pub version: String,
 }
 
-impl<B, R, O, E> Clone for AppState<B, R, O, E>
+impl<B, R, O, E, S> Clone for AppState<B, R, O, E, S>
 where
     B: BrokerPort,
     R: RiskRepositoryPort,
     O: OrderRepository,
     E: EventPublisherPort,
+    S: ScheduledPlanRepositoryPort,
 {
     fn clone(&self) -> Self {
         Self {
             submit_orders: Arc::clone(&self.submit_orders),
             validate_risk: Arc::clone(&self.validate_risk),
             cancel_orders: Arc::clone(&self.cancel_orders),
+            cycle_transition: Arc::clone(&self.cycle_transition),
+            plan_scheduler: Arc::clone(&self.plan_scheduler),
+            readiness: Arc::clone(&self.readiness),
+            kill_switch: Arc::clone(&self.kill_switch),
+            feature_flags: Arc::clone(&self.feature_flags),
+            stats: Arc::clone(&self.stats),
+            plan_progress: Arc::clone(&self.plan_progress),
+            trade_blotter: Arc::clone(&self.trade_blotter),
+            stop_adjuster: Arc::clone(&self.stop_adjuster),
+            shadow_execution: self.shadow_execution.clone(),
+            config_reloader: self.config_reloader.clone(),
+            state_bundle: self.state_bundle.clone(),
+            watchlists: Arc::clone(&self.watchlists),
+            broker_accounts: self.broker_accounts.clone(),
             order_repo: Arc::clone(&self.order_repo),
+            auth: self.auth.clone(),
+            idempotency: Arc::clone(&self.idempotency),
             version: self.version.clone(),
         }
     }
 }
 
 /// Create the HTTP router with all endpoints.
-pub fn create_router<B, R, O, E>(state: AppState<B, R, O, E>) -> Router
+pub fn create_router<B, R, O, E, S>(state: AppState<B, R, O, E, S>) -> Router
 where
     B: BrokerPort + 'static,
     R: RiskRepositoryPort + 'static,
     O: OrderRepository + 'static,
     E: EventPublisherPort + 'static,
+    S: ScheduledPlanRepositoryPort + 'static,
 {
-    Router::new()
-        .route("/health", get(health_check))
-        .route("/api/v1/check-constraints", post(check_constraints))
-        .route("/api/v1/submit-orders", post(submit_orders))
+    let idempotent = || middleware::from_fn_with_state(Arc::clone(&state.idempotency), idempotency);
+
+    let read_routes = Router::new()
+        .route(
+            "/api/v1/check-constraints",
+            post(check_constraints).layer(idempotent()),
+        )
         .route("/api/v1/orders", post(get_order_state))
+        .route("/api/v1/orders/query", post(query_orders))
+        .route("/api/v1/blotter", post(export_blotter))
+        .route("/api/v1/stats", get(session_stats))
+        .route("/api/v1/circuit-breakers", get(list_circuit_breakers))
+        .route("/api/v1/plans/{cycle_id}/progress", get(plan_progress))
+        .route("/api/v1/shadow-execution", get(shadow_execution))
+        .route("/api/v1/watchlists", get(list_watchlists))
+        .route("/api/v1/watchlists/{name}", get(get_watchlist))
+        .route_layer(middleware::from_fn_with_state(
+            RouteAuth::new(state.auth.clone(), Role::ReadOnly),
+            require_role,
+        ));
+
+    let trade_routes = Router::new()
+        .route(
+            "/api/v1/submit-orders",
+            post(submit_orders).layer(idempotent()),
+        )
         .route("/api/v1/cancel-orders", post(cancel_orders))
+        .route(
+            "/api/v1/plans/scheduled/{plan_id}",
+            delete(cancel_scheduled_plan),
+        )
+        .route("/api/v1/positions/{symbol}/stops", post(adjust_stops))
+        .route("/api/v1/watchlists", post(create_watchlist))
+        .route("/api/v1/watchlists/{name}", delete(delete_watchlist))
+        .route(
+            "/api/v1/watchlists/{name}/symbols",
+            post(add_watchlist_symbols),
+        )
+        .route(
+            "/api/v1/watchlists/{name}/symbols/{symbol}",
+            delete(remove_watchlist_symbol),
+        )
+        .route_layer(middleware::from_fn_with_state(
+            RouteAuth::new(state.auth.clone(), Role::Trader),
+            require_role,
+        ));
+
+    let admin_routes = Router::new()
+        .route(
+            "/api/v1/admin/kill-switch",
+            get(get_kill_switch).post(set_kill_switch),
+        )
+        .route(
+            "/api/v1/admin/circuit-breakers/{name}",
+            post(set_circuit_breaker),
+        )
+        .route("/api/v1/admin/feature-flags", get(list_feature_flags))
+        .route(
+            "/api/v1/admin/feature-flags/{name}",
+            post(set_feature_flag).delete(reset_feature_flag),
+        )
+        .route("/api/v1/admin/reload-config", post(reload_config))
+        .route("/api/v1/admin/state", get(export_state).post(import_state))
+        .route("/api/v1/admin/broker-accounts", get(list_broker_accounts))
+        .route(
+            "/api/v1/admin/broker-accounts/{user_id}",
+            delete(disconnect_broker_account),
+        )
+        .route(
+            "/api/v1/admin/broker-accounts/{user_id}/authorize",
+            post(authorize_broker_account),
+        )
+        .route_layer(middleware::from_fn_with_state(
+            RouteAuth::new(state.auth.clone(), Role::Admin),
+            require_role,
+        ));
+
+    Router::new()
+        .route("/health", get(health_check))
+        .route("/healthz", get(health_check))
+        .route("/readyz", get(readiness_check))
+        .route("/metrics", get(metrics))
+        .route("/openapi.json", get(openapi_json))
+        .route("/api/v1/oauth/alpaca/callback", get(oauth_callback))
+        .merge(read_routes)
+        .merge(trade_routes)
+        .merge(admin_routes)
         .with_state(state)
 }
 
-/// Health check endpoint.
-async fn health_check<B, R, O, E>(State(state): State<AppState<B, R, O, E>>) -> impl IntoResponse
+/// Liveness endpoint: OK whenever the process is serving HTTP.
+#[utoipa::path(
+    get,
+    path = "/health",
+    tag = "health",
+    responses((status = 200, description = "Process is serving", body = HealthResponse))
+)]
+pub(super) async fn health_check<B, R, O, E, S>(
+    State(state): State<AppState<B, R, O, E, S>>,
+) -> impl IntoResponse
 where
     B: BrokerPort,
     R: RiskRepositoryPort,
     O: OrderRepository,
     E: EventPublisherPort,
+    S: ScheduledPlanRepositoryPort,
 {
     Json(HealthResponse {
         status: "healthy".to_string(),
@@ -98,16 +269,106 @@ where
     })
 }
 
+/// Prometheus metrics in text format.
+async fn metrics() -> impl IntoResponse {
+    get_metrics_handle().map_or_else(
+        || {
+            (
+                StatusCode::SERVICE_UNAVAILABLE,
+                [("content-type", "text/plain")],
+                "Metrics not initialized".to_string(),
+            )
+        },
+        |handle| {
+            (
+                StatusCode::OK,
+                [("content-type", "text/plain; version=0.0.4; charset=utf-8")],
+                handle.render(),
+            )
+        },
+    )
+}
+
+/// Readiness endpoint: OK only once recovery has completed and the broker
+/// and persistence are reachable. Also reports the latest host clock offset.
+#[utoipa::path(
+    get,
+    path = "/readyz",
+    tag = "health",
+    responses(
+        (status = 200, description = "Ready for trading traffic", body = ReadinessResponse),
+        (status = 503, description = "Not ready", body = ReadinessResponse),
+    )
+)]
+pub(super) async fn readiness_check<B, R, O, E, S>(
+    State(state): State<AppState<B, R, O, E, S>>,
+) -> impl IntoResponse
+where
+    B: BrokerPort,
+    R: RiskRepositoryPort,
+    O: OrderRepository,
+    E: EventPublisherPort,
+    S: ScheduledPlanRepositoryPort,
+{
+    let report = state.readiness.check().await;
+    let (status_code, status) = if report.is_ready() {
+        (StatusCode::OK, "READY")
+    } else {
+        (StatusCode::SERVICE_UNAVAILABLE, "NOT_READY")
+    };
+
+    (
+        status_code,
+        Json(ReadinessResponse {
+            status: status.to_string(),
+            recovered: report.recovered,
+            broker: report.broker,
+            persistence: report.persistence,
+            clock_sync: report.clock_sync.map(|c| ClockSyncResponse {
+                checked_at: c.checked_at,
+                source: c.source,
+                offset_ms: c.offset_ms,
+                skewed: c.skewed,
+                error: c.error,
+            }),
+        }),
+    )
+}
+
+/// `OpenAPI` document describing this API.
+async fn openapi_json() -> impl IntoResponse {
+    Json(openapi::openapi())
+}
+
 /// Check constraints endpoint.
-async fn check_constraints<B, R, O, E>(
-    State(state): State<AppState<B, R, O, E>>,
-    Json(request): Json<CheckConstraintsRequest>,
+#[utoipa::path(
+    post,
+    path = "/api/v1/check-constraints",
+    tag = "orders",
+    request_body = CheckConstraintsRequest,
+    params((
+        "Idempotency-Key" = Option<String>,
+        Header,
+        description = "Retries with the same key replay the first response"
+    )),
+    responses(
+        (status = 200, description = "Constraint check result", body = CheckConstraintsResponse),
+        (status = 401, description = "Missing or invalid API key", body = ApiErrorResponse),
+        (status = 409, description = "Idempotent request still running", body = ApiErrorResponse),
+        (status = 422, description = "Invalid fields or reused idempotency key", body = ApiErrorResponse),
+    ),
+    security(("api_key" = []), ("bearer" = []))
+)]
+pub(super) async fn check_constraints<B, R, O, E, S>(
+    State(state): State<AppState<B, R, O, E, S>>,
+    ValidJson(request): ValidJson<CheckConstraintsRequest>,
 ) -> impl IntoResponse
 where
     B: BrokerPort,
     R: RiskRepositoryPort,
     O: OrderRepository,
     E: EventPublisherPort,
+    S: ScheduledPlanRepositoryPort,
 {
     // Convert decisions to orders for validation
     let orders: Vec<CreateOrderDto> = request
@@ -115,13 +376,16 @@ where
         .into_iter()
         .map(|d| CreateOrderDto {
             client_order_id: format!("{}-{}", request.cycle_id, d.symbol),
+            oto_exit: d.oto_exit(),
             symbol: d.symbol,
             side: d.side,
             order_type: d.order_type,
             quantity: d.quantity,
             limit_price: d.limit_price,
+            stop_price: d.stop_price,
             time_in_force: d.time_in_force,
             purpose: d.purpose,
+            decision: None,
         })
         .collect();
 
@@ -129,6 +393,7 @@ where
     let dto = SubmitOrdersRequestDto {
         orders,
         validate_risk: true,
+        cycle_id: None,
     };
 
     // Execute validation through submit_orders (dry run would require separate use case)
@@ -150,14 +415,7 @@ where
                 .result
                 .violations
                 .into_iter()
-                .map(|v| ViolationResponse {
-                    code: v.code,
-                    severity: v.severity,
-                    message: v.message,
-                    instrument_id: v.instrument_id,
-                    observed: v.observed,
-                    limit: v.limit,
-                })
+                .map(ViolationResponse::from)
                 .collect();
 
             let per_order = if result.per_order_results.is_empty() {
@@ -175,14 +433,7 @@ where
                                     violations: r
                                         .violations
                                         .into_iter()
-                                        .map(|v| ViolationResponse {
-                                            code: v.code,
-                                            severity: v.severity,
-                                            message: v.message,
-                                            instrument_id: v.instrument_id,
-                                            observed: v.observed,
-                                            limit: v.limit,
-                                        })
+                                        .map(ViolationResponse::from)
                                         .collect(),
                                 },
                             )
@@ -219,27 +470,74 @@ where
 }
 
 /// Submit orders endpoint.
-async fn submit_orders<B, R, O, E>(
-    State(state): State<AppState<B, R, O, E>>,
-    Json(request): Json<SubmitOrdersRequest>,
-) -> impl IntoResponse
+#[utoipa::path(
+    post,
+    path = "/api/v1/submit-orders",
+    tag = "orders",
+    request_body = SubmitOrdersRequest,
+    params((
+        "Idempotency-Key" = Option<String>,
+        Header,
+        description = "Retries with the same key replay the first response"
+    )),
+    responses(
+        (status = 200, description = "Submission result", body = SubmitOrdersResponse),
+        (status = 202, description = "Plan scheduled for later execution", body = SubmitOrdersResponse),
+        (status = 401, description = "Missing or invalid API key", body = ApiErrorResponse),
+        (status = 403, description = "API key lacks the trader role", body = ApiErrorResponse),
+        (status = 409, description = "Idempotent request still running, or plan already scheduled or past its window", body = ApiErrorResponse),
+        (status = 422, description = "Invalid fields, execution window or reused idempotency key", body = ApiErrorResponse),
+        (status = 500, description = "Scheduled plan could not be stored", body = ApiErrorResponse),
+    ),
+    security(("api_key" = []), ("bearer" = []))
+)]
+pub(super) async fn submit_orders<B, R, O, E, S>(
+    State(state): State<AppState<B, R, O, E, S>>,
+    ValidJson(request): ValidJson<SubmitOrdersRequest>,
+) -> Response
 where
     B: BrokerPort,
     R: RiskRepositoryPort,
     O: OrderRepository,
     E: EventPublisherPort,
+    S: ScheduledPlanRepositoryPort,
 {
+    // Refuse the plan outright, before cycle cleanup or scheduling
+    if let Err(violation) = state.kill_switch.check() {
+        return (
+            StatusCode::OK,
+            Json(SubmitOrdersResponse {
+                ok: false,
+                orders: vec![],
+                error: None,
+                risk_violations: Some(vec![ViolationResponse {
+                    code: violation.code,
+                    severity: violation.severity.to_string(),
+                    message: violation.message,
+                    instrument_id: None,
+                    observed: None,
+                    limit: None,
+                }]),
+                scheduled_plan_id: None,
+            }),
+        )
+            .into_response();
+    }
+
     // Convert decisions to create order DTOs
     let orders: Vec<CreateOrderDto> = request
         .decisions
         .into_iter()
         .map(|d| CreateOrderDto {
             client_order_id: format!("{}-{}", request.cycle_id, d.symbol),
+            decision: d.decision_context(),
+            oto_exit: d.oto_exit(),
             symbol: d.symbol,
             side: d.side,
             order_type: d.order_type,
             quantity: d.quantity,
             limit_price: d.limit_price,
+            stop_price: d.stop_price,
             time_in_force: d.time_in_force,
             purpose: d.purpose,
         })
@@ -248,8 +546,28 @@ where
     let dto = SubmitOrdersRequestDto {
         orders,
         validate_risk: true,
+        cycle_id: Some(request.cycle_id.clone()),
     };
 
+    // A plan from a new cycle supersedes working orders from earlier cycles
+    state
+        .cycle_transition
+        .on_plan_received(&CycleId::new(&request.cycle_id))
+        .await;
+
+    // Plans with an execution time are accepted now and submitted by the scheduler
+    if let Some(execute_at) = request.execute_at {
+        return schedule_plan(
+            &state.plan_scheduler,
+            request.request_id,
+            request.cycle_id,
+            execute_at,
+            request.execution_window_secs,
+            dto,
+        )
+        .await;
+    }
+
     let result = state.submit_orders.execute(dto).await;
 
     // Convert result to response
@@ -258,18 +576,8 @@ where
         .into_iter()
         .chain(result.rejected)
         .map(|r| OrderResponse {
-            order_id: r.order.order_id,
-            broker_id: r.order.broker_id,
-            symbol: r.order.symbol,
-            side: r.order.side,
-            order_type: r.order.order_type,
-            quantity: r.order.quantity,
-            limit_price: r.order.limit_price,
-            status: r.order.status,
-            time_in_force: r.order.time_in_force,
-            filled_qty: r.order.filled_qty,
-            avg_fill_price: r.order.avg_fill_price,
             error: r.error,
+            ..OrderResponse::from(r.order)
         })
         .collect();
 
@@ -299,20 +607,89 @@ where
             orders: orders_response,
             error: None,
             risk_violations,
+            scheduled_plan_id: None,
         }),
     )
+        .into_response()
+}
+
+/// Accept a submit request for execution within a future window.
+async fn schedule_plan<B, R, O, E, S>(
+    scheduler: &PlanSchedulerService<B, R, O, E, S>,
+    plan_id: String,
+    cycle_id: String,
+    execute_at: Timestamp,
+    window_secs: Option<u64>,
+    request: SubmitOrdersRequestDto,
+) -> Response
+where
+    B: BrokerPort,
+    R: RiskRepositoryPort,
+    O: OrderRepository,
+    E: EventPublisherPort,
+    S: ScheduledPlanRepositoryPort,
+{
+    let window = window_secs.map_or_else(
+        || scheduler.default_window(execute_at),
+        |secs| ExecutionWindow::starting_at(execute_at, secs),
+    );
+
+    let outcome = match window {
+        Ok(window) => {
+            scheduler
+                .schedule(ScheduledPlan::new(
+                    plan_id.clone(),
+                    cycle_id,
+                    request,
+                    window,
+                ))
+                .await
+        }
+        Err(e) => Err(e),
+    };
+
+    match outcome {
+        Ok(()) => (
+            StatusCode::ACCEPTED,
+            Json(SubmitOrdersResponse {
+                ok: true,
+                orders: vec![],
+                error: None,
+                risk_violations: None,
+                scheduled_plan_id: Some(plan_id),
+            }),
+        )
+            .into_response(),
+        Err(e) => {
+            tracing::warn!(plan_id = %plan_id, error = %e, "Failed to schedule plan");
+            ApiError::from_error(&e).into_response()
+        }
+    }
 }
 
 /// Get order state endpoint.
-async fn get_order_state<B, R, O, E>(
-    State(state): State<AppState<B, R, O, E>>,
-    Json(request): Json<GetOrderStateRequest>,
+#[utoipa::path(
+    post,
+    path = "/api/v1/orders",
+    tag = "orders",
+    request_body = GetOrderStateRequest,
+    responses(
+        (status = 200, description = "Known orders and unknown IDs", body = GetOrderStateResponse),
+        (status = 401, description = "Missing or invalid API key", body = ApiErrorResponse),
+        (status = 422, description = "Invalid fields", body = ApiErrorResponse),
+    ),
+    security(("api_key" = []), ("bearer" = []))
+)]
+pub(super) async fn get_order_state<B, R, O, E, S>(
+    State(state): State<AppState<B, R, O, E, S>>,
+    ValidJson(request): ValidJson<GetOrderStateRequest>,
 ) -> impl IntoResponse
 where
     B: BrokerPort,
     R: RiskRepositoryPort,
     O: OrderRepository,
     E: EventPublisherPort,
+    S: ScheduledPlanRepositoryPort,
 {
     let mut orders = Vec::new();
     let mut not_found = Vec::new();
@@ -321,21 +698,7 @@ where
         let id = OrderId::new(&order_id);
         match state.order_repo.find_by_id(&id).await {
             Ok(Some(order)) => {
-                let dto = OrderDto::from_order(&order);
-                orders.push(OrderResponse {
-                    order_id: dto.order_id,
-                    broker_id: dto.broker_id,
-                    symbol: dto.symbol,
-                    side: dto.side,
-                    order_type: dto.order_type,
-                    quantity: dto.quantity,
-                    limit_price: dto.limit_price,
-                    status: dto.status,
-                    time_in_force: dto.time_in_force,
-                    filled_qty: dto.filled_qty,
-                    avg_fill_price: dto.avg_fill_price,
-                    error: None,
-                });
+                orders.push(OrderDto::from_order(&order).into());
             }
             Ok(None) => not_found.push(order_id),
             Err(e) => {
@@ -351,16 +714,136 @@ where
     )
 }
 
+/// Query order history.
+///
+/// Filters by creation time, symbol, status and decision cycle. Orders are
+/// returned newest first with their fills; page with `offset` and `limit`.
+#[utoipa::path(
+    post,
+    path = "/api/v1/orders/query",
+    tag = "orders",
+    request_body = QueryOrdersRequest,
+    responses(
+        (status = 200, description = "One page of matching orders", body = OrderHistoryResponse),
+        (status = 401, description = "Missing or invalid API key", body = ApiErrorResponse),
+        (status = 422, description = "Invalid fields", body = ApiErrorResponse),
+        (status = 500, description = "Order store unavailable", body = ApiErrorResponse),
+    ),
+    security(("api_key" = []), ("bearer" = []))
+)]
+pub(super) async fn query_orders<B, R, O, E, S>(
+    State(state): State<AppState<B, R, O, E, S>>,
+    ValidJson(request): ValidJson<QueryOrdersRequest>,
+) -> impl IntoResponse
+where
+    B: BrokerPort,
+    R: RiskRepositoryPort,
+    O: OrderRepository,
+    E: EventPublisherPort,
+    S: ScheduledPlanRepositoryPort,
+{
+    let query = request.query();
+    match state.order_repo.query(&query).await {
+        Ok(page) => Json(OrderHistoryResponse::from_page(&page, &query)).into_response(),
+        Err(e) => {
+            tracing::error!(error = %e, "Failed to query order history");
+            ApiError::order_store(&e).into_response()
+        }
+    }
+}
+
+/// Export the trade blotter.
+///
+/// Every fill in `[from, to)` with its order, fees and decision tags, as a
+/// CSV or Parquet file for tax and accounting reconciliation.
+#[utoipa::path(
+    post,
+    path = "/api/v1/blotter",
+    tag = "orders",
+    request_body = ExportBlotterRequest,
+    responses(
+        (status = 200, description = "Blotter file, oldest fill first", content(
+            (String = "text/csv"),
+            (Vec<u8> = "application/vnd.apache.parquet"),
+        )),
+        (status = 401, description = "Missing or invalid API key", body = ApiErrorResponse),
+        (status = 422, description = "Invalid fields", body = ApiErrorResponse),
+        (status = 500, description = "Fills could not be read or encoded", body = ApiErrorResponse),
+    ),
+    security(("api_key" = []), ("bearer" = []))
+)]
+pub(super) async fn export_blotter<B, R, O, E, S>(
+    State(state): State<AppState<B, R, O, E, S>>,
+    ValidJson(request): ValidJson<ExportBlotterRequest>,
+) -> Response
+where
+    B: BrokerPort,
+    R: RiskRepositoryPort,
+    O: OrderRepository,
+    E: EventPublisherPort,
+    S: ScheduledPlanRepositoryPort,
+{
+    let fills = match state.trade_blotter.fills(request.from, request.to).await {
+        Ok(fills) => fills,
+        Err(e) => {
+            tracing::error!(error = %e, "Failed to read trade blotter");
+            return ApiError::from_error(&e).into_response();
+        }
+    };
+    let file = match encode_blotter(&fills, request.format) {
+        Ok(file) => file,
+        Err(e) => {
+            tracing::error!(error = %e, "Failed to encode trade blotter");
+            return ApiError::from_error(&e).into_response();
+        }
+    };
+
+    let filename = format!(
+        "blotter-{}-{}.{}",
+        request.from.as_datetime().format("%Y%m%dT%H%M%SZ"),
+        request.to.as_datetime().format("%Y%m%dT%H%M%SZ"),
+        request.format.extension()
+    );
+    (
+        [
+            (
+                header::CONTENT_TYPE,
+                request.format.content_type().to_string(),
+            ),
+            (
+                header::CONTENT_DISPOSITION,
+                format!("attachment; filename=\"{filename}\""),
+            ),
+        ],
+        file,
+    )
+        .into_response()
+}
+
 /// Cancel orders endpoint.
-async fn cancel_orders<B, R, O, E>(
-    State(state): State<AppState<B, R, O, E>>,
-    Json(request): Json<CancelOrdersRequest>,
+#[utoipa::path(
+    post,
+    path = "/api/v1/cancel-orders",
+    tag = "orders",
+    request_body = CancelOrdersRequest,
+    responses(
+        (status = 200, description = "Per-order cancel results", body = CancelOrdersResponse),
+        (status = 401, description = "Missing or invalid API key", body = ApiErrorResponse),
+        (status = 403, description = "API key lacks the trader role", body = ApiErrorResponse),
+        (status = 422, description = "Invalid fields", body = ApiErrorResponse),
+    ),
+    security(("api_key" = []), ("bearer" = []))
+)]
+pub(super) async fn cancel_orders<B, R, O, E, S>(
+    State(state): State<AppState<B, R, O, E, S>>,
+    ValidJson(request): ValidJson<CancelOrdersRequest>,
 ) -> impl IntoResponse
 where
     B: BrokerPort,
     R: RiskRepositoryPort,
     O: OrderRepository,
     E: EventPublisherPort,
+    S: ScheduledPlanRepositoryPort,
 {
     let reason = request
         .reason
@@ -388,220 +871,2245 @@ where
     )
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-    use crate::application::ports::{
-        BrokerError, InMemoryRiskRepository, NoOpEventPublisher, OrderAck,
-    };
-    use crate::domain::order_execution::aggregate::Order;
-    use crate::domain::order_execution::errors::OrderError;
-    use crate::domain::order_execution::value_objects::OrderStatus;
-    use crate::domain::shared::BrokerId;
-    use async_trait::async_trait;
-    use axum::body::Body;
-    use axum::http::{Request, StatusCode};
-    use rust_decimal::Decimal;
-    use std::collections::HashMap;
-    use std::sync::RwLock;
-    use tower::ServiceExt;
-
-    // Mock broker
-    struct MockBroker;
+/// Cancel a scheduled plan before it executes.
+#[utoipa::path(
+    delete,
+    path = "/api/v1/plans/scheduled/{plan_id}",
+    tag = "orders",
+    params(("plan_id" = String, Path, description = "Scheduled plan ID (the submit request ID)")),
+    responses(
+        (status = 204, description = "Scheduled plan canceled"),
+        (status = 401, description = "Missing or invalid API key", body = ApiErrorResponse),
+        (status = 403, description = "API key lacks the trader role", body = ApiErrorResponse),
+        (status = 404, description = "No pending plan with this ID", body = ApiErrorResponse),
+    ),
+    security(("api_key" = []), ("bearer" = []))
+)]
+pub(super) async fn cancel_scheduled_plan<B, R, O, E, S>(
+    State(state): State<AppState<B, R, O, E, S>>,
+    Path(plan_id): Path<String>,
+) -> impl IntoResponse
+where
+    B: BrokerPort,
+    R: RiskRepositoryPort,
+    O: OrderRepository,
+    E: EventPublisherPort,
+    S: ScheduledPlanRepositoryPort,
+{
+    match state.plan_scheduler.cancel(&plan_id).await {
+        Ok(_) => StatusCode::NO_CONTENT.into_response(),
+        Err(e) => ApiError::from_error(&e).into_response(),
+    }
+}
 
-    #[async_trait]
-    impl BrokerPort for MockBroker {
-        async fn submit_order(
-            &self,
-            request: crate::application::ports::SubmitOrderRequest,
-        ) -> Result<OrderAck, BrokerError> {
-            Ok(OrderAck {
-                broker_order_id: BrokerId::new("broker-123"),
-                client_order_id: request.client_order_id,
-                status: OrderStatus::Accepted,
-                filled_qty: Decimal::ZERO,
-                avg_fill_price: None,
+/// Session overview: order activity, exposure, realized P&L and component
+/// health.
+#[utoipa::path(
+    get,
+    path = "/api/v1/stats",
+    tag = "health",
+    responses(
+        (status = 200, description = "Session snapshot", body = SessionStatsResponse),
+        (status = 401, description = "Missing or invalid API key", body = ApiErrorResponse),
+    ),
+    security(("api_key" = []), ("bearer" = []))
+)]
+pub(super) async fn session_stats<B, R, O, E, S>(
+    State(state): State<AppState<B, R, O, E, S>>,
+) -> impl IntoResponse
+where
+    B: BrokerPort,
+    R: RiskRepositoryPort,
+    O: OrderRepository + 'static,
+    E: EventPublisherPort,
+    S: ScheduledPlanRepositoryPort,
+{
+    let snapshot = state.stats.snapshot().await;
+
+    Json(SessionStatsResponse {
+        as_of: snapshot.as_of,
+        orders: snapshot.orders.map(|o| OrderActivityResponse {
+            submitted_today: o.submitted_today,
+            filled_today: o.filled_today,
+            working: o.working,
+        }),
+        exposure: snapshot.exposure.map(|e| ExposureResponse {
+            gross: e.gross.amount(),
+            net: e.net.amount(),
+            long: e.long.amount(),
+            short: e.short.amount(),
+        }),
+        realized_pnl: snapshot.realized_pnl,
+        trading_disabled: state.kill_switch.is_tripped(),
+        circuit_breakers: snapshot
+            .circuit_breakers
+            .into_iter()
+            .map(CircuitBreakerResponse::from)
+            .collect(),
+        feeds: snapshot
+            .feeds
+            .into_iter()
+            .map(|f| FeedResponse {
+                name: f.name,
+                last_update_at: f.last_update_at,
+                age_secs: f.age_secs,
+                stale: f.stale,
             })
-        }
-
-        async fn cancel_order(
-            &self,
-            _request: crate::application::ports::CancelOrderRequest,
-        ) -> Result<(), BrokerError> {
-            Ok(())
-        }
-
-        async fn get_order(&self, _broker_order_id: &BrokerId) -> Result<OrderAck, BrokerError> {
-            Err(BrokerError::OrderNotFound {
-                order_id: "unknown".to_string(),
+            .collect(),
+        rejected_ticks: snapshot
+            .rejected_ticks
+            .into_iter()
+            .map(|(symbol, r)| RejectedTicksResponse {
+                symbol,
+                non_positive_price: r.non_positive_price,
+                outlier: r.outlier,
+                future_timestamp: r.future_timestamp,
+                stale: r.stale,
             })
-        }
+            .collect(),
+        caches: snapshot
+            .caches
+            .into_iter()
+            .map(|c| CacheResponse {
+                name: c.name,
+                entries: c.stats.entries,
+                hits: c.stats.hits,
+                misses: c.stats.misses,
+                refreshes: c.stats.refreshes,
+                refresh_failures: c.stats.refresh_failures,
+            })
+            .collect(),
+        event_outbox: snapshot.event_outbox.map(|o| EventOutboxResponse {
+            depth: o.depth,
+            oldest_recorded_at: o.oldest_recorded_at,
+            delivered: o.delivered,
+            failed_attempts: o.failed_attempts,
+            last_error: o.last_error,
+        }),
+        last_reconciliation: snapshot
+            .last_reconciliation
+            .map(|r| ReconciliationResponse {
+                completed_at: r.completed_at,
+                checked: r.checked,
+                mismatches: r.mismatches,
+                reconciled: r.reconciled,
+                errors: r.errors,
+            }),
+    })
+}
 
-        async fn get_open_orders(&self) -> Result<Vec<OrderAck>, BrokerError> {
-            Ok(vec![])
+/// Execution progress of a decision cycle: status, fill percentage,
+/// average price and errors for each decision.
+#[utoipa::path(
+    get,
+    path = "/api/v1/plans/{cycle_id}/progress",
+    tag = "orders",
+    params(("cycle_id" = String, Path, description = "Decision cycle ID")),
+    responses(
+        (status = 200, description = "Per-decision progress", body = PlanProgressResponse),
+        (status = 401, description = "Missing or invalid API key", body = ApiErrorResponse),
+        (status = 404, description = "No orders or scheduled plans for the cycle", body = ApiErrorResponse),
+        (status = 500, description = "Order store could not be read", body = ApiErrorResponse),
+    ),
+    security(("api_key" = []), ("bearer" = []))
+)]
+pub(super) async fn plan_progress<B, R, O, E, S>(
+    State(state): State<AppState<B, R, O, E, S>>,
+    Path(cycle_id): Path<String>,
+) -> impl IntoResponse
+where
+    B: BrokerPort,
+    R: RiskRepositoryPort,
+    O: OrderRepository,
+    E: EventPublisherPort,
+    S: ScheduledPlanRepositoryPort,
+{
+    let scheduled = state.plan_scheduler.pending().await.unwrap_or_else(|e| {
+        tracing::warn!(error = %e, "Failed to load scheduled plans for plan progress");
+        vec![]
+    });
+
+    let progress = match state
+        .plan_progress
+        .progress(&CycleId::new(&cycle_id), &scheduled)
+        .await
+    {
+        Ok(Some(progress)) => progress,
+        Ok(None) => {
+            return ApiError::new(
+                ErrorKind::NotFound,
+                "PLAN_NOT_FOUND",
+                format!("No orders or scheduled plans for cycle {cycle_id}"),
+            )
+            .into_response();
         }
-
-        async fn get_buying_power(&self) -> Result<Decimal, BrokerError> {
-            Ok(Decimal::new(100_000, 0))
+        Err(e) => {
+            tracing::error!(cycle_id = %cycle_id, error = %e, "Failed to load plan progress");
+            return ApiError::order_store(&e).into_response();
         }
+    };
 
-        async fn get_position(
-            &self,
-            _instrument_id: &crate::domain::shared::InstrumentId,
-        ) -> Result<Option<Decimal>, BrokerError> {
-            Ok(None)
-        }
+    let complete = progress.is_complete();
+    (
+        StatusCode::OK,
+        Json(PlanProgressResponse {
+            cycle_id: progress.cycle_id,
+            as_of: progress.as_of,
+            complete,
+            pending: progress.summary.pending,
+            working: progress.summary.working,
+            filled: progress.summary.filled,
+            failed: progress.summary.failed,
+            decisions: progress
+                .decisions
+                .into_iter()
+                .map(|d| DecisionProgressResponse {
+                    order_id: d.order_id,
+                    symbol: d.symbol,
+                    side: d.side,
+                    status: match d.status {
+                        DecisionStatus::Pending => "PENDING",
+                        DecisionStatus::Working => "WORKING",
+                        DecisionStatus::Filled => "FILLED",
+                        DecisionStatus::Canceled => "CANCELED",
+                        DecisionStatus::Rejected => "REJECTED",
+                        DecisionStatus::Expired => "EXPIRED",
+                    }
+                    .to_string(),
+                    quantity: d.quantity,
+                    filled_qty: d.filled_qty,
+                    filled_pct: d.filled_pct,
+                    avg_fill_price: d.avg_fill_price,
+                    error: d.error,
+                    updated_at: d.updated_at,
+                })
+                .collect(),
+        }),
+    )
+        .into_response()
+}
 
-        async fn get_all_positions(
-            &self,
-        ) -> Result<Vec<crate::application::ports::PositionInfo>, BrokerError> {
-            Ok(vec![])
+/// Realized fills compared with simulated fills under the shadow
+/// execution configuration.
+#[utoipa::path(
+    get,
+    path = "/api/v1/shadow-execution",
+    tag = "orders",
+    responses(
+        (status = 200, description = "Realized versus shadow fills", body = ShadowExecutionResponse),
+        (status = 401, description = "Missing or invalid API key", body = ApiErrorResponse),
+        (status = 404, description = "No shadow execution configured", body = ApiErrorResponse),
+        (status = 500, description = "Order store could not be read", body = ApiErrorResponse),
+    ),
+    security(("api_key" = []), ("bearer" = []))
+)]
+pub(super) async fn shadow_execution<B, R, O, E, S>(
+    State(state): State<AppState<B, R, O, E, S>>,
+) -> impl IntoResponse
+where
+    B: BrokerPort,
+    R: RiskRepositoryPort,
+    O: OrderRepository,
+    E: EventPublisherPort,
+    S: ScheduledPlanRepositoryPort,
+{
+    let Some(shadow) = &state.shadow_execution else {
+        return ApiError::new(
+            ErrorKind::Disabled,
+            "SHADOW_EXECUTION_DISABLED",
+            "No execution.shadow section in the config file",
+        )
+        .into_response();
+    };
+
+    match shadow.report(state.order_repo.as_ref()).await {
+        Ok(report) => Json(ShadowExecutionResponse::from(report)).into_response(),
+        Err(e) => {
+            tracing::error!(error = %e, "Failed to build shadow execution report");
+            ApiError::order_store(&e).into_response()
         }
     }
+}
 
-    // Mock order repository
-    struct MockOrderRepo {
-        orders: RwLock<HashMap<String, Order>>,
+/// All watchlists.
+#[utoipa::path(
+    get,
+    path = "/api/v1/watchlists",
+    tag = "watchlists",
+    responses(
+        (status = 200, description = "Watchlists by name", body = WatchlistsResponse),
+        (status = 401, description = "Missing or invalid API key", body = ApiErrorResponse),
+        (status = 500, description = "Watchlist store could not be read", body = ApiErrorResponse),
+    ),
+    security(("api_key" = []), ("bearer" = []))
+)]
+pub(super) async fn list_watchlists<B, R, O, E, S>(
+    State(state): State<AppState<B, R, O, E, S>>,
+) -> impl IntoResponse
+where
+    B: BrokerPort,
+    R: RiskRepositoryPort,
+    O: OrderRepository,
+    E: EventPublisherPort,
+    S: ScheduledPlanRepositoryPort,
+{
+    match state.watchlists.list().await {
+        Ok(watchlists) => Json(WatchlistsResponse {
+            watchlists: watchlists.into_iter().map(Into::into).collect(),
+        })
+        .into_response(),
+        Err(e) => ApiError::from_error(&e).into_response(),
     }
+}
 
-    impl MockOrderRepo {
-        fn new() -> Self {
-            Self {
-                orders: RwLock::new(HashMap::new()),
-            }
-        }
-    }
+/// A single watchlist.
+#[utoipa::path(
+    get,
+    path = "/api/v1/watchlists/{name}",
+    tag = "watchlists",
+    params(("name" = String, Path, description = "Watchlist name")),
+    responses(
+        (status = 200, description = "The watchlist", body = WatchlistResponse),
+        (status = 401, description = "Missing or invalid API key", body = ApiErrorResponse),
+        (status = 404, description = "No watchlist with this name", body = ApiErrorResponse),
+    ),
+    security(("api_key" = []), ("bearer" = []))
+)]
+pub(super) async fn get_watchlist<B, R, O, E, S>(
+    State(state): State<AppState<B, R, O, E, S>>,
+    Path(name): Path<String>,
+) -> impl IntoResponse
+where
+    B: BrokerPort,
+    R: RiskRepositoryPort,
+    O: OrderRepository,
+    E: EventPublisherPort,
+    S: ScheduledPlanRepositoryPort,
+{
+    watchlist_response(state.watchlists.get(&name).await, StatusCode::OK)
+}
 
-    #[async_trait]
-    impl OrderRepository for MockOrderRepo {
-        async fn save(&self, order: &Order) -> Result<(), OrderError> {
-            let mut orders = self
-                .orders
-                .write()
-                .unwrap_or_else(std::sync::PoisonError::into_inner);
-            orders.insert(order.id().to_string(), order.clone());
-            Ok(())
-        }
+/// Create a watchlist. Its symbols are subscribed on the quote feed.
+#[utoipa::path(
+    post,
+    path = "/api/v1/watchlists",
+    tag = "watchlists",
+    request_body = CreateWatchlistRequest,
+    responses(
+        (status = 201, description = "Watchlist created", body = WatchlistResponse),
+        (status = 400, description = "Invalid name or symbol", body = ApiErrorResponse),
+        (status = 401, description = "Missing or invalid API key", body = ApiErrorResponse),
+        (status = 403, description = "API key lacks the trader role", body = ApiErrorResponse),
+        (status = 409, description = "A watchlist with this name exists", body = ApiErrorResponse),
+        (status = 422, description = "Invalid fields", body = ApiErrorResponse),
+    ),
+    security(("api_key" = []), ("bearer" = []))
+)]
+pub(super) async fn create_watchlist<B, R, O, E, S>(
+    State(state): State<AppState<B, R, O, E, S>>,
+    ValidJson(request): ValidJson<CreateWatchlistRequest>,
+) -> impl IntoResponse
+where
+    B: BrokerPort,
+    R: RiskRepositoryPort,
+    O: OrderRepository,
+    E: EventPublisherPort,
+    S: ScheduledPlanRepositoryPort,
+{
+    watchlist_response(
+        state
+            .watchlists
+            .create(request.name.trim(), &request.symbols)
+            .await,
+        StatusCode::CREATED,
+    )
+}
 
-        async fn find_by_id(&self, id: &OrderId) -> Result<Option<Order>, OrderError> {
-            let orders = self
-                .orders
-                .read()
-                .unwrap_or_else(std::sync::PoisonError::into_inner);
-            Ok(orders.get(id.as_str()).cloned())
-        }
+/// Delete a watchlist. Its symbols are unsubscribed unless still needed.
+#[utoipa::path(
+    delete,
+    path = "/api/v1/watchlists/{name}",
+    tag = "watchlists",
+    params(("name" = String, Path, description = "Watchlist name")),
+    responses(
+        (status = 204, description = "Watchlist deleted"),
+        (status = 401, description = "Missing or invalid API key", body = ApiErrorResponse),
+        (status = 403, description = "API key lacks the trader role", body = ApiErrorResponse),
+        (status = 404, description = "No watchlist with this name", body = ApiErrorResponse),
+    ),
+    security(("api_key" = []), ("bearer" = []))
+)]
+pub(super) async fn delete_watchlist<B, R, O, E, S>(
+    State(state): State<AppState<B, R, O, E, S>>,
+    Path(name): Path<String>,
+) -> impl IntoResponse
+where
+    B: BrokerPort,
+    R: RiskRepositoryPort,
+    O: OrderRepository,
+    E: EventPublisherPort,
+    S: ScheduledPlanRepositoryPort,
+{
+    match state.watchlists.delete(&name).await {
+        Ok(()) => StatusCode::NO_CONTENT.into_response(),
+        Err(e) => ApiError::from_error(&e).into_response(),
+    }
+}
 
-        async fn find_by_broker_id(
-            &self,
-            _broker_id: &BrokerId,
-        ) -> Result<Option<Order>, OrderError> {
-            Ok(None)
-        }
+/// Add symbols to a watchlist.
+#[utoipa::path(
+    post,
+    path = "/api/v1/watchlists/{name}/symbols",
+    tag = "watchlists",
+    params(("name" = String, Path, description = "Watchlist name")),
+    request_body = WatchlistSymbolsRequest,
+    responses(
+        (status = 200, description = "Watchlist after the change", body = WatchlistResponse),
+        (status = 400, description = "Invalid symbol", body = ApiErrorResponse),
+        (status = 401, description = "Missing or invalid API key", body = ApiErrorResponse),
+        (status = 403, description = "API key lacks the trader role", body = ApiErrorResponse),
+        (status = 404, description = "No watchlist with this name", body = ApiErrorResponse),
+        (status = 422, description = "Invalid fields", body = ApiErrorResponse),
+    ),
+    security(("api_key" = []), ("bearer" = []))
+)]
+pub(super) async fn add_watchlist_symbols<B, R, O, E, S>(
+    State(state): State<AppState<B, R, O, E, S>>,
+    Path(name): Path<String>,
+    ValidJson(request): ValidJson<WatchlistSymbolsRequest>,
+) -> impl IntoResponse
+where
+    B: BrokerPort,
+    R: RiskRepositoryPort,
+    O: OrderRepository,
+    E: EventPublisherPort,
+    S: ScheduledPlanRepositoryPort,
+{
+    watchlist_response(
+        state.watchlists.add_symbols(&name, &request.symbols).await,
+        StatusCode::OK,
+    )
+}
 
-        async fn find_by_status(&self, status: OrderStatus) -> Result<Vec<Order>, OrderError> {
-            let orders = self
-                .orders
-                .read()
-                .unwrap_or_else(std::sync::PoisonError::into_inner);
-            Ok(orders
-                .values()
-                .filter(|o| o.status() == status)
-                .cloned()
-                .collect())
-        }
+/// Remove a symbol from a watchlist.
+#[utoipa::path(
+    delete,
+    path = "/api/v1/watchlists/{name}/symbols/{symbol}",
+    tag = "watchlists",
+    params(
+        ("name" = String, Path, description = "Watchlist name"),
+        ("symbol" = String, Path, description = "Symbol to remove"),
+    ),
+    responses(
+        (status = 200, description = "Watchlist after the change", body = WatchlistResponse),
+        (status = 401, description = "Missing or invalid API key", body = ApiErrorResponse),
+        (status = 403, description = "API key lacks the trader role", body = ApiErrorResponse),
+        (status = 404, description = "No watchlist with this name", body = ApiErrorResponse),
+    ),
+    security(("api_key" = []), ("bearer" = []))
+)]
+pub(super) async fn remove_watchlist_symbol<B, R, O, E, S>(
+    State(state): State<AppState<B, R, O, E, S>>,
+    Path((name, symbol)): Path<(String, String)>,
+) -> impl IntoResponse
+where
+    B: BrokerPort,
+    R: RiskRepositoryPort,
+    O: OrderRepository,
+    E: EventPublisherPort,
+    S: ScheduledPlanRepositoryPort,
+{
+    watchlist_response(
+        state.watchlists.remove_symbols(&name, &[symbol]).await,
+        StatusCode::OK,
+    )
+}
 
-        async fn find_active(&self) -> Result<Vec<Order>, OrderError> {
-            let orders = self
-                .orders
-                .read()
-                .unwrap_or_else(std::sync::PoisonError::into_inner);
-            Ok(orders.values().cloned().collect())
-        }
+fn watchlist_response(result: Result<Watchlist, WatchlistError>, status: StatusCode) -> Response {
+    match result {
+        Ok(watchlist) => (status, Json(WatchlistResponse::from(watchlist))).into_response(),
+        Err(e) => ApiError::from_error(&e).into_response(),
+    }
+}
 
-        async fn exists(&self, id: &OrderId) -> Result<bool, OrderError> {
-            let orders = self
-                .orders
-                .read()
-                .unwrap_or_else(std::sync::PoisonError::into_inner);
-            Ok(orders.contains_key(id.as_str()))
-        }
+/// Move the stop and/or target of an open position.
+///
+/// Updates the levels tracked by the position monitor and re-places any
+/// broker-held protective stop at the new stop price. Broker failures are
+/// listed in `errors`; the monitor keeps enforcing the new levels.
+#[utoipa::path(
+    post,
+    path = "/api/v1/positions/{symbol}/stops",
+    tag = "orders",
+    params(("symbol" = String, Path, description = "Position symbol")),
+    request_body = AdjustStopsRequest,
+    responses(
+        (status = 200, description = "Levels moved", body = AdjustStopsResponse),
+        (status = 401, description = "Missing or invalid API key", body = ApiErrorResponse),
+        (status = 403, description = "API key lacks the trader role", body = ApiErrorResponse),
+        (status = 404, description = "No monitored position or protective stop for the symbol", body = ApiErrorResponse),
+        (status = 422, description = "Invalid fields or levels", body = ApiErrorResponse),
+        (status = 500, description = "Order store could not be read", body = ApiErrorResponse),
+    ),
+    security(("api_key" = []), ("bearer" = []))
+)]
+pub(super) async fn adjust_stops<B, R, O, E, S>(
+    State(state): State<AppState<B, R, O, E, S>>,
+    Path(symbol): Path<String>,
+    ValidJson(request): ValidJson<AdjustStopsRequest>,
+) -> impl IntoResponse
+where
+    B: BrokerPort,
+    R: RiskRepositoryPort,
+    O: OrderRepository,
+    E: EventPublisherPort,
+    S: ScheduledPlanRepositoryPort,
+{
+    let adjustment = StopAdjustment {
+        stop_loss: request.stop_loss,
+        take_profit: request.take_profit,
+        reason: request.reason,
+    };
 
-        async fn delete(&self, id: &OrderId) -> Result<(), OrderError> {
-            let mut orders = self
-                .orders
-                .write()
-                .unwrap_or_else(std::sync::PoisonError::into_inner);
-            orders.remove(id.as_str());
-            Ok(())
+    let symbol = Symbol::normalize(&symbol);
+    match state.stop_adjuster.adjust(&symbol, &adjustment).await {
+        Ok(report) => (
+            StatusCode::OK,
+            Json(AdjustStopsResponse {
+                symbol: report.symbol,
+                monitored: report.monitored,
+                canceled: report.canceled,
+                submitted: report.submitted,
+                errors: report.errors,
+            }),
+        )
+            .into_response(),
+        Err(e) => ApiError::from_error(&e).into_response(),
+    }
+}
+
+/// Current kill switch state.
+#[utoipa::path(
+    get,
+    path = "/api/v1/admin/kill-switch",
+    tag = "admin",
+    responses(
+        (status = 200, description = "Kill switch state", body = KillSwitchResponse),
+        (status = 401, description = "Missing or invalid API key", body = ApiErrorResponse),
+        (status = 403, description = "API key lacks the admin role", body = ApiErrorResponse),
+    ),
+    security(("api_key" = []), ("bearer" = []))
+)]
+pub(super) async fn get_kill_switch<B, R, O, E, S>(
+    State(state): State<AppState<B, R, O, E, S>>,
+) -> impl IntoResponse
+where
+    B: BrokerPort,
+    R: RiskRepositoryPort,
+    O: OrderRepository,
+    E: EventPublisherPort,
+    S: ScheduledPlanRepositoryPort,
+{
+    Json(kill_switch_response(&state.kill_switch))
+}
+
+/// Engage or release the kill switch.
+///
+/// Releasing clears a switch engaged through this endpoint or at startup;
+/// trading stays disabled while the kill switch file exists.
+#[utoipa::path(
+    post,
+    path = "/api/v1/admin/kill-switch",
+    tag = "admin",
+    request_body = KillSwitchRequest,
+    responses(
+        (status = 200, description = "Kill switch state after the change", body = KillSwitchResponse),
+        (status = 401, description = "Missing or invalid API key", body = ApiErrorResponse),
+        (status = 403, description = "API key lacks the admin role", body = ApiErrorResponse),
+        (status = 422, description = "Invalid fields", body = ApiErrorResponse),
+    ),
+    security(("api_key" = []), ("bearer" = []))
+)]
+pub(super) async fn set_kill_switch<B, R, O, E, S>(
+    State(state): State<AppState<B, R, O, E, S>>,
+    ValidJson(request): ValidJson<KillSwitchRequest>,
+) -> impl IntoResponse
+where
+    B: BrokerPort,
+    R: RiskRepositoryPort,
+    O: OrderRepository,
+    E: EventPublisherPort,
+    S: ScheduledPlanRepositoryPort,
+{
+    if request.engaged {
+        state.kill_switch.trip(
+            request
+                .reason
+                .unwrap_or_else(|| "engaged via admin API".to_string()),
+        );
+    } else {
+        state.kill_switch.reset();
+    }
+
+    Json(kill_switch_response(&state.kill_switch))
+}
+
+/// Circuit breakers guarding broker calls, with failure rates and state
+/// change counts.
+#[utoipa::path(
+    get,
+    path = "/api/v1/circuit-breakers",
+    tag = "orders",
+    responses(
+        (status = 200, description = "Circuit breaker states", body = CircuitBreakersResponse),
+        (status = 401, description = "Missing or invalid API key", body = ApiErrorResponse),
+    ),
+    security(("api_key" = []), ("bearer" = []))
+)]
+pub(super) async fn list_circuit_breakers<B, R, O, E, S>(
+    State(state): State<AppState<B, R, O, E, S>>,
+) -> impl IntoResponse
+where
+    B: BrokerPort,
+    R: RiskRepositoryPort,
+    O: OrderRepository + 'static,
+    E: EventPublisherPort,
+    S: ScheduledPlanRepositoryPort,
+{
+    Json(CircuitBreakersResponse {
+        circuit_breakers: state
+            .stats
+            .circuit_breakers()
+            .into_iter()
+            .map(CircuitBreakerResponse::from)
+            .collect(),
+    })
+}
+
+/// Trip or reset a circuit breaker.
+///
+/// A tripped breaker stays open until reset here; resetting also clears its
+/// consecutive failure count.
+#[utoipa::path(
+    post,
+    path = "/api/v1/admin/circuit-breakers/{name}",
+    tag = "admin",
+    params(("name" = String, Path, description = "Circuit breaker name")),
+    request_body = CircuitBreakerRequest,
+    responses(
+        (status = 200, description = "Circuit breaker state after the change", body = CircuitBreakerResponse),
+        (status = 401, description = "Missing or invalid API key", body = ApiErrorResponse),
+        (status = 403, description = "API key lacks the admin role", body = ApiErrorResponse),
+        (status = 404, description = "No circuit breaker with that name", body = ApiErrorResponse),
+        (status = 422, description = "Invalid fields", body = ApiErrorResponse),
+    ),
+    security(("api_key" = []), ("bearer" = []))
+)]
+pub(super) async fn set_circuit_breaker<B, R, O, E, S>(
+    State(state): State<AppState<B, R, O, E, S>>,
+    Path(name): Path<String>,
+    ValidJson(request): ValidJson<CircuitBreakerRequest>,
+) -> impl IntoResponse
+where
+    B: BrokerPort,
+    R: RiskRepositoryPort,
+    O: OrderRepository + 'static,
+    E: EventPublisherPort,
+    S: ScheduledPlanRepositoryPort,
+{
+    let Some(breaker) = state.stats.circuit_breaker(&name) else {
+        return ApiError::new(
+            ErrorKind::NotFound,
+            "CIRCUIT_BREAKER_NOT_FOUND",
+            format!("No circuit breaker named {name}"),
+        )
+        .into_response();
+    };
+
+    if request.open {
+        breaker.trip(request.reason.as_deref().unwrap_or("tripped via admin API"));
+    } else {
+        breaker.reset();
+    }
+
+    Json(CircuitBreakerResponse::from(BreakerStatus::of(
+        name, &breaker,
+    )))
+    .into_response()
+}
+
+/// Feature flags with their defaults and overrides.
+#[utoipa::path(
+    get,
+    path = "/api/v1/admin/feature-flags",
+    tag = "admin",
+    responses(
+        (status = 200, description = "Feature flag states", body = FeatureFlagsResponse),
+        (status = 401, description = "Missing or invalid API key", body = ApiErrorResponse),
+        (status = 403, description = "API key lacks the admin role", body = ApiErrorResponse),
+    ),
+    security(("api_key" = []), ("bearer" = []))
+)]
+pub(super) async fn list_feature_flags<B, R, O, E, S>(
+    State(state): State<AppState<B, R, O, E, S>>,
+) -> impl IntoResponse
+where
+    B: BrokerPort,
+    R: RiskRepositoryPort,
+    O: OrderRepository,
+    E: EventPublisherPort,
+    S: ScheduledPlanRepositoryPort,
+{
+    Json(FeatureFlagsResponse {
+        flags: state
+            .feature_flags
+            .flags()
+            .into_iter()
+            .map(FeatureFlagResponse::from)
+            .collect(),
+    })
+}
+
+/// Turn a feature on or off until it is reset or the engine restarts.
+#[utoipa::path(
+    post,
+    path = "/api/v1/admin/feature-flags/{name}",
+    tag = "admin",
+    params(("name" = String, Path, description = "Feature flag name, e.g. `auto_hedging`")),
+    request_body = FeatureFlagRequest,
+    responses(
+        (status = 200, description = "Feature flag state after the change", body = FeatureFlagResponse),
+        (status = 401, description = "Missing or invalid API key", body = ApiErrorResponse),
+        (status = 403, description = "API key lacks the admin role", body = ApiErrorResponse),
+        (status = 404, description = "No feature flag with that name", body = ApiErrorResponse),
+        (status = 422, description = "Invalid fields", body = ApiErrorResponse),
+    ),
+    security(("api_key" = []), ("bearer" = []))
+)]
+pub(super) async fn set_feature_flag<B, R, O, E, S>(
+    State(state): State<AppState<B, R, O, E, S>>,
+    Path(name): Path<String>,
+    ValidJson(request): ValidJson<FeatureFlagRequest>,
+) -> impl IntoResponse
+where
+    B: BrokerPort,
+    R: RiskRepositoryPort,
+    O: OrderRepository,
+    E: EventPublisherPort,
+    S: ScheduledPlanRepositoryPort,
+{
+    let Ok(feature) = name.parse::<Feature>() else {
+        return feature_flag_not_found(&name);
+    };
+
+    state.feature_flags.set(
+        feature,
+        request.enabled,
+        request
+            .reason
+            .unwrap_or_else(|| "set via admin API".to_string()),
+    );
+    Json(FeatureFlagResponse::from(state.feature_flags.flag(feature))).into_response()
+}
+
+/// Return a feature to its default for the trading environment.
+#[utoipa::path(
+    delete,
+    path = "/api/v1/admin/feature-flags/{name}",
+    tag = "admin",
+    params(("name" = String, Path, description = "Feature flag name, e.g. `auto_hedging`")),
+    responses(
+        (status = 200, description = "Feature flag state after the reset", body = FeatureFlagResponse),
+        (status = 401, description = "Missing or invalid API key", body = ApiErrorResponse),
+        (status = 403, description = "API key lacks the admin role", body = ApiErrorResponse),
+        (status = 404, description = "No feature flag with that name", body = ApiErrorResponse),
+    ),
+    security(("api_key" = []), ("bearer" = []))
+)]
+pub(super) async fn reset_feature_flag<B, R, O, E, S>(
+    State(state): State<AppState<B, R, O, E, S>>,
+    Path(name): Path<String>,
+) -> impl IntoResponse
+where
+    B: BrokerPort,
+    R: RiskRepositoryPort,
+    O: OrderRepository,
+    E: EventPublisherPort,
+    S: ScheduledPlanRepositoryPort,
+{
+    let Ok(feature) = name.parse::<Feature>() else {
+        return feature_flag_not_found(&name);
+    };
+
+    state.feature_flags.reset(feature);
+    Json(FeatureFlagResponse::from(state.feature_flags.flag(feature))).into_response()
+}
+
+fn feature_flag_not_found(name: &str) -> Response {
+    ApiError::new(
+        ErrorKind::NotFound,
+        "FEATURE_FLAG_NOT_FOUND",
+        format!("No feature flag named {name}"),
+    )
+    .into_response()
+}
+
+fn kill_switch_response(kill_switch: &KillSwitch) -> KillSwitchResponse {
+    let reason = kill_switch.status();
+    KillSwitchResponse {
+        engaged: reason.is_some(),
+        reason,
+    }
+}
+
+/// Reload tunable settings from the config file.
+///
+/// Applies changed constraint limits and log level. A file that changes
+/// ports, bind address or environment mode is rejected without applying
+/// anything.
+#[utoipa::path(
+    post,
+    path = "/api/v1/admin/reload-config",
+    tag = "admin",
+    responses(
+        (status = 200, description = "Settings applied", body = ConfigReloadResponse),
+        (status = 401, description = "Missing or invalid API key", body = ApiErrorResponse),
+        (status = 403, description = "API key lacks the admin role", body = ApiErrorResponse),
+        (status = 404, description = "Engine started without a config file", body = ApiErrorResponse),
+        (status = 409, description = "Changes require a restart", body = ApiErrorResponse),
+        (status = 422, description = "Config file is invalid", body = ApiErrorResponse),
+        (status = 500, description = "A setting could not be applied", body = ApiErrorResponse),
+    ),
+    security(("api_key" = []), ("bearer" = []))
+)]
+pub(super) async fn reload_config<B, R, O, E, S>(
+    State(state): State<AppState<B, R, O, E, S>>,
+) -> impl IntoResponse
+where
+    B: BrokerPort,
+    R: RiskRepositoryPort,
+    O: OrderRepository,
+    E: EventPublisherPort,
+    S: ScheduledPlanRepositoryPort,
+{
+    let Some(reloader) = &state.config_reloader else {
+        return ApiError::new(
+            ErrorKind::Disabled,
+            "CONFIG_NOT_LOADED",
+            "Engine was started without CONFIG_PATH",
+        )
+        .into_response();
+    };
+
+    match reloader.reload("admin_api").await {
+        Ok(changes) => (
+            StatusCode::OK,
+            Json(ConfigReloadResponse {
+                path: reloader.path().display().to_string(),
+                changes: changes
+                    .into_iter()
+                    .map(|change| ConfigChangeResponse {
+                        field: change.field.to_string(),
+                        old: change.old,
+                        new: change.new,
+                    })
+                    .collect(),
+            }),
+        )
+            .into_response(),
+        Err(e) => ApiError::from_error(&e).into_response(),
+    }
+}
+
+/// Export open positions, working orders and their thesis metadata.
+///
+/// The bundle is signed with `STATE_BUNDLE_KEY` and can be imported on
+/// another instance sharing the key.
+#[utoipa::path(
+    get,
+    path = "/api/v1/admin/state",
+    tag = "admin",
+    responses(
+        (status = 200, description = "Signed state bundle", body = StateBundleResponse),
+        (status = 401, description = "Missing or invalid API key", body = ApiErrorResponse),
+        (status = 403, description = "API key lacks the admin role", body = ApiErrorResponse),
+        (status = 404, description = "Engine started without a state bundle key", body = ApiErrorResponse),
+        (status = 500, description = "Local state could not be read", body = ApiErrorResponse),
+    ),
+    security(("api_key" = []), ("bearer" = []))
+)]
+pub(super) async fn export_state<B, R, O, E, S>(
+    State(state): State<AppState<B, R, O, E, S>>,
+) -> impl IntoResponse
+where
+    B: BrokerPort,
+    R: RiskRepositoryPort,
+    O: OrderRepository,
+    E: EventPublisherPort,
+    S: ScheduledPlanRepositoryPort,
+{
+    let Some(state_bundle) = &state.state_bundle else {
+        return state_bundle_disabled();
+    };
+
+    match state_bundle.export().await {
+        Ok(signed) => Json(StateBundleResponse::from(signed)).into_response(),
+        Err(e) => ApiError::from_error(&e).into_response(),
+    }
+}
+
+/// Import a state bundle exported by another instance.
+///
+/// The bundle is checked against broker and local state first. Conflicts
+/// block the import with 409 unless `force` is set, in which case
+/// positions and orders that already exist locally are still skipped.
+#[utoipa::path(
+    post,
+    path = "/api/v1/admin/state",
+    tag = "admin",
+    request_body = ImportStateRequest,
+    responses(
+        (status = 200, description = "State imported", body = StateImportResponse),
+        (status = 401, description = "Missing or invalid API key", body = ApiErrorResponse),
+        (status = 403, description = "API key lacks the admin role", body = ApiErrorResponse),
+        (status = 404, description = "Engine started without a state bundle key", body = ApiErrorResponse),
+        (status = 409, description = "Bundle conflicts with broker or local state, or is from another environment", body = ApiErrorResponse),
+        (status = 422, description = "Invalid signature, version or bundle", body = ApiErrorResponse),
+        (status = 500, description = "Local state could not be written", body = ApiErrorResponse),
+        (status = 503, description = "Broker state could not be read", body = ApiErrorResponse),
+    ),
+    security(("api_key" = []), ("bearer" = []))
+)]
+pub(super) async fn import_state<B, R, O, E, S>(
+    State(state): State<AppState<B, R, O, E, S>>,
+    ValidJson(request): ValidJson<ImportStateRequest>,
+) -> impl IntoResponse
+where
+    B: BrokerPort,
+    R: RiskRepositoryPort,
+    O: OrderRepository,
+    E: EventPublisherPort,
+    S: ScheduledPlanRepositoryPort,
+{
+    let Some(state_bundle) = &state.state_bundle else {
+        return state_bundle_disabled();
+    };
+
+    let signed = SignedStateBundle {
+        bundle: request.bundle,
+        signature: request.signature,
+    };
+    match state_bundle.import(signed, request.force).await {
+        Ok(report) if report.applied => {
+            (StatusCode::OK, Json(StateImportResponse::from(report))).into_response()
+        }
+        Ok(report) => {
+            let conflicts: Vec<StateConflictResponse> =
+                report.conflicts.into_iter().map(Into::into).collect();
+            let error = ApiError::new(
+                ErrorKind::Conflict,
+                "STATE_CONFLICT",
+                format!(
+                    "{} conflict(s) with broker or local state; retry with force to import anyway",
+                    conflicts.len()
+                ),
+            );
+            match serde_json::to_value(conflicts) {
+                Ok(details) => error.with_details(details),
+                Err(_) => error,
+            }
+            .into_response()
         }
+        Err(e) => ApiError::from_error(&e).into_response(),
     }
+}
 
-    fn create_test_state()
-    -> AppState<MockBroker, InMemoryRiskRepository, MockOrderRepo, NoOpEventPublisher> {
-        let broker = Arc::new(MockBroker);
-        let risk_repo = Arc::new(InMemoryRiskRepository::new());
-        let order_repo = Arc::new(MockOrderRepo::new());
-        let event_publisher = Arc::new(NoOpEventPublisher);
+fn state_bundle_disabled() -> Response {
+    ApiError::new(
+        ErrorKind::Disabled,
+        "STATE_BUNDLE_DISABLED",
+        "Engine was started without STATE_BUNDLE_KEY",
+    )
+    .into_response()
+}
 
-        let submit_orders = Arc::new(SubmitOrdersUseCase::new(
-            Arc::clone(&broker),
-            Arc::clone(&risk_repo),
-            Arc::clone(&order_repo),
-            Arc::clone(&event_publisher),
-        ));
+/// Broker accounts connected over OAuth.
+#[utoipa::path(
+    get,
+    path = "/api/v1/admin/broker-accounts",
+    tag = "admin",
+    responses(
+        (status = 200, description = "Connected accounts by user ID", body = BrokerAccountsResponse),
+        (status = 401, description = "Missing or invalid API key", body = ApiErrorResponse),
+        (status = 403, description = "API key lacks the admin role", body = ApiErrorResponse),
+        (status = 404, description = "Engine started without an OAuth client", body = ApiErrorResponse),
+        (status = 500, description = "Token store could not be read", body = ApiErrorResponse),
+    ),
+    security(("api_key" = []), ("bearer" = []))
+)]
+pub(super) async fn list_broker_accounts<B, R, O, E, S>(
+    State(state): State<AppState<B, R, O, E, S>>,
+) -> impl IntoResponse
+where
+    B: BrokerPort,
+    R: RiskRepositoryPort,
+    O: OrderRepository,
+    E: EventPublisherPort,
+    S: ScheduledPlanRepositoryPort,
+{
+    let Some(broker_accounts) = &state.broker_accounts else {
+        return broker_accounts_disabled();
+    };
+
+    match broker_accounts.accounts().await {
+        Ok(accounts) => Json(BrokerAccountsResponse {
+            accounts: accounts.into_iter().map(Into::into).collect(),
+        })
+        .into_response(),
+        Err(e) => ApiError::from_error(&e).into_response(),
+    }
+}
+
+/// Start connecting a user's broker account.
+///
+/// Returns the broker page to send the user to. After the user grants
+/// access, the broker redirects to the OAuth callback, which stores the
+/// user's token.
+#[utoipa::path(
+    post,
+    path = "/api/v1/admin/broker-accounts/{user_id}/authorize",
+    tag = "admin",
+    params(("user_id" = String, Path, description = "User connecting an account")),
+    responses(
+        (status = 200, description = "Authorization page", body = BrokerAuthorizationResponse),
+        (status = 401, description = "Missing or invalid API key", body = ApiErrorResponse),
+        (status = 403, description = "API key lacks the admin role", body = ApiErrorResponse),
+        (status = 404, description = "Engine started without an OAuth client", body = ApiErrorResponse),
+        (status = 422, description = "Invalid user ID", body = ApiErrorResponse),
+    ),
+    security(("api_key" = []), ("bearer" = []))
+)]
+pub(super) async fn authorize_broker_account<B, R, O, E, S>(
+    State(state): State<AppState<B, R, O, E, S>>,
+    Path(user_id): Path<String>,
+) -> impl IntoResponse
+where
+    B: BrokerPort,
+    R: RiskRepositoryPort,
+    O: OrderRepository,
+    E: EventPublisherPort,
+    S: ScheduledPlanRepositoryPort,
+{
+    let Some(broker_accounts) = &state.broker_accounts else {
+        return broker_accounts_disabled();
+    };
+
+    match broker_accounts.authorize_url(&user_id) {
+        Ok(authorize_url) => Json(BrokerAuthorizationResponse {
+            user_id,
+            authorize_url,
+        })
+        .into_response(),
+        Err(e) => ApiError::from_error(&e).into_response(),
+    }
+}
+
+/// Disconnect a user's broker account, deleting its token.
+#[utoipa::path(
+    delete,
+    path = "/api/v1/admin/broker-accounts/{user_id}",
+    tag = "admin",
+    params(("user_id" = String, Path, description = "User whose account is disconnected")),
+    responses(
+        (status = 204, description = "Account disconnected"),
+        (status = 401, description = "Missing or invalid API key", body = ApiErrorResponse),
+        (status = 403, description = "API key lacks the admin role", body = ApiErrorResponse),
+        (status = 404, description = "No account connected, or no OAuth client", body = ApiErrorResponse),
+    ),
+    security(("api_key" = []), ("bearer" = []))
+)]
+pub(super) async fn disconnect_broker_account<B, R, O, E, S>(
+    State(state): State<AppState<B, R, O, E, S>>,
+    Path(user_id): Path<String>,
+) -> impl IntoResponse
+where
+    B: BrokerPort,
+    R: RiskRepositoryPort,
+    O: OrderRepository,
+    E: EventPublisherPort,
+    S: ScheduledPlanRepositoryPort,
+{
+    let Some(broker_accounts) = &state.broker_accounts else {
+        return broker_accounts_disabled();
+    };
+
+    match broker_accounts.disconnect(&user_id).await {
+        Ok(()) => StatusCode::NO_CONTENT.into_response(),
+        Err(e) => ApiError::from_error(&e).into_response(),
+    }
+}
+
+/// OAuth redirect target: exchanges the code for the user's token.
+///
+/// Unauthenticated, since the user's browser follows the broker's redirect;
+/// only the one-time `state` issued with the authorization URL is accepted.
+#[utoipa::path(
+    get,
+    path = "/api/v1/oauth/alpaca/callback",
+    tag = "admin",
+    params(OAuthCallbackQuery),
+    responses(
+        (status = 200, description = "Account connected", body = BrokerAccountResponse),
+        (status = 404, description = "Engine started without an OAuth client", body = ApiErrorResponse),
+        (status = 409, description = "Broker rejected the code", body = ApiErrorResponse),
+        (status = 422, description = "Unknown or expired state, or access denied", body = ApiErrorResponse),
+        (status = 503, description = "Broker token endpoint unreachable", body = ApiErrorResponse),
+    )
+)]
+pub(super) async fn oauth_callback<B, R, O, E, S>(
+    State(state): State<AppState<B, R, O, E, S>>,
+    Query(query): Query<OAuthCallbackQuery>,
+) -> impl IntoResponse
+where
+    B: BrokerPort,
+    R: RiskRepositoryPort,
+    O: OrderRepository,
+    E: EventPublisherPort,
+    S: ScheduledPlanRepositoryPort,
+{
+    let Some(broker_accounts) = &state.broker_accounts else {
+        return broker_accounts_disabled();
+    };
+    let Some(code) = query.code else {
+        return ApiError::new(
+            ErrorKind::InvalidArgument,
+            "OAUTH_ACCESS_DENIED",
+            query
+                .error
+                .unwrap_or_else(|| "Callback carries no authorization code".to_string()),
+        )
+        .into_response();
+    };
+
+    match broker_accounts.complete(&query.state, &code).await {
+        Ok(account) => Json(BrokerAccountResponse::from(account)).into_response(),
+        Err(e) => ApiError::from_error(&e).into_response(),
+    }
+}
+
+fn broker_accounts_disabled() -> Response {
+    ApiError::new(
+        ErrorKind::Disabled,
+        "BROKER_ACCOUNTS_DISABLED",
+        "Engine was started without ALPACA_OAUTH_CLIENT_ID",
+    )
+    .into_response()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::application::ports::{
+        BrokerError, InMemoryOAuthTokenRepository, InMemoryPositionLifecycleRepository,
+        InMemoryRiskRepository, InMemoryScheduledPlanRepository, InMemoryWatchlistRepository,
+        NoOpEventPublisher, OAuthAuthorizationPort, OAuthError, OAuthToken, OrderAck,
+    };
+    use crate::application::services::PositionLifecycleTracker;
+    use crate::domain::execution_tactics::ExecutionProfiles;
+    use crate::domain::order_execution::aggregate::Order;
+    use crate::domain::order_execution::errors::OrderError;
+    use crate::domain::order_execution::value_objects::{Environment, OrderStatus};
+    use crate::domain::shared::{BrokerId, InstrumentId};
+    use crate::domain::stop_enforcement::{MonitoredPosition, PriceMonitor, StopTargetLevels};
+    use crate::infrastructure::auth::ApiKeyEntry;
+    use async_trait::async_trait;
+    use axum::body::Body;
+    use axum::http::{Request, StatusCode};
+    use rust_decimal::Decimal;
+    use std::collections::HashMap;
+    use std::sync::RwLock;
+    use tokio_util::sync::CancellationToken;
+    use tower::ServiceExt;
+
+    // Mock broker
+    struct MockBroker;
+
+    #[async_trait]
+    impl BrokerPort for MockBroker {
+        async fn submit_order(
+            &self,
+            request: crate::application::ports::SubmitOrderRequest,
+        ) -> Result<OrderAck, BrokerError> {
+            Ok(OrderAck {
+                broker_order_id: BrokerId::new("broker-123"),
+                client_order_id: request.client_order_id,
+                status: OrderStatus::Accepted,
+                filled_qty: Decimal::ZERO,
+                avg_fill_price: None,
+                child_order_ids: Vec::new(),
+                leg_fills: Vec::new(),
+            })
+        }
+
+        async fn cancel_order(
+            &self,
+            _request: crate::application::ports::CancelOrderRequest,
+        ) -> Result<(), BrokerError> {
+            Ok(())
+        }
+
+        async fn get_order(&self, _broker_order_id: &BrokerId) -> Result<OrderAck, BrokerError> {
+            Err(BrokerError::OrderNotFound {
+                order_id: "unknown".to_string(),
+            })
+        }
+
+        async fn get_open_orders(&self) -> Result<Vec<OrderAck>, BrokerError> {
+            Ok(vec![])
+        }
+
+        async fn get_buying_power(&self) -> Result<Decimal, BrokerError> {
+            Ok(Decimal::new(100_000, 0))
+        }
+
+        async fn get_position(
+            &self,
+            _instrument_id: &crate::domain::shared::InstrumentId,
+        ) -> Result<Option<Decimal>, BrokerError> {
+            Ok(None)
+        }
+
+        async fn get_all_positions(
+            &self,
+        ) -> Result<Vec<crate::application::ports::PositionInfo>, BrokerError> {
+            Ok(vec![])
+        }
+    }
+
+    // Mock order repository
+    struct MockOrderRepo {
+        orders: RwLock<HashMap<String, Order>>,
+    }
+
+    impl MockOrderRepo {
+        fn new() -> Self {
+            Self {
+                orders: RwLock::new(HashMap::new()),
+            }
+        }
+    }
+
+    #[async_trait]
+    impl OrderRepository for MockOrderRepo {
+        async fn save(&self, order: &Order) -> Result<(), OrderError> {
+            let mut orders = self
+                .orders
+                .write()
+                .unwrap_or_else(std::sync::PoisonError::into_inner);
+            orders.insert(order.id().to_string(), order.clone());
+            Ok(())
+        }
+
+        async fn find_by_id(&self, id: &OrderId) -> Result<Option<Order>, OrderError> {
+            let orders = self
+                .orders
+                .read()
+                .unwrap_or_else(std::sync::PoisonError::into_inner);
+            Ok(orders.get(id.as_str()).cloned())
+        }
+
+        async fn find_by_broker_id(
+            &self,
+            _broker_id: &BrokerId,
+        ) -> Result<Option<Order>, OrderError> {
+            Ok(None)
+        }
+
+        async fn find_by_status(&self, status: OrderStatus) -> Result<Vec<Order>, OrderError> {
+            let orders = self
+                .orders
+                .read()
+                .unwrap_or_else(std::sync::PoisonError::into_inner);
+            Ok(orders
+                .values()
+                .filter(|o| o.status() == status)
+                .cloned()
+                .collect())
+        }
+
+        async fn find_active(&self) -> Result<Vec<Order>, OrderError> {
+            let orders = self
+                .orders
+                .read()
+                .unwrap_or_else(std::sync::PoisonError::into_inner);
+            Ok(orders.values().cloned().collect())
+        }
+
+        async fn exists(&self, id: &OrderId) -> Result<bool, OrderError> {
+            let orders = self
+                .orders
+                .read()
+                .unwrap_or_else(std::sync::PoisonError::into_inner);
+            Ok(orders.contains_key(id.as_str()))
+        }
+
+        async fn delete(&self, id: &OrderId) -> Result<(), OrderError> {
+            let mut orders = self
+                .orders
+                .write()
+                .unwrap_or_else(std::sync::PoisonError::into_inner);
+            orders.remove(id.as_str());
+            Ok(())
+        }
+    }
+
+    fn create_test_state() -> AppState<
+        MockBroker,
+        InMemoryRiskRepository,
+        MockOrderRepo,
+        NoOpEventPublisher,
+        InMemoryScheduledPlanRepository,
+    > {
+        let broker = Arc::new(MockBroker);
+        let risk_repo = Arc::new(InMemoryRiskRepository::new());
+        let order_repo = Arc::new(MockOrderRepo::new());
+        let event_publisher = Arc::new(NoOpEventPublisher);
+
+        let submit_orders = Arc::new(SubmitOrdersUseCase::new(
+            Arc::clone(&broker),
+            Arc::clone(&risk_repo),
+            Arc::clone(&order_repo),
+            Arc::clone(&event_publisher),
+        ));
+
+        let validate_risk = Arc::new(ValidateRiskUseCase::new(
+            Arc::clone(&risk_repo),
+            Arc::clone(&order_repo),
+        ));
+
+        let cancel_orders = Arc::new(CancelOrdersUseCase::new(
+            Arc::clone(&broker),
+            Arc::clone(&order_repo),
+            Arc::clone(&event_publisher),
+        ));
+
+        let cycle_transition = Arc::new(CycleTransitionUseCase::new(
+            Arc::clone(&cancel_orders),
+            Arc::clone(&order_repo),
+        ));
+
+        let plan_repo = Arc::new(InMemoryScheduledPlanRepository::new());
+        let plan_scheduler = Arc::new(PlanSchedulerService::new(
+            Arc::clone(&submit_orders),
+            Arc::clone(&plan_repo),
+            CancellationToken::new(),
+        ));
+
+        let readiness = Arc::new(ReadinessService::new(Arc::clone(&broker), plan_repo));
+        let stop_adjuster = Arc::new(StopAdjuster::new(
+            Arc::clone(&order_repo),
+            Arc::clone(&submit_orders),
+            Arc::clone(&cancel_orders),
+        ));
+
+        AppState {
+            submit_orders,
+            validate_risk,
+            cancel_orders,
+            cycle_transition,
+            plan_scheduler,
+            readiness,
+            kill_switch: Arc::new(KillSwitch::default()),
+            feature_flags: Arc::new(FeatureFlags::default()),
+            stats: Arc::new(SessionStatsService::new(Arc::clone(&order_repo))),
+            plan_progress: Arc::new(PlanProgressService::new(Arc::clone(&order_repo))),
+            trade_blotter: Arc::new(TradeBlotter::new(Arc::clone(&order_repo))),
+            stop_adjuster,
+            shadow_execution: None,
+            config_reloader: None,
+            state_bundle: None,
+            watchlists: Arc::new(WatchlistService::new(Arc::new(
+                InMemoryWatchlistRepository::new(),
+            ))),
+            broker_accounts: None,
+            order_repo,
+            auth: None,
+            idempotency: Arc::new(IdempotencyStore::default()),
+            version: "1.0.0-test".to_string(),
+        }
+    }
+
+    #[tokio::test]
+    async fn health_check_returns_ok() {
+        let state = create_test_state();
+        let app = create_router(state);
+
+        let response = app
+            .oneshot(
+                Request::builder()
+                    .uri("/health")
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::OK);
+    }
+
+    #[tokio::test]
+    async fn readiness_reports_not_ready_until_recovered() {
+        let state = create_test_state();
+        let readiness = Arc::clone(&state.readiness);
+        let app = create_router(state);
+
+        let request = || {
+            Request::builder()
+                .uri("/readyz")
+                .body(Body::empty())
+                .unwrap()
+        };
+
+        let response = app.clone().oneshot(request()).await.unwrap();
+        assert_eq!(response.status(), StatusCode::SERVICE_UNAVAILABLE);
+
+        readiness.mark_recovered();
+
+        let response = app.oneshot(request()).await.unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+        let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        let readiness: ReadinessResponse = serde_json::from_slice(&body).unwrap();
+        assert_eq!(readiness.status, "READY");
+        assert!(readiness.broker.ok);
+    }
+
+    #[tokio::test]
+    async fn session_stats_reports_snapshot() {
+        let state = create_test_state();
+        state.kill_switch.trip("maintenance");
+        let app = create_router(state);
+
+        let response = app
+            .oneshot(
+                Request::builder()
+                    .uri("/api/v1/stats")
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::OK);
+        let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        let snapshot: SessionStatsResponse = serde_json::from_slice(&body).unwrap();
+        assert_eq!(snapshot.orders.unwrap().submitted_today, 0);
+        assert!(snapshot.trading_disabled);
+        assert!(snapshot.last_reconciliation.is_none());
+    }
+
+    fn authenticated_app() -> Router {
+        let key = |name: &str, role| ApiKeyEntry {
+            name: name.to_string(),
+            key: format!("{name}-key"),
+            role,
+            environments: vec![],
+        };
+        let auth = ApiKeyAuthenticator::new(
+            Environment::Paper,
+            vec![key("reader", Role::ReadOnly), key("trader", Role::Trader)],
+        )
+        .unwrap();
+
+        let state = AppState {
+            auth: Some(Arc::new(auth)),
+            ..create_test_state()
+        };
+        create_router(state)
+    }
+
+    fn order_state_request(api_key: Option<&str>) -> Request<Body> {
+        let mut builder = Request::builder()
+            .method("POST")
+            .uri("/api/v1/orders")
+            .header("content-type", "application/json");
+        if let Some(key) = api_key {
+            builder = builder.header("authorization", format!("Bearer {key}"));
+        }
+        builder.body(Body::from(r#"{"order_ids": []}"#)).unwrap()
+    }
+
+    #[tokio::test]
+    async fn auth_rejects_missing_key() {
+        let app = authenticated_app();
+
+        let response = app.oneshot(order_state_request(None)).await.unwrap();
+
+        assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+    }
+
+    #[tokio::test]
+    async fn auth_allows_read_only_queries() {
+        let app = authenticated_app();
+
+        let response = app
+            .oneshot(order_state_request(Some("reader-key")))
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::OK);
+    }
+
+    #[tokio::test]
+    async fn auth_forbids_read_only_submission() {
+        let app = authenticated_app();
+
+        let response = app
+            .oneshot(
+                Request::builder()
+                    .method("POST")
+                    .uri("/api/v1/cancel-orders")
+                    .header("content-type", "application/json")
+                    .header("x-api-key", "reader-key")
+                    .body(Body::from(r#"{"order_ids": []}"#))
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::FORBIDDEN);
+        let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        let error: ApiErrorResponse = serde_json::from_slice(&body).unwrap();
+        assert_eq!(error.code, "FORBIDDEN");
+    }
+
+    #[tokio::test]
+    async fn auth_forbids_trader_kill_switch() {
+        let app = authenticated_app();
+
+        let response = app
+            .oneshot(
+                Request::builder()
+                    .uri("/api/v1/admin/kill-switch")
+                    .header("x-api-key", "trader-key")
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::FORBIDDEN);
+    }
+
+    #[tokio::test]
+    async fn auth_skips_health_endpoints() {
+        let app = authenticated_app();
+
+        let response = app
+            .oneshot(
+                Request::builder()
+                    .uri("/healthz")
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::OK);
+    }
+
+    #[tokio::test]
+    async fn serves_openapi_document() {
+        let app = authenticated_app();
+
+        let response = app
+            .oneshot(
+                Request::builder()
+                    .uri("/openapi.json")
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::OK);
+        let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        let doc: serde_json::Value = serde_json::from_slice(&body).unwrap();
+        assert!(doc["paths"]["/api/v1/submit-orders"]["post"].is_object());
+    }
+
+    #[tokio::test]
+    async fn get_order_state_not_found() {
+        let state = create_test_state();
+        let app = create_router(state);
+
+        let body = serde_json::json!({
+            "order_ids": ["nonexistent-1", "nonexistent-2"]
+        });
+
+        let response = app
+            .oneshot(
+                Request::builder()
+                    .method("POST")
+                    .uri("/api/v1/orders")
+                    .header("content-type", "application/json")
+                    .body(Body::from(serde_json::to_vec(&body).unwrap()))
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::OK);
+
+        let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        let response: GetOrderStateResponse = serde_json::from_slice(&body).unwrap();
+
+        assert!(response.orders.is_empty());
+        assert_eq!(response.not_found.len(), 2);
+    }
+
+    #[tokio::test]
+    async fn submit_orders_endpoint() {
+        let state = create_test_state();
+        let app = create_router(state);
+
+        let body = serde_json::json!({
+            "request_id": "req-123",
+            "cycle_id": "cycle-123",
+            "risk_policy_id": "default",
+            "account_equity": "100000",
+            "decisions": [{
+                "symbol": "AAPL",
+                "side": "BUY",
+                "quantity": "100"
+            }]
+        });
+
+        let response = app
+            .oneshot(
+                Request::builder()
+                    .method("POST")
+                    .uri("/api/v1/submit-orders")
+                    .header("content-type", "application/json")
+                    .body(Body::from(serde_json::to_vec(&body).unwrap()))
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::OK);
+    }
+
+    #[tokio::test]
+    async fn plan_progress_reports_submitted_decisions() {
+        let app = create_router(create_test_state());
+
+        let body = serde_json::json!({
+            "request_id": "req-progress",
+            "cycle_id": "cycle-progress",
+            "risk_policy_id": "default",
+            "account_equity": "100000",
+            "decisions": [{
+                "symbol": "AAPL",
+                "side": "BUY",
+                "quantity": "100"
+            }]
+        });
+        let response = app
+            .clone()
+            .oneshot(
+                Request::builder()
+                    .method("POST")
+                    .uri("/api/v1/submit-orders")
+                    .header("content-type", "application/json")
+                    .body(Body::from(serde_json::to_vec(&body).unwrap()))
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+
+        let get_progress = |cycle_id: &str| {
+            Request::builder()
+                .uri(format!("/api/v1/plans/{cycle_id}/progress"))
+                .body(Body::empty())
+                .unwrap()
+        };
+
+        let response = app
+            .clone()
+            .oneshot(get_progress("cycle-progress"))
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+        let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        let progress: PlanProgressResponse = serde_json::from_slice(&body).unwrap();
+        assert_eq!(progress.decisions.len(), 1);
+        assert_eq!(progress.decisions[0].symbol, "AAPL");
+        assert_eq!(progress.decisions[0].status, "WORKING");
+        assert_eq!(progress.working, 1);
+        assert!(!progress.complete);
+
+        let response = app.oneshot(get_progress("cycle-unknown")).await.unwrap();
+        assert_eq!(response.status(), StatusCode::NOT_FOUND);
+    }
+
+    #[tokio::test]
+    async fn kill_switch_blocks_submission_until_released() {
+        let app = create_router(create_test_state());
+
+        let set_switch = |engaged: bool| {
+            Request::builder()
+                .method("POST")
+                .uri("/api/v1/admin/kill-switch")
+                .header("content-type", "application/json")
+                .body(Body::from(
+                    serde_json::json!({"engaged": engaged, "reason": "desk halt"}).to_string(),
+                ))
+                .unwrap()
+        };
+
+        let response = app.clone().oneshot(set_switch(true)).await.unwrap();
+        let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        let state: KillSwitchResponse = serde_json::from_slice(&body).unwrap();
+        assert!(state.engaged);
+        assert_eq!(state.reason.as_deref(), Some("desk halt"));
+
+        let response = app
+            .clone()
+            .oneshot(idempotent_submit("halted", "AAPL"))
+            .await
+            .unwrap();
+        let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        let submitted: SubmitOrdersResponse = serde_json::from_slice(&body).unwrap();
+        assert!(!submitted.ok);
+        assert!(submitted.orders.is_empty());
+        assert_eq!(
+            submitted.risk_violations.unwrap()[0].code,
+            "TRADING_DISABLED"
+        );
+
+        app.clone().oneshot(set_switch(false)).await.unwrap();
+        let response = app
+            .oneshot(idempotent_submit("released", "AAPL"))
+            .await
+            .unwrap();
+        let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        let submitted: SubmitOrdersResponse = serde_json::from_slice(&body).unwrap();
+        assert!(submitted.risk_violations.is_none());
+    }
+
+    #[tokio::test]
+    async fn circuit_breaker_trip_and_reset() {
+        let state = create_test_state();
+        let breaker = Arc::new(crate::application::services::CircuitBreaker::new());
+        breaker.record_failure();
+        state
+            .stats
+            .register_circuit_breaker("exit_orders", Arc::clone(&breaker));
+        let app = create_router(state);
+
+        let set_breaker = |name: &str, open: bool| {
+            Request::builder()
+                .method("POST")
+                .uri(format!("/api/v1/admin/circuit-breakers/{name}"))
+                .header("content-type", "application/json")
+                .body(Body::from(
+                    serde_json::json!({"open": open, "reason": "broker incident"}).to_string(),
+                ))
+                .unwrap()
+        };
+
+        let response = app
+            .clone()
+            .oneshot(set_breaker("exit_orders", true))
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+        let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        let tripped: CircuitBreakerResponse = serde_json::from_slice(&body).unwrap();
+        assert_eq!(tripped.state, "OPEN");
+        assert!(tripped.manually_open);
+        assert_eq!(tripped.failure_rate, Decimal::ONE);
+        assert!(!breaker.can_execute());
+
+        app.clone()
+            .oneshot(set_breaker("exit_orders", false))
+            .await
+            .unwrap();
+        let response = app
+            .clone()
+            .oneshot(
+                Request::builder()
+                    .uri("/api/v1/circuit-breakers")
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+        let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        let listed: CircuitBreakersResponse = serde_json::from_slice(&body).unwrap();
+        assert_eq!(listed.circuit_breakers.len(), 1);
+        assert_eq!(listed.circuit_breakers[0].state, "CLOSED");
+        assert_eq!(listed.circuit_breakers[0].failure_count, 0);
+        assert_eq!(listed.circuit_breakers[0].state_changes, 2);
+
+        let response = app.oneshot(set_breaker("unknown", true)).await.unwrap();
+        assert_eq!(response.status(), StatusCode::NOT_FOUND);
+    }
+
+    #[tokio::test]
+    async fn feature_flags_override_and_reset() {
+        let state = create_test_state();
+        let flags = Arc::clone(&state.feature_flags);
+        let app = create_router(state);
+
+        let flag_request = |method: &str, name: &str, body: Body| {
+            Request::builder()
+                .method(method)
+                .uri(format!("/api/v1/admin/feature-flags/{name}"))
+                .header("content-type", "application/json")
+                .body(body)
+                .unwrap()
+        };
+        let disable = || {
+            Body::from(serde_json::json!({"enabled": false, "reason": "noisy fills"}).to_string())
+        };
+
+        let response = app
+            .clone()
+            .oneshot(flag_request("POST", "shadow_execution", disable()))
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+        let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        let flag: FeatureFlagResponse = serde_json::from_slice(&body).unwrap();
+        assert!(!flag.enabled && flag.default);
+        assert_eq!(flag.reason.as_deref(), Some("noisy fills"));
+        assert!(!flags.is_enabled(Feature::ShadowExecution));
+
+        let response = app
+            .clone()
+            .oneshot(
+                Request::builder()
+                    .uri("/api/v1/admin/feature-flags")
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+        let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        let listed: FeatureFlagsResponse = serde_json::from_slice(&body).unwrap();
+        assert_eq!(listed.flags.len(), Feature::ALL.len());
+
+        app.clone()
+            .oneshot(flag_request("DELETE", "shadow_execution", Body::empty()))
+            .await
+            .unwrap();
+        assert!(flags.is_enabled(Feature::ShadowExecution));
+
+        let response = app
+            .oneshot(flag_request("POST", "auto_trading", disable()))
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::NOT_FOUND);
+    }
+
+    #[tokio::test]
+    async fn reload_config_requires_config_file() {
+        let app = create_router(create_test_state());
+
+        let response = app
+            .oneshot(
+                Request::builder()
+                    .method("POST")
+                    .uri("/api/v1/admin/reload-config")
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::NOT_FOUND);
+        let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        let error: ApiErrorResponse = serde_json::from_slice(&body).unwrap();
+        assert_eq!(error.code, "CONFIG_NOT_LOADED");
+    }
+
+    #[tokio::test]
+    async fn shadow_execution_report_requires_configuration() {
+        let request = || {
+            Request::builder()
+                .uri("/api/v1/shadow-execution")
+                .body(Body::empty())
+                .unwrap()
+        };
+
+        let state = create_test_state();
+        let response = create_router(state.clone())
+            .oneshot(request())
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::NOT_FOUND);
+
+        let app = create_router(AppState {
+            shadow_execution: Some(Arc::new(ShadowExecution::new(ExecutionProfiles::default()))),
+            ..state
+        });
+        let response = app.oneshot(request()).await.unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+        let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        let report: ShadowExecutionResponse = serde_json::from_slice(&body).unwrap();
+        assert_eq!(report.compared, 0);
+        assert!(report.orders.is_empty());
+    }
+
+    #[tokio::test]
+    async fn state_export_round_trips_and_rejects_tampering() {
+        let state = create_test_state();
+        let state_bundle = StateBundleUseCase::new(
+            Arc::new(MockBroker),
+            Arc::clone(&state.order_repo),
+            Arc::new(PositionLifecycleTracker::new(Arc::new(
+                InMemoryPositionLifecycleRepository::new(),
+            ))),
+            Environment::Paper,
+            "test-key",
+        );
+        let app = create_router(AppState {
+            state_bundle: Some(Arc::new(state_bundle)),
+            ..state
+        });
+
+        let response = app
+            .clone()
+            .oneshot(
+                Request::builder()
+                    .uri("/api/v1/admin/state")
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+        let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        let exported: StateBundleResponse = serde_json::from_slice(&body).unwrap();
+
+        let import = |signature: &str| {
+            let body = serde_json::json!({
+                "bundle": exported.bundle,
+                "signature": signature,
+            });
+            Request::builder()
+                .method("POST")
+                .uri("/api/v1/admin/state")
+                .header("content-type", "application/json")
+                .body(Body::from(serde_json::to_vec(&body).unwrap()))
+                .unwrap()
+        };
+
+        let response = app.clone().oneshot(import("00")).await.unwrap();
+        assert_eq!(response.status(), StatusCode::UNPROCESSABLE_ENTITY);
+        let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        let error: ApiErrorResponse = serde_json::from_slice(&body).unwrap();
+        assert_eq!(error.code, "INVALID_SIGNATURE");
+
+        let response = app.oneshot(import(&exported.signature)).await.unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+    }
+
+    fn idempotent_submit(key: &str, symbol: &str) -> Request<Body> {
+        let body = serde_json::json!({
+            "request_id": "req-idem",
+            "cycle_id": "cycle-idem",
+            "risk_policy_id": "default",
+            "account_equity": "100000",
+            "decisions": [{
+                "symbol": symbol,
+                "side": "BUY",
+                "quantity": "10"
+            }]
+        });
+
+        Request::builder()
+            .method("POST")
+            .uri("/api/v1/submit-orders")
+            .header("content-type", "application/json")
+            .header("idempotency-key", key)
+            .body(Body::from(serde_json::to_vec(&body).unwrap()))
+            .unwrap()
+    }
+
+    #[tokio::test]
+    async fn submit_orders_replays_idempotent_retry() {
+        let state = create_test_state();
+        let idempotency = Arc::clone(&state.idempotency);
+        let app = create_router(state);
+
+        let first = app
+            .clone()
+            .oneshot(idempotent_submit("plan-1", "AAPL"))
+            .await
+            .unwrap();
+        assert_eq!(first.status(), StatusCode::OK);
+        assert!(first.headers().get("idempotent-replayed").is_none());
+        let first_body = axum::body::to_bytes(first.into_body(), usize::MAX)
+            .await
+            .unwrap();
+
+        let retry = app
+            .oneshot(idempotent_submit("plan-1", "AAPL"))
+            .await
+            .unwrap();
+        assert_eq!(retry.status(), StatusCode::OK);
+        assert_eq!(retry.headers()["idempotent-replayed"], "true");
+        let retry_body = axum::body::to_bytes(retry.into_body(), usize::MAX)
+            .await
+            .unwrap();
+
+        assert_eq!(first_body, retry_body);
+        assert_eq!(idempotency.len(), 1);
+    }
+
+    #[tokio::test]
+    async fn submit_orders_rejects_reused_idempotency_key() {
+        let app = create_router(create_test_state());
+
+        let first = app
+            .clone()
+            .oneshot(idempotent_submit("plan-2", "AAPL"))
+            .await
+            .unwrap();
+        assert_eq!(first.status(), StatusCode::OK);
+
+        let reused = app
+            .oneshot(idempotent_submit("plan-2", "MSFT"))
+            .await
+            .unwrap();
+        assert_eq!(reused.status(), StatusCode::UNPROCESSABLE_ENTITY);
+        let body = axum::body::to_bytes(reused.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        let error: ApiErrorResponse = serde_json::from_slice(&body).unwrap();
+        assert_eq!(error.code, "IDEMPOTENCY_KEY_REUSED");
+    }
+
+    #[tokio::test]
+    async fn submit_orders_rejects_invalid_fields() {
+        let app = create_router(create_test_state());
+
+        let body = serde_json::json!({
+            "request_id": "req-invalid",
+            "cycle_id": "cycle-invalid",
+            "risk_policy_id": "default",
+            "account_equity": "100000",
+            "decisions": [{
+                "symbol": "AAPL",
+                "side": "BUY",
+                "order_type": "LIMIT",
+                "quantity": "0"
+            }]
+        });
+
+        let response = app
+            .oneshot(
+                Request::builder()
+                    .method("POST")
+                    .uri("/api/v1/submit-orders")
+                    .header("content-type", "application/json")
+                    .body(Body::from(serde_json::to_vec(&body).unwrap()))
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::UNPROCESSABLE_ENTITY);
+        let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        let error: ApiErrorResponse = serde_json::from_slice(&body).unwrap();
+        assert_eq!(error.code, "VALIDATION_FAILED");
+        let fields: Vec<_> = error.errors.iter().map(|e| e.field.as_str()).collect();
+        assert_eq!(
+            fields,
+            ["decisions[0].quantity", "decisions[0].limit_price"]
+        );
+    }
+
+    #[tokio::test]
+    async fn malformed_json_is_rejected() {
+        let app = create_router(create_test_state());
+
+        let response = app
+            .oneshot(
+                Request::builder()
+                    .method("POST")
+                    .uri("/api/v1/orders")
+                    .header("content-type", "application/json")
+                    .body(Body::from(r#"{"order_ids": ["#))
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+        let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        let error: ApiErrorResponse = serde_json::from_slice(&body).unwrap();
+        assert_eq!(error.code, "MALFORMED_JSON");
+    }
+
+    #[tokio::test]
+    async fn submit_orders_with_execute_at_is_scheduled() {
+        let state = create_test_state();
+        let scheduler = Arc::clone(&state.plan_scheduler);
+        let app = create_router(state);
+
+        let execute_at = Timestamp::new(chrono::Utc::now() + chrono::Duration::hours(1));
+        let body = serde_json::json!({
+            "request_id": "req-sched",
+            "cycle_id": "cycle-123",
+            "risk_policy_id": "default",
+            "account_equity": "100000",
+            "decisions": [{
+                "symbol": "AAPL",
+                "side": "BUY",
+                "quantity": "100"
+            }],
+            "execute_at": execute_at.to_rfc3339(),
+            "execution_window_secs": 600
+        });
 
-        let validate_risk = Arc::new(ValidateRiskUseCase::new(
-            Arc::clone(&risk_repo),
-            Arc::clone(&order_repo),
-        ));
+        let response = app
+            .oneshot(
+                Request::builder()
+                    .method("POST")
+                    .uri("/api/v1/submit-orders")
+                    .header("content-type", "application/json")
+                    .body(Body::from(serde_json::to_vec(&body).unwrap()))
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
 
-        let cancel_orders = Arc::new(CancelOrdersUseCase::new(
-            Arc::clone(&broker),
-            Arc::clone(&order_repo),
-            Arc::clone(&event_publisher),
-        ));
+        assert_eq!(response.status(), StatusCode::ACCEPTED);
 
-        AppState {
-            submit_orders,
-            validate_risk,
-            cancel_orders,
-            order_repo,
-            version: "1.0.0-test".to_string(),
-        }
+        let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        let response: SubmitOrdersResponse = serde_json::from_slice(&body).unwrap();
+        assert!(response.ok);
+        assert!(response.orders.is_empty());
+        assert_eq!(response.scheduled_plan_id.as_deref(), Some("req-sched"));
+
+        let pending = scheduler.pending().await.unwrap();
+        assert_eq!(pending.len(), 1);
+        assert_eq!(pending[0].request.orders.len(), 1);
     }
 
     #[tokio::test]
-    async fn health_check_returns_ok() {
+    async fn cancel_scheduled_plan_removes_pending_plan() {
         let state = create_test_state();
+        let scheduler = Arc::clone(&state.plan_scheduler);
         let app = create_router(state);
 
+        let execute_at = Timestamp::new(chrono::Utc::now() + chrono::Duration::hours(1));
+        let body = serde_json::json!({
+            "request_id": "req-cancel",
+            "cycle_id": "cycle-123",
+            "risk_policy_id": "default",
+            "account_equity": "100000",
+            "decisions": [],
+            "execute_at": execute_at.to_rfc3339()
+        });
         let response = app
+            .clone()
             .oneshot(
                 Request::builder()
-                    .uri("/health")
-                    .body(Body::empty())
+                    .method("POST")
+                    .uri("/api/v1/submit-orders")
+                    .header("content-type", "application/json")
+                    .body(Body::from(serde_json::to_vec(&body).unwrap()))
                     .unwrap(),
             )
             .await
             .unwrap();
+        assert_eq!(response.status(), StatusCode::ACCEPTED);
+
+        let cancel = || {
+            Request::builder()
+                .method("DELETE")
+                .uri("/api/v1/plans/scheduled/req-cancel")
+                .body(Body::empty())
+                .unwrap()
+        };
 
-        assert_eq!(response.status(), StatusCode::OK);
+        let response = app.clone().oneshot(cancel()).await.unwrap();
+        assert_eq!(response.status(), StatusCode::NO_CONTENT);
+        assert!(scheduler.pending().await.unwrap().is_empty());
+
+        let response = app.oneshot(cancel()).await.unwrap();
+        assert_eq!(response.status(), StatusCode::NOT_FOUND);
+        let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        let error: ApiErrorResponse = serde_json::from_slice(&body).unwrap();
+        assert_eq!(error.code, "PLAN_NOT_FOUND");
     }
 
     #[tokio::test]
-    async fn get_order_state_not_found() {
+    async fn submit_orders_with_missed_window_is_rejected() {
         let state = create_test_state();
         let app = create_router(state);
 
         let body = serde_json::json!({
-            "order_ids": ["nonexistent-1", "nonexistent-2"]
+            "request_id": "req-late",
+            "cycle_id": "cycle-123",
+            "risk_policy_id": "default",
+            "account_equity": "100000",
+            "decisions": [],
+            "execute_at": "2020-01-02T20:50:00Z"
         });
 
         let response = app
             .oneshot(
                 Request::builder()
                     .method("POST")
-                    .uri("/api/v1/orders")
+                    .uri("/api/v1/submit-orders")
                     .header("content-type", "application/json")
                     .body(Body::from(serde_json::to_vec(&body).unwrap()))
                     .unwrap(),
@@ -609,32 +3117,30 @@ mod tests {
             .await
             .unwrap();
 
-        assert_eq!(response.status(), StatusCode::OK);
+        assert_eq!(response.status(), StatusCode::CONFLICT);
 
         let body = axum::body::to_bytes(response.into_body(), usize::MAX)
             .await
             .unwrap();
-        let response: GetOrderStateResponse = serde_json::from_slice(&body).unwrap();
-
-        assert!(response.orders.is_empty());
-        assert_eq!(response.not_found.len(), 2);
+        let error: ApiErrorResponse = serde_json::from_slice(&body).unwrap();
+        assert_eq!(error.code, "EXECUTION_WINDOW_MISSED");
+        assert!(error.message.contains("window missed"));
     }
 
     #[tokio::test]
-    async fn submit_orders_endpoint() {
+    async fn submit_orders_with_invalid_window_is_unprocessable() {
         let state = create_test_state();
         let app = create_router(state);
 
+        let execute_at = Timestamp::new(chrono::Utc::now() + chrono::Duration::hours(1));
         let body = serde_json::json!({
-            "request_id": "req-123",
+            "request_id": "req-zero",
             "cycle_id": "cycle-123",
             "risk_policy_id": "default",
             "account_equity": "100000",
-            "decisions": [{
-                "symbol": "AAPL",
-                "side": "BUY",
-                "quantity": "100"
-            }]
+            "decisions": [],
+            "execute_at": execute_at.to_rfc3339(),
+            "execution_window_secs": u64::MAX
         });
 
         let response = app
@@ -649,7 +3155,94 @@ mod tests {
             .await
             .unwrap();
 
+        assert_eq!(response.status(), StatusCode::UNPROCESSABLE_ENTITY);
+
+        let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        let error: ApiErrorResponse = serde_json::from_slice(&body).unwrap();
+        assert_eq!(error.code, "INVALID_EXECUTION_WINDOW");
+    }
+
+    #[tokio::test]
+    async fn adjust_stops_moves_monitored_levels() {
+        let state = create_test_state();
+        let mut monitor = PriceMonitor::new();
+        monitor.add_position(MonitoredPosition::new(
+            OrderId::new("pos-1"),
+            InstrumentId::new("AAPL"),
+            Decimal::new(100, 0),
+            StopTargetLevels::for_long(
+                Decimal::new(100, 0),
+                Decimal::new(95, 0),
+                Decimal::new(110, 0),
+            ),
+        ));
+        let monitor = Arc::new(parking_lot::RwLock::new(monitor));
+        let stop_adjuster = StopAdjuster::new(
+            Arc::clone(&state.order_repo),
+            Arc::clone(&state.submit_orders),
+            Arc::clone(&state.cancel_orders),
+        )
+        .with_price_monitor(Arc::clone(&monitor));
+        let app = create_router(AppState {
+            stop_adjuster: Arc::new(stop_adjuster),
+            ..state
+        });
+
+        let request = |symbol: &str, body: serde_json::Value| {
+            Request::builder()
+                .method("POST")
+                .uri(format!("/api/v1/positions/{symbol}/stops"))
+                .header("content-type", "application/json")
+                .body(Body::from(serde_json::to_vec(&body).unwrap()))
+                .unwrap()
+        };
+
+        let response = app
+            .clone()
+            .oneshot(request(
+                "AAPL",
+                serde_json::json!({"stop_loss": "98", "reason": "tighten into close"}),
+            ))
+            .await
+            .unwrap();
         assert_eq!(response.status(), StatusCode::OK);
+        let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        let adjusted: AdjustStopsResponse = serde_json::from_slice(&body).unwrap();
+        assert_eq!(adjusted.monitored, ["pos-1"]);
+        assert!(adjusted.errors.is_empty());
+        assert_eq!(
+            monitor
+                .read()
+                .get_position(&OrderId::new("pos-1"))
+                .unwrap()
+                .levels()
+                .stop_loss,
+            Decimal::new(98, 0)
+        );
+
+        let response = app
+            .clone()
+            .oneshot(request("AAPL", serde_json::json!({"stop_loss": "111"})))
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::UNPROCESSABLE_ENTITY);
+
+        let response = app
+            .clone()
+            .oneshot(request("AAPL", serde_json::json!({})))
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::UNPROCESSABLE_ENTITY);
+
+        let response = app
+            .oneshot(request("TSLA", serde_json::json!({"stop_loss": "200"})))
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::NOT_FOUND);
     }
 
     #[tokio::test]
@@ -747,7 +3340,7 @@ mod tests {
         use crate::domain::order_execution::value_objects::{
             OrderPurpose, OrderSide, OrderType, TimeInForce,
         };
-        use crate::domain::shared::{Quantity, Symbol};
+        use crate::domain::shared::Quantity;
 
         let state = create_test_state();
 
@@ -797,6 +3390,138 @@ mod tests {
         assert_eq!(response.orders[0].symbol, "AAPL");
     }
 
+    #[tokio::test]
+    async fn query_orders_filters_and_pages() {
+        use crate::domain::order_execution::aggregate::{CreateOrderCommand, Order};
+        use crate::domain::order_execution::value_objects::{
+            OrderPurpose, OrderSide, OrderType, TimeInForce,
+        };
+        use crate::domain::shared::Quantity;
+
+        let state = create_test_state();
+        for symbol in ["AAPL", "AAPL", "MSFT"] {
+            let mut order = Order::new(CreateOrderCommand {
+                symbol: Symbol::new(symbol),
+                side: OrderSide::Buy,
+                order_type: OrderType::Market,
+                quantity: Quantity::from_i64(10),
+                limit_price: None,
+                stop_price: None,
+                time_in_force: TimeInForce::Day,
+                purpose: OrderPurpose::Entry,
+                legs: vec![],
+            })
+            .unwrap();
+            order.tag_cycle(CycleId::new("cycle-1"));
+            state.order_repo.save(&order).await.unwrap();
+        }
+        let app = create_router(state);
+        let query = |body: serde_json::Value| {
+            Request::builder()
+                .method("POST")
+                .uri("/api/v1/orders/query")
+                .header("content-type", "application/json")
+                .body(Body::from(serde_json::to_vec(&body).unwrap()))
+                .unwrap()
+        };
+
+        let response = app
+            .clone()
+            .oneshot(query(serde_json::json!({
+                "symbol": "AAPL",
+                "statuses": ["NEW"],
+                "cycle_id": "cycle-1",
+                "limit": 1
+            })))
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+        let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        let page: OrderHistoryResponse = serde_json::from_slice(&body).unwrap();
+        assert_eq!(page.total, 2);
+        assert_eq!(page.next_offset, Some(1));
+        assert_eq!(page.orders[0].symbol, "AAPL");
+        assert_eq!(page.orders[0].cycle_id.as_deref(), Some("cycle-1"));
+
+        let response = app
+            .oneshot(query(serde_json::json!({ "limit": 5000 })))
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::UNPROCESSABLE_ENTITY);
+    }
+
+    #[tokio::test]
+    async fn export_blotter_returns_csv_of_fills() {
+        use crate::domain::order_execution::aggregate::{CreateOrderCommand, Order};
+        use crate::domain::order_execution::value_objects::{
+            FillReport, OrderPurpose, OrderSide, OrderType, TimeInForce,
+        };
+        use crate::domain::shared::{BrokerId, Money, Quantity};
+
+        let state = create_test_state();
+        let mut order = Order::new(CreateOrderCommand {
+            symbol: Symbol::new("AAPL"),
+            side: OrderSide::Buy,
+            order_type: OrderType::Market,
+            quantity: Quantity::from_i64(10),
+            limit_price: None,
+            stop_price: None,
+            time_in_force: TimeInForce::Day,
+            purpose: OrderPurpose::Entry,
+            legs: vec![],
+        })
+        .unwrap();
+        order.accept(BrokerId::new("broker-1")).unwrap();
+        order
+            .apply_fill(FillReport::new(
+                "fill-1",
+                Quantity::from_i64(10),
+                Money::new(rust_decimal::Decimal::new(150, 0)),
+                Timestamp::parse("2026-03-02T15:00:00Z").unwrap(),
+                "TEST",
+            ))
+            .unwrap();
+        state.order_repo.save(&order).await.unwrap();
+        let app = create_router(state);
+        let export = |body: serde_json::Value| {
+            Request::builder()
+                .method("POST")
+                .uri("/api/v1/blotter")
+                .header("content-type", "application/json")
+                .body(Body::from(serde_json::to_vec(&body).unwrap()))
+                .unwrap()
+        };
+
+        let response = app
+            .clone()
+            .oneshot(export(serde_json::json!({
+                "from": "2026-03-02T00:00:00Z",
+                "to": "2026-03-03T00:00:00Z"
+            })))
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+        assert_eq!(response.headers()["content-type"], "text/csv");
+        let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        let csv = String::from_utf8(body.to_vec()).unwrap();
+        assert_eq!(csv.lines().count(), 2);
+        assert!(csv.lines().nth(1).unwrap().contains(",fill-1,"));
+
+        let response = app
+            .oneshot(export(serde_json::json!({
+                "from": "2026-03-03T00:00:00Z",
+                "to": "2026-03-02T00:00:00Z",
+                "format": "PARQUET"
+            })))
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::UNPROCESSABLE_ENTITY);
+    }
+
     #[tokio::test]
     async fn submit_orders_with_multiple_decisions() {
         let state = create_test_state();
@@ -1011,4 +3736,210 @@ mod tests {
         // Empty decisions should result in success with no orders
         assert!(response.orders.is_empty());
     }
+
+    #[tokio::test]
+    async fn watchlist_crud() {
+        let app = create_router(create_test_state());
+        let request = |method: &str, uri: &str, body: Option<serde_json::Value>| {
+            let builder = Request::builder().method(method).uri(uri);
+            match body {
+                Some(body) => builder
+                    .header("content-type", "application/json")
+                    .body(Body::from(serde_json::to_vec(&body).unwrap())),
+                None => builder.body(Body::empty()),
+            }
+            .unwrap()
+        };
+        let watchlist = |response: axum::response::Response| async move {
+            let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+                .await
+                .unwrap();
+            serde_json::from_slice::<WatchlistResponse>(&body).unwrap()
+        };
+
+        let response = app
+            .clone()
+            .oneshot(request(
+                "POST",
+                "/api/v1/watchlists",
+                Some(serde_json::json!({"name": "tech", "symbols": ["aapl", "MSFT"]})),
+            ))
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::CREATED);
+        assert_eq!(watchlist(response).await.symbols, ["AAPL", "MSFT"]);
+
+        let response = app
+            .clone()
+            .oneshot(request(
+                "POST",
+                "/api/v1/watchlists",
+                Some(serde_json::json!({"name": "tech"})),
+            ))
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::CONFLICT);
+
+        let response = app
+            .clone()
+            .oneshot(request(
+                "POST",
+                "/api/v1/watchlists/tech/symbols",
+                Some(serde_json::json!({"symbols": ["NVDA"]})),
+            ))
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+        assert_eq!(watchlist(response).await.symbols, ["AAPL", "MSFT", "NVDA"]);
+
+        let response = app
+            .clone()
+            .oneshot(request(
+                "DELETE",
+                "/api/v1/watchlists/tech/symbols/AAPL",
+                None,
+            ))
+            .await
+            .unwrap();
+        assert_eq!(watchlist(response).await.symbols, ["MSFT", "NVDA"]);
+
+        let response = app
+            .clone()
+            .oneshot(request("GET", "/api/v1/watchlists", None))
+            .await
+            .unwrap();
+        let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        let all: WatchlistsResponse = serde_json::from_slice(&body).unwrap();
+        assert_eq!(all.watchlists.len(), 1);
+
+        let response = app
+            .clone()
+            .oneshot(request("DELETE", "/api/v1/watchlists/tech", None))
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::NO_CONTENT);
+
+        let response = app
+            .oneshot(request("GET", "/api/v1/watchlists/tech", None))
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::NOT_FOUND);
+    }
+
+    struct StaticAuthorizer;
+
+    #[async_trait]
+    impl OAuthAuthorizationPort for StaticAuthorizer {
+        fn authorize_url(&self, state: &str) -> String {
+            format!("https://broker.example/oauth/authorize?state={state}")
+        }
+
+        async fn exchange_code(&self, code: &str) -> Result<OAuthToken, OAuthError> {
+            if code != "good-code" {
+                return Err(OAuthError::Rejected {
+                    message: "invalid_grant".to_string(),
+                });
+            }
+            Ok(OAuthToken {
+                access_token: "access".to_string(),
+                refresh_token: None,
+                scope: "trading".to_string(),
+                obtained_at: Timestamp::now(),
+                expires_at: None,
+            })
+        }
+
+        async fn refresh(&self, _refresh_token: &str) -> Result<OAuthToken, OAuthError> {
+            Err(OAuthError::Rejected {
+                message: "invalid_grant".to_string(),
+            })
+        }
+    }
+
+    #[tokio::test]
+    async fn broker_accounts_connect_through_callback() {
+        let request = |method: &str, uri: &str| {
+            Request::builder()
+                .method(method)
+                .uri(uri)
+                .body(Body::empty())
+                .unwrap()
+        };
+
+        let app = create_router(create_test_state());
+        let response = app
+            .oneshot(request("GET", "/api/v1/admin/broker-accounts"))
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::NOT_FOUND);
+
+        let app = create_router(AppState {
+            broker_accounts: Some(Arc::new(BrokerAccountService::new(
+                Arc::new(StaticAuthorizer),
+                Arc::new(InMemoryOAuthTokenRepository::new()),
+            ))),
+            ..create_test_state()
+        });
+
+        let response = app
+            .clone()
+            .oneshot(request(
+                "POST",
+                "/api/v1/admin/broker-accounts/alice/authorize",
+            ))
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+        let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        let authorization: BrokerAuthorizationResponse = serde_json::from_slice(&body).unwrap();
+        let (_, state) = authorization.authorize_url.split_once("state=").unwrap();
+        let callback = |state: &str| {
+            request(
+                "GET",
+                &format!("/api/v1/oauth/alpaca/callback?code=good-code&state={state}"),
+            )
+        };
+
+        let response = app.clone().oneshot(callback("forged")).await.unwrap();
+        assert_eq!(response.status(), StatusCode::UNPROCESSABLE_ENTITY);
+
+        let response = app.clone().oneshot(callback(state)).await.unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+        let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        let account: BrokerAccountResponse = serde_json::from_slice(&body).unwrap();
+        assert_eq!(account.user_id, "alice");
+
+        // States are single use
+        let response = app.clone().oneshot(callback(state)).await.unwrap();
+        assert_eq!(response.status(), StatusCode::UNPROCESSABLE_ENTITY);
+
+        let response = app
+            .clone()
+            .oneshot(request("GET", "/api/v1/admin/broker-accounts"))
+            .await
+            .unwrap();
+        let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        let accounts: BrokerAccountsResponse = serde_json::from_slice(&body).unwrap();
+        assert_eq!(accounts.accounts.len(), 1);
+
+        let response = app
+            .clone()
+            .oneshot(request("DELETE", "/api/v1/admin/broker-accounts/alice"))
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::NO_CONTENT);
+        let response = app
+            .oneshot(request("DELETE", "/api/v1/admin/broker-accounts/alice"))
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::NOT_FOUND);
+    }
 }
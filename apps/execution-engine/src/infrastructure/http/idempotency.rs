@@ -0,0 +1,358 @@
+//! HTTP idempotency middleware.
+//!
+//! Clients may send an `Idempotency-Key` header with a POST. The first
+//! response for a key is stored for a TTL and replayed verbatim on retries,
+//! so a client that times out and retries cannot submit the same plan twice.
+//! Keys are scoped to the caller and route. Reusing a key with a different
+//! body is rejected, as is a retry that arrives while the original request
+//! is still running.
+
+use std::collections::HashMap;
+use std::hash::{DefaultHasher, Hash, Hasher};
+use std::sync::{Arc, Mutex, PoisonError};
+use std::time::{Duration, Instant};
+
+use axum::{
+    body::{Body, Bytes},
+    extract::{Request, State},
+    http::{HeaderValue, StatusCode, header::CONTENT_TYPE},
+    middleware::Next,
+    response::{IntoResponse, Response},
+};
+
+use crate::domain::shared::ErrorKind;
+use crate::infrastructure::api_error::ApiError;
+use crate::infrastructure::auth::Principal;
+
+/// Header carrying the client's idempotency key.
+pub const IDEMPOTENCY_KEY_HEADER: &str = "idempotency-key";
+
+/// Header set to `true` on responses replayed from the store.
+pub const IDEMPOTENT_REPLAYED_HEADER: &str = "idempotent-replayed";
+
+/// Default time a response is kept for replay.
+pub const DEFAULT_IDEMPOTENCY_TTL: Duration = Duration::from_secs(24 * 60 * 60);
+
+/// Maximum idempotency key length.
+const MAX_KEY_LEN: usize = 255;
+
+/// Maximum request or response body buffered for idempotent requests.
+const MAX_BODY_BYTES: usize = 2 * 1024 * 1024;
+
+/// A stored response.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CachedResponse {
+    /// HTTP status.
+    pub status: StatusCode,
+    /// `Content-Type` header, if any.
+    pub content_type: Option<HeaderValue>,
+    /// Response body.
+    pub body: Bytes,
+}
+
+/// Outcome of starting an idempotent request.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum IdempotencyStart {
+    /// First request for the key; the caller must complete or release it.
+    Started,
+    /// The key has a stored response for the same request.
+    Replay(CachedResponse),
+    /// A request with the key is still running.
+    InProgress,
+    /// The key was used with a different request body.
+    Mismatch,
+}
+
+#[derive(Debug)]
+enum EntryState {
+    InFlight,
+    Completed(CachedResponse),
+}
+
+#[derive(Debug)]
+struct Entry {
+    fingerprint: u64,
+    state: EntryState,
+    expires_at: Instant,
+}
+
+/// In-memory store of responses keyed by idempotency key.
+#[derive(Debug)]
+pub struct IdempotencyStore {
+    ttl: Duration,
+    entries: Mutex<HashMap<String, Entry>>,
+}
+
+impl IdempotencyStore {
+    /// Create a store that keeps responses for `ttl`.
+    #[must_use]
+    pub fn new(ttl: Duration) -> Self {
+        Self {
+            ttl,
+            entries: Mutex::new(HashMap::new()),
+        }
+    }
+
+    /// How long responses are kept.
+    #[must_use]
+    pub const fn ttl(&self) -> Duration {
+        self.ttl
+    }
+
+    /// Number of live entries, including in-flight requests.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        let now = Instant::now();
+        self.lock()
+            .values()
+            .filter(|entry| entry.expires_at > now)
+            .count()
+    }
+
+    /// Check if the store has no live entries.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Claim `key` for a request whose body hashes to `fingerprint`.
+    pub fn start(&self, key: &str, fingerprint: u64) -> IdempotencyStart {
+        let now = Instant::now();
+        let mut entries = self.lock();
+        entries.retain(|_, entry| entry.expires_at > now);
+
+        if let Some(entry) = entries.get(key) {
+            if entry.fingerprint != fingerprint {
+                return IdempotencyStart::Mismatch;
+            }
+            return match &entry.state {
+                EntryState::InFlight => IdempotencyStart::InProgress,
+                EntryState::Completed(response) => IdempotencyStart::Replay(response.clone()),
+            };
+        }
+
+        entries.insert(
+            key.to_string(),
+            Entry {
+                fingerprint,
+                state: EntryState::InFlight,
+                expires_at: now + self.ttl,
+            },
+        );
+        IdempotencyStart::Started
+    }
+
+    /// Store the response for a started key.
+    pub fn complete(&self, key: &str, response: CachedResponse) {
+        if let Some(entry) = self.lock().get_mut(key) {
+            entry.state = EntryState::Completed(response);
+            entry.expires_at = Instant::now() + self.ttl;
+        }
+    }
+
+    /// Forget a started key so the request can be retried.
+    pub fn release(&self, key: &str) {
+        let mut entries = self.lock();
+        if matches!(
+            entries.get(key).map(|entry| &entry.state),
+            Some(EntryState::InFlight)
+        ) {
+            entries.remove(key);
+        }
+    }
+
+    fn lock(&self) -> std::sync::MutexGuard<'_, HashMap<String, Entry>> {
+        self.entries.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+}
+
+impl Default for IdempotencyStore {
+    fn default() -> Self {
+        Self::new(DEFAULT_IDEMPOTENCY_TTL)
+    }
+}
+
+/// Releases an in-flight key if the request is dropped before completing.
+struct InFlightGuard<'a> {
+    store: &'a IdempotencyStore,
+    key: &'a str,
+    done: bool,
+}
+
+impl Drop for InFlightGuard<'_> {
+    fn drop(&mut self) {
+        if !self.done {
+            self.store.release(self.key);
+        }
+    }
+}
+
+/// Replay stored responses for requests carrying an `Idempotency-Key`.
+///
+/// Requests without the header pass through unchanged. Server errors are
+/// not stored so the client can retry them.
+pub async fn idempotency(
+    State(store): State<Arc<IdempotencyStore>>,
+    request: Request,
+    next: Next,
+) -> Response {
+    let Some(key) = request.headers().get(IDEMPOTENCY_KEY_HEADER) else {
+        return next.run(request).await;
+    };
+    let key = match key.to_str().map(str::trim) {
+        Ok(key) if !key.is_empty() && key.len() <= MAX_KEY_LEN => key.to_string(),
+        _ => {
+            return error_response(
+                ErrorKind::MalformedRequest,
+                "INVALID_IDEMPOTENCY_KEY",
+                format!("Idempotency-Key must be 1-{MAX_KEY_LEN} visible ASCII characters"),
+            );
+        }
+    };
+
+    let caller = request
+        .extensions()
+        .get::<Principal>()
+        .map_or("anonymous", |p| p.name.as_str());
+    let scoped_key = format!(
+        "{caller}:{} {}:{key}",
+        request.method(),
+        request.uri().path()
+    );
+
+    let (parts, body) = request.into_parts();
+    let Ok(body) = axum::body::to_bytes(body, MAX_BODY_BYTES).await else {
+        return error_response(
+            ErrorKind::PayloadTooLarge,
+            "PAYLOAD_TOO_LARGE",
+            "Request body is too large for an idempotent request".to_string(),
+        );
+    };
+
+    match store.start(&scoped_key, fingerprint(&body)) {
+        IdempotencyStart::Started => {}
+        IdempotencyStart::Replay(cached) => {
+            tracing::info!(idempotency_key = %key, "Replaying stored response");
+            return replay(cached);
+        }
+        IdempotencyStart::InProgress => {
+            return error_response(
+                ErrorKind::Conflict,
+                "IDEMPOTENCY_KEY_IN_USE",
+                "A request with this Idempotency-Key is still being processed".to_string(),
+            );
+        }
+        IdempotencyStart::Mismatch => {
+            return error_response(
+                ErrorKind::InvalidArgument,
+                "IDEMPOTENCY_KEY_REUSED",
+                "Idempotency-Key was already used with a different request body".to_string(),
+            );
+        }
+    }
+
+    let mut guard = InFlightGuard {
+        store: &store,
+        key: &scoped_key,
+        done: false,
+    };
+
+    let response = next.run(Request::from_parts(parts, Body::from(body))).await;
+    if response.status().is_server_error() {
+        return response;
+    }
+
+    let (parts, body) = response.into_parts();
+    let Ok(body) = axum::body::to_bytes(body, MAX_BODY_BYTES).await else {
+        return error_response(
+            ErrorKind::Internal,
+            "INTERNAL_ERROR",
+            "Failed to buffer response".to_string(),
+        );
+    };
+
+    store.complete(
+        &scoped_key,
+        CachedResponse {
+            status: parts.status,
+            content_type: parts.headers.get(CONTENT_TYPE).cloned(),
+            body: body.clone(),
+        },
+    );
+    guard.done = true;
+
+    Response::from_parts(parts, Body::from(body))
+}
+
+fn fingerprint(body: &[u8]) -> u64 {
+    let mut hasher = DefaultHasher::new();
+    body.hash(&mut hasher);
+    hasher.finish()
+}
+
+fn replay(cached: CachedResponse) -> Response {
+    let mut response = (cached.status, cached.body).into_response();
+    let headers = response.headers_mut();
+    match cached.content_type {
+        Some(content_type) => {
+            headers.insert(CONTENT_TYPE, content_type);
+        }
+        None => {
+            headers.remove(CONTENT_TYPE);
+        }
+    }
+    headers.insert(IDEMPOTENT_REPLAYED_HEADER, HeaderValue::from_static("true"));
+    response
+}
+
+fn error_response(kind: ErrorKind, code: &str, message: String) -> Response {
+    ApiError::new(kind, code, message).into_response()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn cached(body: &'static str) -> CachedResponse {
+        CachedResponse {
+            status: StatusCode::OK,
+            content_type: None,
+            body: Bytes::from_static(body.as_bytes()),
+        }
+    }
+
+    #[test]
+    fn replays_completed_response() {
+        let store = IdempotencyStore::default();
+
+        assert_eq!(store.start("k", 1), IdempotencyStart::Started);
+        assert_eq!(store.start("k", 1), IdempotencyStart::InProgress);
+
+        store.complete("k", cached("done"));
+        assert_eq!(
+            store.start("k", 1),
+            IdempotencyStart::Replay(cached("done"))
+        );
+        assert_eq!(store.start("k", 2), IdempotencyStart::Mismatch);
+    }
+
+    #[test]
+    fn released_key_can_be_retried() {
+        let store = IdempotencyStore::default();
+
+        assert_eq!(store.start("k", 1), IdempotencyStart::Started);
+        store.release("k");
+        assert_eq!(store.start("k", 1), IdempotencyStart::Started);
+    }
+
+    #[test]
+    fn expired_entries_are_dropped() {
+        let store = IdempotencyStore::new(Duration::ZERO);
+
+        assert_eq!(store.start("k", 1), IdempotencyStart::Started);
+        store.complete("k", cached("done"));
+
+        assert!(store.is_empty());
+        assert_eq!(store.start("k", 2), IdempotencyStart::Started);
+    }
+}
@@ -2,10 +2,21 @@
 //!
 //! Inbound adapter implementing REST endpoints that delegate to application use cases.
 
+mod auth;
 mod controller;
+mod idempotency;
+mod openapi;
 mod request;
 mod response;
+mod validation;
 
+pub use auth::{RouteAuth, require_role};
 pub use controller::{AppState, create_router};
+pub use idempotency::{
+    CachedResponse, DEFAULT_IDEMPOTENCY_TTL, IDEMPOTENCY_KEY_HEADER, IDEMPOTENT_REPLAYED_HEADER,
+    IdempotencyStart, IdempotencyStore, idempotency,
+};
+pub use openapi::{ApiDoc, openapi};
 pub use request::*;
 pub use response::*;
+pub use validation::{ValidJson, Validate, ValidationRejection};
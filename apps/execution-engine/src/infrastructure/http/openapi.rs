@@ -0,0 +1,250 @@
+//! `OpenAPI` document for the HTTP API.
+//!
+//! Generated from the request and response DTOs and served at
+//! `/openapi.json` so clients can generate types instead of maintaining
+//! them by hand. Domain value objects used in DTOs get their schemas here
+//! so the domain layer stays free of documentation dependencies.
+
+use std::borrow::Cow;
+
+use serde::Serialize;
+use utoipa::openapi::schema::{KnownFormat, ObjectBuilder, Schema, SchemaFormat, Type};
+use utoipa::openapi::security::{ApiKey, ApiKeyValue, Http, HttpAuthScheme, SecurityScheme};
+use utoipa::openapi::{OpenApi as OpenApiDoc, RefOr};
+use utoipa::{Modify, OpenApi, PartialSchema, ToSchema};
+
+use crate::application::ports::{ThesisState, TimeHorizon};
+use crate::application::services::DependencyCheck;
+use crate::domain::execution_tactics::StrategyFamily;
+use crate::domain::order_execution::value_objects::{
+    OrderPurpose, OrderSide, OrderStatus, OrderType, TimeInForce,
+};
+use crate::domain::shared::Timestamp;
+use crate::infrastructure::auth::API_KEY_HEADER;
+use crate::infrastructure::export::BlotterFormat;
+
+use super::controller;
+
+/// `OpenAPI` description of the execution engine HTTP API.
+#[derive(OpenApi)]
+#[openapi(
+    info(title = "Cream Execution Engine"),
+    paths(
+        controller::health_check,
+        controller::readiness_check,
+        controller::check_constraints,
+        controller::submit_orders,
+        controller::get_order_state,
+        controller::query_orders,
+        controller::export_blotter,
+        controller::cancel_orders,
+        controller::cancel_scheduled_plan,
+        controller::session_stats,
+        controller::list_circuit_breakers,
+        controller::plan_progress,
+        controller::shadow_execution,
+        controller::list_watchlists,
+        controller::get_watchlist,
+        controller::create_watchlist,
+        controller::delete_watchlist,
+        controller::add_watchlist_symbols,
+        controller::remove_watchlist_symbol,
+        controller::adjust_stops,
+        controller::get_kill_switch,
+        controller::set_kill_switch,
+        controller::set_circuit_breaker,
+        controller::list_feature_flags,
+        controller::set_feature_flag,
+        controller::reset_feature_flag,
+        controller::reload_config,
+        controller::export_state,
+        controller::import_state,
+        controller::list_broker_accounts,
+        controller::authorize_broker_account,
+        controller::disconnect_broker_account,
+        controller::oauth_callback,
+    ),
+    modifiers(&SecuritySchemes),
+    tags(
+        (name = "health", description = "Liveness and readiness"),
+        (name = "orders", description = "Risk checks and order management"),
+        (name = "watchlists", description = "Symbols kept subscribed on the quote feed"),
+        (name = "admin", description = "Operator controls"),
+    )
+)]
+pub struct ApiDoc;
+
+/// Build the `OpenAPI` document.
+#[must_use]
+pub fn openapi() -> OpenApiDoc {
+    ApiDoc::openapi()
+}
+
+/// Registers the API key schemes accepted by the auth middleware.
+struct SecuritySchemes;
+
+impl Modify for SecuritySchemes {
+    fn modify(&self, openapi: &mut OpenApiDoc) {
+        let components = openapi.components.get_or_insert_with(Default::default);
+        components.add_security_scheme(
+            "api_key",
+            SecurityScheme::ApiKey(ApiKey::Header(ApiKeyValue::new(API_KEY_HEADER))),
+        );
+        components.add_security_scheme(
+            "bearer",
+            SecurityScheme::Http(Http::new(HttpAuthScheme::Bearer)),
+        );
+    }
+}
+
+/// Schema for a unit enum serialized as strings, built from its serde form.
+fn string_enum<T: Serialize>(description: &str, variants: &[T]) -> RefOr<Schema> {
+    ObjectBuilder::new()
+        .schema_type(Type::String)
+        .description(Some(description))
+        .enum_values(Some(
+            variants.iter().filter_map(|v| serde_json::to_value(v).ok()),
+        ))
+        .into()
+}
+
+macro_rules! string_enum_schema {
+    ($ty:ident, $description:literal, [$($variant:ident),+ $(,)?]) => {
+        impl PartialSchema for $ty {
+            fn schema() -> RefOr<Schema> {
+                string_enum($description, &[$($ty::$variant),+])
+            }
+        }
+
+        impl ToSchema for $ty {
+            fn name() -> Cow<'static, str> {
+                Cow::Borrowed(stringify!($ty))
+            }
+        }
+    };
+}
+
+string_enum_schema!(OrderSide, "Order side", [Buy, Sell]);
+string_enum_schema!(OrderType, "Order type", [Market, Limit, Stop, StopLimit]);
+string_enum_schema!(TimeInForce, "Time in force", [Day, Gtc, Ioc, Fok, Opg, Cls]);
+string_enum_schema!(
+    OrderPurpose,
+    "Why the order is being placed",
+    [
+        Entry, Exit, StopLoss, TakeProfit, BracketLeg, ScaleIn, ScaleOut,
+    ]
+);
+string_enum_schema!(
+    ThesisState,
+    "Thesis state of the decision",
+    [Watching, Entered, Adding, Managing, Exiting, Closed]
+);
+string_enum_schema!(
+    TimeHorizon,
+    "Intended holding period of the decision",
+    [Intraday, Swing, Position]
+);
+string_enum_schema!(
+    StrategyFamily,
+    "Strategy family of the decision",
+    [
+        EquityLong,
+        EquityShort,
+        OptionLong,
+        OptionShort,
+        VerticalSpread,
+        IronCondor,
+        Straddle,
+        Strangle,
+        CalendarSpread,
+    ]
+);
+string_enum_schema!(BlotterFormat, "Trade blotter file format", [Csv, Parquet]);
+string_enum_schema!(
+    OrderStatus,
+    "Order lifecycle status",
+    [
+        New,
+        PendingNew,
+        Accepted,
+        PartiallyFilled,
+        Filled,
+        PendingCancel,
+        Canceled,
+        Rejected,
+        Expired,
+    ]
+);
+
+impl PartialSchema for Timestamp {
+    fn schema() -> RefOr<Schema> {
+        ObjectBuilder::new()
+            .schema_type(Type::String)
+            .format(Some(SchemaFormat::KnownFormat(KnownFormat::DateTime)))
+            .description(Some("RFC 3339 UTC timestamp"))
+            .into()
+    }
+}
+
+impl ToSchema for Timestamp {
+    fn name() -> Cow<'static, str> {
+        Cow::Borrowed("Timestamp")
+    }
+}
+
+impl PartialSchema for DependencyCheck {
+    fn schema() -> RefOr<Schema> {
+        ObjectBuilder::new()
+            .description(Some("Result of a single dependency check"))
+            .property("ok", ObjectBuilder::new().schema_type(Type::Boolean))
+            .required("ok")
+            .property("error", ObjectBuilder::new().schema_type(Type::String))
+            .into()
+    }
+}
+
+impl ToSchema for DependencyCheck {
+    fn name() -> Cow<'static, str> {
+        Cow::Borrowed("DependencyCheck")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn documents_order_endpoints() {
+        let doc = openapi();
+
+        for path in [
+            "/api/v1/check-constraints",
+            "/api/v1/submit-orders",
+            "/api/v1/orders",
+            "/api/v1/orders/query",
+            "/api/v1/blotter",
+            "/api/v1/cancel-orders",
+            "/api/v1/stats",
+            "/api/v1/admin/kill-switch",
+            "/api/v1/admin/reload-config",
+        ] {
+            assert!(doc.paths.paths.contains_key(path), "missing {path}");
+        }
+    }
+
+    #[test]
+    fn enum_schemas_match_serde() {
+        let json = serde_json::to_value(openapi()).unwrap();
+        let schemas = &json["components"]["schemas"];
+
+        assert_eq!(
+            schemas["OrderSide"]["enum"],
+            serde_json::json!(["BUY", "SELL"])
+        );
+        assert!(schemas["SubmitOrdersRequest"]["properties"]["decisions"].is_object());
+        assert!(
+            json["components"]["securitySchemes"]["api_key"].is_object(),
+            "auth schemes documented"
+        );
+    }
+}
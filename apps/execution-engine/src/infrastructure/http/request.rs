@@ -1,14 +1,21 @@
 //! HTTP request DTOs.
 
 use rust_decimal::Decimal;
-use serde::{Deserialize, Serialize};
+use serde::{Deserialize, Deserializer, Serialize};
+use utoipa::{IntoParams, ToSchema};
 
+use crate::application::ports::{DecisionContext, ThesisState, TimeHorizon};
+use crate::domain::execution_tactics::StrategyFamily;
+use crate::domain::order_execution::repository::{DEFAULT_ORDER_QUERY_LIMIT, OrderQuery};
 use crate::domain::order_execution::value_objects::{
-    OrderPurpose, OrderSide, OrderType, TimeInForce,
+    OrderPurpose, OrderSide, OrderStatus, OrderType, OtoExit, TimeInForce,
 };
+use crate::domain::shared::{CycleId, Symbol, Timestamp};
+use crate::domain::stop_enforcement::{HoldingPeriod, ScaleOutTarget};
+use crate::infrastructure::export::BlotterFormat;
 
 /// Request to check constraints before order submission.
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
 pub struct CheckConstraintsRequest {
     /// Request ID for correlation.
     pub request_id: String,
@@ -26,9 +33,11 @@ pub struct CheckConstraintsRequest {
 }
 
 /// A single decision/order in a request.
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
 pub struct DecisionRequest {
-    /// Symbol to trade.
+    /// Symbol to trade, in any common notation (`BRK-B`, `BRK/B`, padded
+    /// OCC); normalized on receipt.
+    #[serde(deserialize_with = "normalized_symbol")]
     pub symbol: String,
     /// Order side.
     pub side: OrderSide,
@@ -47,6 +56,98 @@ pub struct DecisionRequest {
     /// Order purpose.
     #[serde(default = "default_purpose")]
     pub purpose: OrderPurpose,
+    /// Caller-assigned ID of the decision, kept on the position lifecycle.
+    #[serde(default)]
+    pub decision_id: Option<String>,
+    /// Thesis state of the decision.
+    #[serde(default)]
+    pub thesis_state: Option<ThesisState>,
+    /// Intended holding period, used by end-of-day handling.
+    #[serde(default)]
+    pub time_horizon: Option<TimeHorizon>,
+    /// Strategy family, used to pick the execution profile.
+    #[serde(default)]
+    pub strategy_family: Option<StrategyFamily>,
+    /// Free-form strategy tag (letters, digits, `_`, `-`, `.`; at most 32),
+    /// prefixed to the order ID and carried onto its fills.
+    #[serde(default)]
+    pub strategy_tag: Option<String>,
+    /// Seconds the position may stay open before the stop monitor closes it.
+    #[serde(default)]
+    pub max_holding_secs: Option<u64>,
+    /// Instead of closing at expiry, tighten the stop to this fraction of
+    /// price (e.g. `0.005`). Requires `max_holding_secs`.
+    #[serde(default)]
+    pub holding_stop_distance: Option<Decimal>,
+    /// Take-profit ladder: parts of the position to exit before the final
+    /// target, nearest first.
+    #[serde(default)]
+    pub scale_out: Vec<ScaleOutTargetRequest>,
+    /// Stop the broker arms once the entry fills (OTO). Exclusive with
+    /// `exit_take_profit`.
+    #[serde(default)]
+    pub exit_stop_loss: Option<Decimal>,
+    /// Target the broker arms once the entry fills (OTO). Exclusive with
+    /// `exit_stop_loss`.
+    #[serde(default)]
+    pub exit_take_profit: Option<Decimal>,
+    /// Rationale of the decision.
+    #[serde(default)]
+    pub rationale: Option<String>,
+}
+
+impl DecisionRequest {
+    /// Decision context to record on the position lifecycle, if any was sent.
+    #[must_use]
+    pub fn decision_context(&self) -> Option<DecisionContext> {
+        let context = DecisionContext {
+            decision_id: self.decision_id.clone(),
+            thesis_state: self.thesis_state,
+            time_horizon: self.time_horizon,
+            strategy_family: self.strategy_family,
+            strategy_tag: self.strategy_tag.clone(),
+            max_holding_period: self.max_holding_period(),
+            scale_out: self
+                .scale_out
+                .iter()
+                .map(|t| ScaleOutTarget::new(t.price, t.fraction))
+                .collect(),
+            rationale: self.rationale.clone(),
+        };
+        (context != DecisionContext::default()).then_some(context)
+    }
+
+    /// Maximum holding period, if `max_holding_secs` was sent.
+    #[must_use]
+    pub fn max_holding_period(&self) -> Option<HoldingPeriod> {
+        let secs = self.max_holding_secs?;
+        Some(self.holding_stop_distance.map_or_else(
+            || HoldingPeriod::close_after(secs),
+            |distance| HoldingPeriod::tighten_after(secs, distance),
+        ))
+    }
+    /// Exit to arm on fill, if exactly one of the exit levels was sent.
+    #[must_use]
+    pub const fn oto_exit(&self) -> Option<OtoExit> {
+        OtoExit::from_levels(self.exit_stop_loss, self.exit_take_profit)
+    }
+}
+
+/// Deserialize a symbol into its canonical notation.
+fn normalized_symbol<'de, D>(deserializer: D) -> Result<String, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    String::deserialize(deserializer).map(|raw| Symbol::normalize(&raw))
+}
+
+/// One rung of a take-profit ladder.
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct ScaleOutTargetRequest {
+    /// Price at which to scale out.
+    pub price: Decimal,
+    /// Fraction of the original quantity to exit, in `(0, 1]`.
+    pub fraction: Decimal,
 }
 
 const fn default_order_type() -> OrderType {
@@ -62,7 +163,7 @@ const fn default_purpose() -> OrderPurpose {
 }
 
 /// Request to submit orders.
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
 pub struct SubmitOrdersRequest {
     /// Request ID for correlation.
     pub request_id: String,
@@ -74,17 +175,74 @@ pub struct SubmitOrdersRequest {
     pub account_equity: Decimal,
     /// Decisions/orders to submit.
     pub decisions: Vec<DecisionRequest>,
+    /// Scheduled execution time. When set, the plan is accepted now and
+    /// submitted once this time is reached.
+    pub execute_at: Option<Timestamp>,
+    /// Seconds after `execute_at` during which the plan may still execute.
+    /// Plans not executed within the window are canceled.
+    pub execution_window_secs: Option<u64>,
 }
 
 /// Request to get order state.
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
 pub struct GetOrderStateRequest {
     /// Order IDs to query.
     pub order_ids: Vec<String>,
 }
 
+/// Request to query order history.
+///
+/// Unset filters match every order.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, ToSchema)]
+pub struct QueryOrdersRequest {
+    /// Orders created at or after this time.
+    pub from: Option<Timestamp>,
+    /// Orders created before this time.
+    pub to: Option<Timestamp>,
+    /// Instrument symbol.
+    pub symbol: Option<String>,
+    /// Any of these statuses.
+    #[serde(default)]
+    pub statuses: Vec<OrderStatus>,
+    /// Decision cycle that produced the order.
+    pub cycle_id: Option<String>,
+    /// Matches to skip.
+    #[serde(default)]
+    pub offset: usize,
+    /// Matches to return (default 100, at most 1000).
+    pub limit: Option<usize>,
+}
+
+impl QueryOrdersRequest {
+    /// Repository query for this request.
+    #[must_use]
+    pub fn query(&self) -> OrderQuery {
+        OrderQuery {
+            from: self.from,
+            to: self.to,
+            symbol: self.symbol.as_deref().map(Symbol::new),
+            statuses: self.statuses.clone(),
+            cycle_id: self.cycle_id.as_deref().map(CycleId::new),
+            offset: self.offset,
+            limit: self.limit.unwrap_or(DEFAULT_ORDER_QUERY_LIMIT),
+        }
+    }
+}
+
+/// Request to export the trade blotter.
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct ExportBlotterRequest {
+    /// Fills at or after this time.
+    pub from: Timestamp,
+    /// Fills before this time.
+    pub to: Timestamp,
+    /// File format (default CSV).
+    #[serde(default)]
+    pub format: BlotterFormat,
+}
+
 /// Request to cancel orders.
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
 pub struct CancelOrdersRequest {
     /// Order IDs to cancel.
     pub order_ids: Vec<String>,
@@ -92,6 +250,74 @@ pub struct CancelOrdersRequest {
     pub reason: Option<String>,
 }
 
+/// Request to move a position's stop and/or target.
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct AdjustStopsRequest {
+    /// New stop-loss price (unchanged if omitted).
+    pub stop_loss: Option<Decimal>,
+    /// New take-profit price (unchanged if omitted).
+    pub take_profit: Option<Decimal>,
+    /// Why the levels are moving.
+    pub reason: Option<String>,
+}
+
+/// Request to engage or release the kill switch.
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct KillSwitchRequest {
+    /// `true` disables trading, `false` re-enables it.
+    pub engaged: bool,
+    /// Why trading is being disabled (logged and echoed in violations).
+    pub reason: Option<String>,
+}
+
+/// Request to trip or reset a circuit breaker.
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct CircuitBreakerRequest {
+    /// `true` holds the breaker open, `false` closes it.
+    pub open: bool,
+    /// Why the breaker is being tripped (logged with the state change).
+    pub reason: Option<String>,
+}
+
+/// Request to turn a feature on or off.
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct FeatureFlagRequest {
+    /// `true` turns the feature on, `false` off.
+    pub enabled: bool,
+    /// Why the flag is changing (logged with the change).
+    pub reason: Option<String>,
+}
+
+/// Request to create a watchlist.
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct CreateWatchlistRequest {
+    /// Unique name (letters, digits, '_', '-' or '.').
+    pub name: String,
+    /// Initial symbols.
+    #[serde(default)]
+    pub symbols: Vec<String>,
+}
+
+/// Symbols to add to a watchlist.
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct WatchlistSymbolsRequest {
+    /// Symbols to add.
+    pub symbols: Vec<String>,
+}
+
+/// State bundle to import, as returned by the export endpoint.
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct ImportStateRequest {
+    /// Exported bundle.
+    #[schema(value_type = Object)]
+    pub bundle: serde_json::Value,
+    /// Signature returned with the bundle.
+    pub signature: String,
+    /// Import even if the bundle conflicts with broker or local state.
+    #[serde(default)]
+    pub force: bool,
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -109,6 +335,74 @@ mod tests {
         assert_eq!(req.order_type, OrderType::Market);
         assert_eq!(req.time_in_force, TimeInForce::Day);
         assert_eq!(req.purpose, OrderPurpose::Entry);
+        assert!(req.decision_context().is_none());
+    }
+
+    #[test]
+    fn decision_request_normalizes_symbol() {
+        let json = r#"{"symbol": "brk-b", "side": "BUY", "quantity": "1"}"#;
+
+        let req: DecisionRequest = serde_json::from_str(json).unwrap();
+        assert_eq!(req.symbol, "BRK.B");
+    }
+
+    #[test]
+    fn decision_request_carries_thesis() {
+        let json = r#"{
+            "symbol": "AAPL",
+            "side": "SELL",
+            "quantity": "100",
+            "purpose": "EXIT",
+            "thesis_state": "EXITING",
+            "rationale": "earnings miss"
+        }"#;
+
+        let req: DecisionRequest = serde_json::from_str(json).unwrap();
+        let context = req.decision_context().unwrap();
+        assert_eq!(context.thesis_state, Some(ThesisState::Exiting));
+        assert_eq!(context.rationale.as_deref(), Some("earnings miss"));
+        assert!(context.decision_id.is_none());
+    }
+
+    #[test]
+    fn decision_request_carries_holding_period() {
+        let json = r#"{
+            "symbol": "AAPL",
+            "side": "BUY",
+            "quantity": "100",
+            "max_holding_secs": 86400,
+            "holding_stop_distance": "0.005"
+        }"#;
+
+        let req: DecisionRequest = serde_json::from_str(json).unwrap();
+        let context = req.decision_context().unwrap();
+        assert_eq!(
+            context.max_holding_period,
+            Some(HoldingPeriod::tighten_after(86_400, Decimal::new(5, 3)))
+        );
+    }
+
+    #[test]
+    fn decision_request_carries_scale_out() {
+        let json = r#"{
+            "symbol": "AAPL",
+            "side": "BUY",
+            "quantity": "100",
+            "scale_out": [
+                {"price": "105", "fraction": "0.5"},
+                {"price": "110", "fraction": "0.5"}
+            ]
+        }"#;
+
+        let req: DecisionRequest = serde_json::from_str(json).unwrap();
+        let context = req.decision_context().unwrap();
+        assert_eq!(
+            context.scale_out,
+            [
+                ScaleOutTarget::new(Decimal::new(105, 0), Decimal::new(5, 1)),
+                ScaleOutTarget::new(Decimal::new(110, 0), Decimal::new(5, 1)),
+            ]
+        );
     }
 
     #[test]
@@ -127,6 +421,17 @@ mod tests {
                 stop_price: None,
                 time_in_force: TimeInForce::Day,
                 purpose: OrderPurpose::Entry,
+                decision_id: None,
+                thesis_state: None,
+                time_horizon: None,
+                strategy_family: None,
+                strategy_tag: None,
+                max_holding_secs: None,
+                holding_stop_distance: None,
+                scale_out: Vec::new(),
+                exit_stop_loss: None,
+                exit_take_profit: None,
+                rationale: None,
             }],
             include_portfolio_context: false,
         };
@@ -135,4 +440,46 @@ mod tests {
         let parsed: CheckConstraintsRequest = serde_json::from_str(&json).unwrap();
         assert_eq!(parsed.request_id, req.request_id);
     }
+
+    #[test]
+    fn submit_orders_request_execution_window() {
+        let json = r#"{
+            "request_id": "req-1",
+            "cycle_id": "cycle-1",
+            "risk_policy_id": "default",
+            "account_equity": "100000",
+            "decisions": [],
+            "execute_at": "2026-01-05T20:50:00Z",
+            "execution_window_secs": 120
+        }"#;
+
+        let req: SubmitOrdersRequest = serde_json::from_str(json).unwrap();
+        assert_eq!(
+            req.execute_at,
+            Some(Timestamp::parse("2026-01-05T20:50:00Z").unwrap())
+        );
+        assert_eq!(req.execution_window_secs, Some(120));
+
+        let immediate = r#"{
+            "request_id": "req-2",
+            "cycle_id": "cycle-1",
+            "risk_policy_id": "default",
+            "account_equity": "100000",
+            "decisions": []
+        }"#;
+        let req: SubmitOrdersRequest = serde_json::from_str(immediate).unwrap();
+        assert!(req.execute_at.is_none());
+    }
+}
+
+/// Query string the broker redirects users back to the OAuth callback with.
+#[derive(Debug, Clone, Deserialize, IntoParams)]
+#[into_params(parameter_in = Query)]
+pub struct OAuthCallbackQuery {
+    /// One-time state issued with the authorization URL.
+    pub state: String,
+    /// Authorization code (absent if the user denied access).
+    pub code: Option<String>,
+    /// Error reported by the broker instead of a code.
+    pub error: Option<String>,
 }
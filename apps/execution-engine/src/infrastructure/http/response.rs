@@ -2,13 +2,23 @@
 
 use rust_decimal::Decimal;
 use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
 
+use crate::application::dto::{OrderDto, ViolationDto};
+use crate::application::ports::Watchlist;
+use crate::application::services::{
+    BreakerStatus, BrokerAccount, DependencyCheck, FeatureFlag, ShadowComparison, ShadowReport,
+};
+use crate::application::use_cases::{SignedStateBundle, StateConflict, StateImportReport};
+use crate::domain::order_execution::aggregate::Order;
+use crate::domain::order_execution::repository::{OrderPage, OrderQuery};
 use crate::domain::order_execution::value_objects::{
-    OrderSide, OrderStatus, OrderType, TimeInForce,
+    FillReport, OrderPurpose, OrderSide, OrderStatus, OrderType, TimeInForce,
 };
+use crate::domain::shared::Timestamp;
 
 /// Response from constraint check.
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
 pub struct CheckConstraintsResponse {
     /// Whether all constraints passed.
     pub ok: bool,
@@ -20,7 +30,7 @@ pub struct CheckConstraintsResponse {
 }
 
 /// A constraint violation.
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
 pub struct ViolationResponse {
     /// Violation code.
     pub code: String,
@@ -39,8 +49,21 @@ pub struct ViolationResponse {
     pub limit: Option<String>,
 }
 
+impl From<ViolationDto> for ViolationResponse {
+    fn from(v: ViolationDto) -> Self {
+        Self {
+            code: v.code,
+            severity: v.severity,
+            message: v.message,
+            instrument_id: v.instrument_id,
+            observed: v.observed,
+            limit: v.limit,
+        }
+    }
+}
+
 /// Constraint result for a single order.
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
 pub struct OrderConstraintResult {
     /// Whether constraints passed for this order.
     pub passed: bool,
@@ -49,7 +72,7 @@ pub struct OrderConstraintResult {
 }
 
 /// Response from order submission.
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
 pub struct SubmitOrdersResponse {
     /// Whether all orders were submitted successfully.
     pub ok: bool,
@@ -61,10 +84,13 @@ pub struct SubmitOrdersResponse {
     /// Risk violations if risk check failed.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub risk_violations: Option<Vec<ViolationResponse>>,
+    /// Plan ID when the request was scheduled for later execution.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub scheduled_plan_id: Option<String>,
 }
 
 /// A submitted order.
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
 pub struct OrderResponse {
     /// Internal order ID.
     pub order_id: String,
@@ -91,13 +117,40 @@ pub struct OrderResponse {
     /// Average fill price.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub avg_fill_price: Option<Decimal>,
+    /// Broker IDs of child orders, such as the exit of an OTO entry.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub child_order_ids: Vec<String>,
+    /// Strategy tag of the decision that produced the order.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub strategy_tag: Option<String>,
     /// Error message if rejected.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub error: Option<String>,
 }
 
+impl From<OrderDto> for OrderResponse {
+    fn from(order: OrderDto) -> Self {
+        Self {
+            order_id: order.order_id,
+            broker_id: order.broker_id,
+            symbol: order.symbol,
+            side: order.side,
+            order_type: order.order_type,
+            quantity: order.quantity,
+            limit_price: order.limit_price,
+            status: order.status,
+            time_in_force: order.time_in_force,
+            filled_qty: order.filled_qty,
+            avg_fill_price: order.avg_fill_price,
+            child_order_ids: order.child_order_ids,
+            strategy_tag: order.strategy_tag,
+            error: None,
+        }
+    }
+}
+
 /// Response from get order state.
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
 pub struct GetOrderStateResponse {
     /// Orders found.
     pub orders: Vec<OrderResponse>,
@@ -105,15 +158,154 @@ pub struct GetOrderStateResponse {
     pub not_found: Vec<String>,
 }
 
+/// One page of order history.
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct OrderHistoryResponse {
+    /// Matching orders, newest first.
+    pub orders: Vec<OrderHistoryEntry>,
+    /// Orders matching the filters across all pages.
+    pub total: usize,
+    /// Offset of the next page (omitted on the last page).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub next_offset: Option<usize>,
+}
+
+impl OrderHistoryResponse {
+    /// Build the response for `page` of `query`.
+    #[must_use]
+    pub fn from_page(page: &OrderPage, query: &OrderQuery) -> Self {
+        Self {
+            orders: page
+                .orders
+                .iter()
+                .map(OrderHistoryEntry::from_order)
+                .collect(),
+            total: page.total,
+            next_offset: page.next_offset(query),
+        }
+    }
+}
+
+/// A historical order with its fills.
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct OrderHistoryEntry {
+    /// Internal order ID.
+    pub order_id: String,
+    /// Broker order ID (if accepted).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub broker_id: Option<String>,
+    /// Symbol.
+    pub symbol: String,
+    /// Side.
+    pub side: OrderSide,
+    /// Order type.
+    pub order_type: OrderType,
+    /// Purpose.
+    pub purpose: OrderPurpose,
+    /// Quantity.
+    pub quantity: Decimal,
+    /// Limit price.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub limit_price: Option<Decimal>,
+    /// Stop price.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub stop_price: Option<Decimal>,
+    /// Status.
+    pub status: OrderStatus,
+    /// Time in force.
+    pub time_in_force: TimeInForce,
+    /// Filled quantity.
+    pub filled_qty: Decimal,
+    /// Average fill price.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub avg_fill_price: Option<Decimal>,
+    /// Decision cycle that produced the order.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cycle_id: Option<String>,
+    /// Strategy tag of the decision that produced the order.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub strategy_tag: Option<String>,
+    /// When the order was created.
+    pub created_at: Timestamp,
+    /// When the order last changed.
+    pub updated_at: Timestamp,
+    /// Executions, oldest first.
+    pub fills: Vec<FillResponse>,
+}
+
+impl OrderHistoryEntry {
+    /// Build an entry from a stored order.
+    #[must_use]
+    pub fn from_order(order: &Order) -> Self {
+        let dto = OrderDto::from_order(order);
+        Self {
+            order_id: dto.order_id,
+            broker_id: dto.broker_id,
+            symbol: dto.symbol,
+            side: dto.side,
+            order_type: dto.order_type,
+            purpose: dto.purpose,
+            quantity: dto.quantity,
+            limit_price: dto.limit_price,
+            stop_price: order.stop_price().map(|p| p.amount()),
+            status: dto.status,
+            time_in_force: dto.time_in_force,
+            filled_qty: dto.filled_qty,
+            avg_fill_price: dto.avg_fill_price,
+            cycle_id: order.cycle_id().map(ToString::to_string),
+            strategy_tag: dto.strategy_tag,
+            created_at: dto.created_at,
+            updated_at: dto.updated_at,
+            fills: order
+                .partial_fill()
+                .fills()
+                .iter()
+                .map(FillResponse::from)
+                .collect(),
+        }
+    }
+}
+
+/// A single execution of an order.
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct FillResponse {
+    /// Venue fill ID.
+    pub fill_id: String,
+    /// Quantity filled.
+    pub quantity: Decimal,
+    /// Fill price.
+    pub price: Decimal,
+    /// Venue where the fill occurred.
+    pub venue: String,
+    /// Commission charged.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub commission: Option<Decimal>,
+    /// When the fill occurred.
+    pub timestamp: Timestamp,
+}
+
+impl From<&FillReport> for FillResponse {
+    fn from(fill: &FillReport) -> Self {
+        Self {
+            fill_id: fill.fill_id.clone(),
+            quantity: fill.quantity.amount(),
+            price: fill.price.amount(),
+            venue: fill.venue.clone(),
+            commission: fill.commission.map(|c| c.amount()),
+            timestamp: fill.timestamp,
+        }
+    }
+}
+
 /// Response from cancel orders.
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
 pub struct CancelOrdersResponse {
     /// Results for each order.
     pub results: Vec<CancelResult>,
 }
 
 /// Result of canceling a single order.
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
 pub struct CancelResult {
     /// Order ID.
     pub order_id: String,
@@ -125,7 +317,7 @@ pub struct CancelResult {
 }
 
 /// Health check response.
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
 pub struct HealthResponse {
     /// Status.
     pub status: String,
@@ -133,16 +325,598 @@ pub struct HealthResponse {
     pub version: String,
 }
 
+/// Readiness check response.
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct ReadinessResponse {
+    /// `READY` or `NOT_READY`.
+    pub status: String,
+    /// Whether startup recovery and reconciliation have completed.
+    pub recovered: bool,
+    /// Broker connectivity.
+    pub broker: DependencyCheck,
+    /// Persistence reachability.
+    pub persistence: DependencyCheck,
+    /// Latest host clock check.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub clock_sync: Option<ClockSyncResponse>,
+}
+
+/// Host clock offset from the reference time source.
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct ClockSyncResponse {
+    /// When the clock was checked.
+    pub checked_at: Timestamp,
+    /// NTP server that answered.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub source: Option<String>,
+    /// Reference time minus host time, in milliseconds.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub offset_ms: Option<i64>,
+    /// Whether the offset exceeds `CLOCK_SKEW_MAX_MS`.
+    pub skewed: bool,
+    /// Why the offset could not be measured.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+}
+
+/// Operator view of the trading session.
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct SessionStatsResponse {
+    /// When the snapshot was taken.
+    pub as_of: Timestamp,
+    /// Order activity (omitted if the order store could not be read).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub orders: Option<OrderActivityResponse>,
+    /// Live exposure including working orders.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub exposure: Option<ExposureResponse>,
+    /// P&L realized since the engine started.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub realized_pnl: Option<Decimal>,
+    /// Whether the kill switch has disabled trading.
+    pub trading_disabled: bool,
+    /// Circuit breaker states.
+    pub circuit_breakers: Vec<CircuitBreakerResponse>,
+    /// Market data feed freshness.
+    pub feeds: Vec<FeedResponse>,
+    /// Ticks dropped by feed sanitation, by symbol.
+    pub rejected_ticks: Vec<RejectedTicksResponse>,
+    /// Cache counters.
+    pub caches: Vec<CacheResponse>,
+    /// Event outbox backlog.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub event_outbox: Option<EventOutboxResponse>,
+    /// Most recent reconciliation.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub last_reconciliation: Option<ReconciliationResponse>,
+}
+
+/// Events waiting for delivery to the event sink.
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct EventOutboxResponse {
+    /// Events waiting for delivery.
+    pub depth: usize,
+    /// When the oldest waiting event was recorded.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub oldest_recorded_at: Option<Timestamp>,
+    /// Events delivered since startup.
+    pub delivered: u64,
+    /// Failed delivery attempts since startup.
+    pub failed_attempts: u64,
+    /// Error from the most recent failed attempt.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub last_error: Option<String>,
+}
+
+/// Order activity for the current UTC day.
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct OrderActivityResponse {
+    /// Orders accepted by the broker today.
+    pub submitted_today: usize,
+    /// Orders completely filled today.
+    pub filled_today: usize,
+    /// Orders still working.
+    pub working: usize,
+}
+
+/// Portfolio exposure in dollars.
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct ExposureResponse {
+    /// Long plus short.
+    pub gross: Decimal,
+    /// Long minus short.
+    pub net: Decimal,
+    /// Long exposure.
+    pub long: Decimal,
+    /// Short exposure.
+    pub short: Decimal,
+}
+
+/// Circuit breaker state.
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct CircuitBreakerResponse {
+    /// Breaker name.
+    pub name: String,
+    /// `CLOSED`, `OPEN` or `HALF_OPEN`.
+    pub state: String,
+    /// Consecutive failures.
+    pub failure_count: u32,
+    /// Whether an operator tripped the breaker and holds it open.
+    pub manually_open: bool,
+    /// Successful calls since startup.
+    pub total_successes: u64,
+    /// Failed calls since startup.
+    pub total_failures: u64,
+    /// Share of calls since startup that failed, from 0 to 1.
+    pub failure_rate: Decimal,
+    /// State changes since startup.
+    pub state_changes: u64,
+    /// When the state last changed.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub last_state_change: Option<Timestamp>,
+}
+
+impl From<BreakerStatus> for CircuitBreakerResponse {
+    fn from(status: BreakerStatus) -> Self {
+        Self {
+            name: status.name,
+            state: status.state.as_str().to_string(),
+            failure_count: status.failure_count,
+            manually_open: status.manually_open,
+            total_successes: status.total_successes,
+            total_failures: status.total_failures,
+            failure_rate: status.failure_rate,
+            state_changes: status.state_changes,
+            last_state_change: status.last_state_change,
+        }
+    }
+}
+
+/// Registered circuit breakers.
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct CircuitBreakersResponse {
+    /// Breakers, by name.
+    pub circuit_breakers: Vec<CircuitBreakerResponse>,
+}
+
+/// Market data feed freshness.
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct FeedResponse {
+    /// Feed name.
+    pub name: String,
+    /// When the last update arrived.
+    pub last_update_at: Timestamp,
+    /// Seconds since the last update.
+    pub age_secs: i64,
+    /// Whether the feed is stale.
+    pub stale: bool,
+}
+
+/// Ticks dropped for one symbol since startup, by reason.
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct RejectedTicksResponse {
+    /// Symbol.
+    pub symbol: String,
+    /// Zero or negative prices.
+    pub non_positive_price: u64,
+    /// Moves beyond the outlier threshold.
+    pub outlier: u64,
+    /// Timestamps too far in the future.
+    pub future_timestamp: u64,
+    /// Ticks too old on arrival.
+    pub stale: u64,
+}
+
+/// Cache counters since startup.
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct CacheResponse {
+    /// Cache name.
+    pub name: String,
+    /// Entries cached.
+    pub entries: usize,
+    /// Requests served from the cache.
+    pub hits: u64,
+    /// Requests sent to the provider.
+    pub misses: u64,
+    /// Successful background refreshes.
+    pub refreshes: u64,
+    /// Failed background refreshes.
+    pub refresh_failures: u64,
+}
+
+/// Reconciliation outcome.
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct ReconciliationResponse {
+    /// When it finished.
+    pub completed_at: Timestamp,
+    /// Orders checked.
+    pub checked: usize,
+    /// Orders with mismatches.
+    pub mismatches: usize,
+    /// Orders reconciled.
+    pub reconciled: usize,
+    /// Errors encountered.
+    pub errors: Vec<String>,
+}
+
+/// Execution progress of a decision cycle.
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct PlanProgressResponse {
+    /// Cycle ID.
+    pub cycle_id: String,
+    /// When the progress was assembled.
+    pub as_of: Timestamp,
+    /// Whether every decision has reached a final status.
+    pub complete: bool,
+    /// Decisions not yet working at the broker.
+    pub pending: usize,
+    /// Decisions working at the broker.
+    pub working: usize,
+    /// Decisions completely filled.
+    pub filled: usize,
+    /// Decisions canceled, rejected or expired.
+    pub failed: usize,
+    /// Per-decision progress.
+    pub decisions: Vec<DecisionProgressResponse>,
+}
+
+/// Execution progress of one decision.
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct DecisionProgressResponse {
+    /// Order ID.
+    pub order_id: String,
+    /// Symbol.
+    pub symbol: String,
+    /// Side.
+    pub side: OrderSide,
+    /// `PENDING`, `WORKING`, `FILLED`, `CANCELED`, `REJECTED` or `EXPIRED`.
+    pub status: String,
+    /// Ordered quantity.
+    pub quantity: Decimal,
+    /// Filled quantity.
+    pub filled_qty: Decimal,
+    /// Filled share of the ordered quantity, in percent.
+    pub filled_pct: Decimal,
+    /// Average fill price.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub avg_fill_price: Option<Decimal>,
+    /// Why the order stopped short of a complete fill.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+    /// Last state change (omitted for decisions not yet submitted).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub updated_at: Option<Timestamp>,
+}
+
+/// Realized fills compared with the shadow execution configuration.
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct ShadowExecutionResponse {
+    /// When the report was assembled.
+    pub as_of: Timestamp,
+    /// Orders with a realized fill to compare against.
+    pub compared: usize,
+    /// Mean live slippage against the arrival mid over compared orders, in
+    /// basis points (positive is a cost).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub avg_realized_slippage_bps: Option<Decimal>,
+    /// Mean simulated shadow slippage over the same orders, in basis points.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub avg_shadow_slippage_bps: Option<Decimal>,
+    /// Compared orders where the shadow fill was cheaper.
+    pub shadow_better: usize,
+    /// Shadowed orders, oldest first.
+    pub orders: Vec<ShadowOrderResponse>,
+}
+
+impl From<ShadowReport> for ShadowExecutionResponse {
+    fn from(report: ShadowReport) -> Self {
+        Self {
+            as_of: report.as_of,
+            compared: report.compared,
+            avg_realized_slippage_bps: report.avg_realized_slippage_bps,
+            avg_shadow_slippage_bps: report.avg_shadow_slippage_bps,
+            shadow_better: report.shadow_better,
+            orders: report.orders.into_iter().map(Into::into).collect(),
+        }
+    }
+}
+
+/// One live order next to its shadow simulation.
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct ShadowOrderResponse {
+    /// Live order ID.
+    pub order_id: String,
+    /// Symbol.
+    pub symbol: String,
+    /// Side.
+    pub side: OrderSide,
+    /// Purpose.
+    pub purpose: OrderPurpose,
+    /// Ordered quantity.
+    pub quantity: Decimal,
+    /// Tactic selected by the live profile.
+    pub live_tactic: String,
+    /// Tactic selected by the shadow profile.
+    pub shadow_tactic: String,
+    /// Quote mid at submission.
+    pub arrival_mid: Decimal,
+    /// Filled quantity of the live order.
+    pub filled_qty: Decimal,
+    /// Average fill price of the live order.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub realized_price: Option<Decimal>,
+    /// Live slippage against the arrival mid, in basis points.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub realized_slippage_bps: Option<Decimal>,
+    /// Simulated fill price of the shadow tactic.
+    pub shadow_price: Decimal,
+    /// Shadow slippage against the arrival mid, in basis points.
+    pub shadow_slippage_bps: Decimal,
+    /// When the order was submitted.
+    pub submitted_at: Timestamp,
+}
+
+impl From<ShadowComparison> for ShadowOrderResponse {
+    fn from(comparison: ShadowComparison) -> Self {
+        let order = comparison.order;
+        Self {
+            order_id: order.order_id,
+            symbol: order.symbol,
+            side: order.side,
+            purpose: order.purpose,
+            quantity: order.quantity,
+            live_tactic: order.live_tactic.to_string(),
+            shadow_tactic: order.shadow_tactic.to_string(),
+            arrival_mid: order.arrival_mid,
+            filled_qty: comparison.filled_qty,
+            realized_price: comparison.realized_price,
+            realized_slippage_bps: comparison.realized_slippage_bps,
+            shadow_price: order.shadow_price,
+            shadow_slippage_bps: comparison.shadow_slippage_bps,
+            submitted_at: order.recorded_at,
+        }
+    }
+}
+
+/// Result of moving a position's stop and/or target.
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct AdjustStopsResponse {
+    /// Symbol adjusted.
+    pub symbol: String,
+    /// Monitored positions whose levels changed.
+    pub monitored: Vec<String>,
+    /// Protective stop orders canceled.
+    pub canceled: Vec<String>,
+    /// Replacement stop orders submitted.
+    pub submitted: Vec<String>,
+    /// Errors replacing broker stops.
+    pub errors: Vec<String>,
+}
+
+/// A watchlist.
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct WatchlistResponse {
+    /// Watchlist name.
+    pub name: String,
+    /// Symbols, sorted.
+    pub symbols: Vec<String>,
+    /// When the watchlist was created.
+    pub created_at: Timestamp,
+    /// When the symbols last changed.
+    pub updated_at: Timestamp,
+}
+
+impl From<Watchlist> for WatchlistResponse {
+    fn from(watchlist: Watchlist) -> Self {
+        Self {
+            name: watchlist.name,
+            symbols: watchlist.symbols,
+            created_at: watchlist.created_at,
+            updated_at: watchlist.updated_at,
+        }
+    }
+}
+
+/// All watchlists.
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct WatchlistsResponse {
+    /// Watchlists, by name.
+    pub watchlists: Vec<WatchlistResponse>,
+}
+
+/// A broker account connected over OAuth.
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct BrokerAccountResponse {
+    /// The user the account belongs to.
+    pub user_id: String,
+    /// Granted scopes, space separated.
+    pub scope: String,
+    /// When the current token was issued.
+    pub token_obtained_at: Timestamp,
+    /// When the current access token expires.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub token_expires_at: Option<Timestamp>,
+}
+
+impl From<BrokerAccount> for BrokerAccountResponse {
+    fn from(account: BrokerAccount) -> Self {
+        Self {
+            user_id: account.user_id,
+            scope: account.scope,
+            token_obtained_at: account.token_obtained_at,
+            token_expires_at: account.token_expires_at,
+        }
+    }
+}
+
+/// All connected broker accounts.
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct BrokerAccountsResponse {
+    /// Accounts, by user ID.
+    pub accounts: Vec<BrokerAccountResponse>,
+}
+
+/// Where to send a user to connect their broker account.
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct BrokerAuthorizationResponse {
+    /// The user connecting an account.
+    pub user_id: String,
+    /// Broker authorization page, valid for 10 minutes.
+    pub authorize_url: String,
+}
+
+/// Kill switch state.
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct KillSwitchResponse {
+    /// Whether trading is disabled.
+    pub engaged: bool,
+    /// Why trading is disabled.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reason: Option<String>,
+}
+
+/// State of a feature flag.
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct FeatureFlagResponse {
+    /// Flag name.
+    pub name: String,
+    /// Whether the feature is on.
+    pub enabled: bool,
+    /// Whether it is on in this environment without an override.
+    pub default: bool,
+    /// Why it was overridden (absent when following the default).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reason: Option<String>,
+}
+
+impl From<FeatureFlag> for FeatureFlagResponse {
+    fn from(flag: FeatureFlag) -> Self {
+        Self {
+            name: flag.feature.as_str().to_string(),
+            enabled: flag.enabled,
+            default: flag.default,
+            reason: flag.reason,
+        }
+    }
+}
+
+/// All feature flags.
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct FeatureFlagsResponse {
+    /// Flags, in a fixed order.
+    pub flags: Vec<FeatureFlagResponse>,
+}
+
+/// Tunable settings changed by a config reload.
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct ConfigReloadResponse {
+    /// Path of the reloaded file.
+    pub path: String,
+    /// Settings that changed (empty if the file was unchanged).
+    pub changes: Vec<ConfigChangeResponse>,
+}
+
+/// A changed setting.
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct ConfigChangeResponse {
+    /// Dotted path of the setting, e.g. `constraints.portfolio.max_leverage`.
+    pub field: String,
+    /// Previous value.
+    pub old: String,
+    /// New value.
+    pub new: String,
+}
+
+/// Signed export of the engine's local state.
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct StateBundleResponse {
+    /// Open position lifecycles, working orders and their thesis metadata.
+    #[schema(value_type = Object)]
+    pub bundle: serde_json::Value,
+    /// Hex HMAC-SHA256 of the bundle.
+    pub signature: String,
+}
+
+impl From<SignedStateBundle> for StateBundleResponse {
+    fn from(signed: SignedStateBundle) -> Self {
+        Self {
+            bundle: signed.bundle,
+            signature: signed.signature,
+        }
+    }
+}
+
+/// Outcome of a state import.
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct StateImportResponse {
+    /// Position lifecycles restored.
+    pub positions: usize,
+    /// Orders restored.
+    pub orders: usize,
+    /// Conflicts overridden by `force`.
+    pub conflicts: Vec<StateConflictResponse>,
+}
+
+impl From<StateImportReport> for StateImportResponse {
+    fn from(report: StateImportReport) -> Self {
+        Self {
+            positions: report.positions,
+            orders: report.orders,
+            conflicts: report.conflicts.into_iter().map(Into::into).collect(),
+        }
+    }
+}
+
+/// Disagreement between a state bundle and broker or local state.
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct StateConflictResponse {
+    /// `POSITION_MISMATCH`, `POSITION_EXISTS`, `ORDER_EXISTS`,
+    /// `ORDER_NOT_AT_BROKER` or `ORDER_NOT_OPEN`.
+    pub kind: String,
+    /// Symbol or order ID.
+    pub subject: String,
+    /// Details.
+    pub message: String,
+}
+
+impl From<StateConflict> for StateConflictResponse {
+    fn from(conflict: StateConflict) -> Self {
+        Self {
+            kind: conflict.kind.as_str().to_string(),
+            subject: conflict.subject,
+            message: conflict.message,
+        }
+    }
+}
+
 /// API error response.
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
 pub struct ApiErrorResponse {
-    /// Error code.
+    /// Stable machine-readable error code.
     pub code: String,
     /// Error message.
     pub message: String,
+    /// Whether retrying the same request later may succeed.
+    #[serde(default)]
+    pub retryable: bool,
     /// Additional details.
     #[serde(skip_serializing_if = "Option::is_none")]
+    #[schema(value_type = Option<Object>)]
     pub details: Option<serde_json::Value>,
+    /// Per-field validation errors.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub errors: Vec<FieldError>,
+}
+
+/// A validation error for a single request field.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+pub struct FieldError {
+    /// Path to the field, e.g. `decisions[0].quantity`.
+    pub field: String,
+    /// Machine-readable error code.
+    pub code: String,
+    /// Human-readable message.
+    pub message: String,
 }
 
 #[cfg(test)]
@@ -194,10 +968,13 @@ mod tests {
                 time_in_force: TimeInForce::Day,
                 filled_qty: Decimal::ZERO,
                 avg_fill_price: None,
+                child_order_ids: Vec::new(),
+                strategy_tag: None,
                 error: None,
             }],
             error: None,
             risk_violations: None,
+            scheduled_plan_id: None,
         };
 
         let json = serde_json::to_string(&resp).unwrap();
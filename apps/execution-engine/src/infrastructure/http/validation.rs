@@ -0,0 +1,567 @@
+//! HTTP request validation.
+//!
+//! [`ValidJson`] replaces axum's `Json` extractor on API routes. It rejects
+//! malformed bodies, type errors (unknown enum values, bad timestamps) and
+//! semantically invalid values (non-positive quantities, missing limit
+//! prices) with an [`ApiErrorResponse`] listing every offending field, before
+//! the request reaches a use case.
+
+use axum::{
+    Json,
+    extract::{FromRequest, Request, rejection::JsonRejection},
+    http::StatusCode,
+    response::{IntoResponse, Response},
+};
+use rust_decimal::Decimal;
+use serde::de::DeserializeOwned;
+
+use crate::domain::order_execution::aggregate::{MAX_STRATEGY_TAG_LEN, is_valid_strategy_tag};
+use crate::domain::order_execution::repository::MAX_ORDER_QUERY_LIMIT;
+use crate::domain::order_execution::value_objects::OrderType;
+use crate::domain::shared::ErrorKind;
+use crate::infrastructure::api_error::{ApiError, http_status};
+
+use super::request::{
+    AdjustStopsRequest, CancelOrdersRequest, CheckConstraintsRequest, CircuitBreakerRequest,
+    CreateWatchlistRequest, DecisionRequest, ExportBlotterRequest, FeatureFlagRequest,
+    GetOrderStateRequest, ImportStateRequest, KillSwitchRequest, QueryOrdersRequest,
+    SubmitOrdersRequest, WatchlistSymbolsRequest,
+};
+use super::response::{ApiErrorResponse, FieldError};
+
+/// Semantic checks run after a request body deserializes.
+pub trait Validate {
+    /// Append a [`FieldError`] for each invalid field.
+    fn validate(&self, errors: &mut Vec<FieldError>);
+}
+
+/// JSON body extractor that deserializes and validates `T`.
+#[derive(Debug, Clone)]
+pub struct ValidJson<T>(pub T);
+
+impl<S, T> FromRequest<S> for ValidJson<T>
+where
+    S: Send + Sync,
+    T: DeserializeOwned + Validate,
+{
+    type Rejection = ValidationRejection;
+
+    async fn from_request(request: Request, state: &S) -> Result<Self, Self::Rejection> {
+        let Json(value) = Json::<serde_json::Value>::from_request(request, state)
+            .await
+            .map_err(|e| ValidationRejection::from_json(&e))?;
+
+        let body: T = serde_path_to_error::deserialize(value)
+            .map_err(|e| ValidationRejection::fields(vec![deserialize_error(&e)]))?;
+
+        let mut errors = Vec::new();
+        body.validate(&mut errors);
+        if !errors.is_empty() {
+            return Err(ValidationRejection::fields(errors));
+        }
+
+        Ok(Self(body))
+    }
+}
+
+/// Rejection returned by [`ValidJson`].
+#[derive(Debug, Clone)]
+pub struct ValidationRejection {
+    status: StatusCode,
+    body: ApiErrorResponse,
+}
+
+impl ValidationRejection {
+    /// HTTP status of the rejection.
+    #[must_use]
+    pub const fn status(&self) -> StatusCode {
+        self.status
+    }
+
+    /// Error envelope sent to the client.
+    #[must_use]
+    pub const fn body(&self) -> &ApiErrorResponse {
+        &self.body
+    }
+
+    fn new(error: ApiError, errors: Vec<FieldError>) -> Self {
+        Self {
+            status: http_status(error.kind),
+            body: ApiErrorResponse {
+                errors,
+                ..error.into()
+            },
+        }
+    }
+
+    fn fields(errors: Vec<FieldError>) -> Self {
+        let error =
+            ApiError::invalid_argument(format!("Request has {} invalid field(s)", errors.len()));
+        Self::new(error, errors)
+    }
+
+    fn from_json(rejection: &JsonRejection) -> Self {
+        let (kind, code) = match rejection {
+            JsonRejection::MissingJsonContentType(_) => {
+                (ErrorKind::UnsupportedMediaType, "UNSUPPORTED_MEDIA_TYPE")
+            }
+            JsonRejection::JsonSyntaxError(_) => (ErrorKind::MalformedRequest, "MALFORMED_JSON"),
+            _ => (ErrorKind::MalformedRequest, "INVALID_BODY"),
+        };
+        Self::new(ApiError::new(kind, code, rejection.body_text()), vec![])
+    }
+}
+
+impl IntoResponse for ValidationRejection {
+    fn into_response(self) -> Response {
+        (self.status, Json(self.body)).into_response()
+    }
+}
+
+fn deserialize_error(error: &serde_path_to_error::Error<serde_json::Error>) -> FieldError {
+    let path = error.path().to_string();
+    let message = error.inner().to_string();
+
+    // Missing fields are reported against their parent; point at the field itself
+    if let Some(name) = message
+        .strip_prefix("missing field `")
+        .and_then(|rest| rest.split('`').next())
+    {
+        let field = if path == "." {
+            name.to_string()
+        } else {
+            format!("{path}.{name}")
+        };
+        return field_error(field, "REQUIRED", message);
+    }
+
+    let code = if message.starts_with("unknown variant") {
+        "UNKNOWN_VALUE"
+    } else {
+        "INVALID_VALUE"
+    };
+    field_error(path, code, message)
+}
+
+fn field_error(field: impl Into<String>, code: &str, message: impl Into<String>) -> FieldError {
+    FieldError {
+        field: field.into(),
+        code: code.to_string(),
+        message: message.into(),
+    }
+}
+
+fn require_non_empty(field: &str, value: &str, errors: &mut Vec<FieldError>) {
+    if value.trim().is_empty() {
+        errors.push(field_error(field, "REQUIRED", "must not be empty"));
+    }
+}
+
+fn require_positive(field: &str, value: Option<Decimal>, errors: &mut Vec<FieldError>) {
+    if let Some(value) = value
+        && value <= Decimal::ZERO
+    {
+        errors.push(field_error(
+            field,
+            "NOT_POSITIVE",
+            format!("must be greater than zero, got {value}"),
+        ));
+    }
+}
+
+fn validate_decisions(decisions: &[DecisionRequest], errors: &mut Vec<FieldError>) {
+    for (i, decision) in decisions.iter().enumerate() {
+        let field = |name: &str| format!("decisions[{i}].{name}");
+
+        require_non_empty(&field("symbol"), &decision.symbol, errors);
+        require_positive(&field("quantity"), Some(decision.quantity), errors);
+        require_positive(&field("limit_price"), decision.limit_price, errors);
+        require_positive(&field("stop_price"), decision.stop_price, errors);
+
+        let needs_limit = matches!(decision.order_type, OrderType::Limit | OrderType::StopLimit);
+        if needs_limit && decision.limit_price.is_none() {
+            errors.push(field_error(
+                field("limit_price"),
+                "REQUIRED",
+                "required for LIMIT and STOP_LIMIT orders",
+            ));
+        }
+
+        let needs_stop = matches!(decision.order_type, OrderType::Stop | OrderType::StopLimit);
+        if needs_stop && decision.stop_price.is_none() {
+            errors.push(field_error(
+                field("stop_price"),
+                "REQUIRED",
+                "required for STOP and STOP_LIMIT orders",
+            ));
+        }
+
+        if let Some(tag) = &decision.strategy_tag
+            && !is_valid_strategy_tag(tag)
+        {
+            errors.push(field_error(
+                field("strategy_tag"),
+                "INVALID_VALUE",
+                format!("must be 1 to {MAX_STRATEGY_TAG_LEN} letters, digits, '_', '-' or '.'"),
+            ));
+        }
+
+        if decision.max_holding_secs == Some(0) {
+            errors.push(field_error(
+                field("max_holding_secs"),
+                "NOT_POSITIVE",
+                "must be greater than zero",
+            ));
+        }
+        if let Some(distance) = decision.holding_stop_distance {
+            if distance <= Decimal::ZERO || distance >= Decimal::ONE {
+                errors.push(field_error(
+                    field("holding_stop_distance"),
+                    "OUT_OF_RANGE",
+                    "must be between 0 and 1",
+                ));
+            } else if decision.max_holding_secs.is_none() {
+                errors.push(field_error(
+                    field("max_holding_secs"),
+                    "REQUIRED",
+                    "required with holding_stop_distance",
+                ));
+            }
+        }
+
+        let mut total_fraction = Decimal::ZERO;
+        for (j, target) in decision.scale_out.iter().enumerate() {
+            require_positive(
+                &field(&format!("scale_out[{j}].price")),
+                Some(target.price),
+                errors,
+            );
+            if target.fraction <= Decimal::ZERO || target.fraction > Decimal::ONE {
+                errors.push(field_error(
+                    field(&format!("scale_out[{j}].fraction")),
+                    "OUT_OF_RANGE",
+                    "must be greater than 0 and at most 1",
+                ));
+            }
+            total_fraction += target.fraction;
+        }
+        if total_fraction > Decimal::ONE {
+            errors.push(field_error(
+                field("scale_out"),
+                "OUT_OF_RANGE",
+                "fractions must add up to at most 1",
+            ));
+        }
+
+        require_positive(&field("exit_stop_loss"), decision.exit_stop_loss, errors);
+        require_positive(
+            &field("exit_take_profit"),
+            decision.exit_take_profit,
+            errors,
+        );
+        if decision.exit_stop_loss.is_some() && decision.exit_take_profit.is_some() {
+            errors.push(field_error(
+                field("exit_take_profit"),
+                "INVALID_VALUE",
+                "only one of exit_stop_loss and exit_take_profit may be set",
+            ));
+        }
+    }
+}
+
+fn validate_order_ids(order_ids: &[String], errors: &mut Vec<FieldError>) {
+    for (i, order_id) in order_ids.iter().enumerate() {
+        require_non_empty(&format!("order_ids[{i}]"), order_id, errors);
+    }
+}
+
+impl Validate for CheckConstraintsRequest {
+    fn validate(&self, errors: &mut Vec<FieldError>) {
+        require_non_empty("cycle_id", &self.cycle_id, errors);
+        validate_decisions(&self.decisions, errors);
+    }
+}
+
+impl Validate for SubmitOrdersRequest {
+    fn validate(&self, errors: &mut Vec<FieldError>) {
+        require_non_empty("request_id", &self.request_id, errors);
+        require_non_empty("cycle_id", &self.cycle_id, errors);
+        validate_decisions(&self.decisions, errors);
+
+        if self.execution_window_secs == Some(0) {
+            errors.push(field_error(
+                "execution_window_secs",
+                "NOT_POSITIVE",
+                "must be greater than zero",
+            ));
+        }
+    }
+}
+
+impl Validate for GetOrderStateRequest {
+    fn validate(&self, errors: &mut Vec<FieldError>) {
+        validate_order_ids(&self.order_ids, errors);
+    }
+}
+
+impl Validate for QueryOrdersRequest {
+    fn validate(&self, errors: &mut Vec<FieldError>) {
+        if let Some(symbol) = &self.symbol {
+            require_non_empty("symbol", symbol, errors);
+        }
+        if let Some(cycle_id) = &self.cycle_id {
+            require_non_empty("cycle_id", cycle_id, errors);
+        }
+        if let (Some(from), Some(to)) = (self.from, self.to)
+            && from >= to
+        {
+            errors.push(field_error("to", "INVALID_VALUE", "must be after from"));
+        }
+        if let Some(limit) = self.limit
+            && !(1..=MAX_ORDER_QUERY_LIMIT).contains(&limit)
+        {
+            errors.push(field_error(
+                "limit",
+                "OUT_OF_RANGE",
+                format!("must be between 1 and {MAX_ORDER_QUERY_LIMIT}"),
+            ));
+        }
+    }
+}
+
+impl Validate for ExportBlotterRequest {
+    fn validate(&self, errors: &mut Vec<FieldError>) {
+        if self.from >= self.to {
+            errors.push(field_error("to", "INVALID_VALUE", "must be after from"));
+        }
+    }
+}
+
+impl Validate for CancelOrdersRequest {
+    fn validate(&self, errors: &mut Vec<FieldError>) {
+        validate_order_ids(&self.order_ids, errors);
+    }
+}
+
+impl Validate for AdjustStopsRequest {
+    fn validate(&self, errors: &mut Vec<FieldError>) {
+        require_positive("stop_loss", self.stop_loss, errors);
+        require_positive("take_profit", self.take_profit, errors);
+        if self.stop_loss.is_none() && self.take_profit.is_none() {
+            errors.push(field_error(
+                "stop_loss",
+                "REQUIRED",
+                "stop_loss or take_profit is required",
+            ));
+        }
+        if let Some(reason) = &self.reason {
+            require_non_empty("reason", reason, errors);
+        }
+    }
+}
+
+impl Validate for KillSwitchRequest {
+    fn validate(&self, errors: &mut Vec<FieldError>) {
+        if let Some(reason) = &self.reason {
+            require_non_empty("reason", reason, errors);
+        }
+    }
+}
+
+impl Validate for CircuitBreakerRequest {
+    fn validate(&self, errors: &mut Vec<FieldError>) {
+        if let Some(reason) = &self.reason {
+            require_non_empty("reason", reason, errors);
+        }
+    }
+}
+
+impl Validate for FeatureFlagRequest {
+    fn validate(&self, errors: &mut Vec<FieldError>) {
+        if let Some(reason) = &self.reason {
+            require_non_empty("reason", reason, errors);
+        }
+    }
+}
+
+fn validate_symbols(symbols: &[String], errors: &mut Vec<FieldError>) {
+    for (i, symbol) in symbols.iter().enumerate() {
+        require_non_empty(&format!("symbols[{i}]"), symbol, errors);
+    }
+}
+
+impl Validate for CreateWatchlistRequest {
+    fn validate(&self, errors: &mut Vec<FieldError>) {
+        require_non_empty("name", &self.name, errors);
+        validate_symbols(&self.symbols, errors);
+    }
+}
+
+impl Validate for WatchlistSymbolsRequest {
+    fn validate(&self, errors: &mut Vec<FieldError>) {
+        if self.symbols.is_empty() {
+            errors.push(field_error("symbols", "REQUIRED", "must not be empty"));
+        }
+        validate_symbols(&self.symbols, errors);
+    }
+}
+
+impl Validate for ImportStateRequest {
+    fn validate(&self, errors: &mut Vec<FieldError>) {
+        require_non_empty("signature", &self.signature, errors);
+        if !self.bundle.is_object() {
+            errors.push(field_error("bundle", "INVALID_TYPE", "must be an object"));
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn submit(decisions: &serde_json::Value) -> serde_json::Value {
+        serde_json::json!({
+            "request_id": "req-1",
+            "cycle_id": "cycle-1",
+            "risk_policy_id": "default",
+            "account_equity": "100000",
+            "decisions": decisions
+        })
+    }
+
+    fn errors_for(body: serde_json::Value) -> Vec<FieldError> {
+        match serde_path_to_error::deserialize::<_, SubmitOrdersRequest>(body) {
+            Ok(request) => {
+                let mut errors = Vec::new();
+                request.validate(&mut errors);
+                errors
+            }
+            Err(e) => vec![deserialize_error(&e)],
+        }
+    }
+
+    #[test]
+    fn accepts_valid_request() {
+        let body = submit(&serde_json::json!([
+            {"symbol": "AAPL", "side": "BUY", "quantity": "10"},
+            {"symbol": "MSFT", "side": "SELL", "order_type": "LIMIT", "quantity": "5", "limit_price": "410.5"}
+        ]));
+
+        assert!(errors_for(body).is_empty());
+    }
+
+    #[test]
+    fn reports_every_invalid_field() {
+        let body = submit(&serde_json::json!([
+            {"symbol": " ", "side": "BUY", "quantity": "-5"},
+            {"symbol": "MSFT", "side": "BUY", "order_type": "STOP_LIMIT", "quantity": "5"}
+        ]));
+
+        let fields: Vec<_> = errors_for(body).into_iter().map(|e| e.field).collect();
+        assert_eq!(
+            fields,
+            [
+                "decisions[0].symbol",
+                "decisions[0].quantity",
+                "decisions[1].limit_price",
+                "decisions[1].stop_price",
+            ]
+        );
+    }
+
+    #[test]
+    fn validates_holding_period() {
+        let body = submit(&serde_json::json!([
+            {"symbol": "AAPL", "side": "BUY", "quantity": "10", "max_holding_secs": 3600, "holding_stop_distance": "0.01"},
+            {"symbol": "AAPL", "side": "BUY", "quantity": "10", "max_holding_secs": 0},
+            {"symbol": "AAPL", "side": "BUY", "quantity": "10", "holding_stop_distance": "0.01"},
+            {"symbol": "AAPL", "side": "BUY", "quantity": "10", "max_holding_secs": 60, "holding_stop_distance": "1.5"}
+        ]));
+
+        let fields: Vec<_> = errors_for(body).into_iter().map(|e| e.field).collect();
+        assert_eq!(
+            fields,
+            [
+                "decisions[1].max_holding_secs",
+                "decisions[2].max_holding_secs",
+                "decisions[3].holding_stop_distance",
+            ]
+        );
+    }
+
+    #[test]
+    fn validates_scale_out() {
+        let body = submit(&serde_json::json!([
+            {"symbol": "AAPL", "side": "BUY", "quantity": "10", "scale_out": [
+                {"price": "105", "fraction": "0.5"}, {"price": "110", "fraction": "0.5"}
+            ]},
+            {"symbol": "AAPL", "side": "BUY", "quantity": "10", "scale_out": [
+                {"price": "0", "fraction": "0.5"}, {"price": "110", "fraction": "0.75"}
+            ]}
+        ]));
+
+        let fields: Vec<_> = errors_for(body).into_iter().map(|e| e.field).collect();
+        assert_eq!(
+            fields,
+            ["decisions[1].scale_out[0].price", "decisions[1].scale_out",]
+        );
+    }
+
+    #[test]
+    fn validates_oto_exit() {
+        let body = submit(&serde_json::json!([
+            {"symbol": "AAPL", "side": "BUY", "quantity": "10", "exit_stop_loss": "95"},
+            {"symbol": "AAPL", "side": "BUY", "quantity": "10", "exit_take_profit": "0"},
+            {"symbol": "AAPL", "side": "BUY", "quantity": "10",
+             "exit_stop_loss": "95", "exit_take_profit": "110"}
+        ]));
+
+        let fields: Vec<_> = errors_for(body).into_iter().map(|e| e.field).collect();
+        assert_eq!(
+            fields,
+            [
+                "decisions[1].exit_take_profit",
+                "decisions[2].exit_take_profit"
+            ]
+        );
+    }
+
+    #[test]
+    fn validates_strategy_tag() {
+        let body = submit(&serde_json::json!([
+            {"symbol": "AAPL", "side": "BUY", "quantity": "10", "strategy_tag": "momentum.v2"},
+            {"symbol": "AAPL", "side": "BUY", "quantity": "10", "strategy_tag": "mean reversion"}
+        ]));
+
+        let errors = errors_for(body);
+        assert_eq!(errors.len(), 1);
+        assert_eq!(errors[0].field, "decisions[1].strategy_tag");
+        assert_eq!(errors[0].code, "INVALID_VALUE");
+    }
+
+    #[test]
+    fn reports_unknown_enum_with_path() {
+        let body = submit(&serde_json::json!([
+            {"symbol": "AAPL", "side": "HOLD", "quantity": "10"}
+        ]));
+
+        let errors = errors_for(body);
+        assert_eq!(errors.len(), 1);
+        assert_eq!(errors[0].field, "decisions[0].side");
+        assert_eq!(errors[0].code, "UNKNOWN_VALUE");
+    }
+
+    #[test]
+    fn reports_bad_timestamp_and_missing_field() {
+        let mut body = submit(&serde_json::json!([]));
+        body["execute_at"] = serde_json::json!("tomorrow");
+        let errors = errors_for(body);
+        assert_eq!(errors[0].field, "execute_at");
+        assert_eq!(errors[0].code, "INVALID_VALUE");
+
+        let body = submit(&serde_json::json!([{"side": "BUY", "quantity": "10"}]));
+        let errors = errors_for(body);
+        assert_eq!(errors[0].field, "decisions[0].symbol");
+        assert_eq!(errors[0].code, "REQUIRED");
+    }
+}
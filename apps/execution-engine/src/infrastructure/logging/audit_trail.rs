@@ -0,0 +1,150 @@
+//! In-memory trail of the most recent audit events, kept so a crash
+//! snapshot can show what led up to a fatal error.
+
+use std::collections::{BTreeMap, VecDeque};
+use std::fmt;
+use std::sync::{Arc, Mutex, PoisonError};
+
+use serde::{Deserialize, Serialize};
+use tracing::field::{Field, Visit};
+use tracing::{Event, Subscriber};
+use tracing_subscriber::Layer;
+use tracing_subscriber::layer::Context;
+
+use super::AUDIT_TARGET;
+use crate::domain::shared::Timestamp;
+
+/// An event logged on the [`AUDIT_TARGET`] target.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct AuditEvent {
+    /// When the event was logged.
+    pub timestamp: Timestamp,
+    /// Log level.
+    pub level: String,
+    /// Event message.
+    pub message: String,
+    /// Other event fields, formatted.
+    pub fields: BTreeMap<String, String>,
+}
+
+/// The last `capacity` audit events.
+#[derive(Debug)]
+pub struct AuditTrail {
+    capacity: usize,
+    events: Mutex<VecDeque<AuditEvent>>,
+}
+
+impl AuditTrail {
+    /// Create a trail keeping the last `capacity` events.
+    #[must_use]
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            capacity,
+            events: Mutex::new(VecDeque::with_capacity(capacity)),
+        }
+    }
+
+    /// Append `event`, dropping the oldest event when full.
+    pub fn record(&self, event: AuditEvent) {
+        if self.capacity == 0 {
+            return;
+        }
+        let mut events = self.events.lock().unwrap_or_else(PoisonError::into_inner);
+        if events.len() == self.capacity {
+            events.pop_front();
+        }
+        events.push_back(event);
+    }
+
+    /// Events kept, oldest first.
+    #[must_use]
+    pub fn recent(&self) -> Vec<AuditEvent> {
+        self.events
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .iter()
+            .cloned()
+            .collect()
+    }
+
+    /// Layer recording audit events into this trail.
+    #[must_use]
+    pub fn layer(self: &Arc<Self>) -> AuditTrailLayer {
+        AuditTrailLayer {
+            trail: Arc::clone(self),
+        }
+    }
+}
+
+/// Tracing layer feeding an [`AuditTrail`].
+#[derive(Debug)]
+pub struct AuditTrailLayer {
+    trail: Arc<AuditTrail>,
+}
+
+impl<S: Subscriber> Layer<S> for AuditTrailLayer {
+    fn on_event(&self, event: &Event<'_>, _ctx: Context<'_, S>) {
+        if event.metadata().target() != AUDIT_TARGET {
+            return;
+        }
+        let mut visitor = FieldVisitor::default();
+        event.record(&mut visitor);
+        self.trail.record(AuditEvent {
+            timestamp: Timestamp::now(),
+            level: event.metadata().level().to_string(),
+            message: visitor.message,
+            fields: visitor.fields,
+        });
+    }
+}
+
+#[derive(Default)]
+struct FieldVisitor {
+    message: String,
+    fields: BTreeMap<String, String>,
+}
+
+impl Visit for FieldVisitor {
+    fn record_str(&mut self, field: &Field, value: &str) {
+        self.insert(field, value.to_string());
+    }
+
+    fn record_debug(&mut self, field: &Field, value: &dyn fmt::Debug) {
+        self.insert(field, format!("{value:?}"));
+    }
+}
+
+impl FieldVisitor {
+    fn insert(&mut self, field: &Field, value: String) {
+        if field.name() == "message" {
+            self.message = value;
+        } else {
+            self.fields.insert(field.name().to_string(), value);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tracing_subscriber::layer::SubscriberExt;
+
+    #[test]
+    fn keeps_the_latest_audit_events() {
+        let trail = Arc::new(AuditTrail::new(2));
+        let subscriber = tracing_subscriber::registry().with(trail.layer());
+        tracing::subscriber::with_default(subscriber, || {
+            tracing::info!(target: "audit", order_id = "o-1", "order submitted");
+            tracing::info!("not audited");
+            tracing::warn!(target: "audit", reason = %"manual", "kill switch tripped");
+            tracing::error!(target: "audit", offset_ms = 900, "clock skewed");
+        });
+
+        let events = trail.recent();
+        assert_eq!(events.len(), 2);
+        assert_eq!(events[0].message, "kill switch tripped");
+        assert_eq!(events[0].level, "WARN");
+        assert_eq!(events[0].fields["reason"], "manual");
+        assert_eq!(events[1].fields["offset_ms"], "900");
+    }
+}
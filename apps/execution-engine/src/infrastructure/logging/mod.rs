@@ -0,0 +1,195 @@
+//! File Logging
+//!
+//! Writes logs to daily files under `observability.logging.file.directory`,
+//! split into two streams:
+//!
+//! - `engine.YYYY-MM-DD.log`: application events
+//! - `audit.YYYY-MM-DD.log`: events on the [`AUDIT_TARGET`] target
+//!
+//! Files rotate at midnight UTC and every line carries an RFC 3339
+//! timestamp. [`LogRetention`] deletes the oldest files of a stream once the
+//! stream exceeds its size limit. Audit lines are never dropped when the
+//! writer falls behind; application lines may be.
+//!
+//! Independently of files, an [`AuditTrail`] keeps the most recent audit
+//! events in memory for crash snapshots.
+
+mod audit_trail;
+mod retention;
+
+use std::path::Path;
+
+use tracing::Subscriber;
+use tracing_appender::non_blocking::{NonBlockingBuilder, WorkerGuard};
+use tracing_appender::rolling::{RollingFileAppender, Rotation};
+use tracing_subscriber::Layer;
+use tracing_subscriber::filter::filter_fn;
+use tracing_subscriber::registry::LookupSpan;
+
+use crate::infrastructure::config::{FileLoggingSettings, LogFileFormat};
+
+pub use audit_trail::{AuditEvent, AuditTrail, AuditTrailLayer};
+pub use retention::LogRetention;
+
+/// Target of audit events, e.g. `tracing::info!(target: "audit", ...)`.
+pub const AUDIT_TARGET: &str = "audit";
+
+/// File name prefix of the application stream.
+pub const APPLICATION_PREFIX: &str = "engine";
+
+/// File name prefix of the audit stream.
+pub const AUDIT_PREFIX: &str = "audit";
+
+/// File name suffix of both streams.
+const LOG_SUFFIX: &str = "log";
+
+/// File logging errors.
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+pub enum LoggingError {
+    /// The log directory could not be created.
+    #[error("Failed to create log directory {path}: {message}")]
+    Directory {
+        /// Directory path.
+        path: String,
+        /// Error details.
+        message: String,
+    },
+
+    /// A log file could not be opened.
+    #[error("Failed to open {stream} log: {message}")]
+    Appender {
+        /// Stream prefix.
+        stream: &'static str,
+        /// Error details.
+        message: String,
+    },
+}
+
+/// Flushes buffered log lines when dropped. Hold until shutdown.
+#[must_use = "log lines are lost if the guard is dropped early"]
+pub struct FileLogGuard {
+    _guards: Vec<WorkerGuard>,
+}
+
+impl std::fmt::Debug for FileLogGuard {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("FileLogGuard").finish_non_exhaustive()
+    }
+}
+
+/// Boxed layer type returned by [`file_layer`].
+pub type BoxedLayer<S> = Box<dyn Layer<S> + Send + Sync + 'static>;
+
+/// Build the layer that writes the application and audit streams.
+///
+/// # Errors
+///
+/// Returns error if the directory or a log file cannot be created.
+pub fn file_layer<S>(
+    settings: &FileLoggingSettings,
+) -> Result<(BoxedLayer<S>, FileLogGuard), LoggingError>
+where
+    S: Subscriber + for<'a> LookupSpan<'a>,
+{
+    let directory = Path::new(&settings.directory);
+    std::fs::create_dir_all(directory).map_err(|e| LoggingError::Directory {
+        path: settings.directory.clone(),
+        message: e.to_string(),
+    })?;
+
+    let (application, application_guard) =
+        stream(directory, APPLICATION_PREFIX, settings.format, true)?;
+    let (audit, audit_guard) = stream(directory, AUDIT_PREFIX, settings.format, false)?;
+
+    let layer = application
+        .with_filter(filter_fn(|metadata| metadata.target() != AUDIT_TARGET))
+        .and_then(audit.with_filter(filter_fn(|metadata| metadata.target() == AUDIT_TARGET)))
+        .boxed();
+
+    Ok((
+        layer,
+        FileLogGuard {
+            _guards: vec![application_guard, audit_guard],
+        },
+    ))
+}
+
+/// A daily rotating, non-blocking file layer.
+fn stream<S>(
+    directory: &Path,
+    prefix: &'static str,
+    format: LogFileFormat,
+    lossy: bool,
+) -> Result<(BoxedLayer<S>, WorkerGuard), LoggingError>
+where
+    S: Subscriber + for<'a> LookupSpan<'a>,
+{
+    let appender = RollingFileAppender::builder()
+        .rotation(Rotation::DAILY)
+        .filename_prefix(prefix)
+        .filename_suffix(LOG_SUFFIX)
+        .build(directory)
+        .map_err(|e| LoggingError::Appender {
+            stream: prefix,
+            message: e.to_string(),
+        })?;
+    let (writer, guard) = NonBlockingBuilder::default().lossy(lossy).finish(appender);
+
+    let layer = tracing_subscriber::fmt::layer()
+        .with_writer(writer)
+        .with_ansi(false);
+    let layer = match format {
+        LogFileFormat::Json => layer.json().with_current_span(true).boxed(),
+        LogFileFormat::Text => layer.boxed(),
+    };
+    Ok((layer, guard))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tracing_subscriber::layer::SubscriberExt;
+
+    fn read_stream(directory: &Path, prefix: &str) -> String {
+        std::fs::read_dir(directory)
+            .unwrap()
+            .map(|entry| entry.unwrap().path())
+            .filter(|path| {
+                path.file_name()
+                    .and_then(|name| name.to_str())
+                    .is_some_and(|name| name.starts_with(&format!("{prefix}.")))
+            })
+            .map(|path| std::fs::read_to_string(path).unwrap())
+            .collect()
+    }
+
+    #[test]
+    fn splits_audit_and_application_streams() {
+        let dir = tempfile::tempdir().unwrap();
+        let settings = FileLoggingSettings {
+            directory: dir.path().display().to_string(),
+            format: LogFileFormat::Json,
+            max_total_mb: 1,
+            audit_max_total_mb: 1,
+        };
+
+        let (layer, guard) = file_layer(&settings).unwrap();
+        let subscriber = tracing_subscriber::registry().with(layer);
+        tracing::subscriber::with_default(subscriber, || {
+            tracing::info!(order_id = "o-1", "order submitted");
+            tracing::info!(target: "audit", field = "max_units", "config changed");
+        });
+        drop(guard);
+
+        let application = read_stream(dir.path(), APPLICATION_PREFIX);
+        assert!(application.contains("order submitted"));
+        assert!(!application.contains("config changed"));
+
+        let audit = read_stream(dir.path(), AUDIT_PREFIX);
+        let line: serde_json::Value = serde_json::from_str(audit.lines().next().unwrap()).unwrap();
+        assert_eq!(line["target"], "audit");
+        assert_eq!(line["fields"]["message"], "config changed");
+        assert_eq!(line["fields"]["field"], "max_units");
+        assert!(line["timestamp"].is_string());
+    }
+}
@@ -0,0 +1,170 @@
+//! Size-based log retention.
+//!
+//! Daily files are named `{prefix}.YYYY-MM-DD.log`, so sorting by name
+//! orders them by age. The newest file of a stream is never deleted, even
+//! when it alone exceeds the limit.
+
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+use std::time::Duration;
+
+use tokio_util::sync::CancellationToken;
+
+use super::{APPLICATION_PREFIX, AUDIT_PREFIX, LOG_SUFFIX};
+use crate::infrastructure::config::FileLoggingSettings;
+
+/// How often log directories are pruned.
+const PRUNE_INTERVAL: Duration = Duration::from_secs(3600);
+
+const BYTES_PER_MB: u64 = 1024 * 1024;
+
+/// Deletes the oldest log files of each stream beyond its size limit.
+#[derive(Debug, Clone)]
+pub struct LogRetention {
+    directory: PathBuf,
+    streams: Vec<(&'static str, u64)>,
+}
+
+impl LogRetention {
+    /// Create a retention policy for `directory` with no streams.
+    #[must_use]
+    pub fn new(directory: impl Into<PathBuf>) -> Self {
+        Self {
+            directory: directory.into(),
+            streams: Vec::new(),
+        }
+    }
+
+    /// Keep at most `max_bytes` of files starting with `prefix`.
+    #[must_use]
+    pub fn with_stream(mut self, prefix: &'static str, max_bytes: u64) -> Self {
+        self.streams.push((prefix, max_bytes));
+        self
+    }
+
+    /// Retention for the application and audit streams.
+    #[must_use]
+    pub fn from_settings(settings: &FileLoggingSettings) -> Self {
+        Self::new(&settings.directory)
+            .with_stream(
+                APPLICATION_PREFIX,
+                settings.max_total_mb.saturating_mul(BYTES_PER_MB),
+            )
+            .with_stream(
+                AUDIT_PREFIX,
+                settings.audit_max_total_mb.saturating_mul(BYTES_PER_MB),
+            )
+    }
+
+    /// Delete files over the limits.
+    ///
+    /// Returns the deleted paths.
+    ///
+    /// # Errors
+    ///
+    /// Returns error if the directory cannot be read or a file cannot be
+    /// deleted.
+    pub fn prune(&self) -> std::io::Result<Vec<PathBuf>> {
+        let mut deleted = Vec::new();
+        for &(prefix, max_bytes) in &self.streams {
+            deleted.extend(prune_stream(&self.directory, prefix, max_bytes)?);
+        }
+        Ok(deleted)
+    }
+
+    /// Prune now and then hourly until shutdown.
+    pub fn start(self: &Arc<Self>, shutdown: CancellationToken) {
+        let retention = Arc::clone(self);
+        tokio::spawn(async move {
+            let mut interval = tokio::time::interval(PRUNE_INTERVAL);
+
+            loop {
+                tokio::select! {
+                    _ = interval.tick() => match retention.prune() {
+                        Ok(deleted) => {
+                            for path in deleted {
+                                tracing::info!(path = %path.display(), "Deleted expired log file");
+                            }
+                        }
+                        Err(e) => tracing::warn!(error = %e, "Failed to prune log files"),
+                    },
+                    () = shutdown.cancelled() => break,
+                }
+            }
+        });
+    }
+}
+
+fn prune_stream(directory: &Path, prefix: &str, max_bytes: u64) -> std::io::Result<Vec<PathBuf>> {
+    let name_prefix = format!("{prefix}.");
+    let name_suffix = format!(".{LOG_SUFFIX}");
+
+    let mut files = Vec::new();
+    for entry in std::fs::read_dir(directory)? {
+        let entry = entry?;
+        let name = entry.file_name().to_string_lossy().into_owned();
+        if name.starts_with(&name_prefix) && name.ends_with(&name_suffix) {
+            files.push((name, entry.metadata()?.len()));
+        }
+    }
+    files.sort();
+
+    let mut total: u64 = files.iter().map(|(_, size)| size).sum();
+    let mut deleted = Vec::new();
+    // Never delete the file currently being written
+    let expired = files.len().saturating_sub(1);
+    for (name, size) in files.into_iter().take(expired) {
+        if total <= max_bytes {
+            break;
+        }
+        let path = directory.join(name);
+        std::fs::remove_file(&path)?;
+        total -= size;
+        deleted.push(path);
+    }
+    Ok(deleted)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn write(directory: &Path, name: &str, bytes: usize) {
+        std::fs::write(directory.join(name), vec![b'x'; bytes]).unwrap();
+    }
+
+    #[test]
+    fn deletes_oldest_files_over_limit() {
+        let dir = tempfile::tempdir().unwrap();
+        write(dir.path(), "engine.2026-01-01.log", 600);
+        write(dir.path(), "engine.2026-01-02.log", 600);
+        write(dir.path(), "engine.2026-01-03.log", 600);
+        write(dir.path(), "audit.2026-01-01.log", 600);
+
+        let retention = LogRetention::new(dir.path())
+            .with_stream(APPLICATION_PREFIX, 1000)
+            .with_stream(AUDIT_PREFIX, 1000);
+        let deleted = retention.prune().unwrap();
+
+        assert_eq!(
+            deleted,
+            vec![
+                dir.path().join("engine.2026-01-01.log"),
+                dir.path().join("engine.2026-01-02.log"),
+            ]
+        );
+        assert!(dir.path().join("engine.2026-01-03.log").exists());
+        assert!(dir.path().join("audit.2026-01-01.log").exists());
+    }
+
+    #[test]
+    fn keeps_current_file_over_limit() {
+        let dir = tempfile::tempdir().unwrap();
+        write(dir.path(), "engine.2026-01-01.log", 2000);
+
+        let retention = LogRetention::new(dir.path()).with_stream(APPLICATION_PREFIX, 1000);
+
+        assert!(retention.prune().unwrap().is_empty());
+        assert!(dir.path().join("engine.2026-01-01.log").exists());
+    }
+}
@@ -16,12 +16,14 @@ use rust_decimal::Decimal;
 use tokio::time::timeout;
 
 use crate::application::ports::{
-    MarketDataError, MarketDataPort, MarketQuote, OptionChainData, OptionContract, OptionGreeks,
-    OptionQuote, OptionType,
+    DailyBar, DailyBars, LastTrade, MarketDataError, MarketDataPort, MarketQuote, OptionChainData,
+    OptionChainFilter, OptionContract, OptionGreeks, OptionQuote, OptionType,
 };
 use crate::domain::shared::Timestamp;
 use crate::infrastructure::broker::alpaca::api_types::AlpacaOptionSnapshotsResponse;
 use crate::infrastructure::broker::alpaca::{AlpacaConfig, AlpacaEnvironment, AlpacaError};
+use crate::infrastructure::decimal::decimal_from_f64;
+use crate::infrastructure::secrets::AlpacaCredentials;
 
 /// Timeout for waiting for quotes via WebSocket.
 const QUOTE_TIMEOUT: Duration = Duration::from_secs(5);
@@ -29,13 +31,19 @@ const QUOTE_TIMEOUT: Duration = Duration::from_secs(5);
 /// Maximum age for cached quotes before they're considered stale.
 const CACHE_MAX_AGE: Duration = Duration::from_secs(30);
 
+/// Option contracts requested per page.
+const CONTRACT_PAGE_SIZE: u32 = 1000;
+
+/// Upper bound on contract pages fetched for one chain.
+const MAX_CONTRACT_PAGES: usize = 50;
+
 /// Alpaca market data adapter using WebSocket streaming.
 ///
 /// This adapter uses Alpaca's WebSocket API for real-time quote streaming
 /// and REST API for option chain data (no WebSocket support for full chains).
 pub struct AlpacaMarketDataAdapter {
-    /// WebSocket client credentials.
-    credentials: Credentials,
+    /// API credentials for WebSocket and REST.
+    credentials: RwLock<AlpacaCredentials>,
     /// Alpaca environment (Paper/Live).
     environment: AlpacaEnv,
     /// Data feed type.
@@ -44,10 +52,6 @@ pub struct AlpacaMarketDataAdapter {
     quote_cache: Arc<RwLock<HashMap<String, CachedQuote>>>,
     /// HTTP client for REST API calls (option chains).
     http_client: reqwest::Client,
-    /// API key for REST.
-    api_key: String,
-    /// API secret for REST.
-    api_secret: String,
     /// Data API base URL.
     data_url: String,
     /// Trading API base URL (for option contracts endpoint).
@@ -82,8 +86,6 @@ impl AlpacaMarketDataAdapter {
             return Err(AlpacaError::AuthenticationFailed);
         }
 
-        let credentials = Credentials::new(config.api_key.clone(), config.api_secret.clone());
-
         let environment = match config.environment {
             AlpacaEnvironment::Paper => AlpacaEnv::Paper,
             AlpacaEnvironment::Live => AlpacaEnv::Live,
@@ -100,18 +102,47 @@ impl AlpacaMarketDataAdapter {
             .map_err(|e| AlpacaError::Network(e.to_string()))?;
 
         Ok(Self {
-            credentials,
+            credentials: RwLock::new(config.credentials()),
             environment,
             feed,
             quote_cache: Arc::new(RwLock::new(HashMap::new())),
             http_client,
-            api_key: config.api_key.clone(),
-            api_secret: config.api_secret.clone(),
             data_url: config.data_base_url().to_string(),
             trading_url: config.trading_base_url().to_string(),
         })
     }
 
+    /// Use new credentials for subsequent requests and streams.
+    ///
+    /// # Errors
+    ///
+    /// Returns error if either credential is empty.
+    pub fn rotate_credentials(&self, credentials: &AlpacaCredentials) -> Result<(), AlpacaError> {
+        if credentials.api_key.is_empty() || credentials.api_secret.is_empty() {
+            return Err(AlpacaError::AuthenticationFailed);
+        }
+        *self.credentials.write() = credentials.clone();
+        Ok(())
+    }
+
+    /// Credentials for a new WebSocket client.
+    fn websocket_credentials(&self) -> Credentials {
+        let credentials = self.credentials.read();
+        Credentials::new(
+            credentials.api_key.expose().to_string(),
+            credentials.api_secret.expose().to_string(),
+        )
+    }
+
+    /// REST GET request with authentication headers.
+    fn get(&self, url: &str) -> reqwest::RequestBuilder {
+        let credentials = self.credentials.read();
+        self.http_client
+            .get(url)
+            .header("APCA-API-KEY-ID", credentials.api_key.expose())
+            .header("APCA-API-SECRET-KEY", credentials.api_secret.expose())
+    }
+
     /// Subscribe to quotes via WebSocket and wait for initial data.
     async fn fetch_quotes_via_websocket(
         &self,
@@ -122,7 +153,7 @@ impl AlpacaMarketDataAdapter {
         }
 
         let client = AlpacaWebSocketClient::with_feed(
-            self.credentials.clone(),
+            self.websocket_credentials(),
             self.environment.clone(),
             self.feed,
         );
@@ -161,8 +192,8 @@ impl AlpacaMarketDataAdapter {
                     {
                         let market_quote = MarketQuote {
                             symbol: symbol.clone(),
-                            bid: Decimal::try_from(quote.bid_price).unwrap_or(Decimal::ZERO),
-                            ask: Decimal::try_from(quote.ask_price).unwrap_or(Decimal::ZERO),
+                            bid: decimal_from_f64(quote.bid_price).unwrap_or(Decimal::ZERO),
+                            ask: decimal_from_f64(quote.ask_price).unwrap_or(Decimal::ZERO),
                             bid_size: i32::try_from(quote.bid_size).unwrap_or(0),
                             ask_size: i32::try_from(quote.ask_size).unwrap_or(0),
                             last: Decimal::ZERO, // WebSocket quote doesn't include last
@@ -221,12 +252,15 @@ impl AlpacaMarketDataAdapter {
     async fn fetch_option_chain_via_rest(
         &self,
         underlying: &str,
+        filter: &OptionChainFilter,
     ) -> Result<OptionChainData, MarketDataError> {
         // First, get the underlying price
         let underlying_price = self.get_underlying_price(underlying).await?;
 
-        // Get option contracts for the underlying
-        let contracts = self.fetch_option_contracts(underlying).await?;
+        // Get option contracts for the underlying, narrowed by the filter so
+        // snapshots are only fetched for contracts that can match
+        let query = contract_query(filter, underlying_price);
+        let contracts = self.fetch_option_contracts(underlying, &query).await?;
 
         if contracts.is_empty() {
             return Ok(OptionChainData {
@@ -250,19 +284,19 @@ impl AlpacaMarketDataAdapter {
                 .and_then(|s| s.latest_quote.as_ref())
                 .map(|q| MarketQuote {
                     symbol: contract.symbol.clone(),
-                    bid: Decimal::try_from(q.bp).unwrap_or(Decimal::ZERO),
-                    ask: Decimal::try_from(q.ap).unwrap_or(Decimal::ZERO),
+                    bid: q.bp,
+                    ask: q.ap,
                     bid_size: q.bs,
                     ask_size: q.ask_size,
                     last: snapshot
                         .and_then(|s| s.latest_trade.as_ref())
-                        .map_or(Decimal::ZERO, |t| {
-                            Decimal::try_from(t.p).unwrap_or(Decimal::ZERO)
-                        }),
+                        .map_or(Decimal::ZERO, |t| t.p),
                     last_size: snapshot
                         .and_then(|s| s.latest_trade.as_ref())
                         .map_or(0, |t| t.s),
-                    volume: 0,
+                    volume: snapshot
+                        .and_then(|s| s.daily_bar.as_ref())
+                        .map_or(0, |b| b.v),
                     timestamp: Timestamp::now(),
                 });
 
@@ -290,16 +324,19 @@ impl AlpacaMarketDataAdapter {
                 quote,
                 implied_volatility: snapshot.and_then(|s| s.implied_volatility),
                 greeks,
-                open_interest: 0, // Not provided in snapshots
+                open_interest: contract.open_interest.unwrap_or(0),
             });
         }
 
-        Ok(OptionChainData {
+        // Expiration lists, open interest and volume are filtered locally
+        let mut chain = OptionChainData {
             underlying: underlying.to_string(),
             underlying_price,
             options,
             as_of: Timestamp::now(),
-        })
+        };
+        filter.apply(&mut chain);
+        Ok(chain)
     }
 
     /// Fetch quotes via REST API (fallback when WebSocket has no data).
@@ -322,16 +359,13 @@ impl AlpacaMarketDataAdapter {
             self.data_url, symbols_param
         );
 
-        let response = self
-            .http_client
-            .get(&url)
-            .header("APCA-API-KEY-ID", &self.api_key)
-            .header("APCA-API-SECRET-KEY", &self.api_secret)
-            .send()
-            .await
-            .map_err(|e| MarketDataError::ConnectionError {
-                message: e.to_string(),
-            })?;
+        let response =
+            self.get(&url)
+                .send()
+                .await
+                .map_err(|e| MarketDataError::ConnectionError {
+                    message: e.to_string(),
+                })?;
 
         if !response.status().is_success() {
             let status = response.status();
@@ -350,8 +384,8 @@ impl AlpacaMarketDataAdapter {
         #[allow(clippy::items_after_statements)]
         #[derive(serde::Deserialize)]
         struct RestQuoteData {
-            bp: f64,
-            ap: f64,
+            bp: Decimal,
+            ap: Decimal,
             bs: i32,
             #[serde(rename = "as")]
             ask_size: i32,
@@ -371,8 +405,8 @@ impl AlpacaMarketDataAdapter {
             if let Some(q) = data.quotes.get(&upper) {
                 quotes.push(MarketQuote {
                     symbol: upper,
-                    bid: Decimal::try_from(q.bp).unwrap_or(Decimal::ZERO),
-                    ask: Decimal::try_from(q.ap).unwrap_or(Decimal::ZERO),
+                    bid: q.bp,
+                    ask: q.ap,
                     bid_size: q.bs,
                     ask_size: q.ask_size,
                     last: Decimal::ZERO,
@@ -392,6 +426,47 @@ impl AlpacaMarketDataAdapter {
         Ok(quotes)
     }
 
+    /// GET a multi-symbol stock data endpoint and parse the JSON body.
+    async fn get_stock_data<T>(
+        &self,
+        endpoint: &str,
+        symbols: &[String],
+        what: &str,
+    ) -> Result<T, MarketDataError>
+    where
+        T: serde::de::DeserializeOwned,
+    {
+        let symbols_param = symbols
+            .iter()
+            .map(|s| s.to_uppercase())
+            .collect::<Vec<_>>()
+            .join(",");
+        let url = format!("{}{endpoint}?symbols={symbols_param}", self.data_url);
+
+        let response =
+            self.get(&url)
+                .send()
+                .await
+                .map_err(|e| MarketDataError::ConnectionError {
+                    message: e.to_string(),
+                })?;
+
+        if !response.status().is_success() {
+            let status = response.status();
+            let body = response.text().await.unwrap_or_default();
+            return Err(MarketDataError::ApiError {
+                message: format!("Failed to get {what} ({status}): {body}"),
+            });
+        }
+
+        response
+            .json()
+            .await
+            .map_err(|e| MarketDataError::ApiError {
+                message: format!("Failed to parse {what}: {e}"),
+            })
+    }
+
     /// Get underlying stock price.
     async fn get_underlying_price(&self, symbol: &str) -> Result<Decimal, MarketDataError> {
         let url = format!(
@@ -400,16 +475,13 @@ impl AlpacaMarketDataAdapter {
             symbol.to_uppercase()
         );
 
-        let response = self
-            .http_client
-            .get(&url)
-            .header("APCA-API-KEY-ID", &self.api_key)
-            .header("APCA-API-SECRET-KEY", &self.api_secret)
-            .send()
-            .await
-            .map_err(|e| MarketDataError::ConnectionError {
-                message: e.to_string(),
-            })?;
+        let response =
+            self.get(&url)
+                .send()
+                .await
+                .map_err(|e| MarketDataError::ConnectionError {
+                    message: e.to_string(),
+                })?;
 
         if !response.status().is_success() {
             return Err(MarketDataError::ApiError {
@@ -426,8 +498,8 @@ impl AlpacaMarketDataAdapter {
         #[allow(clippy::items_after_statements)]
         #[derive(serde::Deserialize)]
         struct QuoteData {
-            bp: f64,
-            ap: f64,
+            bp: Decimal,
+            ap: Decimal,
         }
 
         let data: QuoteResponse = response
@@ -437,57 +509,77 @@ impl AlpacaMarketDataAdapter {
                 message: format!("Failed to parse quote: {e}"),
             })?;
 
-        let mid = f64::midpoint(data.quote.bp, data.quote.ap);
-        Decimal::try_from(mid).map_err(|_| MarketDataError::DataUnavailable {
-            message: "Invalid price data".to_string(),
-        })
+        Ok((data.quote.bp + data.quote.ap) / Decimal::TWO)
     }
 
-    /// Fetch option contracts for an underlying.
+    /// Fetch option contracts for an underlying, following pagination.
     async fn fetch_option_contracts(
         &self,
         underlying: &str,
+        query: &[(&'static str, String)],
     ) -> Result<Vec<OptionContractInfo>, MarketDataError> {
-        let url = format!(
-            "{}/v2/options/contracts?underlying_symbols={}&limit=1000",
-            self.trading_url,
-            underlying.to_uppercase()
-        );
-
-        let response = self
-            .http_client
-            .get(&url)
-            .header("APCA-API-KEY-ID", &self.api_key)
-            .header("APCA-API-SECRET-KEY", &self.api_secret)
-            .send()
-            .await
-            .map_err(|e| MarketDataError::ConnectionError {
-                message: e.to_string(),
-            })?;
-
-        if !response.status().is_success() {
-            let status = response.status();
-            let body = response.text().await.unwrap_or_default();
-            return Err(MarketDataError::ApiError {
-                message: format!("Failed to get option contracts ({status}): {body}"),
-            });
-        }
-
-        #[allow(clippy::items_after_statements)]
         #[derive(serde::Deserialize)]
         struct ContractsResponse {
             option_contracts: Vec<OptionContractInfo>,
+            next_page_token: Option<String>,
         }
 
-        let data: ContractsResponse =
-            response
-                .json()
-                .await
-                .map_err(|e| MarketDataError::ApiError {
-                    message: format!("Failed to parse contracts: {e}"),
-                })?;
+        let mut contracts = Vec::new();
+        let mut page_token: Option<String> = None;
+
+        for _ in 0..MAX_CONTRACT_PAGES {
+            let mut url =
+                reqwest::Url::parse(&format!("{}/v2/options/contracts", self.trading_url))
+                    .map_err(|e| MarketDataError::ApiError {
+                        message: format!("Invalid contracts URL: {e}"),
+                    })?;
+            {
+                let mut pairs = url.query_pairs_mut();
+                pairs.append_pair("underlying_symbols", &underlying.to_uppercase());
+                pairs.append_pair("limit", &CONTRACT_PAGE_SIZE.to_string());
+                for (name, value) in query {
+                    pairs.append_pair(name, value);
+                }
+                if let Some(token) = &page_token {
+                    pairs.append_pair("page_token", token);
+                }
+            }
+
+            let response = self.get(url.as_str()).send().await.map_err(|e| {
+                MarketDataError::ConnectionError {
+                    message: e.to_string(),
+                }
+            })?;
+
+            if !response.status().is_success() {
+                let status = response.status();
+                let body = response.text().await.unwrap_or_default();
+                return Err(MarketDataError::ApiError {
+                    message: format!("Failed to get option contracts ({status}): {body}"),
+                });
+            }
+
+            let data: ContractsResponse =
+                response
+                    .json()
+                    .await
+                    .map_err(|e| MarketDataError::ApiError {
+                        message: format!("Failed to parse contracts: {e}"),
+                    })?;
+
+            contracts.extend(data.option_contracts);
+            match data.next_page_token {
+                Some(token) if !token.is_empty() => page_token = Some(token),
+                _ => return Ok(contracts),
+            }
+        }
 
-        Ok(data.option_contracts)
+        tracing::warn!(
+            underlying = %underlying,
+            contracts = contracts.len(),
+            "Option contract pagination limit reached, chain truncated"
+        );
+        Ok(contracts)
     }
 
     /// Fetch option snapshots (quotes + Greeks).
@@ -512,16 +604,13 @@ impl AlpacaMarketDataAdapter {
                 self.data_url, symbols_param
             );
 
-            let response = self
-                .http_client
-                .get(&url)
-                .header("APCA-API-KEY-ID", &self.api_key)
-                .header("APCA-API-SECRET-KEY", &self.api_secret)
-                .send()
-                .await
-                .map_err(|e| MarketDataError::ConnectionError {
-                    message: e.to_string(),
-                })?;
+            let response =
+                self.get(&url)
+                    .send()
+                    .await
+                    .map_err(|e| MarketDataError::ConnectionError {
+                        message: e.to_string(),
+                    })?;
 
             if response.status().is_success() {
                 let data: AlpacaOptionSnapshotsResponse =
@@ -540,6 +629,91 @@ impl AlpacaMarketDataAdapter {
     }
 }
 
+/// Latest trades response, keyed by symbol.
+#[derive(Debug, serde::Deserialize)]
+struct LatestTradesResponse {
+    trades: HashMap<String, RestTrade>,
+}
+
+/// A trade from the stock data API.
+#[derive(Debug, serde::Deserialize)]
+struct RestTrade {
+    t: Timestamp,
+    p: Decimal,
+    s: i64,
+}
+
+/// Stock snapshot from the data API; only the daily bars are read.
+#[derive(Debug, serde::Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct RestStockSnapshot {
+    daily_bar: Option<RestBar>,
+    prev_daily_bar: Option<RestBar>,
+}
+
+/// A bar from the stock data API.
+#[derive(Debug, serde::Deserialize)]
+struct RestBar {
+    t: Timestamp,
+    o: Decimal,
+    h: Decimal,
+    l: Decimal,
+    c: Decimal,
+    v: i64,
+    vw: Option<Decimal>,
+    n: Option<i64>,
+}
+
+impl From<RestBar> for DailyBar {
+    fn from(bar: RestBar) -> Self {
+        Self {
+            timestamp: bar.t,
+            open: bar.o,
+            high: bar.h,
+            low: bar.l,
+            close: bar.c,
+            volume: bar.v,
+            vwap: bar.vw,
+            trade_count: bar.n,
+        }
+    }
+}
+
+fn latest_trades(response: LatestTradesResponse, symbols: &[String]) -> Vec<LastTrade> {
+    let mut trades = response.trades;
+    symbols
+        .iter()
+        .filter_map(|symbol| {
+            let upper = symbol.to_uppercase();
+            let trade = trades.remove(&upper)?;
+            Some(LastTrade {
+                symbol: upper,
+                price: trade.p,
+                size: trade.s,
+                timestamp: trade.t,
+            })
+        })
+        .collect()
+}
+
+fn daily_bars(
+    mut snapshots: HashMap<String, Option<RestStockSnapshot>>,
+    symbols: &[String],
+) -> Vec<DailyBars> {
+    symbols
+        .iter()
+        .filter_map(|symbol| {
+            let upper = symbol.to_uppercase();
+            let snapshot = snapshots.remove(&upper).flatten()?;
+            Some(DailyBars {
+                symbol: upper,
+                today: snapshot.daily_bar.map(DailyBar::from),
+                previous: snapshot.prev_daily_bar.map(DailyBar::from),
+            })
+        })
+        .collect()
+}
+
 /// Option contract info from Alpaca.
 #[derive(Debug, Clone, serde::Deserialize)]
 struct OptionContractInfo {
@@ -550,6 +724,64 @@ struct OptionContractInfo {
     strike_price: Decimal,
     #[serde(rename = "type")]
     option_type: String,
+    #[serde(default, deserialize_with = "deserialize_optional_i32_from_string")]
+    open_interest: Option<i32>,
+}
+
+/// Contract query parameters for the filters Alpaca applies server-side.
+fn contract_query(
+    filter: &OptionChainFilter,
+    underlying_price: Decimal,
+) -> Vec<(&'static str, String)> {
+    let mut query = Vec::new();
+
+    let min_expiration = filter
+        .expirations
+        .iter()
+        .min()
+        .into_iter()
+        .chain(&filter.min_expiration)
+        .max();
+    let max_expiration = filter
+        .expirations
+        .iter()
+        .max()
+        .into_iter()
+        .chain(&filter.max_expiration)
+        .min();
+    if let Some(date) = min_expiration {
+        query.push(("expiration_date_gte", date.clone()));
+    }
+    if let Some(date) = max_expiration {
+        query.push(("expiration_date_lte", date.clone()));
+    }
+
+    let (min_strike, max_strike) = filter.strike_bounds(underlying_price);
+    if let Some(strike) = min_strike {
+        query.push(("strike_price_gte", strike.round_dp(2).to_string()));
+    }
+    if let Some(strike) = max_strike {
+        query.push(("strike_price_lte", strike.round_dp(2).to_string()));
+    }
+
+    if let Some(option_type) = filter.option_type {
+        let value = match option_type {
+            OptionType::Call => "call",
+            OptionType::Put => "put",
+        };
+        query.push(("type", value.to_string()));
+    }
+
+    query
+}
+
+fn deserialize_optional_i32_from_string<'de, D>(deserializer: D) -> Result<Option<i32>, D::Error>
+where
+    D: serde::Deserializer<'de>,
+{
+    let s: Option<String> = serde::Deserialize::deserialize(deserializer)?;
+    s.map(|s| s.parse().map_err(serde::de::Error::custom))
+        .transpose()
 }
 
 fn deserialize_decimal_from_string<'de, D>(deserializer: D) -> Result<Decimal, D::Error>
@@ -579,7 +811,41 @@ impl MarketDataPort for AlpacaMarketDataAdapter {
 
     async fn get_option_chain(&self, underlying: &str) -> Result<OptionChainData, MarketDataError> {
         tracing::debug!(underlying = %underlying, "Fetching option chain via REST");
-        self.fetch_option_chain_via_rest(underlying).await
+        self.fetch_option_chain_via_rest(underlying, &OptionChainFilter::default())
+            .await
+    }
+
+    async fn get_filtered_option_chain(
+        &self,
+        underlying: &str,
+        filter: &OptionChainFilter,
+    ) -> Result<OptionChainData, MarketDataError> {
+        tracing::debug!(underlying = %underlying, ?filter, "Fetching filtered option chain via REST");
+        self.fetch_option_chain_via_rest(underlying, filter).await
+    }
+
+    async fn get_latest_trades(
+        &self,
+        symbols: &[String],
+    ) -> Result<Vec<LastTrade>, MarketDataError> {
+        if symbols.is_empty() {
+            return Ok(vec![]);
+        }
+        let response = self
+            .get_stock_data("/v2/stocks/trades/latest", symbols, "latest trades")
+            .await?;
+        Ok(latest_trades(response, symbols))
+    }
+
+    async fn get_daily_bars(&self, symbols: &[String]) -> Result<Vec<DailyBars>, MarketDataError> {
+        if symbols.is_empty() {
+            return Ok(vec![]);
+        }
+        // The snapshot endpoint carries both the current and prior daily bar
+        let snapshots = self
+            .get_stock_data("/v2/stocks/snapshots", symbols, "stock snapshots")
+            .await?;
+        Ok(daily_bars(snapshots, symbols))
     }
 }
 
@@ -611,4 +877,67 @@ mod tests {
         let debug = format!("{adapter:?}");
         assert!(debug.contains("AlpacaMarketDataAdapter"));
     }
+
+    #[test]
+    fn contract_query_pushes_filters_to_alpaca() {
+        let filter = OptionChainFilter {
+            expirations: vec!["2025-01-17".to_string(), "2025-02-21".to_string()],
+            max_expiration: Some("2025-01-31".to_string()),
+            strike_range_pct: Some(Decimal::new(5, 2)),
+            option_type: Some(OptionType::Put),
+            min_open_interest: Some(10),
+            ..OptionChainFilter::default()
+        };
+
+        let query = contract_query(&filter, Decimal::new(5000, 0));
+
+        assert_eq!(
+            query,
+            vec![
+                ("expiration_date_gte", "2025-01-17".to_string()),
+                ("expiration_date_lte", "2025-01-31".to_string()),
+                ("strike_price_gte", "4750.00".to_string()),
+                ("strike_price_lte", "5250.00".to_string()),
+                ("type", "put".to_string()),
+            ]
+        );
+        assert!(contract_query(&OptionChainFilter::default(), Decimal::ONE).is_empty());
+    }
+
+    #[test]
+    fn contract_info_parses_open_interest() {
+        let json = r#"{"symbol":"SPX250117P04750000","underlying_symbol":"SPX",
+            "expiration_date":"2025-01-17","strike_price":"4750","type":"put",
+            "open_interest":"1234"}"#;
+        let info: OptionContractInfo = serde_json::from_str(json).unwrap();
+        assert_eq!(info.open_interest, Some(1234));
+
+        let json = json.replace(r#""open_interest":"1234""#, r#""open_interest":null"#);
+        let info: OptionContractInfo = serde_json::from_str(&json).unwrap();
+        assert_eq!(info.open_interest, None);
+    }
+
+    #[test]
+    fn parses_latest_trades_and_daily_bars() {
+        let symbols = vec!["aapl".to_string(), "MSFT".to_string()];
+        let json = r#"{"trades":{"AAPL":{"t":"2026-03-02T15:00:00.123Z","x":"V",
+            "p":150.25,"s":100,"c":["@"],"i":1,"z":"C"}}}"#;
+        let trades = latest_trades(serde_json::from_str(json).unwrap(), &symbols);
+        assert_eq!(trades.len(), 1);
+        assert_eq!(trades[0].symbol, "AAPL");
+        assert_eq!(trades[0].price, Decimal::new(15025, 2));
+        assert_eq!(trades[0].size, 100);
+
+        let json = r#"{"AAPL":{
+            "dailyBar":{"t":"2026-03-02T05:00:00Z","o":149,"h":151,"l":148.5,"c":150.25,"v":1000,"n":12,"vw":150.1},
+            "prevDailyBar":{"t":"2026-02-27T05:00:00Z","o":147,"h":149,"l":146,"c":148,"v":900,"n":10,"vw":148.2}},
+            "MSFT":null}"#;
+        let bars = daily_bars(serde_json::from_str(json).unwrap(), &symbols);
+        assert_eq!(bars.len(), 1);
+        assert_eq!(bars[0].today.as_ref().unwrap().high, Decimal::new(151, 0));
+        assert_eq!(
+            bars[0].previous.as_ref().unwrap().close,
+            Decimal::new(148, 0)
+        );
+    }
 }
@@ -0,0 +1,9 @@
+//! Messaging
+//!
+//! Event sinks that order events are delivered to from the event outbox.
+//!
+//! [`WebhookEventPublisher`] posts events as JSON to an HTTP endpoint.
+
+mod webhook;
+
+pub use webhook::WebhookEventPublisher;
@@ -0,0 +1,131 @@
+//! Webhook event sink.
+//!
+//! Posts order events to an HTTP endpoint as `{"events": [...]}`, each event
+//! tagged with its `type`. Any non-2xx response is a failed delivery and is
+//! retried by the outbox, so a receiver may see an event more than once.
+
+use std::time::Duration;
+
+use async_trait::async_trait;
+use serde::Serialize;
+
+use crate::application::ports::{EventPublishError, EventPublisherPort};
+use crate::domain::order_execution::events::OrderEvent;
+
+/// Request body of a webhook delivery.
+#[derive(Serialize)]
+struct WebhookBody<'a> {
+    events: &'a [OrderEvent],
+}
+
+/// Delivers order events to a webhook.
+#[derive(Debug)]
+pub struct WebhookEventPublisher {
+    client: reqwest::Client,
+    url: String,
+}
+
+impl WebhookEventPublisher {
+    /// Create a publisher posting to `url`.
+    ///
+    /// # Errors
+    ///
+    /// Returns error if the HTTP client cannot be built.
+    pub fn new(url: impl Into<String>) -> Result<Self, EventPublishError> {
+        let client = reqwest::Client::builder()
+            .timeout(Duration::from_secs(10))
+            .build()
+            .map_err(|e| EventPublishError::ConnectionError {
+                message: e.to_string(),
+            })?;
+
+        Ok(Self {
+            client,
+            url: url.into(),
+        })
+    }
+
+    /// Endpoint events are posted to.
+    #[must_use]
+    pub fn url(&self) -> &str {
+        &self.url
+    }
+}
+
+#[async_trait]
+impl EventPublisherPort for WebhookEventPublisher {
+    async fn publish_order_events(&self, events: Vec<OrderEvent>) -> Result<(), EventPublishError> {
+        if events.is_empty() {
+            return Ok(());
+        }
+
+        let body = serde_json::to_vec(&WebhookBody { events: &events }).map_err(|e| {
+            EventPublishError::SerializationError {
+                message: e.to_string(),
+            }
+        })?;
+        let response = self
+            .client
+            .post(&self.url)
+            .header(reqwest::header::CONTENT_TYPE, "application/json")
+            .body(body)
+            .send()
+            .await
+            .map_err(|e| EventPublishError::ConnectionError {
+                message: e.to_string(),
+            })?;
+
+        let status = response.status();
+        if !status.is_success() {
+            return Err(EventPublishError::PublishFailed {
+                message: format!("webhook returned {status}"),
+            });
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::order_execution::events::OrderSubmitted;
+    use crate::domain::order_execution::value_objects::OrderSide;
+    use crate::domain::shared::{OrderId, Quantity, Symbol, Timestamp};
+    use wiremock::matchers::{body_partial_json, method, path};
+    use wiremock::{Mock, MockServer, ResponseTemplate};
+
+    fn submitted() -> OrderEvent {
+        OrderEvent::Submitted(OrderSubmitted {
+            order_id: OrderId::new("o-1"),
+            symbol: Symbol::new("AAPL"),
+            side: OrderSide::Buy,
+            quantity: Quantity::from_i64(100),
+            limit_price: None,
+            occurred_at: Timestamp::parse("2026-03-02T15:00:00Z").unwrap(),
+        })
+    }
+
+    #[tokio::test]
+    async fn posts_events_and_reports_rejections() {
+        let server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .and(path("/events"))
+            .and(body_partial_json(serde_json::json!({
+                "events": [{"type": "SUBMITTED", "order_id": "o-1"}]
+            })))
+            .respond_with(ResponseTemplate::new(204))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let publisher = WebhookEventPublisher::new(format!("{}/events", server.uri())).unwrap();
+        publisher.publish_order_event(submitted()).await.unwrap();
+
+        let rejecting = WebhookEventPublisher::new(format!("{}/down", server.uri())).unwrap();
+        let result = rejecting.publish_order_event(submitted()).await;
+        assert!(matches!(
+            result,
+            Err(EventPublishError::PublishFailed { .. })
+        ));
+    }
+}
@@ -0,0 +1,224 @@
+//! Prometheus Metrics Module
+//!
+//! Exposes execution metrics in Prometheus format for monitoring.
+//!
+//! # Metrics
+//!
+//! - **Orders**: Orders accepted by the broker, by strategy and side
+//! - **Fills**: Fills applied to orders, by strategy and side
+//! - **Slippage**: Fill price against the order's arrival price, in basis
+//!   points, by strategy and side
+//!
+//! The `strategy` label is the order's strategy tag when the tag is one of
+//! the configured strategies, `other` for any other tag and `none` for
+//! untagged orders, so free-form tags cannot grow the number of series.
+//!
+//! # Integration
+//!
+//! Metrics are exposed at `/metrics` on the HTTP port.
+
+use std::collections::HashSet;
+use std::sync::OnceLock;
+
+use metrics::{counter, describe_counter, describe_histogram, histogram};
+use metrics_exporter_prometheus::{PrometheusBuilder, PrometheusHandle};
+
+use crate::application::ports::ExecutionMetricsPort;
+use crate::domain::order_execution::aggregate::Order;
+use crate::domain::order_execution::value_objects::{FillReport, OrderSide};
+use crate::infrastructure::decimal::decimal_to_f64;
+
+/// `strategy` label of orders tagged with an unconfigured strategy.
+pub const OTHER_STRATEGY: &str = "other";
+
+/// `strategy` label of untagged orders.
+pub const NO_STRATEGY: &str = "none";
+
+// =============================================================================
+// Global Metrics Handle
+// =============================================================================
+
+static PROMETHEUS_HANDLE: OnceLock<PrometheusHandle> = OnceLock::new();
+
+/// Initialize the Prometheus metrics recorder.
+///
+/// # Panics
+///
+/// Panics if the recorder cannot be installed.
+#[allow(clippy::expect_used)]
+pub fn init_metrics() -> PrometheusHandle {
+    PROMETHEUS_HANDLE
+        .get_or_init(|| {
+            let handle = PrometheusBuilder::new()
+                .install_recorder()
+                .expect("failed to install Prometheus recorder");
+
+            register_metrics();
+            handle
+        })
+        .clone()
+}
+
+/// Get the Prometheus handle for rendering metrics.
+///
+/// Returns `None` if metrics have not been initialized.
+#[must_use]
+pub fn get_metrics_handle() -> Option<PrometheusHandle> {
+    PROMETHEUS_HANDLE.get().cloned()
+}
+
+// =============================================================================
+// Metric Registration
+// =============================================================================
+
+fn register_metrics() {
+    describe_counter!(
+        "execution_engine_orders_submitted_total",
+        "Orders accepted by the broker"
+    );
+    describe_counter!("execution_engine_fills_total", "Fills applied to orders");
+    describe_histogram!(
+        "execution_engine_fill_slippage_bps",
+        "Fill price against the order's arrival price in basis points, positive when worse"
+    );
+}
+
+// =============================================================================
+// Execution Metrics Adapter
+// =============================================================================
+
+/// Prometheus implementation of `ExecutionMetricsPort`.
+#[derive(Debug, Clone, Default)]
+pub struct PrometheusExecutionMetrics {
+    strategies: HashSet<String>,
+}
+
+impl PrometheusExecutionMetrics {
+    /// Create an adapter that labels orders with the given strategy tags.
+    #[must_use]
+    pub fn new(strategies: impl IntoIterator<Item = String>) -> Self {
+        Self {
+            strategies: strategies.into_iter().collect(),
+        }
+    }
+
+    /// `strategy` label for `order`.
+    fn strategy(&self, order: &Order) -> String {
+        match order.strategy_tag() {
+            Some(tag) if self.strategies.contains(tag) => tag.to_string(),
+            Some(_) => OTHER_STRATEGY.to_string(),
+            None => NO_STRATEGY.to_string(),
+        }
+    }
+}
+
+impl ExecutionMetricsPort for PrometheusExecutionMetrics {
+    fn record_order_submitted(&self, order: &Order) {
+        counter!(
+            "execution_engine_orders_submitted_total",
+            "strategy" => self.strategy(order),
+            "side" => side_label(order.side())
+        )
+        .increment(1);
+    }
+
+    fn record_fill(&self, order: &Order, fill: &FillReport) {
+        let strategy = self.strategy(order);
+        let side = side_label(order.side());
+        counter!(
+            "execution_engine_fills_total",
+            "strategy" => strategy.clone(),
+            "side" => side
+        )
+        .increment(1);
+        if let Some(slippage) = order.fill_slippage_bps(fill) {
+            histogram!(
+                "execution_engine_fill_slippage_bps",
+                "strategy" => strategy,
+                "side" => side
+            )
+            .record(decimal_to_f64(slippage));
+        }
+    }
+}
+
+/// Metric label for an order side.
+const fn side_label(side: OrderSide) -> &'static str {
+    match side {
+        OrderSide::Buy => "buy",
+        OrderSide::Sell => "sell",
+    }
+}
+
+// =============================================================================
+// Tests
+// =============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::order_execution::aggregate::CreateOrderCommand;
+    use crate::domain::order_execution::value_objects::{OrderPurpose, OrderType, TimeInForce};
+    use crate::domain::shared::{Money, Quantity, Symbol, Timestamp};
+
+    fn order(strategy_tag: Option<&str>) -> Order {
+        let command = CreateOrderCommand {
+            symbol: Symbol::new("AAPL"),
+            side: OrderSide::Buy,
+            order_type: OrderType::Market,
+            quantity: Quantity::from_i64(100),
+            limit_price: None,
+            stop_price: None,
+            time_in_force: TimeInForce::Day,
+            purpose: OrderPurpose::Entry,
+            legs: vec![],
+        };
+        let mut order = match strategy_tag {
+            Some(tag) => Order::new_tagged(command, tag).unwrap(),
+            None => Order::new(command).unwrap(),
+        };
+        order.record_arrival_price(Money::usd(100.0));
+        order
+    }
+
+    fn fill(price: f64) -> FillReport {
+        FillReport::new(
+            "fill-1",
+            Quantity::from_i64(100),
+            Money::usd(price),
+            Timestamp::parse("2026-03-02T15:00:00Z").unwrap(),
+            "NYSE",
+        )
+    }
+
+    #[test]
+    fn strategy_label_is_limited_to_configured_tags() {
+        let recorder = PrometheusBuilder::new().build_recorder();
+        let handle = recorder.handle();
+        let adapter = PrometheusExecutionMetrics::new(["momentum".to_string()]);
+
+        metrics::with_local_recorder(&recorder, || {
+            for tag in [Some("momentum"), Some("ad-hoc-1"), Some("ad-hoc-2"), None] {
+                let order = order(tag);
+                adapter.record_order_submitted(&order);
+                adapter.record_fill(&order, &fill(100.05));
+            }
+        });
+
+        let rendered = handle.render();
+        for series in [
+            r#"execution_engine_orders_submitted_total{strategy="momentum",side="buy"} 1"#,
+            r#"execution_engine_orders_submitted_total{strategy="other",side="buy"} 2"#,
+            r#"execution_engine_orders_submitted_total{strategy="none",side="buy"} 1"#,
+            r#"execution_engine_fills_total{strategy="other",side="buy"} 2"#,
+            r#"execution_engine_fill_slippage_bps_count{strategy="momentum",side="buy"} 1"#,
+            r#"execution_engine_fill_slippage_bps_sum{strategy="momentum",side="buy"} 5"#,
+        ] {
+            assert!(
+                rendered.contains(series),
+                "missing {series} in:\n{rendered}"
+            );
+        }
+        assert!(!rendered.contains("ad-hoc"));
+    }
+}
@@ -9,20 +9,43 @@
 //!   - `price_feed/`: Market data adapters
 //!   - `messaging/`: Event publishing adapters
 //!   - `websocket/`: Real-time market data WebSocket streams
+//!   - `chaos/`: Fault-injecting broker and price feed wrappers (PAPER only)
+//!   - `export/`: CSV and Parquet trade blotter files
+//!   - `ha/`: Postgres leader lock and leader-gated broker wrapper
+//!   - `time_sync/`: SNTP time source for clock skew checks
 //!
 //! - **Driver Adapters (Inbound)**: Expose application to external world
 //!   - `http/`: REST API controllers
 //!   - `grpc/`: gRPC service implementations
 //!
+//! - **Cross-cutting**: Shared by adapters
+//!   - `api_error`: Error codes and their HTTP and gRPC status mappings
+//!   - `auth/`: API-key authentication and role-based authorization
+//!   - `decimal`: `f64` conversions where wire formats require floats
+//!   - `secrets/`: Vault/AWS Secrets Manager credentials and rotation
+//!   - `logging/`: Rotating application and audit log files
+//!   - `metrics/`: Prometheus execution metrics
+//!
 //! - **Resilience**: Cross-cutting infrastructure concerns
 //!   - `resilience/`: Retry policies, circuit breakers, rate limiters
 
+pub mod api_error;
+pub mod auth;
 pub mod broker;
+pub mod chaos;
 pub mod config;
+pub mod decimal;
+pub mod export;
 pub mod grpc;
+pub mod ha;
 pub mod http;
+pub mod logging;
 pub mod marketdata;
+pub mod messaging;
+pub mod metrics;
 pub mod persistence;
 pub mod price_feed;
+pub mod secrets;
 pub mod stream_proxy;
+pub mod time_sync;
 pub mod websocket;
@@ -0,0 +1,171 @@
+//! Durable whole-file writes for the file-backed stores.
+//!
+//! A document is written to a temporary file beside its target, synced to
+//! disk, then renamed over the target, and the directory is synced so the
+//! rename itself survives a crash. Readers see either the old or the new
+//! document, never a partial one.
+
+use std::path::{Path, PathBuf};
+
+use serde::Serialize;
+use tokio::io::AsyncWriteExt;
+
+/// Who may read a written file.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum FileAccess {
+    /// Permissions from the process umask.
+    #[default]
+    Default,
+    /// Readable and writable by the owner only (mode 0600 on Unix), for
+    /// credentials.
+    OwnerOnly,
+}
+
+/// A file operation that failed.
+#[derive(Debug, thiserror::Error)]
+#[error("{}: {source}", path.display())]
+pub struct FileError {
+    /// File or directory the failing step touched.
+    pub path: PathBuf,
+    /// Underlying error.
+    #[source]
+    pub source: std::io::Error,
+}
+
+impl FileError {
+    pub(super) fn new(path: &Path, source: impl Into<std::io::Error>) -> Self {
+        Self {
+            path: path.to_path_buf(),
+            source: source.into(),
+        }
+    }
+}
+
+/// Write `value` to `path` as pretty JSON, replacing any previous file
+/// atomically and durably. Missing parent directories are created.
+///
+/// # Errors
+///
+/// Returns error if `value` cannot be serialized or any file step fails.
+pub async fn write_json_atomic<T: Serialize + Sync + ?Sized>(
+    path: &Path,
+    value: &T,
+    access: FileAccess,
+) -> Result<(), FileError> {
+    let bytes = serde_json::to_vec_pretty(value).map_err(|e| FileError::new(path, e))?;
+    write_atomic(path, &bytes, access).await
+}
+
+/// Write `bytes` to `path`, replacing any previous file atomically and
+/// durably. Missing parent directories are created.
+///
+/// # Errors
+///
+/// Returns error if any file step fails.
+pub async fn write_atomic(path: &Path, bytes: &[u8], access: FileAccess) -> Result<(), FileError> {
+    let parent = path.parent().filter(|p| !p.as_os_str().is_empty());
+    if let Some(parent) = parent {
+        tokio::fs::create_dir_all(parent)
+            .await
+            .map_err(|e| FileError::new(parent, e))?;
+    }
+
+    let mut tmp_name = path.as_os_str().to_owned();
+    tmp_name.push(".tmp");
+    let tmp_path = PathBuf::from(tmp_name);
+    write_synced(&tmp_path, bytes, access)
+        .await
+        .map_err(|e| FileError::new(&tmp_path, e))?;
+    tokio::fs::rename(&tmp_path, path)
+        .await
+        .map_err(|e| FileError::new(path, e))?;
+
+    sync_dir(parent.unwrap_or_else(|| Path::new(".")))
+        .await
+        .map_err(|e| FileError::new(parent.unwrap_or(path), e))
+}
+
+/// Create or truncate `path`, write `bytes` and sync them to disk.
+async fn write_synced(path: &Path, bytes: &[u8], access: FileAccess) -> std::io::Result<()> {
+    let mut options = tokio::fs::OpenOptions::new();
+    options.write(true).create(true).truncate(true);
+    #[cfg(unix)]
+    if access == FileAccess::OwnerOnly {
+        options.mode(0o600);
+    }
+    let mut file = options.open(path).await?;
+
+    // A leftover temporary file keeps its old mode
+    #[cfg(unix)]
+    if access == FileAccess::OwnerOnly {
+        use std::os::unix::fs::PermissionsExt;
+        file.set_permissions(std::fs::Permissions::from_mode(0o600))
+            .await?;
+    }
+    #[cfg(not(unix))]
+    let _ = access;
+
+    file.write_all(bytes).await?;
+    file.sync_all().await
+}
+
+/// Sync a directory so renames and new files in it are durable.
+///
+/// Directories cannot be opened for syncing on Windows, where renames are
+/// durable once they return.
+///
+/// # Errors
+///
+/// Returns error if the directory cannot be opened or synced.
+pub async fn sync_dir(dir: &Path) -> std::io::Result<()> {
+    #[cfg(unix)]
+    tokio::fs::File::open(dir).await?.sync_all().await?;
+    #[cfg(not(unix))]
+    let _ = dir;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn replaces_the_file_and_leaves_no_temporary() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("nested").join("state.json");
+
+        write_json_atomic(&path, &vec![1, 2], FileAccess::Default)
+            .await
+            .unwrap();
+        write_json_atomic(&path, &vec![3], FileAccess::Default)
+            .await
+            .unwrap();
+
+        let saved: Vec<i32> = serde_json::from_slice(&std::fs::read(&path).unwrap()).unwrap();
+        assert_eq!(saved, vec![3]);
+        assert!(!path.with_extension("json.tmp").exists());
+    }
+
+    #[cfg(unix)]
+    #[tokio::test]
+    async fn owner_only_files_are_private() {
+        use std::os::unix::fs::PermissionsExt;
+
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("token.json");
+        // A temporary file left by a crash with wider permissions
+        std::fs::write(path.with_extension("json.tmp"), b"{}").unwrap();
+        std::fs::set_permissions(
+            path.with_extension("json.tmp"),
+            std::fs::Permissions::from_mode(0o644),
+        )
+        .unwrap();
+
+        write_json_atomic(&path, "secret", FileAccess::OwnerOnly)
+            .await
+            .unwrap();
+
+        let mode = std::fs::metadata(&path).unwrap().permissions().mode();
+        assert_eq!(mode & 0o777, 0o600);
+    }
+}
@@ -0,0 +1,405 @@
+//! Crash snapshot file.
+//!
+//! When the engine exits on a fatal error, the in-memory order store and the
+//! context leading up to the failure would go with the process. A crash
+//! snapshot records them in one JSON document: the failure class and error,
+//! the working orders, the open position lifecycles and the most recent
+//! audit events. On the next start the working orders are restored into the
+//! order store ahead of startup reconciliation, which settles them against
+//! the broker, and the file is archived so it is applied only once.
+
+use std::fmt;
+use std::path::{Path, PathBuf};
+
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+
+use super::atomic_file::{FileAccess, write_json_atomic};
+use crate::application::ports::PositionLifecycle;
+use crate::application::services::PositionLifecycleTracker;
+use crate::domain::order_execution::aggregate::Order;
+use crate::domain::order_execution::repository::OrderRepository;
+use crate::domain::shared::{ClassifiedError, ErrorKind, Timestamp};
+use crate::infrastructure::logging::AuditEvent;
+
+/// Snapshot format version written by this engine.
+pub const CRASH_SNAPSHOT_VERSION: u32 = 1;
+
+/// Class of a fatal error, deciding the process exit code.
+///
+/// Codes follow `sysexits.h` so supervisors can tell a broken deployment,
+/// which will fail again on restart, from a transient failure.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum FailureClass {
+    /// Invalid configuration (exit code 78, `EX_CONFIG`).
+    Config,
+    /// The broker rejected the credentials (exit code 77, `EX_NOPERM`).
+    BrokerAuth,
+    /// Local state could not be recovered or reconciled with the broker
+    /// (exit code 75, `EX_TEMPFAIL`).
+    Recovery,
+    /// Any other fatal error (exit code 1).
+    Other,
+}
+
+impl FailureClass {
+    /// Process exit code.
+    #[must_use]
+    pub const fn exit_code(self) -> u8 {
+        match self {
+            Self::Config => 78,
+            Self::BrokerAuth => 77,
+            Self::Recovery => 75,
+            Self::Other => 1,
+        }
+    }
+
+    /// Machine-readable name.
+    #[must_use]
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::Config => "CONFIG",
+            Self::BrokerAuth => "BROKER_AUTH",
+            Self::Recovery => "RECOVERY",
+            Self::Other => "OTHER",
+        }
+    }
+}
+
+impl fmt::Display for FailureClass {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// Engine state at the time of a fatal error.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CrashSnapshot {
+    /// Snapshot format version.
+    pub version: u32,
+    /// When the snapshot was taken.
+    pub written_at: Timestamp,
+    /// Class of the failure.
+    pub failure: FailureClass,
+    /// Exit code the process stopped with.
+    pub exit_code: u8,
+    /// The fatal error.
+    pub error: String,
+    /// Working orders.
+    pub open_orders: Vec<Order>,
+    /// Open position lifecycles.
+    pub positions: Vec<PositionLifecycle>,
+    /// Most recent audit events, oldest first.
+    pub audit_events: Vec<AuditEvent>,
+}
+
+impl CrashSnapshot {
+    /// Snapshot of a `failure` without engine state.
+    #[must_use]
+    pub fn new(
+        failure: FailureClass,
+        error: impl Into<String>,
+        audit_events: Vec<AuditEvent>,
+    ) -> Self {
+        Self {
+            version: CRASH_SNAPSHOT_VERSION,
+            written_at: Timestamp::now(),
+            failure,
+            exit_code: failure.exit_code(),
+            error: error.into(),
+            open_orders: Vec::new(),
+            positions: Vec::new(),
+            audit_events,
+        }
+    }
+
+    /// Add the working orders and open positions.
+    ///
+    /// The snapshot is taken on the way out, so a store that cannot be read
+    /// is logged and left out rather than failing the snapshot.
+    pub async fn capture_state<O: OrderRepository>(
+        &mut self,
+        order_repo: &O,
+        lifecycle: &PositionLifecycleTracker,
+    ) {
+        match order_repo.find_active().await {
+            Ok(orders) => self.open_orders = orders,
+            Err(e) => tracing::error!(error = %e, "Failed to read orders for crash snapshot"),
+        }
+        match lifecycle.open_positions().await {
+            Ok(positions) => self.positions = positions,
+            Err(e) => tracing::error!(error = %e, "Failed to read positions for crash snapshot"),
+        }
+    }
+}
+
+/// What was restored from a crash snapshot.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CrashRecovery {
+    /// Class of the failure the snapshot recorded.
+    pub failure: FailureClass,
+    /// The fatal error.
+    pub error: String,
+    /// When the snapshot was taken.
+    pub written_at: Timestamp,
+    /// Working orders restored into the order store.
+    pub orders_restored: usize,
+    /// Where the snapshot was moved after recovery.
+    pub archived_to: PathBuf,
+}
+
+/// Crash snapshot errors.
+#[derive(Debug, Error)]
+pub enum CrashSnapshotError {
+    /// The snapshot could not be read, written or archived.
+    #[error("Crash snapshot I/O error on {path}: {message}")]
+    Io {
+        /// File path.
+        path: String,
+        /// Error details.
+        message: String,
+    },
+
+    /// The snapshot does not parse.
+    #[error("Invalid crash snapshot: {message}")]
+    Malformed {
+        /// Error details.
+        message: String,
+    },
+
+    /// The snapshot was written by an incompatible engine.
+    #[error("Unsupported crash snapshot version {version}")]
+    UnsupportedVersion {
+        /// Version found in the snapshot.
+        version: u32,
+    },
+
+    /// Restored orders could not be saved.
+    #[error("Order store error: {message}")]
+    Storage {
+        /// Error details.
+        message: String,
+    },
+}
+
+impl ClassifiedError for CrashSnapshotError {
+    fn code(&self) -> &str {
+        match self {
+            Self::Io { .. } => "CRASH_SNAPSHOT_IO",
+            Self::Malformed { .. } => "INVALID_CRASH_SNAPSHOT",
+            Self::UnsupportedVersion { .. } => "UNSUPPORTED_VERSION",
+            Self::Storage { .. } => "STATE_STORE_ERROR",
+        }
+    }
+
+    fn kind(&self) -> ErrorKind {
+        match self {
+            Self::Malformed { .. } | Self::UnsupportedVersion { .. } => ErrorKind::InvalidArgument,
+            Self::Io { .. } | Self::Storage { .. } => ErrorKind::Internal,
+        }
+    }
+}
+
+/// Crash snapshot file at a fixed path.
+#[derive(Debug, Clone)]
+pub struct CrashSnapshotStore {
+    path: PathBuf,
+}
+
+impl CrashSnapshotStore {
+    /// Store writing to `path`.
+    #[must_use]
+    pub fn new(path: impl Into<PathBuf>) -> Self {
+        Self { path: path.into() }
+    }
+
+    /// Path of the snapshot file.
+    #[must_use]
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// Where a recovered snapshot is moved.
+    #[must_use]
+    pub fn archive_path(&self) -> PathBuf {
+        self.path.with_extension("recovered.json")
+    }
+
+    /// Write `snapshot`, replacing any previous one atomically.
+    ///
+    /// # Errors
+    ///
+    /// Returns error if the file cannot be written.
+    pub async fn write(&self, snapshot: &CrashSnapshot) -> Result<(), CrashSnapshotError> {
+        write_json_atomic(&self.path, snapshot, FileAccess::Default)
+            .await
+            .map_err(|e| io_error(&e.path, &e.source))
+    }
+
+    /// Restore the working orders of a pending snapshot into `order_repo`
+    /// and archive the snapshot.
+    ///
+    /// Orders already in the store are left alone. Returns `None` when no
+    /// snapshot is pending.
+    ///
+    /// # Errors
+    ///
+    /// Returns error if the snapshot cannot be read or parsed, or the orders
+    /// cannot be saved. The snapshot is kept in that case.
+    pub async fn recover<O: OrderRepository>(
+        &self,
+        order_repo: &O,
+    ) -> Result<Option<CrashRecovery>, CrashSnapshotError> {
+        let bytes = match tokio::fs::read(&self.path).await {
+            Ok(bytes) => bytes,
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(None),
+            Err(e) => return Err(io_error(&self.path, &e)),
+        };
+        let version = serde_json::from_slice::<serde_json::Value>(&bytes)
+            .ok()
+            .and_then(|value| value.get("version")?.as_u64())
+            .and_then(|version| u32::try_from(version).ok());
+        if let Some(version) = version.filter(|v| *v != CRASH_SNAPSHOT_VERSION) {
+            return Err(CrashSnapshotError::UnsupportedVersion { version });
+        }
+        let snapshot: CrashSnapshot =
+            serde_json::from_slice(&bytes).map_err(|e| CrashSnapshotError::Malformed {
+                message: e.to_string(),
+            })?;
+
+        let mut orders_restored = 0;
+        for order in &snapshot.open_orders {
+            let exists = order_repo
+                .exists(order.id())
+                .await
+                .map_err(|e| storage_error(&e))?;
+            if !exists {
+                order_repo
+                    .save(order)
+                    .await
+                    .map_err(|e| storage_error(&e))?;
+                orders_restored += 1;
+            }
+        }
+
+        let archived_to = self.archive_path();
+        tokio::fs::rename(&self.path, &archived_to)
+            .await
+            .map_err(|e| io_error(&self.path, &e))?;
+
+        Ok(Some(CrashRecovery {
+            failure: snapshot.failure,
+            error: snapshot.error,
+            written_at: snapshot.written_at,
+            orders_restored,
+            archived_to,
+        }))
+    }
+}
+
+fn io_error(path: &Path, error: &std::io::Error) -> CrashSnapshotError {
+    CrashSnapshotError::Io {
+        path: path.display().to_string(),
+        message: error.to_string(),
+    }
+}
+
+fn storage_error(error: &dyn fmt::Display) -> CrashSnapshotError {
+    CrashSnapshotError::Storage {
+        message: error.to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeMap;
+    use std::sync::Arc;
+
+    use rust_decimal_macros::dec;
+
+    use super::*;
+    use crate::application::ports::InMemoryPositionLifecycleRepository;
+    use crate::domain::order_execution::aggregate::CreateOrderCommand;
+    use crate::domain::order_execution::value_objects::{
+        OrderPurpose, OrderSide, OrderType, TimeInForce,
+    };
+    use crate::domain::shared::{BrokerId, Money, Quantity, Symbol};
+    use crate::infrastructure::persistence::InMemoryOrderRepository;
+
+    fn order(symbol: &str, broker_id: &str) -> Order {
+        let mut order = Order::new(CreateOrderCommand {
+            symbol: Symbol::new(symbol),
+            side: OrderSide::Buy,
+            order_type: OrderType::Limit,
+            quantity: Quantity::from_i64(100),
+            limit_price: Some(Money::new(dec!(100))),
+            stop_price: None,
+            time_in_force: TimeInForce::Gtc,
+            purpose: OrderPurpose::Entry,
+            legs: vec![],
+        })
+        .unwrap();
+        order.accept(BrokerId::new(broker_id)).unwrap();
+        order
+    }
+
+    #[tokio::test]
+    async fn snapshot_orders_are_restored_once() {
+        let dir = tempfile::tempdir().unwrap();
+        let store = CrashSnapshotStore::new(dir.path().join("data").join("crash_snapshot.json"));
+
+        // The crashed instance was working two orders
+        let crashed_repo = InMemoryOrderRepository::new();
+        let aapl = order("AAPL", "broker-1");
+        let msft = order("MSFT", "broker-2");
+        crashed_repo.save(&aapl).await.unwrap();
+        crashed_repo.save(&msft).await.unwrap();
+        let lifecycle =
+            PositionLifecycleTracker::new(Arc::new(InMemoryPositionLifecycleRepository::new()));
+
+        let event = AuditEvent {
+            timestamp: Timestamp::now(),
+            level: "ERROR".to_string(),
+            message: "Broker authentication failed".to_string(),
+            fields: BTreeMap::new(),
+        };
+        let mut snapshot = CrashSnapshot::new(FailureClass::BrokerAuth, "401", vec![event]);
+        snapshot.capture_state(&crashed_repo, &lifecycle).await;
+        assert_eq!(snapshot.exit_code, 77);
+        assert_eq!(snapshot.open_orders.len(), 2);
+        store.write(&snapshot).await.unwrap();
+
+        // The restarted instance already knows one of them
+        let order_repo = InMemoryOrderRepository::new();
+        order_repo.save(&aapl).await.unwrap();
+        let recovery = store.recover(&order_repo).await.unwrap().unwrap();
+        assert_eq!(recovery.failure, FailureClass::BrokerAuth);
+        assert_eq!(recovery.orders_restored, 1);
+        assert!(order_repo.exists(msft.id()).await.unwrap());
+        assert!(recovery.archived_to.exists());
+        assert!(!store.path().exists());
+
+        assert!(store.recover(&order_repo).await.unwrap().is_none());
+    }
+
+    #[tokio::test]
+    async fn unreadable_snapshot_is_kept() {
+        let dir = tempfile::tempdir().unwrap();
+        let store = CrashSnapshotStore::new(dir.path().join("crash_snapshot.json"));
+        let order_repo = InMemoryOrderRepository::new();
+
+        std::fs::write(store.path(), b"{\"version\": 9}").unwrap();
+        assert!(matches!(
+            store.recover(&order_repo).await,
+            Err(CrashSnapshotError::UnsupportedVersion { version: 9 })
+        ));
+
+        std::fs::write(store.path(), b"not json").unwrap();
+        assert!(matches!(
+            store.recover(&order_repo).await,
+            Err(CrashSnapshotError::Malformed { .. })
+        ));
+        assert!(store.path().exists());
+    }
+}
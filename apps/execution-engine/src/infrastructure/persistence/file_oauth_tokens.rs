@@ -0,0 +1,153 @@
+//! File-backed OAuth token repository.
+//!
+//! Stores each user's token in its own JSON file, `<user_id>.json`, under a
+//! directory, so an engine trading for one user and an engine connecting
+//! accounts can share the directory without rewriting each other's tokens.
+//! Files are written atomically and durably (see [`write_json_atomic`]) and
+//! are readable by the owner only on Unix. Tokens are read from disk on every
+//! lookup, so refreshes and new connections made by another process are
+//! picked up.
+
+use std::path::{Path, PathBuf};
+
+use async_trait::async_trait;
+
+use super::atomic_file::{FileAccess, write_json_atomic};
+use crate::application::ports::{OAuthError, OAuthToken, OAuthTokenRepositoryPort};
+
+/// Extension of token files.
+const TOKEN_EXTENSION: &str = "json";
+
+/// Directory of per-user token files implementing `OAuthTokenRepositoryPort`.
+#[derive(Debug)]
+pub struct FileOAuthTokenRepository {
+    directory: PathBuf,
+}
+
+impl FileOAuthTokenRepository {
+    /// Store tokens under `directory`, created on first write.
+    #[must_use]
+    pub fn new(directory: impl Into<PathBuf>) -> Self {
+        Self {
+            directory: directory.into(),
+        }
+    }
+
+    /// Directory of the token files.
+    #[must_use]
+    pub fn directory(&self) -> &Path {
+        &self.directory
+    }
+
+    fn path(&self, user_id: &str) -> PathBuf {
+        self.directory.join(format!("{user_id}.{TOKEN_EXTENSION}"))
+    }
+}
+
+#[async_trait]
+impl OAuthTokenRepositoryPort for FileOAuthTokenRepository {
+    async fn save(&self, user_id: &str, token: &OAuthToken) -> Result<(), OAuthError> {
+        write_json_atomic(&self.path(user_id), token, FileAccess::OwnerOnly)
+            .await
+            .map_err(|e| storage_error(&e.path, &e.source))
+    }
+
+    async fn find(&self, user_id: &str) -> Result<Option<OAuthToken>, OAuthError> {
+        let path = self.path(user_id);
+        match tokio::fs::read(&path).await {
+            Ok(bytes) => serde_json::from_slice(&bytes)
+                .map(Some)
+                .map_err(|e| storage_error(&path, &e)),
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(None),
+            Err(e) => Err(storage_error(&path, &e)),
+        }
+    }
+
+    async fn users(&self) -> Result<Vec<String>, OAuthError> {
+        let mut entries = match tokio::fs::read_dir(&self.directory).await {
+            Ok(entries) => entries,
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(Vec::new()),
+            Err(e) => return Err(storage_error(&self.directory, &e)),
+        };
+
+        let mut users = Vec::new();
+        while let Some(entry) = entries
+            .next_entry()
+            .await
+            .map_err(|e| storage_error(&self.directory, &e))?
+        {
+            let path = entry.path();
+            if path.extension().and_then(|e| e.to_str()) == Some(TOKEN_EXTENSION)
+                && let Some(user_id) = path.file_stem().and_then(|s| s.to_str())
+            {
+                users.push(user_id.to_string());
+            }
+        }
+        users.sort();
+        Ok(users)
+    }
+
+    async fn delete(&self, user_id: &str) -> Result<(), OAuthError> {
+        let path = self.path(user_id);
+        match tokio::fs::remove_file(&path).await {
+            Ok(()) => Ok(()),
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(()),
+            Err(e) => Err(storage_error(&path, &e)),
+        }
+    }
+}
+
+fn storage_error(path: &Path, error: &dyn std::fmt::Display) -> OAuthError {
+    OAuthError::StorageError {
+        message: format!("{}: {error}", path.display()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::shared::Timestamp;
+
+    fn token(access_token: &str) -> OAuthToken {
+        OAuthToken {
+            access_token: access_token.to_string(),
+            refresh_token: None,
+            scope: "account:write trading".to_string(),
+            obtained_at: Timestamp::parse("2026-03-02T15:00:00Z").unwrap(),
+            expires_at: None,
+        }
+    }
+
+    #[tokio::test]
+    async fn tokens_are_stored_per_user() {
+        let dir = tempfile::tempdir().unwrap();
+        let repo = FileOAuthTokenRepository::new(dir.path().join("oauth"));
+        assert!(repo.users().await.unwrap().is_empty());
+
+        repo.save("bob", &token("b-1")).await.unwrap();
+        repo.save("alice", &token("a-1")).await.unwrap();
+        repo.save("alice", &token("a-2")).await.unwrap();
+        assert_eq!(repo.users().await.unwrap(), vec!["alice", "bob"]);
+
+        // Another process sees the same tokens
+        let other = FileOAuthTokenRepository::new(dir.path().join("oauth"));
+        assert_eq!(
+            other.find("alice").await.unwrap().unwrap().access_token,
+            "a-2"
+        );
+
+        repo.delete("bob").await.unwrap();
+        repo.delete("bob").await.unwrap();
+        assert!(other.find("bob").await.unwrap().is_none());
+
+        #[cfg(unix)]
+        {
+            use std::os::unix::fs::PermissionsExt;
+            let mode = std::fs::metadata(dir.path().join("oauth").join("alice.json"))
+                .unwrap()
+                .permissions()
+                .mode();
+            assert_eq!(mode & 0o777, 0o600);
+        }
+    }
+}
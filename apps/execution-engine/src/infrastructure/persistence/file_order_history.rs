@@ -0,0 +1,75 @@
+//! File-backed order history.
+//!
+//! Appends each order that reaches a terminal status, with its fills, to an
+//! append-only journal (see [`JsonLines`]) so order history queries cover
+//! orders finished before a restart. An order saved again after it finished
+//! (a late fill correction) is appended again; the last record wins.
+
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+
+use tokio::sync::Mutex;
+
+use super::json_lines::JsonLines;
+use crate::domain::order_execution::aggregate::Order;
+use crate::domain::order_execution::errors::OrderError;
+
+/// Journal of finished orders.
+#[derive(Debug)]
+pub struct FileOrderHistory {
+    path: PathBuf,
+    lines: Mutex<JsonLines>,
+}
+
+impl FileOrderHistory {
+    /// Open the history at `path`, returning the orders it records.
+    ///
+    /// A missing file is treated as an empty history. A partially written
+    /// last record is dropped.
+    ///
+    /// # Errors
+    ///
+    /// Returns `OrderError::StorageError` if the file exists but cannot be
+    /// read or truncated, or a record before the last one does not parse.
+    pub fn open(path: impl Into<PathBuf>) -> Result<(Self, Vec<Order>), OrderError> {
+        let path = path.into();
+        let (lines, records) = JsonLines::open::<Order>(path.clone())
+            .map_err(|e| storage_error(&e.path, &e.source))?;
+
+        let mut latest = HashMap::new();
+        for order in records {
+            latest.insert(order.id().to_string(), order);
+        }
+        let history = Self {
+            path,
+            lines: Mutex::new(lines),
+        };
+        Ok((history, latest.into_values().collect()))
+    }
+
+    /// Path of the backing file.
+    #[must_use]
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// Append `order` and sync it to disk.
+    ///
+    /// # Errors
+    ///
+    /// Returns `OrderError::StorageError` if the write fails.
+    pub async fn record(&self, order: &Order) -> Result<(), OrderError> {
+        self.lines
+            .lock()
+            .await
+            .append(std::slice::from_ref(order))
+            .await
+            .map_err(|e| storage_error(&e.path, &e.source))
+    }
+}
+
+fn storage_error(path: &Path, error: &dyn std::fmt::Display) -> OrderError {
+    OrderError::StorageError {
+        message: format!("{}: {error}", path.display()),
+    }
+}
@@ -0,0 +1,367 @@
+//! File-backed event outbox.
+//!
+//! Stores undelivered order events in an append-only journal so they survive
+//! an event sink outage that outlasts the engine. Each mutation appends one
+//! JSON line per change (an entry enqueued, delivered or retried) and syncs
+//! it to disk, undoing the change in memory if the write fails, so the
+//! events of a state change are durable by the time the use case that saved
+//! it returns.
+//!
+//! Opening the outbox replays the journal (see [`JsonLines`]). Once most
+//! records describe entries already delivered, the journal is compacted:
+//! rewritten with one record per undelivered entry.
+
+use std::path::{Path, PathBuf};
+
+use async_trait::async_trait;
+use serde::{Deserialize, Serialize};
+use tokio::sync::Mutex;
+
+use super::json_lines::JsonLines;
+use crate::application::ports::{OutboxEntry, OutboxError, OutboxQueue, OutboxRepositoryPort};
+use crate::domain::order_execution::events::OrderEvent;
+use crate::domain::shared::Timestamp;
+
+/// Journal records written before the journal is compacted, unless more
+/// entries than half of them are still undelivered.
+pub const DEFAULT_COMPACT_AFTER: usize = 1000;
+
+/// One line of the journal.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "op", rename_all = "snake_case")]
+enum JournalRecord {
+    /// An entry was added, or kept by compaction.
+    Enqueue { entry: OutboxEntry },
+    /// The entry was delivered.
+    Ack { id: u64 },
+    /// A delivery attempt failed.
+    Retry {
+        id: u64,
+        error: String,
+        next_attempt_at: Timestamp,
+    },
+}
+
+impl JournalRecord {
+    fn apply(self, queue: &mut OutboxQueue) {
+        match self {
+            Self::Enqueue { entry } => queue.restore(entry),
+            Self::Ack { id } => {
+                queue.remove(id);
+            }
+            Self::Retry {
+                id,
+                error,
+                next_attempt_at,
+            } => {
+                queue.record_failure(id, &error, next_attempt_at);
+            }
+        }
+    }
+}
+
+/// Journal state: the replayed queue and the file behind it.
+#[derive(Debug)]
+struct Journal {
+    queue: OutboxQueue,
+    lines: JsonLines,
+}
+
+/// Journal file implementation of `OutboxRepositoryPort`.
+#[derive(Debug)]
+pub struct FileOutboxRepository {
+    path: PathBuf,
+    compact_after: usize,
+    journal: Mutex<Journal>,
+}
+
+impl FileOutboxRepository {
+    /// Open the outbox at `path`, replaying its journal.
+    ///
+    /// A missing file is treated as an empty outbox. A partially written
+    /// last record is dropped.
+    ///
+    /// # Errors
+    ///
+    /// Returns `OutboxError::StorageError` if the file exists but cannot be
+    /// read or truncated, or a record before the last one does not parse.
+    pub fn open(path: impl Into<PathBuf>) -> Result<Self, OutboxError> {
+        let path = path.into();
+        let (lines, records) = JsonLines::open::<JournalRecord>(path.clone())
+            .map_err(|e| storage_error(&e.path, &e.source))?;
+
+        let mut queue = OutboxQueue::default();
+        for record in records {
+            record.apply(&mut queue);
+        }
+        let queue = OutboxQueue::from_entries(queue.entries().to_vec());
+
+        Ok(Self {
+            path,
+            compact_after: DEFAULT_COMPACT_AFTER,
+            journal: Mutex::new(Journal { queue, lines }),
+        })
+    }
+
+    /// Compact the journal after `records` records instead of
+    /// [`DEFAULT_COMPACT_AFTER`].
+    #[must_use]
+    pub fn with_compact_after(mut self, records: usize) -> Self {
+        self.compact_after = records.max(1);
+        self
+    }
+
+    /// Path of the backing file.
+    #[must_use]
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// Append `records` and sync them to disk, compacting the journal when
+    /// it has grown past the threshold.
+    async fn write(
+        &self,
+        journal: &mut Journal,
+        records: &[JournalRecord],
+    ) -> Result<(), OutboxError> {
+        journal
+            .lines
+            .append(records)
+            .await
+            .map_err(|e| storage_error(&e.path, &e.source))?;
+
+        let live = journal.queue.entries().len();
+        if journal.lines.records() >= self.compact_after.max(live.saturating_mul(2)) {
+            let records: Vec<JournalRecord> = journal
+                .queue
+                .entries()
+                .iter()
+                .map(|entry| JournalRecord::Enqueue {
+                    entry: entry.clone(),
+                })
+                .collect();
+            if let Err(e) = journal.lines.rewrite(&records).await {
+                // The journal is intact, only longer than it needs to be
+                tracing::warn!(error = %e, "Event outbox compaction failed");
+            }
+        }
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl OutboxRepositoryPort for FileOutboxRepository {
+    async fn append(&self, events: Vec<OrderEvent>, now: Timestamp) -> Result<(), OutboxError> {
+        if events.is_empty() {
+            return Ok(());
+        }
+        let mut journal = self.journal.lock().await;
+        let first_id = journal.queue.next_id();
+        journal.queue.append(events, now);
+        let records: Vec<JournalRecord> = journal
+            .queue
+            .entries()
+            .iter()
+            .filter(|entry| entry.id >= first_id)
+            .map(|entry| JournalRecord::Enqueue {
+                entry: entry.clone(),
+            })
+            .collect();
+
+        if let Err(e) = self.write(&mut journal, &records).await {
+            // Keep memory consistent with disk
+            journal.queue.truncate_from(first_id);
+            return Err(e);
+        }
+        drop(journal);
+        Ok(())
+    }
+
+    async fn pending(&self, limit: usize) -> Result<Vec<OutboxEntry>, OutboxError> {
+        Ok(self
+            .journal
+            .lock()
+            .await
+            .queue
+            .entries()
+            .iter()
+            .take(limit)
+            .cloned()
+            .collect())
+    }
+
+    async fn mark_delivered(&self, id: u64) -> Result<(), OutboxError> {
+        let mut journal = self.journal.lock().await;
+        let Some(removed) = journal.queue.remove(id) else {
+            return Ok(());
+        };
+
+        if let Err(e) = self.write(&mut journal, &[JournalRecord::Ack { id }]).await {
+            journal.queue.restore(removed);
+            return Err(e);
+        }
+        drop(journal);
+        Ok(())
+    }
+
+    async fn record_failure(
+        &self,
+        id: u64,
+        error: &str,
+        next_attempt_at: Timestamp,
+    ) -> Result<(), OutboxError> {
+        let mut journal = self.journal.lock().await;
+        let Some(previous) = journal.queue.record_failure(id, error, next_attempt_at) else {
+            return Ok(());
+        };
+
+        let record = JournalRecord::Retry {
+            id,
+            error: error.to_string(),
+            next_attempt_at,
+        };
+        if let Err(e) = self.write(&mut journal, &[record]).await {
+            journal.queue.restore(previous);
+            return Err(e);
+        }
+        drop(journal);
+        Ok(())
+    }
+
+    async fn depth(&self) -> Result<usize, OutboxError> {
+        Ok(self.journal.lock().await.queue.entries().len())
+    }
+}
+
+fn storage_error(path: &Path, error: &dyn std::fmt::Display) -> OutboxError {
+    OutboxError::StorageError {
+        message: format!("{}: {error}", path.display()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::order_execution::events::OrderSubmitted;
+    use crate::domain::order_execution::value_objects::OrderSide;
+    use crate::domain::shared::{OrderId, Quantity, Symbol};
+
+    fn submitted(order_id: &str) -> OrderEvent {
+        OrderEvent::Submitted(OrderSubmitted {
+            order_id: OrderId::new(order_id),
+            symbol: Symbol::new("AAPL"),
+            side: OrderSide::Buy,
+            quantity: Quantity::from_i64(100),
+            limit_price: None,
+            occurred_at: Timestamp::parse("2026-03-02T15:00:00Z").unwrap(),
+        })
+    }
+
+    async fn pending_ids(outbox: &FileOutboxRepository) -> Vec<u64> {
+        outbox
+            .pending(10)
+            .await
+            .unwrap()
+            .iter()
+            .map(|e| e.id)
+            .collect()
+    }
+
+    #[tokio::test]
+    async fn undelivered_events_survive_reopen() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("nested").join("outbox.json");
+        let now = Timestamp::parse("2026-03-02T15:00:00Z").unwrap();
+
+        let outbox = FileOutboxRepository::open(&path).unwrap();
+        outbox
+            .append(vec![submitted("o-1"), submitted("o-2")], now)
+            .await
+            .unwrap();
+        outbox.mark_delivered(1).await.unwrap();
+        outbox.record_failure(2, "sink down", now).await.unwrap();
+        drop(outbox);
+
+        let reopened = FileOutboxRepository::open(&path).unwrap();
+        let pending = reopened.pending(10).await.unwrap();
+        assert_eq!(pending.len(), 1);
+        assert_eq!(pending[0].event, submitted("o-2"));
+        assert_eq!(pending[0].attempts, 1);
+        assert_eq!(pending[0].last_error.as_deref(), Some("sink down"));
+
+        // Numbering continues after the entries already stored
+        reopened.append(vec![submitted("o-3")], now).await.unwrap();
+        assert_eq!(pending_ids(&reopened).await, vec![2, 3]);
+    }
+
+    #[tokio::test]
+    async fn backlog_survives_a_partially_written_record() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("outbox.json");
+        let now = Timestamp::parse("2026-03-02T15:00:00Z").unwrap();
+
+        let outbox = FileOutboxRepository::open(&path).unwrap();
+        outbox
+            .append(vec![submitted("o-1"), submitted("o-2")], now)
+            .await
+            .unwrap();
+        outbox.mark_delivered(1).await.unwrap();
+        drop(outbox);
+
+        // The engine died part way through the next record
+        let mut bytes = std::fs::read(&path).unwrap();
+        bytes.extend_from_slice(br#"{"op":"enqueue","entry":{"id":3,"ev"#);
+        std::fs::write(&path, &bytes).unwrap();
+
+        let reopened = FileOutboxRepository::open(&path).unwrap();
+        assert_eq!(pending_ids(&reopened).await, vec![2]);
+
+        // Later records start cleanly after the cut
+        reopened.append(vec![submitted("o-3")], now).await.unwrap();
+        drop(reopened);
+        let reopened = FileOutboxRepository::open(&path).unwrap();
+        assert_eq!(pending_ids(&reopened).await, vec![2, 3]);
+    }
+
+    #[tokio::test]
+    async fn journal_is_compacted_past_the_threshold() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("outbox.json");
+        let now = Timestamp::parse("2026-03-02T15:00:00Z").unwrap();
+
+        let outbox = FileOutboxRepository::open(&path)
+            .unwrap()
+            .with_compact_after(4);
+        outbox
+            .append(vec![submitted("o-1"), submitted("o-2")], now)
+            .await
+            .unwrap();
+        outbox.mark_delivered(1).await.unwrap();
+        assert_eq!(std::fs::read_to_string(&path).unwrap().lines().count(), 3);
+
+        // The fourth record leaves one live entry
+        outbox.record_failure(2, "sink down", now).await.unwrap();
+        let journal = std::fs::read_to_string(&path).unwrap();
+        assert_eq!(journal.lines().count(), 1);
+        assert!(journal.contains("sink down"));
+
+        // Appends after compaction go to the new file
+        outbox.append(vec![submitted("o-3")], now).await.unwrap();
+        drop(outbox);
+        let reopened = FileOutboxRepository::open(&path).unwrap();
+        let pending = reopened.pending(10).await.unwrap();
+        assert_eq!(pending.len(), 2);
+        assert_eq!(pending[0].attempts, 1);
+        assert_eq!(pending[1].event, submitted("o-3"));
+    }
+
+    #[test]
+    fn open_corrupt_file_fails() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("outbox.json");
+        std::fs::write(&path, b"not json\nnot json\n").unwrap();
+
+        let result = FileOutboxRepository::open(&path);
+        assert!(matches!(result, Err(OutboxError::StorageError { .. })));
+    }
+}
@@ -0,0 +1,278 @@
+//! File-backed position lifecycle repository.
+//!
+//! Stores lifecycles and the orders still being folded into them as a single
+//! JSON document so entry context survives engine restarts. Every mutation
+//! rewrites the file atomically and durably (see [`write_json_atomic`]).
+
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+
+use async_trait::async_trait;
+use serde::Deserialize;
+use tokio::sync::Mutex;
+
+use super::atomic_file::{FileAccess, write_json_atomic};
+use crate::application::ports::{
+    LifecycleError, PositionLifecycle, PositionLifecycleRepositoryPort, TrackedOrder,
+};
+
+/// On-disk document.
+#[derive(Debug, Deserialize)]
+struct LifecycleDocument {
+    #[serde(default)]
+    lifecycles: Vec<PositionLifecycle>,
+    #[serde(default)]
+    orders: Vec<TrackedOrder>,
+}
+
+#[derive(Debug, Default, Clone)]
+struct LifecycleState {
+    lifecycles: HashMap<String, PositionLifecycle>,
+    orders: HashMap<String, TrackedOrder>,
+}
+
+/// JSON file implementation of `PositionLifecycleRepositoryPort`.
+#[derive(Debug)]
+pub struct FilePositionLifecycleRepository {
+    path: PathBuf,
+    state: Mutex<LifecycleState>,
+}
+
+impl FilePositionLifecycleRepository {
+    /// Open the repository at `path`, loading any previously persisted
+    /// lifecycles.
+    ///
+    /// A missing file is treated as an empty store.
+    ///
+    /// # Errors
+    ///
+    /// Returns `LifecycleError::StorageError` if the file exists but cannot be
+    /// read or parsed.
+    pub fn open(path: impl Into<PathBuf>) -> Result<Self, LifecycleError> {
+        let path = path.into();
+
+        let state = match std::fs::read(&path) {
+            Ok(bytes) => {
+                let document: LifecycleDocument =
+                    serde_json::from_slice(&bytes).map_err(|e| storage_error(&path, &e))?;
+                LifecycleState {
+                    lifecycles: document
+                        .lifecycles
+                        .into_iter()
+                        .map(|l| (l.lifecycle_id.clone(), l))
+                        .collect(),
+                    orders: document
+                        .orders
+                        .into_iter()
+                        .map(|o| (o.order_id.clone(), o))
+                        .collect(),
+                }
+            }
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => LifecycleState::default(),
+            Err(e) => return Err(storage_error(&path, &e)),
+        };
+
+        Ok(Self {
+            path,
+            state: Mutex::new(state),
+        })
+    }
+
+    /// Path of the backing file.
+    #[must_use]
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// Write the current snapshot to disk.
+    async fn persist(&self, state: &LifecycleState) -> Result<(), LifecycleError> {
+        let mut lifecycles: Vec<&PositionLifecycle> = state.lifecycles.values().collect();
+        lifecycles.sort_by_key(|l| l.opened_at);
+        let mut orders: Vec<&TrackedOrder> = state.orders.values().collect();
+        orders.sort_by(|a, b| a.order_id.cmp(&b.order_id));
+        let document = serde_json::json!({
+            "lifecycles": lifecycles,
+            "orders": orders,
+        });
+        write_json_atomic(&self.path, &document, FileAccess::Default)
+            .await
+            .map_err(|e| storage_error(&e.path, &e.source))
+    }
+
+    /// Apply `update` and persist, rolling back memory if the write fails.
+    async fn mutate(&self, update: impl FnOnce(&mut LifecycleState)) -> Result<(), LifecycleError> {
+        let mut state = self.state.lock().await;
+        let previous = state.clone();
+        update(&mut state);
+
+        if let Err(e) = self.persist(&state).await {
+            // Keep memory consistent with disk
+            *state = previous;
+            return Err(e);
+        }
+        drop(state);
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl PositionLifecycleRepositoryPort for FilePositionLifecycleRepository {
+    async fn save(&self, lifecycle: &PositionLifecycle) -> Result<(), LifecycleError> {
+        self.mutate(|state| {
+            state
+                .lifecycles
+                .insert(lifecycle.lifecycle_id.clone(), lifecycle.clone());
+        })
+        .await
+    }
+
+    async fn find_open(&self, symbol: &str) -> Result<Option<PositionLifecycle>, LifecycleError> {
+        Ok(self
+            .state
+            .lock()
+            .await
+            .lifecycles
+            .values()
+            .find(|l| l.symbol == symbol && l.is_open())
+            .cloned())
+    }
+
+    async fn list(&self, symbol: Option<&str>) -> Result<Vec<PositionLifecycle>, LifecycleError> {
+        let mut matching: Vec<PositionLifecycle> = self
+            .state
+            .lock()
+            .await
+            .lifecycles
+            .values()
+            .filter(|l| symbol.is_none_or(|s| l.symbol == s))
+            .cloned()
+            .collect();
+        matching.sort_by_key(|l| l.opened_at);
+        Ok(matching)
+    }
+
+    async fn save_order(&self, order: &TrackedOrder) -> Result<(), LifecycleError> {
+        self.mutate(|state| {
+            state.orders.insert(order.order_id.clone(), order.clone());
+        })
+        .await
+    }
+
+    async fn find_order(&self, order_id: &str) -> Result<Option<TrackedOrder>, LifecycleError> {
+        Ok(self.state.lock().await.orders.get(order_id).cloned())
+    }
+
+    async fn delete_order(&self, order_id: &str) -> Result<(), LifecycleError> {
+        if !self.state.lock().await.orders.contains_key(order_id) {
+            return Ok(());
+        }
+        self.mutate(|state| {
+            state.orders.remove(order_id);
+        })
+        .await
+    }
+}
+
+fn storage_error(path: &Path, error: &dyn std::fmt::Display) -> LifecycleError {
+    LifecycleError::StorageError {
+        message: format!("{}: {error}", path.display()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::application::ports::{
+        DecisionContext, LifecycleEvent, LifecycleEventKind, ThesisState,
+    };
+    use crate::domain::order_execution::value_objects::{OrderPurpose, OrderSide};
+    use crate::domain::shared::Timestamp;
+    use rust_decimal::Decimal;
+
+    fn make_lifecycle(symbol: &str) -> PositionLifecycle {
+        PositionLifecycle::open(
+            symbol,
+            LifecycleEvent {
+                kind: LifecycleEventKind::Opened,
+                order_id: "order-1".to_string(),
+                fill_ids: vec!["fill-1".to_string()],
+                quantity: Decimal::new(100, 0),
+                price: Decimal::new(150, 0),
+                position_after: Decimal::new(100, 0),
+                purpose: OrderPurpose::Entry,
+                cycle_id: Some("cycle-1".to_string()),
+                decision: Some(DecisionContext {
+                    decision_id: None,
+                    thesis_state: Some(ThesisState::Entered),
+                    time_horizon: None,
+                    strategy_family: None,
+                    strategy_tag: None,
+                    max_holding_period: None,
+                    scale_out: Vec::new(),
+                    rationale: Some("breakout".to_string()),
+                }),
+                at: Timestamp::now(),
+            },
+        )
+    }
+
+    fn make_order(order_id: &str) -> TrackedOrder {
+        TrackedOrder {
+            order_id: order_id.to_string(),
+            symbol: "AAPL".to_string(),
+            side: OrderSide::Buy,
+            purpose: OrderPurpose::ScaleIn,
+            cycle_id: None,
+            decision: None,
+            fills_applied: 0,
+        }
+    }
+
+    #[tokio::test]
+    async fn lifecycles_and_orders_survive_reopen() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("nested").join("lifecycles.json");
+
+        let repo = FilePositionLifecycleRepository::open(&path).unwrap();
+        repo.save(&make_lifecycle("AAPL")).await.unwrap();
+        repo.save_order(&make_order("order-2")).await.unwrap();
+        drop(repo);
+
+        let reopened = FilePositionLifecycleRepository::open(&path).unwrap();
+        let open = reopened.find_open("AAPL").await.unwrap().unwrap();
+        assert_eq!(
+            open.entry()
+                .unwrap()
+                .decision
+                .as_ref()
+                .unwrap()
+                .thesis_state,
+            Some(ThesisState::Entered)
+        );
+        assert!(reopened.find_order("order-2").await.unwrap().is_some());
+    }
+
+    #[tokio::test]
+    async fn delete_order_is_persisted() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("lifecycles.json");
+
+        let repo = FilePositionLifecycleRepository::open(&path).unwrap();
+        repo.save_order(&make_order("order-1")).await.unwrap();
+        repo.delete_order("order-1").await.unwrap();
+        drop(repo);
+
+        let reopened = FilePositionLifecycleRepository::open(&path).unwrap();
+        assert!(reopened.find_order("order-1").await.unwrap().is_none());
+    }
+
+    #[test]
+    fn open_corrupt_file_fails() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("lifecycles.json");
+        std::fs::write(&path, b"not json").unwrap();
+
+        let result = FilePositionLifecycleRepository::open(&path);
+        assert!(matches!(result, Err(LifecycleError::StorageError { .. })));
+    }
+}
@@ -0,0 +1,184 @@
+//! File-backed scheduled plan repository.
+//!
+//! Stores pending plans as a single JSON document so scheduled executions
+//! survive engine restarts. Every mutation rewrites the file atomically
+//! and durably (see [`write_json_atomic`]).
+
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+
+use async_trait::async_trait;
+use tokio::sync::Mutex;
+
+use super::atomic_file::{FileAccess, write_json_atomic};
+use crate::application::ports::{ScheduleError, ScheduledPlan, ScheduledPlanRepositoryPort};
+
+/// JSON file implementation of `ScheduledPlanRepositoryPort`.
+#[derive(Debug)]
+pub struct FileScheduledPlanRepository {
+    path: PathBuf,
+    plans: Mutex<HashMap<String, ScheduledPlan>>,
+}
+
+impl FileScheduledPlanRepository {
+    /// Open the repository at `path`, loading any previously persisted plans.
+    ///
+    /// A missing file is treated as an empty store.
+    ///
+    /// # Errors
+    ///
+    /// Returns `ScheduleError::StorageError` if the file exists but cannot be
+    /// read or parsed.
+    pub fn open(path: impl Into<PathBuf>) -> Result<Self, ScheduleError> {
+        let path = path.into();
+
+        let plans = match std::fs::read(&path) {
+            Ok(bytes) => {
+                let plans: Vec<ScheduledPlan> =
+                    serde_json::from_slice(&bytes).map_err(|e| storage_error(&path, &e))?;
+                plans.into_iter().map(|p| (p.plan_id.clone(), p)).collect()
+            }
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => HashMap::new(),
+            Err(e) => return Err(storage_error(&path, &e)),
+        };
+
+        Ok(Self {
+            path,
+            plans: Mutex::new(plans),
+        })
+    }
+
+    /// Path of the backing file.
+    #[must_use]
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// Write the current snapshot to disk.
+    async fn persist(&self, plans: &HashMap<String, ScheduledPlan>) -> Result<(), ScheduleError> {
+        let mut snapshot: Vec<&ScheduledPlan> = plans.values().collect();
+        snapshot.sort_by_key(|p| p.window.execute_at);
+        write_json_atomic(&self.path, &snapshot, FileAccess::Default)
+            .await
+            .map_err(|e| storage_error(&e.path, &e.source))
+    }
+}
+
+#[async_trait]
+impl ScheduledPlanRepositoryPort for FileScheduledPlanRepository {
+    async fn save(&self, plan: &ScheduledPlan) -> Result<(), ScheduleError> {
+        let mut plans = self.plans.lock().await;
+        let previous = plans.insert(plan.plan_id.clone(), plan.clone());
+
+        if let Err(e) = self.persist(&plans).await {
+            // Keep memory consistent with disk
+            match previous {
+                Some(prev) => plans.insert(plan.plan_id.clone(), prev),
+                None => plans.remove(&plan.plan_id),
+            };
+            return Err(e);
+        }
+        drop(plans);
+        Ok(())
+    }
+
+    async fn find_by_id(&self, plan_id: &str) -> Result<Option<ScheduledPlan>, ScheduleError> {
+        Ok(self.plans.lock().await.get(plan_id).cloned())
+    }
+
+    async fn list_pending(&self) -> Result<Vec<ScheduledPlan>, ScheduleError> {
+        let mut pending: Vec<ScheduledPlan> = self.plans.lock().await.values().cloned().collect();
+        pending.sort_by_key(|p| p.window.execute_at);
+        Ok(pending)
+    }
+
+    async fn delete(&self, plan_id: &str) -> Result<(), ScheduleError> {
+        let mut plans = self.plans.lock().await;
+        let Some(removed) = plans.remove(plan_id) else {
+            return Ok(());
+        };
+
+        if let Err(e) = self.persist(&plans).await {
+            plans.insert(plan_id.to_string(), removed);
+            return Err(e);
+        }
+        drop(plans);
+        Ok(())
+    }
+}
+
+fn storage_error(path: &Path, error: &dyn std::fmt::Display) -> ScheduleError {
+    ScheduleError::StorageError {
+        message: format!("{}: {error}", path.display()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::application::dto::SubmitOrdersRequestDto;
+    use crate::application::ports::ExecutionWindow;
+    use crate::domain::shared::Timestamp;
+
+    fn make_plan(plan_id: &str) -> ScheduledPlan {
+        ScheduledPlan::new(
+            plan_id,
+            "cycle-1",
+            SubmitOrdersRequestDto {
+                orders: vec![],
+                validate_risk: true,
+                cycle_id: None,
+            },
+            ExecutionWindow::starting_at(Timestamp::parse("2026-01-05T20:50:00Z").unwrap(), 300)
+                .unwrap(),
+        )
+    }
+
+    #[tokio::test]
+    async fn open_missing_file_is_empty() {
+        let dir = tempfile::tempdir().unwrap();
+        let repo = FileScheduledPlanRepository::open(dir.path().join("plans.json")).unwrap();
+
+        assert!(repo.list_pending().await.unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn plans_survive_reopen() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("nested").join("plans.json");
+
+        let repo = FileScheduledPlanRepository::open(&path).unwrap();
+        repo.save(&make_plan("plan-1")).await.unwrap();
+        repo.save(&make_plan("plan-2")).await.unwrap();
+        drop(repo);
+
+        let reopened = FileScheduledPlanRepository::open(&path).unwrap();
+        let pending = reopened.list_pending().await.unwrap();
+        assert_eq!(pending.len(), 2);
+        assert!(reopened.find_by_id("plan-1").await.unwrap().is_some());
+    }
+
+    #[tokio::test]
+    async fn delete_is_persisted() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("plans.json");
+
+        let repo = FileScheduledPlanRepository::open(&path).unwrap();
+        repo.save(&make_plan("plan-1")).await.unwrap();
+        repo.delete("plan-1").await.unwrap();
+        drop(repo);
+
+        let reopened = FileScheduledPlanRepository::open(&path).unwrap();
+        assert!(reopened.list_pending().await.unwrap().is_empty());
+    }
+
+    #[test]
+    fn open_corrupt_file_fails() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("plans.json");
+        std::fs::write(&path, b"not json").unwrap();
+
+        let result = FileScheduledPlanRepository::open(&path);
+        assert!(matches!(result, Err(ScheduleError::StorageError { .. })));
+    }
+}
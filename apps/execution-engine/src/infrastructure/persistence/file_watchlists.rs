@@ -0,0 +1,162 @@
+//! File-backed watchlist repository.
+//!
+//! Stores watchlists as a single JSON document so they survive engine
+//! restarts. Every mutation rewrites the file atomically and durably (see
+//! [`write_json_atomic`]).
+
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+
+use async_trait::async_trait;
+use tokio::sync::Mutex;
+
+use super::atomic_file::{FileAccess, write_json_atomic};
+use crate::application::ports::{Watchlist, WatchlistError, WatchlistRepositoryPort};
+
+/// JSON file implementation of `WatchlistRepositoryPort`.
+#[derive(Debug)]
+pub struct FileWatchlistRepository {
+    path: PathBuf,
+    watchlists: Mutex<HashMap<String, Watchlist>>,
+}
+
+impl FileWatchlistRepository {
+    /// Open the repository at `path`, loading any previously persisted
+    /// watchlists.
+    ///
+    /// A missing file is treated as an empty store.
+    ///
+    /// # Errors
+    ///
+    /// Returns `WatchlistError::StorageError` if the file exists but cannot
+    /// be read or parsed.
+    pub fn open(path: impl Into<PathBuf>) -> Result<Self, WatchlistError> {
+        let path = path.into();
+
+        let watchlists = match std::fs::read(&path) {
+            Ok(bytes) => {
+                let watchlists: Vec<Watchlist> =
+                    serde_json::from_slice(&bytes).map_err(|e| storage_error(&path, &e))?;
+                watchlists
+                    .into_iter()
+                    .map(|w| (w.name.clone(), w))
+                    .collect()
+            }
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => HashMap::new(),
+            Err(e) => return Err(storage_error(&path, &e)),
+        };
+
+        Ok(Self {
+            path,
+            watchlists: Mutex::new(watchlists),
+        })
+    }
+
+    /// Path of the backing file.
+    #[must_use]
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// Write the current snapshot to disk.
+    async fn persist(&self, watchlists: &HashMap<String, Watchlist>) -> Result<(), WatchlistError> {
+        let mut snapshot: Vec<&Watchlist> = watchlists.values().collect();
+        snapshot.sort_by(|a, b| a.name.cmp(&b.name));
+        write_json_atomic(&self.path, &snapshot, FileAccess::Default)
+            .await
+            .map_err(|e| storage_error(&e.path, &e.source))
+    }
+}
+
+#[async_trait]
+impl WatchlistRepositoryPort for FileWatchlistRepository {
+    async fn save(&self, watchlist: &Watchlist) -> Result<(), WatchlistError> {
+        let mut watchlists = self.watchlists.lock().await;
+        let previous = watchlists.insert(watchlist.name.clone(), watchlist.clone());
+
+        if let Err(e) = self.persist(&watchlists).await {
+            // Keep memory consistent with disk
+            match previous {
+                Some(prev) => watchlists.insert(watchlist.name.clone(), prev),
+                None => watchlists.remove(&watchlist.name),
+            };
+            return Err(e);
+        }
+        drop(watchlists);
+        Ok(())
+    }
+
+    async fn find(&self, name: &str) -> Result<Option<Watchlist>, WatchlistError> {
+        Ok(self.watchlists.lock().await.get(name).cloned())
+    }
+
+    async fn list(&self) -> Result<Vec<Watchlist>, WatchlistError> {
+        let mut all: Vec<Watchlist> = self.watchlists.lock().await.values().cloned().collect();
+        all.sort_by(|a, b| a.name.cmp(&b.name));
+        Ok(all)
+    }
+
+    async fn delete(&self, name: &str) -> Result<(), WatchlistError> {
+        let mut watchlists = self.watchlists.lock().await;
+        let Some(removed) = watchlists.remove(name) else {
+            return Ok(());
+        };
+
+        if let Err(e) = self.persist(&watchlists).await {
+            watchlists.insert(name.to_string(), removed);
+            return Err(e);
+        }
+        drop(watchlists);
+        Ok(())
+    }
+}
+
+fn storage_error(path: &Path, error: &dyn std::fmt::Display) -> WatchlistError {
+    WatchlistError::StorageError {
+        message: format!("{}: {error}", path.display()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::shared::Timestamp;
+
+    fn watchlist(name: &str, symbols: &[&str]) -> Watchlist {
+        let now = Timestamp::parse("2026-03-02T15:00:00Z").unwrap();
+        Watchlist {
+            name: name.to_string(),
+            symbols: symbols.iter().map(ToString::to_string).collect(),
+            created_at: now,
+            updated_at: now,
+        }
+    }
+
+    #[tokio::test]
+    async fn watchlists_survive_reopen() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("nested").join("watchlists.json");
+
+        let repo = FileWatchlistRepository::open(&path).unwrap();
+        repo.save(&watchlist("tech", &["AAPL", "MSFT"]))
+            .await
+            .unwrap();
+        repo.save(&watchlist("index", &["SPY"])).await.unwrap();
+        repo.delete("index").await.unwrap();
+        drop(repo);
+
+        let reopened = FileWatchlistRepository::open(&path).unwrap();
+        let all = reopened.list().await.unwrap();
+        assert_eq!(all, vec![watchlist("tech", &["AAPL", "MSFT"])]);
+    }
+
+    #[test]
+    fn open_corrupt_file_fails() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("watchlists.json");
+        std::fs::write(&path, b"not json").unwrap();
+
+        let result = FileWatchlistRepository::open(&path);
+        assert!(matches!(result, Err(WatchlistError::StorageError { .. })));
+    }
+}
@@ -1,22 +1,27 @@
-//! In-memory order repository for testing.
+//! In-memory order repository.
+//!
+//! Orders live in memory; with a [`FileOrderHistory`] attached, finished
+//! orders are also recorded on disk so history survives a restart.
 
 use std::collections::HashMap;
 use std::sync::RwLock;
 
 use async_trait::async_trait;
 
+use super::file_order_history::FileOrderHistory;
 use crate::domain::order_execution::aggregate::Order;
 use crate::domain::order_execution::errors::OrderError;
-use crate::domain::order_execution::repository::OrderRepository;
+use crate::domain::order_execution::repository::{OrderPage, OrderQuery, OrderRepository};
 use crate::domain::order_execution::value_objects::OrderStatus;
 use crate::domain::shared::{BrokerId, OrderId};
 
 /// In-memory implementation of `OrderRepository`.
 ///
-/// Suitable for testing and development. Not for production use.
+/// Without a history, orders are lost on restart.
 #[derive(Debug, Default)]
 pub struct InMemoryOrderRepository {
     orders: RwLock<HashMap<String, Order>>,
+    history: Option<FileOrderHistory>,
 }
 
 impl InMemoryOrderRepository {
@@ -25,9 +30,20 @@ impl InMemoryOrderRepository {
     pub fn new() -> Self {
         Self {
             orders: RwLock::new(HashMap::new()),
+            history: None,
         }
     }
 
+    /// Record orders in `history` when they reach a terminal status.
+    ///
+    /// Orders already in the history are loaded separately, with
+    /// [`Self::add`].
+    #[must_use]
+    pub fn with_history(mut self, history: FileOrderHistory) -> Self {
+        self.history = Some(history);
+        self
+    }
+
     /// Get the number of orders in the repository.
     #[must_use]
     pub fn len(&self) -> usize {
@@ -63,12 +79,32 @@ impl InMemoryOrderRepository {
             .unwrap_or_else(std::sync::PoisonError::into_inner);
         orders.insert(order.id().to_string(), order);
     }
+
+    /// Whether `order` is a finished order the history does not have yet.
+    fn is_unrecorded_terminal(&self, order: &Order) -> bool {
+        if !order.status().is_terminal() {
+            return false;
+        }
+        self.orders
+            .read()
+            .unwrap_or_else(std::sync::PoisonError::into_inner)
+            .get(order.id().as_str())
+            .is_none_or(|saved| {
+                saved.status() != order.status() || saved.updated_at() != order.updated_at()
+            })
+    }
 }
 
 #[async_trait]
 
 impl OrderRepository for InMemoryOrderRepository {
     async fn save(&self, order: &Order) -> Result<(), OrderError> {
+        if let Some(history) = &self.history
+            && self.is_unrecorded_terminal(order)
+        {
+            // Recorded first, so a failed write leaves the order unsaved
+            history.record(order).await?;
+        }
         {
             let mut orders = self
                 .orders
@@ -140,6 +176,14 @@ impl OrderRepository for InMemoryOrderRepository {
             .unwrap_or_else(std::sync::PoisonError::into_inner);
         Ok(orders.contains_key(id.as_str()))
     }
+
+    async fn query(&self, query: &OrderQuery) -> Result<OrderPage, OrderError> {
+        let orders = self
+            .orders
+            .read()
+            .unwrap_or_else(std::sync::PoisonError::into_inner);
+        Ok(query.page(orders.values().filter(|o| query.matches(o)).cloned()))
+    }
 }
 
 #[cfg(test)]
@@ -147,9 +191,9 @@ mod tests {
     use super::*;
     use crate::domain::order_execution::aggregate::CreateOrderCommand;
     use crate::domain::order_execution::value_objects::{
-        CancelReason, OrderPurpose, OrderSide, OrderType, TimeInForce,
+        CancelReason, FillReport, OrderPurpose, OrderSide, OrderType, TimeInForce,
     };
-    use crate::domain::shared::{Quantity, Symbol};
+    use crate::domain::shared::{Money, Quantity, Symbol, Timestamp};
 
     fn create_test_order() -> Order {
         let command = CreateOrderCommand {
@@ -244,6 +288,54 @@ mod tests {
         assert!(repo.exists(&order_id).await.unwrap());
     }
 
+    #[tokio::test]
+    async fn finished_orders_are_queryable_after_reload() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("order_history.jsonl");
+        let (history, loaded) = FileOrderHistory::open(&path).unwrap();
+        assert!(loaded.is_empty());
+        let repo = InMemoryOrderRepository::new().with_history(history);
+
+        let mut filled = create_test_order();
+        filled.accept(BrokerId::new("broker-1")).unwrap();
+        repo.save(&filled).await.unwrap();
+        filled
+            .apply_fill(FillReport::new(
+                "fill-1",
+                Quantity::from_i64(100),
+                Money::usd(150.0),
+                Timestamp::parse("2026-03-02T15:00:00Z").unwrap(),
+                "NYSE",
+            ))
+            .unwrap();
+        repo.save(&filled).await.unwrap();
+        // Saving an unchanged finished order does not record it again
+        repo.save(&filled).await.unwrap();
+        // Working orders are not recorded
+        repo.save(&create_test_order()).await.unwrap();
+        drop(repo);
+        assert_eq!(std::fs::read_to_string(&path).unwrap().lines().count(), 1);
+
+        let (history, loaded) = FileOrderHistory::open(&path).unwrap();
+        let repo = InMemoryOrderRepository::new().with_history(history);
+        for order in loaded {
+            repo.add(order);
+        }
+
+        let page = repo
+            .query(&OrderQuery {
+                statuses: vec![OrderStatus::Filled],
+                ..OrderQuery::default()
+            })
+            .await
+            .unwrap();
+        assert_eq!(page.total, 1);
+        let order = &page.orders[0];
+        assert_eq!(order.id(), filled.id());
+        assert_eq!(order.partial_fill().fills().len(), 1);
+        assert_eq!(order.partial_fill().cum_qty(), Quantity::from_i64(100));
+    }
+
     #[test]
     fn len_and_is_empty() {
         let repo = InMemoryOrderRepository::new();
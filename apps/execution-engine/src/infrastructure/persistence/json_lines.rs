@@ -0,0 +1,193 @@
+//! Append-only journals of JSON records, one per line.
+//!
+//! Appends are synced to disk before they return. A record cut short by a
+//! crash mid-write can only be the last one; opening the journal drops it
+//! and cuts it from the file, so later records start on a fresh line. A
+//! journal can be rewritten atomically (see [`write_atomic`]) to compact it.
+
+use std::path::{Path, PathBuf};
+
+use serde::Serialize;
+use serde::de::DeserializeOwned;
+use tokio::io::AsyncWriteExt;
+
+use super::atomic_file::{FileAccess, FileError, sync_dir, write_atomic};
+
+/// An open journal file.
+#[derive(Debug)]
+pub struct JsonLines {
+    path: PathBuf,
+    /// Append handle, opened on first write.
+    file: Option<tokio::fs::File>,
+    /// Bytes of complete records in the file.
+    len: u64,
+    /// Records in the file.
+    records: usize,
+}
+
+impl JsonLines {
+    /// Open the journal at `path`, returning its records in order.
+    ///
+    /// A missing file is an empty journal. A partially written last record
+    /// is dropped.
+    ///
+    /// # Errors
+    ///
+    /// Returns error if the file exists but cannot be read or truncated, or
+    /// a record before the last one does not parse.
+    pub fn open<T: DeserializeOwned>(
+        path: impl Into<PathBuf>,
+    ) -> Result<(Self, Vec<T>), FileError> {
+        let path = path.into();
+        let bytes = match std::fs::read(&path) {
+            Ok(bytes) => bytes,
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => Vec::new(),
+            Err(e) => return Err(FileError::new(&path, e)),
+        };
+        let (records, len) = replay(&bytes).map_err(|e| FileError::new(&path, e))?;
+
+        let total = bytes.len() as u64;
+        if len < total {
+            tracing::warn!(
+                path = %path.display(),
+                dropped_bytes = total - len,
+                "Dropping partially written journal record"
+            );
+            truncate(&path, len).map_err(|e| FileError::new(&path, e))?;
+        }
+
+        let journal = Self {
+            path,
+            file: None,
+            len,
+            records: records.len(),
+        };
+        Ok((journal, records))
+    }
+
+    /// Path of the backing file.
+    #[must_use]
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// Records in the file.
+    #[must_use]
+    pub const fn records(&self) -> usize {
+        self.records
+    }
+
+    /// Append `records` and sync them to disk. On failure the file is left
+    /// as it was.
+    ///
+    /// # Errors
+    ///
+    /// Returns error if a record cannot be serialized or the write fails.
+    pub async fn append<T: Serialize + Sync>(&mut self, records: &[T]) -> Result<(), FileError> {
+        let bytes = encode(records).map_err(|e| FileError::new(&self.path, e))?;
+        let mut file = match self.file.take() {
+            Some(file) => file,
+            None => self.open_append().await?,
+        };
+
+        let written = append_synced(&mut file, &bytes).await;
+        if written.is_err() {
+            // Cut a partial record so the next one starts on a line
+            let _ = file.set_len(self.len).await;
+        }
+        self.file = Some(file);
+        written.map_err(|e| FileError::new(&self.path, e))?;
+        self.len += bytes.len() as u64;
+        self.records += records.len();
+        Ok(())
+    }
+
+    /// Replace the journal's contents with `records`.
+    ///
+    /// # Errors
+    ///
+    /// Returns error if a record cannot be serialized or the write fails;
+    /// the previous contents are then kept.
+    pub async fn rewrite<T: Serialize + Sync>(&mut self, records: &[T]) -> Result<(), FileError> {
+        let bytes = encode(records).map_err(|e| FileError::new(&self.path, e))?;
+        write_atomic(&self.path, &bytes, FileAccess::Default).await?;
+
+        // The old handle points at the replaced file
+        self.file = None;
+        self.len = bytes.len() as u64;
+        self.records = records.len();
+        Ok(())
+    }
+
+    async fn open_append(&self) -> Result<tokio::fs::File, FileError> {
+        let parent = self.path.parent().filter(|p| !p.as_os_str().is_empty());
+        if let Some(parent) = parent {
+            tokio::fs::create_dir_all(parent)
+                .await
+                .map_err(|e| FileError::new(parent, e))?;
+        }
+        let file = tokio::fs::OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(&self.path)
+            .await
+            .map_err(|e| FileError::new(&self.path, e))?;
+
+        // Make a newly created journal's directory entry durable
+        let dir = parent.unwrap_or_else(|| Path::new("."));
+        sync_dir(dir).await.map_err(|e| FileError::new(dir, e))?;
+        Ok(file)
+    }
+}
+
+/// Parse journal `bytes`, stopping before a partial last record. Returns
+/// the records and the length of the bytes they span.
+fn replay<T: DeserializeOwned>(bytes: &[u8]) -> std::io::Result<(Vec<T>, u64)> {
+    let mut records = Vec::new();
+    let mut len = 0;
+
+    let mut rest = bytes;
+    while !rest.is_empty() {
+        let newline = rest.iter().position(|b| *b == b'\n');
+        let end = newline.map_or(rest.len(), |i| i + 1);
+        let record = newline.and_then(|i| serde_json::from_slice::<T>(&rest[..i]).ok());
+        let Some(record) = record else {
+            // Only the record being written when the engine stopped can be
+            // incomplete
+            if end == rest.len() {
+                break;
+            }
+            return Err(std::io::Error::new(
+                std::io::ErrorKind::InvalidData,
+                format!("corrupt journal record at byte {len}"),
+            ));
+        };
+
+        records.push(record);
+        len += end;
+        rest = &rest[end..];
+    }
+
+    Ok((records, len as u64))
+}
+
+fn encode<T: Serialize>(records: &[T]) -> Result<Vec<u8>, serde_json::Error> {
+    let mut bytes = Vec::new();
+    for record in records {
+        serde_json::to_writer(&mut bytes, record)?;
+        bytes.push(b'\n');
+    }
+    Ok(bytes)
+}
+
+async fn append_synced(file: &mut tokio::fs::File, bytes: &[u8]) -> std::io::Result<()> {
+    file.write_all(bytes).await?;
+    file.sync_data().await
+}
+
+/// Cut `path` to `len` bytes.
+fn truncate(path: &Path, len: u64) -> std::io::Result<()> {
+    let file = std::fs::OpenOptions::new().write(true).open(path)?;
+    file.set_len(len)?;
+    file.sync_all()
+}
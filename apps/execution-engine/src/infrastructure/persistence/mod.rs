@@ -2,8 +2,28 @@
 //!
 //! Database implementations of repository traits.
 
+pub mod atomic_file;
+pub mod crash_snapshot;
+pub mod file_oauth_tokens;
+pub mod file_order_history;
+pub mod file_outbox;
+pub mod file_position_lifecycles;
+pub mod file_scheduled_plans;
+pub mod file_watchlists;
 pub mod in_memory;
+pub mod json_lines;
 
+pub use atomic_file::{FileAccess, FileError, write_atomic, write_json_atomic};
+pub use crash_snapshot::{
+    CRASH_SNAPSHOT_VERSION, CrashRecovery, CrashSnapshot, CrashSnapshotError, CrashSnapshotStore,
+    FailureClass,
+};
+pub use file_oauth_tokens::FileOAuthTokenRepository;
+pub use file_order_history::FileOrderHistory;
+pub use file_outbox::FileOutboxRepository;
+pub use file_position_lifecycles::FilePositionLifecycleRepository;
+pub use file_scheduled_plans::FileScheduledPlanRepository;
+pub use file_watchlists::FileWatchlistRepository;
 pub use in_memory::InMemoryOrderRepository;
 
 // Note: PostgreSQL adapter will be added in Phase 3 when full persistence is migrated.
@@ -1,25 +1,35 @@
 //! Alpaca Market Data price feed adapter.
 
 use std::collections::HashSet;
-use std::sync::RwLock;
+use std::sync::{Arc, RwLock};
 
 use async_trait::async_trait;
 use rust_decimal::Decimal;
 
-use crate::application::ports::{PriceFeedError, PriceFeedPort, Quote};
+use crate::application::ports::{BarTimeframe, Candle, PriceFeedError, PriceFeedPort, Quote};
+use crate::application::services::CandleAggregator;
 use crate::domain::shared::{InstrumentId, Symbol};
 use crate::infrastructure::broker::alpaca::{AlpacaConfig, AlpacaError};
+use crate::infrastructure::secrets::AlpacaCredentials;
 
 /// Alpaca price feed adapter.
 ///
-/// Implements `PriceFeedPort` using Alpaca's Market Data API.
-#[derive(Debug)]
+/// Implements `PriceFeedPort` using Alpaca's Market Data API. Intraday bars
+/// come from the live feed through a [`CandleAggregator`].
 pub struct AlpacaPriceFeedAdapter {
     client: reqwest::Client,
-    api_key: String,
-    api_secret: String,
+    credentials: RwLock<AlpacaCredentials>,
     data_url: String,
     subscriptions: RwLock<HashSet<String>>,
+    candles: Option<Arc<CandleAggregator>>,
+}
+
+impl std::fmt::Debug for AlpacaPriceFeedAdapter {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("AlpacaPriceFeedAdapter")
+            .field("data_url", &self.data_url)
+            .finish_non_exhaustive()
+    }
 }
 
 impl AlpacaPriceFeedAdapter {
@@ -40,13 +50,48 @@ impl AlpacaPriceFeedAdapter {
 
         Ok(Self {
             client,
-            api_key: config.api_key.clone(),
-            api_secret: config.api_secret.clone(),
+            credentials: RwLock::new(config.credentials()),
             data_url: config.data_base_url().to_string(),
             subscriptions: RwLock::new(HashSet::new()),
+            candles: None,
         })
     }
 
+    /// Serve bars built from the live feed by `candles`.
+    #[must_use]
+    pub fn with_candles(mut self, candles: Arc<CandleAggregator>) -> Self {
+        self.candles = Some(candles);
+        self
+    }
+
+    /// Use new credentials for subsequent requests.
+    ///
+    /// # Errors
+    ///
+    /// Returns error if either credential is empty.
+    pub fn rotate_credentials(&self, credentials: &AlpacaCredentials) -> Result<(), AlpacaError> {
+        if credentials.api_key.is_empty() || credentials.api_secret.is_empty() {
+            return Err(AlpacaError::AuthenticationFailed);
+        }
+        *self
+            .credentials
+            .write()
+            .unwrap_or_else(std::sync::PoisonError::into_inner) = credentials.clone();
+        Ok(())
+    }
+
+    /// GET request with authentication headers.
+    fn get(&self, url: &str) -> reqwest::RequestBuilder {
+        let credentials = self
+            .credentials
+            .read()
+            .unwrap_or_else(std::sync::PoisonError::into_inner);
+        self.client
+            .get(url)
+            .header("APCA-API-KEY-ID", credentials.api_key.expose())
+            .header("APCA-API-SECRET-KEY", credentials.api_secret.expose())
+    }
+
     /// Fetch latest quote from Alpaca.
     async fn fetch_quote(&self, symbol: &str) -> Result<AlpacaQuoteResponse, AlpacaError> {
         let url = format!(
@@ -56,10 +101,7 @@ impl AlpacaPriceFeedAdapter {
         );
 
         let response = self
-            .client
             .get(&url)
-            .header("APCA-API-KEY-ID", &self.api_key)
-            .header("APCA-API-SECRET-KEY", &self.api_secret)
             .send()
             .await
             .map_err(|e| AlpacaError::Network(e.to_string()))?;
@@ -94,10 +136,7 @@ impl AlpacaPriceFeedAdapter {
         );
 
         let response = self
-            .client
             .get(&url)
-            .header("APCA-API-KEY-ID", &self.api_key)
-            .header("APCA-API-SECRET-KEY", &self.api_secret)
             .send()
             .await
             .map_err(|e| AlpacaError::Network(e.to_string()))?;
@@ -127,15 +166,7 @@ impl PriceFeedPort for AlpacaPriceFeedAdapter {
             }
         })?;
 
-        let bid =
-            Decimal::try_from(response.quote.bp).map_err(|_| PriceFeedError::DataUnavailable)?;
-        let ask =
-            Decimal::try_from(response.quote.ap).map_err(|_| PriceFeedError::DataUnavailable)?;
-
-        let bid_size = Decimal::from(response.quote.bs);
-        let ask_size = Decimal::from(response.quote.ask_size);
-
-        Ok(Quote::new(symbol.clone(), bid, ask, bid_size, ask_size))
+        Ok(response.quote.to_quote(symbol.clone()))
     }
 
     async fn get_quotes(&self, symbols: &[Symbol]) -> Result<Vec<Quote>, PriceFeedError> {
@@ -150,15 +181,7 @@ impl PriceFeedPort for AlpacaPriceFeedAdapter {
         let mut quotes = Vec::with_capacity(symbols.len());
         for symbol in symbols {
             if let Some(quote_data) = response.quotes.get(symbol.as_str()) {
-                let bid = Decimal::try_from(quote_data.bp)
-                    .map_err(|_| PriceFeedError::DataUnavailable)?;
-                let ask = Decimal::try_from(quote_data.ap)
-                    .map_err(|_| PriceFeedError::DataUnavailable)?;
-
-                let bid_size = Decimal::from(quote_data.bs);
-                let ask_size = Decimal::from(quote_data.ask_size);
-
-                quotes.push(Quote::new(symbol.clone(), bid, ask, bid_size, ask_size));
+                quotes.push(quote_data.to_quote(symbol.clone()));
             }
         }
 
@@ -194,6 +217,19 @@ impl PriceFeedPort for AlpacaPriceFeedAdapter {
         let quote = self.get_quote(&Symbol::new(instrument_id.as_str())).await?;
         Ok(quote.mid())
     }
+
+    async fn get_bars(
+        &self,
+        symbol: &Symbol,
+        timeframe: BarTimeframe,
+        limit: usize,
+    ) -> Result<Vec<Candle>, PriceFeedError> {
+        Ok(self
+            .candles
+            .as_ref()
+            .map(|candles| candles.bars(symbol.as_str(), timeframe, limit))
+            .unwrap_or_default())
+    }
 }
 
 // API response types
@@ -211,9 +247,9 @@ struct AlpacaQuotesResponse {
 #[derive(Debug, serde::Deserialize)]
 struct AlpacaQuoteData {
     /// Bid price.
-    bp: f64,
+    bp: Decimal,
     /// Ask price.
-    ap: f64,
+    ap: Decimal,
     /// Bid size.
     bs: i32,
     /// Ask size.
@@ -221,10 +257,32 @@ struct AlpacaQuoteData {
     ask_size: i32,
 }
 
+impl AlpacaQuoteData {
+    fn to_quote(&self, symbol: Symbol) -> Quote {
+        Quote::new(
+            symbol,
+            self.bp,
+            self.ap,
+            Decimal::from(self.bs),
+            Decimal::from(self.ask_size),
+        )
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    #[test]
+    fn quote_prices_parse_exactly() {
+        let json = r#"{"quote": {"bp": 185.5, "ap": 185.52, "bs": 1, "as": 2}}"#;
+        let response: AlpacaQuoteResponse = serde_json::from_str(json).unwrap();
+
+        let quote = response.quote.to_quote(Symbol::new("AAPL"));
+        assert_eq!(quote.bid.to_string(), "185.5");
+        assert_eq!(quote.ask.to_string(), "185.52");
+    }
+
     #[test]
     fn alpaca_price_feed_creation_fails_empty_credentials() {
         use crate::infrastructure::broker::alpaca::AlpacaEnvironment;
@@ -0,0 +1,294 @@
+//! AWS Secrets Manager secrets provider.
+//!
+//! Calls `GetSecretValue` over the JSON API with a Signature Version 4
+//! signed request. The secret's `SecretString` must be a JSON object with
+//! `api_key` and `api_secret`.
+
+use std::collections::BTreeMap;
+use std::fmt::Write as _;
+use std::time::Duration;
+
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use hmac::{Hmac, Mac};
+use sha2::{Digest, Sha256};
+
+use super::{
+    AlpacaCredentials, Secret, SecretsError, SecretsProvider, error_message, required_env,
+};
+
+const SERVICE: &str = "secretsmanager";
+const TARGET: &str = "secretsmanager.GetSecretValue";
+const CONTENT_TYPE: &str = "application/x-amz-json-1.1";
+
+/// AWS access credentials used to sign requests.
+#[derive(Debug, Clone)]
+pub struct AwsCredentials {
+    /// Access key ID.
+    pub access_key_id: String,
+    /// Secret access key.
+    pub secret_access_key: Secret,
+    /// Session token for temporary credentials.
+    pub session_token: Option<Secret>,
+}
+
+impl AwsCredentials {
+    /// Read `AWS_ACCESS_KEY_ID`, `AWS_SECRET_ACCESS_KEY` and optional
+    /// `AWS_SESSION_TOKEN`.
+    ///
+    /// # Errors
+    ///
+    /// Returns error if the key ID or secret key is missing.
+    pub fn from_env() -> Result<Self, SecretsError> {
+        Ok(Self {
+            access_key_id: required_env("AWS_ACCESS_KEY_ID")?,
+            secret_access_key: Secret::new(required_env("AWS_SECRET_ACCESS_KEY")?),
+            session_token: std::env::var("AWS_SESSION_TOKEN")
+                .ok()
+                .filter(|v| !v.is_empty())
+                .map(Secret::new),
+        })
+    }
+}
+
+/// Reads Alpaca credentials from AWS Secrets Manager.
+#[derive(Debug)]
+pub struct AwsSecretsManagerProvider {
+    client: reqwest::Client,
+    endpoint: reqwest::Url,
+    region: String,
+    secret_id: String,
+    credentials: AwsCredentials,
+}
+
+impl AwsSecretsManagerProvider {
+    /// Create a provider for `secret_id` (name or ARN) in `region`.
+    ///
+    /// # Errors
+    ///
+    /// Returns error if the HTTP client cannot be built.
+    pub fn new(
+        region: impl Into<String>,
+        secret_id: impl Into<String>,
+        credentials: AwsCredentials,
+    ) -> Result<Self, SecretsError> {
+        let region = region.into();
+        let endpoint = format!("https://{SERVICE}.{region}.amazonaws.com/");
+        let client = reqwest::Client::builder()
+            .timeout(Duration::from_secs(10))
+            .build()
+            .map_err(|e| SecretsError::Config(e.to_string()))?;
+
+        Ok(Self {
+            client,
+            endpoint: parse_endpoint(&endpoint)?,
+            region,
+            secret_id: secret_id.into(),
+            credentials,
+        })
+    }
+
+    /// Send requests to a different endpoint (VPC endpoint, `LocalStack`).
+    ///
+    /// # Errors
+    ///
+    /// Returns error if the URL is invalid.
+    pub fn with_endpoint(mut self, endpoint: &str) -> Result<Self, SecretsError> {
+        self.endpoint = parse_endpoint(endpoint)?;
+        Ok(self)
+    }
+
+    /// Create a provider from `AWS_REGION`, `AWS_SECRET_ID`, the standard
+    /// AWS credential variables, and optional `AWS_ENDPOINT_URL`.
+    ///
+    /// # Errors
+    ///
+    /// Returns error if a required variable is missing.
+    pub fn from_env() -> Result<Self, SecretsError> {
+        let region = required_env("AWS_REGION").or_else(|_| required_env("AWS_DEFAULT_REGION"))?;
+        let provider = Self::new(
+            region,
+            required_env("AWS_SECRET_ID")?,
+            AwsCredentials::from_env()?,
+        )?;
+
+        match std::env::var("AWS_ENDPOINT_URL") {
+            Ok(endpoint) if !endpoint.trim().is_empty() => provider.with_endpoint(&endpoint),
+            _ => Ok(provider),
+        }
+    }
+
+    /// Headers for a signed `GetSecretValue` request.
+    fn signed_headers(&self, body: &str, now: DateTime<Utc>) -> BTreeMap<&'static str, String> {
+        let amz_date = now.format("%Y%m%dT%H%M%SZ").to_string();
+        let date = now.format("%Y%m%d").to_string();
+
+        let mut headers = BTreeMap::new();
+        headers.insert("content-type", CONTENT_TYPE.to_string());
+        headers.insert("host", host(&self.endpoint));
+        headers.insert("x-amz-date", amz_date.clone());
+        headers.insert("x-amz-target", TARGET.to_string());
+        if let Some(token) = &self.credentials.session_token {
+            headers.insert("x-amz-security-token", token.expose().to_string());
+        }
+
+        let signed_names = headers.keys().copied().collect::<Vec<_>>().join(";");
+        let canonical_headers = headers
+            .iter()
+            .fold(String::new(), |mut out, (name, value)| {
+                let _ = writeln!(out, "{name}:{}", value.trim());
+                out
+            });
+        let canonical_request = format!(
+            "POST\n{}\n\n{canonical_headers}\n{signed_names}\n{}",
+            self.endpoint.path(),
+            hex::encode(Sha256::digest(body.as_bytes()))
+        );
+
+        let scope = format!("{date}/{}/{SERVICE}/aws4_request", self.region);
+        let string_to_sign = format!(
+            "AWS4-HMAC-SHA256\n{amz_date}\n{scope}\n{}",
+            hex::encode(Sha256::digest(canonical_request.as_bytes()))
+        );
+
+        let secret = format!("AWS4{}", self.credentials.secret_access_key.expose());
+        let key = [date.as_str(), self.region.as_str(), SERVICE, "aws4_request"]
+            .iter()
+            .fold(secret.into_bytes(), |key, part| hmac_sha256(&key, part));
+        let signature = hex::encode(hmac_sha256(&key, &string_to_sign));
+
+        headers.insert(
+            "authorization",
+            format!(
+                "AWS4-HMAC-SHA256 Credential={}/{scope}, SignedHeaders={signed_names}, Signature={signature}",
+                self.credentials.access_key_id
+            ),
+        );
+        headers
+    }
+}
+
+fn parse_endpoint(endpoint: &str) -> Result<reqwest::Url, SecretsError> {
+    reqwest::Url::parse(endpoint)
+        .map_err(|e| SecretsError::Config(format!("invalid endpoint {endpoint}: {e}")))
+}
+
+fn host(url: &reqwest::Url) -> String {
+    let host = url.host_str().unwrap_or_default();
+    url.port()
+        .map_or_else(|| host.to_string(), |port| format!("{host}:{port}"))
+}
+
+#[allow(clippy::expect_used)]
+fn hmac_sha256(key: &[u8], data: &str) -> Vec<u8> {
+    let mut mac = Hmac::<Sha256>::new_from_slice(key).expect("HMAC accepts keys of any length");
+    mac.update(data.as_bytes());
+    mac.finalize().into_bytes().to_vec()
+}
+
+#[async_trait]
+impl SecretsProvider for AwsSecretsManagerProvider {
+    fn name(&self) -> &'static str {
+        "aws"
+    }
+
+    async fn alpaca_credentials(&self) -> Result<AlpacaCredentials, SecretsError> {
+        let body = serde_json::json!({ "SecretId": self.secret_id }).to_string();
+
+        let mut request = self.client.post(self.endpoint.clone());
+        for (name, value) in self.signed_headers(&body, Utc::now()) {
+            // reqwest sets Host from the URL
+            if name != "host" {
+                request = request.header(name, value);
+            }
+        }
+
+        let response = request
+            .body(body)
+            .send()
+            .await
+            .map_err(|e| SecretsError::Network(e.to_string()))?;
+        let status = response.status();
+        let text = response
+            .text()
+            .await
+            .map_err(|e| SecretsError::Network(e.to_string()))?;
+
+        if !status.is_success() {
+            return Err(SecretsError::Rejected {
+                status: status.as_u16(),
+                message: error_message(&text),
+            });
+        }
+
+        let body: serde_json::Value =
+            serde_json::from_str(&text).map_err(|e| SecretsError::Format(e.to_string()))?;
+        let secret_string = body
+            .get("SecretString")
+            .and_then(serde_json::Value::as_str)
+            .ok_or_else(|| SecretsError::Format("secret has no SecretString".to_string()))?;
+        let secret: serde_json::Value = serde_json::from_str(secret_string)
+            .map_err(|_| SecretsError::Format("SecretString is not a JSON object".to_string()))?;
+
+        AlpacaCredentials::from_json(&secret)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+    use wiremock::matchers::{header, header_exists, method};
+    use wiremock::{Mock, MockServer, ResponseTemplate};
+
+    fn credentials() -> AwsCredentials {
+        AwsCredentials {
+            access_key_id: "AKIDEXAMPLE".to_string(),
+            secret_access_key: Secret::new("wJalrXUtnFEMI/K7MDENG+bPxRfiCYEXAMPLEKEY"),
+            session_token: None,
+        }
+    }
+
+    #[test]
+    fn signs_get_secret_value_request() {
+        let provider =
+            AwsSecretsManagerProvider::new("us-east-1", "cream/alpaca", credentials()).unwrap();
+        let now = Utc.with_ymd_and_hms(2024, 1, 15, 12, 0, 0).unwrap();
+
+        let headers = provider.signed_headers(r#"{"SecretId":"cream/alpaca"}"#, now);
+
+        assert_eq!(headers["host"], "secretsmanager.us-east-1.amazonaws.com");
+        assert_eq!(headers["x-amz-date"], "20240115T120000Z");
+        assert_eq!(
+            headers["authorization"],
+            "AWS4-HMAC-SHA256 \
+             Credential=AKIDEXAMPLE/20240115/us-east-1/secretsmanager/aws4_request, \
+             SignedHeaders=content-type;host;x-amz-date;x-amz-target, \
+             Signature=13aeba6036297c2b7c26d9a04bf36b9029ec708ef1f9867875603ac66230fb41"
+        );
+    }
+
+    #[tokio::test]
+    async fn reads_credentials_from_secret_string() {
+        let server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .and(header("x-amz-target", TARGET))
+            .and(header_exists("authorization"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "Name": "cream/alpaca",
+                "SecretString": r#"{"api_key":"aws-key","api_secret":"aws-secret"}"#
+            })))
+            .mount(&server)
+            .await;
+
+        let provider = AwsSecretsManagerProvider::new("us-east-1", "cream/alpaca", credentials())
+            .unwrap()
+            .with_endpoint(&server.uri())
+            .unwrap();
+
+        assert_eq!(
+            provider.alpaca_credentials().await.unwrap(),
+            AlpacaCredentials::new("aws-key", "aws-secret")
+        );
+    }
+}
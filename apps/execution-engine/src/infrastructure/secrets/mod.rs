@@ -0,0 +1,248 @@
+//! Secrets Providers
+//!
+//! Pluggable sources for the Alpaca API credentials. `SECRETS_PROVIDER`
+//! selects one:
+//!
+//! - `env` (default): `ALPACA_KEY` and `ALPACA_SECRET`
+//! - `vault`: a `HashiCorp` Vault KV secret ([`VaultSecretsProvider`])
+//! - `aws`: an AWS Secrets Manager secret ([`AwsSecretsManagerProvider`])
+//!
+//! Vault and AWS secrets hold a JSON object with `api_key` and `api_secret`.
+//! [`CredentialRotator`] polls the provider and pushes changed credentials
+//! into the Alpaca adapters.
+//!
+//! Secret values are wrapped in [`Secret`], whose `Debug` and `Display`
+//! print `[REDACTED]`, so they cannot reach logs through formatting.
+
+mod aws;
+mod rotation;
+mod vault;
+
+use std::sync::Arc;
+use std::time::Duration;
+
+use async_trait::async_trait;
+
+pub use aws::{AwsCredentials, AwsSecretsManagerProvider};
+pub use rotation::{CredentialRotator, CredentialSink};
+pub use vault::VaultSecretsProvider;
+
+/// How often Vault and AWS secrets are re-read by default.
+pub const DEFAULT_ROTATION_INTERVAL: Duration = Duration::from_secs(300);
+
+/// Placeholder printed instead of secret values.
+pub const REDACTED: &str = "[REDACTED]";
+
+/// A secret string that never appears in `Debug` or `Display` output.
+#[derive(Clone, Default, PartialEq, Eq)]
+pub struct Secret(String);
+
+impl Secret {
+    /// Wrap a secret value.
+    #[must_use]
+    pub fn new(value: impl Into<String>) -> Self {
+        Self(value.into())
+    }
+
+    /// The secret value. Never log the result.
+    #[must_use]
+    pub fn expose(&self) -> &str {
+        &self.0
+    }
+
+    /// Check if the value is empty.
+    #[must_use]
+    pub const fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+}
+
+impl From<String> for Secret {
+    fn from(value: String) -> Self {
+        Self(value)
+    }
+}
+
+impl std::fmt::Debug for Secret {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(REDACTED)
+    }
+}
+
+impl std::fmt::Display for Secret {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(REDACTED)
+    }
+}
+
+/// Alpaca API credentials.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AlpacaCredentials {
+    /// API key ID.
+    pub api_key: Secret,
+    /// API secret key.
+    pub api_secret: Secret,
+}
+
+impl AlpacaCredentials {
+    /// Create credentials.
+    #[must_use]
+    pub fn new(api_key: impl Into<String>, api_secret: impl Into<String>) -> Self {
+        Self {
+            api_key: Secret::new(api_key),
+            api_secret: Secret::new(api_secret),
+        }
+    }
+
+    /// Read `api_key` and `api_secret` from a JSON secret.
+    ///
+    /// # Errors
+    ///
+    /// Returns error if either field is missing or empty.
+    pub fn from_json(value: &serde_json::Value) -> Result<Self, SecretsError> {
+        let field = |name: &str| {
+            value
+                .get(name)
+                .and_then(serde_json::Value::as_str)
+                .filter(|v| !v.is_empty())
+                .map(str::to_string)
+                .ok_or_else(|| SecretsError::MissingField {
+                    field: name.to_string(),
+                })
+        };
+        Ok(Self::new(field("api_key")?, field("api_secret")?))
+    }
+}
+
+/// Secrets provider errors.
+///
+/// Messages never include secret values.
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+pub enum SecretsError {
+    /// Provider settings are missing or invalid.
+    #[error("Invalid secrets configuration: {0}")]
+    Config(String),
+
+    /// The provider could not be reached.
+    #[error("Secrets provider unreachable: {0}")]
+    Network(String),
+
+    /// The provider rejected the request.
+    #[error("Secrets provider returned {status}: {message}")]
+    Rejected {
+        /// HTTP status code.
+        status: u16,
+        /// Error details from the provider.
+        message: String,
+    },
+
+    /// The secret is not in the expected format.
+    #[error("Invalid secret format: {0}")]
+    Format(String),
+
+    /// The secret lacks a required field.
+    #[error("Secret is missing field {field}")]
+    MissingField {
+        /// Field name.
+        field: String,
+    },
+}
+
+/// Source of Alpaca API credentials.
+#[async_trait]
+pub trait SecretsProvider: Send + Sync + std::fmt::Debug {
+    /// Provider name for logs.
+    fn name(&self) -> &'static str;
+
+    /// Fetch the current credentials.
+    async fn alpaca_credentials(&self) -> Result<AlpacaCredentials, SecretsError>;
+
+    /// Whether credentials can change while the engine runs.
+    fn supports_rotation(&self) -> bool {
+        true
+    }
+}
+
+/// Reads credentials from `ALPACA_KEY` and `ALPACA_SECRET`.
+#[derive(Debug, Default)]
+pub struct EnvSecretsProvider;
+
+#[async_trait]
+impl SecretsProvider for EnvSecretsProvider {
+    fn name(&self) -> &'static str {
+        "env"
+    }
+
+    async fn alpaca_credentials(&self) -> Result<AlpacaCredentials, SecretsError> {
+        Ok(AlpacaCredentials::new(
+            required_env("ALPACA_KEY")?,
+            required_env("ALPACA_SECRET")?,
+        ))
+    }
+
+    fn supports_rotation(&self) -> bool {
+        false
+    }
+}
+
+/// Build the provider selected by `SECRETS_PROVIDER`.
+///
+/// # Errors
+///
+/// Returns error if the provider is unknown or its settings are missing.
+pub fn provider_from_env() -> Result<Arc<dyn SecretsProvider>, SecretsError> {
+    let name = std::env::var("SECRETS_PROVIDER").unwrap_or_default();
+    match name.to_lowercase().as_str() {
+        "" | "env" => Ok(Arc::new(EnvSecretsProvider)),
+        "vault" => Ok(Arc::new(VaultSecretsProvider::from_env()?)),
+        "aws" => Ok(Arc::new(AwsSecretsManagerProvider::from_env()?)),
+        other => Err(SecretsError::Config(format!(
+            "unknown SECRETS_PROVIDER '{other}'; use env, vault or aws"
+        ))),
+    }
+}
+
+/// Read a required, non-empty environment variable.
+fn required_env(name: &str) -> Result<String, SecretsError> {
+    std::env::var(name)
+        .ok()
+        .filter(|v| !v.trim().is_empty())
+        .ok_or_else(|| SecretsError::Config(format!("{name} is required")))
+}
+
+/// Truncate a provider error body for messages.
+fn error_message(body: &str) -> String {
+    body.chars().take(200).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn secrets_are_redacted() {
+        let credentials = AlpacaCredentials::new("PKTEST123", "hunter2");
+        let debug = format!("{credentials:?}");
+
+        assert!(!debug.contains("PKTEST123"));
+        assert!(!debug.contains("hunter2"));
+        assert!(debug.contains(REDACTED));
+        assert_eq!(credentials.api_secret.to_string(), REDACTED);
+        assert_eq!(credentials.api_secret.expose(), "hunter2");
+    }
+
+    #[test]
+    fn credentials_from_json() {
+        let json = serde_json::json!({"api_key": "key", "api_secret": "secret"});
+        let credentials = AlpacaCredentials::from_json(&json).unwrap();
+        assert_eq!(credentials, AlpacaCredentials::new("key", "secret"));
+
+        let json = serde_json::json!({"api_key": "key", "api_secret": ""});
+        assert_eq!(
+            AlpacaCredentials::from_json(&json).unwrap_err(),
+            SecretsError::MissingField {
+                field: "api_secret".to_string()
+            }
+        );
+    }
+}
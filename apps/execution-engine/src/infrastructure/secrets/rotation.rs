@@ -0,0 +1,261 @@
+//! Credential rotation.
+//!
+//! [`CredentialRotator`] re-reads the secrets provider on an interval and,
+//! when the credentials change, hands them to every registered
+//! [`CredentialSink`]. The Alpaca broker adapter re-creates its HTTP client;
+//! the price feed and market data adapters and the order update streams swap
+//! the credentials they send.
+
+use std::sync::{Arc, PoisonError, RwLock};
+use std::time::Duration;
+
+use tokio_util::sync::CancellationToken;
+
+use super::{AlpacaCredentials, SecretsError, SecretsProvider};
+use crate::infrastructure::broker::alpaca::{
+    AlpacaBrokerAdapter, AlpacaError, AlpacaOrderUpdateStream,
+};
+use crate::infrastructure::marketdata::AlpacaMarketDataAdapter;
+use crate::infrastructure::price_feed::AlpacaPriceFeedAdapter;
+
+/// A component that authenticates with Alpaca credentials.
+pub trait CredentialSink: Send + Sync {
+    /// Component name for logs.
+    fn name(&self) -> &'static str;
+
+    /// Start using new credentials.
+    ///
+    /// # Errors
+    ///
+    /// Returns error if the component cannot be rebuilt with them.
+    fn rotate_credentials(&self, credentials: &AlpacaCredentials) -> Result<(), AlpacaError>;
+}
+
+impl CredentialSink for AlpacaBrokerAdapter {
+    fn name(&self) -> &'static str {
+        "broker"
+    }
+
+    fn rotate_credentials(&self, credentials: &AlpacaCredentials) -> Result<(), AlpacaError> {
+        Self::rotate_credentials(self, credentials)
+    }
+}
+
+impl CredentialSink for AlpacaPriceFeedAdapter {
+    fn name(&self) -> &'static str {
+        "price_feed"
+    }
+
+    fn rotate_credentials(&self, credentials: &AlpacaCredentials) -> Result<(), AlpacaError> {
+        Self::rotate_credentials(self, credentials)
+    }
+}
+
+impl CredentialSink for AlpacaMarketDataAdapter {
+    fn name(&self) -> &'static str {
+        "market_data"
+    }
+
+    fn rotate_credentials(&self, credentials: &AlpacaCredentials) -> Result<(), AlpacaError> {
+        Self::rotate_credentials(self, credentials)
+    }
+}
+
+impl CredentialSink for AlpacaOrderUpdateStream {
+    fn name(&self) -> &'static str {
+        "order_updates"
+    }
+
+    fn rotate_credentials(&self, credentials: &AlpacaCredentials) -> Result<(), AlpacaError> {
+        Self::rotate_credentials(self, credentials)
+    }
+}
+
+/// Polls a secrets provider and applies changed credentials.
+pub struct CredentialRotator {
+    provider: Arc<dyn SecretsProvider>,
+    interval: Duration,
+    current: RwLock<AlpacaCredentials>,
+    sinks: Vec<Arc<dyn CredentialSink>>,
+}
+
+impl std::fmt::Debug for CredentialRotator {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("CredentialRotator")
+            .field("provider", &self.provider.name())
+            .field("interval", &self.interval)
+            .field("sinks", &self.sinks.len())
+            .finish_non_exhaustive()
+    }
+}
+
+impl CredentialRotator {
+    /// Create a rotator starting from the credentials already in use.
+    #[must_use]
+    pub fn new(
+        provider: Arc<dyn SecretsProvider>,
+        current: AlpacaCredentials,
+        interval: Duration,
+    ) -> Self {
+        Self {
+            provider,
+            interval,
+            current: RwLock::new(current),
+            sinks: Vec::new(),
+        }
+    }
+
+    /// Push rotated credentials to `sink`.
+    #[must_use]
+    pub fn with_sink(mut self, sink: Arc<dyn CredentialSink>) -> Self {
+        self.sinks.push(sink);
+        self
+    }
+
+    /// Fetch credentials and apply them if they changed.
+    ///
+    /// Returns whether the credentials changed. A sink that fails to rotate
+    /// is logged and keeps its previous credentials.
+    ///
+    /// # Errors
+    ///
+    /// Returns error if the provider cannot be read.
+    pub async fn check(&self) -> Result<bool, SecretsError> {
+        let fetched = self.provider.alpaca_credentials().await?;
+        if *self.current.read().unwrap_or_else(PoisonError::into_inner) == fetched {
+            return Ok(false);
+        }
+
+        for sink in &self.sinks {
+            match sink.rotate_credentials(&fetched) {
+                Ok(()) => tracing::info!(component = sink.name(), "Alpaca credentials rotated"),
+                Err(e) => tracing::error!(
+                    component = sink.name(),
+                    error = %e,
+                    "Failed to rotate Alpaca credentials"
+                ),
+            }
+        }
+
+        *self.current.write().unwrap_or_else(PoisonError::into_inner) = fetched;
+        Ok(true)
+    }
+
+    /// Poll the provider until shutdown.
+    ///
+    /// Does nothing for providers that do not support rotation or when the
+    /// interval is zero.
+    pub fn start(self: &Arc<Self>, shutdown: CancellationToken) {
+        if !self.provider.supports_rotation() || self.interval.is_zero() {
+            return;
+        }
+
+        let rotator = Arc::clone(self);
+        tokio::spawn(async move {
+            let mut interval = tokio::time::interval(rotator.interval);
+            // The first tick completes immediately; credentials were just read
+            interval.tick().await;
+
+            loop {
+                tokio::select! {
+                    _ = interval.tick() => {
+                        if let Err(e) = rotator.check().await {
+                            tracing::warn!(
+                                provider = rotator.provider.name(),
+                                error = %e,
+                                "Failed to refresh Alpaca credentials"
+                            );
+                        }
+                    }
+                    () = shutdown.cancelled() => {
+                        tracing::info!("Credential rotator shutting down");
+                        break;
+                    }
+                }
+            }
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use async_trait::async_trait;
+    use std::sync::Mutex;
+
+    #[derive(Debug)]
+    struct StaticProvider(Mutex<AlpacaCredentials>);
+
+    #[async_trait]
+    impl SecretsProvider for StaticProvider {
+        fn name(&self) -> &'static str {
+            "static"
+        }
+
+        async fn alpaca_credentials(&self) -> Result<AlpacaCredentials, SecretsError> {
+            Ok(self.0.lock().unwrap().clone())
+        }
+    }
+
+    #[derive(Default)]
+    struct RecordingSink(Mutex<Vec<String>>);
+
+    impl CredentialSink for RecordingSink {
+        fn name(&self) -> &'static str {
+            "recording"
+        }
+
+        fn rotate_credentials(&self, credentials: &AlpacaCredentials) -> Result<(), AlpacaError> {
+            self.0
+                .lock()
+                .unwrap()
+                .push(credentials.api_key.expose().to_string());
+            Ok(())
+        }
+    }
+
+    #[tokio::test]
+    async fn rotates_only_when_credentials_change() {
+        let initial = AlpacaCredentials::new("key-1", "secret-1");
+        let provider = Arc::new(StaticProvider(Mutex::new(initial.clone())));
+        let sink = Arc::new(RecordingSink::default());
+        let rotator = CredentialRotator::new(
+            Arc::clone(&provider) as Arc<dyn SecretsProvider>,
+            initial,
+            Duration::from_secs(60),
+        )
+        .with_sink(Arc::clone(&sink) as Arc<dyn CredentialSink>);
+
+        assert!(!rotator.check().await.unwrap());
+        assert!(sink.0.lock().unwrap().is_empty());
+
+        *provider.0.lock().unwrap() = AlpacaCredentials::new("key-2", "secret-2");
+        assert!(rotator.check().await.unwrap());
+        assert!(!rotator.check().await.unwrap());
+        assert_eq!(*sink.0.lock().unwrap(), vec!["key-2"]);
+    }
+
+    #[test]
+    fn broker_adapter_rebuilds_client() {
+        use crate::infrastructure::broker::alpaca::{AlpacaConfig, AlpacaEnvironment};
+
+        let config = AlpacaConfig::new(
+            "key-1".to_string(),
+            "secret-1".to_string(),
+            AlpacaEnvironment::Paper,
+        );
+        let adapter = AlpacaBrokerAdapter::new(&config).unwrap();
+
+        assert!(
+            CredentialSink::rotate_credentials(
+                &adapter,
+                &AlpacaCredentials::new("key-2", "secret-2")
+            )
+            .is_ok()
+        );
+        assert!(
+            CredentialSink::rotate_credentials(&adapter, &AlpacaCredentials::new("", "secret"))
+                .is_err()
+        );
+    }
+}
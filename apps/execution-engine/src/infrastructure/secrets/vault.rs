@@ -0,0 +1,197 @@
+//! `HashiCorp` Vault secrets provider.
+//!
+//! Reads a KV secret over the HTTP API. Both KV v2 (`secret/data/...`) and
+//! KV v1 paths work; for v2 the payload is unwrapped from `data.data`.
+
+use std::time::Duration;
+
+use async_trait::async_trait;
+
+use super::{
+    AlpacaCredentials, Secret, SecretsError, SecretsProvider, error_message, required_env,
+};
+
+/// Reads Alpaca credentials from a Vault KV secret.
+#[derive(Debug)]
+pub struct VaultSecretsProvider {
+    client: reqwest::Client,
+    address: String,
+    token: Secret,
+    namespace: Option<String>,
+    secret_path: String,
+}
+
+impl VaultSecretsProvider {
+    /// Create a provider.
+    ///
+    /// `secret_path` is the API path below `/v1/`, e.g.
+    /// `secret/data/cream/alpaca` for a KV v2 mount named `secret`.
+    ///
+    /// # Errors
+    ///
+    /// Returns error if the HTTP client cannot be built.
+    pub fn new(
+        address: impl Into<String>,
+        token: Secret,
+        secret_path: impl Into<String>,
+    ) -> Result<Self, SecretsError> {
+        let client = reqwest::Client::builder()
+            .timeout(Duration::from_secs(10))
+            .build()
+            .map_err(|e| SecretsError::Config(e.to_string()))?;
+
+        Ok(Self {
+            client,
+            address: address.into().trim_end_matches('/').to_string(),
+            token,
+            namespace: None,
+            secret_path: secret_path.into().trim_matches('/').to_string(),
+        })
+    }
+
+    /// Send requests to a Vault Enterprise namespace.
+    #[must_use]
+    pub fn with_namespace(mut self, namespace: impl Into<String>) -> Self {
+        self.namespace = Some(namespace.into());
+        self
+    }
+
+    /// Create a provider from `VAULT_ADDR`, `VAULT_TOKEN`,
+    /// `VAULT_SECRET_PATH` and optional `VAULT_NAMESPACE`.
+    ///
+    /// # Errors
+    ///
+    /// Returns error if a required variable is missing.
+    pub fn from_env() -> Result<Self, SecretsError> {
+        let provider = Self::new(
+            required_env("VAULT_ADDR")?,
+            Secret::new(required_env("VAULT_TOKEN")?),
+            required_env("VAULT_SECRET_PATH")?,
+        )?;
+
+        Ok(match std::env::var("VAULT_NAMESPACE") {
+            Ok(namespace) if !namespace.trim().is_empty() => provider.with_namespace(namespace),
+            _ => provider,
+        })
+    }
+
+    fn url(&self) -> String {
+        format!("{}/v1/{}", self.address, self.secret_path)
+    }
+}
+
+/// Extract the secret payload from a KV read response.
+fn payload(body: &serde_json::Value) -> Result<&serde_json::Value, SecretsError> {
+    let data = body
+        .get("data")
+        .ok_or_else(|| SecretsError::Format("response has no data".to_string()))?;
+
+    // KV v2 nests the secret under data.data alongside metadata
+    Ok(match data.get("data") {
+        Some(inner) if data.get("metadata").is_some() => inner,
+        _ => data,
+    })
+}
+
+#[async_trait]
+impl SecretsProvider for VaultSecretsProvider {
+    fn name(&self) -> &'static str {
+        "vault"
+    }
+
+    async fn alpaca_credentials(&self) -> Result<AlpacaCredentials, SecretsError> {
+        let mut request = self
+            .client
+            .get(self.url())
+            .header("X-Vault-Token", self.token.expose());
+        if let Some(namespace) = &self.namespace {
+            request = request.header("X-Vault-Namespace", namespace);
+        }
+
+        let response = request
+            .send()
+            .await
+            .map_err(|e| SecretsError::Network(e.to_string()))?;
+        let status = response.status();
+        let text = response
+            .text()
+            .await
+            .map_err(|e| SecretsError::Network(e.to_string()))?;
+
+        if !status.is_success() {
+            return Err(SecretsError::Rejected {
+                status: status.as_u16(),
+                message: error_message(&text),
+            });
+        }
+
+        let body: serde_json::Value =
+            serde_json::from_str(&text).map_err(|e| SecretsError::Format(e.to_string()))?;
+        AlpacaCredentials::from_json(payload(&body)?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use wiremock::matchers::{header, method, path};
+    use wiremock::{Mock, MockServer, ResponseTemplate};
+
+    #[test]
+    fn unwraps_kv_v2_payload() {
+        let v2 = serde_json::json!({
+            "data": {"data": {"api_key": "k"}, "metadata": {"version": 3}}
+        });
+        assert_eq!(payload(&v2).unwrap()["api_key"], "k");
+
+        let v1 = serde_json::json!({"data": {"api_key": "k"}});
+        assert_eq!(payload(&v1).unwrap()["api_key"], "k");
+    }
+
+    #[tokio::test]
+    async fn reads_credentials_from_kv_secret() {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/v1/secret/data/cream/alpaca"))
+            .and(header("X-Vault-Token", "root-token"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "data": {
+                    "data": {"api_key": "vault-key", "api_secret": "vault-secret"},
+                    "metadata": {"version": 1}
+                }
+            })))
+            .mount(&server)
+            .await;
+
+        let provider = VaultSecretsProvider::new(
+            server.uri(),
+            Secret::new("root-token"),
+            "/secret/data/cream/alpaca",
+        )
+        .unwrap();
+
+        assert_eq!(
+            provider.alpaca_credentials().await.unwrap(),
+            AlpacaCredentials::new("vault-key", "vault-secret")
+        );
+    }
+
+    #[tokio::test]
+    async fn surfaces_permission_errors() {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .respond_with(
+                ResponseTemplate::new(403).set_body_string(r#"{"errors":["permission denied"]}"#),
+            )
+            .mount(&server)
+            .await;
+
+        let provider =
+            VaultSecretsProvider::new(server.uri(), Secret::new("bad"), "secret/data/x").unwrap();
+
+        assert!(matches!(
+            provider.alpaca_credentials().await,
+            Err(SecretsError::Rejected { status: 403, .. })
+        ));
+    }
+}
@@ -10,12 +10,14 @@ use tokio::sync::RwLock;
 use tonic::Streaming;
 use tonic::transport::{Channel, Endpoint};
 
+use crate::domain::shared::Symbol;
 use crate::infrastructure::grpc::proto::cream::v1::{
     ConnectionStatus, GetConnectionStatusRequest, StreamBarsRequest, StreamBarsResponse,
     StreamOptionQuotesRequest, StreamOptionQuotesResponse, StreamOptionTradesRequest,
     StreamOptionTradesResponse, StreamOrderUpdatesRequest, StreamOrderUpdatesResponse,
-    StreamQuotesRequest, StreamQuotesResponse, StreamTradesRequest, StreamTradesResponse,
-    stream_proxy_service_client::StreamProxyServiceClient,
+    StreamQuotesRequest, StreamQuotesResponse, StreamSignalsRequest, StreamSignalsResponse,
+    StreamTradesRequest, StreamTradesResponse, StreamTradingStatusRequest,
+    StreamTradingStatusResponse, stream_proxy_service_client::StreamProxyServiceClient,
 };
 
 use super::config::StreamProxyConfig;
@@ -147,7 +149,7 @@ impl StreamProxyClient {
     ) -> Result<Streaming<StreamQuotesResponse>, StreamProxyError> {
         let mut client = self.inner.read().await.clone();
         let request = StreamQuotesRequest {
-            symbols: symbols.iter().map(|s| (*s).to_string()).collect(),
+            symbols: symbols.iter().map(|s| Symbol::normalize(s)).collect(),
         };
 
         tracing::debug!(symbols = ?symbols, "Starting quote stream");
@@ -171,7 +173,7 @@ impl StreamProxyClient {
     ) -> Result<Streaming<StreamTradesResponse>, StreamProxyError> {
         let mut client = self.inner.read().await.clone();
         let request = StreamTradesRequest {
-            symbols: symbols.iter().map(|s| (*s).to_string()).collect(),
+            symbols: symbols.iter().map(|s| Symbol::normalize(s)).collect(),
         };
 
         tracing::debug!(symbols = ?symbols, "Starting trade stream");
@@ -195,7 +197,7 @@ impl StreamProxyClient {
     ) -> Result<Streaming<StreamBarsResponse>, StreamProxyError> {
         let mut client = self.inner.read().await.clone();
         let request = StreamBarsRequest {
-            symbols: symbols.iter().map(|s| (*s).to_string()).collect(),
+            symbols: symbols.iter().map(|s| Symbol::normalize(s)).collect(),
         };
 
         tracing::debug!(symbols = ?symbols, "Starting bar stream");
@@ -204,6 +206,30 @@ impl StreamProxyClient {
         Ok(response.into_inner())
     }
 
+    /// Stream trading status changes (halts, pauses, resumptions).
+    ///
+    /// # Arguments
+    ///
+    /// * `symbols` - Stock symbols to filter to (empty for all symbols)
+    ///
+    /// # Errors
+    ///
+    /// Returns error if stream creation fails.
+    pub async fn stream_trading_status(
+        &self,
+        symbols: &[&str],
+    ) -> Result<Streaming<StreamTradingStatusResponse>, StreamProxyError> {
+        let mut client = self.inner.read().await.clone();
+        let request = StreamTradingStatusRequest {
+            symbols: symbols.iter().map(|s| Symbol::normalize(s)).collect(),
+        };
+
+        tracing::debug!(symbols = ?symbols, "Starting trading status stream");
+
+        let response = client.stream_trading_status(request).await?;
+        Ok(response.into_inner())
+    }
+
     /// Stream real-time option quotes (OPRA feed).
     ///
     /// # Arguments
@@ -221,8 +247,8 @@ impl StreamProxyClient {
     ) -> Result<Streaming<StreamOptionQuotesResponse>, StreamProxyError> {
         let mut client = self.inner.read().await.clone();
         let request = StreamOptionQuotesRequest {
-            symbols: symbols.iter().map(|s| (*s).to_string()).collect(),
-            underlyings: underlyings.iter().map(|s| (*s).to_string()).collect(),
+            symbols: symbols.iter().map(|s| Symbol::normalize(s)).collect(),
+            underlyings: underlyings.iter().map(|s| Symbol::normalize(s)).collect(),
         };
 
         tracing::debug!(
@@ -252,8 +278,8 @@ impl StreamProxyClient {
     ) -> Result<Streaming<StreamOptionTradesResponse>, StreamProxyError> {
         let mut client = self.inner.read().await.clone();
         let request = StreamOptionTradesRequest {
-            symbols: symbols.iter().map(|s| (*s).to_string()).collect(),
-            underlyings: underlyings.iter().map(|s| (*s).to_string()).collect(),
+            symbols: symbols.iter().map(|s| Symbol::normalize(s)).collect(),
+            underlyings: underlyings.iter().map(|s| Symbol::normalize(s)).collect(),
         };
 
         tracing::debug!(
@@ -266,6 +292,31 @@ impl StreamProxyClient {
         Ok(response.into_inner())
     }
 
+    /// Stream microstructure signals (trade imbalance, tick direction and
+    /// quote stability) derived from the SIP feed.
+    ///
+    /// # Arguments
+    ///
+    /// * `symbols` - Stock symbols to subscribe to (empty for all symbols)
+    ///
+    /// # Errors
+    ///
+    /// Returns error if stream creation fails.
+    pub async fn stream_signals(
+        &self,
+        symbols: &[&str],
+    ) -> Result<Streaming<StreamSignalsResponse>, StreamProxyError> {
+        let mut client = self.inner.read().await.clone();
+        let request = StreamSignalsRequest {
+            symbols: symbols.iter().map(|s| Symbol::normalize(s)).collect(),
+        };
+
+        tracing::debug!(symbols = ?symbols, "Starting signal stream");
+
+        let response = client.stream_signals(request).await?;
+        Ok(response.into_inner())
+    }
+
     /// Stream real-time order updates (trade updates).
     ///
     /// # Arguments
@@ -284,7 +335,7 @@ impl StreamProxyClient {
         let mut client = self.inner.read().await.clone();
         let request = StreamOrderUpdatesRequest {
             order_ids: order_ids.iter().map(|s| (*s).to_string()).collect(),
-            symbols: symbols.iter().map(|s| (*s).to_string()).collect(),
+            symbols: symbols.iter().map(|s| Symbol::normalize(s)).collect(),
         };
 
         tracing::debug!(
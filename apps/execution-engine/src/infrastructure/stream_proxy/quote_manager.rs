@@ -4,19 +4,28 @@
 //! interface for the `PositionMonitorService`. This allows the execution engine
 //! to consume real-time quotes from the centralized stream proxy instead of
 //! maintaining its own WebSocket connections.
+//!
+//! The proxy streams the symbols requested when a stream is opened, so a
+//! change to the subscription list reopens the stream with the new list.
+//! Trades in subscribed stocks are aggregated into intraday bars.
 
 use std::collections::HashSet;
 use std::sync::Arc;
 
 use chrono::{TimeZone, Utc};
 use parking_lot::RwLock;
-use rust_decimal::Decimal;
-use tokio::sync::broadcast;
+use tokio::sync::{broadcast, watch};
 use tokio_util::sync::CancellationToken;
 
 use super::{StreamProxyClient, StreamProxyConfig, StreamProxyError};
+use crate::application::services::{
+    CandleAggregator, FeedSanitizer, TradingHalts, TradingStatusUpdate,
+};
+use crate::domain::shared::Timestamp;
+use crate::infrastructure::decimal::decimal_from_f64;
 use crate::infrastructure::grpc::proto::cream::v1::{
     ConnectionState, OptionQuoteUpdate as ProtoOptionQuote, StockQuote as ProtoStockQuote,
+    StockTrade as ProtoStockTrade, TradingStatus as ProtoTradingStatus,
 };
 use crate::infrastructure::websocket::{QuoteUpdate, TradeUpdate, WebSocketError};
 
@@ -72,12 +81,18 @@ pub struct ProxyQuoteManager {
     stock_subscriptions: Arc<RwLock<HashSet<String>>>,
     /// Subscribed options symbols.
     options_subscriptions: Arc<RwLock<HashSet<String>>>,
+    /// Bumped when the stock subscription list changes.
+    stock_changes: watch::Sender<u64>,
+    /// Bumped when the options subscription list changes.
+    options_changes: watch::Sender<u64>,
     /// Quote update sender.
     quote_tx: broadcast::Sender<QuoteUpdate>,
     /// Trade update sender.
     trade_tx: broadcast::Sender<TradeUpdate>,
     /// Whether connected to the proxy.
     connected: Arc<RwLock<bool>>,
+    /// Drops bad ticks before they are forwarded.
+    sanitizer: Arc<FeedSanitizer>,
     /// Cancellation token for graceful shutdown.
     shutdown: CancellationToken,
 }
@@ -94,13 +109,23 @@ impl ProxyQuoteManager {
             client: None,
             stock_subscriptions: Arc::new(RwLock::new(HashSet::new())),
             options_subscriptions: Arc::new(RwLock::new(HashSet::new())),
+            stock_changes: watch::Sender::new(0),
+            options_changes: watch::Sender::new(0),
             quote_tx,
             trade_tx,
             connected: Arc::new(RwLock::new(false)),
+            sanitizer: Arc::new(FeedSanitizer::default()),
             shutdown,
         }
     }
 
+    /// Screen quotes and trades with `sanitizer` instead of the defaults.
+    #[must_use]
+    pub fn with_feed_sanitizer(mut self, sanitizer: Arc<FeedSanitizer>) -> Self {
+        self.sanitizer = sanitizer;
+        self
+    }
+
     /// Connect to the stream proxy service.
     ///
     /// # Errors
@@ -209,12 +234,23 @@ impl ProxyQuoteManager {
         };
 
         let subscriptions = Arc::clone(&self.stock_subscriptions);
+        let changes = self.stock_changes.subscribe();
         let quote_tx = self.quote_tx.clone();
+        let sanitizer = Arc::clone(&self.sanitizer);
         let connected = Arc::clone(&self.connected);
         let shutdown = self.shutdown.clone();
 
         tokio::spawn(async move {
-            run_stock_quote_stream(client, subscriptions, quote_tx, connected, shutdown).await;
+            run_stock_quote_stream(
+                client,
+                subscriptions,
+                changes,
+                quote_tx,
+                sanitizer,
+                connected,
+                shutdown,
+            )
+            .await;
         });
     }
 
@@ -229,12 +265,60 @@ impl ProxyQuoteManager {
         };
 
         let subscriptions = Arc::clone(&self.options_subscriptions);
+        let changes = self.options_changes.subscribe();
         let quote_tx = self.quote_tx.clone();
+        let sanitizer = Arc::clone(&self.sanitizer);
         let connected = Arc::clone(&self.connected);
         let shutdown = self.shutdown.clone();
 
         tokio::spawn(async move {
-            run_options_quote_stream(client, subscriptions, quote_tx, connected, shutdown).await;
+            run_options_quote_stream(
+                client,
+                subscriptions,
+                changes,
+                quote_tx,
+                sanitizer,
+                connected,
+                shutdown,
+            )
+            .await;
+        });
+    }
+
+    /// Start streaming trading status changes into `halts`.
+    ///
+    /// Statuses are streamed for all symbols so a halt is known before the
+    /// first order in the symbol is submitted.
+    pub fn start_trading_status_stream(&self, halts: Arc<TradingHalts>) {
+        let Some(client) = self.client.clone() else {
+            tracing::warn!("Cannot start trading status stream: not connected to proxy");
+            return;
+        };
+
+        let shutdown = self.shutdown.clone();
+
+        tokio::spawn(async move {
+            run_trading_status_stream(client, halts, shutdown).await;
+        });
+    }
+
+    /// Start streaming trades in subscribed stocks into `candles`.
+    ///
+    /// The stream follows the stock subscriptions and is not opened while
+    /// there are none, since the proxy would send trades in every symbol.
+    pub fn start_trade_stream(&self, candles: Arc<CandleAggregator>) {
+        let Some(client) = self.client.clone() else {
+            tracing::warn!("Cannot start trade stream: not connected to proxy");
+            return;
+        };
+
+        let subscriptions = Arc::clone(&self.stock_subscriptions);
+        let changes = self.stock_changes.subscribe();
+        let sanitizer = Arc::clone(&self.sanitizer);
+        let shutdown = self.shutdown.clone();
+
+        tokio::spawn(async move {
+            run_trade_stream(client, subscriptions, changes, candles, sanitizer, shutdown).await;
         });
     }
 
@@ -245,11 +329,16 @@ impl ProxyQuoteManager {
     /// Returns error if not connected.
     #[allow(clippy::unused_async)]
     pub async fn subscribe_stock_quotes(&self, symbols: &[String]) -> Result<(), WebSocketError> {
-        {
+        let added = {
             let mut subs = self.stock_subscriptions.write();
-            for symbol in symbols {
-                subs.insert(symbol.clone());
-            }
+            symbols
+                .iter()
+                .filter(|symbol| subs.insert((*symbol).clone()))
+                .count()
+        };
+        if added > 0 {
+            self.stock_changes
+                .send_modify(|generation| *generation += 1);
         }
 
         tracing::info!(
@@ -267,11 +356,16 @@ impl ProxyQuoteManager {
     /// Returns error if not connected.
     #[allow(clippy::unused_async)]
     pub async fn subscribe_options_quotes(&self, symbols: &[String]) -> Result<(), WebSocketError> {
-        {
+        let added = {
             let mut subs = self.options_subscriptions.write();
-            for symbol in symbols {
-                subs.insert(symbol.clone());
-            }
+            symbols
+                .iter()
+                .filter(|symbol| subs.insert((*symbol).clone()))
+                .count()
+        };
+        if added > 0 {
+            self.options_changes
+                .send_modify(|generation| *generation += 1);
         }
 
         tracing::info!(
@@ -289,11 +383,13 @@ impl ProxyQuoteManager {
     /// This method currently does not return errors but reserves the ability to do so.
     #[allow(clippy::unused_async)]
     pub async fn unsubscribe_stock_quotes(&self, symbols: &[String]) -> Result<(), WebSocketError> {
-        {
+        let removed = {
             let mut subs = self.stock_subscriptions.write();
-            for symbol in symbols {
-                subs.remove(symbol);
-            }
+            symbols.iter().filter(|symbol| subs.remove(*symbol)).count()
+        };
+        if removed > 0 {
+            self.stock_changes
+                .send_modify(|generation| *generation += 1);
         }
 
         tracing::info!(
@@ -314,11 +410,13 @@ impl ProxyQuoteManager {
         &self,
         symbols: &[String],
     ) -> Result<(), WebSocketError> {
-        {
+        let removed = {
             let mut subs = self.options_subscriptions.write();
-            for symbol in symbols {
-                subs.remove(symbol);
-            }
+            symbols.iter().filter(|symbol| subs.remove(*symbol)).count()
+        };
+        if removed > 0 {
+            self.options_changes
+                .send_modify(|generation| *generation += 1);
         }
 
         tracing::info!(
@@ -404,8 +502,8 @@ fn convert_stock_quote(proto: &ProtoStockQuote) -> QuoteUpdate {
 
     QuoteUpdate {
         symbol: proto.symbol.clone(),
-        bid: Decimal::try_from(proto.bid_price).unwrap_or_default(),
-        ask: Decimal::try_from(proto.ask_price).unwrap_or_default(),
+        bid: decimal_from_f64(proto.bid_price).unwrap_or_default(),
+        ask: decimal_from_f64(proto.ask_price).unwrap_or_default(),
         bid_size: proto.bid_size,
         ask_size: proto.ask_size,
         timestamp,
@@ -422,8 +520,8 @@ fn convert_option_quote(proto: &ProtoOptionQuote) -> QuoteUpdate {
 
     QuoteUpdate {
         symbol: proto.symbol.clone(),
-        bid: Decimal::try_from(proto.bid_price).unwrap_or_default(),
-        ask: Decimal::try_from(proto.ask_price).unwrap_or_default(),
+        bid: decimal_from_f64(proto.bid_price).unwrap_or_default(),
+        ask: decimal_from_f64(proto.ask_price).unwrap_or_default(),
         bid_size: proto.bid_size,
         ask_size: proto.ask_size,
         timestamp,
@@ -431,20 +529,186 @@ fn convert_option_quote(proto: &ProtoOptionQuote) -> QuoteUpdate {
     }
 }
 
+/// Convert a protobuf trading status to a `TradingStatusUpdate`.
+fn convert_trading_status(proto: &ProtoTradingStatus) -> TradingStatusUpdate {
+    let timestamp = proto.timestamp.as_ref().map_or_else(Utc::now, |ts| {
+        Utc.timestamp_opt(ts.seconds, ts.nanos.unsigned_abs())
+            .unwrap()
+    });
+
+    TradingStatusUpdate {
+        symbol: proto.symbol.clone(),
+        status_code: proto.status_code.clone(),
+        status_message: proto.status_message.clone(),
+        reason_code: proto.reason_code.clone(),
+        reason_message: proto.reason_message.clone(),
+        timestamp: Timestamp::new(timestamp),
+    }
+}
+
+/// Add a protobuf stock trade to `candles` unless `sanitizer` rejects it.
+fn record_trade(candles: &CandleAggregator, sanitizer: &FeedSanitizer, proto: &ProtoStockTrade) {
+    let Some(price) = decimal_from_f64(proto.price) else {
+        return;
+    };
+    let timestamp = proto.timestamp.as_ref().map_or_else(Utc::now, |ts| {
+        Utc.timestamp_opt(ts.seconds, ts.nanos.unsigned_abs())
+            .unwrap()
+    });
+    let at = Timestamp::new(timestamp);
+    if sanitizer.check_trade(&proto.symbol, price, at).is_err() {
+        return;
+    }
+
+    candles.record_trade(&proto.symbol, price, i64::from(proto.size), at);
+}
+
+/// Run the stock trade stream from the proxy.
+async fn run_trade_stream(
+    client: Arc<StreamProxyClient>,
+    subscriptions: Arc<RwLock<HashSet<String>>>,
+    mut changes: watch::Receiver<u64>,
+    candles: Arc<CandleAggregator>,
+    sanitizer: Arc<FeedSanitizer>,
+    shutdown: CancellationToken,
+) {
+    'stream: loop {
+        changes.borrow_and_update();
+        let symbols: Vec<String> = {
+            let subs = subscriptions.read();
+            subs.iter().cloned().collect()
+        };
+        candles.retain(&symbols);
+
+        if !symbols.is_empty() {
+            let symbols_ref: Vec<&str> = symbols.iter().map(String::as_str).collect();
+            match client.stream_trades(&symbols_ref).await {
+                Ok(mut stream) => {
+                    tracing::info!(symbols = ?symbols, "Trade stream started");
+
+                    loop {
+                        tokio::select! {
+                            result = stream.message() => {
+                                match result {
+                                    Ok(Some(response)) => {
+                                        if let Some(trade) = response.trade {
+                                            record_trade(&candles, &sanitizer, &trade);
+                                        }
+                                    }
+                                    Ok(None) => {
+                                        tracing::warn!("Trade stream ended");
+                                        break;
+                                    }
+                                    Err(e) => {
+                                        tracing::warn!(error = %e, "Trade stream error");
+                                        break;
+                                    }
+                                }
+                            }
+                            Ok(()) = changes.changed() => {
+                                tracing::info!("Stock subscriptions changed, reopening trade stream");
+                                continue 'stream;
+                            }
+                            () = shutdown.cancelled() => {
+                                tracing::info!("Trade stream shutdown requested");
+                                return;
+                            }
+                        }
+                    }
+                }
+                Err(e) => {
+                    tracing::warn!(error = %e, "Failed to start trade stream");
+                }
+            }
+        }
+
+        // Wait for new subscriptions or retry; the quote streams own the reconnect
+        tokio::select! {
+            Ok(()) = changes.changed() => {}
+            () = tokio::time::sleep(std::time::Duration::from_secs(5)) => {}
+            () = shutdown.cancelled() => {
+                tracing::info!("Trade stream shutting down");
+                return;
+            }
+        }
+    }
+}
+
+/// Run the trading status stream from the proxy.
+async fn run_trading_status_stream(
+    client: Arc<StreamProxyClient>,
+    halts: Arc<TradingHalts>,
+    shutdown: CancellationToken,
+) {
+    loop {
+        if shutdown.is_cancelled() {
+            tracing::info!("Trading status stream shutting down");
+            break;
+        }
+
+        match client.stream_trading_status(&[]).await {
+            Ok(mut stream) => {
+                tracing::info!("Trading status stream started");
+
+                loop {
+                    tokio::select! {
+                        result = stream.message() => {
+                            match result {
+                                Ok(Some(response)) => {
+                                    if let Some(status) = response.status {
+                                        halts.apply(&convert_trading_status(&status));
+                                    }
+                                }
+                                Ok(None) => {
+                                    tracing::warn!("Trading status stream ended");
+                                    break;
+                                }
+                                Err(e) => {
+                                    tracing::warn!(error = %e, "Trading status stream error");
+                                    break;
+                                }
+                            }
+                        }
+                        () = shutdown.cancelled() => {
+                            tracing::info!("Trading status stream shutdown requested");
+                            return;
+                        }
+                    }
+                }
+            }
+            Err(e) => {
+                tracing::warn!(error = %e, "Failed to start trading status stream");
+            }
+        }
+
+        // Wait before reconnecting; the quote streams own the reconnect
+        tokio::select! {
+            () = tokio::time::sleep(std::time::Duration::from_secs(5)) => {}
+            () = shutdown.cancelled() => {
+                tracing::info!("Trading status stream shutdown during reconnect delay");
+                return;
+            }
+        }
+    }
+}
+
 /// Run the stock quote stream from the proxy.
 async fn run_stock_quote_stream(
     client: Arc<StreamProxyClient>,
     subscriptions: Arc<RwLock<HashSet<String>>>,
+    mut changes: watch::Receiver<u64>,
     quote_tx: broadcast::Sender<QuoteUpdate>,
+    sanitizer: Arc<FeedSanitizer>,
     connected: Arc<RwLock<bool>>,
     shutdown: CancellationToken,
 ) {
-    loop {
+    'stream: loop {
         if shutdown.is_cancelled() {
             tracing::info!("Stock quote stream shutting down");
             break;
         }
 
+        changes.borrow_and_update();
         let symbols: Vec<String> = {
             let subs = subscriptions.read();
             subs.iter().cloned().collect()
@@ -469,7 +733,9 @@ async fn run_stock_quote_stream(
 
                                         if is_subscribed {
                                             let update = convert_stock_quote(&quote);
-                                            let _ = quote_tx.send(update);
+                                            if sanitizer.check_quote(&update).is_ok() {
+                                                let _ = quote_tx.send(update);
+                                            }
                                         }
                                     }
                                 }
@@ -484,6 +750,10 @@ async fn run_stock_quote_stream(
                                 }
                             }
                         }
+                        Ok(()) = changes.changed() => {
+                            tracing::info!("Stock subscriptions changed, reopening quote stream");
+                            continue 'stream;
+                        }
                         () = shutdown.cancelled() => {
                             tracing::info!("Stock quote stream shutdown requested");
                             return;
@@ -520,16 +790,19 @@ async fn run_stock_quote_stream(
 async fn run_options_quote_stream(
     client: Arc<StreamProxyClient>,
     subscriptions: Arc<RwLock<HashSet<String>>>,
+    mut changes: watch::Receiver<u64>,
     quote_tx: broadcast::Sender<QuoteUpdate>,
+    sanitizer: Arc<FeedSanitizer>,
     connected: Arc<RwLock<bool>>,
     shutdown: CancellationToken,
 ) {
-    loop {
+    'stream: loop {
         if shutdown.is_cancelled() {
             tracing::info!("Options quote stream shutting down");
             break;
         }
 
+        changes.borrow_and_update();
         let symbols: Vec<String> = {
             let subs = subscriptions.read();
             subs.iter().cloned().collect()
@@ -554,7 +827,9 @@ async fn run_options_quote_stream(
 
                                         if is_subscribed {
                                             let update = convert_option_quote(&quote);
-                                            let _ = quote_tx.send(update);
+                                            if sanitizer.check_quote(&update).is_ok() {
+                                                let _ = quote_tx.send(update);
+                                            }
                                         }
                                     }
                                 }
@@ -569,6 +844,10 @@ async fn run_options_quote_stream(
                                 }
                             }
                         }
+                        Ok(()) = changes.changed() => {
+                            tracing::info!("Options subscriptions changed, reopening quote stream");
+                            continue 'stream;
+                        }
                         () = shutdown.cancelled() => {
                             tracing::info!("Options quote stream shutdown requested");
                             return;
@@ -627,6 +906,14 @@ impl QuoteProviderPort for ProxyQuoteManager {
         self.unsubscribe_options_quotes(symbols).await
     }
 
+    fn stock_subscriptions(&self) -> Vec<String> {
+        self.stock_subscriptions()
+    }
+
+    fn options_subscriptions(&self) -> Vec<String> {
+        self.options_subscriptions()
+    }
+
     fn is_connected(&self) -> bool {
         self.is_connected()
     }
@@ -635,6 +922,7 @@ impl QuoteProviderPort for ProxyQuoteManager {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use rust_decimal::Decimal;
 
     #[test]
     fn config_default() {
@@ -694,6 +982,37 @@ mod tests {
         assert!(subs.contains(&"AAPL240315C00172500".to_string()));
     }
 
+    #[tokio::test]
+    async fn only_actual_changes_reopen_the_stream() {
+        let manager =
+            ProxyQuoteManager::new(ProxyQuoteManagerConfig::default(), CancellationToken::new());
+        let mut changes = manager.stock_changes.subscribe();
+
+        manager
+            .subscribe_stock_quotes(&["AAPL".to_string()])
+            .await
+            .unwrap();
+        assert!(changes.has_changed().unwrap());
+        changes.borrow_and_update();
+
+        // Already subscribed, and never subscribed
+        manager
+            .subscribe_stock_quotes(&["AAPL".to_string()])
+            .await
+            .unwrap();
+        manager
+            .unsubscribe_stock_quotes(&["MSFT".to_string()])
+            .await
+            .unwrap();
+        assert!(!changes.has_changed().unwrap());
+
+        manager
+            .unsubscribe_stock_quotes(&["AAPL".to_string()])
+            .await
+            .unwrap();
+        assert!(changes.has_changed().unwrap());
+    }
+
     #[test]
     fn convert_stock_quote_basic() {
         let proto = ProtoStockQuote {
@@ -711,8 +1030,8 @@ mod tests {
 
         let update = convert_stock_quote(&proto);
         assert_eq!(update.symbol, "AAPL");
-        assert_eq!(update.bid, Decimal::try_from(185.50).unwrap());
-        assert_eq!(update.ask, Decimal::try_from(185.52).unwrap());
+        assert_eq!(update.bid, Decimal::new(18550, 2));
+        assert_eq!(update.ask.to_string(), "185.52");
         assert!(!update.is_option);
     }
 
@@ -732,7 +1051,62 @@ mod tests {
 
         let update = convert_option_quote(&proto);
         assert_eq!(update.symbol, "AAPL240315C00172500");
-        assert_eq!(update.bid, Decimal::try_from(2.84).unwrap());
+        assert_eq!(update.bid.to_string(), "2.84");
         assert!(update.is_option);
     }
+
+    #[test]
+    fn convert_trading_status_basic() {
+        let proto = ProtoTradingStatus {
+            symbol: "AAPL".to_string(),
+            timestamp: Some(prost_types::Timestamp {
+                seconds: 1_700_000_000,
+                nanos: 0,
+            }),
+            status_code: "H".to_string(),
+            status_message: "Trading Halt".to_string(),
+            reason_code: "LUDP".to_string(),
+            reason_message: "Volatility Trading Pause".to_string(),
+            tape: "C".to_string(),
+        };
+
+        let update = convert_trading_status(&proto);
+        assert_eq!(update.symbol, "AAPL");
+        assert_eq!(update.status_code, "H");
+        assert_eq!(update.reason_code, "LUDP");
+        assert_eq!(update.timestamp.unix_seconds(), 1_700_000_000);
+    }
+
+    #[test]
+    fn record_trade_builds_bars() {
+        use crate::application::ports::BarTimeframe;
+        use crate::application::ports::SimulatedClock;
+        use crate::application::services::CandleAggregatorConfig;
+
+        let candles = CandleAggregator::new(CandleAggregatorConfig::default());
+        let now = Timestamp::parse("2023-11-14T22:16:00Z").unwrap();
+        let sanitizer = FeedSanitizer::default().with_clock(Arc::new(SimulatedClock::new(now)));
+        let trades = [
+            (1_700_000_040, 185.5),
+            (1_700_000_070, 0.0),
+            (1_700_000_100, 186.0),
+        ];
+        for (seconds, price) in trades {
+            let proto = ProtoStockTrade {
+                symbol: "AAPL".to_string(),
+                timestamp: Some(prost_types::Timestamp { seconds, nanos: 0 }),
+                price,
+                size: 100,
+                ..ProtoStockTrade::default()
+            };
+            record_trade(&candles, &sanitizer, &proto);
+        }
+
+        let bars = candles.bars("AAPL", BarTimeframe::OneMinute, 10);
+        assert_eq!(bars.len(), 2);
+        assert_eq!(bars[0].close.to_string(), "185.5");
+        assert_eq!(bars[1].close.to_string(), "186");
+        assert_eq!(bars[1].volume, 100);
+        assert_eq!(sanitizer.rejections()["AAPL"].non_positive_price, 1);
+    }
 }
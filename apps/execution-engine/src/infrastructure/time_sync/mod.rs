@@ -0,0 +1,8 @@
+//! Time Synchronization
+//!
+//! [`SntpTimeSource`] measures the host clock against NTP servers with a
+//! single SNTP request per check, for the clock skew monitor.
+
+mod sntp;
+
+pub use sntp::{DEFAULT_NTP_SERVER, SntpTimeSource};
@@ -0,0 +1,200 @@
+//! SNTP (RFC 4330) client time source.
+
+use std::time::Duration;
+
+use async_trait::async_trait;
+use chrono::Utc;
+use tokio::net::UdpSocket;
+
+use crate::application::ports::{ClockOffset, TimeSourceError, TimeSourcePort};
+
+/// NTP server queried when none is configured.
+pub const DEFAULT_NTP_SERVER: &str = "pool.ntp.org";
+
+const NTP_PORT: u16 = 123;
+const PACKET_LEN: usize = 48;
+/// Seconds from the NTP epoch (1900) to the Unix epoch (1970).
+const NTP_UNIX_OFFSET_SECS: i64 = 2_208_988_800;
+
+/// Time source querying NTP servers over SNTP.
+///
+/// Servers are tried in order and the first answer is used.
+#[derive(Debug, Clone)]
+pub struct SntpTimeSource {
+    servers: Vec<String>,
+    timeout: Duration,
+}
+
+impl SntpTimeSource {
+    /// Create a source querying `servers` (`host` or `host:port`).
+    #[must_use]
+    pub const fn new(servers: Vec<String>) -> Self {
+        Self {
+            servers,
+            timeout: Duration::from_secs(2),
+        }
+    }
+
+    /// Give up on a server after `timeout`.
+    #[must_use]
+    pub const fn with_timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = timeout;
+        self
+    }
+
+    async fn query(&self, server: &str) -> Result<ClockOffset, String> {
+        let address = if server.contains(':') {
+            server.to_string()
+        } else {
+            format!("{server}:{NTP_PORT}")
+        };
+        let socket = UdpSocket::bind("0.0.0.0:0")
+            .await
+            .map_err(|e| e.to_string())?;
+        socket.connect(&address).await.map_err(|e| e.to_string())?;
+
+        // LI 0, version 4, mode 3 (client)
+        let mut request = [0u8; PACKET_LEN];
+        request[0] = 0x23;
+        let sent_at = Utc::now().timestamp_micros();
+        socket.send(&request).await.map_err(|e| e.to_string())?;
+
+        let mut reply = [0u8; PACKET_LEN];
+        let len = tokio::time::timeout(self.timeout, socket.recv(&mut reply))
+            .await
+            .map_err(|_| format!("no reply within {}ms", self.timeout.as_millis()))?
+            .map_err(|e| e.to_string())?;
+        let received_at = Utc::now().timestamp_micros();
+
+        let (offset_us, round_trip_us) = measure(&reply[..len], sent_at, received_at)?;
+        Ok(ClockOffset {
+            source: server.to_string(),
+            offset_ms: offset_us / 1_000,
+            round_trip_ms: round_trip_us / 1_000,
+        })
+    }
+}
+
+#[async_trait]
+impl TimeSourcePort for SntpTimeSource {
+    async fn offset(&self) -> Result<ClockOffset, TimeSourceError> {
+        let mut errors = Vec::new();
+        for server in &self.servers {
+            match self.query(server).await {
+                Ok(offset) => return Ok(offset),
+                Err(e) => errors.push(format!("{server}: {e}")),
+            }
+        }
+        Err(TimeSourceError::Unavailable {
+            message: if errors.is_empty() {
+                "no NTP servers configured".to_string()
+            } else {
+                errors.join("; ")
+            },
+        })
+    }
+}
+
+/// Clock offset and round trip, in microseconds, from a server reply to a
+/// request sent at `sent_at` and answered at `received_at` (Unix micros).
+fn measure(reply: &[u8], sent_at: i64, received_at: i64) -> Result<(i64, i64), String> {
+    if reply.len() < PACKET_LEN {
+        return Err(format!("short reply of {} bytes", reply.len()));
+    }
+    if reply[0] & 0x07 != 4 {
+        return Err("reply is not from a server".to_string());
+    }
+    if reply[1] == 0 {
+        // Stratum 0 is a kiss-o'-death telling the client to back off
+        return Err("server refused the request".to_string());
+    }
+
+    let server_received = ntp_micros(&reply[32..40]);
+    let server_sent = ntp_micros(&reply[40..48]);
+    if server_sent == 0 {
+        return Err("reply has no transmit time".to_string());
+    }
+
+    let offset = i64::midpoint(server_received - sent_at, server_sent - received_at);
+    let round_trip = (received_at - sent_at) - (server_sent - server_received);
+    Ok((offset, round_trip.max(0)))
+}
+
+/// Unix microseconds of a 64-bit NTP timestamp, or 0 if it is unset.
+fn ntp_micros(bytes: &[u8]) -> i64 {
+    let seconds = u32::from_be_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]);
+    let fraction = u32::from_be_bytes([bytes[4], bytes[5], bytes[6], bytes[7]]);
+    if seconds == 0 && fraction == 0 {
+        return 0;
+    }
+
+    // Timestamps with the high bit clear are in era 1, which starts in 2036
+    let seconds = if seconds & 0x8000_0000 == 0 {
+        i64::from(seconds) + (1_i64 << 32)
+    } else {
+        i64::from(seconds)
+    };
+    let micros = (u64::from(fraction) * 1_000_000) >> 32;
+    (seconds - NTP_UNIX_OFFSET_SECS) * 1_000_000 + i64::try_from(micros).unwrap_or_default()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// NTP timestamp bytes for `unix_micros`.
+    fn ntp_bytes(unix_micros: i64) -> [u8; 8] {
+        let seconds = u32::try_from(unix_micros / 1_000_000 + NTP_UNIX_OFFSET_SECS).unwrap();
+        let fraction = u32::try_from(((unix_micros % 1_000_000) << 32) / 1_000_000).unwrap();
+        let mut bytes = [0u8; 8];
+        bytes[..4].copy_from_slice(&seconds.to_be_bytes());
+        bytes[4..].copy_from_slice(&fraction.to_be_bytes());
+        bytes
+    }
+
+    fn reply(server_received: i64, server_sent: i64) -> [u8; PACKET_LEN] {
+        let mut reply = [0u8; PACKET_LEN];
+        reply[0] = 0x24;
+        reply[1] = 2;
+        reply[32..40].copy_from_slice(&ntp_bytes(server_received));
+        reply[40..48].copy_from_slice(&ntp_bytes(server_sent));
+        reply
+    }
+
+    #[test]
+    fn measures_offset_and_round_trip() {
+        let sent = 1_772_000_000_000_000;
+        // Host is 250ms behind; 20ms each way and 1ms at the server
+        let server_received = sent + 20_000 + 250_000;
+        let server_sent = server_received + 1_000;
+        let received = sent + 41_000;
+
+        let (offset, round_trip) =
+            measure(&reply(server_received, server_sent), sent, received).unwrap();
+        assert!((offset - 250_000).abs() <= 1, "offset {offset}");
+        assert!((round_trip - 40_000).abs() <= 1, "round trip {round_trip}");
+
+        let mut refused = reply(server_received, server_sent);
+        refused[1] = 0;
+        assert!(measure(&refused, sent, received).is_err());
+        assert!(measure(&[0x24; 12], sent, received).is_err());
+    }
+
+    #[tokio::test]
+    async fn queries_a_server() {
+        let server = UdpSocket::bind("127.0.0.1:0").await.unwrap();
+        let address = server.local_addr().unwrap().to_string();
+        tokio::spawn(async move {
+            let mut request = [0u8; PACKET_LEN];
+            let (_, client) = server.recv_from(&mut request).await.unwrap();
+            let now = Utc::now().timestamp_micros() + 2_000_000;
+            server.send_to(&reply(now, now), client).await.unwrap();
+        });
+
+        let source = SntpTimeSource::new(vec!["127.0.0.1:9".to_string(), address.clone()])
+            .with_timeout(Duration::from_millis(200));
+        let offset = source.offset().await.unwrap();
+        assert_eq!(offset.source, address);
+        assert!((1_900..=2_100).contains(&offset.offset_ms), "{offset:?}");
+    }
+}
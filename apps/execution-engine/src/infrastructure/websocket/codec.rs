@@ -18,10 +18,10 @@ struct RawStockQuote {
     symbol: String,
     /// Bid price.
     #[serde(rename = "bp")]
-    bid_price: f64,
+    bid_price: Decimal,
     /// Ask price.
     #[serde(rename = "ap")]
-    ask_price: f64,
+    ask_price: Decimal,
     /// Bid size.
     #[serde(rename = "bs")]
     bid_size: i32,
@@ -45,10 +45,10 @@ struct RawOptionsQuote {
     symbol: String,
     /// Bid price.
     #[serde(rename = "bp")]
-    bid_price: f64,
+    bid_price: Decimal,
     /// Ask price.
     #[serde(rename = "ap")]
-    ask_price: f64,
+    ask_price: Decimal,
     /// Bid size.
     #[serde(rename = "bs")]
     bid_size: i32,
@@ -146,8 +146,8 @@ pub fn parse_stock_quote(json: &str) -> Result<Option<QuoteUpdate>, WebSocketErr
 
             return Ok(Some(QuoteUpdate {
                 symbol: quote.symbol,
-                bid: decimal_from_f64(quote.bid_price),
-                ask: decimal_from_f64(quote.ask_price),
+                bid: quote.bid_price,
+                ask: quote.ask_price,
                 bid_size: quote.bid_size,
                 ask_size: quote.ask_size,
                 timestamp,
@@ -184,8 +184,8 @@ pub fn parse_options_quote(json: &str) -> Result<Option<QuoteUpdate>, WebSocketE
 
             return Ok(Some(QuoteUpdate {
                 symbol: quote.symbol,
-                bid: decimal_from_f64(quote.bid_price),
-                ask: decimal_from_f64(quote.ask_price),
+                bid: quote.bid_price,
+                ask: quote.ask_price,
                 bid_size: quote.bid_size,
                 ask_size: quote.ask_size,
                 timestamp,
@@ -222,14 +222,13 @@ pub fn parse_trade_update(json: &str) -> Result<Option<TradeUpdate>, WebSocketEr
     let filled_qty = order
         .filled_qty
         .as_deref()
-        .and_then(|s| s.parse::<f64>().ok())
-        .map_or(Decimal::ZERO, decimal_from_f64);
+        .and_then(|s| s.parse::<Decimal>().ok())
+        .unwrap_or(Decimal::ZERO);
 
     let avg_fill_price = order
         .avg_fill_price
         .as_deref()
-        .and_then(|s| s.parse::<f64>().ok())
-        .map(decimal_from_f64);
+        .and_then(|s| s.parse::<Decimal>().ok());
 
     let timestamp = order
         .filled_at
@@ -294,11 +293,6 @@ fn parse_timestamp(s: &str) -> Result<DateTime<Utc>, WebSocketError> {
         })
 }
 
-/// Convert f64 to Decimal with reasonable precision.
-fn decimal_from_f64(value: f64) -> Decimal {
-    Decimal::try_from(value).unwrap_or(Decimal::ZERO)
-}
-
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -312,8 +306,8 @@ mod tests {
 
         let quote = result.unwrap();
         assert_eq!(quote.symbol, "AAPL");
-        assert_eq!(quote.bid, Decimal::try_from(185.50).unwrap());
-        assert_eq!(quote.ask, Decimal::try_from(185.52).unwrap());
+        assert_eq!(quote.bid, Decimal::new(18550, 2));
+        assert_eq!(quote.ask.to_string(), "185.52");
         assert_eq!(quote.bid_size, 100);
         assert_eq!(quote.ask_size, 200);
         assert!(!quote.is_option);
@@ -703,6 +703,14 @@ impl QuoteProviderPort for WebSocketManager {
         self.unsubscribe_options_quotes(symbols).await
     }
 
+    fn stock_subscriptions(&self) -> Vec<String> {
+        self.stock_subscriptions()
+    }
+
+    fn options_subscriptions(&self) -> Vec<String> {
+        self.options_subscriptions()
+    }
+
     fn is_connected(&self) -> bool {
         self.is_connected()
     }
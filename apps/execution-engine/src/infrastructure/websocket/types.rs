@@ -6,6 +6,7 @@ use chrono::{DateTime, Utc};
 use rust_decimal::Decimal;
 use thiserror::Error;
 
+use crate::domain::shared::Timestamp;
 use crate::infrastructure::broker::alpaca::AlpacaEnvironment;
 
 /// WebSocket connection configuration.
@@ -142,7 +143,13 @@ impl QuoteUpdate {
     /// Check if the quote is stale (older than threshold).
     #[must_use]
     pub fn is_stale(&self, max_age: Duration) -> bool {
-        let age = Utc::now().signed_duration_since(self.timestamp);
+        self.is_stale_at(Timestamp::now(), max_age)
+    }
+
+    /// Check if the quote is older than `max_age` as of `now`.
+    #[must_use]
+    pub fn is_stale_at(&self, now: Timestamp, max_age: Duration) -> bool {
+        let age = now.as_datetime().signed_duration_since(self.timestamp);
         age > chrono::Duration::from_std(max_age).unwrap_or(chrono::Duration::MAX)
     }
 }
@@ -64,6 +64,10 @@ pub mod application;
 /// Infrastructure layer - Adapters and external integrations.
 pub mod infrastructure;
 
+/// Test utilities - Wire-level mocks of external services.
+#[cfg(any(test, feature = "test-utils"))]
+pub mod testing;
+
 // =============================================================================
 // Re-exports
 // =============================================================================
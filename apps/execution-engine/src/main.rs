@@ -2,17 +2,32 @@
 //!
 //! Starts the Cream execution engine.
 //!
+//! # Exit Codes
+//!
+//! - `0`: Clean shutdown
+//! - `78`: Invalid configuration
+//! - `77`: The broker rejected the API credentials
+//! - `75`: Startup recovery failed (crash snapshot unreadable, or
+//!   reconciliation did not succeed within `RECOVERY_TIMEOUT_SECS`)
+//! - `1`: Any other fatal error
+//!
+//! A fatal error also writes a crash snapshot (working orders, open
+//! positions and recent audit events) that the next start restores.
+//!
 //! # Usage
 //!
 //! ```bash
 //! cargo run --bin execution-engine
+//!
+//! # Print the merged CONFIG_PATH file and overlays, secrets redacted
+//! cargo run --bin execution-engine -- --print-effective-config
 //! ```
 //!
 //! # Environment Variables
 //!
 //! ## Required
-//! - `ALPACA_KEY`: Broker API key
-//! - `ALPACA_SECRET`: Broker API secret
+//! - `ALPACA_KEY`: Broker API key (with the `env` secrets provider)
+//! - `ALPACA_SECRET`: Broker API secret (with the `env` secrets provider)
 //! - `CREAM_ENV`: PAPER | LIVE
 //! - `STREAM_PROXY_ENDPOINT`: Stream proxy gRPC endpoint
 //!
@@ -20,33 +35,230 @@
 //! - `HTTP_PORT`: HTTP server port (default: 50051)
 //! - `GRPC_PORT`: gRPC server port (default: 50053)
 //! - `POSITION_MONITOR_ENABLED`: Enable position monitoring (default: true)
+//! - `FEED_WATCHLIST`: Symbols kept subscribed on the quote feed besides
+//!   positions and working orders (e.g. `SPY,QQQ`)
+//! - `FEED_RECONCILE_INTERVAL_SECS`: Time between feed subscription
+//!   reconciliation passes (default: 30)
+//! - `WATCHLISTS_PATH`: File for watchlists managed over the HTTP API
+//!   (default: `data/watchlists.json`)
+//! - `LIVE_BAR_HISTORY`: Completed 1m/5m bars kept per symbol from the live
+//!   trade feed (default: 390)
+//! - `FEED_MAX_MOVE_PCT`: Largest move of a stock quote or trade from the
+//!   last accepted price before it is dropped as an outlier, in percent,
+//!   0 disables the check (default: 10)
+//! - `FEED_MAX_OPTION_MOVE_PCT`: Same for option quotes (default: 50)
+//! - `FEED_OUTLIER_CONFIRMATIONS`: Consecutive outliers that establish a new
+//!   price level (default: 3)
+//! - `FEED_MAX_TICK_AGE_SECS`: Oldest tick accepted from the live feed (default: 300)
+//! - `FEED_MAX_CLOCK_DRIFT_SECS`: Furthest in the future a tick may be
+//!   stamped (default: 5)
+//! - `SCHEDULED_PLANS_PATH`: File for pending scheduled plans (default: `data/scheduled_plans.json`)
+//! - `POSITION_LIFECYCLES_PATH`: File for position lifecycle records
+//!   (default: `data/position_lifecycles.json`)
+//! - `ORDER_HISTORY_PATH`: Journal of finished orders and their fills
+//!   (default: `data/order_history.jsonl`)
+//! - `EVENT_WEBHOOK_URL`: Endpoint order events are posted to; enables the
+//!   durable event outbox
+//! - `EVENT_OUTBOX_PATH`: File for events awaiting delivery to the webhook
+//!   (default: `data/event_outbox.json`)
+//! - `STALE_ORDER_ACTION`: CANCEL | RECONFIRM | IGNORE for prior-cycle orders (default: CANCEL)
+//! - `IDEMPOTENCY_TTL_SECS`: How long Idempotency-Key responses are replayed (default: 86400)
+//! - `SUBMIT_LATENCY_BUDGET_MS`: Longest a pre-submission quote lookup may
+//!   take before the order falls back to its default tactic (default: unlimited)
+//! - `QUOTE_CACHE_TTL_MS`: How long quotes fetched for order checks are
+//!   reused, 0 disables the cache (default: 2000)
+//! - `PRICE_SANITY_EQUITY_PCT`: Furthest an equity limit price may be from
+//!   the market, in percent, 0 disables the check (default: 10)
+//! - `PRICE_SANITY_OPTION_PCT`: Same for options (default: 50)
+//! - `OFF_TICK_PRICES`: ROUND | REJECT for prices off the tick grid (default: ROUND)
+//! - `LOT_MATCHING`: FIFO | LIFO for matching closing fills to tax lots (default: FIFO)
+//! - `METRICS_STRATEGY_TAGS`: Comma-separated strategy tags given their own
+//!   `strategy` metric label; other tags are labelled `other`
+//! - `API_KEYS_PATH`: JSON file of API keys and roles; enables HTTP and gRPC authentication
+//! - `GRPC_TLS_CERT_PATH`: PEM certificate chain; enables TLS on the gRPC server
+//! - `GRPC_TLS_KEY_PATH`: PEM private key (required with `GRPC_TLS_CERT_PATH`)
+//! - `GRPC_TLS_CLIENT_CA_PATH`: PEM CA bundle; requires client certificates (mTLS)
+//! - `GRPC_TLS_RELOAD_SECS`: Certificate change poll interval (default: 60)
+//! - `CONCENTRATION_GROUPS_PATH`: JSON file mapping symbols to sectors and
+//!   correlation buckets (optional; enables concentration limits)
+//! - `UNIVERSE_CONSTITUENTS_PATH`: CSV of point-in-time index membership
+//!   (`symbol,added,removed`); entries in symbols that are not members today
+//!   are rejected
+//! - `KILL_SWITCH`: `true` starts with trading disabled (default: false)
+//! - `KILL_SWITCH_FILE`: Trading is disabled while this file exists
+//! - `KILL_SWITCH_FLATTEN`: Cancel open orders and close all positions when
+//!   the kill switch trips (default: false)
+//! - `NTP_SERVERS`: Comma-separated NTP servers the host clock is checked
+//!   against (default: `pool.ntp.org`)
+//! - `CLOCK_SKEW_MAX_MS`: Host clock offset that raises an alert (default: 500)
+//! - `CLOCK_SKEW_CHECK_SECS`: Time between clock checks after the startup
+//!   check, 0 disables clock checks (default: 600)
+//! - `CLOCK_SKEW_HALT`: Trip the kill switch when the clock is skewed, LIVE
+//!   only (default: false)
+//! - `CONFIG_PATH`: `config.yaml` with constraint limits, instrument lists,
+//!   log level, log files, execution profiles and the reconciliation
+//!   interval; reloaded on SIGHUP or
+//!   `POST /api/v1/admin/reload-config`
+//! - `CONFIG_PROFILE`: Overlay merged over `CONFIG_PATH` from
+//!   `<profile>.yaml` in the same directory (default: `CREAM_ENV` in lower
+//!   case)
+//! - `STATE_BUNDLE_KEY`: Key signing state bundles; enables
+//!   `GET`/`POST /api/v1/admin/state` for moving state between hosts
+//! - `SHUTDOWN_SNAPSHOT_PATH`: File the state bundle is written to on
+//!   shutdown (requires `STATE_BUNDLE_KEY`)
+//! - `CRASH_SNAPSHOT_PATH`: File the crash snapshot is written to on a fatal
+//!   error and restored from on start (default: `data/crash_snapshot.json`)
+//! - `CRASH_SNAPSHOT_AUDIT_EVENTS`: Recent audit events kept in the crash
+//!   snapshot (default: 200)
+//! - `RECOVERY_TIMEOUT_SECS`: Longest startup reconciliation may keep failing
+//!   before the engine exits (default: unlimited)
+//! - `HA_ENABLED`: `true` elects a leader among instances sharing an account
+//!   through a Postgres advisory lock; standbys do not trade (default: false)
+//! - `DATABASE_URL`: Postgres holding the leader lock (required with HA)
+//! - `HA_LOCK_KEY`: Advisory lock key (default: one per environment)
+//! - `HA_HEARTBEAT_SECS`: Leader lock check and standby retry interval
+//!   (default: 5)
+//! - `HA_TAKEOVER_DELAY_SECS`: Wait between acquiring the lock and trading,
+//!   longer than the heartbeat (default: 10)
+//! - `ALPACA_OAUTH_CLIENT_ID`, `ALPACA_OAUTH_CLIENT_SECRET`,
+//!   `ALPACA_OAUTH_REDIRECT_URI`: Alpaca OAuth application; enables
+//!   connecting users' accounts through `/api/v1/admin/broker-accounts`
+//! - `ALPACA_OAUTH_TOKENS_DIR`: Directory of per-user OAuth tokens
+//!   (default: `data/alpaca_oauth`)
+//! - `ALPACA_OAUTH_USER`: Trade this connected user's account with their
+//!   OAuth token instead of `ALPACA_KEY` (requires the OAuth application)
+//! - `SECRETS_PROVIDER`: `env` | `vault` | `aws` source of Alpaca
+//!   credentials (default: env)
+//! - `VAULT_ADDR`, `VAULT_TOKEN`, `VAULT_SECRET_PATH`, `VAULT_NAMESPACE`:
+//!   Vault server, token, KV API path and optional namespace
+//! - `AWS_REGION`, `AWS_SECRET_ID`, `AWS_ACCESS_KEY_ID`,
+//!   `AWS_SECRET_ACCESS_KEY`, `AWS_SESSION_TOKEN`, `AWS_ENDPOINT_URL`:
+//!   Secrets Manager secret and signing credentials
+//! - `SECRETS_ROTATION_SECS`: Vault/AWS credential poll interval, 0
+//!   disables rotation (default: 300)
+//! - `OPTION_CHAIN_CACHE_TTL_SECS`: How long option chains are cached and how
+//!   often recently requested chains are refreshed (default: 60)
+//! - `DELTA_HEDGE_UNDERLYINGS`: Underlyings to delta-hedge, each with an
+//!   optional band in shares (e.g. `SPY:200,QQQ`); unset disables hedging
+//! - `DELTA_HEDGE_BAND`: Default band in shares of delta (default: 100)
+//! - `DELTA_HEDGE_SIZING`: TARGET | BAND, hedge back to zero delta or to the
+//!   band edge (default: TARGET)
+//! - `DELTA_HEDGE_MAX_SHARES`: Largest single hedge order (default: unlimited)
+//! - `DELTA_HEDGE_INTERVAL_SECS`: Time between hedging passes (default: 60)
+//! - `LEG_BALANCE_REMEDIATION`: ALERT | REPRICE | UNWIND, what to do with a
+//!   multi-leg order whose legs stay filled out of step (default: ALERT)
+//! - `LEG_BALANCE_STUCK_SECS`: How long legs may stay out of step before the
+//!   remediation applies (default: 30)
+//! - `NET_LADDER_STEP_PCT`: Share of the mid-to-natural distance a built
+//!   spread's net limit moves per step (default: 0.25)
+//! - `NET_LADDER_INTERVAL_SECS`: Time between net limit steps in a built
+//!   spread's ladder plan (default: 15)
+//! - `NET_LADDER_MAX_CONCESSION_PCT`: Share of the mid-to-natural distance
+//!   the net limit may walk, 1 being natural (default: 1)
+//! - `BROKER_RETRY_MODE`: STATIC | ADAPTIVE; ADAPTIVE cuts retries and
+//!   lengthens backoff on broker endpoints with a falling success rate or
+//!   recent `Retry-After`s (default: STATIC)
+//! - `ORDER_UPDATES_SOURCE`: NONE | WEBSOCKET | SSE | BOTH, broker streams
+//!   that apply order updates and fills as they happen, besides
+//!   reconciliation; BOTH runs the trading WebSocket and SSE events endpoint
+//!   as redundant paths (default: NONE)
+//! - `FEATURE_FLAGS`: Feature flag overrides as `name=on|off`, comma
+//!   separated (`auto_hedging`, `eod_policies`, `shadow_execution`); changed
+//!   at runtime via `POST /api/v1/admin/feature-flags/{name}`
+//! - `EOD_INTRADAY_POLICY`, `EOD_SWING_POLICY`, `EOD_POSITION_POLICY`,
+//!   `EOD_DEFAULT_POLICY`: End-of-day action by decision time horizon, as
+//!   `HOLD`, `FLATTEN:<minutes>` or `EXTEND_STOPS:<minutes>` before the close
+//!   (defaults: `FLATTEN:10`, `EXTEND_STOPS:5`, `EXTEND_STOPS:5`, `HOLD`)
+//! - `EOD_POLL_SECS`: How often the market clock is checked (default: 30)
+//! - `PRE_OPEN_CHECK_MINUTES`: How long before the open protective stops and
+//!   gap risk are checked, 0 disables the check (default: 30)
+//! - `PRE_OPEN_RESUBMIT_STOPS`: Re-submit stops missing at the broker
+//!   (default: true)
+//! - `CHAOS_SCENARIO_PATH`: JSON fault injection scenario for the broker and
+//!   price feed adapters; rejected in LIVE
 //! - `RUST_LOG`: Log level (default: info)
 
 use std::net::SocketAddr;
-use std::sync::Arc;
+use std::path::Path;
+use std::process::ExitCode;
+use std::sync::{Arc, OnceLock, RwLock};
 use std::time::Duration;
 
-use execution_engine::application::ports::{InMemoryRiskRepository, NoOpEventPublisher};
-use execution_engine::application::services::{PositionMonitorConfig, PositionMonitorService};
+use execution_engine::application::ports::{
+    BrokerError, BrokerPort, EventPublisherPort, ExecutionMetricsPort, InMemoryOutboxRepository,
+    InMemoryRiskRepository, NoOpEventPublisher, OrderUpdatePort, OrderUpdateTransport,
+    OutboxRepositoryPort, PriceFeedPort, SystemClock,
+};
+use execution_engine::application::services::{
+    AssetMetadataService, BrokerAccountService, CacheStatsSource, CandleAggregator,
+    CandleAggregatorConfig, ClockSkewConfig, ClockSkewMonitor, DEFAULT_QUOTE_CACHE_TTL,
+    DeltaHedger, DeltaHedgerConfig, EodAction, EodPolicies, EodPolicy, EodScheduler,
+    EodSchedulerConfig, EventOutbox, ExposureCache, Feature, FeatureFlags, FeedSanitizer,
+    FeedSanitizerConfig, HedgeRule, HedgeSizing, KillSwitch, KillSwitchConfig, LeaderElection,
+    LeaderElectionConfig, LegBalanceConfig, LegBalanceMonitor, LegRemediation, OptionChainCache,
+    OptionChainCacheConfig, OrderUpdateListener, PlanProgressService, PlanSchedulerService,
+    PositionLifecycleTracker, PositionMonitorConfig, PositionMonitorService, PreOpenCheck,
+    PreOpenCheckConfig, QuoteCache, ReadinessService, RealizedPnlService, SessionStatsService,
+    ShadowExecution, ShutdownCoordinator, StopAdjuster, SubscriptionReconciler,
+    SubscriptionReconcilerConfig, TradeBlotter, TradingHalts, WatchlistService,
+};
 use execution_engine::application::use_cases::{
-    CancelOrdersUseCase, SubmitOrdersUseCase, ValidateRiskUseCase,
+    CancelOrdersUseCase, CycleTransitionConfig, CycleTransitionUseCase, ReconcileUseCase,
+    StaleOrderAction, StateBundleUseCase, SubmitOrdersUseCase, ValidateRiskUseCase,
+};
+use execution_engine::domain::execution_tactics::{ExecutionProfiles, NetLadderConfig};
+use execution_engine::domain::order_execution::value_objects::{Environment, LotMatching};
+use execution_engine::domain::risk_management::{
+    ConcentrationGroups, InstrumentUniverse, OffTickAction, PriceSanityLimits, TickSizeRules,
+    UniverseMembership,
 };
+use execution_engine::infrastructure::auth::ApiKeyAuthenticator;
 use execution_engine::infrastructure::broker::alpaca::{
-    AlpacaBrokerAdapter, AlpacaConfig, AlpacaEnvironment,
+    AdaptiveRetryConfig, AlpacaBrokerAdapter, AlpacaConfig, AlpacaEnvironment, AlpacaOAuthClient,
+    AlpacaOAuthConfig, AlpacaOrderUpdateStream,
+};
+use execution_engine::infrastructure::chaos::{
+    ChaosScenario, FaultInjectingBrokerAdapter, FaultInjectingPriceFeed,
+};
+use execution_engine::infrastructure::config::{
+    ConfigReloader, EngineFileConfig, FileLoggingSettings, LogLevelSetter, load_universe_csv,
+    profile_from_env,
 };
 use execution_engine::infrastructure::grpc::{
+    GrpcAuthLayer, GrpcHealthUpdater, GrpcTlsConfig, ReloadingTlsAcceptor,
     create_execution_service, create_market_data_service,
 };
-use execution_engine::infrastructure::http::{AppState, create_router};
+use execution_engine::infrastructure::ha::{LeaderGatedBroker, PostgresAdvisoryLock};
+use execution_engine::infrastructure::http::{
+    AppState, DEFAULT_IDEMPOTENCY_TTL, IdempotencyStore, create_router,
+};
+use execution_engine::infrastructure::logging::{
+    AUDIT_TARGET, AuditTrail, FileLogGuard, LogRetention, file_layer,
+};
 use execution_engine::infrastructure::marketdata::AlpacaMarketDataAdapter;
-use execution_engine::infrastructure::persistence::InMemoryOrderRepository;
+use execution_engine::infrastructure::messaging::WebhookEventPublisher;
+use execution_engine::infrastructure::metrics::{PrometheusExecutionMetrics, init_metrics};
+use execution_engine::infrastructure::persistence::{
+    CrashSnapshot, CrashSnapshotStore, FailureClass, FileOAuthTokenRepository, FileOrderHistory,
+    FileOutboxRepository, FilePositionLifecycleRepository, FileScheduledPlanRepository,
+    FileWatchlistRepository, InMemoryOrderRepository,
+};
 use execution_engine::infrastructure::price_feed::AlpacaPriceFeedAdapter;
+use execution_engine::infrastructure::secrets::{
+    AlpacaCredentials, CredentialRotator, CredentialSink, DEFAULT_ROTATION_INTERVAL,
+    SecretsProvider, provider_from_env,
+};
 use execution_engine::infrastructure::stream_proxy::{ProxyQuoteManager, ProxyQuoteManagerConfig};
+use execution_engine::infrastructure::time_sync::{DEFAULT_NTP_SERVER, SntpTimeSource};
+use rust_decimal::Decimal;
 use tokio::net::TcpListener;
 use tokio::signal;
-use tokio::sync::broadcast;
+use tokio::sync::{broadcast, watch};
 use tokio::task::JoinHandle;
 use tokio_util::sync::CancellationToken;
+use tracing_subscriber::layer::SubscriberExt;
+use tracing_subscriber::util::SubscriberInitExt;
+use tracing_subscriber::{EnvFilter, reload};
 
 /// Graceful shutdown timeout.
 const SHUTDOWN_TIMEOUT: Duration = Duration::from_secs(30);
@@ -57,15 +269,131 @@ const DEFAULT_HTTP_PORT: u16 = 50051;
 /// Default gRPC server port.
 const DEFAULT_GRPC_PORT: u16 = 50053;
 
+/// Default advisory lock key of PAPER instances.
+const DEFAULT_HA_LOCK_KEY_PAPER: i64 = 0x4352_4541_4D50;
+
+/// Default advisory lock key of LIVE instances.
+const DEFAULT_HA_LOCK_KEY_LIVE: i64 = 0x4352_4541_4D4C;
+
+/// Delay between startup reconciliation attempts.
+const RECOVERY_RETRY_DELAY: Duration = Duration::from_secs(5);
+
+/// Default location of the scheduled plan store.
+const DEFAULT_SCHEDULED_PLANS_PATH: &str = "data/scheduled_plans.json";
+
+/// Default location of the watchlist store.
+const DEFAULT_WATCHLISTS_PATH: &str = "data/watchlists.json";
+
+/// Default location of the position lifecycle store.
+const DEFAULT_POSITION_LIFECYCLES_PATH: &str = "data/position_lifecycles.json";
+
+/// Default location of the order history journal.
+const DEFAULT_ORDER_HISTORY_PATH: &str = "data/order_history.jsonl";
+
+/// Default location of the event outbox.
+const DEFAULT_EVENT_OUTBOX_PATH: &str = "data/event_outbox.json";
+
+/// Default location of the crash snapshot.
+const DEFAULT_CRASH_SNAPSHOT_PATH: &str = "data/crash_snapshot.json";
+
+/// Default directory of per-user Alpaca OAuth tokens.
+const DEFAULT_ALPACA_OAUTH_TOKENS_DIR: &str = "data/alpaca_oauth";
+
+/// Default number of audit events kept for the crash snapshot.
+const DEFAULT_CRASH_SNAPSHOT_AUDIT_EVENTS: usize = 200;
+
 /// Parsed configuration from environment variables.
 struct EngineConfig {
     environment: AlpacaEnvironment,
-    http_port: u16,
-    grpc_port: u16,
-    api_key: String,
-    api_secret: String,
+    broker: BrokerSettings,
+    risk: RiskSettings,
+    persistence: PersistenceSettings,
+    api: ApiSettings,
     position_monitor_enabled: bool,
     stream_proxy_endpoint: String,
+    event_webhook_url: Option<String>,
+    idempotency_ttl: Duration,
+    submit_latency_budget: Option<Duration>,
+    quote_cache_ttl: Duration,
+    lot_matching: LotMatching,
+    metrics_strategy_tags: Vec<String>,
+    option_chain_cache: OptionChainCacheConfig,
+    delta_hedge: DeltaHedgerConfig,
+    leg_balance: LegBalanceConfig,
+    net_ladder: NetLadderConfig,
+    feature_flags: Vec<(Feature, bool)>,
+    feed_subscriptions: SubscriptionReconcilerConfig,
+    live_bars: CandleAggregatorConfig,
+    feed_sanitizer: FeedSanitizerConfig,
+    eod: EodSchedulerConfig,
+    pre_open: PreOpenCheckConfig,
+    config_path: Option<String>,
+    chaos_scenario_path: Option<String>,
+    ha: Option<HaConfig>,
+    clock_skew: Option<ClockSkewSettings>,
+    oauth: Option<OAuthSettings>,
+}
+
+/// Broker credentials, connection and order recovery settings.
+struct BrokerSettings {
+    secrets: Arc<dyn SecretsProvider>,
+    secrets_rotation: Duration,
+    retry: Option<AdaptiveRetryConfig>,
+    order_updates: Vec<OrderUpdateTransport>,
+    stale_order_action: StaleOrderAction,
+    recovery_timeout: Option<Duration>,
+}
+
+/// Pre-trade limits and the kill switch.
+struct RiskSettings {
+    price_sanity: PriceSanityLimits,
+    tick_sizes: TickSizeRules,
+    concentration_groups_path: Option<String>,
+    universe_constituents_path: Option<String>,
+    kill_switch: KillSwitchConfig,
+}
+
+/// Files holding state across restarts.
+struct PersistenceSettings {
+    scheduled_plans_path: String,
+    watchlists_path: String,
+    position_lifecycles_path: String,
+    order_history_path: String,
+    event_outbox_path: String,
+    state_bundle_key: Option<String>,
+    shutdown_snapshot_path: Option<String>,
+}
+
+/// HTTP and gRPC listeners and their authentication.
+struct ApiSettings {
+    http_port: u16,
+    grpc_port: u16,
+    grpc_tls: Option<GrpcTlsConfig>,
+    keys_path: Option<String>,
+}
+
+/// Leader election settings for HA deployments.
+#[derive(Debug, Clone)]
+struct HaConfig {
+    database_url: String,
+    lock_key: i64,
+    election: LeaderElectionConfig,
+}
+
+/// Alpaca OAuth application and the user whose account is traded.
+#[derive(Debug, Clone)]
+struct OAuthSettings {
+    client: AlpacaOAuthConfig,
+    tokens_dir: String,
+    trading_user: Option<String>,
+}
+
+/// Host clock checks against NTP.
+#[derive(Debug, Clone)]
+struct ClockSkewSettings {
+    servers: Vec<String>,
+    monitor: ClockSkewConfig,
+    halt: bool,
 }
 
 impl EngineConfig {
@@ -76,15 +404,26 @@ impl EngineConfig {
             "PAPER"
         }
     }
+
+    const fn trading_environment(&self) -> Environment {
+        if self.environment.is_live() {
+            Environment::Live
+        } else {
+            Environment::Paper
+        }
+    }
 }
 
+/// Broker adapter, with faults injected when a chaos scenario is loaded and
+/// order writes refused while an HA instance is a standby.
+type Broker = LeaderGatedBroker<FaultInjectingBrokerAdapter<AlpacaBrokerAdapter>>;
+
+/// REST price feed, with faults injected when a chaos scenario is loaded.
+type PriceFeed = FaultInjectingPriceFeed<AlpacaPriceFeedAdapter>;
+
 /// Concrete type alias for the submit orders use case.
-type ConcreteSubmitOrdersUseCase = SubmitOrdersUseCase<
-    AlpacaBrokerAdapter,
-    InMemoryRiskRepository,
-    InMemoryOrderRepository,
-    NoOpEventPublisher,
->;
+type ConcreteSubmitOrdersUseCase =
+    SubmitOrdersUseCase<Broker, InMemoryRiskRepository, InMemoryOrderRepository, EventOutbox>;
 
 /// Concrete type alias for the validate risk use case.
 type ConcreteValidateRiskUseCase =
@@ -92,18 +431,166 @@ type ConcreteValidateRiskUseCase =
 
 /// Concrete type alias for the cancel orders use case.
 type ConcreteCancelOrdersUseCase =
-    CancelOrdersUseCase<AlpacaBrokerAdapter, InMemoryOrderRepository, NoOpEventPublisher>;
+    CancelOrdersUseCase<Broker, InMemoryOrderRepository, EventOutbox>;
+
+/// Concrete type alias for the cycle transition use case.
+type ConcreteCycleTransitionUseCase =
+    CycleTransitionUseCase<Broker, InMemoryOrderRepository, EventOutbox>;
+
+/// Concrete type alias for the plan scheduler service.
+type ConcretePlanScheduler = PlanSchedulerService<
+    Broker,
+    InMemoryRiskRepository,
+    InMemoryOrderRepository,
+    EventOutbox,
+    FileScheduledPlanRepository,
+>;
+
+/// Concrete type alias for the stop adjuster.
+type ConcreteStopAdjuster =
+    StopAdjuster<Broker, InMemoryRiskRepository, InMemoryOrderRepository, EventOutbox>;
+
+/// Concrete type alias for the readiness service.
+type ConcreteReadinessService = ReadinessService<Broker, FileScheduledPlanRepository>;
+
+/// Concrete type alias for the session stats service.
+type ConcreteSessionStats = SessionStatsService<InMemoryOrderRepository>;
+
+/// Concrete type alias for the cached market data adapter.
+type ConcreteMarketData = OptionChainCache<AlpacaMarketDataAdapter>;
+
+/// Concrete type alias for the config reloader.
+type ConcreteConfigReloader = ConfigReloader<InMemoryRiskRepository>;
+
+/// Concrete type alias for the reconcile use case.
+type ConcreteReconcileUseCase = ReconcileUseCase<Broker, InMemoryOrderRepository>;
+
+/// Concrete type alias for the state bundle use case.
+type ConcreteStateBundleUseCase = StateBundleUseCase<Broker, InMemoryOrderRepository>;
+
+/// Concrete type alias for the realized P&L service.
+type ConcreteRealizedPnl = RealizedPnlService<InMemoryOrderRepository>;
 
 /// Application use cases wired together for dependency injection.
 struct UseCases {
+    asset_metadata: Arc<AssetMetadataService<Broker>>,
     submit_orders: Arc<ConcreteSubmitOrdersUseCase>,
     validate_risk: Arc<ConcreteValidateRiskUseCase>,
     cancel_orders: Arc<ConcreteCancelOrdersUseCase>,
+    cycle_transition: Arc<ConcreteCycleTransitionUseCase>,
+    plan_scheduler: Arc<ConcretePlanScheduler>,
+    reconcile: Arc<ConcreteReconcileUseCase>,
+    readiness: Arc<ConcreteReadinessService>,
+    kill_switch: Arc<KillSwitch>,
+    feature_flags: Arc<FeatureFlags>,
+    shutdown: Arc<ShutdownCoordinator>,
+    event_outbox: Arc<EventOutbox>,
+    stats: Arc<ConcreteSessionStats>,
+    config_reloader: Option<Arc<ConcreteConfigReloader>>,
+    state_bundle: Option<Arc<ConcreteStateBundleUseCase>>,
+    shadow_execution: Option<Arc<ShadowExecution>>,
+    watchlists: Arc<WatchlistService>,
+    broker_accounts: Option<Arc<BrokerAccountService>>,
+    price_feed: Arc<PriceFeed>,
     order_repo: Arc<InMemoryOrderRepository>,
+    lifecycle: Arc<PositionLifecycleTracker>,
+    realized_pnl: Arc<ConcreteRealizedPnl>,
+    trading_halts: Arc<TradingHalts>,
+    feed_sanitizer: Arc<FeedSanitizer>,
+    clock_skew: Option<Arc<ClockSkewMonitor>>,
+}
+
+/// A fatal error, classified for the exit code.
+struct Fatal {
+    class: FailureClass,
+    error: String,
+}
+
+impl Fatal {
+    fn config(error: impl std::fmt::Display) -> Self {
+        Self {
+            class: FailureClass::Config,
+            error: error.to_string(),
+        }
+    }
+
+    fn broker_auth(error: impl std::fmt::Display) -> Self {
+        Self {
+            class: FailureClass::BrokerAuth,
+            error: error.to_string(),
+        }
+    }
+
+    fn recovery(error: impl std::fmt::Display) -> Self {
+        Self {
+            class: FailureClass::Recovery,
+            error: error.to_string(),
+        }
+    }
+}
+
+impl From<Box<dyn std::error::Error>> for Fatal {
+    fn from(error: Box<dyn std::error::Error>) -> Self {
+        Self {
+            class: FailureClass::Other,
+            error: error.to_string(),
+        }
+    }
+}
+
+/// Where the crash snapshot goes and the state it is built from.
+struct CrashContext {
+    store: CrashSnapshotStore,
+    audit_trail: Arc<AuditTrail>,
+    state: OnceLock<(Arc<InMemoryOrderRepository>, Arc<PositionLifecycleTracker>)>,
+}
+
+impl CrashContext {
+    fn from_env() -> Self {
+        let path = std::env::var("CRASH_SNAPSHOT_PATH")
+            .ok()
+            .filter(|v| !v.trim().is_empty())
+            .unwrap_or_else(|| DEFAULT_CRASH_SNAPSHOT_PATH.to_string());
+        let audit_events = std::env::var("CRASH_SNAPSHOT_AUDIT_EVENTS")
+            .ok()
+            .and_then(|v| v.parse().ok())
+            .unwrap_or(DEFAULT_CRASH_SNAPSHOT_AUDIT_EVENTS);
+        Self {
+            store: CrashSnapshotStore::new(path),
+            audit_trail: Arc::new(AuditTrail::new(audit_events)),
+            state: OnceLock::new(),
+        }
+    }
+
+    /// Include working orders and positions from these stores.
+    fn attach(&self, use_cases: &UseCases) {
+        let _ = self.state.set((
+            Arc::clone(&use_cases.order_repo),
+            Arc::clone(&use_cases.lifecycle),
+        ));
+    }
+
+    /// Write the crash snapshot for `fatal` and return its exit code.
+    async fn fail(&self, fatal: Fatal) -> ExitCode {
+        let mut snapshot = CrashSnapshot::new(fatal.class, fatal.error, self.audit_trail.recent());
+        if let Some((order_repo, lifecycle)) = self.state.get() {
+            snapshot.capture_state(order_repo.as_ref(), lifecycle).await;
+        }
+        match self.store.write(&snapshot).await {
+            Ok(()) => tracing::info!(
+                path = %self.store.path().display(),
+                open_orders = snapshot.open_orders.len(),
+                positions = snapshot.positions.len(),
+                "Crash snapshot written"
+            ),
+            Err(e) => tracing::error!(error = %e, "Failed to write crash snapshot"),
+        }
+        ExitCode::from(fatal.class.exit_code())
+    }
 }
 
 #[tokio::main]
-async fn main() -> Result<(), Box<dyn std::error::Error>> {
+async fn main() -> ExitCode {
     // Install rustls crypto provider before any TLS operations
     if rustls::crypto::ring::default_provider()
         .install_default()
@@ -113,32 +600,392 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
     }
 
     load_dotenv();
-    init_tracing();
+    if std::env::args()
+        .skip(1)
+        .any(|arg| arg == "--print-effective-config")
+    {
+        return print_effective_config();
+    }
+    let crash = CrashContext::from_env();
+    let logging = file_logging_settings().and_then(|file_logging| {
+        let (log_level, guard) = init_tracing(file_logging.as_ref(), &crash.audit_trail)?;
+        Ok((file_logging, log_level, guard))
+    });
+    let (file_logging, log_level, _log_guard) = match logging {
+        Ok(logging) => logging,
+        Err(e) => {
+            eprintln!("Failed to initialize logging: {e}");
+            return crash.fail(Fatal::config(e)).await;
+        }
+    };
 
+    match run(&crash, file_logging, log_level).await {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(fatal) => {
+            tracing::error!(
+                target: "audit",
+                failure = %fatal.class,
+                exit_code = fatal.class.exit_code(),
+                error = %fatal.error,
+                "Execution engine failed"
+            );
+            crash.fail(fatal).await
+        }
+    }
+}
+
+/// Run the engine until shutdown.
+async fn run(
+    crash: &CrashContext,
+    file_logging: Option<FileLoggingSettings>,
+    log_level: LogLevelSetter,
+) -> Result<(), Fatal> {
     tracing::info!("Starting Cream Execution Engine");
 
-    let config = parse_config()?;
+    let config = parse_config().map_err(Fatal::config)?;
     log_config(&config);
 
-    let broker = create_broker(&config)?;
-    let market_data = create_market_data(&config)?;
-    let price_feed = create_price_feed(&config)?;
-    let use_cases = create_use_cases(&broker);
-    let (shutdown_tx, _) = broadcast::channel::<()>(1);
+    let (credentials, alpaca_config) = load_alpaca_config(&config).await?;
+    let chaos = load_chaos_scenario(&config).map_err(Fatal::config)?;
+    let leader_election = create_leader_election(&config);
+    let broker_accounts = create_broker_accounts(&config).map_err(Fatal::config)?;
+    let broker = create_broker(
+        &config,
+        &alpaca_config,
+        chaos.as_ref(),
+        leader_election.as_ref(),
+        broker_accounts.as_ref(),
+    )
+    .map_err(Fatal::config)?;
+    // Fail fast on rejected credentials rather than retrying recovery forever
+    if let Err(e @ BrokerError::AuthenticationFailed) = broker.get_buying_power().await {
+        return Err(Fatal::broker_auth(e));
+    }
+    let market_data = create_market_data(&config, &alpaca_config).map_err(Fatal::config)?;
+    let candles = Arc::new(CandleAggregator::new(config.live_bars.clone()));
+    let price_feed = create_price_feed(
+        &config,
+        &alpaca_config,
+        chaos.as_ref(),
+        Arc::clone(&candles),
+    )
+    .map_err(Fatal::config)?;
+    let order_update_streams =
+        create_order_update_streams(&config, &alpaca_config).map_err(Fatal::config)?;
 
     // Create cancellation token for graceful shutdown coordination
     let shutdown_token = CancellationToken::new();
 
+    // Delete the oldest log files once a stream exceeds its size limit
+    if let Some(settings) = &file_logging {
+        tracing::info!(directory = %settings.directory, "Writing logs to files");
+        Arc::new(LogRetention::from_settings(settings)).start(shutdown_token.clone());
+    }
+
+    start_credential_rotation(
+        &config,
+        credentials,
+        &broker,
+        &market_data,
+        &price_feed,
+        &order_update_streams,
+        shutdown_token.clone(),
+    );
+
+    let use_cases = create_use_cases(
+        &config,
+        &broker,
+        &price_feed,
+        leader_election.as_ref(),
+        broker_accounts,
+        log_level,
+        shutdown_token.clone(),
+    )
+    .map_err(Fatal::config)?;
+
+    start_state_services(
+        &config,
+        crash,
+        &use_cases,
+        leader_election.as_ref(),
+        &shutdown_token,
+    )
+    .await?;
+    let option_chains = start_option_chains(&config, &market_data, &use_cases, &shutdown_token);
+    start_schedulers(
+        &config,
+        &use_cases,
+        &broker,
+        &market_data,
+        &option_chains,
+        order_update_streams,
+        &shutdown_token,
+    );
+    let stop_adjuster = start_position_monitoring(
+        &config,
+        &use_cases,
+        &broker,
+        &price_feed,
+        &candles,
+        &shutdown_token,
+    )
+    .await?;
+
+    let outcome = serve(
+        &config,
+        &use_cases,
+        broker,
+        option_chains,
+        stop_adjuster,
+        &shutdown_token,
+    )
+    .await;
+
+    // Cancel WebSocket streams and position monitor
+    shutdown_token.cancel();
+    tracing::info!("Cancellation token triggered for background services");
+
+    tracing::info!("Execution engine stopped");
+    outcome
+}
+
+/// Load Alpaca credentials from the secrets provider and build the
+/// connection settings from them.
+async fn load_alpaca_config(
+    config: &EngineConfig,
+) -> Result<(AlpacaCredentials, AlpacaConfig), Fatal> {
+    let credentials = config
+        .broker
+        .secrets
+        .alpaca_credentials()
+        .await
+        .map_err(Fatal::broker_auth)?;
+    tracing::info!(
+        provider = config.broker.secrets.name(),
+        "Alpaca credentials loaded"
+    );
+    let mut alpaca_config = AlpacaConfig::from_credentials(&credentials, config.environment);
+    if let Some(adaptive) = &config.broker.retry {
+        alpaca_config = alpaca_config.with_adaptive_retry(adaptive.clone());
+    }
+    Ok((credentials, alpaca_config))
+}
+
+/// Re-read Vault/AWS credentials and push changes into the Alpaca adapters.
+fn start_credential_rotation(
+    config: &EngineConfig,
+    credentials: AlpacaCredentials,
+    broker: &Arc<Broker>,
+    market_data: &Arc<AlpacaMarketDataAdapter>,
+    price_feed: &Arc<PriceFeed>,
+    order_update_streams: &[Arc<AlpacaOrderUpdateStream>],
+    shutdown: CancellationToken,
+) {
+    let mut rotator = CredentialRotator::new(
+        Arc::clone(&config.broker.secrets),
+        credentials,
+        config.broker.secrets_rotation,
+    )
+    .with_sink(Arc::clone(market_data) as Arc<dyn CredentialSink>)
+    .with_sink(Arc::clone(price_feed) as Arc<dyn CredentialSink>);
+    // A broker trading with a user's OAuth token keeps it
+    if config
+        .oauth
+        .as_ref()
+        .is_none_or(|oauth| oauth.trading_user.is_none())
+    {
+        rotator = rotator.with_sink(Arc::clone(broker) as Arc<dyn CredentialSink>);
+    }
+    for stream in order_update_streams {
+        rotator = rotator.with_sink(Arc::clone(stream) as Arc<dyn CredentialSink>);
+    }
+    Arc::new(rotator).start(shutdown);
+}
+
+/// Restore the crash snapshot and start the services that carry state
+/// across restarts: the HA leader lock, config reload, the clock check, the
+/// event outbox and the plan scheduler.
+async fn start_state_services(
+    config: &EngineConfig,
+    crash: &CrashContext,
+    use_cases: &UseCases,
+    leader_election: Option<&Arc<LeaderElection>>,
+    shutdown: &CancellationToken,
+) -> Result<(), Fatal> {
+    // Restore working orders left by a crash; reconciliation settles them
+    crash.attach(use_cases);
+    recover_crash_snapshot(&crash.store, &use_cases.order_repo).await?;
+
+    // Stand by until this instance holds the leader lock (HA only)
+    if let Some(election) = leader_election {
+        start_leader_election(election, use_cases, shutdown.clone());
+    }
+
+    // Apply config.yaml settings, then reload them on SIGHUP
+    if let Some(reloader) = &use_cases.config_reloader {
+        reloader.activate().await.map_err(Fatal::config)?;
+        tracing::info!(path = %reloader.path().display(), "Config file loaded");
+        watch_config_reload(Arc::clone(reloader), shutdown.clone());
+        start_periodic_reconciliation(use_cases, reloader.reconcile_interval(), shutdown.clone());
+    }
+
+    // Check the host clock before any plan can trade, then periodically
+    if let Some(monitor) = &use_cases.clock_skew {
+        monitor.check().await;
+        monitor.start(shutdown.clone());
+    }
+
+    // Deliver order events recorded before restart and from now on
+    start_event_outbox(&use_cases.event_outbox, config, shutdown.clone()).await?;
+
+    // Start plan scheduler (executes or cancels plans persisted before restart)
+    use_cases.plan_scheduler.start();
+    tracing::info!(
+        path = %config.persistence.scheduled_plans_path,
+        "Plan scheduler started"
+    );
+    Ok(())
+}
+
+/// Serve option chains from memory and keep recently requested ones warm.
+fn start_option_chains(
+    config: &EngineConfig,
+    market_data: &Arc<AlpacaMarketDataAdapter>,
+    use_cases: &UseCases,
+    shutdown: &CancellationToken,
+) -> Arc<ConcreteMarketData> {
+    let option_chains = Arc::new(OptionChainCache::with_config(
+        config.option_chain_cache.clone(),
+        Arc::clone(market_data),
+    ));
+    option_chains.start(shutdown.clone());
+    use_cases.stats.register_cache(
+        "option_chains",
+        Arc::clone(&option_chains) as Arc<dyn CacheStatsSource>,
+    );
+    option_chains
+}
+
+/// Start the background jobs that act on the account on their own:
+/// metadata refresh, kill switch flattening, delta hedging, leg balance,
+/// pushed order updates, end-of-day policies and pre-open checks.
+fn start_schedulers(
+    config: &EngineConfig,
+    use_cases: &UseCases,
+    broker: &Arc<Broker>,
+    market_data: &Arc<AlpacaMarketDataAdapter>,
+    option_chains: &Arc<ConcreteMarketData>,
+    order_update_streams: Vec<Arc<AlpacaOrderUpdateStream>>,
+    shutdown: &CancellationToken,
+) {
+    // Refresh asset metadata daily for pre-trade tradability checks
+    use_cases.asset_metadata.start(shutdown.clone());
+
+    // Flatten the account when the kill switch trips (if configured)
+    use_cases
+        .kill_switch
+        .start(Arc::clone(broker), shutdown.clone());
+
+    // Keep hedged underlyings inside their delta bands (if configured)
+    Arc::new(
+        DeltaHedger::new(
+            config.delta_hedge.clone(),
+            Arc::clone(broker),
+            Arc::clone(option_chains),
+        )
+        .with_kill_switch(Arc::clone(&use_cases.kill_switch))
+        .with_feature_flags(Arc::clone(&use_cases.feature_flags)),
+    )
+    .start(shutdown.clone());
+    // Catch multi-leg orders whose legs fill out of step
+    Arc::new(
+        LegBalanceMonitor::new(
+            config.leg_balance.clone(),
+            Arc::clone(broker),
+            Arc::clone(&use_cases.order_repo),
+            SystemClock::shared(),
+        )
+        .with_kill_switch(Arc::clone(&use_cases.kill_switch)),
+    )
+    .start(shutdown.clone());
+    // Apply pushed order updates and fills ahead of reconciliation
+    if !order_update_streams.is_empty() {
+        Arc::new(OrderUpdateListener::new(Arc::clone(&use_cases.reconcile))).start(
+            order_update_streams
+                .into_iter()
+                .map(|stream| stream as Arc<dyn OrderUpdatePort>)
+                .collect(),
+            shutdown.clone(),
+        );
+    }
+    // Apply end-of-day policies by time horizon ahead of the close
+    Arc::new(
+        EodScheduler::new(
+            config.eod.clone(),
+            Arc::clone(broker),
+            Arc::clone(&use_cases.order_repo),
+            Arc::clone(&use_cases.submit_orders),
+            Arc::clone(&use_cases.cancel_orders),
+        )
+        .with_position_lifecycle(Arc::clone(&use_cases.lifecycle))
+        .with_feature_flags(Arc::clone(&use_cases.feature_flags)),
+    )
+    .start(shutdown.clone());
+    // Verify protective stops and gap risk before each open
+    Arc::new(PreOpenCheck::new(
+        config.pre_open.clone(),
+        Arc::clone(broker),
+        Arc::clone(&use_cases.order_repo),
+        Arc::clone(&use_cases.submit_orders),
+        Arc::clone(market_data),
+    ))
+    .start(shutdown.clone());
+}
+
+/// Connect to the stream proxy and start the position monitor, returning the
+/// stop adjuster that moves stops on the positions it watches.
+async fn start_position_monitoring(
+    config: &EngineConfig,
+    use_cases: &UseCases,
+    broker: &Arc<Broker>,
+    price_feed: &Arc<PriceFeed>,
+    candles: &Arc<CandleAggregator>,
+    shutdown: &CancellationToken,
+) -> Result<Arc<ConcreteStopAdjuster>, Fatal> {
     // Create quote provider for real-time quotes (connects to stream-proxy)
-    let quote_provider = create_quote_provider(&config, shutdown_token.clone()).await?;
+    let quote_provider = create_quote_provider(
+        config,
+        Arc::clone(&use_cases.feed_sanitizer),
+        shutdown.clone(),
+    )
+    .await?;
 
     // Create and start position monitor
     let position_monitor = create_position_monitor(
-        &config,
-        Arc::clone(&broker),
-        Arc::clone(&price_feed),
+        config,
+        Arc::clone(broker),
+        Arc::clone(price_feed),
         Arc::clone(&quote_provider),
-        shutdown_token.clone(),
+        shutdown.clone(),
+    );
+
+    use_cases
+        .stats
+        .register_circuit_breaker("exit_orders", position_monitor.circuit_breaker());
+    // Let the decision layer move stops on open positions
+    let stop_adjuster = Arc::new(
+        StopAdjuster::new(
+            Arc::clone(&use_cases.order_repo),
+            Arc::clone(&use_cases.submit_orders),
+            Arc::clone(&use_cases.cancel_orders),
+        )
+        .with_price_monitor(position_monitor.price_monitor())
+        .with_kill_switch(Arc::clone(&use_cases.kill_switch)),
+    );
+    use_cases.stats.watch_feed(
+        "stream_proxy",
+        quote_provider.quote_updates(),
+        shutdown.clone(),
     );
 
     // Start quote streams and position monitor
@@ -151,6 +998,19 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
         // Start quote streams
         quote_provider.start_stock_stream();
         quote_provider.start_options_stream();
+        quote_provider.start_trading_status_stream(Arc::clone(&use_cases.trading_halts));
+        quote_provider.start_trade_stream(Arc::clone(candles));
+        // Keep subscriptions on positions, working orders and the watchlist
+        Arc::new(
+            SubscriptionReconciler::new(
+                config.feed_subscriptions.clone(),
+                Arc::clone(broker),
+                Arc::clone(&use_cases.order_repo),
+                Arc::clone(&quote_provider),
+            )
+            .with_watchlists(Arc::clone(&use_cases.watchlists)),
+        )
+        .start(shutdown.clone());
 
         // Start position monitor service
         if let Err(e) = position_monitor.start().await {
@@ -159,22 +1019,79 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
             tracing::info!("Position monitor service started");
         }
     }
+    Ok(stop_adjuster)
+}
+
+/// Start the HTTP and gRPC servers and startup recovery, then wait for
+/// shutdown and drain working orders.
+async fn serve(
+    config: &EngineConfig,
+    use_cases: &UseCases,
+    broker: Arc<Broker>,
+    option_chains: Arc<ConcreteMarketData>,
+    stop_adjuster: Arc<ConcreteStopAdjuster>,
+    shutdown_token: &CancellationToken,
+) -> Result<(), Fatal> {
+    let (shutdown_tx, _) = broadcast::channel::<()>(1);
+    let security = ApiSecurity {
+        auth: create_authenticator(config).map_err(Fatal::config)?,
+        grpc_tls: create_grpc_tls(config, shutdown_token.clone()).map_err(Fatal::config)?,
+    };
 
-    let http_handle = start_http_server(&config, &use_cases, shutdown_tx.clone()).await?;
+    let http_handle = start_http_server(
+        config,
+        use_cases,
+        stop_adjuster,
+        &security,
+        shutdown_tx.clone(),
+    )
+    .await?;
     let grpc_handle = start_grpc_server(
-        &config,
-        &use_cases,
+        config,
+        use_cases,
         Arc::clone(&broker),
-        Arc::clone(&market_data),
+        option_chains,
+        &security,
         shutdown_tx.clone(),
+        shutdown_token.clone(),
     );
 
-    tracing::info!("Execution engine ready");
+    // Reconcile with the broker before reporting ready
+    let recovery_handle = tokio::spawn(run_startup_recovery(
+        Arc::clone(&use_cases.reconcile),
+        broker,
+        Arc::clone(&use_cases.realized_pnl),
+        Arc::clone(&use_cases.readiness),
+        Arc::clone(&use_cases.stats),
+        config.broker.recovery_timeout,
+        shutdown_token.clone(),
+    ));
+
+    tracing::info!("Execution engine started, awaiting recovery before reporting ready");
 
-    await_shutdown(http_handle, grpc_handle, shutdown_token).await;
+    let outcome = await_shutdown(http_handle, grpc_handle, recovery_handle).await;
+    if outcome.is_err() {
+        // Stop the servers and drain working orders as on SIGTERM
+        use_cases.shutdown.stop_accepting();
+        let _ = shutdown_tx.send(());
+    }
 
-    tracing::info!("Execution engine stopped");
-    Ok(())
+    let report = use_cases
+        .shutdown
+        .shutdown(use_cases.order_repo.as_ref(), SHUTDOWN_TIMEOUT)
+        .await;
+    if let (Some(path), Some(state_bundle)) = (
+        &config.persistence.shutdown_snapshot_path,
+        &use_cases.state_bundle,
+    ) {
+        write_shutdown_snapshot(state_bundle, Path::new(path)).await;
+    }
+    tracing::info!(
+        drained = report.drained,
+        working_orders = report.working_orders.len(),
+        "Shutdown complete"
+    );
+    outcome
 }
 
 /// Load .env file from current or ancestor directories.
@@ -184,30 +1101,156 @@ fn load_dotenv() {
     }
 }
 
-/// Initialize the tracing subscriber with environment filter.
+/// Print the `CONFIG_PATH` file merged with its profile overlays, with
+/// secrets redacted.
+fn print_effective_config() -> ExitCode {
+    let Some(path) = std::env::var("CONFIG_PATH")
+        .ok()
+        .filter(|v| !v.trim().is_empty())
+    else {
+        eprintln!("CONFIG_PATH is not set");
+        return ExitCode::from(FailureClass::Config.exit_code());
+    };
+    match EngineFileConfig::effective_yaml(Path::new(&path)) {
+        Ok(yaml) => {
+            println!("# profile: {}", profile_from_env());
+            print!("{yaml}");
+            ExitCode::SUCCESS
+        }
+        Err(e) => {
+            eprintln!("Invalid config: {e}");
+            ExitCode::from(FailureClass::Config.exit_code())
+        }
+    }
+}
+
+/// File logging settings from the `CONFIG_PATH` file, if any.
 ///
-/// Uses static directive strings that are compile-time constants guaranteed to parse.
-#[allow(clippy::expect_used)]
-fn init_tracing() {
-    tracing_subscriber::fmt()
-        .with_env_filter(
-            tracing_subscriber::EnvFilter::from_default_env()
-                .add_directive(
-                    "execution_engine=info"
-                        .parse()
-                        .expect("static directive 'execution_engine=info' is valid"),
-                )
-                .add_directive(
-                    "tower_http=info"
-                        .parse()
-                        .expect("static directive 'tower_http=info' is valid"),
-                ),
-        )
+/// Read before tracing starts, so the file is parsed again when the config
+/// reloader is created.
+fn file_logging_settings() -> Result<Option<FileLoggingSettings>, Box<dyn std::error::Error>> {
+    let Some(path) = std::env::var("CONFIG_PATH")
+        .ok()
+        .filter(|v| !v.trim().is_empty())
+    else {
+        return Ok(None);
+    };
+    let file = EngineFileConfig::load(Path::new(&path))?;
+    Ok(file.observability.logging.file)
+}
+
+/// Initialize the tracing subscriber with environment filter, writing to the
+/// console and, when configured, to rotating log files. Audit events are
+/// also kept in `audit_trail` for the crash snapshot.
+///
+/// Returns a setter that swaps the engine log level at runtime and a guard
+/// that flushes the log files when dropped.
+fn init_tracing(
+    file_logging: Option<&FileLoggingSettings>,
+    audit_trail: &Arc<AuditTrail>,
+) -> Result<(LogLevelSetter, Option<FileLogGuard>), Box<dyn std::error::Error>> {
+    let (filter, handle) = reload::Layer::new(log_filter("info")?);
+    let (files, guard) = match file_logging {
+        Some(settings) => {
+            let (layer, guard) = file_layer(settings)?;
+            (Some(layer), Some(guard))
+        }
+        None => (None, None),
+    };
+
+    tracing_subscriber::registry()
+        .with(filter)
+        .with(tracing_subscriber::fmt::layer())
+        .with(files)
+        .with(audit_trail.layer())
         .init();
+
+    let log_level: LogLevelSetter = Arc::new(move |level: &str| {
+        let filter = log_filter(level).map_err(|e| e.to_string())?;
+        handle.reload(filter).map_err(|e| e.to_string())
+    });
+    Ok((log_level, guard))
+}
+
+/// `RUST_LOG` with engine and HTTP logs at `level`. Audit events are always
+/// kept.
+fn log_filter(level: &str) -> Result<EnvFilter, tracing_subscriber::filter::ParseError> {
+    Ok(EnvFilter::from_default_env()
+        .add_directive(format!("execution_engine={level}").parse()?)
+        .add_directive(format!("tower_http={level}").parse()?)
+        .add_directive(format!("{AUDIT_TARGET}=info").parse()?))
 }
 
 /// Parse configuration from environment variables.
 fn parse_config() -> Result<EngineConfig, Box<dyn std::error::Error>> {
+    let environment = parse_environment()?;
+
+    let stream_proxy_endpoint = std::env::var("STREAM_PROXY_ENDPOINT").map_err(|_| {
+        std::io::Error::new(
+            std::io::ErrorKind::InvalidInput,
+            "STREAM_PROXY_ENDPOINT environment variable is required.",
+        )
+    })?;
+    if stream_proxy_endpoint.trim().is_empty() {
+        return Err("STREAM_PROXY_ENDPOINT must not be empty".into());
+    }
+
+    Ok(EngineConfig {
+        environment,
+        broker: parse_broker_settings()?,
+        risk: parse_risk_settings()?,
+        persistence: parse_persistence_settings()?,
+        api: parse_api_settings()?,
+        position_monitor_enabled: std::env::var("POSITION_MONITOR_ENABLED")
+            .map(|v| v.to_lowercase() != "false" && v != "0")
+            .unwrap_or(true),
+        stream_proxy_endpoint,
+        event_webhook_url: env_nonempty("EVENT_WEBHOOK_URL"),
+        idempotency_ttl: std::env::var("IDEMPOTENCY_TTL_SECS")
+            .ok()
+            .and_then(|v| v.parse().ok())
+            .map_or(DEFAULT_IDEMPOTENCY_TTL, Duration::from_secs),
+        submit_latency_budget: std::env::var("SUBMIT_LATENCY_BUDGET_MS")
+            .ok()
+            .and_then(|v| v.parse().ok())
+            .filter(|&ms| ms > 0)
+            .map(Duration::from_millis),
+        quote_cache_ttl: std::env::var("QUOTE_CACHE_TTL_MS")
+            .ok()
+            .and_then(|v| v.parse().ok())
+            .map_or(DEFAULT_QUOTE_CACHE_TTL, Duration::from_millis),
+        lot_matching: parse_lot_matching()?,
+        metrics_strategy_tags: std::env::var("METRICS_STRATEGY_TAGS")
+            .unwrap_or_default()
+            .split(',')
+            .map(|s| s.trim().to_string())
+            .filter(|s| !s.is_empty())
+            .collect(),
+        option_chain_cache: parse_option_chain_cache(),
+        delta_hedge: parse_delta_hedge()?,
+        leg_balance: parse_leg_balance()?,
+        net_ladder: parse_net_ladder()?,
+        feature_flags: parse_feature_flags()?,
+        feed_subscriptions: parse_feed_subscriptions(),
+        live_bars: CandleAggregatorConfig {
+            history: std::env::var("LIVE_BAR_HISTORY")
+                .ok()
+                .and_then(|v| v.parse().ok())
+                .unwrap_or_else(|| CandleAggregatorConfig::default().history),
+        },
+        feed_sanitizer: parse_feed_sanitizer()?,
+        eod: parse_eod()?,
+        pre_open: parse_pre_open(),
+        config_path: env_nonempty("CONFIG_PATH"),
+        chaos_scenario_path: env_nonempty("CHAOS_SCENARIO_PATH"),
+        ha: parse_ha_config(environment)?,
+        clock_skew: parse_clock_skew(),
+        oauth: parse_oauth()?,
+    })
+}
+
+/// Parse the required `CREAM_ENV`.
+fn parse_environment() -> Result<AlpacaEnvironment, Box<dyn std::error::Error>> {
     let env = std::env::var("CREAM_ENV")
         .map_err(|_| {
             std::io::Error::new(
@@ -216,102 +1259,762 @@ fn parse_config() -> Result<EngineConfig, Box<dyn std::error::Error>> {
             )
         })?
         .to_uppercase();
-    let environment = match env.as_str() {
-        "LIVE" => AlpacaEnvironment::Live,
-        "PAPER" => AlpacaEnvironment::Paper,
-        _ => {
+    match env.as_str() {
+        "LIVE" => Ok(AlpacaEnvironment::Live),
+        "PAPER" => Ok(AlpacaEnvironment::Paper),
+        _ => Err(
+            format!("Invalid CREAM_ENV value '{env}'. Supported values are PAPER and LIVE.").into(),
+        ),
+    }
+}
+
+/// Parse broker credentials, retries, order update transports and how
+/// orders left over from before a restart are recovered.
+fn parse_broker_settings() -> Result<BrokerSettings, Box<dyn std::error::Error>> {
+    let stale_order_action = match std::env::var("STALE_ORDER_ACTION")
+        .map(|v| v.to_uppercase())
+        .as_deref()
+    {
+        Ok("CANCEL") | Err(_) => StaleOrderAction::Cancel,
+        Ok("RECONFIRM") => StaleOrderAction::Reconfirm,
+        Ok("IGNORE") => StaleOrderAction::Ignore,
+        Ok(other) => {
             return Err(format!(
-                "Invalid CREAM_ENV value '{env}'. Supported values are PAPER and LIVE."
+                "Invalid STALE_ORDER_ACTION value '{other}'. Use CANCEL, RECONFIRM or IGNORE."
             )
             .into());
         }
     };
 
-    let api_key = std::env::var("ALPACA_KEY").unwrap_or_default();
-    let api_secret = std::env::var("ALPACA_SECRET").unwrap_or_default();
+    Ok(BrokerSettings {
+        secrets: provider_from_env()?,
+        secrets_rotation: std::env::var("SECRETS_ROTATION_SECS")
+            .ok()
+            .and_then(|v| v.parse().ok())
+            .map_or(DEFAULT_ROTATION_INTERVAL, Duration::from_secs),
+        retry: parse_broker_retry()?,
+        order_updates: parse_order_updates()?,
+        stale_order_action,
+        recovery_timeout: std::env::var("RECOVERY_TIMEOUT_SECS")
+            .ok()
+            .and_then(|v| v.parse().ok())
+            .filter(|&secs| secs > 0)
+            .map(Duration::from_secs),
+    })
+}
+
+/// Parse pre-trade price checks, universe and concentration files and the
+/// kill switch.
+fn parse_risk_settings() -> Result<RiskSettings, Box<dyn std::error::Error>> {
+    let off_tick = match std::env::var("OFF_TICK_PRICES")
+        .map(|v| v.to_uppercase())
+        .as_deref()
+    {
+        Ok("ROUND") | Err(_) => OffTickAction::Round,
+        Ok("REJECT") => OffTickAction::Reject,
+        Ok(other) => {
+            return Err(
+                format!("Invalid OFF_TICK_PRICES value '{other}'. Use ROUND or REJECT.").into(),
+            );
+        }
+    };
+
+    Ok(RiskSettings {
+        price_sanity: parse_price_sanity()?,
+        tick_sizes: TickSizeRules::new(off_tick),
+        concentration_groups_path: env_nonempty("CONCENTRATION_GROUPS_PATH"),
+        universe_constituents_path: env_nonempty("UNIVERSE_CONSTITUENTS_PATH"),
+        kill_switch: KillSwitchConfig {
+            file_path: env_nonempty("KILL_SWITCH_FILE").map(Into::into),
+            engaged: env_flag("KILL_SWITCH"),
+            flatten_on_trip: env_flag("KILL_SWITCH_FLATTEN"),
+            ..KillSwitchConfig::default()
+        },
+    })
+}
 
-    if api_key.is_empty() || api_secret.is_empty() {
-        return Err("ALPACA_KEY and ALPACA_SECRET environment variables are required".into());
+/// Parse the paths of the state files and the shutdown snapshot settings.
+fn parse_persistence_settings() -> Result<PersistenceSettings, Box<dyn std::error::Error>> {
+    let state_bundle_key = env_nonempty("STATE_BUNDLE_KEY");
+    let shutdown_snapshot_path = env_nonempty("SHUTDOWN_SNAPSHOT_PATH");
+    if shutdown_snapshot_path.is_some() && state_bundle_key.is_none() {
+        return Err("SHUTDOWN_SNAPSHOT_PATH requires STATE_BUNDLE_KEY".into());
     }
 
-    let http_port: u16 = std::env::var("HTTP_PORT")
-        .unwrap_or_else(|_| DEFAULT_HTTP_PORT.to_string())
-        .parse()
-        .unwrap_or(DEFAULT_HTTP_PORT);
+    let path =
+        |name: &str, default: &str| std::env::var(name).unwrap_or_else(|_| default.to_string());
+    Ok(PersistenceSettings {
+        scheduled_plans_path: path("SCHEDULED_PLANS_PATH", DEFAULT_SCHEDULED_PLANS_PATH),
+        watchlists_path: path("WATCHLISTS_PATH", DEFAULT_WATCHLISTS_PATH),
+        position_lifecycles_path: path(
+            "POSITION_LIFECYCLES_PATH",
+            DEFAULT_POSITION_LIFECYCLES_PATH,
+        ),
+        order_history_path: path("ORDER_HISTORY_PATH", DEFAULT_ORDER_HISTORY_PATH),
+        event_outbox_path: path("EVENT_OUTBOX_PATH", DEFAULT_EVENT_OUTBOX_PATH),
+        state_bundle_key,
+        shutdown_snapshot_path,
+    })
+}
 
-    let grpc_port: u16 = std::env::var("GRPC_PORT")
-        .unwrap_or_else(|_| DEFAULT_GRPC_PORT.to_string())
-        .parse()
-        .unwrap_or(DEFAULT_GRPC_PORT);
+/// Parse the HTTP and gRPC ports, gRPC TLS and the API key file.
+fn parse_api_settings() -> Result<ApiSettings, Box<dyn std::error::Error>> {
+    Ok(ApiSettings {
+        http_port: std::env::var("HTTP_PORT")
+            .ok()
+            .and_then(|v| v.parse().ok())
+            .unwrap_or(DEFAULT_HTTP_PORT),
+        grpc_port: std::env::var("GRPC_PORT")
+            .ok()
+            .and_then(|v| v.parse().ok())
+            .unwrap_or(DEFAULT_GRPC_PORT),
+        grpc_tls: GrpcTlsConfig::from_env()?,
+        keys_path: env_nonempty("API_KEYS_PATH"),
+    })
+}
 
-    let position_monitor_enabled = std::env::var("POSITION_MONITOR_ENABLED")
-        .map(|v| v.to_lowercase() != "false" && v != "0")
-        .unwrap_or(true);
+/// Parse `LOT_MATCHING`.
+fn parse_lot_matching() -> Result<LotMatching, Box<dyn std::error::Error>> {
+    match std::env::var("LOT_MATCHING")
+        .map(|v| v.to_uppercase())
+        .as_deref()
+    {
+        Ok("FIFO") | Err(_) => Ok(LotMatching::Fifo),
+        Ok("LIFO") => Ok(LotMatching::Lifo),
+        Ok(other) => Err(format!("Invalid LOT_MATCHING value '{other}'. Use FIFO or LIFO.").into()),
+    }
+}
 
-    let stream_proxy_endpoint = std::env::var("STREAM_PROXY_ENDPOINT").map_err(|_| {
-        std::io::Error::new(
-            std::io::ErrorKind::InvalidInput,
-            "STREAM_PROXY_ENDPOINT environment variable is required.",
+/// Parse the option chain cache lifetime.
+fn parse_option_chain_cache() -> OptionChainCacheConfig {
+    OptionChainCacheConfig {
+        ttl: std::env::var("OPTION_CHAIN_CACHE_TTL_SECS")
+            .ok()
+            .and_then(|v| v.parse().ok())
+            .map_or_else(
+                || OptionChainCacheConfig::default().ttl,
+                Duration::from_secs,
+            ),
+        ..OptionChainCacheConfig::default()
+    }
+}
+
+/// Parse the market data subscription interval and standing watchlist.
+fn parse_feed_subscriptions() -> SubscriptionReconcilerConfig {
+    SubscriptionReconcilerConfig {
+        interval: std::env::var("FEED_RECONCILE_INTERVAL_SECS")
+            .ok()
+            .and_then(|v| v.parse().ok())
+            .map_or_else(
+                || SubscriptionReconcilerConfig::default().interval,
+                Duration::from_secs,
+            ),
+        watchlist: std::env::var("FEED_WATCHLIST")
+            .unwrap_or_default()
+            .split(',')
+            .map(|s| s.trim().to_uppercase())
+            .filter(|s| !s.is_empty())
+            .collect(),
+    }
+}
+
+/// Parse the pre-open check lead time and stop resubmission.
+fn parse_pre_open() -> PreOpenCheckConfig {
+    PreOpenCheckConfig {
+        lead_time: std::env::var("PRE_OPEN_CHECK_MINUTES")
+            .ok()
+            .and_then(|v| v.parse::<u64>().ok())
+            .map_or_else(
+                || PreOpenCheckConfig::default().lead_time,
+                |m| Duration::from_secs(m * 60),
+            ),
+        resubmit_missing: std::env::var("PRE_OPEN_RESUBMIT_STOPS")
+            .map_or(true, |v| !v.eq_ignore_ascii_case("false") && v != "0"),
+        ..PreOpenCheckConfig::default()
+    }
+}
+
+/// Parse leader election settings, if `HA_ENABLED` is set.
+fn parse_ha_config(
+    environment: AlpacaEnvironment,
+) -> Result<Option<HaConfig>, Box<dyn std::error::Error>> {
+    if !env_flag("HA_ENABLED") {
+        return Ok(None);
+    }
+
+    let database_url = std::env::var("DATABASE_URL")
+        .ok()
+        .filter(|v| !v.trim().is_empty())
+        .ok_or("HA_ENABLED requires DATABASE_URL for the leader lock")?;
+    // PAPER and LIVE instances never contend for the same lock
+    let default_key = if environment.is_live() {
+        DEFAULT_HA_LOCK_KEY_LIVE
+    } else {
+        DEFAULT_HA_LOCK_KEY_PAPER
+    };
+    let lock_key = match std::env::var("HA_LOCK_KEY") {
+        Ok(v) => v
+            .parse()
+            .map_err(|_| format!("Invalid HA_LOCK_KEY value '{v}', expected an integer"))?,
+        Err(_) => default_key,
+    };
+    let defaults = LeaderElectionConfig::default();
+    let secs = |name: &str, default: Duration| {
+        std::env::var(name)
+            .ok()
+            .and_then(|v| v.parse().ok())
+            .map_or(default, Duration::from_secs)
+    };
+    let election = LeaderElectionConfig {
+        heartbeat: secs("HA_HEARTBEAT_SECS", defaults.heartbeat),
+        takeover_delay: secs("HA_TAKEOVER_DELAY_SECS", defaults.takeover_delay),
+    };
+    // The old leader must have a heartbeat to notice it lost the lock
+    if election.heartbeat.is_zero() || election.takeover_delay <= election.heartbeat {
+        return Err(
+            "HA_TAKEOVER_DELAY_SECS must be longer than a non-zero HA_HEARTBEAT_SECS".into(),
+        );
+    }
+
+    Ok(Some(HaConfig {
+        database_url,
+        lock_key,
+        election,
+    }))
+}
+
+/// Parse the Alpaca OAuth application from the environment.
+fn parse_oauth() -> Result<Option<OAuthSettings>, Box<dyn std::error::Error>> {
+    let var = |name: &str| std::env::var(name).ok().filter(|v| !v.trim().is_empty());
+    let trading_user = var("ALPACA_OAUTH_USER");
+    let (client_id, client_secret, redirect_uri) = match (
+        var("ALPACA_OAUTH_CLIENT_ID"),
+        var("ALPACA_OAUTH_CLIENT_SECRET"),
+        var("ALPACA_OAUTH_REDIRECT_URI"),
+    ) {
+        (Some(id), Some(secret), Some(redirect)) => (id, secret, redirect),
+        (None, None, None) if trading_user.is_none() => return Ok(None),
+        _ => {
+            return Err("Alpaca OAuth requires ALPACA_OAUTH_CLIENT_ID, \
+                        ALPACA_OAUTH_CLIENT_SECRET and ALPACA_OAUTH_REDIRECT_URI"
+                .into());
+        }
+    };
+
+    Ok(Some(OAuthSettings {
+        client: AlpacaOAuthConfig::new(client_id, client_secret, redirect_uri),
+        tokens_dir: var("ALPACA_OAUTH_TOKENS_DIR")
+            .unwrap_or_else(|| DEFAULT_ALPACA_OAUTH_TOKENS_DIR.to_string()),
+        trading_user,
+    }))
+}
+
+/// Parse host clock check settings from the environment.
+fn parse_clock_skew() -> Option<ClockSkewSettings> {
+    let defaults = ClockSkewConfig::default();
+    let interval = std::env::var("CLOCK_SKEW_CHECK_SECS")
+        .ok()
+        .and_then(|v| v.trim().parse().ok())
+        .map_or(defaults.interval, Duration::from_secs);
+    if interval.is_zero() {
+        return None;
+    }
+
+    let servers: Vec<String> = std::env::var("NTP_SERVERS")
+        .unwrap_or_default()
+        .split(',')
+        .map(|s| s.trim().to_string())
+        .filter(|s| !s.is_empty())
+        .collect();
+    Some(ClockSkewSettings {
+        servers: if servers.is_empty() {
+            vec![DEFAULT_NTP_SERVER.to_string()]
+        } else {
+            servers
+        },
+        monitor: ClockSkewConfig {
+            max_skew: std::env::var("CLOCK_SKEW_MAX_MS")
+                .ok()
+                .and_then(|v| v.trim().parse().ok())
+                .map_or(defaults.max_skew, Duration::from_millis),
+            interval,
+        },
+        halt: env_flag("CLOCK_SKEW_HALT"),
+    })
+}
+
+/// Parse price sanity limits from the environment.
+fn parse_price_sanity() -> Result<PriceSanityLimits, Box<dyn std::error::Error>> {
+    let pct = |name: &str, default: Decimal| -> Result<Decimal, Box<dyn std::error::Error>> {
+        std::env::var(name)
+            .ok()
+            .filter(|v| !v.trim().is_empty())
+            .map_or(Ok(default), |v| {
+                v.trim()
+                    .parse::<Decimal>()
+                    .ok()
+                    .filter(Decimal::is_sign_positive)
+                    .ok_or_else(|| format!("{name} must be a non-negative percentage").into())
+            })
+    };
+
+    let defaults = PriceSanityLimits::default();
+    Ok(PriceSanityLimits {
+        equity_max_deviation_pct: pct(
+            "PRICE_SANITY_EQUITY_PCT",
+            defaults.equity_max_deviation_pct,
+        )?,
+        option_max_deviation_pct: pct(
+            "PRICE_SANITY_OPTION_PCT",
+            defaults.option_max_deviation_pct,
+        )?,
+    })
+}
+
+/// Parse live feed sanitation limits from the environment.
+fn parse_feed_sanitizer() -> Result<FeedSanitizerConfig, Box<dyn std::error::Error>> {
+    let pct = |name: &str, default: Decimal| -> Result<Decimal, Box<dyn std::error::Error>> {
+        std::env::var(name)
+            .ok()
+            .filter(|v| !v.trim().is_empty())
+            .map_or(Ok(default), |v| {
+                v.trim()
+                    .parse::<Decimal>()
+                    .ok()
+                    .filter(Decimal::is_sign_positive)
+                    .ok_or_else(|| format!("{name} must be a non-negative percentage").into())
+            })
+    };
+    let secs = |name: &str, default: Duration| {
+        std::env::var(name)
+            .ok()
+            .and_then(|v| v.trim().parse().ok())
+            .map_or(default, Duration::from_secs)
+    };
+
+    let defaults = FeedSanitizerConfig::default();
+    Ok(FeedSanitizerConfig {
+        equity_max_move_pct: pct("FEED_MAX_MOVE_PCT", defaults.equity_max_move_pct)?,
+        option_max_move_pct: pct("FEED_MAX_OPTION_MOVE_PCT", defaults.option_max_move_pct)?,
+        confirmations: std::env::var("FEED_OUTLIER_CONFIRMATIONS")
+            .ok()
+            .and_then(|v| v.trim().parse().ok())
+            .unwrap_or(defaults.confirmations),
+        max_future: secs("FEED_MAX_CLOCK_DRIFT_SECS", defaults.max_future),
+        max_age: secs("FEED_MAX_TICK_AGE_SECS", defaults.max_age),
+    })
+}
+
+/// Parse feature flag overrides (`FEATURE_FLAGS`, e.g.
+/// `auto_hedging=on,shadow_execution=off`) from the environment.
+fn parse_feature_flags() -> Result<Vec<(Feature, bool)>, Box<dyn std::error::Error>> {
+    let mut overrides = Vec::new();
+    for entry in std::env::var("FEATURE_FLAGS")
+        .unwrap_or_default()
+        .split(',')
+        .map(str::trim)
+        .filter(|entry| !entry.is_empty())
+    {
+        let (name, value) = entry.split_once('=').unwrap_or((entry, "on"));
+        let enabled = match value.trim().to_lowercase().as_str() {
+            "on" | "true" | "1" => true,
+            "off" | "false" | "0" => false,
+            _ => {
+                return Err(
+                    format!("Invalid feature flag value in '{entry}'. Use on or off.").into(),
+                );
+            }
+        };
+        overrides.push((name.parse::<Feature>()?, enabled));
+    }
+    Ok(overrides)
+}
+
+/// Parse delta hedging rules from the environment.
+fn parse_delta_hedge() -> Result<DeltaHedgerConfig, Box<dyn std::error::Error>> {
+    let decimal = |name: &str| -> Result<Option<Decimal>, Box<dyn std::error::Error>> {
+        std::env::var(name)
+            .ok()
+            .filter(|v| !v.trim().is_empty())
+            .map(|v| {
+                v.trim()
+                    .parse::<Decimal>()
+                    .ok()
+                    .filter(Decimal::is_sign_positive)
+                    .ok_or_else(|| format!("{name} must be a non-negative number").into())
+            })
+            .transpose()
+    };
+
+    let default_band = decimal("DELTA_HEDGE_BAND")?.unwrap_or(Decimal::ONE_HUNDRED);
+    let sizing = match std::env::var("DELTA_HEDGE_SIZING")
+        .map(|v| v.to_uppercase())
+        .as_deref()
+    {
+        Ok("TARGET") | Err(_) => HedgeSizing::ToTarget,
+        Ok("BAND") => HedgeSizing::ToBand,
+        Ok(other) => {
+            return Err(
+                format!("Invalid DELTA_HEDGE_SIZING value '{other}'. Use TARGET or BAND.").into(),
+            );
+        }
+    };
+    let max_shares = decimal("DELTA_HEDGE_MAX_SHARES")?;
+
+    let mut underlyings = std::collections::HashMap::new();
+    for entry in std::env::var("DELTA_HEDGE_UNDERLYINGS")
+        .unwrap_or_default()
+        .split(',')
+        .map(str::trim)
+        .filter(|entry| !entry.is_empty())
+    {
+        let (symbol, band) = match entry.split_once(':') {
+            Some((symbol, band)) => (
+                symbol,
+                band.trim()
+                    .parse::<Decimal>()
+                    .ok()
+                    .filter(Decimal::is_sign_positive)
+                    .ok_or_else(|| format!("Invalid delta hedge band in '{entry}'"))?,
+            ),
+            None => (entry, default_band),
+        };
+        underlyings.insert(
+            symbol.trim().to_uppercase(),
+            HedgeRule {
+                sizing,
+                max_shares,
+                ..HedgeRule::with_band(band)
+            },
+        );
+    }
+
+    Ok(DeltaHedgerConfig {
+        underlyings,
+        interval: std::env::var("DELTA_HEDGE_INTERVAL_SECS")
+            .ok()
+            .and_then(|v| v.parse().ok())
+            .map_or_else(
+                || DeltaHedgerConfig::default().interval,
+                Duration::from_secs,
+            ),
+        ..DeltaHedgerConfig::default()
+    })
+}
+
+/// Parse multi-leg imbalance handling from the environment.
+fn parse_leg_balance() -> Result<LegBalanceConfig, Box<dyn std::error::Error>> {
+    let defaults = LegBalanceConfig::default();
+    let remediation = match std::env::var("LEG_BALANCE_REMEDIATION")
+        .map(|v| v.trim().to_uppercase())
+        .as_deref()
+    {
+        Ok("ALERT") | Err(_) => LegRemediation::Alert,
+        Ok("REPRICE") => LegRemediation::Reprice,
+        Ok("UNWIND") => LegRemediation::Unwind,
+        Ok(other) => {
+            return Err(format!(
+                "Invalid LEG_BALANCE_REMEDIATION value '{other}'. Use ALERT, REPRICE or UNWIND."
+            )
+            .into());
+        }
+    };
+
+    Ok(LegBalanceConfig {
+        remediation,
+        stuck_after: std::env::var("LEG_BALANCE_STUCK_SECS")
+            .ok()
+            .and_then(|v| v.parse().ok())
+            .map_or(defaults.stuck_after, Duration::from_secs),
+        ..defaults
+    })
+}
+
+/// Parse the spread net-price ladder from the environment.
+fn parse_net_ladder() -> Result<NetLadderConfig, Box<dyn std::error::Error>> {
+    let pct = |name: &str, default: Decimal| -> Result<Decimal, Box<dyn std::error::Error>> {
+        std::env::var(name)
+            .ok()
+            .filter(|v| !v.trim().is_empty())
+            .map_or(Ok(default), |v| {
+                v.trim()
+                    .parse::<Decimal>()
+                    .ok()
+                    .filter(|pct| *pct > Decimal::ZERO)
+                    .ok_or_else(|| format!("{name} must be a positive fraction").into())
+            })
+    };
+
+    let defaults = NetLadderConfig::default();
+    Ok(NetLadderConfig {
+        step_pct: pct("NET_LADDER_STEP_PCT", defaults.step_pct)?,
+        interval_seconds: std::env::var("NET_LADDER_INTERVAL_SECS")
+            .ok()
+            .and_then(|v| v.trim().parse().ok())
+            .unwrap_or(defaults.interval_seconds),
+        max_concession_pct: pct("NET_LADDER_MAX_CONCESSION_PCT", defaults.max_concession_pct)?,
+    })
+}
+
+/// Parse the broker retry mode from the environment.
+fn parse_broker_retry() -> Result<Option<AdaptiveRetryConfig>, Box<dyn std::error::Error>> {
+    match std::env::var("BROKER_RETRY_MODE")
+        .map(|v| v.trim().to_uppercase())
+        .as_deref()
+    {
+        Ok("STATIC" | "") | Err(_) => Ok(None),
+        Ok("ADAPTIVE") => Ok(Some(AdaptiveRetryConfig::default())),
+        Ok(other) => Err(format!(
+            "Invalid BROKER_RETRY_MODE value '{other}'. Use STATIC or ADAPTIVE."
         )
-    })?;
-    if stream_proxy_endpoint.trim().is_empty() {
-        return Err("STREAM_PROXY_ENDPOINT must not be empty".into());
+        .into()),
     }
+}
 
-    Ok(EngineConfig {
-        environment,
-        http_port,
-        grpc_port,
-        api_key,
-        api_secret,
-        position_monitor_enabled,
-        stream_proxy_endpoint,
+/// Parse the broker order update streams from the environment.
+fn parse_order_updates() -> Result<Vec<OrderUpdateTransport>, Box<dyn std::error::Error>> {
+    match std::env::var("ORDER_UPDATES_SOURCE")
+        .map(|v| v.trim().to_uppercase())
+        .as_deref()
+    {
+        Ok("NONE" | "") | Err(_) => Ok(Vec::new()),
+        Ok("WEBSOCKET") => Ok(vec![OrderUpdateTransport::WebSocket]),
+        Ok("SSE") => Ok(vec![OrderUpdateTransport::Sse]),
+        Ok("BOTH") => Ok(vec![
+            OrderUpdateTransport::WebSocket,
+            OrderUpdateTransport::Sse,
+        ]),
+        Ok(other) => Err(format!(
+            "Invalid ORDER_UPDATES_SOURCE value '{other}'. Use NONE, WEBSOCKET, SSE or BOTH."
+        )
+        .into()),
+    }
+}
+
+/// Parse end-of-day policies from the environment.
+fn parse_eod() -> Result<EodSchedulerConfig, Box<dyn std::error::Error>> {
+    let defaults = EodSchedulerConfig::default();
+    let policy =
+        |name: &str, default: EodPolicy| -> Result<EodPolicy, Box<dyn std::error::Error>> {
+            let Some(value) = std::env::var(name).ok().filter(|v| !v.trim().is_empty()) else {
+                return Ok(default);
+            };
+            let value = value.trim().to_uppercase();
+            let (action, minutes) = value
+                .split_once(':')
+                .map_or((value.as_str(), None), |(a, m)| (a, Some(m.trim())));
+            let minutes = minutes
+                .map(|m| {
+                    m.parse::<u32>()
+                        .map_err(|_| format!("Invalid minutes in {name} value '{value}'"))
+                })
+                .transpose()?
+                .unwrap_or(default.minutes_before_close);
+            let action = match action.trim() {
+                "HOLD" => EodAction::Hold,
+                "FLATTEN" => EodAction::Flatten,
+                "EXTEND_STOPS" => EodAction::ExtendStops,
+                other => {
+                    return Err(format!(
+                        "Invalid {name} action '{other}'. Use HOLD, FLATTEN or EXTEND_STOPS."
+                    )
+                    .into());
+                }
+            };
+            Ok(EodPolicy {
+                action,
+                minutes_before_close: minutes,
+            })
+        };
+
+    Ok(EodSchedulerConfig {
+        policies: EodPolicies {
+            intraday: policy("EOD_INTRADAY_POLICY", defaults.policies.intraday)?,
+            swing: policy("EOD_SWING_POLICY", defaults.policies.swing)?,
+            position: policy("EOD_POSITION_POLICY", defaults.policies.position)?,
+            unspecified: policy("EOD_DEFAULT_POLICY", defaults.policies.unspecified)?,
+        },
+        poll_interval: std::env::var("EOD_POLL_SECS")
+            .ok()
+            .and_then(|v| v.parse().ok())
+            .map_or(defaults.poll_interval, Duration::from_secs),
     })
 }
 
+/// Read a boolean environment variable (`true` or `1`), defaulting to false.
+fn env_flag(name: &str) -> bool {
+    std::env::var(name).is_ok_and(|v| v.eq_ignore_ascii_case("true") || v == "1")
+}
+
+/// Value of `name`, unless unset or blank.
+fn env_nonempty(name: &str) -> Option<String> {
+    std::env::var(name).ok().filter(|v| !v.trim().is_empty())
+}
+
 /// Log the parsed configuration.
 fn log_config(config: &EngineConfig) {
     tracing::info!(
         environment = config.environment_name(),
-        http_port = config.http_port,
-        grpc_port = config.grpc_port,
+        http_port = config.api.http_port,
+        grpc_port = config.api.grpc_port,
         position_monitor_enabled = config.position_monitor_enabled,
+        stale_order_action = ?config.broker.stale_order_action,
+        lot_matching = ?config.lot_matching,
+        grpc_tls = config.api.grpc_tls.is_some(),
+        api_auth = config.api.keys_path.is_some(),
+        secrets_provider = config.broker.secrets.name(),
+        event_webhook = config.event_webhook_url.is_some(),
+        ha = config.ha.is_some(),
+        oauth = config.oauth.is_some(),
         "Configuration loaded"
     );
+
+    if config.risk.kill_switch.engaged {
+        tracing::warn!("Kill switch engaged at startup, trading disabled");
+    }
+}
+
+/// Create the Alpaca broker adapter, injecting the chaos scenario's broker
+/// faults if one is loaded.
+fn create_broker(
+    config: &EngineConfig,
+    alpaca_config: &AlpacaConfig,
+    chaos: Option<&ChaosScenario>,
+    leader_election: Option<&Arc<LeaderElection>>,
+    broker_accounts: Option<&Arc<BrokerAccountService>>,
+) -> Result<Arc<Broker>, Box<dyn std::error::Error>> {
+    let trading_user = config
+        .oauth
+        .as_ref()
+        .and_then(|oauth| oauth.trading_user.as_deref());
+    let broker = match (trading_user, broker_accounts) {
+        (Some(user_id), Some(accounts)) => {
+            tracing::info!(user_id, "Trading the OAuth-connected account");
+            AlpacaBrokerAdapter::with_oauth(alpaca_config, Arc::clone(accounts), user_id)?
+        }
+        _ => AlpacaBrokerAdapter::new(alpaca_config)?,
+    };
+
+    tracing::info!(
+        environment = config.environment_name(),
+        "AlpacaBrokerAdapter initialized for {} trading",
+        config.environment_name()
+    );
+
+    let broker = match chaos {
+        Some(scenario) => FaultInjectingBrokerAdapter::new(
+            broker,
+            scenario.broker_injector(config.trading_environment())?,
+        ),
+        None => FaultInjectingBrokerAdapter::passthrough(broker),
+    };
+    let broker = match leader_election {
+        Some(election) => Broker::new(broker, Arc::clone(election)),
+        None => Broker::passthrough(broker),
+    };
+    Ok(Arc::new(broker))
+}
+
+/// Create the broker account service, if an Alpaca OAuth application is
+/// configured.
+fn create_broker_accounts(
+    config: &EngineConfig,
+) -> Result<Option<Arc<BrokerAccountService>>, Box<dyn std::error::Error>> {
+    let Some(oauth) = &config.oauth else {
+        return Ok(None);
+    };
+    let authorizer = AlpacaOAuthClient::new(oauth.client.clone())?;
+    let tokens = FileOAuthTokenRepository::new(&oauth.tokens_dir);
+    tracing::info!(
+        tokens_dir = %oauth.tokens_dir,
+        "Alpaca OAuth enabled for broker accounts"
+    );
+    Ok(Some(Arc::new(BrokerAccountService::new(
+        Arc::new(authorizer),
+        Arc::new(tokens),
+    ))))
+}
+
+/// Create the leader election, if HA is enabled.
+///
+/// The instance starts as a standby; [`start_leader_election`] campaigns.
+fn create_leader_election(config: &EngineConfig) -> Option<Arc<LeaderElection>> {
+    let ha = config.ha.as_ref()?;
+    tracing::info!(
+        lock_key = ha.lock_key,
+        heartbeat_secs = ha.election.heartbeat.as_secs(),
+        takeover_delay_secs = ha.election.takeover_delay.as_secs(),
+        "HA enabled, starting as standby"
+    );
+    Some(Arc::new(LeaderElection::new(
+        Arc::new(PostgresAdvisoryLock::new(&ha.database_url, ha.lock_key)),
+        ha.election,
+    )))
+}
+
+/// Campaign for leadership, reconciling with the broker before each takeover.
+fn start_leader_election(
+    election: &Arc<LeaderElection>,
+    use_cases: &UseCases,
+    shutdown: CancellationToken,
+) {
+    let reconcile = Arc::clone(&use_cases.reconcile);
+    let stats = Arc::clone(&use_cases.stats);
+    election.start(
+        move || {
+            let reconcile = Arc::clone(&reconcile);
+            let stats = Arc::clone(&stats);
+            async move {
+                let result = reconcile.execute().await;
+                stats.record_reconciliation(&result);
+                if result.errors.is_empty() {
+                    Ok(())
+                } else {
+                    Err(result.errors.join("; "))
+                }
+            }
+        },
+        shutdown,
+    );
 }
 
-/// Create the Alpaca broker adapter.
-fn create_broker(
+/// Create the configured Alpaca order update streams.
+///
+/// The streams authenticate with the API key, so none run while trading an
+/// OAuth-connected account.
+fn create_order_update_streams(
     config: &EngineConfig,
-) -> Result<Arc<AlpacaBrokerAdapter>, Box<dyn std::error::Error>> {
-    let alpaca_config = AlpacaConfig::new(
-        config.api_key.clone(),
-        config.api_secret.clone(),
-        config.environment,
-    );
-
-    let broker = AlpacaBrokerAdapter::new(&alpaca_config)?;
-
-    tracing::info!(
-        environment = config.environment_name(),
-        "AlpacaBrokerAdapter initialized for {} trading",
-        config.environment_name()
-    );
+    alpaca_config: &AlpacaConfig,
+) -> Result<Vec<Arc<AlpacaOrderUpdateStream>>, Box<dyn std::error::Error>> {
+    if config
+        .oauth
+        .as_ref()
+        .is_some_and(|oauth| oauth.trading_user.is_some())
+    {
+        if !config.broker.order_updates.is_empty() {
+            tracing::warn!("ORDER_UPDATES_SOURCE ignored while trading an OAuth-connected account");
+        }
+        return Ok(Vec::new());
+    }
 
-    Ok(Arc::new(broker))
+    config
+        .broker
+        .order_updates
+        .iter()
+        .map(|&transport| {
+            let stream = AlpacaOrderUpdateStream::new(alpaca_config, transport)?;
+            tracing::info!(%transport, "Order update stream configured");
+            Ok(Arc::new(stream))
+        })
+        .collect()
 }
 
 /// Create the Alpaca market data adapter.
 fn create_market_data(
     config: &EngineConfig,
+    alpaca_config: &AlpacaConfig,
 ) -> Result<Arc<AlpacaMarketDataAdapter>, Box<dyn std::error::Error>> {
-    let alpaca_config = AlpacaConfig::new(
-        config.api_key.clone(),
-        config.api_secret.clone(),
-        config.environment,
-    );
-
-    let market_data = AlpacaMarketDataAdapter::new(&alpaca_config)?;
+    let market_data = AlpacaMarketDataAdapter::new(alpaca_config)?;
 
     tracing::info!(
         environment = config.environment_name(),
@@ -322,29 +2025,61 @@ fn create_market_data(
     Ok(Arc::new(market_data))
 }
 
-/// Create the Alpaca price feed adapter for REST fallback.
+/// Create the Alpaca price feed adapter for REST fallback and live bars
+/// from `candles`, injecting the chaos scenario's feed faults if one is
+/// loaded.
 fn create_price_feed(
     config: &EngineConfig,
-) -> Result<Arc<AlpacaPriceFeedAdapter>, Box<dyn std::error::Error>> {
-    let alpaca_config = AlpacaConfig::new(
-        config.api_key.clone(),
-        config.api_secret.clone(),
-        config.environment,
-    );
-
-    let price_feed = AlpacaPriceFeedAdapter::new(&alpaca_config)?;
+    alpaca_config: &AlpacaConfig,
+    chaos: Option<&ChaosScenario>,
+    candles: Arc<CandleAggregator>,
+) -> Result<Arc<PriceFeed>, Box<dyn std::error::Error>> {
+    let price_feed = AlpacaPriceFeedAdapter::new(alpaca_config)?.with_candles(candles);
 
     tracing::info!(
-        environment = config.environment_name(),
+        environment = %alpaca_config.environment,
         "AlpacaPriceFeedAdapter initialized for REST fallback"
     );
 
+    let price_feed = match chaos {
+        Some(scenario) => PriceFeed::new(
+            price_feed,
+            scenario.feed_injector(config.trading_environment())?,
+        ),
+        None => PriceFeed::passthrough(price_feed),
+    };
     Ok(Arc::new(price_feed))
 }
 
+/// Load the fault injection scenario, if configured.
+///
+/// Refused in LIVE, so a stray `CHAOS_SCENARIO_PATH` cannot degrade real
+/// trading.
+fn load_chaos_scenario(
+    config: &EngineConfig,
+) -> Result<Option<ChaosScenario>, Box<dyn std::error::Error>> {
+    let Some(path) = &config.chaos_scenario_path else {
+        return Ok(None);
+    };
+    if config.environment.is_live() {
+        return Err("CHAOS_SCENARIO_PATH is not allowed in LIVE".into());
+    }
+
+    let scenario = ChaosScenario::from_file(Path::new(path))?;
+    tracing::warn!(
+        path = %path,
+        broker_rules = scenario.broker.len(),
+        feed_rules = scenario.feed.len(),
+        "Chaos scenario loaded, injecting faults into broker and price feed"
+    );
+
+    Ok(Some(scenario))
+}
+
 /// Create the quote provider for real-time quotes (connects to stream-proxy).
 async fn create_quote_provider(
     config: &EngineConfig,
+    sanitizer: Arc<FeedSanitizer>,
     shutdown: CancellationToken,
 ) -> Result<Arc<ProxyQuoteManager>, Box<dyn std::error::Error>> {
     use std::time::Duration;
@@ -354,7 +2089,7 @@ async fn create_quote_provider(
         enabled: config.position_monitor_enabled,
     };
 
-    let mut manager = ProxyQuoteManager::new(proxy_config, shutdown);
+    let mut manager = ProxyQuoteManager::new(proxy_config, shutdown).with_feed_sanitizer(sanitizer);
 
     // Connect to the stream proxy with retry (wait up to 60s for proxy to start)
     if config.position_monitor_enabled {
@@ -377,11 +2112,11 @@ async fn create_quote_provider(
 /// Create the position monitor service.
 fn create_position_monitor(
     config: &EngineConfig,
-    broker: Arc<AlpacaBrokerAdapter>,
-    price_feed: Arc<AlpacaPriceFeedAdapter>,
+    broker: Arc<Broker>,
+    price_feed: Arc<PriceFeed>,
     quote_provider: Arc<ProxyQuoteManager>,
     shutdown: CancellationToken,
-) -> PositionMonitorService<AlpacaBrokerAdapter, AlpacaPriceFeedAdapter, ProxyQuoteManager> {
+) -> PositionMonitorService<Broker, PriceFeed, ProxyQuoteManager> {
     let monitor_config = PositionMonitorConfig {
         enabled: config.position_monitor_enabled,
         ..PositionMonitorConfig::default()
@@ -396,35 +2131,539 @@ fn create_position_monitor(
     )
 }
 
+/// Guards every order path checks: exposure, the kill switch, feature
+/// flags, shutdown and trading halts.
+struct TradingGuards {
+    exposure: Arc<ExposureCache>,
+    kill_switch: Arc<KillSwitch>,
+    feature_flags: Arc<FeatureFlags>,
+    shutdown: Arc<ShutdownCoordinator>,
+    trading_halts: Arc<TradingHalts>,
+}
+
+/// Stores shared by the use cases.
+struct Repositories {
+    risk_repo: Arc<InMemoryRiskRepository>,
+    order_repo: Arc<InMemoryOrderRepository>,
+    event_outbox: Arc<EventOutbox>,
+    lifecycle: Arc<PositionLifecycleTracker>,
+}
+
+/// The submit use case and the services created for it.
+struct SubmitPath {
+    submit_orders: Arc<ConcreteSubmitOrdersUseCase>,
+    execution_profiles: Arc<RwLock<ExecutionProfiles>>,
+    asset_metadata: Arc<AssetMetadataService<Broker>>,
+    shadow_execution: Option<Arc<ShadowExecution>>,
+    quote_cache: Option<Arc<QuoteCache>>,
+}
+
 /// Create all application use cases with their dependencies.
-fn create_use_cases(broker: &Arc<AlpacaBrokerAdapter>) -> UseCases {
-    let risk_repo = Arc::new(InMemoryRiskRepository::new());
-    let order_repo = Arc::new(InMemoryOrderRepository::new());
-    let event_publisher = Arc::new(NoOpEventPublisher);
+fn create_use_cases(
+    config: &EngineConfig,
+    broker: &Arc<Broker>,
+    price_feed: &Arc<PriceFeed>,
+    leader_election: Option<&Arc<LeaderElection>>,
+    broker_accounts: Option<Arc<BrokerAccountService>>,
+    log_level: LogLevelSetter,
+    shutdown: CancellationToken,
+) -> Result<UseCases, Box<dyn std::error::Error>> {
+    let guards = create_trading_guards(config);
+    let repos = open_repositories(config, &guards)?;
+    let metrics = create_execution_metrics(config);
+    let (reconcile, realized_pnl) =
+        create_reconcile(config, broker, &guards, &repos, Arc::clone(&metrics));
+    let submit = create_submit_path(
+        config,
+        broker,
+        price_feed,
+        leader_election,
+        &guards,
+        &repos,
+        metrics,
+    )?;
+    let config_reloader = create_config_reloader(config, &repos.risk_repo, log_level, &submit)?;
+    let Repositories {
+        risk_repo,
+        order_repo,
+        event_outbox,
+        lifecycle,
+    } = repos;
 
-    let submit_orders = Arc::new(SubmitOrdersUseCase::new(
-        Arc::clone(broker),
+    let validate_risk = Arc::new(ValidateRiskUseCase::new(
         Arc::clone(&risk_repo),
         Arc::clone(&order_repo),
-        Arc::clone(&event_publisher),
     ));
 
-    let validate_risk = Arc::new(ValidateRiskUseCase::new(
-        Arc::clone(&risk_repo),
+    let cancel_orders = Arc::new(
+        CancelOrdersUseCase::new(
+            Arc::clone(broker),
+            Arc::clone(&order_repo),
+            Arc::clone(&event_outbox),
+        )
+        .with_exposure_cache(Arc::clone(&guards.exposure)),
+    );
+
+    let cycle_transition = Arc::new(CycleTransitionUseCase::with_config(
+        CycleTransitionConfig {
+            action: config.broker.stale_order_action,
+            ..CycleTransitionConfig::default()
+        },
+        Arc::clone(&cancel_orders),
         Arc::clone(&order_repo),
     ));
 
-    let cancel_orders = Arc::new(CancelOrdersUseCase::new(
-        Arc::clone(broker),
-        Arc::clone(&order_repo),
-        Arc::clone(&event_publisher),
+    let plan_repo = Arc::new(FileScheduledPlanRepository::open(
+        &config.persistence.scheduled_plans_path,
+    )?);
+    let plan_scheduler = Arc::new(PlanSchedulerService::new(
+        Arc::clone(&submit.submit_orders),
+        Arc::clone(&plan_repo),
+        shutdown,
     ));
 
-    UseCases {
-        submit_orders,
+    let feed_sanitizer = Arc::new(FeedSanitizer::new(config.feed_sanitizer.clone()));
+    let stats = Arc::new(
+        SessionStatsService::new(Arc::clone(&order_repo))
+            .with_exposure_cache(Arc::clone(&guards.exposure))
+            .with_event_outbox(Arc::clone(&event_outbox))
+            .with_feed_sanitizer(Arc::clone(&feed_sanitizer)),
+    );
+    if let Some(cache) = submit.quote_cache {
+        stats.register_cache("quotes", cache as Arc<dyn CacheStatsSource>);
+    }
+    let clock_skew = create_clock_skew_monitor(config, &guards.kill_switch);
+    let mut readiness = ReadinessService::new(Arc::clone(broker), plan_repo);
+    if let Some(monitor) = &clock_skew {
+        readiness = readiness.with_clock_skew(Arc::clone(monitor));
+    }
+
+    Ok(UseCases {
+        asset_metadata: submit.asset_metadata,
+        submit_orders: submit.submit_orders,
         validate_risk,
         cancel_orders,
+        cycle_transition,
+        plan_scheduler,
+        reconcile,
+        readiness: Arc::new(readiness),
+        kill_switch: guards.kill_switch,
+        feature_flags: guards.feature_flags,
+        shutdown: guards.shutdown,
+        event_outbox,
+        stats,
+        config_reloader,
+        state_bundle: create_state_bundle(config, broker, &order_repo, &lifecycle),
+        shadow_execution: submit.shadow_execution,
+        watchlists: Arc::new(WatchlistService::new(Arc::new(
+            FileWatchlistRepository::open(&config.persistence.watchlists_path)?,
+        ))),
+        broker_accounts,
+        price_feed: Arc::clone(price_feed),
+        realized_pnl,
         order_repo,
+        lifecycle,
+        trading_halts: guards.trading_halts,
+        feed_sanitizer,
+        clock_skew,
+    })
+}
+
+/// Create the guards from the kill switch settings and feature flag
+/// overrides, logging the flags in effect.
+fn create_trading_guards(config: &EngineConfig) -> TradingGuards {
+    // Live positions and working orders, fed by submit/cancel/reconcile
+    let exposure = Arc::new(ExposureCache::new());
+    let feature_flags = Arc::new(
+        FeatureFlags::new(config.trading_environment())
+            .with_overrides(config.feature_flags.iter().copied()),
+    );
+    for flag in feature_flags.flags() {
+        tracing::info!(
+            feature = %flag.feature,
+            enabled = flag.enabled,
+            "Feature flag"
+        );
+    }
+    TradingGuards {
+        kill_switch: Arc::new(KillSwitch::new(config.risk.kill_switch.clone())),
+        feature_flags,
+        shutdown: Arc::new(ShutdownCoordinator::new()),
+        // Halt state, fed by the stream proxy's trading status stream
+        trading_halts: Arc::new(TradingHalts::new().with_exposure_cache(Arc::clone(&exposure))),
+        exposure,
+    }
+}
+
+/// Open the risk, order, outbox and position lifecycle stores.
+fn open_repositories(
+    config: &EngineConfig,
+    guards: &TradingGuards,
+) -> Result<Repositories, Box<dyn std::error::Error>> {
+    Ok(Repositories {
+        risk_repo: Arc::new(
+            InMemoryRiskRepository::new()
+                .with_concentration_groups(load_concentration_groups(config)?)
+                .with_exposure_cache(Arc::clone(&guards.exposure)),
+        ),
+        order_repo: create_order_repository(config)?,
+        event_outbox: create_event_outbox(config)?,
+        lifecycle: Arc::new(PositionLifecycleTracker::new(Arc::new(
+            FilePositionLifecycleRepository::open(&config.persistence.position_lifecycles_path)?,
+        ))),
+    })
+}
+
+/// Create the reconcile use case and the realized P&L service it keeps
+/// up to date with broker fills.
+fn create_reconcile(
+    config: &EngineConfig,
+    broker: &Arc<Broker>,
+    guards: &TradingGuards,
+    repos: &Repositories,
+    metrics: Arc<dyn ExecutionMetricsPort>,
+) -> (Arc<ConcreteReconcileUseCase>, Arc<ConcreteRealizedPnl>) {
+    let realized_pnl = Arc::new(
+        RealizedPnlService::new(Arc::clone(&repos.order_repo))
+            .with_lot_matching(config.lot_matching),
+    );
+    let reconcile = Arc::new(
+        ReconcileUseCase::new(Arc::clone(broker), Arc::clone(&repos.order_repo))
+            .with_exposure_cache(Arc::clone(&guards.exposure))
+            .with_position_lifecycle(Arc::clone(&repos.lifecycle))
+            .with_realized_pnl(Arc::clone(&realized_pnl))
+            .with_event_publisher(Arc::clone(&repos.event_outbox) as Arc<dyn EventPublisherPort>)
+            .with_metrics(metrics),
+    );
+    (reconcile, realized_pnl)
+}
+
+/// Create the submit use case with every pre-trade check the config enables.
+fn create_submit_path(
+    config: &EngineConfig,
+    broker: &Arc<Broker>,
+    price_feed: &Arc<PriceFeed>,
+    leader_election: Option<&Arc<LeaderElection>>,
+    guards: &TradingGuards,
+    repos: &Repositories,
+    metrics: Arc<dyn ExecutionMetricsPort>,
+) -> Result<SubmitPath, Box<dyn std::error::Error>> {
+    let asset_metadata = Arc::new(AssetMetadataService::new(Arc::clone(broker)));
+    let (execution_profiles, shadow_profiles) = load_execution_profiles(config)?;
+    let execution_profiles = Arc::new(RwLock::new(execution_profiles));
+    let shadow_execution = shadow_profiles.map(|profiles| Arc::new(ShadowExecution::new(profiles)));
+    let mut submit_orders = SubmitOrdersUseCase::new(
+        Arc::clone(broker),
+        Arc::clone(&repos.risk_repo),
+        Arc::clone(&repos.order_repo),
+        Arc::clone(&repos.event_outbox),
+    )
+    .with_asset_metadata(Arc::clone(&asset_metadata))
+    .with_exposure_cache(Arc::clone(&guards.exposure))
+    .with_kill_switch(Arc::clone(&guards.kill_switch))
+    .with_feature_flags(Arc::clone(&guards.feature_flags))
+    .with_shutdown(Arc::clone(&guards.shutdown))
+    .with_trading_halts(Arc::clone(&guards.trading_halts))
+    .with_execution_profiles(Arc::clone(&execution_profiles))
+    .with_price_feed(Arc::clone(price_feed) as Arc<dyn PriceFeedPort>)
+    .with_price_sanity(config.risk.price_sanity)
+    .with_tick_size_rules(config.risk.tick_sizes)
+    .with_instrument_universe(load_instrument_universe(config)?)
+    .with_position_lifecycle(Arc::clone(&repos.lifecycle))
+    .with_metrics(metrics);
+    if let Some(membership) = load_universe_membership(config)? {
+        submit_orders = submit_orders.with_universe_membership(membership);
+    }
+    if let Some(budget) = config.submit_latency_budget {
+        submit_orders = submit_orders.with_latency_budget(budget);
+    }
+    let quote_cache = (!config.quote_cache_ttl.is_zero())
+        .then(|| Arc::new(QuoteCache::new(config.quote_cache_ttl)));
+    if let Some(cache) = &quote_cache {
+        submit_orders = submit_orders.with_quote_cache(Arc::clone(cache));
+    }
+    if let Some(shadow) = &shadow_execution {
+        submit_orders = submit_orders.with_shadow_execution(Arc::clone(shadow));
+    }
+    if let Some(election) = leader_election {
+        submit_orders = submit_orders.with_leader_election(Arc::clone(election));
+    }
+
+    Ok(SubmitPath {
+        submit_orders: Arc::new(submit_orders),
+        execution_profiles,
+        asset_metadata,
+        shadow_execution,
+        quote_cache,
+    })
+}
+
+/// Create the state bundle use case, if a bundle key is configured.
+fn create_state_bundle(
+    config: &EngineConfig,
+    broker: &Arc<Broker>,
+    order_repo: &Arc<InMemoryOrderRepository>,
+    lifecycle: &Arc<PositionLifecycleTracker>,
+) -> Option<Arc<ConcreteStateBundleUseCase>> {
+    let key = config.persistence.state_bundle_key.as_deref()?;
+    Some(Arc::new(StateBundleUseCase::new(
+        Arc::clone(broker),
+        Arc::clone(order_repo),
+        Arc::clone(lifecycle),
+        config.trading_environment(),
+        key,
+    )))
+}
+
+/// Create the host clock monitor, halting trading on skew in LIVE if
+/// configured.
+fn create_clock_skew_monitor(
+    config: &EngineConfig,
+    kill_switch: &Arc<KillSwitch>,
+) -> Option<Arc<ClockSkewMonitor>> {
+    let settings = config.clock_skew.as_ref()?;
+    let source = SntpTimeSource::new(settings.servers.clone());
+    let mut monitor = ClockSkewMonitor::new(Arc::new(source), settings.monitor.clone());
+    let halt = settings.halt && config.environment.is_live();
+    if halt {
+        monitor = monitor.with_kill_switch(Arc::clone(kill_switch));
+    }
+    tracing::info!(
+        servers = ?settings.servers,
+        max_skew_ms = settings.monitor.max_skew.as_millis(),
+        halt,
+        "Host clock checks enabled"
+    );
+    Some(Arc::new(monitor))
+}
+
+/// Create the order repository, loading finished orders from the history.
+fn create_order_repository(
+    config: &EngineConfig,
+) -> Result<Arc<InMemoryOrderRepository>, Box<dyn std::error::Error>> {
+    let (history, orders) = FileOrderHistory::open(&config.persistence.order_history_path)?;
+    tracing::info!(
+        path = %config.persistence.order_history_path,
+        orders = orders.len(),
+        "Loaded order history"
+    );
+    let order_repo = InMemoryOrderRepository::new().with_history(history);
+    for order in orders {
+        order_repo.add(order);
+    }
+    Ok(Arc::new(order_repo))
+}
+
+/// Install the Prometheus recorder and create the execution metrics adapter.
+fn create_execution_metrics(config: &EngineConfig) -> Arc<dyn ExecutionMetricsPort> {
+    init_metrics();
+    Arc::new(PrometheusExecutionMetrics::new(
+        config.metrics_strategy_tags.iter().cloned(),
+    ))
+}
+
+/// Create the event outbox. Without a webhook there is nowhere to deliver
+/// events, so they are kept in memory only.
+fn create_event_outbox(
+    config: &EngineConfig,
+) -> Result<Arc<EventOutbox>, Box<dyn std::error::Error>> {
+    let repo: Arc<dyn OutboxRepositoryPort> = if config.event_webhook_url.is_some() {
+        Arc::new(FileOutboxRepository::open(
+            &config.persistence.event_outbox_path,
+        )?)
+    } else {
+        Arc::new(InMemoryOutboxRepository::new())
+    };
+    Ok(Arc::new(EventOutbox::new(repo)))
+}
+
+/// Deliver outbox events to the webhook, if configured.
+async fn start_event_outbox(
+    outbox: &Arc<EventOutbox>,
+    config: &EngineConfig,
+    shutdown: CancellationToken,
+) -> Result<(), Box<dyn std::error::Error>> {
+    let sink: Arc<dyn EventPublisherPort> = match &config.event_webhook_url {
+        Some(url) => Arc::new(WebhookEventPublisher::new(url)?),
+        None => Arc::new(NoOpEventPublisher),
+    };
+    outbox.start(sink, shutdown);
+
+    let stats = outbox.stats().await;
+    tracing::info!(
+        webhook = config.event_webhook_url.is_some(),
+        path = %config.persistence.event_outbox_path,
+        undelivered = stats.depth,
+        "Event outbox started"
+    );
+    Ok(())
+}
+
+/// Load `config.yaml` for runtime-tunable settings, if configured.
+fn create_config_reloader(
+    config: &EngineConfig,
+    risk_repo: &Arc<InMemoryRiskRepository>,
+    log_level: LogLevelSetter,
+    submit: &SubmitPath,
+) -> Result<Option<Arc<ConcreteConfigReloader>>, Box<dyn std::error::Error>> {
+    let Some(path) = &config.config_path else {
+        return Ok(None);
+    };
+
+    let file = EngineFileConfig::load(Path::new(path))?;
+
+    // The environment is bound at startup; refuse a file written for the other one
+    if !file
+        .environment
+        .mode
+        .eq_ignore_ascii_case(config.environment_name())
+    {
+        return Err(format!(
+            "{path} has environment.mode {} but CREAM_ENV is {}",
+            file.environment.mode,
+            config.environment_name()
+        )
+        .into());
+    }
+
+    let mut reloader = ConfigReloader::new(path, file, Arc::clone(risk_repo))
+        .with_log_level(log_level)
+        .with_execution_profiles(Arc::clone(&submit.execution_profiles));
+    if let Some(shadow) = &submit.shadow_execution {
+        reloader = reloader.with_shadow_execution(Arc::clone(shadow));
+    }
+    Ok(Some(Arc::new(reloader)))
+}
+
+/// Restore working orders from a pending crash snapshot.
+async fn recover_crash_snapshot(
+    store: &CrashSnapshotStore,
+    order_repo: &InMemoryOrderRepository,
+) -> Result<(), Fatal> {
+    let Some(recovery) = store.recover(order_repo).await.map_err(Fatal::recovery)? else {
+        return Ok(());
+    };
+    tracing::warn!(
+        target: "audit",
+        failure = %recovery.failure,
+        error = %recovery.error,
+        written_at = %recovery.written_at,
+        orders_restored = recovery.orders_restored,
+        archived_to = %recovery.archived_to.display(),
+        "Recovered crash snapshot from previous run"
+    );
+    Ok(())
+}
+
+/// Reconcile local state with the broker, retrying until it succeeds, seed
+/// tax lots for positions opened before the recorded fills, then mark the
+/// engine as recovered so readiness checks can pass.
+///
+/// Gives up with the last errors once `timeout` has passed, if set.
+async fn run_startup_recovery(
+    reconcile: Arc<ConcreteReconcileUseCase>,
+    broker: Arc<Broker>,
+    realized_pnl: Arc<ConcreteRealizedPnl>,
+    readiness: Arc<ConcreteReadinessService>,
+    stats: Arc<ConcreteSessionStats>,
+    timeout: Option<Duration>,
+    shutdown: CancellationToken,
+) -> Result<(), String> {
+    let deadline = timeout.map(|timeout| tokio::time::Instant::now() + timeout);
+    loop {
+        let result = reconcile.execute().await;
+        stats.record_reconciliation(&result);
+        if result.errors.is_empty() {
+            tracing::info!(
+                checked = result.total_checked,
+                mismatches = result.mismatches,
+                reconciled = result.reconciled,
+                "Startup reconciliation completed"
+            );
+            seed_opening_lots(broker.as_ref(), &realized_pnl).await;
+            readiness.mark_recovered();
+            tracing::info!("Execution engine ready");
+            return Ok(());
+        }
+
+        if deadline.is_some_and(|deadline| tokio::time::Instant::now() >= deadline) {
+            return Err(format!(
+                "startup reconciliation did not succeed within {}s: {}",
+                timeout.unwrap_or_default().as_secs(),
+                result.errors.join("; ")
+            ));
+        }
+        tracing::warn!(
+            errors = ?result.errors,
+            "Startup reconciliation failed, retrying"
+        );
+
+        tokio::select! {
+            () = tokio::time::sleep(RECOVERY_RETRY_DELAY) => {}
+            () = shutdown.cancelled() => return Ok(()),
+        }
+    }
+}
+
+/// Reconcile with the broker every `interval` once startup recovery has
+/// completed, following interval changes from config reloads. A zero
+/// interval pauses the passes.
+fn start_periodic_reconciliation(
+    use_cases: &UseCases,
+    mut interval: watch::Receiver<Duration>,
+    shutdown: CancellationToken,
+) {
+    let reconcile = Arc::clone(&use_cases.reconcile);
+    let readiness = Arc::clone(&use_cases.readiness);
+    let stats = Arc::clone(&use_cases.stats);
+    tokio::spawn(async move {
+        loop {
+            let period = *interval.borrow_and_update();
+            let elapsed = async {
+                if period.is_zero() {
+                    std::future::pending::<()>().await;
+                }
+                tokio::time::sleep(period).await;
+            };
+            tokio::select! {
+                () = elapsed => {}
+                changed = interval.changed() => {
+                    if changed.is_err() {
+                        break;
+                    }
+                    tracing::info!(interval_secs = interval.borrow().as_secs(), "Reconciliation interval changed");
+                    continue;
+                }
+                () = shutdown.cancelled() => break,
+            }
+            // Startup recovery reconciles on its own until it succeeds
+            if !readiness.is_recovered() {
+                continue;
+            }
+            let result = reconcile.execute().await;
+            stats.record_reconciliation(&result);
+            if !result.errors.is_empty() {
+                tracing::warn!(errors = ?result.errors, "Periodic reconciliation failed");
+            }
+        }
+    });
+}
+
+/// Seed opening tax lots from the broker's positions, so closing a position
+/// opened before the recorded fills realizes P&L against its entry price.
+///
+/// A failure only leaves realized P&L without those lots, so it is logged
+/// rather than holding back readiness.
+async fn seed_opening_lots(broker: &Broker, realized_pnl: &ConcreteRealizedPnl) {
+    let seeded = match broker.get_all_positions().await {
+        Ok(positions) => realized_pnl
+            .seed_opening_lots(&positions)
+            .await
+            .map_err(|e| e.to_string()),
+        Err(e) => Err(e.to_string()),
+    };
+    if let Err(error) = seeded {
+        tracing::warn!(%error, "Failed to seed opening tax lots from broker positions");
     }
 }
 
@@ -432,18 +2671,40 @@ fn create_use_cases(broker: &Arc<AlpacaBrokerAdapter>) -> UseCases {
 async fn start_http_server(
     config: &EngineConfig,
     use_cases: &UseCases,
+    stop_adjuster: Arc<ConcreteStopAdjuster>,
+    security: &ApiSecurity,
     shutdown_tx: broadcast::Sender<()>,
 ) -> Result<JoinHandle<()>, Box<dyn std::error::Error>> {
     let http_state = AppState {
         submit_orders: Arc::clone(&use_cases.submit_orders),
         validate_risk: Arc::clone(&use_cases.validate_risk),
         cancel_orders: Arc::clone(&use_cases.cancel_orders),
+        cycle_transition: Arc::clone(&use_cases.cycle_transition),
+        plan_scheduler: Arc::clone(&use_cases.plan_scheduler),
+        readiness: Arc::clone(&use_cases.readiness),
+        kill_switch: Arc::clone(&use_cases.kill_switch),
+        feature_flags: Arc::clone(&use_cases.feature_flags),
+        stats: Arc::clone(&use_cases.stats),
+        plan_progress: Arc::new(PlanProgressService::new(Arc::clone(&use_cases.order_repo))),
+        trade_blotter: Arc::new(
+            TradeBlotter::new(Arc::clone(&use_cases.order_repo))
+                .with_position_lifecycle(Arc::clone(&use_cases.lifecycle))
+                .with_realized_pnl(Arc::clone(&use_cases.realized_pnl)),
+        ),
+        stop_adjuster,
+        shadow_execution: use_cases.shadow_execution.clone(),
+        config_reloader: use_cases.config_reloader.clone(),
+        state_bundle: use_cases.state_bundle.clone(),
+        watchlists: Arc::clone(&use_cases.watchlists),
+        broker_accounts: use_cases.broker_accounts.clone(),
         order_repo: Arc::clone(&use_cases.order_repo),
+        auth: security.auth.clone(),
+        idempotency: Arc::new(IdempotencyStore::new(config.idempotency_ttl)),
         version: env!("CARGO_PKG_VERSION").to_string(),
     };
     let app = create_router(http_state);
 
-    let http_addr: SocketAddr = format!("0.0.0.0:{}", config.http_port).parse()?;
+    let http_addr: SocketAddr = format!("0.0.0.0:{}", config.api.http_port).parse()?;
 
     tracing::info!(%http_addr, "HTTP server starting");
     tracing::info!("Endpoints:");
@@ -451,11 +2712,24 @@ async fn start_http_server(
     tracing::info!("  POST /api/v1/check-constraints");
     tracing::info!("  POST /api/v1/submit-orders");
     tracing::info!("  POST /api/v1/orders");
+    tracing::info!("  POST /api/v1/orders/query");
+    tracing::info!("  POST /api/v1/blotter");
+    tracing::info!("  GET  /api/v1/stats");
+    tracing::info!("  GET  /api/v1/plans/{{cycle_id}}/progress");
+    tracing::info!("  GET  /api/v1/shadow-execution");
     tracing::info!("  POST /api/v1/cancel-orders");
+    tracing::info!("  POST /api/v1/positions/{{symbol}}/stops");
+    tracing::info!("  GET  /api/v1/admin/kill-switch");
+    tracing::info!("  POST /api/v1/admin/kill-switch");
+    tracing::info!("  POST /api/v1/admin/reload-config");
+    tracing::info!("  GET  /api/v1/admin/state");
+    tracing::info!("  POST /api/v1/admin/state");
 
     let listener = TcpListener::bind(http_addr).await?;
-    let http_server =
-        axum::serve(listener, app).with_graceful_shutdown(shutdown_signal(shutdown_tx));
+    let http_server = axum::serve(listener, app).with_graceful_shutdown(shutdown_signal(
+        shutdown_tx,
+        Arc::clone(&use_cases.shutdown),
+    ));
 
     let handle = tokio::spawn(async move {
         if let Err(e) = http_server.await {
@@ -466,6 +2740,137 @@ async fn start_http_server(
     Ok(handle)
 }
 
+/// Authentication and transport security shared by the API servers.
+struct ApiSecurity {
+    auth: Option<Arc<ApiKeyAuthenticator>>,
+    grpc_tls: Option<Arc<ReloadingTlsAcceptor>>,
+}
+
+/// Load sector and correlation groupings for concentration limits.
+fn load_concentration_groups(
+    config: &EngineConfig,
+) -> Result<ConcentrationGroups, Box<dyn std::error::Error>> {
+    let Some(path) = &config.risk.concentration_groups_path else {
+        return Ok(ConcentrationGroups::default());
+    };
+
+    let groups: ConcentrationGroups = serde_json::from_str(&std::fs::read_to_string(path)?)?;
+    tracing::info!(
+        sectors = groups.sectors.len(),
+        correlation_groups = groups.correlation_groups.len(),
+        "Concentration groups loaded"
+    );
+
+    Ok(groups)
+}
+
+/// Load execution profiles by strategy family from `config.yaml`.
+///
+/// Without a config file every family uses the default profile.
+fn load_execution_profiles(
+    config: &EngineConfig,
+) -> Result<(ExecutionProfiles, Option<ExecutionProfiles>), Box<dyn std::error::Error>> {
+    let Some(path) = &config.config_path else {
+        return Ok((ExecutionProfiles::default(), None));
+    };
+
+    let file = EngineFileConfig::load(Path::new(path))?;
+    let profiles = file.execution_profiles()?;
+    tracing::info!(families = profiles.len(), "Execution profiles loaded");
+    let shadow = file.shadow_execution_profiles()?;
+    if let Some(shadow) = &shadow {
+        tracing::info!(families = shadow.len(), "Shadow execution enabled");
+    }
+
+    Ok((profiles, shadow))
+}
+
+/// Load the instrument allowlist and blocklist for the environment from
+/// `config.yaml`.
+///
+/// Without a config file every instrument may be traded.
+fn load_instrument_universe(
+    config: &EngineConfig,
+) -> Result<InstrumentUniverse, Box<dyn std::error::Error>> {
+    let Some(path) = &config.config_path else {
+        return Ok(InstrumentUniverse::default());
+    };
+
+    let file = EngineFileConfig::load(Path::new(path))?;
+    let universe = file.instrument_universe().clone();
+    if !universe.is_unrestricted() {
+        tracing::info!(
+            allowed_symbols = universe.allow.symbols.len(),
+            allowed_patterns = universe.allow.patterns.len(),
+            allowed_asset_classes = ?universe.allow.asset_classes,
+            blocked_symbols = universe.block.symbols.len(),
+            blocked_patterns = universe.block.patterns.len(),
+            blocked_asset_classes = ?universe.block.asset_classes,
+            "Instrument universe loaded"
+        );
+    }
+
+    Ok(universe)
+}
+
+/// Load point-in-time index membership entries are checked against.
+fn load_universe_membership(
+    config: &EngineConfig,
+) -> Result<Option<Arc<UniverseMembership>>, Box<dyn std::error::Error>> {
+    let Some(path) = &config.risk.universe_constituents_path else {
+        return Ok(None);
+    };
+
+    let membership = load_universe_csv(Path::new(path))?;
+    let today = chrono::Utc::now().date_naive();
+    tracing::info!(
+        universe = membership.name(),
+        symbols = membership.symbol_count(),
+        members_today = membership.members_on(today).len(),
+        "Universe membership loaded"
+    );
+
+    Ok(Some(Arc::new(membership)))
+}
+
+/// Load API keys for HTTP and gRPC authentication.
+fn create_authenticator(
+    config: &EngineConfig,
+) -> Result<Option<Arc<ApiKeyAuthenticator>>, Box<dyn std::error::Error>> {
+    let Some(path) = &config.api.keys_path else {
+        if config.environment.is_live() {
+            tracing::warn!("API authentication is disabled in LIVE mode");
+        }
+        return Ok(None);
+    };
+
+    let authenticator =
+        ApiKeyAuthenticator::from_file(std::path::Path::new(path), config.trading_environment())?;
+    tracing::info!(keys = authenticator.len(), "API authentication enabled");
+
+    Ok(Some(Arc::new(authenticator)))
+}
+
+/// Load gRPC TLS certificates and start watching them for rotation.
+fn create_grpc_tls(
+    config: &EngineConfig,
+    shutdown: CancellationToken,
+) -> Result<Option<Arc<ReloadingTlsAcceptor>>, Box<dyn std::error::Error>> {
+    let Some(tls_config) = config.api.grpc_tls.clone() else {
+        if config.environment.is_live() {
+            tracing::warn!("gRPC server is running without TLS in LIVE mode");
+        }
+        return Ok(None);
+    };
+
+    let mutual = tls_config.is_mutual();
+    let acceptor = Arc::new(ReloadingTlsAcceptor::new(tls_config)?);
+    acceptor.start(shutdown);
+    tracing::info!(mutual, "gRPC TLS enabled");
+
+    Ok(Some(acceptor))
+}
+
 /// Start the gRPC server with graceful shutdown support.
 ///
 /// # Panics
@@ -476,11 +2881,13 @@ async fn start_http_server(
 fn start_grpc_server(
     config: &EngineConfig,
     use_cases: &UseCases,
-    broker: Arc<AlpacaBrokerAdapter>,
-    market_data: Arc<AlpacaMarketDataAdapter>,
+    broker: Arc<Broker>,
+    market_data: Arc<ConcreteMarketData>,
+    security: &ApiSecurity,
     shutdown_tx: broadcast::Sender<()>,
+    shutdown_token: CancellationToken,
 ) -> JoinHandle<()> {
-    let grpc_addr: SocketAddr = format!("0.0.0.0:{}", config.grpc_port)
+    let grpc_addr: SocketAddr = format!("0.0.0.0:{}", config.api.grpc_port)
         .parse()
         .expect("static address format '0.0.0.0:{port}' with u16 port is always valid");
 
@@ -488,11 +2895,30 @@ fn start_grpc_server(
     tracing::info!("gRPC services:");
     tracing::info!("  ExecutionService - CheckConstraints, SubmitOrder, GetOrderState, etc.");
     tracing::info!("  MarketDataService - GetSnapshot, GetOptionChain, SubscribeMarketData");
+    tracing::info!("  grpc.health.v1.Health - Check, Watch");
 
     let grpc_submit = Arc::clone(&use_cases.submit_orders);
     let grpc_validate = Arc::clone(&use_cases.validate_risk);
     let grpc_cancel = Arc::clone(&use_cases.cancel_orders);
     let grpc_order_repo = Arc::clone(&use_cases.order_repo);
+    let grpc_lifecycle = Arc::clone(&use_cases.lifecycle);
+    let grpc_price_feed = Arc::clone(&use_cases.price_feed) as Arc<dyn PriceFeedPort>;
+    let grpc_realized_pnl = Arc::clone(&use_cases.realized_pnl);
+    let net_ladder = config.net_ladder.clone();
+
+    let (health_reporter, health_service) = tonic_health::server::health_reporter();
+    let health_updater = GrpcHealthUpdater::new(
+        health_reporter,
+        Arc::clone(&broker),
+        Arc::clone(&market_data),
+    );
+    let health_shutdown = shutdown_token.clone();
+    tokio::spawn(async move {
+        health_updater.run(health_shutdown).await;
+    });
+
+    let auth_layer = GrpcAuthLayer::new(security.auth.clone());
+    let tls = security.grpc_tls.clone();
 
     tokio::spawn(async move {
         let mut shutdown_rx = shutdown_tx.subscribe();
@@ -503,42 +2929,109 @@ fn start_grpc_server(
             grpc_cancel,
             grpc_order_repo,
             broker,
+            grpc_lifecycle,
+            grpc_realized_pnl,
         );
 
-        let market_data_service = create_market_data_service(market_data);
+        let market_data_service =
+            create_market_data_service(market_data, grpc_price_feed, net_ladder);
 
-        let server = tonic::transport::Server::builder()
+        let router = tonic::transport::Server::builder()
+            .layer(auth_layer)
+            .add_service(health_service)
             .add_service(execution_service)
-            .add_service(market_data_service)
-            .serve_with_shutdown(grpc_addr, async move {
-                let _ = shutdown_rx.recv().await;
-                tracing::info!("gRPC server shutting down");
-            });
+            .add_service(market_data_service);
+        let signal = async move {
+            let _ = shutdown_rx.recv().await;
+            tracing::info!("gRPC server shutting down");
+        };
+
+        let result = match tls {
+            Some(tls) => {
+                let listener = match TcpListener::bind(grpc_addr).await {
+                    Ok(listener) => listener,
+                    Err(e) => {
+                        tracing::error!("gRPC server bind error: {e}");
+                        return;
+                    }
+                };
+                router
+                    .serve_with_incoming_shutdown(tls.incoming(listener, shutdown_token), signal)
+                    .await
+            }
+            None => router.serve_with_shutdown(grpc_addr, signal).await,
+        };
 
-        if let Err(e) = server.await {
+        if let Err(e) = result {
             tracing::error!("gRPC server error: {e}");
         }
     })
 }
 
-/// Wait for either server to stop.
+/// Wait for either server to stop, or for startup recovery to give up.
 async fn await_shutdown(
     http_handle: JoinHandle<()>,
     grpc_handle: JoinHandle<()>,
-    shutdown_token: CancellationToken,
-) {
+    recovery_handle: JoinHandle<Result<(), String>>,
+) -> Result<(), Fatal> {
+    let recovery_failed = async {
+        match recovery_handle.await {
+            Ok(Err(message)) => message,
+            _ => std::future::pending().await,
+        }
+    };
     tokio::select! {
         _ = http_handle => {
             tracing::info!("HTTP server stopped");
+            Ok(())
         }
         _ = grpc_handle => {
             tracing::info!("gRPC server stopped");
+            Ok(())
         }
+        message = recovery_failed => Err(Fatal::recovery(message)),
     }
+}
 
-    // Cancel WebSocket streams and position monitor
-    shutdown_token.cancel();
-    tracing::info!("Cancellation token triggered for background services");
+/// Write the signed state bundle to `path` so it can be imported on restart.
+///
+/// The in-memory order store does not survive the process; position
+/// lifecycles and scheduled plans are already on disk.
+async fn write_shutdown_snapshot(state_bundle: &ConcreteStateBundleUseCase, path: &Path) {
+    let bytes = match state_bundle
+        .export()
+        .await
+        .map(|signed| serde_json::to_vec_pretty(&signed))
+    {
+        Ok(Ok(bytes)) => bytes,
+        Ok(Err(e)) => {
+            tracing::error!(error = %e, "Failed to encode shutdown snapshot");
+            return;
+        }
+        Err(e) => {
+            tracing::error!(error = %e, "Failed to export shutdown snapshot");
+            return;
+        }
+    };
+
+    if let Some(parent) = path.parent()
+        && !parent.as_os_str().is_empty()
+        && let Err(e) = tokio::fs::create_dir_all(parent).await
+    {
+        tracing::error!(path = %parent.display(), error = %e, "Failed to create snapshot directory");
+        return;
+    }
+    let tmp_path = path.with_extension("json.tmp");
+    let written = match tokio::fs::write(&tmp_path, bytes).await {
+        Ok(()) => tokio::fs::rename(&tmp_path, path).await,
+        Err(e) => Err(e),
+    };
+    match written {
+        Ok(()) => tracing::info!(path = %path.display(), "Shutdown snapshot written"),
+        Err(e) => {
+            tracing::error!(path = %path.display(), error = %e, "Failed to write shutdown snapshot");
+        }
+    }
 }
 
 /// Load .env file from current directory or any ancestor directory.
@@ -560,6 +3053,42 @@ fn load_dotenv_from_ancestors() {
     }
 }
 
+/// Reload the config file on SIGHUP.
+///
+/// Rejected reloads are logged and leave the running settings in place.
+fn watch_config_reload(reloader: Arc<ConcreteConfigReloader>, shutdown: CancellationToken) {
+    #[cfg(unix)]
+    {
+        let mut hangup = match signal::unix::signal(signal::unix::SignalKind::hangup()) {
+            Ok(hangup) => hangup,
+            Err(e) => {
+                tracing::warn!(error = %e, "Failed to install SIGHUP handler, config reload via API only");
+                return;
+            }
+        };
+
+        tokio::spawn(async move {
+            loop {
+                tokio::select! {
+                    received = hangup.recv() => {
+                        if received.is_none() {
+                            break;
+                        }
+                        tracing::info!("Received SIGHUP, reloading config");
+                        let _ = reloader.reload("SIGHUP").await;
+                    }
+                    () = shutdown.cancelled() => break,
+                }
+            }
+        });
+    }
+
+    #[cfg(not(unix))]
+    {
+        let _ = (reloader, shutdown);
+    }
+}
+
 /// Wait for shutdown signal (SIGTERM or SIGINT).
 ///
 /// # Panics
@@ -569,7 +3098,7 @@ fn load_dotenv_from_ancestors() {
 /// - Failure to install handlers means the process cannot respond to termination signals
 /// - It is better to fail fast during startup than to have an unresponsive process
 #[allow(clippy::expect_used)]
-async fn shutdown_signal(shutdown_tx: broadcast::Sender<()>) {
+async fn shutdown_signal(shutdown_tx: broadcast::Sender<()>, shutdown: Arc<ShutdownCoordinator>) {
     let ctrl_c = async {
         signal::ctrl_c()
             .await
@@ -596,6 +3125,8 @@ async fn shutdown_signal(shutdown_tx: broadcast::Sender<()>) {
         }
     }
 
+    // Refuse new plans before the servers stop accepting connections
+    shutdown.stop_accepting();
     let _ = shutdown_tx.send(());
 
     tracing::info!(
@@ -0,0 +1,1385 @@
+//! Mock Alpaca trading API.
+//!
+//! [`MockAlpacaServer`] listens on a loopback port and speaks Alpaca's wire
+//! format: the same paths, JSON shapes, status codes, and `Retry-After`
+//! headers as `paper-api.alpaca.markets`, plus the `/stream` WebSocket that
+//! carries `trade_updates` and the `/v2/events/trades` SSE endpoint that
+//! carries the same events. Point any adapter built from [`AlpacaConfig`] at
+//! it with [`MockAlpacaServer::config`].
+//!
+//! # Scripting
+//!
+//! - **Faults**: [`Fault`]s queue error statuses, rate limits, and latency
+//!   for the next matching requests, ahead of authentication and routing.
+//! - **Order lifecycle**: submitted orders rest until the test fills,
+//!   cancels, expires, or rejects them; each transition is published on the
+//!   streams and fills are booked as positions and `FILL` activities.
+//! - **Request log**: every REST request is recorded for asserting retry
+//!   counts and pagination.
+//! - **OAuth**: `POST /oauth/token` exchanges [`MockAlpacaServer::OAUTH_CODE`]
+//!   and issued refresh tokens for bearer tokens the REST API accepts in
+//!   place of the key headers.
+//!
+//! Order IDs come from a counter rather than random UUIDs, so runs are
+//! deterministic.
+
+use std::collections::{BTreeMap, HashMap, HashSet};
+use std::convert::Infallible;
+use std::net::SocketAddr;
+use std::sync::Arc;
+use std::sync::atomic::{AtomicU64, AtomicUsize, Ordering};
+use std::time::Duration;
+
+use axum::extract::ws::{Message, WebSocket, WebSocketUpgrade};
+use axum::extract::{Path, Query, Request, State};
+use axum::http::{HeaderMap, Method, StatusCode, header};
+use axum::middleware::{self, Next};
+use axum::response::sse::{Event, KeepAlive, Sse};
+use axum::response::{IntoResponse, Response};
+use axum::routing::{get, post};
+use axum::{Form, Json, Router};
+use chrono::{DateTime, SecondsFormat, Utc};
+use parking_lot::{Mutex, RwLock};
+use rust_decimal::Decimal;
+use serde::Deserialize;
+use serde_json::{Value, json};
+use tokio::net::TcpListener;
+use tokio::sync::broadcast;
+use tokio_util::sync::CancellationToken;
+
+use crate::infrastructure::broker::alpaca::{
+    AlpacaConfig, AlpacaEnvironment, AlpacaOAuthConfig, RetryConfig,
+};
+
+/// Default page size of `GET /v2/orders`.
+const ORDERS_DEFAULT_LIMIT: usize = 50;
+
+/// Maximum page size of `GET /v2/orders`.
+const ORDERS_MAX_LIMIT: usize = 500;
+
+/// Maximum page size of `GET /v2/account/activities`.
+const ACTIVITIES_MAX_PAGE_SIZE: usize = 100;
+
+/// Channel capacity for stream events.
+const STREAM_CHANNEL_CAPACITY: usize = 256;
+
+/// Order statuses that can still fill or be canceled.
+const OPEN_STATUSES: &[&str] = &["new", "accepted", "pending_new", "partially_filled"];
+
+// =============================================================================
+// Faults
+// =============================================================================
+
+/// A scripted failure for requests matching a method and path prefix.
+///
+/// Faults are consumed in the order they were injected; each applies to
+/// [`times`](Self::times) requests and is then discarded.
+#[derive(Debug, Clone)]
+pub struct Fault {
+    method: Option<Method>,
+    path: String,
+    status: Option<StatusCode>,
+    retry_after_secs: Option<u64>,
+    body: Option<String>,
+    delay: Duration,
+    remaining: usize,
+}
+
+impl Fault {
+    /// Respond to requests under `path` with `status`.
+    #[must_use]
+    pub fn new(path: impl Into<String>, status: StatusCode) -> Self {
+        Self {
+            method: None,
+            path: path.into(),
+            status: Some(status),
+            retry_after_secs: None,
+            body: None,
+            delay: Duration::ZERO,
+            remaining: 1,
+        }
+    }
+
+    /// Respond to requests under `path` with `429 Too Many Requests`.
+    #[must_use]
+    pub fn rate_limited(path: impl Into<String>) -> Self {
+        Self::new(path, StatusCode::TOO_MANY_REQUESTS)
+    }
+
+    /// Delay requests under `path` by `delay`, then serve them normally.
+    #[must_use]
+    pub fn latency(path: impl Into<String>, delay: Duration) -> Self {
+        Self {
+            status: None,
+            delay,
+            ..Self::new(path, StatusCode::OK)
+        }
+    }
+
+    /// Only match requests with this method.
+    #[must_use]
+    pub fn on(mut self, method: Method) -> Self {
+        self.method = Some(method);
+        self
+    }
+
+    /// Apply to the next `times` matching requests (default 1).
+    #[must_use]
+    pub const fn times(mut self, times: usize) -> Self {
+        self.remaining = times;
+        self
+    }
+
+    /// Send a `Retry-After` header.
+    #[must_use]
+    pub const fn retry_after(mut self, secs: u64) -> Self {
+        self.retry_after_secs = Some(secs);
+        self
+    }
+
+    /// Replace the default JSON error body.
+    #[must_use]
+    pub fn body(mut self, body: impl Into<String>) -> Self {
+        self.body = Some(body.into());
+        self
+    }
+
+    /// Wait before responding.
+    #[must_use]
+    pub const fn delay(mut self, delay: Duration) -> Self {
+        self.delay = delay;
+        self
+    }
+
+    fn matches(&self, method: &Method, path: &str) -> bool {
+        self.method.as_ref().is_none_or(|m| m == method) && path.starts_with(&self.path)
+    }
+
+    fn response(&self, status: StatusCode) -> Response {
+        let body = self.body.clone().unwrap_or_else(|| {
+            json!({
+                "code": u32::from(status.as_u16()) * 100_000,
+                "message": status.canonical_reason().unwrap_or("mock fault"),
+            })
+            .to_string()
+        });
+
+        let mut response =
+            (status, [(header::CONTENT_TYPE, "application/json")], body).into_response();
+        if let Some(secs) = self.retry_after_secs {
+            response
+                .headers_mut()
+                .insert(header::RETRY_AFTER, secs.into());
+        }
+        response
+    }
+}
+
+// =============================================================================
+// Recorded State
+// =============================================================================
+
+/// A REST request received by the mock.
+#[derive(Debug, Clone)]
+pub struct RecordedRequest {
+    /// HTTP method.
+    pub method: Method,
+    /// Path, without the query string.
+    pub path: String,
+    /// Raw query string.
+    pub query: Option<String>,
+}
+
+/// An order held by the mock.
+#[derive(Debug, Clone)]
+pub struct MockOrder {
+    /// Broker order ID.
+    pub id: String,
+    /// Client order ID.
+    pub client_order_id: String,
+    /// Symbol.
+    pub symbol: String,
+    /// Side (`buy` or `sell`).
+    pub side: String,
+    /// Order type (`market`, `limit`, ...).
+    pub order_type: String,
+    /// Time in force.
+    pub time_in_force: String,
+    /// Ordered quantity.
+    pub qty: Decimal,
+    /// Filled quantity.
+    pub filled_qty: Decimal,
+    /// Volume-weighted fill price.
+    pub filled_avg_price: Option<Decimal>,
+    /// Limit price.
+    pub limit_price: Option<Decimal>,
+    /// Stop price.
+    pub stop_price: Option<Decimal>,
+    /// Alpaca order status.
+    pub status: String,
+    /// Extended hours eligibility.
+    pub extended_hours: bool,
+    /// Creation time.
+    pub created_at: DateTime<Utc>,
+    /// Last update time.
+    pub updated_at: DateTime<Utc>,
+    /// Time the order was completely filled.
+    pub filled_at: Option<DateTime<Utc>>,
+}
+
+impl MockOrder {
+    /// Whether the order can still fill or be canceled.
+    #[must_use]
+    pub fn is_open(&self) -> bool {
+        OPEN_STATUSES.contains(&self.status.as_str())
+    }
+
+    fn to_json(&self) -> Value {
+        json!({
+            "id": self.id,
+            "client_order_id": self.client_order_id,
+            "created_at": timestamp(self.created_at),
+            "updated_at": timestamp(self.updated_at),
+            "submitted_at": timestamp(self.created_at),
+            "filled_at": self.filled_at.map(timestamp),
+            "asset_class": "us_equity",
+            "symbol": self.symbol,
+            "qty": self.qty.to_string(),
+            "filled_qty": self.filled_qty.to_string(),
+            "filled_avg_price": self.filled_avg_price.map(|p| p.normalize().to_string()),
+            "order_class": "",
+            "order_type": self.order_type,
+            "type": self.order_type,
+            "side": self.side,
+            "time_in_force": self.time_in_force,
+            "limit_price": self.limit_price.map(|p| p.to_string()),
+            "stop_price": self.stop_price.map(|p| p.to_string()),
+            "status": self.status,
+            "extended_hours": self.extended_hours,
+            "legs": null,
+        })
+    }
+}
+
+/// A position held by the mock.
+#[derive(Debug, Clone, Copy)]
+struct MockPosition {
+    /// Signed quantity (negative when short).
+    qty: Decimal,
+    avg_entry_price: Decimal,
+}
+
+/// An event for connected `/stream` and `/v2/events/trades` sessions.
+#[derive(Debug, Clone)]
+enum StreamEvent {
+    /// A trade update payload (`{event, timestamp, order}`) and its
+    /// sequential event ID.
+    TradeUpdate(u64, Arc<Value>),
+    /// Drop every connection.
+    Disconnect,
+}
+
+/// Mutable broker state.
+#[derive(Debug)]
+struct Book {
+    orders: Vec<MockOrder>,
+    positions: BTreeMap<String, MockPosition>,
+    assets: BTreeMap<String, bool>,
+    activities: Vec<Value>,
+    buying_power: Decimal,
+    market_open: bool,
+    faults: Vec<Fault>,
+    requests: Vec<RecordedRequest>,
+    next_id: u64,
+    access_tokens: HashSet<String>,
+    refresh_tokens: HashSet<String>,
+    oauth_expires_in: u64,
+    next_token: u64,
+}
+
+impl Book {
+    fn new() -> Self {
+        Self {
+            orders: Vec::new(),
+            positions: BTreeMap::new(),
+            assets: BTreeMap::new(),
+            activities: Vec::new(),
+            buying_power: Decimal::new(100_000, 0),
+            market_open: true,
+            faults: Vec::new(),
+            requests: Vec::new(),
+            next_id: 0,
+            access_tokens: HashSet::new(),
+            refresh_tokens: HashSet::new(),
+            oauth_expires_in: 3600,
+            next_token: 0,
+        }
+    }
+
+    const fn next_id(&mut self) -> u64 {
+        self.next_id += 1;
+        self.next_id
+    }
+
+    fn order_mut(&mut self, order_id: &str) -> Option<&mut MockOrder> {
+        self.orders.iter_mut().find(|o| o.id == order_id)
+    }
+
+    fn take_fault(&mut self, method: &Method, path: &str) -> Option<Fault> {
+        let index = self.faults.iter().position(|f| f.matches(method, path))?;
+        let fault = &mut self.faults[index];
+        fault.remaining = fault.remaining.saturating_sub(1);
+        if fault.remaining == 0 {
+            Some(self.faults.remove(index))
+        } else {
+            Some(fault.clone())
+        }
+    }
+
+    /// Apply a fill to the position in `symbol`.
+    fn book_fill(&mut self, symbol: &str, signed_qty: Decimal, price: Decimal) -> Decimal {
+        let position = self
+            .positions
+            .entry(symbol.to_string())
+            .or_insert(MockPosition {
+                qty: Decimal::ZERO,
+                avg_entry_price: price,
+            });
+
+        let new_qty = position.qty + signed_qty;
+        let was_short = position.qty.is_sign_negative();
+        if position.qty.is_zero() || signed_qty.is_sign_negative() == was_short {
+            position.avg_entry_price =
+                (position.avg_entry_price * position.qty + price * signed_qty) / new_qty;
+        } else if !new_qty.is_zero() && new_qty.is_sign_negative() != was_short {
+            // Flipped through flat: the remainder opened at the fill price
+            position.avg_entry_price = price;
+        }
+        position.qty = new_qty;
+
+        if new_qty.is_zero() {
+            self.positions.remove(symbol);
+        }
+        new_qty
+    }
+}
+
+/// State shared between the server task and the test.
+struct MockState {
+    credentials: RwLock<(String, String)>,
+    book: Mutex<Book>,
+    stream_tx: broadcast::Sender<StreamEvent>,
+    event_seq: AtomicU64,
+    listeners: AtomicUsize,
+    sessions: AtomicUsize,
+    shutdown: CancellationToken,
+}
+
+/// Counts a session receiving trade updates while alive.
+struct Listener(Arc<MockState>);
+
+impl Listener {
+    fn new(state: &Arc<MockState>) -> Self {
+        state.listeners.fetch_add(1, Ordering::Relaxed);
+        state.sessions.fetch_add(1, Ordering::Relaxed);
+        Self(Arc::clone(state))
+    }
+}
+
+impl Drop for Listener {
+    fn drop(&mut self) {
+        self.0.listeners.fetch_sub(1, Ordering::Relaxed);
+    }
+}
+
+impl MockState {
+    fn credentials_match(&self, key: &str, secret: &str) -> bool {
+        let credentials = self.credentials.read();
+        credentials.0 == key && credentials.1 == secret
+    }
+
+    fn is_authorized(&self, headers: &HeaderMap) -> bool {
+        let header = |name| {
+            headers
+                .get(name)
+                .and_then(|v| v.to_str().ok())
+                .unwrap_or_default()
+        };
+        if let Some(token) = header(header::AUTHORIZATION.as_str()).strip_prefix("Bearer ") {
+            return self.book.lock().access_tokens.contains(token);
+        }
+        self.credentials_match(header("APCA-API-KEY-ID"), header("APCA-API-SECRET-KEY"))
+    }
+
+    fn publish(&self, event: &str, order: &MockOrder, fill: Option<(Decimal, Decimal)>) {
+        let mut data = json!({
+            "event": event,
+            "timestamp": timestamp(order.updated_at),
+            "order": order.to_json(),
+        });
+        if let Some((qty, price)) = fill {
+            data["qty"] = json!(qty.to_string());
+            data["price"] = json!(price.to_string());
+        }
+
+        let id = self.event_seq.fetch_add(1, Ordering::Relaxed) + 1;
+        let _ = self
+            .stream_tx
+            .send(StreamEvent::TradeUpdate(id, Arc::new(data)));
+    }
+}
+
+// =============================================================================
+// Server
+// =============================================================================
+
+/// Wire-level mock of the Alpaca trading API.
+///
+/// The server stops when the handle is dropped.
+pub struct MockAlpacaServer {
+    addr: SocketAddr,
+    state: Arc<MockState>,
+}
+
+impl std::fmt::Debug for MockAlpacaServer {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("MockAlpacaServer")
+            .field("addr", &self.addr)
+            .finish_non_exhaustive()
+    }
+}
+
+impl MockAlpacaServer {
+    /// API key the mock accepts until [`set_credentials`](Self::set_credentials).
+    pub const API_KEY: &'static str = "PKMOCK";
+
+    /// API secret the mock accepts until [`set_credentials`](Self::set_credentials).
+    pub const API_SECRET: &'static str = "mock-secret";
+
+    /// OAuth client ID the token endpoint accepts.
+    pub const OAUTH_CLIENT_ID: &'static str = "mock-oauth-client";
+
+    /// OAuth client secret the token endpoint accepts.
+    pub const OAUTH_CLIENT_SECRET: &'static str = "mock-oauth-secret";
+
+    /// Authorization code the token endpoint exchanges.
+    pub const OAUTH_CODE: &'static str = "mock-code";
+
+    /// Start the mock on an ephemeral loopback port.
+    ///
+    /// # Errors
+    ///
+    /// Returns error if the listener cannot be bound.
+    pub async fn start() -> std::io::Result<Self> {
+        let listener = TcpListener::bind("127.0.0.1:0").await?;
+        let addr = listener.local_addr()?;
+
+        let (stream_tx, _) = broadcast::channel(STREAM_CHANNEL_CAPACITY);
+        let state = Arc::new(MockState {
+            credentials: RwLock::new((Self::API_KEY.to_string(), Self::API_SECRET.to_string())),
+            book: Mutex::new(Book::new()),
+            stream_tx,
+            event_seq: AtomicU64::new(0),
+            listeners: AtomicUsize::new(0),
+            sessions: AtomicUsize::new(0),
+            shutdown: CancellationToken::new(),
+        });
+
+        let router = router(Arc::clone(&state));
+        let shutdown = state.shutdown.clone();
+        tokio::spawn(async move {
+            let server = axum::serve(listener, router)
+                .with_graceful_shutdown(async move { shutdown.cancelled().await });
+            if let Err(e) = server.await {
+                tracing::warn!(error = %e, "Mock Alpaca server stopped");
+            }
+        });
+
+        Ok(Self { addr, state })
+    }
+
+    /// Base URL for the REST API (`http://127.0.0.1:<port>`).
+    #[must_use]
+    pub fn base_url(&self) -> String {
+        format!("http://{}", self.addr)
+    }
+
+    /// URL of the trading stream (`ws://127.0.0.1:<port>/stream`).
+    #[must_use]
+    pub fn stream_url(&self) -> String {
+        format!("ws://{}/stream", self.addr)
+    }
+
+    /// Paper config pointed at the mock, with millisecond retry backoff.
+    #[must_use]
+    pub fn config(&self) -> AlpacaConfig {
+        AlpacaConfig::new(
+            Self::API_KEY.to_string(),
+            Self::API_SECRET.to_string(),
+            AlpacaEnvironment::Paper,
+        )
+        .with_base_url(self.base_url())
+        .with_timeout(Duration::from_secs(5))
+        .with_retry(RetryConfig {
+            max_attempts: 3,
+            initial_backoff: Duration::from_millis(1),
+            max_backoff: Duration::from_millis(10),
+            multiplier: 2.0,
+        })
+    }
+
+    /// OAuth config exchanging codes at the mock's token endpoint.
+    #[must_use]
+    pub fn oauth_config(&self) -> AlpacaOAuthConfig {
+        AlpacaOAuthConfig::new(
+            Self::OAUTH_CLIENT_ID,
+            Self::OAUTH_CLIENT_SECRET,
+            "http://127.0.0.1/api/v1/oauth/alpaca/callback",
+        )
+        .with_token_url(format!("{}/oauth/token", self.base_url()))
+    }
+
+    /// Set the `expires_in` of tokens issued from now on.
+    pub fn set_oauth_expires_in(&self, secs: u64) {
+        self.state.book.lock().oauth_expires_in = secs;
+    }
+
+    /// Accept only these credentials from now on.
+    pub fn set_credentials(&self, api_key: &str, api_secret: &str) {
+        *self.state.credentials.write() = (api_key.to_string(), api_secret.to_string());
+    }
+
+    /// Set the account's buying power.
+    pub fn set_buying_power(&self, buying_power: Decimal) {
+        self.state.book.lock().buying_power = buying_power;
+    }
+
+    /// Set whether `/v2/clock` reports the market open.
+    pub fn set_market_open(&self, open: bool) {
+        self.state.book.lock().market_open = open;
+    }
+
+    /// List an active asset.
+    pub fn add_asset(&self, symbol: &str, tradable: bool) {
+        self.state
+            .book
+            .lock()
+            .assets
+            .insert(symbol.to_string(), tradable);
+    }
+
+    /// Set a position directly (negative quantity for short).
+    pub fn set_position(&self, symbol: &str, qty: Decimal, avg_entry_price: Decimal) {
+        let mut book = self.state.book.lock();
+        if qty.is_zero() {
+            book.positions.remove(symbol);
+        } else {
+            book.positions.insert(
+                symbol.to_string(),
+                MockPosition {
+                    qty,
+                    avg_entry_price,
+                },
+            );
+        }
+    }
+
+    /// Add a raw account activity, as returned by `/v2/account/activities`.
+    ///
+    /// Activities are paged in `id` order, so `id` must sort chronologically.
+    pub fn add_activity(&self, activity: Value) {
+        let mut book = self.state.book.lock();
+        book.activities.push(activity);
+        book.activities
+            .sort_by(|a, b| a["id"].as_str().cmp(&b["id"].as_str()));
+    }
+
+    /// Queue a fault.
+    pub fn inject(&self, fault: Fault) {
+        self.state.book.lock().faults.push(fault);
+    }
+
+    /// Fill up to `qty` of an open order at `price`.
+    ///
+    /// Updates the position, books a `FILL` activity, and publishes a
+    /// `partial_fill` or `fill` trade update. Returns the updated order, or
+    /// `None` if the order is unknown or no longer open.
+    #[must_use]
+    pub fn fill_order(&self, order_id: &str, qty: Decimal, price: Decimal) -> Option<MockOrder> {
+        let now = Utc::now();
+        let mut book = self.state.book.lock();
+        let seq = book.next_id();
+
+        let order = book.order_mut(order_id).filter(|o| o.is_open())?;
+        let qty = qty.min(order.qty - order.filled_qty);
+        if qty <= Decimal::ZERO {
+            return None;
+        }
+
+        let notional = order.filled_avg_price.unwrap_or_default() * order.filled_qty + price * qty;
+        order.filled_qty += qty;
+        order.filled_avg_price = Some(notional / order.filled_qty);
+        order.updated_at = now;
+        let event = if order.filled_qty == order.qty {
+            order.status = "filled".to_string();
+            order.filled_at = Some(now);
+            "fill"
+        } else {
+            order.status = "partially_filled".to_string();
+            "partial_fill"
+        };
+        let order = order.clone();
+
+        let signed_qty = if order.side == "buy" { qty } else { -qty };
+        book.book_fill(&order.symbol, signed_qty, price);
+        book.activities.push(json!({
+            "id": format!("{}::{seq:012}", now.format("%Y%m%d%H%M%S%3f")),
+            "activity_type": "FILL",
+            "transaction_time": timestamp(now),
+            "type": event,
+            "price": price.to_string(),
+            "qty": qty.to_string(),
+            "side": order.side,
+            "symbol": order.symbol,
+            "leaves_qty": (order.qty - order.filled_qty).to_string(),
+            "order_id": order.id,
+            "cum_qty": order.filled_qty.to_string(),
+            "order_status": order.status,
+        }));
+        drop(book);
+
+        self.state.publish(event, &order, Some((qty, price)));
+        Some(order)
+    }
+
+    /// Close an open order from the broker side (`canceled`, `expired`, or
+    /// `rejected`) and publish the matching trade update.
+    ///
+    /// Returns the updated order, or `None` if the order is unknown or no
+    /// longer open.
+    #[must_use]
+    pub fn close_order(&self, order_id: &str, status: &str) -> Option<MockOrder> {
+        let mut book = self.state.book.lock();
+        let order = book.order_mut(order_id).filter(|o| o.is_open())?;
+        order.status = status.to_string();
+        order.updated_at = Utc::now();
+        let order = order.clone();
+        drop(book);
+
+        self.state.publish(status, &order, None);
+        Some(order)
+    }
+
+    /// An order by broker ID.
+    #[must_use]
+    pub fn order(&self, order_id: &str) -> Option<MockOrder> {
+        self.state
+            .book
+            .lock()
+            .orders
+            .iter()
+            .find(|o| o.id == order_id)
+            .cloned()
+    }
+
+    /// All orders, oldest first.
+    #[must_use]
+    pub fn orders(&self) -> Vec<MockOrder> {
+        self.state.book.lock().orders.clone()
+    }
+
+    /// Every REST request received so far, oldest first.
+    #[must_use]
+    pub fn requests(&self) -> Vec<RecordedRequest> {
+        self.state.book.lock().requests.clone()
+    }
+
+    /// Number of requests received for `method` and exactly `path`.
+    #[must_use]
+    pub fn request_count(&self, method: &Method, path: &str) -> usize {
+        self.state
+            .book
+            .lock()
+            .requests
+            .iter()
+            .filter(|r| r.method == method && r.path == path)
+            .count()
+    }
+
+    /// Number of sessions receiving trade updates: `/stream` sessions that
+    /// listen to `trade_updates` plus `/v2/events/trades` sessions.
+    #[must_use]
+    pub fn trade_update_listeners(&self) -> usize {
+        self.state.listeners.load(Ordering::Relaxed)
+    }
+
+    /// Total sessions that have received trade updates, including closed
+    /// ones (for waiting on reconnects).
+    #[must_use]
+    pub fn trade_update_sessions(&self) -> usize {
+        self.state.sessions.load(Ordering::Relaxed)
+    }
+
+    /// Drop every `/stream` and `/v2/events/trades` connection, as Alpaca
+    /// does on maintenance.
+    pub fn disconnect_streams(&self) {
+        let _ = self.state.stream_tx.send(StreamEvent::Disconnect);
+    }
+}
+
+impl Drop for MockAlpacaServer {
+    fn drop(&mut self) {
+        self.state.shutdown.cancel();
+    }
+}
+
+fn router(state: Arc<MockState>) -> Router {
+    Router::new()
+        .route("/v2/account", get(get_account))
+        .route("/v2/account/activities", get(list_activities))
+        .route("/v2/clock", get(get_clock))
+        .route("/v2/orders", get(list_orders).post(submit_order))
+        .route(
+            "/v2/orders:by_client_order_id",
+            get(get_order_by_client_id).delete(cancel_order_by_client_id),
+        )
+        .route("/v2/orders/{order_id}", get(get_order).delete(cancel_order))
+        .route("/v2/positions", get(list_positions))
+        .route("/v2/positions/{symbol}", get(get_position))
+        .route("/v2/assets", get(list_assets))
+        .route("/v2/assets/{symbol}", get(get_asset))
+        .route("/v2/events/trades", get(trade_events))
+        .route("/oauth/token", post(oauth_token))
+        .layer(middleware::from_fn_with_state(
+            Arc::clone(&state),
+            intercept,
+        ))
+        .route("/stream", get(stream))
+        .with_state(state)
+}
+
+// =============================================================================
+// REST Handlers
+// =============================================================================
+
+type Shared = State<Arc<MockState>>;
+
+/// Record the request, apply any matching fault, then check credentials.
+async fn intercept(State(state): Shared, request: Request, next: Next) -> Response {
+    let method = request.method().clone();
+    let path = request.uri().path().to_string();
+
+    let fault = {
+        let mut book = state.book.lock();
+        book.requests.push(RecordedRequest {
+            method: method.clone(),
+            path: path.clone(),
+            query: request.uri().query().map(str::to_string),
+        });
+        book.take_fault(&method, &path)
+    };
+
+    if let Some(fault) = fault {
+        if !fault.delay.is_zero() {
+            tokio::time::sleep(fault.delay).await;
+        }
+        if let Some(status) = fault.status {
+            return fault.response(status);
+        }
+    }
+
+    if !path.starts_with("/oauth/") && !state.is_authorized(request.headers()) {
+        return api_error(
+            StatusCode::UNAUTHORIZED,
+            40_110_000,
+            "request is not authorized",
+        );
+    }
+
+    next.run(request).await
+}
+
+/// Exchange a code or rotate a refresh token, issuing counter-based tokens.
+async fn oauth_token(State(state): Shared, Form(form): Form<HashMap<String, String>>) -> Response {
+    let field = |name: &str| form.get(name).map_or("", String::as_str);
+    if field("client_id") != MockAlpacaServer::OAUTH_CLIENT_ID
+        || field("client_secret") != MockAlpacaServer::OAUTH_CLIENT_SECRET
+    {
+        return oauth_error(StatusCode::UNAUTHORIZED, "invalid_client");
+    }
+
+    let mut book = state.book.lock();
+    let granted = match field("grant_type") {
+        "authorization_code" => field("code") == MockAlpacaServer::OAUTH_CODE,
+        "refresh_token" => book.refresh_tokens.remove(field("refresh_token")),
+        _ => return oauth_error(StatusCode::BAD_REQUEST, "unsupported_grant_type"),
+    };
+    if !granted {
+        return oauth_error(StatusCode::BAD_REQUEST, "invalid_grant");
+    }
+
+    book.next_token += 1;
+    let access_token = format!("mock-access-{}", book.next_token);
+    let refresh_token = format!("mock-refresh-{}", book.next_token);
+    book.access_tokens.insert(access_token.clone());
+    book.refresh_tokens.insert(refresh_token.clone());
+    let expires_in = book.oauth_expires_in;
+    drop(book);
+
+    Json(json!({
+        "access_token": access_token,
+        "refresh_token": refresh_token,
+        "token_type": "bearer",
+        "scope": "account:write trading",
+        "expires_in": expires_in,
+    }))
+    .into_response()
+}
+
+async fn get_account(State(state): Shared) -> Response {
+    let buying_power = state.book.lock().buying_power.to_string();
+    Json(json!({
+        "id": "mock-account",
+        "account_number": "PA0000MOCK",
+        "status": "ACTIVE",
+        "currency": "USD",
+        "equity": buying_power,
+        "cash": buying_power,
+        "buying_power": buying_power,
+        "daytrade_count": 0,
+        "pattern_day_trader": false,
+    }))
+    .into_response()
+}
+
+async fn get_clock(State(state): Shared) -> Response {
+    let market_open = state.book.lock().market_open;
+    let now = Utc::now();
+    let (next_open, next_close) = if market_open {
+        (
+            now + chrono::Duration::days(1),
+            now + chrono::Duration::hours(1),
+        )
+    } else {
+        (
+            now + chrono::Duration::hours(1),
+            now + chrono::Duration::hours(8),
+        )
+    };
+
+    Json(json!({
+        "timestamp": timestamp(now),
+        "is_open": market_open,
+        "next_open": timestamp(next_open),
+        "next_close": timestamp(next_close),
+    }))
+    .into_response()
+}
+
+/// Body of `POST /v2/orders`.
+#[derive(Debug, Deserialize)]
+struct OrderBody {
+    symbol: String,
+    qty: Option<String>,
+    side: String,
+    #[serde(rename = "type")]
+    order_type: String,
+    time_in_force: String,
+    limit_price: Option<String>,
+    stop_price: Option<String>,
+    client_order_id: Option<String>,
+    #[serde(default)]
+    extended_hours: bool,
+}
+
+async fn submit_order(State(state): Shared, body: String) -> Response {
+    let body: OrderBody = match serde_json::from_str(&body) {
+        Ok(body) => body,
+        Err(e) => return api_error(StatusCode::UNPROCESSABLE_ENTITY, 40_010_001, &e.to_string()),
+    };
+
+    let Some(qty) = body.qty.as_deref().and_then(|q| q.parse::<Decimal>().ok()) else {
+        return api_error(
+            StatusCode::UNPROCESSABLE_ENTITY,
+            40_010_001,
+            "qty is required",
+        );
+    };
+    if qty <= Decimal::ZERO {
+        return api_error(
+            StatusCode::UNPROCESSABLE_ENTITY,
+            40_010_001,
+            "qty must be > 0",
+        );
+    }
+    if !matches!(body.side.as_str(), "buy" | "sell") {
+        return api_error(StatusCode::UNPROCESSABLE_ENTITY, 40_010_001, "invalid side");
+    }
+    let limit_price = body.limit_price.as_deref().and_then(|p| p.parse().ok());
+    let stop_price = body.stop_price.as_deref().and_then(|p| p.parse().ok());
+    let needs_limit = matches!(body.order_type.as_str(), "limit" | "stop_limit");
+    let needs_stop = matches!(body.order_type.as_str(), "stop" | "stop_limit");
+    if needs_limit && limit_price.is_none() {
+        return api_error(
+            StatusCode::UNPROCESSABLE_ENTITY,
+            40_010_001,
+            "limit_price is required",
+        );
+    }
+    if needs_stop && stop_price.is_none() {
+        return api_error(
+            StatusCode::UNPROCESSABLE_ENTITY,
+            40_010_001,
+            "stop_price is required",
+        );
+    }
+
+    let order = {
+        let mut book = state.book.lock();
+        let id = book.next_id();
+        let client_order_id = body.client_order_id.unwrap_or_else(|| format!("mock-{id}"));
+        if book
+            .orders
+            .iter()
+            .any(|o| o.client_order_id == client_order_id)
+        {
+            return api_error(
+                StatusCode::UNPROCESSABLE_ENTITY,
+                40_010_001,
+                "client_order_id must be unique",
+            );
+        }
+
+        let now = Utc::now();
+        let order = MockOrder {
+            id: format!("00000000-0000-4000-8000-{id:012}"),
+            client_order_id,
+            symbol: body.symbol,
+            side: body.side,
+            order_type: body.order_type,
+            time_in_force: body.time_in_force,
+            qty,
+            filled_qty: Decimal::ZERO,
+            filled_avg_price: None,
+            limit_price,
+            stop_price,
+            status: "new".to_string(),
+            extended_hours: body.extended_hours,
+            created_at: now,
+            updated_at: now,
+            filled_at: None,
+        };
+        book.orders.push(order.clone());
+        order
+    };
+
+    state.publish("new", &order, None);
+    Json(order.to_json()).into_response()
+}
+
+async fn list_orders(
+    State(state): Shared,
+    Query(query): Query<HashMap<String, String>>,
+) -> Response {
+    let status = query.get("status").map_or("open", String::as_str);
+    let limit = query
+        .get("limit")
+        .and_then(|l| l.parse().ok())
+        .unwrap_or(ORDERS_DEFAULT_LIMIT)
+        .min(ORDERS_MAX_LIMIT);
+
+    // Newest first, as Alpaca's default `direction=desc`
+    let orders: Vec<Value> = state
+        .book
+        .lock()
+        .orders
+        .iter()
+        .rev()
+        .filter(|o| match status {
+            "open" => o.is_open(),
+            "closed" => !o.is_open(),
+            _ => true,
+        })
+        .take(limit)
+        .map(MockOrder::to_json)
+        .collect();
+
+    Json(orders).into_response()
+}
+
+async fn get_order(State(state): Shared, Path(order_id): Path<String>) -> Response {
+    let order = state
+        .book
+        .lock()
+        .orders
+        .iter()
+        .find(|o| o.id == order_id)
+        .map(MockOrder::to_json);
+
+    order.map_or_else(order_not_found, |order| Json(order).into_response())
+}
+
+async fn get_order_by_client_id(
+    State(state): Shared,
+    Query(query): Query<HashMap<String, String>>,
+) -> Response {
+    let client_order_id = query.get("client_order_id").map(String::as_str);
+    let order = state
+        .book
+        .lock()
+        .orders
+        .iter()
+        .find(|o| Some(o.client_order_id.as_str()) == client_order_id)
+        .map(MockOrder::to_json);
+
+    order.map_or_else(order_not_found, |order| Json(order).into_response())
+}
+
+async fn cancel_order(State(state): Shared, Path(order_id): Path<String>) -> Response {
+    cancel(&state, |o| o.id == order_id)
+}
+
+async fn cancel_order_by_client_id(
+    State(state): Shared,
+    Query(query): Query<HashMap<String, String>>,
+) -> Response {
+    let client_order_id = query.get("client_order_id").cloned().unwrap_or_default();
+    cancel(&state, |o| o.client_order_id == client_order_id)
+}
+
+/// Cancel the first order matching `predicate`.
+fn cancel(state: &MockState, predicate: impl Fn(&MockOrder) -> bool) -> Response {
+    let mut book = state.book.lock();
+    let Some(order) = book.orders.iter_mut().find(|o| predicate(o)) else {
+        return order_not_found();
+    };
+    if !order.is_open() {
+        return api_error(
+            StatusCode::UNPROCESSABLE_ENTITY,
+            42_210_000,
+            "order is not cancelable",
+        );
+    }
+    order.status = "canceled".to_string();
+    order.updated_at = Utc::now();
+    let order = order.clone();
+    drop(book);
+
+    state.publish("canceled", &order, None);
+    StatusCode::NO_CONTENT.into_response()
+}
+
+async fn list_positions(State(state): Shared) -> Response {
+    let positions: Vec<Value> = state
+        .book
+        .lock()
+        .positions
+        .iter()
+        .map(|(symbol, position)| position_json(symbol, position))
+        .collect();
+
+    Json(positions).into_response()
+}
+
+async fn get_position(State(state): Shared, Path(symbol): Path<String>) -> Response {
+    let position = state.book.lock().positions.get(&symbol).copied();
+
+    position.map_or_else(
+        || api_error(StatusCode::NOT_FOUND, 40_410_000, "position does not exist"),
+        |position| Json(position_json(&symbol, &position)).into_response(),
+    )
+}
+
+async fn list_assets(State(state): Shared) -> Response {
+    let assets: Vec<Value> = state
+        .book
+        .lock()
+        .assets
+        .iter()
+        .map(|(symbol, tradable)| asset_json(symbol, *tradable))
+        .collect();
+
+    Json(assets).into_response()
+}
+
+async fn get_asset(State(state): Shared, Path(symbol): Path<String>) -> Response {
+    let tradable = state.book.lock().assets.get(&symbol).copied();
+
+    tradable.map_or_else(
+        || api_error(StatusCode::NOT_FOUND, 40_410_000, "asset not found"),
+        |tradable| Json(asset_json(&symbol, tradable)).into_response(),
+    )
+}
+
+/// `GET /v2/account/activities`, ascending and paged by activity ID.
+async fn list_activities(
+    State(state): Shared,
+    Query(query): Query<HashMap<String, String>>,
+) -> Response {
+    let types: Option<Vec<&str>> = query.get("activity_types").map(|t| t.split(',').collect());
+    let after = query
+        .get("after")
+        .and_then(|a| DateTime::parse_from_rfc3339(a).ok())
+        .map(|a| a.with_timezone(&Utc));
+    let page_token = query.get("page_token");
+    let page_size = query
+        .get("page_size")
+        .and_then(|s| s.parse().ok())
+        .unwrap_or(ACTIVITIES_MAX_PAGE_SIZE)
+        .min(ACTIVITIES_MAX_PAGE_SIZE);
+
+    let page: Vec<Value> = state
+        .book
+        .lock()
+        .activities
+        .iter()
+        .filter(|a| {
+            types
+                .as_ref()
+                .is_none_or(|t| a["activity_type"].as_str().is_some_and(|k| t.contains(&k)))
+        })
+        .filter(|a| {
+            after.is_none_or(|after| {
+                a["transaction_time"]
+                    .as_str()
+                    .and_then(|t| DateTime::parse_from_rfc3339(t).ok())
+                    .is_none_or(|t| t > after)
+            })
+        })
+        .filter(|a| page_token.is_none_or(|token| a["id"].as_str() > Some(token.as_str())))
+        .take(page_size)
+        .cloned()
+        .collect();
+
+    Json(page).into_response()
+}
+
+// =============================================================================
+// Trading Stream
+// =============================================================================
+
+async fn stream(State(state): Shared, upgrade: WebSocketUpgrade) -> Response {
+    upgrade.on_upgrade(move |socket| run_stream(socket, state))
+}
+
+/// Serve one `/stream` session: authenticate, listen, then forward events.
+async fn run_stream(mut socket: WebSocket, state: Arc<MockState>) {
+    let mut events = state.stream_tx.subscribe();
+    let mut authorized = false;
+    let mut listener = None;
+
+    loop {
+        tokio::select! {
+            incoming = socket.recv() => {
+                let text = match incoming {
+                    Some(Ok(Message::Text(text))) => text.to_string(),
+                    Some(Ok(Message::Binary(bytes))) => String::from_utf8_lossy(&bytes).into_owned(),
+                    Some(Ok(Message::Ping(_) | Message::Pong(_))) => continue,
+                    _ => break,
+                };
+                let Ok(request) = serde_json::from_str::<Value>(&text) else {
+                    continue;
+                };
+
+                let reply = match request["action"].as_str() {
+                    Some("auth" | "authenticate") => {
+                        let (key, secret) = stream_credentials(&request);
+                        authorized = state.credentials_match(key, secret);
+                        json!({
+                            "stream": "authorization",
+                            "data": {
+                                "status": if authorized { "authorized" } else { "unauthorized" },
+                                "action": "authenticate",
+                            },
+                        })
+                    }
+                    Some("listen") if authorized => {
+                        let streams = request["data"]["streams"].clone();
+                        let listening = streams
+                            .as_array()
+                            .is_some_and(|s| s.iter().any(|s| s == "trade_updates"));
+                        listener = listening.then(|| listener.take().unwrap_or_else(|| Listener::new(&state)));
+                        json!({ "stream": "listening", "data": { "streams": streams } })
+                    }
+                    _ => continue,
+                };
+
+                if socket.send(Message::Text(reply.to_string().into())).await.is_err() || !authorized {
+                    break;
+                }
+            }
+            event = events.recv() => match event {
+                Ok(StreamEvent::TradeUpdate(_, data)) => {
+                    let message = json!({ "stream": "trade_updates", "data": *data });
+                    if listener.is_some() && socket.send(Message::Text(message.to_string().into())).await.is_err() {
+                        break;
+                    }
+                }
+                Ok(StreamEvent::Disconnect) => {
+                    let _ = socket.send(Message::Close(None)).await;
+                    break;
+                }
+                Err(broadcast::error::RecvError::Closed) => break,
+                Err(broadcast::error::RecvError::Lagged(_)) => {}
+            },
+            () = state.shutdown.cancelled() => break,
+        }
+    }
+}
+
+/// Serve one `/v2/events/trades` session until the stream disconnects.
+///
+/// Events carry sequential IDs; `Last-Event-ID` is not replayed.
+async fn trade_events(
+    State(state): Shared,
+) -> Sse<impl futures_util::Stream<Item = Result<Event, Infallible>>> {
+    let events = state.stream_tx.subscribe();
+    let listener = Listener::new(&state);
+    let stream =
+        futures_util::stream::unfold((events, listener), |(mut events, listener)| async move {
+            let state = &listener.0;
+            loop {
+                let event = tokio::select! {
+                    event = events.recv() => event,
+                    () = state.shutdown.cancelled() => return None,
+                };
+                match event {
+                    Ok(StreamEvent::TradeUpdate(id, data)) => {
+                        let event = Event::default().id(id.to_string()).data(data.to_string());
+                        return Some((Ok(event), (events, listener)));
+                    }
+                    Ok(StreamEvent::Disconnect) | Err(broadcast::error::RecvError::Closed) => {
+                        return None;
+                    }
+                    Err(broadcast::error::RecvError::Lagged(_)) => {}
+                }
+            }
+        });
+    Sse::new(stream).keep_alive(KeepAlive::default())
+}
+
+/// Key and secret from either stream auth format.
+fn stream_credentials(request: &Value) -> (&str, &str) {
+    let field = |flat: &str, nested: &str| {
+        request[flat]
+            .as_str()
+            .or_else(|| request["data"][nested].as_str())
+            .unwrap_or_default()
+    };
+    (field("key", "key_id"), field("secret", "secret_key"))
+}
+
+// =============================================================================
+// Helpers
+// =============================================================================
+
+/// Alpaca error body: `{"code": <number>, "message": <text>}`.
+fn api_error(status: StatusCode, code: u32, message: &str) -> Response {
+    (status, Json(json!({ "code": code, "message": message }))).into_response()
+}
+
+fn oauth_error(status: StatusCode, error: &str) -> Response {
+    (status, Json(json!({ "error": error }))).into_response()
+}
+
+fn order_not_found() -> Response {
+    api_error(StatusCode::NOT_FOUND, 40_410_000, "order not found")
+}
+
+fn position_json(symbol: &str, position: &MockPosition) -> Value {
+    let market_value = position.avg_entry_price * position.qty;
+    json!({
+        "asset_id": format!("mock-{symbol}"),
+        "symbol": symbol,
+        "exchange": "NASDAQ",
+        "asset_class": "us_equity",
+        "qty": position.qty.to_string(),
+        "side": if position.qty.is_sign_negative() { "short" } else { "long" },
+        "avg_entry_price": position.avg_entry_price.normalize().to_string(),
+        "market_value": market_value.normalize().to_string(),
+        "cost_basis": market_value.normalize().to_string(),
+        "current_price": position.avg_entry_price.normalize().to_string(),
+        "unrealized_pl": "0",
+        "unrealized_plpc": "0",
+    })
+}
+
+fn asset_json(symbol: &str, tradable: bool) -> Value {
+    json!({
+        "id": format!("mock-{symbol}"),
+        "class": "us_equity",
+        "exchange": "NASDAQ",
+        "symbol": symbol,
+        "name": symbol,
+        "status": "active",
+        "tradable": tradable,
+        "marginable": true,
+        "shortable": true,
+        "easy_to_borrow": true,
+        "fractionable": true,
+    })
+}
+
+fn timestamp(time: DateTime<Utc>) -> String {
+    time.to_rfc3339_opts(SecondsFormat::Nanos, true)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn fault_matches_method_and_path_prefix() {
+        let fault = Fault::new("/v2/orders", StatusCode::SERVICE_UNAVAILABLE).on(Method::POST);
+
+        assert!(fault.matches(&Method::POST, "/v2/orders"));
+        assert!(fault.matches(&Method::POST, "/v2/orders/abc"));
+        assert!(!fault.matches(&Method::GET, "/v2/orders"));
+        assert!(!fault.matches(&Method::POST, "/v2/account"));
+    }
+
+    #[test]
+    fn fault_is_consumed_after_its_count() {
+        let mut book = Book::new();
+        book.faults
+            .push(Fault::new("/v2/account", StatusCode::BAD_GATEWAY).times(2));
+
+        assert!(book.take_fault(&Method::GET, "/v2/account").is_some());
+        assert!(book.take_fault(&Method::GET, "/v2/account").is_some());
+        assert!(book.take_fault(&Method::GET, "/v2/account").is_none());
+    }
+
+    #[test]
+    fn book_fill_tracks_average_entry_and_flips() {
+        let mut book = Book::new();
+
+        book.book_fill("AAPL", Decimal::new(100, 0), Decimal::new(100, 0));
+        book.book_fill("AAPL", Decimal::new(100, 0), Decimal::new(110, 0));
+        assert_eq!(book.positions["AAPL"].avg_entry_price, Decimal::new(105, 0));
+
+        // Reducing keeps the entry price
+        book.book_fill("AAPL", Decimal::new(-50, 0), Decimal::new(120, 0));
+        assert_eq!(book.positions["AAPL"].qty, Decimal::new(150, 0));
+        assert_eq!(book.positions["AAPL"].avg_entry_price, Decimal::new(105, 0));
+
+        // Flipping short re-bases at the fill price
+        book.book_fill("AAPL", Decimal::new(-200, 0), Decimal::new(90, 0));
+        assert_eq!(book.positions["AAPL"].qty, Decimal::new(-50, 0));
+        assert_eq!(book.positions["AAPL"].avg_entry_price, Decimal::new(90, 0));
+
+        book.book_fill("AAPL", Decimal::new(50, 0), Decimal::new(95, 0));
+        assert!(!book.positions.contains_key("AAPL"));
+    }
+
+    #[test]
+    fn stream_credentials_accepts_both_auth_formats() {
+        let flat = json!({ "action": "auth", "key": "k", "secret": "s" });
+        let nested = json!({
+            "action": "authenticate",
+            "data": { "key_id": "k", "secret_key": "s" },
+        });
+
+        assert_eq!(stream_credentials(&flat), ("k", "s"));
+        assert_eq!(stream_credentials(&nested), ("k", "s"));
+    }
+}
@@ -0,0 +1,14 @@
+//! Test Utilities
+//!
+//! Wire-level stand-ins for external services, so adapters can be
+//! integration-tested against real HTTP and WebSocket traffic without
+//! network access or broker credentials.
+//!
+//! - [`MockAlpacaServer`]: Alpaca trading REST API and `trade_updates` stream
+//!
+//! Available in unit tests and, with the `test-utils` feature, to
+//! integration tests and downstream crates.
+
+mod alpaca_mock;
+
+pub use alpaca_mock::{Fault, MockAlpacaServer, MockOrder, RecordedRequest};
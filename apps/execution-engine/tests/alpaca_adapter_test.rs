@@ -0,0 +1,564 @@
+//! Alpaca Adapter Regression Tests
+//!
+//! Drives `AlpacaBrokerAdapter` against `MockAlpacaServer` over real HTTP and
+//! WebSocket connections: order lifecycle, retries (static and adaptive),
+//! rate limits, pagination, credential rotation, OAuth bearer tokens, the
+//! `trade_updates` stream, and the order update streams over WebSocket and
+//! SSE.
+//!
+//! Run: `cargo test -p execution-engine --test alpaca_adapter_test`
+
+#![allow(clippy::unwrap_used, clippy::expect_used)]
+
+use std::sync::Arc;
+use std::time::Duration;
+
+use axum::http::{Method, StatusCode};
+use futures_util::{SinkExt, StreamExt};
+use rust_decimal::Decimal;
+use serde_json::json;
+use tokio::sync::mpsc;
+use tokio_tungstenite::tungstenite::{self, Message};
+use tokio_util::sync::CancellationToken;
+
+use execution_engine::application::ports::{
+    AccountActivityPort, ActivityKind, BrokerError, BrokerPort, CancelOrderRequest,
+    InMemoryOAuthTokenRepository, OrderUpdate, OrderUpdatePort, OrderUpdateTransport,
+    SubmitOrderRequest,
+};
+use execution_engine::application::services::BrokerAccountService;
+use execution_engine::domain::order_execution::value_objects::{OrderSide, OrderStatus};
+use execution_engine::domain::shared::{BrokerId, InstrumentId, OrderId, Symbol};
+use execution_engine::infrastructure::broker::AlpacaBrokerAdapter;
+use execution_engine::infrastructure::broker::alpaca::{
+    AdaptiveRetryConfig, AlpacaOAuthClient, AlpacaOrderUpdateStream,
+};
+use execution_engine::infrastructure::secrets::AlpacaCredentials;
+use execution_engine::infrastructure::websocket::{TradeEvent, parse_trade_update};
+use execution_engine::testing::{Fault, MockAlpacaServer};
+
+// =============================================================================
+// Helpers
+// =============================================================================
+
+async fn setup() -> (MockAlpacaServer, AlpacaBrokerAdapter) {
+    let server = MockAlpacaServer::start().await.unwrap();
+    let adapter = AlpacaBrokerAdapter::new(&server.config()).unwrap();
+    (server, adapter)
+}
+
+fn market_buy(client_order_id: &str, qty: i64) -> SubmitOrderRequest {
+    SubmitOrderRequest::market(
+        OrderId::new(client_order_id),
+        Symbol::new("AAPL"),
+        OrderSide::Buy,
+        Decimal::new(qty, 0),
+    )
+}
+
+/// Next text frame from a stream, skipping control frames.
+async fn next_text<S>(ws: &mut S) -> String
+where
+    S: futures_util::Stream<Item = Result<Message, tungstenite::Error>> + Unpin,
+{
+    loop {
+        match ws.next().await.unwrap().unwrap() {
+            Message::Text(text) => return text.to_string(),
+            Message::Close(_) => panic!("stream closed"),
+            _ => {}
+        }
+    }
+}
+
+/// Wait until `sessions` trade update sessions have started and two are live.
+async fn await_listeners(server: &MockAlpacaServer, sessions: usize) {
+    tokio::time::timeout(Duration::from_secs(5), async {
+        while server.trade_update_sessions() < sessions || server.trade_update_listeners() != 2 {
+            tokio::time::sleep(Duration::from_millis(10)).await;
+        }
+    })
+    .await
+    .unwrap();
+}
+
+/// Next `count` updates, grouped by transport.
+async fn next_updates(
+    rx: &mut mpsc::Receiver<OrderUpdate>,
+    count: usize,
+) -> (Vec<OrderUpdate>, Vec<OrderUpdate>) {
+    let mut updates = Vec::new();
+    for _ in 0..count {
+        let update = tokio::time::timeout(Duration::from_secs(5), rx.recv())
+            .await
+            .unwrap()
+            .unwrap();
+        updates.push(update);
+    }
+    updates
+        .into_iter()
+        .partition(|u| u.transport == OrderUpdateTransport::WebSocket)
+}
+
+// =============================================================================
+// Order Lifecycle
+// =============================================================================
+
+#[tokio::test]
+async fn submit_order_round_trips() {
+    let (server, adapter) = setup().await;
+
+    let ack = adapter.submit_order(market_buy("ord-1", 10)).await.unwrap();
+
+    assert_eq!(ack.client_order_id.as_str(), "ord-1");
+    assert_eq!(ack.status, OrderStatus::New);
+    assert_eq!(ack.filled_qty, Decimal::ZERO);
+
+    let order = server.order(ack.broker_order_id.as_str()).unwrap();
+    assert_eq!(order.symbol, "AAPL");
+    assert_eq!(order.side, "buy");
+    assert_eq!(order.order_type, "market");
+    assert_eq!(order.qty, Decimal::new(10, 0));
+}
+
+#[tokio::test]
+async fn partial_then_full_fill_is_reported() {
+    let (server, adapter) = setup().await;
+    let ack = adapter.submit_order(market_buy("ord-1", 10)).await.unwrap();
+    let id = ack.broker_order_id.as_str();
+
+    let _ = server.fill_order(id, Decimal::new(4, 0), Decimal::new(100, 0));
+    let ack = adapter.get_order(&ack.broker_order_id).await.unwrap();
+    assert_eq!(ack.status, OrderStatus::PartiallyFilled);
+    assert_eq!(ack.filled_qty, Decimal::new(4, 0));
+
+    let _ = server.fill_order(id, Decimal::new(6, 0), Decimal::new(105, 0));
+    let ack = adapter.get_order(&ack.broker_order_id).await.unwrap();
+    assert_eq!(ack.status, OrderStatus::Filled);
+    assert_eq!(ack.filled_qty, Decimal::new(10, 0));
+    assert_eq!(ack.avg_fill_price, Some(Decimal::new(103, 0)));
+
+    let positions = adapter.get_all_positions().await.unwrap();
+    assert_eq!(positions.len(), 1);
+    assert_eq!(positions[0].quantity, Decimal::new(10, 0));
+    assert_eq!(positions[0].avg_entry_price, Decimal::new(103, 0));
+}
+
+#[tokio::test]
+async fn cancel_by_broker_and_client_id() {
+    let (server, adapter) = setup().await;
+    let first = adapter.submit_order(market_buy("ord-1", 10)).await.unwrap();
+    let second = adapter.submit_order(market_buy("ord-2", 10)).await.unwrap();
+
+    adapter
+        .cancel_order(CancelOrderRequest::by_broker_id(
+            first.broker_order_id.clone(),
+        ))
+        .await
+        .unwrap();
+    adapter
+        .cancel_order(CancelOrderRequest::by_client_id(OrderId::new("ord-2")))
+        .await
+        .unwrap();
+
+    let first = server.order(first.broker_order_id.as_str()).unwrap();
+    let second = server.order(second.broker_order_id.as_str()).unwrap();
+    assert_eq!(first.status, "canceled");
+    assert_eq!(second.status, "canceled");
+}
+
+#[tokio::test]
+async fn cancel_filled_order_fails() {
+    let (server, adapter) = setup().await;
+    let ack = adapter.submit_order(market_buy("ord-1", 10)).await.unwrap();
+    let _ = server.fill_order(
+        ack.broker_order_id.as_str(),
+        Decimal::new(10, 0),
+        Decimal::new(100, 0),
+    );
+
+    let result = adapter
+        .cancel_order(CancelOrderRequest::by_broker_id(ack.broker_order_id))
+        .await;
+
+    assert!(result.is_err());
+}
+
+#[tokio::test]
+async fn unknown_order_is_not_found() {
+    let (_server, adapter) = setup().await;
+
+    let result = adapter.get_order(&BrokerId::new("missing")).await;
+
+    assert!(matches!(result, Err(BrokerError::OrderNotFound { .. })));
+}
+
+#[tokio::test]
+async fn open_orders_exclude_closed_orders() {
+    let (server, adapter) = setup().await;
+    let open = adapter.submit_order(market_buy("ord-1", 10)).await.unwrap();
+    let filled = adapter.submit_order(market_buy("ord-2", 10)).await.unwrap();
+    let expired = adapter.submit_order(market_buy("ord-3", 10)).await.unwrap();
+    let _ = server.fill_order(
+        filled.broker_order_id.as_str(),
+        Decimal::new(10, 0),
+        Decimal::new(100, 0),
+    );
+    let _ = server.close_order(expired.broker_order_id.as_str(), "expired");
+
+    let orders = adapter.get_open_orders().await.unwrap();
+
+    assert_eq!(orders.len(), 1);
+    assert_eq!(orders[0].broker_order_id, open.broker_order_id);
+}
+
+// =============================================================================
+// Retries and Rate Limits
+// =============================================================================
+
+#[tokio::test]
+async fn server_error_burst_is_retried() {
+    let (server, adapter) = setup().await;
+    server.inject(Fault::new("/v2/account", StatusCode::SERVICE_UNAVAILABLE).times(2));
+
+    let buying_power = adapter.get_buying_power().await.unwrap();
+
+    assert_eq!(buying_power, Decimal::new(100_000, 0));
+    assert_eq!(server.request_count(&Method::GET, "/v2/account"), 3);
+}
+
+#[tokio::test]
+async fn persistent_server_error_gives_up() {
+    let (server, adapter) = setup().await;
+    server.inject(Fault::new("/v2/account", StatusCode::BAD_GATEWAY).times(10));
+
+    let result = adapter.get_buying_power().await;
+
+    assert!(matches!(result, Err(BrokerError::ConnectionError { .. })));
+    assert_eq!(server.request_count(&Method::GET, "/v2/account"), 3);
+}
+
+#[tokio::test]
+async fn failed_order_placement_is_not_retried() {
+    let (server, adapter) = setup().await;
+    server.inject(Fault::new("/v2/orders", StatusCode::SERVICE_UNAVAILABLE).on(Method::POST));
+
+    let result = adapter.submit_order(market_buy("ord-1", 10)).await;
+
+    assert!(result.is_err());
+    assert_eq!(server.request_count(&Method::POST, "/v2/orders"), 1);
+}
+
+#[tokio::test]
+async fn adaptive_retry_stops_retrying_a_failing_endpoint() {
+    let server = MockAlpacaServer::start().await.unwrap();
+    let config = server.config().with_adaptive_retry(AdaptiveRetryConfig {
+        min_samples: 3,
+        ..AdaptiveRetryConfig::default()
+    });
+    let adapter = AlpacaBrokerAdapter::new(&config).unwrap();
+    server.inject(Fault::new("/v2/account", StatusCode::BAD_GATEWAY).times(10));
+
+    assert!(adapter.get_buying_power().await.is_err());
+    assert_eq!(server.request_count(&Method::GET, "/v2/account"), 3);
+
+    // Every recent attempt failed: no retries
+    assert!(adapter.get_buying_power().await.is_err());
+    assert_eq!(server.request_count(&Method::GET, "/v2/account"), 4);
+}
+
+#[tokio::test]
+async fn rate_limit_honors_retry_after() {
+    let (server, adapter) = setup().await;
+    server.inject(
+        Fault::rate_limited("/v2/orders")
+            .on(Method::POST)
+            .retry_after(0),
+    );
+
+    let ack = adapter.submit_order(market_buy("ord-1", 10)).await.unwrap();
+
+    assert_eq!(ack.status, OrderStatus::New);
+    assert_eq!(server.request_count(&Method::POST, "/v2/orders"), 2);
+    assert_eq!(server.orders().len(), 1);
+}
+
+#[tokio::test]
+async fn exhausted_rate_limit_is_reported() {
+    let (server, adapter) = setup().await;
+    server.inject(Fault::rate_limited("/v2/account").times(10));
+
+    let result = adapter.get_buying_power().await;
+
+    assert!(matches!(result, Err(BrokerError::RateLimited)));
+    assert_eq!(server.request_count(&Method::GET, "/v2/account"), 3);
+}
+
+#[tokio::test]
+async fn client_error_is_not_retried() {
+    let (server, adapter) = setup().await;
+    server.inject(
+        Fault::new("/v2/orders", StatusCode::UNPROCESSABLE_ENTITY)
+            .on(Method::POST)
+            .body(r#"{"code":42210000,"message":"qty must be > 0"}"#),
+    );
+
+    let result = adapter.submit_order(market_buy("ord-1", 10)).await;
+
+    assert!(result.is_err());
+    assert_eq!(server.request_count(&Method::POST, "/v2/orders"), 1);
+    assert!(server.orders().is_empty());
+}
+
+#[tokio::test]
+async fn latency_within_timeout_succeeds() {
+    let (server, adapter) = setup().await;
+    server.inject(Fault::latency("/v2/account", Duration::from_millis(50)));
+
+    let buying_power = adapter.get_buying_power().await.unwrap();
+
+    assert_eq!(buying_power, Decimal::new(100_000, 0));
+    assert_eq!(server.request_count(&Method::GET, "/v2/account"), 1);
+}
+
+// =============================================================================
+// Account Data
+// =============================================================================
+
+#[tokio::test]
+async fn activities_are_paginated() {
+    let (server, adapter) = setup().await;
+    for i in 0..250 {
+        server.add_activity(json!({
+            "id": format!("20240102093000000::{i:012}"),
+            "activity_type": "FEE",
+            "date": "2024-01-02",
+            "net_amount": "-0.01",
+            "description": format!("fee {i}"),
+        }));
+    }
+
+    let activities = adapter.get_activities(None).await.unwrap();
+
+    assert_eq!(activities.len(), 250);
+    assert!(activities.iter().all(|a| a.kind == ActivityKind::Fee));
+    assert_eq!(activities[0].id, "20240102093000000::000000000000");
+    assert_eq!(activities[249].id, "20240102093000000::000000000249");
+
+    let pages: Vec<_> = server
+        .requests()
+        .into_iter()
+        .filter(|r| r.path == "/v2/account/activities")
+        .collect();
+    assert_eq!(pages.len(), 3);
+    assert!(!pages[0].query.as_deref().unwrap().contains("page_token"));
+    assert!(
+        pages[1]
+            .query
+            .as_deref()
+            .unwrap()
+            .contains("page_token=20240102093000000::000000000099")
+    );
+}
+
+#[tokio::test]
+async fn fills_are_booked_as_activities() {
+    let (server, adapter) = setup().await;
+    let ack = adapter.submit_order(market_buy("ord-1", 10)).await.unwrap();
+    let _ = server.fill_order(
+        ack.broker_order_id.as_str(),
+        Decimal::new(10, 0),
+        Decimal::new(100, 0),
+    );
+
+    let activities = adapter.get_activities(None).await.unwrap();
+
+    assert_eq!(activities.len(), 1);
+    assert_eq!(activities[0].kind, ActivityKind::Fill);
+    assert_eq!(
+        activities[0].order_id.as_deref(),
+        Some(ack.broker_order_id.as_str())
+    );
+    assert_eq!(activities[0].fill_notional(), Some(Decimal::new(1000, 0)));
+}
+
+#[tokio::test]
+async fn missing_position_is_none() {
+    let (server, adapter) = setup().await;
+    server.set_position("MSFT", Decimal::new(-5, 0), Decimal::new(400, 0));
+
+    let aapl = adapter
+        .get_position(&InstrumentId::new("AAPL"))
+        .await
+        .unwrap();
+    let msft = adapter
+        .get_position(&InstrumentId::new("MSFT"))
+        .await
+        .unwrap();
+
+    assert_eq!(aapl, None);
+    assert_eq!(msft, Some(Decimal::new(-5, 0)));
+}
+
+// =============================================================================
+// Credentials
+// =============================================================================
+
+#[tokio::test]
+async fn rotated_credentials_are_used() {
+    let (server, adapter) = setup().await;
+    server.set_credentials("PKROTATED", "rotated-secret");
+
+    assert!(adapter.get_buying_power().await.is_err());
+
+    adapter
+        .rotate_credentials(&AlpacaCredentials::new("PKROTATED", "rotated-secret"))
+        .unwrap();
+
+    assert!(adapter.get_buying_power().await.is_ok());
+}
+
+#[tokio::test]
+async fn oauth_account_trades_with_refreshed_bearer_token() {
+    let server = MockAlpacaServer::start().await.unwrap();
+    // Tokens expire inside the refresh margin, so every request refreshes
+    server.set_oauth_expires_in(30);
+    let accounts = Arc::new(BrokerAccountService::new(
+        Arc::new(AlpacaOAuthClient::new(server.oauth_config()).unwrap()),
+        Arc::new(InMemoryOAuthTokenRepository::new()),
+    ));
+
+    let url = reqwest::Url::parse(&accounts.authorize_url("alice").unwrap()).unwrap();
+    let state = url
+        .query_pairs()
+        .find(|(name, _)| name == "state")
+        .unwrap()
+        .1
+        .into_owned();
+    accounts
+        .complete(&state, MockAlpacaServer::OAUTH_CODE)
+        .await
+        .unwrap();
+
+    // The key headers are rejected; only the bearer token authorizes
+    server.set_credentials("PKOTHER", "other-secret");
+    let adapter =
+        AlpacaBrokerAdapter::with_oauth(&server.config(), Arc::clone(&accounts), "alice").unwrap();
+
+    assert!(adapter.get_buying_power().await.is_ok());
+    assert!(adapter.get_buying_power().await.is_ok());
+    // One code exchange, then one refresh per request
+    assert_eq!(server.request_count(&Method::POST, "/oauth/token"), 3);
+
+    accounts.disconnect("alice").await.unwrap();
+    assert!(matches!(
+        adapter.get_buying_power().await,
+        Err(BrokerError::AuthenticationFailed)
+    ));
+}
+
+// =============================================================================
+// Trade Updates Stream
+// =============================================================================
+
+#[tokio::test]
+async fn trade_updates_follow_order_lifecycle() {
+    let (server, adapter) = setup().await;
+    let (mut ws, _) = tokio_tungstenite::connect_async(server.stream_url())
+        .await
+        .unwrap();
+
+    // Handshake
+    let auth = json!({
+        "action": "auth",
+        "key": MockAlpacaServer::API_KEY,
+        "secret": MockAlpacaServer::API_SECRET,
+    });
+    ws.send(Message::Text(auth.to_string().into()))
+        .await
+        .unwrap();
+    assert!(next_text(&mut ws).await.contains("authorized"));
+    let listen = json!({ "action": "listen", "data": { "streams": ["trade_updates"] } });
+    ws.send(Message::Text(listen.to_string().into()))
+        .await
+        .unwrap();
+    assert!(next_text(&mut ws).await.contains("listening"));
+
+    let ack = adapter.submit_order(market_buy("ord-1", 10)).await.unwrap();
+    let _ = server.fill_order(
+        ack.broker_order_id.as_str(),
+        Decimal::new(4, 0),
+        Decimal::new(100, 0),
+    );
+    let _ = server.fill_order(
+        ack.broker_order_id.as_str(),
+        Decimal::new(6, 0),
+        Decimal::new(100, 0),
+    );
+
+    let mut events = Vec::new();
+    for _ in 0..3 {
+        let update = parse_trade_update(&next_text(&mut ws).await)
+            .unwrap()
+            .unwrap();
+        assert_eq!(update.order_id, ack.broker_order_id.as_str());
+        assert_eq!(update.client_order_id, "ord-1");
+        events.push(update.event);
+    }
+    assert_eq!(
+        events,
+        [TradeEvent::New, TradeEvent::PartialFill, TradeEvent::Fill]
+    );
+
+    server.disconnect_streams();
+    let closed = tokio::time::timeout(Duration::from_secs(1), ws.next())
+        .await
+        .unwrap();
+    assert!(matches!(closed, Some(Ok(Message::Close(_))) | None));
+}
+
+// =============================================================================
+// Order Update Streams
+// =============================================================================
+
+#[tokio::test]
+async fn websocket_and_sse_streams_deliver_the_same_updates() {
+    let (server, adapter) = setup().await;
+    let shutdown = CancellationToken::new();
+    let (tx, mut rx) = mpsc::channel(16);
+    for transport in [OrderUpdateTransport::WebSocket, OrderUpdateTransport::Sse] {
+        let stream = AlpacaOrderUpdateStream::new(&server.config(), transport).unwrap();
+        let (tx, shutdown) = (tx.clone(), shutdown.clone());
+        tokio::spawn(async move { stream.run(tx, shutdown).await });
+    }
+    await_listeners(&server, 2).await;
+
+    let ack = adapter.submit_order(market_buy("ord-1", 10)).await.unwrap();
+    let _ = server.fill_order(
+        ack.broker_order_id.as_str(),
+        Decimal::new(4, 0),
+        Decimal::new(100, 0),
+    );
+    let (websocket, sse) = next_updates(&mut rx, 4).await;
+    for updates in [&websocket, &sse] {
+        let events: Vec<_> = updates.iter().map(|u| u.event.as_str()).collect();
+        assert_eq!(events, ["new", "partial_fill"]);
+        assert_eq!(updates[1].order.broker_order_id, ack.broker_order_id);
+        assert_eq!(updates[1].order.client_order_id, OrderId::new("ord-1"));
+        assert_eq!(updates[1].order.status, OrderStatus::PartiallyFilled);
+        assert_eq!(updates[1].order.filled_qty, Decimal::new(4, 0));
+    }
+
+    // Both streams reconnect after a disconnect
+    server.disconnect_streams();
+    await_listeners(&server, 4).await;
+    let _ = server.fill_order(
+        ack.broker_order_id.as_str(),
+        Decimal::new(6, 0),
+        Decimal::new(100, 0),
+    );
+    let (websocket, sse) = next_updates(&mut rx, 2).await;
+    assert_eq!(websocket[0].event, "fill");
+    assert_eq!(sse[0].order.status, OrderStatus::Filled);
+
+    shutdown.cancel();
+}
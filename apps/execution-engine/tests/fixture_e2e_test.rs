@@ -25,14 +25,20 @@ use axum::http::{Request, StatusCode};
 use rust_decimal::Decimal;
 use serde::{Deserialize, Serialize};
 use std::sync::RwLock;
+use tokio_util::sync::CancellationToken;
 use tower::ServiceExt;
 
 use execution_engine::application::ports::{
-    BrokerError, BrokerPort, CancelOrderRequest, InMemoryRiskRepository, NoOpEventPublisher,
-    OrderAck, SubmitOrderRequest,
+    BrokerError, BrokerPort, CancelOrderRequest, InMemoryRiskRepository,
+    InMemoryScheduledPlanRepository, InMemoryWatchlistRepository, NoOpEventPublisher, OrderAck,
+    SubmitOrderRequest,
+};
+use execution_engine::application::services::{
+    FeatureFlags, KillSwitch, PlanProgressService, PlanSchedulerService, ReadinessService,
+    SessionStatsService, StopAdjuster, TradeBlotter, WatchlistService,
 };
 use execution_engine::application::use_cases::{
-    CancelOrdersUseCase, SubmitOrdersUseCase, ValidateRiskUseCase,
+    CancelOrdersUseCase, CycleTransitionUseCase, SubmitOrdersUseCase, ValidateRiskUseCase,
 };
 use execution_engine::domain::order_execution::aggregate::Order;
 use execution_engine::domain::order_execution::errors::OrderError;
@@ -41,7 +47,7 @@ use execution_engine::domain::order_execution::value_objects::{
     OrderPurpose, OrderSide, OrderStatus, OrderType, TimeInForce,
 };
 use execution_engine::domain::shared::{BrokerId, InstrumentId, OrderId};
-use execution_engine::infrastructure::http::{AppState, create_router};
+use execution_engine::infrastructure::http::{AppState, IdempotencyStore, create_router};
 
 // =============================================================================
 // Fixture JSON structures
@@ -215,6 +221,8 @@ impl BrokerPort for MockBroker {
                 status: OrderStatus::Accepted,
                 filled_qty: Decimal::ZERO,
                 avg_fill_price: None,
+                child_order_ids: Vec::new(),
+                leg_fills: Vec::new(),
             })
         } else {
             Err(BrokerError::OrderRejected {
@@ -453,11 +461,48 @@ fn create_test_app(broker: Arc<MockBroker>) -> axum::Router {
         Arc::clone(&event_publisher),
     ));
 
+    let cycle_transition = Arc::new(CycleTransitionUseCase::new(
+        Arc::clone(&cancel_orders),
+        Arc::clone(&order_repo),
+    ));
+
+    let plan_repo = Arc::new(InMemoryScheduledPlanRepository::new());
+    let plan_scheduler = Arc::new(PlanSchedulerService::new(
+        Arc::clone(&submit_orders),
+        Arc::clone(&plan_repo),
+        CancellationToken::new(),
+    ));
+
+    let readiness = Arc::new(ReadinessService::new(Arc::clone(&broker), plan_repo));
+    let stop_adjuster = Arc::new(StopAdjuster::new(
+        Arc::clone(&order_repo),
+        Arc::clone(&submit_orders),
+        Arc::clone(&cancel_orders),
+    ));
+
     let state = AppState {
         submit_orders,
         validate_risk,
         cancel_orders,
+        cycle_transition,
+        plan_scheduler,
+        readiness,
+        kill_switch: Arc::new(KillSwitch::default()),
+        feature_flags: Arc::new(FeatureFlags::default()),
+        stats: Arc::new(SessionStatsService::new(Arc::clone(&order_repo))),
+        plan_progress: Arc::new(PlanProgressService::new(Arc::clone(&order_repo))),
+        trade_blotter: Arc::new(TradeBlotter::new(Arc::clone(&order_repo))),
+        stop_adjuster,
+        shadow_execution: None,
+        config_reloader: None,
+        state_bundle: None,
+        watchlists: Arc::new(WatchlistService::new(Arc::new(
+            InMemoryWatchlistRepository::new(),
+        ))),
+        broker_accounts: None,
         order_repo,
+        auth: None,
+        idempotency: Arc::new(IdempotencyStore::default()),
         version: "e2e-test".to_string(),
     };
 
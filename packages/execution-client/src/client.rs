@@ -0,0 +1,442 @@
+//! Execution engine gRPC client.
+
+use std::future::Future;
+use std::sync::Arc;
+
+use tonic::Streaming;
+use tonic::metadata::{Ascii, MetadataValue};
+use tonic::transport::{Certificate, Channel, ClientTlsConfig, Endpoint};
+
+use crate::config::ExecutionClientConfig;
+use crate::error::ExecutionClientError;
+use crate::proto::cream::v1::{
+    CancelOrderRequest, CancelOrderResponse, CheckConstraintsRequest, CheckConstraintsResponse,
+    GetOrderStateRequest, GetOrderStateResponse, StreamExecutionsRequest, StreamExecutionsResponse,
+    SubmitOrderRequest, SubmitOrderResponse, execution_service_client::ExecutionServiceClient,
+};
+use crate::retry::{ExponentialBackoff, is_retryable};
+
+/// Metadata key the engine reads API keys from.
+const API_KEY_HEADER: &str = "x-api-key";
+
+/// An order from a plan that the engine did not answer.
+#[derive(Debug)]
+pub struct OrderSubmitFailure {
+    /// Client order ID of the order.
+    pub client_order_id: String,
+    /// Why the call failed.
+    pub error: ExecutionClientError,
+}
+
+/// Outcome of submitting a plan.
+#[derive(Debug, Default)]
+pub struct PlanSubmission {
+    /// Cycle the orders were tagged with.
+    pub cycle_id: String,
+    /// Engine responses, in submission order. Orders the engine rejected
+    /// carry an `error_message`.
+    pub responses: Vec<SubmitOrderResponse>,
+    /// Orders whose call failed.
+    pub failures: Vec<OrderSubmitFailure>,
+}
+
+impl PlanSubmission {
+    /// Check if every order reached the engine and none was rejected.
+    #[must_use]
+    pub fn all_accepted(&self) -> bool {
+        self.failures.is_empty() && self.responses.iter().all(|r| r.error_message.is_none())
+    }
+}
+
+/// Client for the execution engine gRPC service.
+///
+/// Cheap to clone: clones share one channel, which reconnects on its own
+/// after the engine restarts.
+#[derive(Clone)]
+pub struct ExecutionClient {
+    inner: ExecutionServiceClient<Channel>,
+    config: Arc<ExecutionClientConfig>,
+    api_key: Option<MetadataValue<Ascii>>,
+}
+
+impl std::fmt::Debug for ExecutionClient {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ExecutionClient")
+            .field("config", &self.config)
+            .finish_non_exhaustive()
+    }
+}
+
+impl ExecutionClient {
+    /// Connect to the execution engine.
+    ///
+    /// # Errors
+    ///
+    /// Returns error if the configuration is invalid or the engine is
+    /// unreachable.
+    pub async fn connect(config: ExecutionClientConfig) -> Result<Self, ExecutionClientError> {
+        let endpoint = Self::create_endpoint(&config)?;
+        let channel = endpoint.connect().await?;
+
+        tracing::info!(endpoint = %config.endpoint, "Connected to execution engine");
+        Self::with_channel(channel, config)
+    }
+
+    /// Connect lazily (connection established on first request).
+    ///
+    /// # Errors
+    ///
+    /// Returns error if the configuration is invalid.
+    pub fn connect_lazy(config: ExecutionClientConfig) -> Result<Self, ExecutionClientError> {
+        let endpoint = Self::create_endpoint(&config)?;
+        let channel = endpoint.connect_lazy();
+
+        tracing::debug!(endpoint = %config.endpoint, "Created lazy connection to execution engine");
+        Self::with_channel(channel, config)
+    }
+
+    fn with_channel(
+        channel: Channel,
+        config: ExecutionClientConfig,
+    ) -> Result<Self, ExecutionClientError> {
+        let api_key: Option<MetadataValue<Ascii>> = config
+            .api_key
+            .as_deref()
+            .map(MetadataValue::try_from)
+            .transpose()
+            .map_err(|e| ExecutionClientError::InvalidConfig {
+                message: format!("invalid API key: {e}"),
+            })?;
+
+        Ok(Self {
+            inner: ExecutionServiceClient::new(channel),
+            config: Arc::new(config),
+            api_key,
+        })
+    }
+
+    /// Create a configured endpoint from the config.
+    fn create_endpoint(config: &ExecutionClientConfig) -> Result<Endpoint, ExecutionClientError> {
+        let mut endpoint = Channel::from_shared(config.endpoint.clone())
+            .map_err(|e| ExecutionClientError::InvalidConfig {
+                message: format!("invalid endpoint: {e}"),
+            })?
+            .connect_timeout(config.connect_timeout)
+            .tcp_keepalive(Some(config.tcp_keepalive))
+            .http2_keep_alive_interval(config.http2_keepalive_interval)
+            .keep_alive_timeout(config.keepalive_timeout)
+            .keep_alive_while_idle(true)
+            .tcp_nodelay(true);
+
+        if config.uses_tls() {
+            let tls = config.ca_certificate.as_ref().map_or_else(
+                || ClientTlsConfig::new().with_native_roots(),
+                |pem| ClientTlsConfig::new().ca_certificate(Certificate::from_pem(pem)),
+            );
+            endpoint = endpoint.tls_config(tls)?;
+        }
+
+        Ok(endpoint)
+    }
+
+    /// Generated client sharing this client's channel, for RPCs without a
+    /// helper here. Calls made through it get no deadline, retries or API key.
+    #[must_use]
+    pub fn raw(&self) -> ExecutionServiceClient<Channel> {
+        self.inner.clone()
+    }
+
+    /// Validate a decision plan against risk constraints.
+    ///
+    /// # Errors
+    ///
+    /// Returns error if the call fails after retries.
+    pub async fn check_constraints(
+        &self,
+        request: CheckConstraintsRequest,
+    ) -> Result<CheckConstraintsResponse, ExecutionClientError> {
+        self.call(
+            "CheckConstraints",
+            request,
+            true,
+            true,
+            |mut client, r| async move { client.check_constraints(r).await },
+        )
+        .await
+    }
+
+    /// Submit a single order.
+    ///
+    /// # Errors
+    ///
+    /// Returns error if the call fails. Only calls that could not reach the
+    /// engine are retried.
+    pub async fn submit_order(
+        &self,
+        request: SubmitOrderRequest,
+    ) -> Result<SubmitOrderResponse, ExecutionClientError> {
+        self.call(
+            "SubmitOrder",
+            request,
+            false,
+            true,
+            |mut client, r| async move { client.submit_order(r).await },
+        )
+        .await
+    }
+
+    /// Submit the orders of a plan in order, tagged with `cycle_id`.
+    ///
+    /// Orders without a client order ID get `{cycle_id}-{instrument}`. A
+    /// failed order does not stop the rest of the plan.
+    pub async fn submit_plan(
+        &self,
+        cycle_id: &str,
+        orders: Vec<SubmitOrderRequest>,
+    ) -> PlanSubmission {
+        let mut submission = PlanSubmission {
+            cycle_id: cycle_id.to_string(),
+            ..PlanSubmission::default()
+        };
+
+        for order in prepare_plan(cycle_id, orders) {
+            let client_order_id = order.client_order_id.clone();
+            match self.submit_order(order).await {
+                Ok(response) => submission.responses.push(response),
+                Err(error) => {
+                    tracing::warn!(
+                        cycle_id,
+                        client_order_id = %client_order_id,
+                        error = %error,
+                        "Plan order submission failed"
+                    );
+                    submission.failures.push(OrderSubmitFailure {
+                        client_order_id,
+                        error,
+                    });
+                }
+            }
+        }
+
+        submission
+    }
+
+    /// Get the current state of an order.
+    ///
+    /// # Errors
+    ///
+    /// Returns error if the call fails after retries.
+    pub async fn get_order_state(
+        &self,
+        order_id: &str,
+    ) -> Result<GetOrderStateResponse, ExecutionClientError> {
+        let request = GetOrderStateRequest {
+            order_id: order_id.to_string(),
+        };
+        self.call(
+            "GetOrderState",
+            request,
+            true,
+            true,
+            |mut client, r| async move { client.get_order_state(r).await },
+        )
+        .await
+    }
+
+    /// Cancel an order.
+    ///
+    /// # Errors
+    ///
+    /// Returns error if the call fails. Only calls that could not reach the
+    /// engine are retried.
+    pub async fn cancel_order(
+        &self,
+        order_id: &str,
+    ) -> Result<CancelOrderResponse, ExecutionClientError> {
+        let request = CancelOrderRequest {
+            order_id: order_id.to_string(),
+        };
+        self.call(
+            "CancelOrder",
+            request,
+            false,
+            true,
+            |mut client, r| async move { client.cancel_order(r).await },
+        )
+        .await
+    }
+
+    /// Stream execution updates, optionally filtered by cycle and order IDs.
+    ///
+    /// Opening the stream is retried; the stream itself has no deadline.
+    ///
+    /// # Errors
+    ///
+    /// Returns error if the stream cannot be opened after retries.
+    pub async fn stream_order_updates(
+        &self,
+        cycle_id: Option<&str>,
+        order_ids: &[&str],
+    ) -> Result<Streaming<StreamExecutionsResponse>, ExecutionClientError> {
+        let request = StreamExecutionsRequest {
+            cycle_id: cycle_id.map(ToString::to_string),
+            order_ids: order_ids.iter().map(|id| (*id).to_string()).collect(),
+        };
+        self.call(
+            "StreamExecutions",
+            request,
+            true,
+            false,
+            |mut client, r| async move { client.stream_executions(r).await },
+        )
+        .await
+    }
+
+    /// Wrap `message` with the API key and, for unary calls, the deadline.
+    fn request<T>(&self, message: T, deadline: bool) -> tonic::Request<T> {
+        let mut request = tonic::Request::new(message);
+        if deadline {
+            request.set_timeout(self.config.request_timeout);
+        }
+        if let Some(key) = &self.api_key {
+            request.metadata_mut().insert(API_KEY_HEADER, key.clone());
+        }
+        request
+    }
+
+    /// Make a call, retrying transient failures with backoff.
+    async fn call<T, R, F, Fut>(
+        &self,
+        method: &'static str,
+        message: T,
+        idempotent: bool,
+        deadline: bool,
+        send: F,
+    ) -> Result<R, ExecutionClientError>
+    where
+        T: Clone,
+        F: Fn(ExecutionServiceClient<Channel>, tonic::Request<T>) -> Fut,
+        Fut: Future<Output = Result<tonic::Response<R>, tonic::Status>>,
+    {
+        let mut backoff = ExponentialBackoff::new(&self.config.retry);
+        loop {
+            let status =
+                match send(self.inner.clone(), self.request(message.clone(), deadline)).await {
+                    Ok(response) => return Ok(response.into_inner()),
+                    Err(status) => status,
+                };
+
+            let delay = if is_retryable(status.code(), idempotent) {
+                backoff.next_backoff()
+            } else {
+                None
+            };
+            let Some(delay) = delay else {
+                return Err(status.into());
+            };
+
+            tracing::warn!(
+                method,
+                code = ?status.code(),
+                message = status.message(),
+                delay_ms = delay.as_millis(),
+                "Execution engine call failed, retrying"
+            );
+            tokio::time::sleep(delay).await;
+        }
+    }
+}
+
+/// Tag plan orders with the cycle and fill in missing client order IDs.
+fn prepare_plan(cycle_id: &str, orders: Vec<SubmitOrderRequest>) -> Vec<SubmitOrderRequest> {
+    orders
+        .into_iter()
+        .map(|mut order| {
+            order.cycle_id = cycle_id.to_string();
+            if order.client_order_id.is_empty() {
+                let instrument = order
+                    .instrument
+                    .as_ref()
+                    .map_or("order", |i| i.instrument_id.as_str());
+                order.client_order_id = format!("{cycle_id}-{instrument}");
+            }
+            order
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used, clippy::expect_used)]
+
+    use super::*;
+    use crate::proto::cream::v1::Instrument;
+    use std::time::Duration;
+
+    fn order(instrument_id: &str, client_order_id: &str) -> SubmitOrderRequest {
+        SubmitOrderRequest {
+            instrument: Some(Instrument {
+                instrument_id: instrument_id.to_string(),
+                ..Instrument::default()
+            }),
+            client_order_id: client_order_id.to_string(),
+            ..SubmitOrderRequest::default()
+        }
+    }
+
+    #[test]
+    fn prepare_plan_tags_cycle_and_fills_ids() {
+        let orders = prepare_plan(
+            "cycle-1",
+            vec![order("AAPL", ""), order("MSFT", "custom-id")],
+        );
+
+        assert!(orders.iter().all(|o| o.cycle_id == "cycle-1"));
+        assert_eq!(orders[0].client_order_id, "cycle-1-AAPL");
+        assert_eq!(orders[1].client_order_id, "custom-id");
+    }
+
+    #[tokio::test]
+    async fn requests_carry_api_key_and_deadline() {
+        let config = ExecutionClientConfig::new("http://localhost:50053")
+            .with_api_key("test-key")
+            .with_request_timeout(Duration::from_secs(5));
+        let client = ExecutionClient::connect_lazy(config).unwrap();
+
+        let unary = client.request((), true);
+        assert_eq!(unary.metadata().get(API_KEY_HEADER).unwrap(), "test-key");
+        assert!(unary.metadata().get("grpc-timeout").is_some());
+
+        let stream = client.request((), false);
+        assert!(stream.metadata().get("grpc-timeout").is_none());
+    }
+
+    #[test]
+    fn invalid_endpoint_is_rejected() {
+        let result = ExecutionClient::connect_lazy(ExecutionClientConfig::new("not a uri"));
+        assert!(matches!(
+            result,
+            Err(ExecutionClientError::InvalidConfig { .. })
+        ));
+    }
+
+    #[tokio::test]
+    async fn unreachable_engine_fails_after_retries() {
+        let config = ExecutionClientConfig::new("http://127.0.0.1:1")
+            .with_connect_timeout(Duration::from_millis(200))
+            .with_retry(crate::RetryConfig {
+                max_attempts: 2,
+                initial_backoff: Duration::from_millis(1),
+                ..crate::RetryConfig::default()
+            });
+        let client = ExecutionClient::connect_lazy(config).unwrap();
+
+        let submission = client.submit_plan("cycle-1", vec![order("AAPL", "")]).await;
+        assert!(!submission.all_accepted());
+        assert_eq!(submission.failures[0].client_order_id, "cycle-1-AAPL");
+        assert_eq!(
+            submission.failures[0].error.code(),
+            Some(tonic::Code::Unavailable)
+        );
+    }
+}
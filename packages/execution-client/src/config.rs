@@ -0,0 +1,137 @@
+//! Configuration for the execution engine client.
+
+use std::time::Duration;
+
+use crate::retry::RetryConfig;
+
+/// Default execution engine gRPC endpoint.
+pub const DEFAULT_ENDPOINT: &str = "http://localhost:50053";
+
+/// Configuration for connecting to the execution engine.
+#[derive(Clone)]
+pub struct ExecutionClientConfig {
+    /// Engine gRPC endpoint (e.g., `http://localhost:50053`).
+    pub endpoint: String,
+
+    /// API key sent as `x-api-key` (`None` for engines without auth).
+    pub api_key: Option<String>,
+
+    /// Connection timeout.
+    pub connect_timeout: Duration,
+
+    /// Deadline for unary calls. Streams are not subject to it.
+    pub request_timeout: Duration,
+
+    /// TCP keepalive interval.
+    pub tcp_keepalive: Duration,
+
+    /// HTTP/2 keepalive interval.
+    pub http2_keepalive_interval: Duration,
+
+    /// Keepalive timeout.
+    pub keepalive_timeout: Duration,
+
+    /// PEM CA certificate to trust for TLS (native roots when unset).
+    pub ca_certificate: Option<Vec<u8>>,
+
+    /// Retry policy for transient failures.
+    pub retry: RetryConfig,
+}
+
+impl std::fmt::Debug for ExecutionClientConfig {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ExecutionClientConfig")
+            .field("endpoint", &self.endpoint)
+            .field("api_key", &self.api_key.as_ref().map(|_| "[REDACTED]"))
+            .field("connect_timeout", &self.connect_timeout)
+            .field("request_timeout", &self.request_timeout)
+            .field("tcp_keepalive", &self.tcp_keepalive)
+            .field("http2_keepalive_interval", &self.http2_keepalive_interval)
+            .field("keepalive_timeout", &self.keepalive_timeout)
+            .field("ca_certificate", &self.ca_certificate.is_some())
+            .field("retry", &self.retry)
+            .finish()
+    }
+}
+
+impl Default for ExecutionClientConfig {
+    fn default() -> Self {
+        Self {
+            endpoint: DEFAULT_ENDPOINT.to_string(),
+            api_key: None,
+            connect_timeout: Duration::from_secs(10),
+            request_timeout: Duration::from_secs(30),
+            tcp_keepalive: Duration::from_secs(60),
+            http2_keepalive_interval: Duration::from_secs(75),
+            keepalive_timeout: Duration::from_secs(20),
+            ca_certificate: None,
+            retry: RetryConfig::default(),
+        }
+    }
+}
+
+impl ExecutionClientConfig {
+    /// Create a new configuration with the given endpoint.
+    #[must_use]
+    pub fn new(endpoint: impl Into<String>) -> Self {
+        Self {
+            endpoint: endpoint.into(),
+            ..Default::default()
+        }
+    }
+
+    /// Authenticate with an API key.
+    #[must_use]
+    pub fn with_api_key(mut self, api_key: impl Into<String>) -> Self {
+        self.api_key = Some(api_key.into());
+        self
+    }
+
+    /// Set the connection timeout.
+    #[must_use]
+    pub const fn with_connect_timeout(mut self, timeout: Duration) -> Self {
+        self.connect_timeout = timeout;
+        self
+    }
+
+    /// Set the deadline for unary calls.
+    #[must_use]
+    pub const fn with_request_timeout(mut self, timeout: Duration) -> Self {
+        self.request_timeout = timeout;
+        self
+    }
+
+    /// Trust a PEM CA certificate (e.g. the engine's self-signed dev CA).
+    #[must_use]
+    pub fn with_ca_certificate(mut self, pem: impl Into<Vec<u8>>) -> Self {
+        self.ca_certificate = Some(pem.into());
+        self
+    }
+
+    /// Set the retry policy.
+    #[must_use]
+    pub const fn with_retry(mut self, retry: RetryConfig) -> Self {
+        self.retry = retry;
+        self
+    }
+
+    /// Whether the endpoint uses TLS.
+    #[must_use]
+    pub fn uses_tls(&self) -> bool {
+        self.endpoint.starts_with("https://")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn debug_redacts_api_key() {
+        let config = ExecutionClientConfig::new("https://engine:50053").with_api_key("secret");
+        let debug = format!("{config:?}");
+        assert!(!debug.contains("secret"));
+        assert!(debug.contains("REDACTED"));
+        assert!(config.uses_tls());
+    }
+}
@@ -0,0 +1,33 @@
+//! Error types for the execution engine client.
+
+use thiserror::Error;
+
+/// Errors returned by [`ExecutionClient`](crate::ExecutionClient).
+#[derive(Error, Debug)]
+pub enum ExecutionClientError {
+    /// Invalid configuration.
+    #[error("invalid configuration: {message}")]
+    InvalidConfig {
+        /// Error message describing the configuration issue.
+        message: String,
+    },
+
+    /// Transport error while connecting.
+    #[error("transport error: {0}")]
+    Transport(#[from] tonic::transport::Error),
+
+    /// gRPC status returned by the engine (after retries, if retryable).
+    #[error("grpc error: {0}")]
+    Status(#[from] tonic::Status),
+}
+
+impl ExecutionClientError {
+    /// gRPC status code, if the engine answered.
+    #[must_use]
+    pub fn code(&self) -> Option<tonic::Code> {
+        match self {
+            Self::Status(status) => Some(status.code()),
+            Self::InvalidConfig { .. } | Self::Transport(_) => None,
+        }
+    }
+}
@@ -0,0 +1,54 @@
+//! Cream Execution Engine Client
+//!
+//! Typed client for the execution engine's `ExecutionService`. Wraps the
+//! generated tonic stubs so callers get:
+//!
+//! - Per-call deadlines sent as `grpc-timeout`
+//! - Retries with exponential backoff for transient failures
+//! - API key metadata on every call
+//! - A shared, lazily reconnecting channel
+//!
+//! # Example
+//!
+//! ```ignore
+//! use cream_execution_client::{ExecutionClient, ExecutionClientConfig};
+//!
+//! let config = ExecutionClientConfig::new("http://localhost:50053").with_api_key(key);
+//! let client = ExecutionClient::connect(config).await?;
+//!
+//! let submission = client.submit_plan("cycle-42", orders).await;
+//! assert!(submission.all_accepted());
+//!
+//! let mut updates = client.stream_order_updates(Some("cycle-42"), &[]).await?;
+//! while let Some(update) = updates.message().await? {
+//!     println!("{update:?}");
+//! }
+//! ```
+
+mod client;
+mod config;
+mod error;
+mod retry;
+
+pub use client::{ExecutionClient, OrderSubmitFailure, PlanSubmission};
+pub use config::{DEFAULT_ENDPOINT, ExecutionClientConfig};
+pub use error::ExecutionClientError;
+pub use retry::RetryConfig;
+
+/// Generated protobuf types and client stubs.
+#[allow(
+    dead_code,
+    missing_docs,
+    clippy::all,
+    clippy::pedantic,
+    clippy::nursery,
+    clippy::unwrap_used,
+    clippy::expect_used
+)]
+pub mod proto {
+    pub mod cream {
+        pub mod v1 {
+            include!(concat!(env!("OUT_DIR"), "/cream.v1.rs"));
+        }
+    }
+}
@@ -0,0 +1,128 @@
+//! Retry policy for execution engine calls.
+
+use std::time::Duration;
+
+use tonic::Code;
+
+/// Retry configuration.
+#[derive(Debug, Clone)]
+pub struct RetryConfig {
+    /// Maximum number of attempts, including the first.
+    pub max_attempts: u32,
+    /// Initial backoff duration.
+    pub initial_backoff: Duration,
+    /// Maximum backoff duration.
+    pub max_backoff: Duration,
+    /// Backoff multiplier.
+    pub multiplier: f64,
+}
+
+impl Default for RetryConfig {
+    fn default() -> Self {
+        Self {
+            max_attempts: 3,
+            initial_backoff: Duration::from_millis(100),
+            max_backoff: Duration::from_secs(5),
+            multiplier: 2.0,
+        }
+    }
+}
+
+impl RetryConfig {
+    /// Policy that never retries.
+    #[must_use]
+    pub const fn disabled() -> Self {
+        Self {
+            max_attempts: 1,
+            initial_backoff: Duration::ZERO,
+            max_backoff: Duration::ZERO,
+            multiplier: 1.0,
+        }
+    }
+}
+
+/// Check if a failed call may be retried.
+///
+/// Order submission is not idempotent on the engine, so it is only retried
+/// when the engine was unreachable and cannot have seen the request. Reads
+/// are also retried on deadlines, throttling and aborts.
+pub const fn is_retryable(code: Code, idempotent: bool) -> bool {
+    match code {
+        Code::Unavailable => true,
+        Code::DeadlineExceeded | Code::ResourceExhausted | Code::Aborted => idempotent,
+        _ => false,
+    }
+}
+
+/// Exponential backoff between attempts.
+#[derive(Debug)]
+pub struct ExponentialBackoff {
+    attempt: u32,
+    max_attempts: u32,
+    current_backoff: Duration,
+    max_backoff: Duration,
+    multiplier: f64,
+}
+
+impl ExponentialBackoff {
+    pub const fn new(config: &RetryConfig) -> Self {
+        Self {
+            attempt: 0,
+            max_attempts: config.max_attempts,
+            current_backoff: config.initial_backoff,
+            max_backoff: config.max_backoff,
+            multiplier: config.multiplier,
+        }
+    }
+
+    /// Delay before the next attempt, or `None` once attempts are exhausted.
+    pub fn next_backoff(&mut self) -> Option<Duration> {
+        self.attempt += 1;
+        if self.attempt >= self.max_attempts {
+            return None;
+        }
+
+        let backoff = self.current_backoff;
+        self.current_backoff = Duration::from_secs_f64(
+            (self.current_backoff.as_secs_f64() * self.multiplier)
+                .min(self.max_backoff.as_secs_f64()),
+        );
+
+        Some(backoff)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn backoff_grows_to_cap_then_stops() {
+        let mut backoff = ExponentialBackoff::new(&RetryConfig {
+            max_attempts: 4,
+            initial_backoff: Duration::from_millis(100),
+            max_backoff: Duration::from_millis(250),
+            multiplier: 2.0,
+        });
+
+        assert_eq!(backoff.next_backoff(), Some(Duration::from_millis(100)));
+        assert_eq!(backoff.next_backoff(), Some(Duration::from_millis(200)));
+        assert_eq!(backoff.next_backoff(), Some(Duration::from_millis(250)));
+        assert_eq!(backoff.next_backoff(), None);
+    }
+
+    #[test]
+    fn disabled_never_retries() {
+        let mut backoff = ExponentialBackoff::new(&RetryConfig::disabled());
+        assert_eq!(backoff.next_backoff(), None);
+    }
+
+    #[test]
+    fn submissions_retry_only_when_unreachable() {
+        assert!(is_retryable(Code::Unavailable, false));
+        assert!(!is_retryable(Code::DeadlineExceeded, false));
+        assert!(is_retryable(Code::DeadlineExceeded, true));
+        assert!(!is_retryable(Code::InvalidArgument, true));
+        assert!(!is_retryable(Code::PermissionDenied, true));
+    }
+}
@@ -0,0 +1,69 @@
+//! Reconnect backoff.
+
+use std::time::Duration;
+
+use crate::config::ReconnectConfig;
+
+/// Exponential delay between reconnect attempts, reset once a stream opens.
+#[derive(Debug)]
+pub struct Backoff {
+    config: ReconnectConfig,
+    attempt: u32,
+    current: Duration,
+}
+
+impl Backoff {
+    pub fn new(config: &ReconnectConfig) -> Self {
+        Self {
+            config: config.clone(),
+            attempt: 0,
+            current: config.initial_delay,
+        }
+    }
+
+    /// Attempts since the last successful open.
+    pub const fn attempt(&self) -> u32 {
+        self.attempt
+    }
+
+    /// Delay before the next attempt.
+    pub fn next_delay(&mut self) -> Duration {
+        self.attempt = self.attempt.saturating_add(1);
+        let delay = self.current;
+        self.current = Duration::from_secs_f64(
+            (self.current.as_secs_f64() * self.config.multiplier)
+                .min(self.config.max_delay.as_secs_f64()),
+        );
+        delay
+    }
+
+    /// Start over after a successful open.
+    pub const fn reset(&mut self) {
+        self.attempt = 0;
+        self.current = self.config.initial_delay;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn delays_grow_to_cap_and_reset() {
+        let mut backoff = Backoff::new(&ReconnectConfig {
+            initial_delay: Duration::from_millis(100),
+            max_delay: Duration::from_millis(300),
+            multiplier: 2.0,
+        });
+
+        assert_eq!(backoff.next_delay(), Duration::from_millis(100));
+        assert_eq!(backoff.next_delay(), Duration::from_millis(200));
+        assert_eq!(backoff.next_delay(), Duration::from_millis(300));
+        assert_eq!(backoff.next_delay(), Duration::from_millis(300));
+        assert_eq!(backoff.attempt(), 4);
+
+        backoff.reset();
+        assert_eq!(backoff.attempt(), 0);
+        assert_eq!(backoff.next_delay(), Duration::from_millis(100));
+    }
+}
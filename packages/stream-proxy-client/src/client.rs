@@ -0,0 +1,198 @@
+//! Stream proxy gRPC client.
+
+use std::sync::Arc;
+
+use tonic::transport::{Certificate, Channel, ClientTlsConfig, Endpoint};
+
+use crate::config::StreamProxyClientConfig;
+use crate::error::StreamProxyClientError;
+use crate::feed::{
+    Bars, Feed, OptionQuotes, OptionTrades, OrderUpdates, ProxyClient, Quotes, Trades,
+    TradingStatuses,
+};
+use crate::proto::cream::v1::{
+    ConnectionStatus, GetConnectionStatusRequest, OptionQuoteUpdate, OptionTrade, OrderUpdate,
+    StockBar, StockQuote, StockTrade, TradingStatus,
+    stream_proxy_service_client::StreamProxyServiceClient,
+};
+use crate::subscription::{Subscription, Symbols};
+
+/// Client for the stream proxy gRPC service.
+///
+/// Cheap to clone: clones and their subscriptions share one channel, which
+/// reconnects on its own after the proxy restarts.
+#[derive(Debug, Clone)]
+pub struct StreamProxyClient {
+    inner: ProxyClient,
+    config: Arc<StreamProxyClientConfig>,
+}
+
+impl StreamProxyClient {
+    /// Connect to the stream proxy.
+    ///
+    /// # Errors
+    ///
+    /// Returns error if the configuration is invalid or the proxy is
+    /// unreachable.
+    pub async fn connect(config: StreamProxyClientConfig) -> Result<Self, StreamProxyClientError> {
+        let endpoint = Self::create_endpoint(&config)?;
+        let channel = endpoint.connect().await?;
+
+        tracing::info!(endpoint = %config.endpoint, "Connected to stream proxy");
+        Ok(Self::with_channel(channel, config))
+    }
+
+    /// Connect lazily (connection established on first request).
+    ///
+    /// # Errors
+    ///
+    /// Returns error if the configuration is invalid.
+    pub fn connect_lazy(config: StreamProxyClientConfig) -> Result<Self, StreamProxyClientError> {
+        let endpoint = Self::create_endpoint(&config)?;
+        let channel = endpoint.connect_lazy();
+
+        tracing::debug!(endpoint = %config.endpoint, "Created lazy connection to stream proxy");
+        Ok(Self::with_channel(channel, config))
+    }
+
+    fn with_channel(channel: Channel, config: StreamProxyClientConfig) -> Self {
+        Self {
+            inner: StreamProxyServiceClient::new(channel),
+            config: Arc::new(config),
+        }
+    }
+
+    /// Create a configured endpoint from the config.
+    fn create_endpoint(
+        config: &StreamProxyClientConfig,
+    ) -> Result<Endpoint, StreamProxyClientError> {
+        let mut endpoint = Channel::from_shared(config.endpoint.clone())
+            .map_err(|e| StreamProxyClientError::InvalidConfig {
+                message: format!("invalid endpoint: {e}"),
+            })?
+            .connect_timeout(config.connect_timeout)
+            .tcp_keepalive(Some(config.tcp_keepalive))
+            .http2_keep_alive_interval(config.http2_keepalive_interval)
+            .keep_alive_timeout(config.keepalive_timeout)
+            .keep_alive_while_idle(true)
+            .tcp_nodelay(true);
+
+        if config.uses_tls() {
+            let tls = config.ca_certificate.as_ref().map_or_else(
+                || ClientTlsConfig::new().with_native_roots(),
+                |pem| ClientTlsConfig::new().ca_certificate(Certificate::from_pem(pem)),
+            );
+            endpoint = endpoint.tls_config(tls)?;
+        }
+
+        Ok(endpoint)
+    }
+
+    /// Generated client sharing this client's channel, for calls the
+    /// subscriptions here do not cover. Streams opened through it are not
+    /// reopened when they drop.
+    #[must_use]
+    pub fn raw(&self) -> StreamProxyServiceClient<Channel> {
+        self.inner.clone()
+    }
+
+    fn subscribe<F: Feed>(&self, symbols: Symbols) -> Subscription<F::Event> {
+        Subscription::spawn::<F>(self.inner.clone(), symbols, &self.config)
+    }
+
+    /// Subscribe to stock quotes (all symbols when empty).
+    ///
+    /// Must be called within a Tokio runtime.
+    #[must_use]
+    pub fn quotes<I, S>(&self, symbols: I) -> Subscription<StockQuote>
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        self.subscribe::<Quotes>(Symbols::new(symbols))
+    }
+
+    /// Subscribe to stock trades (all symbols when empty).
+    ///
+    /// Must be called within a Tokio runtime.
+    #[must_use]
+    pub fn trades<I, S>(&self, symbols: I) -> Subscription<StockTrade>
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        self.subscribe::<Trades>(Symbols::new(symbols))
+    }
+
+    /// Subscribe to stock minute bars (all symbols when empty).
+    ///
+    /// Must be called within a Tokio runtime.
+    #[must_use]
+    pub fn bars<I, S>(&self, symbols: I) -> Subscription<StockBar>
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        self.subscribe::<Bars>(Symbols::new(symbols))
+    }
+
+    /// Subscribe to trading status halts and resumptions (all symbols when
+    /// empty).
+    ///
+    /// Must be called within a Tokio runtime.
+    #[must_use]
+    pub fn trading_status<I, S>(&self, symbols: I) -> Subscription<TradingStatus>
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        self.subscribe::<TradingStatuses>(Symbols::new(symbols))
+    }
+
+    /// Subscribe to option quotes for contracts and underlying chains
+    /// (`symbols.underlyings`).
+    ///
+    /// Must be called within a Tokio runtime.
+    #[must_use]
+    pub fn option_quotes(&self, symbols: Symbols) -> Subscription<OptionQuoteUpdate> {
+        self.subscribe::<OptionQuotes>(symbols)
+    }
+
+    /// Subscribe to option trades for contracts and underlying chains
+    /// (`symbols.underlyings`).
+    ///
+    /// Must be called within a Tokio runtime.
+    #[must_use]
+    pub fn option_trades(&self, symbols: Symbols) -> Subscription<OptionTrade> {
+        self.subscribe::<OptionTrades>(symbols)
+    }
+
+    /// Subscribe to order updates (all orders when empty).
+    ///
+    /// Must be called within a Tokio runtime.
+    #[must_use]
+    pub fn order_updates<I, S>(&self, symbols: I) -> Subscription<OrderUpdate>
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        self.subscribe::<OrderUpdates>(Symbols::new(symbols))
+    }
+
+    /// Upstream feed status reported by the proxy.
+    ///
+    /// # Errors
+    ///
+    /// Returns error if the call fails or the proxy omits the status.
+    pub async fn connection_status(&self) -> Result<ConnectionStatus, StreamProxyClientError> {
+        self.inner
+            .clone()
+            .get_connection_status(GetConnectionStatusRequest {})
+            .await?
+            .into_inner()
+            .status
+            .ok_or_else(|| StreamProxyClientError::EmptyResponse {
+                message: "connection status missing".to_string(),
+            })
+    }
+}
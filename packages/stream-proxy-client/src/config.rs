@@ -0,0 +1,117 @@
+//! Configuration for the stream proxy client.
+
+use std::time::Duration;
+
+/// Default stream proxy gRPC endpoint.
+pub const DEFAULT_ENDPOINT: &str = "http://localhost:50052";
+
+/// Backoff between attempts to reopen a dropped stream.
+#[derive(Debug, Clone)]
+pub struct ReconnectConfig {
+    /// Delay before the first reconnect attempt.
+    pub initial_delay: Duration,
+    /// Longest delay between attempts.
+    pub max_delay: Duration,
+    /// Growth factor per failed attempt.
+    pub multiplier: f64,
+}
+
+impl Default for ReconnectConfig {
+    fn default() -> Self {
+        Self {
+            initial_delay: Duration::from_millis(500),
+            max_delay: Duration::from_secs(30),
+            multiplier: 2.0,
+        }
+    }
+}
+
+/// Configuration for connecting to the stream proxy.
+#[derive(Debug, Clone)]
+pub struct StreamProxyClientConfig {
+    /// Proxy gRPC endpoint (e.g., `http://localhost:50052`); `https://`
+    /// enables TLS.
+    pub endpoint: String,
+
+    /// Connection timeout.
+    pub connect_timeout: Duration,
+
+    /// TCP keepalive interval.
+    pub tcp_keepalive: Duration,
+
+    /// HTTP/2 keepalive interval.
+    pub http2_keepalive_interval: Duration,
+
+    /// Keepalive timeout.
+    pub keepalive_timeout: Duration,
+
+    /// PEM CA certificate to trust for TLS (native roots when unset).
+    pub ca_certificate: Option<Vec<u8>>,
+
+    /// Events buffered per subscription before the stream task waits on
+    /// the consumer.
+    pub channel_capacity: usize,
+
+    /// Backoff for reopening dropped streams.
+    pub reconnect: ReconnectConfig,
+}
+
+impl Default for StreamProxyClientConfig {
+    fn default() -> Self {
+        Self {
+            endpoint: DEFAULT_ENDPOINT.to_string(),
+            connect_timeout: Duration::from_secs(10),
+            tcp_keepalive: Duration::from_secs(60),
+            http2_keepalive_interval: Duration::from_secs(75),
+            keepalive_timeout: Duration::from_secs(20),
+            ca_certificate: None,
+            channel_capacity: 1024,
+            reconnect: ReconnectConfig::default(),
+        }
+    }
+}
+
+impl StreamProxyClientConfig {
+    /// Create a new configuration with the given endpoint.
+    #[must_use]
+    pub fn new(endpoint: impl Into<String>) -> Self {
+        Self {
+            endpoint: endpoint.into(),
+            ..Default::default()
+        }
+    }
+
+    /// Set the connection timeout.
+    #[must_use]
+    pub const fn with_connect_timeout(mut self, timeout: Duration) -> Self {
+        self.connect_timeout = timeout;
+        self
+    }
+
+    /// Trust a PEM CA certificate (e.g. the proxy's self-signed dev CA).
+    #[must_use]
+    pub fn with_ca_certificate(mut self, pem: impl Into<Vec<u8>>) -> Self {
+        self.ca_certificate = Some(pem.into());
+        self
+    }
+
+    /// Set the per-subscription channel capacity.
+    #[must_use]
+    pub const fn with_channel_capacity(mut self, capacity: usize) -> Self {
+        self.channel_capacity = capacity;
+        self
+    }
+
+    /// Set the reconnect backoff.
+    #[must_use]
+    pub const fn with_reconnect(mut self, reconnect: ReconnectConfig) -> Self {
+        self.reconnect = reconnect;
+        self
+    }
+
+    /// Whether the endpoint uses TLS.
+    #[must_use]
+    pub fn uses_tls(&self) -> bool {
+        self.endpoint.starts_with("https://")
+    }
+}
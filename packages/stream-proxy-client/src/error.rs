@@ -0,0 +1,29 @@
+//! Error types for the stream proxy client.
+
+use thiserror::Error;
+
+/// Errors returned by [`StreamProxyClient`](crate::StreamProxyClient).
+#[derive(Error, Debug)]
+pub enum StreamProxyClientError {
+    /// Invalid configuration.
+    #[error("invalid configuration: {message}")]
+    InvalidConfig {
+        /// Error message describing the configuration issue.
+        message: String,
+    },
+
+    /// Transport error while connecting.
+    #[error("transport error: {0}")]
+    Transport(#[from] tonic::transport::Error),
+
+    /// gRPC status returned by the proxy.
+    #[error("grpc error: {0}")]
+    Status(#[from] tonic::Status),
+
+    /// The proxy answered without the expected payload.
+    #[error("empty response: {message}")]
+    EmptyResponse {
+        /// What was missing.
+        message: String,
+    },
+}
@@ -0,0 +1,176 @@
+//! Proxy feeds a subscription can follow.
+//!
+//! Each feed knows how to open its server stream for a symbol set and how
+//! to pull the event out of a response, so the subscription task stays
+//! generic.
+
+use std::future::Future;
+
+use tonic::transport::Channel;
+use tonic::{Status, Streaming};
+
+use crate::proto::cream::v1::{
+    OptionQuoteUpdate, OptionTrade, OrderUpdate, StockBar, StockQuote, StockTrade,
+    StreamBarsRequest, StreamBarsResponse, StreamOptionQuotesRequest, StreamOptionQuotesResponse,
+    StreamOptionTradesRequest, StreamOptionTradesResponse, StreamOrderUpdatesRequest,
+    StreamOrderUpdatesResponse, StreamQuotesRequest, StreamQuotesResponse, StreamTradesRequest,
+    StreamTradesResponse, StreamTradingStatusRequest, StreamTradingStatusResponse, TradingStatus,
+    stream_proxy_service_client::StreamProxyServiceClient,
+};
+use crate::subscription::Symbols;
+
+/// Generated client over the shared channel.
+pub type ProxyClient = StreamProxyServiceClient<Channel>;
+
+/// A server-streaming RPC of the proxy.
+pub trait Feed: Send + 'static {
+    /// Streamed response message.
+    type Response: Send + 'static;
+    /// Event delivered to the consumer.
+    type Event: Send + 'static;
+    /// Feed name for logs.
+    const NAME: &'static str;
+
+    /// Open the stream for `symbols`.
+    fn open(
+        client: ProxyClient,
+        symbols: Symbols,
+    ) -> impl Future<Output = Result<Streaming<Self::Response>, Status>> + Send;
+
+    /// Event carried by `response`, if any.
+    fn event(response: Self::Response) -> Option<Self::Event>;
+}
+
+/// Feed keyed by symbols only.
+macro_rules! symbol_feed {
+    ($feed:ident, $name:literal, $method:ident, $request:ident, $response:ident, $field:ident, $event:ty) => {
+        pub struct $feed;
+
+        impl Feed for $feed {
+            type Response = $response;
+            type Event = $event;
+            const NAME: &'static str = $name;
+
+            async fn open(
+                mut client: ProxyClient,
+                symbols: Symbols,
+            ) -> Result<Streaming<$response>, Status> {
+                let request = $request {
+                    symbols: symbols.symbols.into_iter().collect(),
+                };
+                Ok(client.$method(request).await?.into_inner())
+            }
+
+            fn event(response: $response) -> Option<$event> {
+                response.$field
+            }
+        }
+    };
+}
+
+/// Option feed keyed by contracts and underlyings.
+macro_rules! option_feed {
+    ($feed:ident, $name:literal, $method:ident, $request:ident, $response:ident, $field:ident, $event:ty) => {
+        pub struct $feed;
+
+        impl Feed for $feed {
+            type Response = $response;
+            type Event = $event;
+            const NAME: &'static str = $name;
+
+            async fn open(
+                mut client: ProxyClient,
+                symbols: Symbols,
+            ) -> Result<Streaming<$response>, Status> {
+                let request = $request {
+                    symbols: symbols.symbols.into_iter().collect(),
+                    underlyings: symbols.underlyings.into_iter().collect(),
+                };
+                Ok(client.$method(request).await?.into_inner())
+            }
+
+            fn event(response: $response) -> Option<$event> {
+                response.$field
+            }
+        }
+    };
+}
+
+symbol_feed!(
+    Quotes,
+    "stock_quotes",
+    stream_quotes,
+    StreamQuotesRequest,
+    StreamQuotesResponse,
+    quote,
+    StockQuote
+);
+symbol_feed!(
+    Trades,
+    "stock_trades",
+    stream_trades,
+    StreamTradesRequest,
+    StreamTradesResponse,
+    trade,
+    StockTrade
+);
+symbol_feed!(
+    Bars,
+    "stock_bars",
+    stream_bars,
+    StreamBarsRequest,
+    StreamBarsResponse,
+    bar,
+    StockBar
+);
+symbol_feed!(
+    TradingStatuses,
+    "trading_status",
+    stream_trading_status,
+    StreamTradingStatusRequest,
+    StreamTradingStatusResponse,
+    status,
+    TradingStatus
+);
+option_feed!(
+    OptionQuotes,
+    "option_quotes",
+    stream_option_quotes,
+    StreamOptionQuotesRequest,
+    StreamOptionQuotesResponse,
+    quote,
+    OptionQuoteUpdate
+);
+option_feed!(
+    OptionTrades,
+    "option_trades",
+    stream_option_trades,
+    StreamOptionTradesRequest,
+    StreamOptionTradesResponse,
+    trade,
+    OptionTrade
+);
+
+/// Order updates, filtered by symbol.
+pub struct OrderUpdates;
+
+impl Feed for OrderUpdates {
+    type Response = StreamOrderUpdatesResponse;
+    type Event = OrderUpdate;
+    const NAME: &'static str = "order_updates";
+
+    async fn open(
+        mut client: ProxyClient,
+        symbols: Symbols,
+    ) -> Result<Streaming<StreamOrderUpdatesResponse>, Status> {
+        let request = StreamOrderUpdatesRequest {
+            order_ids: vec![],
+            symbols: symbols.symbols.into_iter().collect(),
+        };
+        Ok(client.stream_order_updates(request).await?.into_inner())
+    }
+
+    fn event(response: StreamOrderUpdatesResponse) -> Option<OrderUpdate> {
+        response.update
+    }
+}
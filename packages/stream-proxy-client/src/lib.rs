@@ -0,0 +1,56 @@
+//! Cream Stream Proxy Client
+//!
+//! Client for the stream proxy's `StreamProxyService` that owns the
+//! subscription lifecycle every consumer used to reimplement:
+//!
+//! - Each subscription runs in a background task and delivers typed events
+//!   over a bounded channel
+//! - Dropped streams are reopened with exponential backoff
+//! - The current symbol set is resubscribed after every reconnect, and
+//!   changing it reopens the stream immediately
+//!
+//! # Example
+//!
+//! ```ignore
+//! use cream_stream_proxy_client::{StreamProxyClient, StreamProxyClientConfig};
+//!
+//! let client = StreamProxyClient::connect_lazy(StreamProxyClientConfig::new(
+//!     "http://localhost:50052",
+//! ))?;
+//!
+//! let mut quotes = client.quotes(["AAPL", "MSFT"]);
+//! quotes.add_symbols(["NVDA"]);
+//! while let Some(quote) = quotes.recv().await {
+//!     println!("{} {} x {}", quote.symbol, quote.bid_price, quote.ask_price);
+//! }
+//! ```
+
+mod backoff;
+mod client;
+mod config;
+mod error;
+mod feed;
+mod subscription;
+
+pub use client::StreamProxyClient;
+pub use config::{DEFAULT_ENDPOINT, ReconnectConfig, StreamProxyClientConfig};
+pub use error::StreamProxyClientError;
+pub use subscription::{Subscription, SubscriptionState, Symbols};
+
+/// Generated protobuf types and client stubs.
+#[allow(
+    dead_code,
+    missing_docs,
+    clippy::all,
+    clippy::pedantic,
+    clippy::nursery,
+    clippy::unwrap_used,
+    clippy::expect_used
+)]
+pub mod proto {
+    pub mod cream {
+        pub mod v1 {
+            include!(concat!(env!("OUT_DIR"), "/cream.v1.rs"));
+        }
+    }
+}
@@ -0,0 +1,340 @@
+//! Auto-resubscribing stream subscriptions.
+
+use std::collections::BTreeSet;
+
+use tokio::sync::{mpsc, watch};
+use tokio::task::JoinHandle;
+
+use crate::backoff::Backoff;
+use crate::config::StreamProxyClientConfig;
+use crate::feed::{Feed, ProxyClient};
+
+/// Symbols a subscription follows.
+///
+/// An empty set subscribes to every symbol the proxy carries.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Symbols {
+    /// Stock symbols, or OCC contract symbols for option feeds.
+    pub symbols: BTreeSet<String>,
+    /// Underlyings whose whole option chain to follow (option feeds only).
+    pub underlyings: BTreeSet<String>,
+}
+
+impl Symbols {
+    /// Symbol set from `symbols`.
+    #[must_use]
+    pub fn new<I, S>(symbols: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        Self {
+            symbols: symbols.into_iter().map(Into::into).collect(),
+            underlyings: BTreeSet::new(),
+        }
+    }
+
+    /// Also follow the option chains of `underlyings`.
+    #[must_use]
+    pub fn with_underlyings<I, S>(mut self, underlyings: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        self.underlyings
+            .extend(underlyings.into_iter().map(Into::into));
+        self
+    }
+}
+
+/// Where a subscription's stream stands.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SubscriptionState {
+    /// Opening the stream for the first time.
+    Connecting,
+    /// Stream open and delivering events.
+    Streaming,
+    /// Stream dropped; waiting to reopen it.
+    Reconnecting {
+        /// Failed attempts since the stream was last open.
+        attempt: u32,
+        /// Why the last attempt ended.
+        reason: String,
+    },
+}
+
+/// A live subscription to one proxy feed.
+///
+/// Events arrive through [`recv`](Self::recv). The stream is reopened with
+/// backoff whenever it drops, always for the current symbol set; changing
+/// the set reopens it right away. Dropping the subscription stops its task.
+#[derive(Debug)]
+pub struct Subscription<E> {
+    events: mpsc::Receiver<E>,
+    symbols: watch::Sender<Symbols>,
+    state: watch::Receiver<SubscriptionState>,
+    task: JoinHandle<()>,
+}
+
+impl<E: Send + 'static> Subscription<E> {
+    /// Start following `F` for `symbols`.
+    pub(crate) fn spawn<F>(
+        client: ProxyClient,
+        symbols: Symbols,
+        config: &StreamProxyClientConfig,
+    ) -> Self
+    where
+        F: Feed<Event = E>,
+    {
+        let (events_tx, events) = mpsc::channel(config.channel_capacity.max(1));
+        let (symbols, symbols_rx) = watch::channel(symbols);
+        let (state_tx, state) = watch::channel(SubscriptionState::Connecting);
+        let task = tokio::spawn(run::<F>(
+            client,
+            symbols_rx,
+            events_tx,
+            state_tx,
+            Backoff::new(&config.reconnect),
+        ));
+
+        Self {
+            events,
+            symbols,
+            state,
+            task,
+        }
+    }
+}
+
+impl<E> Subscription<E> {
+    /// Next event, waiting across reconnects.
+    ///
+    /// Returns `None` only once the subscription task has stopped.
+    pub async fn recv(&mut self) -> Option<E> {
+        self.events.recv().await
+    }
+
+    /// Current symbol set.
+    #[must_use]
+    pub fn symbols(&self) -> Symbols {
+        self.symbols.borrow().clone()
+    }
+
+    /// Follow additional symbols.
+    pub fn add_symbols<I, S>(&self, symbols: I)
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        let added: Vec<String> = symbols.into_iter().map(Into::into).collect();
+        self.symbols.send_if_modified(|current| {
+            // Every symbol is inserted; `any` would stop at the first new one
+            let mut changed = false;
+            for symbol in added {
+                changed |= current.symbols.insert(symbol);
+            }
+            changed
+        });
+    }
+
+    /// Stop following symbols.
+    pub fn remove_symbols<I, S>(&self, symbols: I)
+    where
+        I: IntoIterator<Item = S>,
+        S: AsRef<str>,
+    {
+        let removed: Vec<S> = symbols.into_iter().collect();
+        self.symbols.send_if_modified(|current| {
+            // Every symbol is removed; `any` would stop at the first match
+            let mut changed = false;
+            for symbol in &removed {
+                changed |= current.symbols.remove(symbol.as_ref());
+            }
+            changed
+        });
+    }
+
+    /// Replace the symbol set.
+    pub fn set_symbols(&self, symbols: Symbols) {
+        self.symbols.send_if_modified(|current| {
+            if *current == symbols {
+                return false;
+            }
+            *current = symbols;
+            true
+        });
+    }
+
+    /// Current stream state.
+    #[must_use]
+    pub fn state(&self) -> SubscriptionState {
+        self.state.borrow().clone()
+    }
+
+    /// Receiver notified on every state change.
+    #[must_use]
+    pub fn state_changes(&self) -> watch::Receiver<SubscriptionState> {
+        self.state.clone()
+    }
+}
+
+impl<E> Drop for Subscription<E> {
+    fn drop(&mut self) {
+        self.task.abort();
+    }
+}
+
+/// Why an open stream stopped.
+enum StreamEnd {
+    /// The symbol set changed; reopen without waiting.
+    SymbolsChanged,
+    /// The stream failed or the proxy ended it.
+    Dropped(String),
+    /// The subscription is gone.
+    Closed,
+}
+
+/// Keep `F` streaming into `events` until the subscription is dropped.
+async fn run<F: Feed>(
+    client: ProxyClient,
+    mut symbols: watch::Receiver<Symbols>,
+    events: mpsc::Sender<F::Event>,
+    state: watch::Sender<SubscriptionState>,
+    mut backoff: Backoff,
+) {
+    loop {
+        let current = symbols.borrow_and_update().clone();
+        let end = match F::open(client.clone(), current.clone()).await {
+            Ok(mut stream) => {
+                if backoff.attempt() > 0 {
+                    tracing::info!(
+                        feed = F::NAME,
+                        attempts = backoff.attempt(),
+                        "Stream resubscribed"
+                    );
+                }
+                backoff.reset();
+                state.send_replace(SubscriptionState::Streaming);
+                tracing::debug!(feed = F::NAME, symbols = ?current, "Stream opened");
+
+                loop {
+                    tokio::select! {
+                        message = stream.message() => match message {
+                            Ok(Some(response)) => {
+                                let Some(event) = F::event(response) else {
+                                    continue;
+                                };
+                                if events.send(event).await.is_err() {
+                                    break StreamEnd::Closed;
+                                }
+                            }
+                            Ok(None) => break StreamEnd::Dropped("stream ended by proxy".to_string()),
+                            Err(status) => break StreamEnd::Dropped(status.to_string()),
+                        },
+                        changed = symbols.changed() => {
+                            break if changed.is_ok() {
+                                StreamEnd::SymbolsChanged
+                            } else {
+                                StreamEnd::Closed
+                            };
+                        }
+                        () = events.closed() => break StreamEnd::Closed,
+                    }
+                }
+            }
+            Err(status) => StreamEnd::Dropped(status.to_string()),
+        };
+
+        let reason = match end {
+            StreamEnd::SymbolsChanged => {
+                tracing::debug!(feed = F::NAME, "Symbols changed, resubscribing");
+                continue;
+            }
+            StreamEnd::Closed => return,
+            StreamEnd::Dropped(reason) => reason,
+        };
+
+        let delay = backoff.next_delay();
+        tracing::warn!(
+            feed = F::NAME,
+            attempt = backoff.attempt(),
+            delay_ms = delay.as_millis(),
+            reason = %reason,
+            "Stream dropped, reconnecting"
+        );
+        state.send_replace(SubscriptionState::Reconnecting {
+            attempt: backoff.attempt(),
+            reason,
+        });
+
+        tokio::select! {
+            () = tokio::time::sleep(delay) => {}
+            changed = symbols.changed() => {
+                if changed.is_err() {
+                    return;
+                }
+            }
+            () = events.closed() => return,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used, clippy::expect_used)]
+
+    use std::time::Duration;
+
+    use super::*;
+    use crate::{ReconnectConfig, StreamProxyClient};
+
+    fn unreachable_client() -> StreamProxyClient {
+        let config = StreamProxyClientConfig::new("http://127.0.0.1:1")
+            .with_connect_timeout(Duration::from_millis(200))
+            .with_reconnect(ReconnectConfig {
+                initial_delay: Duration::from_secs(60),
+                ..ReconnectConfig::default()
+            });
+        StreamProxyClient::connect_lazy(config).unwrap()
+    }
+
+    #[tokio::test]
+    async fn dropped_stream_reconnects_with_symbols_kept() {
+        let quotes = unreachable_client().quotes(["AAPL"]);
+        let mut states = quotes.state_changes();
+
+        tokio::time::timeout(
+            Duration::from_secs(5),
+            states.wait_for(|s| matches!(s, SubscriptionState::Reconnecting { .. })),
+        )
+        .await
+        .unwrap()
+        .unwrap();
+
+        let SubscriptionState::Reconnecting { attempt, .. } = quotes.state() else {
+            panic!("expected reconnecting");
+        };
+        assert_eq!(attempt, 1);
+        assert_eq!(quotes.symbols(), Symbols::new(["AAPL"]));
+    }
+
+    #[tokio::test]
+    async fn symbol_changes_only_notify_when_modified() {
+        let quotes = unreachable_client().quotes(["AAPL"]);
+        let mut watcher = quotes.symbols.subscribe();
+        watcher.mark_unchanged();
+
+        quotes.add_symbols(["AAPL"]);
+        assert!(!watcher.has_changed().unwrap());
+
+        quotes.add_symbols(["MSFT"]);
+        assert!(watcher.has_changed().unwrap());
+        watcher.mark_unchanged();
+
+        quotes.remove_symbols(["NVDA"]);
+        assert!(!watcher.has_changed().unwrap());
+
+        quotes.remove_symbols(["AAPL"]);
+        assert_eq!(quotes.symbols(), Symbols::new(["MSFT"]));
+    }
+}